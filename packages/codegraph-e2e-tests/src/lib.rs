@@ -0,0 +1,144 @@
+//! Cross-crate integration tests
+//!
+//! Fixture helpers shared by the tests in `tests/`: a small Python
+//! repository written to a temp dir, indexing it with the E2E pipeline, and
+//! converting the IR into the storage and cross-file models, the way a
+//! deployment glues the crates together.
+//!
+//! ```text
+//! fixture repo → IRIndexingOrchestrator → CodeSnapshotStore (SQLite)
+//!                        │                        │
+//!                        ├→ cross-file context    └→ compare_commits
+//!                        └→ QueryEngine
+//! ```
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+
+use chrono::Utc;
+use codegraph_ir::features::cross_file::IRDocument as CrossFileDocument;
+use codegraph_ir::features::ir_generation::domain::ir_document::IRDocument;
+use codegraph_ir::features::storage::Chunk;
+use codegraph_ir::pipeline::{E2EPipelineConfig, E2EPipelineResult, IRIndexingOrchestrator};
+use codegraph_ir::shared::models::{CodegraphError, NodeKind};
+
+pub const REPO_ID: &str = "fixture";
+
+/// Fixture repository at its first commit: `api` → `service` → `db`
+pub const FIXTURE_V1: &[(&str, &str)] = &[
+    (
+        "pkg/db.py",
+        "def connect(url):\n    return url\n\n\ndef query(sql):\n    conn = connect(\"sqlite://\")\n    return conn\n",
+    ),
+    (
+        "pkg/service.py",
+        "from pkg.db import query\n\n\ndef load_user(user_id):\n    return query(\"SELECT * FROM users\")\n",
+    ),
+    (
+        "pkg/api.py",
+        "from pkg.service import load_user\n\n\ndef handler(request):\n    return load_user(request)\n",
+    ),
+];
+
+/// Second commit: `db.query` changes and `db.close` is added
+pub const DB_V2: &str = "def connect(url):\n    return url\n\n\ndef query(sql):\n    conn = connect(\"postgres://\")\n    return [conn, sql]\n\n\ndef close(conn):\n    return None\n";
+
+/// Write `files` under `root`
+pub fn write_files(root: &Path, files: &[(&str, &str)]) {
+    for (path, content) in files {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+}
+
+/// Run the E2E pipeline over `root`
+pub fn index(root: &Path) -> Result<E2EPipelineResult, CodegraphError> {
+    let config = E2EPipelineConfig::fast()
+        .repo_root(root.to_path_buf())
+        .repo_name(REPO_ID.to_string());
+    IRIndexingOrchestrator::new(config).execute()
+}
+
+/// Per-file documents, ordered by path
+pub fn cross_file_documents(result: &E2EPipelineResult) -> Vec<CrossFileDocument> {
+    let mut files: BTreeMap<&str, CrossFileDocument> = BTreeMap::new();
+    for node in &result.nodes {
+        files
+            .entry(node.file_path.as_str())
+            .or_insert_with(|| CrossFileDocument::new(node.file_path.clone(), vec![], vec![]))
+            .nodes
+            .push(node.clone());
+    }
+
+    // Edges belong to the file of their source node
+    let file_of: HashMap<&str, &str> = result
+        .nodes
+        .iter()
+        .map(|n| (n.id.as_str(), n.file_path.as_str()))
+        .collect();
+    for edge in &result.edges {
+        if let Some(doc) = file_of
+            .get(edge.source_id.as_str())
+            .and_then(|file| files.get_mut(file))
+        {
+            doc.edges.push(edge.clone());
+        }
+    }
+    files.into_values().collect()
+}
+
+/// Whole-repository document for `QueryEngine`
+pub fn query_document(result: &E2EPipelineResult) -> IRDocument {
+    IRDocument {
+        file_path: REPO_ID.to_string(),
+        nodes: result.nodes.clone(),
+        edges: result.edges.clone(),
+    }
+}
+
+/// Function/class chunks of one file, as stored for `snapshot_id`
+pub fn file_chunks(
+    result: &E2EPipelineResult,
+    snapshot_id: &str,
+    file_path: &str,
+    source: &str,
+) -> Vec<Chunk> {
+    let lines: Vec<&str> = source.lines().collect();
+    result
+        .nodes
+        .iter()
+        .filter(|n| n.file_path == file_path)
+        .filter(|n| matches!(n.kind, NodeKind::Function | NodeKind::Class))
+        .map(|n| {
+            let start = (n.span.start_line as usize).saturating_sub(1);
+            let end = (n.span.end_line as usize).min(lines.len());
+            let content = lines[start.min(end)..end].join("\n");
+            let mut hasher = DefaultHasher::new();
+            content.hash(&mut hasher);
+
+            Chunk {
+                chunk_id: format!("{}:{}:{}", snapshot_id, file_path, n.fqn),
+                repo_id: REPO_ID.to_string(),
+                snapshot_id: snapshot_id.to_string(),
+                file_path: file_path.to_string(),
+                start_line: n.span.start_line,
+                end_line: n.span.end_line,
+                kind: format!("{:?}", n.kind).to_lowercase(),
+                fqn: Some(n.fqn.clone()),
+                language: n.language.clone(),
+                symbol_visibility: None,
+                content,
+                content_hash: format!("{:016x}", hasher.finish()),
+                summary: None,
+                importance: 0.5,
+                is_deleted: false,
+                attrs: HashMap::new(),
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            }
+        })
+        .collect()
+}
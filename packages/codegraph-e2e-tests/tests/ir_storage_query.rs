@@ -0,0 +1,131 @@
+//! IR → storage → query across a simulated commit
+//!
+//! Indexes the fixture repository at two commits, persists both snapshots
+//! through `CodeSnapshotStore`, then checks the snapshot diff, the
+//! cross-file impact of the change and queries over the new IR.
+
+use std::collections::HashSet;
+
+use chrono::Utc;
+use codegraph_e2e_tests::{
+    cross_file_documents, file_chunks, index, query_document, write_files, DB_V2, FIXTURE_V1,
+    REPO_ID,
+};
+use codegraph_ir::features::cross_file::{
+    build_global_context, update_global_context_affected, ProjectLayout,
+};
+use codegraph_ir::features::query_engine::{QueryEngine, SearchFilters};
+use codegraph_ir::features::storage::{
+    ChunkStore, CodeSnapshotStore, Repository, SqliteChunkStore,
+};
+use codegraph_ir::pipeline::E2EPipelineResult;
+
+/// Store every fixture file of `result` as `snapshot_id`
+async fn persist(
+    store: &CodeSnapshotStore,
+    result: &E2EPipelineResult,
+    snapshot_id: &str,
+    files: &[(&str, &str)],
+) {
+    store
+        .create_snapshot(REPO_ID, snapshot_id, Some(snapshot_id.to_string()), None)
+        .await
+        .unwrap();
+    for (path, source) in files {
+        let chunks = file_chunks(result, snapshot_id, path, source);
+        assert!(!chunks.is_empty(), "no chunks for {}", path);
+        store
+            .replace_file(REPO_ID, snapshot_id, snapshot_id, path, chunks, vec![])
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_index_persist_replace_and_query() {
+    let repo = tempfile::tempdir().unwrap();
+    let db = tempfile::tempdir().unwrap();
+    write_files(repo.path(), FIXTURE_V1);
+
+    let chunk_store = SqliteChunkStore::new(db.path().join("codegraph.db")).unwrap();
+    chunk_store
+        .save_repository(&Repository {
+            repo_id: REPO_ID.to_string(),
+            name: REPO_ID.to_string(),
+            remote_url: None,
+            local_path: Some(repo.path().display().to_string()),
+            default_branch: "main".to_string(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        })
+        .await
+        .unwrap();
+    let store = CodeSnapshotStore::new(chunk_store);
+
+    // Commit 1: full index
+    let v1 = index(repo.path()).unwrap();
+    let context_v1 = build_global_context(cross_file_documents(&v1));
+    assert_eq!(
+        context_v1.file_dependencies["pkg/service.py"],
+        vec!["pkg/db.py"]
+    );
+    assert_eq!(
+        context_v1.file_dependencies["pkg/api.py"],
+        vec!["pkg/service.py"]
+    );
+    persist(&store, &v1, "v1", FIXTURE_V1).await;
+
+    // Commit 2: db.py changes; its file is replaced in the new snapshot
+    write_files(repo.path(), &[("pkg/db.py", DB_V2)]);
+    let v2 = index(repo.path()).unwrap();
+    let files_v2: Vec<(&str, &str)> = FIXTURE_V1
+        .iter()
+        .map(|&(path, source)| (path, if path == "pkg/db.py" { DB_V2 } else { source }))
+        .collect();
+    persist(&store, &v2, "v2", &files_v2).await;
+
+    // Diff: query modified, close added, nothing deleted
+    let diff = store.compare_commits(REPO_ID, "v1", "v2").await.unwrap();
+    let modified: Vec<_> = diff
+        .modified
+        .iter()
+        .filter_map(|(_, new)| new.fqn.as_deref())
+        .collect();
+    let added: Vec<_> = diff.added.iter().filter_map(|c| c.fqn.as_deref()).collect();
+    assert_eq!(modified, vec!["pkg.db.query"]);
+    assert_eq!(added, vec!["pkg.db.close"]);
+    assert!(diff.deleted.is_empty());
+
+    // Impact: db.py's change reaches service.py and, through it, api.py
+    let docs_v2 = cross_file_documents(&v2);
+    let (context_v2, affected) = update_global_context_affected(
+        &context_v1,
+        &["pkg/db.py".to_string()],
+        &docs_v2,
+        &ProjectLayout::default(),
+    );
+    let affected: HashSet<_> = affected.iter().map(String::as_str).collect();
+    assert_eq!(
+        affected,
+        HashSet::from(["pkg/db.py", "pkg/service.py", "pkg/api.py"])
+    );
+    assert_eq!(
+        context_v2.file_dependencies,
+        build_global_context(docs_v2).file_dependencies
+    );
+
+    // Query: stored snapshots and the new IR agree on where symbols live
+    let history = store.get_chunks_by_fqn("pkg.db.query").await.unwrap();
+    let snapshots: HashSet<_> = history.iter().map(|c| c.snapshot_id.as_str()).collect();
+    assert_eq!(snapshots, HashSet::from(["v1", "v2"]));
+    assert_eq!(store.get_chunks(REPO_ID, "v2").await.unwrap().len(), 5);
+
+    let doc = query_document(&v2);
+    let engine = QueryEngine::new(&doc);
+    let hits = engine.search("close", 5, &SearchFilters::default());
+    let close = hits
+        .iter()
+        .find(|h| h.fqn == "pkg.db.close")
+        .expect("pkg.db.close is searchable");
+    assert_eq!(close.file_path, "pkg/db.py");
+}
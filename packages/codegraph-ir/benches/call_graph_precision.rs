@@ -0,0 +1,154 @@
+//! Call graph precision/recall by context depth (k-CFA)
+//!
+//! Ground-truth corpus of callbacks passed through wrapper chains:
+//!
+//! ```text
+//! def caller_i(): w1(cb_i)        # i = 1..callers
+//! def w1(f): w2(f)                # ... chain of `depth` wrappers
+//! def wd(f): f()
+//! ```
+//!
+//! Each caller truly reaches its own callback only. A k-CFA graph separates
+//! the callbacks once `k >= depth`; below that the wrappers' contexts merge
+//! and every caller spuriously reaches every callback. Recall stays 1.0 at
+//! any depth (with or without the context budget), so the trade-off is
+//! precision against contexts and build time.
+//!
+//! Run with `cargo bench --bench call_graph_precision`.
+
+use codegraph_ir::config::CallGraphConfig;
+use codegraph_ir::features::cross_file::{CallGraphAccuracy, ContextCallGraph, IRDocument};
+use codegraph_ir::shared::models::{Edge, EdgeKind, EdgeMetadata, Node, NodeKind, Span};
+use std::collections::BTreeSet;
+use std::time::Instant;
+
+struct Corpus {
+    name: String,
+    docs: Vec<IRDocument>,
+    expected: BTreeSet<(String, String)>,
+}
+
+fn function(fqn: &str, parameters: &[&str]) -> Node {
+    let mut node = Node::new(
+        fqn.to_string(),
+        NodeKind::Function,
+        fqn.to_string(),
+        "corpus.py".to_string(),
+        Span::new(1, 0, 1, 0),
+    )
+    .with_name(fqn.rsplit('.').next().unwrap());
+    node.parameters = Some(parameters.iter().map(|p| p.to_string()).collect());
+    node
+}
+
+fn call(from: &str, to: &str, line: u32, arguments: &[String]) -> Edge {
+    Edge::new(from.to_string(), to.to_string(), EdgeKind::Calls)
+        .with_span(Span::new(line, 4, line, 30))
+        .with_metadata(EdgeMetadata {
+            arguments: Some(arguments.to_vec()),
+            ..Default::default()
+        })
+}
+
+/// `callers` callers, each passing its own callback through `depth` wrappers
+fn wrapper_chain(callers: usize, depth: usize) -> Corpus {
+    let wrapper = |j: usize| format!("corpus.w{}", j);
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+    let mut expected = BTreeSet::new();
+    let mut line = 0;
+    let mut next_line = || {
+        line += 1;
+        line
+    };
+
+    for j in 1..=depth {
+        nodes.push(function(&wrapper(j), &["f"]));
+        if j < depth {
+            edges.push(call(
+                &wrapper(j),
+                &wrapper(j + 1),
+                next_line(),
+                &["f".to_string()],
+            ));
+        }
+        for later in j + 1..=depth {
+            expected.insert((wrapper(j), wrapper(later)));
+        }
+    }
+    let param = format!("{}.f", wrapper(depth));
+    nodes.push(
+        Node::new(
+            param.clone(),
+            NodeKind::Parameter,
+            param.clone(),
+            "corpus.py".to_string(),
+            Span::new(1, 0, 1, 0),
+        )
+        .with_name("f"),
+    );
+    edges.push(call(&wrapper(depth), &param, next_line(), &[]));
+
+    for i in 1..=callers {
+        let caller = format!("corpus.caller{}", i);
+        let callback = format!("corpus.cb{}", i);
+        nodes.push(function(&caller, &[]));
+        nodes.push(function(&callback, &[]));
+        edges.push(call(&caller, &wrapper(1), next_line(), &[callback.clone()]));
+
+        for j in 1..=depth {
+            expected.insert((caller.clone(), wrapper(j)));
+            expected.insert((wrapper(j), callback.clone()));
+        }
+        expected.insert((caller, callback));
+    }
+
+    Corpus {
+        name: format!("chain(callers={}, depth={})", callers, depth),
+        docs: vec![IRDocument::new("corpus.py".to_string(), nodes, edges)],
+        expected,
+    }
+}
+
+fn report(corpus: &Corpus, config: &CallGraphConfig, label: &str) {
+    let start = Instant::now();
+    let graph = ContextCallGraph::build(&corpus.docs, config);
+    let elapsed = start.elapsed();
+    let accuracy = CallGraphAccuracy::measure(&graph.reachable_pairs(), &corpus.expected);
+    let stats = graph.stats();
+
+    println!(
+        "{:28} {:10} precision {:5.3}  recall {:5.3}  f1 {:5.3}  contexts {:6}  collapsed {:5}  {:8.2}ms",
+        corpus.name,
+        label,
+        accuracy.precision(),
+        accuracy.recall(),
+        accuracy.f1(),
+        stats.contexts,
+        stats.collapsed_contexts,
+        elapsed.as_secs_f64() * 1000.0
+    );
+}
+
+fn main() {
+    println!("\n========== CALL GRAPH PRECISION (reachable pairs) ==========\n");
+
+    let corpora = [
+        wrapper_chain(2, 1),
+        wrapper_chain(2, 2),
+        wrapper_chain(8, 2),
+        wrapper_chain(8, 3),
+        wrapper_chain(64, 3),
+    ];
+    for corpus in &corpora {
+        for k in 0..=3 {
+            let config = CallGraphConfig::default().context_depth(k);
+            report(corpus, &config, &format!("{}-CFA", k));
+        }
+        let budgeted = CallGraphConfig::default()
+            .context_depth(3)
+            .max_contexts_per_function(4);
+        report(corpus, &budgeted, "3-CFA/4");
+        println!();
+    }
+}
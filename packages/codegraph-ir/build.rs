@@ -0,0 +1,19 @@
+//! Build script
+//!
+//! With the `grpc` feature, generates the gRPC server code from
+//! `proto/codegraph/v1/engine.proto` (requires `protoc` on PATH or `PROTOC`).
+//! With the `node` feature, sets up the napi-rs link flags.
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=proto");
+
+    #[cfg(feature = "grpc")]
+    tonic_build::configure()
+        .build_client(false)
+        .compile_protos(&["proto/codegraph/v1/engine.proto"], &["proto"])
+        .expect("failed to compile proto/codegraph/v1/engine.proto");
+
+    #[cfg(feature = "node")]
+    napi_build::setup();
+}
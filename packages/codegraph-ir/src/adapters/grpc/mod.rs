@@ -0,0 +1,24 @@
+//! gRPC adapter (`grpc` feature)
+//!
+//! `AnalysisEngine` service (`proto/codegraph/v1/engine.proto`) for clients
+//! that cannot embed the Python bindings: IndexRepository, GetCallGraph,
+//! QuerySymbols, RunTaint and DiffSnapshots. Run it with the
+//! `codegraph-grpc` binary or embed it:
+//!
+//! ```ignore
+//! let service = EngineService::new().with_snapshot_store(store);
+//! serve("127.0.0.1:50051".parse()?, service, async {
+//!     tokio::signal::ctrl_c().await.ok();
+//! })
+//! .await?;
+//! ```
+
+pub mod service;
+
+/// Generated protobuf messages and server traits
+pub mod proto {
+    #![allow(clippy::all)]
+    tonic::include_proto!("codegraph.v1");
+}
+
+pub use service::{error_status, serve, EngineService};
@@ -0,0 +1,644 @@
+//! `AnalysisEngine` gRPC service
+//!
+//! Pipeline runs happen on tokio's blocking pool. Dropping an RPC (client
+//! disconnect, deadline) cancels its run through a `CancellationToken`.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::RwLock;
+use tonic::{Request, Response, Status};
+
+use super::proto::analysis_engine_server::{AnalysisEngine, AnalysisEngineServer};
+use super::proto::{self, CallDirection};
+use crate::config::pipeline_config::StageId;
+use crate::config::{PipelineConfig, Preset, ValidatedConfig};
+use crate::features::storage::api::snapshot_report::ReportFinding;
+use crate::features::storage::api::{SnapshotReport, SnapshotReportOptions};
+use crate::features::storage::CodeSnapshotStore;
+use crate::pipeline::{
+    CancellationToken, E2EPipelineConfig, E2EPipelineResult, IRIndexingOrchestrator, IndexingMode,
+};
+use crate::shared::i18n::ReportOptions;
+use crate::shared::models::{CodegraphError, Edge, EdgeKind, Node, Span};
+
+const DEFAULT_SYMBOL_LIMIT: usize = 100;
+
+/// gRPC status of a pipeline / storage error
+pub fn error_status(error: CodegraphError) -> Status {
+    match error.kind.as_str() {
+        "cancelled" => Status::cancelled(error.message),
+        "config" | "parse" => Status::invalid_argument(error.message),
+        "io" => Status::unavailable(error.message),
+        _ => Status::internal(error.message),
+    }
+}
+
+/// Cancels a pipeline run when its RPC future is dropped
+struct CancelOnDrop(CancellationToken);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.cancel();
+    }
+}
+
+/// Analysis engine served over gRPC
+///
+/// Index results stay in memory (keyed by repository name) until the same
+/// repository is indexed again.
+#[derive(Clone)]
+pub struct EngineService {
+    repos: Arc<RwLock<HashMap<String, Arc<E2EPipelineResult>>>>,
+    snapshots: Option<Arc<CodeSnapshotStore>>,
+    default_preset: Preset,
+}
+
+impl Default for EngineService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EngineService {
+    pub fn new() -> Self {
+        Self {
+            repos: Arc::new(RwLock::new(HashMap::new())),
+            snapshots: None,
+            default_preset: Preset::Balanced,
+        }
+    }
+
+    /// Snapshot store read by `DiffSnapshots`
+    pub fn with_snapshot_store(mut self, store: CodeSnapshotStore) -> Self {
+        self.snapshots = Some(Arc::new(store));
+        self
+    }
+
+    /// Preset used when a request does not name one
+    pub fn with_default_preset(mut self, preset: Preset) -> Self {
+        self.default_preset = preset;
+        self
+    }
+
+    /// Result of the last `IndexRepository` run for `repo_id`
+    pub fn indexed(&self, repo_id: &str) -> Option<Arc<E2EPipelineResult>> {
+        self.repos.read().get(repo_id).cloned()
+    }
+
+    /// tonic service wrapping this engine
+    pub fn into_server(self) -> AnalysisEngineServer<Self> {
+        AnalysisEngineServer::new(self)
+    }
+
+    fn repo(&self, repo_id: &str) -> Result<Arc<E2EPipelineResult>, Status> {
+        self.indexed(repo_id)
+            .ok_or_else(|| Status::not_found(format!("repository '{}' is not indexed", repo_id)))
+    }
+
+    fn pipeline_config(
+        &self,
+        preset: &str,
+        config_yaml: &str,
+        enable: &[StageId],
+    ) -> Result<ValidatedConfig, Status> {
+        if !config_yaml.trim().is_empty() {
+            return PipelineConfig::from_yaml_str(config_yaml, "<request>")
+                .map_err(|e| Status::invalid_argument(e.to_string()));
+        }
+        let preset = if preset.is_empty() {
+            self.default_preset
+        } else {
+            Preset::from_str(preset).map_err(Status::invalid_argument)?
+        };
+        PipelineConfig::preset(preset)
+            .stages(|stages| enable.iter().fold(stages, |s, &stage| s.enable(stage)))
+            .build()
+            .map_err(|e| Status::invalid_argument(e.to_string()))
+    }
+}
+
+/// Pipeline config for one repository; returns it with the repository id
+fn repo_config(
+    pipeline: ValidatedConfig,
+    repo_root: &str,
+    repo_name: &str,
+    file_paths: &[String],
+) -> Result<(String, E2EPipelineConfig), Status> {
+    if repo_root.is_empty() {
+        return Err(Status::invalid_argument("repo_root is required"));
+    }
+    let root = PathBuf::from(repo_root);
+    if !root.is_dir() {
+        return Err(Status::invalid_argument(format!(
+            "repo_root '{}' is not a directory",
+            repo_root
+        )));
+    }
+    let repo_id = if repo_name.is_empty() {
+        root.file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| repo_root.to_string())
+    } else {
+        repo_name.to_string()
+    };
+
+    let mut config = E2EPipelineConfig::with_config(pipeline)
+        .repo_root(root.clone())
+        .repo_name(repo_id.clone())
+        .indexing_mode(IndexingMode::Full);
+    if !file_paths.is_empty() {
+        config = config.file_paths(file_paths.iter().map(|p| root.join(p)).collect());
+    }
+    Ok((repo_id, config))
+}
+
+/// Run the pipeline on the blocking pool, cancelling it if the RPC is dropped
+async fn run_pipeline(config: E2EPipelineConfig) -> Result<E2EPipelineResult, Status> {
+    let token = CancellationToken::new();
+    let _guard = CancelOnDrop(token.clone());
+    tokio::task::spawn_blocking(move || {
+        IRIndexingOrchestrator::new(config)
+            .with_cancellation(token)
+            .execute()
+    })
+    .await
+    .map_err(|e| Status::internal(format!("pipeline task failed: {}", e)))?
+    .map_err(error_status)
+}
+
+fn millis(duration: Duration) -> u64 {
+    duration.as_millis() as u64
+}
+
+fn location(file_path: &str, span: &Span) -> proto::Location {
+    proto::Location {
+        file_path: file_path.to_string(),
+        start_line: span.start_line,
+        start_col: span.start_col,
+        end_line: span.end_line,
+        end_col: span.end_col,
+    }
+}
+
+fn symbol(node: &Node) -> proto::Symbol {
+    proto::Symbol {
+        id: node.id.clone(),
+        fqn: node.fqn.clone(),
+        name: node.name.clone().unwrap_or_default(),
+        kind: node.kind.as_str().to_string(),
+        language: node.language.clone(),
+        location: Some(location(&node.file_path, &node.span)),
+        parent_id: node.parent_id.clone().unwrap_or_default(),
+    }
+}
+
+/// Call graph around `root` (node id, else FQN); `None` if `root` is unknown
+fn call_graph(
+    nodes: &[Node],
+    edges: &[Edge],
+    root: &str,
+    direction: CallDirection,
+    max_depth: usize,
+    max_edges: usize,
+) -> Option<proto::GetCallGraphResponse> {
+    let by_id: HashMap<&str, &Node> = nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+    let root = by_id
+        .get(root)
+        .copied()
+        .or_else(|| nodes.iter().find(|n| n.kind.is_callable() && n.fqn == root))?;
+
+    let mut callees: HashMap<&str, Vec<usize>> = HashMap::new();
+    let mut callers: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (i, edge) in edges.iter().enumerate() {
+        if matches!(edge.kind, EdgeKind::Calls | EdgeKind::Invokes) {
+            callees.entry(edge.source_id.as_str()).or_default().push(i);
+            callers.entry(edge.target_id.as_str()).or_default().push(i);
+        }
+    }
+    let follow_callees = matches!(direction, CallDirection::Callees | CallDirection::Both);
+    let follow_callers = matches!(direction, CallDirection::Callers | CallDirection::Both);
+
+    let mut visited: HashSet<&str> = HashSet::from([root.id.as_str()]);
+    let mut order = vec![root.id.as_str()];
+    let mut taken: HashSet<usize> = HashSet::new();
+    let mut taken_order = Vec::new();
+    let mut truncated = false;
+    let mut queue = VecDeque::from([(root.id.as_str(), 0usize)]);
+
+    'bfs: while let Some((id, depth)) = queue.pop_front() {
+        if depth >= max_depth.max(1) {
+            continue;
+        }
+        let outgoing = follow_callees
+            .then(|| callees.get(id))
+            .flatten()
+            .into_iter()
+            .flatten()
+            .map(|&i| (i, edges[i].target_id.as_str()));
+        let incoming = follow_callers
+            .then(|| callers.get(id))
+            .flatten()
+            .into_iter()
+            .flatten()
+            .map(|&i| (i, edges[i].source_id.as_str()));
+        for (i, next) in outgoing.chain(incoming) {
+            if !taken.insert(i) {
+                continue;
+            }
+            if max_edges > 0 && taken_order.len() == max_edges {
+                truncated = true;
+                break 'bfs;
+            }
+            taken_order.push(i);
+            if visited.insert(next) {
+                order.push(next);
+                queue.push_back((next, depth + 1));
+            }
+        }
+    }
+
+    let edges = taken_order
+        .into_iter()
+        .map(|i| {
+            let edge = &edges[i];
+            let caller_file = by_id
+                .get(edge.source_id.as_str())
+                .map_or("", |n| n.file_path.as_str());
+            proto::CallEdge {
+                caller_id: edge.source_id.clone(),
+                callee_id: edge.target_id.clone(),
+                call_site: edge.span.as_ref().map(|span| location(caller_file, span)),
+            }
+        })
+        .collect();
+    Some(proto::GetCallGraphResponse {
+        functions: order
+            .into_iter()
+            .filter_map(|id| by_id.get(id).map(|n| symbol(n)))
+            .collect(),
+        edges,
+        truncated,
+    })
+}
+
+/// Symbols matching a `QuerySymbols` request, with the total match count
+fn query_symbols(
+    nodes: &[Node],
+    request: &proto::QuerySymbolsRequest,
+) -> (Vec<proto::Symbol>, usize) {
+    let query = request.query.to_lowercase();
+    let limit = match request.limit {
+        0 => DEFAULT_SYMBOL_LIMIT,
+        n => n as usize,
+    };
+    let matches = nodes.iter().filter(|node| {
+        (request.kinds.is_empty() || request.kinds.iter().any(|k| k == node.kind.as_str()))
+            && node.file_path.starts_with(&request.file_prefix)
+            && (query.is_empty()
+                || node.fqn.to_lowercase().contains(&query)
+                || node
+                    .name
+                    .as_deref()
+                    .is_some_and(|name| name.to_lowercase().contains(&query)))
+    });
+
+    let mut total = 0;
+    let mut symbols = Vec::new();
+    for node in matches {
+        total += 1;
+        if symbols.len() < limit {
+            symbols.push(symbol(node));
+        }
+    }
+    (symbols, total)
+}
+
+fn index_response(repo_id: String, result: &E2EPipelineResult) -> proto::IndexRepositoryResponse {
+    let stats = &result.stats;
+    let mut stages: Vec<proto::StageTiming> = stats
+        .stage_durations
+        .iter()
+        .map(|(stage, duration)| proto::StageTiming {
+            stage: stage.clone(),
+            duration_ms: millis(*duration),
+        })
+        .collect();
+    stages.sort_by(|a, b| a.stage.cmp(&b.stage));
+
+    proto::IndexRepositoryResponse {
+        repo_id,
+        files_processed: stats.files_processed as u64,
+        files_cached: stats.files_cached as u64,
+        files_failed: stats.files_failed as u64,
+        nodes: result.nodes.len() as u64,
+        edges: result.edges.len() as u64,
+        chunks: result.chunks.len() as u64,
+        duration_ms: millis(stats.total_duration),
+        stages,
+    }
+}
+
+fn taint_response(repo_id: String, result: &E2EPipelineResult) -> proto::RunTaintResponse {
+    proto::RunTaintResponse {
+        repo_id,
+        functions: result
+            .taint_results
+            .iter()
+            .map(|t| proto::TaintFunction {
+                function_id: t.function_id.clone(),
+                sources_found: t.sources_found as u64,
+                sinks_found: t.sinks_found as u64,
+                taint_flows: t.taint_flows as u64,
+                sanitized_paths: t.sanitized_paths as u64,
+            })
+            .collect(),
+        vulnerabilities: result
+            .security_vulnerabilities
+            .iter()
+            .map(|v| proto::Vulnerability {
+                vuln_type: v.vuln_type.clone(),
+                cwe_id: v.cwe_id.clone().unwrap_or_default(),
+                file_path: v.file_path.clone(),
+                function_id: v.function_id.clone(),
+                line: v.line,
+                severity: v.severity.clone(),
+                description: v.description.clone(),
+                suggested_fix: v.suggested_fix.clone().unwrap_or_default(),
+            })
+            .collect(),
+        total_flows: result
+            .taint_results
+            .iter()
+            .map(|t| t.taint_flows as u64)
+            .sum(),
+        duration_ms: millis(result.stats.total_duration),
+    }
+}
+
+fn diff_response(report: &SnapshotReport, html: Option<String>) -> proto::DiffSnapshotsResponse {
+    let finding = |f: &ReportFinding| proto::Finding {
+        rule_id: f.rule_id.clone(),
+        severity: f.severity.clone(),
+        message: f.message.clone(),
+        file_path: f.file_path.clone(),
+        symbol: f.symbol.clone().unwrap_or_default(),
+        line: f.line.unwrap_or_default(),
+    };
+
+    proto::DiffSnapshotsResponse {
+        summary: report.summary(),
+        symbols: report
+            .symbols
+            .iter()
+            .map(|s| proto::SymbolChange {
+                fqn: s.fqn.clone(),
+                kind: s.kind.clone(),
+                location: Some(location(
+                    &s.file_path,
+                    &Span::new(s.start_line, 0, s.end_line, 0),
+                )),
+                change: s.change.as_str().to_string(),
+            })
+            .collect(),
+        package_dependencies: report
+            .package_dependencies
+            .iter()
+            .map(|d| proto::PackageDependencyChange {
+                from_package: d.from_package.clone(),
+                to_package: d.to_package.clone(),
+                base_count: d.base_count as u64,
+                head_count: d.head_count as u64,
+            })
+            .collect(),
+        findings_introduced: report.findings.introduced.iter().map(finding).collect(),
+        findings_resolved: report.findings.resolved.iter().map(finding).collect(),
+        report_json: report.to_json(),
+        html: html.unwrap_or_default(),
+    }
+}
+
+#[tonic::async_trait]
+impl AnalysisEngine for EngineService {
+    async fn index_repository(
+        &self,
+        request: Request<proto::IndexRepositoryRequest>,
+    ) -> Result<Response<proto::IndexRepositoryResponse>, Status> {
+        let request = request.into_inner();
+        let pipeline = self.pipeline_config(&request.preset, &request.config_yaml, &[])?;
+        let (repo_id, config) = repo_config(
+            pipeline,
+            &request.repo_root,
+            &request.repo_name,
+            &request.file_paths,
+        )?;
+
+        let result = run_pipeline(config).await?;
+        let response = index_response(repo_id.clone(), &result);
+        self.repos.write().insert(repo_id, Arc::new(result));
+        Ok(Response::new(response))
+    }
+
+    async fn get_call_graph(
+        &self,
+        request: Request<proto::GetCallGraphRequest>,
+    ) -> Result<Response<proto::GetCallGraphResponse>, Status> {
+        let request = request.into_inner();
+        let result = self.repo(&request.repo_id)?;
+        call_graph(
+            &result.nodes,
+            &result.edges,
+            &request.root,
+            request.direction(),
+            request.max_depth as usize,
+            request.max_edges as usize,
+        )
+        .map(Response::new)
+        .ok_or_else(|| Status::not_found(format!("function '{}' not found", request.root)))
+    }
+
+    async fn query_symbols(
+        &self,
+        request: Request<proto::QuerySymbolsRequest>,
+    ) -> Result<Response<proto::QuerySymbolsResponse>, Status> {
+        let request = request.into_inner();
+        let result = self.repo(&request.repo_id)?;
+        let (symbols, total) = query_symbols(&result.nodes, &request);
+        Ok(Response::new(proto::QuerySymbolsResponse {
+            symbols,
+            total_matches: total as u64,
+        }))
+    }
+
+    async fn run_taint(
+        &self,
+        request: Request<proto::RunTaintRequest>,
+    ) -> Result<Response<proto::RunTaintResponse>, Status> {
+        let request = request.into_inner();
+        let pipeline =
+            self.pipeline_config(&request.preset, "", &[StageId::Pta, StageId::Taint])?;
+        let (repo_id, config) = repo_config(
+            pipeline,
+            &request.repo_root,
+            &request.repo_name,
+            &request.file_paths,
+        )?;
+
+        let result = run_pipeline(config).await?;
+        Ok(Response::new(taint_response(repo_id, &result)))
+    }
+
+    async fn diff_snapshots(
+        &self,
+        request: Request<proto::DiffSnapshotsRequest>,
+    ) -> Result<Response<proto::DiffSnapshotsResponse>, Status> {
+        let request = request.into_inner();
+        let store = self
+            .snapshots
+            .as_ref()
+            .ok_or_else(|| Status::failed_precondition("server has no snapshot store"))?;
+        let mut options = SnapshotReportOptions::default();
+        if request.impact_depth > 0 {
+            options.impact_depth = request.impact_depth as usize;
+        }
+        let mut report_options = ReportOptions::default();
+        if !request.locale.is_empty() {
+            report_options.locale = request.locale.parse().map_err(Status::invalid_argument)?;
+        }
+
+        let report = SnapshotReport::build_with_options(
+            store,
+            &request.repo_id,
+            &request.base_snapshot,
+            &request.head_snapshot,
+            &options,
+        )
+        .await
+        .map_err(error_status)?;
+        let html = request
+            .include_html
+            .then(|| report.to_html_with(&report_options));
+        Ok(Response::new(diff_response(&report, html)))
+    }
+}
+
+/// Serve `service` on `addr` until `shutdown` resolves
+pub async fn serve<F>(
+    addr: SocketAddr,
+    service: EngineService,
+    shutdown: F,
+) -> crate::shared::models::Result<()>
+where
+    F: std::future::Future<Output = ()> + Send,
+{
+    tonic::transport::Server::builder()
+        .add_service(service.into_server())
+        .serve_with_shutdown(addr, shutdown)
+        .await
+        .map_err(|e| CodegraphError::io(format!("gRPC server on {}: {}", addr, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::models::NodeKind;
+
+    fn function(id: &str, fqn: &str) -> Node {
+        Node::new(
+            id.to_string(),
+            NodeKind::Function,
+            fqn.to_string(),
+            "src/app.py".to_string(),
+            Span::new(1, 0, 3, 0),
+        )
+        .with_name(fqn.rsplit('.').next().unwrap())
+    }
+
+    fn call(caller: &str, callee: &str) -> Edge {
+        Edge::new(caller.to_string(), callee.to_string(), EdgeKind::Calls)
+            .with_span(Span::new(2, 4, 2, 12))
+    }
+
+    fn sample() -> (Vec<Node>, Vec<Edge>) {
+        let nodes = vec![
+            function("f:main", "app.main"),
+            function("f:load", "app.load"),
+            function("f:parse", "app.parse"),
+            function("f:log", "app.log"),
+        ];
+        let edges = vec![
+            call("f:main", "f:load"),
+            call("f:load", "f:parse"),
+            call("f:parse", "f:log"),
+            Edge::new(
+                "f:main".to_string(),
+                "f:log".to_string(),
+                EdgeKind::Contains,
+            ),
+        ];
+        (nodes, edges)
+    }
+
+    #[test]
+    fn test_call_graph_directions_and_depth() {
+        let (nodes, edges) = sample();
+
+        let callees = call_graph(&nodes, &edges, "app.main", CallDirection::Callees, 2, 0).unwrap();
+        let ids: Vec<_> = callees.functions.iter().map(|f| f.id.as_str()).collect();
+        assert_eq!(ids, vec!["f:main", "f:load", "f:parse"]);
+        assert_eq!(callees.edges.len(), 2);
+        assert_eq!(callees.edges[0].call_site.as_ref().unwrap().start_line, 2);
+
+        let callers = call_graph(&nodes, &edges, "f:parse", CallDirection::Callers, 5, 0).unwrap();
+        let ids: Vec<_> = callers.functions.iter().map(|f| f.id.as_str()).collect();
+        assert_eq!(ids, vec!["f:parse", "f:load", "f:main"]);
+
+        let both = call_graph(&nodes, &edges, "f:load", CallDirection::Both, 1, 1).unwrap();
+        assert_eq!(both.edges.len(), 1);
+        assert!(both.truncated);
+
+        assert!(call_graph(&nodes, &edges, "app.missing", CallDirection::Both, 1, 0).is_none());
+    }
+
+    #[test]
+    fn test_query_symbols_filters_and_limits() {
+        let (nodes, _) = sample();
+        let request = proto::QuerySymbolsRequest {
+            repo_id: "app".to_string(),
+            query: "PA".to_string(),
+            kinds: vec!["Function".to_string()],
+            file_prefix: "src/".to_string(),
+            limit: 0,
+        };
+        let (symbols, total) = query_symbols(&nodes, &request);
+        assert_eq!(total, 1);
+        assert_eq!(symbols[0].fqn, "app.parse");
+
+        let request = proto::QuerySymbolsRequest {
+            limit: 2,
+            query: String::new(),
+            ..request
+        };
+        let (symbols, total) = query_symbols(&nodes, &request);
+        assert_eq!((symbols.len(), total), (2, 4));
+    }
+
+    #[test]
+    fn test_error_status_codes() {
+        assert_eq!(
+            error_status(CodegraphError::cancelled("stop")).code(),
+            tonic::Code::Cancelled
+        );
+        assert_eq!(
+            error_status(CodegraphError::config("bad")).code(),
+            tonic::Code::InvalidArgument
+        );
+        assert_eq!(
+            error_status(CodegraphError::internal("boom")).code(),
+            tonic::Code::Internal
+        );
+    }
+}
@@ -0,0 +1,16 @@
+//! MCP (Model Context Protocol) adapter
+//!
+//! Exposes an indexed repository to coding agents as MCP tools:
+//! `find_definition`, `callers_of`, `impact_of_change`, `taint_check_file`
+//! and `repo_map`. Run it with the `codegraph-mcp` binary (stdio transport),
+//! e.g. in an agent's MCP server config:
+//!
+//! ```json
+//! { "command": "codegraph-mcp", "args": ["--repo", "/path/to/repo"] }
+//! ```
+
+pub mod server;
+pub mod tools;
+
+pub use server::{McpServer, PROTOCOL_VERSION};
+pub use tools::{tool_definitions, CodegraphTools};
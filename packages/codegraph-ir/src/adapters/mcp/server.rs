@@ -0,0 +1,211 @@
+//! MCP protocol handling (JSON-RPC 2.0, newline-delimited over stdio)
+//!
+//! Supported methods: `initialize`, `ping`, `tools/list`, `tools/call`.
+//! Notifications (`notifications/initialized`, ...) get no response. Tool
+//! failures are tool results with `isError: true`, not JSON-RPC errors, so
+//! the agent sees the message.
+
+use std::io::{self, BufRead, Write};
+
+use serde_json::{json, Value};
+
+use super::tools::{tool_definitions, CodegraphTools};
+
+/// MCP revision implemented
+pub const PROTOCOL_VERSION: &str = "2024-11-05";
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+/// MCP server exposing `CodegraphTools`
+pub struct McpServer {
+    tools: CodegraphTools,
+}
+
+impl McpServer {
+    pub fn new(tools: CodegraphTools) -> Self {
+        Self { tools }
+    }
+
+    /// Serve newline-delimited messages from stdin until EOF
+    pub fn serve_stdio(&self) -> io::Result<()> {
+        self.serve(io::stdin().lock(), io::stdout().lock())
+    }
+
+    /// Serve newline-delimited messages from `input` until EOF
+    pub fn serve<R: BufRead, W: Write>(&self, input: R, mut output: W) -> io::Result<()> {
+        for line in input.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Some(response) = self.handle_message(&line) {
+                writeln!(output, "{}", response)?;
+                output.flush()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Response to one JSON-RPC message (`None` for notifications)
+    pub fn handle_message(&self, message: &str) -> Option<String> {
+        let response = match serde_json::from_str::<Value>(message) {
+            Ok(Value::Array(batch)) => {
+                let responses: Vec<Value> = batch.iter().filter_map(|m| self.handle(m)).collect();
+                (!responses.is_empty()).then_some(Value::Array(responses))
+            }
+            Ok(request) => self.handle(&request),
+            Err(e) => Some(error_response(
+                Value::Null,
+                PARSE_ERROR,
+                format!("parse error: {}", e),
+            )),
+        };
+        response.map(|r| r.to_string())
+    }
+
+    fn handle(&self, request: &Value) -> Option<Value> {
+        let id = request.get("id").cloned();
+        let Some(method) = request.get("method").and_then(Value::as_str) else {
+            return Some(error_response(
+                id.unwrap_or(Value::Null),
+                INVALID_REQUEST,
+                "missing method".to_string(),
+            ));
+        };
+        // Notifications carry no id and get no response
+        let id = id?;
+        let params = request.get("params").cloned().unwrap_or(Value::Null);
+
+        Some(match self.dispatch(method, &params) {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err((code, message)) => error_response(id, code, message),
+        })
+    }
+
+    fn dispatch(&self, method: &str, params: &Value) -> Result<Value, (i64, String)> {
+        match method {
+            "initialize" => Ok(json!({
+                "protocolVersion": PROTOCOL_VERSION,
+                "capabilities": { "tools": { "listChanged": false } },
+                "serverInfo": {
+                    "name": "codegraph",
+                    "version": env!("CARGO_PKG_VERSION"),
+                },
+                "instructions": format!(
+                    "Code graph of repository '{}': definitions, callers, change impact, \
+                     taint checks and a repository map.",
+                    self.tools.repo_name()
+                ),
+            })),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({ "tools": tool_definitions() })),
+            "tools/call" => {
+                let name = params.get("name").and_then(Value::as_str).ok_or((
+                    INVALID_PARAMS,
+                    "tools/call requires a tool name".to_string(),
+                ))?;
+                let arguments = params.get("arguments").cloned().unwrap_or(json!({}));
+                Ok(match self.tools.call(name, &arguments) {
+                    Ok(result) => tool_result(
+                        serde_json::to_string_pretty(&result).unwrap_or_default(),
+                        false,
+                    ),
+                    Err(message) => tool_result(message, true),
+                })
+            }
+            other => Err((METHOD_NOT_FOUND, format!("method not found: {}", other))),
+        }
+    }
+}
+
+fn tool_result(text: String, is_error: bool) -> Value {
+    json!({
+        "content": [{ "type": "text", "text": text }],
+        "isError": is_error,
+    })
+}
+
+fn error_response(id: Value, code: i64, message: String) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::E2EPipelineResult;
+
+    fn server() -> McpServer {
+        McpServer::new(CodegraphTools::from_result(
+            "repo",
+            E2EPipelineResult::default(),
+        ))
+    }
+
+    fn call(server: &McpServer, message: Value) -> Value {
+        serde_json::from_str(&server.handle_message(&message.to_string()).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_handshake_and_tool_listing() {
+        let server = server();
+        let init = call(
+            &server,
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {} }),
+        );
+        assert_eq!(init["result"]["protocolVersion"], PROTOCOL_VERSION);
+        assert!(server
+            .handle_message(r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#)
+            .is_none());
+
+        let list = call(
+            &server,
+            json!({ "jsonrpc": "2.0", "id": 2, "method": "tools/list" }),
+        );
+        assert_eq!(list["id"], 2);
+        assert_eq!(list["result"]["tools"][0]["name"], "find_definition");
+    }
+
+    #[test]
+    fn test_tool_errors_and_protocol_errors() {
+        let server = server();
+        let failed = call(
+            &server,
+            json!({
+                "jsonrpc": "2.0", "id": "a", "method": "tools/call",
+                "params": { "name": "callers_of", "arguments": { "function": "missing" } }
+            }),
+        );
+        assert_eq!(failed["result"]["isError"], true);
+        assert!(failed["result"]["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("not found"));
+
+        let unknown = call(
+            &server,
+            json!({ "jsonrpc": "2.0", "id": 3, "method": "nope" }),
+        );
+        assert_eq!(unknown["error"]["code"], METHOD_NOT_FOUND);
+
+        let parse: Value = serde_json::from_str(&server.handle_message("{").unwrap()).unwrap();
+        assert_eq!(parse["error"]["code"], PARSE_ERROR);
+    }
+
+    #[test]
+    fn test_serve_reads_lines_until_eof() {
+        let server = server();
+        let input = "{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"ping\"}\n\n";
+        let mut output = Vec::new();
+        server.serve(input.as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.lines().count(), 1);
+        assert!(output.contains("\"result\":{}"));
+    }
+}
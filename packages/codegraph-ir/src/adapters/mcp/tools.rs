@@ -0,0 +1,671 @@
+//! MCP tools over one indexed repository
+//!
+//! | Tool               | Backed by                                        |
+//! |--------------------|--------------------------------------------------|
+//! | `find_definition`  | `HybridRanker` (name match + PageRank/references)|
+//! | `callers_of`       | `GraphIndex` incoming call edges (BFS)           |
+//! | `impact_of_change` | `GraphIndex` incoming dependency edges (BFS)     |
+//! | `taint_check_file` | `TaintAnalysisService::analyze_ir` + pipeline    |
+//! | `repo_map`         | L16 RepoMap snapshot, else `GraphSignals`        |
+//!
+//! Tool results are JSON values; errors are plain messages.
+
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
+use std::sync::Arc;
+
+use serde_json::{json, Value};
+
+use crate::config::pipeline_config::StageId;
+use crate::config::{PipelineConfig, Preset};
+use crate::features::ir_generation::domain::ir_document::IRDocument;
+use crate::features::query_engine::infrastructure::{
+    GraphIndex, GraphSignals, HybridRanker, RankingWeights, SearchFilters,
+};
+use crate::features::repomap::RepoMapSeed;
+use crate::features::taint_analysis::{
+    default_config, DefaultTaintAnalysisService, InMemoryCodeRepository, TaintAnalysisService,
+};
+use crate::pipeline::{
+    E2EPipelineConfig, E2EPipelineResult, IRIndexingOrchestrator, IndexingMode,
+    RepoMapSnapshotSummary, SecurityVulnerabilitySummary,
+};
+use crate::shared::models::{CodegraphError, Edge, EdgeKind, Node, NodeKind, Result};
+
+/// Name, description and JSON input schema of every tool
+pub fn tool_definitions() -> Value {
+    json!([
+        {
+            "name": "find_definition",
+            "description": "Find where a symbol (function, class, method, ...) is defined. \
+                            Matches identifier parts of names; best matches first.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "symbol": {"type": "string", "description": "Symbol name or FQN"},
+                    "kind": {"type": "string", "description": "Only this node kind, e.g. Function, Class"},
+                    "path_prefix": {"type": "string", "description": "Only files under this path"},
+                    "limit": {"type": "integer", "description": "Maximum results (default 5)"}
+                },
+                "required": ["symbol"]
+            }
+        },
+        {
+            "name": "callers_of",
+            "description": "Functions calling the given function, transitively up to `depth`.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "function": {"type": "string", "description": "Node id, FQN or name"},
+                    "depth": {"type": "integer", "description": "Call levels to follow (default 1)"},
+                    "limit": {"type": "integer", "description": "Maximum callers (default 50)"}
+                },
+                "required": ["function"]
+            }
+        },
+        {
+            "name": "impact_of_change",
+            "description": "Symbols and files that (transitively) depend on a changed file or \
+                            symbol through calls, references, imports and inheritance.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "file_path": {"type": "string", "description": "Changed file"},
+                    "symbol": {"type": "string", "description": "Changed symbol (node id, FQN or name)"},
+                    "depth": {"type": "integer", "description": "Dependency levels to follow (default 3)"},
+                    "limit": {"type": "integer", "description": "Maximum impacted symbols listed (default 100)"}
+                }
+            }
+        },
+        {
+            "name": "taint_check_file",
+            "description": "Taint analysis of one file: source-to-sink paths (injection, XSS, ...) \
+                            and vulnerabilities found while indexing.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "file_path": {"type": "string", "description": "File to check"}
+                },
+                "required": ["file_path"]
+            }
+        },
+        {
+            "name": "repo_map",
+            "description": "Most important files of the repository (PageRank over the code \
+                            graph) with their key symbols.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "limit": {"type": "integer", "description": "Maximum files (default 30)"},
+                    "path_prefix": {"type": "string", "description": "Only files under this path"},
+                    "focus": {
+                        "type": "array",
+                        "items": {"type": "string"},
+                        "description": "Files or directories the task is about (e.g. recently \
+                                        edited); ranks their neighbourhood higher"
+                    }
+                }
+            }
+        }
+    ])
+}
+
+/// Indexed repository the tools answer from
+pub struct CodegraphTools {
+    repo_name: String,
+    doc: IRDocument,
+    index: GraphIndex,
+    signals: GraphSignals,
+    weights: RankingWeights,
+    vulnerabilities: Vec<SecurityVulnerabilitySummary>,
+    repomap: Option<RepoMapSnapshotSummary>,
+}
+
+impl CodegraphTools {
+    /// Index `repo_root` with `preset` (RepoMap stage enabled)
+    pub fn index(repo_root: &Path, preset: Preset) -> Result<Self> {
+        let repo_name = repo_root
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "repo".to_string());
+        let pipeline = PipelineConfig::preset(preset)
+            .stages(|s| s.enable(StageId::RepoMap))
+            .build()
+            .map_err(|e| CodegraphError::config(e.to_string()))?;
+        let config = E2EPipelineConfig::with_config(pipeline)
+            .repo_root(repo_root.to_path_buf())
+            .repo_name(repo_name.clone())
+            .indexing_mode(IndexingMode::Full);
+
+        let result = IRIndexingOrchestrator::new(config).execute()?;
+        Ok(Self::from_result(repo_name, result))
+    }
+
+    /// Tools over an existing pipeline result
+    pub fn from_result(repo_name: impl Into<String>, result: E2EPipelineResult) -> Self {
+        let repo_name = repo_name.into();
+        let doc = IRDocument {
+            file_path: repo_name.clone(),
+            nodes: result.nodes,
+            edges: result.edges,
+        };
+        Self {
+            index: GraphIndex::new(&doc),
+            signals: GraphSignals::compute(&doc),
+            weights: RankingWeights::default(),
+            vulnerabilities: result.security_vulnerabilities,
+            repomap: result.repomap_snapshot,
+            repo_name,
+            doc,
+        }
+    }
+
+    pub fn repo_name(&self) -> &str {
+        &self.repo_name
+    }
+
+    /// Run tool `name` with its JSON `arguments`
+    pub fn call(&self, name: &str, arguments: &Value) -> std::result::Result<Value, String> {
+        match name {
+            "find_definition" => self.find_definition(arguments),
+            "callers_of" => self.callers_of(arguments),
+            "impact_of_change" => self.impact_of_change(arguments),
+            "taint_check_file" => self.taint_check_file(arguments),
+            "repo_map" => self.repo_map(arguments),
+            other => Err(format!("unknown tool '{}'", other)),
+        }
+    }
+
+    fn find_definition(&self, args: &Value) -> std::result::Result<Value, String> {
+        let symbol = str_arg(args, "symbol")?;
+        let mut filters = SearchFilters::default();
+        if let Some(kind) = opt_str_arg(args, "kind") {
+            filters = filters.with_kinds(vec![NodeKind::from_str(kind)]);
+        }
+        if let Some(prefix) = opt_str_arg(args, "path_prefix") {
+            filters = filters.with_path_prefix(prefix);
+        }
+        let limit = usize_arg(args, "limit", 5);
+
+        let hits = HybridRanker::new(&self.doc, &self.signals, &self.weights)
+            .rank(symbol, limit, &filters, None);
+        let definitions: Vec<Value> = hits
+            .iter()
+            .map(|hit| {
+                json!({
+                    "id": hit.node_id,
+                    "fqn": hit.fqn,
+                    "name": hit.name,
+                    "kind": hit.kind.as_str(),
+                    "file_path": hit.file_path,
+                    "start_line": hit.span.start_line,
+                    "end_line": hit.span.end_line,
+                    "score": hit.score,
+                })
+            })
+            .collect();
+        Ok(json!({ "symbol": symbol, "definitions": definitions }))
+    }
+
+    fn callers_of(&self, args: &Value) -> std::result::Result<Value, String> {
+        let function = str_arg(args, "function")?;
+        let targets: Vec<&Node> = self
+            .resolve(function)
+            .into_iter()
+            .filter(|n| n.kind.is_callable())
+            .collect();
+        if targets.is_empty() {
+            return Err(format!("function '{}' not found", function));
+        }
+        let depth = usize_arg(args, "depth", 1).max(1);
+        let limit = usize_arg(args, "limit", 50);
+
+        let reached = self.reverse_bfs(&targets, depth, is_call_edge);
+        let truncated = reached.len() > limit;
+        let callers: Vec<Value> = reached
+            .iter()
+            .take(limit)
+            .map(|(node, depth, edge)| {
+                let mut caller = symbol_json(node);
+                caller["depth"] = json!(depth);
+                caller["call_line"] = json!(edge.span.as_ref().map(|s| s.start_line));
+                caller
+            })
+            .collect();
+        Ok(json!({
+            "function": targets.iter().map(|n| symbol_json(n)).collect::<Vec<_>>(),
+            "callers": callers,
+            "total_callers": reached.len(),
+            "truncated": truncated,
+        }))
+    }
+
+    fn impact_of_change(&self, args: &Value) -> std::result::Result<Value, String> {
+        let seeds: Vec<&Node> = match (opt_str_arg(args, "file_path"), opt_str_arg(args, "symbol"))
+        {
+            (_, Some(symbol)) => self.resolve(symbol),
+            (Some(file), None) => self.file_nodes(file),
+            (None, None) => return Err("file_path or symbol is required".to_string()),
+        };
+        if seeds.is_empty() {
+            return Err("nothing indexed matches the changed file or symbol".to_string());
+        }
+        let depth = usize_arg(args, "depth", 3).max(1);
+        let limit = usize_arg(args, "limit", 100);
+
+        let reached = self.reverse_bfs(&seeds, depth, is_dependency_edge);
+        let mut files: HashMap<&str, usize> = HashMap::new();
+        for (node, _, _) in &reached {
+            *files.entry(node.file_path.as_str()).or_default() += 1;
+        }
+        let mut files: Vec<(&str, usize)> = files.into_iter().collect();
+        files.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+
+        let impacted: Vec<Value> = reached
+            .iter()
+            .take(limit)
+            .map(|(node, depth, edge)| {
+                let mut symbol = symbol_json(node);
+                symbol["depth"] = json!(depth);
+                symbol["via"] = json!(edge.kind.as_str());
+                symbol
+            })
+            .collect();
+        Ok(json!({
+            "changed_symbols": seeds.len(),
+            "impacted_symbols": reached.len(),
+            "impacted": impacted,
+            "impacted_files": files
+                .iter()
+                .map(|(file, count)| json!({ "file_path": file, "symbols": count }))
+                .collect::<Vec<_>>(),
+        }))
+    }
+
+    fn taint_check_file(&self, args: &Value) -> std::result::Result<Value, String> {
+        let file = str_arg(args, "file_path")?;
+        let nodes: Vec<Node> = self.file_nodes(file).into_iter().cloned().collect();
+        if nodes.is_empty() {
+            return Err(format!("file '{}' is not indexed", file));
+        }
+        let ids: HashSet<&str> = nodes.iter().map(|n| n.id.as_str()).collect();
+        let edges: Vec<Edge> = self
+            .doc
+            .edges
+            .iter()
+            .filter(|e| ids.contains(e.source_id.as_str()))
+            .cloned()
+            .collect();
+
+        let service =
+            DefaultTaintAnalysisService::new(Arc::new(InMemoryCodeRepository::new()), None);
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .map_err(|e| e.to_string())?;
+        let response = runtime
+            .block_on(service.analyze_ir(nodes, edges, default_config()))
+            .map_err(|e| e.to_string())?;
+
+        let findings: Vec<Value> = self
+            .vulnerabilities
+            .iter()
+            .filter(|v| matches_file(&v.file_path, file))
+            .map(|v| {
+                json!({
+                    "vuln_type": v.vuln_type,
+                    "cwe_id": v.cwe_id,
+                    "severity": v.severity,
+                    "function_id": v.function_id,
+                    "line": v.line,
+                    "description": v.description,
+                    "suggested_fix": v.suggested_fix,
+                })
+            })
+            .collect();
+        Ok(json!({
+            "file_path": file,
+            "taint_paths": response.forward_paths,
+            "implicit_flows": response.implicit_flows,
+            "vulnerabilities": findings,
+            "nodes_analyzed": response.stats.nodes_analyzed,
+        }))
+    }
+
+    fn repo_map(&self, args: &Value) -> std::result::Result<Value, String> {
+        let limit = usize_arg(args, "limit", 30);
+        let prefix = opt_str_arg(args, "path_prefix").unwrap_or("");
+
+        if let Some(snapshot) = &self.repomap {
+            let focus = str_list_arg(args, "focus");
+            let snapshot = if focus.is_empty() {
+                Cow::Borrowed(snapshot)
+            } else {
+                let seeds: Vec<RepoMapSeed> = focus
+                    .into_iter()
+                    .map(RepoMapSeed::recently_edited)
+                    .collect();
+                let mut focused = snapshot.clone();
+                focused.rescore(&seeds);
+                Cow::Owned(focused)
+            };
+            let mut files: Vec<_> = snapshot
+                .nodes
+                .iter()
+                .filter(|n| n.kind == "File" && n.path.starts_with(prefix))
+                .collect();
+            files.sort_by(|a, b| b.combined_importance.total_cmp(&a.combined_importance));
+            let files: Vec<Value> = files
+                .into_iter()
+                .take(limit)
+                .map(|n| {
+                    json!({
+                        "file_path": n.path,
+                        "importance": n.combined_importance,
+                        "loc": n.loc,
+                        "symbols": n.symbol_count,
+                    })
+                })
+                .collect();
+            return Ok(json!({ "source": "repomap", "files": files }));
+        }
+
+        // No RepoMap snapshot: rank files by the PageRank of their symbols
+        let mut files: HashMap<&str, Vec<(&Node, f64)>> = HashMap::new();
+        for node in &self.doc.nodes {
+            if node.kind == NodeKind::File || !node.file_path.starts_with(prefix) {
+                continue;
+            }
+            let rank = self.signals.pagerank(&node.id);
+            if rank > 0.0 || self.signals.reference_count(&node.id) > 0 {
+                files
+                    .entry(node.file_path.as_str())
+                    .or_default()
+                    .push((node, rank));
+            }
+        }
+        let mut files: Vec<(&str, f64, Vec<(&Node, f64)>)> = files
+            .into_iter()
+            .map(|(file, mut symbols)| {
+                symbols.sort_by(|a, b| b.1.total_cmp(&a.1));
+                (file, symbols.iter().map(|(_, rank)| rank).sum(), symbols)
+            })
+            .collect();
+        files.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(b.0)));
+
+        let files: Vec<Value> = files
+            .into_iter()
+            .take(limit)
+            .map(|(file, importance, symbols)| {
+                json!({
+                    "file_path": file,
+                    "importance": importance,
+                    "key_symbols": symbols
+                        .iter()
+                        .take(5)
+                        .map(|(n, _)| {
+                            json!({
+                                "fqn": n.fqn,
+                                "kind": n.kind.as_str(),
+                                "line": n.span.start_line,
+                            })
+                        })
+                        .collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+        Ok(json!({ "source": "pagerank", "files": files }))
+    }
+
+    /// Nodes named by `target`: node id, else FQN, else simple name
+    fn resolve(&self, target: &str) -> Vec<&Node> {
+        if let Some(node) = self.index.get_node(target) {
+            return vec![node];
+        }
+        let by_fqn: Vec<&Node> = self.doc.nodes.iter().filter(|n| n.fqn == target).collect();
+        if !by_fqn.is_empty() {
+            return by_fqn;
+        }
+        self.index.find_nodes_by_name(target)
+    }
+
+    fn file_nodes(&self, file: &str) -> Vec<&Node> {
+        self.doc
+            .nodes
+            .iter()
+            .filter(|n| n.kind != NodeKind::File && matches_file(&n.file_path, file))
+            .collect()
+    }
+
+    /// Nodes reaching `seeds` over incoming `follow` edges, nearest first
+    ///
+    /// Each entry: (node, depth, edge into the previous level). Edge targets
+    /// are node ids when resolved and FQNs otherwise, so both are looked up.
+    fn reverse_bfs<'a>(
+        &'a self,
+        seeds: &[&'a Node],
+        max_depth: usize,
+        follow: fn(EdgeKind) -> bool,
+    ) -> Vec<(&'a Node, usize, &'a Edge)> {
+        let mut visited: HashSet<&str> = seeds.iter().map(|n| n.id.as_str()).collect();
+        let mut queue: VecDeque<(&Node, usize)> = seeds.iter().map(|&n| (n, 0)).collect();
+        let mut reached = Vec::new();
+
+        while let Some((node, depth)) = queue.pop_front() {
+            if depth >= max_depth {
+                continue;
+            }
+            let mut incoming = self.index.get_edges_to(&node.id);
+            if node.fqn != node.id {
+                incoming.extend(self.index.get_edges_to(&node.fqn));
+            }
+            for edge in incoming {
+                if !follow(edge.kind) {
+                    continue;
+                }
+                let Some(source) = self.index.get_node(&edge.source_id) else {
+                    continue;
+                };
+                if visited.insert(source.id.as_str()) {
+                    reached.push((source, depth + 1, edge));
+                    queue.push_back((source, depth + 1));
+                }
+            }
+        }
+        reached
+    }
+}
+
+fn is_call_edge(kind: EdgeKind) -> bool {
+    matches!(kind, EdgeKind::Calls | EdgeKind::Invokes)
+}
+
+fn is_dependency_edge(kind: EdgeKind) -> bool {
+    matches!(
+        kind,
+        EdgeKind::Calls
+            | EdgeKind::Invokes
+            | EdgeKind::References
+            | EdgeKind::Imports
+            | EdgeKind::Inherits
+            | EdgeKind::Implements
+            | EdgeKind::Extends
+            | EdgeKind::Overrides
+            | EdgeKind::TypeAnnotation
+    )
+}
+
+/// Exact path, or `file` relative to the indexed (usually absolute) path
+fn matches_file(indexed: &str, file: &str) -> bool {
+    indexed == file || indexed.ends_with(&format!("/{}", file.trim_start_matches("./")))
+}
+
+fn symbol_json(node: &Node) -> Value {
+    json!({
+        "id": node.id,
+        "fqn": node.fqn,
+        "name": node.name,
+        "kind": node.kind.as_str(),
+        "file_path": node.file_path,
+        "start_line": node.span.start_line,
+        "end_line": node.span.end_line,
+    })
+}
+
+fn str_arg<'a>(args: &'a Value, key: &str) -> std::result::Result<&'a str, String> {
+    opt_str_arg(args, key).ok_or_else(|| format!("missing string argument '{}'", key))
+}
+
+fn opt_str_arg<'a>(args: &'a Value, key: &str) -> Option<&'a str> {
+    args.get(key)
+        .and_then(Value::as_str)
+        .filter(|s| !s.is_empty())
+}
+
+fn str_list_arg<'a>(args: &'a Value, key: &str) -> Vec<&'a str> {
+    args.get(key)
+        .and_then(Value::as_array)
+        .map(|items| items.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+fn usize_arg(args: &Value, key: &str, default: usize) -> usize {
+    args.get(key)
+        .and_then(Value::as_u64)
+        .map_or(default, |n| n as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::models::Span;
+
+    fn node(id: &str, kind: NodeKind, fqn: &str, file: &str, line: u32) -> Node {
+        Node::new(
+            id.to_string(),
+            kind,
+            fqn.to_string(),
+            file.to_string(),
+            Span::new(line, 0, line + 2, 0),
+        )
+        .with_name(fqn.rsplit('.').next().unwrap())
+    }
+
+    fn tools() -> CodegraphTools {
+        let nodes = vec![
+            node(
+                "f:load",
+                NodeKind::Function,
+                "db.load_user",
+                "/repo/db.py",
+                1,
+            ),
+            node(
+                "f:view",
+                NodeKind::Function,
+                "web.user_view",
+                "/repo/web.py",
+                3,
+            ),
+            node(
+                "f:route",
+                NodeKind::Function,
+                "web.route",
+                "/repo/web.py",
+                10,
+            ),
+            node(
+                "c:User",
+                NodeKind::Class,
+                "models.User",
+                "/repo/models.py",
+                1,
+            ),
+        ];
+        let edges = vec![
+            Edge::new("f:view".to_string(), "f:load".to_string(), EdgeKind::Calls)
+                .with_span(Span::new(4, 4, 4, 20)),
+            Edge::new(
+                "f:route".to_string(),
+                "web.user_view".to_string(),
+                EdgeKind::Calls,
+            ),
+            Edge::new(
+                "f:load".to_string(),
+                "c:User".to_string(),
+                EdgeKind::References,
+            ),
+        ];
+        CodegraphTools::from_result(
+            "repo",
+            E2EPipelineResult {
+                nodes,
+                edges,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn test_callers_of_follows_fqn_targets() {
+        let tools = tools();
+        let direct = tools
+            .call("callers_of", &json!({ "function": "load_user" }))
+            .unwrap();
+        assert_eq!(direct["total_callers"], 1);
+        assert_eq!(direct["callers"][0]["fqn"], "web.user_view");
+        assert_eq!(direct["callers"][0]["call_line"], 4);
+
+        let transitive = tools
+            .call(
+                "callers_of",
+                &json!({ "function": "db.load_user", "depth": 2 }),
+            )
+            .unwrap();
+        assert_eq!(transitive["callers"][1]["fqn"], "web.route");
+        assert_eq!(transitive["callers"][1]["depth"], 2);
+
+        assert!(tools
+            .call("callers_of", &json!({ "function": "User" }))
+            .is_err());
+    }
+
+    #[test]
+    fn test_impact_of_change_groups_files() {
+        let tools = tools();
+        let impact = tools
+            .call("impact_of_change", &json!({ "file_path": "models.py" }))
+            .unwrap();
+        assert_eq!(impact["changed_symbols"], 1);
+        assert_eq!(impact["impacted_symbols"], 3);
+        assert_eq!(impact["impacted_files"][0]["file_path"], "/repo/web.py");
+        assert_eq!(impact["impacted_files"][0]["symbols"], 2);
+
+        let shallow = tools
+            .call(
+                "impact_of_change",
+                &json!({ "symbol": "models.User", "depth": 1 }),
+            )
+            .unwrap();
+        assert_eq!(shallow["impacted"][0]["via"], "REFERENCES");
+        assert_eq!(shallow["impacted_symbols"], 1);
+    }
+
+    #[test]
+    fn test_find_definition_and_arguments() {
+        let tools = tools();
+        let found = tools
+            .call("find_definition", &json!({ "symbol": "user view" }))
+            .unwrap();
+        assert_eq!(found["definitions"][0]["fqn"], "web.user_view");
+        assert_eq!(found["definitions"][0]["file_path"], "/repo/web.py");
+
+        assert!(tools.call("find_definition", &json!({})).is_err());
+        assert!(tools.call("no_such_tool", &json!({})).is_err());
+        assert_eq!(tool_definitions().as_array().unwrap().len(), 5);
+
+        let map = tools.call("repo_map", &json!({ "limit": 2 })).unwrap();
+        assert_eq!(map["source"], "pagerank");
+        assert!(map["files"].as_array().unwrap().len() <= 2);
+    }
+}
@@ -1,7 +1,19 @@
 //! Inbound adapters - external systems connecting to our application
 //!
-//! Currently: PyO3 (Python bindings)
-//! Future: CLI, gRPC, HTTP, etc.
+//! Currently: PyO3 (Python bindings), gRPC (`grpc` feature), Node.js
+//! (`node` feature), WebAssembly (`wasm` feature), MCP (stdio)
+//! Future: CLI, HTTP, etc.
 
 #[cfg(feature = "python")]
 pub mod pyo3;
+
+#[cfg(feature = "grpc")]
+pub mod grpc;
+
+#[cfg(feature = "node")]
+pub mod node;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub mod mcp;
@@ -0,0 +1,335 @@
+//! Node.js adapter (`node` feature)
+//!
+//! napi-rs bindings over `api::Engine` for TypeScript tooling. Call graph and
+//! taint queries reuse the MCP tool implementations, so results have the same
+//! JSON shape; bulk data leaves as msgpack (`GraphQuery::from_ir_bytes`
+//! format) or Arrow IPC (`result_to_arrow_ipc`) buffers.
+//!
+//! ```js
+//! const { indexRepository } = require('codegraph-ir');
+//!
+//! const graph = await indexRepository('/repo', { repomap: true });
+//! graph.queryEngine.callersOf('load_user', { depth: 2 });
+//! graph.checkTaint('app/views.py');
+//! const { nodes } = graph.toArrow(); // Buffer, e.g. for apache-arrow tableFromIPC
+//! ```
+//!
+//! Build with `napi build --release --features node` (see `package.json`).
+
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
+
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::adapters::mcp::CodegraphTools;
+use crate::api::{Engine, IndexOptions, IndexResult};
+use crate::pipeline::{result_to_arrow_ipc, E2EPipelineResult};
+use crate::shared::models::{Edge, Node};
+
+/// `indexRepository` options; unset fields keep the `IndexOptions` defaults
+#[napi(object)]
+#[derive(Default)]
+pub struct JsIndexOptions {
+    /// Defaults to the repository directory name
+    pub repo_name: Option<String>,
+    pub file_paths: Option<Vec<String>>,
+    pub chunking: Option<bool>,
+    pub cross_file: Option<bool>,
+    pub symbols: Option<bool>,
+    pub points_to: Option<bool>,
+    pub repomap: Option<bool>,
+    pub taint: Option<bool>,
+    pub clone: Option<bool>,
+    /// Worker threads (0 = auto)
+    pub parallel_workers: Option<u32>,
+}
+
+impl JsIndexOptions {
+    fn into_options(self, repo_root: &str) -> IndexOptions {
+        let repo_name = self.repo_name.unwrap_or_else(|| {
+            Path::new(repo_root)
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "repo".to_string())
+        });
+        let defaults = IndexOptions::new(repo_root, repo_name);
+        let mut options = IndexOptions {
+            chunking: self.chunking.unwrap_or(defaults.chunking),
+            cross_file: self.cross_file.unwrap_or(defaults.cross_file),
+            symbols: self.symbols.unwrap_or(defaults.symbols),
+            points_to: self.points_to.unwrap_or(defaults.points_to),
+            repomap: self.repomap.unwrap_or(defaults.repomap),
+            taint: self.taint.unwrap_or(defaults.taint),
+            clone: self.clone.unwrap_or(defaults.clone),
+            parallel_workers: self
+                .parallel_workers
+                .map_or(defaults.parallel_workers, |n| n as usize),
+            ..defaults
+        };
+        if let Some(file_paths) = self.file_paths {
+            options = options.with_file_paths(file_paths.into_iter().map(Into::into).collect());
+        }
+        options
+    }
+}
+
+/// `callersOf` options
+#[napi(object)]
+pub struct CallersOptions {
+    /// Call levels to follow (default 1)
+    pub depth: Option<u32>,
+    /// Maximum callers (default 50)
+    pub limit: Option<u32>,
+}
+
+/// Subset of `PipelineStats` for JS
+#[napi(object)]
+pub struct IndexStats {
+    pub files_processed: u32,
+    pub files_failed: u32,
+    pub total_loc: u32,
+    pub duration_ms: f64,
+    pub loc_per_second: f64,
+    pub errors: Vec<String>,
+}
+
+/// Arrow IPC stream per table; `file_id` columns index `files`
+#[napi(object)]
+pub struct ArrowTables {
+    pub files: Vec<String>,
+    pub nodes: Buffer,
+    pub edges: Buffer,
+    pub occurrences: Buffer,
+    pub chunks: Buffer,
+    pub file_metrics: Buffer,
+}
+
+/// Background indexing behind `indexRepository`
+pub struct IndexTask {
+    options: IndexOptions,
+}
+
+impl Task for IndexTask {
+    type Output = E2EPipelineResult;
+    type JsValue = CodeGraph;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        Engine::new()
+            .index(&self.options)
+            .map(IndexResult::into_pipeline)
+            .map_err(|e| Error::from_reason(e.to_string()))
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(CodeGraph::new(self.options.repo_name.clone(), output))
+    }
+}
+
+/// Index `repoRoot` on the libuv thread pool; resolves to a `CodeGraph`
+#[napi]
+pub fn index_repository(
+    repo_root: String,
+    options: Option<JsIndexOptions>,
+) -> AsyncTask<IndexTask> {
+    AsyncTask::new(IndexTask {
+        options: options.unwrap_or_default().into_options(&repo_root),
+    })
+}
+
+/// One indexed repository
+#[napi]
+pub struct CodeGraph {
+    repo_name: String,
+    result: E2EPipelineResult,
+    /// Query/taint tools, built on first use
+    tools: OnceLock<Arc<CodegraphTools>>,
+}
+
+impl CodeGraph {
+    fn new(repo_name: String, result: E2EPipelineResult) -> Self {
+        Self {
+            repo_name,
+            result,
+            tools: OnceLock::new(),
+        }
+    }
+
+    fn tools(&self) -> &Arc<CodegraphTools> {
+        self.tools.get_or_init(|| {
+            Arc::new(CodegraphTools::from_result(
+                self.repo_name.clone(),
+                self.result.clone(),
+            ))
+        })
+    }
+}
+
+#[napi]
+impl CodeGraph {
+    #[napi(getter)]
+    pub fn repo_name(&self) -> String {
+        self.repo_name.clone()
+    }
+
+    #[napi(getter)]
+    pub fn stats(&self) -> IndexStats {
+        let stats = &self.result.stats;
+        IndexStats {
+            files_processed: stats.files_processed as u32,
+            files_failed: stats.files_failed as u32,
+            total_loc: stats.total_loc as u32,
+            duration_ms: stats.total_duration.as_secs_f64() * 1000.0,
+            loc_per_second: stats.loc_per_second,
+            errors: stats.errors.clone(),
+        }
+    }
+
+    #[napi(getter)]
+    pub fn query_engine(&self) -> JsQueryEngine {
+        JsQueryEngine {
+            tools: Arc::clone(self.tools()),
+        }
+    }
+
+    /// Taint paths and indexed vulnerabilities of one file
+    #[napi]
+    pub fn check_taint(&self, file_path: String) -> Result<Value> {
+        call_tool(
+            self.tools(),
+            "taint_check_file",
+            json!({ "file_path": file_path }),
+        )
+    }
+
+    /// Nodes and edges as msgpack (`GraphQuery::from_ir_bytes` input)
+    #[napi]
+    pub fn to_msgpack(&self) -> Result<Buffer> {
+        #[derive(Serialize)]
+        struct IrPayload<'a> {
+            nodes: &'a [Node],
+            edges: &'a [Edge],
+        }
+        let payload = IrPayload {
+            nodes: &self.result.nodes,
+            edges: &self.result.edges,
+        };
+        rmp_serde::to_vec_named(&payload)
+            .map(Buffer::from)
+            .map_err(|e| Error::from_reason(e.to_string()))
+    }
+
+    /// Result tables as Arrow IPC streams
+    #[napi]
+    pub fn to_arrow(&self) -> Result<ArrowTables> {
+        let tables =
+            result_to_arrow_ipc(&self.result).map_err(|e| Error::from_reason(e.to_string()))?;
+        Ok(ArrowTables {
+            files: self.result.file_table().paths().to_vec(),
+            nodes: tables.nodes.into(),
+            edges: tables.edges.into(),
+            occurrences: tables.occurrences.into(),
+            chunks: tables.chunks.into(),
+            file_metrics: tables.file_metrics.into(),
+        })
+    }
+}
+
+/// Call graph queries (`graph.queryEngine`)
+#[napi(js_name = "QueryEngine")]
+pub struct JsQueryEngine {
+    tools: Arc<CodegraphTools>,
+}
+
+#[napi]
+impl JsQueryEngine {
+    /// Functions calling `function` (node id, FQN or name)
+    #[napi]
+    pub fn callers_of(&self, function: String, options: Option<CallersOptions>) -> Result<Value> {
+        call_tool(&self.tools, "callers_of", callers_args(function, options))
+    }
+}
+
+/// `callers_of` tool arguments; unset options keep the tool defaults
+fn callers_args(function: String, options: Option<CallersOptions>) -> Value {
+    let mut args = json!({ "function": function });
+    if let Some(options) = options {
+        if let Some(depth) = options.depth {
+            args["depth"] = json!(depth);
+        }
+        if let Some(limit) = options.limit {
+            args["limit"] = json!(limit);
+        }
+    }
+    args
+}
+
+fn call_tool(tools: &CodegraphTools, name: &str, args: Value) -> Result<Value> {
+    tools.call(name, &args).map_err(Error::from_reason)
+}
+
+#[cfg(all(test, feature = "node"))]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_index_options_keep_defaults() {
+        let options = JsIndexOptions::default().into_options("/work/shop");
+        let defaults = IndexOptions::new("/work/shop", "shop");
+
+        assert_eq!(options.repo_root, PathBuf::from("/work/shop"));
+        assert_eq!(options.repo_name, "shop");
+        assert_eq!(options.file_paths, None);
+        assert_eq!(options.chunking, defaults.chunking);
+        assert_eq!(options.cross_file, defaults.cross_file);
+        assert_eq!(options.points_to, defaults.points_to);
+        assert_eq!(options.repomap, defaults.repomap);
+        assert_eq!(options.taint, defaults.taint);
+        assert_eq!(options.parallel_workers, 0);
+
+        // No directory name to fall back on
+        assert_eq!(
+            JsIndexOptions::default().into_options("/").repo_name,
+            "repo"
+        );
+    }
+
+    #[test]
+    fn test_index_options_overrides() {
+        let options = JsIndexOptions {
+            repo_name: Some("acme".to_string()),
+            file_paths: Some(vec!["app/main.py".to_string()]),
+            chunking: Some(false),
+            taint: Some(true),
+            parallel_workers: Some(4),
+            ..Default::default()
+        }
+        .into_options("/work/shop");
+
+        assert_eq!(options.repo_name, "acme");
+        assert_eq!(options.file_paths, Some(vec![PathBuf::from("app/main.py")]));
+        assert!(!options.chunking);
+        assert!(options.taint);
+        assert!(options.cross_file);
+        assert_eq!(options.parallel_workers, 4);
+    }
+
+    #[test]
+    fn test_callers_args() {
+        assert_eq!(
+            callers_args("load_user".to_string(), None),
+            json!({ "function": "load_user" })
+        );
+        let options = CallersOptions {
+            depth: Some(2),
+            limit: None,
+        };
+        assert_eq!(
+            callers_args("load_user".to_string(), Some(options)),
+            json!({ "function": "load_user", "depth": 2 })
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Call-path search between two symbols
+//!
+//! ```python
+//! paths = codegraph_ir.find_call_paths(ir_docs, "app.views.handle", "app.db.execute",
+//!                                      max_depth=6, edge_kinds=["Calls"], max_paths=3)
+//! for path in paths:
+//!     print(" -> ".join(step["fqn"] for step in path["steps"]))
+//! ```
+//!
+//! Each path is `{"hops": int, "steps": [...]}`; a step has `fqn`,
+//! `file_path`, `span` and, after the first, `edge_kind` and `call_site`
+//! (span of the reference in the previous symbol, or None).
+
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::features::cross_file::{IRDocument, SymbolDependencyGraph, SymbolEdgeKind, SymbolPath};
+use crate::shared::models::Span;
+
+/// Up to `max_paths` shortest symbol chains `from_fqn → to_fqn`
+///
+/// `edge_kinds` names the edges to follow ("Calls", "Imports", "Reads",
+/// ...); default `["Calls"]`.
+#[pyfunction]
+#[pyo3(name = "find_call_paths")]
+#[pyo3(signature = (ir_docs, from_fqn, to_fqn, max_depth = 8, edge_kinds = None, max_paths = 5))]
+pub fn find_call_paths_py(
+    py: Python,
+    ir_docs: Vec<IRDocument>,
+    from_fqn: String,
+    to_fqn: String,
+    max_depth: usize,
+    edge_kinds: Option<Vec<String>>,
+    max_paths: usize,
+) -> PyResult<Py<PyList>> {
+    let kinds = edge_kinds
+        .unwrap_or_default()
+        .iter()
+        .map(|name| {
+            crate::parse_symbol_edge_kind(name).ok_or_else(|| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Unknown edge kind: {}",
+                    name
+                ))
+            })
+        })
+        .collect::<PyResult<Vec<SymbolEdgeKind>>>()?;
+
+    crate::init_rayon();
+    let paths = py.allow_threads(|| {
+        SymbolDependencyGraph::build_from_irs(&ir_docs)
+            .find_paths(&from_fqn, &to_fqn, max_depth, &kinds, max_paths)
+    });
+
+    let list = PyList::empty(py);
+    for path in &paths {
+        list.append(path_to_py_dict(py, path)?)?;
+    }
+    Ok(list.into())
+}
+
+fn path_to_py_dict<'py>(py: Python<'py>, path: &SymbolPath) -> PyResult<&'py PyDict> {
+    let dict = PyDict::new(py);
+    dict.set_item("hops", path.hops())?;
+    let steps = PyList::empty(py);
+    for step in &path.steps {
+        let step_dict = PyDict::new(py);
+        step_dict.set_item("fqn", &step.fqn)?;
+        step_dict.set_item("file_path", &step.file_path)?;
+        step_dict.set_item("span", span_to_py_dict(py, &step.span)?)?;
+        step_dict.set_item(
+            "edge_kind",
+            step.edge_kind.map(crate::symbol_edge_kind_to_str),
+        )?;
+        let call_site = step
+            .call_site
+            .as_ref()
+            .map(|span| span_to_py_dict(py, span))
+            .transpose()?;
+        step_dict.set_item("call_site", call_site)?;
+        steps.append(step_dict)?;
+    }
+    dict.set_item("steps", steps)?;
+    Ok(dict)
+}
+
+fn span_to_py_dict<'py>(py: Python<'py>, span: &Span) -> PyResult<&'py PyDict> {
+    let dict = PyDict::new(py);
+    dict.set_item("start_line", span.start_line)?;
+    dict.set_item("start_col", span.start_col)?;
+    dict.set_item("end_line", span.end_line)?;
+    dict.set_item("end_col", span.end_col)?;
+    Ok(dict)
+}
+
+/// Register the call-path API
+pub fn register_call_paths_api(m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(find_call_paths_py, m)?)?;
+    Ok(())
+}
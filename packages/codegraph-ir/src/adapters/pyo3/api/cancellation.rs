@@ -0,0 +1,97 @@
+//! Python pipeline cancellation
+//!
+//! `CancellationToken` is passed as `cancel_token` to `run_pipeline_with_config`
+//! or `run_pipeline_streaming`. The pipeline runs with the GIL released, so
+//! the token is cancelled from another thread (or from a signal handler while
+//! the pipeline runs in a worker thread):
+//!
+//! ```python
+//! token = CancellationToken()
+//! worker = threading.Thread(target=run_pipeline_with_config,
+//!                           args=(repo, "my-repo", config),
+//!                           kwargs={"cancel_token": token})
+//! worker.start()
+//! signal.signal(signal.SIGINT, lambda *_: token.cancel())
+//! ```
+//!
+//! A cancelled run raises `PipelineCancelled(message, partial)`, with
+//! `partial` a dict of what completed (`stage`, `files_parsed`,
+//! `total_files`, `stages_completed`, `files_cached`, `total_duration_ms`).
+//! Parsed files stay in the IR cache for the next run.
+
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::pipeline::{CancellationToken, CancelledRun};
+use crate::shared::models::CodegraphError;
+
+pyo3::create_exception!(
+    codegraph_ir,
+    PipelineCancelled,
+    pyo3::exceptions::PyRuntimeError,
+    "Pipeline stopped by its CancellationToken"
+);
+
+/// Token stopping a running pipeline
+#[pyclass(name = "CancellationToken")]
+#[derive(Clone, Default)]
+pub struct PyCancellationToken {
+    pub(crate) token: CancellationToken,
+}
+
+#[pymethods]
+impl PyCancellationToken {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation; running files and stages finish first
+    fn cancel(&self) {
+        self.token.cancel();
+    }
+
+    #[getter]
+    fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("CancellationToken(cancelled={})", self.token.is_cancelled())
+    }
+}
+
+/// Python exception for a failed run (`PipelineCancelled` when cancelled)
+pub fn pipeline_error_to_python(py: Python, error: CodegraphError) -> PyErr {
+    match CancelledRun::from_error(&error) {
+        Some(partial) => match partial_to_python(py, partial) {
+            Ok(dict) => PipelineCancelled::new_err((error.message.clone(), dict.to_object(py))),
+            Err(e) => e,
+        },
+        None => PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(error.message),
+    }
+}
+
+fn partial_to_python<'py>(py: Python<'py>, partial: &CancelledRun) -> PyResult<&'py PyDict> {
+    let dict = PyDict::new(py);
+    dict.set_item("stage", &partial.stage)?;
+    dict.set_item("files_parsed", partial.files_parsed)?;
+    dict.set_item("total_files", partial.total_files)?;
+    dict.set_item(
+        "stages_completed",
+        PyList::new(py, partial.stages_completed.iter().map(String::as_str)),
+    )?;
+    dict.set_item("files_cached", partial.stats.files_cached)?;
+    dict.set_item(
+        "total_duration_ms",
+        partial.stats.total_duration.as_millis(),
+    )?;
+    Ok(dict)
+}
+
+/// Register `CancellationToken` and `PipelineCancelled`
+pub fn register_cancellation_api(m: &PyModule) -> PyResult<()> {
+    m.add_class::<PyCancellationToken>()?;
+    m.add("PipelineCancelled", m.py().get_type::<PipelineCancelled>())?;
+    Ok(())
+}
@@ -32,8 +32,8 @@ use pyo3::types::{PyDict, PyList};
 use serde::{Deserialize, Serialize};
 
 use crate::features::clone_detection::{
-    CodeFragment, CloneDetector, Type1Detector, Type2Detector, Type3Detector, Type4Detector,
-    MultiLevelDetector, CloneType,
+    CloneDetector, CloneType, CodeFragment, MultiLevelDetector, Type1Detector, Type2Detector,
+    Type3Detector, Type4Detector,
 };
 use crate::shared::models::Span;
 
@@ -54,7 +54,12 @@ impl From<PyCodeFragment> for CodeFragment {
     fn from(py_frag: PyCodeFragment) -> Self {
         CodeFragment::new(
             py_frag.file_path,
-            Span::new(py_frag.start_line, py_frag.start_col, py_frag.end_line, py_frag.end_col),
+            Span::new(
+                py_frag.start_line,
+                py_frag.start_col,
+                py_frag.end_line,
+                py_frag.end_col,
+            ),
             py_frag.content,
             py_frag.token_count,
             py_frag.loc,
@@ -116,63 +121,48 @@ impl From<crate::features::clone_detection::domain::ClonePair> for PyClonePair {
     }
 }
 
+/// Required key of a fragment dict
+fn required<'py, T: FromPyObject<'py>>(dict: &'py PyDict, key: &str) -> PyResult<T> {
+    dict.get_item(key)?
+        .ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Missing '{}'", key))
+        })?
+        .extract()
+}
+
+/// Optional key of a fragment dict (columns default to 0)
+fn optional<'py, T: FromPyObject<'py>>(dict: &'py PyDict, key: &str) -> PyResult<Option<T>> {
+    dict.get_item(key)?.map(|v| v.extract()).transpose()
+}
+
 /// Convert Python list to Rust CodeFragment vector
-fn parse_fragments(py: Python, fragments: &PyList) -> PyResult<Vec<CodeFragment>> {
+fn parse_fragments(fragments: &PyList) -> PyResult<Vec<CodeFragment>> {
     let mut result = Vec::with_capacity(fragments.len());
 
     for item in fragments.iter() {
         let dict = item.downcast::<PyDict>()?;
 
-        let file_path = dict.get_item("file_path")?
-            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("Missing 'file_path'"))?
-            .extract::<String>()?;
-
-        let start_line = dict.get_item("start_line")?
-            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("Missing 'start_line'"))?
-            .extract::<u32>()?;
-
-        let start_col = dict.get_item("start_col")
-            .ok()
-            .and_then(|opt| opt)
-            .and_then(|v| v.extract::<u32>().ok())
-            .unwrap_or(0);
-
-        let end_line = dict.get_item("end_line")?
-            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("Missing 'end_line'"))?
-            .extract::<u32>()?;
-
-        let end_col = dict.get_item("end_col")
-            .ok()
-            .and_then(|opt| opt)
-            .and_then(|v| v.extract::<u32>().ok())
-            .unwrap_or(0);
-
-        let content = dict.get_item("content")?
-            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("Missing 'content'"))?
-            .extract::<String>()?;
-
-        let token_count = dict.get_item("token_count")?
-            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("Missing 'token_count'"))?
-            .extract::<usize>()?;
-
-        let loc = dict.get_item("loc")?
-            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("Missing 'loc'"))?
-            .extract::<usize>()?;
-
-        result.push(CodeFragment::new(
-            file_path,
-            Span::new(start_line, start_col, end_line, end_col),
-            content,
-            token_count,
-            loc,
-        ));
+        let fragment = PyCodeFragment {
+            file_path: required(dict, "file_path")?,
+            start_line: required(dict, "start_line")?,
+            start_col: optional(dict, "start_col")?.unwrap_or(0),
+            end_line: required(dict, "end_line")?,
+            end_col: optional(dict, "end_col")?.unwrap_or(0),
+            content: required(dict, "content")?,
+            token_count: required(dict, "token_count")?,
+            loc: required(dict, "loc")?,
+        };
+        result.push(fragment.into());
     }
 
     Ok(result)
 }
 
 /// Convert clone pairs to Python dict list
-fn pairs_to_py_list(py: Python, pairs: Vec<crate::features::clone_detection::domain::ClonePair>) -> PyResult<Py<PyList>> {
+fn pairs_to_py_list(
+    py: Python,
+    pairs: Vec<crate::features::clone_detection::domain::ClonePair>,
+) -> PyResult<Py<PyList>> {
     let py_pairs: Vec<PyClonePair> = pairs.into_iter().map(PyClonePair::from).collect();
     let list = PyList::empty(py);
 
@@ -237,7 +227,7 @@ fn pairs_to_py_list(py: Python, pairs: Vec<crate::features::clone_detection::dom
 #[pyfunction]
 #[pyo3(name = "detect_clones_all")]
 pub fn detect_clones_all_py(py: Python, fragments: &PyList) -> PyResult<Py<PyList>> {
-    let fragments = parse_fragments(py, fragments)?;
+    let fragments = parse_fragments(fragments)?;
 
     let pairs = py.allow_threads(|| {
         let detector = MultiLevelDetector::new();
@@ -264,7 +254,7 @@ pub fn detect_clones_type1_py(
     min_tokens: usize,
     min_loc: usize,
 ) -> PyResult<Py<PyList>> {
-    let fragments = parse_fragments(py, fragments)?;
+    let fragments = parse_fragments(fragments)?;
 
     let pairs = py.allow_threads(|| {
         let detector = Type1Detector::with_thresholds(min_tokens, min_loc);
@@ -293,7 +283,7 @@ pub fn detect_clones_type2_py(
     min_loc: usize,
     min_similarity: f64,
 ) -> PyResult<Py<PyList>> {
-    let fragments = parse_fragments(py, fragments)?;
+    let fragments = parse_fragments(fragments)?;
 
     let pairs = py.allow_threads(|| {
         let detector = Type2Detector::with_thresholds(min_tokens, min_loc, min_similarity);
@@ -324,10 +314,11 @@ pub fn detect_clones_type3_py(
     min_similarity: f64,
     max_gap_ratio: f64,
 ) -> PyResult<Py<PyList>> {
-    let fragments = parse_fragments(py, fragments)?;
+    let fragments = parse_fragments(fragments)?;
 
     let pairs = py.allow_threads(|| {
-        let detector = Type3Detector::with_thresholds(min_tokens, min_loc, min_similarity, max_gap_ratio);
+        let detector =
+            Type3Detector::with_thresholds(min_tokens, min_loc, min_similarity, max_gap_ratio);
         detector.detect(&fragments)
     });
 
@@ -359,7 +350,7 @@ pub fn detect_clones_type4_py(
     edge_weight: f64,
     pattern_weight: f64,
 ) -> PyResult<Py<PyList>> {
-    let fragments = parse_fragments(py, fragments)?;
+    let fragments = parse_fragments(fragments)?;
 
     let pairs = py.allow_threads(|| {
         let detector = Type4Detector::with_thresholds(
@@ -393,37 +384,36 @@ pub fn detect_clones_in_file_py(
     file_path: &str,
     clone_type: &str,
 ) -> PyResult<Py<PyList>> {
-    let fragments = parse_fragments(py, fragments)?;
+    let fragments = parse_fragments(fragments)?;
 
-    let pairs = py.allow_threads(|| {
-        match clone_type {
-            "type1" => {
-                let detector = Type1Detector::new();
-                detector.detect_in_file(&fragments, file_path)
-            }
-            "type2" => {
-                let detector = Type2Detector::new();
-                detector.detect_in_file(&fragments, file_path)
-            }
-            "type3" => {
-                let detector = Type3Detector::new();
-                detector.detect_in_file(&fragments, file_path)
-            }
-            "type4" => {
-                let detector = Type4Detector::new();
-                detector.detect_in_file(&fragments, file_path)
-            }
-            "all" => {
-                let detector = MultiLevelDetector::new();
-                let all_pairs = detector.detect_all(&fragments);
-                all_pairs.into_iter()
-                    .filter(|pair| {
-                        pair.source.file_path == file_path && pair.target.file_path == file_path
-                    })
-                    .collect()
-            }
-            _ => Vec::new(),
+    let pairs = py.allow_threads(|| match clone_type {
+        "type1" => {
+            let detector = Type1Detector::new();
+            detector.detect_in_file(&fragments, file_path)
+        }
+        "type2" => {
+            let detector = Type2Detector::new();
+            detector.detect_in_file(&fragments, file_path)
+        }
+        "type3" => {
+            let detector = Type3Detector::new();
+            detector.detect_in_file(&fragments, file_path)
         }
+        "type4" => {
+            let detector = Type4Detector::new();
+            detector.detect_in_file(&fragments, file_path)
+        }
+        "all" => {
+            let detector = MultiLevelDetector::new();
+            let all_pairs = detector.detect_all(&fragments);
+            all_pairs
+                .into_iter()
+                .filter(|pair| {
+                    pair.source.file_path == file_path && pair.target.file_path == file_path
+                })
+                .collect()
+        }
+        _ => Vec::new(),
     });
 
     pairs_to_py_list(py, pairs)
@@ -432,7 +422,6 @@ pub fn detect_clones_in_file_py(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use pyo3::types::IntoPyDict;
 
     #[test]
     fn test_py_code_fragment_conversion() {
@@ -458,36 +447,42 @@ mod tests {
         pyo3::prepare_freethreaded_python();
 
         Python::with_gil(|py| {
-            let fragments = PyList::new(
-                py,
-                &[
-                    [
-                        ("file_path", "file1.py"),
-                        ("start_line", "1"),
-                        ("end_line", "2"),
-                        ("content", "def foo(): return 42"),
-                        ("token_count", "50"),
-                        ("loc", "1"),
-                    ]
-                    .into_py_dict(py),
-                    [
-                        ("file_path", "file2.py"),
-                        ("start_line", "10"),
-                        ("end_line", "11"),
-                        ("content", "def foo(): return 42"),
-                        ("token_count", "50"),
-                        ("loc", "1"),
-                    ]
-                    .into_py_dict(py),
-                ],
-            );
-
-            let result = detect_clones_type1_py(py, fragments, 10, 1);
-            assert!(result.is_ok());
-
-            let pairs = result.unwrap();
-            let pairs_ref = pairs.as_ref(py);
-            assert!(pairs_ref.len() > 0);
+            let fragment = |file_path: &str, start_line: u32| {
+                let dict = PyDict::new(py);
+                dict.set_item("file_path", file_path).unwrap();
+                dict.set_item("start_line", start_line).unwrap();
+                dict.set_item("end_line", start_line + 1).unwrap();
+                dict.set_item("content", "def foo(): return 42").unwrap();
+                dict.set_item("token_count", 50).unwrap();
+                dict.set_item("loc", 1).unwrap();
+                dict
+            };
+            let fragments = PyList::new(py, [fragment("file1.py", 1), fragment("file2.py", 10)]);
+
+            let pairs = detect_clones_type1_py(py, fragments, 10, 1).unwrap();
+            let pairs = pairs.as_ref(py);
+            assert!(!pairs.is_empty());
+
+            let pair = pairs.get_item(0).unwrap().downcast::<PyDict>().unwrap();
+            let similarity: f64 = pair
+                .get_item("similarity")
+                .unwrap()
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(similarity, 1.0);
+        });
+    }
+
+    #[test]
+    fn test_parse_fragments_missing_key() {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let dict = PyDict::new(py);
+            dict.set_item("file_path", "file1.py").unwrap();
+            let err = parse_fragments(PyList::new(py, [dict])).unwrap_err();
+            assert!(err.to_string().contains("start_line"));
         });
     }
 }
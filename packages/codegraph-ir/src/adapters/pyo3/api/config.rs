@@ -25,9 +25,11 @@ use pyo3::types::{PyDict, PyList};
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use super::cancellation::PyCancellationToken;
+use super::points_to::PyPointsToResult;
 use crate::config::{
     preset::Preset, ChunkingConfig, HeapConfig, PDGConfig,
-    PTAConfig, PTAMode, ParallelConfig, PipelineConfig, SlicingConfig, StageControl, TaintConfig,
+    PTAConfig, PTAMode, ParallelConfig, PipelineConfig, SliceCriterion, SliceKind, SlicingConfig, StageControl, TaintConfig,
 };
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -224,6 +226,24 @@ impl PyStageControl {
         self.inner.slicing = v;
     }
 
+    #[getter]
+    fn vector(&self) -> bool {
+        self.inner.vector
+    }
+    #[setter]
+    fn set_vector(&mut self, v: bool) {
+        self.inner.vector = v;
+    }
+
+    #[getter]
+    fn git_blame(&self) -> bool {
+        self.inner.git_blame
+    }
+    #[setter]
+    fn set_git_blame(&mut self, v: bool) {
+        self.inner.git_blame = v;
+    }
+
     fn __repr__(&self) -> String {
         let enabled: Vec<&str> = vec![
             if self.inner.parsing { "parsing" } else { "" },
@@ -237,6 +257,8 @@ impl PyStageControl {
             if self.inner.heap { "heap" } else { "" },
             if self.inner.pdg { "pdg" } else { "" },
             if self.inner.slicing { "slicing" } else { "" },
+            if self.inner.vector { "vector" } else { "" },
+            if self.inner.git_blame { "git_blame" } else { "" },
         ]
         .into_iter()
         .filter(|s| !s.is_empty())
@@ -385,6 +407,12 @@ impl PyPipelineConfig {
             if let Ok(Some(v)) = kw.get_item("slicing") {
                 self.stages.inner.slicing = v.extract().unwrap_or(false);
             }
+            if let Ok(Some(v)) = kw.get_item("vector") {
+                self.stages.inner.vector = v.extract().unwrap_or(false);
+            }
+            if let Ok(Some(v)) = kw.get_item("git_blame") {
+                self.stages.inner.git_blame = v.extract().unwrap_or(false);
+            }
         }
     }
 
@@ -424,7 +452,8 @@ impl PyPipelineConfig {
     /// Override points-to analysis configuration
     ///
     /// Args:
-    ///     mode: "fast", "precise", "hybrid", or "auto"
+    ///     mode: "fast", "precise", "hybrid", "auto", "call_site_sensitive"
+    ///         (1-CFA) or "object_sensitive" (1-object)
     ///     field_sensitive: Field-sensitive analysis
     ///     max_iterations: Max Andersen iterations
     ///     auto_threshold: Size threshold for Auto mode
@@ -499,6 +528,9 @@ impl PyPipelineConfig {
     ///     interprocedural: Cross-function slicing
     ///     strict_mode: Error on missing nodes
     ///     cache_capacity: LRU cache size
+    ///     criteria: Slices computed during the pipeline, as dicts with
+    ///         function (FQN or name), variable, line and optional kinds
+    ///         (["backward", "forward", "thin"])
     #[pyo3(signature = (**kwargs))]
     fn set_slicing(&mut self, py: Python, kwargs: Option<&PyDict>) {
         if let Some(kw) = kwargs {
@@ -760,6 +792,8 @@ impl PyPipelineConfig {
                     "precise" => PTAMode::Precise,
                     "hybrid" => PTAMode::Hybrid,
                     "auto" => PTAMode::Auto,
+                    "call_site_sensitive" => PTAMode::CallSiteSensitive,
+                    "object_sensitive" => PTAMode::ObjectSensitive,
                     _ => cfg.mode,
                 };
             }
@@ -903,6 +937,26 @@ impl PyPipelineConfig {
                 cfg.cache_capacity = n;
             }
         }
+        if let Some(v) = overrides.get("criteria") {
+            if let Ok(criteria) = v.extract::<Vec<HashMap<String, PyObject>>>(py) {
+                cfg.criteria = criteria
+                    .iter()
+                    .filter_map(|c| Self::extract_slice_criterion(py, c))
+                    .collect();
+            }
+        }
+    }
+
+    /// `{"function": ..., "variable": ..., "line": ..., "kinds": [...]}`
+    fn extract_slice_criterion(py: Python, c: &HashMap<String, PyObject>) -> Option<SliceCriterion> {
+        let function = c.get("function")?.extract::<String>(py).ok()?;
+        let variable = c.get("variable")?.extract::<String>(py).ok()?;
+        let line = c.get("line")?.extract::<u32>(py).ok()?;
+        let mut criterion = SliceCriterion::new(function, variable, line);
+        if let Some(kinds) = c.get("kinds").and_then(|k| k.extract::<Vec<String>>(py).ok()) {
+            criterion.kinds = kinds.iter().filter_map(|k| SliceKind::parse(k)).collect();
+        }
+        Some(criterion)
     }
 
     fn apply_chunking_overrides(py: Python, cfg: &mut ChunkingConfig, overrides: &HashMap<String, PyObject>) {
@@ -976,6 +1030,10 @@ impl PyPipelineConfig {
 ///     repo_name: Repository name
 ///     config: PipelineConfig instance
 ///     file_paths: Optional list of specific files to process
+///     on_progress: Optional callable receiving progress event dicts
+///         (event, percent, eta_ms, ...; see `progress.rs`)
+///     cancel_token: Optional CancellationToken stopping the run; raises
+///         PipelineCancelled (see `cancellation.rs`)
 ///
 /// Returns:
 ///     Pipeline result dict (nodes, edges, chunks, symbols, analysis results)
@@ -999,17 +1057,22 @@ impl PyPipelineConfig {
 /// print(f"Taint flows: {result['taint_results']}")
 /// ```
 #[pyfunction]
-#[pyo3(signature = (repo_root, repo_name, config, file_paths = None))]
+#[pyo3(signature = (repo_root, repo_name, config, file_paths = None, on_progress = None, cancel_token = None))]
 pub fn run_pipeline_with_config(
     py: Python,
     repo_root: String,
     repo_name: String,
     config: &PyPipelineConfig,
     file_paths: Option<Vec<String>>,
+    on_progress: Option<PyObject>,
+    cancel_token: Option<&PyCancellationToken>,
 ) -> PyResult<Py<PyDict>> {
     use crate::pipeline::{
         E2EPipelineConfig, IRIndexingOrchestrator, IndexingMode, RepoInfo,
     };
+    use super::cancellation::pipeline_error_to_python;
+    use super::progress::PyProgressReporter;
+    use std::sync::Arc;
     use std::time::Instant;
 
     // Initialize Rayon
@@ -1043,12 +1106,20 @@ pub fn run_pipeline_with_config(
     };
 
     // Execute pipeline with GIL released
+    let cancellation = cancel_token.map(|t| t.token.clone());
     let result = py
         .allow_threads(|| {
-            let orchestrator = IRIndexingOrchestrator::new(e2e_config);
+            let mut orchestrator = IRIndexingOrchestrator::new(e2e_config);
+            if let Some(callback) = on_progress {
+                orchestrator =
+                    orchestrator.with_progress(Arc::new(PyProgressReporter::new(callback)));
+            }
+            if let Some(token) = cancellation {
+                orchestrator = orchestrator.with_cancellation(token);
+            }
             orchestrator.execute()
         })
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.message))?;
+        .map_err(|e| pipeline_error_to_python(py, e))?;
 
     let process_time = total_start.elapsed();
 
@@ -1086,6 +1157,12 @@ fn convert_pipeline_result_to_python(
 ) -> PyResult<Py<PyDict>> {
     let dict = PyDict::new(py);
 
+    // Paths are interned: entities carry `file_id` into `files`
+    // (`materialize_file_paths` restores `file_path` for older consumers)
+    let files = result.file_table();
+    let file_id = |path: &str| files.id(path);
+    dict.set_item("files", files.paths())?;
+
     // Config info
     let config_dict = PyDict::new(py);
     config_dict.set_item("description", validated.describe())?;
@@ -1099,7 +1176,7 @@ fn convert_pipeline_result_to_python(
             let _ = d.set_item("id", &n.id);
             let _ = d.set_item("kind", format!("{:?}", n.kind));
             let _ = d.set_item("fqn", &n.fqn);
-            let _ = d.set_item("file_path", &n.file_path);
+            let _ = d.set_item("file_id", file_id(&n.file_path));
             let _ = d.set_item("name", &n.name);
 
             let span_dict = PyDict::new(py);
@@ -1132,7 +1209,7 @@ fn convert_pipeline_result_to_python(
         result.chunks.iter().map(|c| {
             let d = PyDict::new(py);
             let _ = d.set_item("id", &c.id);
-            let _ = d.set_item("file_path", &c.file_path);
+            let _ = d.set_item("file_id", file_id(&c.file_path));
             let _ = d.set_item("content", &c.content);
             let _ = d.set_item("start_line", c.start_line);
             let _ = d.set_item("end_line", c.end_line);
@@ -1151,7 +1228,7 @@ fn convert_pipeline_result_to_python(
             let _ = d.set_item("id", &s.id);
             let _ = d.set_item("name", &s.name);
             let _ = d.set_item("kind", &s.kind);
-            let _ = d.set_item("file_path", &s.file_path);
+            let _ = d.set_item("file_id", file_id(&s.file_path));
             let _ = d.set_item("definition", (s.definition.0, s.definition.1));
             let _ = d.set_item("documentation", &s.documentation);
             d
@@ -1166,7 +1243,7 @@ fn convert_pipeline_result_to_python(
             let d = PyDict::new(py);
             let _ = d.set_item("id", &o.id);
             let _ = d.set_item("symbol_id", &o.symbol_id);
-            let _ = d.set_item("file_path", &o.file_path);
+            let _ = d.set_item("file_id", file_id(&o.file_path));
             let _ = d.set_item("roles", o.roles);
             let _ = d.set_item("importance_score", o.importance_score);
 
@@ -1216,6 +1293,20 @@ fn convert_pipeline_result_to_python(
 
     dict.set_item("stats", py_stats)?;
 
+    // Grammars loaded by the language plugins
+    let py_grammars = PyList::empty(py);
+    for g in &result.grammars {
+        let d = PyDict::new(py);
+        d.set_item("language", &g.language)?;
+        d.set_item("plugin", &g.plugin)?;
+        d.set_item("plugin_version", &g.plugin_version)?;
+        d.set_item("grammar", &g.grammar)?;
+        d.set_item("abi_version", g.abi_version)?;
+        d.set_item("node_kind_count", g.node_kind_count)?;
+        py_grammars.append(d)?;
+    }
+    dict.set_item("grammars", py_grammars)?;
+
     // Points-to summary
     if let Some(ref pts) = result.points_to_summary {
         let py_pts = PyDict::new(py);
@@ -1229,6 +1320,13 @@ fn convert_pipeline_result_to_python(
     } else {
         dict.set_item("points_to_summary", py.None())?;
     }
+    match result.points_to {
+        Some(ref facts) => dict.set_item(
+            "points_to",
+            Py::new(py, PyPointsToResult::new(facts.clone()))?,
+        )?,
+        None => dict.set_item("points_to", py.None())?,
+    }
 
     // RepoMap snapshot
     if let Some(ref snapshot) = result.repomap_snapshot {
@@ -0,0 +1,278 @@
+//! Shared Graph Context PyO3 Bindings
+//!
+//! Python handle to `SharedContext`: the batch pipeline publishes new
+//! versions while IDE integrations analyze unsaved buffers against the
+//! current one from other threads. The GIL is released for indexing and
+//! analysis.
+//!
+//! Usage:
+//! ```python
+//! import codegraph_ir
+//!
+//! ctx = codegraph_ir.GraphContextHandle("/path/to/repo", "my-repo")
+//! ctx.index()                                  # batch pipeline, version 1
+//!
+//! result = ctx.analyze_buffer("app/views.py", buffer_text)
+//! for d in result["diagnostics"]:
+//!     print(d["line"], d["severity"], d["message"])
+//!
+//! ctx.apply_buffer("app/views.py", saved_text)  # on save, version 2
+//!
+//! # pre-commit hook: staged diff, staged contents
+//! report = ctx.analyze_diff(diff_text, {"app/views.py": staged_text})
+//! if any(f["severity"] >= 7 for f in report["findings"]):
+//!     sys.exit(1)
+//! ```
+
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::pipeline::unified_orchestrator::{
+    BufferAnalysis, BufferDiagnostic, GraphContext, PrecommitReport, SharedContext,
+    UnifiedOrchestrator, UnifiedOrchestratorConfig,
+};
+use crate::shared::models::CodegraphError;
+
+/// Thread-safe, versioned graph context
+#[pyclass(name = "GraphContextHandle")]
+#[derive(Clone)]
+pub struct PyGraphContextHandle {
+    shared: SharedContext,
+    repo_root: PathBuf,
+    repo_name: String,
+}
+
+#[pymethods]
+impl PyGraphContextHandle {
+    /// Empty context (version 0) for a repository
+    #[new]
+    fn new(repo_root: String, repo_name: String) -> Self {
+        let shared = SharedContext::new(GraphContext::new(repo_name.clone(), repo_root.clone()));
+        Self {
+            shared,
+            repo_root: PathBuf::from(repo_root),
+            repo_name,
+        }
+    }
+
+    /// Run the batch pipeline and publish its context
+    ///
+    /// Readers keep the previous version until this returns.
+    ///
+    /// Returns:
+    ///     New version number
+    fn index(&self, py: Python) -> PyResult<u64> {
+        py.allow_threads(|| -> Result<u64, CodegraphError> {
+            let config =
+                UnifiedOrchestratorConfig::new(self.repo_root.clone(), self.repo_name.clone());
+            let orchestrator = UnifiedOrchestrator::new(config)?;
+            orchestrator.index_repository()?;
+            Ok(self.shared.publish(orchestrator.get_context()))
+        })
+        .map_err(|e| PyRuntimeError::new_err(e.to_string()))
+    }
+
+    /// Current version (0 until the first publish)
+    #[getter]
+    fn version(&self) -> u64 {
+        self.shared.version()
+    }
+
+    /// Sizes of the current version
+    fn stats(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let snapshot = self.shared.snapshot();
+        let dict = PyDict::new(py);
+        dict.set_item("version", snapshot.version)?;
+        dict.set_item("nodes", snapshot.nodes.len())?;
+        dict.set_item("edges", snapshot.edges.len())?;
+        dict.set_item("chunks", snapshot.chunks.len())?;
+        dict.set_item("symbols", snapshot.symbols.len())?;
+        Ok(dict.into())
+    }
+
+    /// Analyze an unsaved buffer against the current version
+    ///
+    /// Returns:
+    ///     Dict with `diagnostics`, `symbols`, `imports`, `version`, `elapsed_ms`
+    fn analyze_buffer(
+        &self,
+        py: Python,
+        file_path: String,
+        content: String,
+    ) -> PyResult<Py<PyDict>> {
+        let version = self.shared.version();
+        let analysis = py
+            .allow_threads(|| self.shared.analyze_buffer(&file_path, &content))
+            .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+        analysis_to_py_dict(py, &analysis, version)
+    }
+
+    /// Analyze a saved buffer and publish it as the file's new contents
+    ///
+    /// Returns:
+    ///     New version number
+    fn apply_buffer(&self, py: Python, file_path: String, content: String) -> PyResult<u64> {
+        py.allow_threads(|| -> Result<u64, CodegraphError> {
+            let analysis = self.shared.analyze_buffer(&file_path, &content)?;
+            Ok(self.shared.apply_buffer(&analysis))
+        })
+        .map_err(|e| PyRuntimeError::new_err(e.to_string()))
+    }
+
+    /// Pre-commit check of a unified diff against the current version
+    ///
+    /// Args:
+    ///     diff: Unified diff (`git diff --cached -U0`)
+    ///     contents: New contents by path; files missing here are read from
+    ///         the repository root
+    ///
+    /// Returns:
+    ///     Dict with `findings`, `files`, `skipped`, `timed_out`, `elapsed_ms`
+    #[pyo3(signature = (diff, contents=None))]
+    fn analyze_diff(
+        &self,
+        py: Python,
+        diff: String,
+        contents: Option<HashMap<String, String>>,
+    ) -> PyResult<Py<PyDict>> {
+        let contents = contents.unwrap_or_default();
+        let report = py.allow_threads(|| {
+            self.shared.analyze_diff(&diff, |path| {
+                contents
+                    .get(path)
+                    .cloned()
+                    .or_else(|| std::fs::read_to_string(self.repo_root.join(path)).ok())
+            })
+        });
+        report_to_py_dict(py, &report)
+    }
+}
+
+impl PyGraphContextHandle {
+    /// Wrap an existing handle (e.g. `UnifiedOrchestrator::shared_context`)
+    pub fn from_shared(shared: SharedContext) -> Self {
+        let snapshot = shared.snapshot();
+        Self {
+            repo_root: PathBuf::from(&snapshot.repo_root),
+            repo_name: snapshot.repo_name.clone(),
+            shared,
+        }
+    }
+}
+
+fn analysis_to_py_dict(
+    py: Python,
+    analysis: &BufferAnalysis,
+    version: u64,
+) -> PyResult<Py<PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("file_path", &analysis.file_path)?;
+    dict.set_item("version", version)?;
+    dict.set_item("elapsed_ms", analysis.elapsed.as_secs_f64() * 1000.0)?;
+
+    dict.set_item(
+        "diagnostics",
+        diagnostics_to_py_list(py, &analysis.diagnostics)?,
+    )?;
+
+    let symbols = PyList::empty(py);
+    for symbol in &analysis.symbols {
+        let s = PyDict::new(py);
+        s.set_item("id", &symbol.id)?;
+        s.set_item("name", &symbol.name)?;
+        s.set_item("kind", &symbol.kind)?;
+        s.set_item("line", symbol.definition.0)?;
+        s.set_item("column", symbol.definition.1)?;
+        s.set_item("documentation", &symbol.documentation)?;
+        symbols.append(s)?;
+    }
+    dict.set_item("symbols", symbols)?;
+
+    let imports = PyList::empty(py);
+    for import in &analysis.imports {
+        let i = PyDict::new(py);
+        i.set_item("fqn", &import.fqn)?;
+        i.set_item("line", import.line)?;
+        i.set_item("resolved_file", &import.resolved_file)?;
+        i.set_item("is_external", import.is_external)?;
+        imports.append(i)?;
+    }
+    dict.set_item("imports", imports)?;
+
+    Ok(dict.into())
+}
+
+fn report_to_py_dict(py: Python, report: &PrecommitReport) -> PyResult<Py<PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("timed_out", report.timed_out)?;
+    dict.set_item("elapsed_ms", report.elapsed.as_secs_f64() * 1000.0)?;
+
+    let findings = PyList::empty(py);
+    for finding in &report.findings {
+        let f = PyDict::new(py);
+        f.set_item("check", finding.check.as_str())?;
+        f.set_item("file_path", &finding.file_path)?;
+        f.set_item("line", finding.line)?;
+        f.set_item("symbol", &finding.symbol)?;
+        f.set_item("severity", finding.severity)?;
+        f.set_item("cwe_id", finding.cwe_id)?;
+        f.set_item("message", &finding.message)?;
+        findings.append(f)?;
+    }
+    dict.set_item("findings", findings)?;
+
+    let files = PyList::empty(py);
+    for file in &report.files {
+        let f = PyDict::new(py);
+        f.set_item("file_path", &file.file_path)?;
+        f.set_item("changed_lines", &file.changed_lines)?;
+        let symbols = PyList::empty(py);
+        for symbol in &file.symbols {
+            let s = PyDict::new(py);
+            s.set_item("id", &symbol.id)?;
+            s.set_item("fqn", &symbol.fqn)?;
+            s.set_item("kind", symbol.kind.as_str())?;
+            s.set_item("start_line", symbol.start_line)?;
+            s.set_item("end_line", symbol.end_line)?;
+            s.set_item("lines", &symbol.lines)?;
+            symbols.append(s)?;
+        }
+        f.set_item("symbols", symbols)?;
+        f.set_item(
+            "diagnostics",
+            diagnostics_to_py_list(py, &file.diagnostics)?,
+        )?;
+        files.append(f)?;
+    }
+    dict.set_item("files", files)?;
+
+    let skipped = PyList::empty(py);
+    for (path, reason) in &report.skipped {
+        let s = PyDict::new(py);
+        s.set_item("file_path", path)?;
+        s.set_item("reason", reason)?;
+        skipped.append(s)?;
+    }
+    dict.set_item("skipped", skipped)?;
+
+    Ok(dict.into())
+}
+
+fn diagnostics_to_py_list<'py>(
+    py: Python<'py>,
+    diagnostics: &[BufferDiagnostic],
+) -> PyResult<&'py PyList> {
+    let list = PyList::empty(py);
+    for diagnostic in diagnostics {
+        let d = PyDict::new(py);
+        d.set_item("severity", diagnostic.severity.as_str())?;
+        d.set_item("code", diagnostic.code)?;
+        d.set_item("message", &diagnostic.message)?;
+        d.set_item("line", diagnostic.line)?;
+        list.append(d)?;
+    }
+    Ok(list)
+}
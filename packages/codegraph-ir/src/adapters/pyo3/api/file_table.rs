@@ -0,0 +1,122 @@
+//! File Table PyO3 Bindings
+//!
+//! Pipeline results intern file paths: entities carry `file_id`, an index
+//! into the result's `files` list. Consumers that need a path string per
+//! entity can restore them in place:
+//!
+//! ```python
+//! import codegraph_ir
+//!
+//! result = codegraph_ir.run_ir_indexing_pipeline("/path/to/repo", "my-repo")
+//! path = result["files"][result["nodes"][0]["file_id"]]
+//!
+//! codegraph_ir.materialize_file_paths(result)  # adds node["file_path"], ...
+//! ```
+
+use pyo3::exceptions::PyKeyError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+/// Entity lists whose items carry `file_id`
+const ENTITY_KEYS: [&str; 4] = ["nodes", "chunks", "symbols", "occurrences"];
+
+/// (id key, path key) pairs on clone pairs
+const CLONE_PAIR_KEYS: [(&str, &str); 2] = [
+    ("source_file_id", "source_file"),
+    ("target_file_id", "target_file"),
+];
+
+/// Add path strings next to every file id in a pipeline result
+///
+/// Sets `file_path` on nodes, chunks, symbols and occurrences, and
+/// `source_file` / `target_file` on clone pairs. Idempotent; entries
+/// without an id are left alone.
+///
+/// Args:
+///     result: Dict returned by `run_ir_indexing_pipeline`
+///
+/// Raises:
+///     KeyError: If `result` has no `files` table or an id is out of range
+#[pyfunction]
+#[pyo3(name = "materialize_file_paths")]
+pub fn materialize_file_paths_py(result: &PyDict) -> PyResult<()> {
+    let files: Vec<String> = result
+        .get_item("files")?
+        .ok_or_else(|| PyKeyError::new_err("files"))?
+        .extract()?;
+
+    for key in ENTITY_KEYS {
+        if let Some(items) = result.get_item(key)? {
+            materialize(
+                items.downcast::<PyList>()?,
+                &files,
+                &[("file_id", "file_path")],
+            )?;
+        }
+    }
+    if let Some(pairs) = result.get_item("clone_pairs")? {
+        materialize(pairs.downcast::<PyList>()?, &files, &CLONE_PAIR_KEYS)?;
+    }
+    Ok(())
+}
+
+fn materialize(items: &PyList, files: &[String], keys: &[(&str, &str)]) -> PyResult<()> {
+    for item in items {
+        let item = item.downcast::<PyDict>()?;
+        for &(id_key, path_key) in keys {
+            let Some(id) = item.get_item(id_key)? else {
+                continue;
+            };
+            let Some(id) = id.extract::<Option<usize>>()? else {
+                continue;
+            };
+            let path = files
+                .get(id)
+                .ok_or_else(|| PyKeyError::new_err(format!("file id {} not in files", id)))?;
+            item.set_item(path_key, path)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_materialize_file_paths() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let result = PyDict::new(py);
+            result.set_item("files", vec!["a.py", "b.py"]).unwrap();
+
+            let node = PyDict::new(py);
+            node.set_item("file_id", 1).unwrap();
+            result.set_item("nodes", vec![node]).unwrap();
+
+            let pair = PyDict::new(py);
+            pair.set_item("source_file_id", 0).unwrap();
+            pair.set_item("target_file_id", 1).unwrap();
+            result.set_item("clone_pairs", vec![pair]).unwrap();
+
+            materialize_file_paths_py(result).unwrap();
+            let path: String = node
+                .get_item("file_path")
+                .unwrap()
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(path, "b.py");
+            let source: String = pair
+                .get_item("source_file")
+                .unwrap()
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(source, "a.py");
+
+            node.set_item("file_id", 5).unwrap();
+            assert!(materialize_file_paths_py(result).is_err());
+        });
+    }
+}
@@ -1,8 +1,9 @@
 //! PyO3 Bindings for Lexical Search
 //!
 //! Python API for TantivyLexicalIndex:
-//! - Index files batch (parallel indexing)
-//! - Search with BM25 ranking
+//! - Index files batch (parallel indexing, unchanged files skipped)
+//! - Incremental updates (changed + removed files)
+//! - Search with BM25 ranking, hits mapped to chunks
 //! - Chunk management (SQLite storage)
 //! - Hybrid search (lexical + vector + symbol)
 //!
@@ -16,10 +17,9 @@ use pyo3::types::{PyBytes, PyDict, PyList};
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use crate::features::chunking::ChunkKind;
 use crate::features::lexical::{
-    Chunk, ChunkStore, FileToIndex, Filter, HybridSearchConfig, IndexingMode,
-    SearchHit, SearchRequest, TantivyLexicalIndex,
+    Chunk, FileToIndex, Filter, HybridSearchConfig, IndexingMode, IndexingResult,
+    SearchHit, SearchRequest, SqliteChunkStore, TantivyLexicalIndex,
 };
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -35,7 +35,7 @@ use crate::features::lexical::{
 /// import codegraph_ir
 ///
 /// # Create index
-/// index = codegraph_ir.LexicalIndex.new(
+/// index = codegraph_ir.LexicalIndex(
 ///     index_dir="/tmp/tantivy_index",
 ///     chunk_db_path="/tmp/chunks.db",
 ///     repo_id="my_repo",
@@ -50,14 +50,26 @@ use crate::features::lexical::{
 /// result = index.index_files(files, fail_fast=False)
 /// print(f"Indexed {result['success_count']}/{result['total_files']} files")
 ///
+/// # Map hits to chunks (e.g. from run_ir_indexing_pipeline)
+/// index.add_chunks([{"chunk_id": "c1", "file_path": "src/main.py",
+///                    "start_line": 1, "end_line": 1, "content": "def hello(): pass"}])
+///
 /// # Search
 /// hits = index.search("hello", limit=10)
 /// for hit in hits:
-///     print(f"{hit['file_path']}:{hit['line_number']} (score: {hit['score']})")
+///     print(f"{hit['file_path']}:{hit['line']} {hit['chunk_id']} (score: {hit['score']})")
+///
+/// # After files change on disk
+/// index.update_files(changed=[{"file_path": "src/main.py", "content": "..."}],
+///                    removed=["src/utils.py"])
 /// ```
 #[pyclass(name = "LexicalIndex")]
 pub struct PyLexicalIndex {
     index: TantivyLexicalIndex,
+    /// Snapshot whose chunks and file hashes this index reads and writes
+    snapshot_id: String,
+    /// Drives the async chunk store
+    runtime: tokio::runtime::Runtime,
 }
 
 #[pymethods]
@@ -69,12 +81,19 @@ impl PyLexicalIndex {
     ///     chunk_db_path: Path to SQLite database for chunk storage
     ///     repo_id: Repository identifier
     ///     mode: Indexing mode ("Fast", "Balanced", or "Thorough")
+    ///     snapshot_id: Snapshot for chunk lookups and change tracking
     ///
     /// Returns:
     ///     LexicalIndex instance
     #[new]
-    #[pyo3(signature = (index_dir, chunk_db_path, repo_id, mode = "Balanced"))]
-    fn new(index_dir: String, chunk_db_path: String, repo_id: String, mode: &str) -> PyResult<Self> {
+    #[pyo3(signature = (index_dir, chunk_db_path, repo_id, mode = "Balanced", snapshot_id = "main"))]
+    fn new(
+        index_dir: String,
+        chunk_db_path: String,
+        repo_id: String,
+        mode: &str,
+        snapshot_id: &str,
+    ) -> PyResult<Self> {
         let indexing_mode = match mode {
             "Fast" | "Conservative" => IndexingMode::Conservative,
             "Balanced" => IndexingMode::Balanced,
@@ -101,11 +120,24 @@ impl PyLexicalIndex {
             format!("Failed to create lexical index: {:?}", e)
         ))?;
 
-        Ok(PyLexicalIndex { index })
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+                format!("Failed to start runtime: {}", e)
+            ))?;
+
+        Ok(PyLexicalIndex {
+            index,
+            snapshot_id: snapshot_id.to_string(),
+            runtime,
+        })
     }
 
     /// Index a batch of files
     ///
+    /// Files whose content is unchanged since they were last indexed for
+    /// this snapshot are skipped.
+    ///
     /// Args:
     ///     files: List of dicts with "file_path" and "content" keys
     ///     fail_fast: If True, stop on first error
@@ -113,6 +145,7 @@ impl PyLexicalIndex {
     /// Returns:
     ///     Dict with indexing results:
     ///     - success_count: Number of successfully indexed files
+    ///     - skipped_count: Number of unchanged files skipped
     ///     - total_files: Total number of files attempted
     ///     - duration_secs: Indexing duration in seconds
     ///     - failures: List of (file_path, error_message) tuples
@@ -126,52 +159,88 @@ impl PyLexicalIndex {
     ///     >>> print(f"Indexed {result['success_count']} files")
     #[pyo3(signature = (files, fail_fast = false))]
     fn index_files(&self, py: Python, files: &PyList, fail_fast: bool) -> PyResult<Py<PyDict>> {
-        // Extract file data from Python
-        let mut rust_files = Vec::with_capacity(files.len());
-        for item in files.iter() {
-            let dict = item.downcast::<PyDict>()?;
+        self.update(py, files, Vec::new(), fail_fast)
+    }
+
+    /// Incrementally update the index after files changed
+    ///
+    /// Changed files are re-indexed (unchanged content is skipped), removed
+    /// files are dropped from the index. Chunks of changed and removed files
+    /// are retired; call `add_chunks` with the new chunks of changed files.
+    ///
+    /// Args:
+    ///     changed: List of dicts with "file_path" and "content" keys
+    ///     removed: List of removed file paths
+    ///     fail_fast: If True, stop on first error
+    ///
+    /// Returns:
+    ///     Dict with the same keys as `index_files`, plus removed_count
+    ///
+    /// Example:
+    ///     >>> index.update_files(
+    ///     ...     changed=[{"file_path": "main.py", "content": "def foo(): return 1"}],
+    ///     ...     removed=["utils.py"],
+    ///     ... )
+    #[pyo3(signature = (changed, removed = Vec::new(), fail_fast = false))]
+    fn update_files(
+        &self,
+        py: Python,
+        changed: &PyList,
+        removed: Vec<String>,
+        fail_fast: bool,
+    ) -> PyResult<Py<PyDict>> {
+        self.update(py, changed, removed, fail_fast)
+    }
 
-            let file_path: String = dict
-                .get_item("file_path")?
-                .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyKeyError, _>("file_path"))?
-                .extract()?;
-
-            let content: String = dict
-                .get_item("content")?
-                .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyKeyError, _>("content"))?
-                .extract()?;
-
-            rust_files.push(FileToIndex {
-                repo_id: self.index.get_repo_id().to_string(),
-                file_path,
-                content,
-            });
+    /// Store chunks so search hits map to them
+    ///
+    /// Args:
+    ///     chunks: List of dicts with "chunk_id", "file_path", "start_line",
+    ///         "end_line" and "content"; optional "kind" (default "block"),
+    ///         "fqn" and "language" (default "unknown")
+    ///
+    /// Returns:
+    ///     Number of chunks stored
+    ///
+    /// Example:
+    ///     >>> result = codegraph_ir.run_ir_indexing_pipeline(repo, "my_repo")
+    ///     >>> codegraph_ir.materialize_file_paths(result)
+    ///     >>> index.add_chunks(result["chunks"])
+    fn add_chunks(&self, py: Python, chunks: &PyList) -> PyResult<usize> {
+        let mut rust_chunks = Vec::with_capacity(chunks.len());
+        for item in chunks.iter() {
+            let dict = item.downcast::<PyDict>()?;
+            let get = |key: &str| {
+                dict.get_item(key)?
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyKeyError, _>(key.to_string()))
+            };
+
+            let mut chunk = Chunk::new(
+                self.index.get_repo_id().to_string(),
+                self.snapshot_id.clone(),
+                get("file_path")?.extract()?,
+                get("start_line")?.extract()?,
+                get("end_line")?.extract()?,
+                optional_str(dict, "kind")?.unwrap_or_else(|| "block".to_string()),
+                get("content")?.extract()?,
+            );
+            chunk.chunk_id = get("chunk_id")?.extract()?;
+            chunk.fqn = optional_str(dict, "fqn")?;
+            if let Some(language) = optional_str(dict, "language")? {
+                chunk.language = language;
+            }
+            rust_chunks.push(chunk);
         }
 
-        // GIL RELEASE - Index in Rust (parallel processing with Rayon)
-        let result = py.allow_threads(|| {
-            self.index.index_files_batch(&rust_files, fail_fast)
+        py.allow_threads(|| {
+            self.runtime
+                .block_on(self.index.chunk_store().save_chunks(&rust_chunks))
         })
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
-            format!("Indexing failed: {:?}", e)
+            format!("Failed to store chunks: {}", e)
         ))?;
 
-        // Convert result to Python dict
-        let result_dict = PyDict::new(py);
-        result_dict.set_item("success_count", result.success_count)?;
-        result_dict.set_item("total_files", result.total_files)?;
-        result_dict.set_item("duration_secs", result.duration_seconds)?;
-        result_dict.set_item("throughput", result.throughput())?;
-
-        // Convert failures
-        let py_failures = PyList::empty(py);
-        for (file_path, error) in result.failed_files {
-            let failure_tuple = (file_path, error);
-            py_failures.append(failure_tuple)?;
-        }
-        result_dict.set_item("failures", py_failures)?;
-
-        Ok(result_dict.into())
+        Ok(rust_chunks.len())
     }
 
     /// Search for documents matching a query
@@ -219,9 +288,13 @@ impl PyLexicalIndex {
             }
         }
 
-        // GIL RELEASE - Search in Rust
+        // GIL RELEASE - Search in Rust, mapping hits to stored chunks
         let hits = py.allow_threads(|| {
-            self.index.search(&request.query, request.limit)
+            self.runtime.block_on(self.index.search_chunks(
+                &self.snapshot_id,
+                &request.query,
+                request.limit,
+            ))
         })
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
             format!("Search failed: {:?}", e)
@@ -276,7 +349,11 @@ impl PyLexicalIndex {
         // For now, only lexical is implemented - just call search
         // TODO: Implement actual hybrid fusion when vector/symbol search is ready
         let hits = py.allow_threads(|| {
-            self.index.search(&request.query, request.limit)
+            self.runtime.block_on(self.index.search_chunks(
+                &self.snapshot_id,
+                &request.query,
+                request.limit,
+            ))
         })
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
             format!("Hybrid search failed: {:?}", e)
@@ -333,10 +410,93 @@ impl PyLexicalIndex {
     }
 }
 
+impl PyLexicalIndex {
+    /// Shared body of `index_files` / `update_files`
+    fn update(
+        &self,
+        py: Python,
+        changed: &PyList,
+        removed: Vec<String>,
+        fail_fast: bool,
+    ) -> PyResult<Py<PyDict>> {
+        let rust_files = extract_files(changed, self.index.get_repo_id())?;
+
+        // GIL RELEASE - Index in Rust (parallel processing with Rayon)
+        let result = py.allow_threads(|| {
+            self.runtime.block_on(self.index.update_files(
+                &self.snapshot_id,
+                &rust_files,
+                &removed,
+                fail_fast,
+            ))
+        })
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+            format!("Indexing failed: {:?}", e)
+        ))?;
+
+        let result_dict = convert_indexing_result_to_python(py, result)?;
+        result_dict.as_ref(py).set_item("removed_count", removed.len())?;
+        Ok(result_dict)
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Helper Functions
 // ═══════════════════════════════════════════════════════════════════════════
 
+/// Extract FileToIndex list from Python dicts ("file_path", "content")
+fn extract_files(files: &PyList, repo_id: &str) -> PyResult<Vec<FileToIndex>> {
+    let mut rust_files = Vec::with_capacity(files.len());
+    for item in files.iter() {
+        let dict = item.downcast::<PyDict>()?;
+
+        let file_path: String = dict
+            .get_item("file_path")?
+            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyKeyError, _>("file_path"))?
+            .extract()?;
+
+        let content: String = dict
+            .get_item("content")?
+            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyKeyError, _>("content"))?
+            .extract()?;
+
+        rust_files.push(FileToIndex {
+            repo_id: repo_id.to_string(),
+            file_path,
+            content,
+        });
+    }
+    Ok(rust_files)
+}
+
+/// Optional string item (missing and None both map to None)
+fn optional_str(dict: &PyDict, key: &str) -> PyResult<Option<String>> {
+    match dict.get_item(key)? {
+        Some(value) => value.extract(),
+        None => Ok(None),
+    }
+}
+
+/// Convert IndexingResult to Python dict
+fn convert_indexing_result_to_python(py: Python, result: IndexingResult) -> PyResult<Py<PyDict>> {
+    let result_dict = PyDict::new(py);
+    result_dict.set_item("success_count", result.success_count)?;
+    result_dict.set_item("skipped_count", result.skipped_count)?;
+    result_dict.set_item("total_files", result.total_files)?;
+    result_dict.set_item("duration_secs", result.duration_seconds)?;
+    result_dict.set_item("throughput", result.throughput())?;
+
+    // Convert failures
+    let py_failures = PyList::empty(py);
+    for (file_path, error) in result.failed_files {
+        let failure_tuple = (file_path, error);
+        py_failures.append(failure_tuple)?;
+    }
+    result_dict.set_item("failures", py_failures)?;
+
+    Ok(result_dict.into())
+}
+
 /// Convert SearchHit vector to Python list
 fn convert_hits_to_python(py: Python, hits: &[SearchHit]) -> Py<PyList> {
     let py_hits = PyList::empty(py);
@@ -384,31 +544,16 @@ fn index_files_msgpack<'py>(
     files: &PyList,
     fail_fast: bool,
 ) -> PyResult<&'py PyBytes> {
-    // Extract file data
-    let mut rust_files = Vec::with_capacity(files.len());
-    for item in files.iter() {
-        let dict = item.downcast::<PyDict>()?;
-
-        let file_path: String = dict
-            .get_item("file_path")?
-            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyKeyError, _>("file_path"))?
-            .extract()?;
-
-        let content: String = dict
-            .get_item("content")?
-            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyKeyError, _>("content"))?
-            .extract()?;
-
-        rust_files.push(FileToIndex {
-            repo_id: index.index.get_repo_id().to_string(),
-            file_path,
-            content,
-        });
-    }
+    let rust_files = extract_files(files, index.index.get_repo_id())?;
 
     // GIL RELEASE - Index
     let result = py.allow_threads(|| {
-        index.index.index_files_batch(&rust_files, fail_fast)
+        index.runtime.block_on(index.index.update_files(
+            &index.snapshot_id,
+            &rust_files,
+            &[],
+            fail_fast,
+        ))
     })
     .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
         format!("Indexing failed: {:?}", e)
@@ -443,7 +588,9 @@ fn search_msgpack<'py>(
 ) -> PyResult<&'py PyBytes> {
     // GIL RELEASE - Search
     let hits = py.allow_threads(|| {
-        index.index.search(query, limit)
+        index
+            .runtime
+            .block_on(index.index.search_chunks(&index.snapshot_id, query, limit))
     })
     .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
         format!("Search failed: {:?}", e)
@@ -10,26 +10,39 @@
 //! - Output: msgpack bytes (zero-copy to Python)
 //! - GIL released during computation (true parallelism)
 
+pub mod call_paths;
+pub mod cancellation;
+pub mod clone_detection;
 pub mod config;
+pub mod context_handle;
+pub mod file_table;
 pub mod graph_builder;
 pub mod ir_processor;
+pub mod points_to;
+pub mod progress;
 pub mod query;
+pub mod repomap;
 pub mod slice;
 pub mod streaming;
 pub mod taint;
-// TEMPORARILY DISABLED: compilation errors preventing testing
-// pub mod clone_detection;
+pub mod test_selection;
 pub mod rust_query_engine;
-// pub mod lexical;  // TEMPORARILY DISABLED: SqliteChunkStore compilation error
+pub mod lexical;
 
 // Re-export all pyfunction for lib.rs registration
+pub use call_paths::*;
+pub use clone_detection::*;
 pub use config::*;
+pub use context_handle::*;
+pub use file_table::*;
 pub use graph_builder::*;
 pub use ir_processor::*;
+pub use points_to::*;
 pub use query::*;
+pub use repomap::*;
 pub use slice::*;
 pub use streaming::*;
 pub use taint::*;
-// pub use clone_detection::*;
+pub use test_selection::*;
 pub use rust_query_engine::*;
-// pub use lexical::*;  // TEMPORARILY DISABLED
+pub use lexical::*;
@@ -0,0 +1,143 @@
+//! Points-to queries
+//!
+//! ```python
+//! pta = codegraph_ir.analyze_points_to(ir_docs)
+//! pta.may_alias("var:a", "var:b")
+//! pta.alias_set("var:a")          # ["var:b", ...]
+//! pta.allocation_sites("var:a")   # [{"id": 3, "site": "def:...", ...}]
+//! ```
+//!
+//! Variables are named by IR node ID (edge endpoints as written). Pipeline
+//! results carry the same object under `points_to`.
+
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::features::cross_file::IRDocument;
+use crate::features::points_to::{
+    AllocationSite, AnalysisConfig, AnalysisMode, PointsToAnalyzer, PointsToResult,
+};
+use crate::features::taint_analysis::infrastructure::pta_ir_extractor::PTAIRExtractor;
+
+/// Queryable points-to facts (variable → allocation sites, aliasing)
+#[pyclass(name = "PointsToResult")]
+#[derive(Clone)]
+pub struct PyPointsToResult {
+    inner: PointsToResult,
+}
+
+impl PyPointsToResult {
+    pub fn new(inner: PointsToResult) -> Self {
+        Self { inner }
+    }
+}
+
+fn site_dict(py: Python, site: &AllocationSite) -> PyResult<Py<PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("id", site.id)?;
+    dict.set_item("site", &site.site)?;
+    dict.set_item("type_info", &site.type_info)?;
+    dict.set_item("file_path", &site.file_path)?;
+    dict.set_item("line", site.line)?;
+    Ok(dict.into())
+}
+
+#[pymethods]
+impl PyPointsToResult {
+    /// Solver used ("Fast", "Precise", ...)
+    #[getter]
+    fn mode_used(&self) -> &str {
+        &self.inner.mode_used
+    }
+
+    /// All analyzed variables, sorted
+    fn variables(&self) -> Vec<&str> {
+        self.inner.points_to.keys().map(String::as_str).collect()
+    }
+
+    /// Allocation sites `variable` may point to (dicts with `id`, `site`,
+    /// `type_info`, `file_path`, `line`)
+    fn allocation_sites(&self, py: Python, variable: &str) -> PyResult<Py<PyList>> {
+        let list = PyList::empty(py);
+        for site in self.inner.allocation_sites(variable) {
+            list.append(site_dict(py, site)?)?;
+        }
+        Ok(list.into())
+    }
+
+    /// Whether the two variables may refer to the same object
+    fn may_alias(&self, a: &str, b: &str) -> bool {
+        self.inner.may_alias(a, b)
+    }
+
+    /// Variables `variable` may alias
+    fn alias_set(&self, variable: &str) -> Vec<&str> {
+        self.inner.alias_set(variable)
+    }
+
+    fn __contains__(&self, variable: &str) -> bool {
+        self.inner.contains(variable)
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.points_to.len()
+    }
+
+    /// JSON form (`sites`, `points_to`, `alias_classes`, `mode_used`)
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.inner)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+}
+
+/// Solve points-to constraints of `ir_docs`
+///
+/// `mode` is "fast" (Steensgaard), "precise" (Andersen), "hybrid", "auto",
+/// "call_site_sensitive" (1-CFA) or "object_sensitive" (1-object).
+#[pyfunction]
+#[pyo3(name = "analyze_points_to")]
+#[pyo3(signature = (ir_docs, mode = "fast"))]
+pub fn analyze_points_to_py(
+    py: Python,
+    ir_docs: Vec<IRDocument>,
+    mode: &str,
+) -> PyResult<PyPointsToResult> {
+    let mode = match mode.to_ascii_lowercase().as_str() {
+        "fast" => AnalysisMode::Fast,
+        "precise" => AnalysisMode::Precise,
+        "hybrid" => AnalysisMode::Hybrid,
+        "auto" => AnalysisMode::Auto,
+        "call_site_sensitive" => AnalysisMode::CallSiteSensitive,
+        "object_sensitive" => AnalysisMode::ObjectSensitive,
+        other => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Invalid mode: {}. Must be 'fast', 'precise', 'hybrid', 'auto', \
+                 'call_site_sensitive' or 'object_sensitive'",
+                other
+            )))
+        }
+    };
+    let facts = py.allow_threads(|| {
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        for doc in ir_docs {
+            nodes.extend(doc.nodes);
+            edges.extend(doc.edges);
+        }
+        let mut analyzer = PointsToAnalyzer::new(AnalysisConfig {
+            mode,
+            ..Default::default()
+        });
+        PTAIRExtractor::new().extract_constraints(&nodes, &edges, &mut analyzer);
+        let result = analyzer.solve();
+        PointsToResult::from_analysis(&analyzer, &result)
+    });
+    Ok(PyPointsToResult::new(facts))
+}
+
+/// Register the points-to API
+pub fn register_points_to_api(m: &PyModule) -> PyResult<()> {
+    m.add_class::<PyPointsToResult>()?;
+    m.add_function(wrap_pyfunction!(analyze_points_to_py, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,114 @@
+//! Python progress callbacks
+//!
+//! Wraps a Python callable as a `ProgressReporter`. `on_progress(event)`
+//! receives one dict per event:
+//!
+//! ```python
+//! def on_progress(event):
+//!     # event["event"]: "started" | "file_parsed" | "stage_completed" | "finished"
+//!     bar.update(event["percent"], eta=event["eta_ms"])
+//!     if event["event"] == "stage_completed":
+//!         print(event["stage"], event["duration_ms"], event["succeeded"])
+//! ```
+//!
+//! Every dict carries `files_parsed`, `total_files`, `stages_completed`,
+//! `total_stages`, `percent`, `elapsed_ms` and `eta_ms` (None until anything
+//! completed). `file_parsed` events are throttled to one per
+//! `FILE_EVENT_INTERVAL` (the last file is always reported): each needs the
+//! GIL, which Rayon workers would otherwise queue on.
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::pipeline::{Progress, ProgressEvent, ProgressReporter};
+
+/// Minimum time between two forwarded `file_parsed` events
+pub const FILE_EVENT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// `ProgressReporter` calling a Python callable with an event dict
+///
+/// Exceptions raised by the callable are logged and do not stop the run.
+pub struct PyProgressReporter {
+    callback: PyObject,
+    last_file_event: Mutex<Option<Instant>>,
+}
+
+impl PyProgressReporter {
+    pub fn new(callback: PyObject) -> Self {
+        Self {
+            callback,
+            last_file_event: Mutex::new(None),
+        }
+    }
+
+    fn throttled(&self, progress: &Progress) -> bool {
+        if progress.files_parsed >= progress.total_files {
+            return false;
+        }
+        let mut last = self.last_file_event.lock().unwrap();
+        let now = Instant::now();
+        match *last {
+            Some(at) if now.duration_since(at) < FILE_EVENT_INTERVAL => true,
+            _ => {
+                *last = Some(now);
+                false
+            }
+        }
+    }
+}
+
+impl ProgressReporter for PyProgressReporter {
+    fn on_progress(&self, event: &ProgressEvent<'_>, progress: &Progress) {
+        if matches!(event, ProgressEvent::FileParsed { .. }) && self.throttled(progress) {
+            return;
+        }
+        let result = Python::with_gil(|py| {
+            let dict = event_to_python(py, event, progress)?;
+            self.callback.call1(py, (dict,)).map(|_| ())
+        });
+        if let Err(e) = result {
+            tracing::warn!("[Progress] on_progress failed: {}", e);
+        }
+    }
+}
+
+fn event_to_python<'py>(
+    py: Python<'py>,
+    event: &ProgressEvent<'_>,
+    progress: &Progress,
+) -> PyResult<&'py PyDict> {
+    let dict = PyDict::new(py);
+    dict.set_item("event", event.kind())?;
+    match *event {
+        ProgressEvent::Started { files, stages } => {
+            dict.set_item("files", files)?;
+            dict.set_item("stages", stages)?;
+        }
+        ProgressEvent::FileParsed { file_path, cached } => {
+            dict.set_item("file_path", file_path)?;
+            dict.set_item("cached", cached)?;
+        }
+        ProgressEvent::StageCompleted {
+            stage,
+            duration,
+            succeeded,
+        } => {
+            dict.set_item("stage", stage)?;
+            dict.set_item("duration_ms", duration.as_secs_f64() * 1000.0)?;
+            dict.set_item("succeeded", succeeded)?;
+        }
+        ProgressEvent::Finished { duration } => {
+            dict.set_item("duration_ms", duration.as_secs_f64() * 1000.0)?;
+        }
+    }
+    dict.set_item("files_parsed", progress.files_parsed)?;
+    dict.set_item("total_files", progress.total_files)?;
+    dict.set_item("stages_completed", progress.stages_completed)?;
+    dict.set_item("total_stages", progress.total_stages)?;
+    dict.set_item("percent", progress.percent())?;
+    dict.set_item("elapsed_ms", progress.elapsed.as_secs_f64() * 1000.0)?;
+    dict.set_item("eta_ms", progress.eta.map(|eta| eta.as_secs_f64() * 1000.0))?;
+    Ok(dict)
+}
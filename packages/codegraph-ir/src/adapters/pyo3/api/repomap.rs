@@ -0,0 +1,128 @@
+//! Token-budgeted RepoMap rendering for LLM prompts
+//!
+//! ```python
+//! result = codegraph_ir.run_ir_indexing_pipeline(repo, enable_repomap=True)
+//! rendered = codegraph_ir.render_repo_map(
+//!     result["repomap_snapshot"], token_budget=2048,
+//!     focus=["src/auth/login.py"], exclude=["tests/"], ir_docs=ir_docs,
+//! )
+//! prompt += rendered["text"]
+//! ```
+//!
+//! The dict has `text`, `tokens` (estimated), `files` (shown, most important
+//! first) and `omitted_files`. `ir_docs` are optional and add signatures.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::features::cross_file::IRDocument;
+use crate::features::repomap::infrastructure::PageRankSettings;
+use crate::features::repomap::{RenderOptions, RepoMapRenderer};
+use crate::pipeline::end_to_end_result::{RepoMapNodeSummary, RepoMapSnapshotSummary};
+
+/// Render `snapshot` (a pipeline `repomap_snapshot` dict) within
+/// `token_budget` tokens
+#[pyfunction]
+#[pyo3(name = "render_repo_map")]
+#[pyo3(signature = (
+    snapshot,
+    token_budget = 1024,
+    focus = None,
+    exclude = None,
+    ir_docs = None,
+    symbols_per_file = 8
+))]
+pub fn render_repo_map_py(
+    py: Python,
+    snapshot: &PyDict,
+    token_budget: usize,
+    focus: Option<Vec<String>>,
+    exclude: Option<Vec<String>>,
+    ir_docs: Option<Vec<IRDocument>>,
+    symbols_per_file: usize,
+) -> PyResult<Py<PyDict>> {
+    let snapshot = snapshot_from_dict(snapshot)?;
+    let options = RenderOptions::new(token_budget)
+        .with_focus(focus.unwrap_or_default())
+        .with_exclude(exclude.unwrap_or_default())
+        .with_symbols_per_file(symbols_per_file);
+
+    let rendered = py
+        .allow_threads(|| {
+            let nodes: Vec<_> = ir_docs
+                .into_iter()
+                .flatten()
+                .flat_map(|doc| doc.nodes)
+                .collect();
+            RepoMapRenderer::new(&snapshot)
+                .with_nodes(&nodes)
+                .render(&options)
+        })
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+    let dict = PyDict::new(py);
+    dict.set_item("text", &rendered.text)?;
+    dict.set_item("tokens", rendered.tokens)?;
+    dict.set_item("files", &rendered.files)?;
+    dict.set_item("omitted_files", rendered.omitted_files)?;
+    Ok(dict.into())
+}
+
+fn snapshot_from_dict(dict: &PyDict) -> PyResult<RepoMapSnapshotSummary> {
+    let nodes: &PyList = dict
+        .get_item("nodes")?
+        .ok_or_else(|| PyValueError::new_err("repomap snapshot has no 'nodes'"))?
+        .downcast()?;
+    let nodes = nodes
+        .iter()
+        .map(|node| node_from_dict(node.downcast()?))
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(RepoMapSnapshotSummary {
+        repo_id: item_or_default(dict, "repo_id")?,
+        snapshot_id: item_or_default(dict, "snapshot_id")?,
+        total_nodes: nodes.len(),
+        root_id: item_or_default(dict, "root_id")?,
+        nodes,
+        total_loc: item_or_default(dict, "total_loc")?,
+        total_symbols: item_or_default(dict, "total_symbols")?,
+        total_files: item_or_default(dict, "total_files")?,
+        created_at: item_or_default(dict, "created_at")?,
+        pagerank_settings: PageRankSettings::default(),
+    })
+}
+
+fn node_from_dict(dict: &PyDict) -> PyResult<RepoMapNodeSummary> {
+    Ok(RepoMapNodeSummary {
+        id: item_or_default(dict, "id")?,
+        kind: item_or_default(dict, "kind")?,
+        name: item_or_default(dict, "name")?,
+        path: item_or_default(dict, "path")?,
+        parent_id: item_or_default(dict, "parent_id")?,
+        children_count: item_or_default(dict, "children_count")?,
+        depth: item_or_default(dict, "depth")?,
+        pagerank: item_or_default(dict, "pagerank")?,
+        authority: item_or_default(dict, "authority")?,
+        hub: item_or_default(dict, "hub")?,
+        combined_importance: item_or_default(dict, "combined_importance")?,
+        loc: item_or_default(dict, "loc")?,
+        symbol_count: item_or_default(dict, "symbol_count")?,
+        complexity: item_or_default(dict, "complexity")?,
+        cognitive_complexity: item_or_default(dict, "cognitive_complexity")?,
+        max_nesting: item_or_default(dict, "max_nesting")?,
+    })
+}
+
+/// `dict[key]`, or the default when missing or `None`
+fn item_or_default<'p, T: FromPyObject<'p> + Default>(dict: &'p PyDict, key: &str) -> PyResult<T> {
+    match dict.get_item(key)? {
+        Some(value) if !value.is_none() => value.extract(),
+        _ => Ok(T::default()),
+    }
+}
+
+/// Register the RepoMap rendering API
+pub fn register_repomap_api(m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(render_repo_map_py, m)?)?;
+    Ok(())
+}
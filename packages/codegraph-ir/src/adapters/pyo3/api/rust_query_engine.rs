@@ -7,10 +7,15 @@
 
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
+use pythonize::{depythonize, pythonize};
 use std::sync::Arc;
+use std::time::Instant;
 
+use crate::features::datalog::DatalogEngine;
 use crate::features::ir_generation::domain::ir_document::IRDocument;
-use crate::features::query_engine::{QueryEngine, E, Q};
+use crate::features::query_engine::{
+    NodeSetQuery, QueryEngine, QueryExplain, RankedHit, SearchFilters, E, Q,
+};
 use crate::shared::models::{Node, NodeKind};
 
 /// Rust QueryEngine wrapper for Python
@@ -102,6 +107,124 @@ impl PyRustQueryEngine {
         self.nodes_to_py_list(py, &nodes)
     }
 
+    /// Ranked symbol search (name match + PageRank + references + kind)
+    ///
+    /// Symbols in test/fixture files are demoted, or dropped with
+    /// `exclude_tests=True`. `kinds` takes node kind names ("Function", ...).
+    #[pyo3(signature = (query, top_k=10, kinds=None, path_prefix=None, language=None, exclude_tests=false))]
+    fn search(
+        &self,
+        py: Python,
+        query: &str,
+        top_k: usize,
+        kinds: Option<Vec<String>>,
+        path_prefix: Option<String>,
+        language: Option<String>,
+        exclude_tests: bool,
+    ) -> PyResult<Py<PyList>> {
+        let filters = search_filters(kinds, path_prefix, language, exclude_tests);
+        let hits = py.allow_threads(|| self.engine.search(query, top_k, &filters));
+        hits_to_py_list(py, &hits)
+    }
+
+    /// `search` plus an explain report
+    ///
+    /// Returns `{"hits": [...], "explain": {...}}`; the report lists each
+    /// step's index, candidates in/out and time (see `explain_to_dict`).
+    #[pyo3(signature = (query, top_k=10, kinds=None, path_prefix=None, language=None, exclude_tests=false))]
+    fn explain_search(
+        &self,
+        py: Python,
+        query: &str,
+        top_k: usize,
+        kinds: Option<Vec<String>>,
+        path_prefix: Option<String>,
+        language: Option<String>,
+        exclude_tests: bool,
+    ) -> PyResult<Py<PyDict>> {
+        let filters = search_filters(kinds, path_prefix, language, exclude_tests);
+        let (hits, explain) =
+            py.allow_threads(|| self.engine.search_explain(query, top_k, &filters));
+
+        let dict = PyDict::new(py);
+        dict.set_item("hits", hits_to_py_list(py, &hits)?)?;
+        dict.set_item("explain", explain_to_dict(py, &explain)?)?;
+        Ok(dict.into())
+    }
+
+    /// Explain a `find_*` lookup (`kind` is a node kind name, e.g. "Function")
+    ///
+    /// Returns `{"count": n, "explain": {...}}`.
+    #[pyo3(signature = (kind, name_pattern=None))]
+    fn explain_find(
+        &self,
+        py: Python,
+        kind: &str,
+        name_pattern: Option<String>,
+    ) -> PyResult<Py<PyDict>> {
+        let node_kind = NodeKind::from_str(kind);
+        let mut explain = QueryExplain::start(match &name_pattern {
+            Some(pattern) => format!("find {} name~{:?}", kind, pattern),
+            None => format!("find {}", kind),
+        });
+        let started = Instant::now();
+        let count = self
+            .find_nodes_by_kind_and_name(node_kind, name_pattern.as_deref())
+            .len();
+        explain.record(
+            "filter_nodes",
+            "IRDocument.nodes (scan)",
+            self.ir_doc.nodes.len(),
+            count,
+            started,
+        );
+        let explain = explain.finish();
+
+        let dict = PyDict::new(py);
+        dict.set_item("count", count)?;
+        dict.set_item("explain", explain_to_dict(py, &explain)?)?;
+        Ok(dict.into())
+    }
+
+    /// Composable node-set query (`NodeSetQuery`)
+    ///
+    /// `query` is a list of steps or its JSON string, e.g.
+    /// `[{"filter": {"kinds": ["Method"]}}, {"limit": 10}]`. With
+    /// `arrow=True` returns Arrow IPC bytes (requires the `arrow` feature).
+    #[pyo3(signature = (query, arrow=false))]
+    fn select(&self, py: Python, query: &PyAny, arrow: bool) -> PyResult<PyObject> {
+        let query: NodeSetQuery = match query.extract::<&str>() {
+            Ok(json) => serde_json::from_str(json).map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid query: {}", e))
+            })?,
+            Err(_) => depythonize(query).map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid query: {}", e))
+            })?,
+        };
+        let nodes = self
+            .engine
+            .select(&query)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+        if arrow {
+            nodes_to_arrow_bytes(py, &nodes)
+        } else {
+            Ok(self.nodes_to_py_list(py, &nodes)?.into_py(py))
+        }
+    }
+
+    /// Run a Datalog program over the IR relations
+    ///
+    /// Returns `{relation: [tuple, ...]}` for the program's `.output`
+    /// relations (every derived relation when none are declared).
+    fn datalog(&self, py: Python, program: &str) -> PyResult<PyObject> {
+        let result = DatalogEngine::from_ir(&self.ir_doc)
+            .run_source(program)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        pythonize(py, &result.relations)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+    }
+
     /// Get statistics about the indexed code
     fn get_stats(&self, py: Python) -> PyResult<Py<PyDict>> {
         let dict = PyDict::new(py);
@@ -202,3 +325,88 @@ impl PyRustQueryEngine {
         Ok(py_list.into())
     }
 }
+
+/// Arrow IPC stream of `nodes` (pipeline node table schema)
+#[cfg(feature = "arrow")]
+fn nodes_to_arrow_bytes(py: Python, nodes: &[&Node]) -> PyResult<PyObject> {
+    let bytes = crate::pipeline::nodes_to_arrow_ipc(nodes)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    Ok(pyo3::types::PyBytes::new(py, &bytes).into_py(py))
+}
+
+#[cfg(not(feature = "arrow"))]
+fn nodes_to_arrow_bytes(_py: Python, _nodes: &[&Node]) -> PyResult<PyObject> {
+    Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+        "arrow=True requires the 'arrow' feature",
+    ))
+}
+
+/// `SearchFilters` from the Python keyword arguments
+fn search_filters(
+    kinds: Option<Vec<String>>,
+    path_prefix: Option<String>,
+    language: Option<String>,
+    exclude_tests: bool,
+) -> SearchFilters {
+    SearchFilters {
+        kinds: kinds
+            .unwrap_or_default()
+            .iter()
+            .map(|k| NodeKind::from_str(k))
+            .collect(),
+        path_prefix,
+        language,
+        exclude_tests,
+    }
+}
+
+/// Ranked hits as a list of dicts (with their signal breakdown)
+fn hits_to_py_list(py: Python, hits: &[RankedHit]) -> PyResult<Py<PyList>> {
+    let py_list = PyList::empty(py);
+    for hit in hits {
+        let hit_dict = PyDict::new(py);
+        hit_dict.set_item("id", &hit.node_id)?;
+        hit_dict.set_item("fqn", &hit.fqn)?;
+        hit_dict.set_item("name", &hit.name)?;
+        hit_dict.set_item("kind", hit.kind.as_str())?;
+        hit_dict.set_item("file_path", &hit.file_path)?;
+        hit_dict.set_item("start_line", hit.span.start_line)?;
+        hit_dict.set_item("end_line", hit.span.end_line)?;
+        hit_dict.set_item("score", hit.score)?;
+
+        let signals = PyDict::new(py);
+        signals.set_item("lexical", hit.signals.lexical)?;
+        signals.set_item("pagerank", hit.signals.pagerank)?;
+        signals.set_item("references", hit.signals.references)?;
+        signals.set_item("reference_count", hit.signals.reference_count)?;
+        signals.set_item("kind", hit.signals.kind)?;
+        signals.set_item("is_test", hit.signals.is_test)?;
+        hit_dict.set_item("signals", signals)?;
+
+        py_list.append(hit_dict)?;
+    }
+    Ok(py_list.into())
+}
+
+/// Explain report as `{"query", "total_ms", "steps": [...], "text"}`
+///
+/// Each step has `name`, `index`, `input`, `output` and `duration_ms`.
+fn explain_to_dict<'py>(py: Python<'py>, explain: &QueryExplain) -> PyResult<&'py PyDict> {
+    let dict = PyDict::new(py);
+    dict.set_item("query", &explain.query)?;
+    dict.set_item("total_ms", explain.total.as_secs_f64() * 1000.0)?;
+
+    let steps = PyList::empty(py);
+    for step in &explain.steps {
+        let d = PyDict::new(py);
+        d.set_item("name", step.name)?;
+        d.set_item("index", &step.index)?;
+        d.set_item("input", step.input)?;
+        d.set_item("output", step.output)?;
+        d.set_item("duration_ms", step.duration.as_secs_f64() * 1000.0)?;
+        steps.append(d)?;
+    }
+    dict.set_item("steps", steps)?;
+    dict.set_item("text", explain.to_string())?;
+    Ok(dict)
+}
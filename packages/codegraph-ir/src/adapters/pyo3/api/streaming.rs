@@ -49,14 +49,18 @@
 //! ```
 
 use pyo3::prelude::*;
-use pyo3::types::PyBytes;
+use pyo3::types::{PyBytes, PyDict, PyList};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 
+use super::cancellation::PyCancellationToken;
+use super::config::PyPipelineConfig;
 use crate::pipeline::processor::process_file;
-use crate::shared::models::{Edge, Node};
+use crate::pipeline::result_stream::{ResultBatch, DEFAULT_BATCH_SIZE};
+use crate::pipeline::StageId;
+use crate::shared::models::{CodegraphError, Edge, Node, Span};
 
 // ═══════════════════════════════════════════════════════════════════════════
 // Streaming Configuration
@@ -462,6 +466,211 @@ impl FileStreamProcessor {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// Pipeline Result Streaming
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn span_to_python<'py>(py: Python<'py>, span: &Span) -> PyResult<&'py PyDict> {
+    let d = PyDict::new(py);
+    d.set_item("start_line", span.start_line)?;
+    d.set_item("start_col", span.start_col)?;
+    d.set_item("end_line", span.end_line)?;
+    d.set_item("end_col", span.end_col)?;
+    Ok(d)
+}
+
+/// One batch as a list of dicts (same fields as `run_pipeline_with_config`,
+/// with `file_path` instead of `file_id` since there is no file table)
+fn batch_to_python<'py>(py: Python<'py>, batch: ResultBatch<'_>) -> PyResult<&'py PyList> {
+    let list = PyList::empty(py);
+    match batch {
+        ResultBatch::Nodes(nodes) => {
+            for n in nodes {
+                let d = PyDict::new(py);
+                d.set_item("id", &n.id)?;
+                d.set_item("kind", format!("{:?}", n.kind))?;
+                d.set_item("fqn", &n.fqn)?;
+                d.set_item("file_path", &n.file_path)?;
+                d.set_item("name", &n.name)?;
+                d.set_item("span", span_to_python(py, &n.span)?)?;
+                list.append(d)?;
+            }
+        }
+        ResultBatch::Edges(edges) => {
+            for e in edges {
+                let d = PyDict::new(py);
+                d.set_item("source_id", &e.source_id)?;
+                d.set_item("target_id", &e.target_id)?;
+                d.set_item("kind", format!("{:?}", e.kind))?;
+                list.append(d)?;
+            }
+        }
+        ResultBatch::Occurrences(occurrences) => {
+            for o in occurrences {
+                let d = PyDict::new(py);
+                d.set_item("id", &o.id)?;
+                d.set_item("symbol_id", &o.symbol_id)?;
+                d.set_item("file_path", &o.file_path)?;
+                d.set_item("roles", o.roles)?;
+                d.set_item("importance_score", o.importance_score)?;
+                d.set_item("span", span_to_python(py, &o.span)?)?;
+                list.append(d)?;
+            }
+        }
+        ResultBatch::Chunks(chunks) => {
+            for c in chunks {
+                let d = PyDict::new(py);
+                d.set_item("id", &c.id)?;
+                d.set_item("file_path", &c.file_path)?;
+                d.set_item("content", &c.content)?;
+                d.set_item("start_line", c.start_line)?;
+                d.set_item("end_line", c.end_line)?;
+                d.set_item("chunk_type", &c.chunk_type)?;
+                d.set_item("symbol_id", &c.symbol_id)?;
+                list.append(d)?;
+            }
+        }
+        ResultBatch::Symbols(symbols) => {
+            for s in symbols {
+                let d = PyDict::new(py);
+                d.set_item("id", &s.id)?;
+                d.set_item("name", &s.name)?;
+                d.set_item("kind", &s.kind)?;
+                d.set_item("file_path", &s.file_path)?;
+                d.set_item("definition", (s.definition.0, s.definition.1))?;
+                d.set_item("documentation", &s.documentation)?;
+                list.append(d)?;
+            }
+        }
+        ResultBatch::ClonePairs(pairs) => {
+            for p in pairs {
+                let d = PyDict::new(py);
+                d.set_item("clone_type", &p.clone_type)?;
+                d.set_item("source_file", &p.source_file)?;
+                d.set_item("source_span", span_to_python(py, &p.source_span)?)?;
+                d.set_item("target_file", &p.target_file)?;
+                d.set_item("target_span", span_to_python(py, &p.target_span)?)?;
+                d.set_item("similarity", p.similarity)?;
+                d.set_item("token_count", p.token_count)?;
+                d.set_item("loc", p.loc)?;
+                list.append(d)?;
+            }
+        }
+    }
+    Ok(list)
+}
+
+/// Run the pipeline and stream results to a Python callback
+///
+/// `on_batch(stage, kind, items)` is called as each stage finishes, with
+/// `kind` one of "nodes", "edges", "occurrences", "chunks", "symbols",
+/// "clone_pairs" and at most `batch_size` dicts in `items`. Nothing is
+/// accumulated into a result dict, so batches can be written to storage
+/// and dropped while the pipeline keeps running. An exception raised by
+/// the callback stops the pipeline and is re-raised. `on_progress`, if
+/// given, receives progress event dicts and `cancel_token` stops the run
+/// (see `run_pipeline_with_config`).
+///
+/// Returns:
+///     Stats dict (files_processed, total_loc, total_duration_ms, stage_durations, ...)
+///
+/// Example:
+/// ```python
+/// from codegraph_ir import PipelineConfig, run_pipeline_streaming
+///
+/// def on_batch(stage, kind, items):
+///     db.insert_many(kind, items)
+///
+/// stats = run_pipeline_streaming("/path/to/repo", "my-repo",
+///                                PipelineConfig.preset("balanced"), on_batch)
+/// ```
+#[pyfunction]
+#[pyo3(signature = (repo_root, repo_name, config, on_batch, file_paths = None, batch_size = DEFAULT_BATCH_SIZE, on_progress = None, cancel_token = None))]
+pub fn run_pipeline_streaming(
+    py: Python,
+    repo_root: String,
+    repo_name: String,
+    config: &PyPipelineConfig,
+    on_batch: PyObject,
+    file_paths: Option<Vec<String>>,
+    batch_size: usize,
+    on_progress: Option<PyObject>,
+    cancel_token: Option<&PyCancellationToken>,
+) -> PyResult<Py<PyDict>> {
+    use super::cancellation::pipeline_error_to_python;
+    use super::progress::PyProgressReporter;
+    use crate::pipeline::{E2EPipelineConfig, IRIndexingOrchestrator, IndexingMode};
+    use std::sync::Arc;
+
+    crate::init_rayon();
+
+    let validated = config.get_rust_config(py)?.build().map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Configuration validation failed: {}",
+            e
+        ))
+    })?;
+    let e2e_config = E2EPipelineConfig::with_config(validated)
+        .repo_root(PathBuf::from(&repo_root))
+        .repo_name(repo_name)
+        .indexing_mode(IndexingMode::Full)
+        .mmap_threshold(1024 * 1024);
+    let e2e_config = match file_paths {
+        Some(fps) => e2e_config.file_paths(fps.into_iter().map(PathBuf::from).collect()),
+        None => e2e_config,
+    };
+
+    // Keep the callback's own exception to re-raise it unchanged
+    let mut callback_error: Option<PyErr> = None;
+    let cancellation = cancel_token.map(|t| t.token.clone());
+    let result = py.allow_threads(|| {
+        let mut sink = |stage: StageId, batch: ResultBatch<'_>| -> Result<(), CodegraphError> {
+            Python::with_gil(|py| {
+                batch_to_python(py, batch)
+                    .and_then(|items| on_batch.call1(py, (stage.name(), batch.kind(), items)))
+                    .map(|_| ())
+            })
+            .map_err(|e| {
+                let message = format!("on_batch failed: {}", e);
+                callback_error = Some(e);
+                CodegraphError::internal(message)
+            })
+        };
+        let mut orchestrator = IRIndexingOrchestrator::new(e2e_config);
+        if let Some(callback) = on_progress {
+            orchestrator = orchestrator.with_progress(Arc::new(PyProgressReporter::new(callback)));
+        }
+        if let Some(token) = cancellation {
+            orchestrator = orchestrator.with_cancellation(token);
+        }
+        orchestrator.execute_streaming(&mut sink, batch_size)
+    });
+    let stats = match (result, callback_error) {
+        (Ok(stats), _) => stats,
+        (Err(_), Some(err)) => return Err(err),
+        (Err(e), None) => return Err(pipeline_error_to_python(py, e)),
+    };
+
+    let py_stats = PyDict::new(py);
+    py_stats.set_item("total_duration_ms", stats.total_duration.as_millis())?;
+    py_stats.set_item("files_processed", stats.files_processed)?;
+    py_stats.set_item("files_cached", stats.files_cached)?;
+    py_stats.set_item("files_failed", stats.files_failed)?;
+    py_stats.set_item("total_loc", stats.total_loc)?;
+    py_stats.set_item("loc_per_second", stats.loc_per_second)?;
+    py_stats.set_item("cache_hit_rate", stats.cache_hit_rate)?;
+    let py_stage_durations = PyDict::new(py);
+    for (stage, duration) in &stats.stage_durations {
+        py_stage_durations.set_item(stage, duration.as_millis())?;
+    }
+    py_stats.set_item("stage_durations", py_stage_durations)?;
+    py_stats.set_item(
+        "errors",
+        PyList::new(py, stats.errors.iter().map(|e| e.as_str())),
+    )?;
+    Ok(py_stats.into())
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // PyO3 Module Registration
 // ═══════════════════════════════════════════════════════════════════════════
@@ -469,6 +678,7 @@ impl FileStreamProcessor {
 /// Register streaming API with Python module
 pub fn register_streaming_api(m: &PyModule) -> PyResult<()> {
     m.add_class::<FileStreamProcessor>()?;
+    m.add_function(wrap_pyfunction!(run_pipeline_streaming, m)?)?;
     Ok(())
 }
 
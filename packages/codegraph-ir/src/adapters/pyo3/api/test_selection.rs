@@ -0,0 +1,73 @@
+//! Impacted-test selection for CI
+//!
+//! ```python
+//! selection = codegraph_ir.select_impacted_tests(ir_docs, ["app/codec.py"])
+//! if selection["full_suite"]:
+//!     run_all()
+//! else:
+//!     run(selection["runner_ids"])  # e.g. pytest node ids
+//! ```
+//!
+//! The dict has `tests` (`symbol_id`, `fqn`, `name`, `file_path`,
+//! `framework`, `runner_id`), `test_ids`, `runner_ids`, `full_suite`,
+//! `fallback_reasons`, `changed_symbols`, `uncovered_symbols` and
+//! `unindexed_files`.
+
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::features::cross_file::IRDocument;
+use crate::features::query_engine::{select_impacted_tests, TestSelectionConfig};
+
+/// Tests affected by `changed_files`, or the full suite when the changes
+/// cannot be fully traced (unless `fallback_to_full_suite=False`)
+#[pyfunction]
+#[pyo3(name = "select_impacted_tests")]
+#[pyo3(signature = (ir_docs, changed_files, fallback_to_full_suite = true))]
+pub fn select_impacted_tests_py(
+    py: Python,
+    ir_docs: Vec<IRDocument>,
+    changed_files: Vec<String>,
+    fallback_to_full_suite: bool,
+) -> PyResult<Py<PyDict>> {
+    let config = TestSelectionConfig {
+        fallback_to_full_suite,
+    };
+    let selection = py.allow_threads(|| {
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        for doc in ir_docs {
+            nodes.extend(doc.nodes);
+            edges.extend(doc.edges);
+        }
+        select_impacted_tests(&nodes, &edges, &changed_files, &config)
+    });
+
+    let dict = PyDict::new(py);
+    let tests = PyList::empty(py);
+    for test in &selection.tests {
+        let test_dict = PyDict::new(py);
+        test_dict.set_item("symbol_id", &test.symbol_id)?;
+        test_dict.set_item("fqn", &test.fqn)?;
+        test_dict.set_item("name", &test.name)?;
+        test_dict.set_item("file_path", &test.file_path)?;
+        test_dict.set_item("framework", test.framework.as_str())?;
+        test_dict.set_item("runner_id", &test.runner_id)?;
+        tests.append(test_dict)?;
+    }
+    dict.set_item("tests", tests)?;
+    dict.set_item("test_ids", selection.test_ids())?;
+    dict.set_item("runner_ids", selection.runner_ids())?;
+    dict.set_item("full_suite", selection.full_suite)?;
+    dict.set_item("fallback_reasons", &selection.fallback_reasons)?;
+    dict.set_item("changed_symbols", &selection.changed_symbols)?;
+    dict.set_item("uncovered_symbols", &selection.uncovered_symbols)?;
+    dict.set_item("unindexed_files", &selection.unindexed_files)?;
+    Ok(dict.into())
+}
+
+/// Register the test-selection API
+pub fn register_test_selection_api(m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(select_impacted_tests_py, m)?)?;
+    Ok(())
+}
@@ -394,6 +394,14 @@ impl ToPyDict for SliceSummary {
         dict.set_item("function_id", &self.function_id)?;
         dict.set_item("criterion", &self.criterion)?;
         dict.set_item("slice_size", self.slice_size)?;
+        dict.set_item("slice_type", &self.slice_type)?;
+        dict.set_item("file_path", &self.file_path)?;
+        dict.set_item("spans", vec_to_py_list(py, &self.spans)?)?;
+        let external = PyDict::new(py);
+        for (file_path, spans) in &self.external_spans {
+            external.set_item(file_path, vec_to_py_list(py, spans)?)?;
+        }
+        dict.set_item("external_spans", external)?;
 
         Ok(dict.into())
     }
@@ -0,0 +1,255 @@
+//! Single-file analysis behind the WASM bindings
+//!
+//! L1 (parse + IR) and occurrences of one in-memory file. Outline and local
+//! call graph are derived from that IR alone; calls into other files stay
+//! unresolved (`callee: None`).
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::pipeline::process_any_file;
+use crate::pipeline::processor::generate_occurrences_pub;
+use crate::shared::models::{Edge, EdgeKind, Node, NodeKind, Occurrence, Result};
+
+/// Repository id of in-browser analyses
+const REPO_ID: &str = "wasm";
+
+/// Parent links followed before giving up (guards malformed IR)
+const MAX_PARENT_DEPTH: usize = 64;
+
+/// IR of one analyzed file
+#[derive(Debug, Clone, Default)]
+pub struct FileAnalysis {
+    pub file_path: String,
+    pub nodes: Vec<Node>,
+    pub edges: Vec<Edge>,
+    pub occurrences: Vec<Occurrence>,
+    /// Parse/IR errors (the IR may still be partial)
+    pub errors: Vec<String>,
+}
+
+/// Outline entry: a declaration and the declarations nested in it
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutlineItem {
+    pub id: String,
+    pub name: String,
+    pub kind: &'static str,
+    pub fqn: String,
+    pub start_line: u32,
+    pub end_line: u32,
+    pub children: Vec<OutlineItem>,
+}
+
+/// Call graph within one file
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LocalCallGraph {
+    pub functions: Vec<LocalFunction>,
+    pub calls: Vec<LocalCall>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LocalFunction {
+    pub id: String,
+    pub name: String,
+    pub fqn: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LocalCall {
+    /// Calling function's node id
+    pub caller: String,
+    /// Called function's node id when defined in this file
+    pub callee: Option<String>,
+    /// Call target as written in the IR (FQN or name)
+    pub target: String,
+    pub line: Option<u32>,
+}
+
+impl FileAnalysis {
+    /// Parse `source` and build its IR and occurrences
+    pub fn analyze(file_path: &str, source: &str) -> Result<Self> {
+        let result = process_any_file(file_path, source, REPO_ID)?;
+        let occurrences = if result.occurrences.is_empty() {
+            generate_occurrences_pub(&result.nodes, &result.edges)
+        } else {
+            result.occurrences
+        };
+        Ok(Self {
+            file_path: file_path.to_string(),
+            nodes: result.nodes,
+            edges: result.edges,
+            occurrences,
+            errors: result.errors,
+        })
+    }
+
+    /// Declarations as a tree, in source order
+    pub fn outline(&self) -> Vec<OutlineItem> {
+        let by_id: HashMap<&str, &Node> = self.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+        let mut parents: HashMap<&str, &str> = self
+            .edges
+            .iter()
+            .filter(|e| e.kind == EdgeKind::Contains)
+            .map(|e| (e.target_id.as_str(), e.source_id.as_str()))
+            .collect();
+        for node in &self.nodes {
+            if let Some(parent) = &node.parent_id {
+                parents.insert(node.id.as_str(), parent.as_str());
+            }
+        }
+
+        // Nearest outlined ancestor of each outlined node
+        let mut children: HashMap<Option<&str>, Vec<&Node>> = HashMap::new();
+        for node in self.nodes.iter().filter(|n| is_outlined(n.kind)) {
+            let mut parent = parents.get(node.id.as_str()).copied();
+            for _ in 0..MAX_PARENT_DEPTH {
+                match parent.and_then(|id| by_id.get(id)) {
+                    Some(p) if is_outlined(p.kind) => break,
+                    Some(p) => parent = parents.get(p.id.as_str()).copied(),
+                    None => {
+                        parent = None;
+                        break;
+                    }
+                }
+            }
+            children.entry(parent).or_default().push(node);
+        }
+        build_outline(None, &children)
+    }
+
+    /// Functions of this file and the calls they make
+    pub fn call_graph(&self) -> LocalCallGraph {
+        let functions: Vec<&Node> = self.nodes.iter().filter(|n| n.kind.is_callable()).collect();
+        let mut by_target: HashMap<&str, &str> = HashMap::new();
+        for function in &functions {
+            by_target.insert(function.fqn.as_str(), function.id.as_str());
+            by_target.insert(function.id.as_str(), function.id.as_str());
+        }
+        // Bare names only resolve when unambiguous
+        let mut by_name: HashMap<&str, Option<&str>> = HashMap::new();
+        for function in &functions {
+            if let Some(name) = function.name.as_deref() {
+                by_name
+                    .entry(name)
+                    .and_modify(|id| *id = None)
+                    .or_insert(Some(function.id.as_str()));
+            }
+        }
+
+        let calls = self
+            .edges
+            .iter()
+            .filter(|e| e.kind == EdgeKind::Calls)
+            .map(|e| {
+                let target = e.target_id.as_str();
+                let name = target.rsplit(['.', ':']).next().unwrap_or(target);
+                LocalCall {
+                    caller: e.source_id.clone(),
+                    callee: by_target
+                        .get(target)
+                        .copied()
+                        .or_else(|| by_name.get(name).copied().flatten())
+                        .map(str::to_string),
+                    target: e.target_id.clone(),
+                    line: e.span.as_ref().map(|s| s.start_line),
+                }
+            })
+            .collect();
+
+        LocalCallGraph {
+            functions: functions
+                .iter()
+                .map(|f| LocalFunction {
+                    id: f.id.clone(),
+                    name: f.name.clone().unwrap_or_default(),
+                    fqn: f.fqn.clone(),
+                })
+                .collect(),
+            calls,
+        }
+    }
+}
+
+/// Declarations shown in the outline
+fn is_outlined(kind: NodeKind) -> bool {
+    match kind {
+        NodeKind::File | NodeKind::Module | NodeKind::Lambda => false,
+        NodeKind::Constant
+        | NodeKind::Field
+        | NodeKind::Property
+        | NodeKind::EnumMember
+        | NodeKind::TypeAlias => true,
+        kind => kind.is_container() || kind.is_callable(),
+    }
+}
+
+fn build_outline(
+    parent: Option<&str>,
+    children: &HashMap<Option<&str>, Vec<&Node>>,
+) -> Vec<OutlineItem> {
+    let mut nodes = children.get(&parent).cloned().unwrap_or_default();
+    nodes.sort_by_key(|n| (n.span.start_line, n.span.start_col));
+    nodes
+        .into_iter()
+        .map(|node| OutlineItem {
+            id: node.id.clone(),
+            name: node.name.clone().unwrap_or_else(|| node.fqn.clone()),
+            kind: node.kind.as_str(),
+            fqn: node.fqn.clone(),
+            start_line: node.span.start_line,
+            end_line: node.span.end_line,
+            children: build_outline(Some(node.id.as_str()), children),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::models::Span;
+
+    #[test]
+    fn test_outline_and_local_calls() {
+        let node = |id: &str, kind, name: &str, line, parent: Option<&str>| {
+            let mut node = Node::new(
+                id.to_string(),
+                kind,
+                format!("app.{}", name),
+                "app.py".to_string(),
+                Span::new(line, 0, line + 1, 0),
+            );
+            node.name = Some(name.to_string());
+            node.parent_id = parent.map(str::to_string);
+            node
+        };
+        let analysis = FileAnalysis {
+            file_path: "app.py".to_string(),
+            nodes: vec![
+                node("file", NodeKind::File, "app", 1, None),
+                node("m", NodeKind::Method, "save", 6, Some("c")),
+                node("c", NodeKind::Class, "User", 5, Some("file")),
+                node("f", NodeKind::Function, "main", 1, Some("file")),
+                node("v", NodeKind::Variable, "x", 2, Some("f")),
+            ],
+            edges: vec![
+                Edge::new("f".to_string(), "app.save".to_string(), EdgeKind::Calls),
+                Edge::new("f".to_string(), "save".to_string(), EdgeKind::Calls),
+                Edge::new("f".to_string(), "os.path.join".to_string(), EdgeKind::Calls),
+            ],
+            ..Default::default()
+        };
+
+        let outline = analysis.outline();
+        let names: Vec<_> = outline.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, vec!["main", "User"]);
+        assert!(outline[0].children.is_empty());
+        assert_eq!(outline[1].children[0].name, "save");
+
+        let graph = analysis.call_graph();
+        assert_eq!(graph.functions.len(), 2);
+        let callees: Vec<_> = graph.calls.iter().map(|c| c.callee.as_deref()).collect();
+        assert_eq!(callees, vec![Some("m"), Some("m"), None]);
+    }
+}
@@ -0,0 +1,81 @@
+//! WebAssembly adapter (`wasm` feature)
+//!
+//! Single-file analysis (L1 parse + IR, occurrences) for in-browser use,
+//! e.g. outlines and local call graphs in a code-review UI. No repository
+//! context: cross-file calls stay unresolved.
+//!
+//! ```js
+//! import init, { FileAnalysis } from 'codegraph-ir-wasm';
+//!
+//! await init();
+//! const analysis = new FileAnalysis('app/views.py', source);
+//! analysis.outline();    // [{ name, kind, fqn, startLine, endLine, children }]
+//! analysis.callGraph();  // { functions: [...], calls: [{ caller, callee, target, line }] }
+//! ```
+//!
+//! Build with `wasm-pack build --target web -- --no-default-features --features wasm`.
+
+pub mod analysis;
+
+pub use analysis::{FileAnalysis, LocalCall, LocalCallGraph, LocalFunction, OutlineItem};
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+/// One analyzed file (`FileAnalysis` in JS)
+#[wasm_bindgen(js_name = FileAnalysis)]
+pub struct WasmFileAnalysis {
+    inner: FileAnalysis,
+}
+
+#[wasm_bindgen(js_class = FileAnalysis)]
+impl WasmFileAnalysis {
+    /// Parse `source`; the language comes from `filePath`'s extension
+    #[wasm_bindgen(constructor)]
+    pub fn new(file_path: &str, source: &str) -> Result<WasmFileAnalysis, JsError> {
+        let inner =
+            FileAnalysis::analyze(file_path, source).map_err(|e| JsError::new(&e.to_string()))?;
+        Ok(Self { inner })
+    }
+
+    #[wasm_bindgen(getter, js_name = filePath)]
+    pub fn file_path(&self) -> String {
+        self.inner.file_path.clone()
+    }
+
+    /// Parse/IR errors (the analysis may be partial)
+    #[wasm_bindgen(getter)]
+    pub fn errors(&self) -> Vec<String> {
+        self.inner.errors.clone()
+    }
+
+    pub fn outline(&self) -> Result<JsValue, JsError> {
+        to_js(&self.inner.outline())
+    }
+
+    #[wasm_bindgen(js_name = callGraph)]
+    pub fn call_graph(&self) -> Result<JsValue, JsError> {
+        to_js(&self.inner.call_graph())
+    }
+
+    /// IR nodes (same shape as the pipeline's `Node`)
+    pub fn nodes(&self) -> Result<JsValue, JsError> {
+        to_js(&self.inner.nodes)
+    }
+
+    pub fn edges(&self) -> Result<JsValue, JsError> {
+        to_js(&self.inner.edges)
+    }
+
+    /// SCIP occurrences (definitions and references)
+    pub fn occurrences(&self) -> Result<JsValue, JsError> {
+        to_js(&self.inner.occurrences)
+    }
+}
+
+/// Plain JS objects (maps become objects, not `Map`)
+fn to_js<T: Serialize + ?Sized>(value: &T) -> Result<JsValue, JsError> {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| JsError::new(&e.to_string()))
+}
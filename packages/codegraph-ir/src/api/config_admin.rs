@@ -0,0 +1,95 @@
+//! ConfigAdmin - Language-Agnostic Config Admin API
+//!
+//! Transport-independent handlers behind the admin endpoints of the service
+//! adapters (gRPC/REST/MCP). Each adapter maps its own routes onto these:
+//!
+//! | Handler      | Suggested route                | Purpose                      |
+//! |--------------|--------------------------------|------------------------------|
+//! | `status`     | `GET  /admin/config`           | Active version + config hash |
+//! | `reload`     | `POST /admin/config/reload`    | Re-read the config file      |
+//! | `apply`      | `PUT  /admin/config`           | Push a new config (YAML/JSON)|
+//!
+//! Design principles (same as `graph_query`):
+//! - Simple error handling (Result<T, String>)
+//! - Serialization-agnostic outputs (serde types + JSON helpers)
+//! - Thread-safe (Send + Sync)
+
+use std::sync::Arc;
+
+use crate::config::hot_reload::{ApplyOutcome, ConfigStatus, HotConfigStore, ServiceConfig};
+
+/// Admin handlers over a shared `HotConfigStore`
+#[derive(Clone)]
+pub struct ConfigAdmin {
+    store: Arc<HotConfigStore>,
+}
+
+impl ConfigAdmin {
+    pub fn new(store: Arc<HotConfigStore>) -> Self {
+        Self { store }
+    }
+
+    /// Underlying store (for request handlers reading the active config)
+    pub fn store(&self) -> &Arc<HotConfigStore> {
+        &self.store
+    }
+
+    /// Active config version, hash, source and recent history
+    pub fn status(&self) -> ConfigStatus {
+        self.store.status()
+    }
+
+    /// `status()` as JSON
+    pub fn status_json(&self) -> Result<String, String> {
+        serde_json::to_string(&self.status()).map_err(|e| e.to_string())
+    }
+
+    /// Re-read the config file the store was created from
+    pub fn reload(&self) -> Result<ApplyOutcome, String> {
+        self.store.reload().map_err(|e| e.to_string())
+    }
+
+    /// Apply a config document (YAML or JSON)
+    ///
+    /// With `expected_version`, the config is only applied if the active
+    /// version still matches (optimistic concurrency, e.g. HTTP `If-Match`).
+    pub fn apply(&self, body: &str, expected_version: Option<u64>) -> Result<ApplyOutcome, String> {
+        let config = ServiceConfig::from_yaml_str(body).map_err(|e| e.to_string())?;
+        let result = match expected_version {
+            Some(version) => self.store.apply_if_version(version, config, "admin"),
+            None => self.store.apply(config, "admin"),
+        };
+        result.map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_and_apply() {
+        let store = Arc::new(HotConfigStore::new(ServiceConfig::default()).unwrap());
+        let admin = ConfigAdmin::new(Arc::clone(&store));
+
+        let status: serde_json::Value =
+            serde_json::from_str(&admin.status_json().unwrap()).unwrap();
+        assert_eq!(status["version"], 1);
+        assert_eq!(status["hash"].as_str().unwrap().len(), 64);
+
+        let body = r#"{"version": 1, "retrieval": {"channels": {"lexical": 1.0}}}"#;
+        let outcome = admin.apply(body, Some(1)).unwrap();
+        assert!(matches!(outcome, ApplyOutcome::Applied { version: 2, .. }));
+        assert_eq!(store.version(), 2);
+
+        // Stale version and invalid documents are rejected without side effects
+        assert!(admin.apply(body, Some(1)).is_err());
+        assert!(admin
+            .apply("version: 1\ntaint:\n  max_paths: 0\n", None)
+            .is_err());
+        assert_eq!(admin.status().version, 2);
+
+        // In-memory store has no file to reload from
+        assert!(admin.reload().is_err());
+    }
+}
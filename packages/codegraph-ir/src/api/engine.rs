@@ -0,0 +1,374 @@
+//! Engine - Rust-native indexing entry point
+//!
+//! Plain Rust structs for services that embed the engine directly; the PyO3
+//! `run_ir_indexing_pipeline*` functions are thin wrappers over this.
+//!
+//! ```rust,ignore
+//! use codegraph_ir::api::{Engine, IndexOptions};
+//!
+//! let result = Engine::new().index(&IndexOptions::new("/repo", "repo").with_repomap(true))?;
+//! let query = result.query();
+//! let hits = query.search("login", 10, &Default::default());
+//! ```
+
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
+
+use crate::config::{PipelineConfig, Preset};
+use crate::features::datalog::{DatalogEngine, DatalogResult};
+use crate::features::ir_generation::domain::ir_document::IRDocument;
+use crate::features::query_engine::{QueryEngine, RankedHit, SearchFilters};
+use crate::pipeline::{
+    CancellationToken, E2EPipelineConfig, E2EPipelineResult, IRIndexingOrchestrator, IndexingMode,
+    PipelineStats, ProgressReporter,
+};
+use crate::shared::models::{CodegraphError, Edge, Node, NodeKind, Result};
+
+/// Files above this size are memory-mapped
+const MMAP_THRESHOLD: usize = 1024 * 1024;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// IndexOptions
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// What to index and which stages to run
+///
+/// Defaults match `run_ir_indexing_pipeline`: chunking, cross-file, symbols
+/// and points-to on; RepoMap, taint and clone detection off.
+#[derive(Debug, Clone)]
+pub struct IndexOptions {
+    pub repo_root: PathBuf,
+    pub repo_name: String,
+    /// Index only these files (all discovered files when `None`)
+    pub file_paths: Option<Vec<PathBuf>>,
+    pub chunking: bool,
+    pub cross_file: bool,
+    pub symbols: bool,
+    /// Points-to analysis; also enables heap analysis
+    pub points_to: bool,
+    pub repomap: bool,
+    pub taint: bool,
+    pub clone: bool,
+    /// Worker threads (0 = auto)
+    pub parallel_workers: usize,
+}
+
+impl IndexOptions {
+    pub fn new(repo_root: impl Into<PathBuf>, repo_name: impl Into<String>) -> Self {
+        Self {
+            repo_root: repo_root.into(),
+            repo_name: repo_name.into(),
+            file_paths: None,
+            chunking: true,
+            cross_file: true,
+            symbols: true,
+            points_to: true,
+            repomap: false,
+            taint: false,
+            clone: false,
+            parallel_workers: 0,
+        }
+    }
+
+    pub fn with_file_paths(mut self, file_paths: Vec<PathBuf>) -> Self {
+        self.file_paths = Some(file_paths);
+        self
+    }
+
+    pub fn with_chunking(mut self, enabled: bool) -> Self {
+        self.chunking = enabled;
+        self
+    }
+
+    pub fn with_cross_file(mut self, enabled: bool) -> Self {
+        self.cross_file = enabled;
+        self
+    }
+
+    pub fn with_symbols(mut self, enabled: bool) -> Self {
+        self.symbols = enabled;
+        self
+    }
+
+    pub fn with_points_to(mut self, enabled: bool) -> Self {
+        self.points_to = enabled;
+        self
+    }
+
+    pub fn with_repomap(mut self, enabled: bool) -> Self {
+        self.repomap = enabled;
+        self
+    }
+
+    pub fn with_taint(mut self, enabled: bool) -> Self {
+        self.taint = enabled;
+        self
+    }
+
+    pub fn with_clone(mut self, enabled: bool) -> Self {
+        self.clone = enabled;
+        self
+    }
+
+    pub fn with_parallel_workers(mut self, workers: usize) -> Self {
+        self.parallel_workers = workers;
+        self
+    }
+
+    /// E2E pipeline config for these options (RFC-001 `Balanced` preset)
+    pub fn to_config(&self) -> Result<E2EPipelineConfig> {
+        let pipeline_config = PipelineConfig::preset(Preset::Balanced)
+            .stages(|mut s| {
+                s.parsing = true; // Always enabled
+                s.chunking = self.chunking;
+                s.lexical = false;
+                s.cross_file = self.cross_file;
+                s.clone = self.clone;
+                s.pta = self.points_to;
+                s.flow_graphs = false;
+                s.type_inference = false;
+                s.symbols = self.symbols;
+                s.effects = false;
+                s.taint = self.taint;
+                s.repomap = self.repomap;
+                s.heap = self.points_to;
+                s.pdg = false;
+                s.concurrency = false;
+                s.slicing = false;
+                s
+            })
+            .parallel(|mut p| {
+                p.num_workers = self.parallel_workers;
+                p.batch_size = 100;
+                p
+            })
+            .build()
+            .map_err(|e| CodegraphError::config(format!("Configuration build failed: {}", e)))?;
+
+        let config = E2EPipelineConfig::with_config(pipeline_config)
+            .repo_root(self.repo_root.clone())
+            .repo_name(self.repo_name.clone())
+            .indexing_mode(IndexingMode::Full)
+            .mmap_threshold(MMAP_THRESHOLD);
+
+        Ok(match &self.file_paths {
+            Some(file_paths) => config.file_paths(file_paths.clone()),
+            None => config,
+        })
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Engine
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Indexing engine
+///
+/// Holds run-wide hooks (progress, cancellation); reusable across `index`
+/// calls.
+#[derive(Default)]
+pub struct Engine {
+    progress: Option<Arc<dyn ProgressReporter>>,
+    cancellation: Option<CancellationToken>,
+}
+
+impl Engine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_progress(mut self, reporter: Arc<dyn ProgressReporter>) -> Self {
+        self.progress = Some(reporter);
+        self
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Run the pipeline for `options`
+    pub fn index(&self, options: &IndexOptions) -> Result<IndexResult> {
+        self.index_with_config(options.to_config()?)
+    }
+
+    /// Run the pipeline with a hand-built config
+    pub fn index_with_config(&self, config: E2EPipelineConfig) -> Result<IndexResult> {
+        let mut orchestrator = IRIndexingOrchestrator::new(config);
+        if let Some(reporter) = &self.progress {
+            orchestrator = orchestrator.with_progress(Arc::clone(reporter));
+        }
+        if let Some(token) = &self.cancellation {
+            orchestrator = orchestrator.with_cancellation(token.clone());
+        }
+        Ok(IndexResult::new(orchestrator.execute()?))
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// IndexResult / QueryHandle
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Output of one `Engine::index` run
+pub struct IndexResult {
+    pipeline: E2EPipelineResult,
+    /// Merged graph for `query`, built on first use
+    ir_doc: OnceLock<IRDocument>,
+}
+
+impl IndexResult {
+    pub fn new(pipeline: E2EPipelineResult) -> Self {
+        Self {
+            pipeline,
+            ir_doc: OnceLock::new(),
+        }
+    }
+
+    /// Full pipeline result (chunks, symbols, RepoMap, ...)
+    pub fn pipeline(&self) -> &E2EPipelineResult {
+        &self.pipeline
+    }
+
+    /// Mutable pipeline result; drops the cached query graph
+    pub fn pipeline_mut(&mut self) -> &mut E2EPipelineResult {
+        self.ir_doc = OnceLock::new();
+        &mut self.pipeline
+    }
+
+    pub fn into_pipeline(self) -> E2EPipelineResult {
+        self.pipeline
+    }
+
+    pub fn nodes(&self) -> &[Node] {
+        &self.pipeline.nodes
+    }
+
+    pub fn edges(&self) -> &[Edge] {
+        &self.pipeline.edges
+    }
+
+    pub fn stats(&self) -> &PipelineStats {
+        &self.pipeline.stats
+    }
+
+    /// All nodes and edges as one document
+    pub fn ir_document(&self) -> &IRDocument {
+        self.ir_doc.get_or_init(|| IRDocument {
+            file_path: String::new(),
+            nodes: self.pipeline.nodes.clone(),
+            edges: self.pipeline.edges.clone(),
+        })
+    }
+
+    /// Query handle over the indexed graph
+    pub fn query(&self) -> QueryHandle<'_> {
+        QueryHandle::new(self.ir_document())
+    }
+}
+
+/// Graph queries over an `IndexResult`
+pub struct QueryHandle<'a> {
+    engine: QueryEngine<'a>,
+    ir_doc: &'a IRDocument,
+}
+
+impl<'a> QueryHandle<'a> {
+    pub fn new(ir_doc: &'a IRDocument) -> Self {
+        Self {
+            engine: QueryEngine::new(ir_doc),
+            ir_doc,
+        }
+    }
+
+    /// Underlying query engine (path queries, explain, reachability)
+    pub fn engine(&self) -> &QueryEngine<'a> {
+        &self.engine
+    }
+
+    /// Ranked symbol search
+    pub fn search(&self, query: &str, top_k: usize, filters: &SearchFilters) -> Vec<RankedHit> {
+        self.engine.search(query, top_k, filters)
+    }
+
+    /// Nodes of `kind`, optionally with `name_pattern` in their name
+    pub fn find(&self, kind: NodeKind, name_pattern: Option<&str>) -> Vec<&'a Node> {
+        self.ir_doc
+            .nodes
+            .iter()
+            .filter(|n| n.kind == kind)
+            .filter(|n| match name_pattern {
+                Some(pattern) => n.name.as_deref().is_some_and(|name| name.contains(pattern)),
+                None => true,
+            })
+            .collect()
+    }
+
+    pub fn nodes_in_file(&self, file_path: &str) -> Vec<&'a Node> {
+        self.ir_doc
+            .nodes
+            .iter()
+            .filter(|n| n.file_path == file_path)
+            .collect()
+    }
+
+    pub fn can_reach(&self, source: &str, target: &str) -> bool {
+        self.engine.can_reach(source, target)
+    }
+
+    /// Run a Datalog program over the IR relations (see `features::datalog`)
+    pub fn datalog(&self, program: &str) -> Result<DatalogResult> {
+        DatalogEngine::from_ir(self.ir_doc).run_source(program)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::models::Span;
+
+    #[test]
+    fn test_index_options_map_to_stage_flags() {
+        let config = IndexOptions::new("/repo", "repo")
+            .with_chunking(false)
+            .with_repomap(true)
+            .with_points_to(false)
+            .to_config()
+            .unwrap();
+        assert!(!config.enable_chunking());
+        assert!(config.enable_repomap());
+        assert!(!config.enable_points_to());
+        assert!(!config.enable_heap_analysis());
+        assert!(config.enable_cross_file());
+    }
+
+    #[test]
+    fn test_query_handle_over_index_result() {
+        let mut pipeline = E2EPipelineResult::new();
+        for (id, kind, name, path) in [
+            ("f1", NodeKind::Function, "login", "src/auth.py"),
+            ("f2", NodeKind::Function, "logout", "src/auth.py"),
+            ("c1", NodeKind::Class, "User", "src/models.py"),
+        ] {
+            let mut node = Node::new(
+                id.to_string(),
+                kind,
+                format!("{}.{}", path, name),
+                path.to_string(),
+                Span::new(1, 0, 2, 0),
+            );
+            node.name = Some(name.to_string());
+            pipeline.nodes.push(node);
+        }
+
+        let mut result = IndexResult::new(pipeline);
+        {
+            let query = result.query();
+            assert_eq!(query.find(NodeKind::Function, None).len(), 2);
+            assert_eq!(query.find(NodeKind::Function, Some("out"))[0].id, "f2");
+            assert_eq!(query.nodes_in_file("src/models.py")[0].id, "c1");
+        }
+
+        result.pipeline_mut().nodes.pop();
+        assert!(result.query().nodes_in_file("src/models.py").is_empty());
+    }
+}
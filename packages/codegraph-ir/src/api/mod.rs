@@ -3,6 +3,10 @@
 //! This module provides pure Rust APIs that can be wrapped by any language binding.
 //! All APIs are designed to be FFI-friendly and avoid language-specific types.
 
+pub mod config_admin;
+pub mod engine;
 pub mod graph_query;
 
+pub use config_admin::ConfigAdmin;
+pub use engine::{Engine, IndexOptions, IndexResult, QueryHandle};
 pub use graph_query::{GraphQuery, GraphStats, QueryFilter};
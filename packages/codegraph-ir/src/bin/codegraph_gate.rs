@@ -0,0 +1,183 @@
+/*
+ * CI Gate CLI
+ *
+ * Evaluates findings against a severity policy and exits with the verdict:
+ *   0 = pass, 1 = fail, 2 = warn (0 with --warn-exit-zero), 3 = error
+ *
+ * Usage:
+ *   codegraph-gate --findings findings.json --policy gate.yaml \
+ *       --baseline main-findings.json --changed-files changed.txt
+ */
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use codegraph_ir::features::gating::{GateContext, GateFinding, GatePolicy, PolicyEngine};
+
+/// CLI arguments
+struct Args {
+    findings: PathBuf,
+    policy: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    changed_files: Option<PathBuf>,
+    json: bool,
+    warn_exit_zero: bool,
+}
+
+impl Args {
+    fn parse() -> Result<Self, String> {
+        let args: Vec<String> = std::env::args().collect();
+
+        let mut findings = None;
+        let mut policy = None;
+        let mut baseline = None;
+        let mut changed_files = None;
+        let mut json = false;
+        let mut warn_exit_zero = false;
+
+        let mut i = 1;
+        while i < args.len() {
+            match args[i].as_str() {
+                "--findings" | "-f" => {
+                    i += 1;
+                    findings = Some(PathBuf::from(
+                        args.get(i).ok_or("--findings requires a value")?,
+                    ));
+                }
+                "--policy" | "-p" => {
+                    i += 1;
+                    policy = Some(PathBuf::from(
+                        args.get(i).ok_or("--policy requires a value")?,
+                    ));
+                }
+                "--baseline" | "-b" => {
+                    i += 1;
+                    baseline = Some(PathBuf::from(
+                        args.get(i).ok_or("--baseline requires a value")?,
+                    ));
+                }
+                "--changed-files" | "-c" => {
+                    i += 1;
+                    changed_files = Some(PathBuf::from(
+                        args.get(i).ok_or("--changed-files requires a value")?,
+                    ));
+                }
+                "--json" => json = true,
+                "--warn-exit-zero" => warn_exit_zero = true,
+                "--help" | "-h" => {
+                    print_help();
+                    std::process::exit(0);
+                }
+                other => return Err(format!("Unknown argument: {}", other)),
+            }
+            i += 1;
+        }
+
+        Ok(Self {
+            findings: findings.ok_or("--findings is required")?,
+            policy,
+            baseline,
+            changed_files,
+            json,
+            warn_exit_zero,
+        })
+    }
+}
+
+fn print_help() {
+    println!(
+        r#"
+CI Gate
+
+USAGE:
+    codegraph-gate --findings <PATH> [OPTIONS]
+
+OPTIONS:
+    -f, --findings <PATH>       Findings JSON (array of gate findings)
+    -p, --policy <PATH>         Policy YAML (default: fail on new critical,
+                                warn on high in changed files)
+    -b, --baseline <PATH>       Findings JSON from the base branch
+    -c, --changed-files <PATH>  Changed file paths, one per line
+        --json                  Print the verdict as JSON
+        --warn-exit-zero        Exit 0 on warn
+
+EXIT STATUS:
+    0 pass, 1 fail, 2 warn, 3 usage/input error
+
+EXAMPLES:
+    git diff --name-only origin/main > changed.txt
+    codegraph-gate -f findings.json -b main-findings.json -c changed.txt
+"#
+    );
+}
+
+fn load_findings(path: &Path) -> Result<Vec<GateFinding>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("invalid findings {}: {}", path.display(), e))
+}
+
+fn run(args: &Args) -> Result<codegraph_ir::features::gating::GateVerdict, String> {
+    let policy = match &args.policy {
+        Some(path) => GatePolicy::from_file(path).map_err(|e| e.to_string())?,
+        None => GatePolicy::default(),
+    };
+
+    let findings = load_findings(&args.findings)?;
+
+    let baseline = match &args.baseline {
+        Some(path) => Some(PolicyEngine::baseline_of(&load_findings(path)?)),
+        None => None,
+    };
+
+    let changed_files: HashSet<String> = match &args.changed_files {
+        Some(path) => std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .map(str::to_string)
+            .collect(),
+        None => HashSet::new(),
+    };
+
+    let ctx = GateContext {
+        baseline,
+        changed_files,
+    };
+    Ok(PolicyEngine::from_policy(&policy).evaluate(&findings, &ctx))
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse() {
+        Ok(a) => a,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::from(3);
+        }
+    };
+
+    let verdict = match run(&args) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::from(3);
+        }
+    };
+
+    if args.json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&verdict).unwrap_or_default()
+        );
+    } else {
+        println!("{}", verdict.summary());
+    }
+
+    match verdict.exit_code() {
+        2 if args.warn_exit_zero => ExitCode::SUCCESS,
+        code => ExitCode::from(code as u8),
+    }
+}
@@ -0,0 +1,136 @@
+/*
+ * Analysis Engine gRPC Server
+ *
+ * Serves the `codegraph.v1.AnalysisEngine` API (proto/codegraph/v1/engine.proto)
+ * for non-Python clients such as Go backends and CI agents. Stops on Ctrl-C.
+ *
+ * Usage:
+ *   codegraph-grpc --addr 0.0.0.0:50051 --snapshot-db codegraph.db --preset fast
+ */
+
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use codegraph_ir::adapters::grpc::{serve, EngineService};
+use codegraph_ir::config::Preset;
+use codegraph_ir::features::storage::{CodeSnapshotStore, SqliteChunkStore};
+
+/// CLI arguments
+struct Args {
+    addr: SocketAddr,
+    snapshot_db: Option<PathBuf>,
+    preset: Preset,
+}
+
+impl Args {
+    fn parse() -> Result<Self, String> {
+        let args: Vec<String> = std::env::args().collect();
+
+        let mut addr: SocketAddr = "127.0.0.1:50051".parse().unwrap();
+        let mut snapshot_db = None;
+        let mut preset = Preset::Balanced;
+
+        let mut i = 1;
+        while i < args.len() {
+            match args[i].as_str() {
+                "--addr" | "-a" => {
+                    i += 1;
+                    addr = args
+                        .get(i)
+                        .ok_or("--addr requires a value")?
+                        .parse()
+                        .map_err(|_| "--addr must be HOST:PORT")?;
+                }
+                "--snapshot-db" | "-d" => {
+                    i += 1;
+                    snapshot_db = Some(PathBuf::from(
+                        args.get(i).ok_or("--snapshot-db requires a value")?,
+                    ));
+                }
+                "--preset" | "-p" => {
+                    i += 1;
+                    preset = Preset::from_str(args.get(i).ok_or("--preset requires a value")?)?;
+                }
+                "--help" | "-h" => {
+                    print_help();
+                    std::process::exit(0);
+                }
+                other => return Err(format!("Unknown argument: {}", other)),
+            }
+            i += 1;
+        }
+
+        Ok(Self {
+            addr,
+            snapshot_db,
+            preset,
+        })
+    }
+}
+
+fn print_help() {
+    println!(
+        r#"
+Analysis Engine gRPC Server
+
+USAGE:
+    codegraph-grpc [OPTIONS]
+
+OPTIONS:
+    -a, --addr <HOST:PORT>      Listen address (default: 127.0.0.1:50051)
+    -d, --snapshot-db <PATH>    SQLite snapshot store for DiffSnapshots (default: disabled)
+    -p, --preset <PRESET>       Default preset: fast, balanced, thorough (default: balanced)
+
+RPCS (codegraph.v1.AnalysisEngine):
+    IndexRepository, GetCallGraph, QuerySymbols, RunTaint, DiffSnapshots
+
+EXAMPLES:
+    codegraph-grpc --addr 0.0.0.0:50051 --snapshot-db codegraph.db
+    grpcurl -plaintext -d '{{"repo_root": "/src/api"}}' localhost:50051 \
+        codegraph.v1.AnalysisEngine/IndexRepository
+"#
+    );
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse() {
+        Ok(a) => a,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut service = EngineService::new().with_default_preset(args.preset);
+    if let Some(db) = &args.snapshot_db {
+        match SqliteChunkStore::new(db) {
+            Ok(s) => service = service.with_snapshot_store(CodeSnapshotStore::new(s)),
+            Err(e) => {
+                eprintln!("Error: cannot open {}: {}", db.display(), e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("codegraph gRPC server listening on {}", args.addr);
+    let shutdown = async {
+        tokio::signal::ctrl_c().await.ok();
+        println!("shutting down");
+    };
+    match runtime.block_on(serve(args.addr, service, shutdown)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
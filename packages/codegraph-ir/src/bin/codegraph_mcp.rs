@@ -0,0 +1,103 @@
+/*
+ * MCP Server CLI
+ *
+ * Indexes a repository, then serves MCP tools (find_definition, callers_of,
+ * impact_of_change, taint_check_file, repo_map) over stdio. stdout carries
+ * the protocol; progress goes to stderr.
+ *
+ * Usage:
+ *   codegraph-mcp --repo /path/to/repo --preset fast
+ */
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use codegraph_ir::adapters::mcp::{CodegraphTools, McpServer};
+use codegraph_ir::config::Preset;
+
+/// CLI arguments
+struct Args {
+    repo: PathBuf,
+    preset: Preset,
+}
+
+impl Args {
+    fn parse() -> Result<Self, String> {
+        let args: Vec<String> = std::env::args().collect();
+
+        let mut repo = None;
+        let mut preset = Preset::Fast;
+
+        let mut i = 1;
+        while i < args.len() {
+            match args[i].as_str() {
+                "--repo" | "-r" => {
+                    i += 1;
+                    repo = Some(PathBuf::from(args.get(i).ok_or("--repo requires a value")?));
+                }
+                "--preset" | "-p" => {
+                    i += 1;
+                    preset = Preset::from_str(args.get(i).ok_or("--preset requires a value")?)?;
+                }
+                "--help" | "-h" => {
+                    print_help();
+                    std::process::exit(0);
+                }
+                other => return Err(format!("Unknown argument: {}", other)),
+            }
+            i += 1;
+        }
+
+        let repo = match repo {
+            Some(repo) => repo,
+            None => std::env::current_dir().map_err(|e| e.to_string())?,
+        };
+        Ok(Self { repo, preset })
+    }
+}
+
+fn print_help() {
+    eprintln!(
+        r#"
+Codegraph MCP Server
+
+USAGE:
+    codegraph-mcp [OPTIONS]
+
+OPTIONS:
+    -r, --repo <PATH>       Repository to index (default: current directory)
+    -p, --preset <PRESET>   Indexing preset: fast, balanced, thorough (default: fast)
+
+TOOLS:
+    find_definition, callers_of, impact_of_change, taint_check_file, repo_map
+"#
+    );
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse() {
+        Ok(a) => a,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    eprintln!("codegraph-mcp: indexing {}", args.repo.display());
+    let tools = match CodegraphTools::index(&args.repo, args.preset) {
+        Ok(t) => t,
+        Err(e) => {
+            eprintln!("Indexing error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    eprintln!("codegraph-mcp: serving '{}' on stdio", tools.repo_name());
+
+    match McpServer::new(tools).serve_stdio() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,337 @@
+/*
+ * Pipeline Record/Replay CLI
+ *
+ * Captures the exact inputs of a pipeline run into a replay bundle and
+ * re-executes the pipeline from it (for reproducing reported analysis bugs).
+ *
+ * Usage:
+ *   pipeline-replay record --repo . --out bundle.json
+ *   pipeline-replay replay bundle.json
+ *   pipeline-replay inspect bundle.json
+ */
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use codegraph_ir::pipeline::{
+    telemetry, CancellationToken, E2EOrchestrator, E2EPipelineConfig, E2EPipelineResult,
+    ReplayBundle, TelemetryConfig,
+};
+use codegraph_ir::shared::models::CodegraphError;
+
+/// CLI command
+enum Command {
+    Record {
+        repo_path: PathBuf,
+        out: PathBuf,
+        name: Option<String>,
+        config: Option<String>,
+        preset: String,
+        cache_dir: Option<PathBuf>,
+        no_cache: bool,
+    },
+    Replay {
+        bundle: PathBuf,
+        json: bool,
+    },
+    Inspect {
+        bundle: PathBuf,
+    },
+}
+
+impl Command {
+    fn parse() -> Result<Self, String> {
+        let args: Vec<String> = std::env::args().collect();
+        let command = args.get(1).map(String::as_str).unwrap_or("--help");
+
+        let mut repo_path = PathBuf::from(".");
+        let mut out = PathBuf::from("replay-bundle.json");
+        let mut name = None;
+        let mut config = None;
+        let mut preset = String::from("balanced");
+        let mut cache_dir = None;
+        let mut no_cache = false;
+        let mut json = false;
+        let mut positional = None;
+
+        let mut i = 2;
+        while i < args.len() {
+            match args[i].as_str() {
+                "--repo" | "-r" => {
+                    i += 1;
+                    repo_path = PathBuf::from(args.get(i).ok_or("--repo requires a value")?);
+                }
+                "--out" | "-o" => {
+                    i += 1;
+                    out = PathBuf::from(args.get(i).ok_or("--out requires a value")?);
+                }
+                "--name" | "-n" => {
+                    i += 1;
+                    name = Some(args.get(i).ok_or("--name requires a value")?.clone());
+                }
+                "--config" | "-c" => {
+                    i += 1;
+                    config = Some(args.get(i).ok_or("--config requires a value")?.clone());
+                }
+                "--preset" | "-p" => {
+                    i += 1;
+                    preset = args.get(i).ok_or("--preset requires a value")?.clone();
+                }
+                "--cache-dir" => {
+                    i += 1;
+                    cache_dir = Some(PathBuf::from(
+                        args.get(i).ok_or("--cache-dir requires a value")?,
+                    ));
+                }
+                "--no-cache" => {
+                    no_cache = true;
+                }
+                "--json" => {
+                    json = true;
+                }
+                other => positional = Some(PathBuf::from(other)),
+            }
+            i += 1;
+        }
+
+        match command {
+            "record" => Ok(Command::Record {
+                repo_path,
+                out,
+                name,
+                config,
+                preset,
+                cache_dir,
+                no_cache,
+            }),
+            "replay" => Ok(Command::Replay {
+                bundle: positional.ok_or("replay requires a bundle path")?,
+                json,
+            }),
+            "inspect" => Ok(Command::Inspect {
+                bundle: positional.ok_or("inspect requires a bundle path")?,
+            }),
+            "--help" | "-h" | "help" => {
+                print_help();
+                std::process::exit(0);
+            }
+            other => Err(format!("Unknown command: {}", other)),
+        }
+    }
+}
+
+fn print_help() {
+    println!(
+        r#"
+Pipeline Record/Replay CLI
+
+USAGE:
+    pipeline-replay <COMMAND> [OPTIONS]
+
+COMMANDS:
+    record              Run the pipeline and record its exact inputs
+    replay <BUNDLE>     Re-execute the pipeline from a recorded bundle
+    inspect <BUNDLE>    Show bundle contents without running anything
+
+RECORD OPTIONS:
+    -r, --repo <PATH>       Repository path (default: .)
+    -o, --out <PATH>        Bundle output path (default: replay-bundle.json)
+    -n, --name <NAME>       Repository name (default: directory name)
+    -c, --config <PATH>     Pipeline config YAML (RFC-001 v1)
+    -p, --preset <PRESET>   fast, balanced, thorough (default: balanced; ignored with --config)
+    --cache-dir <PATH>      Reuse per-file IR from this cache directory
+    --no-cache              Ignore the IR cache for this run
+
+REPLAY OPTIONS:
+    --json                  Print summary as JSON
+
+EXAMPLES:
+    # Customer side: record the failing run
+    pipeline-replay record --repo ./service --out issue-123.json
+
+    # Developer side: reproduce it
+    pipeline-replay replay issue-123.json
+"#
+    );
+}
+
+fn record_config(
+    repo_path: PathBuf,
+    name: Option<String>,
+    config: Option<String>,
+    preset: &str,
+) -> Result<E2EPipelineConfig, String> {
+    let base = match config {
+        Some(path) => E2EPipelineConfig::from_yaml(&path).map_err(|e| e.to_string())?,
+        None => match preset {
+            "fast" => E2EPipelineConfig::fast(),
+            "balanced" => E2EPipelineConfig::balanced(),
+            "thorough" => E2EPipelineConfig::thorough(),
+            other => return Err(format!("Unknown preset: {}", other)),
+        },
+    };
+
+    let name = name.unwrap_or_else(|| {
+        repo_path
+            .canonicalize()
+            .ok()
+            .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string()))
+            .unwrap_or_else(|| "unknown".to_string())
+    });
+
+    Ok(base.repo_root(repo_path).repo_name(name))
+}
+
+fn print_summary(label: &str, result: &E2EPipelineResult, fingerprint: &str, json: bool) {
+    if json {
+        let summary = serde_json::json!({
+            "mode": label,
+            "fingerprint": fingerprint,
+            "files": result.stats.files_processed,
+            "files_cached": result.stats.files_cached,
+            "nodes": result.nodes.len(),
+            "edges": result.edges.len(),
+            "chunks": result.chunks.len(),
+            "duration_ms": result.stats.total_duration.as_millis() as u64,
+        });
+        println!("{}", summary);
+    } else {
+        println!("{} [{}]", label, fingerprint);
+        println!("  files:  {}", result.stats.files_processed);
+        println!("  cached: {}", result.stats.files_cached);
+        println!("  nodes:  {}", result.nodes.len());
+        println!("  edges:  {}", result.edges.len());
+        println!("  chunks: {}", result.chunks.len());
+        println!("  time:   {:?}", result.stats.total_duration);
+    }
+}
+
+/// First Ctrl-C cancels the run gracefully (parsed files stay in the IR
+/// cache), a second one exits immediately
+fn cancel_on_ctrl_c() -> CancellationToken {
+    let token = CancellationToken::new();
+    let handle = token.clone();
+    let installed = ctrlc::set_handler(move || {
+        if handle.is_cancelled() {
+            std::process::exit(130);
+        }
+        eprintln!("Cancelling after running stages finish (Ctrl-C again to exit now)");
+        handle.cancel();
+    });
+    if let Err(e) = installed {
+        eprintln!("Warning: Ctrl-C handler not installed: {}", e);
+    }
+    token
+}
+
+/// Report a failed run; 130 (interrupted) when it was cancelled
+fn pipeline_failure(context: &str, e: &CodegraphError) -> ExitCode {
+    if e.is_cancelled() {
+        eprintln!("{}", e.message);
+        return ExitCode::from(130);
+    }
+    eprintln!("{}: {}", context, e);
+    ExitCode::FAILURE
+}
+
+fn main() -> ExitCode {
+    // RUST_LOG / OTEL_EXPORTER_OTLP_ENDPOINT; kept alive until exit so
+    // pending spans are flushed
+    let _telemetry = match telemetry::init(&TelemetryConfig::from_env()) {
+        Ok(guard) => guard,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let command = match Command::parse() {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match command {
+        Command::Record {
+            repo_path,
+            out,
+            name,
+            config,
+            preset,
+            cache_dir,
+            no_cache,
+        } => {
+            let config = match record_config(repo_path, name, config, &preset) {
+                Ok(c) => {
+                    let c = match cache_dir {
+                        Some(dir) => c.ir_cache_dir(dir),
+                        None => c,
+                    };
+                    c.no_cache(no_cache)
+                }
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let orchestrator = E2EOrchestrator::new(config)
+                .with_recording(out.clone())
+                .with_cancellation(cancel_on_ctrl_c());
+            let result = match orchestrator.execute() {
+                Ok(r) => r,
+                Err(e) => return pipeline_failure("Pipeline error", &e),
+            };
+            let fingerprint = ReplayBundle::load(&out)
+                .map(|b| b.fingerprint())
+                .unwrap_or_default();
+            print_summary("recorded", &result, &fingerprint, false);
+        }
+        Command::Replay { bundle, json } => {
+            let bundle = match ReplayBundle::load(&bundle) {
+                Ok(b) => b,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let fingerprint = bundle.fingerprint();
+            let token = cancel_on_ctrl_c();
+            let result = match E2EOrchestrator::from_replay(bundle)
+                .and_then(|orchestrator| orchestrator.with_cancellation(token).execute())
+            {
+                Ok(r) => r,
+                Err(e) => return pipeline_failure("Replay error", &e),
+            };
+            print_summary("replayed", &result, &fingerprint, json);
+        }
+        Command::Inspect { bundle } => {
+            let bundle = match ReplayBundle::load(&bundle) {
+                Ok(b) => b,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            println!("bundle v{} [{}]", bundle.version, bundle.fingerprint());
+            println!("  recorded by: codegraph-ir {}", bundle.recorder_version);
+            println!(
+                "  repository:  {} ({})",
+                bundle.repo_name,
+                bundle.repo_root.display()
+            );
+            println!("  mode:        {:?}", bundle.mode);
+            println!(
+                "  files:       {} ({} bytes)",
+                bundle.files.len(),
+                bundle.total_bytes()
+            );
+            for file in &bundle.files {
+                println!("    {}  {}", &file.content_hash[..12], file.path);
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
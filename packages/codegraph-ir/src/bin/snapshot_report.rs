@@ -0,0 +1,192 @@
+/*
+ * Snapshot Comparison Report CLI
+ *
+ * Compares two snapshots stored in a SQLite chunk store and writes an HTML
+ * (or JSON) report, e.g. for CI to attach to pull requests.
+ *
+ * Usage:
+ *   snapshot-report --db codegraph.db --repo my-repo --base my-repo:main --head my-repo:pr-42
+ */
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use codegraph_ir::features::storage::api::{SnapshotReport, SnapshotReportOptions};
+use codegraph_ir::features::storage::{CodeSnapshotStore, SqliteChunkStore};
+use codegraph_ir::shared::i18n::{Locale, ReportEncoding, ReportOptions};
+
+/// CLI arguments
+struct Args {
+    db: PathBuf,
+    repo_id: String,
+    base: String,
+    head: String,
+    out: PathBuf,
+    json: bool,
+    options: SnapshotReportOptions,
+    report: ReportOptions,
+}
+
+impl Args {
+    fn parse() -> Result<Self, String> {
+        let args: Vec<String> = std::env::args().collect();
+
+        let mut db = PathBuf::from("codegraph.db");
+        let mut repo_id = None;
+        let mut base = None;
+        let mut head = None;
+        let mut out = None;
+        let mut json = false;
+        let mut options = SnapshotReportOptions::default();
+        let mut report = ReportOptions::default().with_locale(Locale::from_env());
+
+        let mut i = 1;
+        while i < args.len() {
+            match args[i].as_str() {
+                "--db" | "-d" => {
+                    i += 1;
+                    db = PathBuf::from(args.get(i).ok_or("--db requires a value")?);
+                }
+                "--repo" | "-r" => {
+                    i += 1;
+                    repo_id = Some(args.get(i).ok_or("--repo requires a value")?.clone());
+                }
+                "--base" | "-b" => {
+                    i += 1;
+                    base = Some(args.get(i).ok_or("--base requires a value")?.clone());
+                }
+                "--head" => {
+                    i += 1;
+                    head = Some(args.get(i).ok_or("--head requires a value")?.clone());
+                }
+                "--out" | "-o" => {
+                    i += 1;
+                    out = Some(PathBuf::from(args.get(i).ok_or("--out requires a value")?));
+                }
+                "--depth" => {
+                    i += 1;
+                    options.impact_depth = args
+                        .get(i)
+                        .ok_or("--depth requires a value")?
+                        .parse()
+                        .map_err(|_| "--depth must be a number")?;
+                }
+                "--json" => {
+                    json = true;
+                }
+                "--locale" => {
+                    i += 1;
+                    report.locale = args.get(i).ok_or("--locale requires a value")?.parse()?;
+                }
+                "--ascii" => {
+                    report.encoding = ReportEncoding::Ascii;
+                }
+                "--help" | "-h" => {
+                    print_help();
+                    std::process::exit(0);
+                }
+                other => return Err(format!("Unknown argument: {}", other)),
+            }
+            i += 1;
+        }
+
+        let out = out.unwrap_or_else(|| {
+            PathBuf::from(if json {
+                "snapshot-report.json"
+            } else {
+                "snapshot-report.html"
+            })
+        });
+
+        Ok(Self {
+            db,
+            repo_id: repo_id.ok_or("--repo is required")?,
+            base: base.ok_or("--base is required")?,
+            head: head.ok_or("--head is required")?,
+            out,
+            json,
+            options,
+            report,
+        })
+    }
+}
+
+fn print_help() {
+    println!(
+        r#"
+Snapshot Comparison Report
+
+USAGE:
+    snapshot-report --repo <ID> --base <SNAPSHOT> --head <SNAPSHOT> [OPTIONS]
+
+OPTIONS:
+    -d, --db <PATH>         SQLite chunk store (default: codegraph.db)
+    -r, --repo <ID>         Repository ID
+    -b, --base <SNAPSHOT>   Base snapshot ID (e.g. my-repo:main)
+        --head <SNAPSHOT>   Head snapshot ID (e.g. my-repo:pr-42)
+    -o, --out <PATH>        Output path (default: snapshot-report.html / .json)
+        --depth <N>         Reverse-dependency depth for hot zones (default: 3)
+        --json              Write JSON instead of HTML
+        --locale <LANG>     Report language: en, ko (default: $CODEGRAPH_LOCALE / $LANG, else en)
+        --ascii             Escape non-ASCII characters (HTML character references)
+
+EXAMPLES:
+    # CI: attach to the pull request as an artifact
+    snapshot-report --repo api --base api:main --head api:$GITHUB_SHA -o report.html
+"#
+    );
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse() {
+        Ok(a) => a,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let store = match SqliteChunkStore::new(&args.db) {
+        Ok(s) => CodeSnapshotStore::new(s),
+        Err(e) => {
+            eprintln!("Error: cannot open {}: {}", args.db.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let report = match runtime.block_on(SnapshotReport::build_with_options(
+        &store,
+        &args.repo_id,
+        &args.base,
+        &args.head,
+        &args.options,
+    )) {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("Report error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let content = if args.json {
+        report.to_json()
+    } else {
+        report.to_html_with(&args.report)
+    };
+    if let Err(e) = std::fs::write(&args.out, content) {
+        eprintln!("Error: cannot write {}: {}", args.out.display(), e);
+        return ExitCode::FAILURE;
+    }
+
+    println!("{}", report.summary());
+    println!("report written to {}", args.out.display());
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,639 @@
+//! Hot-reloadable service configuration
+//!
+//! Long-running service modes (gRPC/REST/MCP adapters) keep an index in
+//! memory and must pick up new analysis rules without a restart or re-index.
+//! This module holds the reloadable part of their configuration:
+//!
+//! - **Rule packs**: named bundles of taint sources/sinks/sanitizers
+//! - **Taint model**: taint analysis parameters (`TaintConfig`)
+//! - **Retrieval weights**: ranking weights used by context retrieval
+//!
+//! Configs are applied atomically and versioned. Readers take an
+//! `Arc<AppliedConfig>` snapshot, so in-flight requests finish on the config
+//! they started with while new requests see the new one. A config that fails
+//! validation is rejected and the active config stays in place.
+//!
+//! # Example
+//! ```rust,ignore
+//! let store = HotConfigStore::from_file("service.yaml")?;
+//!
+//! // Per request
+//! let active = store.current();
+//! let analyzer = active.taint_analyzer();
+//!
+//! // On SIGHUP / admin call / file watcher event
+//! store.reload()?;
+//! println!("{}", store.status().hash);
+//! ```
+//!
+//! # File format (YAML or JSON)
+//! ```yaml
+//! version: 1
+//! rule_packs:
+//!   - name: flask
+//!     sources: [{ pattern: 'request\.args', description: "Query args" }]
+//!     sinks: [{ pattern: 'cursor\.execute', description: "SQL", severity: high }]
+//!     sanitizers: [escape]
+//! taint:
+//!   max_depth: 30
+//! retrieval:
+//!   channels: { lexical: 0.6, graph: 0.4 }
+//! ```
+
+use super::error::{ConfigError, ConfigResult};
+use super::stage_configs::TaintConfig;
+use crate::features::repomap::domain::metrics::ImportanceWeights;
+use crate::features::taint_analysis::infrastructure::taint::{
+    TaintAnalyzer, TaintSeverity, TaintSink, TaintSource,
+};
+use chrono::{DateTime, Utc};
+use parking_lot::{Mutex, RwLock};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+/// Supported service config schema versions
+pub const SERVICE_CONFIG_VERSIONS: &[u32] = &[1];
+
+/// Number of applied configs kept in the status history
+const HISTORY_LIMIT: usize = 16;
+
+/// Taint source rule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceRule {
+    /// Regex matched against function/call names
+    pub pattern: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// Taint sink rule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SinkRule {
+    pub pattern: String,
+    #[serde(default)]
+    pub description: String,
+    /// "high", "medium" or "low"
+    #[serde(default = "default_severity")]
+    pub severity: String,
+}
+
+fn default_severity() -> String {
+    "high".to_string()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Named bundle of taint rules
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RulePack {
+    pub name: String,
+    /// Pack version (informational, shown in status)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub sources: Vec<SourceRule>,
+    #[serde(default)]
+    pub sinks: Vec<SinkRule>,
+    #[serde(default)]
+    pub sanitizers: Vec<String>,
+}
+
+/// Ranking weights for context retrieval
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetrievalWeights {
+    /// Per-channel fusion weights (e.g. "lexical", "symbol", "graph")
+    #[serde(default = "default_channels")]
+    pub channels: BTreeMap<String, f64>,
+    /// Reciprocal Rank Fusion constant
+    #[serde(default = "default_rrf_k")]
+    pub rrf_k: usize,
+    /// RepoMap importance weights
+    #[serde(default)]
+    pub importance: ImportanceWeights,
+}
+
+fn default_channels() -> BTreeMap<String, f64> {
+    [("lexical", 0.5), ("symbol", 0.3), ("graph", 0.2)]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v))
+        .collect()
+}
+
+fn default_rrf_k() -> usize {
+    60
+}
+
+impl Default for RetrievalWeights {
+    fn default() -> Self {
+        Self {
+            channels: default_channels(),
+            rrf_k: default_rrf_k(),
+            importance: ImportanceWeights::default(),
+        }
+    }
+}
+
+/// Reloadable service configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceConfig {
+    /// Schema version
+    pub version: u32,
+    /// Taint rule packs (none = built-in default rules)
+    #[serde(default)]
+    pub rule_packs: Vec<RulePack>,
+    /// Taint analysis parameters
+    #[serde(default)]
+    pub taint: TaintConfig,
+    /// Retrieval ranking weights
+    #[serde(default)]
+    pub retrieval: RetrievalWeights,
+}
+
+impl Default for ServiceConfig {
+    fn default() -> Self {
+        Self {
+            version: 1,
+            rule_packs: Vec::new(),
+            taint: TaintConfig::default(),
+            retrieval: RetrievalWeights::default(),
+        }
+    }
+}
+
+impl ServiceConfig {
+    /// Parse from YAML (JSON is accepted as a YAML subset)
+    pub fn from_yaml_str(content: &str) -> ConfigResult<Self> {
+        let config: Self = serde_yaml::from_str(content)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Read and parse a config file
+    pub fn from_file(path: impl AsRef<Path>) -> ConfigResult<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Self::from_yaml_str(&content)
+    }
+
+    /// Validate all sections
+    pub fn validate(&self) -> ConfigResult<()> {
+        if !SERVICE_CONFIG_VERSIONS.contains(&self.version) {
+            return Err(ConfigError::UnsupportedVersion {
+                found: self.version,
+                supported: SERVICE_CONFIG_VERSIONS.to_vec(),
+            });
+        }
+
+        let mut names = HashSet::new();
+        for pack in &self.rule_packs {
+            if pack.name.is_empty() {
+                return Err(ConfigError::Validation(
+                    "rule pack name must not be empty".into(),
+                ));
+            }
+            if !names.insert(pack.name.as_str()) {
+                return Err(ConfigError::Validation(format!(
+                    "duplicate rule pack '{}'",
+                    pack.name
+                )));
+            }
+            let patterns = pack
+                .sources
+                .iter()
+                .map(|s| &s.pattern)
+                .chain(pack.sinks.iter().map(|s| &s.pattern));
+            for pattern in patterns {
+                Regex::new(pattern).map_err(|e| {
+                    ConfigError::Validation(format!(
+                        "rule pack '{}': invalid pattern '{}': {}",
+                        pack.name, pattern, e
+                    ))
+                })?;
+            }
+            for sink in &pack.sinks {
+                parse_severity(&sink.severity).ok_or_else(|| {
+                    ConfigError::Validation(format!(
+                        "rule pack '{}': unknown severity '{}' (expected high, medium, low)",
+                        pack.name, sink.severity
+                    ))
+                })?;
+            }
+        }
+
+        self.taint.validate()?;
+
+        if self.retrieval.rrf_k == 0 {
+            return Err(ConfigError::range_with_hint(
+                "retrieval.rrf_k",
+                0,
+                1,
+                usize::MAX,
+                "RRF constant must be positive",
+            ));
+        }
+        for (channel, weight) in &self.retrieval.channels {
+            if !weight.is_finite() || *weight < 0.0 {
+                return Err(ConfigError::Validation(format!(
+                    "retrieval weight for '{}' must be a non-negative number, got {}",
+                    channel, weight
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Content hash (BLAKE3 over canonical JSON; key order independent)
+    pub fn content_hash(&self) -> String {
+        // `Value` objects are BTreeMap-backed, so keys serialize sorted
+        let canonical = serde_json::to_value(self)
+            .and_then(|v| serde_json::to_vec(&v))
+            .unwrap_or_default();
+        blake3::hash(&canonical).to_hex().to_string()
+    }
+
+    /// Build a taint analyzer from the enabled rule packs
+    ///
+    /// Without any enabled pack, the analyzer's built-in rules are used.
+    pub fn taint_analyzer(&self) -> TaintAnalyzer {
+        let enabled: Vec<&RulePack> = self.rule_packs.iter().filter(|p| p.enabled).collect();
+        if enabled.is_empty() {
+            return TaintAnalyzer::new();
+        }
+
+        let sources = enabled
+            .iter()
+            .flat_map(|p| &p.sources)
+            .map(|s| TaintSource::new(&s.pattern, &s.description))
+            .collect();
+        let sinks = enabled
+            .iter()
+            .flat_map(|p| &p.sinks)
+            .map(|s| {
+                let severity = parse_severity(&s.severity).unwrap_or(TaintSeverity::High);
+                TaintSink::new(&s.pattern, &s.description, severity)
+            })
+            .collect();
+        let sanitizers = enabled
+            .iter()
+            .flat_map(|p| p.sanitizers.iter().cloned())
+            .collect();
+
+        TaintAnalyzer::with_rules(sources, sinks, sanitizers)
+    }
+}
+
+fn parse_severity(severity: &str) -> Option<TaintSeverity> {
+    match severity.to_ascii_lowercase().as_str() {
+        "high" => Some(TaintSeverity::High),
+        "medium" => Some(TaintSeverity::Medium),
+        "low" => Some(TaintSeverity::Low),
+        _ => None,
+    }
+}
+
+/// An applied (active or historical) config
+#[derive(Debug)]
+pub struct AppliedConfig {
+    /// Monotonic application version (1 = initial config)
+    pub version: u64,
+    /// `ServiceConfig::content_hash`
+    pub hash: String,
+    /// Where the config came from (file path, "admin", "default", ...)
+    pub source: String,
+    pub applied_at: DateTime<Utc>,
+    pub config: ServiceConfig,
+    analyzer: Arc<TaintAnalyzer>,
+}
+
+impl AppliedConfig {
+    fn new(version: u64, config: ServiceConfig, source: String) -> Self {
+        Self {
+            version,
+            hash: config.content_hash(),
+            source,
+            applied_at: Utc::now(),
+            analyzer: Arc::new(config.taint_analyzer()),
+            config,
+        }
+    }
+
+    /// Taint analyzer compiled from this config's rule packs
+    pub fn taint_analyzer(&self) -> Arc<TaintAnalyzer> {
+        Arc::clone(&self.analyzer)
+    }
+
+    fn summary(&self) -> AppliedConfigSummary {
+        AppliedConfigSummary {
+            version: self.version,
+            hash: self.hash.clone(),
+            source: self.source.clone(),
+            applied_at: self.applied_at,
+        }
+    }
+}
+
+/// History entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppliedConfigSummary {
+    pub version: u64,
+    pub hash: String,
+    pub source: String,
+    pub applied_at: DateTime<Utc>,
+}
+
+/// Active config report (admin endpoint payload)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigStatus {
+    pub version: u64,
+    pub hash: String,
+    pub source: String,
+    pub applied_at: DateTime<Utc>,
+    /// Enabled rule packs as "name" or "name@version"
+    pub rule_packs: Vec<String>,
+    /// Watched config file, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config_path: Option<PathBuf>,
+    /// Most recent first
+    pub history: Vec<AppliedConfigSummary>,
+}
+
+/// Result of an apply/reload
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum ApplyOutcome {
+    /// New config is active
+    Applied { version: u64, hash: String },
+    /// Content identical to the active config; nothing changed
+    Unchanged { version: u64, hash: String },
+}
+
+/// Versioned, atomically swappable service config
+pub struct HotConfigStore {
+    active: RwLock<Arc<AppliedConfig>>,
+    history: Mutex<Vec<AppliedConfigSummary>>,
+    path: Option<PathBuf>,
+    last_modified: Mutex<Option<SystemTime>>,
+}
+
+impl HotConfigStore {
+    /// Start with an in-memory config
+    pub fn new(config: ServiceConfig) -> ConfigResult<Self> {
+        config.validate()?;
+        let applied = AppliedConfig::new(1, config, "initial".to_string());
+        Ok(Self {
+            history: Mutex::new(vec![applied.summary()]),
+            active: RwLock::new(Arc::new(applied)),
+            path: None,
+            last_modified: Mutex::new(None),
+        })
+    }
+
+    /// Start from a config file; `reload()` re-reads the same file
+    pub fn from_file(path: impl Into<PathBuf>) -> ConfigResult<Self> {
+        let path = path.into();
+        let config = ServiceConfig::from_file(&path)?;
+        let applied = AppliedConfig::new(1, config, path.display().to_string());
+        Ok(Self {
+            history: Mutex::new(vec![applied.summary()]),
+            active: RwLock::new(Arc::new(applied)),
+            last_modified: Mutex::new(modified_time(&path)),
+            path: Some(path),
+        })
+    }
+
+    /// Snapshot of the active config
+    pub fn current(&self) -> Arc<AppliedConfig> {
+        Arc::clone(&self.active.read())
+    }
+
+    /// Active application version
+    pub fn version(&self) -> u64 {
+        self.active.read().version
+    }
+
+    /// Validate and activate a config
+    pub fn apply(
+        &self,
+        config: ServiceConfig,
+        source: impl Into<String>,
+    ) -> ConfigResult<ApplyOutcome> {
+        self.apply_inner(config, source.into(), None)
+    }
+
+    /// Activate a config only if the active version is still `expected_version`
+    ///
+    /// Lets concurrent admins detect that someone else applied a config
+    /// in between reading the status and pushing their change.
+    pub fn apply_if_version(
+        &self,
+        expected_version: u64,
+        config: ServiceConfig,
+        source: impl Into<String>,
+    ) -> ConfigResult<ApplyOutcome> {
+        self.apply_inner(config, source.into(), Some(expected_version))
+    }
+
+    /// Re-read the config file
+    pub fn reload(&self) -> ConfigResult<ApplyOutcome> {
+        let path = self
+            .path
+            .as_ref()
+            .ok_or_else(|| ConfigError::Custom("no config file to reload from".into()))?;
+        let modified = modified_time(path);
+        let config = ServiceConfig::from_file(path)?;
+        let outcome = self.apply_inner(config, path.display().to_string(), None)?;
+        *self.last_modified.lock() = modified;
+        Ok(outcome)
+    }
+
+    /// Reload only if the file's modification time changed (for polling loops)
+    pub fn reload_if_changed(&self) -> ConfigResult<Option<ApplyOutcome>> {
+        let Some(path) = self.path.as_ref() else {
+            return Ok(None);
+        };
+        if modified_time(path) == *self.last_modified.lock() {
+            return Ok(None);
+        }
+        self.reload().map(Some)
+    }
+
+    /// Report the active config
+    pub fn status(&self) -> ConfigStatus {
+        let active = self.current();
+        let mut history = self.history.lock().clone();
+        history.reverse();
+
+        ConfigStatus {
+            version: active.version,
+            hash: active.hash.clone(),
+            source: active.source.clone(),
+            applied_at: active.applied_at,
+            rule_packs: active
+                .config
+                .rule_packs
+                .iter()
+                .filter(|p| p.enabled)
+                .map(|p| match &p.version {
+                    Some(v) => format!("{}@{}", p.name, v),
+                    None => p.name.clone(),
+                })
+                .collect(),
+            config_path: self.path.clone(),
+            history,
+        }
+    }
+
+    fn apply_inner(
+        &self,
+        config: ServiceConfig,
+        source: String,
+        expected_version: Option<u64>,
+    ) -> ConfigResult<ApplyOutcome> {
+        config.validate()?;
+        let hash = config.content_hash();
+
+        let mut active = self.active.write();
+        if let Some(expected) = expected_version {
+            if active.version != expected {
+                return Err(ConfigError::Custom(format!(
+                    "config version conflict: expected {}, active is {}",
+                    expected, active.version
+                )));
+            }
+        }
+        if active.hash == hash {
+            return Ok(ApplyOutcome::Unchanged {
+                version: active.version,
+                hash,
+            });
+        }
+
+        let applied = AppliedConfig::new(active.version + 1, config, source);
+        let outcome = ApplyOutcome::Applied {
+            version: applied.version,
+            hash: applied.hash.clone(),
+        };
+
+        let mut history = self.history.lock();
+        history.push(applied.summary());
+        if history.len() > HISTORY_LIMIT {
+            history.remove(0);
+        }
+        *active = Arc::new(applied);
+
+        Ok(outcome)
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const PACK: &str = r#"
+version: 1
+rule_packs:
+  - name: flask
+    version: "2"
+    sources:
+      - pattern: 'request\.args'
+        description: Query args
+    sinks:
+      - pattern: 'cursor\.execute'
+        description: SQL
+        severity: high
+    sanitizers: [escape]
+retrieval:
+  channels: { lexical: 0.7, graph: 0.3 }
+"#;
+
+    #[test]
+    fn test_parse_and_hash_stability() {
+        let a = ServiceConfig::from_yaml_str(PACK).unwrap();
+        let b = ServiceConfig::from_yaml_str(PACK).unwrap();
+        assert_eq!(a.content_hash(), b.content_hash());
+        assert_ne!(a.content_hash(), ServiceConfig::default().content_hash());
+        assert_eq!(a.retrieval.channels["lexical"], 0.7);
+    }
+
+    #[test]
+    fn test_invalid_config_rejected() {
+        let bad_regex = PACK.replace(r"cursor\.execute", "cursor(");
+        assert!(ServiceConfig::from_yaml_str(&bad_regex).is_err());
+
+        let bad_severity = PACK.replace("severity: high", "severity: urgent");
+        assert!(ServiceConfig::from_yaml_str(&bad_severity).is_err());
+
+        let bad_version = PACK.replace("version: 1", "version: 7");
+        assert!(matches!(
+            ServiceConfig::from_yaml_str(&bad_version),
+            Err(ConfigError::UnsupportedVersion { found: 7, .. })
+        ));
+    }
+
+    #[test]
+    fn test_versioned_apply() {
+        let store = HotConfigStore::new(ServiceConfig::default()).unwrap();
+        let before = store.current();
+        assert_eq!(before.version, 1);
+
+        let config = ServiceConfig::from_yaml_str(PACK).unwrap();
+        let outcome = store.apply(config.clone(), "admin").unwrap();
+        assert!(matches!(outcome, ApplyOutcome::Applied { version: 2, .. }));
+
+        // Snapshots taken before the swap keep the old config
+        assert_eq!(before.version, 1);
+        assert_eq!(store.version(), 2);
+
+        // Same content is a no-op
+        let outcome = store.apply(config.clone(), "admin").unwrap();
+        assert!(matches!(
+            outcome,
+            ApplyOutcome::Unchanged { version: 2, .. }
+        ));
+
+        // Stale expected version is rejected
+        assert!(store
+            .apply_if_version(1, ServiceConfig::default(), "admin")
+            .is_err());
+        assert_eq!(store.version(), 2);
+
+        let status = store.status();
+        assert_eq!(status.rule_packs, vec!["flask@2".to_string()]);
+        assert_eq!(status.history.len(), 2);
+        assert_eq!(status.history[0].version, 2);
+    }
+
+    #[test]
+    fn test_reload_from_file_keeps_active_on_error() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("service.yaml");
+        std::fs::write(&path, "version: 1\n").unwrap();
+
+        let store = HotConfigStore::from_file(&path).unwrap();
+        let initial_hash = store.status().hash;
+
+        std::fs::write(&path, PACK).unwrap();
+        assert!(matches!(
+            store.reload().unwrap(),
+            ApplyOutcome::Applied { version: 2, .. }
+        ));
+
+        std::fs::write(&path, "version: 1\ntaint:\n  max_depth: 0\n").unwrap();
+        assert!(store.reload().is_err());
+        assert_eq!(store.version(), 2);
+        assert_ne!(store.status().hash, initial_hash);
+    }
+}
@@ -3,7 +3,10 @@
 //! Defines YAML schema types. Implementation methods are in pipeline_config.rs
 //! to avoid field visibility issues.
 
-use super::{pipeline_config::StageControl, stage_configs::*, CacheConfig, PageRankConfig};
+use super::{
+    pipeline_config::StageControl, stage_configs::*, ArchitectureConfig, CacheConfig,
+    DeadCodeConfig, PageRankConfig, SarifConfig,
+};
 use serde::{Deserialize, Serialize};
 
 /// YAML Schema v1
@@ -55,6 +58,24 @@ pub struct ConfigOverrides {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub heap: Option<HeapConfig>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<MemoryConfig>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discovery: Option<DiscoveryConfig>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call_graph: Option<CallGraphConfig>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sarif: Option<SarifConfig>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub architecture: Option<ArchitectureConfig>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dead_code: Option<DeadCodeConfig>,
 }
 
 #[cfg(test)]
@@ -105,6 +126,125 @@ overrides:
         assert_eq!(taint.max_paths, 1000);
     }
 
+    #[test]
+    fn test_yaml_sarif_output() {
+        let yaml_content = r#"
+version: 1
+preset: balanced
+overrides:
+  sarif:
+    enabled: true
+    output_path: reports/codegraph.sarif
+"#;
+        let config = PipelineConfig::from_yaml_str(yaml_content, "inline").unwrap();
+        let sarif = config.sarif().unwrap();
+        assert_eq!(
+            sarif.output_path.as_deref(),
+            Some("reports/codegraph.sarif")
+        );
+        assert!(sarif.include_code_flows);
+        assert!(config.to_yaml().unwrap().contains("sarif:"));
+
+        // Not selected unless enabled
+        let config = PipelineConfig::preset(Preset::Balanced).build().unwrap();
+        assert!(config.sarif().is_none());
+        let config = PipelineConfig::preset(Preset::Balanced)
+            .sarif(|c| c.include_sanitized(true))
+            .build()
+            .unwrap();
+        assert!(config.sarif().unwrap().include_sanitized);
+    }
+
+    #[test]
+    fn test_yaml_architecture_rules() {
+        let yaml_content = r#"
+version: 1
+preset: balanced
+overrides:
+  architecture:
+    enabled: true
+    rules:
+      version: 1
+      layers:
+        - name: ui
+          paths: ["src/ui/**"]
+        - name: storage
+          paths: ["src/storage/**"]
+      rules:
+        - name: ui-not-storage
+          from: ui
+          deny: [storage]
+"#;
+        let config = PipelineConfig::from_yaml_str(yaml_content, "inline").unwrap();
+        let architecture = config.architecture().unwrap();
+        assert_eq!(architecture.rules.unwrap().rules[0].name, "ui-not-storage");
+        assert!(config.to_yaml().unwrap().contains("architecture:"));
+
+        // Enabled without rules is rejected
+        let result = PipelineConfig::preset(Preset::Balanced)
+            .architecture(|c| c)
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_yaml_memory_budget() {
+        let yaml_content = r#"
+version: 1
+preset: balanced
+overrides:
+  memory:
+    max_memory_mb: 2048
+    spill_dir: /tmp/codegraph-spill
+"#;
+        let config = PipelineConfig::from_yaml_str(yaml_content, "inline").unwrap();
+        let memory = config.memory().unwrap();
+        assert_eq!(memory.max_memory_mb, 2048);
+        assert_eq!(memory.batch_size_mb, 64);
+        assert_eq!(memory.spill_dir.as_deref(), Some("/tmp/codegraph-spill"));
+        assert!(config.to_yaml().unwrap().contains("max_memory_mb: 2048"));
+
+        // Unlimited unless a budget is set
+        let config = PipelineConfig::preset(Preset::Balanced).build().unwrap();
+        assert!(config.memory().is_none());
+
+        let yaml_content = r#"
+version: 1
+preset: balanced
+overrides:
+  memory:
+    max_memory_mb: 8
+"#;
+        assert!(PipelineConfig::from_yaml_str(yaml_content, "inline").is_err());
+    }
+
+    #[test]
+    fn test_yaml_call_graph_context_depth() {
+        let yaml_content = r#"
+version: 1
+preset: balanced
+overrides:
+  call_graph:
+    context_depth: 2
+"#;
+        let config = PipelineConfig::from_yaml_str(yaml_content, "inline").unwrap();
+        let call_graph = config.call_graph().unwrap();
+        assert_eq!(call_graph.context_depth, 2);
+        assert_eq!(call_graph.max_contexts_per_function, 64);
+        assert!(config.to_yaml().unwrap().contains("context_depth: 2"));
+
+        // Context-insensitive unless selected (or Thorough)
+        let config = PipelineConfig::preset(Preset::Balanced).build().unwrap();
+        assert!(config.call_graph().is_none());
+        let config = PipelineConfig::preset(Preset::Thorough).build().unwrap();
+        assert_eq!(config.call_graph().unwrap().context_depth, 1);
+        let config = PipelineConfig::preset(Preset::Thorough)
+            .call_graph(|c| c.context_depth(0))
+            .build()
+            .unwrap();
+        assert!(config.call_graph().is_none());
+    }
+
     #[test]
     fn test_yaml_missing_version() {
         let yaml_content = r#"
@@ -35,6 +35,7 @@
 //! - **IDE Support**: JSON Schema for autocomplete
 
 pub mod error;
+pub mod hot_reload;
 pub mod io;
 pub mod patch;
 pub mod performance;
@@ -46,6 +47,9 @@ pub mod validation;
 
 // Re-exports
 pub use error::{ConfigError, ConfigResult};
+pub use hot_reload::{
+    ApplyOutcome, ConfigStatus, HotConfigStore, RetrievalWeights, RulePack, ServiceConfig,
+};
 pub use io::{ConfigExportV1, ConfigOverrides};
 pub use patch::{
     ChunkingConfigPatch,
@@ -63,15 +67,20 @@ pub use pipeline_config::{PipelineConfig, StageControl, ValidatedConfig};
 pub use preset::Preset;
 pub use provenance::{ConfigProvenance, ConfigSource};
 pub use stage_configs::{
+    CallGraphConfig, // Call graph construction (k-CFA context depth)
     ChunkingConfig,
     CloneConfig,
     CloneType,
+    DiscoveryConfig, // Repository file discovery (.gitignore, globs, size/binary)
     HeapConfig, // L7: Heap Analysis config (memory safety, ownership, escape)
     LexicalConfig,
+    MemoryConfig, // Memory budget (batched L1, spill-to-disk)
     PDGConfig,
     PTAConfig,
     PTAMode,
     ParallelConfig,
+    SliceCriterion,
+    SliceKind,
     SlicingConfig, // L17-L18 configs
     TaintConfig,
     Type1Config,
@@ -89,3 +98,12 @@ pub type CacheConfig = crate::features::cache::config::TieredCacheConfig;
 
 // PageRank config (re-enabled after adding Serialize/Deserialize)
 pub type PageRankConfig = crate::features::repomap::infrastructure::PageRankSettings;
+
+// SARIF output config (report format, not a stage)
+pub type SarifConfig = crate::features::sarif::SarifConfig;
+
+// Architecture rules config (report, not a stage)
+pub type ArchitectureConfig = crate::features::architecture::ArchitectureConfig;
+
+// Dead code report config (report, not a stage)
+pub type DeadCodeConfig = crate::features::query_engine::DeadCodeConfig;
@@ -217,6 +217,8 @@ pub struct LexicalConfigPatch {
     pub enable_ngram: Option<bool>,
     pub ngram_size: Option<usize>,
     pub enable_stemming: Option<bool>,
+    pub split_identifiers: Option<bool>,
+    pub stop_symbol_languages: Option<Vec<String>>,
 }
 
 impl PipelineConfig {
@@ -242,6 +244,12 @@ impl PipelineConfig {
         if let Some(v) = patch.enable_stemming {
             base.enable_stemming = v;
         }
+        if let Some(v) = patch.split_identifiers {
+            base.split_identifiers = v;
+        }
+        if let Some(v) = patch.stop_symbol_languages {
+            base.stop_symbol_languages = v;
+        }
 
         self.lexical = Some(base);
         self.provenance
@@ -12,8 +12,11 @@ use super::{
 use serde::{Deserialize, Serialize};
 
 // Optional imports (conditionally compiled)
+use super::ArchitectureConfig;
 use super::CacheConfig;
+use super::DeadCodeConfig;
 use super::PageRankConfig;
+use super::SarifConfig;
 
 /// Stage identifier for L1-L37 pipeline stages
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -87,6 +90,14 @@ pub struct StageControl {
     // L19: Slicing (backward, forward, thin, chop)
     #[serde(default)]
     pub slicing: bool,
+
+    // L2.6: Vector index (chunk embeddings + HNSW, default off - embedding cost)
+    #[serde(default)]
+    pub vector: bool,
+
+    // L33: Per-symbol git blame / churn (default off - blames every file, `git` feature)
+    #[serde(default)]
+    pub git_blame: bool,
 }
 
 fn default_true() -> bool {
@@ -121,6 +132,12 @@ impl Default for StageControl {
             pdg: false,
             concurrency: false,
             slicing: false,
+
+            // L2.6: Vector index
+            vector: false,
+
+            // L33: Git blame / churn
+            git_blame: false,
         }
     }
 }
@@ -145,6 +162,8 @@ impl StageControl {
             pdg: true,
             concurrency: true,
             slicing: true,
+            vector: true,
+            git_blame: true,
         }
     }
 
@@ -167,6 +186,8 @@ impl StageControl {
             pdg: true,         // Program Dependence Graph for slicing
             concurrency: true, // Race condition detection
             slicing: true,     // Thin slicing for bug localization
+            vector: false,
+            git_blame: false,
         }
     }
 
@@ -248,6 +269,24 @@ pub struct PipelineConfig {
     pub(crate) pdg: Option<PDGConfig>,
     pub(crate) slicing: Option<SlicingConfig>,
 
+    /// Report output (SARIF)
+    pub(crate) sarif: Option<SarifConfig>,
+
+    /// Architecture rules (layer/dependency constraints)
+    pub(crate) architecture: Option<ArchitectureConfig>,
+
+    /// Dead code report (unreferenced functions/classes)
+    pub(crate) dead_code: Option<DeadCodeConfig>,
+
+    /// Memory budget (batched L1, spill-to-disk)
+    pub(crate) memory: Option<MemoryConfig>,
+
+    /// Repository file discovery
+    pub(crate) discovery: Option<DiscoveryConfig>,
+
+    /// Call graph construction (k-CFA)
+    pub(crate) call_graph: Option<CallGraphConfig>,
+
     /// Provenance tracking (field-level)
     pub(crate) provenance: ConfigProvenance,
 }
@@ -270,6 +309,12 @@ impl PipelineConfig {
             heap: None,
             pdg: None,
             slicing: None,
+            sarif: None,
+            architecture: None,
+            dead_code: None,
+            memory: None,
+            discovery: None,
+            call_graph: None,
             provenance: ConfigProvenance::from_preset(preset),
         }
     }
@@ -434,6 +479,117 @@ impl PipelineConfig {
         self
     }
 
+    /// Level 2: Enable SARIF output
+    ///
+    /// # Example
+    /// ```ignore
+    /// let config = PipelineConfig::preset(Preset::Balanced)
+    ///     .sarif(|c| c.output_path("reports/codegraph.sarif"))
+    ///     .build()?;
+    /// ```
+    pub fn sarif<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(SarifConfig) -> SarifConfig,
+    {
+        self.sarif = Some(f(SarifConfig::enabled()));
+        self.provenance
+            .track_field("sarif.*", ConfigSource::Builder);
+        self
+    }
+
+    /// Level 2: Check architecture rules
+    ///
+    /// # Example
+    /// ```ignore
+    /// let config = PipelineConfig::preset(Preset::Balanced)
+    ///     .architecture(|c| c.rules_path(".codegraph/architecture.toml"))
+    ///     .build()?;
+    /// ```
+    pub fn architecture<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(ArchitectureConfig) -> ArchitectureConfig,
+    {
+        self.architecture = Some(f(ArchitectureConfig::enabled()));
+        self.provenance
+            .track_field("architecture.*", ConfigSource::Builder);
+        self
+    }
+
+    /// Level 2: Report dead code
+    ///
+    /// # Example
+    /// ```ignore
+    /// let config = PipelineConfig::preset(Preset::Balanced)
+    ///     .dead_code(|c| c.entry_pattern("cli.*"))
+    ///     .build()?;
+    /// ```
+    pub fn dead_code<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(DeadCodeConfig) -> DeadCodeConfig,
+    {
+        self.dead_code = Some(f(DeadCodeConfig::enabled()));
+        self.provenance
+            .track_field("dead_code.*", ConfigSource::Builder);
+        self
+    }
+
+    /// Level 2: Set a memory budget
+    ///
+    /// # Example
+    /// ```ignore
+    /// let config = PipelineConfig::preset(Preset::Balanced)
+    ///     .memory(|c| c.max_memory_mb(8192).spill_dir("/scratch/codegraph"))
+    ///     .build()?;
+    /// ```
+    pub fn memory<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(MemoryConfig) -> MemoryConfig,
+    {
+        let base = MemoryConfig::from_preset(self.preset);
+        self.memory = Some(f(base));
+        self.provenance
+            .track_field("memory.*", ConfigSource::Builder);
+        self
+    }
+
+    /// Level 2: Override file discovery
+    ///
+    /// # Example
+    /// ```ignore
+    /// let config = PipelineConfig::preset(Preset::Balanced)
+    ///     .discovery(|c| c.include("services/**").exclude("**/*_pb2.py"))
+    ///     .build()?;
+    /// ```
+    pub fn discovery<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(DiscoveryConfig) -> DiscoveryConfig,
+    {
+        let base = DiscoveryConfig::from_preset(self.preset);
+        self.discovery = Some(f(base));
+        self.provenance
+            .track_field("discovery.*", ConfigSource::Builder);
+        self
+    }
+
+    /// Level 2: Override call graph construction
+    ///
+    /// # Example
+    /// ```ignore
+    /// let config = PipelineConfig::preset(Preset::Balanced)
+    ///     .call_graph(|c| c.context_depth(2).max_contexts_per_function(128))
+    ///     .build()?;
+    /// ```
+    pub fn call_graph<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(CallGraphConfig) -> CallGraphConfig,
+    {
+        let base = CallGraphConfig::from_preset(self.preset);
+        self.call_graph = Some(f(base));
+        self.provenance
+            .track_field("call_graph.*", ConfigSource::Builder);
+        self
+    }
+
     /// Build and validate
     pub fn build(self) -> ConfigResult<ValidatedConfig> {
         // Step 1: Validate individual stage configs
@@ -464,6 +620,27 @@ impl PipelineConfig {
         if let Some(ref cfg) = self.heap {
             cfg.validate()?;
         }
+        if let Some(ref cfg) = self.sarif {
+            cfg.validate()?;
+        }
+        if let Some(ref cfg) = self.architecture {
+            cfg.validate()?;
+        }
+        if let Some(ref cfg) = self.dead_code {
+            cfg.validate()?;
+        }
+        if let Some(ref cfg) = self.memory {
+            cfg.validate()?;
+        }
+        if let Some(ref cfg) = self.discovery {
+            cfg.validate()?;
+        }
+        if let Some(ref cfg) = self.call_graph {
+            cfg.validate()?;
+        }
+        if let Some(ref cfg) = self.pagerank {
+            cfg.validate()?;
+        }
 
         // Step 2: Check StageControl consistency
         self.validate_stage_control()?;
@@ -629,10 +806,17 @@ impl PipelineConfig {
 
     /// Load from YAML file (v1 schema)
     pub fn from_yaml(path: &str) -> ConfigResult<ValidatedConfig> {
+        let content = std::fs::read_to_string(path)?;
+        Self::from_yaml_str(&content, path)
+    }
+
+    /// Load from YAML content (v1 schema)
+    ///
+    /// `path` is only used for provenance (e.g. the replay bundle the config came from).
+    pub fn from_yaml_str(content: &str, path: &str) -> ConfigResult<ValidatedConfig> {
         use crate::config::io::ConfigExportV1;
 
-        let content = std::fs::read_to_string(path)?;
-        let export: ConfigExportV1 = serde_yaml::from_str(&content)?;
+        let export: ConfigExportV1 = serde_yaml::from_str(content)?;
 
         // Version check
         if export.version != 1 {
@@ -736,6 +920,60 @@ impl PipelineConfig {
                     },
                 );
             }
+            if let Some(sarif) = overrides.sarif {
+                config.sarif = Some(sarif);
+                config.provenance.track_field(
+                    "sarif.*",
+                    ConfigSource::Yaml {
+                        path: path.to_string(),
+                    },
+                );
+            }
+            if let Some(architecture) = overrides.architecture {
+                config.architecture = Some(architecture);
+                config.provenance.track_field(
+                    "architecture.*",
+                    ConfigSource::Yaml {
+                        path: path.to_string(),
+                    },
+                );
+            }
+            if let Some(dead_code) = overrides.dead_code {
+                config.dead_code = Some(dead_code);
+                config.provenance.track_field(
+                    "dead_code.*",
+                    ConfigSource::Yaml {
+                        path: path.to_string(),
+                    },
+                );
+            }
+            if let Some(memory) = overrides.memory {
+                config.memory = Some(memory);
+                config.provenance.track_field(
+                    "memory.*",
+                    ConfigSource::Yaml {
+                        path: path.to_string(),
+                    },
+                );
+            }
+            if let Some(discovery) = overrides.discovery {
+                config.discovery = Some(discovery);
+                config.provenance.track_field(
+                    "discovery.*",
+                    ConfigSource::Yaml {
+                        path: path.to_string(),
+                    },
+                );
+            }
+            if let Some(call_graph) = overrides.call_graph {
+                config.call_graph = Some(call_graph);
+                config.provenance.track_field(
+                    "call_graph.*",
+                    ConfigSource::Yaml {
+                        path: path.to_string(),
+                    },
+                );
+            }
         }
 
         config.build()
@@ -759,6 +997,12 @@ impl PipelineConfig {
                 parallel: self.parallel.clone(),
                 cache: self.cache.clone(),
                 heap: self.heap.clone(),
+                sarif: self.sarif.clone(),
+                architecture: self.architecture.clone(),
+                dead_code: self.dead_code.clone(),
+                memory: self.memory.clone(),
+                discovery: self.discovery.clone(),
+                call_graph: self.call_graph.clone(),
             }),
         };
 
@@ -952,6 +1196,44 @@ impl ValidatedConfig {
         )
     }
 
+    /// Get SARIF output config (None unless enabled)
+    pub fn sarif(&self) -> Option<SarifConfig> {
+        self.0.sarif.clone().filter(|c| c.enabled)
+    }
+
+    /// Get architecture rules config (None unless enabled)
+    pub fn architecture(&self) -> Option<ArchitectureConfig> {
+        self.0.architecture.clone().filter(|c| c.enabled)
+    }
+
+    /// Get dead code report config (None unless enabled)
+    pub fn dead_code(&self) -> Option<DeadCodeConfig> {
+        self.0.dead_code.clone().filter(|c| c.enabled)
+    }
+
+    /// Get memory budget config (None unless a budget is set)
+    pub fn memory(&self) -> Option<MemoryConfig> {
+        self.0.memory.clone().filter(|c| c.is_limited())
+    }
+
+    /// Get effective file discovery config
+    pub fn discovery(&self) -> DiscoveryConfig {
+        self.0
+            .discovery
+            .clone()
+            .unwrap_or_else(|| DiscoveryConfig::from_preset(self.0.preset))
+    }
+
+    /// Get call graph config (None unless context-sensitive)
+    pub fn call_graph(&self) -> Option<CallGraphConfig> {
+        let config = self
+            .0
+            .call_graph
+            .clone()
+            .unwrap_or_else(|| CallGraphConfig::from_preset(self.0.preset));
+        config.is_context_sensitive().then_some(config)
+    }
+
     /// Get stage control
     pub fn stages(&self) -> &StageControl {
         &self.0.stages
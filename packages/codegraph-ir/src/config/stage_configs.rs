@@ -10,6 +10,7 @@
 use super::error::{ConfigError, ConfigResult};
 use super::preset::Preset;
 use super::validation::Validatable;
+use super::PageRankConfig;
 use serde::{Deserialize, Serialize};
 
 // ============================================================================
@@ -32,6 +33,12 @@ pub struct TaintConfig {
     /// Enable field-sensitive tracking
     pub field_sensitive: bool,
 
+    /// Maximum access-path length when field-sensitive (1..=16)
+    ///
+    /// Longer paths are truncated: with 2, `a.b.c.d` is tracked as `a.b`.
+    #[serde(default = "default_max_field_depth")]
+    pub max_field_depth: usize,
+
     /// Enable SSA-based analysis
     pub use_ssa: bool,
 
@@ -106,11 +113,30 @@ pub struct TaintConfig {
     /// Analysis timeout in seconds (0 = unlimited)
     #[serde(default = "default_timeout")]
     pub timeout_seconds: u64,
+
+    // ========================================
+    // Rule Packs
+    // ========================================
+    /// Taint rule files or directories (YAML/JSON, see `TaintRuleSet`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rule_files: Vec<String>,
+
+    /// Keep the built-in sources/sinks/sanitizers alongside rule files
+    #[serde(default = "default_true")]
+    pub include_default_rules: bool,
+
+    /// Library summary files or directories, merged over the bundled models
+    /// (see `LibrarySummaryDb`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub library_summary_files: Vec<String>,
 }
 
 fn default_true() -> bool {
     true
 }
+fn default_max_field_depth() -> usize {
+    3
+}
 fn default_ifds_max_iterations() -> usize {
     10000
 }
@@ -155,6 +181,16 @@ impl TaintConfig {
             ));
         }
 
+        if self.field_sensitive && (self.max_field_depth == 0 || self.max_field_depth > 16) {
+            return Err(ConfigError::range_with_hint(
+                "max_field_depth",
+                self.max_field_depth,
+                1,
+                16,
+                "Field-sensitive access paths need at least one field",
+            ));
+        }
+
         if self.worklist_max_iterations == 0 || self.worklist_max_iterations > 10000 {
             return Err(ConfigError::range_with_hint(
                 "worklist_max_iterations",
@@ -201,6 +237,26 @@ impl TaintConfig {
             ));
         }
 
+        if !self.include_default_rules && self.rule_files.is_empty() {
+            return Err(ConfigError::Validation(
+                "include_default_rules is false but no rule_files are configured".to_string(),
+            ));
+        }
+        if self.rule_files.iter().any(|p| p.trim().is_empty()) {
+            return Err(ConfigError::Validation(
+                "rule_files must not contain empty paths".to_string(),
+            ));
+        }
+        if self
+            .library_summary_files
+            .iter()
+            .any(|p| p.trim().is_empty())
+        {
+            return Err(ConfigError::Validation(
+                "library_summary_files must not contain empty paths".to_string(),
+            ));
+        }
+
         // Implicit flow with IFDS is recommended
         if self.implicit_flow_enabled && !self.ifds_enabled {
             // This is a warning-level issue, not an error - allow but log
@@ -234,6 +290,12 @@ impl TaintConfig {
         self
     }
 
+    /// Builder: Set max_field_depth
+    pub fn max_field_depth(mut self, v: usize) -> Self {
+        self.max_field_depth = v;
+        self
+    }
+
     /// Builder: Set use_ssa
     pub fn use_ssa(mut self, v: bool) -> Self {
         self.use_ssa = v;
@@ -344,6 +406,24 @@ impl TaintConfig {
         self
     }
 
+    /// Builder: Add a taint rule file or directory
+    pub fn rule_file(mut self, path: impl Into<String>) -> Self {
+        self.rule_files.push(path.into());
+        self
+    }
+
+    /// Builder: Keep/drop the built-in taint rules
+    pub fn include_default_rules(mut self, v: bool) -> Self {
+        self.include_default_rules = v;
+        self
+    }
+
+    /// Builder: Add a library summary file or directory
+    pub fn library_summary_file(mut self, path: impl Into<String>) -> Self {
+        self.library_summary_files.push(path.into());
+        self
+    }
+
     /// Get preset configuration
     pub fn from_preset(preset: Preset) -> Self {
         match preset {
@@ -352,6 +432,7 @@ impl TaintConfig {
                 max_paths: 100,
                 use_points_to: false, // Skip for speed
                 field_sensitive: false,
+                max_field_depth: 3,
                 use_ssa: false,
                 detect_sanitizers: false,
                 enable_interprocedural: false,
@@ -371,12 +452,16 @@ impl TaintConfig {
                 context_sensitive: false,
                 path_sensitive: false,
                 timeout_seconds: 5,
+                rule_files: Vec::new(),
+                include_default_rules: true,
+                library_summary_files: Vec::new(),
             },
             Preset::Balanced => Self {
                 max_depth: 30,
                 max_paths: 500,
                 use_points_to: true,
                 field_sensitive: true,
+                max_field_depth: 3,
                 use_ssa: true,
                 detect_sanitizers: true,
                 enable_interprocedural: true,
@@ -396,12 +481,16 @@ impl TaintConfig {
                 context_sensitive: true,
                 path_sensitive: false,
                 timeout_seconds: 60,
+                rule_files: Vec::new(),
+                include_default_rules: true,
+                library_summary_files: Vec::new(),
             },
             Preset::Thorough => Self {
                 max_depth: 100,
                 max_paths: 5000,
                 use_points_to: true,
                 field_sensitive: true,
+                max_field_depth: 5,
                 use_ssa: true,
                 detect_sanitizers: true,
                 enable_interprocedural: true,
@@ -421,6 +510,9 @@ impl TaintConfig {
                 context_sensitive: true,
                 path_sensitive: true,
                 timeout_seconds: 300, // 5 minutes for full analysis
+                rule_files: Vec::new(),
+                include_default_rules: true,
+                library_summary_files: Vec::new(),
             },
             Preset::Custom => Self::default(),
         }
@@ -438,6 +530,17 @@ impl Default for TaintConfig {
 // ============================================================================
 
 /// PTA algorithm mode
+///
+/// Precision/performance, cheapest first:
+/// - `Fast`: near-linear; one alias class per unified group, coarsest
+/// - `Precise`: Andersen, roughly quadratic; ignores which call a value came from
+/// - `CallSiteSensitive`: 1-CFA; separates helpers called from different
+///   places, each method analyzed once per call site
+/// - `ObjectSensitive`: 1-object; separates methods of different instances
+///   of a class (setters/getters, containers), each method analyzed once per
+///   receiver allocation site. Best fit for OO-heavy Python/Java
+///
+/// Context-sensitive modes typically cost 2–10× `Precise` in time and memory.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum PTAMode {
@@ -449,6 +552,12 @@ pub enum PTAMode {
     Hybrid,
     /// Auto: Choose based on code size
     Auto,
+    /// Andersen with 1-call-site contexts (1-CFA)
+    #[serde(rename = "call_site_sensitive")]
+    CallSiteSensitive,
+    /// Andersen with 1-object contexts (receiver allocation site)
+    #[serde(rename = "object_sensitive")]
+    ObjectSensitive,
 }
 
 /// L6: Points-to Analysis Configuration
@@ -1013,6 +1122,12 @@ pub struct LexicalConfig {
 
     /// Enable stemming
     pub enable_stemming: bool,
+
+    /// Split identifiers into words (`getUserById` → get, user, by, id)
+    pub split_identifiers: bool,
+
+    /// Languages whose stop symbols (`def`, `self`, `return`, ...) are not indexed
+    pub stop_symbol_languages: Vec<String>,
 }
 
 impl LexicalConfig {
@@ -1048,6 +1163,17 @@ impl LexicalConfig {
             ));
         }
 
+        if let Some(language) = self
+            .stop_symbol_languages
+            .iter()
+            .find(|l| crate::features::lexical::tokenizer::stop_symbols(l).is_none())
+        {
+            return Err(ConfigError::Validation(format!(
+                "stop_symbol_languages: no stop symbols for language '{}'",
+                language
+            )));
+        }
+
         Ok(())
     }
 
@@ -1061,6 +1187,8 @@ impl LexicalConfig {
                 enable_ngram: false,
                 ngram_size: 3,
                 enable_stemming: false,
+                split_identifiers: true,
+                stop_symbol_languages: Vec::new(),
             },
             Preset::Balanced => Self {
                 enable_fuzzy: true,
@@ -1069,6 +1197,8 @@ impl LexicalConfig {
                 enable_ngram: true,
                 ngram_size: 3,
                 enable_stemming: false,
+                split_identifiers: true,
+                stop_symbol_languages: Vec::new(),
             },
             Preset::Thorough => Self {
                 enable_fuzzy: true,
@@ -1077,6 +1207,8 @@ impl LexicalConfig {
                 enable_ngram: true,
                 ngram_size: 3,
                 enable_stemming: true,
+                split_identifiers: true,
+                stop_symbol_languages: Vec::new(),
             },
             Preset::Custom => Self::default(),
         }
@@ -1178,6 +1310,347 @@ impl Default for ParallelConfig {
     }
 }
 
+// ============================================================================
+// Memory Budget Configuration
+// ============================================================================
+
+/// Memory budget for huge repositories
+///
+/// With a budget set, L1 parses files in batches of at most `batch_size_mb`
+/// source bytes. Once resident memory reaches `spill_threshold_percent` of
+/// the budget, intermediate per-file results the DAG stages do not read are
+/// spilled to `spill_dir` until final aggregation, and later batches shrink.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MemoryConfig {
+    /// Memory budget in MB (0=unlimited, otherwise 64..=1048576)
+    pub max_memory_mb: usize,
+
+    /// Source bytes per L1 batch in MB (1..=4096)
+    pub batch_size_mb: usize,
+
+    /// Spill once resident memory reaches this share of the budget (10..=100)
+    pub spill_threshold_percent: usize,
+
+    /// Spill directory (None = system temp dir)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spill_dir: Option<String>,
+}
+
+impl MemoryConfig {
+    /// Validate configuration
+    pub fn validate(&self) -> ConfigResult<()> {
+        if self.max_memory_mb != 0 && !(64..=1_048_576).contains(&self.max_memory_mb) {
+            return Err(ConfigError::range_with_hint(
+                "max_memory_mb",
+                self.max_memory_mb,
+                64,
+                1_048_576,
+                "Memory budget must be reasonable (0=unlimited)",
+            ));
+        }
+
+        if self.batch_size_mb < 1 || self.batch_size_mb > 4096 {
+            return Err(ConfigError::range_with_hint(
+                "batch_size_mb",
+                self.batch_size_mb,
+                1,
+                4096,
+                "Batch size must be reasonable",
+            ));
+        }
+
+        if self.spill_threshold_percent < 10 || self.spill_threshold_percent > 100 {
+            return Err(ConfigError::range_with_hint(
+                "spill_threshold_percent",
+                self.spill_threshold_percent,
+                10,
+                100,
+                "Spill threshold is a percentage of max_memory_mb",
+            ));
+        }
+
+        if matches!(&self.spill_dir, Some(dir) if dir.trim().is_empty()) {
+            return Err(ConfigError::Validation(
+                "memory.spill_dir must not be empty (omit it to use the temp dir)".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Get preset configuration (unlimited for every preset)
+    pub fn from_preset(preset: Preset) -> Self {
+        match preset {
+            Preset::Fast | Preset::Balanced | Preset::Custom => Self {
+                max_memory_mb: 0,
+                batch_size_mb: 64,
+                spill_threshold_percent: 80,
+                spill_dir: None,
+            },
+            Preset::Thorough => Self {
+                max_memory_mb: 0,
+                batch_size_mb: 32,
+                spill_threshold_percent: 80,
+                spill_dir: None,
+            },
+        }
+    }
+
+    /// Whether a budget is set
+    pub fn is_limited(&self) -> bool {
+        self.max_memory_mb > 0
+    }
+
+    /// Builder: Set max_memory_mb
+    pub fn max_memory_mb(mut self, v: usize) -> Self {
+        self.max_memory_mb = v;
+        self
+    }
+
+    /// Builder: Set batch_size_mb
+    pub fn batch_size_mb(mut self, v: usize) -> Self {
+        self.batch_size_mb = v;
+        self
+    }
+
+    /// Builder: Set spill_threshold_percent
+    pub fn spill_threshold_percent(mut self, v: usize) -> Self {
+        self.spill_threshold_percent = v;
+        self
+    }
+
+    /// Builder: Set spill_dir
+    pub fn spill_dir(mut self, dir: impl Into<String>) -> Self {
+        self.spill_dir = Some(dir.into());
+        self
+    }
+}
+
+impl Default for MemoryConfig {
+    fn default() -> Self {
+        Self::from_preset(Preset::Balanced)
+    }
+}
+
+// ============================================================================
+// File Discovery Configuration
+// ============================================================================
+
+fn default_excludes() -> Vec<String> {
+    [
+        "node_modules/",
+        "target/",
+        "__pycache__/",
+        "venv/",
+        ".venv/",
+        "vendor/",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}
+
+/// Repository file discovery (full scans only)
+///
+/// Globs use `.gitignore` syntax relative to the repository root
+/// (`vendor/`, `**/*.generated.py`, `/docs`). Excludes win over includes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DiscoveryConfig {
+    /// Honor `.gitignore`, `.git/info/exclude` and `.ignore` files
+    pub respect_gitignore: bool,
+
+    /// Descend into hidden files and directories
+    pub include_hidden: bool,
+
+    /// Skip files containing NUL bytes in their first 8 KB
+    pub skip_binary: bool,
+
+    /// Skip files larger than this in KB (0=unlimited, otherwise 1..=1048576)
+    pub max_file_size_kb: usize,
+
+    /// Only index files matching one of these globs (empty = all)
+    pub include: Vec<String>,
+
+    /// Never index files matching these globs
+    pub exclude: Vec<String>,
+}
+
+impl DiscoveryConfig {
+    /// Validate configuration
+    pub fn validate(&self) -> ConfigResult<()> {
+        if self.max_file_size_kb > 1_048_576 {
+            return Err(ConfigError::range_with_hint(
+                "max_file_size_kb",
+                self.max_file_size_kb,
+                0,
+                1_048_576,
+                "File size limit must be reasonable (0=unlimited)",
+            ));
+        }
+
+        let mut globs = ignore::overrides::OverrideBuilder::new("/");
+        for glob in self.include.iter().chain(&self.exclude) {
+            if glob.trim().is_empty() || glob.starts_with('!') {
+                return Err(ConfigError::Validation(format!(
+                    "discovery glob {:?} must be non-empty and not negated (use exclude)",
+                    glob
+                )));
+            }
+            globs.add(glob).map_err(|e| {
+                ConfigError::Validation(format!("invalid discovery glob {:?}: {}", glob, e))
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Get preset configuration (same discovery for every preset)
+    pub fn from_preset(_preset: Preset) -> Self {
+        Self {
+            respect_gitignore: true,
+            include_hidden: false,
+            skip_binary: true,
+            max_file_size_kb: 1024,
+            include: Vec::new(),
+            exclude: default_excludes(),
+        }
+    }
+
+    /// Builder: Set respect_gitignore
+    pub fn respect_gitignore(mut self, v: bool) -> Self {
+        self.respect_gitignore = v;
+        self
+    }
+
+    /// Builder: Set include_hidden
+    pub fn include_hidden(mut self, v: bool) -> Self {
+        self.include_hidden = v;
+        self
+    }
+
+    /// Builder: Set skip_binary
+    pub fn skip_binary(mut self, v: bool) -> Self {
+        self.skip_binary = v;
+        self
+    }
+
+    /// Builder: Set max_file_size_kb
+    pub fn max_file_size_kb(mut self, v: usize) -> Self {
+        self.max_file_size_kb = v;
+        self
+    }
+
+    /// Builder: Add an include glob
+    pub fn include(mut self, glob: impl Into<String>) -> Self {
+        self.include.push(glob.into());
+        self
+    }
+
+    /// Builder: Add an exclude glob
+    pub fn exclude(mut self, glob: impl Into<String>) -> Self {
+        self.exclude.push(glob.into());
+        self
+    }
+
+    /// Builder: Drop the default excludes (node_modules/, target/, vendor/, ...)
+    pub fn clear_excludes(mut self) -> Self {
+        self.exclude.clear();
+        self
+    }
+}
+
+impl Default for DiscoveryConfig {
+    fn default() -> Self {
+        Self::from_preset(Preset::Balanced)
+    }
+}
+
+// ============================================================================
+// Call Graph Configuration
+// ============================================================================
+
+/// Call graph construction (cross-file stage)
+///
+/// `context_depth` > 0 additionally builds a k-CFA call graph: functions are
+/// analyzed per call string of the last `context_depth` call sites, so
+/// calls through parameters resolve per caller. Functions exceeding
+/// `max_contexts_per_function` fall back to the context-insensitive result.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CallGraphConfig {
+    /// Call sites per context (0=context-insensitive, otherwise 1..=3)
+    pub context_depth: usize,
+
+    /// Contexts per function before falling back (1..=65536)
+    pub max_contexts_per_function: usize,
+}
+
+impl CallGraphConfig {
+    /// Validate configuration
+    pub fn validate(&self) -> ConfigResult<()> {
+        if self.context_depth > 3 {
+            return Err(ConfigError::range_with_hint(
+                "context_depth",
+                self.context_depth,
+                0,
+                3,
+                "Contexts grow exponentially with depth (0=context-insensitive)",
+            ));
+        }
+
+        if self.max_contexts_per_function < 1 || self.max_contexts_per_function > 65_536 {
+            return Err(ConfigError::range_with_hint(
+                "max_contexts_per_function",
+                self.max_contexts_per_function,
+                1,
+                65_536,
+                "Context budget must be reasonable",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Get preset configuration (1-CFA for Thorough only)
+    pub fn from_preset(preset: Preset) -> Self {
+        match preset {
+            Preset::Fast | Preset::Balanced | Preset::Custom => Self {
+                context_depth: 0,
+                max_contexts_per_function: 64,
+            },
+            Preset::Thorough => Self {
+                context_depth: 1,
+                max_contexts_per_function: 256,
+            },
+        }
+    }
+
+    /// Whether a context-sensitive call graph is built
+    pub fn is_context_sensitive(&self) -> bool {
+        self.context_depth > 0
+    }
+
+    /// Builder: Set context_depth
+    pub fn context_depth(mut self, v: usize) -> Self {
+        self.context_depth = v;
+        self
+    }
+
+    /// Builder: Set max_contexts_per_function
+    pub fn max_contexts_per_function(mut self, v: usize) -> Self {
+        self.max_contexts_per_function = v;
+        self
+    }
+}
+
+impl Default for CallGraphConfig {
+    fn default() -> Self {
+        Self::from_preset(Preset::Balanced)
+    }
+}
+
 // ============================================================================
 // L17: PDG (Program Dependence Graph) Configuration
 // ============================================================================
@@ -1312,6 +1785,75 @@ pub struct SlicingConfig {
 
     /// LRU cache capacity (0 = disabled)
     pub cache_capacity: usize,
+
+    /// Slices computed during the pipeline (L6) and attached to the result
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub criteria: Vec<SliceCriterion>,
+}
+
+/// Slice direction computed for a criterion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SliceKind {
+    /// Statements affecting the criterion
+    Backward,
+    /// Statements affected by the criterion
+    Forward,
+    /// Backward over data dependencies only
+    Thin,
+}
+
+impl SliceKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SliceKind::Backward => "backward",
+            SliceKind::Forward => "forward",
+            SliceKind::Thin => "thin",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "backward" => Some(SliceKind::Backward),
+            "forward" => Some(SliceKind::Forward),
+            "thin" => Some(SliceKind::Thin),
+            _ => None,
+        }
+    }
+}
+
+fn default_slice_kinds() -> Vec<SliceKind> {
+    vec![SliceKind::Backward, SliceKind::Forward, SliceKind::Thin]
+}
+
+/// Slicing criterion: `variable` at `line` in `function`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SliceCriterion {
+    /// Function FQN or name
+    pub function: String,
+    pub variable: String,
+    /// 1-based source line
+    pub line: u32,
+    /// Slices to compute (default: backward, forward and thin)
+    #[serde(default = "default_slice_kinds")]
+    pub kinds: Vec<SliceKind>,
+}
+
+impl SliceCriterion {
+    pub fn new(function: impl Into<String>, variable: impl Into<String>, line: u32) -> Self {
+        Self {
+            function: function.into(),
+            variable: variable.into(),
+            line,
+            kinds: default_slice_kinds(),
+        }
+    }
+
+    /// Builder: Set the slices to compute
+    pub fn with_kinds(mut self, kinds: Vec<SliceKind>) -> Self {
+        self.kinds = kinds;
+        self
+    }
 }
 
 impl SlicingConfig {
@@ -1337,6 +1879,26 @@ impl SlicingConfig {
             ));
         }
 
+        for criterion in &self.criteria {
+            if criterion.function.trim().is_empty() || criterion.variable.trim().is_empty() {
+                return Err(ConfigError::Validation(
+                    "slice criteria need a function and a variable".to_string(),
+                ));
+            }
+            if criterion.line == 0 {
+                return Err(ConfigError::Validation(format!(
+                    "slice criterion {}:{} must have a 1-based line",
+                    criterion.function, criterion.variable
+                )));
+            }
+            if criterion.kinds.is_empty() {
+                return Err(ConfigError::Validation(format!(
+                    "slice criterion {}:{} has no slice kinds",
+                    criterion.function, criterion.variable
+                )));
+            }
+        }
+
         Ok(())
     }
 
@@ -1388,6 +1950,12 @@ impl SlicingConfig {
         self
     }
 
+    /// Builder: Add a slicing criterion
+    pub fn criterion(mut self, criterion: SliceCriterion) -> Self {
+        self.criteria.push(criterion);
+        self
+    }
+
     /// Create Thin Slicing config (data dependencies only)
     ///
     /// Reference: Sridharan et al., "Thin Slicing", PLDI 2007
@@ -1412,6 +1980,7 @@ impl SlicingConfig {
                 interprocedural: false,
                 strict_mode: false,
                 cache_capacity: 100,
+                criteria: Vec::new(),
             },
             Preset::Balanced => Self {
                 enabled: true,
@@ -1422,6 +1991,7 @@ impl SlicingConfig {
                 interprocedural: true,
                 strict_mode: false,
                 cache_capacity: 1000,
+                criteria: Vec::new(),
             },
             Preset::Thorough => Self {
                 enabled: true,
@@ -1432,6 +2002,7 @@ impl SlicingConfig {
                 interprocedural: true,
                 strict_mode: true,
                 cache_capacity: 10000,
+                criteria: Vec::new(),
             },
             Preset::Custom => Self::default(),
         }
@@ -1798,6 +2369,39 @@ impl Default for HeapConfig {
     }
 }
 
+impl PageRankConfig {
+    /// Validate configuration
+    pub fn validate(&self) -> ConfigResult<()> {
+        if !(self.damping > 0.0 && self.damping < 1.0) {
+            return Err(ConfigError::range_with_hint(
+                "damping",
+                self.damping,
+                0.0,
+                1.0,
+                "Damping is the probability of following an edge (exclusive bounds)",
+            ));
+        }
+
+        if self.max_iterations < 1 || self.max_iterations > 1000 {
+            return Err(ConfigError::range_with_hint(
+                "max_iterations",
+                self.max_iterations,
+                1,
+                1000,
+                "PageRank converges within tens of iterations",
+            ));
+        }
+
+        if self.personalization.iter().any(|seed| seed.path.is_empty()) {
+            return Err(ConfigError::Validation(
+                "pagerank.personalization: seed path must not be empty".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Validatable Trait Implementations (DIP - Dependency Inversion Principle)
 // ═══════════════════════════════════════════════════════════════════════════
@@ -1882,6 +2486,36 @@ impl Validatable for SlicingConfig {
     }
 }
 
+impl Validatable for MemoryConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        MemoryConfig::validate(self)
+    }
+
+    fn config_name(&self) -> &'static str {
+        "MemoryConfig"
+    }
+}
+
+impl Validatable for DiscoveryConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        DiscoveryConfig::validate(self)
+    }
+
+    fn config_name(&self) -> &'static str {
+        "DiscoveryConfig"
+    }
+}
+
+impl Validatable for CallGraphConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        CallGraphConfig::validate(self)
+    }
+
+    fn config_name(&self) -> &'static str {
+        "CallGraphConfig"
+    }
+}
+
 impl Validatable for HeapConfig {
     fn validate(&self) -> ConfigResult<()> {
         HeapConfig::validate(self)
@@ -1892,6 +2526,16 @@ impl Validatable for HeapConfig {
     }
 }
 
+impl Validatable for PageRankConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        PageRankConfig::validate(self)
+    }
+
+    fn config_name(&self) -> &'static str {
+        "PageRankConfig"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1912,6 +2556,25 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_pagerank_config_validation() {
+        use crate::features::repomap::RepoMapSeed;
+
+        assert!(PageRankConfig::default().validate().is_ok());
+        assert!(PageRankConfig::default()
+            .with_damping(1.0)
+            .validate()
+            .is_err());
+        assert!(PageRankConfig::default()
+            .with_personalization(vec![RepoMapSeed::recently_edited("src/app.py")])
+            .validate()
+            .is_ok());
+        assert!(PageRankConfig::default()
+            .with_personalization(vec![RepoMapSeed::recently_edited("")])
+            .validate()
+            .is_err());
+    }
+
     #[test]
     fn test_heap_config_presets() {
         // Fast: disabled
@@ -2094,6 +2757,20 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_taint_config_field_depth_validation() {
+        let mut config = TaintConfig::from_preset(Preset::Balanced).max_field_depth(0);
+        assert!(config.validate().is_err());
+
+        // Only checked when field-sensitive
+        config.field_sensitive = false;
+        assert!(config.validate().is_ok());
+
+        let config = TaintConfig::from_preset(Preset::Thorough);
+        assert_eq!(config.max_field_depth, 5);
+        assert!(config.validate().is_ok());
+    }
+
     #[test]
     fn test_taint_config_builder() {
         let config = TaintConfig::from_preset(Preset::Fast)
@@ -2222,6 +2899,13 @@ mod tests {
     fn test_lexical_config_validation() {
         let config = LexicalConfig::from_preset(Preset::Balanced);
         assert!(config.validate().is_ok());
+        assert!(config.split_identifiers);
+
+        let mut config = LexicalConfig::from_preset(Preset::Balanced);
+        config.stop_symbol_languages = vec!["python".to_string(), "java".to_string()];
+        assert!(config.validate().is_ok());
+        config.stop_symbol_languages.push("cobol".to_string());
+        assert!(config.validate().is_err());
     }
 
     #[test]
@@ -2360,6 +3044,80 @@ mod tests {
         assert_eq!(config.cache_capacity, 5000);
     }
 
+    // ==================== MemoryConfig Tests ====================
+
+    #[test]
+    fn test_memory_config_validation() {
+        let config = MemoryConfig::from_preset(Preset::Balanced);
+        assert!(config.validate().is_ok());
+        assert!(!config.is_limited());
+
+        let config = MemoryConfig::default().max_memory_mb(4096);
+        assert!(config.validate().is_ok());
+        assert!(config.is_limited());
+
+        assert!(MemoryConfig::default()
+            .max_memory_mb(16)
+            .validate()
+            .is_err());
+        assert!(MemoryConfig::default().batch_size_mb(0).validate().is_err());
+        assert!(MemoryConfig::default()
+            .spill_threshold_percent(5)
+            .validate()
+            .is_err());
+        assert!(MemoryConfig::default().spill_dir(" ").validate().is_err());
+    }
+
+    // ==================== DiscoveryConfig Tests ====================
+
+    #[test]
+    fn test_discovery_config_validation() {
+        let config = DiscoveryConfig::default();
+        assert!(config.validate().is_ok());
+        assert!(config.exclude.contains(&"node_modules/".to_string()));
+
+        let config = DiscoveryConfig::default()
+            .include("src/**")
+            .exclude("**/*_pb2.py");
+        assert!(config.validate().is_ok());
+
+        assert!(DiscoveryConfig::default()
+            .include("src/[")
+            .validate()
+            .is_err());
+        assert!(DiscoveryConfig::default()
+            .exclude("!keep")
+            .validate()
+            .is_err());
+        assert!(DiscoveryConfig::default()
+            .max_file_size_kb(2_000_000)
+            .validate()
+            .is_err());
+    }
+
+    // ==================== CallGraphConfig Tests ====================
+
+    #[test]
+    fn test_call_graph_config_validation() {
+        let config = CallGraphConfig::from_preset(Preset::Balanced);
+        assert!(config.validate().is_ok());
+        assert!(!config.is_context_sensitive());
+        assert!(CallGraphConfig::from_preset(Preset::Thorough).is_context_sensitive());
+
+        assert!(CallGraphConfig::default()
+            .context_depth(2)
+            .validate()
+            .is_ok());
+        assert!(CallGraphConfig::default()
+            .context_depth(4)
+            .validate()
+            .is_err());
+        assert!(CallGraphConfig::default()
+            .max_contexts_per_function(0)
+            .validate()
+            .is_err());
+    }
+
     #[test]
     fn test_slicing_thin_slicing_preset() {
         // Thin Slicing = data dependencies only
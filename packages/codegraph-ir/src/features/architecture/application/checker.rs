@@ -0,0 +1,336 @@
+//! Architecture rule checker
+//!
+//! Resolves the import and call edges of IR documents to the files they
+//! point at, assigns both ends to layers and reports every dependency a
+//! rule forbids. Imports resolve like `ImportResolver` (exact FQN, then
+//! the longest known prefix, then module path); calls resolve by target
+//! node ID, then by FQN. Unresolved targets (third-party code) are not
+//! checked.
+
+use ignore::overrides::{Override, OverrideBuilder};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::features::architecture::domain::{
+    ArchitectureRules, ArchitectureViolation, DependencyKind,
+};
+use crate::features::cross_file::{IRDocument, SymbolIndex};
+use crate::shared::models::{CodegraphError, EdgeKind, Node, Result, Span};
+
+/// Layer with compiled path globs
+struct CompiledLayer {
+    name: String,
+    globs: Override,
+}
+
+/// Resolved end of a dependency
+struct Target {
+    file_path: String,
+    fqn: String,
+}
+
+/// Checks dependencies against architecture rules
+pub struct ArchitectureChecker {
+    rules: ArchitectureRules,
+    layers: Vec<CompiledLayer>,
+}
+
+impl ArchitectureChecker {
+    /// Checker for validated `rules`
+    pub fn new(rules: ArchitectureRules) -> Result<Self> {
+        rules.validate()?;
+        let layers = rules
+            .layers
+            .iter()
+            .map(|layer| {
+                let mut builder = OverrideBuilder::new("");
+                for pattern in &layer.paths {
+                    builder.add(pattern).map_err(|e| {
+                        CodegraphError::config(format!(
+                            "invalid path glob {:?} of layer '{}': {}",
+                            pattern, layer.name, e
+                        ))
+                    })?;
+                }
+                let globs = builder.build().map_err(|e| {
+                    CodegraphError::config(format!(
+                        "invalid globs of layer '{}': {}",
+                        layer.name, e
+                    ))
+                })?;
+                Ok(CompiledLayer {
+                    name: layer.name.clone(),
+                    globs,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { rules, layers })
+    }
+
+    pub fn rules(&self) -> &ArchitectureRules {
+        &self.rules
+    }
+
+    /// Layer of a repository-relative file path (first matching layer)
+    pub fn layer_of(&self, file_path: &str) -> Option<&str> {
+        let path = Path::new(file_path);
+        self.layers
+            .iter()
+            .find(|layer| {
+                // A directory glob ("src/ui/") selects everything below it
+                path.ancestors()
+                    .filter(|p| !p.as_os_str().is_empty())
+                    .enumerate()
+                    .any(|(i, p)| layer.globs.matched(p, i > 0).is_whitelist())
+            })
+            .map(|layer| layer.name.as_str())
+    }
+
+    /// Violations of the enabled rules, ordered by file and position
+    pub fn check(&self, docs: &[IRDocument]) -> Vec<ArchitectureViolation> {
+        if self.rules.rules.iter().all(|r| !r.enabled) {
+            return Vec::new();
+        }
+        let index = SymbolIndex::build_from_irs(docs);
+        let nodes: HashMap<&str, &Node> = docs
+            .iter()
+            .flat_map(|doc| doc.nodes.iter())
+            .map(|node| (node.id.as_str(), node))
+            .collect();
+
+        let mut violations = Vec::new();
+        for doc in docs {
+            let Some(from_layer) = self.layer_of(&doc.file_path) else {
+                continue;
+            };
+            for edge in &doc.edges {
+                let kind = match edge.kind {
+                    EdgeKind::Imports => DependencyKind::Imports,
+                    EdgeKind::Calls => DependencyKind::Calls,
+                    _ => continue,
+                };
+                let target = match kind {
+                    DependencyKind::Imports => resolve_import(&index, &nodes, &edge.target_id),
+                    DependencyKind::Calls => resolve_call(&index, &nodes, &edge.target_id),
+                };
+                let Some(target) = target else {
+                    continue;
+                };
+                if target.file_path == doc.file_path {
+                    continue;
+                }
+                let Some(to_layer) = self.layer_of(&target.file_path) else {
+                    continue;
+                };
+
+                let source = nodes.get(edge.source_id.as_str());
+                let from_symbol = source.map_or_else(|| edge.source_id.clone(), |n| n.fqn.clone());
+                let span = edge
+                    .span
+                    .or_else(|| source.map(|n| n.span))
+                    .unwrap_or_else(|| Span::new(0, 0, 0, 0));
+
+                for rule in &self.rules.rules {
+                    if rule.from != from_layer || !rule.forbids(kind, to_layer) {
+                        continue;
+                    }
+                    let mut message = format!(
+                        "{} ({}) {} {} ({}): forbidden by rule '{}'",
+                        from_symbol,
+                        from_layer,
+                        kind.as_str(),
+                        target.fqn,
+                        to_layer,
+                        rule.name
+                    );
+                    if let Some(description) = &rule.description {
+                        message.push_str(&format!(" ({})", description));
+                    }
+                    violations.push(ArchitectureViolation {
+                        rule: rule.name.clone(),
+                        severity: rule.severity,
+                        kind,
+                        from_layer: from_layer.to_string(),
+                        to_layer: to_layer.to_string(),
+                        from_file: doc.file_path.clone(),
+                        from_symbol: from_symbol.clone(),
+                        to_file: target.file_path.clone(),
+                        to_symbol: target.fqn.clone(),
+                        span,
+                        message,
+                    });
+                }
+            }
+        }
+
+        violations.sort_by(|a, b| {
+            (&a.from_file, a.span.start_line, a.span.start_col, &a.rule).cmp(&(
+                &b.from_file,
+                b.span.start_line,
+                b.span.start_col,
+                &b.rule,
+            ))
+        });
+        violations
+    }
+}
+
+fn resolve_import(
+    index: &SymbolIndex,
+    nodes: &HashMap<&str, &Node>,
+    target_id: &str,
+) -> Option<Target> {
+    // Import edges point at an import node carrying the imported FQN, or
+    // at the FQN itself
+    let name = match nodes.get(target_id) {
+        Some(node) if !node.fqn.is_empty() => node.fqn.as_str(),
+        Some(node) => node.name.as_deref().unwrap_or_default(),
+        None => target_id,
+    };
+    if name.is_empty() {
+        return None;
+    }
+    let symbol = index
+        .resolve_exact(name)
+        .or_else(|| index.resolve_partial(name).map(|(symbol, _)| symbol))
+        .or_else(|| index.resolve_by_module_path(name))?;
+    Some(Target {
+        file_path: symbol.file_path.clone(),
+        fqn: name.to_string(),
+    })
+}
+
+fn resolve_call(
+    index: &SymbolIndex,
+    nodes: &HashMap<&str, &Node>,
+    target_id: &str,
+) -> Option<Target> {
+    if let Some(node) = nodes.get(target_id) {
+        return Some(Target {
+            file_path: node.file_path.clone(),
+            fqn: node.fqn.clone(),
+        });
+    }
+    let symbol = index.resolve_exact(target_id)?;
+    Some(Target {
+        file_path: symbol.file_path.clone(),
+        fqn: symbol.fqn.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::architecture::domain::{ArchitectureRule, LayerDefinition, RuleSeverity};
+    use crate::shared::models::{Edge, NodeKind};
+
+    fn node(id: &str, kind: NodeKind, fqn: &str, file: &str, line: u32) -> Node {
+        Node::new(
+            id.to_string(),
+            kind,
+            fqn.to_string(),
+            file.to_string(),
+            Span::new(line, 0, line, 20),
+        )
+    }
+
+    fn doc(file: &str, nodes: Vec<Node>, edges: Vec<Edge>) -> IRDocument {
+        IRDocument {
+            file_path: file.to_string(),
+            nodes,
+            edges,
+            repo_id: None,
+        }
+    }
+
+    #[test]
+    fn test_reports_forbidden_imports_and_calls_with_spans() {
+        let docs = vec![
+            doc(
+                "src/ui/views.py",
+                vec![
+                    node(
+                        "v1",
+                        NodeKind::Function,
+                        "ui.views.show",
+                        "src/ui/views.py",
+                        3,
+                    ),
+                    node(
+                        "v2",
+                        NodeKind::Import,
+                        "storage.db.query",
+                        "src/ui/views.py",
+                        1,
+                    ),
+                ],
+                vec![
+                    Edge::new("v1".into(), "v2".into(), EdgeKind::Imports)
+                        .with_span(Span::new(1, 0, 1, 30)),
+                    Edge::new("v1".into(), "s1".into(), EdgeKind::Calls)
+                        .with_span(Span::new(4, 4, 4, 18)),
+                    Edge::new("v1".into(), "d1".into(), EdgeKind::Calls),
+                ],
+            ),
+            doc(
+                "src/storage/db.py",
+                vec![node(
+                    "s1",
+                    NodeKind::Function,
+                    "storage.db.query",
+                    "src/storage/db.py",
+                    1,
+                )],
+                vec![],
+            ),
+            doc(
+                "src/domain/model.py",
+                vec![node(
+                    "d1",
+                    NodeKind::Function,
+                    "domain.model.load",
+                    "src/domain/model.py",
+                    1,
+                )],
+                vec![Edge::new("d1".into(), "s1".into(), EdgeKind::Calls)],
+            ),
+        ];
+        let rules = ArchitectureRules {
+            version: 1,
+            layers: vec![
+                LayerDefinition::new("ui", &["src/ui/"]),
+                LayerDefinition::new("domain", &["src/domain/**"]),
+                LayerDefinition::new("storage", &["src/storage/**"]),
+            ],
+            rules: vec![
+                ArchitectureRule::deny("ui-not-storage", "ui", &["storage"]),
+                ArchitectureRule::allow_only("domain-is-pure", "domain", &[])
+                    .edges(vec![DependencyKind::Calls])
+                    .severity(RuleSeverity::Warning),
+            ],
+        };
+
+        let checker = ArchitectureChecker::new(rules).unwrap();
+        assert_eq!(checker.layer_of("src/ui/views.py"), Some("ui"));
+        assert_eq!(checker.layer_of("scripts/run.py"), None);
+
+        let violations = checker.check(&docs);
+        assert_eq!(violations.len(), 3);
+
+        // Sorted by file: domain first
+        assert_eq!(violations[0].rule, "domain-is-pure");
+        assert_eq!(violations[0].severity, RuleSeverity::Warning);
+        assert_eq!(violations[0].to_symbol, "storage.db.query");
+
+        let import = &violations[1];
+        assert_eq!(import.kind, DependencyKind::Imports);
+        assert_eq!(import.to_file, "src/storage/db.py");
+        assert_eq!(import.span.start_line, 1);
+        let call = &violations[2];
+        assert_eq!(call.kind, DependencyKind::Calls);
+        assert_eq!(call.from_symbol, "ui.views.show");
+        assert_eq!(call.location(), "src/ui/views.py:4");
+        // ui → domain is not denied
+        assert!(violations.iter().all(|v| v.to_layer == "storage"));
+    }
+}
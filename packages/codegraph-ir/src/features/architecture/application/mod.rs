@@ -0,0 +1,5 @@
+//! Architecture Application Layer
+
+mod checker;
+
+pub use checker::ArchitectureChecker;
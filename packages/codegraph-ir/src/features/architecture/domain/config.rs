@@ -0,0 +1,93 @@
+//! Architecture check configuration
+//!
+//! Selected from the pipeline config (`overrides.architecture` in YAML, or
+//! `PipelineConfig::architecture(..)`). Rules come from a rule file
+//! (TOML or YAML, relative paths resolved against the repository root) or
+//! are given inline:
+//!
+//! ```yaml
+//! overrides:
+//!   architecture:
+//!     enabled: true
+//!     rules_path: .codegraph/architecture.toml
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::rules::ArchitectureRules;
+use crate::config::{ConfigError, ConfigResult};
+use crate::shared::models::Result;
+
+/// Architecture check settings
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ArchitectureConfig {
+    /// Check the rules during the run
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Rule file (`.toml` or YAML)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rules_path: Option<String>,
+
+    /// Inline rules (instead of `rules_path`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rules: Option<ArchitectureRules>,
+}
+
+impl ArchitectureConfig {
+    /// Enabled config without rules
+    pub fn enabled() -> Self {
+        Self {
+            enabled: true,
+            ..Self::default()
+        }
+    }
+
+    /// Builder: Load rules from a file
+    pub fn rules_path(mut self, path: impl Into<String>) -> Self {
+        self.rules_path = Some(path.into());
+        self
+    }
+
+    /// Builder: Use inline rules
+    pub fn rules(mut self, rules: ArchitectureRules) -> Self {
+        self.rules = Some(rules);
+        self
+    }
+
+    /// Rules to check, reading `rules_path` relative to `repo_root`
+    pub fn load_rules(&self, repo_root: &Path) -> Result<ArchitectureRules> {
+        match (&self.rules, &self.rules_path) {
+            (Some(rules), _) => Ok(rules.clone()),
+            (None, Some(path)) => ArchitectureRules::from_file(repo_root.join(path)),
+            (None, None) => Ok(ArchitectureRules {
+                version: 1,
+                layers: Vec::new(),
+                rules: Vec::new(),
+            }),
+        }
+    }
+
+    pub fn validate(&self) -> ConfigResult<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+        match (&self.rules, &self.rules_path) {
+            (Some(_), Some(_)) => Err(ConfigError::Validation(
+                "architecture: set either rules_path or rules, not both".to_string(),
+            )),
+            (None, None) => Err(ConfigError::Validation(
+                "architecture: rules_path or rules is required".to_string(),
+            )),
+            (Some(rules), None) => rules
+                .validate()
+                .map_err(|e| ConfigError::Validation(format!("architecture: {}", e))),
+            (None, Some(path)) if path.trim().is_empty() => Err(ConfigError::Validation(
+                "architecture.rules_path must not be empty".to_string(),
+            )),
+            (None, Some(_)) => Ok(()),
+        }
+    }
+}
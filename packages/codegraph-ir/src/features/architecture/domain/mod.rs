@@ -0,0 +1,12 @@
+//! Domain models for architecture rules
+
+mod config;
+mod rules;
+mod violation;
+
+pub use config::ArchitectureConfig;
+pub use rules::{
+    ArchitectureRule, ArchitectureRules, DependencyKind, LayerDefinition, RuleSeverity,
+    ARCHITECTURE_RULES_VERSIONS,
+};
+pub use violation::ArchitectureViolation;
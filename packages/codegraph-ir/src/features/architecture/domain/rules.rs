@@ -0,0 +1,366 @@
+//! Architecture rule files
+//!
+//! A rule file declares layers (path globs, `.gitignore` syntax, relative
+//! to the repository root) and rules between them. A rule either denies
+//! dependencies from one layer into the listed layers, or allows only the
+//! listed ones (the layer itself is always allowed). Files outside every
+//! layer are not constrained.
+//!
+//! ```yaml
+//! version: 1
+//! layers:
+//!   - name: ui
+//!     paths: ["src/ui/**"]
+//!   - name: domain
+//!     paths: ["src/domain/**"]
+//!   - name: storage
+//!     paths: ["src/storage/**"]
+//! rules:
+//!   - name: ui-not-storage
+//!     from: ui
+//!     deny: [storage]
+//!   - name: domain-is-pure
+//!     from: domain
+//!     allow: []
+//!     edges: [calls]
+//!     severity: warning
+//! ```
+//!
+//! The same file in TOML:
+//!
+//! ```toml
+//! version = 1
+//!
+//! [[layers]]
+//! name = "ui"
+//! paths = ["src/ui/**"]
+//!
+//! [[rules]]
+//! name = "ui-not-storage"
+//! from = "ui"
+//! deny = ["storage"]
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::shared::models::{CodegraphError, Result};
+
+/// Supported rule file versions
+pub const ARCHITECTURE_RULES_VERSIONS: &[u32] = &[1];
+
+/// Dependency kinds a rule applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DependencyKind {
+    Imports,
+    Calls,
+}
+
+impl DependencyKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Imports => "imports",
+            Self::Calls => "calls",
+        }
+    }
+}
+
+fn default_edges() -> Vec<DependencyKind> {
+    vec![DependencyKind::Imports, DependencyKind::Calls]
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Severity of a violation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleSeverity {
+    Note,
+    Warning,
+    #[default]
+    Error,
+}
+
+impl RuleSeverity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Note => "note",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// Named set of files
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LayerDefinition {
+    pub name: String,
+    /// Globs selecting the layer's files (a file belongs to the first
+    /// layer that matches)
+    pub paths: Vec<String>,
+}
+
+impl LayerDefinition {
+    pub fn new(name: impl Into<String>, paths: &[&str]) -> Self {
+        Self {
+            name: name.into(),
+            paths: paths.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+}
+
+/// Constraint on the dependencies of one layer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ArchitectureRule {
+    pub name: String,
+    /// Layer whose dependencies are checked
+    pub from: String,
+    /// Layers `from` must not depend on
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deny: Vec<String>,
+    /// Only layers `from` may depend on (exclusive with `deny`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow: Option<Vec<String>>,
+    /// Dependency kinds checked (default: imports and calls)
+    #[serde(default = "default_edges")]
+    pub edges: Vec<DependencyKind>,
+    #[serde(default)]
+    pub severity: RuleSeverity,
+    /// Shown with each violation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+impl ArchitectureRule {
+    /// `from` must not depend on `layers`
+    pub fn deny(name: impl Into<String>, from: impl Into<String>, layers: &[&str]) -> Self {
+        Self {
+            name: name.into(),
+            from: from.into(),
+            deny: layers.iter().map(|l| l.to_string()).collect(),
+            allow: None,
+            edges: default_edges(),
+            severity: RuleSeverity::default(),
+            description: None,
+            enabled: true,
+        }
+    }
+
+    /// `from` may depend on `layers` only
+    pub fn allow_only(name: impl Into<String>, from: impl Into<String>, layers: &[&str]) -> Self {
+        Self {
+            deny: Vec::new(),
+            allow: Some(layers.iter().map(|l| l.to_string()).collect()),
+            ..Self::deny(name, from, &[])
+        }
+    }
+
+    /// Builder: Restrict to dependency kinds
+    pub fn edges(mut self, edges: Vec<DependencyKind>) -> Self {
+        self.edges = edges;
+        self
+    }
+
+    /// Builder: Set severity
+    pub fn severity(mut self, severity: RuleSeverity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Whether a `kind` dependency from `from` into `to_layer` breaks the rule
+    pub fn forbids(&self, kind: DependencyKind, to_layer: &str) -> bool {
+        if !self.enabled || !self.edges.contains(&kind) || to_layer == self.from {
+            return false;
+        }
+        match &self.allow {
+            Some(allowed) => !allowed.iter().any(|l| l == to_layer),
+            None => self.deny.iter().any(|l| l == to_layer),
+        }
+    }
+}
+
+/// Rule file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ArchitectureRules {
+    pub version: u32,
+    pub layers: Vec<LayerDefinition>,
+    #[serde(default)]
+    pub rules: Vec<ArchitectureRule>,
+}
+
+impl ArchitectureRules {
+    /// Parse rules (YAML or JSON)
+    pub fn from_yaml_str(content: &str) -> Result<Self> {
+        let rules: Self = serde_yaml::from_str(content)
+            .map_err(|e| CodegraphError::config(format!("invalid architecture rules: {}", e)))?;
+        rules.validate()?;
+        Ok(rules)
+    }
+
+    /// Parse rules (TOML)
+    pub fn from_toml_str(content: &str) -> Result<Self> {
+        let rules: Self = toml::from_str(content)
+            .map_err(|e| CodegraphError::config(format!("invalid architecture rules: {}", e)))?;
+        rules.validate()?;
+        Ok(rules)
+    }
+
+    /// Load a rule file (`.toml` as TOML, anything else as YAML)
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            CodegraphError::io(format!(
+                "cannot read architecture rules {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => Self::from_toml_str(&content),
+            _ => Self::from_yaml_str(&content),
+        }
+    }
+
+    /// Layer by name
+    pub fn layer(&self, name: &str) -> Option<&LayerDefinition> {
+        self.layers.iter().find(|l| l.name == name)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if !ARCHITECTURE_RULES_VERSIONS.contains(&self.version) {
+            return Err(CodegraphError::config(format!(
+                "unsupported architecture rules version {} (supported: {:?})",
+                self.version, ARCHITECTURE_RULES_VERSIONS
+            )));
+        }
+
+        let mut layers = HashSet::new();
+        for layer in &self.layers {
+            if layer.name.trim().is_empty() {
+                return Err(CodegraphError::config(
+                    "architecture layer name must not be empty",
+                ));
+            }
+            if !layers.insert(layer.name.as_str()) {
+                return Err(CodegraphError::config(format!(
+                    "duplicate architecture layer '{}'",
+                    layer.name
+                )));
+            }
+            if layer.paths.is_empty() {
+                return Err(CodegraphError::config(format!(
+                    "architecture layer '{}' has no paths",
+                    layer.name
+                )));
+            }
+        }
+
+        let mut names = HashSet::new();
+        for rule in &self.rules {
+            if rule.name.trim().is_empty() {
+                return Err(CodegraphError::config(
+                    "architecture rule name must not be empty",
+                ));
+            }
+            if !names.insert(rule.name.as_str()) {
+                return Err(CodegraphError::config(format!(
+                    "duplicate architecture rule '{}'",
+                    rule.name
+                )));
+            }
+            if rule.allow.is_some() == !rule.deny.is_empty() {
+                return Err(CodegraphError::config(format!(
+                    "architecture rule '{}' needs exactly one of `deny` and `allow`",
+                    rule.name
+                )));
+            }
+            let referenced = std::iter::once(&rule.from)
+                .chain(&rule.deny)
+                .chain(rule.allow.iter().flatten());
+            for layer in referenced {
+                if !layers.contains(layer.as_str()) {
+                    return Err(CodegraphError::config(format!(
+                        "architecture rule '{}' references unknown layer '{}'",
+                        rule.name, layer
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_yaml_and_toml_rules_agree() {
+        let yaml = r#"
+version: 1
+layers:
+  - name: ui
+    paths: ["src/ui/**"]
+  - name: storage
+    paths: ["src/storage/**"]
+rules:
+  - name: ui-not-storage
+    from: ui
+    deny: [storage]
+    edges: [imports]
+"#;
+        let toml = r#"
+version = 1
+
+[[layers]]
+name = "ui"
+paths = ["src/ui/**"]
+
+[[layers]]
+name = "storage"
+paths = ["src/storage/**"]
+
+[[rules]]
+name = "ui-not-storage"
+from = "ui"
+deny = ["storage"]
+edges = ["imports"]
+"#;
+        let from_yaml = ArchitectureRules::from_yaml_str(yaml).unwrap();
+        assert_eq!(from_yaml, ArchitectureRules::from_toml_str(toml).unwrap());
+
+        let rule = &from_yaml.rules[0];
+        assert_eq!(rule.severity, RuleSeverity::Error);
+        assert!(rule.forbids(DependencyKind::Imports, "storage"));
+        assert!(!rule.forbids(DependencyKind::Calls, "storage"));
+        assert!(!rule.forbids(DependencyKind::Imports, "ui"));
+
+        let allow = ArchitectureRule::allow_only("ui-only-domain", "ui", &["domain"]);
+        assert!(allow.forbids(DependencyKind::Calls, "storage"));
+        assert!(!allow.forbids(DependencyKind::Calls, "domain"));
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let unknown = "version: 1\nlayers: [{name: ui, paths: [ui/**]}]\n\
+                       rules: [{name: r, from: ui, deny: [db]}]\n";
+        let err = ArchitectureRules::from_yaml_str(unknown).unwrap_err();
+        assert!(err.to_string().contains("unknown layer 'db'"));
+
+        let both = "version: 1\nlayers: [{name: ui, paths: [ui/**]}]\n\
+                    rules: [{name: r, from: ui, deny: [ui], allow: []}]\n";
+        assert!(ArchitectureRules::from_yaml_str(both).is_err());
+
+        let version = "version: 2\nlayers: []\n";
+        assert!(ArchitectureRules::from_yaml_str(version).is_err());
+    }
+}
@@ -0,0 +1,32 @@
+//! Architecture rule violations
+
+use serde::{Deserialize, Serialize};
+
+use super::rules::{DependencyKind, RuleSeverity};
+use crate::shared::models::Span;
+
+/// Dependency that breaks an architecture rule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArchitectureViolation {
+    pub rule: String,
+    pub severity: RuleSeverity,
+    pub kind: DependencyKind,
+    pub from_layer: String,
+    pub to_layer: String,
+    pub from_file: String,
+    /// FQN of the importing / calling symbol
+    pub from_symbol: String,
+    pub to_file: String,
+    /// FQN of the imported / called symbol
+    pub to_symbol: String,
+    /// Import statement or call site in `from_file`
+    pub span: Span,
+    pub message: String,
+}
+
+impl ArchitectureViolation {
+    /// "file:line" of the dependency
+    pub fn location(&self) -> String {
+        format!("{}:{}", self.from_file, self.span.start_line)
+    }
+}
@@ -0,0 +1,48 @@
+//! Architecture rules - layer and dependency constraints
+//!
+//! Checks user-declared rules ("ui must not import storage", "domain may
+//! not call adapters") against the import and call dependencies of a run
+//! and reports each violation with the span of the import or call site.
+//!
+//! # Architecture
+//!
+//! ```text
+//! ┌──────────────────────────────────────────────────┐
+//! │ Domain Layer                                     │
+//! │  - ArchitectureRules (layers + rules, YAML/TOML) │
+//! │  - ArchitectureConfig (pipeline selection)       │
+//! │  - ArchitectureViolation (rule + span)           │
+//! └──────────────────────────────────────────────────┘
+//!                   ▲
+//!                   │
+//! ┌──────────────────────────────────────────────────┐
+//! │ Application Layer                                │
+//! │  - ArchitectureChecker (edges → violations)      │
+//! └──────────────────────────────────────────────────┘
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use codegraph_ir::features::architecture::{ArchitectureChecker, ArchitectureRules};
+//!
+//! let rules = ArchitectureRules::from_file(".codegraph/architecture.toml")?;
+//! for violation in ArchitectureChecker::new(rules)?.check(&ir_docs) {
+//!     println!("{} {}", violation.location(), violation.message);
+//! }
+//! ```
+//!
+//! In the pipeline, `PipelineConfig::architecture(..)` puts violations in
+//! `E2EPipelineResult::architecture_violations` and the SARIF log.
+
+pub mod application;
+pub mod domain;
+
+// Re-export application layer
+pub use application::ArchitectureChecker;
+
+// Re-exports for convenience
+pub use domain::{
+    ArchitectureConfig, ArchitectureRule, ArchitectureRules, ArchitectureViolation,
+    DependencyKind, LayerDefinition, RuleSeverity,
+};
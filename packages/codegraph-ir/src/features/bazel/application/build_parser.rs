@@ -0,0 +1,567 @@
+//! BUILD file parser
+//!
+//! Evaluates the Starlark subset BUILD files are written in: top-level rule
+//! and macro calls, string/list variables, `+` concatenation, `glob()` and
+//! `select()` (every branch is taken). `load()`, `package()` and other
+//! non-target calls are ignored; anything else evaluates to an opaque value.
+
+use std::collections::HashMap;
+
+use crate::features::bazel::domain::{canonical_label, BazelTarget};
+use crate::shared::models::{CodegraphError, Result};
+
+/// Attributes listing source files
+const SRC_ATTRS: &[&str] = &["srcs", "hdrs"];
+
+/// Attributes listing target dependencies
+const DEP_ATTRS: &[&str] = &["deps", "runtime_deps", "exports"];
+
+/// Top-level calls that never declare a target
+const NON_TARGET_CALLS: &[&str] = &[
+    "load",
+    "package",
+    "licenses",
+    "exports_files",
+    "package_group",
+    "workspace",
+];
+
+/// Targets declared by the BUILD file of `package`
+///
+/// `package_files` are the files of the package (subpackages excluded),
+/// relative to the package directory; `glob()` matches against them.
+pub fn parse_build_file(
+    text: &str,
+    package: &str,
+    package_files: &[String],
+) -> Result<Vec<BazelTarget>> {
+    let tokens = tokenize(text)?;
+    let mut parser = Parser {
+        tokens: &tokens,
+        pos: 0,
+        vars: HashMap::new(),
+        package_files,
+    };
+    let mut targets = Vec::new();
+    while let Some(token) = tokens.get(parser.pos) {
+        let Token::Name(name) = token else {
+            parser.pos += 1;
+            continue;
+        };
+        let name = name.clone();
+        match (tokens.get(parser.pos + 1), tokens.get(parser.pos + 2)) {
+            (Some(Token::Punct('(')), _) => {
+                parser.pos += 2;
+                let args = parser.parse_args()?;
+                if let Some(target) = target_of(&name, args, package) {
+                    targets.push(target);
+                }
+            }
+            (Some(Token::Punct('=')), _) => {
+                parser.pos += 2;
+                let value = parser.parse_expr()?;
+                parser.vars.insert(name, value);
+            }
+            (Some(Token::Punct('+')), Some(Token::Punct('='))) => {
+                parser.pos += 3;
+                let value = parser.parse_expr()?;
+                let current = parser.vars.remove(&name).unwrap_or(Value::Opaque);
+                parser.vars.insert(name, current.concat(value));
+            }
+            _ => parser.pos += 1,
+        }
+    }
+    Ok(targets)
+}
+
+fn target_of(kind: &str, args: Vec<(Option<String>, Value)>, package: &str) -> Option<BazelTarget> {
+    if NON_TARGET_CALLS.contains(&kind) {
+        return None;
+    }
+    let mut kwargs: HashMap<String, Value> = args
+        .into_iter()
+        .filter_map(|(key, value)| Some((key?, value)))
+        .collect();
+    let Some(Value::Str(name)) = kwargs.remove("name") else {
+        return None;
+    };
+
+    let mut srcs: Vec<String> = SRC_ATTRS
+        .iter()
+        .filter_map(|attr| kwargs.remove(*attr))
+        .flat_map(Value::into_strings)
+        .filter_map(|src| src_path(package, &src))
+        .collect();
+    srcs.sort();
+    srcs.dedup();
+    let mut deps: Vec<String> = DEP_ATTRS
+        .iter()
+        .filter_map(|attr| kwargs.remove(*attr))
+        .flat_map(Value::into_strings)
+        .map(|dep| canonical_label(package, &dep))
+        .collect();
+    deps.sort();
+    deps.dedup();
+
+    Some(BazelTarget {
+        label: canonical_label(package, &name),
+        kind: kind.to_string(),
+        package: package.to_string(),
+        srcs,
+        deps,
+    })
+}
+
+/// Workspace-relative file of a `srcs` entry; external labels have none
+fn src_path(package: &str, src: &str) -> Option<String> {
+    if src.starts_with('@') {
+        return None;
+    }
+    let (pkg, file) = match src.strip_prefix("//") {
+        Some(absolute) => absolute.split_once(':')?,
+        None => (package, src.trim_start_matches(':')),
+    };
+    Some(if pkg.is_empty() {
+        file.to_string()
+    } else {
+        format!("{}/{}", pkg, file)
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Str(String),
+    Name(String),
+    Punct(char),
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '#' {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c.is_whitespace() || c == '\\' {
+            i += 1;
+        } else if c == '"' || c == '\'' {
+            let (value, next) = read_string(&chars, i)?;
+            tokens.push(Token::Str(value));
+            i = next;
+        } else if c.is_alphanumeric() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            let prefixed = matches!(word.as_str(), "r" | "b" | "rb" | "br");
+            if prefixed && matches!(chars.get(i), Some('"' | '\'')) {
+                continue;
+            }
+            tokens.push(Token::Name(word));
+        } else {
+            tokens.push(Token::Punct(c));
+            i += 1;
+        }
+    }
+    Ok(tokens)
+}
+
+/// String literal starting at `start`; returns its value and the index
+/// after the closing quote
+fn read_string(chars: &[char], start: usize) -> Result<(String, usize)> {
+    let quote = chars[start];
+    let triple = chars.get(start + 1) == Some(&quote) && chars.get(start + 2) == Some(&quote);
+    let mut i = start + if triple { 3 } else { 1 };
+    let mut value = String::new();
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\\' {
+            if let Some(&escaped) = chars.get(i + 1) {
+                value.push(escaped);
+            }
+            i += 2;
+            continue;
+        }
+        if c == quote {
+            if !triple {
+                return Ok((value, i + 1));
+            }
+            if chars.get(i + 1) == Some(&quote) && chars.get(i + 2) == Some(&quote) {
+                return Ok((value, i + 3));
+            }
+        }
+        if c == '\n' && !triple {
+            break;
+        }
+        value.push(c);
+        i += 1;
+    }
+    Err(CodegraphError::parse(
+        "unterminated string literal in BUILD file",
+    ))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Str(String),
+    List(Vec<String>),
+    /// Anything the subset does not evaluate (numbers, booleans, calls)
+    Opaque,
+}
+
+impl Value {
+    fn into_strings(self) -> Vec<String> {
+        match self {
+            Value::Str(s) => vec![s],
+            Value::List(items) => items,
+            Value::Opaque => Vec::new(),
+        }
+    }
+
+    fn concat(self, other: Value) -> Value {
+        match (self, other) {
+            (Value::Str(a), Value::Str(b)) => Value::Str(a + &b),
+            (Value::Opaque, Value::Opaque) => Value::Opaque,
+            (a, b) => {
+                let mut items = a.into_strings();
+                items.extend(b.into_strings());
+                Value::List(items)
+            }
+        }
+    }
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    vars: HashMap<String, Value>,
+    package_files: &'a [String],
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + offset)
+    }
+
+    fn at_punct(&self, c: char) -> bool {
+        self.peek() == Some(&Token::Punct(c))
+    }
+
+    fn at_name(&self, name: &str) -> bool {
+        matches!(self.peek(), Some(Token::Name(n)) if n == name)
+    }
+
+    fn expect_punct(&mut self, c: char) -> Result<()> {
+        if self.at_punct(c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("'{}'", c)))
+        }
+    }
+
+    fn unexpected(&self, expected: &str) -> CodegraphError {
+        CodegraphError::parse(format!(
+            "BUILD file: expected {}, found {:?}",
+            expected,
+            self.peek()
+        ))
+    }
+
+    /// `term ('+' term)*`, ignoring a trailing `if ... else ...`
+    fn parse_expr(&mut self) -> Result<Value> {
+        let mut value = self.parse_term()?;
+        while self.at_punct('+') && self.peek_at(1) != Some(&Token::Punct('=')) {
+            self.pos += 1;
+            value = value.concat(self.parse_term()?);
+        }
+        if self.at_name("if") {
+            self.pos += 1;
+            self.parse_expr()?;
+            if self.at_name("else") {
+                self.pos += 1;
+                self.parse_expr()?;
+            }
+        }
+        Ok(value)
+    }
+
+    fn parse_term(&mut self) -> Result<Value> {
+        let Some(token) = self.peek().cloned() else {
+            return Err(self.unexpected("expression"));
+        };
+        match token {
+            Token::Str(mut value) => {
+                self.pos += 1;
+                while let Some(Token::Str(next)) = self.peek() {
+                    value.push_str(next);
+                    self.pos += 1;
+                }
+                Ok(Value::Str(value))
+            }
+            Token::Punct('[') => {
+                self.pos += 1;
+                self.parse_sequence(']')
+            }
+            Token::Punct('(') => {
+                self.pos += 1;
+                self.parse_sequence(')')
+            }
+            Token::Punct('{') => {
+                self.pos += 1;
+                self.parse_dict()
+            }
+            Token::Punct('-') | Token::Punct('*') => {
+                self.pos += 1;
+                self.parse_term()
+            }
+            Token::Name(name) => {
+                self.pos += 1;
+                if name == "not" {
+                    return self.parse_term();
+                }
+                let mut dotted = false;
+                while self.at_punct('.') {
+                    self.pos += 1;
+                    dotted = true;
+                    if let Some(Token::Name(_)) = self.peek() {
+                        self.pos += 1;
+                    }
+                }
+                if !self.at_punct('(') {
+                    return Ok(if dotted {
+                        Value::Opaque
+                    } else {
+                        self.vars.get(&name).cloned().unwrap_or(Value::Opaque)
+                    });
+                }
+                self.pos += 1;
+                let args = self.parse_args()?;
+                Ok(match name.as_str() {
+                    "glob" if !dotted => self.glob(args),
+                    "select" if !dotted => args
+                        .into_iter()
+                        .find(|(key, _)| key.is_none())
+                        .map_or(Value::Opaque, |(_, value)| value),
+                    _ => Value::Opaque,
+                })
+            }
+            Token::Punct(_) => Err(self.unexpected("expression")),
+        }
+    }
+
+    /// List or tuple items up to `close`; comprehensions are opaque
+    fn parse_sequence(&mut self, close: char) -> Result<Value> {
+        let mut items = Vec::new();
+        loop {
+            if self.at_punct(close) {
+                self.pos += 1;
+                return Ok(Value::List(items));
+            }
+            if self.at_name("for") {
+                self.skip_until_close()?;
+                return Ok(Value::Opaque);
+            }
+            items.extend(self.parse_expr()?.into_strings());
+            if self.at_punct(',') {
+                self.pos += 1;
+            } else if !self.at_punct(close) && !self.at_name("for") {
+                return Err(self.unexpected(&format!("',' or '{}'", close)));
+            }
+        }
+    }
+
+    /// Dict literals evaluate to the union of their values, which is what
+    /// `select()` needs
+    fn parse_dict(&mut self) -> Result<Value> {
+        let mut items = Vec::new();
+        loop {
+            if self.at_punct('}') {
+                self.pos += 1;
+                return Ok(Value::List(items));
+            }
+            self.parse_expr()?;
+            self.expect_punct(':')?;
+            items.extend(self.parse_expr()?.into_strings());
+            if self.at_punct(',') {
+                self.pos += 1;
+            } else if !self.at_punct('}') {
+                return Err(self.unexpected("',' or '}'"));
+            }
+        }
+    }
+
+    /// Call arguments after the opening parenthesis
+    fn parse_args(&mut self) -> Result<Vec<(Option<String>, Value)>> {
+        let mut args = Vec::new();
+        loop {
+            if self.at_punct(')') {
+                self.pos += 1;
+                return Ok(args);
+            }
+            let keyword = match (self.peek(), self.peek_at(1), self.peek_at(2)) {
+                (Some(Token::Name(name)), Some(Token::Punct('=')), next)
+                    if next != Some(&Token::Punct('=')) =>
+                {
+                    Some(name.clone())
+                }
+                _ => None,
+            };
+            if keyword.is_some() {
+                self.pos += 2;
+            }
+            args.push((keyword, self.parse_expr()?));
+            if self.at_punct(',') {
+                self.pos += 1;
+            } else if !self.at_punct(')') {
+                return Err(self.unexpected("',' or ')'"));
+            }
+        }
+    }
+
+    /// Skip to just past the bracket closing the current one
+    fn skip_until_close(&mut self) -> Result<()> {
+        let tokens = self.tokens;
+        let mut depth = 1usize;
+        while let Some(token) = tokens.get(self.pos) {
+            self.pos += 1;
+            match token {
+                Token::Punct('(' | '[' | '{') => depth += 1,
+                Token::Punct(')' | ']' | '}') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                _ => {}
+            }
+        }
+        Err(self.unexpected("closing bracket"))
+    }
+
+    fn glob(&self, args: Vec<(Option<String>, Value)>) -> Value {
+        let mut include = Vec::new();
+        let mut exclude = Vec::new();
+        for (key, value) in args {
+            match key.as_deref() {
+                None | Some("include") if include.is_empty() => include = value.into_strings(),
+                Some("exclude") => exclude = value.into_strings(),
+                _ => {}
+            }
+        }
+        let mut matched: Vec<String> = self
+            .package_files
+            .iter()
+            .filter(|file| include.iter().any(|p| glob_matches(p, file)))
+            .filter(|file| !exclude.iter().any(|p| glob_matches(p, file)))
+            .cloned()
+            .collect();
+        matched.sort();
+        Value::List(matched)
+    }
+}
+
+/// Bazel glob: `**` spans directories, `*` and `?` stay within one
+fn glob_matches(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<&str> = pattern.split('/').collect();
+    let path: Vec<&str> = path.split('/').collect();
+    match_segments(&pattern, &path)
+}
+
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| match_segments(rest, &path[skip..])),
+        Some((segment, rest)) => match path.split_first() {
+            Some((name, path_rest)) => {
+                wildcard_matches(segment.as_bytes(), name.as_bytes())
+                    && match_segments(rest, path_rest)
+            }
+            None => false,
+        },
+    }
+}
+
+fn wildcard_matches(pattern: &[u8], name: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some((b'*', rest)) => (0..=name.len()).any(|skip| wildcard_matches(rest, &name[skip..])),
+        Some((b'?', rest)) => !name.is_empty() && wildcard_matches(rest, &name[1..]),
+        Some((c, rest)) => name.first() == Some(c) && wildcard_matches(rest, &name[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_targets_with_glob_select_and_variables() {
+        let build = r#"
+load("@rules_python//python:defs.bzl", "py_library", "py_test")
+
+package(default_visibility = ["//visibility:public"])
+
+COMMON_DEPS = [
+    "//lib:db",  # database layer
+    "@pypi//requests",
+]
+
+py_library(
+    name = "api",
+    srcs = glob(["**/*.py"], exclude = ["*_test.py"]) + ["//gen:schema.py"],
+    deps = COMMON_DEPS + select({
+        "//conditions:linux": [":linux_io"],
+        "//conditions:default": [],
+    }),
+)
+
+py_test(
+    name = "api_test",
+    srcs = ["api_test.py"],
+    deps = [":api"],
+    tags = ["unit"] if True else [],
+)
+
+my_macro(name = """macro""", size = 3)
+"#;
+        let files: Vec<String> = ["server.py", "handlers/users.py", "api_test.py", "README.md"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let targets = parse_build_file(build, "services/api", &files).unwrap();
+        assert_eq!(targets.len(), 3);
+
+        let api = &targets[0];
+        assert_eq!(api.label, "//services/api:api");
+        assert_eq!(api.kind, "py_library");
+        assert_eq!(
+            api.srcs,
+            vec![
+                "gen/schema.py",
+                "services/api/handlers/users.py",
+                "services/api/server.py"
+            ]
+        );
+        assert_eq!(
+            api.deps,
+            vec![
+                "//lib:db",
+                "//services/api:linux_io",
+                "@pypi//requests:requests"
+            ]
+        );
+
+        assert_eq!(targets[1].deps, vec!["//services/api:api"]);
+        assert_eq!(targets[2].label, "//services/api:macro");
+        assert_eq!(targets[2].kind, "my_macro");
+    }
+}
@@ -0,0 +1,121 @@
+//! Symbol-level change impact in Bazel targets
+
+use std::collections::{BTreeSet, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use crate::features::bazel::domain::{TargetGraph, TargetOverlay};
+use crate::features::cross_file::GlobalContextResult;
+
+/// Targets a change to one symbol invalidates
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TargetImpact {
+    /// Canonical FQN of the changed symbol
+    pub symbol: String,
+    /// The symbol's file and every file depending on it, transitively
+    pub files: Vec<String>,
+    /// Targets listing the symbol's file in `srcs`
+    pub direct: Vec<String>,
+    /// Targets to rebuild/retest: owners of `files` and their reverse deps
+    pub invalidated: Vec<String>,
+}
+
+/// Answers impact queries by combining the file graph with the target graph
+pub struct BazelImpactAnalyzer {
+    graph: TargetGraph,
+}
+
+impl BazelImpactAnalyzer {
+    pub fn new(graph: TargetGraph) -> Self {
+        Self { graph }
+    }
+
+    pub fn graph(&self) -> &TargetGraph {
+        &self.graph
+    }
+
+    /// Which targets does a change to `fqn` invalidate? `None` when the
+    /// symbol is unknown
+    pub fn invalidated_targets(
+        &self,
+        context: &GlobalContextResult,
+        fqn: &str,
+    ) -> Option<TargetImpact> {
+        let symbol_fqn = context.canonical_fqn(fqn);
+        let symbol = context.symbol_table.get(symbol_fqn)?;
+
+        let mut files = BTreeSet::new();
+        let mut queue = VecDeque::from([symbol.file_path.clone()]);
+        while let Some(file) = queue.pop_front() {
+            if let Some(dependents) = context.file_dependents.get(&file) {
+                queue.extend(dependents.iter().filter(|d| !files.contains(*d)).cloned());
+            }
+            files.insert(file);
+        }
+        let files: Vec<String> = files.into_iter().collect();
+
+        Some(TargetImpact {
+            symbol: symbol_fqn.to_string(),
+            direct: self.graph.owners(&symbol.file_path).to_vec(),
+            invalidated: self.graph.invalidated_by(&files),
+            files,
+        })
+    }
+
+    /// Target-level overlay of the context's file dependencies
+    pub fn overlay(&self, context: &GlobalContextResult) -> TargetOverlay {
+        self.graph.overlay(&context.file_dependencies)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::bazel::domain::BazelTarget;
+    use crate::features::cross_file::Symbol;
+    use crate::shared::models::{NodeKind, Span};
+
+    #[test]
+    fn test_invalidated_targets_follow_file_dependents() {
+        let target = |label: &str, src: &str, deps: &[&str]| BazelTarget {
+            label: label.to_string(),
+            kind: "py_library".to_string(),
+            package: String::new(),
+            srcs: vec![src.to_string()],
+            deps: deps.iter().map(|d| d.to_string()).collect(),
+        };
+        let analyzer = BazelImpactAnalyzer::new(TargetGraph::new(vec![
+            target("//lib:db", "lib/db.py", &[]),
+            target("//app:server", "app/server.py", &[]),
+            target("//app:server_test", "app/server_test.py", &["//app:server"]),
+            target("//tools:cli", "tools/cli.py", &[]),
+        ]));
+
+        let mut context = GlobalContextResult::default();
+        context.symbol_table.insert(
+            "lib.db.connect".to_string(),
+            Symbol::new(
+                "lib.db.connect".to_string(),
+                "connect".to_string(),
+                NodeKind::Function,
+                "lib/db.py".to_string(),
+                "n1".to_string(),
+                Span::new(1, 0, 3, 0),
+            ),
+        );
+        context
+            .file_dependents
+            .insert("lib/db.py".to_string(), vec!["app/server.py".to_string()]);
+
+        let impact = analyzer
+            .invalidated_targets(&context, "lib.db.connect")
+            .unwrap();
+        assert_eq!(impact.files, vec!["app/server.py", "lib/db.py"]);
+        assert_eq!(impact.direct, vec!["//lib:db"]);
+        assert_eq!(
+            impact.invalidated,
+            vec!["//app:server", "//app:server_test", "//lib:db"]
+        );
+        assert!(analyzer.invalidated_targets(&context, "missing").is_none());
+    }
+}
@@ -0,0 +1,9 @@
+//! Bazel Application Layer
+
+mod build_parser;
+mod impact;
+mod workspace;
+
+pub use build_parser::parse_build_file;
+pub use impact::{BazelImpactAnalyzer, TargetImpact};
+pub use workspace::load_workspace;
@@ -0,0 +1,84 @@
+//! Workspace loading: every BUILD file under a repository root
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use walkdir::WalkDir;
+
+use super::build_parser::parse_build_file;
+use crate::features::bazel::domain::TargetGraph;
+
+/// BUILD file names, preferred first
+const BUILD_FILES: &[&str] = &["BUILD.bazel", "BUILD"];
+
+/// Target graph of the workspace at `repo_root`; paths are relative to it
+///
+/// Output trees (`bazel-*`) and hidden directories are skipped. BUILD files
+/// that fail to parse are logged and skipped.
+pub fn load_workspace(repo_root: &Path) -> TargetGraph {
+    let entries = WalkDir::new(repo_root).into_iter().filter_entry(|e| {
+        let name = e.file_name().to_string_lossy();
+        e.depth() == 0 || !(name.starts_with('.') || name.starts_with("bazel-"))
+    });
+    let files: Vec<String> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            let relative = e.path().strip_prefix(repo_root).ok()?;
+            Some(relative.to_string_lossy().replace('\\', "/"))
+        })
+        .collect();
+
+    let packages: HashSet<&str> = files
+        .iter()
+        .filter(|f| BUILD_FILES.contains(&file_name(f)))
+        .map(|f| parent_dir(f))
+        .collect();
+
+    // Package → files relative to it (subpackages excluded)
+    let mut package_files: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for file in &files {
+        let mut dir = parent_dir(file);
+        loop {
+            if packages.contains(dir) {
+                let relative = file[dir.len()..].trim_start_matches('/');
+                package_files
+                    .entry(dir)
+                    .or_default()
+                    .push(relative.to_string());
+                break;
+            }
+            if dir.is_empty() {
+                break;
+            }
+            dir = parent_dir(dir);
+        }
+    }
+
+    let mut targets = Vec::new();
+    for (package, files) in &package_files {
+        let Some(build_file) = BUILD_FILES
+            .iter()
+            .find(|name| files.iter().any(|f| f == *name))
+        else {
+            continue;
+        };
+        let path = repo_root.join(package).join(build_file);
+        let parsed = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| parse_build_file(&text, package, files).map_err(|e| e.to_string()));
+        match parsed {
+            Ok(declared) => targets.extend(declared),
+            Err(e) => tracing::warn!("[bazel] Skipping {}: {}", path.display(), e),
+        }
+    }
+    TargetGraph::new(targets)
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+fn parent_dir(path: &str) -> &str {
+    path.rsplit_once('/').map_or("", |(dir, _)| dir)
+}
@@ -0,0 +1,236 @@
+//! Target-level dependency graph and its overlay on the file graph
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use super::target::BazelTarget;
+
+/// Targets of a workspace with file ownership and reverse dependencies
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TargetGraph {
+    targets: BTreeMap<String, BazelTarget>,
+    /// File → labels of the targets listing it in `srcs`
+    owners: HashMap<String, Vec<String>>,
+    /// Label → labels of the targets depending on it directly
+    rdeps: HashMap<String, Vec<String>>,
+}
+
+impl TargetGraph {
+    pub fn new(targets: Vec<BazelTarget>) -> Self {
+        let mut graph = Self::default();
+        for target in targets {
+            for src in &target.srcs {
+                graph
+                    .owners
+                    .entry(src.clone())
+                    .or_default()
+                    .push(target.label.clone());
+            }
+            for dep in &target.deps {
+                graph
+                    .rdeps
+                    .entry(dep.clone())
+                    .or_default()
+                    .push(target.label.clone());
+            }
+            graph.targets.insert(target.label.clone(), target);
+        }
+        graph.owners.values_mut().for_each(|labels| labels.sort());
+        graph.rdeps.values_mut().for_each(|labels| labels.sort());
+        graph
+    }
+
+    pub fn len(&self) -> usize {
+        self.targets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    pub fn target(&self, label: &str) -> Option<&BazelTarget> {
+        self.targets.get(label)
+    }
+
+    /// Targets in label order
+    pub fn targets(&self) -> impl Iterator<Item = &BazelTarget> {
+        self.targets.values()
+    }
+
+    /// Labels of the targets listing `file` in `srcs`
+    pub fn owners(&self, file: &str) -> &[String] {
+        self.owners.get(file).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// `labels` and every target depending on them, transitively (sorted)
+    pub fn reverse_dependencies<'a>(
+        &self,
+        labels: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<String> {
+        let mut seen: BTreeSet<String> = BTreeSet::new();
+        let mut queue: VecDeque<String> = labels.into_iter().map(str::to_string).collect();
+        while let Some(label) = queue.pop_front() {
+            if !seen.insert(label.clone()) {
+                continue;
+            }
+            if let Some(dependents) = self.rdeps.get(&label) {
+                queue.extend(dependents.iter().cloned());
+            }
+        }
+        seen.into_iter().collect()
+    }
+
+    /// Targets a change to `files` invalidates: their owners and everything
+    /// depending on those owners
+    pub fn invalidated_by(&self, files: &[String]) -> Vec<String> {
+        let owners = files
+            .iter()
+            .flat_map(|file| self.owners(file))
+            .map(String::as_str);
+        self.reverse_dependencies(owners)
+    }
+
+    /// Lift file dependencies (`importer → imported`) to target edges
+    ///
+    /// An edge is declared when the importing target lists the imported
+    /// one in `deps` directly; undeclared edges are strict-deps violations.
+    pub fn overlay(&self, file_dependencies: &HashMap<String, Vec<String>>) -> TargetOverlay {
+        let mut edges: BTreeMap<(String, String), usize> = BTreeMap::new();
+        let mut unowned: BTreeSet<String> = BTreeSet::new();
+        for (from_file, to_files) in file_dependencies {
+            let from_owners = self.owners(from_file);
+            if from_owners.is_empty() {
+                unowned.insert(from_file.clone());
+                continue;
+            }
+            for to_file in to_files {
+                for from in from_owners {
+                    for to in self.owners(to_file) {
+                        if from != to {
+                            *edges.entry((from.clone(), to.clone())).or_default() += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        let edges = edges
+            .into_iter()
+            .map(|((from, to), file_edges)| {
+                let declared = self
+                    .targets
+                    .get(&from)
+                    .is_some_and(|t| t.deps.contains(&to));
+                TargetEdge {
+                    from,
+                    to,
+                    declared,
+                    file_edges,
+                }
+            })
+            .collect();
+        TargetOverlay {
+            edges,
+            unowned_files: unowned.into_iter().collect(),
+        }
+    }
+}
+
+/// Target edge implied by file dependencies
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TargetEdge {
+    pub from: String,
+    pub to: String,
+    /// `to` is a direct `deps` entry of `from`
+    pub declared: bool,
+    /// File dependencies behind the edge
+    pub file_edges: usize,
+}
+
+/// Target-level view of the file dependency graph
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TargetOverlay {
+    pub edges: Vec<TargetEdge>,
+    /// Importing files no target lists in `srcs`
+    pub unowned_files: Vec<String>,
+}
+
+impl TargetOverlay {
+    /// Edges the BUILD files do not declare
+    pub fn undeclared(&self) -> impl Iterator<Item = &TargetEdge> {
+        self.edges.iter().filter(|e| !e.declared)
+    }
+
+    /// Declared dependencies no file dependency uses
+    pub fn unused_deps<'a>(&self, graph: &'a TargetGraph) -> Vec<(&'a str, &'a str)> {
+        let used: HashSet<(&str, &str)> = self
+            .edges
+            .iter()
+            .map(|e| (e.from.as_str(), e.to.as_str()))
+            .collect();
+        graph
+            .targets()
+            .flat_map(|t| {
+                t.deps
+                    .iter()
+                    .map(move |dep| (t.label.as_str(), dep.as_str()))
+            })
+            .filter(|(from, to)| graph.target(to).is_some() && !used.contains(&(*from, *to)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(label: &str, srcs: &[&str], deps: &[&str]) -> BazelTarget {
+        BazelTarget {
+            label: label.to_string(),
+            kind: "py_library".to_string(),
+            package: String::new(),
+            srcs: srcs.iter().map(|s| s.to_string()).collect(),
+            deps: deps.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_invalidation_and_overlay() {
+        let graph = TargetGraph::new(vec![
+            target("//lib:db", &["lib/db.py"], &[]),
+            target("//lib:models", &["lib/models.py"], &["//lib:db"]),
+            target(
+                "//app:server",
+                &["app/server.py"],
+                &["//lib:models", "//lib:db"],
+            ),
+            target("//tools:cli", &["tools/cli.py"], &[]),
+        ]);
+
+        assert_eq!(
+            graph.invalidated_by(&["lib/db.py".to_string()]),
+            vec!["//app:server", "//lib:db", "//lib:models"]
+        );
+
+        let files = HashMap::from([
+            (
+                "app/server.py".to_string(),
+                vec!["lib/models.py".to_string()],
+            ),
+            ("tools/cli.py".to_string(), vec!["lib/db.py".to_string()]),
+            ("scripts/run.py".to_string(), vec!["lib/db.py".to_string()]),
+        ]);
+        let overlay = graph.overlay(&files);
+        let undeclared: Vec<_> = overlay
+            .undeclared()
+            .map(|e| (e.from.as_str(), e.to.as_str()))
+            .collect();
+        assert_eq!(undeclared, vec![("//tools:cli", "//lib:db")]);
+        assert_eq!(overlay.unowned_files, vec!["scripts/run.py"]);
+        assert_eq!(
+            overlay.unused_deps(&graph),
+            vec![("//app:server", "//lib:db"), ("//lib:models", "//lib:db")]
+        );
+    }
+}
@@ -0,0 +1,7 @@
+//! Domain models for Bazel targets
+
+mod graph;
+mod target;
+
+pub use graph::{TargetEdge, TargetGraph, TargetOverlay};
+pub use target::{canonical_label, split_label, BazelTarget};
@@ -0,0 +1,58 @@
+//! Bazel targets and labels
+
+use serde::{Deserialize, Serialize};
+
+/// A rule instance declared in a BUILD file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BazelTarget {
+    /// Canonical label (`//services/api:server`)
+    pub label: String,
+    /// Rule or macro name (`py_library`, `go_binary`)
+    pub kind: String,
+    /// Package directory relative to the workspace root (`services/api`)
+    pub package: String,
+    /// Source files (`srcs`, `hdrs`) relative to the workspace root
+    pub srcs: Vec<String>,
+    /// Canonical labels of `deps`, `runtime_deps` and `exports`
+    pub deps: Vec<String>,
+}
+
+impl BazelTarget {
+    /// Target name (`server` for `//services/api:server`)
+    pub fn name(&self) -> &str {
+        self.label
+            .rsplit_once(':')
+            .map_or(self.label.as_str(), |(_, name)| name)
+    }
+}
+
+/// Canonical form of `label` written in `package`
+///
+/// `:lib` and `lib` → `//pkg:lib`, `//a/b` → `//a/b:b`; external labels
+/// (`@repo//x:y`) are kept with their repository.
+pub fn canonical_label(package: &str, label: &str) -> String {
+    let (repo, rest) = match label.strip_prefix('@') {
+        Some(external) => match external.find("//") {
+            Some(i) => (&label[..i + 1], &external[i..]),
+            None => return format!("{}//:{}", label, external),
+        },
+        None => ("", label),
+    };
+    let body = if let Some(absolute) = rest.strip_prefix("//") {
+        match absolute.split_once(':') {
+            Some((pkg, name)) => format!("//{}:{}", pkg, name),
+            None => {
+                let name = absolute.rsplit('/').next().unwrap_or(absolute);
+                format!("//{}:{}", absolute, name)
+            }
+        }
+    } else {
+        format!("//{}:{}", package, rest.trim_start_matches(':'))
+    };
+    format!("{}{}", repo, body)
+}
+
+/// (package, name) of a canonical workspace label
+pub fn split_label(label: &str) -> Option<(&str, &str)> {
+    label.strip_prefix("//")?.split_once(':')
+}
@@ -0,0 +1,51 @@
+//! Bazel - BUILD target graph over the file graph
+//!
+//! Parses BUILD/BUILD.bazel files into targets (label, kind, `srcs`,
+//! `deps`) and overlays the target-level dependency graph onto the
+//! cross-file dependency graph. This answers "which targets does changing
+//! this symbol invalidate?" and flags strict-deps violations: file imports
+//! crossing targets that do not declare each other in `deps`.
+//!
+//! # Architecture
+//!
+//! ```text
+//! ┌─────────────────────────────────────────┐
+//! │ Domain Layer                            │
+//! │  - BazelTarget (label, srcs, deps)      │
+//! │  - TargetGraph (owners, reverse deps)   │
+//! │  - TargetOverlay (declared/undeclared)  │
+//! └─────────────────────────────────────────┘
+//!                   ▲
+//!                   │
+//! ┌─────────────────────────────────────────┐
+//! │ Application Layer                       │
+//! │  - parse_build_file (Starlark subset)   │
+//! │  - load_workspace (BUILD file walk)     │
+//! │  - BazelImpactAnalyzer (invalidation)   │
+//! └─────────────────────────────────────────┘
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use codegraph_ir::features::bazel::{load_workspace, BazelImpactAnalyzer};
+//!
+//! let analyzer = BazelImpactAnalyzer::new(load_workspace(repo_root));
+//! if let Some(impact) = analyzer.invalidated_targets(&context, "lib.db.connect") {
+//!     println!("{} targets to rebuild", impact.invalidated.len());
+//! }
+//! for edge in analyzer.overlay(&context).undeclared() {
+//!     println!("{} uses {} without declaring it", edge.from, edge.to);
+//! }
+//! ```
+
+pub mod application;
+pub mod domain;
+
+// Re-export application layer
+pub use application::{load_workspace, parse_build_file, BazelImpactAnalyzer, TargetImpact};
+
+// Re-exports for convenience
+pub use domain::{
+    canonical_label, split_label, BazelTarget, TargetEdge, TargetGraph, TargetOverlay,
+};
@@ -14,6 +14,10 @@ pub enum Language {
     Java,
     Kotlin,
     Go,
+    C,
+    Cpp,
+    Php,
+    Ruby,
 }
 
 impl Language {
@@ -26,6 +30,10 @@ impl Language {
             Language::Java => "java",
             Language::Kotlin => "kotlin",
             Language::Go => "go",
+            Language::C => "c",
+            Language::Cpp => "cpp",
+            Language::Php => "php",
+            Language::Ruby => "ruby",
         }
     }
 }
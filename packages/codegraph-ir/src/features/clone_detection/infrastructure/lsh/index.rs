@@ -143,6 +143,37 @@ impl LSHIndex {
         candidates.into_iter().collect()
     }
 
+    /// Query for candidates, ignoring buckets larger than `max_bucket_size`
+    ///
+    /// Oversized buckets come from boilerplate shared by many fragments
+    /// (getters, `__init__`); skipping them keeps queries sub-linear on
+    /// large repositories at the cost of recall for those fragments.
+    pub fn query_bounded(
+        &self,
+        signature: &MinHashSignature,
+        max_bucket_size: usize,
+    ) -> Vec<usize> {
+        assert_eq!(
+            signature.num_hashes(),
+            self.num_bands * self.rows_per_band,
+            "Query signature size must match index configuration"
+        );
+
+        let mut candidates = HashSet::new();
+
+        for band_idx in 0..self.num_bands {
+            let band_hash = self.hash_band(signature, band_idx);
+
+            if let Some(bucket) = self.buckets[band_idx].get(&band_hash) {
+                if bucket.len() <= max_bucket_size {
+                    candidates.extend(bucket.iter().copied());
+                }
+            }
+        }
+
+        candidates.into_iter().collect()
+    }
+
     /// Hash a specific band of a signature
     fn hash_band(&self, signature: &MinHashSignature, band_idx: usize) -> BandHash {
         let start = band_idx * self.rows_per_band;
@@ -277,6 +308,18 @@ mod tests {
         assert_eq!(candidates.len(), 0);
     }
 
+    #[test]
+    fn test_query_bounded_skips_oversized_buckets() {
+        let mut index = LSHIndex::new(16, 8);
+        let sig = create_signature("def __init__(self): pass");
+        for i in 0..5 {
+            index.insert(&sig, i);
+        }
+
+        assert_eq!(index.query_bounded(&sig, 5).len(), 5);
+        assert!(index.query_bounded(&sig, 4).is_empty());
+    }
+
     #[test]
     fn test_multiple_bands_increase_recall() {
         // More bands → higher recall
@@ -91,15 +91,16 @@ impl MinHashSignature {
 
     /// Hash a value with a seed (simulates independent hash functions)
     ///
-    /// Uses FNV-1a variant for speed + quality.
+    /// The seed is spread over all bits and the result run through the
+    /// splitmix64 finalizer. (A single FNV step only perturbs the low bits,
+    /// so every hash function picked the same minimum shingle.)
     fn hash_with_seed(value: u64, seed: u64) -> u64 {
-        // FNV-1a constants
-        const FNV_OFFSET: u64 = 14695981039346656037;
-        const FNV_PRIME: u64 = 1099511628211;
+        const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
 
-        let mut hash = FNV_OFFSET ^ seed;
-        hash = (hash ^ value).wrapping_mul(FNV_PRIME);
-        hash
+        let mut z = value ^ seed.wrapping_add(1).wrapping_mul(GOLDEN_GAMMA);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
     }
 
     /// Estimate Jaccard similarity from MinHash signatures
@@ -221,6 +222,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_estimate_tracks_jaccard() {
+        // |A ∩ B| = 50, |A ∪ B| = 150
+        let a: HashSet<u64> = (0..100).collect();
+        let b: HashSet<u64> = (50..150).collect();
+
+        let sim = MinHashSignature::from_shingles(&a, 128)
+            .jaccard_estimate(&MinHashSignature::from_shingles(&b, 128));
+        assert!(
+            (sim - 1.0 / 3.0).abs() < 0.15,
+            "estimate {} far from 0.33",
+            sim
+        );
+    }
+
     #[test]
     fn test_jaccard_symmetry() {
         let sig1 = MinHashSignature::from_text("foo bar", 3, 64);
@@ -14,6 +14,7 @@ mod edge_case_tests;
 pub mod hybrid_detector; // SOTA: Industry-grade hybrid approach
 pub mod lsh; // LSH infrastructure for optimization
 pub mod optimized_detector; // Phase 4: Optimized detector with LSH + caching
+pub mod repo_clone_index; // Repository-wide Type-3/4 via MinHash + WL LSH
 pub mod token_hash_index; // Fast Type-1 detection via token hashing
 pub mod type1_detector;
 pub mod type2_detector;
@@ -22,6 +23,7 @@ pub mod type4_detector; // Edge case and stress tests
 
 pub use hybrid_detector::{HybridCloneDetector, HybridDetectorStats};
 pub use optimized_detector::{OptimizedCloneDetector, OptimizedDetectorStats};
+pub use repo_clone_index::{RepoCloneIndex, RepoCloneIndexConfig, RepoCloneIndexStats};
 pub use token_hash_index::{TokenHashIndex, TokenHashStats};
 pub use type1_detector::Type1Detector;
 pub use type2_detector::Type2Detector;
@@ -0,0 +1,504 @@
+//! Repository-wide Clone Index
+//!
+//! The baseline Type-3/4 detectors compare every fragment pair, which only
+//! scales to a file or a small package. This index finds near-duplicate
+//! functions across a whole repository with sub-quadratic candidate
+//! retrieval:
+//!
+//! ```text
+//! Type-3: token k-shingles → MinHash → banded LSH → MinHash Jaccard ≥ min_similarity
+//! Type-4: PDG → WL signature → graph LSH (+ text candidates) → WL similarity ≥ min_semantic_similarity
+//! ```
+//!
+//! # Safeguards for large repositories
+//!
+//! - `min_tokens`: small fragments (getters, stubs) are not indexed
+//! - `max_bucket_size`: LSH buckets shared by more fragments are ignored
+//! - `max_candidates`: candidates verified per fragment, best estimate first
+//!
+//! Exact and renamed duplicates also match here (as Type-3); when merging
+//! with the Type-1/2 detectors, list their pairs first.
+
+use super::lsh::{
+    GraphLSHIndex, GraphLSHIndexStats, LSHIndex, LSHIndexStats, MinHashSignature, WLSignature,
+};
+use super::Type4Detector;
+use crate::features::clone_detection::domain::{
+    CloneMetrics, ClonePair, CloneType, CodeFragment, DetectionInfo,
+};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+/// Repository clone index configuration
+#[derive(Debug, Clone)]
+pub struct RepoCloneIndexConfig {
+    /// Report Type-3 (token similarity) pairs
+    pub enable_type3: bool,
+
+    /// Report Type-4 (PDG similarity) pairs
+    pub enable_type4: bool,
+
+    /// Minimum token shingle Jaccard for Type-3 [0.0, 1.0]
+    pub min_similarity: f64,
+
+    /// Minimum WL graph similarity for Type-4 [0.0, 1.0]
+    pub min_semantic_similarity: f64,
+
+    /// Fragments with fewer tokens are not indexed
+    pub min_tokens: usize,
+
+    /// Candidates verified per fragment
+    pub max_candidates: usize,
+
+    /// LSH buckets with more fragments are ignored
+    pub max_bucket_size: usize,
+
+    /// Tokens per shingle
+    pub shingle_size: usize,
+
+    /// LSH bands (signature size = bands × rows)
+    pub num_bands: usize,
+
+    /// LSH rows per band
+    pub rows_per_band: usize,
+
+    /// WL refinement iterations for Type-4 signatures
+    pub wl_iterations: usize,
+
+    /// Only report pairs whose fragments are in different files
+    pub cross_file_only: bool,
+}
+
+impl Default for RepoCloneIndexConfig {
+    fn default() -> Self {
+        Self {
+            enable_type3: true,
+            enable_type4: true,
+            min_similarity: 0.7,
+            min_semantic_similarity: 0.9,
+            min_tokens: 30,
+            max_candidates: 50,
+            max_bucket_size: 256,
+            shingle_size: 5,
+            num_bands: 32, // t ≈ 0.42, catches pairs well below min_similarity
+            rows_per_band: 4,
+            wl_iterations: 2,
+            cross_file_only: true,
+        }
+    }
+}
+
+/// Indexed fragment representations
+struct IndexedFragment {
+    fragment: CodeFragment,
+    minhash: MinHashSignature,
+    /// None when the PDG is empty (nothing to compare structurally)
+    wl_signature: Option<WLSignature>,
+}
+
+/// MinHash/LSH + WL index over all function fragments of a repository
+pub struct RepoCloneIndex {
+    config: RepoCloneIndexConfig,
+    entries: Vec<IndexedFragment>,
+    text_lsh: LSHIndex,
+    graph_lsh: GraphLSHIndex,
+    pdg_builder: Type4Detector,
+    skipped_small: usize,
+}
+
+impl RepoCloneIndex {
+    /// Create an empty index
+    pub fn new(config: RepoCloneIndexConfig) -> Self {
+        Self {
+            text_lsh: LSHIndex::new(config.num_bands, config.rows_per_band),
+            graph_lsh: GraphLSHIndex::new(0),
+            pdg_builder: Type4Detector::new(),
+            entries: Vec::new(),
+            skipped_small: 0,
+            config,
+        }
+    }
+
+    /// Build an index over `fragments`
+    pub fn build(config: RepoCloneIndexConfig, fragments: &[CodeFragment]) -> Self {
+        let mut index = Self::new(config);
+        for fragment in fragments {
+            index.insert(fragment.clone());
+        }
+        index
+    }
+
+    /// Index a fragment
+    ///
+    /// Returns false if it has fewer than `min_tokens` tokens.
+    pub fn insert(&mut self, fragment: CodeFragment) -> bool {
+        let tokens = tokenize(&fragment.content);
+        if tokens.len() < self.config.min_tokens.max(1) {
+            self.skipped_small += 1;
+            return false;
+        }
+
+        let shingles = token_shingles(&tokens, self.config.shingle_size);
+        let minhash = MinHashSignature::from_shingles(
+            &shingles,
+            self.config.num_bands * self.config.rows_per_band,
+        );
+        let pdg = self.pdg_builder.build_pdg(&fragment.content);
+        let wl_signature = if pdg.nodes.is_empty() {
+            None
+        } else {
+            Some(WLSignature::from_pdg(&pdg, self.config.wl_iterations))
+        };
+
+        let id = self.entries.len();
+        self.text_lsh.insert(&minhash, id);
+        if let Some(ref signature) = wl_signature {
+            self.graph_lsh.insert(signature, id);
+        }
+        self.entries.push(IndexedFragment {
+            fragment,
+            minhash,
+            wl_signature,
+        });
+        true
+    }
+
+    /// Number of indexed fragments
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Find Type-3/4 clone pairs across the indexed fragments
+    ///
+    /// Each pair is reported once, with the earlier-indexed fragment as
+    /// source. A pair that passes the Type-3 threshold is not re-reported
+    /// as Type-4.
+    pub fn detect(&self) -> Vec<ClonePair> {
+        let mut pairs = Vec::new();
+
+        for (id, entry) in self.entries.iter().enumerate() {
+            for (candidate, token_similarity) in self.candidates(id) {
+                if let Some(pair) = self.verify(entry, &self.entries[candidate], token_similarity) {
+                    pairs.push(pair);
+                }
+            }
+        }
+
+        pairs
+    }
+
+    /// Ranked candidates for `id` (later fragments only)
+    fn candidates(&self, id: usize) -> Vec<(usize, f64)> {
+        let entry = &self.entries[id];
+        let mut ids: HashSet<usize> = self
+            .text_lsh
+            .query_bounded(&entry.minhash, self.config.max_bucket_size)
+            .into_iter()
+            .collect();
+        if self.config.enable_type4 {
+            if let Some(ref signature) = entry.wl_signature {
+                let bucket = self.graph_lsh.query(signature);
+                if bucket.len() <= self.config.max_bucket_size {
+                    ids.extend(bucket);
+                }
+            }
+        }
+
+        let mut ranked: Vec<(usize, f64)> = ids
+            .into_iter()
+            .filter(|&other| other > id && self.comparable(entry, &self.entries[other]))
+            .map(|other| {
+                let similarity = entry.minhash.jaccard_estimate(&self.entries[other].minhash);
+                (other, similarity)
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        ranked.truncate(self.config.max_candidates);
+        ranked
+    }
+
+    fn comparable(&self, a: &IndexedFragment, b: &IndexedFragment) -> bool {
+        if a.fragment.file_path != b.fragment.file_path {
+            return true;
+        }
+        !self.config.cross_file_only && !a.fragment.overlaps(&b.fragment)
+    }
+
+    fn verify(
+        &self,
+        source: &IndexedFragment,
+        target: &IndexedFragment,
+        token_similarity: f64,
+    ) -> Option<ClonePair> {
+        let (source_fragment, target_fragment) = (&source.fragment, &target.fragment);
+        let tokens = source_fragment.token_count.min(target_fragment.token_count);
+        let loc = source_fragment.loc.min(target_fragment.loc);
+
+        if self.config.enable_type3 && token_similarity >= self.config.min_similarity {
+            let metrics = CloneMetrics::new(tokens, loc, token_similarity);
+            let detection_info = DetectionInfo::new("Type-3 (Repository MinHash LSH)".to_string())
+                .with_confidence(token_similarity);
+            return Some(
+                ClonePair::new(
+                    CloneType::Type3,
+                    source_fragment.clone(),
+                    target_fragment.clone(),
+                    token_similarity,
+                )
+                .with_metrics(metrics)
+                .with_detection_info(detection_info),
+            );
+        }
+
+        if !self.config.enable_type4 {
+            return None;
+        }
+        let similarity = source
+            .wl_signature
+            .as_ref()?
+            .similarity(target.wl_signature.as_ref()?);
+        if similarity < self.config.min_semantic_similarity {
+            return None;
+        }
+
+        let mut metrics =
+            CloneMetrics::new(tokens, loc, similarity).with_semantic_similarity(similarity);
+        metrics.token_similarity = token_similarity;
+        let detection_info = DetectionInfo::new("Type-4 (Repository WL graph LSH)".to_string())
+            .with_confidence(similarity);
+        Some(
+            ClonePair::new(
+                CloneType::Type4,
+                source_fragment.clone(),
+                target_fragment.clone(),
+                similarity,
+            )
+            .with_metrics(metrics)
+            .with_detection_info(detection_info),
+        )
+    }
+
+    /// Get index statistics
+    pub fn stats(&self) -> RepoCloneIndexStats {
+        RepoCloneIndexStats {
+            indexed_fragments: self.entries.len(),
+            skipped_small: self.skipped_small,
+            text_index: self.text_lsh.stats(),
+            graph_index: self.graph_lsh.stats(),
+        }
+    }
+}
+
+/// Repository clone index statistics
+#[derive(Debug, Clone)]
+pub struct RepoCloneIndexStats {
+    pub indexed_fragments: usize,
+    pub skipped_small: usize,
+    pub text_index: LSHIndexStats,
+    pub graph_index: GraphLSHIndexStats,
+}
+
+/// Split source into identifier/number runs and single punctuation tokens
+///
+/// Whitespace and `#` / `//` line comments are dropped, so formatting
+/// differences do not change the shingle set.
+fn tokenize(content: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('#') || line.starts_with("//") {
+            continue;
+        }
+
+        let mut word_start = None;
+        for (i, c) in line.char_indices() {
+            if c.is_alphanumeric() || c == '_' {
+                word_start.get_or_insert(i);
+                continue;
+            }
+            if let Some(start) = word_start.take() {
+                tokens.push(&line[start..i]);
+            }
+            if !c.is_whitespace() {
+                tokens.push(&line[i..i + c.len_utf8()]);
+            }
+        }
+        if let Some(start) = word_start {
+            tokens.push(&line[start..]);
+        }
+    }
+
+    tokens
+}
+
+/// Hashed k-token windows (the whole sequence if shorter than k)
+fn token_shingles(tokens: &[&str], k: usize) -> HashSet<u64> {
+    let hash = |window: &[&str]| {
+        let mut hasher = DefaultHasher::new();
+        window.hash(&mut hasher);
+        hasher.finish()
+    };
+
+    let k = k.max(1);
+    if tokens.len() < k {
+        return std::iter::once(hash(tokens)).collect();
+    }
+    tokens.windows(k).map(hash).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::models::Span;
+
+    fn fragment(file: &str, start: u32, content: &str) -> CodeFragment {
+        let loc = content.lines().count();
+        CodeFragment::new(
+            file.to_string(),
+            Span::new(start, 0, start + loc as u32 - 1, 0),
+            content.to_string(),
+            content.split_whitespace().count(),
+            loc,
+        )
+    }
+
+    const LOAD_CONFIG: &str = "def load_config(path, defaults):
+    with open(path) as handle:
+        data = json.load(handle)
+    for key, value in defaults.items():
+        data.setdefault(key, value)
+    validate_schema(data, CONFIG_SCHEMA)
+    return data";
+
+    const LOAD_CONFIG_LOGGED: &str = "def load_config(path, defaults):
+    with open(path) as handle:
+        data = json.load(handle)
+    for key, value in defaults.items():
+        data.setdefault(key, value)
+    logger.info(\"loaded %s\", path)
+    validate_schema(data, CONFIG_SCHEMA)
+    return data";
+
+    const RENDER: &str = "class Renderer:
+    def render(self, template, context):
+        output = []
+        for node in template.nodes:
+            output.append(node.render(context))
+        return ''.join(output)";
+
+    fn config(min_tokens: usize) -> RepoCloneIndexConfig {
+        RepoCloneIndexConfig {
+            min_tokens,
+            min_similarity: 0.6,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_tokenize_drops_whitespace_and_comments() {
+        let tokens = tokenize("# note\n  x  =  f(a_1)\n    // comment");
+        assert_eq!(tokens, vec!["x", "=", "f", "(", "a_1", ")"]);
+        assert_eq!(tokenize("x=f(a_1)"), tokenize("x = f( a_1 )"));
+    }
+
+    #[test]
+    fn test_gapped_clone_across_directories() {
+        let fragments = vec![
+            fragment("services/billing/config.py", 10, LOAD_CONFIG),
+            fragment("tools/render.py", 1, RENDER),
+            fragment("services/shipping/settings.py", 40, LOAD_CONFIG_LOGGED),
+        ];
+        let index = RepoCloneIndex::build(config(10), &fragments);
+        assert_eq!(index.len(), 3);
+
+        let pairs = index.detect();
+        assert_eq!(pairs.len(), 1);
+        let pair = &pairs[0];
+        assert_eq!(pair.clone_type, CloneType::Type3);
+        assert_eq!(pair.source.file_path, "services/billing/config.py");
+        assert_eq!(pair.target.file_path, "services/shipping/settings.py");
+        assert!(pair.similarity >= 0.6 && pair.similarity < 1.0);
+    }
+
+    #[test]
+    fn test_semantic_clone_with_renamed_identifiers() {
+        let fragments = vec![
+            fragment(
+                "a/sum.py",
+                1,
+                "def sum_items(items):\n    total = 0\n    for item in items:\n        total += item\n    return total",
+            ),
+            fragment(
+                "b/acc.py",
+                1,
+                "def add_all(values):\n    acc = 0\n    for value in values:\n        acc += value\n    return acc",
+            ),
+        ];
+        let index = RepoCloneIndex::build(config(5), &fragments);
+
+        let pairs = index.detect();
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].clone_type, CloneType::Type4);
+        assert_eq!(pairs[0].metrics.semantic_similarity, Some(1.0));
+        assert!(pairs[0].metrics.token_similarity < 0.6);
+    }
+
+    #[test]
+    fn test_safeguards() {
+        let fragments = vec![
+            fragment("a.py", 1, LOAD_CONFIG),
+            fragment("a.py", 20, LOAD_CONFIG_LOGGED),
+            fragment("b.py", 1, LOAD_CONFIG),
+        ];
+
+        // Same-file pair is ignored by default
+        let pairs = RepoCloneIndex::build(config(10), &fragments).detect();
+        assert_eq!(pairs.len(), 2);
+        assert!(pairs.iter().all(|p| !p.is_same_file()));
+
+        let pairs = RepoCloneIndex::build(
+            RepoCloneIndexConfig {
+                cross_file_only: false,
+                ..config(10)
+            },
+            &fragments,
+        )
+        .detect();
+        assert_eq!(pairs.len(), 3);
+
+        // One candidate per fragment: the exact copy wins over the gapped one
+        let pairs = RepoCloneIndex::build(
+            RepoCloneIndexConfig {
+                max_candidates: 1,
+                cross_file_only: false,
+                ..config(10)
+            },
+            &fragments,
+        )
+        .detect();
+        assert_eq!(pairs.len(), 2);
+        assert_eq!(pairs[0].target.file_path, "b.py");
+        assert_eq!(pairs[0].similarity, 1.0);
+
+        // Buckets with all three fragments exceed the limit
+        let pairs = RepoCloneIndex::build(
+            RepoCloneIndexConfig {
+                max_bucket_size: 1,
+                ..config(10)
+            },
+            &fragments,
+        )
+        .detect();
+        assert!(pairs.is_empty());
+
+        let index = RepoCloneIndex::build(config(1000), &fragments);
+        assert!(index.is_empty());
+        assert_eq!(index.stats().skipped_small, 3);
+    }
+}
@@ -48,6 +48,7 @@ pub use domain::{
 // Re-export infrastructure (internal use - prefer application layer)
 #[doc(hidden)]
 pub use infrastructure::{
-    CloneDetector, HybridCloneDetector, HybridDetectorStats, MultiLevelDetector, Type1Detector,
-    Type2Detector, Type3Detector, Type4Detector,
+    CloneDetector, HybridCloneDetector, HybridDetectorStats, MultiLevelDetector, RepoCloneIndex,
+    RepoCloneIndexConfig, RepoCloneIndexStats, Type1Detector, Type2Detector, Type3Detector,
+    Type4Detector,
 };
@@ -0,0 +1,560 @@
+//! SCC condensation of the file / symbol dependency graphs
+//!
+//! Collapses every strongly connected component into one node, giving the
+//! DAG of "cyclic cores" and the acyclic parts depending on them. Components
+//! are numbered in topological order (a component only depends on components
+//! with a larger id), so the export reads top-down from entry points to
+//! leaves.
+//!
+//! - DOT: one node per component, cyclic cores filled, members in the tooltip
+//! - Parquet (`parquet` feature): `components` and `edges` tables
+//!
+//! `DependencyScc` keeps the member edges of each component instead, for
+//! reporting the cycles themselves (`get_sccs` / `get_cycles`).
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt::Write as _;
+
+use petgraph::algo::tarjan_scc;
+use petgraph::graph::{DiGraph, NodeIndex};
+
+use super::graph_export::{dot_quote, ExportGraph, GraphExportOptions};
+
+/// Members listed in a component's DOT label before eliding the rest
+const DOT_LABEL_MEMBERS: usize = 3;
+
+/// One strongly connected component
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SccComponent {
+    /// Topological position (dependencies have a larger id)
+    pub id: usize,
+
+    /// Member ids (file paths or symbol FQNs), sorted
+    pub members: Vec<String>,
+
+    /// Part of a cycle: several members, or one member depending on itself
+    pub cyclic: bool,
+
+    /// Edges between members of this component
+    pub internal_edges: usize,
+}
+
+impl SccComponent {
+    pub fn size(&self) -> usize {
+        self.members.len()
+    }
+}
+
+/// Dependency between two components
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CondensedEdge {
+    pub source: usize,
+    pub target: usize,
+
+    /// Member edges collapsed into this one
+    pub weight: usize,
+}
+
+/// Strongly connected component with the edges between its members
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyScc {
+    /// Member ids (file paths or symbol FQNs), sorted
+    pub members: Vec<String>,
+
+    /// `source -> target` edges between members, sorted
+    pub edges: Vec<(String, String)>,
+
+    /// Part of a cycle: several members, or one member depending on itself
+    pub cyclic: bool,
+}
+
+impl DependencyScc {
+    /// Shortest cycle through the first member, closed (`[a, b, a]`)
+    ///
+    /// A readable witness for reports; `edges` holds every member edge.
+    pub fn shortest_cycle(&self) -> Option<Vec<String>> {
+        let start = self.members.first()?;
+        let mut successors: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for (source, target) in &self.edges {
+            successors.entry(source).or_default().push(target);
+        }
+
+        let mut parent: HashMap<&str, &str> = HashMap::new();
+        let mut queue = VecDeque::from([start.as_str()]);
+        while let Some(current) = queue.pop_front() {
+            for &next in successors.get(current).into_iter().flatten() {
+                if next == start {
+                    let mut path = vec![start.clone(), current.to_string()];
+                    let mut node = current;
+                    while let Some(&prev) = parent.get(node) {
+                        path.push(prev.to_string());
+                        node = prev;
+                    }
+                    path.reverse();
+                    return Some(path);
+                }
+                if !parent.contains_key(next) {
+                    parent.insert(next, current);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Strongly connected components in topological order (dependents first)
+pub(super) fn sccs_from_export(export: &ExportGraph) -> Vec<DependencyScc> {
+    let (graph, pairs) = member_graph(export);
+    let sccs = tarjan_scc(&graph);
+    let mut component_of: HashMap<NodeIndex, usize> = HashMap::new();
+    let mut components: Vec<DependencyScc> = sccs
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(id, scc)| {
+            for &node in scc {
+                component_of.insert(node, id);
+            }
+            let mut members: Vec<String> = scc.iter().map(|&n| graph[n].to_string()).collect();
+            members.sort();
+            DependencyScc {
+                cyclic: members.len() > 1,
+                members,
+                edges: Vec::new(),
+            }
+        })
+        .collect();
+
+    for &(source, target) in &pairs {
+        let component = component_of[&source];
+        if component == component_of[&target] {
+            components[component]
+                .edges
+                .push((graph[source].to_string(), graph[target].to_string()));
+        }
+    }
+    for component in &mut components {
+        component.edges.sort();
+        component.cyclic |= !component.edges.is_empty();
+    }
+    components
+}
+
+/// Member graph of an export, parallel edges of different kinds counting once
+fn member_graph(export: &ExportGraph) -> (DiGraph<&str, ()>, Vec<(NodeIndex, NodeIndex)>) {
+    let mut graph: DiGraph<&str, ()> = DiGraph::new();
+    let mut node_index: HashMap<&str, NodeIndex> = HashMap::new();
+    for node in &export.nodes {
+        node_index.insert(node.id.as_str(), graph.add_node(node.id.as_str()));
+    }
+
+    let mut pairs: Vec<(NodeIndex, NodeIndex)> = export
+        .edges
+        .iter()
+        .filter_map(|edge| {
+            Some((
+                *node_index.get(edge.source.as_str())?,
+                *node_index.get(edge.target.as_str())?,
+            ))
+        })
+        .collect();
+    pairs.sort();
+    pairs.dedup();
+    for &(source, target) in &pairs {
+        graph.add_edge(source, target, ());
+    }
+    (graph, pairs)
+}
+
+/// Condensation (SCC DAG) of a dependency graph
+#[derive(Debug, Clone, Default)]
+pub struct Condensation {
+    /// Components ordered by id
+    pub components: Vec<SccComponent>,
+
+    /// Edges sorted by (source, target)
+    pub edges: Vec<CondensedEdge>,
+
+    /// Member id -> position in `components`
+    index: HashMap<String, usize>,
+}
+
+impl Condensation {
+    pub(super) fn from_export(export: &ExportGraph) -> Self {
+        let (graph, pairs) = member_graph(export);
+
+        // Tarjan yields components in reverse topological order
+        let sccs = tarjan_scc(&graph);
+        let mut component_of: HashMap<NodeIndex, usize> = HashMap::new();
+        let mut components: Vec<SccComponent> = sccs
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(id, scc)| {
+                for &node in scc {
+                    component_of.insert(node, id);
+                }
+                let mut members: Vec<String> = scc.iter().map(|&n| graph[n].to_string()).collect();
+                members.sort();
+                SccComponent {
+                    id,
+                    members,
+                    cyclic: false,
+                    internal_edges: 0,
+                }
+            })
+            .collect();
+
+        let mut weights: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+        for &(source, target) in &pairs {
+            let (from, to) = (component_of[&source], component_of[&target]);
+            if from == to {
+                components[from].internal_edges += 1;
+            } else {
+                *weights.entry((from, to)).or_default() += 1;
+            }
+        }
+        for component in &mut components {
+            component.cyclic = component.size() > 1 || component.internal_edges > 0;
+        }
+
+        let index = components
+            .iter()
+            .flat_map(|c| c.members.iter().map(move |m| (m.clone(), c.id)))
+            .collect();
+        let edges = weights
+            .into_iter()
+            .map(|((source, target), weight)| CondensedEdge {
+                source,
+                target,
+                weight,
+            })
+            .collect();
+
+        Self {
+            components,
+            edges,
+            index,
+        }
+    }
+
+    /// Component containing a file path / symbol FQN
+    pub fn component_of(&self, member: &str) -> Option<&SccComponent> {
+        self.index.get(member).map(|&i| &self.components[i])
+    }
+
+    /// Cyclic components, largest first
+    pub fn cyclic_cores(&self) -> Vec<&SccComponent> {
+        let mut cores: Vec<&SccComponent> = self.components.iter().filter(|c| c.cyclic).collect();
+        cores.sort_by(|a, b| b.size().cmp(&a.size()).then(a.id.cmp(&b.id)));
+        cores
+    }
+
+    /// Size of the largest component (1 for an acyclic graph)
+    pub fn largest_component_size(&self) -> usize {
+        self.components
+            .iter()
+            .map(SccComponent::size)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Keep only cyclic components and the edges between them (ids unchanged)
+    pub fn cyclic_only(&self) -> Condensation {
+        let components: Vec<SccComponent> = self
+            .components
+            .iter()
+            .filter(|c| c.cyclic)
+            .cloned()
+            .collect();
+        let kept = |id: usize| self.components[id].cyclic;
+        let edges = self
+            .edges
+            .iter()
+            .filter(|e| kept(e.source) && kept(e.target))
+            .cloned()
+            .collect();
+        let index = components
+            .iter()
+            .enumerate()
+            .flat_map(|(i, c)| c.members.iter().map(move |m| (m.clone(), i)))
+            .collect();
+
+        Condensation {
+            components,
+            edges,
+            index,
+        }
+    }
+
+    /// Export as Graphviz DOT (edge labels = collapsed edge count)
+    pub fn to_dot(&self, options: &GraphExportOptions) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "digraph {} {{", dot_quote(&options.graph_name));
+        out.push_str("  rankdir=TB;\n");
+        out.push_str("  node [shape=box, style=rounded, fontname=\"Helvetica\"];\n");
+        out.push_str("  edge [fontname=\"Helvetica\", fontsize=9];\n");
+
+        for component in &self.components {
+            let mut label: Vec<String> = Vec::new();
+            if component.size() > 1 {
+                label.push(format!("{} members", component.size()));
+            }
+            label.extend(component.members.iter().take(DOT_LABEL_MEMBERS).cloned());
+            if component.size() > DOT_LABEL_MEMBERS {
+                label.push("...".to_string());
+            }
+            let style = if component.cyclic {
+                ", style=\"rounded,filled\", fillcolor=\"#f4cccc\", color=\"#d62728\""
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                out,
+                "  scc{} [label={}, tooltip={}{}];",
+                component.id,
+                dot_lines(&label),
+                dot_lines(&component.members),
+                style
+            );
+        }
+
+        for edge in &self.edges {
+            let _ = write!(out, "  scc{} -> scc{}", edge.source, edge.target);
+            if edge.weight > 1 {
+                let _ = write!(out, " [label=\"{}\"]", edge.weight);
+            }
+            out.push_str(";\n");
+        }
+
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// Quote lines as one multi-line DOT string
+fn dot_lines(lines: &[String]) -> String {
+    let escaped: Vec<String> = lines
+        .iter()
+        .map(|line| line.replace('\\', "\\\\").replace('"', "\\\""))
+        .collect();
+    format!("\"{}\"", escaped.join("\\n"))
+}
+
+/// Parquet files of a condensation
+#[cfg(feature = "parquet")]
+#[derive(Debug, Clone, Default)]
+pub struct CondensationParquet {
+    /// `component_id`, `size`, `cyclic`, `internal_edges`, `members` (list)
+    pub components: Vec<u8>,
+
+    /// `source`, `target`, `weight`
+    pub edges: Vec<u8>,
+}
+
+#[cfg(feature = "parquet")]
+impl Condensation {
+    /// Export as Parquet tables
+    pub fn to_parquet(&self) -> crate::shared::models::Result<CondensationParquet> {
+        use std::sync::Arc;
+
+        use arrow::array::{ArrayRef, BooleanArray, ListBuilder, StringBuilder, UInt32Array};
+
+        let mut members = ListBuilder::new(StringBuilder::new());
+        for component in &self.components {
+            for member in &component.members {
+                members.values().append_value(member);
+            }
+            members.append(true);
+        }
+        let u32_column = |values: Vec<usize>| -> ArrayRef {
+            Arc::new(UInt32Array::from_iter_values(
+                values.into_iter().map(|v| v as u32),
+            ))
+        };
+
+        let components = parquet_bytes(vec![
+            (
+                "component_id",
+                u32_column(self.components.iter().map(|c| c.id).collect()),
+            ),
+            (
+                "size",
+                u32_column(self.components.iter().map(SccComponent::size).collect()),
+            ),
+            (
+                "cyclic",
+                Arc::new(BooleanArray::from_iter(
+                    self.components.iter().map(|c| Some(c.cyclic)),
+                )),
+            ),
+            (
+                "internal_edges",
+                u32_column(self.components.iter().map(|c| c.internal_edges).collect()),
+            ),
+            ("members", Arc::new(members.finish())),
+        ])?;
+        let edges = parquet_bytes(vec![
+            (
+                "source",
+                u32_column(self.edges.iter().map(|e| e.source).collect()),
+            ),
+            (
+                "target",
+                u32_column(self.edges.iter().map(|e| e.target).collect()),
+            ),
+            (
+                "weight",
+                u32_column(self.edges.iter().map(|e| e.weight).collect()),
+            ),
+        ])?;
+
+        Ok(CondensationParquet { components, edges })
+    }
+}
+
+#[cfg(feature = "parquet")]
+fn parquet_bytes(
+    columns: Vec<(&str, arrow::array::ArrayRef)>,
+) -> crate::shared::models::Result<Vec<u8>> {
+    use arrow::record_batch::RecordBatch;
+    use parquet::arrow::ArrowWriter;
+
+    use crate::shared::models::CodegraphError;
+
+    let parquet_error = |e: &dyn std::fmt::Display| {
+        CodegraphError::internal(format!("Parquet export failed: {}", e))
+    };
+
+    let batch = RecordBatch::try_from_iter(columns).map_err(|e| parquet_error(&e))?;
+    let mut buffer = Vec::new();
+    let mut writer =
+        ArrowWriter::try_new(&mut buffer, batch.schema(), None).map_err(|e| parquet_error(&e))?;
+    writer.write(&batch).map_err(|e| parquet_error(&e))?;
+    writer.close().map_err(|e| parquet_error(&e))?;
+    Ok(buffer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::graph_export::{ExportEdge, ExportNode};
+    use super::*;
+
+    fn export(nodes: &[&str], edges: &[(&str, &str)]) -> ExportGraph {
+        ExportGraph {
+            nodes: nodes
+                .iter()
+                .map(|id| ExportNode {
+                    id: id.to_string(),
+                    label: id.to_string(),
+                    kind: "File",
+                    file: None,
+                    cluster: None,
+                })
+                .collect(),
+            edges: edges
+                .iter()
+                .map(|(source, target)| ExportEdge {
+                    source: source.to_string(),
+                    target: target.to_string(),
+                    kind: "imports",
+                    confidence: 1.0,
+                })
+                .collect(),
+        }
+    }
+
+    fn sample() -> Condensation {
+        // entry -> {a, b} -> c (self-loop); a and b point at c twice
+        Condensation::from_export(&export(
+            &["a", "b", "c", "entry"],
+            &[
+                ("entry", "a"),
+                ("a", "b"),
+                ("b", "a"),
+                ("a", "c"),
+                ("b", "c"),
+                ("c", "c"),
+            ],
+        ))
+    }
+
+    #[test]
+    fn test_condensation_components_and_edges() {
+        let condensation = sample();
+        assert_eq!(condensation.components.len(), 3);
+        assert_eq!(condensation.largest_component_size(), 2);
+
+        let core = condensation.component_of("b").unwrap();
+        assert_eq!(core.members, vec!["a", "b"]);
+        assert!(core.cyclic);
+        assert_eq!(core.internal_edges, 2);
+
+        let leaf = condensation.component_of("c").unwrap();
+        assert!(leaf.cyclic, "self-loop is a cycle");
+        let entry = condensation.component_of("entry").unwrap();
+        assert!(!entry.cyclic);
+
+        // Topological ids: entry < core < leaf
+        assert!(entry.id < core.id && core.id < leaf.id);
+        let weight = condensation
+            .edges
+            .iter()
+            .find(|e| e.source == core.id && e.target == leaf.id)
+            .unwrap()
+            .weight;
+        assert_eq!(weight, 2);
+        assert!(condensation.edges.iter().all(|e| e.source < e.target));
+
+        let cores: Vec<usize> = condensation
+            .cyclic_cores()
+            .iter()
+            .map(|c| c.size())
+            .collect();
+        assert_eq!(cores, vec![2, 1]);
+    }
+
+    #[test]
+    fn test_sccs_keep_member_edges_and_cycle_witness() {
+        let sccs = sccs_from_export(&export(
+            &["a", "b", "c", "d", "entry"],
+            &[
+                ("entry", "a"),
+                ("a", "b"),
+                ("b", "c"),
+                ("c", "a"),
+                ("b", "a"),
+                ("c", "d"),
+                ("d", "d"),
+            ],
+        ));
+        assert_eq!(sccs.len(), 3);
+        assert_eq!(sccs[0].members, vec!["entry"]);
+        assert!(!sccs[0].cyclic);
+        assert!(sccs[0].shortest_cycle().is_none());
+
+        let core = &sccs[1];
+        assert_eq!(core.members, vec!["a", "b", "c"]);
+        assert_eq!(core.edges.len(), 4);
+        assert_eq!(core.shortest_cycle().unwrap(), vec!["a", "b", "a"]);
+
+        assert!(sccs[2].cyclic, "self-loop is a cycle");
+        assert_eq!(sccs[2].shortest_cycle().unwrap(), vec!["d", "d"]);
+    }
+
+    #[test]
+    fn test_condensation_dot_and_cyclic_filter() {
+        let condensation = sample();
+        let dot = condensation.to_dot(&GraphExportOptions::default());
+        assert!(dot.contains("label=\"2 members\\na\\nb\""));
+        assert_eq!(dot.matches("fillcolor").count(), 2);
+        assert!(dot.contains("[label=\"2\"]"));
+
+        let cyclic = condensation.cyclic_only();
+        assert_eq!(cyclic.components.len(), 2);
+        assert_eq!(cyclic.edges.len(), 1);
+        assert!(cyclic.component_of("entry").is_none());
+        assert_eq!(cyclic.component_of("c").unwrap().size(), 1);
+    }
+}
@@ -0,0 +1,822 @@
+//! Context-sensitive call graph (k-CFA lite)
+//!
+//! Opt-in alternative to [`CallGraph`](super::CallGraph) for
+//! precision-sensitive queries, selected with
+//! `PipelineConfig::call_graph(|c| c.context_depth(1))` (`overrides.call_graph`
+//! in YAML).
+//!
+//! Every function is analyzed once per *context*: the last `k` call sites on
+//! the way to it (call-string sensitivity, Shivers' k-CFA). Contexts carry
+//! the functions bound to each parameter, so a call through a parameter
+//! (`def apply(f): f()`) only resolves to the callbacks passed along that
+//! call string:
+//!
+//! ```text
+//! def a(): apply(foo)      k=0: a → apply → {foo, bar}   (a reaches bar)
+//! def b(): apply(bar)      k=1: a → apply@a:1 → {foo}
+//! ```
+//!
+//! - `k = 0` is the context-insensitive baseline (0-CFA)
+//! - every function is also analyzed in the empty context (unknown callers),
+//!   so direct edges match [`CallGraph`](super::CallGraph)
+//! - functions over `max_contexts_per_function` fall back to the empty
+//!   context, which stays sound but merges bindings
+//!
+//! Call sites are `Calls` edges grouped by caller and span; arguments come
+//! from `EdgeMetadata::arguments` and bind positionally to
+//! `Node::parameters`. Targets are functions of the caller's file, by node
+//! id, FQN or the name L1 wrote (`external.helper`), or the symbols L3
+//! resolved them to in other files (`build_with_resolved_edges`). A call to
+//! a parameter of the caller is a call through that parameter.
+//!
+//! Measured by `benches/call_graph_precision.rs` on the higher-order
+//! corpus: reachability precision rises from 0-CFA to 2-CFA at unchanged
+//! recall, while contexts grow with `k`.
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use super::edge_resolution::{find_resolved, index_by_source, ResolvedEdge};
+use super::IRDocument;
+use crate::config::CallGraphConfig;
+use crate::shared::models::{EdgeKind, Node, NodeKind};
+
+/// Index of a call site in [`ContextCallGraph::call_site`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct CallSiteId(pub usize);
+
+/// Callee of a call site
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallTarget {
+    /// Resolved functions
+    Direct(Vec<String>),
+    /// The caller's parameter at this position
+    Parameter(usize),
+}
+
+/// One call expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallSite {
+    pub caller: String,
+    pub line: u32,
+    pub col: u32,
+    pub target: CallTarget,
+    /// Argument expressions, positional
+    pub arguments: Vec<String>,
+}
+
+/// Construction statistics
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContextCallGraphStats {
+    pub context_depth: usize,
+    pub functions: usize,
+    pub call_sites: usize,
+    /// Call sites through a parameter
+    pub indirect_call_sites: usize,
+    /// Call sites without any callee in any context
+    pub unresolved_call_sites: usize,
+    /// (function, context) pairs
+    pub contexts: usize,
+    /// Edges between (function, context) pairs
+    pub context_edges: usize,
+    /// Caller → callee edges with contexts dropped
+    pub call_edges: usize,
+    /// Contexts merged into the empty context (over budget)
+    pub collapsed_contexts: usize,
+}
+
+/// Precision/recall of predicted caller → callee pairs against ground truth
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CallGraphAccuracy {
+    pub true_positives: usize,
+    pub false_positives: usize,
+    pub false_negatives: usize,
+}
+
+impl CallGraphAccuracy {
+    pub fn measure(
+        predicted: &BTreeSet<(String, String)>,
+        expected: &BTreeSet<(String, String)>,
+    ) -> Self {
+        let true_positives = predicted.intersection(expected).count();
+        Self {
+            true_positives,
+            false_positives: predicted.len() - true_positives,
+            false_negatives: expected.len() - true_positives,
+        }
+    }
+
+    pub fn precision(&self) -> f64 {
+        if self.true_positives + self.false_positives == 0 {
+            return 1.0;
+        }
+        self.true_positives as f64 / (self.true_positives + self.false_positives) as f64
+    }
+
+    pub fn recall(&self) -> f64 {
+        if self.true_positives + self.false_negatives == 0 {
+            return 1.0;
+        }
+        self.true_positives as f64 / (self.true_positives + self.false_negatives) as f64
+    }
+
+    pub fn f1(&self) -> f64 {
+        let (p, r) = (self.precision(), self.recall());
+        if p + r == 0.0 {
+            return 0.0;
+        }
+        2.0 * p * r / (p + r)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FunctionInfo {
+    file_path: String,
+    parameters: Vec<String>,
+    call_sites: Vec<CallSiteId>,
+}
+
+/// Call graph over (function, call-string context) pairs
+#[derive(Debug, Clone)]
+pub struct ContextCallGraph {
+    config: CallGraphConfig,
+    functions: HashMap<String, FunctionInfo>,
+    sites: Vec<CallSite>,
+    /// Context node → (function, context)
+    nodes: Vec<(String, Vec<CallSiteId>)>,
+    node_index: HashMap<(String, Vec<CallSiteId>), usize>,
+    /// Context node → functions bound to each parameter
+    bindings: Vec<Vec<BTreeSet<String>>>,
+    /// (caller node, call site, callee node)
+    edges: BTreeSet<(usize, CallSiteId, usize)>,
+    /// Function → non-empty contexts created
+    context_counts: HashMap<String, usize>,
+    collapsed_contexts: usize,
+}
+
+impl ContextCallGraph {
+    /// Build from IR documents
+    pub fn build(irs: &[IRDocument], config: &CallGraphConfig) -> Self {
+        Self::build_with_resolved_edges(irs, &[], config)
+    }
+
+    /// Build from IR documents, following calls to other files through their
+    /// cross-file resolutions (`resolve_cross_file_edges`)
+    pub fn build_with_resolved_edges(
+        irs: &[IRDocument],
+        resolved: &[ResolvedEdge],
+        config: &CallGraphConfig,
+    ) -> Self {
+        let by_source = index_by_source(resolved);
+        let per_file: Vec<_> = irs
+            .par_iter()
+            .map(|ir| collect_from_ir(ir, &by_source))
+            .collect();
+
+        let mut functions = HashMap::new();
+        let mut sites = Vec::new();
+        for (file_functions, file_sites) in per_file {
+            functions.extend(file_functions);
+            sites.extend(file_sites);
+        }
+        // Stable ids regardless of document order
+        sites.sort_by(|a, b| {
+            (&a.caller, a.line, a.col, &a.arguments).cmp(&(&b.caller, b.line, b.col, &b.arguments))
+        });
+        for (i, site) in sites.iter().enumerate() {
+            if let Some(info) = functions.get_mut(&site.caller) {
+                info.call_sites.push(CallSiteId(i));
+            }
+        }
+
+        let mut graph = Self {
+            config: config.clone(),
+            functions,
+            sites,
+            nodes: Vec::new(),
+            node_index: HashMap::new(),
+            bindings: Vec::new(),
+            edges: BTreeSet::new(),
+            context_counts: HashMap::new(),
+            collapsed_contexts: 0,
+        };
+        graph.solve();
+        graph
+    }
+
+    fn solve(&mut self) {
+        let short_names = self.short_names();
+        let mut queue = VecDeque::new();
+        let mut queued = HashSet::new();
+
+        let mut roots: Vec<String> = self.functions.keys().cloned().collect();
+        roots.sort();
+        for fqn in roots {
+            let (node, _) = self.node(&fqn, Vec::new());
+            queue.push_back(node);
+            queued.insert(node);
+        }
+
+        while let Some(node) = queue.pop_front() {
+            queued.remove(&node);
+            let (caller, context) = self.nodes[node].clone();
+            let caller_bindings = self.bindings[node].clone();
+            let site_ids = self.functions[&caller].call_sites.clone();
+
+            for site_id in site_ids {
+                let site = &self.sites[site_id.0];
+                let targets: Vec<String> = match &site.target {
+                    CallTarget::Direct(targets) => targets.clone(),
+                    CallTarget::Parameter(p) => caller_bindings
+                        .get(*p)
+                        .map(|b| b.iter().cloned().collect())
+                        .unwrap_or_default(),
+                };
+                let arguments: Vec<BTreeSet<String>> = site
+                    .arguments
+                    .iter()
+                    .map(|arg| self.resolve_argument(&caller, &caller_bindings, arg, &short_names))
+                    .collect();
+
+                for target in targets {
+                    if !self.functions.contains_key(&target) {
+                        continue;
+                    }
+                    let callee_context = self.push_context(&context, site_id, &target);
+                    let (callee, created) = self.node(&target, callee_context);
+
+                    let mut changed = created;
+                    let bound = &mut self.bindings[callee];
+                    for (slot, values) in bound.iter_mut().zip(&arguments) {
+                        for value in values {
+                            changed |= slot.insert(value.clone());
+                        }
+                    }
+                    self.edges.insert((node, site_id, callee));
+
+                    if changed && queued.insert(callee) {
+                        queue.push_back(callee);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Context node of `fqn` in `context`, and whether it was created
+    fn node(&mut self, fqn: &str, context: Vec<CallSiteId>) -> (usize, bool) {
+        let key = (fqn.to_string(), context);
+        if let Some(&node) = self.node_index.get(&key) {
+            return (node, false);
+        }
+        let node = self.nodes.len();
+        let parameters = self.functions[fqn].parameters.len();
+        if !key.1.is_empty() {
+            *self.context_counts.entry(key.0.clone()).or_default() += 1;
+        }
+        self.nodes.push(key.clone());
+        self.node_index.insert(key, node);
+        self.bindings.push(vec![BTreeSet::new(); parameters]);
+        (node, true)
+    }
+
+    /// Callee context: the last `k` call sites, or the empty context over budget
+    fn push_context(
+        &mut self,
+        context: &[CallSiteId],
+        site: CallSiteId,
+        callee: &str,
+    ) -> Vec<CallSiteId> {
+        let k = self.config.context_depth;
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut callee_context = context.to_vec();
+        callee_context.push(site);
+        if callee_context.len() > k {
+            callee_context.drain(..callee_context.len() - k);
+        }
+
+        let key = (callee.to_string(), callee_context);
+        if self.node_index.contains_key(&key) {
+            return key.1;
+        }
+        let existing = self.context_counts.get(callee).copied().unwrap_or(0);
+        if existing >= self.config.max_contexts_per_function {
+            self.collapsed_contexts += 1;
+            return Vec::new();
+        }
+        key.1
+    }
+
+    /// Functions an argument expression may evaluate to
+    fn resolve_argument(
+        &self,
+        caller: &str,
+        caller_bindings: &[BTreeSet<String>],
+        argument: &str,
+        short_names: &HashMap<String, Vec<String>>,
+    ) -> BTreeSet<String> {
+        let info = &self.functions[caller];
+        if let Some(p) = info.parameters.iter().position(|name| name == argument) {
+            return caller_bindings.get(p).cloned().unwrap_or_default();
+        }
+        if self.functions.contains_key(argument) {
+            return BTreeSet::from([argument.to_string()]);
+        }
+        let candidates = short_names.get(argument).map(Vec::as_slice).unwrap_or(&[]);
+        let same_file: BTreeSet<String> = candidates
+            .iter()
+            .filter(|fqn| self.functions[fqn.as_str()].file_path == info.file_path)
+            .cloned()
+            .collect();
+        if !same_file.is_empty() {
+            return same_file;
+        }
+        candidates.iter().cloned().collect()
+    }
+
+    fn short_names(&self) -> HashMap<String, Vec<String>> {
+        let mut names: HashMap<String, Vec<String>> = HashMap::new();
+        for fqn in self.functions.keys() {
+            let name = fqn.rsplit('.').next().unwrap_or(fqn);
+            names.entry(name.to_string()).or_default().push(fqn.clone());
+        }
+        names
+    }
+
+    /// Context depth this graph was built with
+    pub fn context_depth(&self) -> usize {
+        self.config.context_depth
+    }
+
+    /// Call site by id
+    pub fn call_site(&self, id: CallSiteId) -> Option<&CallSite> {
+        self.sites.get(id.0)
+    }
+
+    /// Contexts `fqn` was analyzed in (the empty context first)
+    pub fn contexts(&self, fqn: &str) -> Vec<Vec<CallSiteId>> {
+        let mut contexts: Vec<_> = self
+            .nodes
+            .iter()
+            .filter(|(f, _)| f == fqn)
+            .map(|(_, ctx)| ctx.clone())
+            .collect();
+        contexts.sort();
+        contexts
+    }
+
+    /// Functions called by `fqn` in any context, sorted
+    pub fn callees(&self, fqn: &str) -> Vec<String> {
+        let callees: BTreeSet<&str> = self
+            .edges
+            .iter()
+            .filter(|(from, _, _)| self.nodes[*from].0 == fqn)
+            .map(|(_, _, to)| self.nodes[*to].0.as_str())
+            .collect();
+        callees.into_iter().map(str::to_string).collect()
+    }
+
+    /// Functions called by `fqn` in `context`, sorted
+    pub fn callees_in_context(&self, fqn: &str, context: &[CallSiteId]) -> Vec<String> {
+        let Some(&node) = self.node_index.get(&(fqn.to_string(), context.to_vec())) else {
+            return Vec::new();
+        };
+        let callees: BTreeSet<&str> = self
+            .edges
+            .range((node, CallSiteId(0), 0)..(node + 1, CallSiteId(0), 0))
+            .map(|(_, _, to)| self.nodes[*to].0.as_str())
+            .collect();
+        callees.into_iter().map(str::to_string).collect()
+    }
+
+    /// Functions calling `fqn` in any context, sorted
+    pub fn callers(&self, fqn: &str) -> Vec<String> {
+        let callers: BTreeSet<&str> = self
+            .edges
+            .iter()
+            .filter(|(_, _, to)| self.nodes[*to].0 == fqn)
+            .map(|(from, _, _)| self.nodes[*from].0.as_str())
+            .collect();
+        callers.into_iter().map(str::to_string).collect()
+    }
+
+    /// Functions reachable from `fqn` along context-consistent paths, sorted
+    pub fn transitive_callees(&self, fqn: &str) -> Vec<String> {
+        let successors = self.successors();
+        let mut visited = HashSet::new();
+        let mut queue: VecDeque<usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, (f, _))| f == fqn)
+            .map(|(node, _)| node)
+            .collect();
+        let mut reached = BTreeSet::new();
+        while let Some(node) = queue.pop_front() {
+            for &next in successors.get(&node).map(Vec::as_slice).unwrap_or(&[]) {
+                if visited.insert(next) {
+                    reached.insert(self.nodes[next].0.as_str());
+                    queue.push_back(next);
+                }
+            }
+        }
+        reached.into_iter().map(str::to_string).collect()
+    }
+
+    /// Caller → callee edges with contexts dropped
+    pub fn call_edges(&self) -> BTreeSet<(String, String)> {
+        self.edges
+            .iter()
+            .map(|(from, _, to)| (self.nodes[*from].0.clone(), self.nodes[*to].0.clone()))
+            .collect()
+    }
+
+    /// (function, transitively reachable function) pairs
+    pub fn reachable_pairs(&self) -> BTreeSet<(String, String)> {
+        let mut fqns: Vec<&String> = self.functions.keys().collect();
+        fqns.sort();
+        fqns.into_iter()
+            .flat_map(|fqn| {
+                self.transitive_callees(fqn)
+                    .into_iter()
+                    .map(move |callee| (fqn.clone(), callee))
+            })
+            .collect()
+    }
+
+    fn successors(&self) -> HashMap<usize, Vec<usize>> {
+        let mut successors: HashMap<usize, Vec<usize>> = HashMap::new();
+        for &(from, _, to) in &self.edges {
+            successors.entry(from).or_default().push(to);
+        }
+        successors
+    }
+
+    /// Get graph statistics
+    pub fn stats(&self) -> ContextCallGraphStats {
+        let resolved: HashSet<CallSiteId> = self.edges.iter().map(|(_, site, _)| *site).collect();
+        ContextCallGraphStats {
+            context_depth: self.config.context_depth,
+            functions: self.functions.len(),
+            call_sites: self.sites.len(),
+            indirect_call_sites: self
+                .sites
+                .iter()
+                .filter(|s| matches!(s.target, CallTarget::Parameter(_)))
+                .count(),
+            unresolved_call_sites: self.sites.len() - resolved.len(),
+            contexts: self.nodes.len(),
+            context_edges: self.edges.len(),
+            call_edges: self.call_edges().len(),
+            collapsed_contexts: self.collapsed_contexts,
+        }
+    }
+}
+
+fn is_function(node: &Node) -> bool {
+    matches!(
+        node.kind,
+        NodeKind::Function | NodeKind::Method | NodeKind::Lambda
+    ) && !node.fqn.is_empty()
+}
+
+/// Parameter names call arguments bind to (`*args: int = 0` → `args`); a
+/// `self` / `cls` receiver is not passed as an argument
+fn bound_parameters(node: &Node) -> Vec<String> {
+    let mut parameters: Vec<String> = node
+        .parameters
+        .iter()
+        .flatten()
+        .map(|p| {
+            let end = p.find([':', '=']).unwrap_or(p.len());
+            p[..end].trim().trim_start_matches('*').to_string()
+        })
+        .collect();
+    if matches!(parameters.first().map(String::as_str), Some("self" | "cls")) {
+        parameters.remove(0);
+    }
+    parameters
+}
+
+/// Functions and call sites of a single IR document
+fn collect_from_ir(
+    ir: &IRDocument,
+    resolved: &HashMap<&str, Vec<&ResolvedEdge>>,
+) -> (HashMap<String, FunctionInfo>, Vec<CallSite>) {
+    let node_by_id: HashMap<&str, &Node> = ir.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+    // Functions of the file by FQN and by name
+    let mut local: HashMap<&str, BTreeSet<&str>> = HashMap::new();
+    for node in ir.nodes.iter().filter(|n| is_function(n)) {
+        local
+            .entry(node.fqn.as_str())
+            .or_default()
+            .insert(&node.fqn);
+        if let Some(name) = node.name.as_deref() {
+            local.entry(name).or_default().insert(&node.fqn);
+        }
+    }
+
+    let functions: HashMap<String, FunctionInfo> = ir
+        .nodes
+        .iter()
+        .filter(|n| is_function(n))
+        .map(|n| {
+            let info = FunctionInfo {
+                file_path: ir.file_path.clone(),
+                parameters: bound_parameters(n),
+                call_sites: Vec::new(),
+            };
+            (n.fqn.clone(), info)
+        })
+        .collect();
+
+    // (caller, line, col) → direct targets / parameter call, arguments
+    let mut grouped: HashMap<(String, u32, u32), CallSite> = HashMap::new();
+    for (i, edge) in ir.edges.iter().enumerate() {
+        if edge.kind != EdgeKind::Calls {
+            continue;
+        }
+        let Some(caller) = node_by_id.get(edge.source_id.as_str()) else {
+            continue;
+        };
+        let Some(info) = functions.get(&caller.fqn).filter(|_| is_function(caller)) else {
+            continue;
+        };
+        let parameter_index = |name: &str| info.parameters.iter().position(|p| p == name);
+
+        let target = if let Some(r) = find_resolved(resolved, edge) {
+            CallTarget::Direct(vec![r.target_fqn.clone()])
+        } else if let Some(target) = node_by_id.get(edge.target_id.as_str()) {
+            if is_function(target) {
+                CallTarget::Direct(vec![target.fqn.clone()])
+            } else if target.kind == NodeKind::Parameter {
+                let name = target
+                    .name
+                    .as_deref()
+                    .unwrap_or_else(|| target.fqn.rsplit('.').next().unwrap_or(&target.fqn));
+                match parameter_index(name) {
+                    Some(p) => CallTarget::Parameter(p),
+                    None => continue,
+                }
+            } else {
+                continue;
+            }
+        } else {
+            // L1 marks simple names it could not resolve as "external.<name>"
+            let name = edge
+                .target_id
+                .strip_prefix("external.")
+                .unwrap_or(&edge.target_id);
+            if let Some(p) = parameter_index(name) {
+                CallTarget::Parameter(p)
+            } else if let Some(fqns) = local
+                .get(edge.target_id.as_str())
+                .or_else(|| local.get(name))
+            {
+                CallTarget::Direct(fqns.iter().map(|fqn| fqn.to_string()).collect())
+            } else {
+                continue;
+            }
+        };
+
+        // Edges without a span are separate call sites
+        let (line, col) = edge
+            .span
+            .map(|s| (s.start_line, s.start_col))
+            .unwrap_or((u32::MAX, i as u32));
+        let arguments = edge
+            .metadata
+            .as_ref()
+            .and_then(|m| m.arguments.clone())
+            .unwrap_or_default();
+
+        let site = grouped
+            .entry((caller.fqn.clone(), line, col))
+            .or_insert_with(|| CallSite {
+                caller: caller.fqn.clone(),
+                line,
+                col,
+                target: CallTarget::Direct(Vec::new()),
+                arguments,
+            });
+        // A call through a parameter wins over direct candidates
+        match target {
+            CallTarget::Direct(new) => {
+                if let CallTarget::Direct(targets) = &mut site.target {
+                    for fqn in new {
+                        if !targets.contains(&fqn) {
+                            targets.push(fqn);
+                        }
+                    }
+                }
+            }
+            parameter => site.target = parameter,
+        }
+    }
+
+    (functions, grouped.into_values().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::models::{Edge, EdgeMetadata, Span};
+
+    fn function(fqn: &str, parameters: &[&str]) -> Node {
+        let mut node = Node::new(
+            fqn.to_string(),
+            NodeKind::Function,
+            fqn.to_string(),
+            "app.py".to_string(),
+            Span::new(1, 0, 10, 0),
+        )
+        .with_name(fqn.rsplit('.').next().unwrap());
+        node.parameters = Some(parameters.iter().map(|p| p.to_string()).collect());
+        node
+    }
+
+    fn parameter(owner: &str, name: &str) -> Node {
+        Node::new(
+            format!("{}.{}", owner, name),
+            NodeKind::Parameter,
+            format!("{}.{}", owner, name),
+            "app.py".to_string(),
+            Span::new(1, 0, 1, 0),
+        )
+        .with_name(name)
+    }
+
+    fn call(from: &str, to: &str, line: u32, arguments: &[&str]) -> Edge {
+        Edge::new(from.to_string(), to.to_string(), EdgeKind::Calls)
+            .with_span(Span::new(line, 4, line, 20))
+            .with_metadata(EdgeMetadata {
+                arguments: Some(arguments.iter().map(|a| a.to_string()).collect()),
+                ..Default::default()
+            })
+    }
+
+    /// a → wrap(foo), b → wrap(bar); wrap(f) → apply(f); apply(g) → g()
+    fn higher_order() -> IRDocument {
+        IRDocument::new(
+            "app.py".to_string(),
+            vec![
+                function("app.a", &[]),
+                function("app.b", &[]),
+                function("app.wrap", &["f"]),
+                function("app.apply", &["g"]),
+                function("app.foo", &[]),
+                function("app.bar", &[]),
+                parameter("app.apply", "g"),
+            ],
+            vec![
+                call("app.a", "app.wrap", 2, &["foo"]),
+                call("app.b", "app.wrap", 5, &["bar"]),
+                call("app.wrap", "app.apply", 8, &["f"]),
+                call("app.apply", "app.apply.g", 11, &[]),
+            ],
+        )
+    }
+
+    fn build(k: usize) -> ContextCallGraph {
+        ContextCallGraph::build(
+            &[higher_order()],
+            &CallGraphConfig::default().context_depth(k),
+        )
+    }
+
+    #[test]
+    fn test_insensitive_merges_callbacks() {
+        let graph = build(0);
+        assert_eq!(graph.callees("app.apply"), vec!["app.bar", "app.foo"]);
+        assert_eq!(
+            graph.transitive_callees("app.a"),
+            vec!["app.apply", "app.bar", "app.foo", "app.wrap"]
+        );
+        assert_eq!(graph.stats().indirect_call_sites, 1);
+        assert_eq!(graph.stats().unresolved_call_sites, 0);
+    }
+
+    #[test]
+    fn test_call_strings_separate_callbacks() {
+        // One call site of apply: 1-CFA still merges both callbacks
+        let graph = build(1);
+        assert!(graph
+            .transitive_callees("app.a")
+            .contains(&"app.bar".to_string()));
+
+        let graph = build(2);
+        assert_eq!(
+            graph.transitive_callees("app.a"),
+            vec!["app.apply", "app.foo", "app.wrap"]
+        );
+        assert_eq!(
+            graph.transitive_callees("app.b"),
+            vec!["app.apply", "app.bar", "app.wrap"]
+        );
+        // Projected edges stay the same
+        assert_eq!(graph.call_edges(), build(0).call_edges());
+        // [], [wrap@8] (wrap called from unknown callers), [a@2, wrap@8], [b@5, wrap@8]
+        assert_eq!(graph.contexts("app.apply").len(), 4);
+    }
+
+    #[test]
+    fn test_precision_recall_by_depth() {
+        let expected: BTreeSet<(String, String)> = [
+            ("app.a", "app.wrap"),
+            ("app.a", "app.apply"),
+            ("app.a", "app.foo"),
+            ("app.b", "app.wrap"),
+            ("app.b", "app.apply"),
+            ("app.b", "app.bar"),
+            ("app.wrap", "app.apply"),
+            ("app.wrap", "app.foo"),
+            ("app.wrap", "app.bar"),
+            ("app.apply", "app.foo"),
+            ("app.apply", "app.bar"),
+        ]
+        .iter()
+        .map(|(a, b)| (a.to_string(), b.to_string()))
+        .collect();
+
+        let insensitive = CallGraphAccuracy::measure(&build(0).reachable_pairs(), &expected);
+        let sensitive = CallGraphAccuracy::measure(&build(2).reachable_pairs(), &expected);
+        assert_eq!(insensitive.false_positives, 2);
+        assert_eq!(sensitive.false_positives, 0);
+        assert_eq!(insensitive.recall(), 1.0);
+        assert_eq!(sensitive.recall(), 1.0);
+        assert!(sensitive.precision() > insensitive.precision());
+    }
+
+    #[test]
+    fn test_parser_ir_binds_callbacks_across_files() {
+        use crate::features::cross_file::build_global_context;
+        use crate::pipeline::processor::process_python_file;
+
+        let files = [
+            (
+                "a.py",
+                "a",
+                "from b import apply\n\ndef foo():\n    return 1\n\ndef bar():\n    return 2\n\n\
+                 def run_foo():\n    return apply(foo)\n\ndef run_bar():\n    return apply(bar)\n",
+            ),
+            ("b.py", "b", "def apply(f):\n    return f()\n"),
+        ];
+        let irs: Vec<IRDocument> = files
+            .iter()
+            .map(|(path, module, source)| {
+                let result = process_python_file(source, "repo", path, module);
+                IRDocument::new(path.to_string(), result.nodes, result.edges)
+            })
+            .collect();
+        let resolved = build_global_context(irs.clone()).resolved_edges;
+        let config = |k: usize| CallGraphConfig::default().context_depth(k);
+
+        let unresolved = ContextCallGraph::build(&irs, &config(1));
+        assert!(!unresolved
+            .callees("a.run_foo")
+            .contains(&"b.apply".to_string()));
+
+        let graph = ContextCallGraph::build_with_resolved_edges(&irs, &resolved, &config(1));
+        assert!(graph.callees("a.run_foo").contains(&"b.apply".to_string()));
+        assert_eq!(graph.stats().indirect_call_sites, 1);
+        let site = (0..graph.stats().call_sites)
+            .filter_map(|i| graph.call_site(CallSiteId(i)))
+            .find(|site| {
+                site.caller == "a.run_foo"
+                    && matches!(&site.target, CallTarget::Direct(t) if t.contains(&"b.apply".to_string()))
+            })
+            .unwrap();
+        assert_eq!(site.arguments, vec!["foo"]);
+
+        // apply's `f()` resolves to the callback of each call string
+        assert_eq!(
+            graph.transitive_callees("a.run_foo"),
+            vec!["a.foo", "b.apply"]
+        );
+        assert_eq!(
+            graph.transitive_callees("a.run_bar"),
+            vec!["a.bar", "b.apply"]
+        );
+        let insensitive = ContextCallGraph::build_with_resolved_edges(&irs, &resolved, &config(0));
+        assert!(insensitive
+            .transitive_callees("a.run_foo")
+            .contains(&"a.bar".to_string()));
+    }
+
+    #[test]
+    fn test_context_budget_collapses() {
+        let config = CallGraphConfig::default()
+            .context_depth(2)
+            .max_contexts_per_function(1);
+        let graph = ContextCallGraph::build(&[higher_order()], &config);
+        let stats = graph.stats();
+        assert!(stats.collapsed_contexts > 0);
+        // Still sound: every insensitive edge is kept
+        assert_eq!(graph.call_edges(), build(0).call_edges());
+        assert!(graph
+            .transitive_callees("app.a")
+            .contains(&"app.foo".to_string()));
+    }
+}
@@ -9,6 +9,8 @@ use petgraph::visit::EdgeRef;
 use petgraph::Direction;
 use std::collections::{HashMap, HashSet, VecDeque};
 
+use super::condensation::{sccs_from_export, Condensation, DependencyScc};
+use super::graph_export::{ExportEdge, ExportGraph, ExportNode, GraphExportOptions};
 use super::types::ResolvedImport;
 
 /// PageRank configuration
@@ -66,44 +68,75 @@ impl DependencyGraph {
 
     /// Build dependency graph from resolved imports
     pub fn build(resolved_imports: &HashMap<String, Vec<ResolvedImport>>) -> Self {
-        let mut graph = DiGraph::new();
-        let mut path_to_node = HashMap::new();
-
         // Collect all file paths
         let mut all_files: HashSet<String> = resolved_imports.keys().cloned().collect();
         for imports in resolved_imports.values() {
             for import in imports {
-                if let Some(ref source_file) = import.source_file {
-                    all_files.insert(source_file.clone());
-                }
+                all_files.extend(import.source_file.iter().cloned());
+                all_files.extend(import.reexport_file.iter().cloned());
             }
         }
 
+        // An import resolved through a re-export also depends on the
+        // re-exporting file (editing the `__init__` changes its resolution)
+        let edges = resolved_imports.iter().flat_map(|(from_path, imports)| {
+            imports
+                .iter()
+                .flat_map(|import| {
+                    import
+                        .source_file
+                        .as_deref()
+                        .into_iter()
+                        .chain(import.reexport_file.as_deref())
+                })
+                .map(move |file| (from_path.as_str(), file))
+        });
+
+        Self::from_edges(&all_files, edges)
+    }
+
+    /// Build dependency graph from a file → dependencies map
+    ///
+    /// Used to rebuild the graph of a previous snapshot without re-resolving
+    /// its imports.
+    pub fn from_dependencies(dependencies: &HashMap<String, Vec<String>>) -> Self {
+        let mut all_files: HashSet<String> = dependencies.keys().cloned().collect();
+        all_files.extend(dependencies.values().flatten().cloned());
+
+        let edges = dependencies.iter().flat_map(|(from_path, deps)| {
+            deps.iter()
+                .map(move |dep| (from_path.as_str(), dep.as_str()))
+        });
+
+        Self::from_edges(&all_files, edges)
+    }
+
+    /// Build from nodes and `from depends on to` edges
+    fn from_edges<'a>(
+        all_files: &HashSet<String>,
+        edges: impl Iterator<Item = (&'a str, &'a str)>,
+    ) -> Self {
+        let mut graph = DiGraph::new();
+        let mut path_to_node = HashMap::new();
+
         // Add all files as nodes
-        for file_path in &all_files {
+        for file_path in all_files {
             let idx = graph.add_node(file_path.clone());
             path_to_node.insert(file_path.clone(), idx);
         }
 
         // Add dependency edges
-        for (from_path, imports) in resolved_imports {
-            let from_idx = match path_to_node.get(from_path) {
-                Some(idx) => *idx,
-                None => continue,
-            };
-
-            for import in imports {
-                if let Some(ref source_file) = import.source_file {
-                    // Don't add self-loops
-                    if source_file == from_path {
-                        continue;
-                    }
+        for (from_path, to_path) in edges {
+            // Don't add self-loops
+            if from_path == to_path {
+                continue;
+            }
 
-                    if let Some(&to_idx) = path_to_node.get(source_file) {
-                        // Add edge: from_path depends on source_file
-                        graph.add_edge(from_idx, to_idx, ());
-                    }
-                }
+            if let (Some(&from_idx), Some(&to_idx)) =
+                (path_to_node.get(from_path), path_to_node.get(to_path))
+            {
+                // Add edge: from_path depends on to_path
+                graph.add_edge(from_idx, to_idx, ());
             }
         }
 
@@ -465,6 +498,64 @@ impl DependencyGraph {
 
         ranked
     }
+
+    /// Export as Graphviz DOT (import edges, optional per-directory clusters)
+    pub fn to_dot(&self, options: &GraphExportOptions) -> String {
+        self.export_graph().to_dot(options)
+    }
+
+    /// Export as GraphML
+    pub fn to_graphml(&self, options: &GraphExportOptions) -> String {
+        self.export_graph().to_graphml(options)
+    }
+
+    /// Condensation into import cycles (SCC DAG)
+    pub fn condensation(&self) -> Condensation {
+        Condensation::from_export(&self.export_graph())
+    }
+
+    /// Strongly connected components with their import edges, dependents first
+    pub fn get_sccs(&self) -> Vec<DependencyScc> {
+        sccs_from_export(&self.export_graph())
+    }
+
+    /// Import cycles: components of several files, with the imports forming them
+    pub fn get_cycles(&self) -> Vec<DependencyScc> {
+        self.get_sccs().into_iter().filter(|c| c.cyclic).collect()
+    }
+
+    fn export_graph(&self) -> ExportGraph {
+        let nodes = self
+            .graph
+            .node_weights()
+            .map(|path| {
+                let (dir, name) = match path.rsplit_once('/') {
+                    Some((dir, name)) => (dir.to_string(), name),
+                    None => (".".to_string(), path.as_str()),
+                };
+                ExportNode {
+                    id: path.clone(),
+                    label: name.to_string(),
+                    kind: "File",
+                    file: Some(path.clone()),
+                    cluster: Some(dir),
+                }
+            })
+            .collect();
+
+        let edges = self
+            .graph
+            .edge_references()
+            .map(|edge| ExportEdge {
+                source: self.graph[edge.source()].clone(),
+                target: self.graph[edge.target()].clone(),
+                kind: "imports",
+                confidence: 1.0,
+            })
+            .collect();
+
+        ExportGraph { nodes, edges }.sorted()
+    }
 }
 
 impl Default for DependencyGraph {
@@ -571,6 +662,40 @@ mod tests {
         assert_eq!(graph.cycles()[0].len(), 2);
     }
 
+    #[test]
+    fn test_get_cycles_reports_members_and_edges() {
+        let dependencies: HashMap<String, Vec<String>> = [
+            ("main.py", vec!["a.py"]),
+            ("a.py", vec!["b.py", "util.py"]),
+            ("b.py", vec!["a.py"]),
+        ]
+        .into_iter()
+        .map(|(file, deps)| {
+            (
+                file.to_string(),
+                deps.into_iter().map(String::from).collect(),
+            )
+        })
+        .collect();
+        let graph = DependencyGraph::from_dependencies(&dependencies);
+
+        assert_eq!(graph.get_sccs().len(), 3);
+        let cycles = graph.get_cycles();
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].members, vec!["a.py", "b.py"]);
+        assert_eq!(
+            cycles[0].edges,
+            vec![
+                ("a.py".to_string(), "b.py".to_string()),
+                ("b.py".to_string(), "a.py".to_string()),
+            ]
+        );
+        assert_eq!(
+            cycles[0].shortest_cycle().unwrap(),
+            vec!["a.py", "b.py", "a.py"]
+        );
+    }
+
     #[test]
     fn test_transitive_dependents() {
         let mut imports = HashMap::new();
@@ -1305,4 +1430,56 @@ mod tests {
         // B scores should be higher than A scores
         assert!(*score_b1 > *score_a1);
     }
+
+    #[test]
+    fn test_export_dot_clusters_by_directory() {
+        let mut imports = HashMap::new();
+        imports.insert(
+            "src/api/views.py".to_string(),
+            vec![
+                make_resolved_import("core.db", Some("src/core/db.py")),
+                make_resolved_import("core.db.session", Some("src/core/db.py")),
+            ],
+        );
+        let graph = DependencyGraph::build(&imports);
+
+        let dot = graph.to_dot(&GraphExportOptions::default().with_clusters());
+        assert_eq!(dot.matches("subgraph cluster_").count(), 2);
+        // Parallel import edges collapse into one
+        assert_eq!(dot.matches(" -> ").count(), 1);
+
+        let graphml = graph.to_graphml(&GraphExportOptions::default());
+        assert!(graphml.contains("source=\"src/api/views.py\" target=\"src/core/db.py\""));
+    }
+
+    #[test]
+    fn test_condensation_collapses_import_cycle() {
+        let mut imports = HashMap::new();
+        imports.insert(
+            "app.py".to_string(),
+            vec![make_resolved_import("a", Some("a.py"))],
+        );
+        imports.insert(
+            "a.py".to_string(),
+            vec![
+                make_resolved_import("b", Some("b.py")),
+                make_resolved_import("util", Some("util.py")),
+            ],
+        );
+        imports.insert(
+            "b.py".to_string(),
+            vec![make_resolved_import("a", Some("a.py"))],
+        );
+        let graph = DependencyGraph::build(&imports);
+
+        let condensation = graph.condensation();
+        assert_eq!(condensation.components.len(), 3);
+        let cores = condensation.cyclic_cores();
+        assert_eq!(cores.len(), 1);
+        assert_eq!(cores[0].members, vec!["a.py", "b.py"]);
+        assert_eq!(
+            condensation.component_of("b.py").map(|c| c.id),
+            Some(cores[0].id)
+        );
+    }
 }
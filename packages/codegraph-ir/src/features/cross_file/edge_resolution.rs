@@ -0,0 +1,206 @@
+//! Cross-file edge resolution
+//!
+//! L1 emits CALLS / INHERITS edges to the name written at the use site
+//! (`external.helper`, `utils.helper`) when the symbol is defined in another
+//! file. After import resolution each such target is resolved through the
+//! imports of its file (bound name or alias, `module.attr` through an imported
+//! module), else by exact FQN. Resolved edges point at the symbol's node and
+//! carry `EdgeStage::CrossFile` provenance.
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use super::{IRDocument, ResolvedImport, SymbolIndex};
+use crate::shared::models::{Edge, EdgeKind, EdgeProvenance, EdgeStage};
+
+/// Provenance rule of targets bound by an import of the file
+pub const RULE_IMPORT: &str = "import_resolution";
+
+/// Provenance rule of targets written as the symbol's FQN
+pub const RULE_FQN: &str = "fqn_match";
+
+/// Edge of `file_path` whose target resolved to a symbol of another file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResolvedEdge {
+    pub file_path: String,
+    pub source_id: String,
+    /// Target as emitted by L1 (name or FQN)
+    pub target: String,
+    pub kind: EdgeKind,
+    /// Node of the resolved symbol
+    pub target_id: String,
+    pub target_fqn: String,
+    pub provenance: EdgeProvenance,
+}
+
+fn is_resolvable(kind: EdgeKind) -> bool {
+    matches!(kind, EdgeKind::Calls | EdgeKind::Inherits)
+}
+
+/// FQN, rule and confidence of an edge target, given the names bound by the
+/// file's imports
+fn resolve_target(
+    target: &str,
+    bindings: &HashMap<&str, (&str, f32)>,
+) -> (String, &'static str, f32) {
+    // L1 marks simple names it could not resolve as "external.<name>"
+    let name = target.strip_prefix("external.").unwrap_or(target);
+    if let Some(&(fqn, confidence)) = bindings.get(name) {
+        return (fqn.to_string(), RULE_IMPORT, confidence);
+    }
+    if let Some((head, rest)) = name.split_once('.') {
+        if let Some(&(module, confidence)) = bindings.get(head) {
+            return (format!("{}.{}", module, rest), RULE_IMPORT, confidence);
+        }
+    }
+    (target.to_string(), RULE_FQN, 1.0)
+}
+
+/// Resolve the edges of `irs` that target symbols of other files
+pub fn resolve_cross_file_edges(
+    irs: &[IRDocument],
+    symbol_index: &SymbolIndex,
+    resolved_imports: &HashMap<String, Vec<ResolvedImport>>,
+) -> Vec<ResolvedEdge> {
+    irs.par_iter()
+        .flat_map(|ir| {
+            let local: HashSet<&str> = ir.nodes.iter().map(|n| n.id.as_str()).collect();
+            let bindings: HashMap<&str, (&str, f32)> = resolved_imports
+                .get(&ir.file_path)
+                .into_iter()
+                .flatten()
+                .filter_map(|import| {
+                    let fqn = import.resolved_fqn.as_deref()?;
+                    let name = import.alias.as_deref().unwrap_or_else(|| {
+                        import.import_fqn.rsplit('.').next().unwrap_or_default()
+                    });
+                    Some((name, (fqn, import.confidence)))
+                })
+                .collect();
+
+            ir.edges
+                .iter()
+                .filter(|edge| is_resolvable(edge.kind) && !local.contains(edge.target_id.as_str()))
+                .filter_map(|edge| {
+                    let (fqn, rule, confidence) = resolve_target(&edge.target_id, &bindings);
+                    let symbol = symbol_index.resolve_exact(&fqn)?;
+                    if symbol.file_path == ir.file_path {
+                        return None;
+                    }
+                    let edge_confidence = edge.provenance().and_then(|p| p.confidence);
+                    Some(ResolvedEdge {
+                        file_path: ir.file_path.clone(),
+                        source_id: edge.source_id.clone(),
+                        target: edge.target_id.clone(),
+                        kind: edge.kind,
+                        target_id: symbol.node_id.clone(),
+                        target_fqn: symbol.fqn.clone(),
+                        provenance: EdgeProvenance::new(EdgeStage::CrossFile)
+                            .with_rule(rule)
+                            .with_confidence(confidence.min(edge_confidence.unwrap_or(1.0))),
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Resolution of `edge`, if any
+pub(crate) fn find_resolved<'a>(
+    by_source: &HashMap<&str, Vec<&'a ResolvedEdge>>,
+    edge: &Edge,
+) -> Option<&'a ResolvedEdge> {
+    by_source
+        .get(edge.source_id.as_str())?
+        .iter()
+        .find(|r| r.kind == edge.kind && r.target == edge.target_id)
+        .copied()
+}
+
+/// Resolutions keyed by source node (for `find_resolved`)
+pub(crate) fn index_by_source(resolved: &[ResolvedEdge]) -> HashMap<&str, Vec<&ResolvedEdge>> {
+    let mut by_source: HashMap<&str, Vec<&ResolvedEdge>> = HashMap::new();
+    for r in resolved {
+        by_source.entry(r.source_id.as_str()).or_default().push(r);
+    }
+    by_source
+}
+
+/// Point `edges` at their resolved symbols and stamp the cross-file
+/// provenance, returning the number of rewritten edges
+pub fn apply_resolved_edges(edges: &mut [Edge], resolved: &[ResolvedEdge]) -> usize {
+    let by_source = index_by_source(resolved);
+    let mut applied = 0;
+    for edge in edges.iter_mut() {
+        let Some(r) = find_resolved(&by_source, edge) else {
+            continue;
+        };
+        edge.target_id = r.target_id.clone();
+        edge.metadata
+            .get_or_insert_with(Default::default)
+            .provenance = Some(r.provenance.clone());
+        applied += 1;
+    }
+    applied
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::cross_file::ImportResolver;
+    use crate::shared::models::{Node, NodeKind, Span};
+
+    fn node(id: &str, kind: NodeKind, fqn: &str, file_path: &str) -> Node {
+        Node::new(
+            id.to_string(),
+            kind,
+            fqn.to_string(),
+            file_path.to_string(),
+            Span::new(1, 0, 2, 0),
+        )
+        .with_name(fqn.rsplit('.').next().unwrap().to_string())
+    }
+
+    #[test]
+    fn test_resolve_calls_through_imports() {
+        let util = IRDocument::new(
+            "util.py".to_string(),
+            vec![node(
+                "f:helper",
+                NodeKind::Function,
+                "util.helper",
+                "util.py",
+            )],
+            vec![],
+        );
+        let main = IRDocument::new(
+            "main.py".to_string(),
+            vec![
+                node("f:main", NodeKind::Function, "main.main", "main.py"),
+                node("f:local", NodeKind::Function, "main.local", "main.py"),
+                node("i:helper", NodeKind::Import, "util.helper", "main.py"),
+            ],
+            vec![
+                Edge::new("f:main".into(), "i:helper".into(), EdgeKind::Imports),
+                Edge::new("f:main".into(), "external.helper".into(), EdgeKind::Calls),
+                Edge::new("f:main".into(), "f:local".into(), EdgeKind::Calls),
+                Edge::new("f:main".into(), "builtins.print".into(), EdgeKind::Calls),
+            ],
+        );
+        let irs = vec![util, main];
+        let symbol_index = SymbolIndex::build_from_irs(&irs);
+        let imports = ImportResolver::new(&symbol_index).resolve_all(&irs);
+
+        let resolved = resolve_cross_file_edges(&irs, &symbol_index, &imports);
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved[0].target_id, "f:helper");
+        assert_eq!(resolved[0].provenance.rule.as_deref(), Some(RULE_IMPORT));
+
+        let mut edges = irs[1].edges.clone();
+        assert_eq!(apply_resolved_edges(&mut edges, &resolved), 1);
+        assert_eq!(edges[1].target_id, "f:helper");
+        assert_eq!(edges[1].provenance().unwrap().stage, EdgeStage::CrossFile);
+        assert!(edges[2].provenance().is_none());
+    }
+}
@@ -0,0 +1,214 @@
+//! Go module resolution
+//!
+//! Maps Go import paths to in-repo package directories using `go.mod`:
+//! the `module` path prefixes every package of the module, local `replace`
+//! directives (`=> ../lib`) redirect a module into the repository, and
+//! `vendor/` copies take precedence like `-mod=vendor` builds.
+
+use std::path::Path;
+
+use walkdir::WalkDir;
+
+use super::tsconfig::{join_path, normalize_path, parent_dir};
+use crate::shared::models::{CodegraphError, Result};
+
+/// One `go.mod`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GoModule {
+    /// Directory containing `go.mod`, relative to the repo root
+    pub dir: String,
+    /// `module` path (`github.com/acme/shop`)
+    pub module_path: String,
+    /// (module path, local directory) of `replace` directives with a
+    /// filesystem target, directories resolved against `dir`
+    pub replaces: Vec<(String, String)>,
+}
+
+impl GoModule {
+    /// Parse `go.mod` text of the module in `dir`
+    pub fn parse(text: &str, dir: &str) -> Result<Self> {
+        let mut module_path = None;
+        let mut replaces = Vec::new();
+        let mut block: Option<String> = None;
+        for line in text.lines() {
+            let line = line.split("//").next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            if block.is_some() && line == ")" {
+                block = None;
+                continue;
+            }
+            let (directive, rest) = match &block {
+                Some(directive) => (directive.clone(), line),
+                None => {
+                    let (directive, rest) =
+                        line.split_once(char::is_whitespace).unwrap_or((line, ""));
+                    let rest = rest.trim();
+                    if rest == "(" {
+                        block = Some(directive.to_string());
+                        continue;
+                    }
+                    (directive.to_string(), rest)
+                }
+            };
+            match directive.as_str() {
+                "module" => module_path = Some(rest.trim_matches('"').to_string()),
+                "replace" => {
+                    let Some((old, new)) = rest.split_once("=>") else {
+                        continue;
+                    };
+                    let old = old.split_whitespace().next().unwrap_or("");
+                    let new = new.split_whitespace().next().unwrap_or("");
+                    if is_local_path(new) {
+                        replaces.push((old.to_string(), join_path(dir, new)));
+                    }
+                }
+                _ => {}
+            }
+        }
+        let module_path = module_path
+            .filter(|m| !m.is_empty())
+            .ok_or_else(|| CodegraphError::parse(format!("{}/go.mod: no module directive", dir)))?;
+        Ok(Self {
+            dir: normalize_path(dir),
+            module_path,
+            replaces,
+        })
+    }
+
+    /// Modules of every `go.mod` under `repo_root` (`vendor` and
+    /// `testdata` excluded); paths are relative to `repo_root`
+    pub fn discover(repo_root: &Path) -> Vec<Self> {
+        let root = normalize_path(&repo_root.to_string_lossy());
+        let entries = WalkDir::new(repo_root).into_iter().filter_entry(|e| {
+            let name = e.file_name().to_string_lossy();
+            e.depth() == 0 || !(name.starts_with('.') || name == "vendor" || name == "testdata")
+        });
+        let mut modules = Vec::new();
+        for entry in entries.filter_map(|e| e.ok()) {
+            if entry.file_name() != "go.mod" {
+                continue;
+            }
+            let dir = parent_dir(&normalize_path(&entry.path().to_string_lossy()));
+            let dir = match dir.strip_prefix(root.as_str()) {
+                Some(rest) => rest.trim_start_matches('/').to_string(),
+                None => dir,
+            };
+            let parsed = std::fs::read_to_string(entry.path())
+                .map_err(|e| CodegraphError::io(e.to_string()))
+                .and_then(|text| Self::parse(&text, &dir));
+            match parsed {
+                Ok(module) => modules.push(module),
+                Err(e) => tracing::warn!("[go.mod] Skipping {}: {}", entry.path().display(), e),
+            }
+        }
+        modules.sort_by(|a, b| a.dir.cmp(&b.dir));
+        modules
+    }
+
+    /// Whether `file` belongs to this module (nested modules aside)
+    pub fn governs(&self, file: &str) -> bool {
+        self.dir.is_empty()
+            || normalize_path(file)
+                .strip_prefix(self.dir.as_str())
+                .is_some_and(|rest| rest.starts_with('/'))
+    }
+
+    /// Directories that may hold the package `import_path`, most specific
+    /// first: vendored copy, local replacement, then the module itself
+    pub fn package_dirs(&self, import_path: &str) -> Vec<String> {
+        let mut dirs = vec![join_path(&join_path(&self.dir, "vendor"), import_path)];
+        let replaced = self
+            .replaces
+            .iter()
+            .filter_map(|(module, dir)| Some((module, dir, strip_module(import_path, module)?)))
+            .max_by_key(|(module, _, _)| module.len());
+        if let Some((_, dir, rest)) = replaced {
+            dirs.push(join_path(dir, rest));
+        }
+        dirs.extend(self.package_dir(import_path));
+        dirs
+    }
+
+    /// Directory of `import_path` when it is a package of this module
+    pub fn package_dir(&self, import_path: &str) -> Option<String> {
+        strip_module(import_path, &self.module_path).map(|rest| join_path(&self.dir, rest))
+    }
+}
+
+/// Module of the innermost `go.mod` governing `file`
+pub fn nearest_go_module<'a>(modules: &'a [GoModule], file: &str) -> Option<&'a GoModule> {
+    modules
+        .iter()
+        .filter(|m| m.governs(file))
+        .max_by_key(|m| m.dir.len())
+}
+
+/// Go source file
+pub fn is_go_file(path: &str) -> bool {
+    path.ends_with(".go")
+}
+
+/// Package path below `module` (`""` for the module root package)
+fn strip_module<'a>(import_path: &'a str, module: &str) -> Option<&'a str> {
+    match import_path.strip_prefix(module)? {
+        "" => Some(""),
+        rest => rest.strip_prefix('/'),
+    }
+}
+
+fn is_local_path(path: &str) -> bool {
+    path.starts_with("./") || path.starts_with("../") || path.starts_with('/')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_and_replace_directives() {
+        let go_mod = "\
+module github.com/acme/shop // the shop
+
+go 1.21
+
+require github.com/acme/lib v1.2.0
+
+replace github.com/acme/lib => ../lib
+
+replace (
+\tgithub.com/acme/util v0.3.0 => ./third_party/util
+\tgolang.org/x/net => golang.org/x/net v0.17.0
+)
+";
+        let module = GoModule::parse(go_mod, "services/shop").unwrap();
+        assert_eq!(module.module_path, "github.com/acme/shop");
+        assert_eq!(
+            module.replaces,
+            vec![
+                (
+                    "github.com/acme/lib".to_string(),
+                    "services/lib".to_string()
+                ),
+                (
+                    "github.com/acme/util".to_string(),
+                    "services/shop/third_party/util".to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            module.package_dirs("github.com/acme/shop/internal/cart"),
+            vec![
+                "services/shop/vendor/github.com/acme/shop/internal/cart",
+                "services/shop/internal/cart"
+            ]
+        );
+        assert_eq!(
+            module.package_dirs("github.com/acme/lib/money")[1],
+            "services/lib/money"
+        );
+        assert!(module.governs("services/shop/main.go"));
+        assert!(!module.governs("services/shopping/main.go"));
+    }
+}
@@ -0,0 +1,366 @@
+//! Graph Export (GraphML / Graphviz DOT)
+//!
+//! Serializes `SymbolDependencyGraph` and `DependencyGraph` for standard
+//! visualization tools (Graphviz, Gephi, yEd, Cytoscape).
+//!
+//! - DOT: edges colored by kind, optional `subgraph cluster_*` grouping
+//! - GraphML: `kind`/`file`/`cluster` node attributes, `kind`/`confidence`
+//!   edge attributes (yEd/Gephi import them as data columns)
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use super::symbol_graph::SymbolEdgeKind;
+
+/// Export options
+#[derive(Debug, Clone)]
+pub struct GraphExportOptions {
+    /// Graph name (DOT `digraph` id / GraphML `graph` id)
+    pub graph_name: String,
+
+    /// Group nodes by file (symbol graph) or directory (file graph)
+    pub cluster: bool,
+
+    /// Only export these symbol edge kinds (None = all)
+    pub edge_kinds: Option<Vec<SymbolEdgeKind>>,
+
+    /// Include reverse lookup edges (CalledBy, Exports)
+    ///
+    /// Off by default: they mirror Calls/Imports and double the edge count.
+    pub include_reverse_edges: bool,
+}
+
+impl Default for GraphExportOptions {
+    fn default() -> Self {
+        Self {
+            graph_name: "codegraph".to_string(),
+            cluster: false,
+            edge_kinds: None,
+            include_reverse_edges: false,
+        }
+    }
+}
+
+impl GraphExportOptions {
+    /// Builder: Group nodes into clusters
+    pub fn with_clusters(mut self) -> Self {
+        self.cluster = true;
+        self
+    }
+
+    /// Builder: Restrict exported edge kinds
+    pub fn with_edge_kinds(mut self, kinds: Vec<SymbolEdgeKind>) -> Self {
+        self.edge_kinds = Some(kinds);
+        self
+    }
+
+    /// Builder: Set graph name
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.graph_name = name.into();
+        self
+    }
+
+    /// Whether an edge of this kind is exported
+    pub(super) fn accepts(&self, kind: SymbolEdgeKind) -> bool {
+        if !self.include_reverse_edges
+            && matches!(kind, SymbolEdgeKind::CalledBy | SymbolEdgeKind::Exports)
+        {
+            return false;
+        }
+        match &self.edge_kinds {
+            Some(kinds) => kinds.contains(&kind),
+            None => true,
+        }
+    }
+}
+
+/// Exported node
+pub(super) struct ExportNode {
+    pub id: String,
+    pub label: String,
+    pub kind: &'static str,
+    pub file: Option<String>,
+    pub cluster: Option<String>,
+}
+
+/// Exported edge
+pub(super) struct ExportEdge {
+    pub source: String,
+    pub target: String,
+    pub kind: &'static str,
+    pub confidence: f32,
+}
+
+/// Format-neutral graph view
+#[derive(Default)]
+pub(super) struct ExportGraph {
+    pub nodes: Vec<ExportNode>,
+    pub edges: Vec<ExportEdge>,
+}
+
+/// Edge label used in both formats
+pub(super) fn edge_kind_name(kind: SymbolEdgeKind) -> &'static str {
+    match kind {
+        SymbolEdgeKind::Calls => "calls",
+        SymbolEdgeKind::CalledBy => "called_by",
+        SymbolEdgeKind::Overrides => "overrides",
+        SymbolEdgeKind::Inherits => "inherits",
+        SymbolEdgeKind::Implements => "implements",
+        SymbolEdgeKind::Reads => "reads",
+        SymbolEdgeKind::Writes => "writes",
+        SymbolEdgeKind::Imports => "imports",
+        SymbolEdgeKind::Exports => "exports",
+        SymbolEdgeKind::InstanceOf => "instance_of",
+        SymbolEdgeKind::Returns => "returns",
+    }
+}
+
+/// DOT edge color per kind
+fn edge_color(kind: &str) -> &'static str {
+    match kind {
+        "calls" | "called_by" => "#1f77b4",
+        "imports" | "exports" => "#2ca02c",
+        "inherits" | "implements" | "overrides" => "#9467bd",
+        "reads" => "#17becf",
+        "writes" => "#d62728",
+        "instance_of" | "returns" => "#ff7f0e",
+        _ => "#7f7f7f",
+    }
+}
+
+impl ExportGraph {
+    /// Sort nodes/edges so output is stable across runs, dropping parallel
+    /// edges of the same kind
+    pub fn sorted(mut self) -> Self {
+        self.nodes.sort_by(|a, b| a.id.cmp(&b.id));
+        self.edges
+            .sort_by(|a, b| (&a.source, &a.target, a.kind).cmp(&(&b.source, &b.target, b.kind)));
+        self.edges
+            .dedup_by(|a, b| a.source == b.source && a.target == b.target && a.kind == b.kind);
+        self
+    }
+
+    pub fn to_dot(&self, options: &GraphExportOptions) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "digraph {} {{", dot_quote(&options.graph_name));
+        out.push_str("  rankdir=LR;\n");
+        out.push_str("  node [shape=box, style=rounded, fontname=\"Helvetica\"];\n");
+        out.push_str("  edge [fontname=\"Helvetica\", fontsize=9];\n");
+
+        let node_line = |node: &ExportNode| {
+            format!(
+                "{} [label={}, tooltip={}];",
+                dot_quote(&node.id),
+                dot_quote(&node.label),
+                dot_quote(node.kind)
+            )
+        };
+
+        if options.cluster {
+            let mut clusters: BTreeMap<&str, Vec<&ExportNode>> = BTreeMap::new();
+            let mut loose = Vec::new();
+            for node in &self.nodes {
+                match &node.cluster {
+                    Some(cluster) => clusters.entry(cluster).or_default().push(node),
+                    None => loose.push(node),
+                }
+            }
+            for (i, (cluster, nodes)) in clusters.into_iter().enumerate() {
+                let _ = writeln!(out, "  subgraph cluster_{} {{", i);
+                let _ = writeln!(out, "    label={};", dot_quote(cluster));
+                out.push_str("    style=dashed; color=\"#999999\";\n");
+                for node in nodes {
+                    let _ = writeln!(out, "    {}", node_line(node));
+                }
+                out.push_str("  }\n");
+            }
+            for node in loose {
+                let _ = writeln!(out, "  {}", node_line(node));
+            }
+        } else {
+            for node in &self.nodes {
+                let _ = writeln!(out, "  {}", node_line(node));
+            }
+        }
+
+        for edge in &self.edges {
+            let color = edge_color(edge.kind);
+            let style = if edge.confidence < 1.0 {
+                ", style=dashed"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                out,
+                "  {} -> {} [label={}, color={}, fontcolor={}{}];",
+                dot_quote(&edge.source),
+                dot_quote(&edge.target),
+                dot_quote(edge.kind),
+                dot_quote(color),
+                dot_quote(color),
+                style
+            );
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
+    pub fn to_graphml(&self, options: &GraphExportOptions) -> String {
+        let mut out = String::new();
+        out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        out.push_str(
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\" \
+             xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" \
+             xsi:schemaLocation=\"http://graphml.graphdrawing.org/xmlns \
+             http://graphml.graphdrawing.org/xmlns/1.0/graphml.xsd\">\n",
+        );
+        for (id, target, name, ty) in [
+            ("n_label", "node", "label", "string"),
+            ("n_kind", "node", "kind", "string"),
+            ("n_file", "node", "file", "string"),
+            ("n_cluster", "node", "cluster", "string"),
+            ("e_kind", "edge", "kind", "string"),
+            ("e_color", "edge", "color", "string"),
+            ("e_confidence", "edge", "confidence", "double"),
+        ] {
+            let _ = writeln!(
+                out,
+                "  <key id=\"{}\" for=\"{}\" attr.name=\"{}\" attr.type=\"{}\"/>",
+                id, target, name, ty
+            );
+        }
+        let _ = writeln!(
+            out,
+            "  <graph id=\"{}\" edgedefault=\"directed\">",
+            xml_escape(&options.graph_name)
+        );
+
+        for node in &self.nodes {
+            let _ = writeln!(out, "    <node id=\"{}\">", xml_escape(&node.id));
+            let _ = writeln!(
+                out,
+                "      <data key=\"n_label\">{}</data>",
+                xml_escape(&node.label)
+            );
+            let _ = writeln!(out, "      <data key=\"n_kind\">{}</data>", node.kind);
+            if let Some(file) = &node.file {
+                let _ = writeln!(
+                    out,
+                    "      <data key=\"n_file\">{}</data>",
+                    xml_escape(file)
+                );
+            }
+            if let Some(cluster) = node.cluster.as_ref().filter(|_| options.cluster) {
+                let _ = writeln!(
+                    out,
+                    "      <data key=\"n_cluster\">{}</data>",
+                    xml_escape(cluster)
+                );
+            }
+            out.push_str("    </node>\n");
+        }
+
+        for (i, edge) in self.edges.iter().enumerate() {
+            let _ = writeln!(
+                out,
+                "    <edge id=\"e{}\" source=\"{}\" target=\"{}\">",
+                i,
+                xml_escape(&edge.source),
+                xml_escape(&edge.target)
+            );
+            let _ = writeln!(out, "      <data key=\"e_kind\">{}</data>", edge.kind);
+            let _ = writeln!(
+                out,
+                "      <data key=\"e_color\">{}</data>",
+                edge_color(edge.kind)
+            );
+            let _ = writeln!(
+                out,
+                "      <data key=\"e_confidence\">{}</data>",
+                edge.confidence
+            );
+            out.push_str("    </edge>\n");
+        }
+
+        out.push_str("  </graph>\n</graphml>\n");
+        out
+    }
+}
+
+/// Quote a DOT identifier
+pub(super) fn dot_quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Escape XML text / attribute content
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> ExportGraph {
+        ExportGraph {
+            nodes: vec![
+                ExportNode {
+                    id: "a.f".into(),
+                    label: "f".into(),
+                    kind: "Function",
+                    file: Some("a.py".into()),
+                    cluster: Some("a.py".into()),
+                },
+                ExportNode {
+                    id: "b.<g>".into(),
+                    label: "\"g\"".into(),
+                    kind: "Function",
+                    file: Some("b.py".into()),
+                    cluster: Some("b.py".into()),
+                },
+            ],
+            edges: vec![ExportEdge {
+                source: "a.f".into(),
+                target: "b.<g>".into(),
+                kind: "calls",
+                confidence: 0.5,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_dot_clusters_and_escaping() {
+        let options = GraphExportOptions::default().with_clusters();
+        let dot = sample().to_dot(&options);
+        assert!(dot.starts_with("digraph \"codegraph\" {"));
+        assert_eq!(dot.matches("subgraph cluster_").count(), 2);
+        assert!(dot.contains("label=\"\\\"g\\\"\""));
+        assert!(dot.contains("\"a.f\" -> \"b.<g>\" [label=\"calls\", color=\"#1f77b4\""));
+        assert!(dot.contains("style=dashed"));
+    }
+
+    #[test]
+    fn test_graphml_escaping() {
+        let xml = sample().to_graphml(&GraphExportOptions::default());
+        assert!(xml.contains("<node id=\"b.&lt;g&gt;\">"));
+        assert!(xml.contains("source=\"a.f\" target=\"b.&lt;g&gt;\""));
+        assert!(xml.contains("<data key=\"e_confidence\">0.5</data>"));
+        assert!(!xml.contains("n_cluster\">"));
+    }
+
+    #[test]
+    fn test_reverse_edges_filtered_by_default() {
+        let options = GraphExportOptions::default();
+        assert!(options.accepts(SymbolEdgeKind::Calls));
+        assert!(!options.accepts(SymbolEdgeKind::CalledBy));
+
+        let options = options.with_edge_kinds(vec![SymbolEdgeKind::Imports]);
+        assert!(!options.accepts(SymbolEdgeKind::Calls));
+        assert!(options.accepts(SymbolEdgeKind::Imports));
+    }
+}
@@ -14,13 +14,15 @@
 //!
 //! Key features:
 //! - Direct and transitive dependent analysis
-//! - Risk scoring (0.0-1.0) based on usage frequency
+//! - Risk scoring (0.0-1.0) based on usage frequency, discounted by edge confidence
 //! - Affected file computation for incremental updates
 //! - Test impact analysis (which tests need to run?)
+//! - Owning teams of affected files (CODEOWNERS, last touch)
 
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
+use super::ownership::{FileOwnership, OwnershipIndex};
 use super::symbol_graph::{SymbolDependencyGraph, SymbolEdgeKind};
 
 /// Impact analysis result
@@ -55,6 +57,20 @@ pub struct ImpactAnalysis {
 
     /// Impact by edge type
     pub impact_by_kind: HashMap<SymbolEdgeKind, usize>,
+
+    /// Confidence (0.0-1.0) that each transitive dependent is really affected
+    ///
+    /// Product of edge confidences along the most confident dependency path.
+    #[serde(default)]
+    pub dependent_confidence: HashMap<String, f32>,
+
+    /// Ownership of each affected file (filled by `with_ownership`)
+    #[serde(default)]
+    pub file_owners: HashMap<String, FileOwnership>,
+
+    /// Owners of each transitive dependent's file (filled by `with_ownership`)
+    #[serde(default)]
+    pub dependent_owners: HashMap<String, Vec<String>>,
 }
 
 impl ImpactAnalysis {
@@ -78,12 +94,19 @@ impl ImpactAnalysis {
         // Compute affected files
         let affected_files = Self::compute_affected_files(graph, &transitive_dependents);
 
+        // Confidence-weighted dependent counts (low-confidence edges count less)
+        let dependent_confidence = graph.get_transitive_dependents_with_confidence(target_fqn);
+        let direct_weight: f64 = direct_dependents
+            .iter()
+            .map(|dep| graph.edge_confidence(dep, target_fqn) as f64)
+            .sum();
+        let transitive_weight: f64 = transitive_dependents
+            .iter()
+            .map(|dep| dependent_confidence.get(dep).copied().unwrap_or(1.0) as f64)
+            .sum();
+
         // Compute risk score
-        let risk_score = Self::compute_risk_score(
-            direct_dependents.len(),
-            transitive_dependents.len(),
-            total_symbols,
-        );
+        let risk_score = Self::compute_risk_score(direct_weight, transitive_weight, total_symbols);
 
         // Compute max call depth (if function)
         let max_call_depth = if let Some(call_graph) = graph.call_graph() {
@@ -115,9 +138,48 @@ impl ImpactAnalysis {
             risk_score,
             max_call_depth,
             impact_by_kind,
+            dependent_confidence,
+            file_owners: HashMap::new(),
+            dependent_owners: HashMap::new(),
         })
     }
 
+    /// Attach owners of the affected files and transitive dependents
+    pub fn with_ownership(mut self, graph: &SymbolDependencyGraph, index: &OwnershipIndex) -> Self {
+        self.file_owners = self
+            .affected_files
+            .iter()
+            .map(|file| (file.clone(), index.lookup(file)))
+            .collect();
+        self.dependent_owners = self
+            .transitive_dependents
+            .iter()
+            .filter_map(|fqn| {
+                let file = &graph.get_symbol(fqn)?.file_path;
+                let contacts = match self.file_owners.get(file) {
+                    Some(ownership) => ownership.contacts(),
+                    None => index.lookup(file).contacts(),
+                };
+                Some((fqn.clone(), contacts))
+            })
+            .collect();
+        self
+    }
+
+    /// Who to notify about the change: owners of the affected files (last
+    /// authors of unowned files), most affected files first
+    pub fn notify(&self) -> Vec<String> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for ownership in self.file_owners.values() {
+            for contact in ownership.contacts() {
+                *counts.entry(contact).or_default() += 1;
+            }
+        }
+        let mut contacts: Vec<(String, usize)> = counts.into_iter().collect();
+        contacts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        contacts.into_iter().map(|(contact, _)| contact).collect()
+    }
+
     /// Compute affected files from dependent symbols
     fn compute_affected_files(
         graph: &SymbolDependencyGraph,
@@ -134,29 +196,28 @@ impl ImpactAnalysis {
         files.into_iter().collect()
     }
 
-    /// Compute risk score based on dependent count
+    /// Compute risk score based on (confidence-weighted) dependent count
     ///
     /// Formula: min(1.0, (transitive_count / total_symbols) * 10.0)
     ///
+    /// Counts are sums of dependent confidences, so a dependent reached only
+    /// through a 0.5-confidence call edge contributes half.
+    ///
     /// Logic:
     /// - If 10% of codebase depends on this: High risk (1.0)
     /// - If 5% of codebase depends on this: Medium-high risk (0.5)
     /// - If 1% of codebase depends on this: Low-medium risk (0.1)
-    fn compute_risk_score(
-        direct_count: usize,
-        transitive_count: usize,
-        total_symbols: usize,
-    ) -> f64 {
+    fn compute_risk_score(direct_count: f64, transitive_count: f64, total_symbols: usize) -> f64 {
         if total_symbols == 0 {
             return 0.0;
         }
 
         // Base score from transitive impact
-        let transitive_ratio = transitive_count as f64 / total_symbols as f64;
+        let transitive_ratio = transitive_count / total_symbols as f64;
         let base_score = (transitive_ratio * 10.0).min(1.0);
 
         // Boost score if high direct impact (sign of core infrastructure)
-        let direct_ratio = direct_count as f64 / total_symbols as f64;
+        let direct_ratio = direct_count / total_symbols as f64;
         let direct_boost = (direct_ratio * 5.0).min(0.3);
 
         (base_score + direct_boost).min(1.0)
@@ -275,6 +336,16 @@ impl BatchImpactAnalysis {
             summary,
         }
     }
+
+    /// Attach ownership to every impact (see `ImpactAnalysis::with_ownership`)
+    pub fn with_ownership(mut self, graph: &SymbolDependencyGraph, index: &OwnershipIndex) -> Self {
+        self.impacts = self
+            .impacts
+            .into_iter()
+            .map(|impact| impact.with_ownership(graph, index))
+            .collect();
+        self
+    }
 }
 
 /// Impact summary statistics
@@ -373,6 +444,57 @@ mod tests {
         assert_eq!(impact_a.risk_level(), RiskLevel::Low);
     }
 
+    #[test]
+    fn test_low_confidence_edges_discount_risk() {
+        use crate::shared::models::{EdgeProvenance, EdgeStage};
+
+        let nodes = vec![
+            make_test_node("a", NodeKind::Function, "test.a", "src/test.py"),
+            make_test_node("b", NodeKind::Function, "test.b", "src/test.py"),
+            make_test_node("c", NodeKind::Function, "test.c", "src/test.py"),
+            make_test_node("d", NodeKind::Function, "test.d", "src/test.py"),
+            make_test_node("e", NodeKind::Function, "test.e", "src/test.py"),
+        ];
+        let certain = IRDocument {
+            file_path: "src/test.py".to_string(),
+            nodes: nodes.clone(),
+            edges: vec![make_call_edge("a", "b"), make_call_edge("c", "b")],
+            repo_id: None,
+        };
+        let guessed = IRDocument {
+            file_path: "src/test.py".to_string(),
+            nodes,
+            edges: vec![
+                make_call_edge("a", "b"),
+                make_call_edge("c", "b").with_provenance(
+                    EdgeProvenance::new(EdgeStage::IrBuild)
+                        .with_rule("call_site")
+                        .with_confidence(0.25),
+                ),
+            ],
+            repo_id: None,
+        };
+
+        let certain_graph = SymbolDependencyGraph::build_from_irs(&[certain]);
+        let guessed_graph = SymbolDependencyGraph::build_from_irs(&[guessed]);
+        assert_eq!(guessed_graph.edge_confidence("test.c", "test.b"), 0.25);
+        assert_eq!(guessed_graph.edge_confidence("test.a", "test.b"), 1.0);
+
+        let certain_impact = ImpactAnalysis::compute(&certain_graph, "test.b", 100).unwrap();
+        let guessed_impact = ImpactAnalysis::compute(&guessed_graph, "test.b", 100).unwrap();
+
+        // Same dependents, lower risk
+        assert_eq!(
+            certain_impact.direct_dependents.len(),
+            guessed_impact.direct_dependents.len()
+        );
+        assert!(guessed_impact.risk_score < certain_impact.risk_score);
+        assert_eq!(
+            guessed_impact.dependent_confidence.get("test.c").copied(),
+            Some(0.25)
+        );
+    }
+
     #[test]
     fn test_batch_impact_analysis() {
         let ir = IRDocument {
@@ -426,4 +548,58 @@ mod tests {
         let impact_b = ImpactAnalysis::compute(&graph, "test.b", total_symbols).unwrap();
         assert_eq!(impact_b.max_call_depth, 2); // b → c → d
     }
+
+    #[test]
+    fn test_impact_owners() {
+        use crate::features::cross_file::{CodeOwners, OwnershipIndex};
+
+        // api.route → billing.pay → lib.util (callees imported per file)
+        let lib = IRDocument {
+            file_path: "lib/util.py".to_string(),
+            nodes: vec![make_test_node(
+                "u",
+                NodeKind::Function,
+                "lib.util",
+                "lib/util.py",
+            )],
+            edges: vec![],
+            repo_id: None,
+        };
+        let billing = IRDocument {
+            file_path: "billing/pay.py".to_string(),
+            nodes: vec![
+                make_test_node("p", NodeKind::Function, "billing.pay", "billing/pay.py"),
+                make_test_node("u", NodeKind::Import, "lib.util", "billing/pay.py"),
+            ],
+            edges: vec![make_call_edge("p", "u")],
+            repo_id: None,
+        };
+        let api = IRDocument {
+            file_path: "api/routes.py".to_string(),
+            nodes: vec![
+                make_test_node("r", NodeKind::Function, "api.route", "api/routes.py"),
+                make_test_node("p", NodeKind::Import, "billing.pay", "api/routes.py"),
+            ],
+            edges: vec![make_call_edge("r", "p")],
+            repo_id: None,
+        };
+        let graph = SymbolDependencyGraph::build_from_irs(&[lib, billing, api]);
+        let index = OwnershipIndex::new(CodeOwners::parse(
+            "* @acme/platform\n/billing/ @acme/billing\n/api/ @acme/api @acme/billing\n",
+        ));
+
+        let impact = ImpactAnalysis::compute(&graph, "lib.util", 3)
+            .unwrap()
+            .with_ownership(&graph, &index);
+
+        assert_eq!(
+            impact.file_owners["billing/pay.py"].owners,
+            vec!["@acme/billing"]
+        );
+        assert_eq!(
+            impact.dependent_owners["api.route"],
+            vec!["@acme/api", "@acme/billing"]
+        );
+        assert_eq!(impact.notify(), vec!["@acme/billing", "@acme/api"]);
+    }
 }
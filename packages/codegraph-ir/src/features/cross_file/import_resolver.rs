@@ -5,13 +5,23 @@
 
 use dashmap::DashMap;
 use rayon::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, OnceLock};
 
+use super::go_module::{is_go_file, nearest_go_module, GoModule};
+use super::jvm_project::{is_jvm_source_file, JvmProject};
 use super::scope_index::ScopeAwareIndex;
-use super::symbol_index::SymbolIndex;
-use super::types::{ImportInfo, ResolutionMethod, ResolvedImport};
+use super::symbol_index::{absolute_import_fqn, SymbolIndex};
+use super::tsconfig::{
+    is_relative_specifier, is_ts_module_file, join_path, module_file_candidates, nearest_ts_config,
+    normalize_path, parent_dir, split_ts_import, TsConfig,
+};
+use super::types::{ImportInfo, ResolutionMethod, ResolvedImport, Symbol};
 use super::IRDocument;
-use crate::shared::models::{EdgeKind, Node};
+use crate::shared::models::{EdgeKind, Node, NodeKind};
+
+/// (file, name) pairs visited while following TS/JS re-export chains
+const MAX_TS_REEXPORT_HOPS: usize = 64;
 
 /// Import resolver with parallel processing
 ///
@@ -24,6 +34,21 @@ pub struct ImportResolver<'a> {
     /// When present, aliases are registered in appropriate scope
     /// instead of file-level only
     scope_index: Option<&'a ScopeAwareIndex>,
+
+    /// tsconfig files for TS/JS path aliases (nearest config per file)
+    ts_configs: &'a [TsConfig],
+
+    /// Normalized path → indexed file, for TS/JS module lookup
+    ts_files: OnceLock<HashMap<String, String>>,
+
+    /// Gradle/Maven modules: Java/Kotlin imports stay within dependencies
+    jvm_project: Option<&'a JvmProject>,
+
+    /// go.mod files for Go import paths
+    go_modules: &'a [GoModule],
+
+    /// Directory → indexed Go files (tests excluded), for package lookup
+    go_packages: OnceLock<HashMap<String, Vec<String>>>,
 }
 
 impl<'a> ImportResolver<'a> {
@@ -32,6 +57,11 @@ impl<'a> ImportResolver<'a> {
         Self {
             symbol_index,
             scope_index: None,
+            ts_configs: &[],
+            ts_files: OnceLock::new(),
+            jvm_project: None,
+            go_modules: &[],
+            go_packages: OnceLock::new(),
         }
     }
 
@@ -43,9 +73,32 @@ impl<'a> ImportResolver<'a> {
         Self {
             symbol_index,
             scope_index: Some(scope_index),
+            ts_configs: &[],
+            ts_files: OnceLock::new(),
+            jvm_project: None,
+            go_modules: &[],
+            go_packages: OnceLock::new(),
         }
     }
 
+    /// Resolve non-relative TS/JS specifiers with `paths`/`baseUrl` of `ts_configs`
+    pub fn with_ts_configs(mut self, ts_configs: &'a [TsConfig]) -> Self {
+        self.ts_configs = ts_configs;
+        self
+    }
+
+    /// Resolve Go import paths with the `module`/`replace` directives of `go_modules`
+    pub fn with_go_modules(mut self, go_modules: &'a [GoModule]) -> Self {
+        self.go_modules = go_modules;
+        self
+    }
+
+    /// Reject Java/Kotlin imports into modules the importer does not depend on
+    pub fn with_jvm_project(mut self, jvm_project: &'a JvmProject) -> Self {
+        self.jvm_project = Some(jvm_project);
+        self
+    }
+
     /// Resolve all imports from IR documents (parallel)
     ///
     /// Returns: file_path → Vec<ResolvedImport>
@@ -86,6 +139,11 @@ impl<'a> ImportResolver<'a> {
         let node_by_id: HashMap<&str, &Node> =
             ir.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
 
+        let module_path = ir
+            .nodes
+            .iter()
+            .find_map(|n| n.module_path.clone().filter(|m| !m.is_empty()));
+
         let mut imports = Vec::new();
 
         for edge in &ir.edges {
@@ -124,6 +182,7 @@ impl<'a> ImportResolver<'a> {
                 target_node_id: edge.target_id.clone(),
                 imported_name,
                 alias,
+                module_path: module_path.clone(),
             };
 
             imports.push((ir.file_path.clone(), import_info));
@@ -134,8 +193,54 @@ impl<'a> ImportResolver<'a> {
 
     /// Resolve a single import
     fn resolve_single(&self, import: &ImportInfo) -> ResolvedImport {
+        let resolved = self.resolve_candidate(import);
+        match (self.jvm_project, resolved.source_file.as_deref()) {
+            (Some(jvm), Some(target))
+                if is_jvm_source_file(&import.file_path)
+                    && !jvm.is_visible(&import.file_path, target) =>
+            {
+                // First-party, but outside the module's declared dependencies
+                let mut unresolved = ResolvedImport::unresolved(import.imported_name.clone());
+                unresolved.is_external = false;
+                unresolved
+            }
+            _ => resolved,
+        }
+    }
+
+    /// Resolve a single import, ignoring module boundaries
+    fn resolve_candidate(&self, import: &ImportInfo) -> ResolvedImport {
         let fqn = &import.imported_name;
 
+        // Strategy 0: TS/JS module specifier (`./utils#helper`, `@/lib#api`)
+        if is_ts_module_file(&import.file_path) {
+            if let Some(mut resolved) = self.resolve_ts_import(import) {
+                if let Some(ref alias) = import.alias {
+                    if let Some(ref resolved_fqn) = resolved.resolved_fqn {
+                        self.register_alias_in_scope(import, alias, resolved_fqn);
+                        resolved = resolved.with_alias(alias.clone());
+                    }
+                }
+                return resolved;
+            }
+        }
+
+        // Strategy 0: Go import path (`github.com/acme/shop/internal/cart`)
+        if is_go_file(&import.file_path) {
+            if let Some(resolved) = self.resolve_go_import(import) {
+                return match import.alias.as_deref() {
+                    // Blank and dot imports bind no package name
+                    Some(alias) if alias != "_" && alias != "." => {
+                        if let Some(ref resolved_fqn) = resolved.resolved_fqn {
+                            self.register_alias_in_scope(import, alias, resolved_fqn);
+                        }
+                        resolved.with_alias(alias.to_string())
+                    }
+                    _ => resolved,
+                };
+            }
+        }
+
         // Strategy 1: Exact FQN match
         if let Some(symbol) = self.symbol_index.resolve_exact(fqn) {
             let mut resolved = ResolvedImport::resolved(
@@ -155,7 +260,28 @@ impl<'a> ImportResolver<'a> {
             return resolved;
         }
 
-        // Strategy 2: Partial match (module.submodule.Class → module.submodule)
+        // Strategy 2: Re-export (core.login → core.auth.login)
+        if let Some(alias) = self.symbol_index.resolve_reexport(fqn) {
+            if let Some(symbol) = self.symbol_index.get(&alias.canonical_fqn) {
+                let mut resolved = ResolvedImport::resolved(
+                    fqn.clone(),
+                    symbol.fqn.clone(),
+                    symbol.file_path.clone(),
+                    symbol.node_id.clone(),
+                    ResolutionMethod::ExactMatch,
+                )
+                .with_reexport_file(alias.file_path);
+
+                if let Some(ref alias) = import.alias {
+                    self.register_alias_in_scope(import, alias, &symbol.fqn);
+                    resolved = resolved.with_alias(alias.clone());
+                }
+
+                return resolved;
+            }
+        }
+
+        // Strategy 3: Partial match (module.submodule.Class → module.submodule)
         if let Some((symbol, matched_fqn)) = self.symbol_index.resolve_partial(fqn) {
             let mut resolved = ResolvedImport::resolved(
                 fqn.clone(),
@@ -174,7 +300,7 @@ impl<'a> ImportResolver<'a> {
             return resolved;
         }
 
-        // Strategy 3: Module path pattern match
+        // Strategy 4: Module path pattern match
         if let Some(symbol) = self.symbol_index.resolve_by_module_path(fqn) {
             let mut resolved = ResolvedImport::resolved(
                 fqn.clone(),
@@ -193,7 +319,7 @@ impl<'a> ImportResolver<'a> {
             return resolved;
         }
 
-        // Strategy 4: Try relative import resolution
+        // Strategy 5: Try relative import resolution
         if fqn.starts_with('.') {
             if let Some(mut resolved) = self.resolve_relative_import(import) {
                 // SOTA: Register alias in appropriate scope
@@ -248,6 +374,145 @@ impl<'a> ImportResolver<'a> {
         );
     }
 
+    /// Resolve a TS/JS import through its module file and re-export chains
+    fn resolve_ts_import(&self, import: &ImportInfo) -> Option<ResolvedImport> {
+        let (specifier, name) = split_ts_import(&import.imported_name);
+        let file = self.resolve_ts_module(&import.file_path, specifier)?;
+
+        // Default exports are found by the local name (`import Button from './Button'`)
+        let mut visited = HashSet::new();
+        let found = match (name, import.alias.as_deref()) {
+            ("default", Some(local)) => self.find_ts_export(&file, local, &mut visited),
+            _ => None,
+        };
+        let (symbol, via) = found.or_else(|| self.find_ts_export(&file, name, &mut visited))?;
+
+        let method = if symbol.kind == NodeKind::File {
+            ResolutionMethod::ModulePath
+        } else {
+            ResolutionMethod::ExactMatch
+        };
+        let resolved = ResolvedImport::resolved(
+            import.imported_name.clone(),
+            symbol.fqn.clone(),
+            symbol.file_path.clone(),
+            symbol.node_id.clone(),
+            method,
+        );
+        Some(match via {
+            Some(via) => resolved.with_reexport_file(via),
+            None => resolved,
+        })
+    }
+
+    /// Symbol exported as `name` by `file`, with the first re-exporting file
+    /// on the way (`*` and anonymous defaults name the module itself)
+    fn find_ts_export(
+        &self,
+        file: &str,
+        name: &str,
+        visited: &mut HashSet<(String, String)>,
+    ) -> Option<(Arc<Symbol>, Option<String>)> {
+        if visited.len() >= MAX_TS_REEXPORT_HOPS
+            || !visited.insert((file.to_string(), name.to_string()))
+        {
+            return None;
+        }
+        let symbols = self.symbol_index.get_file_symbols(file);
+        if name == "*" || name == "default" {
+            let module = symbols.iter().find(|s| s.kind == NodeKind::File);
+            return module.or(symbols.first()).map(|s| (Arc::clone(s), None));
+        }
+
+        // Top-level definitions have the shortest FQN among same-named symbols
+        let defined = symbols
+            .into_iter()
+            .filter(|s| s.name == name && s.kind != NodeKind::File)
+            .min_by_key(|s| s.fqn.matches('.').count());
+        if let Some(symbol) = defined {
+            return Some((symbol, None));
+        }
+
+        for reexport in self.symbol_index.ts_reexports(file) {
+            // `export *` forwards every name but the default
+            let imported = match reexport.exported.as_str() {
+                "*" if name != "default" => name,
+                exported if exported == name => reexport.imported.as_str(),
+                _ => continue,
+            };
+            let Some(target) = self.resolve_ts_module(file, &reexport.specifier) else {
+                continue;
+            };
+            if let Some((symbol, _)) = self.find_ts_export(&target, imported, visited) {
+                return Some((symbol, Some(file.to_string())));
+            }
+        }
+        None
+    }
+
+    /// Indexed file a TS/JS `specifier` imported from `importing_file` names
+    fn resolve_ts_module(&self, importing_file: &str, specifier: &str) -> Option<String> {
+        let modules = if is_relative_specifier(specifier) {
+            vec![join_path(&parent_dir(importing_file), specifier)]
+        } else {
+            nearest_ts_config(self.ts_configs, importing_file)
+                .map(|config| config.module_candidates(specifier))
+                .unwrap_or_default()
+        };
+        let files = self.ts_files.get_or_init(|| {
+            self.symbol_index
+                .file_paths()
+                .into_iter()
+                .filter(|f| is_ts_module_file(f))
+                .map(|f| (normalize_path(&f), f))
+                .collect()
+        });
+        modules
+            .iter()
+            .flat_map(|module| module_file_candidates(module))
+            .find_map(|candidate| files.get(&candidate).cloned())
+    }
+
+    /// Resolve a Go import path to the in-repo package directory it names
+    fn resolve_go_import(&self, import: &ImportInfo) -> Option<ResolvedImport> {
+        let path = &import.imported_name;
+        let mut dirs = nearest_go_module(self.go_modules, &import.file_path)
+            .map(|module| module.package_dirs(path))
+            .unwrap_or_default();
+        // Other modules of a multi-module repository
+        dirs.extend(self.go_modules.iter().filter_map(|m| m.package_dir(path)));
+
+        let packages = self.go_packages.get_or_init(|| {
+            let mut packages: HashMap<String, Vec<String>> = HashMap::new();
+            for file in self.symbol_index.file_paths() {
+                if is_go_file(&file) && !file.ends_with("_test.go") {
+                    packages
+                        .entry(parent_dir(&normalize_path(&file)))
+                        .or_default()
+                        .push(file);
+                }
+            }
+            packages.values_mut().for_each(|files| files.sort());
+            packages
+        });
+        let file = dirs
+            .iter()
+            .find_map(|dir| packages.get(dir).and_then(|files| files.first()))?;
+
+        let symbols = self.symbol_index.get_file_symbols(file);
+        let symbol = symbols
+            .iter()
+            .find(|s| s.kind == NodeKind::File)
+            .or(symbols.first())?;
+        Some(ResolvedImport::resolved(
+            path.clone(),
+            symbol.fqn.clone(),
+            symbol.file_path.clone(),
+            symbol.node_id.clone(),
+            ResolutionMethod::ModulePath,
+        ))
+    }
+
     /// Resolve relative import (e.g., ".utils" from "package/main.py")
     fn resolve_relative_import(&self, import: &ImportInfo) -> Option<ResolvedImport> {
         let fqn = &import.imported_name;
@@ -257,23 +522,32 @@ impl<'a> ImportResolver<'a> {
         let dots = fqn.chars().take_while(|c| *c == '.').count();
         let remainder = &fqn[dots..];
 
-        // Get current module path from file path
-        let module_parts: Vec<&str> = file_path
-            .trim_end_matches(".py")
-            .split('/')
-            .filter(|s| !s.is_empty() && *s != "src")
-            .collect();
-
-        if module_parts.len() < dots {
-            return None;
-        }
+        let absolute_fqn = match import.module_path.as_deref() {
+            // Module path from the IR already has package roots applied
+            Some(module) => {
+                let (package, _) = module.rsplit_once('.')?;
+                absolute_import_fqn(package, fqn)?
+            }
+            None => {
+                // Get current module path from file path
+                let module_parts: Vec<&str> = file_path
+                    .trim_end_matches(".py")
+                    .split('/')
+                    .filter(|s| !s.is_empty() && *s != "src")
+                    .collect();
+
+                if module_parts.len() < dots {
+                    return None;
+                }
 
-        // Build absolute module path
-        let base_parts = &module_parts[..module_parts.len() - dots + 1];
-        let absolute_fqn = if remainder.is_empty() {
-            base_parts.join(".")
-        } else {
-            format!("{}.{}", base_parts.join("."), remainder)
+                // Build absolute module path
+                let base_parts = &module_parts[..module_parts.len() - dots + 1];
+                if remainder.is_empty() {
+                    base_parts.join(".")
+                } else {
+                    format!("{}.{}", base_parts.join("."), remainder)
+                }
+            }
         };
 
         // Try to resolve the absolute FQN
@@ -332,6 +606,7 @@ pub fn resolve_imports_for_files(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::features::cross_file::JvmModule;
     use crate::shared::models::{Edge, EdgeKind, Node, NodeKind, Span};
 
     fn make_test_node(id: &str, fqn: &str, file_path: &str, name: &str) -> Node {
@@ -468,6 +743,188 @@ mod tests {
         assert_eq!(imports[0].resolution_method, ResolutionMethod::NotFound);
     }
 
+    #[test]
+    fn test_resolve_ts_path_alias_through_export_star() {
+        let file_node = |id: &str, path: &str| {
+            Node::new(
+                id.to_string(),
+                NodeKind::File,
+                path.to_string(),
+                path.to_string(),
+                Span::new(1, 0, 10, 0),
+            )
+            .with_name(path.to_string())
+        };
+        let ir_api = IRDocument {
+            file_path: "src/lib/api.ts".to_string(),
+            nodes: vec![
+                file_node("api_file", "src/lib/api.ts"),
+                make_test_node("api_fetch", "fetchUser", "src/lib/api.ts", "fetchUser"),
+            ],
+            edges: vec![],
+            repo_id: None,
+        };
+        // src/lib/index.ts: export * from './api'
+        let mut reexport = make_import_node("index_star", "./api#*", "src/lib/index.ts", "*");
+        reexport.modifiers = Some(vec!["export".to_string()]);
+        let ir_index = IRDocument {
+            file_path: "src/lib/index.ts".to_string(),
+            nodes: vec![file_node("index_file", "src/lib/index.ts"), reexport],
+            edges: vec![],
+            repo_id: None,
+        };
+        // src/app.ts: import { fetchUser } from '@/lib'
+        let ir_app = IRDocument {
+            file_path: "src/app.ts".to_string(),
+            nodes: vec![file_node("app_file", "src/app.ts")],
+            edges: vec![make_import_edge("app_file", "@/lib#fetchUser")],
+            repo_id: None,
+        };
+
+        let index = SymbolIndex::build_from_irs(&[ir_api, ir_index, ir_app.clone()]);
+        let configs =
+            [
+                TsConfig::parse(r#"{"compilerOptions": {"paths": {"@/*": ["src/*"]}}}"#, "")
+                    .unwrap(),
+            ];
+        let resolver = ImportResolver::new(&index).with_ts_configs(&configs);
+        let resolved = resolver.resolve_all(&[ir_app]);
+
+        let import = &resolved["src/app.ts"][0];
+        assert_eq!(import.resolved_fqn.as_deref(), Some("fetchUser"));
+        assert_eq!(import.source_file.as_deref(), Some("src/lib/api.ts"));
+        assert_eq!(import.reexport_file.as_deref(), Some("src/lib/index.ts"));
+        assert_eq!(import.resolution_method, ResolutionMethod::ExactMatch);
+    }
+
+    #[test]
+    fn test_resolve_relative_import_with_module_path() {
+        // services/api/src is a package root: handlers.py is `acme.handlers`
+        let ir_models = IRDocument {
+            file_path: "services/api/src/acme/models.py".to_string(),
+            nodes: vec![make_test_node(
+                "models_user",
+                "acme.models.User",
+                "services/api/src/acme/models.py",
+                "User",
+            )
+            .with_module_path("acme.models")],
+            edges: vec![],
+            repo_id: None,
+        };
+        let ir_handlers = IRDocument {
+            file_path: "services/api/src/acme/handlers.py".to_string(),
+            nodes: vec![make_test_node(
+                "handlers_get",
+                "acme.handlers.get",
+                "services/api/src/acme/handlers.py",
+                "get",
+            )
+            .with_module_path("acme.handlers")],
+            edges: vec![make_import_edge("handlers_get", ".models.User")],
+            repo_id: None,
+        };
+
+        let index = SymbolIndex::build_from_irs(&[ir_models, ir_handlers.clone()]);
+        let resolver = ImportResolver::new(&index);
+        let resolved = resolver.resolve_all(&[ir_handlers]);
+
+        let import = &resolved["services/api/src/acme/handlers.py"][0];
+        assert_eq!(import.resolved_fqn.as_deref(), Some("acme.models.User"));
+        assert_eq!(import.resolution_method, ResolutionMethod::ExactMatch);
+    }
+
+    #[test]
+    fn test_jvm_imports_respect_module_dependencies() {
+        let module = |name: &str, dir: &str, deps: &[&str]| JvmModule {
+            name: name.to_string(),
+            dir: dir.to_string(),
+            source_roots: vec![format!("{}/src/main/java", dir)],
+            dependencies: deps.iter().map(|d| d.to_string()).collect(),
+        };
+        let jvm = JvmProject::from_modules(vec![
+            module(":app", "app", &[":core"]),
+            module(":core", "core", &[]),
+        ]);
+
+        let app_file = "app/src/main/java/com/acme/App.java";
+        let core_file = "core/src/main/java/com/acme/core/Repo.java";
+        let ir_app = IRDocument {
+            file_path: app_file.to_string(),
+            nodes: vec![make_test_node("app", "com.acme.App", app_file, "App")],
+            edges: vec![make_import_edge("app", "com.acme.core.Repo")],
+            repo_id: None,
+        };
+        // core may not reach back into app
+        let ir_core = IRDocument {
+            file_path: core_file.to_string(),
+            nodes: vec![make_test_node(
+                "repo",
+                "com.acme.core.Repo",
+                core_file,
+                "Repo",
+            )],
+            edges: vec![make_import_edge("repo", "com.acme.App")],
+            repo_id: None,
+        };
+
+        let irs = [ir_app, ir_core];
+        let index = SymbolIndex::build_from_irs(&irs);
+        let resolver = ImportResolver::new(&index).with_jvm_project(&jvm);
+        let resolved = resolver.resolve_all(&irs);
+
+        let app_import = &resolved[app_file][0];
+        assert_eq!(app_import.source_file.as_deref(), Some(core_file));
+        let core_import = &resolved[core_file][0];
+        assert_eq!(core_import.resolution_method, ResolutionMethod::NotFound);
+        assert!(!core_import.is_external);
+    }
+
+    #[test]
+    fn test_resolve_go_module_and_vendored_imports() {
+        let go_file = |id: &str, path: &str, fqn: &str| IRDocument {
+            file_path: path.to_string(),
+            nodes: vec![make_test_node(id, fqn, path, "New")],
+            edges: vec![],
+            repo_id: None,
+        };
+        let mut ir_main = go_file("main", "cmd/shop/main.go", "main.main");
+        ir_main.edges = vec![
+            make_import_edge("main", "github.com/acme/shop/internal/cart"),
+            make_import_edge("main", "github.com/acme/lib/money"),
+            make_import_edge("main", "fmt"),
+        ];
+        let irs = [
+            ir_main.clone(),
+            go_file("cart", "internal/cart/cart.go", "cart.New"),
+            go_file(
+                "money",
+                "vendor/github.com/acme/lib/money/money.go",
+                "money.New",
+            ),
+        ];
+        let modules = [GoModule::parse("module github.com/acme/shop\n", "").unwrap()];
+
+        let index = SymbolIndex::build_from_irs(&irs);
+        let resolver = ImportResolver::new(&index).with_go_modules(&modules);
+        let resolved = resolver.resolve_all(&[ir_main]);
+
+        let by_path: HashMap<&str, &ResolvedImport> = resolved["cmd/shop/main.go"]
+            .iter()
+            .map(|r| (r.import_fqn.as_str(), r))
+            .collect();
+        let file = |path: &str| by_path[path].source_file.as_deref();
+        assert_eq!(
+            file("github.com/acme/shop/internal/cart"),
+            Some("internal/cart/cart.go")
+        );
+        assert_eq!(
+            file("github.com/acme/lib/money"),
+            Some("vendor/github.com/acme/lib/money/money.go")
+        );
+        assert!(by_path["fmt"].is_external);
+    }
+
     #[test]
     fn test_resolve_multiple_files_parallel() {
         let irs: Vec<IRDocument> = (0..10)
@@ -0,0 +1,490 @@
+//! JVM project model
+//!
+//! Gradle (`settings.gradle[.kts]`) and Maven (`pom.xml`) modules with their
+//! source roots and inter-module dependencies. Java/Kotlin module paths are
+//! the package path below a source root (`app/src/main/java/com/acme/App.java`
+//! → `com.acme.App`), and an import may only resolve into the importing
+//! module or one it (transitively) depends on.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use walkdir::WalkDir;
+
+use super::tsconfig::{join_path, normalize_path};
+
+/// Source set directories under `src/` searched when a module declares none
+const DEFAULT_SOURCE_SETS: &[&str] = &["main", "test"];
+const SOURCE_LANGUAGES: &[&str] = &["java", "kotlin"];
+
+/// Gradle project or Maven module
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JvmModule {
+    /// Gradle path (`:lib:core`) or Maven artifactId
+    pub name: String,
+    /// Module directory relative to the repo root
+    pub dir: String,
+    /// Source roots relative to the repo root (`lib/core/src/main/java`)
+    pub source_roots: Vec<String>,
+    /// Modules this one depends on directly
+    pub dependencies: Vec<String>,
+}
+
+/// Modules of every Gradle build and Maven reactor in a repository
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JvmProject {
+    /// Innermost module directory first
+    pub modules: Vec<JvmModule>,
+}
+
+impl JvmProject {
+    /// Modules under `repo_root`; paths are relative to it
+    pub fn discover(repo_root: &Path) -> Self {
+        let root = normalize_path(&repo_root.to_string_lossy());
+        let relative = |path: &Path| {
+            let path = normalize_path(&path.to_string_lossy());
+            match path.strip_prefix(root.as_str()) {
+                Some(rest) => rest.trim_start_matches('/').to_string(),
+                None => path,
+            }
+        };
+        let read = |dir: &Path, names: &[&str]| {
+            names
+                .iter()
+                .find_map(|name| std::fs::read_to_string(dir.join(name)).ok())
+        };
+
+        let mut modules = Vec::new();
+        let mut gradle_dirs = HashSet::new();
+        let mut poms = Vec::new();
+        let entries = WalkDir::new(repo_root)
+            .into_iter()
+            .filter_entry(|e| e.depth() == 0 || !is_skipped_dir(&e.file_name().to_string_lossy()));
+        for entry in entries.filter_map(|e| e.ok()) {
+            match entry.file_name().to_string_lossy().as_ref() {
+                "settings.gradle" | "settings.gradle.kts" => {
+                    let Ok(settings) = std::fs::read_to_string(entry.path()) else {
+                        continue;
+                    };
+                    let build_dir = entry.path().parent().unwrap_or(repo_root);
+                    let build = relative(build_dir);
+                    for (name, dir) in gradle_projects(&settings) {
+                        let dir = join_path(&build, &dir);
+                        let abs = repo_root.join(&dir);
+                        let script = read(&abs, &["build.gradle.kts", "build.gradle"]);
+                        let script = script.unwrap_or_default();
+                        gradle_dirs.insert(dir.clone());
+                        modules.push(JvmModule {
+                            name,
+                            source_roots: gradle_source_roots(&dir, &abs, &script),
+                            dependencies: gradle_dependencies(&script),
+                            dir,
+                        });
+                    }
+                }
+                "pom.xml" => poms.push(entry.path().to_path_buf()),
+                _ => {}
+            }
+        }
+        for pom_path in poms {
+            let dir = relative(pom_path.parent().unwrap_or(repo_root));
+            if gradle_dirs.contains(&dir) {
+                continue;
+            }
+            let Ok(pom) = std::fs::read_to_string(&pom_path) else {
+                continue;
+            };
+            if let Some(module) = maven_module(&dir, &pom) {
+                modules.push(module);
+            }
+        }
+        Self::from_modules(modules)
+    }
+
+    /// Order modules innermost first and drop dependencies on unknown modules
+    pub fn from_modules(mut modules: Vec<JvmModule>) -> Self {
+        let known: HashSet<String> = modules.iter().map(|m| m.name.clone()).collect();
+        for module in &mut modules {
+            module
+                .dependencies
+                .retain(|dep| known.contains(dep) && *dep != module.name);
+            module
+                .source_roots
+                .sort_by_key(|root| std::cmp::Reverse(root.len()));
+        }
+        modules
+            .sort_by_key(|m| std::cmp::Reverse(m.dir.split('/').filter(|p| !p.is_empty()).count()));
+        Self { modules }
+    }
+
+    /// No Gradle or Maven build found
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    /// Module owning `file` (a source root under its directory)
+    pub fn module_of(&self, file: &str) -> Option<&JvmModule> {
+        let file = normalize_path(file);
+        self.modules
+            .iter()
+            .find(|m| m.source_roots.iter().any(|root| is_under(&file, root)))
+    }
+
+    /// Package path of a Java/Kotlin file below its source root
+    pub fn module_path(&self, file: &str) -> Option<String> {
+        let file = normalize_path(file);
+        let module = self.module_of(&file)?;
+        let root = module
+            .source_roots
+            .iter()
+            .find(|root| is_under(&file, root))?;
+        let relative = if root.is_empty() {
+            file.as_str()
+        } else {
+            &file[root.len() + 1..]
+        };
+        let stem = relative
+            .rsplit_once('.')
+            .map(|(stem, _)| stem)
+            .unwrap_or(relative);
+        Some(stem.replace('/', "."))
+    }
+
+    /// Whether code in `from` may reference code in `to`: same module or a
+    /// transitive dependency (files outside modules see everything)
+    pub fn is_visible(&self, from: &str, to: &str) -> bool {
+        let (Some(from), Some(to)) = (self.module_of(from), self.module_of(to)) else {
+            return true;
+        };
+        if from.name == to.name {
+            return true;
+        }
+        let by_name: HashMap<&str, &JvmModule> =
+            self.modules.iter().map(|m| (m.name.as_str(), m)).collect();
+        let mut seen = HashSet::new();
+        let mut stack = vec![from];
+        while let Some(module) = stack.pop() {
+            for dep in &module.dependencies {
+                if dep == &to.name {
+                    return true;
+                }
+                if seen.insert(dep.as_str()) {
+                    if let Some(&next) = by_name.get(dep.as_str()) {
+                        stack.push(next);
+                    }
+                }
+            }
+        }
+        false
+    }
+}
+
+/// Java or Kotlin source file
+pub fn is_jvm_source_file(path: &str) -> bool {
+    path.ends_with(".java") || path.ends_with(".kt")
+}
+
+fn is_under(file: &str, dir: &str) -> bool {
+    dir.is_empty()
+        || file
+            .strip_prefix(dir)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Directories never holding first-party modules
+fn is_skipped_dir(name: &str) -> bool {
+    name.starts_with('.') || matches!(name, "node_modules" | "build" | "target" | "out")
+}
+
+/// (Gradle path, directory relative to the settings file) of each project,
+/// the root project included
+fn gradle_projects(settings: &str) -> Vec<(String, String)> {
+    let settings = strip_line_comments(settings);
+    let mut overrides = HashMap::new();
+    // project(":app").projectDir = file("apps/app")
+    for (i, _) in settings.match_indices("project(") {
+        let rest = &settings[i + "project(".len()..];
+        let Some(path) = first_quoted(rest) else {
+            continue;
+        };
+        if let Some(assign) = rest.find(".projectDir") {
+            let tail = &rest[assign..];
+            let line_end = tail.find('\n').unwrap_or(tail.len());
+            if let Some(dir) = tail[..line_end]
+                .find("file(")
+                .and_then(|f| first_quoted(&tail[f..line_end]))
+            {
+                overrides.insert(path, dir);
+            }
+        }
+    }
+
+    let mut projects = vec![(":".to_string(), String::new())];
+    for line in settings.lines() {
+        let line = line.trim();
+        let Some(args) = line.strip_prefix("include") else {
+            continue;
+        };
+        if !args.starts_with(['(', ' ', '\t']) {
+            continue;
+        }
+        for path in quoted_strings(args) {
+            let name = if path.starts_with(':') {
+                path.clone()
+            } else {
+                format!(":{}", path)
+            };
+            let dir = overrides
+                .get(&name)
+                .or_else(|| overrides.get(&path))
+                .cloned()
+                .unwrap_or_else(|| name.trim_start_matches(':').replace(':', "/"));
+            projects.push((name, dir));
+        }
+    }
+    projects
+}
+
+/// Source roots of a Gradle module: `srcDir(s)` declarations, or the
+/// `src/<set>/<java|kotlin>` directories that exist
+fn gradle_source_roots(dir: &str, abs: &Path, script: &str) -> Vec<String> {
+    let script = strip_line_comments(script);
+    let mut roots: Vec<String> = Vec::new();
+    for (i, _) in script.match_indices("srcDir") {
+        let rest = &script[i..];
+        let end = rest.find([')', ']', '\n']).unwrap_or(rest.len());
+        roots.extend(
+            quoted_strings(&rest[..end])
+                .iter()
+                .map(|d| join_path(dir, d)),
+        );
+    }
+    if roots.is_empty() {
+        roots = existing_source_sets(dir, abs);
+    }
+    roots
+}
+
+/// `src/<set>/<java|kotlin>` directories of a module, falling back to
+/// `src/main/java` and `src/main/kotlin` when nothing exists yet
+fn existing_source_sets(dir: &str, abs: &Path) -> Vec<String> {
+    let mut roots = Vec::new();
+    if let Ok(sets) = std::fs::read_dir(abs.join("src")) {
+        for set in sets.filter_map(|e| e.ok()) {
+            let set_name = set.file_name().to_string_lossy().to_string();
+            for language in SOURCE_LANGUAGES {
+                if set.path().join(language).is_dir() {
+                    roots.push(join_path(dir, &format!("src/{}/{}", set_name, language)));
+                }
+            }
+        }
+    }
+    if roots.is_empty() {
+        for set in DEFAULT_SOURCE_SETS {
+            for language in SOURCE_LANGUAGES {
+                roots.push(join_path(dir, &format!("src/{}/{}", set, language)));
+            }
+        }
+    }
+    roots.sort();
+    roots
+}
+
+/// Gradle paths of `project(":x")` and `projects.x` dependencies
+fn gradle_dependencies(script: &str) -> Vec<String> {
+    let script = strip_line_comments(script);
+    let mut deps = Vec::new();
+    for (i, _) in script.match_indices("project(") {
+        let rest = &script[i + "project(".len()..];
+        // project(path: ":x") / project(":x")
+        let end = rest.find(')').unwrap_or(rest.len());
+        if let Some(path) = first_quoted(&rest[..end]) {
+            deps.push(path);
+        }
+    }
+    // Type-safe accessors: projects.lib.core → :lib:core
+    for (i, _) in script.match_indices("projects.") {
+        let rest = &script[i + "projects.".len()..];
+        let end = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '.' || c == '_'))
+            .unwrap_or(rest.len());
+        let path = rest[..end].trim_end_matches('.');
+        if !path.is_empty() {
+            deps.push(format!(":{}", path.replace('.', ":")));
+        }
+    }
+    deps.sort();
+    deps.dedup();
+    deps
+}
+
+/// Maven module of the `pom.xml` in `dir`
+fn maven_module(dir: &str, pom: &str) -> Option<JvmModule> {
+    let pom = strip_sections(pom, &["<!--"]);
+    let own = strip_sections(
+        &pom,
+        &[
+            "parent",
+            "dependencies",
+            "dependencyManagement",
+            "build",
+            "profiles",
+            "plugins",
+        ],
+    );
+    let name = xml_text(&own, "artifactId").into_iter().next()?;
+
+    let mut source_roots: Vec<String> = ["sourceDirectory", "testSourceDirectory"]
+        .iter()
+        .filter_map(|tag| xml_text(&pom, tag).into_iter().next())
+        .map(|d| join_path(dir, d.trim_start_matches("${project.basedir}/")))
+        .collect();
+    if source_roots.is_empty() {
+        for set in DEFAULT_SOURCE_SETS {
+            for language in SOURCE_LANGUAGES {
+                source_roots.push(join_path(dir, &format!("src/{}/{}", set, language)));
+            }
+        }
+    }
+
+    let dependencies = xml_text(&pom, "dependency")
+        .iter()
+        .filter_map(|dep| xml_text(dep, "artifactId").into_iter().next())
+        .collect();
+    Some(JvmModule {
+        name,
+        dir: dir.to_string(),
+        source_roots,
+        dependencies,
+    })
+}
+
+/// Trimmed inner text of every non-nested `<tag>...</tag>`
+fn xml_text(xml: &str, tag: &str) -> Vec<String> {
+    let open = format!("<{}>", tag);
+    let close = format!("</{}>", tag);
+    let mut found = Vec::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find(&open) {
+        let body = &rest[start + open.len()..];
+        let Some(end) = body.find(&close) else {
+            break;
+        };
+        found.push(body[..end].trim().to_string());
+        rest = &body[end + close.len()..];
+    }
+    found
+}
+
+/// `xml` without `<tag>...</tag>` sections (`<!--` strips comments)
+fn strip_sections(xml: &str, tags: &[&str]) -> String {
+    let mut text = xml.to_string();
+    for tag in tags {
+        let (open, close) = if *tag == "<!--" {
+            ("<!--".to_string(), "-->".to_string())
+        } else {
+            (format!("<{}>", tag), format!("</{}>", tag))
+        };
+        while let Some(start) = text.find(&open) {
+            let end = text[start..]
+                .find(&close)
+                .map(|e| start + e + close.len())
+                .unwrap_or(text.len());
+            text.replace_range(start..end, "");
+        }
+    }
+    text
+}
+
+/// Groovy/Kotlin script without `//` comments
+fn strip_line_comments(script: &str) -> String {
+    script
+        .lines()
+        .map(|line| match line.find("//") {
+            // `//` inside a string (`"https://..."`) is kept
+            Some(i) if line[..i].matches(['"', '\'']).count() % 2 == 0 => &line[..i],
+            _ => line,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Every `"..."` / `'...'` string in `text`
+fn quoted_strings(text: &str) -> Vec<String> {
+    let mut strings = Vec::new();
+    let mut chars = text.char_indices();
+    while let Some((start, c)) = chars.next() {
+        if c != '"' && c != '\'' {
+            continue;
+        }
+        let Some((end, _)) = chars.by_ref().find(|&(_, q)| q == c) else {
+            break;
+        };
+        strings.push(text[start + 1..end].to_string());
+    }
+    strings
+}
+
+fn first_quoted(text: &str) -> Option<String> {
+    quoted_strings(text).into_iter().next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gradle_and_maven_modules() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let write = |path: &str, content: &str| {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        };
+        write(
+            "settings.gradle.kts",
+            "rootProject.name = \"shop\"\ninclude(\":app\", \":lib:core\")\ninclude(\":lib:util\")\n",
+        );
+        write(
+            "app/build.gradle.kts",
+            "dependencies {\n    implementation(project(\":lib:core\"))\n}\n",
+        );
+        write(
+            "lib/core/build.gradle",
+            "dependencies {\n    api projects.lib.util // shared helpers\n}\n",
+        );
+        write("lib/util/build.gradle", "");
+        write("app/src/main/kotlin/com/acme/App.kt", "");
+        write("lib/core/src/main/java/com/acme/core/Repo.java", "");
+        write("lib/util/src/main/java/com/acme/util/Strings.java", "");
+        write(
+            "legacy/pom.xml",
+            "<project><parent><artifactId>root</artifactId></parent>\
+             <artifactId>legacy</artifactId></project>",
+        );
+        write("legacy/src/main/java/com/acme/old/Old.java", "");
+
+        let project = JvmProject::discover(root);
+        assert_eq!(
+            project
+                .module_path("app/src/main/kotlin/com/acme/App.kt")
+                .as_deref(),
+            Some("com.acme.App")
+        );
+        assert_eq!(
+            project
+                .module_of("legacy/src/main/java/com/acme/old/Old.java")
+                .map(|m| m.name.as_str()),
+            Some("legacy")
+        );
+
+        let app = "app/src/main/kotlin/com/acme/App.kt";
+        let core = "lib/core/src/main/java/com/acme/core/Repo.java";
+        let util = "lib/util/src/main/java/com/acme/util/Strings.java";
+        assert!(project.is_visible(app, core));
+        assert!(project.is_visible(app, util));
+        assert!(!project.is_visible(core, app));
+        assert!(!project.is_visible(util, core));
+    }
+}
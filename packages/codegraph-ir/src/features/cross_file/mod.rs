@@ -7,28 +7,61 @@
 //! - Lock-free symbol index with DashMap
 //! - Parallel import resolution with Rayon
 //! - Dependency graph with petgraph (Tarjan SCC for cycle detection)
+//! - SCC condensation of file / symbol graphs (cyclic cores)
+//! - TypeScript `paths`/`baseUrl` aliases and `export ... from` chains
+//! - Python package roots from pyproject.toml / setup.cfg (src layouts)
+//! - Gradle/Maven modules: JVM imports respect module dependencies
+//! - go.mod module paths, local replace directives and vendor/
 //! - Incremental update support
+//! - Owning teams of impacted files (CODEOWNERS, git last touch)
 //!
 //! Performance target: 62s → 5s (12x improvement)
 
+mod condensation;
+mod context_call_graph;
 mod dep_graph;
+mod edge_resolution;
+mod go_module;
+mod graph_export;
 mod impact;
 mod import_resolver;
+mod jvm_project;
+mod ownership;
+mod project_layout;
+mod python_project;
 mod scope;
 mod scope_index;
 mod symbol_graph;
 mod symbol_index;
+mod tsconfig;
 mod types;
 
+#[cfg(feature = "parquet")]
+pub use condensation::CondensationParquet;
+pub use condensation::{Condensation, CondensedEdge, DependencyScc, SccComponent};
+pub use context_call_graph::{
+    CallGraphAccuracy, CallSite, CallSiteId, CallTarget, ContextCallGraph, ContextCallGraphStats,
+};
 pub use dep_graph::{DependencyGraph, PageRankConfig};
+pub use edge_resolution::{apply_resolved_edges, resolve_cross_file_edges, ResolvedEdge};
+pub use go_module::GoModule;
+pub use graph_export::GraphExportOptions;
 pub use impact::{BatchImpactAnalysis, ImpactAnalysis, ImpactSummary, RiskLevel};
 pub use import_resolver::ImportResolver;
+pub use jvm_project::{JvmModule, JvmProject};
+pub use ownership::{
+    CodeOwners, FileOwnership, LastTouch, OwnershipIndex, CODEOWNERS_LOCATIONS,
+};
+pub use project_layout::ProjectLayout;
+pub use python_project::{PackageRoot, PythonProject};
 pub use scope::{Scope, ScopeKind};
 pub use scope_index::{ScopeAwareIndex, ScopeStats};
 pub use symbol_graph::{
-    CallGraph, SymbolDependencyGraph, SymbolEdgeKind, SymbolGraphStats, SymbolNode,
+    CallGraph, PathStep, SymbolDependencyGraph, SymbolEdgeKind, SymbolGraphStats, SymbolNode,
+    SymbolPath,
 };
 pub use symbol_index::SymbolIndex;
+pub use tsconfig::TsConfig;
 pub use types::*;
 
 #[cfg(feature = "python")]
@@ -96,6 +129,26 @@ pub struct GlobalContextResult {
 
     // SOTA Priority 3: Symbol-level dependency graph
     pub symbol_graph_stats: Option<SymbolGraphStats>,
+
+    /// Re-exported FQN → canonical symbol (alias edges)
+    ///
+    /// Imports of an alias resolve to the canonical symbol, so references
+    /// are counted once per symbol rather than per re-export.
+    #[serde(default)]
+    pub symbol_aliases: HashMap<String, SymbolAlias>,
+
+    /// Edges resolved to symbols of other files (`EdgeStage::CrossFile`)
+    #[serde(default)]
+    pub resolved_edges: Vec<ResolvedEdge>,
+}
+
+impl GlobalContextResult {
+    /// FQN of the symbol `fqn` names (itself unless it is a re-export)
+    pub fn canonical_fqn<'a>(&'a self, fqn: &'a str) -> &'a str {
+        self.symbol_aliases
+            .get(fqn)
+            .map_or(fqn, |alias| alias.canonical_fqn.as_str())
+    }
 }
 
 /// Build global context from IR documents (main entry point)
@@ -105,6 +158,15 @@ pub struct GlobalContextResult {
 ///
 /// SOTA: Now includes scope-aware resolution and symbol-level dependency graph
 pub fn build_global_context(ir_docs: Vec<IRDocument>) -> GlobalContextResult {
+    build_global_context_with_layout(ir_docs, &ProjectLayout::default())
+}
+
+/// Build global context, resolving imports with the repo's build `layout`
+/// (TS/JS path aliases, JVM module boundaries, Go module paths)
+pub fn build_global_context_with_layout(
+    ir_docs: Vec<IRDocument>,
+    layout: &ProjectLayout,
+) -> GlobalContextResult {
     let start = Instant::now();
 
     // Phase 1: Build symbol index (parallel)
@@ -114,14 +176,18 @@ pub fn build_global_context(ir_docs: Vec<IRDocument>) -> GlobalContextResult {
     let scope_index = ScopeAwareIndex::build_from_irs(Arc::clone(&symbol_index), &ir_docs);
 
     // Phase 2: Resolve imports with scope awareness (parallel)
-    let import_resolver = ImportResolver::new_with_scope(&symbol_index, &scope_index);
+    let import_resolver = ImportResolver::new_with_scope(&symbol_index, &scope_index)
+        .with_ts_configs(&layout.ts_configs)
+        .with_jvm_project(&layout.jvm)
+        .with_go_modules(&layout.go_modules);
     let resolved_imports = import_resolver.resolve_all(&ir_docs);
+    let resolved_edges = resolve_cross_file_edges(&ir_docs, &symbol_index, &resolved_imports);
 
     // Phase 3: Build file-level dependency graph
     let dep_graph = DependencyGraph::build(&resolved_imports);
 
     // Phase 4: Build symbol-level dependency graph - SOTA Priority 3
-    let symbol_graph = SymbolDependencyGraph::build_from_irs(&ir_docs);
+    let symbol_graph = SymbolDependencyGraph::build_with_resolved_edges(&ir_docs, &resolved_edges);
     let symbol_graph_stats = Some(symbol_graph.stats());
 
     let duration = start.elapsed();
@@ -138,6 +204,8 @@ pub fn build_global_context(ir_docs: Vec<IRDocument>) -> GlobalContextResult {
         topological_order: dep_graph.topological_order(),
         build_duration_ms: duration.as_millis() as u64,
         symbol_graph_stats,
+        symbol_aliases: symbol_index.reexports_to_hashmap(),
+        resolved_edges,
     }
 }
 
@@ -170,12 +238,14 @@ pub fn update_global_context(
     // Resolve imports for all with scope awareness (symbol table needs to be consistent)
     let import_resolver = ImportResolver::new_with_scope(&symbol_index, &scope_index);
     let resolved_imports = import_resolver.resolve_all(&all_ir_docs);
+    let resolved_edges = resolve_cross_file_edges(&all_ir_docs, &symbol_index, &resolved_imports);
 
     // Build file-level dependency graph
     let dep_graph = DependencyGraph::build(&resolved_imports);
 
     // Build symbol-level dependency graph - SOTA Priority 3
-    let symbol_graph = SymbolDependencyGraph::build_from_irs(&all_ir_docs);
+    let symbol_graph =
+        SymbolDependencyGraph::build_with_resolved_edges(&all_ir_docs, &resolved_edges);
     let symbol_graph_stats = Some(symbol_graph.stats());
 
     let duration = start.elapsed();
@@ -191,6 +261,105 @@ pub fn update_global_context(
         topological_order: dep_graph.topological_order(),
         build_duration_ms: duration.as_millis() as u64,
         symbol_graph_stats,
+        symbol_aliases: symbol_index.reexports_to_hashmap(),
+        resolved_edges,
+    };
+
+    (result, affected_files)
+}
+
+/// Incremental update that re-resolves only affected files
+///
+/// Imports are resolved again only for `changed` files and their transitive
+/// dependents in `previous`; every other file keeps its previous
+/// dependencies. `changed` may name deleted files (absent from `ir_docs`),
+/// whose dependents are then re-resolved. `total_imports` counts the imports
+/// resolved by this update.
+pub fn update_global_context_affected(
+    previous: &GlobalContextResult,
+    changed: &[String],
+    ir_docs: &[IRDocument],
+    layout: &ProjectLayout,
+) -> (GlobalContextResult, Vec<String>) {
+    use std::collections::HashSet;
+
+    let start = Instant::now();
+
+    // Symbols of all files (resolution targets)
+    let symbol_index = Arc::new(SymbolIndex::build_from_irs(ir_docs));
+    let scope_index = ScopeAwareIndex::build_from_irs(Arc::clone(&symbol_index), ir_docs);
+
+    let affected_files = compute_affected_files(changed, &previous.file_dependents);
+    let affected: HashSet<&str> = affected_files.iter().map(String::as_str).collect();
+    let present: HashSet<&str> = ir_docs.iter().map(|ir| ir.file_path.as_str()).collect();
+
+    let affected_docs: Vec<IRDocument> = ir_docs
+        .iter()
+        .filter(|ir| affected.contains(ir.file_path.as_str()))
+        .cloned()
+        .collect();
+    let import_resolver = ImportResolver::new_with_scope(&symbol_index, &scope_index)
+        .with_ts_configs(&layout.ts_configs)
+        .with_jvm_project(&layout.jvm)
+        .with_go_modules(&layout.go_modules);
+    let resolved_imports = import_resolver.resolve_all(&affected_docs);
+
+    // Unaffected files keep their dependencies (minus deleted files)
+    let mut dependencies: HashMap<String, Vec<String>> = previous
+        .file_dependencies
+        .iter()
+        .filter(|(file, _)| {
+            present.contains(file.as_str()) && !affected.contains(file.as_str())
+        })
+        .map(|(file, deps)| {
+            let deps = deps
+                .iter()
+                .filter(|dep| present.contains(dep.as_str()))
+                .cloned()
+                .collect();
+            (file.clone(), deps)
+        })
+        .collect();
+    let resolved_graph = DependencyGraph::build(&resolved_imports);
+    for ir in &affected_docs {
+        dependencies.insert(
+            ir.file_path.clone(),
+            resolved_graph.get_dependencies(&ir.file_path),
+        );
+    }
+    let dep_graph = DependencyGraph::from_dependencies(&dependencies);
+
+    // Unaffected files keep their resolved edges too
+    let mut resolved_edges: Vec<ResolvedEdge> = previous
+        .resolved_edges
+        .iter()
+        .filter(|r| {
+            present.contains(r.file_path.as_str()) && !affected.contains(r.file_path.as_str())
+        })
+        .cloned()
+        .collect();
+    resolved_edges.extend(resolve_cross_file_edges(
+        &affected_docs,
+        &symbol_index,
+        &resolved_imports,
+    ));
+
+    let symbol_graph = SymbolDependencyGraph::build_with_resolved_edges(ir_docs, &resolved_edges);
+    let symbol_graph_stats = Some(symbol_graph.stats());
+
+    let result = GlobalContextResult {
+        total_symbols: symbol_index.len(),
+        total_files: ir_docs.len(),
+        total_imports: resolved_imports.values().map(|v| v.len()).sum(),
+        total_dependencies: dep_graph.edge_count(),
+        symbol_table: symbol_index.to_hashmap(),
+        file_dependencies: dep_graph.get_all_dependencies(),
+        file_dependents: dep_graph.get_all_dependents(),
+        topological_order: dep_graph.topological_order(),
+        build_duration_ms: start.elapsed().as_millis() as u64,
+        symbol_graph_stats,
+        symbol_aliases: symbol_index.reexports_to_hashmap(),
+        resolved_edges,
     };
 
     (result, affected_files)
@@ -300,6 +469,59 @@ mod tests {
         assert!(result.total_symbols >= 2);
     }
 
+    #[test]
+    fn test_reexported_symbol_resolves_to_canonical() {
+        let import = |id: &str, fqn: &str, file: &str, name: &str| {
+            Node::new(
+                id.to_string(),
+                NodeKind::Import,
+                fqn.to_string(),
+                file.to_string(),
+                Span::new(1, 0, 1, 30),
+            )
+            .with_name(name.to_string())
+        };
+        let auth = IRDocument::new(
+            "core/auth.py".to_string(),
+            vec![make_test_node("login", "core.auth.login", "core/auth.py")],
+            vec![],
+        );
+        let core_init = IRDocument::new(
+            "core/__init__.py".to_string(),
+            vec![import("reexport", "core.auth.login", "core/__init__.py", "login")],
+            vec![],
+        );
+        // from core import login / from core.auth import login
+        let api = IRDocument::new(
+            "api.py".to_string(),
+            vec![
+                make_test_node("handler", "api.handler", "api.py"),
+                import("via_package", "core.login", "api.py", "login"),
+            ],
+            vec![make_import_edge("handler", "via_package")],
+        );
+        let cli = IRDocument::new(
+            "cli.py".to_string(),
+            vec![
+                make_test_node("run", "cli.run", "cli.py"),
+                import("direct", "core.auth.login", "cli.py", "login"),
+            ],
+            vec![make_import_edge("run", "direct")],
+        );
+
+        let result = build_global_context(vec![auth, core_init, api, cli]);
+        assert_eq!(result.symbol_aliases.len(), 1);
+        assert_eq!(result.canonical_fqn("core.login"), "core.auth.login");
+        assert!(!result.symbol_table.contains_key("core.login"));
+
+        // Both importers depend on the defining file; the alias import also
+        // on the re-exporting package
+        let mut api_deps = result.file_dependencies["api.py"].clone();
+        api_deps.sort();
+        assert_eq!(api_deps, vec!["core/__init__.py", "core/auth.py"]);
+        assert_eq!(result.file_dependencies["cli.py"], vec!["core/auth.py"]);
+    }
+
     /// SOTA Test: Function-scoped imports
     ///
     /// Tests the following Python code:
@@ -710,4 +932,46 @@ mod tests {
         assert_eq!(impact_b.direct_dependents.len(), 1); // Only 'a' directly calls 'b'
         assert!(impact_b.direct_dependents.contains(&"test.a".to_string()));
     }
+
+    #[test]
+    fn test_update_global_context_affected() {
+        // c imports b, b imports a; d is standalone
+        let doc = |file: &str, fqn: &str, import: Option<&str>| IRDocument {
+            file_path: format!("src/{}.py", file),
+            nodes: vec![make_test_node(fqn, fqn, &format!("src/{}.py", file))],
+            edges: import
+                .map(|target| make_import_edge(fqn, target))
+                .into_iter()
+                .collect(),
+            repo_id: None,
+        };
+        let previous = build_global_context(vec![
+            doc("a", "a.f", None),
+            doc("b", "b.g", Some("a.f")),
+            doc("c", "c.h", Some("b.g")),
+            doc("d", "d.k", None),
+        ]);
+        assert_eq!(previous.file_dependencies["src/b.py"], vec!["src/a.py"]);
+
+        // b switches its import from a to d
+        let docs = vec![
+            doc("a", "a.f", None),
+            doc("b", "b.g", Some("d.k")),
+            doc("c", "c.h", Some("b.g")),
+            doc("d", "d.k", None),
+        ];
+        let (updated, mut affected) = update_global_context_affected(
+            &previous,
+            &["src/b.py".to_string()],
+            &docs,
+            &ProjectLayout::default(),
+        );
+
+        affected.sort();
+        assert_eq!(affected, vec!["src/b.py", "src/c.py"]);
+        assert_eq!(updated.file_dependencies["src/b.py"], vec!["src/d.py"]);
+        assert_eq!(updated.file_dependencies["src/c.py"], vec!["src/b.py"]);
+        assert!(updated.file_dependents["src/d.py"].contains(&"src/b.py".to_string()));
+        assert!(updated.file_dependents["src/a.py"].is_empty());
+    }
 }
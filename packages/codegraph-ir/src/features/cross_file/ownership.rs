@@ -0,0 +1,383 @@
+//! Code ownership for impact analysis
+//!
+//! `CodeOwners` parses GitHub / GitLab CODEOWNERS files: gitignore-style
+//! patterns, the last matching rule wins (per section for GitLab
+//! `[Section]` files, whose owners are combined). `OwnershipIndex` adds
+//! optional last-touch data (latest commit per file) so files no rule
+//! covers still name someone to ask.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::features::git_history::GitExecutor;
+
+/// Where CODEOWNERS files are looked up, in order
+pub const CODEOWNERS_LOCATIONS: &[&str] = &[
+    ".github/CODEOWNERS",
+    "CODEOWNERS",
+    "docs/CODEOWNERS",
+    ".gitlab/CODEOWNERS",
+];
+
+#[derive(Debug, Clone)]
+struct OwnerRule {
+    /// Pattern segments; unanchored patterns start with `**`
+    segments: Vec<String>,
+    /// Pattern ended with `/`: only matches inside the directory
+    dir_only: bool,
+    owners: Vec<String>,
+    section: usize,
+}
+
+impl OwnerRule {
+    fn parse(pattern: &str, owners: Vec<String>, section: usize) -> Self {
+        let dir_only = pattern.ends_with('/');
+        let trimmed = pattern.trim_end_matches('/');
+        let anchored = trimmed.starts_with('/') || trimmed.contains('/');
+        let mut segments: Vec<String> = trimmed
+            .trim_start_matches('/')
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+            .collect();
+        if !anchored || segments.is_empty() {
+            segments.insert(0, "**".to_string());
+        }
+        Self {
+            segments,
+            dir_only,
+            owners,
+            section,
+        }
+    }
+
+    fn matches(&self, path: &[&str]) -> bool {
+        // `dir/*` covers the directory's files, not its subdirectories
+        if self.segments.last().is_some_and(|s| s == "*") {
+            return glob_segments(&self.segments, path);
+        }
+        // Matching a directory covers everything inside it
+        let last = if self.dir_only {
+            path.len().saturating_sub(1)
+        } else {
+            path.len()
+        };
+        (1..=last).any(|len| glob_segments(&self.segments, &path[..len]))
+    }
+}
+
+fn glob_segments(pattern: &[String], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((first, rest)) if first == "**" => {
+            (0..=path.len()).any(|skip| glob_segments(rest, &path[skip..]))
+        }
+        Some((first, rest)) => match path.split_first() {
+            Some((segment, path_rest)) => {
+                glob_chars(first.as_bytes(), segment.as_bytes()) && glob_segments(rest, path_rest)
+            }
+            None => false,
+        },
+    }
+}
+
+fn glob_chars(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => (0..=text.len()).any(|skip| glob_chars(rest, &text[skip..])),
+        Some((b'?', rest)) => !text.is_empty() && glob_chars(rest, &text[1..]),
+        Some((c, rest)) => text.first() == Some(c) && glob_chars(rest, &text[1..]),
+    }
+}
+
+/// Parsed CODEOWNERS file
+#[derive(Debug, Clone, Default)]
+pub struct CodeOwners {
+    rules: Vec<OwnerRule>,
+}
+
+impl CodeOwners {
+    pub fn parse(content: &str) -> Self {
+        let mut rules = Vec::new();
+        let mut section = 0;
+        let mut section_owners: Vec<String> = Vec::new();
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            // GitLab section header: `[Name]`, `^[Optional]`, `[Name][2] @default`
+            if line.starts_with('[') || line.starts_with("^[") {
+                section += 1;
+                section_owners = line
+                    .rsplit(']')
+                    .next()
+                    .unwrap_or("")
+                    .split_whitespace()
+                    .filter(|t| is_owner(t))
+                    .map(str::to_string)
+                    .collect();
+                continue;
+            }
+
+            let line = line.split(" #").next().unwrap_or(line);
+            let mut tokens = line.split_whitespace();
+            let Some(pattern) = tokens.next() else {
+                continue;
+            };
+            let mut owners: Vec<String> =
+                tokens.filter(|t| is_owner(t)).map(str::to_string).collect();
+            if owners.is_empty() && section > 0 {
+                owners = section_owners.clone();
+            }
+            rules.push(OwnerRule::parse(pattern, owners, section));
+        }
+
+        Self { rules }
+    }
+
+    /// First CODEOWNERS file of `CODEOWNERS_LOCATIONS` under `repo_root`
+    pub fn discover(repo_root: impl AsRef<Path>) -> Option<Self> {
+        CODEOWNERS_LOCATIONS
+            .iter()
+            .find_map(|location| std::fs::read_to_string(repo_root.as_ref().join(location)).ok())
+            .map(|content| Self::parse(&content))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Owners of a repo-relative path (`/`-separated)
+    pub fn owners_of(&self, path: &str) -> Vec<String> {
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let mut by_section: Vec<(usize, &OwnerRule)> = Vec::new();
+        for rule in self.rules.iter().filter(|r| r.matches(&segments)) {
+            match by_section.iter_mut().find(|(s, _)| *s == rule.section) {
+                Some(entry) => entry.1 = rule,
+                None => by_section.push((rule.section, rule)),
+            }
+        }
+
+        let mut owners: Vec<String> = Vec::new();
+        for (_, rule) in by_section {
+            for owner in &rule.owners {
+                if !owners.contains(owner) {
+                    owners.push(owner.clone());
+                }
+            }
+        }
+        owners
+    }
+}
+
+/// `@user`, `@org/team` or an email address
+fn is_owner(token: &str) -> bool {
+    token.starts_with('@') || token.contains('@')
+}
+
+/// Latest commit touching a file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LastTouch {
+    pub commit: String,
+    pub author_name: String,
+    pub author_email: String,
+    pub date: DateTime<Utc>,
+}
+
+/// Owners and last touch of one file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileOwnership {
+    /// CODEOWNERS owners (teams / users)
+    pub owners: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_touch: Option<LastTouch>,
+}
+
+impl FileOwnership {
+    /// Who to notify: the owners, or the last author when there are none
+    pub fn contacts(&self) -> Vec<String> {
+        if !self.owners.is_empty() {
+            return self.owners.clone();
+        }
+        self.last_touch
+            .iter()
+            .map(|t| t.author_email.clone())
+            .collect()
+    }
+}
+
+/// Ownership lookup for the files of one repository
+#[derive(Debug, Clone, Default)]
+pub struct OwnershipIndex {
+    codeowners: CodeOwners,
+    repo_root: Option<PathBuf>,
+    last_touch: HashMap<String, LastTouch>,
+}
+
+impl OwnershipIndex {
+    pub fn new(codeowners: CodeOwners) -> Self {
+        Self {
+            codeowners,
+            ..Self::default()
+        }
+    }
+
+    /// CODEOWNERS of `repo_root` (empty if it has none); file paths under
+    /// the root are matched relative to it
+    pub fn discover(repo_root: impl AsRef<Path>) -> Self {
+        let repo_root = repo_root.as_ref();
+        Self::new(CodeOwners::discover(repo_root).unwrap_or_default()).with_repo_root(repo_root)
+    }
+
+    pub fn with_repo_root(mut self, repo_root: impl Into<PathBuf>) -> Self {
+        self.repo_root = Some(repo_root.into());
+        self
+    }
+
+    pub fn with_last_touch(mut self, file_path: &str, touch: LastTouch) -> Self {
+        let key = self.relative(file_path);
+        self.last_touch.insert(key, touch);
+        self
+    }
+
+    /// Last touch of `files` from `git log` (needs the repo root; files git
+    /// does not know are skipped)
+    pub fn with_git_last_touch<S: AsRef<str>>(mut self, files: &[S]) -> Self {
+        let Some(git) = self
+            .repo_root
+            .as_ref()
+            .and_then(|root| GitExecutor::new(root).ok())
+        else {
+            return self;
+        };
+        for file in files {
+            let key = self.relative(file.as_ref());
+            if self.last_touch.contains_key(&key) {
+                continue;
+            }
+            let Ok(out) = git.run_command(&[
+                "log",
+                "-1",
+                "--format=%H%x1f%an%x1f%ae%x1f%aI",
+                "--",
+                key.as_str(),
+            ]) else {
+                continue;
+            };
+            if let Some(touch) = parse_last_touch(&out) {
+                self.last_touch.insert(key, touch);
+            }
+        }
+        self
+    }
+
+    /// Ownership of a file (absolute, or relative to the repo root)
+    pub fn lookup(&self, file_path: &str) -> FileOwnership {
+        let key = self.relative(file_path);
+        FileOwnership {
+            owners: self.codeowners.owners_of(&key),
+            last_touch: self.last_touch.get(&key).cloned(),
+        }
+    }
+
+    fn relative(&self, file_path: &str) -> String {
+        let path = Path::new(file_path);
+        let path = self
+            .repo_root
+            .as_ref()
+            .and_then(|root| path.strip_prefix(root).ok())
+            .unwrap_or(path);
+        path.to_string_lossy()
+            .replace('\\', "/")
+            .trim_start_matches("./")
+            .trim_start_matches('/')
+            .to_string()
+    }
+}
+
+fn parse_last_touch(log: &str) -> Option<LastTouch> {
+    let mut fields = log.trim().split('\u{1f}');
+    let commit = fields.next().filter(|c| !c.is_empty())?.to_string();
+    let author_name = fields.next()?.to_string();
+    let author_email = fields.next()?.to_string();
+    let date = DateTime::parse_from_rfc3339(fields.next()?).ok()?;
+    Some(LastTouch {
+        commit,
+        author_name,
+        author_email,
+        date: date.with_timezone(&Utc),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codeowners_last_match_wins() {
+        let codeowners = CodeOwners::parse(
+            "# global owners\n\
+             *           @acme/platform\n\
+             *.py        @acme/python\n\
+             /docs/      docs@acme.dev\n\
+             src/api/*   @acme/api  # not subdirectories\n\
+             **/generated/\n\
+             /build/logs @alice\n",
+        );
+
+        assert_eq!(codeowners.owners_of("README.md"), vec!["@acme/platform"]);
+        assert_eq!(codeowners.owners_of("tools/run.py"), vec!["@acme/python"]);
+        assert_eq!(
+            codeowners.owners_of("docs/guide/intro.md"),
+            vec!["docs@acme.dev"]
+        );
+        assert_eq!(codeowners.owners_of("src/api/routes.py"), vec!["@acme/api"]);
+        assert_eq!(
+            codeowners.owners_of("src/api/v2/routes.py"),
+            vec!["@acme/python"]
+        );
+        assert!(codeowners.owners_of("src/generated/schema.py").is_empty());
+        assert_eq!(codeowners.owners_of("build/logs/today.txt"), vec!["@alice"]);
+        assert_eq!(
+            codeowners.owners_of("app/build/logs"),
+            vec!["@acme/platform"]
+        );
+    }
+
+    #[test]
+    fn test_gitlab_sections_combine_owners() {
+        let codeowners = CodeOwners::parse(
+            "[Backend] @acme/backend\n\
+             app/\n\
+             app/billing/ @carol\n\
+             ^[Security][2] @acme/security\n\
+             app/auth/\n",
+        );
+        let index = OwnershipIndex::new(codeowners)
+            .with_repo_root("/repo")
+            .with_last_touch(
+                "/repo/app/auth/login.py",
+                parse_last_touch(
+                    "abc123\u{1f}Dana\u{1f}dana@acme.dev\u{1f}2026-01-02T03:04:05+00:00\n",
+                )
+                .unwrap(),
+            );
+
+        let auth = index.lookup("/repo/app/auth/login.py");
+        assert_eq!(auth.owners, vec!["@acme/backend", "@acme/security"]);
+        assert_eq!(auth.last_touch.as_ref().unwrap().author_name, "Dana");
+        assert_eq!(index.lookup("app/billing/pay.py").owners, vec!["@carol"]);
+
+        let unowned = index.lookup("/repo/scripts/x.sh");
+        assert!(unowned.contacts().is_empty());
+        let touched = FileOwnership {
+            last_touch: auth.last_touch.clone(),
+            ..FileOwnership::default()
+        };
+        assert_eq!(touched.contacts(), vec!["dana@acme.dev"]);
+    }
+}
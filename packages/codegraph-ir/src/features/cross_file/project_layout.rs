@@ -0,0 +1,44 @@
+//! Build-system layout of a repository
+//!
+//! What the cross-file stage learns from build files rather than sources:
+//! TS/JS path aliases, Python package roots, JVM modules and Go modules.
+
+use std::path::Path;
+
+use super::go_module::GoModule;
+use super::jvm_project::{is_jvm_source_file, JvmProject};
+use super::python_project::PythonProject;
+use super::tsconfig::TsConfig;
+
+/// tsconfigs, Python projects, Gradle/Maven modules and go.mod files of
+/// one repository
+#[derive(Debug, Clone, Default)]
+pub struct ProjectLayout {
+    pub ts_configs: Vec<TsConfig>,
+    pub python: PythonProject,
+    pub jvm: JvmProject,
+    pub go_modules: Vec<GoModule>,
+}
+
+impl ProjectLayout {
+    /// Discover every build file under `repo_root`; paths are relative to it
+    pub fn discover(repo_root: &Path) -> Self {
+        Self {
+            ts_configs: TsConfig::discover(repo_root),
+            python: PythonProject::discover(repo_root),
+            jvm: JvmProject::discover(repo_root),
+            go_modules: GoModule::discover(repo_root),
+        }
+    }
+
+    /// Module path the layout assigns to a Python or Java/Kotlin file
+    pub fn module_path(&self, file_path: &str) -> Option<String> {
+        if file_path.ends_with(".py") || file_path.ends_with(".pyi") {
+            Some(self.python.module_path(file_path))
+        } else if is_jvm_source_file(file_path) {
+            self.jvm.module_path(file_path)
+        } else {
+            None
+        }
+    }
+}
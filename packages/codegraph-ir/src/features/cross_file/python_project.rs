@@ -0,0 +1,293 @@
+//! Python project model
+//!
+//! Package roots of the Python projects in a repository, read from
+//! `pyproject.toml` (setuptools, poetry, hatch, pdm) and `setup.cfg`, with a
+//! `src/` layout fallback. A file's module path is its path below the
+//! innermost root, so `src/acme/api.py` is `acme.api` and namespace packages
+//! (directories without `__init__.py`) need no special casing. Mapped
+//! package directories (`package-dir = {"acme" = "lib"}`, which editable
+//! installs honour) prefix their package name.
+
+use std::path::Path;
+
+use walkdir::WalkDir;
+
+use super::tsconfig::{join_path, normalize_path, parent_dir};
+
+/// Directory whose contents import as `package`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageRoot {
+    /// Directory relative to the repo root (`""` is the repo root)
+    pub dir: String,
+    /// Import prefix of the directory (`""` for a plain source root)
+    pub package: String,
+}
+
+/// Package roots of every Python project in a repository
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PythonProject {
+    /// Innermost first; the repo root is always last
+    pub roots: Vec<PackageRoot>,
+}
+
+impl PythonProject {
+    /// Projects (`pyproject.toml`, `setup.cfg` or `setup.py`) under `repo_root`
+    ///
+    /// Paths are relative to `repo_root`, like the pipeline's file paths.
+    pub fn discover(repo_root: &Path) -> Self {
+        let root = normalize_path(&repo_root.to_string_lossy());
+        let mut project = Self::default();
+        let entries = WalkDir::new(repo_root)
+            .into_iter()
+            .filter_entry(|e| e.depth() == 0 || !is_skipped_dir(&e.file_name().to_string_lossy()));
+        for entry in entries.filter_map(|e| e.ok()) {
+            if !entry.file_type().is_dir() {
+                continue;
+            }
+            let path = entry.path();
+            let pyproject = std::fs::read_to_string(path.join("pyproject.toml")).ok();
+            let setup_cfg = std::fs::read_to_string(path.join("setup.cfg")).ok();
+            if pyproject.is_none() && setup_cfg.is_none() && !path.join("setup.py").is_file() {
+                continue;
+            }
+            let dir = normalize_path(&path.to_string_lossy());
+            let dir = match dir.strip_prefix(root.as_str()) {
+                Some(rest) => rest.trim_start_matches('/').to_string(),
+                None => dir,
+            };
+            let src_layout = path.join("src").is_dir() && !path.join("src/__init__.py").exists();
+            project.add_project(&dir, pyproject.as_deref(), setup_cfg.as_deref(), src_layout);
+        }
+        project.finish()
+    }
+
+    /// Register the package roots of the project in `dir`
+    ///
+    /// `src_layout`: `dir/src` exists and is not itself a package.
+    pub fn add_project(
+        &mut self,
+        dir: &str,
+        pyproject: Option<&str>,
+        setup_cfg: Option<&str>,
+        src_layout: bool,
+    ) {
+        let mut roots = Vec::new();
+        if let Some(text) = pyproject {
+            match text.parse::<toml::Table>() {
+                Ok(table) => pyproject_roots(&table, &mut roots),
+                Err(e) => tracing::warn!("[python] Skipping {}/pyproject.toml: {}", dir, e),
+            }
+        }
+        if let Some(text) = setup_cfg {
+            setup_cfg_roots(text, &mut roots);
+        }
+        if roots.is_empty() && src_layout {
+            roots.push(("src".to_string(), String::new()));
+        }
+        // Tests and scripts beside the packages import from the project dir
+        roots.push((String::new(), String::new()));
+
+        for (relative, package) in roots {
+            self.roots.push(PackageRoot {
+                dir: join_path(dir, &relative),
+                package,
+            });
+        }
+    }
+
+    /// Deduplicate and order the roots innermost first
+    pub fn finish(mut self) -> Self {
+        self.roots.push(PackageRoot {
+            dir: String::new(),
+            package: String::new(),
+        });
+        let mut seen = std::collections::HashSet::new();
+        self.roots.retain(|root| seen.insert(root.dir.clone()));
+        self.roots.sort_by_key(|root| {
+            let depth = root.dir.split('/').filter(|p| !p.is_empty()).count();
+            std::cmp::Reverse(depth)
+        });
+        self
+    }
+
+    /// Module path of a Python file (`svc/src/acme/api.py` → `acme.api`)
+    pub fn module_path(&self, file_path: &str) -> String {
+        let file = normalize_path(file_path);
+        let stem = file
+            .strip_suffix(".pyi")
+            .or_else(|| file.strip_suffix(".py"))
+            .unwrap_or(&file);
+        let found = self.roots.iter().find_map(|root| {
+            let relative = if root.dir.is_empty() {
+                stem
+            } else {
+                stem.strip_prefix(root.dir.as_str())?.strip_prefix('/')?
+            };
+            Some((root, relative))
+        });
+
+        match found {
+            Some((root, relative)) if !root.package.is_empty() => {
+                if relative.is_empty() {
+                    root.package.clone()
+                } else {
+                    format!("{}.{}", root.package, relative.replace('/', "."))
+                }
+            }
+            Some((_, relative)) => relative.replace('/', "."),
+            None => stem.replace('/', "."),
+        }
+    }
+}
+
+/// Directories never holding first-party projects
+fn is_skipped_dir(name: &str) -> bool {
+    name.starts_with('.')
+        || matches!(
+            name,
+            "node_modules" | "__pycache__" | "site-packages" | "venv" | "build" | "dist"
+        )
+}
+
+/// (directory, package) roots declared in `pyproject.toml`
+fn pyproject_roots(table: &toml::Table, roots: &mut Vec<(String, String)>) {
+    let get = |path: &[&str]| lookup(table, path);
+
+    // setuptools: package-dir = {"" = "src", "acme" = "lib/acme"}
+    if let Some(dirs) = get(&["tool", "setuptools", "package-dir"]).and_then(|v| v.as_table()) {
+        for (package, dir) in dirs {
+            if let Some(dir) = dir.as_str() {
+                roots.push((dir.to_string(), package.clone()));
+            }
+        }
+    }
+    for dir in strings(get(&["tool", "setuptools", "packages", "find", "where"])) {
+        roots.push((dir, String::new()));
+    }
+
+    // poetry: packages = [{ include = "acme", from = "src" }]
+    if let Some(packages) = get(&["tool", "poetry", "packages"]).and_then(|v| v.as_array()) {
+        for from in packages.iter().filter_map(|p| p.get("from")?.as_str()) {
+            roots.push((from.to_string(), String::new()));
+        }
+    }
+
+    // hatch: packages = ["src/acme"] → the parent directory is the root
+    for package in strings(get(&[
+        "tool", "hatch", "build", "targets", "wheel", "packages",
+    ])) {
+        let dir = parent_dir(&normalize_path(&package));
+        if !dir.is_empty() {
+            roots.push((dir, String::new()));
+        }
+    }
+
+    // pdm: package-dir = "src"
+    for dir in strings(get(&["tool", "pdm", "build", "package-dir"])) {
+        roots.push((dir, String::new()));
+    }
+}
+
+/// Value at a dotted key path (`tool.setuptools.package-dir`)
+fn lookup<'a>(table: &'a toml::Table, path: &[&str]) -> Option<&'a toml::Value> {
+    let (first, rest) = path.split_first()?;
+    rest.iter()
+        .try_fold(table.get(*first)?, |value, key| value.get(*key))
+}
+
+/// A string or array of strings
+fn strings(value: Option<&toml::Value>) -> Vec<String> {
+    match value {
+        Some(toml::Value::String(s)) => vec![s.clone()],
+        Some(toml::Value::Array(items)) => items
+            .iter()
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// (directory, package) roots declared in `setup.cfg`
+fn setup_cfg_roots(text: &str, roots: &mut Vec<(String, String)>) {
+    let mut section = String::new();
+    let mut key = String::new();
+    let mut values: Vec<(String, String, String)> = Vec::new();
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with(';') {
+            continue;
+        }
+        if let Some(name) = trimmed.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            section = name.trim().to_string();
+            key.clear();
+            continue;
+        }
+        if line.starts_with([' ', '\t']) && !key.is_empty() {
+            // Continuation line of a multi-line value
+            values.push((section.clone(), key.clone(), trimmed.to_string()));
+        } else if let Some((k, v)) = trimmed.split_once('=') {
+            key = k.trim().to_string();
+            values.push((section.clone(), key.clone(), v.trim().to_string()));
+        }
+    }
+
+    for (section, key, value) in values {
+        if value.is_empty() {
+            continue;
+        }
+        match (section.as_str(), key.as_str()) {
+            // package_dir =
+            //     =src
+            //     acme = lib/acme
+            ("options", "package_dir") => {
+                if let Some((package, dir)) = value.split_once('=') {
+                    roots.push((dir.trim().to_string(), package.trim().to_string()));
+                }
+            }
+            ("options.packages.find", "where") => roots.push((value, String::new())),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_paths_from_project_configs() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let write = |path: &str, content: &str| {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        };
+        write(
+            "services/billing/pyproject.toml",
+            "[tool.setuptools.packages.find]\nwhere = [\"src\"]\n",
+        );
+        // Namespace package: no __init__.py under src/acme
+        write("services/billing/src/acme/billing/api.py", "");
+        write(
+            "libs/core/setup.cfg",
+            "[options]\npackage_dir =\n    core = lib\n",
+        );
+        write("libs/core/lib/__init__.py", "");
+        write("scripts/run.py", "");
+
+        let project = PythonProject::discover(root);
+        let module = |path: &str| project.module_path(path);
+        assert_eq!(
+            module("services/billing/src/acme/billing/api.py"),
+            "acme.billing.api"
+        );
+        assert_eq!(
+            module("services/billing/tests/test_api.py"),
+            "tests.test_api"
+        );
+        assert_eq!(module("libs/core/lib/__init__.py"), "core.__init__");
+        assert_eq!(module("libs/core/lib/db/pool.py"), "core.db.pool");
+        assert_eq!(module("scripts/run.py"), "scripts.run");
+    }
+}
@@ -16,17 +16,27 @@
 //! - Symbol → Symbol edges (Calls, Inherits, Reads, Writes)
 //! - Call graph with transitive closure
 //! - Impact analysis (what breaks if I change this?)
+//! - Call-path search (K shortest chains between two symbols)
 //! - Lock-free concurrent access with DashMap
 //! - Parallel construction with Rayon
 
 use petgraph::graph::{DiGraph, NodeIndex};
+use petgraph::visit::EdgeRef;
 use petgraph::Direction;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet, VecDeque};
 
+use super::condensation::{sccs_from_export, Condensation, DependencyScc};
+use super::edge_resolution::{find_resolved, index_by_source, ResolvedEdge};
+use super::graph_export::{
+    edge_kind_name, ExportEdge, ExportGraph, ExportNode, GraphExportOptions,
+};
 use super::IRDocument;
-use crate::shared::models::{EdgeKind, Node, NodeKind, Span};
+use crate::shared::models::{EdgeKind, EdgeStage, Node, NodeKind, Span};
+
+/// Bound on partial paths expanded by one path search
+const MAX_PATH_EXPANSIONS: usize = 100_000;
 
 /// Symbol-level edge kinds
 ///
@@ -104,6 +114,39 @@ impl SymbolNode {
     }
 }
 
+/// One symbol on a path between two symbols
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PathStep {
+    pub fqn: String,
+    pub file_path: String,
+    /// Definition span of the symbol
+    pub span: Span,
+    /// Edge from the previous step (None on the first step)
+    pub edge_kind: Option<SymbolEdgeKind>,
+    /// Span of the reference in the previous step's symbol (call site)
+    pub call_site: Option<Span>,
+    /// The previous step awaits / schedules this one
+    #[serde(default)]
+    pub is_async: bool,
+}
+
+/// Chain of symbols from a source to a target symbol
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SymbolPath {
+    pub steps: Vec<PathStep>,
+}
+
+impl SymbolPath {
+    /// Number of edges
+    pub fn hops(&self) -> usize {
+        self.steps.len().saturating_sub(1)
+    }
+
+    pub fn fqns(&self) -> Vec<&str> {
+        self.steps.iter().map(|s| s.fqn.as_str()).collect()
+    }
+}
+
 /// Symbol-level dependency graph
 ///
 /// SOTA Optimizations:
@@ -125,6 +168,20 @@ pub struct SymbolDependencyGraph {
     /// Example: edges_by_kind[Calls] → all call edges
     edges_by_kind: HashMap<SymbolEdgeKind, Vec<(NodeIndex, NodeIndex)>>,
 
+    /// Edge confidence (0.0-1.0) from IR edge provenance
+    ///
+    /// Only edges with confidence < 1.0 are stored; missing = certain
+    edge_confidence: HashMap<(NodeIndex, NodeIndex), f32>,
+
+    /// Span of the first IR edge between two symbols (call site, import, ...)
+    edge_spans: HashMap<(NodeIndex, NodeIndex), Span>,
+
+    /// Symbol pairs linked by an async call (await, task, promise continuation)
+    async_edges: HashSet<(NodeIndex, NodeIndex)>,
+
+    /// Stage of the first IR edge with provenance between two symbols
+    edge_stages: HashMap<(NodeIndex, NodeIndex), EdgeStage>,
+
     /// Cached call graph (functions only)
     call_graph: Option<CallGraph>,
 }
@@ -136,6 +193,10 @@ impl SymbolDependencyGraph {
             graph: DiGraph::new(),
             symbol_to_node: HashMap::new(),
             edges_by_kind: HashMap::new(),
+            edge_confidence: HashMap::new(),
+            edge_spans: HashMap::new(),
+            async_edges: HashSet::new(),
+            edge_stages: HashMap::new(),
             call_graph: None,
         }
     }
@@ -144,6 +205,12 @@ impl SymbolDependencyGraph {
     ///
     /// SOTA: Parallel edge collection with Rayon
     pub fn build_from_irs(irs: &[IRDocument]) -> Self {
+        Self::build_with_resolved_edges(irs, &[])
+    }
+
+    /// Build from IR documents, following edges to symbols of other files
+    /// through their cross-file resolutions (`resolve_cross_file_edges`)
+    pub fn build_with_resolved_edges(irs: &[IRDocument], resolved: &[ResolvedEdge]) -> Self {
         let mut graph = Self::new();
 
         // Phase 1: Collect all symbols (nodes)
@@ -174,14 +241,22 @@ impl SymbolDependencyGraph {
         }
 
         // Phase 2: Collect all edges (symbol relationships)
-        let all_edges: Vec<(String, String, SymbolEdgeKind)> = irs
+        // Node ID → Node across files, for resolved cross-file targets
+        let node_by_id: HashMap<&str, &Node> = irs
+            .iter()
+            .flat_map(|ir| ir.nodes.iter().map(|n| (n.id.as_str(), n)))
+            .collect();
+        let resolved = index_by_source(resolved);
+        let all_edges: Vec<SymbolEdge> = irs
             .par_iter()
-            .flat_map(|ir| graph.collect_edges_from_ir(ir))
+            .flat_map(|ir| Self::collect_edges_from_ir(ir, &node_by_id, &resolved))
             .collect();
 
         // Add edges to graph
-        for (from_fqn, to_fqn, edge_kind) in all_edges {
-            graph.add_edge(&from_fqn, &to_fqn, edge_kind);
+        for (from_fqn, to_fqn, edge_kind, confidence, span, is_async, stage) in all_edges {
+            graph.add_edge(
+                &from_fqn, &to_fqn, edge_kind, confidence, span, is_async, stage,
+            );
         }
 
         // Phase 3: Build specialized call graph
@@ -191,13 +266,13 @@ impl SymbolDependencyGraph {
     }
 
     /// Collect symbol edges from a single IR document
-    fn collect_edges_from_ir(&self, ir: &IRDocument) -> Vec<(String, String, SymbolEdgeKind)> {
+    fn collect_edges_from_ir(
+        ir: &IRDocument,
+        node_by_id: &HashMap<&str, &Node>,
+        resolved: &HashMap<&str, Vec<&ResolvedEdge>>,
+    ) -> Vec<SymbolEdge> {
         let mut edges = Vec::new();
 
-        // Build node ID → Node mapping for fast lookup
-        let node_by_id: HashMap<&str, &Node> =
-            ir.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
-
         for edge in &ir.edges {
             // Get source and target nodes
             let source_node = match node_by_id.get(edge.source_id.as_str()) {
@@ -205,10 +280,16 @@ impl SymbolDependencyGraph {
                 None => continue,
             };
 
-            let target_node = match node_by_id.get(edge.target_id.as_str()) {
+            // Targets named at the use site resolve to symbols of other files
+            let resolution = find_resolved(resolved, edge);
+            let target_id = resolution.map_or(edge.target_id.as_str(), |r| r.target_id.as_str());
+            let target_node = match node_by_id.get(target_id) {
                 Some(n) => n,
                 None => continue,
             };
+            let provenance = resolution
+                .map(|r| &r.provenance)
+                .or_else(|| edge.provenance());
 
             // Skip if either node has empty FQN
             if source_node.fqn.is_empty() || target_node.fqn.is_empty() {
@@ -225,10 +306,16 @@ impl SymbolDependencyGraph {
                 _ => continue, // Skip other edge kinds
             };
 
+            let confidence = provenance.and_then(|p| p.confidence).unwrap_or(1.0);
+
             edges.push((
                 source_node.fqn.clone(),
                 target_node.fqn.clone(),
                 symbol_edge_kind,
+                confidence,
+                edge.span,
+                edge.is_async(),
+                provenance.map(|p| p.stage),
             ));
         }
 
@@ -238,7 +325,17 @@ impl SymbolDependencyGraph {
     /// Add edge between symbols
     ///
     /// Also adds reverse edge if applicable (e.g., Calls → CalledBy)
-    fn add_edge(&mut self, from_fqn: &str, to_fqn: &str, edge_kind: SymbolEdgeKind) {
+    #[allow(clippy::too_many_arguments)]
+    fn add_edge(
+        &mut self,
+        from_fqn: &str,
+        to_fqn: &str,
+        edge_kind: SymbolEdgeKind,
+        confidence: f32,
+        span: Option<Span>,
+        is_async: bool,
+        stage: Option<EdgeStage>,
+    ) {
         let from_idx = match self.symbol_to_node.get(from_fqn) {
             Some(&idx) => idx,
             None => return,
@@ -251,6 +348,16 @@ impl SymbolDependencyGraph {
 
         // Add forward edge
         self.graph.add_edge(from_idx, to_idx, edge_kind);
+        self.record_confidence(from_idx, to_idx, confidence);
+        if let Some(span) = span {
+            self.edge_spans.entry((from_idx, to_idx)).or_insert(span);
+        }
+        if is_async {
+            self.async_edges.insert((from_idx, to_idx));
+        }
+        if let Some(stage) = stage {
+            self.edge_stages.entry((from_idx, to_idx)).or_insert(stage);
+        }
 
         // Track edge by kind
         self.edges_by_kind
@@ -261,6 +368,16 @@ impl SymbolDependencyGraph {
         // Add reverse edge if applicable
         if let Some(reverse_kind) = edge_kind.reverse() {
             self.graph.add_edge(to_idx, from_idx, reverse_kind);
+            self.record_confidence(to_idx, from_idx, confidence);
+            if let Some(span) = span {
+                self.edge_spans.entry((to_idx, from_idx)).or_insert(span);
+            }
+            if is_async {
+                self.async_edges.insert((to_idx, from_idx));
+            }
+            if let Some(stage) = stage {
+                self.edge_stages.entry((to_idx, from_idx)).or_insert(stage);
+            }
 
             self.edges_by_kind
                 .entry(reverse_kind)
@@ -269,6 +386,61 @@ impl SymbolDependencyGraph {
         }
     }
 
+    /// Keep the most confident edge between two symbols
+    fn record_confidence(&mut self, from_idx: NodeIndex, to_idx: NodeIndex, confidence: f32) {
+        let key = (from_idx, to_idx);
+        let best = match self.edge_confidence.get(&key) {
+            Some(&existing) => existing.max(confidence),
+            // First edge: only uncertain edges are stored
+            None if self.graph.edges_connecting(from_idx, to_idx).count() > 1 => return,
+            None => confidence,
+        };
+        if best >= 1.0 {
+            self.edge_confidence.remove(&key);
+        } else {
+            self.edge_confidence.insert(key, best);
+        }
+    }
+
+    /// Confidence (0.0-1.0) of the edge `from_fqn → to_fqn`
+    ///
+    /// Returns 1.0 for edges without a recorded score, 0.0 if no edge exists.
+    pub fn edge_confidence(&self, from_fqn: &str, to_fqn: &str) -> f32 {
+        let (Some(&from_idx), Some(&to_idx)) = (
+            self.symbol_to_node.get(from_fqn),
+            self.symbol_to_node.get(to_fqn),
+        ) else {
+            return 0.0;
+        };
+        if self.graph.find_edge(from_idx, to_idx).is_none() {
+            return 0.0;
+        }
+        self.edge_confidence
+            .get(&(from_idx, to_idx))
+            .copied()
+            .unwrap_or(1.0)
+    }
+
+    /// Whether `from_fqn` awaits or schedules `to_fqn` (or is awaited by it,
+    /// for CalledBy lookups)
+    pub fn is_async_call(&self, from_fqn: &str, to_fqn: &str) -> bool {
+        match (
+            self.symbol_to_node.get(from_fqn),
+            self.symbol_to_node.get(to_fqn),
+        ) {
+            (Some(&from_idx), Some(&to_idx)) => self.async_edges.contains(&(from_idx, to_idx)),
+            _ => false,
+        }
+    }
+
+    /// Stage that created the edge `from_fqn → to_fqn` (None without an
+    /// edge or provenance)
+    pub fn edge_stage(&self, from_fqn: &str, to_fqn: &str) -> Option<EdgeStage> {
+        let from_idx = self.symbol_to_node.get(from_fqn)?;
+        let to_idx = self.symbol_to_node.get(to_fqn)?;
+        self.edge_stages.get(&(*from_idx, *to_idx)).copied()
+    }
+
     /// Get all symbols that this symbol depends on
     pub fn get_dependencies(&self, fqn: &str, edge_kind: Option<SymbolEdgeKind>) -> Vec<String> {
         let idx = match self.symbol_to_node.get(fqn) {
@@ -361,6 +533,115 @@ impl SymbolDependencyGraph {
         visited.into_iter().collect()
     }
 
+    /// Get transitive dependents with path confidence
+    ///
+    /// Confidence of a dependent = max over paths of the product of edge
+    /// confidences, so one low-confidence hop discounts everything behind it.
+    pub fn get_transitive_dependents_with_confidence(&self, fqn: &str) -> HashMap<String, f32> {
+        let idx = match self.symbol_to_node.get(fqn) {
+            Some(&idx) => idx,
+            None => return HashMap::new(),
+        };
+
+        let mut best: HashMap<NodeIndex, f32> = HashMap::new();
+        let mut queue = VecDeque::new();
+        queue.push_back((idx, 1.0f32));
+
+        while let Some((current, confidence)) = queue.pop_front() {
+            for neighbor in self.graph.neighbors_directed(current, Direction::Incoming) {
+                if neighbor == idx {
+                    continue;
+                }
+                let edge_conf = self
+                    .edge_confidence
+                    .get(&(neighbor, current))
+                    .copied()
+                    .unwrap_or(1.0);
+                let path_conf = confidence * edge_conf;
+                let improved = best.get(&neighbor).map_or(true, |&b| path_conf > b);
+                if improved {
+                    best.insert(neighbor, path_conf);
+                    queue.push_back((neighbor, path_conf));
+                }
+            }
+        }
+
+        best.into_iter()
+            .map(|(node, conf)| (self.graph[node].fqn.clone(), conf))
+            .collect()
+    }
+
+    /// Up to `max_paths` shortest paths `from_fqn → to_fqn` of at most
+    /// `max_depth` edges, following only `edge_kinds` (empty = `Calls`)
+    ///
+    /// Paths are simple (no symbol twice) and ordered by length, ties by
+    /// FQN. Answers "how does request handling reach this SQL call?".
+    pub fn find_paths(
+        &self,
+        from_fqn: &str,
+        to_fqn: &str,
+        max_depth: usize,
+        edge_kinds: &[SymbolEdgeKind],
+        max_paths: usize,
+    ) -> Vec<SymbolPath> {
+        let (Some(&from_idx), Some(&to_idx)) = (
+            self.symbol_to_node.get(from_fqn),
+            self.symbol_to_node.get(to_fqn),
+        ) else {
+            return Vec::new();
+        };
+        let kinds = if edge_kinds.is_empty() {
+            &[SymbolEdgeKind::Calls][..]
+        } else {
+            edge_kinds
+        };
+
+        let successors = |&idx: &NodeIndex| {
+            let mut next: Vec<NodeIndex> = self
+                .graph
+                .edges_directed(idx, Direction::Outgoing)
+                .filter(|edge| kinds.contains(edge.weight()))
+                .map(|edge| edge.target())
+                .collect();
+            next.sort_by(|a, b| self.graph[*a].fqn.cmp(&self.graph[*b].fqn));
+            next.dedup();
+            next
+        };
+
+        shortest_paths(from_idx, &to_idx, max_depth, max_paths, successors)
+            .into_iter()
+            .map(|path| self.symbol_path(&path, kinds))
+            .collect()
+    }
+
+    fn symbol_path(&self, path: &[NodeIndex], kinds: &[SymbolEdgeKind]) -> SymbolPath {
+        let steps = path
+            .iter()
+            .enumerate()
+            .map(|(i, &idx)| {
+                let symbol = &self.graph[idx];
+                let prev = i.checked_sub(1).map(|p| path[p]);
+                let edge_kind = prev.and_then(|prev| {
+                    let connecting: Vec<SymbolEdgeKind> = self
+                        .graph
+                        .edges_connecting(prev, idx)
+                        .map(|edge| *edge.weight())
+                        .collect();
+                    kinds.iter().copied().find(|k| connecting.contains(k))
+                });
+                PathStep {
+                    fqn: symbol.fqn.clone(),
+                    file_path: symbol.file_path.clone(),
+                    span: symbol.span,
+                    edge_kind,
+                    call_site: prev.and_then(|prev| self.edge_spans.get(&(prev, idx)).copied()),
+                    is_async: prev.is_some_and(|prev| self.async_edges.contains(&(prev, idx))),
+                }
+            })
+            .collect();
+        SymbolPath { steps }
+    }
+
     /// Get symbol node by FQN
     pub fn get_symbol(&self, fqn: &str) -> Option<&SymbolNode> {
         let idx = self.symbol_to_node.get(fqn)?;
@@ -385,6 +666,141 @@ impl SymbolDependencyGraph {
             edges_by_kind: edges_by_kind_count,
         }
     }
+
+    /// Export as Graphviz DOT (edges colored by kind, optional per-file clusters)
+    pub fn to_dot(&self, options: &GraphExportOptions) -> String {
+        self.export_graph(options).to_dot(options)
+    }
+
+    /// Export as GraphML
+    pub fn to_graphml(&self, options: &GraphExportOptions) -> String {
+        self.export_graph(options).to_graphml(options)
+    }
+
+    /// Condensation into dependency cycles (SCC DAG) over the edge kinds
+    /// accepted by `options`
+    pub fn condensation(&self, options: &GraphExportOptions) -> Condensation {
+        Condensation::from_export(&self.export_graph(options))
+    }
+
+    /// Strongly connected components over forward edges (no `CalledBy` /
+    /// `Exports` mirrors), dependents first
+    pub fn get_sccs(&self) -> Vec<DependencyScc> {
+        sccs_from_export(&self.export_graph(&GraphExportOptions::default()))
+    }
+
+    /// Dependency cycles over forward edges, with the edges forming them
+    pub fn get_cycles(&self) -> Vec<DependencyScc> {
+        self.get_cycles_with(&GraphExportOptions::default())
+    }
+
+    /// Dependency cycles over the edge kinds accepted by `options`
+    /// (e.g. only `Imports` for circular-import checks)
+    pub fn get_cycles_with(&self, options: &GraphExportOptions) -> Vec<DependencyScc> {
+        sccs_from_export(&self.export_graph(options))
+            .into_iter()
+            .filter(|c| c.cyclic)
+            .collect()
+    }
+
+    fn export_graph(&self, options: &GraphExportOptions) -> ExportGraph {
+        let nodes = self
+            .graph
+            .node_weights()
+            .map(|symbol| ExportNode {
+                id: symbol.fqn.clone(),
+                label: symbol
+                    .fqn
+                    .rsplit('.')
+                    .next()
+                    .unwrap_or(&symbol.fqn)
+                    .to_string(),
+                kind: symbol.kind.as_str(),
+                file: Some(symbol.file_path.clone()),
+                cluster: Some(symbol.file_path.clone()),
+            })
+            .collect();
+
+        let edges = self
+            .graph
+            .edge_references()
+            .filter(|edge| options.accepts(*edge.weight()))
+            .map(|edge| ExportEdge {
+                source: self.graph[edge.source()].fqn.clone(),
+                target: self.graph[edge.target()].fqn.clone(),
+                kind: edge_kind_name(*edge.weight()),
+                confidence: self
+                    .edge_confidence
+                    .get(&(edge.source(), edge.target()))
+                    .copied()
+                    .unwrap_or(1.0),
+            })
+            .collect();
+
+        ExportGraph { nodes, edges }.sorted()
+    }
+}
+
+/// (from FQN, to FQN, kind, confidence, IR edge span, is async call,
+/// provenance stage)
+type SymbolEdge = (
+    String,
+    String,
+    SymbolEdgeKind,
+    f32,
+    Option<Span>,
+    bool,
+    Option<EdgeStage>,
+);
+
+/// Breadth-first search for up to `max_paths` simple paths of at most
+/// `max_depth` edges, shortest first
+fn shortest_paths<N, F, I>(
+    start: N,
+    goal: &N,
+    max_depth: usize,
+    max_paths: usize,
+    mut successors: F,
+) -> Vec<Vec<N>>
+where
+    N: Clone + PartialEq,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut found = Vec::new();
+    if max_paths == 0 {
+        return found;
+    }
+    if start == *goal {
+        found.push(vec![start]);
+        return found;
+    }
+
+    let mut queue = VecDeque::new();
+    queue.push_back(vec![start]);
+    let mut expansions = 0;
+    while let Some(path) = queue.pop_front() {
+        if path.len() > max_depth || expansions >= MAX_PATH_EXPANSIONS {
+            continue;
+        }
+        expansions += 1;
+        for next in successors(&path[path.len() - 1]) {
+            if path.contains(&next) {
+                continue;
+            }
+            let mut extended = path.clone();
+            extended.push(next);
+            if extended.last() == Some(goal) {
+                found.push(extended);
+                if found.len() >= max_paths {
+                    return found;
+                }
+            } else {
+                queue.push_back(extended);
+            }
+        }
+    }
+    found
 }
 
 impl Default for SymbolDependencyGraph {
@@ -469,6 +885,30 @@ impl CallGraph {
         visited.into_iter().collect()
     }
 
+    /// Up to `max_paths` shortest call chains `from_fqn → to_fqn` of at
+    /// most `max_depth` calls (FQNs; spans via `SymbolDependencyGraph::find_paths`)
+    pub fn find_paths(
+        &self,
+        from_fqn: &str,
+        to_fqn: &str,
+        max_depth: usize,
+        max_paths: usize,
+    ) -> Vec<Vec<String>> {
+        let successors = |fqn: &String| {
+            let mut next = self.get_callees(fqn);
+            next.sort();
+            next.dedup();
+            next
+        };
+        shortest_paths(
+            from_fqn.to_string(),
+            &to_fqn.to_string(),
+            max_depth,
+            max_paths,
+            successors,
+        )
+    }
+
     /// Get transitive callers (all functions that can reach this function)
     pub fn get_transitive_callers(&self, fqn: &str) -> Vec<String> {
         let mut visited = HashSet::new();
@@ -556,6 +996,57 @@ mod tests {
         assert_eq!(callers, vec!["main.func_a".to_string()]);
     }
 
+    #[test]
+    fn test_resolved_cross_file_call() {
+        let util = IRDocument {
+            file_path: "util.py".to_string(),
+            nodes: vec![make_test_node(
+                "f:helper",
+                NodeKind::Function,
+                "util.helper",
+                "util.py",
+            )],
+            edges: vec![],
+            repo_id: None,
+        };
+        let main = IRDocument {
+            file_path: "main.py".to_string(),
+            nodes: vec![make_test_node(
+                "f:main",
+                NodeKind::Function,
+                "main.main",
+                "main.py",
+            )],
+            edges: vec![make_call_edge("f:main", "helper")],
+            repo_id: None,
+        };
+        let resolved = ResolvedEdge {
+            file_path: "main.py".to_string(),
+            source_id: "f:main".to_string(),
+            target: "helper".to_string(),
+            kind: EdgeKind::Calls,
+            target_id: "f:helper".to_string(),
+            target_fqn: "util.helper".to_string(),
+            provenance: crate::shared::models::EdgeProvenance::new(EdgeStage::CrossFile),
+        };
+        let irs = vec![util, main];
+
+        let unresolved = SymbolDependencyGraph::build_from_irs(&irs);
+        assert!(unresolved
+            .get_dependencies("main.main", Some(SymbolEdgeKind::Calls))
+            .is_empty());
+
+        let graph = SymbolDependencyGraph::build_with_resolved_edges(&irs, &[resolved]);
+        assert_eq!(
+            graph.get_dependencies("main.main", Some(SymbolEdgeKind::Calls)),
+            vec!["util.helper".to_string()]
+        );
+        assert_eq!(
+            graph.edge_stage("main.main", "util.helper"),
+            Some(EdgeStage::CrossFile)
+        );
+    }
+
     #[test]
     fn test_call_graph() {
         let ir = IRDocument {
@@ -623,4 +1114,142 @@ mod tests {
         assert!(dependents.contains(&"test.a".to_string()));
         assert!(dependents.contains(&"test.b".to_string()));
     }
+
+    #[test]
+    fn test_find_paths_shortest_first_with_call_sites() {
+        // handler → service → repo → sql, handler → repo, handler reads config
+        let ir = IRDocument {
+            file_path: "src/app.py".to_string(),
+            nodes: vec![
+                make_test_node("h", NodeKind::Function, "app.handler", "src/app.py"),
+                make_test_node("s", NodeKind::Function, "app.service", "src/app.py"),
+                make_test_node("r", NodeKind::Function, "app.repo", "src/app.py"),
+                make_test_node("q", NodeKind::Function, "app.sql", "src/app.py"),
+                make_test_node("c", NodeKind::Variable, "app.config", "src/app.py"),
+            ],
+            edges: vec![
+                make_call_edge("h", "s"),
+                make_call_edge("s", "r"),
+                make_call_edge("h", "r").with_span(Span::new(3, 4, 3, 12)),
+                make_call_edge("r", "q"),
+                Edge::new("h".to_string(), "c".to_string(), EdgeKind::Reads),
+            ],
+            repo_id: None,
+        };
+        let graph = SymbolDependencyGraph::build_from_irs(&[ir]);
+
+        let paths = graph.find_paths("app.handler", "app.sql", 5, &[], 5);
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[0].fqns(), vec!["app.handler", "app.repo", "app.sql"]);
+        assert_eq!(paths[1].hops(), 3);
+        let hop = &paths[0].steps[1];
+        assert_eq!(hop.edge_kind, Some(SymbolEdgeKind::Calls));
+        assert_eq!(hop.call_site, Some(Span::new(3, 4, 3, 12)));
+        assert!(paths[0].steps[0].edge_kind.is_none());
+
+        assert_eq!(
+            graph.find_paths("app.handler", "app.sql", 2, &[], 5).len(),
+            1
+        );
+        assert_eq!(
+            graph.find_paths("app.handler", "app.sql", 5, &[], 1).len(),
+            1
+        );
+        assert!(graph
+            .find_paths("app.handler", "app.config", 5, &[], 5)
+            .is_empty());
+        let reads = graph.find_paths("app.handler", "app.config", 5, &[SymbolEdgeKind::Reads], 5);
+        assert_eq!(reads[0].steps[1].edge_kind, Some(SymbolEdgeKind::Reads));
+
+        let call_graph = graph.call_graph().unwrap();
+        assert_eq!(
+            call_graph.find_paths("app.handler", "app.sql", 5, 5)[1],
+            vec!["app.handler", "app.service", "app.repo", "app.sql"]
+        );
+    }
+
+    #[test]
+    fn test_async_call_edges() {
+        let ir = IRDocument {
+            file_path: "src/svc.py".to_string(),
+            nodes: vec![
+                make_test_node("m", NodeKind::Function, "svc.main", "src/svc.py"),
+                make_test_node("f", NodeKind::Function, "svc.fetch", "src/svc.py"),
+                make_test_node("l", NodeKind::Function, "svc.log", "src/svc.py"),
+            ],
+            edges: vec![
+                make_call_edge("m", "f").with_is_async(true),
+                make_call_edge("m", "l"),
+                make_call_edge("l", "f"),
+            ],
+            repo_id: None,
+        };
+        let graph = SymbolDependencyGraph::build_from_irs(&[ir]);
+
+        assert!(graph.is_async_call("svc.main", "svc.fetch"));
+        assert!(graph.is_async_call("svc.fetch", "svc.main"));
+        assert!(!graph.is_async_call("svc.main", "svc.log"));
+        assert!(graph
+            .get_transitive_dependents("svc.fetch")
+            .contains(&"svc.main".to_string()));
+
+        let paths = graph.find_paths("svc.main", "svc.fetch", 3, &[], 2);
+        assert!(paths[0].steps[1].is_async);
+        assert!(!paths[1].steps[1].is_async);
+    }
+
+    #[test]
+    fn test_get_cycles_ignores_reverse_edges() {
+        // even ↔ odd (mutual recursion), main → even
+        let ir = IRDocument {
+            file_path: "src/num.py".to_string(),
+            nodes: vec![
+                make_test_node("m", NodeKind::Function, "num.main", "src/num.py"),
+                make_test_node("e", NodeKind::Function, "num.even", "src/num.py"),
+                make_test_node("o", NodeKind::Function, "num.odd", "src/num.py"),
+            ],
+            edges: vec![
+                make_call_edge("m", "e"),
+                make_call_edge("e", "o"),
+                make_call_edge("o", "e"),
+            ],
+            repo_id: None,
+        };
+        let graph = SymbolDependencyGraph::build_from_irs(&[ir]);
+
+        assert_eq!(graph.get_sccs().len(), 2);
+        let cycles = graph.get_cycles();
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].members, vec!["num.even", "num.odd"]);
+        assert_eq!(cycles[0].edges.len(), 2);
+
+        let imports_only =
+            GraphExportOptions::default().with_edge_kinds(vec![SymbolEdgeKind::Imports]);
+        assert!(graph.get_cycles_with(&imports_only).is_empty());
+    }
+
+    #[test]
+    fn test_export_dot_and_graphml() {
+        let ir = IRDocument {
+            file_path: "src/test.py".to_string(),
+            nodes: vec![
+                make_test_node("a", NodeKind::Function, "test.a", "src/test.py"),
+                make_test_node("b", NodeKind::Function, "test.b", "src/test.py"),
+            ],
+            edges: vec![make_call_edge("a", "b")],
+            repo_id: None,
+        };
+        let graph = SymbolDependencyGraph::build_from_irs(&[ir]);
+
+        let options = GraphExportOptions::default().with_clusters();
+        let dot = graph.to_dot(&options);
+        assert!(dot.contains("label=\"src/test.py\""));
+        assert!(dot.contains("\"test.a\" -> \"test.b\" [label=\"calls\""));
+        // CalledBy mirror edge is omitted by default
+        assert!(!dot.contains("called_by"));
+
+        let graphml = graph.to_graphml(&options);
+        assert_eq!(graphml.matches("<node id=").count(), 2);
+        assert_eq!(graphml.matches("<edge id=").count(), 1);
+    }
 }
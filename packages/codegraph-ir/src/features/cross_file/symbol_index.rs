@@ -11,11 +11,13 @@
 
 use dashmap::DashMap;
 use rayon::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use super::types::Symbol;
+use super::tsconfig::{is_ts_module_file, split_ts_import};
+use super::types::{Symbol, SymbolAlias, TsReexport};
 use super::IRDocument;
+use crate::shared::models::{Node, NodeKind};
 
 /// Lock-free concurrent symbol index
 ///
@@ -38,6 +40,13 @@ pub struct SymbolIndex {
     /// Example: "np" → "numpy", "pd" → "pandas"
     /// Key: (file_path, alias) → Value: FQN
     alias_to_fqn: DashMap<(String, String), String>,
+
+    /// Re-exported FQN → canonical symbol
+    /// Example: "core.login" → "core.auth.login"
+    reexports: DashMap<String, SymbolAlias>,
+
+    /// File → TS/JS re-exports, resolved on lookup (specifiers need tsconfig)
+    ts_reexports: DashMap<String, Vec<TsReexport>>,
 }
 
 impl SymbolIndex {
@@ -48,6 +57,8 @@ impl SymbolIndex {
             file_symbols: DashMap::new(),
             name_to_fqns: DashMap::new(),
             alias_to_fqn: DashMap::new(),
+            reexports: DashMap::new(),
+            ts_reexports: DashMap::new(),
         }
     }
 
@@ -115,8 +126,17 @@ impl SymbolIndex {
             if !file_fqns.is_empty() {
                 index.file_symbols.insert(ir.file_path.clone(), file_fqns);
             }
+            let ts_reexports = collect_ts_reexports(ir);
+            if !ts_reexports.is_empty() {
+                index
+                    .ts_reexports
+                    .insert(ir.file_path.clone(), ts_reexports);
+            }
         });
 
+        // Re-exports resolve against the complete symbol table
+        index.register_reexports(irs);
+
         index
     }
 
@@ -146,6 +166,19 @@ impl SymbolIndex {
             .unwrap_or_default()
     }
 
+    /// Files with at least one symbol
+    pub fn file_paths(&self) -> Vec<String> {
+        self.file_symbols.iter().map(|e| e.key().clone()).collect()
+    }
+
+    /// TS/JS re-exports of a file
+    pub fn ts_reexports(&self, file_path: &str) -> Vec<TsReexport> {
+        self.ts_reexports
+            .get(file_path)
+            .map(|v| v.clone())
+            .unwrap_or_default()
+    }
+
     /// Get total symbol count
     pub fn len(&self) -> usize {
         self.symbols.len()
@@ -215,9 +248,12 @@ impl SymbolIndex {
     /// - src/module.py
     /// - module/__init__.py
     /// - src/module/__init__.py
+    /// - the literal path (PHP `require 'config/app.php'`)
+    /// - a/b.rb, lib/a/b.rb (Ruby `require 'a/b'`, emitted as `a.b`)
     pub fn resolve_by_module_path(&self, module_name: &str) -> Option<Arc<Symbol>> {
         // Get the first part of the module name
         let base_module = module_name.split('.').next().unwrap_or(module_name);
+        let ruby_path = module_name.replace('.', "/");
 
         // Look for any symbol in files matching common patterns
         let patterns = [
@@ -225,6 +261,9 @@ impl SymbolIndex {
             format!("src/{}.py", base_module),
             format!("{}/__init__.py", base_module),
             format!("src/{}/__init__.py", base_module),
+            module_name.to_string(),
+            format!("{}.rb", ruby_path),
+            format!("lib/{}.rb", ruby_path),
         ];
 
         for pattern in &patterns {
@@ -241,6 +280,78 @@ impl SymbolIndex {
         None
     }
 
+    /// Register package re-exports of `irs` as aliases of their symbols
+    ///
+    /// A re-export is a module-level import in a package `__init__.py`:
+    /// `from core.auth import login` in `core/__init__.py` makes `core.login`
+    /// an alias of `core.auth.login`. Private names, re-exports of symbols
+    /// outside the index (third-party packages) and aliases shadowed by a
+    /// definition of the same FQN are skipped.
+    pub fn register_reexports(&self, irs: &[IRDocument]) {
+        // Alias FQN → (target FQN, file, import node)
+        let hops: HashMap<String, (String, &str, &str)> =
+            irs.iter().flat_map(collect_reexports).collect();
+
+        for (alias_fqn, (target_fqn, file_path, node_id)) in &hops {
+            if self.symbols.contains_key(alias_fqn) {
+                continue;
+            }
+            if let Some(canonical_fqn) = self.follow_reexports(target_fqn, &hops) {
+                self.reexports.insert(
+                    alias_fqn.clone(),
+                    SymbolAlias {
+                        alias_fqn: alias_fqn.clone(),
+                        target_fqn: target_fqn.clone(),
+                        canonical_fqn,
+                        file_path: file_path.to_string(),
+                        node_id: node_id.to_string(),
+                    },
+                );
+            }
+        }
+    }
+
+    /// Canonical FQN reached from `target` through re-exports (None if the
+    /// chain leaves the index or is cyclic)
+    fn follow_reexports(
+        &self,
+        target: &str,
+        hops: &HashMap<String, (String, &str, &str)>,
+    ) -> Option<String> {
+        let mut current = target.to_string();
+        for _ in 0..=hops.len() {
+            if self.symbols.contains_key(&current) {
+                return Some(current);
+            }
+            if let Some(alias) = self.reexports.get(&current) {
+                return Some(alias.canonical_fqn.clone());
+            }
+            current = hops.get(&current)?.0.clone();
+        }
+        None
+    }
+
+    /// Re-export registered for `fqn`
+    pub fn resolve_reexport(&self, fqn: &str) -> Option<SymbolAlias> {
+        self.reexports.get(fqn).map(|v| v.clone())
+    }
+
+    /// FQN of the symbol `fqn` names (itself unless it is a re-export)
+    pub fn canonical_fqn(&self, fqn: &str) -> String {
+        self.reexports
+            .get(fqn)
+            .map(|alias| alias.canonical_fqn.clone())
+            .unwrap_or_else(|| fqn.to_string())
+    }
+
+    /// Re-exports by alias FQN
+    pub fn reexports_to_hashmap(&self) -> HashMap<String, SymbolAlias> {
+        self.reexports
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect()
+    }
+
     /// Register an import alias for a file
     ///
     /// Example: `register_alias("main.py", "np", "numpy")`
@@ -285,6 +396,17 @@ impl SymbolIndex {
 
     /// Remove symbols from a file (for incremental updates)
     pub fn remove_file(&self, file_path: &str) -> Vec<String> {
+        // Re-exports of this file, and aliases of its symbols elsewhere
+        let fqns: HashSet<String> = self
+            .file_symbols
+            .get(file_path)
+            .map(|fqns| fqns.iter().cloned().collect())
+            .unwrap_or_default();
+        self.reexports.retain(|_, alias| {
+            alias.file_path != file_path && !fqns.contains(&alias.canonical_fqn)
+        });
+        self.ts_reexports.remove(file_path);
+
         if let Some((_, fqns)) = self.file_symbols.remove(file_path) {
             for fqn in &fqns {
                 self.symbols.remove(fqn);
@@ -335,6 +457,11 @@ impl SymbolIndex {
         if !file_fqns.is_empty() {
             self.file_symbols.insert(ir.file_path.clone(), file_fqns);
         }
+        let ts_reexports = collect_ts_reexports(ir);
+        if !ts_reexports.is_empty() {
+            self.ts_reexports.insert(ir.file_path.clone(), ts_reexports);
+        }
+        self.register_reexports(std::slice::from_ref(ir));
     }
 }
 
@@ -344,6 +471,106 @@ impl Default for SymbolIndex {
     }
 }
 
+/// Module-level imports of a package `__init__` as (alias FQN, (target FQN,
+/// file, import node))
+fn collect_reexports(ir: &IRDocument) -> Vec<(String, (String, &str, &str))> {
+    let file_name = ir.file_path.rsplit(['/', '\\']).next().unwrap_or_default();
+    if file_name != "__init__.py" && file_name != "__init__.pyi" {
+        return Vec::new();
+    }
+
+    // Imports inside functions and classes are not re-exports
+    let scoped: HashSet<&str> = ir
+        .nodes
+        .iter()
+        .filter(|n| !matches!(n.kind, NodeKind::File | NodeKind::Module | NodeKind::Import))
+        .map(|n| n.id.as_str())
+        .collect();
+
+    ir.nodes
+        .iter()
+        .filter(|n| matches!(n.kind, NodeKind::Import))
+        .filter(|n| n.parent_id.as_deref().map_or(true, |p| !scoped.contains(p)))
+        .filter_map(|node| {
+            // Bound name: alias or imported name (`import a.b` binds no new name)
+            let name = node.name.as_deref()?;
+            if name.is_empty() || name.starts_with('_') || name.contains(['.', '*']) {
+                return None;
+            }
+            let package = package_fqn(ir, node)?;
+            let target = absolute_import_fqn(&package, &node.fqn)?;
+            let alias = format!("{}.{}", package, name);
+            if alias == target {
+                return None;
+            }
+            Some((alias, (target, ir.file_path.as_str(), node.id.as_str())))
+        })
+        .collect()
+}
+
+/// Exported Import nodes of a TS/JS file (`export ... from`)
+fn collect_ts_reexports(ir: &IRDocument) -> Vec<TsReexport> {
+    if !is_ts_module_file(&ir.file_path) {
+        return Vec::new();
+    }
+    ir.nodes
+        .iter()
+        .filter(|n| matches!(n.kind, NodeKind::Import))
+        .filter(|n| {
+            n.modifiers
+                .as_ref()
+                .is_some_and(|m| m.iter().any(|m| m == "export"))
+        })
+        .map(|node| {
+            let (specifier, imported) = split_ts_import(&node.fqn);
+            TsReexport {
+                exported: node.name.clone().unwrap_or_else(|| imported.to_string()),
+                specifier: specifier.to_string(),
+                imported: imported.to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Package FQN of an `__init__` file (`core/__init__.py` → `core`)
+fn package_fqn(ir: &IRDocument, node: &Node) -> Option<String> {
+    let package = match node.module_path.as_deref() {
+        Some(module) if !module.is_empty() => module
+            .strip_suffix("__init__")
+            .map(|m| m.trim_end_matches('.'))
+            .unwrap_or(module)
+            .to_string(),
+        _ => {
+            let parts: Vec<&str> = ir
+                .file_path
+                .split(['/', '\\'])
+                .filter(|s| !s.is_empty() && *s != "src")
+                .collect();
+            parts[..parts.len().saturating_sub(1)].join(".")
+        }
+    };
+    (!package.is_empty()).then_some(package)
+}
+
+/// Absolute FQN of an import made in `package` (`.auth.login` → `core.auth.login`)
+pub(super) fn absolute_import_fqn(package: &str, fqn: &str) -> Option<String> {
+    let dots = fqn.chars().take_while(|c| *c == '.').count();
+    if dots == 0 {
+        return Some(fqn.to_string());
+    }
+    let parts: Vec<&str> = package.split('.').collect();
+    if dots > parts.len() {
+        return None;
+    }
+    let base = parts[..parts.len() + 1 - dots].join(".");
+    let remainder = &fqn[dots..];
+    Some(if remainder.is_empty() {
+        base
+    } else {
+        format!("{}.{}", base, remainder)
+    })
+}
+
 /// Extract name from FQN (last component)
 fn extract_name(fqn: &str) -> String {
     fqn.split('.').last().unwrap_or(fqn).to_string()
@@ -436,6 +663,37 @@ mod tests {
         assert_eq!(matched, "module.submodule");
     }
 
+    #[test]
+    fn test_resolve_by_module_path_php_ruby() {
+        let irs = vec![
+            IRDocument {
+                file_path: "config/app.php".to_string(),
+                nodes: vec![make_test_node("n1", "boot", "config/app.php", "boot")],
+                edges: vec![],
+                repo_id: None,
+            },
+            IRDocument {
+                file_path: "lib/billing/invoice.rb".to_string(),
+                nodes: vec![make_test_node(
+                    "n2",
+                    "Billing.Invoice.total",
+                    "lib/billing/invoice.rb",
+                    "total",
+                )],
+                edges: vec![],
+                repo_id: None,
+            },
+        ];
+
+        let index = SymbolIndex::build_from_irs(&irs);
+
+        let php = index.resolve_by_module_path("config/app.php").unwrap();
+        assert_eq!(php.fqn, "boot");
+
+        let ruby = index.resolve_by_module_path("billing.invoice").unwrap();
+        assert_eq!(ruby.fqn, "Billing.Invoice.total");
+    }
+
     #[test]
     fn test_symbol_index_get_by_name() {
         let ir1 = IRDocument {
@@ -575,4 +833,58 @@ mod tests {
         assert_eq!(index.resolve_alias("main.py", "pd"), None);
         assert_eq!(index.get_file_aliases("main.py").len(), 0);
     }
+
+    #[test]
+    fn test_reexport_aliases() {
+        let import = |id: &str, fqn: &str, file: &str, name: &str| {
+            Node::new(
+                id.to_string(),
+                NodeKind::Import,
+                fqn.to_string(),
+                file.to_string(),
+                Span::new(1, 0, 1, 30),
+            )
+            .with_name(name.to_string())
+        };
+        let auth = IRDocument::new(
+            "core/auth.py".to_string(),
+            vec![
+                make_test_node("login", "core.auth.login", "core/auth.py", "login"),
+                make_test_node("hash", "core.auth._hash", "core/auth.py", "_hash"),
+            ],
+            vec![],
+        );
+        // from .auth import login, _hash
+        let core_init = IRDocument::new(
+            "core/__init__.py".to_string(),
+            vec![
+                import("i1", ".auth.login", "core/__init__.py", "login"),
+                import("i2", ".auth._hash", "core/__init__.py", "_hash"),
+            ],
+            vec![],
+        );
+        // from core import login as signin; import os
+        let app_init = IRDocument::new(
+            "app/__init__.py".to_string(),
+            vec![
+                import("i3", "core.login", "app/__init__.py", "signin"),
+                import("i4", "os", "app/__init__.py", "os"),
+            ],
+            vec![],
+        );
+
+        let index = SymbolIndex::build_from_irs(&[auth, core_init, app_init]);
+        let aliases = index.reexports_to_hashmap();
+        assert_eq!(aliases.len(), 2);
+        assert_eq!(aliases["core.login"].target_fqn, "core.auth.login");
+        assert_eq!(aliases["app.signin"].target_fqn, "core.login");
+        assert_eq!(index.canonical_fqn("app.signin"), "core.auth.login");
+        assert_eq!(index.canonical_fqn("core.login"), "core.auth.login");
+        assert_eq!(index.canonical_fqn("core.auth.login"), "core.auth.login");
+
+        // Removing the defining file drops its aliases
+        index.remove_file("core/auth.py");
+        assert!(index.resolve_reexport("core.login").is_none());
+        assert!(index.resolve_reexport("app.signin").is_none());
+    }
 }
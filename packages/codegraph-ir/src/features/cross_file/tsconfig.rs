@@ -0,0 +1,432 @@
+//! TypeScript module resolution
+//!
+//! Maps import specifiers to files using `tsconfig.json` / `jsconfig.json`
+//! `compilerOptions.baseUrl` and `paths` (relative `extends` chains are
+//! followed). TS/JS import targets are written `specifier#name`:
+//! `import { a as b } from '@/lib'` → `@/lib#a` with alias `b`; `default`
+//! and `*` (namespace, `export *`) are names too.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use walkdir::WalkDir;
+
+use crate::shared::models::{CodegraphError, Result};
+
+/// Separator between specifier and imported name in TS/JS import targets
+pub const TS_IMPORT_SEPARATOR: char = '#';
+
+/// `extends` hops followed before giving up (cycles)
+const MAX_EXTENDS_DEPTH: usize = 8;
+
+/// Extensions tried for a specifier without one, in TypeScript's order
+const TS_EXTENSIONS: &[&str] = &[".ts", ".tsx", ".d.ts", ".js", ".jsx", ".mjs", ".cjs"];
+
+/// Compiler options of one tsconfig that affect module resolution
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TsConfig {
+    /// Directory containing the config; it governs the files below it
+    pub dir: String,
+    /// `compilerOptions.baseUrl`, resolved against `dir`
+    pub base_url: Option<String>,
+    /// `compilerOptions.paths` in declaration order, targets resolved
+    /// against `baseUrl` (or the directory of the declaring config)
+    pub paths: Vec<(String, Vec<String>)>,
+}
+
+/// `compilerOptions` of a single file, before `extends` is applied
+struct RawConfig {
+    extends: Option<String>,
+    base_url: Option<String>,
+    /// (declaring directory, raw entries)
+    paths: Option<(String, Vec<(String, Vec<String>)>)>,
+}
+
+impl TsConfig {
+    /// Parse tsconfig JSON (comments and trailing commas allowed) found in
+    /// `dir`; `extends` is ignored
+    pub fn parse(json: &str, dir: &str) -> Result<Self> {
+        let raw = parse_raw(json, dir)?;
+        Ok(Self::from_raw(dir, raw.base_url, raw.paths))
+    }
+
+    /// Load the config at `path`, following relative `extends`
+    pub fn load(path: &Path) -> Result<Self> {
+        let dir = parent_dir(&path.to_string_lossy());
+        let raw = load_raw(path, 0)?;
+        Ok(Self::from_raw(&dir, raw.base_url, raw.paths))
+    }
+
+    /// Configs of every `tsconfig.json` (or `jsconfig.json` without a
+    /// sibling tsconfig) under `repo_root`, `node_modules` excluded
+    ///
+    /// Paths are relative to `repo_root`, like the pipeline's file paths.
+    pub fn discover(repo_root: &Path) -> Vec<Self> {
+        let root = normalize_path(&repo_root.to_string_lossy());
+        let mut found: HashMap<String, std::path::PathBuf> = HashMap::new();
+        let entries = WalkDir::new(repo_root).into_iter().filter_entry(|e| {
+            let name = e.file_name().to_string_lossy();
+            e.depth() == 0 || !(name == "node_modules" || name.starts_with('.'))
+        });
+        for entry in entries.filter_map(|e| e.ok()) {
+            let name = entry.file_name().to_string_lossy();
+            if name != "tsconfig.json" && name != "jsconfig.json" {
+                continue;
+            }
+            let dir = parent_dir(&entry.path().to_string_lossy());
+            if name == "tsconfig.json" || !found.contains_key(&dir) {
+                found.insert(dir, entry.path().to_path_buf());
+            }
+        }
+
+        let mut configs: Vec<Self> = found
+            .into_values()
+            .filter_map(|path| match Self::load(&path) {
+                Ok(config) => Some(config.relative_to(&root)),
+                Err(e) => {
+                    tracing::warn!("[tsconfig] Skipping {}: {}", path.display(), e);
+                    None
+                }
+            })
+            .collect();
+        configs.sort_by(|a, b| a.dir.cmp(&b.dir));
+        configs
+    }
+
+    fn from_raw(
+        dir: &str,
+        base_url: Option<String>,
+        paths: Option<(String, Vec<(String, Vec<String>)>)>,
+    ) -> Self {
+        let paths = paths
+            .map(|(declared_in, entries)| {
+                let base = base_url.clone().unwrap_or(declared_in);
+                entries
+                    .into_iter()
+                    .map(|(pattern, targets)| {
+                        let targets = targets.iter().map(|t| join_path(&base, t)).collect();
+                        (pattern, targets)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            dir: normalize_path(dir),
+            base_url,
+            paths,
+        }
+    }
+
+    /// Strip the `root` directory from all paths
+    fn relative_to(self, root: &str) -> Self {
+        if root.is_empty() {
+            return self;
+        }
+        let strip = |path: String| match path.strip_prefix(root) {
+            Some("") => String::new(),
+            Some(rest) if rest.starts_with('/') => rest[1..].to_string(),
+            _ => path,
+        };
+        Self {
+            dir: strip(self.dir),
+            base_url: self.base_url.map(strip),
+            paths: self
+                .paths
+                .into_iter()
+                .map(|(pattern, targets)| (pattern, targets.into_iter().map(strip).collect()))
+                .collect(),
+        }
+    }
+
+    /// Whether `file` lies under this config's directory
+    pub fn governs(&self, file: &str) -> bool {
+        let file = normalize_path(file);
+        self.dir.is_empty()
+            || file
+                .strip_prefix(self.dir.as_str())
+                .is_some_and(|rest| rest.starts_with('/'))
+    }
+
+    /// Paths (without extension) a non-relative `specifier` may name
+    ///
+    /// The `paths` pattern with the longest prefix wins; `baseUrl` comes
+    /// after its targets.
+    pub fn module_candidates(&self, specifier: &str) -> Vec<String> {
+        let mut best: Option<(usize, &str, &[String])> = None;
+        for (pattern, targets) in &self.paths {
+            let matched = match pattern.split_once('*') {
+                None => (pattern == specifier).then_some((pattern.len(), "")),
+                Some((prefix, suffix)) => specifier
+                    .strip_prefix(prefix)
+                    .and_then(|rest| rest.strip_suffix(suffix))
+                    .map(|star| (prefix.len(), star)),
+            };
+            if let Some((len, star)) = matched {
+                if best.map_or(true, |(best_len, _, _)| len > best_len) {
+                    best = Some((len, star, targets.as_slice()));
+                }
+            }
+        }
+
+        let mut candidates: Vec<String> = best
+            .map(|(_, star, targets)| targets.iter().map(|t| t.replace('*', star)).collect())
+            .unwrap_or_default();
+        if let Some(base_url) = &self.base_url {
+            candidates.push(join_path(base_url, specifier));
+        }
+        candidates
+    }
+}
+
+/// Config governing `file`: the one with the deepest directory
+pub fn nearest_ts_config<'a>(configs: &'a [TsConfig], file: &str) -> Option<&'a TsConfig> {
+    configs
+        .iter()
+        .filter(|c| c.governs(file))
+        .max_by_key(|c| c.dir.len())
+}
+
+/// Split a TS/JS import target into specifier and imported name
+///
+/// Targets without a name (side-effect imports) import the module (`*`).
+pub fn split_ts_import(target: &str) -> (&str, &str) {
+    match target.rsplit_once(TS_IMPORT_SEPARATOR) {
+        Some((specifier, name)) if !specifier.is_empty() && !name.is_empty() => (specifier, name),
+        _ => (target, "*"),
+    }
+}
+
+/// Whether `file_path` is resolved with TypeScript module rules
+pub fn is_ts_module_file(file_path: &str) -> bool {
+    [".ts", ".tsx", ".mts", ".cts", ".js", ".jsx", ".mjs", ".cjs"]
+        .iter()
+        .any(|ext| file_path.ends_with(ext))
+}
+
+/// Whether `specifier` is relative to the importing file
+pub fn is_relative_specifier(specifier: &str) -> bool {
+    specifier == "."
+        || specifier == ".."
+        || specifier.starts_with("./")
+        || specifier.starts_with("../")
+}
+
+/// Files (normalized) a module path may resolve to, in lookup order
+///
+/// `./a.js` also tries `a.ts`/`a.tsx` (ESM TypeScript imports name the
+/// emitted file).
+pub fn module_file_candidates(module: &str) -> Vec<String> {
+    let module = normalize_path(module);
+    let mut candidates = vec![module.clone()];
+    let stem = [".js", ".jsx", ".mjs", ".cjs"]
+        .iter()
+        .find_map(|ext| module.strip_suffix(ext));
+    if let Some(stem) = stem {
+        candidates.push(format!("{}.ts", stem));
+        candidates.push(format!("{}.tsx", stem));
+    }
+    candidates.extend(TS_EXTENSIONS.iter().map(|ext| format!("{}{}", module, ext)));
+    candidates.extend(
+        TS_EXTENSIONS
+            .iter()
+            .map(|ext| format!("{}/index{}", module, ext)),
+    );
+    candidates
+}
+
+/// Lexically normalize `/`-separated `path` (drops `.`, folds `..`)
+pub fn normalize_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    let absolute = path.starts_with('/');
+    let mut parts: Vec<&str> = Vec::new();
+    for part in path.split('/') {
+        match part {
+            "" | "." => {}
+            ".." if parts.last().is_some_and(|p| *p != "..") => {
+                parts.pop();
+            }
+            ".." if absolute => {}
+            _ => parts.push(part),
+        }
+    }
+    let joined = parts.join("/");
+    if absolute {
+        format!("/{}", joined)
+    } else {
+        joined
+    }
+}
+
+/// Directory part of a file path ("" at the top level)
+pub fn parent_dir(file: &str) -> String {
+    let file = file.replace('\\', "/");
+    file.rsplit_once('/')
+        .map(|(dir, _)| dir.to_string())
+        .unwrap_or_default()
+}
+
+/// `relative` resolved against directory `dir`, normalized
+pub fn join_path(dir: &str, relative: &str) -> String {
+    if dir.is_empty() || relative.starts_with('/') {
+        normalize_path(relative)
+    } else {
+        normalize_path(&format!("{}/{}", dir, relative))
+    }
+}
+
+fn load_raw(path: &Path, depth: usize) -> Result<RawConfig> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| CodegraphError::io(format!("{}: {}", path.display(), e)))?;
+    let dir = parent_dir(&path.to_string_lossy());
+    let mut raw = parse_raw(&text, &dir)?;
+
+    // Package configs (`@tsconfig/node18`) live in node_modules: ignored
+    let Some(extends) = raw.extends.take().filter(|e| is_relative_specifier(e)) else {
+        return Ok(raw);
+    };
+    if depth >= MAX_EXTENDS_DEPTH {
+        return Err(CodegraphError::config(format!(
+            "{}: extends chain deeper than {}",
+            path.display(),
+            MAX_EXTENDS_DEPTH
+        )));
+    }
+    let mut parent_path = join_path(&dir, &extends);
+    if !parent_path.ends_with(".json") {
+        parent_path.push_str(".json");
+    }
+    let parent = load_raw(Path::new(&parent_path), depth + 1)?;
+    Ok(RawConfig {
+        extends: None,
+        base_url: raw.base_url.or(parent.base_url),
+        paths: raw.paths.or(parent.paths),
+    })
+}
+
+fn parse_raw(json: &str, dir: &str) -> Result<RawConfig> {
+    let value: serde_json::Value = serde_json::from_str(&strip_jsonc(json))
+        .map_err(|e| CodegraphError::config(format!("invalid tsconfig: {}", e)))?;
+    let options = value.get("compilerOptions");
+    let base_url = options
+        .and_then(|o| o.get("baseUrl"))
+        .and_then(|b| b.as_str())
+        .map(|b| join_path(dir, b));
+    let paths = options
+        .and_then(|o| o.get("paths"))
+        .and_then(|p| p.as_object())
+        .map(|paths| {
+            let entries = paths
+                .iter()
+                .map(|(pattern, targets)| {
+                    let targets = targets
+                        .as_array()
+                        .map(|t| {
+                            t.iter()
+                                .filter_map(|t| t.as_str().map(String::from))
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    (pattern.clone(), targets)
+                })
+                .collect();
+            (dir.to_string(), entries)
+        });
+    Ok(RawConfig {
+        extends: value
+            .get("extends")
+            .and_then(|e| e.as_str())
+            .map(String::from),
+        base_url,
+        paths,
+    })
+}
+
+/// Drop `//` and `/* */` comments and trailing commas outside strings
+fn strip_jsonc(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut in_string = false;
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            match c {
+                '\\' => out.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match (c, chars.peek().copied()) {
+            ('"', _) => {
+                in_string = true;
+                out.push(c);
+            }
+            ('/', Some('/')) => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            (',', _) => {
+                // Trailing comma: next significant char closes the container
+                let rest = chars.clone().find(|c| !c.is_whitespace());
+                if !matches!(rest, Some('}') | Some(']')) {
+                    out.push(c);
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paths_and_base_url() {
+        let json = r#"{
+            // comments and trailing commas are allowed
+            "compilerOptions": {
+                "baseUrl": "./src",
+                "paths": {
+                    "@/*": ["*"],
+                    "@ui/*": ["components/ui/*", "legacy/ui/*"],
+                    "config": ["../config/index.ts"], /* exact */
+                },
+            },
+        }"#;
+        let config = TsConfig::parse(json, "web").unwrap();
+        assert_eq!(config.base_url.as_deref(), Some("web/src"));
+        assert_eq!(
+            config.module_candidates("@ui/button"),
+            vec![
+                "web/src/components/ui/button",
+                "web/src/legacy/ui/button",
+                "web/src/@ui/button"
+            ]
+        );
+        assert_eq!(config.module_candidates("@/lib/api")[0], "web/src/lib/api");
+        assert_eq!(config.module_candidates("config")[0], "web/config/index.ts");
+        assert!(config.governs("web/src/app.ts"));
+        assert!(!config.governs("website/app.ts"));
+
+        assert_eq!(split_ts_import("@/lib#fetch"), ("@/lib", "fetch"));
+        assert_eq!(split_ts_import("./polyfill"), ("./polyfill", "*"));
+        assert_eq!(normalize_path("./web/src/../lib/a.ts"), "web/lib/a.ts");
+        assert!(module_file_candidates("src/a.js").contains(&"src/a.ts".to_string()));
+    }
+}
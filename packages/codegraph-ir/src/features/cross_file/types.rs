@@ -87,6 +87,36 @@ impl Symbol {
     }
 }
 
+/// Symbol re-exported under another FQN
+///
+/// `from core.auth import login` in `core/__init__.py` makes `core.login`
+/// an alias of `core.auth.login`. Chains of re-exports (`app` re-exporting
+/// `core.login`) share the canonical FQN of the defining symbol.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolAlias {
+    /// Re-exported FQN (e.g. `core.login`)
+    pub alias_fqn: String,
+    /// FQN named by the re-exporting import (one alias edge)
+    pub target_fqn: String,
+    /// FQN of the defining symbol
+    pub canonical_fqn: String,
+    /// File containing the re-export
+    pub file_path: String,
+    /// Node ID of the re-exporting import
+    pub node_id: String,
+}
+
+/// TS/JS re-export (`export { a as b } from './x'`, `export * from './x'`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TsReexport {
+    /// Name exported by the re-exporting file (`b`; `*` for `export *`)
+    pub exported: String,
+    /// Module specifier as written (`./x`)
+    pub specifier: String,
+    /// Name imported from the specifier (`a`; `*` for the whole module)
+    pub imported: String,
+}
+
 /// Symbol visibility
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Visibility {
@@ -124,6 +154,19 @@ pub struct ResolvedImport {
 
     /// Resolution method used
     pub resolution_method: ResolutionMethod,
+
+    /// Confidence that `resolved_fqn` is the real target (0.0-1.0)
+    #[serde(default = "default_confidence")]
+    pub confidence: f32,
+
+    /// File whose re-export the import went through (`from core import login`
+    /// → `core/__init__.py`)
+    #[serde(default)]
+    pub reexport_file: Option<String>,
+}
+
+fn default_confidence() -> f32 {
+    1.0
 }
 
 impl ResolvedImport {
@@ -142,6 +185,8 @@ impl ResolvedImport {
             is_external: false,
             alias: None,
             resolution_method: method,
+            confidence: method.confidence(),
+            reexport_file: None,
         }
     }
 
@@ -154,6 +199,8 @@ impl ResolvedImport {
             is_external: true,
             alias: None,
             resolution_method: ResolutionMethod::NotFound,
+            confidence: ResolutionMethod::NotFound.confidence(),
+            reexport_file: None,
         }
     }
 
@@ -161,6 +208,17 @@ impl ResolvedImport {
         self.alias = Some(alias);
         self
     }
+
+    pub fn with_reexport_file(mut self, file_path: String) -> Self {
+        self.reexport_file = Some(file_path);
+        self
+    }
+
+    /// Override the method-derived confidence (clamped to [0.0, 1.0])
+    pub fn with_confidence(mut self, confidence: f32) -> Self {
+        self.confidence = confidence.clamp(0.0, 1.0);
+        self
+    }
 }
 
 /// Method used to resolve an import
@@ -176,6 +234,23 @@ pub enum ResolutionMethod {
     NotFound,
 }
 
+impl ResolutionMethod {
+    /// Heuristic confidence of a resolution made with this method
+    ///
+    /// - ExactMatch: 1.0 (FQN matched a known symbol)
+    /// - ModulePath: 0.8 (file matched, symbol assumed)
+    /// - PartialMatch: 0.6 (only a prefix matched)
+    /// - NotFound: 0.0
+    pub fn confidence(&self) -> f32 {
+        match self {
+            ResolutionMethod::ExactMatch => 1.0,
+            ResolutionMethod::ModulePath => 0.8,
+            ResolutionMethod::PartialMatch => 0.6,
+            ResolutionMethod::NotFound => 0.0,
+        }
+    }
+}
+
 /// Import statement from IR
 #[derive(Debug, Clone)]
 pub struct ImportInfo {
@@ -193,6 +268,9 @@ pub struct ImportInfo {
 
     /// Import alias
     pub alias: Option<String>,
+
+    /// Module path of the importing file, for relative imports
+    pub module_path: Option<String>,
 }
 
 /// Statistics for cross-file resolution
@@ -245,5 +323,22 @@ mod tests {
 
         assert!(unresolved.is_external);
         assert_eq!(unresolved.resolution_method, ResolutionMethod::NotFound);
+        assert_eq!(unresolved.confidence, 0.0);
+    }
+
+    #[test]
+    fn test_resolved_import_confidence() {
+        let partial = ResolvedImport::resolved(
+            "pkg.mod.Thing".to_string(),
+            "pkg.mod".to_string(),
+            "pkg/mod.py".to_string(),
+            "node_1".to_string(),
+            ResolutionMethod::PartialMatch,
+        );
+        assert_eq!(partial.confidence, 0.6);
+        assert!(partial.confidence < ResolutionMethod::ExactMatch.confidence());
+
+        let overridden = partial.with_confidence(2.0);
+        assert_eq!(overridden.confidence, 1.0);
     }
 }
@@ -0,0 +1,681 @@
+//! Stratified semi-naive Datalog evaluation
+//!
+//! Rules are grouped into strata by the strongly connected components of
+//! the relation dependency graph; a negated relation must be complete
+//! before its stratum runs. Within a stratum every round joins one body
+//! atom against the previous round's new tuples (the delta) and the rest
+//! against the full relations. Joins probe hash indexes on the columns
+//! bound at that point of the rule body.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use petgraph::algo::tarjan_scc;
+use petgraph::graph::{DiGraph, NodeIndex};
+use regex::Regex;
+use serde::Serialize;
+
+use super::ir_facts::ir_database;
+use crate::features::datalog::domain::{
+    Builtin, CompareOp, Database, Literal, Program, Rule, Symbol, Term,
+};
+use crate::features::ir_generation::domain::ir_document::IRDocument;
+use crate::features::taint_analysis::infrastructure::TaintAnalyzer;
+use crate::shared::models::{CodegraphError, Result};
+
+type Tuple = Box<[Symbol]>;
+
+/// Output relations of one run
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct DatalogResult {
+    pub relations: BTreeMap<String, Vec<Vec<String>>>,
+}
+
+/// Evaluates programs over a base database (e.g. the IR relations)
+#[derive(Debug, Clone, Default)]
+pub struct DatalogEngine {
+    base: Database,
+}
+
+impl DatalogEngine {
+    pub fn new(base: Database) -> Self {
+        Self { base }
+    }
+
+    /// IR relations with the default taint sources/sinks
+    pub fn from_ir(ir_doc: &IRDocument) -> Self {
+        Self::new(ir_database(ir_doc, &TaintAnalyzer::new()))
+    }
+
+    /// IR relations with custom taint sources/sinks
+    pub fn from_ir_with_taint(ir_doc: &IRDocument, taint: &TaintAnalyzer) -> Self {
+        Self::new(ir_database(ir_doc, taint))
+    }
+
+    pub fn database(&self) -> &Database {
+        &self.base
+    }
+
+    /// Parse and run `source`
+    pub fn run_source(&self, source: &str) -> Result<DatalogResult> {
+        self.run(&Program::parse(source)?)
+    }
+
+    /// Output relations of `program`
+    pub fn run(&self, program: &Program) -> Result<DatalogResult> {
+        let db = self.evaluate(program)?;
+        let relations = program
+            .output_relations()
+            .into_iter()
+            .map(|name| match db.relation(name) {
+                Some(_) => Ok((name.to_string(), db.rows(name))),
+                None => Err(CodegraphError::parse(format!(
+                    "unknown output relation '{}'",
+                    name
+                ))),
+            })
+            .collect::<Result<_>>()?;
+        Ok(DatalogResult { relations })
+    }
+
+    /// Base database plus every relation `program` derives
+    pub fn evaluate(&self, program: &Program) -> Result<Database> {
+        let mut db = self.base.clone();
+        for fact in &program.facts {
+            let values: Vec<&str> = fact
+                .terms
+                .iter()
+                .map(|t| match t {
+                    Term::Const(value) => value.as_str(),
+                    _ => "",
+                })
+                .collect();
+            db.insert(&fact.relation, &values)?;
+        }
+        for rule in &program.rules {
+            rule.validate()?;
+            db.declare(&rule.head.relation, rule.head.terms.len())?;
+        }
+        for rule in &program.rules {
+            for literal in &rule.body {
+                if let Literal::Positive(atom) | Literal::Negative(atom) = literal {
+                    if db.relation(&atom.relation).is_none() {
+                        return Err(CodegraphError::parse(format!(
+                            "unknown relation '{}' in rule for '{}'",
+                            atom.relation, rule.head.relation
+                        )));
+                    }
+                    db.declare(&atom.relation, atom.terms.len())?;
+                }
+            }
+        }
+
+        for stratum in stratify(&program.rules)? {
+            let rules: Vec<CompiledRule> = stratum
+                .rules
+                .iter()
+                .map(|rule| CompiledRule::compile(rule, &mut db))
+                .collect();
+            run_stratum(&mut db, &rules, &stratum.relations)?;
+        }
+        Ok(db)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Stratification
+// ═══════════════════════════════════════════════════════════════════════════
+
+struct Stratum<'p> {
+    relations: HashSet<String>,
+    rules: Vec<&'p Rule>,
+}
+
+/// Strata in evaluation order (dependencies first)
+fn stratify(rules: &[Rule]) -> Result<Vec<Stratum<'_>>> {
+    let mut graph = DiGraph::<&str, ()>::new();
+    let mut nodes: HashMap<&str, NodeIndex> = HashMap::new();
+    for rule in rules {
+        let head = rule.head.relation.as_str();
+        nodes.entry(head).or_insert_with(|| graph.add_node(head));
+    }
+    for rule in rules {
+        let head = nodes[rule.head.relation.as_str()];
+        for literal in &rule.body {
+            if let Literal::Positive(atom) | Literal::Negative(atom) = literal {
+                if let Some(&dep) = nodes.get(atom.relation.as_str()) {
+                    graph.add_edge(head, dep, ());
+                }
+            }
+        }
+    }
+
+    // Tarjan yields SCCs in reverse topological order: dependencies first
+    tarjan_scc(&graph)
+        .into_iter()
+        .map(|component| {
+            let relations: HashSet<String> =
+                component.iter().map(|&n| graph[n].to_string()).collect();
+            let rules: Vec<&Rule> = rules
+                .iter()
+                .filter(|r| relations.contains(&r.head.relation))
+                .collect();
+            for rule in &rules {
+                for literal in &rule.body {
+                    if let Literal::Negative(atom) = literal {
+                        if relations.contains(&atom.relation) {
+                            return Err(CodegraphError::parse(format!(
+                                "'{}' depends on the negation of '{}' recursively (not stratifiable)",
+                                rule.head.relation, atom.relation
+                            )));
+                        }
+                    }
+                }
+            }
+            Ok(Stratum { relations, rules })
+        })
+        .collect()
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Compiled rules and join plans
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Arg {
+    Var(usize),
+    Const(Symbol),
+    Wildcard,
+}
+
+enum BodyLiteral {
+    Positive {
+        relation: String,
+        args: Vec<Arg>,
+    },
+    Negative {
+        relation: String,
+        args: Vec<Arg>,
+    },
+    Compare {
+        left: Arg,
+        op: CompareOp,
+        right: Arg,
+    },
+    Builtin {
+        builtin: Builtin,
+        pattern: Arg,
+        value: Arg,
+    },
+}
+
+impl BodyLiteral {
+    fn vars(&self) -> Vec<usize> {
+        let args: Vec<Arg> = match self {
+            Self::Positive { args, .. } | Self::Negative { args, .. } => args.clone(),
+            Self::Compare { left, right, .. } => vec![*left, *right],
+            Self::Builtin { pattern, value, .. } => vec![*pattern, *value],
+        };
+        args.into_iter()
+            .filter_map(|a| match a {
+                Arg::Var(v) => Some(v),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+struct CompiledRule {
+    head_relation: String,
+    head: Vec<Arg>,
+    body: Vec<BodyLiteral>,
+    var_count: usize,
+}
+
+impl CompiledRule {
+    fn compile(rule: &Rule, db: &mut Database) -> Self {
+        let mut vars: HashMap<String, usize> = HashMap::new();
+        let mut arg = |term: &Term, db: &mut Database| match term {
+            Term::Var(name) => {
+                let next = vars.len();
+                Arg::Var(*vars.entry(name.clone()).or_insert(next))
+            }
+            Term::Const(value) => Arg::Const(db.symbols_mut().intern(value)),
+            Term::Wildcard => Arg::Wildcard,
+        };
+
+        let body = rule
+            .body
+            .iter()
+            .map(|literal| match literal {
+                Literal::Positive(atom) => BodyLiteral::Positive {
+                    relation: atom.relation.clone(),
+                    args: atom.terms.iter().map(|t| arg(t, db)).collect(),
+                },
+                Literal::Negative(atom) => BodyLiteral::Negative {
+                    relation: atom.relation.clone(),
+                    args: atom.terms.iter().map(|t| arg(t, db)).collect(),
+                },
+                Literal::Compare { left, op, right } => BodyLiteral::Compare {
+                    left: arg(left, db),
+                    op: *op,
+                    right: arg(right, db),
+                },
+                Literal::Builtin {
+                    builtin,
+                    pattern,
+                    value,
+                } => BodyLiteral::Builtin {
+                    builtin: *builtin,
+                    pattern: arg(pattern, db),
+                    value: arg(value, db),
+                },
+            })
+            .collect();
+        let head = rule.head.terms.iter().map(|t| arg(t, db)).collect();
+        Self {
+            head_relation: rule.head.relation.clone(),
+            head,
+            body,
+            var_count: vars.len(),
+        }
+    }
+}
+
+/// Hash index: values at `positions` → tuple offsets
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct IndexKey {
+    relation: String,
+    delta: bool,
+    positions: Vec<usize>,
+}
+
+type Index = HashMap<Vec<Symbol>, Vec<usize>>;
+
+enum Step<'r> {
+    Scan {
+        key: IndexKey,
+        /// Argument per key position
+        probe: Vec<Arg>,
+        /// (column, var) bound by this scan
+        binds: Vec<(usize, usize)>,
+        /// (column, var) repeated within the atom
+        checks: Vec<(usize, usize)>,
+    },
+    Absent {
+        key: IndexKey,
+        probe: Vec<Arg>,
+    },
+    Filter(&'r BodyLiteral),
+}
+
+struct Plan<'r> {
+    rule: &'r CompiledRule,
+    steps: Vec<Step<'r>>,
+}
+
+impl<'r> Plan<'r> {
+    /// Join order: the `delta` atom first, then positive atoms in source
+    /// order; each filter runs as soon as its variables are bound
+    fn new(rule: &'r CompiledRule, delta: Option<usize>) -> Self {
+        let mut positives: Vec<usize> = (0..rule.body.len())
+            .filter(|&i| matches!(rule.body[i], BodyLiteral::Positive { .. }))
+            .collect();
+        if let Some(d) = delta {
+            positives.retain(|&i| i != d);
+            positives.insert(0, d);
+        }
+        let mut pending: Vec<&BodyLiteral> = rule
+            .body
+            .iter()
+            .filter(|l| !matches!(l, BodyLiteral::Positive { .. }))
+            .collect();
+
+        let mut bound = vec![false; rule.var_count];
+        let mut steps = Vec::new();
+        let flush =
+            |bound: &[bool], pending: &mut Vec<&'r BodyLiteral>, steps: &mut Vec<Step<'r>>| {
+                pending.retain(|literal| {
+                    if !literal.vars().iter().all(|&v| bound[v]) {
+                        return true;
+                    }
+                    steps.push(match literal {
+                        BodyLiteral::Negative { relation, args } => {
+                            let positions: Vec<usize> = (0..args.len())
+                                .filter(|&i| args[i] != Arg::Wildcard)
+                                .collect();
+                            Step::Absent {
+                                probe: positions.iter().map(|&i| args[i]).collect(),
+                                key: IndexKey {
+                                    relation: relation.clone(),
+                                    delta: false,
+                                    positions,
+                                },
+                            }
+                        }
+                        _ => Step::Filter(*literal),
+                    });
+                    false
+                });
+            };
+
+        flush(&bound, &mut pending, &mut steps);
+        for i in positives {
+            let BodyLiteral::Positive { relation, args } = &rule.body[i] else {
+                continue;
+            };
+            let mut positions = Vec::new();
+            let mut binds = Vec::new();
+            let mut checks = Vec::new();
+            let mut seen = HashSet::new();
+            for (column, arg) in args.iter().enumerate() {
+                match *arg {
+                    Arg::Const(_) => positions.push(column),
+                    Arg::Var(v) if bound[v] => positions.push(column),
+                    Arg::Var(v) if !seen.insert(v) => checks.push((column, v)),
+                    Arg::Var(v) => binds.push((column, v)),
+                    Arg::Wildcard => {}
+                }
+            }
+            for &(_, v) in &binds {
+                bound[v] = true;
+            }
+            steps.push(Step::Scan {
+                probe: positions.iter().map(|&c| args[c]).collect(),
+                key: IndexKey {
+                    relation: relation.clone(),
+                    delta: delta == Some(i),
+                    positions,
+                },
+                binds,
+                checks,
+            });
+            flush(&bound, &mut pending, &mut steps);
+        }
+        Self { rule, steps }
+    }
+
+    fn index_keys(&self) -> impl Iterator<Item = &IndexKey> {
+        self.steps.iter().filter_map(|step| match step {
+            Step::Scan { key, .. } | Step::Absent { key, .. } if !key.positions.is_empty() => {
+                Some(key)
+            }
+            _ => None,
+        })
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Execution
+// ═══════════════════════════════════════════════════════════════════════════
+
+struct Round<'a> {
+    db: &'a Database,
+    delta: &'a HashMap<String, Vec<Tuple>>,
+    indexes: HashMap<&'a IndexKey, Index>,
+    /// `match` patterns by symbol
+    regexes: RefCell<HashMap<Symbol, Regex>>,
+}
+
+impl<'a> Round<'a> {
+    fn source(&self, relation: &str, delta: bool) -> &'a [Tuple] {
+        let (db, deltas) = (self.db, self.delta);
+        if delta {
+            deltas.get(relation).map(Vec::as_slice).unwrap_or(&[])
+        } else {
+            db.relation(relation).map(|r| r.tuples()).unwrap_or(&[])
+        }
+    }
+
+    fn build_index(&mut self, key: &'a IndexKey) {
+        if self.indexes.contains_key(key) {
+            return;
+        }
+        let mut index = Index::new();
+        for (offset, tuple) in self.source(&key.relation, key.delta).iter().enumerate() {
+            let values = key.positions.iter().map(|&p| tuple[p]).collect();
+            index.entry(values).or_default().push(offset);
+        }
+        self.indexes.insert(key, index);
+    }
+
+    fn run(
+        &self,
+        plan: &Plan<'_>,
+        step: usize,
+        binding: &mut Vec<Symbol>,
+        out: &mut Vec<(String, Tuple)>,
+    ) -> Result<()> {
+        let Some(current) = plan.steps.get(step) else {
+            let tuple = plan.rule.head.iter().map(|&a| value(a, binding)).collect();
+            out.push((plan.rule.head_relation.clone(), tuple));
+            return Ok(());
+        };
+
+        match current {
+            Step::Scan {
+                key,
+                probe,
+                binds,
+                checks,
+            } => {
+                let source = self.source(&key.relation, key.delta);
+                let all: Vec<usize>;
+                let offsets: &[usize] = if key.positions.is_empty() {
+                    all = (0..source.len()).collect();
+                    &all
+                } else {
+                    let values: Vec<Symbol> = probe.iter().map(|&a| value(a, binding)).collect();
+                    self.indexes
+                        .get(key)
+                        .and_then(|index| index.get(&values))
+                        .map(Vec::as_slice)
+                        .unwrap_or(&[])
+                };
+                for &offset in offsets {
+                    let tuple = &source[offset];
+                    for &(column, var) in binds {
+                        binding[var] = tuple[column];
+                    }
+                    if checks
+                        .iter()
+                        .all(|&(column, var)| binding[var] == tuple[column])
+                    {
+                        self.run(plan, step + 1, binding, out)?;
+                    }
+                }
+                Ok(())
+            }
+            Step::Absent { key, probe } => {
+                let values: Vec<Symbol> = probe.iter().map(|&a| value(a, binding)).collect();
+                let present = if key.positions.is_empty() {
+                    !self.source(&key.relation, false).is_empty()
+                } else {
+                    self.indexes
+                        .get(key)
+                        .is_some_and(|index| index.contains_key(&values))
+                };
+                if present {
+                    Ok(())
+                } else {
+                    self.run(plan, step + 1, binding, out)
+                }
+            }
+            Step::Filter(literal) => {
+                if self.test(literal, binding)? {
+                    self.run(plan, step + 1, binding, out)
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    fn test(&self, literal: &BodyLiteral, binding: &[Symbol]) -> Result<bool> {
+        match literal {
+            BodyLiteral::Compare { left, op, right } => {
+                let equal = value(*left, binding) == value(*right, binding);
+                Ok(equal == (*op == CompareOp::Eq))
+            }
+            BodyLiteral::Builtin {
+                builtin,
+                pattern,
+                value: text,
+            } => {
+                let symbols = self.db.symbols();
+                let pattern = value(*pattern, binding);
+                let text = symbols.resolve(value(*text, binding));
+                match builtin {
+                    Builtin::Contains => Ok(text.contains(symbols.resolve(pattern))),
+                    Builtin::Match => {
+                        let mut regexes = self.regexes.borrow_mut();
+                        if !regexes.contains_key(&pattern) {
+                            let source = symbols.resolve(pattern);
+                            let regex = Regex::new(&format!("^(?:{})$", source)).map_err(|e| {
+                                CodegraphError::parse(format!(
+                                    "invalid match pattern {:?}: {}",
+                                    source, e
+                                ))
+                            })?;
+                            regexes.insert(pattern, regex);
+                        }
+                        Ok(regexes[&pattern].is_match(text))
+                    }
+                }
+            }
+            _ => Ok(true),
+        }
+    }
+}
+
+fn value(arg: Arg, binding: &[Symbol]) -> Symbol {
+    match arg {
+        Arg::Var(v) => binding[v],
+        Arg::Const(c) => c,
+        // Head and filter wildcards are rejected by `Rule::validate`
+        Arg::Wildcard => Symbol::MAX,
+    }
+}
+
+/// Evaluate `plans` against `db` and the current delta
+fn execute(
+    db: &Database,
+    delta: &HashMap<String, Vec<Tuple>>,
+    plans: &[Plan<'_>],
+) -> Result<Vec<(String, Tuple)>> {
+    let mut round = Round {
+        db,
+        delta,
+        indexes: HashMap::new(),
+        regexes: RefCell::new(HashMap::new()),
+    };
+    for plan in plans {
+        for key in plan.index_keys() {
+            round.build_index(key);
+        }
+    }
+
+    let mut out = Vec::new();
+    for plan in plans {
+        let mut binding = vec![Symbol::MAX; plan.rule.var_count];
+        round.run(plan, 0, &mut binding, &mut out)?;
+    }
+    Ok(out)
+}
+
+fn run_stratum(
+    db: &mut Database,
+    rules: &[CompiledRule],
+    relations: &HashSet<String>,
+) -> Result<()> {
+    let insert = |db: &mut Database, derived: Vec<(String, Tuple)>| {
+        let mut delta: HashMap<String, Vec<Tuple>> = HashMap::new();
+        for (relation, tuple) in derived {
+            if db.insert_symbols(&relation, tuple.clone()) {
+                delta.entry(relation).or_default().push(tuple);
+            }
+        }
+        delta
+    };
+
+    let plans: Vec<Plan> = rules.iter().map(|rule| Plan::new(rule, None)).collect();
+    let derived = execute(db, &HashMap::new(), &plans)?;
+    let mut delta = insert(db, derived);
+
+    // Recursive atoms: (rule, body position) over this stratum's relations
+    let recursive: Vec<(&CompiledRule, usize)> = rules
+        .iter()
+        .flat_map(|rule| {
+            rule.body
+                .iter()
+                .enumerate()
+                .filter(|(_, l)| {
+                    matches!(l, BodyLiteral::Positive { relation, .. } if relations.contains(relation))
+                })
+                .map(move |(i, _)| (rule, i))
+        })
+        .collect();
+
+    while !delta.is_empty() && !recursive.is_empty() {
+        let plans: Vec<Plan> = recursive
+            .iter()
+            .filter(|(rule, i)| match &rule.body[*i] {
+                BodyLiteral::Positive { relation, .. } => delta.contains_key(relation),
+                _ => false,
+            })
+            .map(|&(rule, i)| Plan::new(rule, Some(i)))
+            .collect();
+        let derived = execute(db, &delta, &plans)?;
+        delta = insert(db, derived);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recursion_negation_and_builtins() {
+        let mut db = Database::new();
+        for (caller, callee) in [
+            ("main", "handler"),
+            ("handler", "query"),
+            ("query", "execute"),
+        ] {
+            db.insert("calls", &[caller, callee]).unwrap();
+        }
+        db.insert("calls", &["audit", "execute"]).unwrap();
+        db.insert("sanitizer", &["query"]).unwrap();
+
+        let result = DatalogEngine::new(db)
+            .run_source(
+                r#"
+                // Transitive closure
+                reaches(A, B) :- calls(A, B).
+                reaches(A, C) :- reaches(A, B), calls(B, C).
+
+                entry("main").
+                unsafe_path(E, S) :- entry(E), reaches(E, S), S = "execute",
+                                     !guarded(E).
+                guarded(E) :- entry(E), reaches(E, X), sanitizer(X).
+                direct(A) :- calls(A, S), match("exec.*", S), contains("u", A), !sanitizer(A).
+
+                .output reaches
+                .output unsafe_path
+                .output direct
+                "#,
+            )
+            .unwrap();
+
+        assert_eq!(result.relations["reaches"].len(), 7);
+        assert!(result.relations["unsafe_path"].is_empty());
+        assert_eq!(result.relations["direct"], vec![vec!["audit".to_string()]]);
+
+        // Stratification and range restriction errors
+        let engine = DatalogEngine::default();
+        assert!(engine.run_source("p(X) :- q(X), !p(X). q(\"a\").").is_err());
+        assert!(engine.run_source("p(X, Y) :- q(X). q(\"a\").").is_err());
+        assert!(engine.run_source("p(X) :- missing(X).").is_err());
+    }
+}
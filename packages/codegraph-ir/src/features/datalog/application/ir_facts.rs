@@ -0,0 +1,181 @@
+//! IR → base relations
+//!
+//! | relation                      | tuples                                  |
+//! |-------------------------------|-----------------------------------------|
+//! | `node(Id, Kind, Name, Fqn, File)` | every node (`Name` is `""` when unset) |
+//! | `edge(Src, Dst, Kind)`        | every edge as stored (`Kind` = `CALLS`) |
+//! | `contains(Parent, Child)`     | `CONTAINS` edges                        |
+//! | `calls(Caller, Callee)`       | `CALLS` / `INVOKES` edges               |
+//! | `reads(Fn, Var)` / `writes(Fn, Var)` | `READS` / `WRITES` edges         |
+//! | `inherits(Class, Base)`       | `INHERITS` / `EXTENDS` / `IMPLEMENTS`   |
+//! | `imports(Src, Target)`        | `IMPORTS` edges                         |
+//! | `references(Src, Target)`     | `REFERENCES` edges                      |
+//! | `taint_source(Callee)`        | called targets matching a taint source  |
+//! | `taint_sink(Callee, Severity)`| called targets matching a taint sink    |
+//!
+//! Edge targets resolve to node IDs (by ID, then FQN); unresolved targets
+//! (library calls) keep the target text, so `taint_source("request.args.get")`
+//! style facts still join with `calls`.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::features::datalog::domain::{Database, Symbol};
+use crate::features::ir_generation::domain::ir_document::IRDocument;
+use crate::features::taint_analysis::infrastructure::TaintAnalyzer;
+use crate::shared::models::{EdgeKind, Node};
+
+/// Derived edge relations
+const EDGE_RELATIONS: &[(&str, &[EdgeKind])] = &[
+    ("contains", &[EdgeKind::Contains]),
+    ("calls", &[EdgeKind::Calls, EdgeKind::Invokes]),
+    ("reads", &[EdgeKind::Reads]),
+    ("writes", &[EdgeKind::Writes]),
+    (
+        "inherits",
+        &[EdgeKind::Inherits, EdgeKind::Extends, EdgeKind::Implements],
+    ),
+    ("imports", &[EdgeKind::Imports]),
+    ("references", &[EdgeKind::References]),
+];
+
+/// Base relations of `ir_doc`; `taint` supplies source/sink patterns
+pub fn ir_database(ir_doc: &IRDocument, taint: &TaintAnalyzer) -> Database {
+    let mut db = Database::new();
+    let schema = [
+        ("node", 5),
+        ("edge", 3),
+        ("taint_source", 1),
+        ("taint_sink", 2),
+    ];
+    for (name, arity) in schema
+        .into_iter()
+        .chain(EDGE_RELATIONS.iter().map(|(name, _)| (*name, 2)))
+    {
+        // Fresh database: arities cannot conflict
+        let _ = db.declare(name, arity);
+    }
+
+    let mut by_key: HashMap<&str, &Node> = HashMap::new();
+    for node in &ir_doc.nodes {
+        by_key.entry(node.fqn.as_str()).or_insert(node);
+    }
+    for node in &ir_doc.nodes {
+        by_key.insert(node.id.as_str(), node);
+        let row = [
+            node.id.as_str(),
+            node.kind.as_str(),
+            node.name.as_deref().unwrap_or(""),
+            node.fqn.as_str(),
+            node.file_path.as_str(),
+        ];
+        insert(&mut db, "node", &row);
+    }
+
+    let mut callees: HashSet<&str> = HashSet::new();
+    for edge in &ir_doc.edges {
+        insert(
+            &mut db,
+            "edge",
+            &[
+                edge.source_id.as_str(),
+                edge.target_id.as_str(),
+                edge.kind.as_str(),
+            ],
+        );
+        let target = by_key
+            .get(edge.target_id.as_str())
+            .map_or(edge.target_id.as_str(), |n| n.id.as_str());
+        for (name, kinds) in EDGE_RELATIONS {
+            if kinds.contains(&edge.kind) {
+                insert(&mut db, name, &[edge.source_id.as_str(), target]);
+            }
+        }
+        if matches!(edge.kind, EdgeKind::Calls | EdgeKind::Invokes) {
+            callees.insert(target);
+        }
+    }
+
+    for callee in callees {
+        let names: Vec<&str> = match by_key.get(callee) {
+            Some(node) => [Some(node.fqn.as_str()), node.name.as_deref()]
+                .into_iter()
+                .flatten()
+                .collect(),
+            None => vec![callee],
+        };
+        if taint
+            .get_sources()
+            .iter()
+            .any(|s| names.iter().any(|n| s.matches(n)))
+        {
+            insert(&mut db, "taint_source", &[callee]);
+        }
+        if let Some(sink) = taint
+            .get_sinks()
+            .iter()
+            .find(|s| names.iter().any(|n| s.matches(n)))
+        {
+            insert(&mut db, "taint_sink", &[callee, sink.severity.as_str()]);
+        }
+    }
+    db
+}
+
+fn insert(db: &mut Database, relation: &str, values: &[&str]) {
+    let tuple: Box<[Symbol]> = values.iter().map(|v| db.symbols_mut().intern(v)).collect();
+    db.insert_symbols(relation, tuple);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::datalog::DatalogEngine;
+    use crate::shared::models::{Edge, NodeKind, Span};
+
+    #[test]
+    fn test_custom_security_query_over_ir() {
+        let function = |id: &str, name: &str| {
+            let mut node = Node::new(
+                id.to_string(),
+                NodeKind::Function,
+                format!("app.views.{}", name),
+                "app/views.py".to_string(),
+                Span::new(1, 0, 2, 0),
+            );
+            node.name = Some(name.to_string());
+            node
+        };
+        let ir_doc = IRDocument {
+            file_path: "app/views.py".to_string(),
+            nodes: vec![function("f1", "search"), function("f2", "run_query")],
+            edges: vec![
+                Edge::new("f1".into(), "request.args.get".into(), EdgeKind::Calls),
+                Edge::new("f1".into(), "app.views.run_query".into(), EdgeKind::Calls),
+                Edge::new("f2".into(), "cursor.execute".into(), EdgeKind::Calls),
+            ],
+        };
+
+        let engine = DatalogEngine::from_ir(&ir_doc);
+        assert_eq!(engine.database().rows("calls")[1], vec!["f1", "f2"]);
+        let result = engine
+            .run_source(
+                r#"
+                reaches(F, G) :- calls(F, G).
+                reaches(F, H) :- reaches(F, G), calls(G, H).
+                reads_input(F) :- calls(F, S), taint_source(S).
+                vuln(F, Name, Sink) :- reads_input(F), reaches(F, Sink),
+                                       taint_sink(Sink, "high"), node(F, _, Name, _, _).
+                .output vuln
+                "#,
+            )
+            .unwrap();
+        assert_eq!(
+            result.relations["vuln"],
+            vec![vec![
+                "f1".to_string(),
+                "search".to_string(),
+                "cursor.execute".to_string()
+            ]]
+        );
+    }
+}
@@ -0,0 +1,7 @@
+//! Datalog Application Layer
+
+mod evaluator;
+mod ir_facts;
+
+pub use evaluator::{DatalogEngine, DatalogResult};
+pub use ir_facts::ir_database;
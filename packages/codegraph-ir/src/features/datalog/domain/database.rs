@@ -0,0 +1,168 @@
+//! Relations of interned string tuples
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::shared::models::{CodegraphError, Result};
+
+/// Interned constant
+pub type Symbol = u32;
+
+/// Constant ⇄ symbol table
+#[derive(Debug, Clone, Default)]
+pub struct Interner {
+    ids: HashMap<Arc<str>, Symbol>,
+    values: Vec<Arc<str>>,
+}
+
+impl Interner {
+    pub fn intern(&mut self, value: &str) -> Symbol {
+        if let Some(&id) = self.ids.get(value) {
+            return id;
+        }
+        let id = self.values.len() as Symbol;
+        let value: Arc<str> = Arc::from(value);
+        self.values.push(Arc::clone(&value));
+        self.ids.insert(value, id);
+        id
+    }
+
+    pub fn get(&self, value: &str) -> Option<Symbol> {
+        self.ids.get(value).copied()
+    }
+
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        &self.values[symbol as usize]
+    }
+}
+
+/// Set of same-arity tuples, in insertion order
+#[derive(Debug, Clone)]
+pub struct Relation {
+    arity: usize,
+    tuples: Vec<Box<[Symbol]>>,
+    seen: HashSet<Box<[Symbol]>>,
+}
+
+impl Relation {
+    pub fn new(arity: usize) -> Self {
+        Self {
+            arity,
+            tuples: Vec::new(),
+            seen: HashSet::new(),
+        }
+    }
+
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+
+    pub fn len(&self) -> usize {
+        self.tuples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tuples.is_empty()
+    }
+
+    pub fn tuples(&self) -> &[Box<[Symbol]>] {
+        &self.tuples
+    }
+
+    pub fn contains(&self, tuple: &[Symbol]) -> bool {
+        self.seen.contains(tuple)
+    }
+
+    /// Add `tuple`; false when already present
+    pub fn insert(&mut self, tuple: Box<[Symbol]>) -> bool {
+        debug_assert_eq!(tuple.len(), self.arity);
+        if self.seen.contains(&tuple) {
+            return false;
+        }
+        self.seen.insert(tuple.clone());
+        self.tuples.push(tuple);
+        true
+    }
+}
+
+/// Named relations sharing one symbol table
+#[derive(Debug, Clone, Default)]
+pub struct Database {
+    symbols: Interner,
+    relations: HashMap<String, Relation>,
+}
+
+impl Database {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Empty relation `name` (no-op when it exists with this arity)
+    pub fn declare(&mut self, name: &str, arity: usize) -> Result<()> {
+        match self.relations.get(name) {
+            Some(relation) if relation.arity != arity => Err(CodegraphError::parse(format!(
+                "relation '{}' has arity {}, used with {}",
+                name, relation.arity, arity
+            ))),
+            Some(_) => Ok(()),
+            None => {
+                self.relations
+                    .insert(name.to_string(), Relation::new(arity));
+                Ok(())
+            }
+        }
+    }
+
+    /// Add one fact
+    pub fn insert(&mut self, name: &str, values: &[&str]) -> Result<bool> {
+        self.declare(name, values.len())?;
+        let tuple: Box<[Symbol]> = values.iter().map(|v| self.symbols.intern(v)).collect();
+        Ok(self.insert_symbols(name, tuple))
+    }
+
+    /// Add one interned fact to a declared relation
+    pub(crate) fn insert_symbols(&mut self, name: &str, tuple: Box<[Symbol]>) -> bool {
+        self.relations
+            .get_mut(name)
+            .is_some_and(|relation| relation.insert(tuple))
+    }
+
+    pub fn relation(&self, name: &str) -> Option<&Relation> {
+        self.relations.get(name)
+    }
+
+    pub fn relation_names(&self) -> impl Iterator<Item = &str> {
+        self.relations.keys().map(String::as_str)
+    }
+
+    pub fn symbols(&self) -> &Interner {
+        &self.symbols
+    }
+
+    pub(crate) fn symbols_mut(&mut self) -> &mut Interner {
+        &mut self.symbols
+    }
+
+    /// Tuples of `name` as strings (empty when unknown)
+    pub fn rows(&self, name: &str) -> Vec<Vec<String>> {
+        self.relation(name).map_or_else(Vec::new, |relation| {
+            relation
+                .tuples()
+                .iter()
+                .map(|t| {
+                    t.iter()
+                        .map(|&s| self.symbols.resolve(s).to_string())
+                        .collect()
+                })
+                .collect()
+        })
+    }
+
+    /// Tuple count per relation
+    pub fn sizes(&self) -> BTreeMap<&str, usize> {
+        self.relations
+            .iter()
+            .map(|(name, relation)| (name.as_str(), relation.len()))
+            .collect()
+    }
+}
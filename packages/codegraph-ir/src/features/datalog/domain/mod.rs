@@ -0,0 +1,8 @@
+//! Datalog domain: programs and relations
+
+mod database;
+mod parser;
+mod program;
+
+pub use database::{Database, Interner, Relation, Symbol};
+pub use program::{Atom, Builtin, CompareOp, Literal, Program, Rule, Term};
@@ -0,0 +1,352 @@
+//! Soufflé-style Datalog parser
+//!
+//! ```text
+//! program   := (directive | clause)*
+//! directive := ".output" ident | ".decl" ident "(" ... ")"
+//! clause    := atom "." | atom ":-" literal ("," literal)* "."
+//! literal   := "!" atom | atom | term ("=" | "!=") term
+//! atom      := ident "(" term ("," term)* ")"
+//! term      := Var | "_" | "string" | integer
+//! ```
+
+use super::program::{Atom, Builtin, CompareOp, Literal, Program, Rule, Term};
+use crate::shared::models::{CodegraphError, Result};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// Relation name (lowercase start)
+    Ident(String),
+    Var(String),
+    Wildcard,
+    Const(String),
+    /// `.output`, `.decl`
+    Directive(String),
+    LParen,
+    RParen,
+    Comma,
+    Dot,
+    Implies,
+    Bang,
+    Eq,
+    Ne,
+}
+
+fn error(line: usize, message: impl std::fmt::Display) -> CodegraphError {
+    CodegraphError::parse(format!("datalog line {}: {}", line, message))
+}
+
+fn tokenize(source: &str) -> Result<Vec<(Token, usize)>> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        match c {
+            '\n' => {
+                line += 1;
+                i += 1;
+            }
+            c if c.is_whitespace() => i += 1,
+            '/' if next == Some('/') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '/' if next == Some('*') => {
+                let start = line;
+                i += 2;
+                loop {
+                    match chars.get(i) {
+                        None => return Err(error(start, "unterminated comment")),
+                        Some('*') if chars.get(i + 1) == Some(&'/') => break,
+                        Some('\n') => line += 1,
+                        Some(_) => {}
+                    }
+                    i += 1;
+                }
+                i += 2;
+            }
+            '"' => {
+                let start = line;
+                let mut value = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None | Some('\n') => return Err(error(start, "unterminated string")),
+                        Some('"') => break,
+                        Some('\\') => {
+                            match chars.get(i + 1) {
+                                Some('n') => value.push('\n'),
+                                Some('t') => value.push('\t'),
+                                Some(&escaped) => value.push(escaped),
+                                None => return Err(error(start, "unterminated string")),
+                            }
+                            i += 1;
+                        }
+                        Some(&c) => value.push(c),
+                    }
+                    i += 1;
+                }
+                i += 1;
+                tokens.push((Token::Const(value), start));
+            }
+            '.' if next.is_some_and(|n| n.is_ascii_alphabetic()) => {
+                let start = i + 1;
+                i = start;
+                while i < chars.len() && chars[i].is_ascii_alphanumeric() {
+                    i += 1;
+                }
+                let name: String = chars[start..i].iter().collect();
+                tokens.push((Token::Directive(name), line));
+            }
+            ':' if next == Some('-') => {
+                tokens.push((Token::Implies, line));
+                i += 2;
+            }
+            '!' if next == Some('=') => {
+                tokens.push((Token::Ne, line));
+                i += 2;
+            }
+            '!' => {
+                tokens.push((Token::Bang, line));
+                i += 1;
+            }
+            '=' => {
+                tokens.push((Token::Eq, line));
+                i += 1;
+            }
+            '(' | ')' | ',' | '.' => {
+                let token = match c {
+                    '(' => Token::LParen,
+                    ')' => Token::RParen,
+                    ',' => Token::Comma,
+                    _ => Token::Dot,
+                };
+                tokens.push((token, line));
+                i += 1;
+            }
+            c if c.is_ascii_digit() || (c == '-' && next.is_some_and(|n| n.is_ascii_digit())) => {
+                let start = i;
+                i += 1;
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+                tokens.push((Token::Const(chars[start..i].iter().collect()), line));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                let token = if word == "_" {
+                    Token::Wildcard
+                } else if c.is_uppercase() || c == '_' {
+                    Token::Var(word)
+                } else {
+                    Token::Ident(word)
+                };
+                tokens.push((token, line));
+            }
+            c => return Err(error(line, format!("unexpected character {:?}", c))),
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .or(self.tokens.last())
+            .map_or(1, |(_, line)| *line)
+    }
+
+    fn advance(&mut self) -> Result<Token> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .map(|(t, _)| t.clone())
+            .ok_or_else(|| error(self.line(), "unexpected end of input"))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<()> {
+        let line = self.line();
+        let token = self.advance()?;
+        if token == expected {
+            Ok(())
+        } else {
+            Err(error(
+                line,
+                format!("expected {:?}, found {:?}", expected, token),
+            ))
+        }
+    }
+
+    fn ident(&mut self) -> Result<String> {
+        let line = self.line();
+        match self.advance()? {
+            Token::Ident(name) => Ok(name),
+            token => Err(error(
+                line,
+                format!("expected relation name, found {:?}", token),
+            )),
+        }
+    }
+
+    fn term(&mut self) -> Result<Term> {
+        let line = self.line();
+        match self.advance()? {
+            Token::Var(name) => Ok(Term::Var(name)),
+            Token::Wildcard => Ok(Term::Wildcard),
+            Token::Const(value) => Ok(Term::Const(value)),
+            token => Err(error(line, format!("expected term, found {:?}", token))),
+        }
+    }
+
+    fn atom_args(&mut self) -> Result<Vec<Term>> {
+        self.expect(Token::LParen)?;
+        let mut terms = vec![self.term()?];
+        while self.peek() == Some(&Token::Comma) {
+            self.pos += 1;
+            terms.push(self.term()?);
+        }
+        self.expect(Token::RParen)?;
+        Ok(terms)
+    }
+
+    fn atom(&mut self) -> Result<Atom> {
+        let relation = self.ident()?;
+        Ok(Atom::new(relation, self.atom_args()?))
+    }
+
+    fn literal(&mut self) -> Result<Literal> {
+        let line = self.line();
+        match self.peek() {
+            Some(Token::Bang) => {
+                self.pos += 1;
+                let atom = self.atom()?;
+                if Builtin::from_name(&atom.relation).is_some() {
+                    return Err(error(line, format!("{} cannot be negated", atom.relation)));
+                }
+                Ok(Literal::Negative(atom))
+            }
+            Some(Token::Ident(name)) => match Builtin::from_name(name) {
+                Some(builtin) => {
+                    self.pos += 1;
+                    let mut args = self.atom_args()?;
+                    if args.len() != 2 {
+                        return Err(error(
+                            line,
+                            format!("{} takes 2 arguments", name_of(builtin)),
+                        ));
+                    }
+                    let value = args.pop().unwrap_or(Term::Wildcard);
+                    let pattern = args.pop().unwrap_or(Term::Wildcard);
+                    Ok(Literal::Builtin {
+                        builtin,
+                        pattern,
+                        value,
+                    })
+                }
+                None => Ok(Literal::Positive(self.atom()?)),
+            },
+            _ => {
+                let left = self.term()?;
+                let op = match self.advance()? {
+                    Token::Eq => CompareOp::Eq,
+                    Token::Ne => CompareOp::Ne,
+                    token => {
+                        return Err(error(
+                            line,
+                            format!("expected '=' or '!=', found {:?}", token),
+                        ))
+                    }
+                };
+                let right = self.term()?;
+                Ok(Literal::Compare { left, op, right })
+            }
+        }
+    }
+
+    fn directive(&mut self, name: &str, program: &mut Program) -> Result<()> {
+        let line = self.line();
+        match name {
+            "output" => program.outputs.push(self.ident()?),
+            "decl" => {
+                self.ident()?;
+                self.expect(Token::LParen)?;
+                while self.advance()? != Token::RParen {}
+            }
+            other => return Err(error(line, format!("unknown directive .{}", other))),
+        }
+        Ok(())
+    }
+
+    fn program(&mut self) -> Result<Program> {
+        let mut program = Program::default();
+        while let Some(token) = self.peek().cloned() {
+            if let Token::Directive(name) = token {
+                self.pos += 1;
+                self.directive(&name, &mut program)?;
+                continue;
+            }
+
+            let line = self.line();
+            let head = self.atom()?;
+            match self.advance()? {
+                Token::Dot => {
+                    if head.terms.iter().any(|t| !matches!(t, Term::Const(_))) {
+                        return Err(error(line, "facts may only contain constants"));
+                    }
+                    program.facts.push(head);
+                }
+                Token::Implies => {
+                    let mut body = vec![self.literal()?];
+                    while self.peek() == Some(&Token::Comma) {
+                        self.pos += 1;
+                        body.push(self.literal()?);
+                    }
+                    self.expect(Token::Dot)?;
+                    program.rules.push(Rule { head, body });
+                }
+                token => {
+                    return Err(error(
+                        line,
+                        format!("expected '.' or ':-', found {:?}", token),
+                    ))
+                }
+            }
+        }
+        Ok(program)
+    }
+}
+
+fn name_of(builtin: Builtin) -> &'static str {
+    match builtin {
+        Builtin::Match => "match",
+        Builtin::Contains => "contains",
+    }
+}
+
+pub(super) fn parse(source: &str) -> Result<Program> {
+    Parser {
+        tokens: tokenize(source)?,
+        pos: 0,
+    }
+    .program()
+}
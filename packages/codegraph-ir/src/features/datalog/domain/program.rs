@@ -0,0 +1,182 @@
+//! Datalog programs: facts, rules and output directives
+
+use std::collections::HashSet;
+
+use super::parser;
+use crate::shared::models::{CodegraphError, Result};
+
+/// Rule argument
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Term {
+    /// Named variable (`X`, `_Caller`)
+    Var(String),
+    /// String or number constant
+    Const(String),
+    /// `_`: matches anything, binds nothing
+    Wildcard,
+}
+
+/// `relation(term, ...)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Atom {
+    pub relation: String,
+    pub terms: Vec<Term>,
+}
+
+impl Atom {
+    pub fn new(relation: impl Into<String>, terms: Vec<Term>) -> Self {
+        Self {
+            relation: relation.into(),
+            terms,
+        }
+    }
+
+    fn vars(&self) -> impl Iterator<Item = &str> {
+        self.terms.iter().filter_map(|t| match t {
+            Term::Var(v) => Some(v.as_str()),
+            _ => None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+}
+
+/// String predicates (Soufflé argument order: pattern first)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Builtin {
+    /// `match("regex", X)`: the whole of `X` matches
+    Match,
+    /// `contains("sub", X)`
+    Contains,
+}
+
+impl Builtin {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "match" => Some(Self::Match),
+            "contains" => Some(Self::Contains),
+            _ => None,
+        }
+    }
+}
+
+/// Body literal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Literal {
+    Positive(Atom),
+    /// `!relation(...)` (stratified)
+    Negative(Atom),
+    Compare {
+        left: Term,
+        op: CompareOp,
+        right: Term,
+    },
+    Builtin {
+        builtin: Builtin,
+        pattern: Term,
+        value: Term,
+    },
+}
+
+/// `head :- body.`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    pub head: Atom,
+    pub body: Vec<Literal>,
+}
+
+impl Rule {
+    /// Range restriction: every variable outside positive atoms (head,
+    /// negations, comparisons, builtins) must occur in a positive atom
+    pub fn validate(&self) -> Result<()> {
+        let bound: HashSet<&str> = self
+            .body
+            .iter()
+            .filter_map(|l| match l {
+                Literal::Positive(atom) => Some(atom.vars()),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+
+        if self.head.terms.contains(&Term::Wildcard) {
+            return Err(self.error("'_' in rule head"));
+        }
+        let mut used: Vec<&str> = self.head.vars().collect();
+        for literal in &self.body {
+            match literal {
+                Literal::Positive(_) => {}
+                Literal::Negative(atom) => used.extend(atom.vars()),
+                Literal::Compare { left, right, .. }
+                | Literal::Builtin {
+                    pattern: left,
+                    value: right,
+                    ..
+                } => {
+                    for term in [left, right] {
+                        match term {
+                            Term::Var(v) => used.push(v),
+                            Term::Wildcard => return Err(self.error("'_' outside an atom")),
+                            Term::Const(_) => {}
+                        }
+                    }
+                }
+            }
+        }
+        match used.into_iter().find(|v| !bound.contains(v)) {
+            Some(var) => {
+                Err(self.error(&format!("variable {} is not bound by a positive atom", var)))
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn error(&self, message: &str) -> CodegraphError {
+        CodegraphError::parse(format!(
+            "datalog rule for '{}': {}",
+            self.head.relation, message
+        ))
+    }
+}
+
+/// Parsed Datalog program
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Program {
+    /// Ground atoms (`taint_source("app.read_cookie").`)
+    pub facts: Vec<Atom>,
+    pub rules: Vec<Rule>,
+    /// `.output` relations (every derived relation when empty)
+    pub outputs: Vec<String>,
+}
+
+impl Program {
+    /// Parse Soufflé-style source
+    ///
+    /// `//` and `/* */` comments; variables start with an uppercase letter
+    /// or `_`; constants are quoted strings or integers. `.decl` lines are
+    /// accepted and ignored (relations are untyped).
+    pub fn parse(source: &str) -> Result<Self> {
+        let program = parser::parse(source)?;
+        for rule in &program.rules {
+            rule.validate()?;
+        }
+        Ok(program)
+    }
+
+    /// Output relation names, in declaration order
+    pub fn output_relations(&self) -> Vec<&str> {
+        if !self.outputs.is_empty() {
+            return self.outputs.iter().map(String::as_str).collect();
+        }
+        let mut seen = HashSet::new();
+        self.rules
+            .iter()
+            .map(|r| r.head.relation.as_str())
+            .filter(|r| seen.insert(*r))
+            .collect()
+    }
+}
@@ -0,0 +1,51 @@
+//! Datalog - declarative analyses over the IR
+//!
+//! Exposes nodes and edges as relations (`node`, `edge`, `calls`, `reads`,
+//! `writes`, `taint_source`, ...; see `application::ir_facts`) and
+//! evaluates Soufflé-style rules over them, so one-off security or
+//! architecture queries are a few lines of Datalog instead of a new pass.
+//! Evaluation is stratified (negation allowed across strata) and
+//! semi-naive.
+//!
+//! # Architecture
+//!
+//! ```text
+//! ┌──────────────────────────────────────────────────┐
+//! │ Domain Layer                                     │
+//! │  - Program (facts, rules, .output; parser)       │
+//! │  - Database (interned relations)                 │
+//! └──────────────────────────────────────────────────┘
+//!                   ▲
+//!                   │
+//! ┌──────────────────────────────────────────────────┐
+//! │ Application Layer                                │
+//! │  - ir_database (IRDocument → base relations)     │
+//! │  - DatalogEngine (stratified evaluation)         │
+//! └──────────────────────────────────────────────────┘
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use codegraph_ir::features::datalog::DatalogEngine;
+//!
+//! let result = DatalogEngine::from_ir(&ir_doc).run_source(r#"
+//!     reaches(F, G) :- calls(F, G).
+//!     reaches(F, H) :- reaches(F, G), calls(G, H).
+//!     vuln(F, S) :- calls(F, Src), taint_source(Src), reaches(F, S),
+//!                   taint_sink(S, "high"), !calls(F, "html.escape").
+//!     .output vuln
+//! "#)?;
+//! for row in &result.relations["vuln"] {
+//!     println!("{} reaches {}", row[0], row[1]);
+//! }
+//! ```
+
+pub mod application;
+pub mod domain;
+
+// Re-export application layer
+pub use application::{ir_database, DatalogEngine, DatalogResult};
+
+// Re-exports for convenience
+pub use domain::{Database, Program};
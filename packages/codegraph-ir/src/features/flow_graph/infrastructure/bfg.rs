@@ -11,10 +11,12 @@
  * MATCHES: BfgBuilder.build_full()
  */
 
+use super::exception_flow::{ExceptionFlow, Handler, RaiseSite, TryRegion};
 use crate::features::ir_generation::infrastructure::visitor::AstVisitor;
-use crate::features::parsing::ports::LanguagePlugin;
+use crate::features::parsing::ports::{ControlFlowType, LanguagePlugin};
 use crate::shared::models::span_ref::BlockRef;
 use crate::shared::models::Span;
+use serde::{Deserialize, Serialize};
 use tree_sitter::Node;
 
 /// Block kind (matches Python BFGBlockKind enum values exactly)
@@ -57,7 +59,7 @@ impl BlockKind {
 }
 
 /// Basic Flow Graph
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BasicFlowGraph {
     pub id: String,
     pub function_id: String,
@@ -65,6 +67,9 @@ pub struct BasicFlowGraph {
     pub exit_block_id: String,
     pub blocks: Vec<BlockRef>,
     pub total_statements: usize,
+    /// Try regions and raise sites (see `build_cfg_edges_with_exceptions`)
+    #[serde(default)]
+    pub exception_flow: ExceptionFlow,
 }
 
 /// BFG Visitor - extracts control flow blocks
@@ -83,6 +88,10 @@ pub struct BfgVisitor<'a> {
     block_counter: usize,
     current_block_statements: Vec<Span>,
     language_plugin: &'a dyn LanguagePlugin,
+    exception_flow: ExceptionFlow,
+    /// End byte of the last processed construct; traversal of its
+    /// descendants is skipped
+    consumed_until: usize,
 }
 
 impl<'a> BfgVisitor<'a> {
@@ -93,6 +102,8 @@ impl<'a> BfgVisitor<'a> {
             block_counter: 0,
             current_block_statements: Vec::new(),
             language_plugin,
+            exception_flow: ExceptionFlow::default(),
+            consumed_until: 0,
         }
     }
 
@@ -104,6 +115,10 @@ impl<'a> BfgVisitor<'a> {
         &self.blocks
     }
 
+    pub fn exception_flow(&self) -> &ExceptionFlow {
+        &self.exception_flow
+    }
+
     pub fn finalize(&mut self) {
         // Create final block if statements remain
         if !self.current_block_statements.is_empty() {
@@ -174,12 +189,50 @@ impl<'a> BfgVisitor<'a> {
             }
             // Accumulate regular statements
             else if self.is_statement(&child) {
-                let span = node_to_span(&child);
-                self.current_block_statements.push(span);
+                self.push_statement(&child, source);
             }
         }
     }
 
+    /// Accumulate a statement; a raise or return ends its block
+    fn push_statement(&mut self, node: &Node, source: &str) {
+        self.current_block_statements.push(node_to_span(node));
+        let cf_type = self.language_plugin.get_control_flow_type(node);
+        if !matches!(
+            cf_type,
+            Some(ControlFlowType::Raise | ControlFlowType::Return)
+        ) {
+            return;
+        }
+        self.flush_current_block(BlockKind::Statement);
+        let block_id = self.blocks.last().map(|b| b.id.clone()).unwrap_or_default();
+        if cf_type == Some(ControlFlowType::Raise) {
+            self.exception_flow.raises.push(RaiseSite {
+                block_id,
+                exception: raised_type(node, source),
+            });
+        } else {
+            self.exception_flow.returns.push(block_id);
+        }
+    }
+
+    /// Body of a try/handler; falls back to the first block-like child for
+    /// grammars without a `body` field (Python `except`, `finally`)
+    fn construct_body<'t>(&self, node: &Node<'t>) -> Option<Node<'t>> {
+        self.language_plugin
+            .get_control_flow_body(node)
+            .or_else(|| {
+                let mut cursor = node.walk();
+                let mut children = node.named_children(&mut cursor);
+                children.find(|c| matches!(c.kind(), "block" | "statements"))
+            })
+    }
+
+    /// IDs of the blocks flushed since `start`
+    fn block_ids_from(&self, start: usize) -> Vec<String> {
+        self.blocks[start..].iter().map(|b| b.id.clone()).collect()
+    }
+
     // ========================================
     // Control Flow Processing Helpers
     // ========================================
@@ -255,31 +308,59 @@ impl<'a> BfgVisitor<'a> {
     }
 
     /// Process try/catch/finally construct
+    ///
+    /// Records the blocks of each part as a `TryRegion`.
     fn process_try(&mut self, node: &Node, source: &str) {
+        let mut region = TryRegion::default();
+
         // 1. Try block
-        if let Some(body) = self.language_plugin.get_control_flow_body(node) {
+        let start = self.blocks.len();
+        if let Some(body) = self.construct_body(node) {
             self.visit_block_statements(&body, source);
             self.flush_current_block(BlockKind::Try);
         }
+        region.body = self.block_ids_from(start);
 
         // 2. Exception handlers
         let handlers = self.language_plugin.get_exception_handlers(node);
 
         // Catch blocks
         for catch_block in handlers.catch_blocks {
-            if let Some(catch_body) = self.language_plugin.get_control_flow_body(&catch_block) {
+            let start = self.blocks.len();
+            let catch_body = self.construct_body(&catch_block);
+            if let Some(catch_body) = catch_body {
                 self.visit_block_statements(&catch_body, source);
                 self.flush_current_block(BlockKind::Catch);
             }
+            region.handlers.push(Handler {
+                blocks: self.block_ids_from(start),
+                caught: caught_types(&catch_block, catch_body.as_ref(), source),
+            });
+        }
+
+        // Python `try ... else`
+        let mut cursor = node.walk();
+        let orelse = node
+            .children(&mut cursor)
+            .find(|c| c.kind() == "else_clause");
+        if let Some(else_body) = orelse.and_then(|e| self.construct_body(&e)) {
+            let start = self.blocks.len();
+            self.visit_block_statements(&else_body, source);
+            self.flush_current_block(BlockKind::Statement);
+            region.orelse = self.block_ids_from(start);
         }
 
         // Finally block
         if let Some(finally_block) = handlers.finally_block {
-            if let Some(finally_body) = self.language_plugin.get_control_flow_body(&finally_block) {
+            if let Some(finally_body) = self.construct_body(&finally_block) {
+                let start = self.blocks.len();
                 self.visit_block_statements(&finally_body, source);
                 self.flush_current_block(BlockKind::Finally);
+                region.finally = self.block_ids_from(start);
             }
         }
+
+        self.exception_flow.try_regions.push(region);
     }
 }
 
@@ -287,13 +368,17 @@ impl<'a> AstVisitor for BfgVisitor<'a> {
     fn visit_node(&mut self, node: &Node, source: &str, _depth: usize) {
         // Process all nodes (function body assumed)
 
+        // Descendants of a processed construct were handled with it
+        if node.start_byte() < self.consumed_until {
+            return;
+        }
+
         // Control flow nodes cause block split
         if self.is_control_flow(node) {
             // Flush current block before control flow
             self.flush_current_block(BlockKind::Statement);
 
             // Dispatch to appropriate handler based on control flow type
-            use crate::features::parsing::ports::ControlFlowType;
             if let Some(cf_type) = self.language_plugin.get_control_flow_type(node) {
                 match cf_type {
                     ControlFlowType::If => {
@@ -313,15 +398,16 @@ impl<'a> AstVisitor for BfgVisitor<'a> {
                         self.current_block_statements.push(span);
                         self.flush_current_block(BlockKind::Yield);
                     }
-                    ControlFlowType::Return
-                    | ControlFlowType::Break
-                    | ControlFlowType::Continue
-                    | ControlFlowType::Raise => {
+                    ControlFlowType::Return | ControlFlowType::Raise => {
+                        self.push_statement(node, source);
+                    }
+                    ControlFlowType::Break | ControlFlowType::Continue => {
                         let span = node_to_span(node);
                         self.current_block_statements.push(span);
                         self.flush_current_block(BlockKind::Statement);
                     }
                 }
+                self.consumed_until = node.end_byte();
             } else {
                 // Fallback: treat as generic control flow (shouldn't happen if plugin is correct)
                 let span = node_to_span(node);
@@ -331,8 +417,7 @@ impl<'a> AstVisitor for BfgVisitor<'a> {
         }
         // Regular statements accumulate
         else if self.is_statement(node) {
-            let span = node_to_span(node);
-            self.current_block_statements.push(span);
+            self.push_statement(node, source);
         }
     }
 
@@ -346,6 +431,47 @@ impl<'a> AstVisitor for BfgVisitor<'a> {
     }
 }
 
+/// Raised type of a raise/throw statement, when spelled out
+/// (`raise ValueError(..)`, `throw new IOException(..)`)
+fn raised_type(node: &Node, source: &str) -> Option<String> {
+    let text = source.get(node.byte_range())?;
+    let mut tokens = type_tokens(text).skip(1);
+    let token = match tokens.next()? {
+        "new" => tokens.next()?,
+        token => token,
+    };
+    exception_name(token)
+}
+
+/// Types named in a handler header (`except (A, B) as e:`, `catch (A | B e)`,
+/// `catch (e: A)`); empty for a catch-all
+fn caught_types(handler: &Node, body: Option<&Node>, source: &str) -> Vec<String> {
+    let end = body.map_or(handler.end_byte(), |b| b.start_byte());
+    let Some(header) = source.get(handler.start_byte()..end) else {
+        return Vec::new();
+    };
+    let header = header.split('{').next().unwrap_or_default();
+    let mut types: Vec<String> = Vec::new();
+    for name in type_tokens(header).skip(1).filter_map(exception_name) {
+        if !types.contains(&name) {
+            types.push(name);
+        }
+    }
+    types
+}
+
+fn type_tokens(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+        .filter(|t| !t.is_empty())
+}
+
+/// Last dotted segment of `token` if it looks like a type name
+fn exception_name(token: &str) -> Option<String> {
+    let name = token.rsplit('.').next()?;
+    name.starts_with(|c: char| c.is_uppercase())
+        .then(|| name.to_string())
+}
+
 fn node_to_span(node: &Node) -> Span {
     let start_pos = node.start_position();
     let end_pos = node.end_position();
@@ -419,4 +545,45 @@ def test(x):
             "Should have condition blocks for if statement"
         );
     }
+
+    #[test]
+    #[cfg(feature = "java-lang")]
+    fn test_bfg_visitor_records_java_try_regions() {
+        let code = r#"
+class A {
+    void run() {
+        try {
+            open();
+            throw new IOException("closed");
+        } catch (IllegalStateException | java.io.IOException e) {
+            log(e);
+        } finally {
+            close();
+        }
+    }
+}
+"#;
+
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_java::language()).unwrap();
+        let tree = parser.parse(code, None).unwrap();
+        let java_plugin = crate::features::parsing::plugins::JavaPlugin::new();
+        let mut visitor = BfgVisitor::new(&java_plugin);
+        visitor.set_function_id("run".to_string());
+
+        traverse_with_visitor(&tree.root_node(), code, &mut visitor);
+        visitor.finalize();
+
+        let flow = visitor.exception_flow();
+        assert_eq!(flow.try_regions.len(), 1);
+        let region = &flow.try_regions[0];
+        assert_eq!(
+            region.handlers[0].caught,
+            vec!["IllegalStateException", "IOException"]
+        );
+        assert_eq!(region.finally.len(), 1);
+        assert_eq!(flow.raises.len(), 1);
+        assert_eq!(flow.raises[0].exception.as_deref(), Some("IOException"));
+        assert!(region.body.contains(&flow.raises[0].block_id));
+    }
 }
@@ -30,6 +30,7 @@ pub enum CFGEdgeType {
     LoopBack,      // Loop back to header
     LoopExit,      // Exit loop
     Exception,     // Exception handler
+    Finally,       // Return through a finally block
 }
 
 impl CFGEdgeType {
@@ -41,6 +42,7 @@ impl CFGEdgeType {
             CFGEdgeType::LoopBack => "LOOP_BACK",
             CFGEdgeType::LoopExit => "LOOP_EXIT",
             CFGEdgeType::Exception => "EXCEPTION",
+            CFGEdgeType::Finally => "FINALLY",
         }
     }
 }
@@ -0,0 +1,582 @@
+//! Exceptional control flow of one function
+//!
+//! `BfgVisitor` records where exceptions arise and where they are handled:
+//! try regions (body, `else`, handler and `finally` block IDs, caught types
+//! per handler), explicit raise/throw sites with the raised type when it is
+//! spelled out, and blocks ending in a return. `build_cfg_edges_with_exceptions`
+//! adds the matching CFG edges to the structural ones:
+//!
+//! - try body block → handlers of its innermost try (EXCEPTION)
+//! - raise → matching handlers, else `finally`, else the enclosing try, else
+//!   the exit (EXCEPTION); a raise does not fall through
+//! - end of body / `else` / handler → `finally`, or the block after the try
+//! - end of `finally` → the block after the try, and the next handlers or the
+//!   exit while an exception is still in flight (EXCEPTION)
+//! - return inside a try with `finally` → `finally`, whose end then continues
+//!   to the next `finally` or the exit (FINALLY)
+//!
+//! Handler entries are reached through exception edges only. Types match by
+//! last dotted segment, without a class hierarchy: a handler without types,
+//! or one listing `Exception`, `BaseException` or `Throwable`, catches all.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::cfg::{build_cfg_edges, CFGEdge, CFGEdgeType};
+use crate::shared::models::span_ref::BlockRef;
+use crate::shared::models::Node;
+
+/// Node metadata key holding a function's `ExceptionSummary`
+pub const EXCEPTIONS_KEY: &str = "exceptions";
+
+/// Types treated as catching every exception
+const ROOT_EXCEPTIONS: &[&str] = &["Exception", "BaseException", "Throwable"];
+
+/// Try regions, raise sites and returns of one function (block IDs)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExceptionFlow {
+    /// Innermost first
+    pub try_regions: Vec<TryRegion>,
+    pub raises: Vec<RaiseSite>,
+    /// Blocks ending in a return
+    pub returns: Vec<String>,
+}
+
+/// One try/catch/finally construct
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TryRegion {
+    /// Protected blocks, nested constructs included
+    pub body: Vec<String>,
+    pub handlers: Vec<Handler>,
+    /// Python `try ... else` blocks (run when the body completes normally)
+    #[serde(default)]
+    pub orelse: Vec<String>,
+    pub finally: Vec<String>,
+}
+
+/// One except/catch clause
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Handler {
+    pub blocks: Vec<String>,
+    /// Caught type names; empty for a bare `except:`
+    pub caught: Vec<String>,
+}
+
+/// Block ending in an explicit raise/throw
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaiseSite {
+    pub block_id: String,
+    /// Raised type; `None` for re-raises and raised variables
+    pub exception: Option<String>,
+}
+
+/// Exception types a function raises and catches (function node metadata)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExceptionSummary {
+    /// Explicitly raised types, sorted
+    pub raises: Vec<String>,
+    /// Types named by handlers, sorted
+    pub catches: Vec<String>,
+    /// Raised types no handler of the function surely catches
+    pub uncaught: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    Body,
+    Orelse,
+    Handler,
+    Finally,
+}
+
+/// Where an exception raised in a block goes
+struct Escape<'a> {
+    /// Handler / `finally` entries
+    targets: Vec<&'a str>,
+    /// Not surely caught anywhere in the function
+    uncaught: bool,
+    /// Leaves the function without passing a `finally`
+    to_exit: bool,
+}
+
+impl Handler {
+    fn catches_all(&self) -> bool {
+        self.caught.is_empty()
+            || self
+                .caught
+                .iter()
+                .any(|c| ROOT_EXCEPTIONS.contains(&c.as_str()))
+    }
+
+    fn may_catch(&self, exception: Option<&str>) -> bool {
+        match exception {
+            None => true,
+            Some(ty) => self.catches_all() || self.caught.iter().any(|c| c == ty),
+        }
+    }
+
+    fn surely_catches(&self, exception: Option<&str>) -> bool {
+        self.catches_all() || exception.is_some_and(|ty| self.caught.iter().any(|c| c == ty))
+    }
+}
+
+impl TryRegion {
+    fn role_of(&self, block: &str) -> Option<Role> {
+        let has = |ids: &[String]| ids.iter().any(|id| id == block);
+        if has(&self.body) {
+            Some(Role::Body)
+        } else if has(&self.orelse) {
+            Some(Role::Orelse)
+        } else if self.handlers.iter().any(|h| has(&h.blocks)) {
+            Some(Role::Handler)
+        } else if has(&self.finally) {
+            Some(Role::Finally)
+        } else {
+            None
+        }
+    }
+
+    fn finally_entry(&self) -> Option<&str> {
+        self.finally.first().map(String::as_str)
+    }
+
+    fn blocks(&self) -> impl Iterator<Item = &String> {
+        self.body
+            .iter()
+            .chain(self.orelse.iter())
+            .chain(self.handlers.iter().flat_map(|h| h.blocks.iter()))
+            .chain(self.finally.iter())
+    }
+}
+
+impl ExceptionFlow {
+    pub fn is_empty(&self) -> bool {
+        self.try_regions.is_empty() && self.raises.is_empty()
+    }
+
+    pub fn summary(&self) -> ExceptionSummary {
+        let mut raises: Vec<String> = self
+            .raises
+            .iter()
+            .filter_map(|r| r.exception.clone())
+            .collect();
+        let mut catches: Vec<String> = self
+            .try_regions
+            .iter()
+            .flat_map(|r| r.handlers.iter().flat_map(|h| h.caught.iter().cloned()))
+            .collect();
+        let mut uncaught: Vec<String> = self
+            .raises
+            .iter()
+            .filter_map(|r| {
+                let ty = r.exception.as_deref()?;
+                self.escape(&r.block_id, Some(ty))
+                    .uncaught
+                    .then(|| ty.to_string())
+            })
+            .collect();
+        for types in [&mut raises, &mut catches, &mut uncaught] {
+            types.sort();
+            types.dedup();
+        }
+        ExceptionSummary {
+            raises,
+            catches,
+            uncaught,
+        }
+    }
+
+    fn escape(&self, block: &str, exception: Option<&str>) -> Escape<'_> {
+        let mut targets = Vec::new();
+        let mut via_finally = false;
+        for region in &self.try_regions {
+            let Some(role) = region.role_of(block) else {
+                continue;
+            };
+            if role == Role::Body {
+                if !via_finally {
+                    targets.extend(
+                        region
+                            .handlers
+                            .iter()
+                            .filter(|h| h.may_catch(exception))
+                            .filter_map(|h| h.blocks.first().map(String::as_str)),
+                    );
+                }
+                if region.handlers.iter().any(|h| h.surely_catches(exception)) {
+                    return Escape {
+                        targets,
+                        uncaught: false,
+                        to_exit: false,
+                    };
+                }
+            }
+            if role != Role::Finally && !via_finally {
+                if let Some(entry) = region.finally_entry() {
+                    targets.push(entry);
+                    via_finally = true;
+                }
+            }
+        }
+        Escape {
+            targets,
+            uncaught: true,
+            to_exit: !via_finally,
+        }
+    }
+
+    /// `finally` entry a return in `block` runs first
+    fn return_target(&self, block: &str) -> Option<&str> {
+        self.try_regions
+            .iter()
+            .find_map(|region| match region.role_of(block)? {
+                Role::Finally => None,
+                _ => region.finally_entry(),
+            })
+    }
+}
+
+impl ExceptionSummary {
+    /// Summary attached to a function node by the exceptions stage
+    pub fn from_node(node: &Node) -> Option<Self> {
+        let metadata: serde_json::Value = serde_json::from_str(node.metadata.as_deref()?).ok()?;
+        serde_json::from_value(metadata.get(EXCEPTIONS_KEY)?.clone()).ok()
+    }
+}
+
+/// `build_cfg_edges` plus the exceptional edges of `flow`
+///
+/// `blocks` are the BFG blocks in order, the exit block last.
+pub fn build_cfg_edges_with_exceptions(blocks: &[BlockRef], flow: &ExceptionFlow) -> Vec<CFGEdge> {
+    let mut edges = build_cfg_edges(blocks);
+    if flow.is_empty() || blocks.is_empty() {
+        return edges;
+    }
+    let exit = blocks[blocks.len() - 1].id.as_str();
+    let position: HashMap<&str, usize> = blocks
+        .iter()
+        .enumerate()
+        .map(|(i, b)| (b.id.as_str(), i))
+        .collect();
+
+    // Handlers are entered by exceptions only; raises and rerouted returns
+    // do not fall through
+    let handler_entries: HashSet<&str> = flow
+        .try_regions
+        .iter()
+        .flat_map(|r| r.handlers.iter().filter_map(|h| h.blocks.first()))
+        .map(String::as_str)
+        .collect();
+    let mut no_fallthrough: HashSet<&str> =
+        flow.raises.iter().map(|r| r.block_id.as_str()).collect();
+    let returns: Vec<(&str, &str)> = flow
+        .returns
+        .iter()
+        .filter_map(|id| Some((id.as_str(), flow.return_target(id)?)))
+        .collect();
+    no_fallthrough.extend(returns.iter().map(|&(id, _)| id));
+
+    // Block after each construct; one ending an enclosing body or handler
+    // continues where that one does
+    let next_blocks: Vec<Option<&str>> = flow
+        .try_regions
+        .iter()
+        .map(|region| {
+            region
+                .blocks()
+                .filter_map(|id| position.get(id.as_str()))
+                .max()
+                .and_then(|&i| blocks.get(i + 1))
+                .map(|b| b.id.as_str())
+        })
+        .collect();
+    let afters: Vec<Option<&str>> = flow
+        .try_regions
+        .iter()
+        .enumerate()
+        .map(|(i, region)| {
+            let mut inner = region.body.first()?.as_str();
+            let mut after = next_blocks[i];
+            for _ in 0..flow.try_regions.len() {
+                let Some(next) = after.filter(|id| handler_entries.contains(id)) else {
+                    break;
+                };
+                let Some(j) = flow.try_regions.iter().position(|r| {
+                    r.handlers
+                        .iter()
+                        .any(|h| h.blocks.first().map(String::as_str) == Some(next))
+                }) else {
+                    break;
+                };
+                let outer = &flow.try_regions[j];
+                let orelse = match outer.role_of(inner) {
+                    Some(Role::Body) => outer.orelse.first().map(String::as_str),
+                    _ => None,
+                };
+                after = orelse.or(outer.finally_entry()).or(next_blocks[j]);
+                inner = next;
+            }
+            after
+        })
+        .collect();
+
+    // Falling off a body or handler into the next handler continues after it
+    for edge in &mut edges {
+        let Some((region, after)) = flow.try_regions.iter().zip(&afters).find(|(r, _)| {
+            r.handlers
+                .iter()
+                .any(|h| h.blocks.first() == Some(&edge.target_block_id))
+        }) else {
+            continue;
+        };
+        let next = match region.role_of(&edge.source_block_id) {
+            Some(Role::Body) => region.orelse.first().map(String::as_str),
+            Some(Role::Handler) => None,
+            _ => continue,
+        };
+        if let Some(next) = next.or(region.finally_entry()).or(*after) {
+            edge.target_block_id = next.to_string();
+        }
+    }
+
+    let mut seen: HashSet<(String, String, &'static str)> = HashSet::new();
+    edges.retain(|e| {
+        !handler_entries.contains(e.target_block_id.as_str())
+            && !no_fallthrough.contains(e.source_block_id.as_str())
+            && seen.insert((
+                e.source_block_id.clone(),
+                e.target_block_id.clone(),
+                e.edge_type.as_str(),
+            ))
+    });
+    let mut add = |source: &str, target: &str, edge_type: CFGEdgeType| {
+        if seen.insert((source.to_string(), target.to_string(), edge_type.as_str())) {
+            edges.push(CFGEdge {
+                source_block_id: source.to_string(),
+                target_block_id: target.to_string(),
+                edge_type,
+            });
+        }
+    };
+
+    for (region, &after) in flow.try_regions.iter().zip(&afters) {
+        let join = region.finally_entry().or(after);
+
+        // Normal completion
+        let mut ends: Vec<&str> = region
+            .handlers
+            .iter()
+            .filter_map(|h| h.blocks.last())
+            .chain(region.orelse.last())
+            .map(String::as_str)
+            .collect();
+        if let Some(body_end) = region.body.last() {
+            match region.orelse.first() {
+                Some(orelse) => {
+                    if !no_fallthrough.contains(body_end.as_str()) {
+                        add(body_end, orelse, CFGEdgeType::Unconditional);
+                    }
+                }
+                None => ends.push(body_end),
+            }
+        }
+        if let Some(join) = join {
+            for end in ends {
+                if !no_fallthrough.contains(end) {
+                    add(end, join, CFGEdgeType::Unconditional);
+                }
+            }
+        }
+
+        // Any protected statement may raise
+        let catches_all = region.handlers.iter().any(Handler::catches_all);
+        for block in &region.body {
+            for handler in region.handlers.iter().filter_map(|h| h.blocks.first()) {
+                add(block, handler, CFGEdgeType::Exception);
+            }
+            if !catches_all {
+                if let Some(finally) = region.finally_entry() {
+                    add(block, finally, CFGEdgeType::Exception);
+                }
+            }
+        }
+        if let Some(finally) = region.finally_entry() {
+            let unprotected = region
+                .orelse
+                .iter()
+                .chain(region.handlers.iter().flat_map(|h| h.blocks.iter()));
+            for block in unprotected {
+                add(block, finally, CFGEdgeType::Exception);
+            }
+        }
+
+        // `finally` resumes normal flow or rethrows
+        if let Some(finally_end) = region.finally.last() {
+            if let Some(after) = after {
+                add(finally_end, after, CFGEdgeType::Unconditional);
+            }
+            let escape = flow.escape(finally_end, None);
+            for target in escape.targets {
+                add(finally_end, target, CFGEdgeType::Exception);
+            }
+            if escape.to_exit {
+                add(finally_end, exit, CFGEdgeType::Exception);
+            }
+        }
+    }
+
+    for raise in &flow.raises {
+        let escape = flow.escape(&raise.block_id, raise.exception.as_deref());
+        for target in escape.targets {
+            add(&raise.block_id, target, CFGEdgeType::Exception);
+        }
+        if escape.to_exit {
+            add(&raise.block_id, exit, CFGEdgeType::Exception);
+        }
+    }
+
+    // Returns run the enclosing `finally` blocks, innermost first
+    let mut return_finallies: Vec<&str> = Vec::new();
+    for &(block, finally) in &returns {
+        add(block, finally, CFGEdgeType::Finally);
+        return_finallies.push(finally);
+    }
+    let mut visited = HashSet::new();
+    while let Some(entry) = return_finallies.pop() {
+        if !visited.insert(entry) {
+            continue;
+        }
+        let Some(region) = flow
+            .try_regions
+            .iter()
+            .find(|r| r.finally_entry() == Some(entry))
+        else {
+            continue;
+        };
+        let finally_end = &region.finally[region.finally.len() - 1];
+        match flow.return_target(finally_end) {
+            Some(next) => {
+                add(finally_end, next, CFGEdgeType::Finally);
+                return_finallies.push(next);
+            }
+            None => add(finally_end, exit, CFGEdgeType::Finally),
+        }
+    }
+
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::models::Span;
+
+    fn block(id: &str, kind: &str) -> BlockRef {
+        BlockRef::new(id.to_string(), kind.to_string(), Span::new(1, 0, 1, 0), 1)
+    }
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn has(edges: &[CFGEdge], source: &str, target: &str, edge_type: CFGEdgeType) -> bool {
+        edges.iter().any(|e| {
+            e.source_block_id == source && e.target_block_id == target && e.edge_type == edge_type
+        })
+    }
+
+    #[test]
+    fn test_raise_to_handler_and_finally_ordering() {
+        // try: a; raise ValueError   except KeyError: k   except ValueError: v
+        // finally: f   after
+        let blocks = vec![
+            block("entry", "ENTRY"),
+            block("a", "Statement"),
+            block("raise", "Try"),
+            block("k", "Catch"),
+            block("v", "Catch"),
+            block("f", "Finally"),
+            block("after", "Statement"),
+            block("exit", "EXIT"),
+        ];
+        let flow = ExceptionFlow {
+            try_regions: vec![TryRegion {
+                body: ids(&["a", "raise"]),
+                handlers: vec![
+                    Handler {
+                        blocks: ids(&["k"]),
+                        caught: ids(&["KeyError"]),
+                    },
+                    Handler {
+                        blocks: ids(&["v"]),
+                        caught: ids(&["ValueError"]),
+                    },
+                ],
+                orelse: Vec::new(),
+                finally: ids(&["f"]),
+            }],
+            raises: vec![RaiseSite {
+                block_id: "raise".to_string(),
+                exception: Some("ValueError".to_string()),
+            }],
+            returns: Vec::new(),
+        };
+
+        let edges = build_cfg_edges_with_exceptions(&blocks, &flow);
+
+        assert!(has(&edges, "raise", "v", CFGEdgeType::Exception));
+        assert!(!has(&edges, "raise", "k", CFGEdgeType::Exception));
+        assert!(has(&edges, "a", "k", CFGEdgeType::Exception));
+        // Handlers are not entered sequentially, the raise does not fall through
+        assert!(!edges
+            .iter()
+            .any(|e| e.target_block_id == "k" && e.edge_type != CFGEdgeType::Exception));
+        assert!(!has(&edges, "raise", "k", CFGEdgeType::Unconditional));
+        assert!(has(&edges, "k", "f", CFGEdgeType::Unconditional));
+        assert!(has(&edges, "v", "f", CFGEdgeType::Unconditional));
+        assert!(has(&edges, "f", "after", CFGEdgeType::Unconditional));
+        // No catch-all: exceptions pass through finally to the exit
+        assert!(has(&edges, "a", "f", CFGEdgeType::Exception));
+        assert!(has(&edges, "f", "exit", CFGEdgeType::Exception));
+
+        let summary = flow.summary();
+        assert_eq!(summary.raises, vec!["ValueError"]);
+        assert_eq!(summary.catches, vec!["KeyError", "ValueError"]);
+        assert!(summary.uncaught.is_empty());
+    }
+
+    #[test]
+    fn test_return_runs_finally_and_uncaught_raise_exits() {
+        let blocks = vec![
+            block("entry", "ENTRY"),
+            block("ret", "Try"),
+            block("f", "Finally"),
+            block("raise", "Statement"),
+            block("exit", "EXIT"),
+        ];
+        let flow = ExceptionFlow {
+            try_regions: vec![TryRegion {
+                body: ids(&["ret"]),
+                handlers: Vec::new(),
+                orelse: Vec::new(),
+                finally: ids(&["f"]),
+            }],
+            raises: vec![RaiseSite {
+                block_id: "raise".to_string(),
+                exception: Some("OSError".to_string()),
+            }],
+            returns: ids(&["ret"]),
+        };
+
+        let edges = build_cfg_edges_with_exceptions(&blocks, &flow);
+
+        assert!(has(&edges, "ret", "f", CFGEdgeType::Finally));
+        assert!(has(&edges, "f", "exit", CFGEdgeType::Finally));
+        assert!(has(&edges, "raise", "exit", CFGEdgeType::Exception));
+        assert!(!edges
+            .iter()
+            .any(|e| e.source_block_id == "raise" && e.edge_type != CFGEdgeType::Exception));
+        assert_eq!(flow.summary().uncaught, vec!["OSError"]);
+    }
+}
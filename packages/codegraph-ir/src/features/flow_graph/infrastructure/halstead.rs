@@ -0,0 +1,132 @@
+//! Halstead operator/operand counts from the tree-sitter syntax tree
+//!
+//! Language-agnostic token classification:
+//!
+//! - operands: named leaves (identifiers, numbers, `True`/`None`, ...) and
+//!   string literals as a whole, keyed by their text
+//! - operators: anonymous leaves (keywords, punctuation, operators), keyed by
+//!   kind; closing brackets are counted with their opening one
+//! - comments and zero-width nodes are ignored
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use tree_sitter::Node as TSNode;
+
+/// Distinct and total operators/operands of a syntax tree
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HalsteadCounts {
+    /// n1
+    pub distinct_operators: u32,
+    /// n2
+    pub distinct_operands: u32,
+    /// N1
+    pub total_operators: u32,
+    /// N2
+    pub total_operands: u32,
+}
+
+impl HalsteadCounts {
+    /// Counts over the tree rooted at `root`, parsed from `source`
+    pub fn from_tree(root: &TSNode, source: &str) -> Self {
+        let mut operators: HashSet<&str> = HashSet::new();
+        let mut operands: HashSet<&str> = HashSet::new();
+        let mut counts = Self::default();
+
+        let mut cursor = root.walk();
+        loop {
+            let node = cursor.node();
+            let kind = node.kind();
+            let text = source.get(node.byte_range()).unwrap_or_default();
+            let mut descend = false;
+            if kind.contains("comment") || text.trim().is_empty() {
+                // Skipped with its subtree
+            } else if node.is_named() && (node.child_count() == 0 || is_string(kind)) {
+                operands.insert(text);
+                counts.total_operands += 1;
+            } else if node.child_count() == 0 {
+                if !matches!(kind, ")" | "]" | "}") {
+                    operators.insert(kind);
+                    counts.total_operators += 1;
+                }
+            } else {
+                descend = true;
+            }
+
+            if descend && cursor.goto_first_child() {
+                continue;
+            }
+            while !cursor.goto_next_sibling() {
+                if !cursor.goto_parent() {
+                    counts.distinct_operators = operators.len() as u32;
+                    counts.distinct_operands = operands.len() as u32;
+                    return counts;
+                }
+            }
+        }
+    }
+
+    /// n = n1 + n2
+    pub fn vocabulary(&self) -> u32 {
+        self.distinct_operators + self.distinct_operands
+    }
+
+    /// N = N1 + N2
+    pub fn length(&self) -> u32 {
+        self.total_operators + self.total_operands
+    }
+
+    /// V = N * log2(n)
+    pub fn volume(&self) -> f64 {
+        let vocabulary = self.vocabulary();
+        if vocabulary < 2 {
+            return 0.0;
+        }
+        f64::from(self.length()) * f64::from(vocabulary).log2()
+    }
+
+    /// D = (n1 / 2) * (N2 / n2)
+    pub fn difficulty(&self) -> f64 {
+        if self.distinct_operands == 0 {
+            return 0.0;
+        }
+        f64::from(self.distinct_operators) / 2.0
+            * (f64::from(self.total_operands) / f64::from(self.distinct_operands))
+    }
+
+    /// E = D * V
+    pub fn effort(&self) -> f64 {
+        self.difficulty() * self.volume()
+    }
+}
+
+/// String literal nodes are one operand (not their fragments and quotes)
+fn is_string(kind: &str) -> bool {
+    kind.contains("string") || kind == "char_literal" || kind == "character_literal"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tree_sitter::Parser;
+
+    #[test]
+    fn test_counts_python_tokens() {
+        // x = a + 1  /  y = "a b" # note
+        let source = "x = a + 1\ny = \"a b\"  # note\n";
+        let mut parser = Parser::new();
+        parser
+            .set_language(&tree_sitter_python::language())
+            .unwrap();
+        let tree = parser.parse(source, None).unwrap();
+
+        let counts = HalsteadCounts::from_tree(&tree.root_node(), source);
+        // Operators: = (x2), +; operands: x, a, 1, y, "a b"
+        assert_eq!(counts.distinct_operators, 2);
+        assert_eq!(counts.total_operators, 3);
+        assert_eq!(counts.distinct_operands, 5);
+        assert_eq!(counts.total_operands, 5);
+        assert_eq!(counts.vocabulary(), 7);
+        assert!((counts.volume() - 8.0 * 7f64.log2()).abs() < 1e-9);
+        assert_eq!(counts.difficulty(), 1.0);
+    }
+}
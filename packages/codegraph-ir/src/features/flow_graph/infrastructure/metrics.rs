@@ -0,0 +1,348 @@
+//! Per-function complexity metrics from the BFG
+//!
+//! - cyclomatic: 1 + decision blocks (Condition, LoopHeader, Catch)
+//! - cognitive: each decision adds 1 plus its nesting level; `elif` /
+//!   `else if` adds 1 without the nesting bonus (Sonar-style, boolean
+//!   operator sequences are not visible in the BFG)
+//! - max_nesting: deepest chain of nested decisions (0 = straight-line code)
+//! - loc: non-blank lines of the function span
+//!
+//! BFG blocks are flat, so nesting is recovered from the indentation of the
+//! line each decision starts on.
+//!
+//! Per file, `FileMetrics` adds Halstead measures and the maintainability
+//! index (0-100, Visual Studio scale):
+//!
+//! `MI = max(0, (171 - 5.2 ln V - 0.23 G - 16.2 ln LOC) * 100 / 171)`
+//!
+//! with V the Halstead volume and G the summed cyclomatic complexity of the
+//! file's functions. Rows are exported as `FileMetricsTable` (JSON) and the
+//! `file_metrics` Arrow stream.
+
+use serde::{Deserialize, Serialize};
+
+use super::bfg::BasicFlowGraph;
+use super::halstead::HalsteadCounts;
+use crate::shared::models::span_ref::BlockRef;
+use crate::shared::models::{Node, Result, Span};
+
+/// Node metadata key holding a function's metrics
+pub const METRICS_KEY: &str = "metrics";
+
+/// Complexity metrics of one function
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionMetrics {
+    pub cyclomatic: u32,
+    pub cognitive: u32,
+    pub max_nesting: u32,
+    pub loc: u32,
+}
+
+impl FunctionMetrics {
+    /// Metrics of `bfg`, a function spanning `span` in `source`
+    pub fn from_bfg(bfg: &BasicFlowGraph, source: &str, span: Span) -> Self {
+        let lines: Vec<&str> = source.lines().collect();
+
+        // (line, indentation) of each decision, in source order
+        let mut decisions: Vec<(usize, usize, &BlockRef)> = bfg
+            .blocks
+            .iter()
+            .filter(|b| is_decision(b))
+            .filter_map(|b| decision_anchor(b, &lines).map(|(line, indent)| (line, indent, b)))
+            .collect();
+        decisions.sort_by_key(|&(line, _, _)| line);
+
+        // Indentation of the enclosing decisions
+        let mut open: Vec<usize> = Vec::new();
+        let mut cognitive = 0;
+        let mut max_nesting = 0;
+        for (line, indent, block) in decisions {
+            while open.last().is_some_and(|&outer| outer >= indent) {
+                open.pop();
+            }
+            let nesting = open.len() as u32;
+            cognitive += if is_else_if(block, lines[line]) {
+                1
+            } else {
+                1 + nesting
+            };
+            max_nesting = max_nesting.max(nesting + 1);
+            open.push(indent);
+        }
+
+        let first = span.start_line.saturating_sub(1) as usize;
+        let last = (span.end_line as usize).min(lines.len());
+        let loc = lines
+            .get(first..last)
+            .unwrap_or_default()
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .count() as u32;
+
+        Self {
+            cyclomatic: cyclomatic_complexity(&bfg.blocks),
+            cognitive,
+            max_nesting,
+            loc,
+        }
+    }
+
+    /// Metrics attached to a function node by the metrics stage
+    pub fn from_node(node: &Node) -> Option<Self> {
+        let metadata: serde_json::Value = serde_json::from_str(node.metadata.as_deref()?).ok()?;
+        serde_json::from_value(metadata.get(METRICS_KEY)?.clone()).ok()
+    }
+}
+
+/// Size, complexity and maintainability of one file
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FileMetrics {
+    pub file_path: String,
+    pub language: String,
+    /// Non-blank lines
+    pub loc: u32,
+    pub functions: u32,
+    /// Sum over functions (1 for a file without functions)
+    pub cyclomatic: u32,
+    /// Sum over functions
+    pub cognitive: u32,
+    pub halstead_vocabulary: u32,
+    pub halstead_length: u32,
+    pub halstead_volume: f64,
+    pub halstead_difficulty: f64,
+    pub halstead_effort: f64,
+    pub maintainability_index: f64,
+}
+
+impl FileMetrics {
+    pub fn new(
+        file_path: impl Into<String>,
+        language: impl Into<String>,
+        source: &str,
+        halstead: &HalsteadCounts,
+        functions: &[FunctionMetrics],
+    ) -> Self {
+        let loc = source.lines().filter(|l| !l.trim().is_empty()).count() as u32;
+        let cyclomatic = functions.iter().map(|f| f.cyclomatic).sum::<u32>().max(1);
+        let volume = halstead.volume();
+        Self {
+            file_path: file_path.into(),
+            language: language.into(),
+            loc,
+            functions: functions.len() as u32,
+            cyclomatic,
+            cognitive: functions.iter().map(|f| f.cognitive).sum(),
+            halstead_vocabulary: halstead.vocabulary(),
+            halstead_length: halstead.length(),
+            halstead_volume: volume,
+            halstead_difficulty: halstead.difficulty(),
+            halstead_effort: halstead.effort(),
+            maintainability_index: maintainability_index(volume, cyclomatic, loc),
+        }
+    }
+}
+
+/// Maintainability index on a 0-100 scale (higher is easier to maintain)
+pub fn maintainability_index(volume: f64, cyclomatic: u32, loc: u32) -> f64 {
+    let raw = 171.0
+        - 5.2 * volume.max(1.0).ln()
+        - 0.23 * f64::from(cyclomatic)
+        - 16.2 * f64::from(loc.max(1)).ln();
+    (raw * 100.0 / 171.0).clamp(0.0, 100.0)
+}
+
+/// Current `FileMetricsTable::version`
+pub const FILE_METRICS_TABLE_VERSION: u32 = 1;
+
+/// File metrics of one run, the JSON export for quality dashboards
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FileMetricsTable {
+    pub version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot_id: Option<String>,
+    /// Ordered by path
+    pub files: Vec<FileMetrics>,
+}
+
+impl FileMetricsTable {
+    pub fn new(snapshot_id: Option<String>, mut files: Vec<FileMetrics>) -> Self {
+        files.sort_by(|a, b| a.file_path.cmp(&b.file_path));
+        Self {
+            version: FILE_METRICS_TABLE_VERSION,
+            snapshot_id,
+            files,
+        }
+    }
+
+    pub fn file(&self, file_path: &str) -> Option<&FileMetrics> {
+        self.files.iter().find(|f| f.file_path == file_path)
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+/// 1 + number of decision blocks
+pub fn cyclomatic_complexity(blocks: &[BlockRef]) -> u32 {
+    1 + blocks.iter().filter(|b| is_decision(b)).count() as u32
+}
+
+fn is_decision(block: &BlockRef) -> bool {
+    matches!(
+        block.kind.as_str(),
+        "Condition" | "BRANCH" | "LoopHeader" | "LOOP" | "Catch"
+    )
+}
+
+/// Line index and indentation of the statement a decision block belongs to
+fn decision_anchor(block: &BlockRef, lines: &[&str]) -> Option<(usize, usize)> {
+    let span = block.span_ref.span;
+    let index = span.start_line.checked_sub(1)? as usize;
+    let indent = indentation(lines.get(index)?);
+    // Catch blocks hold (the tail of) the handler body; on its own line, the
+    // handler (`except ...:` / `} catch (..) {`) is the nearest less indented
+    // line above
+    if block.kind == "Catch" && indent >= span.start_col as usize {
+        let handler = (0..index)
+            .rev()
+            .filter(|&i| !lines[i].trim().is_empty())
+            .map(|i| (i, indentation(lines[i])))
+            .find(|&(_, i)| i < indent);
+        return handler.or(Some((index, indent)));
+    }
+    Some((index, indent))
+}
+
+fn is_else_if(block: &BlockRef, line: &str) -> bool {
+    if !matches!(block.kind.as_str(), "Condition" | "BRANCH") {
+        return false;
+    }
+    let line = line.trim_start().trim_start_matches('}').trim_start();
+    line.starts_with("elif") || line.starts_with("else")
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(kind: &str, line: u32, col: u32) -> BlockRef {
+        BlockRef::new(
+            format!("bfg:f:block:{}", line),
+            kind.to_string(),
+            Span::new(line, col, line, col + 5),
+            1,
+        )
+    }
+
+    #[test]
+    fn test_metrics_from_nested_decisions() {
+        let source = "\
+def f(items):
+    for item in items:
+        if item:
+            x = 1
+        elif item is None:
+            x = 2
+
+    try:
+        g()
+    except ValueError:
+        if x:
+            pass
+        log()
+    return x
+";
+        let bfg = BasicFlowGraph {
+            id: "bfg:f".to_string(),
+            function_id: "f".to_string(),
+            entry_block_id: "bfg:f:entry".to_string(),
+            exit_block_id: "bfg:f:exit".to_string(),
+            blocks: vec![
+                block("ENTRY", 2, 4),
+                block("LoopHeader", 2, 8),
+                block("Condition", 3, 11),
+                block("Statement", 4, 12),
+                block("Condition", 5, 13),
+                block("Statement", 6, 12),
+                block("Try", 9, 8),
+                // The nested condition is flushed before the handler tail
+                block("Condition", 11, 11),
+                block("Statement", 12, 12),
+                block("Catch", 13, 8),
+                block("Statement", 14, 4),
+                block("EXIT", 2, 4),
+            ],
+            total_statements: 8,
+            exception_flow: Default::default(),
+        };
+
+        let metrics = FunctionMetrics::from_bfg(&bfg, source, Span::new(1, 0, 14, 12));
+        assert_eq!(metrics.cyclomatic, 6);
+        // for 1, if 2, elif 1, except 1, nested if 2
+        assert_eq!(metrics.cognitive, 7);
+        assert_eq!(metrics.max_nesting, 2);
+        assert_eq!(metrics.loc, 13);
+
+        let node = Node::new(
+            "func:f".to_string(),
+            crate::shared::models::NodeKind::Function,
+            "m.f".to_string(),
+            "m.py".to_string(),
+            Span::new(1, 0, 14, 12),
+        );
+        assert_eq!(FunctionMetrics::from_node(&node), None);
+    }
+
+    #[test]
+    fn test_file_metrics_and_table_round_trip() {
+        let halstead = HalsteadCounts {
+            distinct_operators: 10,
+            distinct_operands: 20,
+            total_operators: 60,
+            total_operands: 40,
+        };
+        let functions = [
+            FunctionMetrics {
+                cyclomatic: 3,
+                cognitive: 2,
+                max_nesting: 1,
+                loc: 5,
+            },
+            FunctionMetrics {
+                cyclomatic: 1,
+                cognitive: 0,
+                max_nesting: 0,
+                loc: 2,
+            },
+        ];
+        let source = "def a():\n    pass\n\ndef b():\n    pass\n";
+        let metrics = FileMetrics::new("m.py", "python", source, &halstead, &functions);
+        assert_eq!(metrics.loc, 4);
+        assert_eq!(metrics.cyclomatic, 4);
+        assert_eq!(metrics.cognitive, 2);
+        assert_eq!(metrics.halstead_length, 100);
+        assert!((metrics.halstead_volume - 100.0 * 30f64.log2()).abs() < 1e-9);
+        assert_eq!(metrics.halstead_difficulty, 10.0);
+        assert!(metrics.maintainability_index > 0.0 && metrics.maintainability_index < 100.0);
+        // More code, lower index
+        assert!(maintainability_index(5000.0, 40, 400) < metrics.maintainability_index);
+        assert_eq!(maintainability_index(1e12, 1000, 1_000_000), 0.0);
+
+        let other = FileMetrics::new("a.py", "python", "", &HalsteadCounts::default(), &[]);
+        assert_eq!(other.cyclomatic, 1);
+        let table = FileMetricsTable::new(Some("snap-1".to_string()), vec![metrics, other]);
+        assert_eq!(table.files[0].file_path, "a.py");
+        let json = table.to_json().unwrap();
+        assert_eq!(FileMetricsTable::from_json(&json).unwrap(), table);
+        assert_eq!(table.file("m.py").unwrap().functions, 2);
+    }
+}
@@ -0,0 +1,144 @@
+//! Snapshot-keyed persistence for file metrics
+//!
+//! `FileMetricsTable`s are saved to codegraph-storage's `AnalysisResultStore`
+//! under `AnalysisKind::Metrics`: the table is the report and every file is a
+//! finding keyed by its path, so dashboards read one file's metrics across
+//! snapshots with per-file queries instead of loading whole tables.
+
+use std::sync::Arc;
+
+use codegraph_storage::{AnalysisFinding, AnalysisKind, AnalysisResult, AnalysisResultStore};
+
+use super::metrics::{FileMetrics, FileMetricsTable};
+use crate::shared::models::{CodegraphError, Result};
+
+fn storage_error(err: codegraph_storage::StorageError) -> CodegraphError {
+    CodegraphError::storage(err.message)
+}
+
+/// File metrics of one snapshot, with history over the other snapshots
+pub struct SnapshotMetricsStore {
+    store: Arc<dyn AnalysisResultStore>,
+    snapshot_id: String,
+}
+
+impl SnapshotMetricsStore {
+    pub fn new(store: Arc<dyn AnalysisResultStore>, snapshot_id: impl Into<String>) -> Self {
+        Self {
+            store,
+            snapshot_id: snapshot_id.into(),
+        }
+    }
+
+    /// Same store, saving under another snapshot
+    pub fn for_snapshot(&self, snapshot_id: impl Into<String>) -> Self {
+        Self::new(Arc::clone(&self.store), snapshot_id)
+    }
+
+    pub fn snapshot_id(&self) -> &str {
+        &self.snapshot_id
+    }
+
+    /// Save (or replace) the snapshot's metrics
+    pub async fn save(&self, files: Vec<FileMetrics>) -> Result<FileMetricsTable> {
+        let table = FileMetricsTable::new(Some(self.snapshot_id.clone()), files);
+        let findings = table
+            .files
+            .iter()
+            .map(|file| {
+                Ok(
+                    AnalysisFinding::new(&file.file_path, serde_json::to_value(file)?)
+                        .with_file(&file.file_path),
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let result = AnalysisResult::new(
+            &self.snapshot_id,
+            AnalysisKind::Metrics,
+            serde_json::to_value(&table)?,
+        )
+        .with_findings(findings);
+        self.store
+            .save_results(&result)
+            .await
+            .map_err(storage_error)?;
+        Ok(table)
+    }
+
+    /// Metrics table of an analyzed snapshot
+    pub async fn load(&self, snapshot_id: &str) -> Result<FileMetricsTable> {
+        let result = self
+            .store
+            .get_results(snapshot_id, AnalysisKind::Metrics)
+            .await
+            .map_err(storage_error)?;
+        Ok(serde_json::from_value(result.report)?)
+    }
+
+    /// Snapshots with stored metrics (newest first)
+    pub async fn snapshots(&self) -> Result<Vec<String>> {
+        self.store
+            .list_analyzed_snapshots(AnalysisKind::Metrics)
+            .await
+            .map_err(storage_error)
+    }
+
+    /// `(snapshot_id, metrics)` of one file, oldest snapshot first; snapshots
+    /// without the file are left out
+    pub async fn file_trend(&self, file_path: &str) -> Result<Vec<(String, FileMetrics)>> {
+        let mut trend = Vec::new();
+        for snapshot_id in self.snapshots().await?.into_iter().rev() {
+            let findings = self
+                .store
+                .get_file_findings(&snapshot_id, AnalysisKind::Metrics, file_path)
+                .await
+                .map_err(storage_error)?;
+            if let Some(finding) = findings.into_iter().next() {
+                trend.push((snapshot_id, serde_json::from_value(finding.payload)?));
+            }
+        }
+        Ok(trend)
+    }
+}
+
+#[cfg(all(test, feature = "sqlite"))]
+mod tests {
+    use super::*;
+    use codegraph_storage::infrastructure::SqliteAnalysisResultStore;
+
+    fn metrics(file_path: &str, maintainability_index: f64) -> FileMetrics {
+        FileMetrics {
+            file_path: file_path.to_string(),
+            language: "python".to_string(),
+            maintainability_index,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_save_load_and_file_trend() {
+        let store = Arc::new(SqliteAnalysisResultStore::new_in_memory().unwrap());
+        let base = SnapshotMetricsStore::new(store, "base");
+        let head = base.for_snapshot("head");
+
+        base.save(vec![metrics("b.py", 80.0), metrics("a.py", 70.0)])
+            .await
+            .unwrap();
+        // Snapshots are ordered by save time
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        head.save(vec![metrics("a.py", 55.0)]).await.unwrap();
+
+        let table = head.load("base").await.unwrap();
+        assert_eq!(table.snapshot_id.as_deref(), Some("base"));
+        assert_eq!(table.files[0].file_path, "a.py");
+
+        let trend = head.file_trend("a.py").await.unwrap();
+        let points: Vec<_> = trend
+            .iter()
+            .map(|(id, m)| (id.as_str(), m.maintainability_index))
+            .collect();
+        assert_eq!(points, vec![("base", 70.0), ("head", 55.0)]);
+        assert_eq!(head.file_trend("b.py").await.unwrap().len(), 1);
+        assert!(head.load("missing").await.is_err());
+    }
+}
@@ -2,8 +2,21 @@
 
 pub mod bfg;
 pub mod cfg;
+pub mod exception_flow;
 pub mod exceptional_cfg_builder;
+pub mod halstead;
+pub mod metrics;
+pub mod metrics_store; // File metrics persisted per snapshot (codegraph-storage)
 
 pub use bfg::*;
 pub use cfg::*;
+pub use exception_flow::{
+    build_cfg_edges_with_exceptions, ExceptionFlow, ExceptionSummary, EXCEPTIONS_KEY,
+};
 pub use exceptional_cfg_builder::ExceptionalCFGBuilder;
+pub use halstead::HalsteadCounts;
+pub use metrics::{
+    cyclomatic_complexity, maintainability_index, FileMetrics, FileMetricsTable,
+    FunctionMetrics, FILE_METRICS_TABLE_VERSION, METRICS_KEY,
+};
+pub use metrics_store::SnapshotMetricsStore;
@@ -0,0 +1,5 @@
+//! Function Summaries Application Layer
+
+mod summary_cache;
+
+pub use summary_cache::{FunctionSummaries, SummaryCache, SummaryRun, SummaryStats};
@@ -0,0 +1,400 @@
+//! Incremental summary computation over the call graph
+//!
+//! Functions are visited in SCC order (callees first). An SCC is reused from
+//! the store when every member has a record for its current body and the
+//! callee digests it was built from are unchanged; otherwise its locals are
+//! recomputed and `transfer` is iterated to a fixpoint. A changed function
+//! only invalidates callers when its summary actually changes.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use petgraph::algo::tarjan_scc;
+use petgraph::graph::{DiGraph, NodeIndex};
+
+use crate::features::cross_file::IRDocument;
+use crate::features::effect_analysis::EffectSet;
+use crate::features::function_summaries::domain::{
+    stable_id, summary_digest, SummaryAnalysis, SummaryKey, SummaryRecord, SummaryStore,
+};
+use crate::features::function_summaries::infrastructure::{
+    EffectSummaryAnalysis, EscapeSummaryAnalysis, InMemorySummaryStore, TaintTransferAnalysis,
+};
+use crate::features::heap_analysis::FunctionEscapeInfo;
+use crate::features::taint_analysis::{FunctionSummaryCache, FunctionTaintSummary};
+use crate::shared::models::{EdgeKind, Node, NodeKind, Result};
+
+/// Upper bound on fixpoint rounds inside one recursive SCC
+const MAX_FIXPOINT_ROUNDS: usize = 16;
+
+/// Counters for one `SummaryCache::compute`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SummaryStats {
+    pub functions: usize,
+    pub reused: usize,
+    pub recomputed: usize,
+    pub recomputed_sccs: usize,
+}
+
+/// Summaries of one analysis, by function node id
+#[derive(Debug, Clone)]
+pub struct SummaryRun<S> {
+    pub summaries: HashMap<String, S>,
+    pub stats: SummaryStats,
+}
+
+impl<S> SummaryRun<S> {
+    pub fn get(&self, function_id: &str) -> Option<&S> {
+        self.summaries.get(function_id)
+    }
+}
+
+impl SummaryRun<FunctionTaintSummary> {
+    /// Seed the taint analyzer's LRU cache with these summaries
+    pub fn to_summary_cache(&self) -> FunctionSummaryCache {
+        let mut cache = FunctionSummaryCache::new(self.summaries.len().max(1));
+        for summary in self.summaries.values() {
+            cache.put(summary.clone());
+        }
+        cache
+    }
+}
+
+/// Results of `SummaryCache::compute_all`
+#[derive(Debug, Clone)]
+pub struct FunctionSummaries {
+    pub taint: SummaryRun<FunctionTaintSummary>,
+    pub effects: SummaryRun<EffectSet>,
+    pub escape: SummaryRun<FunctionEscapeInfo>,
+}
+
+/// Computes function summaries, reusing stored ones for unchanged functions
+#[derive(Clone)]
+pub struct SummaryCache {
+    store: Arc<dyn SummaryStore>,
+}
+
+impl SummaryCache {
+    pub fn new(store: Arc<dyn SummaryStore>) -> Self {
+        Self { store }
+    }
+
+    pub fn in_memory() -> Self {
+        Self::new(Arc::new(InMemorySummaryStore::new()))
+    }
+
+    pub fn store(&self) -> &Arc<dyn SummaryStore> {
+        &self.store
+    }
+
+    /// Taint transfer, effects and escape with the default analyses
+    pub fn compute_all(&self, ir_doc: &IRDocument) -> Result<FunctionSummaries> {
+        Ok(FunctionSummaries {
+            taint: self.compute(&TaintTransferAnalysis::default(), ir_doc)?,
+            effects: self.compute(&EffectSummaryAnalysis::default(), ir_doc)?,
+            escape: self.compute(&EscapeSummaryAnalysis::default(), ir_doc)?,
+        })
+    }
+
+    pub fn compute<A: SummaryAnalysis>(
+        &self,
+        analysis: &A,
+        ir_doc: &IRDocument,
+    ) -> Result<SummaryRun<A::Summary>> {
+        let functions: Vec<&Node> = ir_doc
+            .nodes
+            .iter()
+            .filter(|n| matches!(n.kind, NodeKind::Function | NodeKind::Method))
+            .collect();
+        let callees = resolve_callees(&functions, ir_doc);
+
+        let mut graph: DiGraph<usize, ()> = DiGraph::new();
+        let indices: Vec<NodeIndex> = (0..functions.len()).map(|i| graph.add_node(i)).collect();
+        for (caller, targets) in callees.iter().enumerate() {
+            for &callee in targets {
+                graph.add_edge(indices[caller], indices[callee], ());
+            }
+        }
+
+        let keys: Vec<Option<SummaryKey>> =
+            functions.iter().map(|f| SummaryKey::for_node(f)).collect();
+        let wanted: Vec<SummaryKey> = keys.iter().flatten().cloned().collect();
+        let stored: HashMap<SummaryKey, SummaryRecord> = self
+            .store
+            .load(analysis.kind(), &wanted)?
+            .into_iter()
+            .filter(|r| r.version == analysis.version())
+            .map(|r| (r.key.clone(), r))
+            .collect();
+
+        let mut summaries: Vec<Option<A::Summary>> = vec![None; functions.len()];
+        let mut digests: Vec<String> = vec![String::new(); functions.len()];
+        let mut dirty = Vec::new();
+        let mut stats = SummaryStats {
+            functions: functions.len(),
+            ..Default::default()
+        };
+
+        // tarjan_scc yields SCCs in postorder: callees before callers
+        for scc in tarjan_scc(&graph) {
+            let members: Vec<usize> = scc.iter().map(|&ix| graph[ix]).collect();
+
+            if let Some(reused) =
+                reuse_scc::<A>(&members, &functions, &keys, &stored, &callees, &digests)
+            {
+                for (member, (summary, digest)) in members.iter().zip(reused) {
+                    summaries[*member] = Some(summary);
+                    digests[*member] = digest;
+                }
+                stats.reused += members.len();
+                continue;
+            }
+
+            let locals: HashMap<usize, A::Summary> = members
+                .iter()
+                .map(|&m| (m, analysis.local(functions[m], ir_doc)))
+                .collect();
+            for &m in &members {
+                summaries[m] = Some(locals[&m].clone());
+            }
+
+            let cyclic = members.len() > 1 || callees[members[0]].contains(&members[0]);
+            let rounds = if cyclic { MAX_FIXPOINT_ROUNDS } else { 1 };
+            for _ in 0..rounds {
+                let mut changed = false;
+                for &m in &members {
+                    let next = {
+                        let inputs: Vec<&A::Summary> = callees[m]
+                            .iter()
+                            .filter_map(|&c| summaries[c].as_ref())
+                            .collect();
+                        analysis.transfer(&locals[&m], &inputs)
+                    };
+                    if summaries[m].as_ref() != Some(&next) {
+                        summaries[m] = Some(next);
+                        changed = true;
+                    }
+                }
+                if !changed {
+                    break;
+                }
+            }
+
+            let mut values = Vec::with_capacity(members.len());
+            for &m in &members {
+                let value = serde_json::to_value(summaries[m].as_ref())?;
+                digests[m] = summary_digest(&value);
+                values.push(value);
+            }
+            for (&m, summary) in members.iter().zip(values) {
+                if let Some(key) = &keys[m] {
+                    let record = SummaryRecord {
+                        key: key.clone(),
+                        kind: analysis.kind().to_string(),
+                        version: analysis.version(),
+                        dependencies: dependencies(&functions, &callees[m], &digests),
+                        digest: digests[m].clone(),
+                        summary,
+                    };
+                    dirty.push(record);
+                }
+            }
+            stats.recomputed += members.len();
+            stats.recomputed_sccs += 1;
+        }
+
+        if !dirty.is_empty() {
+            self.store.save(&dirty)?;
+        }
+
+        let summaries = functions
+            .iter()
+            .zip(summaries)
+            .filter_map(|(f, s)| s.map(|s| (f.id.clone(), s)))
+            .collect();
+        Ok(SummaryRun { summaries, stats })
+    }
+}
+
+/// Stored summaries and digests for `members` when all are still valid
+fn reuse_scc<A: SummaryAnalysis>(
+    members: &[usize],
+    functions: &[&Node],
+    keys: &[Option<SummaryKey>],
+    stored: &HashMap<SummaryKey, SummaryRecord>,
+    callees: &[Vec<usize>],
+    digests: &[String],
+) -> Option<Vec<(A::Summary, String)>> {
+    let records: Vec<&SummaryRecord> = members
+        .iter()
+        .map(|&m| keys[m].as_ref().and_then(|key| stored.get(key)))
+        .collect::<Option<_>>()?;
+
+    // Members' stored digests stand in for callees inside the SCC
+    let mut current = digests.to_vec();
+    for (&m, record) in members.iter().zip(&records) {
+        current[m] = record.digest.clone();
+    }
+
+    members
+        .iter()
+        .zip(records)
+        .map(|(&m, record)| {
+            let unchanged = record.dependencies == dependencies(functions, &callees[m], &current);
+            if !unchanged {
+                return None;
+            }
+            let summary = serde_json::from_value(record.summary.clone()).ok()?;
+            Some((summary, record.digest.clone()))
+        })
+        .collect()
+}
+
+fn dependencies(
+    functions: &[&Node],
+    callees: &[usize],
+    digests: &[String],
+) -> BTreeMap<String, String> {
+    callees
+        .iter()
+        .map(|&c| (stable_id(functions[c]).to_string(), digests[c].clone()))
+        .collect()
+}
+
+/// Resolved CALLS / INVOKES targets per function (by id, FQN, then unique
+/// bare name), sorted and deduplicated
+fn resolve_callees(functions: &[&Node], ir_doc: &IRDocument) -> Vec<Vec<usize>> {
+    let mut by_id = HashMap::new();
+    let mut by_fqn = HashMap::new();
+    let mut by_name: HashMap<&str, Option<usize>> = HashMap::new();
+    for (i, f) in functions.iter().enumerate() {
+        by_id.insert(f.id.as_str(), i);
+        by_fqn.insert(f.fqn.as_str(), i);
+        by_name
+            .entry(bare_name(&f.fqn))
+            .and_modify(|slot| *slot = None)
+            .or_insert(Some(i));
+    }
+
+    let mut callees = vec![Vec::new(); functions.len()];
+    for edge in &ir_doc.edges {
+        if !matches!(edge.kind, EdgeKind::Calls | EdgeKind::Invokes) {
+            continue;
+        }
+        let Some(&caller) = by_id.get(edge.source_id.as_str()) else {
+            continue;
+        };
+        let target = edge.target_id.as_str();
+        let callee = by_id
+            .get(target)
+            .or_else(|| by_fqn.get(target))
+            .copied()
+            .or_else(|| by_name.get(bare_name(target)).copied().flatten());
+        if let Some(callee) = callee {
+            callees[caller].push(callee);
+        }
+    }
+    for targets in &mut callees {
+        targets.sort_unstable();
+        targets.dedup();
+    }
+    callees
+}
+
+fn bare_name(fqn: &str) -> &str {
+    fqn.rsplit('.').next().unwrap_or(fqn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::models::{Edge, Span};
+
+    fn function(name: &str, hash: &str) -> Node {
+        let mut node = Node::new(
+            format!("id:{}", name),
+            NodeKind::Function,
+            format!("app.{}", name),
+            "app.py".to_string(),
+            Span::new(1, 0, 2, 0),
+        )
+        .with_name(name);
+        node.content_hash = Some(hash.to_string());
+        node
+    }
+
+    fn calls(caller: &str, callee: &str) -> Edge {
+        Edge::new(
+            format!("id:{}", caller),
+            callee.to_string(),
+            EdgeKind::Calls,
+        )
+    }
+
+    /// handler -> load -> get_param (source), handler -> helper
+    fn chain(load_hash: &str, load_calls_source: bool) -> IRDocument {
+        let nodes = vec![
+            function("handler", "h1"),
+            function("load", load_hash),
+            function("helper", "x1"),
+        ];
+        let mut edges = vec![
+            calls("handler", "external.load"),
+            calls("handler", "app.helper"),
+        ];
+        if load_calls_source {
+            edges.push(calls("load", "external.get_param"));
+        }
+        IRDocument::new("app.py".to_string(), nodes, edges)
+    }
+
+    #[test]
+    fn test_unchanged_functions_are_reused() {
+        let cache = SummaryCache::in_memory();
+        let analysis = TaintTransferAnalysis::default();
+
+        let first = cache.compute(&analysis, &chain("l1", true)).unwrap();
+        assert_eq!(first.stats.recomputed, 3);
+        assert!(first.get("id:handler").unwrap().tainted_return);
+
+        let second = cache.compute(&analysis, &chain("l1", true)).unwrap();
+        assert_eq!(second.stats.reused, 3);
+        assert_eq!(second.stats.recomputed, 0);
+        assert_eq!(second.summaries, first.summaries);
+    }
+
+    #[test]
+    fn test_changed_callee_invalidates_callers_only_when_summary_changes() {
+        let cache = SummaryCache::in_memory();
+        let analysis = TaintTransferAnalysis::default();
+        cache.compute(&analysis, &chain("l1", true)).unwrap();
+
+        // New body, same summary: callers stay cached
+        let same = cache.compute(&analysis, &chain("l2", true)).unwrap();
+        assert_eq!(same.stats.recomputed, 1);
+        assert_eq!(same.stats.reused, 2);
+
+        // Source removed: load and handler are recomputed, helper is not
+        let changed = cache.compute(&analysis, &chain("l3", false)).unwrap();
+        assert_eq!(changed.stats.recomputed, 2);
+        assert!(!changed.get("id:handler").unwrap().tainted_return);
+    }
+
+    #[test]
+    fn test_recursive_scc_reaches_fixpoint() {
+        let nodes = vec![function("ping", "p1"), function("pong", "q1")];
+        let edges = vec![
+            calls("ping", "app.pong"),
+            calls("pong", "app.ping"),
+            calls("pong", "external.get_param"),
+        ];
+        let ir_doc = IRDocument::new("app.py".to_string(), nodes, edges);
+
+        let run = SummaryCache::in_memory()
+            .compute(&TaintTransferAnalysis::default(), &ir_doc)
+            .unwrap();
+        assert_eq!(run.stats.recomputed_sccs, 1);
+        assert!(run.get("id:ping").unwrap().tainted_return);
+        assert!(run.get("id:pong").unwrap().tainted_return);
+    }
+}
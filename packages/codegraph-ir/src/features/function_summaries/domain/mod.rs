@@ -0,0 +1,132 @@
+//! Summary identity, persistence port and the per-analysis extension point
+
+use std::collections::{BTreeMap, HashSet};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::features::cross_file::IRDocument;
+use crate::shared::models::{Node, Result};
+
+/// Identity of one function body across commits
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct SummaryKey {
+    /// See `stable_id`
+    pub stable_id: String,
+    /// `Node::content_hash` of the function source
+    pub content_hash: String,
+}
+
+impl SummaryKey {
+    pub fn new(stable_id: impl Into<String>, content_hash: impl Into<String>) -> Self {
+        Self {
+            stable_id: stable_id.into(),
+            content_hash: content_hash.into(),
+        }
+    }
+
+    /// Key of a function node; `None` without a content hash (never cached)
+    pub fn for_node(node: &Node) -> Option<Self> {
+        let content_hash = node.content_hash.as_ref()?;
+        Some(Self::new(stable_id(node), content_hash.clone()))
+    }
+}
+
+/// Symbol identity that survives edits: `Node::stable_id`, else `Node::id`
+/// (a hash of repository, file and FQN)
+pub fn stable_id(node: &Node) -> &str {
+    node.stable_id.as_deref().unwrap_or(&node.id)
+}
+
+/// Stored summary of one function body for one analysis
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SummaryRecord {
+    pub key: SummaryKey,
+    /// `SummaryAnalysis::kind`
+    pub kind: String,
+    /// `SummaryAnalysis::version`; records of other versions are recomputed
+    pub version: u32,
+    /// Callee stable id → digest of the callee summary this one was built from
+    pub dependencies: BTreeMap<String, String>,
+    /// `summary_digest` of `summary` (what callers record as a dependency)
+    pub digest: String,
+    pub summary: serde_json::Value,
+}
+
+/// Per-function analysis expressed as a summary
+///
+/// `local` may only look at the function body: it is skipped for functions
+/// whose content hash is unchanged. `transfer` folds resolved callee
+/// summaries into the local one and must be monotone, since it is iterated
+/// to a fixpoint inside recursive call cycles.
+pub trait SummaryAnalysis: Send + Sync {
+    type Summary: Clone + PartialEq + Serialize + DeserializeOwned;
+
+    /// Store namespace (`taint`, `effects`, `escape`)
+    fn kind(&self) -> &'static str;
+
+    /// Bump when the meaning of stored summaries changes
+    fn version(&self) -> u32 {
+        1
+    }
+
+    fn local(&self, function: &Node, ir_doc: &IRDocument) -> Self::Summary;
+
+    fn transfer(&self, local: &Self::Summary, callees: &[&Self::Summary]) -> Self::Summary;
+}
+
+/// Summary persistence, keyed by (kind, stable id, content hash)
+pub trait SummaryStore: Send + Sync {
+    /// Stored records of `kind` for `keys` (unknown keys are left out)
+    fn load(&self, kind: &str, keys: &[SummaryKey]) -> Result<Vec<SummaryRecord>>;
+
+    /// Insert or replace
+    fn save(&self, records: &[SummaryRecord]) -> Result<()>;
+
+    /// Delete records of `kind` not in `live` (old bodies), returning how many
+    fn retain(&self, kind: &str, live: &HashSet<SummaryKey>) -> Result<usize>;
+}
+
+/// Order-independent digest of a serialized summary
+///
+/// Sequences are hashed as sets: summaries keep their sets in `HashSet`s,
+/// whose serialization order differs between runs.
+pub fn summary_digest(summary: &serde_json::Value) -> String {
+    let mut canonical = String::new();
+    write_canonical(summary, &mut canonical);
+    blake3::hash(canonical.as_bytes()).to_hex()[..32].to_string()
+}
+
+fn write_canonical(value: &serde_json::Value, out: &mut String) {
+    match value {
+        serde_json::Value::Array(items) => {
+            let mut items: Vec<String> = items
+                .iter()
+                .map(|item| {
+                    let mut s = String::new();
+                    write_canonical(item, &mut s);
+                    s
+                })
+                .collect();
+            items.sort();
+            out.push('[');
+            out.push_str(&items.join(","));
+            out.push(']');
+        }
+        serde_json::Value::Object(fields) => {
+            let mut fields: Vec<_> = fields.iter().collect();
+            fields.sort_by(|a, b| a.0.cmp(b.0));
+            out.push('{');
+            for (i, (name, field)) in fields.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&serde_json::Value::String(name.clone()).to_string());
+                out.push(':');
+                write_canonical(field, out);
+            }
+            out.push('}');
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
@@ -0,0 +1,202 @@
+//! Summary analyses: taint transfer, effects, escape
+//!
+//! Each wraps the feature's existing per-function analysis; only the
+//! callee-folding (`transfer`) is specific to summaries.
+
+use std::collections::BTreeSet;
+
+use crate::features::cross_file::IRDocument;
+use crate::features::effect_analysis::{EffectSet, LocalEffectAnalyzer};
+use crate::features::function_summaries::domain::SummaryAnalysis;
+use crate::features::heap_analysis::{EscapeAnalyzer, EscapeNode, FunctionEscapeInfo};
+use crate::features::taint_analysis::{
+    default_sanitizer_patterns, default_sink_patterns, default_source_patterns,
+    FunctionTaintSummary,
+};
+use crate::pipeline::processor::stages::heap::{build_def_use_map, node_to_escape_node};
+use crate::shared::models::{EdgeKind, Node};
+
+/// `FunctionTaintSummary::metadata` key: source call the return value carries
+pub const TAINT_SOURCE_KEY: &str = "source";
+/// `FunctionTaintSummary::metadata` key: sink call reached from the function
+pub const TAINT_SINK_KEY: &str = "sink";
+
+/// Call-level taint transfer
+///
+/// A function returns taint when it calls a source (or a callee returning
+/// taint); the source and the first sink reached are kept in `metadata`.
+/// Functions named like sanitizers are marked `sanitizes` and stop
+/// propagation. Matching is lowercase substring, as in the taint use case;
+/// a callee matching both a source and a sink pattern is a sink.
+pub struct TaintTransferAnalysis {
+    sources: Vec<String>,
+    sinks: Vec<String>,
+    sanitizers: Vec<String>,
+}
+
+impl TaintTransferAnalysis {
+    pub fn new(sources: Vec<String>, sinks: Vec<String>, sanitizers: Vec<String>) -> Self {
+        Self {
+            sources,
+            sinks,
+            sanitizers,
+        }
+    }
+
+    fn matches(patterns: &[String], name: &str) -> bool {
+        let name = name.to_lowercase();
+        patterns.iter().any(|p| name.contains(p.as_str()))
+    }
+}
+
+impl Default for TaintTransferAnalysis {
+    fn default() -> Self {
+        Self::new(
+            default_source_patterns(),
+            default_sink_patterns(),
+            default_sanitizer_patterns(),
+        )
+    }
+}
+
+impl SummaryAnalysis for TaintTransferAnalysis {
+    type Summary = FunctionTaintSummary;
+
+    fn kind(&self) -> &'static str {
+        "taint"
+    }
+
+    fn local(&self, function: &Node, ir_doc: &IRDocument) -> FunctionTaintSummary {
+        let mut summary = FunctionTaintSummary::new(function.id.clone());
+        let name = function.name.as_deref().unwrap_or(&function.fqn);
+        summary.sanitizes = Self::matches(&self.sanitizers, name);
+
+        for callee in callees(function, ir_doc) {
+            if Self::matches(&self.sinks, callee) {
+                summary
+                    .metadata
+                    .entry(TAINT_SINK_KEY.to_string())
+                    .or_insert_with(|| callee.to_string());
+            } else if Self::matches(&self.sources, callee) {
+                summary.tainted_return = true;
+                summary
+                    .metadata
+                    .entry(TAINT_SOURCE_KEY.to_string())
+                    .or_insert_with(|| callee.to_string());
+            }
+        }
+        summary
+    }
+
+    fn transfer(
+        &self,
+        local: &FunctionTaintSummary,
+        callees: &[&FunctionTaintSummary],
+    ) -> FunctionTaintSummary {
+        let mut summary = local.clone();
+        if summary.sanitizes {
+            return summary;
+        }
+        for callee in callees.iter().filter(|c| !c.sanitizes) {
+            if callee.tainted_return {
+                summary.tainted_return = true;
+                if let Some(source) = callee.metadata.get(TAINT_SOURCE_KEY) {
+                    summary
+                        .metadata
+                        .entry(TAINT_SOURCE_KEY.to_string())
+                        .or_insert_with(|| source.clone());
+                }
+            }
+            if let Some(sink) = callee.metadata.get(TAINT_SINK_KEY) {
+                summary
+                    .metadata
+                    .entry(TAINT_SINK_KEY.to_string())
+                    .or_insert_with(|| sink.clone());
+            }
+            summary.confidence = summary.confidence.min(callee.confidence);
+        }
+        summary
+    }
+}
+
+/// Effects: `LocalEffectAnalyzer` plus callee effects
+/// (as `EffectAnalyzer::analyze_all`)
+#[derive(Default)]
+pub struct EffectSummaryAnalysis {
+    local: LocalEffectAnalyzer,
+}
+
+impl SummaryAnalysis for EffectSummaryAnalysis {
+    type Summary = EffectSet;
+
+    fn kind(&self) -> &'static str {
+        "effects"
+    }
+
+    fn local(&self, function: &Node, ir_doc: &IRDocument) -> EffectSet {
+        self.local.analyze(function, ir_doc)
+    }
+
+    fn transfer(&self, local: &EffectSet, callees: &[&EffectSet]) -> EffectSet {
+        let mut effects = local.clone();
+        for callee in callees {
+            effects.merge(callee);
+        }
+        effects
+    }
+}
+
+/// Escape: `EscapeAnalyzer` over the nodes inside the function's span
+///
+/// Intraprocedural, so `transfer` keeps the local result; caching it still
+/// saves re-running the def-use propagation for unchanged functions.
+#[derive(Default)]
+pub struct EscapeSummaryAnalysis {
+    analyzer: EscapeAnalyzer,
+}
+
+impl SummaryAnalysis for EscapeSummaryAnalysis {
+    type Summary = FunctionEscapeInfo;
+
+    fn kind(&self) -> &'static str {
+        "escape"
+    }
+
+    fn local(&self, function: &Node, ir_doc: &IRDocument) -> FunctionEscapeInfo {
+        let (defs, uses) = build_def_use_map(&ir_doc.edges);
+        let nodes: Vec<EscapeNode> = ir_doc
+            .nodes
+            .iter()
+            .filter(|node| {
+                node.id != function.id
+                    && node.file_path == function.file_path
+                    && node.span.start_line >= function.span.start_line
+                    && node.span.end_line <= function.span.end_line
+            })
+            .map(|node| node_to_escape_node(node, &defs, &uses))
+            .collect();
+        self.analyzer
+            .analyze(function.id.clone(), &nodes)
+            .unwrap_or_else(|_| FunctionEscapeInfo::new(function.id.clone()))
+    }
+
+    fn transfer(
+        &self,
+        local: &FunctionEscapeInfo,
+        _callees: &[&FunctionEscapeInfo],
+    ) -> FunctionEscapeInfo {
+        local.clone()
+    }
+}
+
+/// CALLS / INVOKES targets of `function`, sorted (first-match metadata is deterministic)
+fn callees<'a>(function: &Node, ir_doc: &'a IRDocument) -> BTreeSet<&'a str> {
+    ir_doc
+        .edges
+        .iter()
+        .filter(|e| {
+            e.source_id == function.id && matches!(e.kind, EdgeKind::Calls | EdgeKind::Invokes)
+        })
+        .map(|e| e.target_id.as_str())
+        .collect()
+}
@@ -0,0 +1,12 @@
+//! Function summary analyses and stores
+
+mod analyses;
+mod store;
+
+pub use analyses::{
+    EffectSummaryAnalysis, EscapeSummaryAnalysis, TaintTransferAnalysis, TAINT_SINK_KEY,
+    TAINT_SOURCE_KEY,
+};
+#[cfg(feature = "sqlite")]
+pub use store::SqliteSummaryStore;
+pub use store::InMemorySummaryStore;
@@ -0,0 +1,185 @@
+//! Summary stores: in-memory (tests, single process) and SQLite (persisted)
+
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+
+use crate::features::function_summaries::domain::{SummaryKey, SummaryRecord, SummaryStore};
+use crate::shared::models::{CodegraphError, Result};
+
+/// `SummaryStore` kept in process memory
+#[derive(Default)]
+pub struct InMemorySummaryStore {
+    records: RwLock<HashMap<(String, SummaryKey), SummaryRecord>>,
+}
+
+impl InMemorySummaryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.read().map(|r| r.len()).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+fn poisoned() -> CodegraphError {
+    CodegraphError::internal("Summary store lock poisoned")
+}
+
+impl SummaryStore for InMemorySummaryStore {
+    fn load(&self, kind: &str, keys: &[SummaryKey]) -> Result<Vec<SummaryRecord>> {
+        let records = self.records.read().map_err(|_| poisoned())?;
+        Ok(keys
+            .iter()
+            .filter_map(|key| records.get(&(kind.to_string(), key.clone())).cloned())
+            .collect())
+    }
+
+    fn save(&self, records: &[SummaryRecord]) -> Result<()> {
+        let mut stored = self.records.write().map_err(|_| poisoned())?;
+        for record in records {
+            stored.insert((record.kind.clone(), record.key.clone()), record.clone());
+        }
+        Ok(())
+    }
+
+    fn retain(&self, kind: &str, live: &HashSet<SummaryKey>) -> Result<usize> {
+        let mut stored = self.records.write().map_err(|_| poisoned())?;
+        let before = stored.len();
+        stored.retain(|(k, key), _| k != kind || live.contains(key));
+        Ok(before - stored.len())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteSummaryStore;
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+    use super::*;
+    use rusqlite::{params, Connection, OptionalExtension};
+    use std::path::Path;
+    use std::sync::{Arc, Mutex};
+
+    /// `SummaryStore` in a SQLite database (one table, `function_summaries`)
+    #[derive(Clone)]
+    pub struct SqliteSummaryStore {
+        conn: Arc<Mutex<Connection>>,
+    }
+
+    impl SqliteSummaryStore {
+        pub fn new(db_path: impl AsRef<Path>) -> Result<Self> {
+            Self::with_connection(Connection::open(db_path)?)
+        }
+
+        pub fn in_memory() -> Result<Self> {
+            Self::with_connection(Connection::open_in_memory()?)
+        }
+
+        fn with_connection(conn: Connection) -> Result<Self> {
+            conn.execute_batch(
+                "CREATE TABLE IF NOT EXISTS function_summaries (
+                    kind TEXT NOT NULL,
+                    stable_id TEXT NOT NULL,
+                    content_hash TEXT NOT NULL,
+                    version INTEGER NOT NULL,
+                    digest TEXT NOT NULL,
+                    dependencies TEXT NOT NULL,
+                    summary TEXT NOT NULL,
+                    PRIMARY KEY (kind, stable_id, content_hash)
+                )",
+            )?;
+            Ok(Self {
+                conn: Arc::new(Mutex::new(conn)),
+            })
+        }
+    }
+
+    impl SummaryStore for SqliteSummaryStore {
+        fn load(&self, kind: &str, keys: &[SummaryKey]) -> Result<Vec<SummaryRecord>> {
+            let conn = self.conn.lock()?;
+            let mut stmt = conn.prepare_cached(
+                "SELECT version, digest, dependencies, summary FROM function_summaries
+                 WHERE kind = ?1 AND stable_id = ?2 AND content_hash = ?3",
+            )?;
+            let mut records = Vec::new();
+            for key in keys {
+                let row = stmt
+                    .query_row(params![kind, key.stable_id, key.content_hash], |row| {
+                        Ok((
+                            row.get::<_, u32>(0)?,
+                            row.get::<_, String>(1)?,
+                            row.get::<_, String>(2)?,
+                            row.get::<_, String>(3)?,
+                        ))
+                    })
+                    .optional()?;
+                if let Some((version, digest, dependencies, summary)) = row {
+                    records.push(SummaryRecord {
+                        key: key.clone(),
+                        kind: kind.to_string(),
+                        version,
+                        dependencies: serde_json::from_str(&dependencies)?,
+                        digest,
+                        summary: serde_json::from_str(&summary)?,
+                    });
+                }
+            }
+            Ok(records)
+        }
+
+        fn save(&self, records: &[SummaryRecord]) -> Result<()> {
+            let mut conn = self.conn.lock()?;
+            let tx = conn.transaction()?;
+            {
+                let mut stmt = tx.prepare_cached(
+                    "INSERT OR REPLACE INTO function_summaries
+                     (kind, stable_id, content_hash, version, digest, dependencies, summary)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                )?;
+                for record in records {
+                    stmt.execute(params![
+                        record.kind,
+                        record.key.stable_id,
+                        record.key.content_hash,
+                        record.version,
+                        record.digest,
+                        serde_json::to_string(&record.dependencies)?,
+                        serde_json::to_string(&record.summary)?,
+                    ])?;
+                }
+            }
+            tx.commit()?;
+            Ok(())
+        }
+
+        fn retain(&self, kind: &str, live: &HashSet<SummaryKey>) -> Result<usize> {
+            let mut conn = self.conn.lock()?;
+            let tx = conn.transaction()?;
+            let stale: Vec<(String, String)> = {
+                let mut stmt = tx.prepare(
+                    "SELECT stable_id, content_hash FROM function_summaries WHERE kind = ?1",
+                )?;
+                let rows = stmt.query_map(params![kind], |row| Ok((row.get(0)?, row.get(1)?)))?;
+                rows.filter_map(|row| row.ok())
+                    .filter(|(stable_id, content_hash): &(String, String)| {
+                        !live.contains(&SummaryKey::new(stable_id.as_str(), content_hash.as_str()))
+                    })
+                    .collect()
+            };
+            for (stable_id, content_hash) in &stale {
+                tx.execute(
+                    "DELETE FROM function_summaries
+                     WHERE kind = ?1 AND stable_id = ?2 AND content_hash = ?3",
+                    params![kind, stable_id, content_hash],
+                )?;
+            }
+            tx.commit()?;
+            Ok(stale.len())
+        }
+    }
+}
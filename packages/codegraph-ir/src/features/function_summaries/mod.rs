@@ -0,0 +1,56 @@
+//! Function Summaries - Incremental interprocedural analysis cache
+//!
+//! Computes per-function summaries (taint transfer, effects, escape) once
+//! and persists them keyed by stable symbol id + content hash. Later runs
+//! reuse the stored summary of every function whose body and callee
+//! summaries are unchanged, so a commit only pays for the functions it
+//! touches and the callers whose summaries actually change.
+//!
+//! # Architecture
+//!
+//! ```text
+//! ┌─────────────────────────────────────────┐
+//! │ Domain Layer                            │
+//! │  - SummaryKey, SummaryRecord            │
+//! │  - SummaryAnalysis, SummaryStore        │
+//! └─────────────────────────────────────────┘
+//!                   ▲
+//!                   │
+//! ┌─────────────────────────────────────────┐
+//! │ Application Layer                       │
+//! │  - SummaryCache (SCC order, reuse)      │
+//! └─────────────────────────────────────────┘
+//!                   ▲
+//!                   │
+//! ┌─────────────────────────────────────────┐
+//! │ Infrastructure Layer                    │
+//! │  - Taint / Effect / Escape analyses     │
+//! │  - InMemory / SQLite stores             │
+//! └─────────────────────────────────────────┘
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use std::sync::Arc;
+//! use codegraph_ir::features::function_summaries::{SqliteSummaryStore, SummaryCache};
+//!
+//! let cache = SummaryCache::new(Arc::new(SqliteSummaryStore::new(".codegraph/summaries.db")?));
+//! let summaries = cache.compute_all(&ir_doc)?;
+//! println!("reused {} of {}", summaries.taint.stats.reused, summaries.taint.stats.functions);
+//! ```
+
+pub mod application;
+pub mod domain;
+pub mod infrastructure;
+
+// Re-export application layer
+pub use application::{FunctionSummaries, SummaryCache, SummaryRun, SummaryStats};
+
+// Re-exports for convenience
+pub use domain::{summary_digest, SummaryAnalysis, SummaryKey, SummaryRecord, SummaryStore};
+#[cfg(feature = "sqlite")]
+pub use infrastructure::SqliteSummaryStore;
+pub use infrastructure::{
+    EffectSummaryAnalysis, EscapeSummaryAnalysis, InMemorySummaryStore, TaintTransferAnalysis,
+};
@@ -0,0 +1,5 @@
+//! Gating Application Layer
+
+mod policy_engine;
+
+pub use policy_engine::PolicyEngine;
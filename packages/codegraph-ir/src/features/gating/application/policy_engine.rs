@@ -0,0 +1,204 @@
+//! Policy Engine
+//!
+//! Evaluates findings against a set of gate rules and folds the rule
+//! outcomes into a single verdict. Shared by the `codegraph-gate` CLI and
+//! service adapters (webhooks), which only differ in how they obtain the
+//! findings, baseline and changed files.
+
+use std::collections::HashSet;
+
+use crate::features::gating::domain::{
+    GateContext, GateFinding, GatePolicy, GateRule, GateStatus, GateVerdict,
+};
+
+/// Ordered set of gate rules
+pub struct PolicyEngine {
+    rules: Vec<Box<dyn GateRule>>,
+}
+
+impl PolicyEngine {
+    /// Engine without rules (always passes)
+    pub fn empty() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Engine running every enabled rule of a policy file
+    pub fn from_policy(policy: &GatePolicy) -> Self {
+        let mut engine = Self::empty();
+        for rule in policy.rules.iter().filter(|r| r.enabled) {
+            engine.rules.push(Box::new(rule.clone()));
+        }
+        engine
+    }
+
+    /// Add a custom rule
+    pub fn with_rule(mut self, rule: impl GateRule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Rule names in evaluation order
+    pub fn rule_names(&self) -> Vec<&str> {
+        self.rules.iter().map(|r| r.name()).collect()
+    }
+
+    /// Evaluate findings
+    pub fn evaluate(&self, findings: &[GateFinding], ctx: &GateContext) -> GateVerdict {
+        let rules: Vec<_> = self
+            .rules
+            .iter()
+            .map(|rule| rule.evaluate(findings, ctx))
+            .collect();
+
+        let status = rules
+            .iter()
+            .filter(|r| r.triggered)
+            .map(|r| GateStatus::from(r.action))
+            .max()
+            .unwrap_or(GateStatus::Pass);
+
+        GateVerdict {
+            status,
+            total_findings: findings.len(),
+            new_findings: findings.iter().filter(|f| ctx.is_new(f)).count(),
+            rules,
+        }
+    }
+
+    /// Fingerprints of `findings`, for storing as the next baseline
+    pub fn baseline_of(findings: &[GateFinding]) -> HashSet<String> {
+        findings.iter().map(GateFinding::fingerprint).collect()
+    }
+}
+
+impl Default for PolicyEngine {
+    fn default() -> Self {
+        Self::from_policy(&GatePolicy::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::gating::domain::{
+        FindingCategory, FindingSeverity, GateAction, PolicyRule, RuleOutcome,
+    };
+
+    fn finding(severity: FindingSeverity, file: &str, rule: &str) -> GateFinding {
+        GateFinding::new(FindingCategory::Security, rule, severity, file, "msg")
+    }
+
+    #[test]
+    fn test_default_policy() {
+        let engine = PolicyEngine::default();
+        let existing_critical = finding(FindingSeverity::Critical, "old.py", "SQLInjection");
+        let high_elsewhere = finding(FindingSeverity::High, "other.py", "XSS");
+
+        let ctx = GateContext {
+            baseline: Some(PolicyEngine::baseline_of(std::slice::from_ref(
+                &existing_critical,
+            ))),
+            changed_files: ["api.py".to_string()].into_iter().collect(),
+        };
+
+        // Pre-existing critical + high outside the change → pass
+        let verdict = engine.evaluate(&[existing_critical.clone(), high_elsewhere], &ctx);
+        assert_eq!(verdict.status, GateStatus::Pass);
+        assert_eq!(verdict.new_findings, 1);
+
+        // High in a changed file → warn
+        let high_changed = finding(FindingSeverity::High, "api.py", "XSS");
+        let verdict = engine.evaluate(&[existing_critical.clone(), high_changed.clone()], &ctx);
+        assert_eq!(verdict.status, GateStatus::Warn);
+        assert_eq!(verdict.exit_code(), 2);
+
+        // New critical → fail, regardless of file
+        let new_critical = finding(FindingSeverity::Critical, "lib.py", "CommandInjection");
+        let verdict = engine.evaluate(&[existing_critical, high_changed, new_critical], &ctx);
+        assert_eq!(verdict.status, GateStatus::Fail);
+        assert_eq!(verdict.exit_code(), 1);
+        assert!(verdict
+            .summary()
+            .contains("[FAIL] no-new-critical: 1 matched"));
+    }
+
+    #[test]
+    fn test_policy_file_and_custom_rule() {
+        let policy = GatePolicy::from_yaml_str(
+            r#"
+version: 1
+rules:
+  - name: dead-code-budget
+    action: warn
+    min_severity: info
+    categories: [dead_code]
+    max_count: 1
+"#,
+        )
+        .unwrap();
+
+        struct NoFindingsInVendor;
+        impl GateRule for NoFindingsInVendor {
+            fn name(&self) -> &str {
+                "no-vendor"
+            }
+            fn evaluate(&self, findings: &[GateFinding], _ctx: &GateContext) -> RuleOutcome {
+                let matched = findings
+                    .iter()
+                    .filter(|f| f.file_path.starts_with("vendor/"))
+                    .count();
+                RuleOutcome {
+                    rule: "no-vendor".into(),
+                    action: GateAction::Fail,
+                    matched,
+                    triggered: matched > 0,
+                    examples: Vec::new(),
+                }
+            }
+        }
+
+        let engine = PolicyEngine::from_policy(&policy).with_rule(NoFindingsInVendor);
+        assert_eq!(engine.rule_names(), vec!["dead-code-budget", "no-vendor"]);
+
+        let dead = |file: &str| {
+            GateFinding::new(
+                FindingCategory::DeadCode,
+                "unused-function",
+                FindingSeverity::Low,
+                file,
+                "never called",
+            )
+        };
+        let ctx = GateContext::default();
+        assert_eq!(
+            engine.evaluate(&[dead("a.py")], &ctx).status,
+            GateStatus::Pass
+        );
+        assert_eq!(
+            engine.evaluate(&[dead("a.py"), dead("b.py")], &ctx).status,
+            GateStatus::Warn
+        );
+        assert_eq!(
+            engine.evaluate(&[dead("vendor/x.py")], &ctx).status,
+            GateStatus::Fail
+        );
+    }
+
+    #[test]
+    fn test_policy_validation() {
+        assert!(GatePolicy::from_yaml_str("version: 2\nrules: []\n").is_err());
+        assert!(GatePolicy::from_yaml_str(
+            "version: 1\nrules:\n  - {name: a, action: fail}\n  - {name: a, action: warn}\n"
+        )
+        .is_err());
+        assert!(
+            GatePolicy::from_yaml_str("version: 1\nrules:\n  - {name: a, action: block}\n")
+                .is_err()
+        );
+
+        let rule = PolicyRule::new("r", GateAction::Fail, FindingSeverity::High);
+        assert_eq!(rule.min_severity, FindingSeverity::High);
+        assert_eq!(FindingSeverity::from_score(9), FindingSeverity::Critical);
+        assert_eq!(FindingSeverity::from_label("HIGH"), FindingSeverity::High);
+    }
+}
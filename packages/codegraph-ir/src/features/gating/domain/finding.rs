@@ -0,0 +1,303 @@
+//! Gate findings
+//!
+//! Normalized view of analysis results (taint, security, memory safety,
+//! architecture rules, dead code) that policies are evaluated against.
+
+use serde::{Deserialize, Serialize};
+
+use crate::features::architecture::{ArchitectureViolation, RuleSeverity};
+use crate::features::heap_analysis::{MemorySafetyIssue, SecurityVulnerability};
+use crate::features::query_engine::dead_code::DeadSymbol;
+use crate::features::query_engine::unused::{find_unused, UnusedKind, UnusedSymbol};
+use crate::features::taint_analysis::ports::TaintPathDTO;
+use crate::pipeline::end_to_end_result::{
+    E2EPipelineResult, MemorySafetyIssueSummary, SecurityVulnerabilitySummary,
+};
+
+/// Finding severity (ordered: Info < Low < Medium < High < Critical)
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum FindingSeverity {
+    Info,
+    Low,
+    #[default]
+    Medium,
+    High,
+    Critical,
+}
+
+impl FindingSeverity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+            Self::Critical => "critical",
+        }
+    }
+
+    /// Parse a severity label ("CRITICAL", "High", "warning", ...)
+    ///
+    /// Unknown labels map to Medium.
+    pub fn from_label(label: &str) -> Self {
+        match label.trim().to_ascii_lowercase().as_str() {
+            "critical" | "blocker" => Self::Critical,
+            "high" | "error" | "major" => Self::High,
+            "medium" | "warning" | "moderate" => Self::Medium,
+            "low" | "minor" | "note" => Self::Low,
+            "info" | "informational" | "none" => Self::Info,
+            _ => Self::Medium,
+        }
+    }
+
+    /// Map a 1-10 score (heap/security analyzers) to a severity
+    pub fn from_score(score: u8) -> Self {
+        match score {
+            9.. => Self::Critical,
+            7..=8 => Self::High,
+            4..=6 => Self::Medium,
+            1..=3 => Self::Low,
+            0 => Self::Info,
+        }
+    }
+}
+
+/// Analysis that produced a finding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingCategory {
+    Taint,
+    Security,
+    MemorySafety,
+    Architecture,
+    DeadCode,
+    Other,
+}
+
+/// Single finding submitted to the gate
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GateFinding {
+    pub category: FindingCategory,
+    /// Rule / vulnerability type (e.g. "SQLInjection", "layer-violation")
+    pub rule_id: String,
+    pub severity: FindingSeverity,
+    #[serde(default)]
+    pub file_path: String,
+    #[serde(default)]
+    pub line: Option<u32>,
+    #[serde(default)]
+    pub message: String,
+}
+
+impl GateFinding {
+    pub fn new(
+        category: FindingCategory,
+        rule_id: impl Into<String>,
+        severity: FindingSeverity,
+        file_path: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            category,
+            rule_id: rule_id.into(),
+            severity,
+            file_path: file_path.into(),
+            line: None,
+            message: message.into(),
+        }
+    }
+
+    /// Builder: Set line
+    pub fn with_line(mut self, line: u32) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Collect gate findings from a pipeline run
+    pub fn from_pipeline_result(result: &E2EPipelineResult) -> Vec<Self> {
+        result
+            .security_vulnerabilities
+            .iter()
+            .map(Self::from)
+            .chain(result.memory_safety_issues.iter().map(Self::from))
+            .chain(result.architecture_violations.iter().map(Self::from))
+            .collect()
+    }
+
+    /// Unused import / parameter and dead code findings of a pipeline run
+    ///
+    /// Not part of `from_pipeline_result`: dead-code budgets would start
+    /// counting them.
+    pub fn unused_from_pipeline_result(result: &E2EPipelineResult) -> Vec<Self> {
+        find_unused(&result.nodes, &result.edges, &result.occurrences, &[])
+            .iter()
+            .map(Self::from)
+            .chain(result.dead_code.iter().map(Self::from))
+            .collect()
+    }
+
+    /// Stable identity used to match findings against a baseline
+    ///
+    /// Excludes the line number so unrelated edits above a finding do not
+    /// turn it into a "new" one.
+    pub fn fingerprint(&self) -> String {
+        let mut hasher = blake3::Hasher::new();
+        for part in [
+            format!("{:?}", self.category).as_str(),
+            &self.rule_id,
+            &self.file_path,
+            &self.message,
+        ] {
+            hasher.update(part.as_bytes());
+            hasher.update(&[0]);
+        }
+        hasher.finalize().to_hex()[..16].to_string()
+    }
+}
+
+/// Split "path/to/file.py:42" into (path, line)
+fn split_location(location: &str) -> (String, Option<u32>) {
+    match location.rsplit_once(':') {
+        Some((path, line)) => match line.parse() {
+            Ok(line) => (path.to_string(), Some(line)),
+            Err(_) => (location.to_string(), None),
+        },
+        None => (location.to_string(), None),
+    }
+}
+
+impl From<&SecurityVulnerability> for GateFinding {
+    fn from(vuln: &SecurityVulnerability) -> Self {
+        let (file_path, line) = split_location(&vuln.location);
+        Self {
+            category: FindingCategory::Security,
+            rule_id: format!("{:?}", vuln.vuln_type),
+            severity: FindingSeverity::from_score(vuln.severity),
+            file_path,
+            line,
+            message: vuln.message.clone(),
+        }
+    }
+}
+
+impl From<&MemorySafetyIssue> for GateFinding {
+    fn from(issue: &MemorySafetyIssue) -> Self {
+        let (file_path, line) = split_location(&issue.location);
+        Self {
+            category: FindingCategory::MemorySafety,
+            rule_id: format!("{:?}", issue.kind),
+            severity: FindingSeverity::from_score(issue.severity),
+            file_path,
+            line,
+            message: issue.message.clone(),
+        }
+    }
+}
+
+impl From<&ArchitectureViolation> for GateFinding {
+    fn from(violation: &ArchitectureViolation) -> Self {
+        let severity = match violation.severity {
+            RuleSeverity::Error => FindingSeverity::High,
+            RuleSeverity::Warning => FindingSeverity::Medium,
+            RuleSeverity::Note => FindingSeverity::Low,
+        };
+        Self::new(
+            FindingCategory::Architecture,
+            &violation.rule,
+            severity,
+            &violation.from_file,
+            &violation.message,
+        )
+        .with_line(violation.span.start_line)
+    }
+}
+
+impl From<&UnusedSymbol> for GateFinding {
+    fn from(unused: &UnusedSymbol) -> Self {
+        let (severity, message) = match unused.kind {
+            UnusedKind::Import if unused.fqn != unused.name => (
+                FindingSeverity::Low,
+                format!("'{}' ({}) imported but unused", unused.name, unused.fqn),
+            ),
+            UnusedKind::Import => (
+                FindingSeverity::Low,
+                format!("'{}' imported but unused", unused.name),
+            ),
+            UnusedKind::Parameter => (
+                FindingSeverity::Info,
+                format!(
+                    "Parameter '{}' of {} is never used",
+                    unused.name, unused.fqn
+                ),
+            ),
+        };
+        Self::new(
+            FindingCategory::DeadCode,
+            unused.kind.rule_id(),
+            severity,
+            &unused.file_path,
+            message,
+        )
+        .with_line(unused.span.start_line)
+    }
+}
+
+impl From<&DeadSymbol> for GateFinding {
+    fn from(dead: &DeadSymbol) -> Self {
+        Self::new(
+            FindingCategory::DeadCode,
+            "dead-code",
+            FindingSeverity::Low,
+            &dead.file_path,
+            format!("{:?} '{}' is never referenced", dead.kind, dead.fqn),
+        )
+        .with_line(dead.span.start_line)
+    }
+}
+
+impl From<&TaintPathDTO> for GateFinding {
+    fn from(path: &TaintPathDTO) -> Self {
+        Self {
+            category: FindingCategory::Taint,
+            rule_id: path.vulnerability_type.clone(),
+            // Sanitized flows are reported but never gate
+            severity: if path.is_sanitized {
+                FindingSeverity::Info
+            } else {
+                FindingSeverity::from_label(&path.severity)
+            },
+            file_path: String::new(),
+            line: None,
+            message: format!("{} → {}", path.source, path.sink),
+        }
+    }
+}
+
+impl From<&SecurityVulnerabilitySummary> for GateFinding {
+    fn from(vuln: &SecurityVulnerabilitySummary) -> Self {
+        Self {
+            category: FindingCategory::Security,
+            rule_id: vuln.vuln_type.clone(),
+            severity: FindingSeverity::from_label(&vuln.severity),
+            file_path: vuln.file_path.clone(),
+            line: Some(vuln.line),
+            message: vuln.description.clone(),
+        }
+    }
+}
+
+impl From<&MemorySafetyIssueSummary> for GateFinding {
+    fn from(issue: &MemorySafetyIssueSummary) -> Self {
+        Self {
+            category: FindingCategory::MemorySafety,
+            rule_id: issue.issue_type.clone(),
+            severity: FindingSeverity::from_label(&issue.severity),
+            file_path: issue.file_path.clone(),
+            line: Some(issue.line),
+            message: issue.description.clone(),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! Domain models for CI gating
+
+mod finding;
+mod policy;
+
+pub use finding::{FindingCategory, FindingSeverity, GateFinding};
+pub use policy::{
+    GateAction, GateContext, GatePolicy, GateRule, GateStatus, GateVerdict, PolicyRule,
+    RuleOutcome, GATE_POLICY_VERSIONS,
+};
@@ -0,0 +1,342 @@
+//! Gate policies and verdicts
+//!
+//! A policy is an ordered list of rules. Each rule selects findings
+//! (severity, category, new-only, changed-files-only) and fails or warns
+//! when more than `max_count` of them match.
+//!
+//! ```yaml
+//! version: 1
+//! rules:
+//!   - name: no-new-critical
+//!     action: fail
+//!     min_severity: critical
+//!     new_only: true
+//!   - name: high-in-changed-files
+//!     action: warn
+//!     min_severity: high
+//!     changed_files_only: true
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+
+use super::finding::{FindingCategory, FindingSeverity, GateFinding};
+use crate::shared::models::{CodegraphError, Result};
+
+/// Supported policy file versions
+pub const GATE_POLICY_VERSIONS: &[u32] = &[1];
+
+/// What a triggered rule does to the verdict
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GateAction {
+    Warn,
+    Fail,
+}
+
+/// Overall gate status (ordered: Pass < Warn < Fail)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GateStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl GateStatus {
+    /// Process exit code: 0 = pass, 1 = fail, 2 = warn
+    ///
+    /// Warn is non-zero so CI can choose to surface it; pass
+    /// `--warn-exit-zero` in the CLI to treat it as success.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Pass => 0,
+            Self::Fail => 1,
+            Self::Warn => 2,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Warn => "warn",
+            Self::Fail => "fail",
+        }
+    }
+}
+
+impl From<GateAction> for GateStatus {
+    fn from(action: GateAction) -> Self {
+        match action {
+            GateAction::Warn => Self::Warn,
+            GateAction::Fail => Self::Fail,
+        }
+    }
+}
+
+/// Context a rule is evaluated in
+#[derive(Debug, Clone, Default)]
+pub struct GateContext {
+    /// Fingerprints of findings already present on the base branch
+    ///
+    /// `None` = no baseline; every finding counts as new.
+    pub baseline: Option<HashSet<String>>,
+    /// Files touched by the change (empty = whole repository)
+    pub changed_files: HashSet<String>,
+}
+
+impl GateContext {
+    pub fn is_new(&self, finding: &GateFinding) -> bool {
+        match &self.baseline {
+            Some(baseline) => !baseline.contains(&finding.fingerprint()),
+            None => true,
+        }
+    }
+
+    pub fn in_changed_files(&self, finding: &GateFinding) -> bool {
+        self.changed_files.is_empty() || self.changed_files.contains(&finding.file_path)
+    }
+}
+
+/// Outcome of a single rule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleOutcome {
+    pub rule: String,
+    pub action: GateAction,
+    /// Findings selected by the rule
+    pub matched: usize,
+    /// Whether `matched` exceeded the rule's allowance
+    pub triggered: bool,
+    /// Up to 10 example findings (for the summary)
+    pub examples: Vec<GateFinding>,
+}
+
+/// Policy rule extension point
+///
+/// `PolicyRule` (from policy files) is the built-in implementation; custom
+/// rules (e.g. ownership-aware or budget-based) can be added to a
+/// `PolicyEngine` alongside it.
+pub trait GateRule: Send + Sync {
+    fn name(&self) -> &str;
+    fn evaluate(&self, findings: &[GateFinding], ctx: &GateContext) -> RuleOutcome;
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Declarative rule from a policy file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyRule {
+    pub name: String,
+    pub action: GateAction,
+    /// Minimum severity selected
+    #[serde(default)]
+    pub min_severity: FindingSeverity,
+    /// Categories selected (empty = all)
+    #[serde(default)]
+    pub categories: Vec<FindingCategory>,
+    /// Only findings absent from the baseline
+    #[serde(default)]
+    pub new_only: bool,
+    /// Only findings in changed files
+    #[serde(default)]
+    pub changed_files_only: bool,
+    /// Matches allowed before the rule triggers
+    #[serde(default)]
+    pub max_count: usize,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+impl PolicyRule {
+    pub fn new(name: impl Into<String>, action: GateAction, min_severity: FindingSeverity) -> Self {
+        Self {
+            name: name.into(),
+            action,
+            min_severity,
+            categories: Vec::new(),
+            new_only: false,
+            changed_files_only: false,
+            max_count: 0,
+            enabled: true,
+        }
+    }
+
+    /// Builder: Only findings absent from the baseline
+    pub fn new_only(mut self) -> Self {
+        self.new_only = true;
+        self
+    }
+
+    /// Builder: Only findings in changed files
+    pub fn changed_files_only(mut self) -> Self {
+        self.changed_files_only = true;
+        self
+    }
+
+    /// Builder: Restrict to categories
+    pub fn categories(mut self, categories: Vec<FindingCategory>) -> Self {
+        self.categories = categories;
+        self
+    }
+
+    fn selects(&self, finding: &GateFinding, ctx: &GateContext) -> bool {
+        finding.severity >= self.min_severity
+            && (self.categories.is_empty() || self.categories.contains(&finding.category))
+            && (!self.new_only || ctx.is_new(finding))
+            && (!self.changed_files_only || ctx.in_changed_files(finding))
+    }
+}
+
+impl GateRule for PolicyRule {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn evaluate(&self, findings: &[GateFinding], ctx: &GateContext) -> RuleOutcome {
+        let selected: Vec<&GateFinding> = findings
+            .iter()
+            .filter(|f| self.enabled && self.selects(f, ctx))
+            .collect();
+
+        RuleOutcome {
+            rule: self.name.clone(),
+            action: self.action,
+            matched: selected.len(),
+            triggered: selected.len() > self.max_count,
+            examples: selected.into_iter().take(10).cloned().collect(),
+        }
+    }
+}
+
+/// Policy file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GatePolicy {
+    pub version: u32,
+    pub rules: Vec<PolicyRule>,
+}
+
+impl Default for GatePolicy {
+    /// Fail on new Critical, warn on High in changed files
+    fn default() -> Self {
+        Self {
+            version: 1,
+            rules: vec![
+                PolicyRule::new(
+                    "no-new-critical",
+                    GateAction::Fail,
+                    FindingSeverity::Critical,
+                )
+                .new_only(),
+                PolicyRule::new(
+                    "high-in-changed-files",
+                    GateAction::Warn,
+                    FindingSeverity::High,
+                )
+                .changed_files_only(),
+            ],
+        }
+    }
+}
+
+impl GatePolicy {
+    /// Parse a policy (YAML or JSON)
+    pub fn from_yaml_str(content: &str) -> Result<Self> {
+        let policy: Self = serde_yaml::from_str(content)
+            .map_err(|e| CodegraphError::config(format!("invalid gate policy: {}", e)))?;
+        policy.validate()?;
+        Ok(policy)
+    }
+
+    /// Load a policy file
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            CodegraphError::io(format!("cannot read policy {}: {}", path.display(), e))
+        })?;
+        Self::from_yaml_str(&content)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if !GATE_POLICY_VERSIONS.contains(&self.version) {
+            return Err(CodegraphError::config(format!(
+                "unsupported gate policy version {} (supported: {:?})",
+                self.version, GATE_POLICY_VERSIONS
+            )));
+        }
+        let mut names = HashSet::new();
+        for rule in &self.rules {
+            if rule.name.trim().is_empty() {
+                return Err(CodegraphError::config("gate rule name must not be empty"));
+            }
+            if !names.insert(rule.name.as_str()) {
+                return Err(CodegraphError::config(format!(
+                    "duplicate gate rule '{}'",
+                    rule.name
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Gate result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GateVerdict {
+    pub status: GateStatus,
+    pub total_findings: usize,
+    pub new_findings: usize,
+    pub rules: Vec<RuleOutcome>,
+}
+
+impl GateVerdict {
+    pub fn exit_code(&self) -> i32 {
+        self.status.exit_code()
+    }
+
+    /// Rules that triggered
+    pub fn triggered(&self) -> impl Iterator<Item = &RuleOutcome> {
+        self.rules.iter().filter(|r| r.triggered)
+    }
+
+    /// Human-readable summary (CLI output / PR comment body)
+    pub fn summary(&self) -> String {
+        let mut lines = vec![format!(
+            "Gate: {} ({} findings, {} new)",
+            self.status.as_str().to_uppercase(),
+            self.total_findings,
+            self.new_findings
+        )];
+        for outcome in &self.rules {
+            let mark = match (outcome.triggered, outcome.action) {
+                (false, _) => "ok  ",
+                (true, GateAction::Warn) => "WARN",
+                (true, GateAction::Fail) => "FAIL",
+            };
+            lines.push(format!(
+                "  [{}] {}: {} matched",
+                mark, outcome.rule, outcome.matched
+            ));
+            if outcome.triggered {
+                for finding in &outcome.examples {
+                    let location = match finding.line {
+                        Some(line) => format!("{}:{}", finding.file_path, line),
+                        None => finding.file_path.clone(),
+                    };
+                    lines.push(format!(
+                        "         {} {} {} {}",
+                        finding.severity.as_str(),
+                        finding.rule_id,
+                        location,
+                        finding.message
+                    ));
+                }
+            }
+        }
+        lines.join("\n")
+    }
+}
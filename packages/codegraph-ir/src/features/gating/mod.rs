@@ -0,0 +1,47 @@
+//! CI Gating - Severity policy engine
+//!
+//! Turns analysis findings into a pass/warn/fail verdict and process exit
+//! status according to a policy file.
+//!
+//! # Architecture
+//!
+//! ```text
+//! ┌─────────────────────────────────────────┐
+//! │ Domain Layer                            │
+//! │  - GateFinding (normalized finding)     │
+//! │  - GatePolicy / PolicyRule (YAML)       │
+//! │  - GateRule (extension point)           │
+//! │  - GateVerdict (status + rule outcomes) │
+//! └─────────────────────────────────────────┘
+//!                   ▲
+//!                   │
+//! ┌─────────────────────────────────────────┐
+//! │ Application Layer                       │
+//! │  - PolicyEngine (rule evaluation)       │
+//! └─────────────────────────────────────────┘
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use codegraph_ir::features::gating::{GateContext, GateFinding, GatePolicy, PolicyEngine};
+//!
+//! let policy = GatePolicy::from_file(".codegraph/gate.yaml")?;
+//! let findings = GateFinding::from_pipeline_result(&result);
+//!
+//! let verdict = PolicyEngine::from_policy(&policy).evaluate(&findings, &GateContext::default());
+//! println!("{}", verdict.summary());
+//! std::process::exit(verdict.exit_code());
+//! ```
+
+pub mod application;
+pub mod domain;
+
+// Re-export application layer
+pub use application::PolicyEngine;
+
+// Re-exports for convenience
+pub use domain::{
+    FindingCategory, FindingSeverity, GateAction, GateContext, GateFinding, GatePolicy, GateRule,
+    GateStatus, GateVerdict, PolicyRule, RuleOutcome,
+};
@@ -2,7 +2,9 @@
 pub mod blame_info;
 pub mod churn_metrics;
 pub mod cochange_pattern;
+pub mod symbol_history;
 
 pub use blame_info::*;
 pub use churn_metrics::*;
 pub use cochange_pattern::*;
+pub use symbol_history::*;
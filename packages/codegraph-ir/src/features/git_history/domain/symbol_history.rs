@@ -0,0 +1,167 @@
+/// Per-symbol authorship and churn (git blame stage)
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::shared::models::{Node, Span};
+
+/// Node metadata key of `SymbolHistory`
+pub const SYMBOL_HISTORY_KEY: &str = "git_history";
+
+/// Default churn window
+pub const CHURN_WINDOW_DAYS: i64 = 90;
+
+/// Consecutive lines last changed by one commit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlameHunk {
+    /// First line (1-based)
+    pub start_line: u32,
+    pub lines: u32,
+    pub commit: String,
+    pub author_name: String,
+    pub author_email: String,
+    pub time: DateTime<Utc>,
+}
+
+impl BlameHunk {
+    fn end_line(&self) -> u32 {
+        self.start_line + self.lines.saturating_sub(1)
+    }
+
+    /// Lines of `start..=end` this hunk covers
+    fn overlap(&self, start: u32, end: u32) -> u32 {
+        let from = self.start_line.max(start);
+        let to = self.end_line().min(end);
+        if self.lines == 0 || to < from {
+            0
+        } else {
+            to - from + 1
+        }
+    }
+}
+
+/// Lines of a symbol last changed by one author
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthorShare {
+    pub name: String,
+    pub email: String,
+    pub lines: u32,
+}
+
+/// Authorship, last change and churn of one symbol
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolHistory {
+    /// Ordered by lines, highest first
+    pub authors: Vec<AuthorShare>,
+    pub last_commit: String,
+    pub last_author: String,
+    pub last_author_email: String,
+    pub last_modified: DateTime<Utc>,
+    /// Distinct commits within the churn window that last changed lines
+    /// of the symbol
+    pub churn: u32,
+    /// Commits within the churn window touching the symbol's file
+    pub file_churn: u32,
+    pub churn_window_days: i64,
+}
+
+impl SymbolHistory {
+    /// History of the lines of `span` from the blame of its file
+    pub fn from_hunks(
+        hunks: &[BlameHunk],
+        span: &Span,
+        now: DateTime<Utc>,
+        window_days: i64,
+        file_churn: u32,
+    ) -> Option<Self> {
+        let start = span.start_line.max(1);
+        let end = span.end_line.max(start);
+        let cutoff = now - Duration::days(window_days);
+
+        let mut authors: Vec<AuthorShare> = Vec::new();
+        let mut recent: Vec<&str> = Vec::new();
+        let mut last: Option<&BlameHunk> = None;
+        for hunk in hunks {
+            let lines = hunk.overlap(start, end);
+            if lines == 0 {
+                continue;
+            }
+            match authors.iter_mut().find(|a| a.email == hunk.author_email) {
+                Some(author) => author.lines += lines,
+                None => authors.push(AuthorShare {
+                    name: hunk.author_name.clone(),
+                    email: hunk.author_email.clone(),
+                    lines,
+                }),
+            }
+            if hunk.time >= cutoff && !recent.contains(&hunk.commit.as_str()) {
+                recent.push(&hunk.commit);
+            }
+            if last.map_or(true, |l| hunk.time > l.time) {
+                last = Some(hunk);
+            }
+        }
+        let last = last?;
+        authors.sort_by(|a, b| b.lines.cmp(&a.lines).then_with(|| a.email.cmp(&b.email)));
+
+        Some(Self {
+            authors,
+            last_commit: last.commit.clone(),
+            last_author: last.author_name.clone(),
+            last_author_email: last.author_email.clone(),
+            last_modified: last.time,
+            churn: recent.len() as u32,
+            file_churn,
+            churn_window_days: window_days,
+        })
+    }
+
+    /// Author of most lines
+    pub fn primary_author(&self) -> Option<&AuthorShare> {
+        self.authors.first()
+    }
+
+    /// History attached to a node by the git blame stage
+    pub fn from_node(node: &Node) -> Option<Self> {
+        let metadata: serde_json::Value = serde_json::from_str(node.metadata.as_deref()?).ok()?;
+        serde_json::from_value(metadata.get(SYMBOL_HISTORY_KEY)?.clone()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hunk(start_line: u32, lines: u32, commit: &str, email: &str, days_ago: i64) -> BlameHunk {
+        BlameHunk {
+            start_line,
+            lines,
+            commit: commit.to_string(),
+            author_name: email.split('@').next().unwrap().to_string(),
+            author_email: email.to_string(),
+            time: DateTime::from_timestamp(1_800_000_000, 0).unwrap() - Duration::days(days_ago),
+        }
+    }
+
+    #[test]
+    fn test_history_of_span() {
+        let now = DateTime::from_timestamp(1_800_000_000, 0).unwrap();
+        let hunks = vec![
+            hunk(1, 4, "c1", "ann@x.dev", 400),
+            hunk(5, 3, "c2", "bob@x.dev", 30),
+            hunk(8, 2, "c3", "ann@x.dev", 10),
+            hunk(10, 5, "c4", "cid@x.dev", 1),
+        ];
+
+        // Lines 3-9: ann 2 + 2, bob 3; c4 is outside the span
+        let history =
+            SymbolHistory::from_hunks(&hunks, &Span::new(3, 0, 9, 0), now, 90, 7).unwrap();
+
+        assert_eq!(history.primary_author().unwrap().email, "ann@x.dev");
+        assert_eq!(history.authors[0].lines, 4);
+        assert_eq!(history.authors[1].lines, 3);
+        assert_eq!(history.last_commit, "c3");
+        assert_eq!(history.churn, 2);
+        assert_eq!(history.file_churn, 7);
+        assert!(SymbolHistory::from_hunks(&hunks, &Span::new(20, 0, 30, 0), now, 90, 7).is_none());
+    }
+}
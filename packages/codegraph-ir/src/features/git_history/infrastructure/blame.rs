@@ -0,0 +1,156 @@
+/// libgit2 blame and churn (`git` feature)
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Duration, Utc};
+use git2::{BlameOptions, DiffOptions, Repository, Sort};
+
+use super::error::{GitError, Result};
+use crate::features::git_history::domain::BlameHunk;
+
+fn git_error(e: git2::Error) -> GitError {
+    GitError::CommandFailed(e.message().to_string())
+}
+
+fn commit_time(time: git2::Time) -> DateTime<Utc> {
+    DateTime::from_timestamp(time.seconds(), 0).unwrap_or_default()
+}
+
+/// Blame and churn of one repository through libgit2
+pub struct GitBlame {
+    repo: Repository,
+}
+
+impl GitBlame {
+    pub fn open(repo_root: impl AsRef<Path>) -> Result<Self> {
+        let repo_root = repo_root.as_ref();
+        let repo = Repository::discover(repo_root)
+            .map_err(|_| GitError::NotARepository(repo_root.display().to_string()))?;
+        Ok(Self { repo })
+    }
+
+    /// Working directory (paths are relative to it)
+    pub fn workdir(&self) -> Option<PathBuf> {
+        self.repo.workdir().map(Path::to_path_buf)
+    }
+
+    /// Hunks of the committed (`HEAD`) version of `path`
+    pub fn blame_file(&self, path: &str) -> Result<Vec<BlameHunk>> {
+        let mut options = BlameOptions::new();
+        let blame = self
+            .repo
+            .blame_file(Path::new(path), Some(&mut options))
+            .map_err(git_error)?;
+
+        Ok(blame
+            .iter()
+            .filter(|hunk| !hunk.final_commit_id().is_zero())
+            .map(|hunk| {
+                let signature = hunk.final_signature();
+                BlameHunk {
+                    start_line: hunk.final_start_line() as u32,
+                    lines: hunk.lines_in_hunk() as u32,
+                    commit: hunk.final_commit_id().to_string(),
+                    author_name: signature.name().unwrap_or_default().to_string(),
+                    author_email: signature.email().unwrap_or_default().to_string(),
+                    time: commit_time(signature.when()),
+                }
+            })
+            .collect())
+    }
+
+    /// Commits since `now - window_days` per file (first-parent diffs)
+    pub fn file_churn(&self, now: DateTime<Utc>, window_days: i64) -> Result<HashMap<String, u32>> {
+        let cutoff = (now - Duration::days(window_days)).timestamp();
+        let mut revwalk = self.repo.revwalk().map_err(git_error)?;
+        revwalk.set_sorting(Sort::TIME).map_err(git_error)?;
+        if revwalk.push_head().is_err() {
+            // Unborn branch: no history yet
+            return Ok(HashMap::new());
+        }
+
+        let mut churn: HashMap<String, u32> = HashMap::new();
+        for oid in revwalk {
+            let commit = self
+                .repo
+                .find_commit(oid.map_err(git_error)?)
+                .map_err(git_error)?;
+            if commit.time().seconds() < cutoff {
+                break;
+            }
+            let tree = commit.tree().map_err(git_error)?;
+            let parent = match commit.parent(0) {
+                Ok(parent) => Some(parent.tree().map_err(git_error)?),
+                Err(_) => None,
+            };
+            let diff = self
+                .repo
+                .diff_tree_to_tree(parent.as_ref(), Some(&tree), Some(&mut DiffOptions::new()))
+                .map_err(git_error)?;
+            for delta in diff.deltas() {
+                if let Some(path) = delta.new_file().path().or_else(|| delta.old_file().path()) {
+                    *churn
+                        .entry(path.to_string_lossy().replace('\\', "/"))
+                        .or_default() += 1;
+                }
+            }
+        }
+        Ok(churn)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use git2::{IndexAddOption, Signature};
+
+    #[test]
+    fn test_blame_and_churn() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        let commit = |message: &str, email: &str| {
+            let mut index = repo.index().unwrap();
+            index.add_all(["*"], IndexAddOption::DEFAULT, None).unwrap();
+            index.write().unwrap();
+            let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+            let signature = Signature::now("dev", email).unwrap();
+            let parents: Vec<git2::Commit> = repo
+                .head()
+                .ok()
+                .and_then(|h| h.peel_to_commit().ok())
+                .into_iter()
+                .collect();
+            let parents: Vec<&git2::Commit> = parents.iter().collect();
+            repo.commit(
+                Some("HEAD"),
+                &signature,
+                &signature,
+                message,
+                &tree,
+                &parents,
+            )
+            .unwrap();
+        };
+
+        std::fs::write(dir.path().join("a.py"), "def f():\n    return 1\n").unwrap();
+        commit("add f", "ann@x.dev");
+        std::fs::write(
+            dir.path().join("a.py"),
+            "def f():\n    return 1\n\ndef g():\n    return 2\n",
+        )
+        .unwrap();
+        commit("add g", "bob@x.dev");
+
+        let blame = GitBlame::open(dir.path()).unwrap();
+        let hunks = blame.blame_file("a.py").unwrap();
+        assert_eq!(hunks.len(), 2);
+        assert_eq!(hunks[0].author_email, "ann@x.dev");
+        assert_eq!(
+            (hunks[1].start_line, hunks[1].author_email.as_str()),
+            (3, "bob@x.dev")
+        );
+
+        let churn = blame.file_churn(Utc::now(), 90).unwrap();
+        assert_eq!(churn.get("a.py"), Some(&2));
+    }
+}
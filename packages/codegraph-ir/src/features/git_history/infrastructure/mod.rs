@@ -1,7 +1,11 @@
+#[cfg(feature = "git")]
+pub mod blame;
 pub mod error;
 /// Git History Infrastructure
 pub mod git_executor;
 
+#[cfg(feature = "git")]
+pub use blame::*;
 pub use error::*;
 pub use git_executor::*;
 
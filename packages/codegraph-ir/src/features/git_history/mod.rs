@@ -7,9 +7,11 @@ pub mod application;
 /// - **Blame Analysis**: Track authorship and modification history
 /// - **Churn Analysis**: Identify volatile/risky files
 /// - **Co-change Analysis**: Find logically coupled files
+/// - **Symbol History**: Per-symbol authorship and churn from libgit2 blame
+///   (`git` feature)
 ///
 /// ## Note
-/// This feature requires git command-line tool.
+/// This feature requires git command-line tool (except `GitBlame`).
 /// Performance depends on repository size.
 pub mod domain;
 pub mod infrastructure;
@@ -82,6 +82,12 @@ impl EdgeConverter {
                                 attrs.insert(k.clone(), v.clone());
                             }
                         }
+                        if let Some(provenance) = ir_edge.provenance() {
+                            attrs.insert("provenance".to_string(), serde_json::json!(provenance));
+                        }
+                        if ir_edge.is_async() {
+                            attrs.insert("is_async".to_string(), serde_json::json!(true));
+                        }
 
                         Some(GraphEdge {
                             id: edge_id,
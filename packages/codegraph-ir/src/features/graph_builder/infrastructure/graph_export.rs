@@ -0,0 +1,543 @@
+// Graph Export - Neo4j Cypher script and bulk-import CSV
+//
+// Maps GraphDocument nodes/edges to labelled property-graph records:
+// - Node labels: base label (`CodeNode`) + NodeKind (`Function`, `Class`, ...)
+// - Relationship types: EdgeKind (`CALLS`, `CONTAINS`, ...)
+// - Scalar attrs become `attr_<key>` properties, others JSON strings
+//
+// Output is sorted by id and grouped by label/type, so exports diff cleanly.
+// Edges whose endpoints are not in the document are skipped (counted in
+// ExportStats); Neo4j cannot create relationships to missing nodes.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+
+use crate::features::graph_builder::domain::{GraphDocument, GraphEdge, GraphNode};
+use crate::shared::models::{CodegraphError, EdgeKind, NodeKind, Result};
+
+/// Default label put on every exported node (unique `id` constraint)
+pub const DEFAULT_BASE_LABEL: &str = "CodeNode";
+
+/// Rows per `UNWIND` statement
+pub const DEFAULT_EXPORT_BATCH: usize = 1000;
+
+/// Property value
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyValue {
+    String(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+}
+
+impl PropertyValue {
+    fn from_json(value: &serde_json::Value) -> Option<Self> {
+        match value {
+            serde_json::Value::Null => None,
+            serde_json::Value::Bool(b) => Some(Self::Bool(*b)),
+            serde_json::Value::Number(n) => Some(match n.as_i64() {
+                Some(i) => Self::Int(i),
+                None => Self::Float(n.as_f64().unwrap_or_default()),
+            }),
+            serde_json::Value::String(s) => Some(Self::String(s.clone())),
+            other => Some(Self::String(other.to_string())),
+        }
+    }
+
+    /// Cypher literal
+    fn to_cypher(&self) -> String {
+        match self {
+            Self::String(s) => cypher_string(s),
+            Self::Int(i) => i.to_string(),
+            Self::Float(f) if f.is_finite() => format!("{:?}", f),
+            Self::Float(_) => "null".to_string(),
+            Self::Bool(b) => b.to_string(),
+        }
+    }
+}
+
+/// Ordered property list of one node or relationship
+pub type Properties = Vec<(String, PropertyValue)>;
+
+/// Export counts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExportStats {
+    pub nodes: usize,
+    pub relationships: usize,
+    /// Edges to/from nodes outside the document
+    pub skipped_relationships: usize,
+}
+
+/// NodeKind → label and EdgeKind → relationship type mapping
+#[derive(Debug, Clone)]
+pub struct GraphLabels {
+    base_label: String,
+    node_labels: HashMap<NodeKind, String>,
+    edge_types: HashMap<EdgeKind, String>,
+}
+
+impl Default for GraphLabels {
+    fn default() -> Self {
+        Self {
+            base_label: DEFAULT_BASE_LABEL.to_string(),
+            node_labels: HashMap::new(),
+            edge_types: HashMap::new(),
+        }
+    }
+}
+
+impl GraphLabels {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_base_label(mut self, label: impl Into<String>) -> Self {
+        self.base_label = label.into();
+        self
+    }
+
+    /// Override the label of `kind` (default: `NodeKind::as_str`)
+    pub fn with_node_label(mut self, kind: NodeKind, label: impl Into<String>) -> Self {
+        self.node_labels.insert(kind, label.into());
+        self
+    }
+
+    /// Override the type of `kind` (default: `EdgeKind::as_str`)
+    pub fn with_edge_type(mut self, kind: EdgeKind, rel_type: impl Into<String>) -> Self {
+        self.edge_types.insert(kind, rel_type.into());
+        self
+    }
+
+    pub fn base_label(&self) -> &str {
+        &self.base_label
+    }
+
+    pub fn node_label(&self, kind: NodeKind) -> &str {
+        self.node_labels
+            .get(&kind)
+            .map(String::as_str)
+            .unwrap_or_else(|| kind.as_str())
+    }
+
+    pub fn edge_type(&self, kind: EdgeKind) -> &str {
+        self.edge_types
+            .get(&kind)
+            .map(String::as_str)
+            .unwrap_or_else(|| kind.as_str())
+    }
+}
+
+/// Exports GraphDocuments as Cypher or neo4j-admin CSV
+#[derive(Debug, Clone)]
+pub struct GraphExporter {
+    labels: GraphLabels,
+    batch_size: usize,
+}
+
+impl Default for GraphExporter {
+    fn default() -> Self {
+        Self::new(GraphLabels::default())
+    }
+}
+
+impl GraphExporter {
+    pub fn new(labels: GraphLabels) -> Self {
+        Self {
+            labels,
+            batch_size: DEFAULT_EXPORT_BATCH,
+        }
+    }
+
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    pub fn labels(&self) -> &GraphLabels {
+        &self.labels
+    }
+
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// Nodes grouped by label, sorted by id
+    pub fn node_groups<'d>(&self, doc: &'d GraphDocument) -> BTreeMap<&str, Vec<&'d GraphNode>> {
+        let mut groups: BTreeMap<&str, Vec<&GraphNode>> = BTreeMap::new();
+        for node in doc.graph_nodes.values() {
+            groups
+                .entry(self.labels.node_label(node.kind))
+                .or_default()
+                .push(node);
+        }
+        for nodes in groups.values_mut() {
+            nodes.sort_by(|a, b| a.id.cmp(&b.id));
+        }
+        groups
+    }
+
+    /// Edges between exported nodes grouped by type, sorted by id, and the
+    /// number of skipped dangling edges
+    pub fn edge_groups<'d>(
+        &self,
+        doc: &'d GraphDocument,
+    ) -> (BTreeMap<&str, Vec<&'d GraphEdge>>, usize) {
+        let mut groups: BTreeMap<&str, Vec<&GraphEdge>> = BTreeMap::new();
+        let mut skipped = 0;
+        for edge in &doc.graph_edges {
+            if doc.get_node(&edge.source_id).is_none() || doc.get_node(&edge.target_id).is_none() {
+                skipped += 1;
+                continue;
+            }
+            groups
+                .entry(self.labels.edge_type(edge.kind))
+                .or_default()
+                .push(edge);
+        }
+        for edges in groups.values_mut() {
+            edges.sort_by(|a, b| a.id.cmp(&b.id));
+        }
+        (groups, skipped)
+    }
+
+    /// `MERGE` statement for one batch of node rows (`$rows` or a literal)
+    pub fn node_statement(&self, label: &str, rows: &str) -> String {
+        format!(
+            "UNWIND {} AS row\nMERGE (n:{} {{id: row.id}})\nSET n += row, n:{}",
+            rows,
+            cypher_name(&self.labels.base_label),
+            cypher_name(label)
+        )
+    }
+
+    /// `MERGE` statement for one batch of relationship rows
+    /// (`{source, target, props}` maps)
+    pub fn edge_statement(&self, rel_type: &str, rows: &str) -> String {
+        let base = cypher_name(&self.labels.base_label);
+        format!(
+            "UNWIND {} AS row\nMATCH (a:{base} {{id: row.source}})\nMATCH (b:{base} {{id: row.target}})\nMERGE (a)-[r:{} {{id: row.props.id}}]->(b)\nSET r += row.props",
+            rows,
+            cypher_name(rel_type),
+            base = base
+        )
+    }
+
+    /// Unique-id constraint on the base label
+    pub fn constraint_statement(&self) -> String {
+        let base = cypher_name(&self.labels.base_label);
+        format!(
+            "CREATE CONSTRAINT {} IF NOT EXISTS FOR (n:{}) REQUIRE n.id IS UNIQUE",
+            cypher_name(&format!("{}_id", self.labels.base_label.to_lowercase())),
+            base
+        )
+    }
+
+    /// Cypher script (`cypher-shell -f`): constraint, node batches, edge batches
+    pub fn write_cypher<W: Write>(&self, doc: &GraphDocument, out: &mut W) -> Result<ExportStats> {
+        let mut stats = ExportStats::default();
+        writeln!(
+            out,
+            "// codegraph export: {} @ {}",
+            doc.repo_id, doc.snapshot_id
+        )
+        .map_err(io)?;
+        writeln!(out, "{};", self.constraint_statement()).map_err(io)?;
+
+        for (label, nodes) in self.node_groups(doc) {
+            for batch in nodes.chunks(self.batch_size) {
+                let rows: Vec<String> = batch
+                    .iter()
+                    .map(|n| cypher_map(&node_properties(n)))
+                    .collect();
+                let rows = format!("[\n  {}\n]", rows.join(",\n  "));
+                writeln!(out, "\n{};", self.node_statement(label, &rows)).map_err(io)?;
+                stats.nodes += batch.len();
+            }
+        }
+
+        let (groups, skipped) = self.edge_groups(doc);
+        stats.skipped_relationships = skipped;
+        for (rel_type, edges) in groups {
+            for batch in edges.chunks(self.batch_size) {
+                let rows: Vec<String> = batch
+                    .iter()
+                    .map(|e| {
+                        format!(
+                            "{{source: {}, target: {}, props: {}}}",
+                            cypher_string(&e.source_id),
+                            cypher_string(&e.target_id),
+                            cypher_map(&edge_properties(e))
+                        )
+                    })
+                    .collect();
+                let rows = format!("[\n  {}\n]", rows.join(",\n  "));
+                writeln!(out, "\n{};", self.edge_statement(rel_type, &rows)).map_err(io)?;
+                stats.relationships += batch.len();
+            }
+        }
+        Ok(stats)
+    }
+
+    /// `neo4j-admin database import` CSV: one node file, one relationship file
+    pub fn write_csv<N: Write, R: Write>(
+        &self,
+        doc: &GraphDocument,
+        nodes_out: &mut N,
+        relationships_out: &mut R,
+    ) -> Result<ExportStats> {
+        let mut stats = ExportStats::default();
+        writeln!(
+            nodes_out,
+            "id:ID,:LABEL,kind,fqn,name,path,repo_id,snapshot_id,start_line:int,end_line:int,attrs"
+        )
+        .map_err(io)?;
+        for (label, nodes) in self.node_groups(doc) {
+            let labels = format!("{};{}", self.labels.base_label, label);
+            for node in nodes {
+                let (start, end) = node
+                    .span
+                    .as_ref()
+                    .map(|s| (s.start_line.to_string(), s.end_line.to_string()))
+                    .unwrap_or_default();
+                let row = [
+                    csv_field(&node.id),
+                    csv_field(&labels),
+                    csv_field(node.kind.as_str()),
+                    csv_field(&node.fqn),
+                    csv_field(&node.name),
+                    csv_field(node.path.as_deref().unwrap_or("")),
+                    csv_field(&node.repo_id),
+                    csv_field(node.snapshot_id.as_deref().unwrap_or("")),
+                    start,
+                    end,
+                    csv_field(&attrs_json(&node.attrs)),
+                ];
+                writeln!(nodes_out, "{}", row.join(",")).map_err(io)?;
+                stats.nodes += 1;
+            }
+        }
+
+        writeln!(relationships_out, ":START_ID,:END_ID,:TYPE,id,attrs").map_err(io)?;
+        let (groups, skipped) = self.edge_groups(doc);
+        stats.skipped_relationships = skipped;
+        for (rel_type, edges) in groups {
+            for edge in edges {
+                let row = [
+                    csv_field(&edge.source_id),
+                    csv_field(&edge.target_id),
+                    csv_field(rel_type),
+                    csv_field(&edge.id),
+                    csv_field(&attrs_json(&edge.attrs)),
+                ];
+                writeln!(relationships_out, "{}", row.join(",")).map_err(io)?;
+                stats.relationships += 1;
+            }
+        }
+        Ok(stats)
+    }
+}
+
+/// Node properties: core fields, span, then `attr_*` in key order
+pub fn node_properties(node: &GraphNode) -> Properties {
+    let mut props = vec![
+        ("id".to_string(), PropertyValue::String(node.id.to_string())),
+        (
+            "kind".to_string(),
+            PropertyValue::String(node.kind.as_str().to_string()),
+        ),
+        (
+            "fqn".to_string(),
+            PropertyValue::String(node.fqn.to_string()),
+        ),
+        (
+            "name".to_string(),
+            PropertyValue::String(node.name.to_string()),
+        ),
+        (
+            "repo_id".to_string(),
+            PropertyValue::String(node.repo_id.to_string()),
+        ),
+    ];
+    if let Some(path) = &node.path {
+        props.push(("path".to_string(), PropertyValue::String(path.to_string())));
+    }
+    if let Some(snapshot_id) = &node.snapshot_id {
+        props.push((
+            "snapshot_id".to_string(),
+            PropertyValue::String(snapshot_id.to_string()),
+        ));
+    }
+    if let Some(span) = &node.span {
+        for (key, value) in [
+            ("start_line", span.start_line),
+            ("start_col", span.start_col),
+            ("end_line", span.end_line),
+            ("end_col", span.end_col),
+        ] {
+            props.push((key.to_string(), PropertyValue::Int(value as i64)));
+        }
+    }
+    props.extend(attr_properties(&node.attrs));
+    props
+}
+
+/// Relationship properties: `id` then `attr_*`
+pub fn edge_properties(edge: &GraphEdge) -> Properties {
+    let mut props = vec![("id".to_string(), PropertyValue::String(edge.id.to_string()))];
+    props.extend(attr_properties(&edge.attrs));
+    props
+}
+
+fn attr_properties<'a>(
+    attrs: impl IntoIterator<Item = (&'a String, &'a serde_json::Value)>,
+) -> Properties {
+    let mut props: Properties = attrs
+        .into_iter()
+        .filter_map(|(key, value)| {
+            PropertyValue::from_json(value).map(|v| (format!("attr_{}", key), v))
+        })
+        .collect();
+    props.sort_by(|a, b| a.0.cmp(&b.0));
+    props
+}
+
+fn attrs_json<'a>(attrs: impl IntoIterator<Item = (&'a String, &'a serde_json::Value)>) -> String {
+    let sorted: BTreeMap<&String, &serde_json::Value> = attrs.into_iter().collect();
+    if sorted.is_empty() {
+        String::new()
+    } else {
+        serde_json::to_string(&sorted).unwrap_or_default()
+    }
+}
+
+fn cypher_map(props: &Properties) -> String {
+    let entries: Vec<String> = props
+        .iter()
+        .map(|(key, value)| format!("{}: {}", cypher_name(key), value.to_cypher()))
+        .collect();
+    format!("{{{}}}", entries.join(", "))
+}
+
+/// Identifier, backquoted unless plain
+fn cypher_name(name: &str) -> String {
+    let plain = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if plain {
+        name.to_string()
+    } else {
+        format!("`{}`", name.replace('`', "``"))
+    }
+}
+
+fn cypher_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('\'');
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\'' => out.push_str("\\'"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('\'');
+    out
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn io(err: std::io::Error) -> CodegraphError {
+    CodegraphError::io(format!("graph export: {}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::graph_builder::domain::intern;
+    use crate::shared::models::Span;
+    use ahash::AHashMap;
+
+    fn graph() -> GraphDocument {
+        let mut doc = GraphDocument::new(intern("repo"), intern("snap"));
+        for (id, kind, name) in [
+            ("c1", NodeKind::Class, "User"),
+            ("f1", NodeKind::Function, "it's"),
+        ] {
+            let mut attrs = AHashMap::new();
+            attrs.insert("async".to_string(), serde_json::json!(false));
+            doc.graph_nodes.insert(
+                intern(id),
+                GraphNode {
+                    id: intern(id),
+                    kind,
+                    repo_id: intern("repo"),
+                    snapshot_id: Some(intern("snap")),
+                    fqn: intern(format!("app.{}", name)),
+                    name: intern(name),
+                    path: Some(intern("app, models.py")),
+                    span: Some(Box::new(Span::new(3, 0, 9, 4))),
+                    attrs,
+                },
+            );
+        }
+        for (id, kind, target) in [
+            ("e1", EdgeKind::Contains, "f1"),
+            ("e2", EdgeKind::Calls, "external"),
+        ] {
+            doc.graph_edges.push(GraphEdge {
+                id: intern(id),
+                kind,
+                source_id: intern("c1"),
+                target_id: intern(target),
+                attrs: AHashMap::new(),
+            });
+        }
+        doc
+    }
+
+    #[test]
+    fn test_cypher_and_csv_export() {
+        let doc = graph();
+        let exporter =
+            GraphExporter::new(GraphLabels::new().with_node_label(NodeKind::Function, "Fn"));
+
+        let mut cypher = Vec::new();
+        let stats = exporter.write_cypher(&doc, &mut cypher).unwrap();
+        assert_eq!(
+            stats,
+            ExportStats {
+                nodes: 2,
+                relationships: 1,
+                skipped_relationships: 1
+            }
+        );
+        let cypher = String::from_utf8(cypher).unwrap();
+        assert!(cypher.contains("MERGE (n:CodeNode {id: row.id})\nSET n += row, n:Fn;"));
+        assert!(cypher.contains("name: 'it\\'s'"));
+        assert!(cypher.contains("attr_async: false"));
+        assert!(cypher.contains("MERGE (a)-[r:CONTAINS {id: row.props.id}]->(b)"));
+        assert!(!cypher.contains("CALLS"));
+
+        let (mut nodes, mut rels) = (Vec::new(), Vec::new());
+        exporter.write_csv(&doc, &mut nodes, &mut rels).unwrap();
+        let nodes = String::from_utf8(nodes).unwrap();
+        let lines: Vec<&str> = nodes.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("c1,CodeNode;Class,Class,app.User,User,\"app, models.py\""));
+        assert_eq!(
+            String::from_utf8(rels).unwrap().lines().nth(1),
+            Some("c1,f1,CONTAINS,e1,")
+        );
+    }
+}
@@ -4,7 +4,13 @@
 
 pub mod builder;
 pub mod edge_converter;
+pub mod graph_export;
 pub mod index_builder;
 pub mod node_converter;
+#[cfg(feature = "neo4j")]
+pub mod neo4j_sink;
 
 pub use builder::GraphBuilder;
+pub use graph_export::{ExportStats, GraphExporter, GraphLabels};
+#[cfg(feature = "neo4j")]
+pub use neo4j_sink::Neo4jSink;
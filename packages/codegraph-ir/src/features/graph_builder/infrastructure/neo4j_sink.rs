@@ -0,0 +1,113 @@
+// Neo4j Sink - stream GraphDocuments over Bolt (`neo4j` feature)
+//
+// Same statements as the Cypher script export, with `$rows` parameters
+// instead of literals: one transaction-less `UNWIND` per batch and label.
+
+use neo4rs::{query, BoltMap, BoltString, BoltType, Graph};
+
+use super::graph_export::{
+    edge_properties, node_properties, ExportStats, GraphExporter, Properties, PropertyValue,
+};
+use crate::features::graph_builder::domain::GraphDocument;
+use crate::shared::models::{CodegraphError, Result};
+
+/// Bolt connection plus export settings
+pub struct Neo4jSink {
+    graph: Graph,
+    exporter: GraphExporter,
+}
+
+impl Neo4jSink {
+    /// Connect to `uri` (e.g. `bolt://localhost:7687`)
+    pub async fn connect(uri: &str, user: &str, password: &str) -> Result<Self> {
+        let graph = Graph::new(uri, user, password).await.map_err(neo4j_error)?;
+        Ok(Self::new(graph))
+    }
+
+    /// Wrap an existing driver handle
+    pub fn new(graph: Graph) -> Self {
+        Self {
+            graph,
+            exporter: GraphExporter::default(),
+        }
+    }
+
+    /// Labels and batch size
+    pub fn with_exporter(mut self, exporter: GraphExporter) -> Self {
+        self.exporter = exporter;
+        self
+    }
+
+    /// MERGE every node, then every relationship between exported nodes
+    pub async fn export(&self, doc: &GraphDocument) -> Result<ExportStats> {
+        let exporter = &self.exporter;
+        let mut stats = ExportStats::default();
+        self.graph
+            .run(query(&exporter.constraint_statement()))
+            .await
+            .map_err(neo4j_error)?;
+
+        for (label, nodes) in exporter.node_groups(doc) {
+            let statement = exporter.node_statement(label, "$rows");
+            for batch in nodes.chunks(exporter.batch_size()) {
+                let rows: Vec<BoltType> = batch
+                    .iter()
+                    .map(|n| bolt_map(&node_properties(n)))
+                    .collect();
+                self.graph
+                    .run(query(&statement).param("rows", rows))
+                    .await
+                    .map_err(neo4j_error)?;
+                stats.nodes += batch.len();
+            }
+        }
+
+        let (groups, skipped) = exporter.edge_groups(doc);
+        stats.skipped_relationships = skipped;
+        for (rel_type, edges) in groups {
+            let statement = exporter.edge_statement(rel_type, "$rows");
+            for batch in edges.chunks(exporter.batch_size()) {
+                let rows: Vec<BoltType> = batch
+                    .iter()
+                    .map(|e| {
+                        let mut row = BoltMap::new();
+                        row.put(
+                            BoltString::from("source"),
+                            BoltType::from(e.source_id.to_string()),
+                        );
+                        row.put(
+                            BoltString::from("target"),
+                            BoltType::from(e.target_id.to_string()),
+                        );
+                        row.put(BoltString::from("props"), bolt_map(&edge_properties(e)));
+                        BoltType::Map(row)
+                    })
+                    .collect();
+                self.graph
+                    .run(query(&statement).param("rows", rows))
+                    .await
+                    .map_err(neo4j_error)?;
+                stats.relationships += batch.len();
+            }
+        }
+        Ok(stats)
+    }
+}
+
+fn bolt_map(props: &Properties) -> BoltType {
+    let mut map = BoltMap::new();
+    for (key, value) in props {
+        let value = match value {
+            PropertyValue::String(s) => BoltType::from(s.clone()),
+            PropertyValue::Int(i) => BoltType::from(*i),
+            PropertyValue::Float(f) => BoltType::from(*f),
+            PropertyValue::Bool(b) => BoltType::from(*b),
+        };
+        map.put(BoltString::from(key.as_str()), value);
+    }
+    BoltType::Map(map)
+}
+
+fn neo4j_error(err: neo4rs::Error) -> CodegraphError {
+    CodegraphError::storage(format!("Neo4j error: {}", err))
+}
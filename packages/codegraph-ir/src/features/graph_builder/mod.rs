@@ -12,6 +12,7 @@
 // ## Architecture
 // - Domain: Pure models (GraphNode, GraphEdge, GraphIndex)
 // - Infrastructure: Builder implementation with 4 parallel phases
+// - Export: Neo4j Cypher script / bulk-import CSV (GraphExporter), Bolt (`neo4j` feature)
 //
 // ## Expected Performance
 // - 10-20x faster than Python (949 LOC)
@@ -31,3 +32,8 @@ pub use domain::{GraphDocument, GraphEdge, GraphIndex, GraphNode};
 // Re-export infrastructure (internal use - prefer application layer)
 #[doc(hidden)]
 pub use infrastructure::GraphBuilder;
+
+// Export adapters
+pub use infrastructure::{ExportStats, GraphExporter, GraphLabels};
+#[cfg(feature = "neo4j")]
+pub use infrastructure::Neo4jSink;
@@ -41,6 +41,7 @@
 //! Time: O(n × m) where n=variables, m=statements
 //! Space: O(n) for escape state map
 
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::fmt;
 
@@ -114,7 +115,7 @@ impl EscapeNode {
 }
 
 /// Escape state of an object reference
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum EscapeState {
     /// Object never leaves local scope
     NoEscape,
@@ -199,7 +200,7 @@ impl fmt::Display for EscapeState {
 }
 
 /// Allocation site information
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AllocationSite {
     /// Unique identifier for allocation
     pub id: String,
@@ -215,7 +216,7 @@ pub struct AllocationSite {
 }
 
 /// Kind of memory allocation
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AllocKind {
     /// Object allocation (new, Box::new)
     Object,
@@ -231,7 +232,7 @@ pub enum AllocKind {
 }
 
 /// Escape analysis result for a single function
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FunctionEscapeInfo {
     /// Function identifier
     pub function_id: String,
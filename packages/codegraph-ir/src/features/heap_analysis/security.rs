@@ -180,6 +180,9 @@ pub enum VulnerabilityType {
     MissingAuthentication,
     SessionFixation,
 
+    // Logging (A09)
+    SensitiveDataLogging,
+
     // Misc
     CSRF,
     XXE,
@@ -202,6 +205,7 @@ impl VulnerabilityType {
             Self::WeakPassword | Self::MissingAuthentication | Self::SessionFixation => {
                 OWASPCategory::A07_AuthenticationFailures
             }
+            Self::SensitiveDataLogging => OWASPCategory::A09_LoggingMonitoringFailures,
             Self::CSRF => OWASPCategory::A01_BrokenAccessControl,
             Self::XXE | Self::InsecureDeserialization => OWASPCategory::A08_IntegrityFailures,
             Self::SSRF => OWASPCategory::A10_SSRF,
@@ -222,6 +226,7 @@ impl VulnerabilityType {
             Self::WeakPassword => 521,
             Self::MissingAuthentication => 306,
             Self::SessionFixation => 384,
+            Self::SensitiveDataLogging => 532,
             Self::CSRF => 352,
             Self::XXE => 611,
             Self::InsecureDeserialization => 502,
@@ -238,7 +243,7 @@ impl VulnerabilityType {
             | Self::SSRF => 10,
             Self::XSS | Self::PathTraversal | Self::HardcodedCredentials => 9,
             Self::LDAPInjection | Self::XMLInjection | Self::CSRF => 8,
-            Self::WeakCrypto | Self::SessionFixation => 7,
+            Self::WeakCrypto | Self::SessionFixation | Self::SensitiveDataLogging => 7,
             Self::InsecureRandom | Self::WeakPassword => 6,
             Self::MissingAuthentication => 8,
         }
@@ -512,6 +517,9 @@ impl DeepSecurityAnalyzer {
             VulnerabilityType::SessionFixation => {
                 "Regenerate session ID after login; use secure session management".to_string()
             }
+            VulnerabilityType::SensitiveDataLogging => {
+                "Never log credentials; mask or drop secret values before logging".to_string()
+            }
             VulnerabilityType::CSRF => {
                 "Use CSRF tokens; validate Origin/Referer headers".to_string()
             }
@@ -19,7 +19,9 @@
 
 use crate::features::type_resolution::domain::type_entity::TypeEntity;
 use crate::features::type_resolution::infrastructure::type_resolver::TypeResolver;
-use crate::shared::models::{Edge, EdgeKind, Node, NodeKind, Span};
+use crate::shared::models::{
+    Edge, EdgeKind, EdgeMetadata, EdgeProvenance, EdgeStage, Node, NodeKind, Span,
+};
 use sha2::{Digest, Sha256};
 
 /// Scope context for FQN building
@@ -177,7 +179,7 @@ impl IRBuilder {
             target_id: child_id,
             kind: EdgeKind::Contains,
             span: Some(span),
-            metadata: None,
+            metadata: Some(EdgeMetadata::with_provenance(l1_provenance("contains"))),
             attrs: None,
         });
     }
@@ -189,11 +191,58 @@ impl IRBuilder {
             target_id: callee_fqn,
             kind: EdgeKind::Calls,
             span: Some(span),
-            metadata: None,
+            metadata: Some(EdgeMetadata::with_provenance(l1_provenance("call_site"))),
             attrs: None,
         });
     }
 
+    /// Add CALLS edge with a resolution confidence (0.0-1.0) and the call's
+    /// positional arguments
+    ///
+    /// Use when the callee FQN is a heuristic guess (dynamic receivers,
+    /// unresolved names) so downstream impact analysis can discount it.
+    pub fn add_calls_edge_with_confidence(
+        &mut self,
+        caller_id: String,
+        callee_fqn: String,
+        span: Span,
+        confidence: f32,
+        arguments: Vec<String>,
+    ) {
+        self.edges.push(Edge {
+            source_id: caller_id,
+            target_id: callee_fqn,
+            kind: EdgeKind::Calls,
+            span: Some(span),
+            metadata: Some(EdgeMetadata {
+                arguments: Some(arguments),
+                ..EdgeMetadata::with_provenance(
+                    l1_provenance("call_site").with_confidence(confidence),
+                )
+            }),
+            attrs: None,
+        });
+    }
+
+    /// Add CALLS edge for an awaited / scheduled invocation
+    pub fn add_async_calls_edge(
+        &mut self,
+        caller_id: String,
+        callee_fqn: String,
+        span: Span,
+        confidence: f32,
+        arguments: Vec<String>,
+    ) {
+        let mut edge = Edge::new(caller_id, callee_fqn, EdgeKind::Calls)
+            .with_span(span)
+            .with_provenance(l1_provenance("async_call_site").with_confidence(confidence))
+            .with_is_async(true);
+        if let Some(metadata) = edge.metadata.as_mut() {
+            metadata.arguments = Some(arguments);
+        }
+        self.edges.push(edge);
+    }
+
     /// Add READS edge
     pub fn add_reads_edge(&mut self, reader_id: String, variable_fqn: String, span: Span) {
         self.edges.push(Edge {
@@ -201,7 +250,7 @@ impl IRBuilder {
             target_id: variable_fqn,
             kind: EdgeKind::Reads,
             span: Some(span),
-            metadata: None,
+            metadata: Some(EdgeMetadata::with_provenance(l1_provenance("read"))),
             attrs: None,
         });
     }
@@ -213,7 +262,7 @@ impl IRBuilder {
             target_id: variable_fqn,
             kind: EdgeKind::Writes,
             span: Some(span),
-            metadata: None,
+            metadata: Some(EdgeMetadata::with_provenance(l1_provenance("write"))),
             attrs: None,
         });
     }
@@ -225,7 +274,7 @@ impl IRBuilder {
             target_id: parent_fqn,
             kind: EdgeKind::Inherits,
             span: Some(span),
-            metadata: None,
+            metadata: Some(EdgeMetadata::with_provenance(l1_provenance("class_bases"))),
             attrs: None,
         });
     }
@@ -252,8 +301,6 @@ impl IRBuilder {
         alias: Option<String>,
         _is_from_import: bool,
     ) {
-        use crate::shared::models::EdgeMetadata;
-
         self.edges.push(Edge {
             source_id: importer_id,
             target_id: imported_fqn,
@@ -261,6 +308,7 @@ impl IRBuilder {
             span: Some(span),
             metadata: Some(EdgeMetadata {
                 alias,
+                provenance: Some(l1_provenance("import_stmt")),
                 ..Default::default()
             }),
             attrs: None,
@@ -506,6 +554,13 @@ impl IRBuilder {
         Ok(node_id)
     }
 
+    /// Record the parameter names of a function node created by this builder
+    pub fn set_parameters(&mut self, node_id: &str, parameters: Vec<String>) {
+        if let Some(node) = self.nodes.iter_mut().rev().find(|n| n.id == node_id) {
+            node.parameters = Some(parameters);
+        }
+    }
+
     /// Finish function/class processing (pop scope)
     pub fn finish_scope(&mut self) {
         self.pop_scope();
@@ -595,6 +650,11 @@ impl IRBuilder {
     }
 }
 
+/// Provenance for edges emitted by the L1 IR builder
+fn l1_provenance(rule: &str) -> EdgeProvenance {
+    EdgeProvenance::new(EdgeStage::IrBuild).with_rule(rule)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -711,6 +771,10 @@ mod tests {
             .filter(|e| e.kind == EdgeKind::Inherits)
             .collect();
         assert_eq!(inherits_edges.len(), 2);
+
+        let provenance = inherits_edges[0].provenance().unwrap();
+        assert_eq!(provenance.stage, EdgeStage::IrBuild);
+        assert_eq!(provenance.rule.as_deref(), Some("class_bases"));
     }
 
     #[test]
@@ -336,6 +336,7 @@ def hello():
     }
 
     #[test]
+    #[cfg(feature = "rust-lang")]
     fn test_tree_sitter_rust() {
         let source = r#"
         /// This is a doc comment
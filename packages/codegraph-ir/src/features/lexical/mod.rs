@@ -18,6 +18,8 @@
 //!
 //! - **Native Tantivy**: 2x faster than Lucene, no Python FFI overhead
 //! - **3-gram + CamelCase Tokenizer**: "getUserName" → ["get", "User", "Name"]
+//! - **Identifier-aware Analyzer**: camelCase/snake_case/digit splitting,
+//!   optional stemming and per-language stop symbols (`CodeAnalyzerOptions`)
 //! - **Incremental Updates**: Tree-sitter + IndexPlugin.apply_delta()
 //! - **Chunk Mapping**: file:line → chunk_id via SQLite
 //! - **BM25 Ranking**: Default Tantivy similarity
@@ -64,7 +66,10 @@ pub use extractor::{ExtractedFields, FieldExtractor, RegexExtractor};
 pub use query_router::{Filter, HybridSearchConfig, QueryRouter, SearchRequest, SearchResponse};
 pub use schema::{build_schema, FIELD_CONTENT, FIELD_FILE_PATH, FIELD_REPO_ID};
 pub use tantivy_index::{IndexingMode, SearchHit, TantivyLexicalIndex};
-pub use tokenizer::{build_code_analyzer, build_ngram_analyzer, CamelCaseTokenizer};
+pub use tokenizer::{
+    build_code_analyzer, build_ngram_analyzer, build_search_analyzer, split_identifier,
+    stop_symbols, CamelCaseTokenizer, CodeAnalyzerOptions, IdentifierTokenizer, CODE_TOKENIZER,
+};
 
 // Re-export from storage (PostgreSQL chunk store)
 pub use crate::features::storage::{Chunk, ChunkStore, InMemoryChunkStore, SqliteChunkStore};
@@ -119,6 +124,9 @@ impl FileToIndex {
 pub struct IndexingResult {
     pub total_files: usize,
     pub success_count: usize,
+    /// Files skipped by `update_files` because their content was unchanged
+    #[serde(default)]
+    pub skipped_count: usize,
     #[serde(rename = "failures")]
     pub failed_files: Vec<(String, String)>, // (file_path, error)
     #[serde(rename = "duration")]
@@ -127,15 +135,20 @@ pub struct IndexingResult {
 
 impl IndexingResult {
     pub fn is_complete_success(&self) -> bool {
-        self.success_count == self.total_files
+        self.up_to_date_count() == self.total_files
     }
 
     pub fn is_partial_success(&self) -> bool {
-        self.success_count > 0 && self.success_count < self.total_files
+        self.up_to_date_count() > 0 && self.up_to_date_count() < self.total_files
     }
 
     pub fn is_complete_failure(&self) -> bool {
-        self.success_count == 0
+        self.up_to_date_count() == 0
+    }
+
+    /// Files indexed or already current
+    fn up_to_date_count(&self) -> usize {
+        self.success_count + self.skipped_count
     }
 
     pub fn throughput(&self) -> f64 {
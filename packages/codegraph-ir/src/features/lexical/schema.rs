@@ -10,9 +10,9 @@
 //! 6. `repo_id` - Repository ID (STORED, keyword)
 //! 7. `indexed_at` - Indexing timestamp (STORED, date)
 
-use tantivy::schema::{
-    Field, IndexRecordOption, Schema, TextFieldIndexing, TextOptions, STORED, TEXT,
-};
+use tantivy::schema::{Field, IndexRecordOption, Schema, TextFieldIndexing, TextOptions, STORED};
+
+use super::tokenizer::CODE_TOKENIZER;
 
 // Field name constants (for type-safe access)
 pub const FIELD_CONTENT: &str = "content";
@@ -27,13 +27,20 @@ pub const FIELD_INDEXED_AT: &str = "indexed_at";
 pub fn build_schema() -> Schema {
     let mut schema_builder = Schema::builder();
 
+    // Searchable text: code analyzer (identifier splitting, registered by the index)
+    let code_text = TextOptions::default().set_indexing_options(
+        TextFieldIndexing::default()
+            .set_tokenizer(CODE_TOKENIZER)
+            .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+    );
+
     // 1. content - Code body (STORED for snippet, indexed for search)
-    schema_builder.add_text_field(FIELD_CONTENT, TEXT | STORED);
+    schema_builder.add_text_field(FIELD_CONTENT, code_text.clone().set_stored());
 
     // 2-4. Searchable fields (NOT STORED to save space)
-    schema_builder.add_text_field(FIELD_STRING_LITERALS, TEXT);
-    schema_builder.add_text_field(FIELD_COMMENTS, TEXT);
-    schema_builder.add_text_field(FIELD_DOCSTRING, TEXT);
+    schema_builder.add_text_field(FIELD_STRING_LITERALS, code_text.clone());
+    schema_builder.add_text_field(FIELD_COMMENTS, code_text.clone());
+    schema_builder.add_text_field(FIELD_DOCSTRING, code_text);
 
     // 5-6. Metadata (STORED, keyword for exact matching)
     let opts = TextOptions::default()
@@ -117,6 +124,19 @@ mod tests {
         assert!(entry.is_stored());
     }
 
+    #[test]
+    fn test_text_fields_use_code_tokenizer() {
+        let schema = build_schema();
+        for name in [FIELD_CONTENT, FIELD_COMMENTS, FIELD_DOCSTRING] {
+            let entry = schema.get_field_entry(schema.get_field(name).unwrap());
+            let tantivy::schema::FieldType::Str(options) = entry.field_type() else {
+                panic!("{} is not a text field", name);
+            };
+            let indexing = options.get_indexing_options().unwrap();
+            assert_eq!(indexing.tokenizer(), CODE_TOKENIZER);
+        }
+    }
+
     #[test]
     fn test_string_literals_not_stored() {
         let schema = build_schema();
@@ -21,6 +21,7 @@
 use crate::features::lexical::{
     extractor::{FieldExtractor, RegexExtractor},
     schema::{build_schema, SchemaFields, FIELD_FILE_PATH, FIELD_REPO_ID},
+    tokenizer::{build_search_analyzer, CodeAnalyzerOptions, CODE_TOKENIZER},
     FileToIndex, IndexingResult,
 };
 use crate::features::multi_index::ports::{
@@ -123,6 +124,27 @@ impl TantivyLexicalIndex {
         chunk_store: Arc<dyn ChunkStore>,
         repo_id: String,
         mode: IndexingMode,
+    ) -> Result<Self, IndexError> {
+        Self::new_with_analyzer(
+            index_dir,
+            chunk_store,
+            repo_id,
+            mode,
+            &CodeAnalyzerOptions::default(),
+        )
+    }
+
+    /// Create a new Tantivy lexical index with custom analyzer options
+    /// (identifier splitting, stemming, stop symbols).
+    ///
+    /// Documents and queries go through the same analyzer, so an index must
+    /// always be reopened with the options it was built with.
+    pub fn new_with_analyzer(
+        index_dir: &Path,
+        chunk_store: Arc<dyn ChunkStore>,
+        repo_id: String,
+        mode: IndexingMode,
+        analyzer: &CodeAnalyzerOptions,
     ) -> Result<Self, IndexError> {
         let schema_fields = SchemaFields::new();
 
@@ -138,6 +160,11 @@ impl TantivyLexicalIndex {
                 .map_err(|e| IndexError::InternalError(format!("Failed to create index: {}", e)))?
         };
 
+        // Register before the writer exists: segment writers pick up analyzers on creation
+        index
+            .tokenizers()
+            .register(CODE_TOKENIZER, build_search_analyzer(analyzer));
+
         // Create writer with parallel threads
         let writer = index
             .writer(50_000_000) // 50MB heap
@@ -227,11 +254,93 @@ impl TantivyLexicalIndex {
         Ok(IndexingResult {
             total_files: files.len(),
             success_count,
+            skipped_count: 0,
             failed_files,
             duration_seconds: duration.as_secs_f64(),
         })
     }
 
+    /// Incrementally sync the index with changed and removed files.
+    ///
+    /// Files whose content hash matches the one recorded in the chunk store
+    /// for `snapshot_id` are skipped; the rest are upserted and their hash
+    /// recorded. Removed files are deleted from the index. Chunks of changed
+    /// and removed files are soft-deleted, so callers re-save chunks for
+    /// changed files afterwards.
+    pub async fn update_files(
+        &self,
+        snapshot_id: &str,
+        changed: &[FileToIndex],
+        removed: &[String],
+        fail_fast: bool,
+    ) -> Result<IndexingResult, IndexError> {
+        let start = Instant::now();
+
+        let mut to_index = Vec::new();
+        let mut hashes = Vec::new();
+        for file in changed {
+            let hash = Chunk::compute_content_hash(&file.content);
+            let stored = self
+                .chunk_store
+                .get_file_hash(&self.repo_id, snapshot_id, &file.file_path)
+                .await
+                .map_err(store_error)?;
+            if stored.as_deref() != Some(hash.as_str()) {
+                to_index.push(file.clone());
+                hashes.push(hash);
+            }
+        }
+        let skipped_count = changed.len() - to_index.len();
+
+        if !removed.is_empty() {
+            let mut writer = self.writer.lock().unwrap();
+            for file_path in removed {
+                writer.delete_term(Term::from_field_text(
+                    self.schema_fields.file_path,
+                    file_path,
+                ));
+            }
+            writer
+                .commit()
+                .map_err(|e| IndexError::InternalError(format!("Failed to commit: {}", e)))?;
+        }
+        for file_path in removed {
+            self.chunk_store
+                .soft_delete_file_chunks(&self.repo_id, snapshot_id, file_path)
+                .await
+                .map_err(store_error)?;
+            // No delete in the store API: an empty hash never matches content
+            self.chunk_store
+                .update_file_metadata(&self.repo_id, snapshot_id, file_path, String::new())
+                .await
+                .map_err(store_error)?;
+        }
+
+        let mut result = self.index_files_batch(&to_index, fail_fast)?;
+        for (file, hash) in to_index.iter().zip(hashes) {
+            if result
+                .failed_files
+                .iter()
+                .any(|(path, _)| *path == file.file_path)
+            {
+                continue;
+            }
+            self.chunk_store
+                .soft_delete_file_chunks(&self.repo_id, snapshot_id, &file.file_path)
+                .await
+                .map_err(store_error)?;
+            self.chunk_store
+                .update_file_metadata(&self.repo_id, snapshot_id, &file.file_path, hash)
+                .await
+                .map_err(store_error)?;
+        }
+
+        result.total_files = changed.len();
+        result.skipped_count = skipped_count;
+        result.duration_seconds = start.elapsed().as_secs_f64();
+        Ok(result)
+    }
+
     /// Build a Tantivy document from file.
     ///
     /// Same as Python `_build_document()` (code_index.py:273-310).
@@ -331,6 +440,70 @@ impl TantivyLexicalIndex {
 
         Ok(hits)
     }
+
+    /// Search the index (BM25) and map each hit to a chunk.
+    ///
+    /// A hit's `line` is the first line of the file matching a query term;
+    /// its `chunk_id` is the innermost chunk of `snapshot_id` covering that
+    /// line. Both stay `None` when nothing matches or the file has no chunks.
+    pub async fn search_chunks(
+        &self,
+        snapshot_id: &str,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<SearchHit>, IndexError> {
+        let mut hits = self.search(query, limit)?;
+        let terms = query_terms(query);
+
+        for hit in &mut hits {
+            let Some(line) = first_matching_line(&hit.content, &terms) else {
+                continue;
+            };
+            hit.line = Some(line);
+
+            let chunks = self
+                .chunk_store
+                .get_chunks_by_file(&self.repo_id, snapshot_id, &hit.file_path)
+                .await
+                .map_err(store_error)?;
+            hit.chunk_id = chunks
+                .into_iter()
+                .filter(|c| c.start_line as usize <= line && line <= c.end_line as usize)
+                .min_by_key(|c| c.end_line - c.start_line)
+                .map(|c| c.chunk_id);
+        }
+
+        Ok(hits)
+    }
+
+    /// Chunk store backing chunk lookups and incremental state
+    pub fn chunk_store(&self) -> &Arc<dyn ChunkStore> {
+        &self.chunk_store
+    }
+}
+
+fn store_error(e: crate::shared::models::CodegraphError) -> IndexError {
+    IndexError::InternalError(format!("Chunk store error: {}", e))
+}
+
+/// Lowercased alphanumeric query words (operators and 1-char words dropped)
+fn query_terms(query: &str) -> Vec<String> {
+    query
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|t| t.len() > 1 && !matches!(*t, "AND" | "OR" | "NOT"))
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// 1-based number of the first line containing any term
+fn first_matching_line(content: &str, terms: &[String]) -> Option<usize> {
+    content
+        .lines()
+        .position(|line| {
+            let line = line.to_lowercase();
+            terms.iter().any(|t| line.contains(t.as_str()))
+        })
+        .map(|i| i + 1)
 }
 
 /// Search result hit.
@@ -625,4 +798,155 @@ mod tests {
         let result = index.index_files_batch(&files, false).unwrap();
         assert_eq!(result.success_count, 10);
     }
+
+    #[test]
+    fn test_identifier_aware_search() {
+        let temp_dir = TempDir::new().unwrap();
+        let index_dir = temp_dir.path().join("index");
+        let chunk_store = Arc::new(SqliteChunkStore::in_memory().unwrap());
+
+        let index = TantivyLexicalIndex::new_with_analyzer(
+            &index_dir,
+            chunk_store,
+            "test_repo".to_string(),
+            IndexingMode::Balanced,
+            &CodeAnalyzerOptions {
+                stemming: true,
+                stop_symbol_languages: vec!["python".to_string()],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let files = vec![FileToIndex {
+            repo_id: "test_repo".to_string(),
+            file_path: "src/repo.py".to_string(),
+            content: "def getUserById(self, user_id):\n    return self.users[user_id]\n"
+                .to_string(),
+        }];
+        index.index_files_batch(&files, false).unwrap();
+
+        // Natural-language words find the camelCase identifier
+        assert_eq!(index.search("user by id", 10).unwrap().len(), 1);
+        // Whole identifiers still match, case-insensitively
+        assert_eq!(index.search("getuserbyid", 10).unwrap().len(), 1);
+        // Stemming: "users" in the code matches "user"
+        assert_eq!(index.search("users", 10).unwrap().len(), 1);
+        // Python stop symbols are not indexed
+        assert!(index.search("self", 10).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_files_is_incremental() {
+        let temp_dir = TempDir::new().unwrap();
+        let chunk_store = Arc::new(SqliteChunkStore::in_memory().unwrap());
+        let index = TantivyLexicalIndex::new(
+            &temp_dir.path().join("index"),
+            chunk_store,
+            "test_repo".to_string(),
+            IndexingMode::Balanced,
+        )
+        .unwrap();
+        let file = |content: &str| FileToIndex {
+            repo_id: "test_repo".to_string(),
+            file_path: "src/auth.py".to_string(),
+            content: content.to_string(),
+        };
+
+        let result = index
+            .update_files("main", &[file("def login(): pass")], &[], false)
+            .await
+            .unwrap();
+        assert_eq!((result.success_count, result.skipped_count), (1, 0));
+
+        // Unchanged content is skipped
+        let result = index
+            .update_files("main", &[file("def login(): pass")], &[], false)
+            .await
+            .unwrap();
+        assert_eq!((result.success_count, result.skipped_count), (0, 1));
+        assert!(result.is_complete_success());
+
+        // Changed content replaces the old document
+        index
+            .update_files("main", &[file("def logout(): pass")], &[], false)
+            .await
+            .unwrap();
+        assert!(index.search("login", 10).unwrap().is_empty());
+        assert_eq!(index.search("logout", 10).unwrap().len(), 1);
+
+        // Removed files leave the index; re-adding them indexes again
+        index
+            .update_files("main", &[], &["src/auth.py".to_string()], false)
+            .await
+            .unwrap();
+        assert!(index.search("logout", 10).unwrap().is_empty());
+        let result = index
+            .update_files("main", &[file("def logout(): pass")], &[], false)
+            .await
+            .unwrap();
+        assert_eq!(result.success_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_chunks_maps_hits_to_innermost_chunk() {
+        let temp_dir = TempDir::new().unwrap();
+        let chunk_store = Arc::new(SqliteChunkStore::in_memory().unwrap());
+        let index = TantivyLexicalIndex::new(
+            &temp_dir.path().join("index"),
+            chunk_store.clone(),
+            "test_repo".to_string(),
+            IndexingMode::Balanced,
+        )
+        .unwrap();
+
+        let content =
+            "class Auth:\n    def login(self, user):\n        return check_password(user)\n";
+        let files = vec![FileToIndex {
+            repo_id: "test_repo".to_string(),
+            file_path: "src/auth.py".to_string(),
+            content: content.to_string(),
+        }];
+        index
+            .update_files("main", &files, &[], false)
+            .await
+            .unwrap();
+
+        let chunk = |id: &str, start: u32, kind: &str| {
+            let mut chunk = Chunk::new(
+                "test_repo".to_string(),
+                "main".to_string(),
+                "src/auth.py".to_string(),
+                start,
+                3,
+                kind.to_string(),
+                content.to_string(),
+            );
+            chunk.chunk_id = id.to_string();
+            chunk
+        };
+        chunk_store
+            .save_chunks(&[
+                chunk("chunk:class", 1, "class"),
+                chunk("chunk:login", 2, "function"),
+            ])
+            .await
+            .unwrap();
+
+        let hits = index
+            .search_chunks("main", "check_password", 10)
+            .await
+            .unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].line, Some(3));
+        assert_eq!(hits[0].chunk_id.as_deref(), Some("chunk:login"));
+
+        // Chunks of another snapshot are not used
+        let hits = index
+            .search_chunks("feature", "check_password", 10)
+            .await
+            .unwrap();
+        assert_eq!(hits[0].line, Some(3));
+        assert_eq!(hits[0].chunk_id, None);
+    }
 }
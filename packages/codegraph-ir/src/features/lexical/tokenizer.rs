@@ -5,16 +5,31 @@
 //! 1. **3-gram Tokenizer**: Partial matching (e.g., "getUserName" → ["get", "etU", "tUs", ...])
 //! 2. **CamelCase Tokenizer**: Code identifier splitting (e.g., "getUserName" → ["get", "User", "Name"])
 //! 3. **Lowercase Filter**: Case-insensitive search
+//! 4. **Search Analyzer**: Full-text analyzer registered on the index as
+//!    [`CODE_TOKENIZER`]: word splitting + identifier splitting (camelCase,
+//!    snake_case, digits) + optional stemming and per-language stop symbols
 //!
 //! This combination enables:
 //! - Fuzzy matching for typos
 //! - Identifier-aware search
 //! - Case-insensitive queries
+//! - Natural-language queries ("user by id" finds `getUserById`)
+
+use std::ops::Range;
 
 use tantivy::tokenizer::{
-    LowerCaser, NgramTokenizer, SimpleTokenizer, TextAnalyzer, Token, TokenStream, Tokenizer,
+    Language, LowerCaser, NgramTokenizer, RemoveLongFilter, SimpleTokenizer, Stemmer,
+    StopWordFilter, TextAnalyzer, Token, TokenStream, Tokenizer,
 };
 
+use crate::config::LexicalConfig;
+
+/// Tokenizer name of the search analyzer (used by the text fields of the schema)
+pub const CODE_TOKENIZER: &str = "code";
+
+/// Tokens longer than this (minified code, base64 blobs) are not indexed
+const MAX_TOKEN_LEN: usize = 64;
+
 /// CamelCase/snake_case tokenizer for code identifiers.
 ///
 /// Splits:
@@ -169,6 +184,285 @@ pub fn build_ngram_analyzer() -> TextAnalyzer {
         .build()
 }
 
+/// Search analyzer options
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeAnalyzerOptions {
+    /// Split identifiers into words (camelCase, snake_case, digits)
+    pub split_identifiers: bool,
+    /// Also index the whole identifier next to its parts (exact matches rank higher)
+    pub keep_original: bool,
+    /// English stemming (`users` → `user`)
+    pub stemming: bool,
+    /// Languages whose stop symbols are dropped ("python", "java", ...)
+    pub stop_symbol_languages: Vec<String>,
+}
+
+impl CodeAnalyzerOptions {
+    /// Options from the pipeline's lexical configuration
+    pub fn from_config(config: &LexicalConfig) -> Self {
+        Self {
+            split_identifiers: config.split_identifiers,
+            keep_original: true,
+            stemming: config.enable_stemming,
+            stop_symbol_languages: config.stop_symbol_languages.clone(),
+        }
+    }
+}
+
+impl Default for CodeAnalyzerOptions {
+    fn default() -> Self {
+        Self {
+            split_identifiers: true,
+            keep_original: true,
+            stemming: false,
+            stop_symbol_languages: Vec::new(),
+        }
+    }
+}
+
+/// Word + identifier tokenizer for source text.
+///
+/// Words are runs of alphanumerics and underscores; each word is then split
+/// into identifier parts:
+/// - `getUserById` → `["getUserById", "get", "User", "By", "Id"]` (with `keep_original`)
+/// - `MAX_RETRY_COUNT` → `["MAX_RETRY_COUNT", "MAX", "RETRY", "COUNT"]`
+/// - `base64Decode` → `["base64Decode", "base", "64", "Decode"]`
+///
+/// The original identifier shares the position of its first part, so phrase
+/// queries over the parts still match.
+#[derive(Clone)]
+pub struct IdentifierTokenizer {
+    split_identifiers: bool,
+    keep_original: bool,
+}
+
+impl IdentifierTokenizer {
+    pub fn new(split_identifiers: bool, keep_original: bool) -> Self {
+        Self {
+            split_identifiers,
+            keep_original,
+        }
+    }
+
+    fn tokenize(&self, text: &str) -> Vec<Token> {
+        let mut tokens = Vec::new();
+        let mut position = 0;
+        for (start, word) in words(text) {
+            let parts = if self.split_identifiers {
+                identifier_parts(word)
+            } else {
+                vec![0..word.len()]
+            };
+            if self.keep_original && parts.len() > 1 {
+                tokens.push(Token {
+                    offset_from: start,
+                    offset_to: start + word.len(),
+                    position,
+                    text: word.to_string(),
+                    position_length: parts.len(),
+                });
+            }
+            for part in parts {
+                tokens.push(Token {
+                    offset_from: start + part.start,
+                    offset_to: start + part.end,
+                    position,
+                    text: word[part].to_string(),
+                    position_length: 1,
+                });
+                position += 1;
+            }
+        }
+        tokens
+    }
+}
+
+impl Default for IdentifierTokenizer {
+    fn default() -> Self {
+        Self::new(true, true)
+    }
+}
+
+impl Tokenizer for IdentifierTokenizer {
+    type TokenStream<'a> = IdentifierTokenStream;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        IdentifierTokenStream {
+            tokens: self.tokenize(text),
+            current_index: 0,
+        }
+    }
+}
+
+pub struct IdentifierTokenStream {
+    tokens: Vec<Token>,
+    current_index: usize,
+}
+
+impl TokenStream for IdentifierTokenStream {
+    fn advance(&mut self) -> bool {
+        if self.current_index < self.tokens.len() {
+            self.current_index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.tokens[self.current_index - 1]
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.tokens[self.current_index - 1]
+    }
+}
+
+/// Split an identifier into its words.
+///
+/// Boundaries: underscores, lower → upper (`getUser`), the last capital of
+/// an acronym (`HTTPSConnection` → `HTTPS`, `Connection`) and letter ↔ digit
+/// transitions (`sha256sum` → `sha`, `256`, `sum`).
+pub fn split_identifier(identifier: &str) -> Vec<&str> {
+    identifier_parts(identifier)
+        .into_iter()
+        .map(|part| &identifier[part])
+        .collect()
+}
+
+/// Byte ranges of the identifier parts of `word`
+fn identifier_parts(word: &str) -> Vec<Range<usize>> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Class {
+        Upper,
+        Lower,
+        Digit,
+    }
+    let class = |c: char| {
+        if c.is_numeric() {
+            Class::Digit
+        } else if c.is_uppercase() {
+            Class::Upper
+        } else {
+            Class::Lower
+        }
+    };
+
+    let chars: Vec<(usize, char)> = word.char_indices().collect();
+    let mut parts = Vec::new();
+    let mut start: Option<usize> = None;
+    for (i, &(offset, ch)) in chars.iter().enumerate() {
+        if ch == '_' {
+            if let Some(s) = start.take() {
+                parts.push(s..offset);
+            }
+            continue;
+        }
+        let Some(s) = start else {
+            start = Some(offset);
+            continue;
+        };
+
+        let prev = class(chars[i - 1].1);
+        let current = class(ch);
+        let next_is_lower = chars
+            .get(i + 1)
+            .is_some_and(|&(_, c)| c != '_' && class(c) == Class::Lower);
+        let boundary = (prev == Class::Digit) != (current == Class::Digit)
+            || (prev == Class::Lower && current == Class::Upper)
+            || (prev == Class::Upper && current == Class::Upper && next_is_lower);
+        if boundary {
+            parts.push(s..offset);
+            start = Some(offset);
+        }
+    }
+    if let Some(s) = start {
+        parts.push(s..word.len());
+    }
+    parts
+}
+
+/// Words of `text` with their byte offsets (runs of alphanumerics and `_`)
+fn words(text: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (offset, ch) in text.char_indices() {
+        let is_word = ch.is_alphanumeric() || ch == '_';
+        match (start, is_word) {
+            (None, true) => start = Some(offset),
+            (Some(s), false) => {
+                words.push((s, &text[s..offset]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        words.push((s, &text[s..]));
+    }
+    words
+}
+
+/// Stop symbols of a language (lowercase), `None` for unknown languages.
+///
+/// Only keywords so frequent that they carry no search signal (`def`,
+/// `self`, `return`, ...); declaration keywords people search for (`class`,
+/// `async`, `interface`) are kept.
+pub fn stop_symbols(language: &str) -> Option<impl Iterator<Item = &'static str>> {
+    let symbols = match language {
+        "python" => "def self cls return pass none true false if elif else in is not and or",
+        "java" => {
+            "public private protected static final void return this new null true false if else"
+        }
+        "kotlin" => "fun val var this return null true false override private public if else",
+        "typescript" | "javascript" => {
+            "const let var function return this null undefined true false export if else"
+        }
+        "rust" => "fn let mut pub self return use crate super true false if else",
+        "go" => "func var return nil true false if else package",
+        "c" | "cpp" => "int void char const static return this null nullptr true false if else",
+        "php" => "function return this public private protected null true false echo if else",
+        "ruby" => "def end self return nil true false do if else",
+        _ => return None,
+    };
+    Some(symbols.split_whitespace())
+}
+
+/// Build the search analyzer registered as [`CODE_TOKENIZER`].
+///
+/// Pipeline:
+/// 1. IdentifierTokenizer - Words + identifier parts
+/// 2. RemoveLongFilter - Drop blobs longer than 64 bytes
+/// 3. LowerCaser - Normalize case
+/// 4. StopWordFilter - Stop symbols of `stop_symbol_languages` (optional)
+/// 5. Stemmer - English stemming (optional)
+///
+/// The same analyzer runs on documents and queries.
+pub fn build_search_analyzer(options: &CodeAnalyzerOptions) -> TextAnalyzer {
+    let mut builder = TextAnalyzer::builder(IdentifierTokenizer::new(
+        options.split_identifiers,
+        options.keep_original,
+    ))
+    .filter(RemoveLongFilter::limit(MAX_TOKEN_LEN))
+    .filter(LowerCaser)
+    .dynamic();
+
+    let stop_words: Vec<String> = options
+        .stop_symbol_languages
+        .iter()
+        .filter_map(|language| stop_symbols(language))
+        .flatten()
+        .map(|symbol| symbol.to_string())
+        .collect();
+    if !stop_words.is_empty() {
+        builder = builder.filter_dynamic(StopWordFilter::remove(stop_words));
+    }
+    if options.stemming {
+        builder = builder.filter_dynamic(Stemmer::new(Language::English));
+    }
+    builder.build()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -266,4 +560,97 @@ mod tests {
         // "hello" → ["hel", "ell", "llo"]
         assert_eq!(tokens, vec!["hel", "ell", "llo"]);
     }
+
+    fn analyze(options: &CodeAnalyzerOptions, text: &str) -> Vec<String> {
+        let mut analyzer = build_search_analyzer(options);
+        let mut stream = analyzer.token_stream(text);
+
+        let mut tokens = Vec::new();
+        while stream.advance() {
+            tokens.push(stream.token().text.clone());
+        }
+        tokens
+    }
+
+    #[test]
+    fn test_split_identifier() {
+        assert_eq!(
+            split_identifier("getUserById"),
+            vec!["get", "User", "By", "Id"]
+        );
+        assert_eq!(
+            split_identifier("MAX_RETRY_COUNT"),
+            vec!["MAX", "RETRY", "COUNT"]
+        );
+        assert_eq!(
+            split_identifier("HTTPSConnection"),
+            vec!["HTTPS", "Connection"]
+        );
+        assert_eq!(
+            split_identifier("base64Decode"),
+            vec!["base", "64", "Decode"]
+        );
+        assert_eq!(split_identifier("__init__"), vec!["init"]);
+        assert!(split_identifier("___").is_empty());
+    }
+
+    #[test]
+    fn test_search_analyzer() {
+        let options = CodeAnalyzerOptions::default();
+        assert_eq!(
+            analyze(&options, "user = repo.getUserById(42)"),
+            vec![
+                "user",
+                "repo",
+                "getuserbyid",
+                "get",
+                "user",
+                "by",
+                "id",
+                "42"
+            ]
+        );
+
+        let mut tokenizer = IdentifierTokenizer::default();
+        let mut stream = tokenizer.token_stream("getUserById()");
+        let mut positions = Vec::new();
+        while stream.advance() {
+            let token = stream.token();
+            positions.push((token.text.clone(), token.position, token.offset_from));
+        }
+        // Original shares its first part's position
+        assert_eq!(positions[0], ("getUserById".to_string(), 0, 0));
+        assert_eq!(positions[1], ("get".to_string(), 0, 0));
+        assert_eq!(positions[4], ("Id".to_string(), 3, 9));
+
+        let unsplit = CodeAnalyzerOptions {
+            split_identifiers: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            analyze(&unsplit, "getUserById(x)"),
+            vec!["getuserbyid", "x"]
+        );
+    }
+
+    #[test]
+    fn test_stemming_and_stop_symbols() {
+        let options = CodeAnalyzerOptions {
+            stemming: true,
+            stop_symbol_languages: vec!["python".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            analyze(&options, "def load(users, self):\n    return None"),
+            vec!["load", "user"]
+        );
+
+        // Stop symbols are per language: `def` is a word in Java code
+        let java = CodeAnalyzerOptions {
+            stop_symbol_languages: vec!["java".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(analyze(&java, "public void def()"), vec!["def"]);
+        assert!(stop_symbols("cobol").is_none());
+    }
 }
@@ -48,6 +48,7 @@ pub mod effect_analysis;
 pub mod git_history;
 
 // RFC-073: File Watcher - SOTA Rust-native file system monitoring
+#[cfg(feature = "file-watcher")]
 pub mod file_watcher;
 
 // RFC-074: SMT Engine (SOTA v2 Enhanced) - 90%+ accuracy, <1ms, Zero dependencies
@@ -74,6 +75,9 @@ pub mod repomap;
 // - Search: < 5ms p95 (vs Python 15ms)
 pub mod lexical; // ✅ Enabled - Lexical Search with Tantivy
 
+// Vector Search: chunk embeddings (pluggable provider) + HNSW index per snapshot
+pub mod vector;
+
 // RFC-CONFIG-SYSTEM: Tiered Cache Configuration (L0 + L1 + L2)
 // - L0: Session Cache (Bloom filter + LRU)
 // - L1: Adaptive Cache (moka with TTL)
@@ -100,3 +104,31 @@ pub mod expression_builder; // ✅ NEW - L1 Expression IR extraction
 // - Explicit data flow edges (READS, WRITES, CONTROLS)
 // - SSA-friendly Node IR generation
 pub mod lowering; // ✅ NEW - L1→L2 Progressive Lowering
+
+// CI Gating: severity policy engine (pass/warn/fail verdicts for CI and webhooks)
+pub mod gating;
+
+// SARIF: 2.1.0 export for security, memory safety and taint findings
+pub mod sarif;
+
+// PR Review: GitHub/GitLab review comments from snapshot diff analysis (deduplicated)
+pub mod pr_review;
+
+// Architecture Rules: layer/dependency constraints (YAML/TOML) checked against imports and calls
+pub mod architecture;
+
+// Semantic Tags: docstring/comment intent tags (deprecations, tickets, keywords)
+pub mod semantic_tags;
+
+// Secret Detection: hardcoded credentials (CWE-798) and their flow to log sinks (CWE-532)
+pub mod secret_detection;
+
+// Function Summaries: per-function taint/effect/escape summaries cached by stable id + content hash
+pub mod function_summaries;
+
+// Datalog: IR relations + stratified rule evaluation for custom analyses
+pub mod datalog;
+
+// Bazel: BUILD target graph overlay (strict deps, change invalidation)
+#[cfg(feature = "bazel")]
+pub mod bazel;
@@ -0,0 +1,183 @@
+//! Grammar Compatibility Checks
+//!
+//! Tree-sitter grammar releases rename and restructure node kinds without
+//! changing the Rust API, so a plugin built against one grammar silently
+//! extracts nothing from another. Each plugin declares the grammar it was
+//! written against and the node kinds its extractor relies on; this module
+//! checks those against the loaded grammar and reports what was loaded.
+//!
+//! ```ignore
+//! use codegraph_ir::features::parsing::grammar::check_registered_plugins;
+//!
+//! // Fails with the plugin, grammar and missing kinds on mismatch
+//! let grammars = check_registered_plugins()?;
+//! ```
+
+use super::ports::LanguagePlugin;
+use super::sdk::{self, PluginDescriptor};
+use crate::shared::models::CodegraphError;
+use serde::{Deserialize, Serialize};
+
+/// Grammar loaded for a plugin
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GrammarInfo {
+    /// Language name (e.g. "python")
+    pub language: String,
+    /// Plugin name from its descriptor
+    pub plugin: String,
+    /// Plugin version from its descriptor
+    pub plugin_version: String,
+    /// Grammar crate the plugin targets (e.g. "tree-sitter-python 0.21")
+    pub grammar: Option<String>,
+    /// Tree-sitter ABI version of the loaded grammar
+    pub abi_version: usize,
+    /// Number of node kinds in the loaded grammar
+    pub node_kind_count: usize,
+}
+
+/// Check a plugin against the grammar it loads
+///
+/// Fails if the grammar's ABI is outside what the linked tree-sitter
+/// runtime supports, or if any of the plugin's expected node kinds are
+/// missing from the grammar.
+pub fn check_plugin(
+    plugin: &dyn LanguagePlugin,
+    name: &str,
+    version: &str,
+) -> Result<GrammarInfo, CodegraphError> {
+    let language = plugin.tree_sitter_language();
+    let info = GrammarInfo {
+        language: plugin.language_id().name().to_string(),
+        plugin: name.to_string(),
+        plugin_version: version.to_string(),
+        grammar: plugin.grammar_version().map(str::to_string),
+        abi_version: language.version(),
+        node_kind_count: language.node_kind_count(),
+    };
+
+    let supported = tree_sitter::MIN_COMPATIBLE_LANGUAGE_VERSION..=tree_sitter::LANGUAGE_VERSION;
+    if !supported.contains(&info.abi_version) {
+        return Err(CodegraphError::config(format!(
+            "Grammar mismatch for plugin '{}' ({}): ABI version {} is not supported by tree-sitter (expected {}-{})",
+            info.plugin,
+            info.grammar_label(),
+            info.abi_version,
+            supported.start(),
+            supported.end()
+        )));
+    }
+
+    let missing: Vec<&str> = plugin
+        .expected_node_kinds()
+        .iter()
+        .copied()
+        .filter(|kind| language.id_for_node_kind(kind, true) == 0)
+        .collect();
+    if !missing.is_empty() {
+        return Err(CodegraphError::config(format!(
+            "Grammar mismatch for plugin '{}' ({}, ABI {}): missing node kinds {}; the plugin was written for a different grammar version",
+            info.plugin,
+            info.grammar_label(),
+            info.abi_version,
+            missing
+                .iter()
+                .map(|kind| format!("`{}`", kind))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )));
+    }
+
+    Ok(info)
+}
+
+/// Check the plugin a descriptor constructs
+pub fn check_descriptor(descriptor: &PluginDescriptor) -> Result<GrammarInfo, CodegraphError> {
+    let plugin = (descriptor.factory)();
+    check_plugin(plugin.as_ref(), descriptor.name, descriptor.version)
+}
+
+/// Check every registered plugin, failing on the first mismatch
+///
+/// Returns the loaded grammars sorted by language, for recording in
+/// pipeline results.
+pub fn check_registered_plugins() -> Result<Vec<GrammarInfo>, CodegraphError> {
+    let mut grammars = sdk::registered_plugins()
+        .iter()
+        .map(check_descriptor)
+        .collect::<Result<Vec<_>, _>>()?;
+    grammars.sort_by(|a, b| a.language.cmp(&b.language));
+    Ok(grammars)
+}
+
+impl GrammarInfo {
+    fn grammar_label(&self) -> &str {
+        self.grammar.as_deref().unwrap_or("unknown grammar")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::parsing::domain::SyntaxKind;
+    use crate::features::parsing::ports::{ExtractionContext, ExtractionResult, LanguageId};
+    use crate::features::parsing::PythonPlugin;
+    use crate::shared::models::{NodeKind, Result};
+    use tree_sitter::{Language as TSLanguage, Tree};
+
+    /// Python plugin expecting a node kind the grammar does not have
+    struct DriftedPlugin(PythonPlugin);
+
+    impl LanguagePlugin for DriftedPlugin {
+        fn tree_sitter_language(&self) -> TSLanguage {
+            self.0.tree_sitter_language()
+        }
+        fn language_id(&self) -> LanguageId {
+            self.0.language_id()
+        }
+        fn grammar_version(&self) -> Option<&'static str> {
+            Some("tree-sitter-python 0.99")
+        }
+        fn expected_node_kinds(&self) -> &[&str] {
+            &["function_definition", "function_def"]
+        }
+        fn map_node_kind(&self, ts_kind: &str) -> Option<NodeKind> {
+            self.0.map_node_kind(ts_kind)
+        }
+        fn map_syntax_kind(&self, ts_kind: &str) -> SyntaxKind {
+            self.0.map_syntax_kind(ts_kind)
+        }
+        fn extract(&self, ctx: &mut ExtractionContext, tree: &Tree) -> Result<ExtractionResult> {
+            self.0.extract(ctx, tree)
+        }
+    }
+
+    #[test]
+    fn test_builtin_plugins_match_their_grammars() {
+        let grammars = check_registered_plugins().unwrap();
+        let python = grammars.iter().find(|g| g.language == "python").unwrap();
+        assert_eq!(python.plugin, "python");
+        assert_eq!(python.grammar.as_deref(), Some("tree-sitter-python 0.21"));
+        assert!(python.abi_version >= tree_sitter::MIN_COMPATIBLE_LANGUAGE_VERSION);
+        assert!(python.node_kind_count > 0);
+
+        for descriptor in sdk::builtin_descriptors() {
+            let plugin = (descriptor.factory)();
+            assert!(
+                !plugin.expected_node_kinds().is_empty(),
+                "{} declares no node kinds",
+                descriptor.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_missing_node_kind_is_reported() {
+        let plugin = DriftedPlugin(PythonPlugin::new());
+        let err = check_plugin(&plugin, "drifted", "0.1.0").unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("plugin 'drifted'"), "{}", message);
+        assert!(message.contains("tree-sitter-python 0.99"), "{}", message);
+        assert!(message.contains("`function_def`"), "{}", message);
+        assert!(!message.contains("`function_definition`"), "{}", message);
+    }
+}
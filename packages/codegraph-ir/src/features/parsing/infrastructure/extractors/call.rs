@@ -21,6 +21,42 @@ pub struct FunctionCall {
     pub callee_name: String,
     pub span: Span,
     pub is_method_call: bool,
+    /// Awaited, scheduled as a task/callback, or passed to a gather-style combinator
+    pub is_async: bool,
+    /// Positional argument expressions (up to the first keyword or unpacking)
+    pub arguments: Vec<String>,
+}
+
+/// How an asyncio entry point runs the code passed to it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpawnKind {
+    /// Coroutine arguments: `asyncio.gather(a(), b())`
+    Coroutine,
+    /// Callable at this positional index: `loop.call_soon(cb)`
+    Callback(usize),
+}
+
+/// Classify `callee` (dotted call target) as an asyncio spawner
+pub fn async_spawn_kind(callee: &str) -> Option<SpawnKind> {
+    let (receiver, name) = match callee.rsplit_once('.') {
+        Some((receiver, name)) => (Some(receiver), name),
+        None => (None, callee),
+    };
+    match name {
+        "create_task"
+        | "ensure_future"
+        | "gather"
+        | "run_until_complete"
+        | "run_coroutine_threadsafe"
+        | "wait_for"
+        | "shield"
+        | "as_completed" => Some(SpawnKind::Coroutine),
+        // Too generic to match without the module
+        "run" | "wait" if receiver == Some("asyncio") => Some(SpawnKind::Coroutine),
+        "to_thread" | "call_soon" | "call_soon_threadsafe" => Some(SpawnKind::Callback(0)),
+        "run_in_executor" | "call_later" => Some(SpawnKind::Callback(1)),
+        _ => None,
+    }
 }
 
 /// Extract function calls from block
@@ -29,6 +65,8 @@ pub struct FunctionCall {
 /// - Direct calls: func()
 /// - Method calls: obj.method()
 /// - Chained calls: obj.method1().method2()
+/// - Async calls: `await f()`, coroutines passed to `asyncio.gather` /
+///   `create_task`, callbacks passed to `call_soon` / `run_in_executor`
 ///
 /// # Arguments
 /// * `block_node` - Block AST node
@@ -48,7 +86,19 @@ pub fn extract_calls_in_block(block_node: &Node, source: &str) -> Vec<FunctionCa
 fn traverse_for_calls(node: &Node, source: &str, calls: &mut Vec<FunctionCall>) {
     match node.kind() {
         "call" => {
-            extract_call(node, source, calls);
+            extract_call(node, source, false, calls);
+        }
+
+        "await" => {
+            for i in 0..node.child_count() {
+                if let Some(child) = node.child(i) {
+                    if child.kind() == "call" {
+                        extract_call(&child, source, true, calls);
+                    } else {
+                        traverse_for_calls(&child, source, calls);
+                    }
+                }
+            }
         }
 
         _ => {
@@ -63,10 +113,11 @@ fn traverse_for_calls(node: &Node, source: &str, calls: &mut Vec<FunctionCall>)
 }
 
 /// Extract call expression
-fn extract_call(node: &Node, source: &str, calls: &mut Vec<FunctionCall>) {
+fn extract_call(node: &Node, source: &str, is_async: bool, calls: &mut Vec<FunctionCall>) {
     // Find function/attribute being called
     if let Some(func_node) = node.child(0) {
         let span = node_to_span(node);
+        let arguments = positional_arguments(node, source);
 
         match func_node.kind() {
             // Direct call: func()
@@ -77,6 +128,8 @@ fn extract_call(node: &Node, source: &str, calls: &mut Vec<FunctionCall>) {
                     callee_name: name,
                     span,
                     is_method_call: false,
+                    is_async,
+                    arguments,
                 });
             }
 
@@ -88,14 +141,71 @@ fn extract_call(node: &Node, source: &str, calls: &mut Vec<FunctionCall>) {
                     callee_name: name,
                     span,
                     is_method_call: true,
+                    is_async,
+                    arguments,
                 });
             }
 
-            _ => {}
+            _ => return,
+        }
+
+        if let (Some(kind), Some(args)) = (
+            async_spawn_kind(&get_node_text(&func_node, source)),
+            node.child_by_field_name("arguments"),
+        ) {
+            match kind {
+                SpawnKind::Coroutine => extract_coroutine_args(&args, source, calls),
+                SpawnKind::Callback(index) => extract_callback_arg(&args, index, source, calls),
+            }
+        }
+    }
+}
+
+/// Calls passed (directly, in lists or comprehensions) to a coroutine spawner
+fn extract_coroutine_args(node: &Node, source: &str, calls: &mut Vec<FunctionCall>) {
+    match node.kind() {
+        "call" => extract_call(node, source, true, calls),
+        // `timeout=...` and friends are not coroutines
+        "keyword_argument" | "lambda" => {}
+        "list_comprehension" | "generator_expression" | "set_comprehension" => {
+            if let Some(body) = node.child_by_field_name("body") {
+                extract_coroutine_args(&body, source, calls);
+            }
+        }
+        _ => {
+            for i in 0..node.child_count() {
+                if let Some(child) = node.child(i) {
+                    extract_coroutine_args(&child, source, calls);
+                }
+            }
         }
     }
 }
 
+/// The callable at positional `index` of a callback spawner
+fn extract_callback_arg(args: &Node, index: usize, source: &str, calls: &mut Vec<FunctionCall>) {
+    let mut cursor = args.walk();
+    let Some(arg) = args
+        .named_children(&mut cursor)
+        .filter(|c| c.kind() != "comment")
+        .nth(index)
+    else {
+        return;
+    };
+    let (callee_name, is_method_call) = match arg.kind() {
+        "identifier" => (get_node_text(&arg, source), false),
+        "attribute" => (extract_attribute_name(&arg, source), true),
+        _ => return,
+    };
+    calls.push(FunctionCall {
+        callee_name,
+        span: node_to_span(&arg),
+        is_method_call,
+        is_async: true,
+        arguments: Vec::new(),
+    });
+}
+
 /// Extract attribute name (for method calls)
 fn extract_attribute_name(node: &Node, source: &str) -> String {
     // attribute node: object.method
@@ -112,6 +222,29 @@ fn extract_attribute_name(node: &Node, source: &str) -> String {
     get_node_text(node, source)
 }
 
+/// Positional argument expressions of a call
+fn positional_arguments(call: &Node, source: &str) -> Vec<String> {
+    let Some(args) = call
+        .child_by_field_name("arguments")
+        .filter(|args| args.kind() == "argument_list")
+    else {
+        return Vec::new();
+    };
+    let mut cursor = args.walk();
+    let arguments = args
+        .named_children(&mut cursor)
+        .filter(|arg| arg.kind() != "comment")
+        .take_while(|arg| {
+            !matches!(
+                arg.kind(),
+                "keyword_argument" | "list_splat" | "dictionary_splat"
+            )
+        })
+        .map(|arg| get_node_text(&arg, source))
+        .collect();
+    arguments
+}
+
 /// Get node text
 fn get_node_text(node: &Node, source: &str) -> String {
     let start = node.start_byte();
@@ -197,6 +330,19 @@ mod tests {
         assert!(calls[0].is_method_call);
     }
 
+    #[test]
+    fn test_call_arguments() {
+        let code = "def f(cb):\n    apply(cb, items[0], *rest, key=1)\n    run(x for x in xs)";
+        let tree = parse_python(code);
+        let body = get_function_body(&tree).unwrap();
+        let calls = extract_calls_in_block(&body, code);
+
+        assert_eq!(calls[0].callee_name, "apply");
+        assert_eq!(calls[0].arguments, vec!["cb", "items[0]"]);
+        assert_eq!(calls[1].callee_name, "run");
+        assert!(calls[1].arguments.is_empty());
+    }
+
     #[test]
     fn test_chained_calls() {
         let code = "def f():\n    obj.method1().method2()";
@@ -218,4 +364,27 @@ mod tests {
 
         assert_eq!(calls.len(), 3); // print, range, len
     }
+
+    #[test]
+    fn test_async_calls() {
+        let code = "async def f():\n    await fetch(1)\n    await asyncio.gather(a(), *[b(u) for u in urls()], return_exceptions=flag())\n    asyncio.create_task(c())\n    loop.run_in_executor(None, blocking, 1)\n    log()";
+        let tree = parse_python(code);
+        let body = get_function_body(&tree).unwrap();
+        let calls = extract_calls_in_block(&body, code);
+
+        let is_async = |name: &str| {
+            calls
+                .iter()
+                .find(|c| c.callee_name == name)
+                .map(|c| c.is_async)
+        };
+        for name in ["fetch", "gather", "a", "b", "c", "blocking"] {
+            assert_eq!(is_async(name), Some(true), "{}", name);
+        }
+        for name in ["create_task", "run_in_executor", "log"] {
+            assert_eq!(is_async(name), Some(false), "{}", name);
+        }
+        assert_eq!(is_async("urls"), None);
+        assert_eq!(is_async("flag"), None);
+    }
 }
@@ -0,0 +1,265 @@
+/*
+ * Decorator and Callback Module
+ *
+ * Extracts decorator applications and function-valued call arguments,
+ * the call relationships that do not appear as `f()` in the source:
+ * - `@decorator` / `@factory(args)` above a definition
+ * - `map(f, xs)`, `sorted(xs, key=f)`, `app.add_url_rule("/", view_func=index)`
+ *
+ * Callback candidates are names; callers resolve them against defined
+ * functions so plain variables passed as arguments are dropped.
+ */
+
+use super::call::async_spawn_kind;
+use crate::shared::models::Span;
+use tree_sitter::Node;
+
+/// Decorator applied to a definition
+#[derive(Debug, Clone)]
+pub struct Decorator {
+    /// Dotted name without arguments (`app.route` for `@app.route("/")`)
+    pub name: String,
+    pub span: Span,
+    /// `@factory(args)`: the factory's result wraps the definition
+    pub is_factory: bool,
+}
+
+/// Name passed as an argument to a call
+#[derive(Debug, Clone)]
+pub struct CallbackArg {
+    /// Argument text (`f`, `self.handle`)
+    pub callback_name: String,
+    /// Callee receiving the argument (`map`, `app.add_url_rule`)
+    pub receiver: String,
+    pub span: Span,
+}
+
+/// Decorators of a function/class definition, top to bottom
+pub fn extract_decorators(def_node: &Node, source: &str) -> Vec<Decorator> {
+    let Some(parent) = def_node
+        .parent()
+        .filter(|p| p.kind() == "decorated_definition")
+    else {
+        return Vec::new();
+    };
+    let mut decorators = Vec::new();
+    let mut cursor = parent.walk();
+    for child in parent.named_children(&mut cursor) {
+        if child.kind() != "decorator" {
+            continue;
+        }
+        let Some(expr) = child.named_child(0) else {
+            continue;
+        };
+        let (target, is_factory) = match expr.kind() {
+            "call" => match expr.child_by_field_name("function") {
+                Some(function) => (function, true),
+                None => continue,
+            },
+            _ => (expr, false),
+        };
+        if matches!(target.kind(), "identifier" | "attribute") {
+            decorators.push(Decorator {
+                name: get_node_text(&target, source),
+                span: node_to_span(&child),
+                is_factory,
+            });
+        }
+    }
+    decorators
+}
+
+/// Whether a decorator definition wraps its argument with `functools.wraps`
+pub fn uses_functools_wraps(def_node: &Node, source: &str) -> bool {
+    let mut stack = vec![*def_node];
+    while let Some(node) = stack.pop() {
+        if node.kind() == "function_definition" && node.id() != def_node.id() {
+            let wraps = extract_decorators(&node, source)
+                .iter()
+                .any(|d| d.is_factory && d.name.rsplit('.').next() == Some("wraps"));
+            if wraps {
+                return true;
+            }
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+    false
+}
+
+/// Higher-order builtins and registration APIs whose arguments get called
+pub fn is_higher_order(receiver: &str) -> bool {
+    let name = receiver.rsplit('.').next().unwrap_or(receiver);
+    matches!(
+        name,
+        "map"
+            | "filter"
+            | "sorted"
+            | "min"
+            | "max"
+            | "reduce"
+            | "partial"
+            | "Thread"
+            | "Process"
+            | "Timer"
+            | "submit"
+            | "apply_async"
+            | "register"
+            | "add_url_rule"
+            | "add_api_route"
+            | "add_route"
+            | "add_websocket_route"
+            | "add_listener"
+            | "add_done_callback"
+            | "connect"
+            | "subscribe"
+            | "signal"
+    )
+}
+
+/// Name-valued arguments of every call under `node`
+///
+/// Asyncio spawners are skipped; `extract_calls_in_block` already reports
+/// their callbacks as async calls.
+pub fn extract_callback_args(node: &Node, source: &str) -> Vec<CallbackArg> {
+    let mut callbacks = Vec::new();
+    let mut stack = vec![*node];
+    while let Some(node) = stack.pop() {
+        if node.kind() == "call" {
+            collect_call_args(&node, source, &mut callbacks);
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+    callbacks.sort_by_key(|c| (c.span.start_line, c.span.start_col));
+    callbacks
+}
+
+fn collect_call_args(call: &Node, source: &str, callbacks: &mut Vec<CallbackArg>) {
+    let (Some(function), Some(args)) = (
+        call.child_by_field_name("function"),
+        call.child_by_field_name("arguments"),
+    ) else {
+        return;
+    };
+    let receiver = get_node_text(&function, source);
+    if async_spawn_kind(&receiver).is_some() {
+        return;
+    }
+    let mut cursor = args.walk();
+    for arg in args.named_children(&mut cursor) {
+        let value = match arg.kind() {
+            "keyword_argument" => match arg.child_by_field_name("value") {
+                Some(value) => value,
+                None => continue,
+            },
+            _ => arg,
+        };
+        if matches!(value.kind(), "identifier" | "attribute") {
+            callbacks.push(CallbackArg {
+                callback_name: get_node_text(&value, source),
+                receiver: receiver.clone(),
+                span: node_to_span(&value),
+            });
+        }
+    }
+}
+
+/// Get node text
+fn get_node_text(node: &Node, source: &str) -> String {
+    source[node.start_byte()..node.end_byte()].to_string()
+}
+
+/// Convert node to Span
+fn node_to_span(node: &Node) -> Span {
+    let start_pos = node.start_position();
+    let end_pos = node.end_position();
+
+    Span::new(
+        start_pos.row as u32 + 1,
+        start_pos.column as u32,
+        end_pos.row as u32 + 1,
+        end_pos.column as u32,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tree_sitter::Parser;
+
+    fn parse_python(code: &str) -> tree_sitter::Tree {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&tree_sitter_python::language())
+            .unwrap();
+        parser.parse(code, None).unwrap()
+    }
+
+    fn find_def<'a>(node: Node<'a>, name: &str, source: &str) -> Option<Node<'a>> {
+        if node.kind() == "function_definition"
+            && node
+                .child_by_field_name("name")
+                .map(|n| get_node_text(&n, source))
+                .as_deref()
+                == Some(name)
+        {
+            return Some(node);
+        }
+        let mut cursor = node.walk();
+        let children: Vec<_> = node.children(&mut cursor).collect();
+        children.into_iter().find_map(|c| find_def(c, name, source))
+    }
+
+    #[test]
+    fn test_decorators_and_wraps() {
+        let code = "\
+def logged(func):
+    @functools.wraps(func)
+    def inner(*args):
+        return func(*args)
+    return inner
+
+@app.route(\"/\")
+@logged
+def index():
+    pass
+";
+        let tree = parse_python(code);
+        let index = find_def(tree.root_node(), "index", code).unwrap();
+        let decorators = extract_decorators(&index, code);
+
+        let names: Vec<_> = decorators.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["app.route", "logged"]);
+        assert!(decorators[0].is_factory);
+        assert!(!decorators[1].is_factory);
+
+        let logged = find_def(tree.root_node(), "logged", code).unwrap();
+        assert!(uses_functools_wraps(&logged, code));
+        assert!(!uses_functools_wraps(&index, code));
+    }
+
+    #[test]
+    fn test_callback_args() {
+        let code = "def f(xs):\n    list(map(parse, xs))\n    sorted(xs, key=self.rank)\n    asyncio.create_task(run)";
+        let tree = parse_python(code);
+        let f = find_def(tree.root_node(), "f", code).unwrap();
+        let callbacks = extract_callback_args(&f, code);
+
+        let pairs: Vec<_> = callbacks
+            .iter()
+            .map(|c| (c.receiver.as_str(), c.callback_name.as_str()))
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![
+                ("map", "parse"),
+                ("map", "xs"),
+                ("sorted", "xs"),
+                ("sorted", "self.rank")
+            ]
+        );
+        assert!(is_higher_order("functools.partial"));
+        assert!(!is_higher_order("print"));
+    }
+}
@@ -86,6 +86,24 @@ impl FqnResolver {
             format!("external.{}", name)
         }
     }
+
+    /// Confidence (0.0-1.0) that `resolve(name)` is the actual call target
+    ///
+    /// Python resolution is heuristic, so not every FQN is equally certain:
+    /// - Built-in simple name (`len`): 1.0
+    /// - Alias-expanded module call (`np.array`): 0.9
+    /// - `self.method` / `cls.method`: 0.7 (class known, override possible)
+    /// - Dotted call on an unknown receiver (`obj.run`): 0.5 (dynamic dispatch)
+    /// - Unknown simple name (`helper`): 0.6 (local or imported, unresolved)
+    pub fn confidence(&self, name: &str) -> f32 {
+        match name.split_once('.') {
+            Some((first, _)) if self.import_aliases.contains_key(first) => 0.9,
+            Some(("self" | "cls", _)) => 0.7,
+            Some(_) => 0.5,
+            None if is_python_builtin(name) => 1.0,
+            None => 0.6,
+        }
+    }
 }
 
 impl Default for FqnResolver {
@@ -345,6 +363,18 @@ mod tests {
         assert_eq!(resolver.resolve("np.random.rand"), "numpy.random.rand");
     }
 
+    #[test]
+    fn test_resolution_confidence() {
+        let mut resolver = FqnResolver::new();
+        resolver.register_alias("np".to_string(), "numpy".to_string());
+
+        assert_eq!(resolver.confidence("len"), 1.0);
+        assert_eq!(resolver.confidence("np.array"), 0.9);
+        assert_eq!(resolver.confidence("self.save"), 0.7);
+        assert_eq!(resolver.confidence("helper"), 0.6);
+        assert_eq!(resolver.confidence("conn.execute"), 0.5);
+    }
+
     #[test]
     fn test_unknown_functions() {
         let resolver = FqnResolver::new();
@@ -2,6 +2,7 @@
 
 pub mod call;
 pub mod class;
+pub mod decorator; // Decorator applications and callback arguments
 pub mod fqn_resolver;
 pub mod function;
 pub mod identifier;
@@ -16,6 +17,7 @@ pub use parameter::*;
 pub use variable::*;
 // Multi-language variable extraction (only export trait and factory, not VariableAssignment to avoid conflict)
 pub use call::*;
+pub use decorator::*;
 pub use fqn_resolver::*;
 pub use identifier::*;
 pub use import::*; // RFC-062: Import extraction
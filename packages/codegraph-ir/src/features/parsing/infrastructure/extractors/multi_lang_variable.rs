@@ -1114,6 +1114,7 @@ def test():
     }
 
     #[test]
+    #[cfg(feature = "ts-lang")]
     fn test_typescript_variable_extraction() {
         let code = r#"
 function test() {
@@ -1148,6 +1149,7 @@ function test() {
     }
 
     #[test]
+    #[cfg(feature = "java-lang")]
     fn test_java_variable_extraction() {
         let code = r#"
 public class Test {
@@ -1173,6 +1175,7 @@ public class Test {
     }
 
     #[test]
+    #[cfg(feature = "kotlin-lang")]
     fn test_kotlin_variable_extraction() {
         let code = r#"
 fun test() {
@@ -1207,6 +1210,7 @@ fun test() {
     }
 
     #[test]
+    #[cfg(feature = "kotlin-lang")]
     fn test_kotlin_when_statement_debug() {
         let code = r#"
 fun whenStmt(x: Int): Int {
@@ -1268,6 +1272,7 @@ fun whenStmt(x: Int): Int {
     }
 
     #[test]
+    #[cfg(feature = "rust-lang")]
     fn test_rust_variable_extraction() {
         let code = r#"
 fn test() {
@@ -1327,6 +1332,7 @@ fn test() {
     }
 
     #[test]
+    #[cfg(feature = "go-lang")]
     fn test_go_variable_extraction() {
         let code = r#"
 func test() {
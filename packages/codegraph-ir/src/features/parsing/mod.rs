@@ -8,24 +8,45 @@
 //! - `application/` - ParseFileUseCase
 //! - `infrastructure/` - TreeSitterParser, Extractors
 //! - `plugins/` - Language-specific plugins (Python, Java, TypeScript, etc.)
+//! - `sdk` - Dynamic plugin registration (extension → plugin routing)
+//! - `grammar` - Grammar version reporting and plugin compatibility checks
 
 pub mod application;
 pub mod domain;
+pub mod grammar;
 pub mod infrastructure;
 pub mod plugins;
 pub mod ports;
+pub mod sdk;
 
 // Re-exports
 pub use domain::ParsedTree;
+pub use grammar::{check_registered_plugins, GrammarInfo};
 
 // Re-export infrastructure (internal use - prefer application layer)
 #[doc(hidden)]
 pub use infrastructure::TreeSitterParser;
-pub use plugins::{
-    create_full_registry, create_registry, GoPlugin, JavaPlugin, KotlinPlugin, PythonPlugin,
-    RustPlugin, TypeScriptPlugin,
-};
+#[cfg(feature = "c-lang")]
+pub use plugins::CPlugin;
+#[cfg(feature = "cpp-lang")]
+pub use plugins::CppPlugin;
+#[cfg(feature = "go-lang")]
+pub use plugins::GoPlugin;
+#[cfg(feature = "java-lang")]
+pub use plugins::JavaPlugin;
+#[cfg(feature = "kotlin-lang")]
+pub use plugins::KotlinPlugin;
+#[cfg(feature = "php-lang")]
+pub use plugins::PhpPlugin;
+#[cfg(feature = "ruby-lang")]
+pub use plugins::RubyPlugin;
+#[cfg(feature = "rust-lang")]
+pub use plugins::RustPlugin;
+#[cfg(feature = "ts-lang")]
+pub use plugins::TypeScriptPlugin;
+pub use plugins::{create_full_registry, create_registry, PythonPlugin};
 pub use ports::{
     ExtractionContext, ExtractionResult, IdGenerator, LanguageId, LanguagePlugin, LanguageRegistry,
-    Parser, SpanExt,
+    Parser, PluginCapabilities, SpanExt,
 };
+pub use sdk::{register_plugin, PluginDescriptor, PluginFactory};
@@ -0,0 +1,1186 @@
+//! C Language Plugin
+//!
+//! Implements LanguagePlugin for C source code.
+//! Supports: functions, prototypes, structs, unions, enums, typedefs, macros, #include
+//!
+//! Preprocessor awareness:
+//! - `#include` directives become Import nodes + IMPORTS edges (file → header)
+//! - `#define` / function-like macros become Macro nodes
+//! - Declarations inside `#if`/`#ifdef` blocks are extracted from every branch and
+//!   tagged with the guarding condition(s) in `metadata.preproc_conditions`
+//!
+//! Header/implementation split:
+//! - Prototypes (`int foo(int);`) become Function nodes tagged `is_declaration`
+//! - Definitions share the same FQN, so cross-file resolution links the header
+//!   declaration and the `.c` definition without extra bookkeeping
+//!
+//! The extraction core (`CFamilyExtractor`) is shared with the C++ plugin.
+
+use tree_sitter::{Language as TSLanguage, Node as TSNode, Tree};
+
+use crate::features::parsing::domain::SyntaxKind;
+use crate::features::parsing::ports::{
+    ControlFlowType, ExtractionContext, ExtractionResult, IdGenerator, LanguageId, LanguagePlugin,
+    PluginCapabilities, SpanExt,
+};
+use crate::shared::models::{Edge, EdgeKind, Node, NodeKind, Result};
+
+/// C language plugin
+pub struct CPlugin;
+
+impl CPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for CPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Shared C/C++ extraction logic
+///
+/// Keeps the per-file preprocessor condition stack while walking the tree.
+pub(super) struct CFamilyExtractor {
+    /// Enable C++ constructs (classes, namespaces, templates, new/throw)
+    cpp: bool,
+    /// Active `#if`/`#ifdef` conditions (outermost first)
+    conditions: Vec<String>,
+    /// Currently walking a struct/class body (function definitions are inline methods)
+    in_record: bool,
+}
+
+impl CFamilyExtractor {
+    pub(super) fn new(cpp: bool) -> Self {
+        Self {
+            cpp,
+            conditions: Vec::new(),
+            in_record: false,
+        }
+    }
+
+    /// Extract a whole translation unit (file node + all declarations)
+    pub(super) fn extract_tree(
+        &mut self,
+        ctx: &mut ExtractionContext,
+        tree: &Tree,
+    ) -> ExtractionResult {
+        let mut result = ExtractionResult::new();
+        let mut id_gen = IdGenerator::new(format!("{}:{}", ctx.repo_id, ctx.file_path));
+
+        // Create file node
+        let file_node_id = id_gen.next_node();
+        let file_node = Node::new(
+            file_node_id.clone(),
+            NodeKind::File,
+            ctx.file_path.to_string(),
+            ctx.file_path.to_string(),
+            tree.root_node().to_span(),
+        )
+        .with_language(ctx.language.name().to_string())
+        .with_name(ctx.file_path.to_string());
+
+        result.add_node(file_node);
+        ctx.parent_id = Some(file_node_id);
+
+        let root = tree.root_node();
+        let mut cursor = root.walk();
+        for child in root.children(&mut cursor) {
+            self.extract_node(ctx, &child, &mut id_gen, &mut result);
+        }
+
+        result
+    }
+
+    /// Build FQN from current scope
+    fn make_fqn(&self, ctx: &ExtractionContext, name: &str) -> String {
+        if ctx.fqn_prefix().is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", ctx.fqn_prefix(), name)
+        }
+    }
+
+    /// Normalize C++ scope separators to the IR's dotted FQN form
+    fn normalize_name(name: &str) -> String {
+        name.replace("::", ".")
+            .replace("->", ".")
+            .trim_start_matches('.')
+            .to_string()
+    }
+
+    /// Build metadata JSON for a node (preprocessor conditions + extra flags)
+    fn metadata(&self, extra: &[(&str, serde_json::Value)]) -> Option<String> {
+        if self.conditions.is_empty() && extra.is_empty() {
+            return None;
+        }
+
+        let mut metadata = serde_json::Map::new();
+        if !self.conditions.is_empty() {
+            metadata.insert(
+                "preproc_conditions".to_string(),
+                serde_json::Value::Array(
+                    self.conditions
+                        .iter()
+                        .map(|c| serde_json::Value::String(c.clone()))
+                        .collect(),
+                ),
+            );
+        }
+        for (key, value) in extra {
+            metadata.insert(key.to_string(), value.clone());
+        }
+        serde_json::to_string(&metadata).ok()
+    }
+
+    /// Find the function_declarator inside a (possibly pointer/reference) declarator
+    fn find_function_declarator<'a>(node: &TSNode<'a>) -> Option<TSNode<'a>> {
+        let mut current = *node;
+        loop {
+            match current.kind() {
+                "function_declarator" => return Some(current),
+                "pointer_declarator"
+                | "reference_declarator"
+                | "parenthesized_declarator"
+                | "attributed_declarator"
+                | "init_declarator" => {
+                    current = current.child_by_field_name("declarator").or_else(|| {
+                        let mut cursor = current.walk();
+                        let found = current
+                            .named_children(&mut cursor)
+                            .find(|c| c.kind().ends_with("declarator"));
+                        found
+                    })?;
+                }
+                _ => return None,
+            }
+        }
+    }
+
+    /// Get the declared name from a declarator (unwrapping pointers/arrays/functions)
+    fn declarator_name(&self, ctx: &ExtractionContext, node: &TSNode) -> Option<String> {
+        let mut current = *node;
+        loop {
+            match current.kind() {
+                "identifier"
+                | "field_identifier"
+                | "type_identifier"
+                | "qualified_identifier"
+                | "destructor_name"
+                | "operator_name"
+                | "namespace_identifier" => {
+                    let text = ctx.node_text(&current);
+                    return if text.is_empty() {
+                        None
+                    } else {
+                        Some(Self::normalize_name(text))
+                    };
+                }
+                _ => {
+                    current = current.child_by_field_name("declarator").or_else(|| {
+                        let mut cursor = current.walk();
+                        let found = current.named_children(&mut cursor).find(|c| {
+                            c.kind().ends_with("declarator")
+                                || c.kind().ends_with("identifier")
+                                || matches!(c.kind(), "destructor_name" | "operator_name")
+                        });
+                        found
+                    })?;
+                }
+            }
+        }
+    }
+
+    /// Extract a single node
+    fn extract_node(
+        &mut self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        match node.kind() {
+            "preproc_include" => self.extract_include(ctx, node, id_gen, result),
+            "preproc_def" | "preproc_function_def" => self.extract_macro(ctx, node, id_gen, result),
+            "preproc_if" | "preproc_ifdef" | "preproc_elif" | "preproc_else"
+            | "preproc_elifdef" => self.extract_conditional(ctx, node, id_gen, result),
+            "function_definition" => self.extract_function(ctx, node, id_gen, result),
+            "declaration" | "field_declaration" => {
+                self.extract_declaration(ctx, node, id_gen, result)
+            }
+            "struct_specifier" | "union_specifier" | "class_specifier" => {
+                self.extract_record(ctx, node, id_gen, result)
+            }
+            "enum_specifier" => self.extract_enum(ctx, node, id_gen, result),
+            "type_definition" => self.extract_typedef(ctx, node, id_gen, result),
+            "namespace_definition" if self.cpp => self.extract_namespace(ctx, node, id_gen, result),
+            "call_expression" => self.extract_call(ctx, node, id_gen, result),
+            "new_expression" if self.cpp => self.extract_new(ctx, node, id_gen, result),
+            _ => {
+                let mut cursor = node.walk();
+                for child in node.children(&mut cursor) {
+                    self.extract_node(ctx, &child, id_gen, result);
+                }
+            }
+        }
+    }
+
+    /// Extract `#include` → Import node + IMPORTS edge
+    fn extract_include(
+        &self,
+        ctx: &ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let Some(path_node) = node.child_by_field_name("path") else {
+            return;
+        };
+
+        let raw = ctx.node_text(&path_node);
+        let is_system = path_node.kind() == "system_lib_string";
+        let header = raw
+            .trim_matches(|c| c == '"' || c == '<' || c == '>')
+            .to_string();
+        if header.is_empty() {
+            return;
+        }
+
+        let node_id = id_gen.next_node();
+        let mut ir_node = ctx.new_node(
+            node_id,
+            NodeKind::Import,
+            format!("include:{}", header),
+            header.clone(),
+            node,
+        );
+        ir_node.metadata = self.metadata(&[("system", serde_json::Value::Bool(is_system))]);
+        result.add_node(ir_node);
+
+        if let Some(ref parent) = ctx.parent_id {
+            result.add_edge(
+                Edge::new(parent.clone(), header, EdgeKind::Imports).with_span(node.to_span()),
+            );
+        }
+    }
+
+    /// Extract `#define` (object-like and function-like macros)
+    fn extract_macro(
+        &self,
+        ctx: &ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let name = node
+            .child_by_field_name("name")
+            .map(|n| ctx.node_text(&n).to_string())
+            .unwrap_or_default();
+        if name.is_empty() {
+            return;
+        }
+
+        let is_function_like = node.kind() == "preproc_function_def";
+        let node_id = id_gen.next_node();
+        let fqn = self.make_fqn(ctx, &name);
+        let mut ir_node = ctx.new_node(node_id, NodeKind::Macro, fqn, name, node);
+        ir_node.metadata =
+            self.metadata(&[("function_like", serde_json::Value::Bool(is_function_like))]);
+        result.add_node(ir_node);
+    }
+
+    /// Walk both branches of `#if`/`#ifdef`, tagging nested declarations
+    fn extract_conditional(
+        &mut self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let condition = match node.kind() {
+            "preproc_ifdef" | "preproc_elifdef" => {
+                let name = node
+                    .child_by_field_name("name")
+                    .map(|n| ctx.node_text(&n).to_string())
+                    .unwrap_or_default();
+                let negated = ctx.node_text(node).starts_with("#ifndef")
+                    || ctx.node_text(node).starts_with("#elifndef");
+                if negated {
+                    format!("!defined({})", name)
+                } else {
+                    format!("defined({})", name)
+                }
+            }
+            "preproc_else" => "else".to_string(),
+            _ => node
+                .child_by_field_name("condition")
+                .map(|n| ctx.node_text(&n).trim().to_string())
+                .unwrap_or_default(),
+        };
+
+        // `#ifndef FOO_H / #define FOO_H` include guards are not real conditions
+        if self.is_include_guard(ctx, node) {
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                self.extract_node(ctx, &child, id_gen, result);
+            }
+            return;
+        }
+
+        self.conditions.push(condition);
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            // The `alternative` branch (#else/#elif) carries its own condition
+            if matches!(
+                child.kind(),
+                "preproc_else" | "preproc_elif" | "preproc_elifdef"
+            ) {
+                continue;
+            }
+            self.extract_node(ctx, &child, id_gen, result);
+        }
+        self.conditions.pop();
+
+        if let Some(alternative) = node.child_by_field_name("alternative") {
+            self.extract_node(ctx, &alternative, id_gen, result);
+        }
+    }
+
+    /// Check for the `#ifndef X` + `#define X` include-guard idiom
+    fn is_include_guard(&self, ctx: &ExtractionContext, node: &TSNode) -> bool {
+        if node.kind() != "preproc_ifdef" || !ctx.node_text(node).starts_with("#ifndef") {
+            return false;
+        }
+        let Some(name) = node.child_by_field_name("name") else {
+            return false;
+        };
+        let guard = ctx.node_text(&name);
+        let mut cursor = node.walk();
+        let first_def = node
+            .named_children(&mut cursor)
+            .find(|c| c.kind() != "identifier" && c.kind() != "comment");
+        first_def
+            .filter(|d| d.kind() == "preproc_def")
+            .and_then(|d| d.child_by_field_name("name"))
+            .map(|n| ctx.node_text(&n) == guard)
+            .unwrap_or(false)
+    }
+
+    /// Extract function definition (C function, C++ free function or out-of-line method)
+    fn extract_function(
+        &mut self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let Some(declarator) = node
+            .child_by_field_name("declarator")
+            .and_then(|d| Self::find_function_declarator(&d))
+        else {
+            return;
+        };
+        let Some(name) = declarator
+            .child_by_field_name("declarator")
+            .and_then(|d| self.declarator_name(ctx, &d))
+        else {
+            return;
+        };
+
+        let node_id = id_gen.next_node();
+        let fqn = self.make_fqn(ctx, &name);
+        let short_name = name.rsplit('.').next().unwrap_or(&name).to_string();
+
+        // `void Foo::bar() {}` or a function nested in a class body → method
+        let kind = if name.contains('.') || self.in_record {
+            NodeKind::Method
+        } else {
+            NodeKind::Function
+        };
+
+        let mut ir_node = ctx.new_node(node_id.clone(), kind, fqn, short_name, node);
+        if let Some(ret) = node.child_by_field_name("type") {
+            ir_node.return_type = Some(ctx.node_text(&ret).to_string());
+        }
+        if let Some(body) = node.child_by_field_name("body") {
+            ir_node.body_span = Some(body.to_span());
+        }
+        if self.is_static(ctx, node) {
+            ir_node.is_static = Some(true);
+        }
+        ir_node.metadata = self.metadata(&[]);
+        result.add_node(ir_node);
+
+        let old_parent = ctx.parent_id.take();
+        let old_in_record = std::mem::replace(&mut self.in_record, false);
+        ctx.parent_id = Some(node_id.clone());
+        ctx.push_scope(&name);
+
+        // Extract parameters
+        if let Some(params) = declarator.child_by_field_name("parameters") {
+            self.extract_parameters(ctx, &params, id_gen, result, &node_id);
+        }
+
+        // Walk body for calls / allocations
+        if let Some(body) = node.child_by_field_name("body") {
+            self.extract_node(ctx, &body, id_gen, result);
+        }
+
+        ctx.pop_scope();
+        ctx.parent_id = old_parent;
+        self.in_record = old_in_record;
+    }
+
+    /// Extract declaration (prototype, global variable, or class member)
+    fn extract_declaration(
+        &mut self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        // Inline record definitions (`struct Foo { ... } foo;`)
+        if let Some(type_node) = node.child_by_field_name("type") {
+            if matches!(
+                type_node.kind(),
+                "struct_specifier" | "union_specifier" | "class_specifier" | "enum_specifier"
+            ) && type_node.child_by_field_name("body").is_some()
+            {
+                self.extract_node(ctx, &type_node, id_gen, result);
+            }
+        }
+
+        let in_function = ctx
+            .parent_id
+            .as_ref()
+            .map(|p| {
+                result
+                    .nodes
+                    .iter()
+                    .rev()
+                    .find(|n| &n.id == p)
+                    .map(|n| matches!(n.kind, NodeKind::Function | NodeKind::Method))
+                    .unwrap_or(false)
+            })
+            .unwrap_or(false);
+
+        let mut cursor = node.walk();
+        let declarators: Vec<TSNode> = node
+            .children_by_field_name("declarator", &mut cursor)
+            .collect();
+
+        for declarator in declarators {
+            if let Some(func_decl) = Self::find_function_declarator(&declarator) {
+                // Prototype: `int foo(int a);` / in-class method declaration
+                let Some(name) = func_decl
+                    .child_by_field_name("declarator")
+                    .and_then(|d| self.declarator_name(ctx, &d))
+                else {
+                    continue;
+                };
+
+                let node_id = id_gen.next_node();
+                let fqn = self.make_fqn(ctx, &name);
+                let kind = if node.kind() == "field_declaration" || name.contains('.') {
+                    NodeKind::Method
+                } else {
+                    NodeKind::Function
+                };
+                let short_name = name.rsplit('.').next().unwrap_or(&name).to_string();
+
+                let mut ir_node = ctx.new_node(node_id.clone(), kind, fqn, short_name, node);
+                if let Some(ret) = node.child_by_field_name("type") {
+                    ir_node.return_type = Some(ctx.node_text(&ret).to_string());
+                }
+                ir_node.metadata =
+                    self.metadata(&[("is_declaration", serde_json::Value::Bool(true))]);
+                result.add_node(ir_node);
+
+                if let Some(params) = func_decl.child_by_field_name("parameters") {
+                    ctx.push_scope(&name);
+                    self.extract_parameters(ctx, &params, id_gen, result, &node_id);
+                    ctx.pop_scope();
+                }
+            } else if !in_function {
+                // Global variable or struct/class field
+                let Some(name) = self.declarator_name(ctx, &declarator) else {
+                    continue;
+                };
+
+                let kind = if node.kind() == "field_declaration" {
+                    NodeKind::Field
+                } else {
+                    NodeKind::Variable
+                };
+
+                let node_id = id_gen.next_node();
+                let fqn = self.make_fqn(ctx, &name);
+                let mut ir_node = ctx.new_node(node_id, kind, fqn, name, &declarator);
+                if let Some(type_node) = node.child_by_field_name("type") {
+                    ir_node.type_annotation = Some(ctx.node_text(&type_node).to_string());
+                }
+                ir_node.metadata = self.metadata(&[]);
+                result.add_node(ir_node);
+            }
+
+            // Initializers may contain calls (e.g. `char *p = malloc(n);`)
+            if let Some(value) = declarator.child_by_field_name("value") {
+                self.extract_node(ctx, &value, id_gen, result);
+            }
+        }
+    }
+
+    /// Extract struct / union / class
+    fn extract_record(
+        &mut self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let name = node
+            .child_by_field_name("name")
+            .map(|n| Self::normalize_name(ctx.node_text(&n)))
+            .unwrap_or_default();
+        let Some(body) = node.child_by_field_name("body") else {
+            // Forward declaration / type reference only
+            return;
+        };
+        if name.is_empty() {
+            // Anonymous record: still walk members for nested declarations
+            self.extract_node(ctx, &body, id_gen, result);
+            return;
+        }
+
+        let kind = if node.kind() == "class_specifier" {
+            NodeKind::Class
+        } else {
+            NodeKind::Struct
+        };
+
+        let node_id = id_gen.next_node();
+        let fqn = self.make_fqn(ctx, &name);
+        let mut ir_node = ctx.new_node(node_id.clone(), kind, fqn, name.clone(), node);
+
+        // C++ base classes
+        let mut cursor = node.walk();
+        let base_clause = node
+            .children(&mut cursor)
+            .find(|c| c.kind() == "base_class_clause");
+        if let Some(base_clause) = base_clause {
+            let mut bases = Vec::new();
+            let mut base_cursor = base_clause.walk();
+            for base in base_clause.named_children(&mut base_cursor) {
+                if matches!(
+                    base.kind(),
+                    "type_identifier" | "qualified_identifier" | "template_type"
+                ) {
+                    bases.push(Self::normalize_name(ctx.node_text(&base)));
+                }
+            }
+            for base in &bases {
+                result.add_edge(Edge::new(node_id.clone(), base.clone(), EdgeKind::Inherits));
+            }
+            if !bases.is_empty() {
+                ir_node.base_classes = Some(bases);
+            }
+        }
+
+        ir_node.metadata = self.metadata(&[("record_kind", node.kind().into())]);
+        result.add_node(ir_node);
+
+        let old_parent = ctx.parent_id.take();
+        let old_in_record = std::mem::replace(&mut self.in_record, true);
+        ctx.parent_id = Some(node_id);
+        ctx.push_scope(&name);
+        self.extract_node(ctx, &body, id_gen, result);
+        ctx.pop_scope();
+        ctx.parent_id = old_parent;
+        self.in_record = old_in_record;
+    }
+
+    /// Extract enum + enumerators
+    fn extract_enum(
+        &self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let name = node
+            .child_by_field_name("name")
+            .map(|n| Self::normalize_name(ctx.node_text(&n)))
+            .unwrap_or_default();
+        let Some(body) = node.child_by_field_name("body") else {
+            return;
+        };
+
+        let enum_id = if name.is_empty() {
+            None
+        } else {
+            let node_id = id_gen.next_node();
+            let fqn = self.make_fqn(ctx, &name);
+            let mut ir_node =
+                ctx.new_node(node_id.clone(), NodeKind::Enum, fqn, name.clone(), node);
+            ir_node.metadata = self.metadata(&[]);
+            result.add_node(ir_node);
+            Some(node_id)
+        };
+
+        let old_parent = ctx.parent_id.clone();
+        if let Some(ref id) = enum_id {
+            ctx.parent_id = Some(id.clone());
+            ctx.push_scope(&name);
+        }
+
+        let mut cursor = body.walk();
+        for member in body.named_children(&mut cursor) {
+            if member.kind() != "enumerator" {
+                continue;
+            }
+            let Some(member_name) = member
+                .child_by_field_name("name")
+                .map(|n| ctx.node_text(&n).to_string())
+            else {
+                continue;
+            };
+            let node_id = id_gen.next_node();
+            let fqn = self.make_fqn(ctx, &member_name);
+            let ir_node = ctx.new_node(node_id, NodeKind::EnumMember, fqn, member_name, &member);
+            result.add_node(ir_node);
+        }
+
+        if enum_id.is_some() {
+            ctx.pop_scope();
+        }
+        ctx.parent_id = old_parent;
+    }
+
+    /// Extract typedef (`typedef struct {...} Foo;`)
+    fn extract_typedef(
+        &mut self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        if let Some(type_node) = node.child_by_field_name("type") {
+            if type_node.child_by_field_name("body").is_some() {
+                self.extract_node(ctx, &type_node, id_gen, result);
+            }
+        }
+
+        let mut cursor = node.walk();
+        let declarators: Vec<TSNode> = node
+            .children_by_field_name("declarator", &mut cursor)
+            .collect();
+        for declarator in declarators {
+            let Some(name) = self.declarator_name(ctx, &declarator) else {
+                continue;
+            };
+            let node_id = id_gen.next_node();
+            let fqn = self.make_fqn(ctx, &name);
+            let mut ir_node = ctx.new_node(node_id, NodeKind::TypeAlias, fqn, name, node);
+            if let Some(type_node) = node.child_by_field_name("type") {
+                ir_node.type_annotation = Some(ctx.node_text(&type_node).to_string());
+            }
+            ir_node.metadata = self.metadata(&[]);
+            result.add_node(ir_node);
+        }
+    }
+
+    /// Extract C++ namespace (scope only, no node)
+    fn extract_namespace(
+        &mut self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let name = node
+            .child_by_field_name("name")
+            .map(|n| Self::normalize_name(ctx.node_text(&n)))
+            .unwrap_or_default();
+        let Some(body) = node.child_by_field_name("body") else {
+            return;
+        };
+
+        if name.is_empty() {
+            // Anonymous namespace: internal linkage, same scope
+            self.extract_node(ctx, &body, id_gen, result);
+            return;
+        }
+
+        ctx.push_scope(&name);
+        self.extract_node(ctx, &body, id_gen, result);
+        ctx.pop_scope();
+    }
+
+    /// Extract parameters
+    fn extract_parameters(
+        &self,
+        ctx: &ExtractionContext,
+        params_node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+        func_id: &str,
+    ) {
+        let mut cursor = params_node.walk();
+        for param in params_node.named_children(&mut cursor) {
+            if !matches!(
+                param.kind(),
+                "parameter_declaration" | "optional_parameter_declaration"
+            ) {
+                continue;
+            }
+            let Some(name) = param
+                .child_by_field_name("declarator")
+                .and_then(|d| self.declarator_name(ctx, &d))
+            else {
+                // Unnamed parameter (`void`, `int`)
+                continue;
+            };
+
+            let node_id = id_gen.next_node();
+            let fqn = self.make_fqn(ctx, &name);
+            let mut ir_node = Node::new(
+                node_id,
+                NodeKind::Parameter,
+                fqn,
+                ctx.file_path.to_string(),
+                param.to_span(),
+            )
+            .with_language(ctx.language.name().to_string())
+            .with_name(name);
+
+            if let Some(type_node) = param.child_by_field_name("type") {
+                ir_node.type_annotation = Some(ctx.node_text(&type_node).to_string());
+            }
+            ir_node.parent_id = Some(func_id.to_string());
+            result.add_node(ir_node);
+        }
+    }
+
+    /// Extract call expression → CALLS edge from enclosing function
+    fn extract_call(
+        &mut self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        if let (Some(function), Some(parent)) =
+            (node.child_by_field_name("function"), ctx.parent_id.clone())
+        {
+            let callee = Self::normalize_name(ctx.node_text(&function));
+            if !callee.is_empty() {
+                result
+                    .add_edge(Edge::new(parent, callee, EdgeKind::Calls).with_span(node.to_span()));
+            }
+        }
+
+        // Recurse into arguments (nested calls: free(malloc(n)))
+        if let Some(args) = node.child_by_field_name("arguments") {
+            self.extract_node(ctx, &args, id_gen, result);
+        }
+    }
+
+    /// Extract C++ `new T(...)` → INSTANTIATES edge
+    fn extract_new(
+        &mut self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        if let (Some(type_node), Some(parent)) =
+            (node.child_by_field_name("type"), ctx.parent_id.clone())
+        {
+            let type_name = Self::normalize_name(ctx.node_text(&type_node));
+            result.add_edge(
+                Edge::new(parent, type_name, EdgeKind::Instantiates).with_span(node.to_span()),
+            );
+        }
+
+        if let Some(args) = node.child_by_field_name("arguments") {
+            self.extract_node(ctx, &args, id_gen, result);
+        }
+    }
+
+    /// Check for `static` storage class
+    fn is_static(&self, ctx: &ExtractionContext, node: &TSNode) -> bool {
+        let mut cursor = node.walk();
+        let found = node
+            .children(&mut cursor)
+            .any(|c| c.kind() == "storage_class_specifier" && ctx.node_text(&c) == "static");
+        found
+    }
+}
+
+/// Shared `is_statement_node` for C and C++
+pub(super) fn is_c_family_statement(kind: &str) -> bool {
+    matches!(
+        kind,
+        // Declarations
+        "declaration" | "type_definition" |
+        // Expression statements
+        "expression_statement" |
+        // Control flow
+        "if_statement" | "switch_statement" | "for_statement" |
+        "while_statement" | "do_statement" | "for_range_loop" | "try_statement" |
+        // Other statements
+        "return_statement" | "break_statement" | "continue_statement" |
+        "goto_statement" | "labeled_statement" | "throw_statement" |
+        // Definitions
+        "function_definition"
+    )
+}
+
+/// Shared `is_control_flow_node` for C and C++
+pub(super) fn is_c_family_control_flow(kind: &str) -> bool {
+    matches!(
+        kind,
+        "if_statement"
+            | "switch_statement"
+            | "for_statement"
+            | "for_range_loop"
+            | "while_statement"
+            | "do_statement"
+            | "case_statement"
+            | "try_statement"
+    )
+}
+
+/// Shared `get_control_flow_type` for C and C++
+pub(super) fn c_family_control_flow_type(kind: &str) -> Option<ControlFlowType> {
+    match kind {
+        "if_statement" => Some(ControlFlowType::If),
+        "for_statement" | "for_range_loop" | "while_statement" | "do_statement" => {
+            Some(ControlFlowType::Loop)
+        }
+        "switch_statement" => Some(ControlFlowType::Match),
+        "try_statement" => Some(ControlFlowType::Try),
+        "return_statement" => Some(ControlFlowType::Return),
+        "break_statement" => Some(ControlFlowType::Break),
+        "continue_statement" => Some(ControlFlowType::Continue),
+        "throw_statement" => Some(ControlFlowType::Raise),
+        _ => None,
+    }
+}
+
+/// Shared `map_syntax_kind` for C and C++
+pub(super) fn c_family_syntax_kind(ts_kind: &str) -> SyntaxKind {
+    match ts_kind {
+        "function_definition" => SyntaxKind::FunctionDef,
+        "struct_specifier" | "union_specifier" | "class_specifier" => SyntaxKind::ClassDef,
+        "declaration" | "init_declarator" => SyntaxKind::VariableDecl,
+        "field_declaration" => SyntaxKind::FieldDecl,
+        "parameter_declaration" => SyntaxKind::ParameterDecl,
+        "preproc_include" => SyntaxKind::ImportDecl,
+        "assignment_expression" => SyntaxKind::AssignmentStmt,
+        "call_expression" => SyntaxKind::CallExpr,
+        "identifier" => SyntaxKind::NameExpr,
+        "field_expression" => SyntaxKind::AttributeExpr,
+        "number_literal" | "string_literal" | "char_literal" => SyntaxKind::LiteralExpr,
+        "binary_expression" => SyntaxKind::BinaryExpr,
+        "unary_expression" | "pointer_expression" => SyntaxKind::UnaryExpr,
+        "return_statement" => SyntaxKind::ReturnStmt,
+        "if_statement" => SyntaxKind::IfStmt,
+        "for_statement" | "for_range_loop" => SyntaxKind::ForStmt,
+        "while_statement" | "do_statement" => SyntaxKind::WhileStmt,
+        "try_statement" => SyntaxKind::TryStmt,
+        "break_statement" => SyntaxKind::BreakStmt,
+        "continue_statement" => SyntaxKind::ContinueStmt,
+        "throw_statement" => SyntaxKind::RaiseStmt,
+        "compound_statement" | "translation_unit" => SyntaxKind::Block,
+        "comment" => SyntaxKind::Comment,
+        "lambda_expression" => SyntaxKind::LambdaDef,
+        other => SyntaxKind::Other(other.to_string()),
+    }
+}
+
+/// Shared doc-comment extraction (`/** ... */` or `//` directly above)
+pub(super) fn c_family_docstring(node: &TSNode, source: &str) -> Option<String> {
+    let prev = node.prev_sibling()?;
+    if prev.kind() != "comment" {
+        return None;
+    }
+    let text = source.get(prev.byte_range())?;
+    Some(
+        text.trim_start_matches("/**")
+            .trim_start_matches("/*")
+            .trim_end_matches("*/")
+            .trim_start_matches("//")
+            .trim()
+            .to_string(),
+    )
+}
+
+impl LanguagePlugin for CPlugin {
+    fn tree_sitter_language(&self) -> TSLanguage {
+        tree_sitter_c::language()
+    }
+
+    fn language_id(&self) -> LanguageId {
+        LanguageId::C
+    }
+
+    fn capabilities(&self) -> PluginCapabilities {
+        // No dedicated variable extractor yet (DFG/SSA would use the Python fallback)
+        PluginCapabilities {
+            data_flow: false,
+            ..PluginCapabilities::full()
+        }
+    }
+
+    fn grammar_version(&self) -> Option<&'static str> {
+        Some("tree-sitter-c 0.21")
+    }
+
+    fn expected_node_kinds(&self) -> &[&str] {
+        &[
+            "function_definition",
+            "struct_specifier",
+            "enum_specifier",
+            "type_definition",
+            "preproc_include",
+        ]
+    }
+
+    fn map_node_kind(&self, ts_kind: &str) -> Option<NodeKind> {
+        match ts_kind {
+            "function_definition" => Some(NodeKind::Function),
+            "struct_specifier" | "union_specifier" => Some(NodeKind::Struct),
+            "enum_specifier" => Some(NodeKind::Enum),
+            "enumerator" => Some(NodeKind::EnumMember),
+            "type_definition" => Some(NodeKind::TypeAlias),
+            "preproc_include" => Some(NodeKind::Import),
+            "preproc_def" | "preproc_function_def" => Some(NodeKind::Macro),
+            "parameter_declaration" => Some(NodeKind::Parameter),
+            "field_declaration" => Some(NodeKind::Field),
+            _ => None,
+        }
+    }
+
+    fn map_syntax_kind(&self, ts_kind: &str) -> SyntaxKind {
+        c_family_syntax_kind(ts_kind)
+    }
+
+    fn extract(&self, ctx: &mut ExtractionContext, tree: &Tree) -> Result<ExtractionResult> {
+        Ok(CFamilyExtractor::new(false).extract_tree(ctx, tree))
+    }
+
+    fn comment_patterns(&self) -> &[&str] {
+        &["//", "/*"]
+    }
+
+    fn is_public(&self, _name: &str) -> bool {
+        // C: visibility is controlled by `static` (checked on the node), not naming
+        true
+    }
+
+    fn extract_docstring(&self, node: &TSNode, source: &str) -> Option<String> {
+        c_family_docstring(node, source)
+    }
+
+    fn is_statement_node(&self, node: &TSNode) -> bool {
+        is_c_family_statement(node.kind())
+    }
+
+    fn is_control_flow_node(&self, node: &TSNode) -> bool {
+        is_c_family_control_flow(node.kind())
+    }
+
+    fn get_control_flow_type(&self, node: &TSNode) -> Option<ControlFlowType> {
+        c_family_control_flow_type(node.kind())
+    }
+
+    fn get_match_arms<'a>(&self, node: &TSNode<'a>) -> Vec<TSNode<'a>> {
+        // switch_statement → body (compound_statement) → case_statement*
+        let mut arms = Vec::new();
+        if let Some(body) = node.child_by_field_name("body") {
+            let mut cursor = body.walk();
+            for child in body.children(&mut cursor) {
+                if child.kind() == "case_statement" {
+                    arms.push(child);
+                }
+            }
+        }
+        arms
+    }
+
+    fn get_loop_iterator<'a>(&self, node: &TSNode<'a>) -> Vec<TSNode<'a>> {
+        let mut result = Vec::new();
+        for field in ["initializer", "condition", "update"] {
+            if let Some(n) = node.child_by_field_name(field) {
+                result.push(n);
+            }
+        }
+        result
+    }
+
+    fn is_chained_condition(&self, node: &TSNode) -> bool {
+        // `else if`: if_statement wrapped in else_clause
+        node.kind() == "if_statement"
+            && node
+                .parent()
+                .map(|p| p.kind() == "else_clause")
+                .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tree_sitter::Parser;
+
+    fn parse_c(source: &str) -> Tree {
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_c::language()).unwrap();
+        parser.parse(source, None).unwrap()
+    }
+
+    fn extract(source: &str, file: &str) -> ExtractionResult {
+        let tree = parse_c(source);
+        let plugin = CPlugin::new();
+        let mut ctx = ExtractionContext::new(source, file, "test-repo", LanguageId::C);
+        plugin.extract(&mut ctx, &tree).unwrap()
+    }
+
+    #[test]
+    fn test_extract_function_and_calls() {
+        let source = r#"
+#include <stdlib.h>
+#include "buffer.h"
+
+static char *make(int n) {
+    char *p = malloc(n);
+    free(p);
+    return p;
+}
+"#;
+        let result = extract(source, "buffer.c");
+
+        let func = result
+            .nodes
+            .iter()
+            .find(|n| n.kind == NodeKind::Function)
+            .unwrap();
+        assert_eq!(func.name, Some("make".to_string()));
+        assert_eq!(func.is_static, Some(true));
+
+        let imports: Vec<_> = result
+            .edges
+            .iter()
+            .filter(|e| e.kind == EdgeKind::Imports)
+            .map(|e| e.target_id.as_str())
+            .collect();
+        assert_eq!(imports, vec!["stdlib.h", "buffer.h"]);
+
+        let calls: Vec<_> = result
+            .edges
+            .iter()
+            .filter(|e| e.kind == EdgeKind::Calls && e.source_id == func.id)
+            .map(|e| e.target_id.as_str())
+            .collect();
+        assert!(calls.contains(&"malloc"));
+        assert!(calls.contains(&"free"));
+    }
+
+    #[test]
+    fn test_prototype_is_declaration() {
+        let source = "int add(int a, int b);\n";
+        let result = extract(source, "math.h");
+
+        let proto = result
+            .nodes
+            .iter()
+            .find(|n| n.kind == NodeKind::Function)
+            .unwrap();
+        assert_eq!(proto.fqn, "add");
+        assert!(proto
+            .metadata
+            .as_deref()
+            .unwrap_or("")
+            .contains("\"is_declaration\":true"));
+
+        let params = result
+            .nodes
+            .iter()
+            .filter(|n| n.kind == NodeKind::Parameter)
+            .count();
+        assert_eq!(params, 2);
+    }
+
+    #[test]
+    fn test_struct_typedef_and_macro() {
+        let source = r#"
+#define MAX_LEN 64
+#define SQUARE(x) ((x) * (x))
+
+typedef struct node {
+    int value;
+    struct node *next;
+} node_t;
+"#;
+        let result = extract(source, "list.h");
+
+        let macros = result
+            .nodes
+            .iter()
+            .filter(|n| n.kind == NodeKind::Macro)
+            .count();
+        assert_eq!(macros, 2);
+
+        let record = result
+            .nodes
+            .iter()
+            .find(|n| n.kind == NodeKind::Struct)
+            .unwrap();
+        assert_eq!(record.name, Some("node".to_string()));
+
+        let fields = result
+            .nodes
+            .iter()
+            .filter(|n| n.kind == NodeKind::Field)
+            .count();
+        assert_eq!(fields, 2);
+
+        assert!(result
+            .nodes
+            .iter()
+            .any(|n| n.kind == NodeKind::TypeAlias && n.name.as_deref() == Some("node_t")));
+    }
+
+    #[test]
+    fn test_preprocessor_conditions() {
+        let source = r#"
+#ifdef USE_FAST
+void fast_path(void) {}
+#else
+void slow_path(void) {}
+#endif
+"#;
+        let result = extract(source, "path.c");
+
+        let fast = result
+            .nodes
+            .iter()
+            .find(|n| n.name.as_deref() == Some("fast_path"))
+            .unwrap();
+        assert!(fast
+            .metadata
+            .as_deref()
+            .unwrap()
+            .contains("defined(USE_FAST)"));
+
+        let slow = result
+            .nodes
+            .iter()
+            .find(|n| n.name.as_deref() == Some("slow_path"))
+            .unwrap();
+        assert!(slow.metadata.as_deref().unwrap().contains("else"));
+    }
+}
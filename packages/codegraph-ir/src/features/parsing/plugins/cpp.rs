@@ -0,0 +1,243 @@
+//! C++ Language Plugin
+//!
+//! Implements LanguagePlugin for C++ source code.
+//! Supports: everything in the C plugin plus classes, inheritance, namespaces,
+//! templates, out-of-line method definitions (`void Foo::bar() {}`), `new` expressions
+//!
+//! Extraction is shared with the C plugin (`CFamilyExtractor`), so `#include`
+//! edges, macros and preprocessor condition tagging behave identically.
+
+use tree_sitter::{Language as TSLanguage, Node as TSNode, Tree};
+
+use super::c::{
+    c_family_control_flow_type, c_family_docstring, c_family_syntax_kind, is_c_family_control_flow,
+    is_c_family_statement, CFamilyExtractor,
+};
+use crate::features::parsing::domain::SyntaxKind;
+use crate::features::parsing::ports::{
+    ControlFlowType, ExtractionContext, ExtractionResult, LanguageId, LanguagePlugin,
+    PluginCapabilities,
+};
+use crate::shared::models::{NodeKind, Result};
+
+/// C++ language plugin
+pub struct CppPlugin;
+
+impl CppPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for CppPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LanguagePlugin for CppPlugin {
+    fn tree_sitter_language(&self) -> TSLanguage {
+        tree_sitter_cpp::language()
+    }
+
+    fn language_id(&self) -> LanguageId {
+        LanguageId::Cpp
+    }
+
+    fn capabilities(&self) -> PluginCapabilities {
+        // No dedicated variable extractor yet (DFG/SSA would use the Python fallback)
+        PluginCapabilities {
+            data_flow: false,
+            ..PluginCapabilities::full()
+        }
+    }
+
+    fn grammar_version(&self) -> Option<&'static str> {
+        Some("tree-sitter-cpp 0.22")
+    }
+
+    fn expected_node_kinds(&self) -> &[&str] {
+        &[
+            "function_definition",
+            "class_specifier",
+            "struct_specifier",
+            "namespace_definition",
+            "preproc_include",
+        ]
+    }
+
+    fn map_node_kind(&self, ts_kind: &str) -> Option<NodeKind> {
+        match ts_kind {
+            "function_definition" => Some(NodeKind::Function),
+            "class_specifier" => Some(NodeKind::Class),
+            "struct_specifier" | "union_specifier" => Some(NodeKind::Struct),
+            "enum_specifier" => Some(NodeKind::Enum),
+            "enumerator" => Some(NodeKind::EnumMember),
+            "type_definition" | "alias_declaration" => Some(NodeKind::TypeAlias),
+            "preproc_include" => Some(NodeKind::Import),
+            "preproc_def" | "preproc_function_def" => Some(NodeKind::Macro),
+            "parameter_declaration" | "optional_parameter_declaration" => Some(NodeKind::Parameter),
+            "field_declaration" => Some(NodeKind::Field),
+            "lambda_expression" => Some(NodeKind::Lambda),
+            "template_parameter_list" => Some(NodeKind::TypeParameter),
+            _ => None,
+        }
+    }
+
+    fn map_syntax_kind(&self, ts_kind: &str) -> SyntaxKind {
+        c_family_syntax_kind(ts_kind)
+    }
+
+    fn extract(&self, ctx: &mut ExtractionContext, tree: &Tree) -> Result<ExtractionResult> {
+        Ok(CFamilyExtractor::new(true).extract_tree(ctx, tree))
+    }
+
+    fn comment_patterns(&self) -> &[&str] {
+        &["//", "/*"]
+    }
+
+    fn is_public(&self, _name: &str) -> bool {
+        // C++: visibility comes from access specifiers, not naming
+        true
+    }
+
+    fn extract_docstring(&self, node: &TSNode, source: &str) -> Option<String> {
+        c_family_docstring(node, source)
+    }
+
+    fn is_statement_node(&self, node: &TSNode) -> bool {
+        is_c_family_statement(node.kind())
+            || matches!(node.kind(), "alias_declaration" | "using_declaration")
+    }
+
+    fn is_control_flow_node(&self, node: &TSNode) -> bool {
+        is_c_family_control_flow(node.kind())
+    }
+
+    fn get_control_flow_type(&self, node: &TSNode) -> Option<ControlFlowType> {
+        c_family_control_flow_type(node.kind())
+    }
+
+    fn get_match_arms<'a>(&self, node: &TSNode<'a>) -> Vec<TSNode<'a>> {
+        let mut arms = Vec::new();
+        if let Some(body) = node.child_by_field_name("body") {
+            let mut cursor = body.walk();
+            for child in body.children(&mut cursor) {
+                if child.kind() == "case_statement" {
+                    arms.push(child);
+                }
+            }
+        }
+        arms
+    }
+
+    fn get_loop_iterator<'a>(&self, node: &TSNode<'a>) -> Vec<TSNode<'a>> {
+        let mut result = Vec::new();
+        // for (init; cond; update) and for (decl : range)
+        for field in ["initializer", "condition", "update", "declarator", "right"] {
+            if let Some(n) = node.child_by_field_name(field) {
+                result.push(n);
+            }
+        }
+        result
+    }
+
+    fn is_chained_condition(&self, node: &TSNode) -> bool {
+        node.kind() == "if_statement"
+            && node
+                .parent()
+                .map(|p| p.kind() == "else_clause")
+                .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::models::EdgeKind;
+    use tree_sitter::Parser;
+
+    fn extract(source: &str, file: &str) -> ExtractionResult {
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_cpp::language()).unwrap();
+        let tree = parser.parse(source, None).unwrap();
+        let plugin = CppPlugin::new();
+        let mut ctx = ExtractionContext::new(source, file, "test-repo", LanguageId::Cpp);
+        plugin.extract(&mut ctx, &tree).unwrap()
+    }
+
+    #[test]
+    fn test_class_with_base_and_methods() {
+        let source = r#"
+namespace app {
+class Widget : public Base {
+public:
+    void draw();
+    int size() const { return n_; }
+private:
+    int n_;
+};
+}
+"#;
+        let result = extract(source, "widget.hpp");
+
+        let class = result
+            .nodes
+            .iter()
+            .find(|n| n.kind == NodeKind::Class)
+            .unwrap();
+        assert_eq!(class.fqn, "app.Widget");
+        assert_eq!(class.base_classes, Some(vec!["Base".to_string()]));
+
+        let methods: Vec<_> = result
+            .nodes
+            .iter()
+            .filter(|n| n.kind == NodeKind::Method)
+            .map(|n| n.fqn.as_str())
+            .collect();
+        assert!(methods.contains(&"app.Widget.draw"));
+        assert!(methods.contains(&"app.Widget.size"));
+
+        assert!(result
+            .edges
+            .iter()
+            .any(|e| e.kind == EdgeKind::Inherits && e.target_id == "Base"));
+    }
+
+    #[test]
+    fn test_out_of_line_definition_matches_header_fqn() {
+        let source = r#"
+#include "widget.hpp"
+
+namespace app {
+void Widget::draw() {
+    auto *buf = new Buffer(16);
+    render(buf);
+    delete buf;
+}
+}
+"#;
+        let result = extract(source, "widget.cpp");
+
+        let method = result
+            .nodes
+            .iter()
+            .find(|n| n.kind == NodeKind::Method)
+            .unwrap();
+        assert_eq!(method.fqn, "app.Widget.draw");
+        assert_eq!(method.name, Some("draw".to_string()));
+
+        assert!(result
+            .edges
+            .iter()
+            .any(|e| e.kind == EdgeKind::Instantiates && e.target_id == "Buffer"));
+        assert!(result
+            .edges
+            .iter()
+            .any(|e| e.kind == EdgeKind::Calls && e.target_id == "render"));
+        assert!(result
+            .edges
+            .iter()
+            .any(|e| e.kind == EdgeKind::Imports && e.target_id == "widget.hpp"));
+    }
+}
@@ -7,9 +7,10 @@ use tree_sitter::{Language as TSLanguage, Node as TSNode, Tree};
 
 use crate::features::parsing::domain::SyntaxKind;
 use crate::features::parsing::ports::{
-    ExtractionContext, ExtractionResult, IdGenerator, LanguageId, LanguagePlugin, SpanExt,
+    ExtractionContext, ExtractionResult, IdGenerator, LanguageId, LanguagePlugin,
+    PluginCapabilities, SpanExt,
 };
-use crate::shared::models::{Edge, EdgeKind, Node, NodeKind, Result};
+use crate::shared::models::{Edge, EdgeKind, EdgeMetadata, Node, NodeKind, Result};
 
 /// Go language plugin
 pub struct GoPlugin;
@@ -375,9 +376,28 @@ impl GoPlugin {
                 node.to_span(),
             )
             .with_language(ctx.language.name().to_string())
-            .with_name(path);
+            .with_name(path.clone());
 
             result.add_node(ir_node);
+
+            // IMPORTS edge targets the bare import path; `name` is the alias
+            if let Some(ref parent) = ctx.parent_id {
+                let alias = node
+                    .child_by_field_name("name")
+                    .map(|n| ctx.node_text(&n).to_string());
+                result.add_edge(
+                    Edge::new(
+                        parent.clone(),
+                        path.trim_matches(|c| c == '"' || c == '`').to_string(),
+                        EdgeKind::Imports,
+                    )
+                    .with_span(node.to_span())
+                    .with_metadata(EdgeMetadata {
+                        alias,
+                        ..Default::default()
+                    }),
+                );
+            }
         }
     }
 
@@ -740,6 +760,24 @@ impl LanguagePlugin for GoPlugin {
         LanguageId::Go
     }
 
+    fn capabilities(&self) -> PluginCapabilities {
+        PluginCapabilities::full()
+    }
+
+    fn grammar_version(&self) -> Option<&'static str> {
+        Some("tree-sitter-go 0.21")
+    }
+
+    fn expected_node_kinds(&self) -> &[&str] {
+        &[
+            "function_declaration",
+            "method_declaration",
+            "type_spec",
+            "import_declaration",
+            "short_var_declaration",
+        ]
+    }
+
     fn map_node_kind(&self, ts_kind: &str) -> Option<NodeKind> {
         match ts_kind {
             "type_spec" => Some(NodeKind::TypeAlias), // Could be struct/interface
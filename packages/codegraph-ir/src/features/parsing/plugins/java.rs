@@ -7,7 +7,8 @@ use tree_sitter::{Language as TSLanguage, Node as TSNode, Tree};
 
 use crate::features::parsing::domain::SyntaxKind;
 use crate::features::parsing::ports::{
-    ExtractionContext, ExtractionResult, IdGenerator, LanguageId, LanguagePlugin, SpanExt,
+    ExtractionContext, ExtractionResult, IdGenerator, LanguageId, LanguagePlugin,
+    PluginCapabilities, SpanExt,
 };
 use crate::shared::models::{Edge, EdgeKind, Node, NodeKind, Result};
 
@@ -1008,6 +1009,25 @@ impl LanguagePlugin for JavaPlugin {
         LanguageId::Java
     }
 
+    fn capabilities(&self) -> PluginCapabilities {
+        PluginCapabilities::full()
+    }
+
+    fn grammar_version(&self) -> Option<&'static str> {
+        Some("tree-sitter-java 0.21")
+    }
+
+    fn expected_node_kinds(&self) -> &[&str] {
+        &[
+            "class_declaration",
+            "interface_declaration",
+            "method_declaration",
+            "constructor_declaration",
+            "field_declaration",
+            "import_declaration",
+        ]
+    }
+
     fn map_node_kind(&self, ts_kind: &str) -> Option<NodeKind> {
         match ts_kind {
             "class_declaration" => Some(NodeKind::Class),
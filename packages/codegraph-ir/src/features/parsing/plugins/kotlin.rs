@@ -7,7 +7,8 @@ use tree_sitter::{Language as TSLanguage, Node as TSNode, Tree};
 
 use crate::features::parsing::domain::SyntaxKind;
 use crate::features::parsing::ports::{
-    ExtractionContext, ExtractionResult, IdGenerator, LanguageId, LanguagePlugin, SpanExt,
+    ExtractionContext, ExtractionResult, IdGenerator, LanguageId, LanguagePlugin,
+    PluginCapabilities, SpanExt,
 };
 use crate::shared::models::{Edge, EdgeKind, Node, NodeKind, Result};
 
@@ -777,6 +778,24 @@ impl LanguagePlugin for KotlinPlugin {
         LanguageId::Kotlin
     }
 
+    fn capabilities(&self) -> PluginCapabilities {
+        PluginCapabilities::full()
+    }
+
+    fn grammar_version(&self) -> Option<&'static str> {
+        Some("tree-sitter-kotlin 0.3.8")
+    }
+
+    fn expected_node_kinds(&self) -> &[&str] {
+        &[
+            "class_declaration",
+            "object_declaration",
+            "function_declaration",
+            "property_declaration",
+            "import_header",
+        ]
+    }
+
     fn map_node_kind(&self, ts_kind: &str) -> Option<NodeKind> {
         match ts_kind {
             "class_declaration" => Some(NodeKind::Class),
@@ -921,6 +940,13 @@ impl LanguagePlugin for KotlinPlugin {
             "break_expression" => Some(ControlFlowType::Break),
             "continue_expression" => Some(ControlFlowType::Continue),
             "throw_expression" => Some(ControlFlowType::Raise),
+            // `throw` / `return` / `break` / `continue` share one node kind
+            "jump_expression" => match node.child(0)?.kind() {
+                "throw" => Some(ControlFlowType::Raise),
+                "break" | "break@" => Some(ControlFlowType::Break),
+                "continue" | "continue@" => Some(ControlFlowType::Continue),
+                _ => Some(ControlFlowType::Return),
+            },
             _ => None,
         }
     }
@@ -3,49 +3,74 @@
 //! Each language has its own plugin implementing the LanguagePlugin trait.
 //!
 //! SOTA Multi-Language Support:
-//! - Python, Java, TypeScript, Kotlin, Rust, Go
+//! - Python, Java, TypeScript, Kotlin, Rust, Go, C, C++, PHP, Ruby
+//!
+//! Python is always built in; every other grammar sits behind a `*-lang`
+//! cargo feature (all enabled by default via `all-languages`).
 
+#[cfg(feature = "c-lang")]
+pub mod c;
+#[cfg(feature = "cpp-lang")]
+pub mod cpp;
+#[cfg(feature = "go-lang")]
 pub mod go;
+#[cfg(feature = "java-lang")]
 pub mod java;
+#[cfg(feature = "kotlin-lang")]
 pub mod kotlin;
+#[cfg(feature = "php-lang")]
+pub mod php;
 pub mod python;
+#[cfg(feature = "ruby-lang")]
+pub mod ruby;
+#[cfg(feature = "rust-lang")]
 pub mod rust_lang;
+#[cfg(feature = "ts-lang")]
 pub mod typescript;
 
+#[cfg(feature = "c-lang")]
+pub use c::CPlugin;
+#[cfg(feature = "cpp-lang")]
+pub use cpp::CppPlugin;
+#[cfg(feature = "go-lang")]
 pub use go::GoPlugin;
+#[cfg(feature = "java-lang")]
 pub use java::JavaPlugin;
+#[cfg(feature = "kotlin-lang")]
 pub use kotlin::KotlinPlugin;
+#[cfg(feature = "php-lang")]
+pub use php::PhpPlugin;
 pub use python::PythonPlugin;
+#[cfg(feature = "ruby-lang")]
+pub use ruby::RubyPlugin;
+#[cfg(feature = "rust-lang")]
 pub use rust_lang::RustPlugin;
+#[cfg(feature = "ts-lang")]
 pub use typescript::TypeScriptPlugin;
 
 use crate::features::parsing::ports::{LanguageId, LanguageRegistry};
+use crate::features::parsing::sdk;
 
-/// Create a registry with all language plugins registered
+/// Create a registry with all registered language plugins
+///
+/// Includes the built-in plugins enabled by cargo features plus any plugin
+/// registered through the SDK.
 pub fn create_full_registry() -> LanguageRegistry {
     let mut registry = LanguageRegistry::new();
-    registry.register(Box::new(PythonPlugin::new()));
-    registry.register(Box::new(JavaPlugin::new()));
-    registry.register(Box::new(TypeScriptPlugin::new()));
-    registry.register(Box::new(KotlinPlugin::new()));
-    registry.register(Box::new(RustPlugin::new()));
-    registry.register(Box::new(GoPlugin::new()));
+    for descriptor in sdk::registered_plugins() {
+        registry.register((descriptor.factory)());
+    }
     registry
 }
 
 /// Create a registry with only specific languages
+///
+/// Languages without a registered plugin (e.g. grammar feature disabled) are skipped.
 pub fn create_registry(languages: &[LanguageId]) -> LanguageRegistry {
     let mut registry = LanguageRegistry::new();
     for lang in languages {
-        match lang {
-            LanguageId::Python => registry.register(Box::new(PythonPlugin::new())),
-            LanguageId::Java => registry.register(Box::new(JavaPlugin::new())),
-            LanguageId::TypeScript | LanguageId::JavaScript => {
-                registry.register(Box::new(TypeScriptPlugin::new()))
-            }
-            LanguageId::Kotlin => registry.register(Box::new(KotlinPlugin::new())),
-            LanguageId::Rust => registry.register(Box::new(RustPlugin::new())),
-            LanguageId::Go => registry.register(Box::new(GoPlugin::new())),
+        if let Some(plugin) = sdk::plugin_for_language(*lang) {
+            registry.register(plugin);
         }
     }
     registry
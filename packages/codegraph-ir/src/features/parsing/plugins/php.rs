@@ -0,0 +1,1064 @@
+//! PHP Language Plugin
+//!
+//! Implements LanguagePlugin for PHP source code.
+//! Supports: namespaces, classes, interfaces, traits, enums, functions, methods,
+//! properties, `use` imports, `require`/`include`, calls, `new` expressions
+//!
+//! FQNs use the IR's dotted form: `App\Models\User` → `App.Models.User`, so
+//! `use` statements resolve against class FQNs in the cross-file resolver.
+
+use tree_sitter::{Language as TSLanguage, Node as TSNode, Tree};
+
+use crate::features::parsing::domain::SyntaxKind;
+use crate::features::parsing::ports::{
+    ControlFlowType, ExtractionContext, ExtractionResult, IdGenerator, LanguageId, LanguagePlugin,
+    PluginCapabilities, SpanExt,
+};
+use crate::shared::models::{Edge, EdgeKind, EdgeMetadata, Node, NodeKind, Result};
+
+/// PHP language plugin
+pub struct PhpPlugin;
+
+impl PhpPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Normalize PHP names to dotted FQN form (`\App\Foo` → `App.Foo`, `$x->y` → `x.y`)
+    fn normalize_name(name: &str) -> String {
+        name.replace("?->", ".")
+            .replace("->", ".")
+            .replace("::", ".")
+            .replace('\\', ".")
+            .replace('$', "")
+            .trim_matches('.')
+            .to_string()
+    }
+
+    /// Build FQN from current scope
+    fn make_fqn(&self, ctx: &ExtractionContext, name: &str) -> String {
+        if ctx.fqn_prefix().is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", ctx.fqn_prefix(), name)
+        }
+    }
+
+    /// `ExtractionContext::new_node` with the node's doc comment
+    fn new_node(
+        &self,
+        ctx: &ExtractionContext,
+        node_id: String,
+        kind: NodeKind,
+        fqn: String,
+        name: String,
+        node: &TSNode,
+    ) -> Node {
+        let ir_node = ctx.new_node(node_id, kind, fqn, name, node);
+        match self.extract_docstring(node, ctx.source) {
+            Some(doc) => ir_node.with_docstring(doc),
+            None => ir_node,
+        }
+    }
+
+    /// Extract a single node
+    fn extract_node(
+        &self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        match node.kind() {
+            "namespace_use_declaration" => self.extract_use(ctx, node, id_gen, result),
+            "include_expression"
+            | "include_once_expression"
+            | "require_expression"
+            | "require_once_expression" => self.extract_require(ctx, node, id_gen, result),
+            "class_declaration"
+            | "interface_declaration"
+            | "trait_declaration"
+            | "enum_declaration" => self.extract_class_like(ctx, node, id_gen, result),
+            "function_definition" | "method_declaration" => {
+                self.extract_function(ctx, node, id_gen, result)
+            }
+            "property_declaration" => self.extract_property(ctx, node, id_gen, result),
+            "const_declaration" => self.extract_const(ctx, node, id_gen, result),
+            "use_declaration" => self.extract_trait_use(ctx, node, result),
+            "enum_case" => self.extract_enum_case(ctx, node, id_gen, result),
+            "function_call_expression"
+            | "member_call_expression"
+            | "nullsafe_member_call_expression"
+            | "scoped_call_expression" => self.extract_call(ctx, node, id_gen, result),
+            "object_creation_expression" => self.extract_new(ctx, node, id_gen, result),
+            _ => self.extract_children(ctx, node, id_gen, result),
+        }
+    }
+
+    fn extract_children(
+        &self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.extract_node(ctx, &child, id_gen, result);
+        }
+    }
+
+    /// Extract `use App\Models\User [as U];` (including group use `use App\{A, B}`)
+    fn extract_use(
+        &self,
+        ctx: &ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        // Prefix for group use: `use App\Models\{User, Post};`
+        let mut cursor = node.walk();
+        let prefix = node
+            .named_children(&mut cursor)
+            .find(|c| c.kind() == "namespace_name")
+            .map(|n| Self::normalize_name(ctx.node_text(&n)));
+
+        let mut clauses = Vec::new();
+        Self::collect_use_clauses(node, &mut clauses);
+
+        for clause in clauses {
+            let mut clause_cursor = clause.walk();
+            let target = clause
+                .named_children(&mut clause_cursor)
+                .find(|c| matches!(c.kind(), "qualified_name" | "name" | "namespace_name"));
+            let Some(target) = target else {
+                continue;
+            };
+
+            let mut imported = Self::normalize_name(ctx.node_text(&target));
+            if let Some(ref prefix) = prefix {
+                imported = format!("{}.{}", prefix, imported);
+            }
+            if imported.is_empty() {
+                continue;
+            }
+
+            let alias = Self::use_alias(ctx, &clause);
+            let short_name = alias
+                .clone()
+                .unwrap_or_else(|| imported.rsplit('.').next().unwrap_or(&imported).to_string());
+
+            let node_id = id_gen.next_node();
+            let ir_node = self.new_node(
+                ctx,
+                node_id,
+                NodeKind::Import,
+                imported.clone(),
+                short_name,
+                &clause,
+            );
+            result.add_node(ir_node);
+
+            if let Some(ref parent) = ctx.parent_id {
+                result.add_edge(
+                    Edge::new(parent.clone(), imported, EdgeKind::Imports)
+                        .with_span(clause.to_span())
+                        .with_metadata(EdgeMetadata {
+                            alias,
+                            ..Default::default()
+                        }),
+                );
+            }
+        }
+    }
+
+    /// Collect `namespace_use_clause` / `namespace_use_group_clause` nodes
+    fn collect_use_clauses<'a>(node: &TSNode<'a>, clauses: &mut Vec<TSNode<'a>>) {
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            match child.kind() {
+                "namespace_use_clause" | "namespace_use_group_clause" => clauses.push(child),
+                "namespace_use_group" => Self::collect_use_clauses(&child, clauses),
+                _ => {}
+            }
+        }
+    }
+
+    /// Alias of a use clause (`as U`), across grammar versions
+    fn use_alias(ctx: &ExtractionContext, clause: &TSNode) -> Option<String> {
+        if let Some(alias) = clause.child_by_field_name("alias") {
+            return Some(ctx.node_text(&alias).to_string());
+        }
+        let mut cursor = clause.walk();
+        let aliasing = clause
+            .named_children(&mut cursor)
+            .find(|c| c.kind() == "namespace_aliasing_clause")?;
+        let mut alias_cursor = aliasing.walk();
+        let name = aliasing
+            .named_children(&mut alias_cursor)
+            .find(|c| c.kind() == "name")
+            .map(|n| ctx.node_text(&n).to_string());
+        name
+    }
+
+    /// Extract `require`/`include` with a literal path → Import node + IMPORTS edge
+    fn extract_require(
+        &self,
+        ctx: &ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let Some(path) = self.literal_path(ctx, node) else {
+            return;
+        };
+
+        let node_id = id_gen.next_node();
+        let mut ir_node = self.new_node(
+            ctx,
+            node_id,
+            NodeKind::Import,
+            format!("require:{}", path),
+            path.clone(),
+            node,
+        );
+        ir_node.metadata = serde_json::to_string(&serde_json::json!({
+            "require_kind": node.kind().trim_end_matches("_expression"),
+        }))
+        .ok();
+        result.add_node(ir_node);
+
+        if let Some(ref parent) = ctx.parent_id {
+            result.add_edge(
+                Edge::new(parent.clone(), path, EdgeKind::Imports).with_span(node.to_span()),
+            );
+        }
+    }
+
+    /// Find the literal file path in `require 'x.php'` or `require __DIR__ . '/x.php'`
+    fn literal_path(&self, ctx: &ExtractionContext, node: &TSNode) -> Option<String> {
+        let mut cursor = node.walk();
+        let mut last_string = None;
+        for child in node.named_children(&mut cursor) {
+            match child.kind() {
+                "string" | "encapsed_string" => last_string = Some(child),
+                "binary_expression" | "parenthesized_expression" => {
+                    if let Some(path) = self.literal_path(ctx, &child) {
+                        return Some(path);
+                    }
+                }
+                _ => {}
+            }
+        }
+        let text = ctx.node_text(&last_string?);
+        let path = text.trim_matches(|c| c == '\'' || c == '"');
+        if path.is_empty() {
+            return None;
+        }
+
+        // `__DIR__ . '/x.php'` is relative to the including file
+        let expr = ctx.node_text(node);
+        if expr.contains("__DIR__") || expr.contains("dirname(__FILE__)") {
+            Some(ctx.resolve_relative_path(path))
+        } else {
+            Some(path.trim_start_matches("./").to_string())
+        }
+    }
+
+    /// Extract class / interface / trait / enum
+    fn extract_class_like(
+        &self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let name = node
+            .child_by_field_name("name")
+            .map(|n| ctx.node_text(&n).to_string())
+            .unwrap_or_default();
+        if name.is_empty() {
+            return;
+        }
+
+        let kind = match node.kind() {
+            "interface_declaration" => NodeKind::Interface,
+            "trait_declaration" => NodeKind::Trait,
+            "enum_declaration" => NodeKind::Enum,
+            _ => NodeKind::Class,
+        };
+
+        let node_id = id_gen.next_node();
+        let fqn = self.make_fqn(ctx, &name);
+        let mut ir_node = self.new_node(ctx, node_id.clone(), kind, fqn, name.clone(), node);
+
+        let mut bases = Vec::new();
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            let edge_kind = match child.kind() {
+                // `class A extends B` / `interface I extends J, K`
+                "base_clause" if kind == NodeKind::Interface => EdgeKind::Extends,
+                "base_clause" => EdgeKind::Inherits,
+                "class_interface_clause" => EdgeKind::Implements,
+                _ => continue,
+            };
+            let mut clause_cursor = child.walk();
+            for base in child.named_children(&mut clause_cursor) {
+                if !matches!(base.kind(), "name" | "qualified_name") {
+                    continue;
+                }
+                let base_name = Self::normalize_name(ctx.node_text(&base));
+                if edge_kind == EdgeKind::Inherits {
+                    bases.push(base_name.clone());
+                }
+                result.add_edge(
+                    Edge::new(node_id.clone(), base_name, edge_kind).with_span(base.to_span()),
+                );
+            }
+        }
+        if !bases.is_empty() {
+            ir_node.base_classes = Some(bases);
+        }
+
+        let modifiers = self.modifiers(ctx, node);
+        if modifiers.iter().any(|m| m == "abstract") {
+            ir_node.is_abstract = Some(true);
+        }
+        if !modifiers.is_empty() {
+            ir_node.modifiers = Some(modifiers);
+        }
+        result.add_node(ir_node);
+
+        if let Some(body) = node.child_by_field_name("body") {
+            let old_parent = ctx.parent_id.take();
+            ctx.parent_id = Some(node_id);
+            ctx.push_scope(&name);
+            self.extract_children(ctx, &body, id_gen, result);
+            ctx.pop_scope();
+            ctx.parent_id = old_parent;
+        }
+    }
+
+    /// Extract function definition or method declaration
+    fn extract_function(
+        &self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let name = node
+            .child_by_field_name("name")
+            .map(|n| ctx.node_text(&n).to_string())
+            .unwrap_or_default();
+        if name.is_empty() {
+            return;
+        }
+
+        let kind = if node.kind() == "method_declaration" {
+            NodeKind::Method
+        } else {
+            NodeKind::Function
+        };
+
+        let node_id = id_gen.next_node();
+        let fqn = self.make_fqn(ctx, &name);
+        let mut ir_node = self.new_node(ctx, node_id.clone(), kind, fqn, name.clone(), node);
+
+        if let Some(ret) = node.child_by_field_name("return_type") {
+            ir_node.return_type = Some(
+                ctx.node_text(&ret)
+                    .trim_start_matches(':')
+                    .trim()
+                    .to_string(),
+            );
+        }
+        if let Some(body) = node.child_by_field_name("body") {
+            ir_node.body_span = Some(body.to_span());
+        }
+
+        let modifiers = self.modifiers(ctx, node);
+        if modifiers.iter().any(|m| m == "static") {
+            ir_node.is_static = Some(true);
+        }
+        if modifiers.iter().any(|m| m == "abstract") {
+            ir_node.is_abstract = Some(true);
+        }
+        if !modifiers.is_empty() {
+            ir_node.modifiers = Some(modifiers);
+        }
+
+        let params = node
+            .child_by_field_name("parameters")
+            .map(|p| self.parameter_names(ctx, &p))
+            .unwrap_or_default();
+        if !params.is_empty() {
+            ir_node.parameters = Some(params);
+        }
+        result.add_node(ir_node);
+
+        let old_parent = ctx.parent_id.take();
+        ctx.parent_id = Some(node_id.clone());
+        ctx.push_scope(&name);
+
+        if let Some(params) = node.child_by_field_name("parameters") {
+            self.extract_parameters(ctx, &params, id_gen, result, &node_id);
+        }
+        if let Some(body) = node.child_by_field_name("body") {
+            self.extract_children(ctx, &body, id_gen, result);
+        }
+
+        ctx.pop_scope();
+        ctx.parent_id = old_parent;
+    }
+
+    /// Collect modifiers (`public`, `static`, `abstract`, `final`, `readonly`)
+    fn modifiers(&self, ctx: &ExtractionContext, node: &TSNode) -> Vec<String> {
+        let mut cursor = node.walk();
+        let modifiers = node
+            .children(&mut cursor)
+            .filter(|c| c.kind().ends_with("_modifier"))
+            .map(|c| ctx.node_text(&c).to_lowercase())
+            .collect();
+        modifiers
+    }
+
+    /// Parameter variable name without `$`
+    fn parameter_name(&self, ctx: &ExtractionContext, param: &TSNode) -> Option<String> {
+        let name_node = param.child_by_field_name("name")?;
+        let name = ctx
+            .node_text(&name_node)
+            .trim_start_matches('$')
+            .to_string();
+        (!name.is_empty()).then_some(name)
+    }
+
+    fn parameter_names(&self, ctx: &ExtractionContext, params: &TSNode) -> Vec<String> {
+        let mut cursor = params.walk();
+        let names = params
+            .named_children(&mut cursor)
+            .filter_map(|p| self.parameter_name(ctx, &p))
+            .collect();
+        names
+    }
+
+    /// Extract parameters (simple, variadic, constructor promotion)
+    fn extract_parameters(
+        &self,
+        ctx: &ExtractionContext,
+        params_node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+        func_id: &str,
+    ) {
+        let mut cursor = params_node.walk();
+        for param in params_node.named_children(&mut cursor) {
+            if !matches!(
+                param.kind(),
+                "simple_parameter" | "variadic_parameter" | "property_promotion_parameter"
+            ) {
+                continue;
+            }
+            let Some(name) = self.parameter_name(ctx, &param) else {
+                continue;
+            };
+
+            let node_id = id_gen.next_node();
+            let fqn = self.make_fqn(ctx, &name);
+            let mut ir_node = Node::new(
+                node_id,
+                NodeKind::Parameter,
+                fqn,
+                ctx.file_path.to_string(),
+                param.to_span(),
+            )
+            .with_language(ctx.language.name().to_string())
+            .with_name(name);
+
+            if let Some(type_node) = param.child_by_field_name("type") {
+                ir_node.type_annotation = Some(ctx.node_text(&type_node).to_string());
+            }
+            ir_node.parent_id = Some(func_id.to_string());
+            result.add_node(ir_node);
+        }
+    }
+
+    /// Extract class property (`private string $name = '';`)
+    fn extract_property(
+        &self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let type_annotation = node
+            .child_by_field_name("type")
+            .map(|t| ctx.node_text(&t).to_string());
+        let modifiers = self.modifiers(ctx, node);
+
+        let mut cursor = node.walk();
+        let elements: Vec<TSNode> = node
+            .named_children(&mut cursor)
+            .filter(|c| c.kind() == "property_element")
+            .collect();
+        for element in elements {
+            let mut element_cursor = element.walk();
+            let name = element
+                .named_children(&mut element_cursor)
+                .find(|c| c.kind() == "variable_name")
+                .map(|n| ctx.node_text(&n).trim_start_matches('$').to_string())
+                .unwrap_or_default();
+            if name.is_empty() {
+                continue;
+            }
+
+            let node_id = id_gen.next_node();
+            let fqn = self.make_fqn(ctx, &name);
+            let mut ir_node = self.new_node(ctx, node_id, NodeKind::Field, fqn, name, &element);
+            ir_node.type_annotation = type_annotation.clone();
+            if modifiers.iter().any(|m| m == "static") {
+                ir_node.is_static = Some(true);
+            }
+            if !modifiers.is_empty() {
+                ir_node.modifiers = Some(modifiers.clone());
+            }
+            result.add_node(ir_node);
+
+            // Default values may instantiate objects or call functions
+            self.extract_children(ctx, &element, id_gen, result);
+        }
+    }
+
+    /// Extract `const FOO = 1;` (class or namespace level)
+    fn extract_const(
+        &self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let mut cursor = node.walk();
+        let elements: Vec<TSNode> = node
+            .named_children(&mut cursor)
+            .filter(|c| c.kind() == "const_element")
+            .collect();
+        for element in elements {
+            let mut element_cursor = element.walk();
+            let name = element
+                .named_children(&mut element_cursor)
+                .find(|c| c.kind() == "name")
+                .map(|n| ctx.node_text(&n).to_string())
+                .unwrap_or_default();
+            if name.is_empty() {
+                continue;
+            }
+            let node_id = id_gen.next_node();
+            let fqn = self.make_fqn(ctx, &name);
+            let ir_node = self.new_node(ctx, node_id, NodeKind::Constant, fqn, name, &element);
+            result.add_node(ir_node);
+        }
+    }
+
+    /// Extract `use SomeTrait;` inside a class body → IMPLEMENTS edge (trait mixin)
+    fn extract_trait_use(
+        &self,
+        ctx: &ExtractionContext,
+        node: &TSNode,
+        result: &mut ExtractionResult,
+    ) {
+        let Some(ref class_id) = ctx.parent_id else {
+            return;
+        };
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            if matches!(child.kind(), "name" | "qualified_name") {
+                result.add_edge(
+                    Edge::new(
+                        class_id.clone(),
+                        Self::normalize_name(ctx.node_text(&child)),
+                        EdgeKind::Implements,
+                    )
+                    .with_span(child.to_span()),
+                );
+            }
+        }
+    }
+
+    /// Extract `case Active;` inside an enum
+    fn extract_enum_case(
+        &self,
+        ctx: &ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let name = node
+            .child_by_field_name("name")
+            .map(|n| ctx.node_text(&n).to_string())
+            .unwrap_or_default();
+        if name.is_empty() {
+            return;
+        }
+        let node_id = id_gen.next_node();
+        let fqn = self.make_fqn(ctx, &name);
+        let ir_node = self.new_node(ctx, node_id, NodeKind::EnumMember, fqn, name, node);
+        result.add_node(ir_node);
+    }
+
+    /// Extract function / method / static call → CALLS edge from enclosing scope
+    fn extract_call(
+        &self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let callee = match node.kind() {
+            "function_call_expression" => node
+                .child_by_field_name("function")
+                .map(|f| Self::normalize_name(ctx.node_text(&f))),
+            _ => {
+                let receiver = node
+                    .child_by_field_name("object")
+                    .or_else(|| node.child_by_field_name("scope"));
+                node.child_by_field_name("name").map(|name| {
+                    let method = ctx.node_text(&name).to_string();
+                    match receiver {
+                        // Only keep simple receivers: `$this->save()`, `User::find()`
+                        Some(r)
+                            if matches!(
+                                r.kind(),
+                                "variable_name" | "name" | "qualified_name" | "relative_scope"
+                            ) =>
+                        {
+                            format!("{}.{}", Self::normalize_name(ctx.node_text(&r)), method)
+                        }
+                        _ => method,
+                    }
+                })
+            }
+        };
+
+        if let (Some(callee), Some(parent)) = (callee, ctx.parent_id.clone()) {
+            if !callee.is_empty() {
+                result
+                    .add_edge(Edge::new(parent, callee, EdgeKind::Calls).with_span(node.to_span()));
+            }
+        }
+
+        // Receivers and arguments may contain nested calls: `$a->b(foo())`
+        self.extract_children(ctx, node, id_gen, result);
+    }
+
+    /// Extract `new Foo(...)` → INSTANTIATES edge
+    fn extract_new(
+        &self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let mut cursor = node.walk();
+        let class_name = node
+            .named_children(&mut cursor)
+            .find(|c| matches!(c.kind(), "name" | "qualified_name"));
+        if let (Some(class_name), Some(parent)) = (class_name, ctx.parent_id.clone()) {
+            result.add_edge(
+                Edge::new(
+                    parent,
+                    Self::normalize_name(ctx.node_text(&class_name)),
+                    EdgeKind::Instantiates,
+                )
+                .with_span(node.to_span()),
+            );
+        }
+
+        self.extract_children(ctx, node, id_gen, result);
+    }
+}
+
+impl Default for PhpPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LanguagePlugin for PhpPlugin {
+    fn tree_sitter_language(&self) -> TSLanguage {
+        tree_sitter_php::language_php()
+    }
+
+    fn language_id(&self) -> LanguageId {
+        LanguageId::Php
+    }
+
+    fn capabilities(&self) -> PluginCapabilities {
+        // No dedicated variable extractor yet (DFG/SSA would use the Python fallback)
+        PluginCapabilities {
+            data_flow: false,
+            ..PluginCapabilities::full()
+        }
+    }
+
+    fn grammar_version(&self) -> Option<&'static str> {
+        Some("tree-sitter-php 0.22")
+    }
+
+    fn expected_node_kinds(&self) -> &[&str] {
+        &[
+            "class_declaration",
+            "interface_declaration",
+            "trait_declaration",
+            "function_definition",
+            "method_declaration",
+        ]
+    }
+
+    fn map_node_kind(&self, ts_kind: &str) -> Option<NodeKind> {
+        match ts_kind {
+            "class_declaration" => Some(NodeKind::Class),
+            "interface_declaration" => Some(NodeKind::Interface),
+            "trait_declaration" => Some(NodeKind::Trait),
+            "enum_declaration" => Some(NodeKind::Enum),
+            "enum_case" => Some(NodeKind::EnumMember),
+            "function_definition" => Some(NodeKind::Function),
+            "method_declaration" => Some(NodeKind::Method),
+            "property_element" => Some(NodeKind::Field),
+            "const_element" => Some(NodeKind::Constant),
+            "simple_parameter" | "variadic_parameter" | "property_promotion_parameter" => {
+                Some(NodeKind::Parameter)
+            }
+            "namespace_use_clause"
+            | "require_expression"
+            | "require_once_expression"
+            | "include_expression"
+            | "include_once_expression" => Some(NodeKind::Import),
+            "anonymous_function_creation_expression" | "arrow_function" => Some(NodeKind::Lambda),
+            _ => None,
+        }
+    }
+
+    fn map_syntax_kind(&self, ts_kind: &str) -> SyntaxKind {
+        match ts_kind {
+            "function_definition" | "method_declaration" => SyntaxKind::FunctionDef,
+            "class_declaration"
+            | "interface_declaration"
+            | "trait_declaration"
+            | "enum_declaration" => SyntaxKind::ClassDef,
+            "property_declaration" => SyntaxKind::FieldDecl,
+            "simple_parameter" => SyntaxKind::ParameterDecl,
+            "namespace_use_declaration" => SyntaxKind::ImportDecl,
+            "assignment_expression" => SyntaxKind::AssignmentStmt,
+            "function_call_expression" | "member_call_expression" | "scoped_call_expression" => {
+                SyntaxKind::CallExpr
+            }
+            "variable_name" | "name" => SyntaxKind::NameExpr,
+            "member_access_expression" => SyntaxKind::AttributeExpr,
+            "integer" | "float" | "string" | "encapsed_string" | "boolean" | "null" => {
+                SyntaxKind::LiteralExpr
+            }
+            "binary_expression" => SyntaxKind::BinaryExpr,
+            "unary_op_expression" => SyntaxKind::UnaryExpr,
+            "return_statement" => SyntaxKind::ReturnStmt,
+            "if_statement" => SyntaxKind::IfStmt,
+            "for_statement" | "foreach_statement" => SyntaxKind::ForStmt,
+            "while_statement" | "do_statement" => SyntaxKind::WhileStmt,
+            "try_statement" => SyntaxKind::TryStmt,
+            "break_statement" => SyntaxKind::BreakStmt,
+            "continue_statement" => SyntaxKind::ContinueStmt,
+            "throw_expression" | "throw_statement" => SyntaxKind::RaiseStmt,
+            "compound_statement" | "program" => SyntaxKind::Block,
+            "comment" => SyntaxKind::Comment,
+            "anonymous_function_creation_expression" | "arrow_function" => SyntaxKind::LambdaDef,
+            other => SyntaxKind::Other(other.to_string()),
+        }
+    }
+
+    fn extract(&self, ctx: &mut ExtractionContext, tree: &Tree) -> Result<ExtractionResult> {
+        let mut result = ExtractionResult::new();
+        let mut id_gen = IdGenerator::new(format!("{}:{}", ctx.repo_id, ctx.file_path));
+
+        // Create file node
+        let file_node_id = id_gen.next_node();
+        let file_node = Node::new(
+            file_node_id.clone(),
+            NodeKind::File,
+            ctx.file_path.to_string(),
+            ctx.file_path.to_string(),
+            tree.root_node().to_span(),
+        )
+        .with_language(ctx.language.name().to_string())
+        .with_name(ctx.file_path.to_string());
+
+        result.add_node(file_node);
+        ctx.parent_id = Some(file_node_id);
+
+        // `namespace Foo;` applies to the rest of the file (until the next one);
+        // `namespace Foo { ... }` only to its block
+        let mut in_file_namespace = false;
+        let root = tree.root_node();
+        let mut cursor = root.walk();
+        for child in root.children(&mut cursor) {
+            if child.kind() != "namespace_definition" {
+                self.extract_node(ctx, &child, &mut id_gen, &mut result);
+                continue;
+            }
+
+            let name = child
+                .child_by_field_name("name")
+                .map(|n| Self::normalize_name(ctx.node_text(&n)))
+                .unwrap_or_default();
+            if in_file_namespace {
+                ctx.pop_scope();
+                in_file_namespace = false;
+            }
+
+            match child.child_by_field_name("body") {
+                Some(body) => {
+                    if !name.is_empty() {
+                        ctx.push_scope(&name);
+                    }
+                    self.extract_children(ctx, &body, &mut id_gen, &mut result);
+                    if !name.is_empty() {
+                        ctx.pop_scope();
+                    }
+                }
+                None if !name.is_empty() => {
+                    ctx.push_scope(&name);
+                    in_file_namespace = true;
+                }
+                None => {}
+            }
+        }
+        if in_file_namespace {
+            ctx.pop_scope();
+        }
+
+        Ok(result)
+    }
+
+    fn comment_patterns(&self) -> &[&str] {
+        &["//", "#", "/*"]
+    }
+
+    fn is_public(&self, _name: &str) -> bool {
+        // PHP: visibility comes from modifiers (recorded on the node), not naming
+        true
+    }
+
+    fn extract_docstring(&self, node: &TSNode, source: &str) -> Option<String> {
+        // PHPDoc: `/** ... */` directly above the declaration
+        let prev = node.prev_sibling()?;
+        if prev.kind() != "comment" {
+            return None;
+        }
+        let text = source.get(prev.byte_range())?;
+        if !text.starts_with("/**") {
+            return None;
+        }
+        Some(
+            text.trim_start_matches("/**")
+                .trim_end_matches("*/")
+                .lines()
+                .map(|l| l.trim().trim_start_matches('*').trim())
+                .filter(|l| !l.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    fn is_statement_node(&self, node: &TSNode) -> bool {
+        matches!(
+            node.kind(),
+            // Expression statements (assignments, calls, echo)
+            "expression_statement" | "echo_statement" | "unset_statement" |
+            // Control flow
+            "if_statement" | "switch_statement" | "for_statement" | "foreach_statement" |
+            "while_statement" | "do_statement" | "try_statement" |
+            // Other statements
+            "return_statement" | "break_statement" | "continue_statement" |
+            "global_declaration" | "function_static_declaration" |
+            // Declarations
+            "function_definition" | "class_declaration" | "const_declaration"
+        )
+    }
+
+    fn is_control_flow_node(&self, node: &TSNode) -> bool {
+        matches!(
+            node.kind(),
+            "if_statement"
+                | "switch_statement"
+                | "match_expression"
+                | "for_statement"
+                | "foreach_statement"
+                | "while_statement"
+                | "do_statement"
+                | "try_statement"
+        )
+    }
+
+    fn get_control_flow_type(&self, node: &TSNode) -> Option<ControlFlowType> {
+        match node.kind() {
+            "if_statement" => Some(ControlFlowType::If),
+            "for_statement" | "foreach_statement" | "while_statement" | "do_statement" => {
+                Some(ControlFlowType::Loop)
+            }
+            "switch_statement" | "match_expression" => Some(ControlFlowType::Match),
+            "try_statement" => Some(ControlFlowType::Try),
+            "return_statement" => Some(ControlFlowType::Return),
+            "break_statement" => Some(ControlFlowType::Break),
+            "continue_statement" => Some(ControlFlowType::Continue),
+            "throw_expression" | "throw_statement" => Some(ControlFlowType::Raise),
+            "yield_expression" => Some(ControlFlowType::Yield),
+            _ => None,
+        }
+    }
+
+    fn get_match_arms<'a>(&self, node: &TSNode<'a>) -> Vec<TSNode<'a>> {
+        let mut arms = Vec::new();
+        if let Some(body) = node.child_by_field_name("body") {
+            let mut cursor = body.walk();
+            for child in body.children(&mut cursor) {
+                if matches!(
+                    child.kind(),
+                    "case_statement"
+                        | "default_statement"
+                        | "match_conditional_expression"
+                        | "match_default_expression"
+                ) {
+                    arms.push(child);
+                }
+            }
+        }
+        arms
+    }
+
+    fn is_chained_condition(&self, node: &TSNode) -> bool {
+        matches!(node.kind(), "else_if_clause")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tree_sitter::Parser;
+
+    fn extract(source: &str, file: &str) -> ExtractionResult {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&tree_sitter_php::language_php())
+            .unwrap();
+        let tree = parser.parse(source, None).unwrap();
+        let plugin = PhpPlugin::new();
+        let mut ctx = ExtractionContext::new(source, file, "test-repo", LanguageId::Php);
+        plugin.extract(&mut ctx, &tree).unwrap()
+    }
+
+    #[test]
+    fn test_namespaced_class_with_methods() {
+        let source = r#"<?php
+namespace App\Http\Controllers;
+
+use App\Models\User;
+use Illuminate\Support\Facades\Log as Logger;
+
+class UserController extends Controller implements HasMiddleware
+{
+    private string $name = '';
+
+    public static function show(int $id): User
+    {
+        Logger::info('show');
+        return User::find($id);
+    }
+}
+"#;
+        let result = extract(source, "app/Http/Controllers/UserController.php");
+
+        let class = result
+            .nodes
+            .iter()
+            .find(|n| n.kind == NodeKind::Class)
+            .unwrap();
+        assert_eq!(class.fqn, "App.Http.Controllers.UserController");
+        assert_eq!(class.base_classes, Some(vec!["Controller".to_string()]));
+
+        let method = result
+            .nodes
+            .iter()
+            .find(|n| n.kind == NodeKind::Method)
+            .unwrap();
+        assert_eq!(method.fqn, "App.Http.Controllers.UserController.show");
+        assert_eq!(method.is_static, Some(true));
+        assert_eq!(method.parameters, Some(vec!["id".to_string()]));
+
+        assert!(result
+            .nodes
+            .iter()
+            .any(|n| n.kind == NodeKind::Field && n.name.as_deref() == Some("name")));
+
+        // Imports feed the cross-file resolver with dotted FQNs (+ alias)
+        assert!(result
+            .edges
+            .iter()
+            .any(|e| e.kind == EdgeKind::Imports && e.target_id == "App.Models.User"));
+        let aliased = result
+            .edges
+            .iter()
+            .find(|e| {
+                e.kind == EdgeKind::Imports && e.target_id == "Illuminate.Support.Facades.Log"
+            })
+            .unwrap();
+        assert_eq!(
+            aliased.metadata.as_ref().unwrap().alias.as_deref(),
+            Some("Logger")
+        );
+
+        assert!(result
+            .edges
+            .iter()
+            .any(|e| e.kind == EdgeKind::Implements && e.target_id == "HasMiddleware"));
+        assert!(result
+            .edges
+            .iter()
+            .any(|e| e.kind == EdgeKind::Calls && e.target_id == "User.find"));
+    }
+
+    #[test]
+    fn test_functions_require_and_new() {
+        let source = r#"<?php
+require_once __DIR__ . '/bootstrap.php';
+
+function make_report($rows) {
+    $r = new Report($rows);
+    $r->render();
+    return count($rows);
+}
+"#;
+        let result = extract(source, "report.php");
+
+        let func = result
+            .nodes
+            .iter()
+            .find(|n| n.kind == NodeKind::Function)
+            .unwrap();
+        assert_eq!(func.fqn, "make_report");
+
+        assert!(result
+            .edges
+            .iter()
+            .any(|e| e.kind == EdgeKind::Imports && e.target_id == "bootstrap.php"));
+        assert!(result
+            .edges
+            .iter()
+            .any(|e| e.kind == EdgeKind::Instantiates && e.target_id == "Report"));
+        assert!(result
+            .edges
+            .iter()
+            .any(|e| e.kind == EdgeKind::Calls && e.target_id == "r.render"));
+        assert!(result
+            .edges
+            .iter()
+            .any(|e| e.kind == EdgeKind::Calls && e.target_id == "count"));
+    }
+}
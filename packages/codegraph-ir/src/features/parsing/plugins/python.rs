@@ -5,8 +5,10 @@
 use tree_sitter::{Language as TSLanguage, Node as TSNode, Tree};
 
 use crate::features::parsing::domain::SyntaxKind;
+use crate::features::parsing::infrastructure::extractors::call::{async_spawn_kind, SpawnKind};
 use crate::features::parsing::ports::{
-    ExtractionContext, ExtractionResult, IdGenerator, LanguageId, LanguagePlugin, SpanExt,
+    ExtractionContext, ExtractionResult, IdGenerator, LanguageId, LanguagePlugin,
+    PluginCapabilities, SpanExt,
 };
 use crate::shared::models::{Edge, EdgeKind, Node, NodeKind, Result};
 
@@ -384,6 +386,35 @@ impl PythonPlugin {
         }
     }
 
+    /// FQN of a called name: locally defined, else via `FqnResolver`
+    fn resolve_callee(
+        &self,
+        ctx: &ExtractionContext,
+        result: &ExtractionResult,
+        callee_name: &str,
+    ) -> String {
+        use crate::features::parsing::infrastructure::extractors::fqn_resolver::FqnResolver;
+
+        // Check if it's a locally defined function
+        let local_fqn = if ctx.fqn_prefix().is_empty() {
+            callee_name.to_string()
+        } else {
+            format!("{}.{}", ctx.fqn_prefix(), callee_name)
+        };
+
+        let is_local = result.nodes.iter().any(|n| {
+            n.name.as_deref() == Some(callee_name) || n.fqn == callee_name || n.fqn == local_fqn
+        });
+
+        if is_local {
+            // Local function: use module.function
+            local_fqn
+        } else {
+            // Not local: resolve via FqnResolver (built-ins, stdlib, external)
+            FqnResolver::new().resolve(callee_name)
+        }
+    }
+
     /// Extract function call with FQN resolution
     fn extract_call(
         &self,
@@ -392,45 +423,42 @@ impl PythonPlugin {
         id_gen: &mut IdGenerator,
         result: &mut ExtractionResult,
     ) {
-        use crate::features::parsing::infrastructure::extractors::fqn_resolver::FqnResolver;
-
         // Get the function being called
         let function_node = call_node.child_by_field_name("function");
         if let Some(function_node) = function_node {
             let callee_name = ctx.node_text(&function_node).to_string();
 
             if !callee_name.is_empty() && ctx.parent_id.is_some() {
-                // Check if it's a locally defined function
-                let local_fqn = if ctx.fqn_prefix().is_empty() {
-                    callee_name.clone()
-                } else {
-                    format!("{}.{}", ctx.fqn_prefix(), callee_name)
-                };
-
-                let is_local = result.nodes.iter().any(|n| {
-                    n.name.as_ref() == Some(&callee_name)
-                        || n.fqn == callee_name
-                        || n.fqn == local_fqn
-                });
-
-                let callee_fqn = if is_local {
-                    // Local function: use module.function
-                    local_fqn
-                } else {
-                    // Not local: resolve via FqnResolver (built-ins, stdlib, external)
-                    let fqn_resolver = FqnResolver::new();
-                    fqn_resolver.resolve(&callee_name)
-                };
+                let callee_fqn = self.resolve_callee(ctx, result, &callee_name);
 
                 // Create CALLS edge from current function/method to the callee
                 // SAFETY: parent_id is guaranteed to be Some by the check on line 405
-                let edge = Edge::new(
-                    ctx.parent_id.as_ref().unwrap().clone(),
-                    callee_fqn.clone(),
-                    EdgeKind::Calls,
-                );
-
+                let caller_id = ctx.parent_id.as_ref().unwrap().clone();
+                let mut edge = Edge::new(caller_id.clone(), callee_fqn.clone(), EdgeKind::Calls);
+                if self.is_async_call(ctx, call_node) {
+                    edge = edge.with_is_async(true);
+                }
                 result.add_edge(edge);
+
+                // `loop.call_soon(cb)`: the callback runs later on the event loop
+                if let (Some(SpawnKind::Callback(index)), Some(args)) = (
+                    async_spawn_kind(&callee_name),
+                    call_node.child_by_field_name("arguments"),
+                ) {
+                    let mut cursor = args.walk();
+                    let callback = args
+                        .named_children(&mut cursor)
+                        .filter(|c| c.kind() != "comment")
+                        .nth(index)
+                        .filter(|c| matches!(c.kind(), "identifier" | "attribute"));
+                    if let Some(callback) = callback {
+                        let name = ctx.node_text(&callback).to_string();
+                        let callback_fqn = self.resolve_callee(ctx, result, &name);
+                        result.add_edge(
+                            Edge::new(caller_id, callback_fqn, EdgeKind::Calls).with_is_async(true),
+                        );
+                    }
+                }
             }
         }
 
@@ -441,6 +469,36 @@ impl PythonPlugin {
         }
     }
 
+    /// Whether `call_node` is awaited or handed to a coroutine spawner
+    /// (`asyncio.gather(a(), *[b(x) for x in xs])`)
+    fn is_async_call(&self, ctx: &ExtractionContext, call_node: &TSNode) -> bool {
+        let mut child = *call_node;
+        while let Some(parent) = child.parent() {
+            match parent.kind() {
+                "await" => return true,
+                "argument_list" | "list" | "tuple" | "list_splat" | "parenthesized_expression" => {}
+                "list_comprehension" | "generator_expression" | "set_comprehension" => {
+                    if parent.child_by_field_name("body").map(|b| b.id()) != Some(child.id()) {
+                        return false;
+                    }
+                }
+                "call" => {
+                    // `child` is the argument list, or a bare generator argument
+                    let is_argument =
+                        parent.child_by_field_name("arguments").map(|a| a.id()) == Some(child.id());
+                    return is_argument
+                        && parent
+                            .child_by_field_name("function")
+                            .and_then(|f| async_spawn_kind(ctx.node_text(&f)))
+                            == Some(SpawnKind::Coroutine);
+                }
+                _ => return false,
+            }
+            child = parent;
+        }
+        false
+    }
+
     /// Extract function body (recursive)
     fn extract_body(
         &self,
@@ -874,6 +932,25 @@ impl LanguagePlugin for PythonPlugin {
         LanguageId::Python
     }
 
+    fn capabilities(&self) -> PluginCapabilities {
+        PluginCapabilities::full()
+    }
+
+    fn grammar_version(&self) -> Option<&'static str> {
+        Some("tree-sitter-python 0.21")
+    }
+
+    fn expected_node_kinds(&self) -> &[&str] {
+        &[
+            "function_definition",
+            "class_definition",
+            "decorated_definition",
+            "import_statement",
+            "import_from_statement",
+            "assignment",
+        ]
+    }
+
     fn map_node_kind(&self, ts_kind: &str) -> Option<NodeKind> {
         match ts_kind {
             "function_definition" => Some(NodeKind::Function),
@@ -1226,4 +1303,44 @@ from pathlib import Path
             .collect();
         assert_eq!(imports.len(), 2);
     }
+
+    #[test]
+    fn test_extract_async_calls() {
+        let source = r#"
+async def main(loop):
+    def tick():
+        pass
+
+    await fetch()
+    await asyncio.gather(a(), b())
+    loop.call_soon(tick)
+    log()
+"#;
+        let tree = parse_python(source);
+        let plugin = PythonPlugin::new();
+        let mut ctx = ExtractionContext::new(source, "test.py", "test-repo", LanguageId::Python);
+
+        let result = plugin.extract(&mut ctx, &tree).unwrap();
+
+        let is_async = |fqn: &str| {
+            result
+                .edges
+                .iter()
+                .filter(|e| e.kind == EdgeKind::Calls)
+                .find(|e| e.target_id == fqn)
+                .map(|e| e.is_async())
+        };
+        for fqn in [
+            "external.fetch",
+            "asyncio.gather",
+            "external.a",
+            "external.b",
+            // Local callback, resolved like a direct call
+            "main.tick",
+        ] {
+            assert_eq!(is_async(fqn), Some(true), "{}", fqn);
+        }
+        assert_eq!(is_async("loop.call_soon"), Some(false));
+        assert_eq!(is_async("external.log"), Some(false));
+    }
 }
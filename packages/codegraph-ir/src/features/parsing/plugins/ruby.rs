@@ -0,0 +1,869 @@
+//! Ruby Language Plugin
+//!
+//! Implements LanguagePlugin for Ruby source code.
+//! Supports: modules, classes, methods, singleton methods (`def self.x`),
+//! constants, `attr_*` fields, mixins (`include`/`extend`/`prepend`),
+//! `require`/`require_relative`/`load`, calls, `Foo.new`
+//!
+//! Scope resolution (`Foo::Bar`) maps to the IR's dotted FQN form (`Foo.Bar`).
+//! Require targets are emitted as dotted file paths (`app/models/user` →
+//! `app.models.user`) so the cross-file resolver can map them to `.rb` files.
+
+use tree_sitter::{Language as TSLanguage, Node as TSNode, Tree};
+
+use crate::features::parsing::domain::SyntaxKind;
+use crate::features::parsing::ports::{
+    ControlFlowType, ExceptionHandlers, ExtractionContext, ExtractionResult, IdGenerator,
+    LanguageId, LanguagePlugin, PluginCapabilities, SpanExt,
+};
+use crate::shared::models::{Edge, EdgeKind, Node, NodeKind, Result};
+
+/// Ruby language plugin
+pub struct RubyPlugin;
+
+impl RubyPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Normalize Ruby constant paths to dotted FQN form (`::Foo::Bar` → `Foo.Bar`)
+    fn normalize_name(name: &str) -> String {
+        name.replace("::", ".")
+            .replace('@', "")
+            .trim_matches('.')
+            .to_string()
+    }
+
+    /// Map a require path to the dotted module form (`app/models/user.rb` → `app.models.user`)
+    fn require_target(path: &str) -> String {
+        path.trim_end_matches(".rb").replace('/', ".")
+    }
+
+    /// Build FQN from current scope
+    fn make_fqn(&self, ctx: &ExtractionContext, name: &str) -> String {
+        if ctx.fqn_prefix().is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", ctx.fqn_prefix(), name)
+        }
+    }
+
+    /// `ExtractionContext::new_node` with the node's doc comment
+    fn new_node(
+        &self,
+        ctx: &ExtractionContext,
+        node_id: String,
+        kind: NodeKind,
+        fqn: String,
+        name: String,
+        node: &TSNode,
+    ) -> Node {
+        let ir_node = ctx.new_node(node_id, kind, fqn, name, node);
+        match self.extract_docstring(node, ctx.source) {
+            Some(doc) => ir_node.with_docstring(doc),
+            None => ir_node,
+        }
+    }
+
+    /// Extract a single node
+    fn extract_node(
+        &self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+        in_class: bool,
+    ) {
+        match node.kind() {
+            "class" | "module" => self.extract_class_or_module(ctx, node, id_gen, result),
+            "method" | "singleton_method" => {
+                self.extract_method(ctx, node, id_gen, result, in_class)
+            }
+            "assignment" => self.extract_assignment(ctx, node, id_gen, result, in_class),
+            "call" => self.extract_call(ctx, node, id_gen, result, in_class),
+            // Bare command without receiver/arguments: `helper`
+            "identifier" if Self::is_bare_call(node) => {
+                if let Some(ref parent) = ctx.parent_id {
+                    result.add_edge(
+                        Edge::new(
+                            parent.clone(),
+                            ctx.node_text(node).to_string(),
+                            EdgeKind::Calls,
+                        )
+                        .with_span(node.to_span()),
+                    );
+                }
+            }
+            _ => self.extract_children(ctx, node, id_gen, result, in_class),
+        }
+    }
+
+    fn extract_children(
+        &self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+        in_class: bool,
+    ) {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.extract_node(ctx, &child, id_gen, result, in_class);
+        }
+    }
+
+    /// An identifier statement inside a method body is a zero-arg self call
+    fn is_bare_call(node: &TSNode) -> bool {
+        node.parent()
+            .map(|p| {
+                matches!(
+                    p.kind(),
+                    "body_statement" | "then" | "else" | "do" | "method" | "singleton_method"
+                )
+            })
+            .unwrap_or(false)
+    }
+
+    /// Extract `class Foo < Bar` / `module Foo`
+    fn extract_class_or_module(
+        &self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let Some(name_node) = node.child_by_field_name("name") else {
+            return;
+        };
+        // `class Admin::User` nests under `Admin`
+        let path = Self::normalize_name(ctx.node_text(&name_node));
+        if path.is_empty() {
+            return;
+        }
+        let name = path.rsplit('.').next().unwrap_or(&path).to_string();
+
+        let kind = if node.kind() == "module" {
+            NodeKind::Module
+        } else {
+            NodeKind::Class
+        };
+
+        let node_id = id_gen.next_node();
+        let fqn = self.make_fqn(ctx, &path);
+        let mut ir_node = self.new_node(ctx, node_id.clone(), kind, fqn, name, node);
+
+        if let Some(superclass) = node.child_by_field_name("superclass") {
+            let mut cursor = superclass.walk();
+            let base = superclass
+                .named_children(&mut cursor)
+                .find(|c| matches!(c.kind(), "constant" | "scope_resolution"));
+            if let Some(base) = base {
+                let base_name = Self::normalize_name(ctx.node_text(&base));
+                ir_node.base_classes = Some(vec![base_name.clone()]);
+                result.add_edge(
+                    Edge::new(node_id.clone(), base_name, EdgeKind::Inherits)
+                        .with_span(base.to_span()),
+                );
+            }
+        }
+        result.add_node(ir_node);
+
+        let old_parent = ctx.parent_id.take();
+        ctx.parent_id = Some(node_id);
+        ctx.push_scope(&path);
+
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            if Some(child) == node.child_by_field_name("name")
+                || Some(child) == node.child_by_field_name("superclass")
+            {
+                continue;
+            }
+            self.extract_node(ctx, &child, id_gen, result, true);
+        }
+
+        ctx.pop_scope();
+        ctx.parent_id = old_parent;
+    }
+
+    /// Extract `def foo(a, b = 1)` / `def self.build`
+    fn extract_method(
+        &self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+        in_class: bool,
+    ) {
+        let name = node
+            .child_by_field_name("name")
+            .map(|n| ctx.node_text(&n).to_string())
+            .unwrap_or_default();
+        if name.is_empty() {
+            return;
+        }
+
+        let is_singleton = node.kind() == "singleton_method";
+        let kind = if in_class || is_singleton {
+            NodeKind::Method
+        } else {
+            NodeKind::Function
+        };
+
+        let node_id = id_gen.next_node();
+        let fqn = self.make_fqn(ctx, &name);
+        let mut ir_node = self.new_node(ctx, node_id.clone(), kind, fqn, name.clone(), node);
+        if is_singleton {
+            ir_node.is_static = Some(true);
+        }
+
+        let params = node
+            .child_by_field_name("parameters")
+            .map(|p| self.parameter_names(ctx, &p))
+            .unwrap_or_default();
+        if !params.is_empty() {
+            ir_node.parameters = Some(params);
+        }
+        result.add_node(ir_node);
+
+        let old_parent = ctx.parent_id.take();
+        ctx.parent_id = Some(node_id.clone());
+        ctx.push_scope(&name);
+
+        if let Some(params) = node.child_by_field_name("parameters") {
+            self.extract_parameters(ctx, &params, id_gen, result, &node_id);
+        }
+        // Body statements (wrapped in `body_statement` or direct, depending on grammar version)
+        let skip = [
+            node.child_by_field_name("name"),
+            node.child_by_field_name("parameters"),
+            node.child_by_field_name("object"),
+        ];
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            if !skip.contains(&Some(child)) {
+                self.extract_node(ctx, &child, id_gen, result, false);
+            }
+        }
+
+        ctx.pop_scope();
+        ctx.parent_id = old_parent;
+    }
+
+    /// Parameter name (plain, optional, keyword, splat, block)
+    fn parameter_name(&self, ctx: &ExtractionContext, param: &TSNode) -> Option<String> {
+        let name = match param.kind() {
+            "identifier" => ctx.node_text(param).to_string(),
+            "optional_parameter"
+            | "keyword_parameter"
+            | "splat_parameter"
+            | "hash_splat_parameter"
+            | "block_parameter" => ctx
+                .node_text(&param.child_by_field_name("name")?)
+                .to_string(),
+            _ => return None,
+        };
+        (!name.is_empty()).then_some(name)
+    }
+
+    fn parameter_names(&self, ctx: &ExtractionContext, params: &TSNode) -> Vec<String> {
+        let mut cursor = params.walk();
+        let names = params
+            .named_children(&mut cursor)
+            .filter_map(|p| self.parameter_name(ctx, &p))
+            .collect();
+        names
+    }
+
+    /// Extract method parameters
+    fn extract_parameters(
+        &self,
+        ctx: &ExtractionContext,
+        params_node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+        func_id: &str,
+    ) {
+        let mut cursor = params_node.walk();
+        for param in params_node.named_children(&mut cursor) {
+            let Some(name) = self.parameter_name(ctx, &param) else {
+                continue;
+            };
+
+            let node_id = id_gen.next_node();
+            let fqn = self.make_fqn(ctx, &name);
+            let mut ir_node = Node::new(
+                node_id,
+                NodeKind::Parameter,
+                fqn,
+                ctx.file_path.to_string(),
+                param.to_span(),
+            )
+            .with_language(ctx.language.name().to_string())
+            .with_name(name);
+
+            ir_node.parent_id = Some(func_id.to_string());
+            result.add_node(ir_node);
+        }
+    }
+
+    /// Extract `MAX_RETRIES = 3` → Constant (other assignments only walk the value)
+    fn extract_assignment(
+        &self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+        in_class: bool,
+    ) {
+        if let Some(left) = node.child_by_field_name("left") {
+            if left.kind() == "constant" {
+                let name = ctx.node_text(&left).to_string();
+                let node_id = id_gen.next_node();
+                let fqn = self.make_fqn(ctx, &name);
+                let ir_node = self.new_node(ctx, node_id, NodeKind::Constant, fqn, name, node);
+                result.add_node(ir_node);
+            }
+        }
+        if let Some(right) = node.child_by_field_name("right") {
+            self.extract_node(ctx, &right, id_gen, result, in_class);
+        }
+    }
+
+    /// Extract calls, including the DSL-style ones that declare structure
+    fn extract_call(
+        &self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+        in_class: bool,
+    ) {
+        let method = node
+            .child_by_field_name("method")
+            .map(|m| ctx.node_text(&m).to_string())
+            .unwrap_or_default();
+        let receiver = node.child_by_field_name("receiver");
+
+        if receiver.is_none() {
+            match method.as_str() {
+                "require" | "require_relative" | "load" => {
+                    if self.extract_require(ctx, node, &method, id_gen, result) {
+                        return;
+                    }
+                }
+                "include" | "extend" | "prepend" if in_class => {
+                    self.extract_mixin(ctx, node, result);
+                    return;
+                }
+                "attr_accessor" | "attr_reader" | "attr_writer" if in_class => {
+                    self.extract_attrs(ctx, node, id_gen, result);
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(ref parent) = ctx.parent_id {
+            let edge = match receiver {
+                // `User.new` → INSTANTIATES User
+                Some(r)
+                    if method == "new" && matches!(r.kind(), "constant" | "scope_resolution") =>
+                {
+                    Some(Edge::new(
+                        parent.clone(),
+                        Self::normalize_name(ctx.node_text(&r)),
+                        EdgeKind::Instantiates,
+                    ))
+                }
+                // Only keep simple receivers: `user.save`, `User.find`, `self.log`
+                Some(r)
+                    if matches!(
+                        r.kind(),
+                        "identifier"
+                            | "constant"
+                            | "scope_resolution"
+                            | "self"
+                            | "instance_variable"
+                    ) =>
+                {
+                    Some(Edge::new(
+                        parent.clone(),
+                        format!("{}.{}", Self::normalize_name(ctx.node_text(&r)), method),
+                        EdgeKind::Calls,
+                    ))
+                }
+                _ if !method.is_empty() => {
+                    Some(Edge::new(parent.clone(), method.clone(), EdgeKind::Calls))
+                }
+                _ => None,
+            };
+            if let Some(edge) = edge {
+                result.add_edge(edge.with_span(node.to_span()));
+            }
+        }
+
+        // Receivers, arguments and blocks may contain nested calls
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            if Some(child) != node.child_by_field_name("method") {
+                self.extract_node(ctx, &child, id_gen, result, in_class);
+            }
+        }
+    }
+
+    /// First string literal argument of a call (`require 'json'`)
+    fn string_argument(&self, ctx: &ExtractionContext, node: &TSNode) -> Option<String> {
+        let args = node.child_by_field_name("arguments")?;
+        let mut cursor = args.walk();
+        let string = args
+            .named_children(&mut cursor)
+            .find(|c| c.kind() == "string")?;
+        let mut string_cursor = string.walk();
+        let content = string
+            .named_children(&mut string_cursor)
+            .find(|c| c.kind() == "string_content")?;
+        Some(ctx.node_text(&content).to_string())
+    }
+
+    /// Extract `require`/`require_relative`/`load` → Import node + IMPORTS edge
+    ///
+    /// Returns false for dynamic requires (interpolated or computed paths).
+    fn extract_require(
+        &self,
+        ctx: &ExtractionContext,
+        node: &TSNode,
+        method: &str,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) -> bool {
+        let Some(path) = self.string_argument(ctx, node) else {
+            return false;
+        };
+        let path = if method == "require_relative" {
+            ctx.resolve_relative_path(&path)
+        } else {
+            path
+        };
+        let target = Self::require_target(&path);
+        if target.is_empty() {
+            return false;
+        }
+
+        let node_id = id_gen.next_node();
+        let mut ir_node = self.new_node(
+            ctx,
+            node_id,
+            NodeKind::Import,
+            format!("require:{}", path),
+            path,
+            node,
+        );
+        ir_node.metadata = serde_json::to_string(&serde_json::json!({
+            "require_kind": method,
+        }))
+        .ok();
+        result.add_node(ir_node);
+
+        if let Some(ref parent) = ctx.parent_id {
+            result.add_edge(
+                Edge::new(parent.clone(), target, EdgeKind::Imports).with_span(node.to_span()),
+            );
+        }
+        true
+    }
+
+    /// Extract `include Comparable` → IMPLEMENTS edge (module mixin)
+    fn extract_mixin(&self, ctx: &ExtractionContext, node: &TSNode, result: &mut ExtractionResult) {
+        let (Some(class_id), Some(args)) = (
+            ctx.parent_id.as_ref(),
+            node.child_by_field_name("arguments"),
+        ) else {
+            return;
+        };
+        let mut cursor = args.walk();
+        for arg in args.named_children(&mut cursor) {
+            if matches!(arg.kind(), "constant" | "scope_resolution") {
+                result.add_edge(
+                    Edge::new(
+                        class_id.clone(),
+                        Self::normalize_name(ctx.node_text(&arg)),
+                        EdgeKind::Implements,
+                    )
+                    .with_span(arg.to_span()),
+                );
+            }
+        }
+    }
+
+    /// Extract `attr_accessor :name, :email` → Field nodes
+    fn extract_attrs(
+        &self,
+        ctx: &ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let Some(args) = node.child_by_field_name("arguments") else {
+            return;
+        };
+        let mut cursor = args.walk();
+        for arg in args.named_children(&mut cursor) {
+            if arg.kind() != "simple_symbol" {
+                continue;
+            }
+            let name = ctx.node_text(&arg).trim_start_matches(':').to_string();
+            if name.is_empty() {
+                continue;
+            }
+            let node_id = id_gen.next_node();
+            let fqn = self.make_fqn(ctx, &name);
+            let ir_node = self.new_node(ctx, node_id, NodeKind::Field, fqn, name, &arg);
+            result.add_node(ir_node);
+        }
+    }
+}
+
+impl Default for RubyPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LanguagePlugin for RubyPlugin {
+    fn tree_sitter_language(&self) -> TSLanguage {
+        tree_sitter_ruby::language()
+    }
+
+    fn language_id(&self) -> LanguageId {
+        LanguageId::Ruby
+    }
+
+    fn capabilities(&self) -> PluginCapabilities {
+        // No dedicated variable extractor yet (DFG/SSA would use the Python fallback)
+        PluginCapabilities {
+            data_flow: false,
+            ..PluginCapabilities::full()
+        }
+    }
+
+    fn grammar_version(&self) -> Option<&'static str> {
+        Some("tree-sitter-ruby 0.21")
+    }
+
+    fn expected_node_kinds(&self) -> &[&str] {
+        &["class", "module", "method", "singleton_method", "call"]
+    }
+
+    fn map_node_kind(&self, ts_kind: &str) -> Option<NodeKind> {
+        match ts_kind {
+            "class" | "singleton_class" => Some(NodeKind::Class),
+            "module" => Some(NodeKind::Module),
+            "method" | "singleton_method" => Some(NodeKind::Method),
+            "optional_parameter"
+            | "keyword_parameter"
+            | "splat_parameter"
+            | "hash_splat_parameter"
+            | "block_parameter" => Some(NodeKind::Parameter),
+            "lambda" | "block" | "do_block" => Some(NodeKind::Lambda),
+            _ => None,
+        }
+    }
+
+    fn map_syntax_kind(&self, ts_kind: &str) -> SyntaxKind {
+        match ts_kind {
+            "method" | "singleton_method" => SyntaxKind::MethodDef,
+            "class" | "module" | "singleton_class" => SyntaxKind::ClassDef,
+            "assignment" | "operator_assignment" => SyntaxKind::AssignmentStmt,
+            "call" => SyntaxKind::CallExpr,
+            "identifier" | "constant" | "instance_variable" => SyntaxKind::NameExpr,
+            "scope_resolution" => SyntaxKind::AttributeExpr,
+            "integer" | "float" | "string" | "simple_symbol" | "true" | "false" | "nil" => {
+                SyntaxKind::LiteralExpr
+            }
+            "binary" => SyntaxKind::BinaryExpr,
+            "unary" => SyntaxKind::UnaryExpr,
+            "return" => SyntaxKind::ReturnStmt,
+            "if" | "unless" | "if_modifier" | "unless_modifier" => SyntaxKind::IfStmt,
+            "for" => SyntaxKind::ForStmt,
+            "while" | "until" | "while_modifier" | "until_modifier" => SyntaxKind::WhileStmt,
+            "begin" => SyntaxKind::TryStmt,
+            "break" => SyntaxKind::BreakStmt,
+            "next" => SyntaxKind::ContinueStmt,
+            "yield" => SyntaxKind::YieldExpr,
+            "body_statement" | "program" => SyntaxKind::Block,
+            "comment" => SyntaxKind::Comment,
+            "lambda" | "block" | "do_block" => SyntaxKind::LambdaDef,
+            other => SyntaxKind::Other(other.to_string()),
+        }
+    }
+
+    fn extract(&self, ctx: &mut ExtractionContext, tree: &Tree) -> Result<ExtractionResult> {
+        let mut result = ExtractionResult::new();
+        let mut id_gen = IdGenerator::new(format!("{}:{}", ctx.repo_id, ctx.file_path));
+
+        // Create file node
+        let file_node_id = id_gen.next_node();
+        let file_node = Node::new(
+            file_node_id.clone(),
+            NodeKind::File,
+            ctx.file_path.to_string(),
+            ctx.file_path.to_string(),
+            tree.root_node().to_span(),
+        )
+        .with_language(ctx.language.name().to_string())
+        .with_name(ctx.file_path.to_string());
+
+        result.add_node(file_node);
+        ctx.parent_id = Some(file_node_id);
+
+        let root = tree.root_node();
+        self.extract_children(ctx, &root, &mut id_gen, &mut result, false);
+
+        Ok(result)
+    }
+
+    fn comment_patterns(&self) -> &[&str] {
+        &["#", "=begin"]
+    }
+
+    fn extract_docstring(&self, node: &TSNode, source: &str) -> Option<String> {
+        // Consecutive `#` comment lines directly above the declaration
+        let mut lines = Vec::new();
+        let mut prev = node.prev_sibling();
+        while let Some(comment) = prev {
+            if comment.kind() != "comment" {
+                break;
+            }
+            let text = source.get(comment.byte_range())?;
+            lines.push(text.trim_start_matches('#').trim().to_string());
+            prev = comment.prev_sibling();
+        }
+        if lines.is_empty() {
+            return None;
+        }
+        lines.reverse();
+        Some(lines.join("\n"))
+    }
+
+    fn is_statement_node(&self, node: &TSNode) -> bool {
+        matches!(
+            node.kind(),
+            // Assignments and calls
+            "assignment" | "operator_assignment" | "call" |
+            // Control flow
+            "if" | "unless" | "while" | "until" | "for" | "case" | "case_match" | "begin" |
+            "if_modifier" | "unless_modifier" | "while_modifier" | "until_modifier" |
+            "rescue_modifier" |
+            // Other statements
+            "return" | "break" | "next" | "redo" | "retry" | "yield" |
+            // Declarations
+            "method" | "singleton_method" | "class" | "module" | "alias" | "undef"
+        )
+    }
+
+    fn is_control_flow_node(&self, node: &TSNode) -> bool {
+        matches!(
+            node.kind(),
+            "if" | "unless"
+                | "if_modifier"
+                | "unless_modifier"
+                | "while"
+                | "until"
+                | "while_modifier"
+                | "until_modifier"
+                | "for"
+                | "case"
+                | "case_match"
+                | "begin"
+        )
+    }
+
+    fn get_control_flow_type(&self, node: &TSNode) -> Option<ControlFlowType> {
+        match node.kind() {
+            "if" | "unless" | "if_modifier" | "unless_modifier" => Some(ControlFlowType::If),
+            "while" | "until" | "while_modifier" | "until_modifier" | "for" => {
+                Some(ControlFlowType::Loop)
+            }
+            "case" | "case_match" => Some(ControlFlowType::Match),
+            "begin" => Some(ControlFlowType::Try),
+            "return" => Some(ControlFlowType::Return),
+            "break" => Some(ControlFlowType::Break),
+            "next" => Some(ControlFlowType::Continue),
+            "yield" => Some(ControlFlowType::Yield),
+            // `raise` is a plain method call in Ruby, not a syntax node
+            _ => None,
+        }
+    }
+
+    fn get_match_arms<'a>(&self, node: &TSNode<'a>) -> Vec<TSNode<'a>> {
+        let mut arms = Vec::new();
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            if matches!(child.kind(), "when" | "in_clause" | "else") {
+                arms.push(child);
+            }
+        }
+        arms
+    }
+
+    fn get_loop_iterator<'a>(&self, node: &TSNode<'a>) -> Vec<TSNode<'a>> {
+        let mut result = Vec::new();
+        // for x in items / while cond
+        for field in ["pattern", "value", "condition"] {
+            if let Some(n) = node.child_by_field_name(field) {
+                result.push(n);
+            }
+        }
+        result
+    }
+
+    fn get_exception_handlers<'a>(&self, node: &TSNode<'a>) -> ExceptionHandlers<'a> {
+        let mut handlers = ExceptionHandlers::default();
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            match child.kind() {
+                "rescue" => handlers.catch_blocks.push(child),
+                "ensure" => handlers.finally_block = Some(child),
+                _ => {}
+            }
+        }
+        handlers
+    }
+
+    fn is_chained_condition(&self, node: &TSNode) -> bool {
+        node.kind() == "elsif"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tree_sitter::Parser;
+
+    fn extract(source: &str, file: &str) -> ExtractionResult {
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_ruby::language()).unwrap();
+        let tree = parser.parse(source, None).unwrap();
+        let plugin = RubyPlugin::new();
+        let mut ctx = ExtractionContext::new(source, file, "test-repo", LanguageId::Ruby);
+        plugin.extract(&mut ctx, &tree).unwrap()
+    }
+
+    #[test]
+    fn test_module_class_and_methods() {
+        let source = r#"
+module Billing
+  # Issues invoices for accounts
+  class Invoice < ApplicationRecord
+    include Comparable
+    attr_reader :total
+
+    MAX_LINES = 50
+
+    def self.build(account, lines = [])
+      new(account, lines)
+    end
+
+    def send_to(email)
+      Mailer.deliver(email, render)
+    end
+  end
+end
+"#;
+        let result = extract(source, "app/models/billing/invoice.rb");
+
+        let class = result
+            .nodes
+            .iter()
+            .find(|n| n.kind == NodeKind::Class)
+            .unwrap();
+        assert_eq!(class.fqn, "Billing.Invoice");
+        assert_eq!(
+            class.base_classes,
+            Some(vec!["ApplicationRecord".to_string()])
+        );
+        assert_eq!(
+            class.docstring.as_deref(),
+            Some("Issues invoices for accounts")
+        );
+
+        assert!(result
+            .nodes
+            .iter()
+            .any(|n| n.kind == NodeKind::Module && n.fqn == "Billing"));
+
+        let build = result
+            .nodes
+            .iter()
+            .find(|n| n.fqn == "Billing.Invoice.build")
+            .unwrap();
+        assert_eq!(build.kind, NodeKind::Method);
+        assert_eq!(build.is_static, Some(true));
+        assert_eq!(
+            build.parameters,
+            Some(vec!["account".to_string(), "lines".to_string()])
+        );
+
+        assert!(result
+            .nodes
+            .iter()
+            .any(|n| n.kind == NodeKind::Field && n.fqn == "Billing.Invoice.total"));
+        assert!(result
+            .nodes
+            .iter()
+            .any(|n| n.kind == NodeKind::Constant && n.fqn == "Billing.Invoice.MAX_LINES"));
+
+        assert!(result
+            .edges
+            .iter()
+            .any(|e| e.kind == EdgeKind::Implements && e.target_id == "Comparable"));
+        assert!(result
+            .edges
+            .iter()
+            .any(|e| e.kind == EdgeKind::Calls && e.target_id == "Mailer.deliver"));
+    }
+
+    #[test]
+    fn test_requires_and_instantiation() {
+        let source = r#"
+require 'json'
+require_relative '../models/user'
+
+def load_users(path)
+  data = JSON.parse(File.read(path))
+  data.map { |row| User.new(row) }
+end
+"#;
+        let result = extract(source, "app/services/loader.rb");
+
+        let func = result
+            .nodes
+            .iter()
+            .find(|n| n.kind == NodeKind::Function)
+            .unwrap();
+        assert_eq!(func.fqn, "load_users");
+
+        let imports: Vec<_> = result
+            .edges
+            .iter()
+            .filter(|e| e.kind == EdgeKind::Imports)
+            .map(|e| e.target_id.as_str())
+            .collect();
+        assert!(imports.contains(&"json"));
+        assert!(imports.contains(&"app.models.user"));
+
+        assert!(result
+            .edges
+            .iter()
+            .any(|e| e.kind == EdgeKind::Instantiates && e.target_id == "User"));
+        assert!(result
+            .edges
+            .iter()
+            .any(|e| e.kind == EdgeKind::Calls && e.target_id == "JSON.parse"));
+        assert!(result
+            .edges
+            .iter()
+            .any(|e| e.kind == EdgeKind::Calls && e.target_id == "File.read"));
+    }
+}
@@ -7,7 +7,8 @@ use tree_sitter::{Language as TSLanguage, Node as TSNode, Tree};
 
 use crate::features::parsing::domain::SyntaxKind;
 use crate::features::parsing::ports::{
-    ExtractionContext, ExtractionResult, IdGenerator, LanguageId, LanguagePlugin, SpanExt,
+    ExtractionContext, ExtractionResult, IdGenerator, LanguageId, LanguagePlugin,
+    PluginCapabilities, SpanExt,
 };
 use crate::shared::models::{Edge, EdgeKind, Node, NodeKind, Result};
 
@@ -1049,6 +1050,26 @@ impl LanguagePlugin for RustPlugin {
         LanguageId::Rust
     }
 
+    fn capabilities(&self) -> PluginCapabilities {
+        PluginCapabilities::full()
+    }
+
+    fn grammar_version(&self) -> Option<&'static str> {
+        Some("tree-sitter-rust 0.21")
+    }
+
+    fn expected_node_kinds(&self) -> &[&str] {
+        &[
+            "struct_item",
+            "enum_item",
+            "trait_item",
+            "impl_item",
+            "function_item",
+            "mod_item",
+            "use_declaration",
+        ]
+    }
+
     fn map_node_kind(&self, ts_kind: &str) -> Option<NodeKind> {
         match ts_kind {
             "struct_item" => Some(NodeKind::Struct),
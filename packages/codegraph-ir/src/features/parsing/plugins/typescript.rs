@@ -7,9 +7,10 @@ use tree_sitter::{Language as TSLanguage, Node as TSNode, Tree};
 
 use crate::features::parsing::domain::SyntaxKind;
 use crate::features::parsing::ports::{
-    ExtractionContext, ExtractionResult, IdGenerator, LanguageId, LanguagePlugin, SpanExt,
+    ExtractionContext, ExtractionResult, IdGenerator, LanguageId, LanguagePlugin,
+    PluginCapabilities, SpanExt,
 };
-use crate::shared::models::{Edge, EdgeKind, Node, NodeKind, Result};
+use crate::shared::models::{Edge, EdgeKind, EdgeMetadata, Node, NodeKind, Result};
 
 /// TypeScript language plugin
 pub struct TypeScriptPlugin {
@@ -398,6 +399,151 @@ impl TypeScriptPlugin {
         if let Some(params) = node.child_by_field_name("parameters") {
             self.extract_parameters(ctx, &params, id_gen, result, &node_id);
         }
+
+        // CALLS edges from the body
+        if let Some(body) = node.child_by_field_name("body") {
+            self.extract_calls(ctx, &body, &node_id, false, result);
+        }
+    }
+
+    /// Extract CALLS edges under `node`
+    ///
+    /// Nested declarations are skipped; inline callbacks belong to the
+    /// enclosing function. `in_async` is set inside promise continuations and
+    /// timer callbacks, which run after the caller yields.
+    fn extract_calls(
+        &self,
+        ctx: &ExtractionContext,
+        node: &TSNode,
+        caller_id: &str,
+        in_async: bool,
+        result: &mut ExtractionResult,
+    ) {
+        match node.kind() {
+            "function_declaration"
+            | "generator_function_declaration"
+            | "method_definition"
+            | "class_declaration"
+            | "class" => {}
+            "call_expression" => {
+                self.extract_call(ctx, node, caller_id, in_async, in_async, result)
+            }
+            "await_expression" => {
+                let mut cursor = node.walk();
+                for child in node.named_children(&mut cursor) {
+                    if child.kind() == "call_expression" {
+                        self.extract_call(ctx, &child, caller_id, true, in_async, result);
+                    } else {
+                        self.extract_calls(ctx, &child, caller_id, in_async, result);
+                    }
+                }
+            }
+            _ => {
+                let mut cursor = node.walk();
+                for child in node.named_children(&mut cursor) {
+                    self.extract_calls(ctx, &child, caller_id, in_async, result);
+                }
+            }
+        }
+    }
+
+    /// Extract one call expression and its arguments
+    fn extract_call(
+        &self,
+        ctx: &ExtractionContext,
+        node: &TSNode,
+        caller_id: &str,
+        is_async: bool,
+        in_async: bool,
+        result: &mut ExtractionResult,
+    ) {
+        let Some(function) = node.child_by_field_name("function") else {
+            return;
+        };
+        let callee = callee_name(ctx, &function);
+        if !callee.is_empty() {
+            self.add_call_edge(caller_id, &callee, node, is_async, result);
+        }
+        // Receiver of a chained call: `fetch(url).then(...)`
+        self.extract_calls(ctx, &function, caller_id, in_async, result);
+
+        let Some(args) = node.child_by_field_name("arguments") else {
+            return;
+        };
+        let mut cursor = args.walk();
+        let args: Vec<TSNode> = args
+            .named_children(&mut cursor)
+            .filter(|a| a.kind() != "comment")
+            .collect();
+        match async_args(&callee, function.kind() == "member_expression") {
+            AsyncArgs::Awaited => {
+                for arg in &args {
+                    self.extract_awaited(ctx, arg, caller_id, in_async, result);
+                }
+            }
+            AsyncArgs::Callbacks(count) => {
+                for (i, arg) in args.iter().enumerate() {
+                    if i >= count {
+                        self.extract_calls(ctx, arg, caller_id, in_async, result);
+                        continue;
+                    }
+                    match arg.kind() {
+                        "identifier" | "member_expression" => {
+                            let name = callee_name(ctx, arg);
+                            self.add_call_edge(caller_id, &name, arg, true, result);
+                        }
+                        "arrow_function" | "function_expression" | "function" => {
+                            if let Some(body) = arg.child_by_field_name("body") {
+                                self.extract_calls(ctx, &body, caller_id, true, result);
+                            }
+                        }
+                        _ => self.extract_calls(ctx, arg, caller_id, in_async, result),
+                    }
+                }
+            }
+            AsyncArgs::None => {
+                for arg in &args {
+                    self.extract_calls(ctx, arg, caller_id, in_async, result);
+                }
+            }
+        }
+    }
+
+    /// Calls handed to a promise combinator (`Promise.all([a(), b()])`)
+    fn extract_awaited(
+        &self,
+        ctx: &ExtractionContext,
+        node: &TSNode,
+        caller_id: &str,
+        in_async: bool,
+        result: &mut ExtractionResult,
+    ) {
+        match node.kind() {
+            "call_expression" => self.extract_call(ctx, node, caller_id, true, in_async, result),
+            "array" | "spread_element" | "parenthesized_expression" => {
+                let mut cursor = node.walk();
+                for child in node.named_children(&mut cursor) {
+                    self.extract_awaited(ctx, &child, caller_id, in_async, result);
+                }
+            }
+            _ => self.extract_calls(ctx, node, caller_id, in_async, result),
+        }
+    }
+
+    fn add_call_edge(
+        &self,
+        caller_id: &str,
+        callee: &str,
+        node: &TSNode,
+        is_async: bool,
+        result: &mut ExtractionResult,
+    ) {
+        let mut edge = Edge::new(caller_id.to_string(), callee.to_string(), EdgeKind::Calls)
+            .with_span(node.to_span());
+        if is_async {
+            edge = edge.with_is_async(true);
+        }
+        result.add_edge(edge);
     }
 
     /// Extract variable/const/let declaration
@@ -477,9 +623,78 @@ impl TypeScriptPlugin {
             node.to_span(),
         )
         .with_language(ctx.language.name().to_string())
-        .with_name(source_module);
+        .with_name(source_module.clone());
 
         result.add_node(ir_node);
+
+        // One IMPORTS edge per binding, targeting `specifier#imported`
+        let Some(ref parent) = ctx.parent_id else {
+            return;
+        };
+        let mut bindings = Vec::new();
+        let mut cursor = node.walk();
+        for clause in node.named_children(&mut cursor) {
+            if clause.kind() == "import_clause" {
+                Self::collect_import_bindings(ctx, &clause, &mut bindings);
+            }
+        }
+        if bindings.is_empty() {
+            // Side-effect import (`import './polyfills'`)
+            bindings.push(("*".to_string(), None, *node));
+        }
+        for (imported, local, binding) in bindings {
+            let alias = local.filter(|local| *local != imported);
+            result.add_edge(
+                Edge::new(
+                    parent.clone(),
+                    format!("{}#{}", source_module, imported),
+                    EdgeKind::Imports,
+                )
+                .with_span(binding.to_span())
+                .with_metadata(EdgeMetadata {
+                    alias,
+                    ..Default::default()
+                }),
+            );
+        }
+    }
+
+    /// (imported name, local name, node) of each binding in an `import_clause`
+    fn collect_import_bindings<'a>(
+        ctx: &ExtractionContext,
+        clause: &TSNode<'a>,
+        bindings: &mut Vec<(String, Option<String>, TSNode<'a>)>,
+    ) {
+        let mut cursor = clause.walk();
+        for child in clause.named_children(&mut cursor) {
+            match child.kind() {
+                "identifier" => {
+                    let local = ctx.node_text(&child).to_string();
+                    bindings.push(("default".to_string(), Some(local), child));
+                }
+                "namespace_import" => {
+                    let local = child.named_child(0).map(|n| ctx.node_text(&n).to_string());
+                    bindings.push(("*".to_string(), local, child));
+                }
+                "named_imports" => {
+                    let mut specifiers = child.walk();
+                    for spec in child.named_children(&mut specifiers) {
+                        if spec.kind() != "import_specifier" {
+                            continue;
+                        }
+                        let Some(name) = spec.child_by_field_name("name") else {
+                            continue;
+                        };
+                        let imported = ctx.node_text(&name).to_string();
+                        let local = spec
+                            .child_by_field_name("alias")
+                            .map(|n| ctx.node_text(&n).to_string());
+                        bindings.push((imported, local, spec));
+                    }
+                }
+                _ => {}
+            }
+        }
     }
 
     /// Extract export statement
@@ -490,6 +705,11 @@ impl TypeScriptPlugin {
         id_gen: &mut IdGenerator,
         result: &mut ExtractionResult,
     ) {
+        if let Some(source) = node.child_by_field_name("source") {
+            self.extract_reexport(ctx, node, &source, id_gen, result);
+            return;
+        }
+
         // Process the exported declaration
         let mut cursor = node.walk();
         for child in node.children(&mut cursor) {
@@ -497,6 +717,80 @@ impl TypeScriptPlugin {
         }
     }
 
+    /// Extract `export * from` / `export { a as b } from` as exported Import nodes
+    fn extract_reexport(
+        &self,
+        ctx: &ExtractionContext,
+        node: &TSNode,
+        source: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let source_module = ctx
+            .node_text(source)
+            .trim_matches(|c| c == '"' || c == '\'')
+            .to_string();
+
+        // (exported name, imported name, node)
+        let mut bindings = Vec::new();
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            match child.kind() {
+                "namespace_export" => {
+                    if let Some(name) = child.named_child(0) {
+                        let exported = ctx.node_text(&name).to_string();
+                        bindings.push((exported, "*".to_string(), child));
+                    }
+                }
+                "export_clause" => {
+                    let mut specifiers = child.walk();
+                    for spec in child.named_children(&mut specifiers) {
+                        if spec.kind() != "export_specifier" {
+                            continue;
+                        }
+                        let Some(name) = spec.child_by_field_name("name") else {
+                            continue;
+                        };
+                        let imported = ctx.node_text(&name).to_string();
+                        let exported = spec
+                            .child_by_field_name("alias")
+                            .map(|n| ctx.node_text(&n).to_string())
+                            .unwrap_or_else(|| imported.clone());
+                        bindings.push((exported, imported, spec));
+                    }
+                }
+                _ => {}
+            }
+        }
+        if bindings.is_empty() {
+            // `export * from './module'`
+            bindings.push(("*".to_string(), "*".to_string(), *node));
+        }
+
+        for (exported, imported, binding) in bindings {
+            let target = format!("{}#{}", source_module, imported);
+            let mut ir_node = Node::new(
+                id_gen.next_node(),
+                NodeKind::Import,
+                target.clone(),
+                ctx.file_path.to_string(),
+                binding.to_span(),
+            )
+            .with_language(ctx.language.name().to_string())
+            .with_name(exported);
+            ir_node.modifiers = Some(vec!["export".to_string()]);
+
+            if let Some(ref parent) = ctx.parent_id {
+                ir_node.parent_id = Some(parent.clone());
+                result.add_edge(
+                    Edge::new(parent.clone(), target, EdgeKind::Imports)
+                        .with_span(binding.to_span()),
+                );
+            }
+            result.add_node(ir_node);
+        }
+    }
+
     /// Extract parameters
     fn extract_parameters(
         &self,
@@ -957,6 +1251,47 @@ impl TypeScriptPlugin {
     }
 }
 
+/// How a call runs the code passed to it
+enum AsyncArgs {
+    None,
+    /// Argument calls are awaited together
+    Awaited,
+    /// The first `n` arguments are callbacks run later
+    Callbacks(usize),
+}
+
+fn async_args(callee: &str, is_method: bool) -> AsyncArgs {
+    match callee {
+        "Promise.all" | "Promise.allSettled" | "Promise.race" | "Promise.any" => {
+            return AsyncArgs::Awaited
+        }
+        "setTimeout"
+        | "setInterval"
+        | "setImmediate"
+        | "queueMicrotask"
+        | "requestAnimationFrame"
+        | "process.nextTick" => return AsyncArgs::Callbacks(1),
+        _ => {}
+    }
+    match callee.rsplit('.').next() {
+        Some("then") if is_method => AsyncArgs::Callbacks(2),
+        Some("catch" | "finally") if is_method => AsyncArgs::Callbacks(1),
+        _ => AsyncArgs::None,
+    }
+}
+
+/// Dotted callee path; only the method name when the receiver is itself a call
+fn callee_name(ctx: &ExtractionContext, node: &TSNode) -> String {
+    let text = ctx.node_text(node);
+    if !text.contains(['(', '\n', '[']) {
+        return text.replace("?.", ".");
+    }
+    match node.child_by_field_name("property") {
+        Some(property) => ctx.node_text(&property).to_string(),
+        None => String::new(),
+    }
+}
+
 impl Default for TypeScriptPlugin {
     fn default() -> Self {
         Self::new()
@@ -980,6 +1315,26 @@ impl LanguagePlugin for TypeScriptPlugin {
         }
     }
 
+    fn capabilities(&self) -> PluginCapabilities {
+        PluginCapabilities::full()
+    }
+
+    fn grammar_version(&self) -> Option<&'static str> {
+        Some("tree-sitter-typescript 0.21")
+    }
+
+    fn expected_node_kinds(&self) -> &[&str] {
+        &[
+            "class_declaration",
+            "interface_declaration",
+            "function_declaration",
+            "method_definition",
+            "arrow_function",
+            "lexical_declaration",
+            "import_statement",
+        ]
+    }
+
     fn extensions(&self) -> &[&str] {
         if self.use_typescript {
             &["ts", "tsx"]
@@ -1273,4 +1628,44 @@ const arrowFunc = (x: number) => x * 2;
             .collect();
         assert!(!funcs.is_empty());
     }
+
+    #[test]
+    fn test_extract_async_calls() {
+        let source = r#"
+async function load(ids: string[]) {
+    const user = await fetchUser(ids[0]);
+    await Promise.all([fetchPosts(user), fetchTags()]);
+    fetchFeed(user).then(render).catch((e) => report(e));
+    setTimeout(refresh, 100);
+    log(user);
+}
+"#;
+        let tree = parse_typescript(source);
+        let plugin = TypeScriptPlugin::new();
+        let mut ctx =
+            ExtractionContext::new(source, "test.ts", "test-repo", LanguageId::TypeScript);
+
+        let result = plugin.extract(&mut ctx, &tree).unwrap();
+
+        let is_async = |name: &str| {
+            result
+                .edges
+                .iter()
+                .find(|e| e.kind == EdgeKind::Calls && e.target_id == name)
+                .map(|e| e.is_async())
+        };
+        for name in [
+            "fetchUser",
+            "fetchPosts",
+            "fetchTags",
+            "render",
+            "report",
+            "refresh",
+        ] {
+            assert_eq!(is_async(name), Some(true), "{}", name);
+        }
+        for name in ["Promise.all", "fetchFeed", "then", "setTimeout", "log"] {
+            assert_eq!(is_async(name), Some(false), "{}", name);
+        }
+    }
 }
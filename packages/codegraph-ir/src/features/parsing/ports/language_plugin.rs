@@ -7,7 +7,7 @@ use std::collections::HashMap;
 use tree_sitter::{Language as TSLanguage, Node as TSNode, Tree};
 
 use crate::features::parsing::domain::SyntaxKind;
-use crate::shared::models::{Edge, Node, NodeKind, Result, Span};
+use crate::shared::models::{Edge, EdgeProvenance, EdgeStage, Node, NodeKind, Result, Span};
 
 /// Control flow type classification
 ///
@@ -46,7 +46,55 @@ pub struct ExceptionHandlers<'a> {
     pub finally_block: Option<TSNode<'a>>,
 }
 
+/// Per-plugin capability flags
+///
+/// Tells the pipeline which per-file analyses are meaningful for a language.
+/// Stages whose capability is off are skipped instead of running with
+/// fallback (usually Python-shaped) heuristics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PluginCapabilities {
+    /// `is_statement_node`/`is_control_flow_node` are precise enough for BFG/CFG
+    pub control_flow: bool,
+    /// A variable extractor exists (DFG, SSA, PDG)
+    pub data_flow: bool,
+    /// Call names follow conventions the built-in taint sources/sinks match
+    pub taint: bool,
+    /// Imports are extracted as IMPORTS edges for cross-file resolution
+    pub imports: bool,
+}
+
+impl PluginCapabilities {
+    /// Everything enabled
+    pub const fn full() -> Self {
+        Self {
+            control_flow: true,
+            data_flow: true,
+            taint: true,
+            imports: true,
+        }
+    }
+
+    /// Structural extraction + CFG only (default for third-party plugins)
+    pub const fn extraction_only() -> Self {
+        Self {
+            control_flow: true,
+            data_flow: false,
+            taint: false,
+            imports: true,
+        }
+    }
+}
+
+impl Default for PluginCapabilities {
+    fn default() -> Self {
+        Self::extraction_only()
+    }
+}
+
 /// Language identifier
+///
+/// Built-in languages have dedicated variants; plugins registered through the
+/// SDK (`features::parsing::sdk`) use `Custom` with their language name.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum LanguageId {
     Python,
@@ -56,6 +104,12 @@ pub enum LanguageId {
     Kotlin,
     Rust,
     Go,
+    C,
+    Cpp,
+    Php,
+    Ruby,
+    /// Third-party language registered at runtime (e.g. `Custom("elixir")`)
+    Custom(&'static str),
 }
 
 impl LanguageId {
@@ -69,10 +123,17 @@ impl LanguageId {
             LanguageId::Kotlin => "kotlin",
             LanguageId::Rust => "rust",
             LanguageId::Go => "go",
+            LanguageId::C => "c",
+            LanguageId::Cpp => "cpp",
+            LanguageId::Php => "php",
+            LanguageId::Ruby => "ruby",
+            LanguageId::Custom(name) => name,
         }
     }
 
-    /// Get language from file extension
+    /// Get built-in language from file extension
+    ///
+    /// Runtime-registered plugins are resolved by `sdk::plugin_for_extension`.
     pub fn from_extension(ext: &str) -> Option<Self> {
         match ext.to_lowercase().as_str() {
             "py" | "pyi" => Some(LanguageId::Python),
@@ -82,6 +143,13 @@ impl LanguageId {
             "kt" | "kts" => Some(LanguageId::Kotlin),
             "rs" => Some(LanguageId::Rust),
             "go" => Some(LanguageId::Go),
+            "c" => Some(LanguageId::C),
+            // `.h` goes to C++: its grammar accepts C headers, the reverse does not hold
+            "h" | "cc" | "cpp" | "cxx" | "c++" | "hh" | "hpp" | "hxx" | "h++" => {
+                Some(LanguageId::Cpp)
+            }
+            "php" | "phtml" => Some(LanguageId::Php),
+            "rb" | "rake" => Some(LanguageId::Ruby),
             _ => None,
         }
     }
@@ -96,6 +164,12 @@ impl LanguageId {
             LanguageId::Kotlin => &["kt", "kts"],
             LanguageId::Rust => &["rs"],
             LanguageId::Go => &["go"],
+            LanguageId::C => &["c"],
+            LanguageId::Cpp => &["h", "cc", "cpp", "cxx", "c++", "hh", "hpp", "hxx", "h++"],
+            LanguageId::Php => &["php", "phtml"],
+            LanguageId::Ruby => &["rb", "rake"],
+            // Custom plugins declare extensions via `LanguagePlugin::extensions`
+            LanguageId::Custom(_) => &[],
         }
     }
 }
@@ -164,6 +238,49 @@ impl<'a> ExtractionContext<'a> {
     pub fn node_text(&self, node: &TSNode) -> &str {
         self.source.get(node.byte_range()).unwrap_or("")
     }
+
+    /// Create a node of this file with the common fields filled in
+    /// (language, name, span, current parent)
+    pub fn new_node(
+        &self,
+        node_id: String,
+        kind: NodeKind,
+        fqn: String,
+        name: String,
+        node: &TSNode,
+    ) -> Node {
+        let mut ir_node = Node::new(
+            node_id,
+            kind,
+            fqn,
+            self.file_path.to_string(),
+            node.to_span(),
+        )
+        .with_language(self.language.name().to_string())
+        .with_name(name);
+        ir_node.parent_id = self.parent_id.clone();
+        ir_node
+    }
+
+    /// Resolve a path relative to the current file's directory
+    ///
+    /// Used for `require_relative` / `__DIR__`-style includes.
+    /// `../models/user` from `app/controllers/x.rb` → `app/models/user`
+    pub fn resolve_relative_path(&self, relative: &str) -> String {
+        let mut parts: Vec<&str> = self.file_path.split('/').collect();
+        parts.pop(); // file name
+
+        for segment in relative.split('/') {
+            match segment {
+                "" | "." => {}
+                ".." => {
+                    parts.pop();
+                }
+                other => parts.push(other),
+            }
+        }
+        parts.join("/")
+    }
 }
 
 /// Result of extraction from a single file
@@ -186,7 +303,13 @@ impl ExtractionResult {
         self.nodes.push(node);
     }
 
+    /// Add an edge, stamping L1 provenance if the extractor didn't set one
     pub fn add_edge(&mut self, edge: Edge) {
+        let edge = if edge.provenance().is_none() {
+            edge.with_provenance(EdgeProvenance::new(EdgeStage::IrBuild).with_rule("extractor"))
+        } else {
+            edge
+        };
         self.edges.push(edge);
     }
 
@@ -224,10 +347,33 @@ pub trait LanguagePlugin: Send + Sync {
     fn language_id(&self) -> LanguageId;
 
     /// Get supported file extensions
+    ///
+    /// Plugins using `LanguageId::Custom` must override this.
     fn extensions(&self) -> &[&str] {
         self.language_id().extensions()
     }
 
+    /// Analyses this plugin supports beyond structural extraction
+    fn capabilities(&self) -> PluginCapabilities {
+        PluginCapabilities::default()
+    }
+
+    /// Grammar crate and version this plugin was written against
+    /// (e.g. "tree-sitter-python 0.21"), reported in pipeline results
+    fn grammar_version(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Node kinds the extractor relies on
+    ///
+    /// Checked against the loaded grammar at pipeline startup
+    /// (`features::parsing::grammar`); a missing kind means the grammar
+    /// changed under the plugin and fails the run instead of silently
+    /// extracting nothing.
+    fn expected_node_kinds(&self) -> &[&str] {
+        &[]
+    }
+
     /// Check if this plugin supports a file extension
     fn supports(&self, ext: &str) -> bool {
         self.extensions()
@@ -468,8 +614,11 @@ impl LanguageRegistry {
 
     /// Get plugin by file extension
     pub fn get_by_extension(&self, ext: &str) -> Option<&dyn LanguagePlugin> {
-        let lang = LanguageId::from_extension(ext)?;
-        self.get(lang)
+        if let Some(plugin) = LanguageId::from_extension(ext).and_then(|lang| self.get(lang)) {
+            return Some(plugin);
+        }
+        // Custom plugins declare their own extensions
+        self.all().find(|p| p.supports(ext))
     }
 
     /// Get all registered plugins
@@ -552,6 +701,11 @@ mod tests {
         assert_eq!(LanguageId::from_extension("kt"), Some(LanguageId::Kotlin));
         assert_eq!(LanguageId::from_extension("rs"), Some(LanguageId::Rust));
         assert_eq!(LanguageId::from_extension("go"), Some(LanguageId::Go));
+        assert_eq!(LanguageId::from_extension("c"), Some(LanguageId::C));
+        assert_eq!(LanguageId::from_extension("h"), Some(LanguageId::Cpp));
+        assert_eq!(LanguageId::from_extension("hpp"), Some(LanguageId::Cpp));
+        assert_eq!(LanguageId::from_extension("php"), Some(LanguageId::Php));
+        assert_eq!(LanguageId::from_extension("rb"), Some(LanguageId::Ruby));
         assert_eq!(LanguageId::from_extension("unknown"), None);
     }
 
@@ -573,6 +727,23 @@ mod tests {
         assert_eq!(ctx.fqn_prefix(), "foo.bar.MyClass");
     }
 
+    #[test]
+    fn test_resolve_relative_path() {
+        let ctx = ExtractionContext::new("", "app/controllers/users.rb", "repo", LanguageId::Ruby);
+        assert_eq!(
+            ctx.resolve_relative_path("../models/user"),
+            "app/models/user"
+        );
+        assert_eq!(
+            ctx.resolve_relative_path("./helper"),
+            "app/controllers/helper"
+        );
+        assert_eq!(
+            ctx.resolve_relative_path("/bootstrap.php"),
+            "app/controllers/bootstrap.php"
+        );
+    }
+
     #[test]
     fn test_id_generator() {
         let mut gen = IdGenerator::new("repo:file");
@@ -5,6 +5,7 @@ mod parser;
 
 pub use language_plugin::{
     ControlFlowType, // ✅ Export ControlFlowType enum
+    ExceptionHandlers,
     ExtractionContext,
     ExtractionResult,
     IdGenerator,
@@ -12,6 +13,7 @@ pub use language_plugin::{
     LanguagePlugin,
     LanguageRegistry,
     NodeKindMapper,
+    PluginCapabilities,
     SpanExt,
 };
 pub use parser::Parser;
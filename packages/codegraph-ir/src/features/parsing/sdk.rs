@@ -0,0 +1,351 @@
+//! Language Plugin SDK
+//!
+//! Process-wide registry that maps file extensions to `LanguagePlugin`
+//! factories. Core routing (`get_plugin_for_file`, code-file detection,
+//! repository scanning) goes through this registry, so adding a language no
+//! longer requires editing core match statements.
+//!
+//! Built-in plugins are seeded on first access. Third-party crates register
+//! their own descriptor once at startup:
+//!
+//! ```ignore
+//! use codegraph_ir::features::parsing::sdk::{register_plugin, PluginDescriptor};
+//! use codegraph_ir::features::parsing::LanguageId;
+//!
+//! fn make() -> Box<dyn LanguagePlugin + Send + Sync> {
+//!     Box::new(ElixirPlugin::new())
+//! }
+//!
+//! register_plugin(PluginDescriptor {
+//!     name: "elixir",
+//!     version: env!("CARGO_PKG_VERSION"),
+//!     language: LanguageId::Custom("elixir"),
+//!     extensions: &["ex", "exs"],
+//!     factory: make,
+//! })?;
+//! ```
+//!
+//! A template crate lives in `packages/codegraph-ir/templates/language-plugin`.
+
+#[cfg(feature = "c-lang")]
+use super::plugins::CPlugin;
+#[cfg(feature = "cpp-lang")]
+use super::plugins::CppPlugin;
+#[cfg(feature = "go-lang")]
+use super::plugins::GoPlugin;
+#[cfg(feature = "java-lang")]
+use super::plugins::JavaPlugin;
+#[cfg(feature = "kotlin-lang")]
+use super::plugins::KotlinPlugin;
+#[cfg(feature = "php-lang")]
+use super::plugins::PhpPlugin;
+use super::plugins::PythonPlugin;
+#[cfg(feature = "ruby-lang")]
+use super::plugins::RubyPlugin;
+#[cfg(feature = "rust-lang")]
+use super::plugins::RustPlugin;
+#[cfg(feature = "ts-lang")]
+use super::plugins::TypeScriptPlugin;
+use super::ports::{LanguageId, LanguagePlugin, PluginCapabilities};
+use crate::shared::models::CodegraphError;
+use parking_lot::RwLock;
+use std::sync::OnceLock;
+
+/// Creates a fresh plugin instance (plugins hold per-instance parser state)
+pub type PluginFactory = fn() -> Box<dyn LanguagePlugin + Send + Sync>;
+
+/// Static description of a registered plugin
+#[derive(Clone, Copy)]
+pub struct PluginDescriptor {
+    /// Plugin name (e.g. "python", "elixir")
+    pub name: &'static str,
+    /// Plugin version (usually the providing crate's `CARGO_PKG_VERSION`)
+    pub version: &'static str,
+    /// Language produced by this plugin
+    pub language: LanguageId,
+    /// File extensions routed to this plugin (without the dot)
+    pub extensions: &'static [&'static str],
+    /// Plugin constructor
+    pub factory: PluginFactory,
+}
+
+impl PluginDescriptor {
+    /// Capabilities reported by a freshly constructed plugin
+    pub fn capabilities(&self) -> PluginCapabilities {
+        (self.factory)().capabilities()
+    }
+}
+
+impl std::fmt::Debug for PluginDescriptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PluginDescriptor")
+            .field("name", &self.name)
+            .field("version", &self.version)
+            .field("language", &self.language)
+            .field("extensions", &self.extensions)
+            .finish()
+    }
+}
+
+static REGISTRY: OnceLock<RwLock<Vec<PluginDescriptor>>> = OnceLock::new();
+
+fn registry() -> &'static RwLock<Vec<PluginDescriptor>> {
+    REGISTRY.get_or_init(|| RwLock::new(builtin_descriptors()))
+}
+
+/// Descriptors for the plugins compiled into this crate (per `*-lang` feature)
+pub fn builtin_descriptors() -> Vec<PluginDescriptor> {
+    const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+    // Python is always built in
+    let mut descriptors = vec![PluginDescriptor {
+        name: "python",
+        version: VERSION,
+        language: LanguageId::Python,
+        extensions: &["py"],
+        factory: || Box::new(PythonPlugin::new()),
+    }];
+    #[cfg(feature = "java-lang")]
+    descriptors.push(PluginDescriptor {
+        name: "java",
+        version: VERSION,
+        language: LanguageId::Java,
+        extensions: &["java"],
+        factory: || Box::new(JavaPlugin::new()),
+    });
+    #[cfg(feature = "ts-lang")]
+    descriptors.push(PluginDescriptor {
+        name: "typescript",
+        version: VERSION,
+        language: LanguageId::TypeScript,
+        extensions: &["ts", "tsx"],
+        factory: || Box::new(TypeScriptPlugin::new()),
+    });
+    #[cfg(feature = "ts-lang")]
+    descriptors.push(PluginDescriptor {
+        name: "javascript",
+        version: VERSION,
+        language: LanguageId::JavaScript,
+        extensions: &["js", "jsx"],
+        factory: || Box::new(TypeScriptPlugin::new()),
+    });
+    #[cfg(feature = "kotlin-lang")]
+    descriptors.push(PluginDescriptor {
+        name: "kotlin",
+        version: VERSION,
+        language: LanguageId::Kotlin,
+        extensions: &["kt", "kts"],
+        factory: || Box::new(KotlinPlugin::new()),
+    });
+    #[cfg(feature = "rust-lang")]
+    descriptors.push(PluginDescriptor {
+        name: "rust",
+        version: VERSION,
+        language: LanguageId::Rust,
+        extensions: &["rs"],
+        factory: || Box::new(RustPlugin::new()),
+    });
+    #[cfg(feature = "go-lang")]
+    descriptors.push(PluginDescriptor {
+        name: "go",
+        version: VERSION,
+        language: LanguageId::Go,
+        extensions: &["go"],
+        factory: || Box::new(GoPlugin::new()),
+    });
+    #[cfg(feature = "c-lang")]
+    descriptors.push(PluginDescriptor {
+        name: "c",
+        version: VERSION,
+        language: LanguageId::C,
+        // Headers go to the C++ plugin when it is built in
+        extensions: if cfg!(feature = "cpp-lang") {
+            &["c"]
+        } else {
+            &["c", "h"]
+        },
+        factory: || Box::new(CPlugin::new()),
+    });
+    #[cfg(feature = "cpp-lang")]
+    descriptors.push(PluginDescriptor {
+        name: "cpp",
+        version: VERSION,
+        language: LanguageId::Cpp,
+        extensions: &["h", "cc", "cpp", "cxx", "c++", "hh", "hpp", "hxx", "h++"],
+        factory: || Box::new(CppPlugin::new()),
+    });
+    #[cfg(feature = "php-lang")]
+    descriptors.push(PluginDescriptor {
+        name: "php",
+        version: VERSION,
+        language: LanguageId::Php,
+        extensions: &["php", "phtml"],
+        factory: || Box::new(PhpPlugin::new()),
+    });
+    #[cfg(feature = "ruby-lang")]
+    descriptors.push(PluginDescriptor {
+        name: "ruby",
+        version: VERSION,
+        language: LanguageId::Ruby,
+        extensions: &["rb", "rake"],
+        factory: || Box::new(RubyPlugin::new()),
+    });
+    descriptors
+}
+
+/// Register a plugin
+///
+/// Fails if one of its extensions is already claimed by a plugin for a
+/// different language. Re-registering the same language extends/updates it.
+pub fn register_plugin(descriptor: PluginDescriptor) -> Result<(), CodegraphError> {
+    let mut plugins = registry().write();
+
+    for existing in plugins.iter() {
+        if existing.language == descriptor.language {
+            continue;
+        }
+        if let Some(ext) = descriptor
+            .extensions
+            .iter()
+            .find(|ext| existing.extensions.contains(ext))
+        {
+            return Err(CodegraphError::config(format!(
+                "Extension '.{}' of plugin '{}' is already handled by plugin '{}'",
+                ext, descriptor.name, existing.name
+            )));
+        }
+    }
+
+    plugins.retain(|p| p.language != descriptor.language);
+    plugins.push(descriptor);
+    Ok(())
+}
+
+/// Register a plugin, taking over any extensions it declares
+///
+/// Use this to override a built-in plugin (e.g. a patched grammar).
+pub fn replace_plugin(descriptor: PluginDescriptor) {
+    let mut plugins = registry().write();
+    plugins.retain(|p| {
+        p.language != descriptor.language
+            && !p
+                .extensions
+                .iter()
+                .any(|ext| descriptor.extensions.contains(ext))
+    });
+    plugins.push(descriptor);
+}
+
+/// Remove the plugin for a language, returning its descriptor
+pub fn unregister_plugin(language: LanguageId) -> Option<PluginDescriptor> {
+    let mut plugins = registry().write();
+    let index = plugins.iter().position(|p| p.language == language)?;
+    Some(plugins.remove(index))
+}
+
+/// Descriptor handling a file extension (without the dot)
+pub fn descriptor_for_extension(ext: &str) -> Option<PluginDescriptor> {
+    registry()
+        .read()
+        .iter()
+        .find(|p| p.extensions.contains(&ext))
+        .copied()
+}
+
+/// Instantiate the plugin handling a file extension
+pub fn plugin_for_extension(
+    ext: &str,
+) -> Option<(Box<dyn LanguagePlugin + Send + Sync>, LanguageId)> {
+    descriptor_for_extension(ext).map(|d| ((d.factory)(), d.language))
+}
+
+/// Instantiate the plugin for a language
+pub fn plugin_for_language(language: LanguageId) -> Option<Box<dyn LanguagePlugin + Send + Sync>> {
+    registry()
+        .read()
+        .iter()
+        .find(|p| p.language == language)
+        .map(|d| (d.factory)())
+}
+
+/// Whether any registered plugin handles the extension
+pub fn is_supported_extension(ext: &str) -> bool {
+    descriptor_for_extension(ext).is_some()
+}
+
+/// All extensions handled by registered plugins
+pub fn supported_extensions() -> Vec<&'static str> {
+    registry()
+        .read()
+        .iter()
+        .flat_map(|p| p.extensions.iter().copied())
+        .collect()
+}
+
+/// Snapshot of all registered plugins
+pub fn registered_plugins() -> Vec<PluginDescriptor> {
+    registry().read().clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_python() -> Box<dyn LanguagePlugin + Send + Sync> {
+        Box::new(PythonPlugin::new())
+    }
+
+    #[test]
+    fn test_builtins_registered() {
+        let (_, lang) = plugin_for_extension("py").unwrap();
+        assert_eq!(lang, LanguageId::Python);
+        assert!(!is_supported_extension("txt"));
+        assert!(
+            descriptor_for_extension("py")
+                .unwrap()
+                .capabilities()
+                .data_flow
+        );
+
+        #[cfg(feature = "cpp-lang")]
+        assert_eq!(plugin_for_extension("hpp").unwrap().1, LanguageId::Cpp);
+        #[cfg(feature = "cpp-lang")]
+        assert_eq!(plugin_for_extension("h").unwrap().1, LanguageId::Cpp);
+        #[cfg(all(feature = "c-lang", not(feature = "cpp-lang")))]
+        assert_eq!(plugin_for_extension("h").unwrap().1, LanguageId::C);
+        #[cfg(feature = "ruby-lang")]
+        assert!(is_supported_extension("rake"));
+    }
+
+    #[test]
+    fn test_register_custom_plugin() {
+        // Grammar reuse is fine for routing tests
+        register_plugin(PluginDescriptor {
+            name: "starlark",
+            version: "0.1.0",
+            language: LanguageId::Custom("starlark"),
+            extensions: &["star", "bzl"],
+            factory: make_python,
+        })
+        .unwrap();
+
+        let (_, lang) = plugin_for_extension("bzl").unwrap();
+        assert_eq!(lang, LanguageId::Custom("starlark"));
+        assert_eq!(lang.name(), "starlark");
+        assert!(supported_extensions().contains(&"star"));
+
+        // Claiming an extension of another language is rejected
+        let err = register_plugin(PluginDescriptor {
+            name: "pyish",
+            version: "0.1.0",
+            language: LanguageId::Custom("pyish"),
+            extensions: &["py"],
+            factory: make_python,
+        });
+        assert!(err.is_err());
+        assert_eq!(plugin_for_extension("py").unwrap().1, LanguageId::Python);
+
+        assert!(unregister_plugin(LanguageId::Custom("starlark")).is_some());
+        assert!(!is_supported_extension("bzl"));
+    }
+}
@@ -1,7 +1,7 @@
 //! High-Level Points-to Analyzer
 //!
 //! Unified API for points-to analysis supporting:
-//! - Multiple analysis modes (Fast, Precise, Hybrid)
+//! - Multiple analysis modes (Fast, Precise, Hybrid, 1-CFA, 1-object)
 //! - Automatic algorithm selection based on constraints
 //! - Integration with IR documents
 //!
@@ -31,10 +31,12 @@ use crate::features::points_to::domain::{
 };
 use crate::features::points_to::infrastructure::{
     andersen_solver::{AndersenConfig, AndersenSolver},
+    k_limited_solver::{CallSite, ContextKind, KLimitedSolver, MethodId, MethodSignature},
     steensgaard_solver::SteensgaardSolver,
 };
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::time::Instant;
 
 /// Analysis mode selection
@@ -51,6 +53,14 @@ pub enum AnalysisMode {
 
     /// Automatic: Choose based on constraint count
     Auto,
+
+    /// Andersen with 1-call-site contexts (1-CFA): callees analyzed per call
+    /// site. Separates helpers called from different places.
+    CallSiteSensitive,
+
+    /// Andersen with 1-object contexts: methods analyzed per receiver
+    /// allocation site. Separates instances of one class (OO code).
+    ObjectSensitive,
 }
 
 impl Default for AnalysisMode {
@@ -154,6 +164,18 @@ pub struct PointsToAnalyzer {
 
     /// SOTA: Symbolic memory for precise heap modeling
     symbolic_memory: SymbolicMemory,
+
+    /// Method name → ID mapping
+    method_to_id: FxHashMap<String, MethodId>,
+
+    /// Declared method formals
+    methods: FxHashMap<MethodId, MethodSignature>,
+
+    /// Local variable → enclosing method
+    var_scopes: FxHashMap<VarId, MethodId>,
+
+    /// Call sites (bound per context, or lowered to copies)
+    calls: Vec<CallSite>,
 }
 
 impl Default for PointsToAnalyzer {
@@ -176,6 +198,10 @@ impl PointsToAnalyzer {
             next_var_id: 1,
             next_field_id: 1,
             symbolic_memory: SymbolicMemory::new(),
+            method_to_id: FxHashMap::default(),
+            methods: FxHashMap::default(),
+            var_scopes: FxHashMap::default(),
+            calls: Vec::new(),
         }
     }
 
@@ -222,6 +248,16 @@ impl PointsToAnalyzer {
         id
     }
 
+    /// Get or create method ID
+    fn get_or_create_method(&mut self, name: &str) -> MethodId {
+        if let Some(&id) = self.method_to_id.get(name) {
+            return id;
+        }
+        let id = self.method_to_id.len() as MethodId + 1;
+        self.method_to_id.insert(name.to_string(), id);
+        id
+    }
+
     /// Get or create field ID
     fn get_or_create_field(&mut self, name: &str) -> u32 {
         if let Some(&id) = self.field_to_id.get(name) {
@@ -283,6 +319,51 @@ impl PointsToAnalyzer {
             .push(Constraint::field_store(base_id, field_id, rhs_id));
     }
 
+    /// Declare a method's formals (receiver excluded from `params`)
+    pub fn add_method(
+        &mut self,
+        method: &str,
+        params: &[&str],
+        this: Option<&str>,
+        ret: Option<&str>,
+    ) {
+        let id = self.get_or_create_method(method);
+        let signature = MethodSignature {
+            params: params.iter().map(|p| self.get_or_create_var(p)).collect(),
+            this: this.map(|v| self.get_or_create_var(v)),
+            ret: ret.map(|v| self.get_or_create_var(v)),
+        };
+        self.methods.insert(id, signature);
+    }
+
+    /// Scope a local variable to `method`; context-sensitive modes analyze
+    /// it once per context
+    pub fn add_method_var(&mut self, method: &str, var: &str) {
+        let id = self.get_or_create_method(method);
+        let var_id = self.get_or_create_var(var);
+        self.var_scopes.insert(var_id, id);
+    }
+
+    /// Add a call `result = receiver.callee(args)` made from `caller`
+    /// (None = module level)
+    pub fn add_call(
+        &mut self,
+        caller: Option<&str>,
+        callee: &str,
+        receiver: Option<&str>,
+        args: &[&str],
+        result: Option<&str>,
+    ) {
+        let call = CallSite {
+            caller: caller.map(|m| self.get_or_create_method(m)),
+            callee: self.get_or_create_method(callee),
+            receiver: receiver.map(|v| self.get_or_create_var(v)),
+            args: args.iter().map(|a| self.get_or_create_var(a)).collect(),
+            result: result.map(|v| self.get_or_create_var(v)),
+        };
+        self.calls.push(call);
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // Low-Level Constraint API (ID-based)
     // ═══════════════════════════════════════════════════════════════════════
@@ -324,6 +405,14 @@ impl PointsToAnalyzer {
             self.config.mode, constraint_count, mode
         );
 
+        // Context-insensitive solvers see calls as plain copies
+        let constraints = match mode {
+            AnalysisMode::CallSiteSensitive | AnalysisMode::ObjectSensitive => {
+                Cow::Borrowed(self.constraints.as_slice())
+            }
+            _ => self.with_call_copies(),
+        };
+
         // Run analysis
         let (graph, inner_stats) = match mode {
             AnalysisMode::Fast => {
@@ -331,22 +420,24 @@ impl PointsToAnalyzer {
                     "[PTA DEBUG] → Calling solve_steensgaard() with {} constraints",
                     constraint_count
                 );
-                self.solve_steensgaard()
+                self.solve_steensgaard(&constraints)
             }
             AnalysisMode::Precise => {
                 eprintln!(
                     "[PTA DEBUG] → Calling solve_andersen() with {} constraints",
                     constraint_count
                 );
-                self.solve_andersen()
+                self.solve_andersen(&constraints)
             }
             AnalysisMode::Hybrid => {
                 eprintln!(
                     "[PTA DEBUG] → Calling solve_hybrid() with {} constraints",
                     constraint_count
                 );
-                self.solve_hybrid()
+                self.solve_hybrid(&constraints)
             }
+            AnalysisMode::CallSiteSensitive => self.solve_context_sensitive(ContextKind::CallSite),
+            AnalysisMode::ObjectSensitive => self.solve_context_sensitive(ContextKind::Object),
             AnalysisMode::Auto => unreachable!(),
         };
 
@@ -356,18 +447,42 @@ impl PointsToAnalyzer {
             graph,
             mode_used: mode,
             stats: AnalysisStats {
-                constraints_total: constraint_count,
+                constraints_total: constraints.len(),
                 duration_ms,
                 ..inner_stats
             },
         }
     }
 
-    fn solve_steensgaard(&self) -> (PointsToGraph, AnalysisStats) {
+    /// Constraints plus `param = arg`, `this = receiver`, `result = ret`
+    /// copies for every call
+    fn with_call_copies(&self) -> Cow<'_, [Constraint]> {
+        if self.calls.is_empty() {
+            return Cow::Borrowed(&self.constraints);
+        }
+        let mut constraints = self.constraints.clone();
+        for call in &self.calls {
+            let Some(signature) = self.methods.get(&call.callee) else {
+                continue;
+            };
+            for (&arg, &param) in call.args.iter().zip(&signature.params) {
+                constraints.push(Constraint::copy(param, arg));
+            }
+            if let (Some(receiver), Some(this)) = (call.receiver, signature.this) {
+                constraints.push(Constraint::copy(this, receiver));
+            }
+            if let (Some(ret), Some(result)) = (signature.ret, call.result) {
+                constraints.push(Constraint::copy(result, ret));
+            }
+        }
+        Cow::Owned(constraints)
+    }
+
+    fn solve_steensgaard(&self, constraints: &[Constraint]) -> (PointsToGraph, AnalysisStats) {
         let mut solver =
-            SteensgaardSolver::with_capacity(self.next_var_id as usize, self.constraints.len());
+            SteensgaardSolver::with_capacity(self.next_var_id as usize, constraints.len());
 
-        for c in &self.constraints {
+        for c in constraints {
             solver.add_constraint(c.clone());
         }
 
@@ -384,7 +499,7 @@ impl PointsToAnalyzer {
         (result.graph, stats)
     }
 
-    fn solve_andersen(&self) -> (PointsToGraph, AnalysisStats) {
+    fn solve_andersen(&self, constraints: &[Constraint]) -> (PointsToGraph, AnalysisStats) {
         let config = AndersenConfig {
             field_sensitive: self.config.field_sensitive,
             max_iterations: self.config.max_iterations,
@@ -396,7 +511,7 @@ impl PointsToAnalyzer {
 
         let mut solver = AndersenSolver::new(config);
 
-        for c in &self.constraints {
+        for c in constraints {
             solver.add_constraint(c.clone());
         }
 
@@ -413,12 +528,12 @@ impl PointsToAnalyzer {
         (result.graph, stats)
     }
 
-    fn solve_hybrid(&self) -> (PointsToGraph, AnalysisStats) {
+    fn solve_hybrid(&self, constraints: &[Constraint]) -> (PointsToGraph, AnalysisStats) {
         // Phase 1: Quick Steensgaard pass for initial approximation
         let mut steensgaard =
-            SteensgaardSolver::with_capacity(self.next_var_id as usize, self.constraints.len());
+            SteensgaardSolver::with_capacity(self.next_var_id as usize, constraints.len());
 
-        for c in &self.constraints {
+        for c in constraints {
             steensgaard.add_constraint(c.clone());
         }
 
@@ -431,7 +546,7 @@ impl PointsToAnalyzer {
 
         let refinement_threshold = 5000;
 
-        if self.constraints.len() > refinement_threshold {
+        if constraints.len() > refinement_threshold {
             // Large codebase: Use Steensgaard result directly
             // Over-approximation is acceptable for scalability
             let stats = AnalysisStats {
@@ -460,7 +575,7 @@ impl PointsToAnalyzer {
 
         let mut andersen = AndersenSolver::new(config);
 
-        for c in &self.constraints {
+        for c in constraints {
             andersen.add_constraint(c.clone());
         }
 
@@ -487,6 +602,34 @@ impl PointsToAnalyzer {
         (result.graph, stats)
     }
 
+    fn solve_context_sensitive(&self, kind: ContextKind) -> (PointsToGraph, AnalysisStats) {
+        let mut solver = KLimitedSolver::new(kind, self.config.field_sensitive);
+
+        for c in &self.constraints {
+            solver.add_constraint(c.clone());
+        }
+        for (&var, &method) in &self.var_scopes {
+            solver.set_scope(var, method);
+        }
+        for (&method, signature) in &self.methods {
+            solver.add_method(method, signature.clone());
+        }
+        for call in &self.calls {
+            solver.add_call(call.clone());
+        }
+
+        let result = solver.solve();
+        let stats = AnalysisStats {
+            variables: result.graph.stats.total_variables,
+            locations: result.graph.stats.total_locations,
+            edges: result.graph.stats.total_edges,
+            iterations: result.stats.iterations,
+            ..Default::default()
+        };
+
+        (result.graph, stats)
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // Query API (String-based)
     // ═══════════════════════════════════════════════════════════════════════
@@ -501,6 +644,16 @@ impl PointsToAnalyzer {
         self.id_to_var.get(&id).map(|s| s.as_str())
     }
 
+    /// All variables with their IDs
+    pub fn variables(&self) -> impl Iterator<Item = (&str, VarId)> + '_ {
+        self.var_to_id.iter().map(|(name, &id)| (name.as_str(), id))
+    }
+
+    /// All allocation sites with their location IDs
+    pub fn locations(&self) -> impl Iterator<Item = (&str, LocationId)> + '_ {
+        self.loc_to_id.iter().map(|(name, &id)| (name.as_str(), id))
+    }
+
     /// Number of variables
     pub fn var_count(&self) -> usize {
         self.var_to_id.len()
@@ -518,6 +671,10 @@ impl PointsToAnalyzer {
         self.loc_to_id.clear();
         self.field_to_id.clear();
         self.constraints.clear();
+        self.method_to_id.clear();
+        self.methods.clear();
+        self.var_scopes.clear();
+        self.calls.clear();
         self.location_factory = LocationFactory::new();
         self.next_var_id = 1;
         self.next_field_id = 1;
@@ -633,4 +790,51 @@ mod tests {
             .graph
             .may_alias_by_name(&analyzer, "x", "nonexistent"));
     }
+
+    #[test]
+    fn test_context_sensitive_modes() {
+        let boxes = |mode| {
+            let mut analyzer = PointsToAnalyzer::new(AnalysisConfig {
+                mode,
+                ..Default::default()
+            });
+            // class Box: def set(self, v): self.item = v / def get(self): return self.item
+            analyzer.add_method("Box.set", &["set.v"], Some("set.self"), None);
+            analyzer.add_field_store("set.self", "item", "set.v");
+            analyzer.add_method("Box.get", &[], Some("get.self"), Some("get.ret"));
+            analyzer.add_field_load("get.ret", "get.self", "item");
+
+            analyzer.add_alloc("b1", "alloc:1:Box");
+            analyzer.add_alloc("b2", "alloc:2:Box");
+            analyzer.add_alloc("x", "alloc:3:A");
+            analyzer.add_alloc("y", "alloc:4:B");
+            analyzer.add_call(None, "Box.set", Some("b1"), &["x"], None);
+            analyzer.add_call(None, "Box.set", Some("b2"), &["y"], None);
+            analyzer.add_call(None, "Box.get", Some("b1"), &[], Some("r1"));
+            analyzer.add_call(None, "Box.get", Some("b2"), &[], Some("r2"));
+
+            let result = analyzer.solve();
+            assert_eq!(result.mode_used, mode);
+            result.graph.may_alias_by_name(&analyzer, "r1", "r2")
+        };
+        assert!(!boxes(AnalysisMode::ObjectSensitive));
+        assert!(!boxes(AnalysisMode::CallSiteSensitive));
+
+        // a = id(x); b = id(y): calls lowered to copies merge both callers
+        let identity = |mode| {
+            let mut analyzer = PointsToAnalyzer::new(AnalysisConfig {
+                mode,
+                ..Default::default()
+            });
+            analyzer.add_method("id", &["id.p"], None, Some("id.p"));
+            analyzer.add_alloc("x", "alloc:1:A");
+            analyzer.add_alloc("y", "alloc:2:B");
+            analyzer.add_call(None, "id", None, &["x"], Some("a"));
+            analyzer.add_call(None, "id", None, &["y"], Some("b"));
+            let result = analyzer.solve();
+            result.graph.may_alias_by_name(&analyzer, "a", "b")
+        };
+        assert!(identity(AnalysisMode::Precise));
+        assert!(!identity(AnalysisMode::CallSiteSensitive));
+    }
 }
@@ -155,7 +155,9 @@ impl FlowSensitiveAnalyzer {
 
         let precision = match config.mode {
             PTAMode::Fast => FlowPrecision::Fast,
-            PTAMode::Precise => FlowPrecision::Precise,
+            PTAMode::Precise | PTAMode::CallSiteSensitive | PTAMode::ObjectSensitive => {
+                FlowPrecision::Precise
+            }
             PTAMode::Hybrid | PTAMode::Auto => FlowPrecision::Standard,
         };
 
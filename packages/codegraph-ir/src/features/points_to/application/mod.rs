@@ -2,6 +2,7 @@
 //!
 //! High-level APIs for different use cases:
 //! - **PointsToAnalyzer**: Standard analysis (Hybrid/Fast/Precise modes)
+//! - **PointsToResult**: Queryable points-to facts of a solved analysis
 //! - **SecurityAnalyzer**: Context-sensitive analysis for security scanning
 //! - **RealtimeAnalyzer**: Demand-driven analysis for IDE/real-time queries
 //! - **IncrementalAnalyzer**: Incremental analysis for CI/CD and watch mode
@@ -11,6 +12,7 @@
 
 pub mod analyzer;
 pub mod null_safety;
+pub mod points_to_result;
 pub mod security_analyzer;
 pub mod realtime_analyzer;
 pub mod incremental_analyzer;
@@ -19,6 +21,7 @@ pub mod parallel_analyzer;
 
 // Standard analyzer
 pub use analyzer::{AnalysisConfig, AnalysisMode, PointsToAnalyzer};
+pub use points_to_result::{AllocationSite, PointsToResult};
 
 // Null safety
 pub use null_safety::{NullDereferenceError, NullSafetyAnalyzer, NULL_LOCATION};
@@ -0,0 +1,161 @@
+//! Queryable points-to facts
+//!
+//! `PointsToResult` keeps what a solved analysis knows by name, so clients
+//! (taint, refactoring) can ask about aliasing without holding the analyzer:
+//!
+//! ```text
+//! let result = analyzer.solve();
+//! let facts = PointsToResult::from_analysis(&analyzer, &result);
+//! facts.allocation_sites("y");  // [alloc:1:A]
+//! facts.may_alias("x", "y");    // true
+//! facts.alias_set("x");         // ["y"]
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::analyzer::{AnalysisResult, PointsToAnalyzer};
+use crate::features::points_to::domain::abstract_location::LocationId;
+
+/// Abstract heap location a variable may point to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllocationSite {
+    pub id: LocationId,
+    /// Allocation site name (e.g. `alloc:10:MyClass`, `fn:app.main`)
+    pub site: String,
+    pub type_info: Option<String>,
+    pub file_path: Option<String>,
+    pub line: Option<u32>,
+}
+
+/// Points-to sets and alias classes of a solved analysis, by variable name
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PointsToResult {
+    /// Solver that produced the facts (Fast, Precise, ...)
+    pub mode_used: String,
+    pub sites: BTreeMap<LocationId, AllocationSite>,
+    /// Variable → location IDs, ascending
+    pub points_to: BTreeMap<String, Vec<LocationId>>,
+    /// Variable → alias class; variables of one class always alias
+    /// (Steensgaard classes, Andersen cycles)
+    pub alias_classes: BTreeMap<String, u32>,
+}
+
+impl PointsToResult {
+    pub fn from_analysis(analyzer: &PointsToAnalyzer, result: &AnalysisResult) -> Self {
+        let graph = &result.graph;
+        let mut facts = Self {
+            mode_used: format!("{:?}", result.mode_used),
+            ..Self::default()
+        };
+        for (name, id) in analyzer.locations() {
+            let location = graph.get_location(id);
+            facts.sites.insert(
+                id,
+                AllocationSite {
+                    id,
+                    site: name.to_string(),
+                    type_info: location.and_then(|l| l.type_info.clone()),
+                    file_path: location.and_then(|l| l.file_path.clone()),
+                    line: location.and_then(|l| l.line),
+                },
+            );
+        }
+        for (name, var) in analyzer.variables() {
+            let mut locations = graph.get_points_to(var);
+            locations.sort_unstable();
+            facts.points_to.insert(name.to_string(), locations);
+            facts
+                .alias_classes
+                .insert(name.to_string(), graph.get_representative(var));
+        }
+        facts
+    }
+
+    pub fn contains(&self, variable: &str) -> bool {
+        self.points_to.contains_key(variable)
+    }
+
+    /// Allocation sites `variable` may point to (empty if unknown)
+    pub fn allocation_sites(&self, variable: &str) -> Vec<&AllocationSite> {
+        self.points_to
+            .get(variable)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| self.sites.get(id))
+            .collect()
+    }
+
+    /// Whether `a` and `b` may refer to the same object: same alias class,
+    /// or a shared allocation site. Unknown variables alias nothing.
+    pub fn may_alias(&self, a: &str, b: &str) -> bool {
+        let (Some(class_a), Some(class_b)) = (self.alias_classes.get(a), self.alias_classes.get(b))
+        else {
+            return false;
+        };
+        if a == b || class_a == class_b {
+            return true;
+        }
+        let (pts_a, pts_b) = (&self.points_to[a], &self.points_to[b]);
+        pts_a.iter().any(|id| pts_b.binary_search(id).is_ok())
+    }
+
+    /// Variables other than `variable` it may alias, by name
+    pub fn alias_set(&self, variable: &str) -> Vec<&str> {
+        if !self.contains(variable) {
+            return Vec::new();
+        }
+        self.points_to
+            .keys()
+            .map(String::as_str)
+            .filter(|other| *other != variable && self.may_alias(variable, other))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::points_to::application::analyzer::{AnalysisConfig, AnalysisMode};
+
+    fn solve(mode: AnalysisMode) -> PointsToResult {
+        let mut analyzer = PointsToAnalyzer::new(AnalysisConfig {
+            mode,
+            ..Default::default()
+        });
+        analyzer.add_alloc("x", "alloc:1:A");
+        analyzer.add_copy("y", "x");
+        analyzer.add_alloc("z", "alloc:2:B");
+        let result = analyzer.solve();
+        PointsToResult::from_analysis(&analyzer, &result)
+    }
+
+    #[test]
+    fn test_queries_points_to_facts() {
+        for mode in [AnalysisMode::Precise, AnalysisMode::Fast] {
+            let facts = solve(mode);
+
+            let sites: Vec<_> = facts
+                .allocation_sites("y")
+                .iter()
+                .map(|s| s.site.as_str())
+                .collect();
+            assert_eq!(sites, vec!["alloc:1:A"]);
+            assert!(facts.may_alias("x", "y"));
+            assert!(!facts.may_alias("x", "z"));
+            assert_eq!(facts.alias_set("x"), vec!["y"]);
+            assert!(facts.alias_set("z").is_empty());
+            assert!(!facts.may_alias("x", "missing"));
+            assert!(facts.allocation_sites("missing").is_empty());
+        }
+    }
+
+    #[test]
+    fn test_round_trips_through_json() {
+        let facts = solve(AnalysisMode::Precise);
+        let json = serde_json::to_string(&facts).unwrap();
+        let restored: PointsToResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, facts);
+        assert!(restored.may_alias("y", "x"));
+    }
+}
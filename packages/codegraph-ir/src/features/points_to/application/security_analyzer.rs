@@ -103,6 +103,8 @@ impl SecurityAnalyzer {
             PTAMode::Fast => ContextStrategy::ObjectSensitive,
             PTAMode::Precise => ContextStrategy::TwoObjectSensitive,
             PTAMode::Hybrid | PTAMode::Auto => ContextStrategy::Selective,
+            PTAMode::CallSiteSensitive => ContextStrategy::CallString(1),
+            PTAMode::ObjectSensitive => ContextStrategy::ObjectSensitive,
         };
 
         Self {
@@ -0,0 +1,595 @@
+//! 1-Limited Context-Sensitive Points-to Solver
+//!
+//! Inclusion-based (Andersen) propagation over `(variable, context)` nodes,
+//! with call bindings resolved per context on the fly:
+//! - **Call-site (1-CFA)**: a callee is analyzed once per call site
+//! - **Object (1-obj)**: a method is analyzed once per receiver allocation
+//!   site; calls without a receiver inherit the caller's context
+//!
+//! The heap is context-insensitive (one cell per allocation site and field)
+//! and the result is projected back onto a plain `PointsToGraph`, so clients
+//! query it like any other solver's output. Variables not scoped to a method
+//! (module-level code, unknown scope) are context-insensitive.
+//!
+//! # Tradeoffs
+//! Each method's variables are duplicated per call site (1-CFA) or per
+//! receiver object (1-obj): expect roughly 2–10× Andersen's time and memory.
+//! 1-CFA separates helpers called from different places; 1-obj separates
+//! methods of different instances of one class (getters/setters, container
+//! wrappers), which is where OO code loses most precision.
+//!
+//! # References
+//! - Shivers "Control-Flow Analysis of Higher-Order Languages" (1991)
+//! - Milanova et al. "Parameterized Object Sensitivity" (TOSEM 2005)
+
+use crate::features::points_to::domain::{
+    abstract_location::{AbstractLocation, LocationId},
+    constraint::{Constraint, ConstraintKind, VarId},
+    points_to_graph::PointsToGraph,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// Method identifier
+pub type MethodId = u32;
+
+/// Context of entry methods and context-insensitive variables
+const EMPTY_CONTEXT: u64 = 0;
+
+/// Field key of plain dereferences, and of every access when field-insensitive
+const DEREF: u32 = u32::MAX;
+
+/// Context abstraction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ContextKind {
+    /// Most recent call site
+    CallSite,
+    /// Allocation site of the receiver
+    Object,
+}
+
+/// Formal parameters of a method
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MethodSignature {
+    /// Positional parameters, receiver excluded
+    pub params: Vec<VarId>,
+    /// Receiver (`self` / `this`)
+    pub this: Option<VarId>,
+    pub ret: Option<VarId>,
+}
+
+/// A call `result = receiver.callee(args)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallSite {
+    /// Calling method (None = module level)
+    pub caller: Option<MethodId>,
+    pub callee: MethodId,
+    pub receiver: Option<VarId>,
+    pub args: Vec<VarId>,
+    pub result: Option<VarId>,
+}
+
+/// Statistics
+#[derive(Debug, Clone, Default)]
+pub struct KLimitedStats {
+    /// (method, context) pairs analyzed
+    pub method_contexts: usize,
+    /// (variable, context) nodes
+    pub context_vars: usize,
+    pub iterations: usize,
+}
+
+/// Solver result (context-insensitive projection)
+#[derive(Debug)]
+pub struct KLimitedResult {
+    pub graph: PointsToGraph,
+    pub stats: KLimitedStats,
+}
+
+/// 1-call-site / 1-object sensitive solver
+pub struct KLimitedSolver {
+    kind: ContextKind,
+    field_sensitive: bool,
+    constraints: Vec<Constraint>,
+    scopes: FxHashMap<VarId, MethodId>,
+    methods: FxHashMap<MethodId, MethodSignature>,
+    calls: Vec<CallSite>,
+}
+
+impl KLimitedSolver {
+    pub fn new(kind: ContextKind, field_sensitive: bool) -> Self {
+        Self {
+            kind,
+            field_sensitive,
+            constraints: Vec::new(),
+            scopes: FxHashMap::default(),
+            methods: FxHashMap::default(),
+            calls: Vec::new(),
+        }
+    }
+
+    pub fn add_constraint(&mut self, constraint: Constraint) {
+        self.constraints.push(constraint);
+    }
+
+    /// Scope a local variable to `method`
+    pub fn set_scope(&mut self, var: VarId, method: MethodId) {
+        self.scopes.insert(var, method);
+    }
+
+    /// Declare a method; its formals are scoped to it
+    pub fn add_method(&mut self, method: MethodId, signature: MethodSignature) {
+        for &var in signature
+            .params
+            .iter()
+            .chain(&signature.this)
+            .chain(&signature.ret)
+        {
+            self.scopes.insert(var, method);
+        }
+        self.methods.insert(method, signature);
+    }
+
+    pub fn add_call(&mut self, call: CallSite) {
+        self.calls.push(call);
+    }
+
+    pub fn solve(&self) -> KLimitedResult {
+        let mut propagation = Propagation::new(self);
+        propagation.run();
+        propagation.into_result()
+    }
+
+    /// Scopes with conflicting variables demoted to context-insensitive:
+    /// a constraint or call argument mixing two methods' variables would
+    /// otherwise have no context to be read in.
+    fn effective_scopes(&self) -> FxHashMap<VarId, MethodId> {
+        let mut scopes = self.scopes.clone();
+        let mut demoted = Vec::new();
+        for c in &self.constraints {
+            if c.kind == ConstraintKind::Alloc {
+                continue;
+            }
+            if let (Some(a), Some(b)) = (self.scopes.get(&c.lhs), self.scopes.get(&c.rhs)) {
+                if a != b {
+                    demoted.extend([c.lhs, c.rhs]);
+                }
+            }
+        }
+        for call in &self.calls {
+            let actuals = call.receiver.iter().chain(&call.args).chain(&call.result);
+            for &var in actuals {
+                match self.scopes.get(&var) {
+                    Some(&scope) if Some(scope) != call.caller => demoted.push(var),
+                    _ => {}
+                }
+            }
+        }
+        for var in demoted {
+            scopes.remove(&var);
+        }
+        scopes
+    }
+
+    fn field_key(&self, field: Option<u32>) -> u32 {
+        match field {
+            Some(f) if self.field_sensitive => f,
+            _ => DEREF,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Key {
+    Var(VarId, u64),
+    Cell(LocationId, u32),
+}
+
+/// Worklist state of one solve
+struct Propagation<'a> {
+    solver: &'a KLimitedSolver,
+    scopes: FxHashMap<VarId, MethodId>,
+    constraints_by_scope: FxHashMap<Option<MethodId>, Vec<usize>>,
+    calls_by_caller: FxHashMap<Option<MethodId>, Vec<usize>>,
+
+    nodes: FxHashMap<Key, usize>,
+    keys: Vec<Key>,
+    pts: Vec<FxHashSet<LocationId>>,
+    succ: Vec<FxHashSet<usize>>,
+    /// Base node → (destination, field)
+    loads: Vec<Vec<(usize, u32)>>,
+    /// Base node → (source, field)
+    stores: Vec<Vec<(usize, u32)>>,
+    /// Receiver node → (call index, caller context), object mode only
+    dispatches: Vec<Vec<(usize, u64)>>,
+
+    reached: FxHashSet<(Option<MethodId>, u64)>,
+    pending: Vec<(Option<MethodId>, u64)>,
+    worklist: Vec<(usize, Vec<LocationId>)>,
+    iterations: usize,
+}
+
+impl<'a> Propagation<'a> {
+    fn new(solver: &'a KLimitedSolver) -> Self {
+        let scopes = solver.effective_scopes();
+        let mut constraints_by_scope: FxHashMap<_, Vec<usize>> = FxHashMap::default();
+        for (i, c) in solver.constraints.iter().enumerate() {
+            let scope = match c.kind {
+                ConstraintKind::Alloc => scopes.get(&c.lhs),
+                _ => scopes.get(&c.lhs).or_else(|| scopes.get(&c.rhs)),
+            };
+            constraints_by_scope
+                .entry(scope.copied())
+                .or_default()
+                .push(i);
+        }
+        let mut calls_by_caller: FxHashMap<_, Vec<usize>> = FxHashMap::default();
+        for (i, call) in solver.calls.iter().enumerate() {
+            calls_by_caller.entry(call.caller).or_default().push(i);
+        }
+        Self {
+            solver,
+            scopes,
+            constraints_by_scope,
+            calls_by_caller,
+            nodes: FxHashMap::default(),
+            keys: Vec::new(),
+            pts: Vec::new(),
+            succ: Vec::new(),
+            loads: Vec::new(),
+            stores: Vec::new(),
+            dispatches: Vec::new(),
+            reached: FxHashSet::default(),
+            pending: Vec::new(),
+            worklist: Vec::new(),
+            iterations: 0,
+        }
+    }
+
+    fn run(&mut self) {
+        let solver = self.solver;
+        let called: FxHashSet<MethodId> = solver.calls.iter().map(|c| c.callee).collect();
+        let methods: BTreeSet<MethodId> = solver
+            .methods
+            .keys()
+            .chain(self.scopes.values())
+            .chain(solver.calls.iter().filter_map(|c| c.caller.as_ref()))
+            .copied()
+            .collect();
+
+        // Module level and methods nobody calls are entry points
+        self.reach(None, EMPTY_CONTEXT);
+        for &m in methods.iter().filter(|m| !called.contains(m)) {
+            self.reach(Some(m), EMPTY_CONTEXT);
+        }
+
+        loop {
+            if let Some((method, ctx)) = self.pending.pop() {
+                self.instantiate(method, ctx);
+            } else if let Some((node, delta)) = self.worklist.pop() {
+                self.iterations += 1;
+                self.propagate(node, &delta);
+            } else {
+                // Methods only called from unreachable code (e.g. recursion
+                // without an outside caller) are analyzed as entry points
+                let reached: FxHashSet<MethodId> =
+                    self.reached.iter().filter_map(|(m, _)| *m).collect();
+                let unreached: Vec<MethodId> = methods
+                    .iter()
+                    .filter(|m| !reached.contains(m))
+                    .copied()
+                    .collect();
+                if unreached.is_empty() {
+                    break;
+                }
+                for m in unreached {
+                    self.reach(Some(m), EMPTY_CONTEXT);
+                }
+            }
+        }
+    }
+
+    fn into_result(self) -> KLimitedResult {
+        let mut graph = PointsToGraph::new();
+        for c in &self.solver.constraints {
+            if c.kind == ConstraintKind::Alloc && graph.get_location(c.rhs).is_none() {
+                graph.add_location(AbstractLocation::new(c.rhs, format!("alloc:{}", c.rhs)));
+            }
+        }
+        let mut context_vars = 0;
+        for (key, pts) in self.keys.iter().zip(&self.pts) {
+            if let Key::Var(var, _) = key {
+                context_vars += 1;
+                for &loc in pts {
+                    graph.add_points_to(*var, loc);
+                }
+            }
+        }
+        graph.update_stats();
+        KLimitedResult {
+            graph,
+            stats: KLimitedStats {
+                method_contexts: self.reached.len(),
+                context_vars,
+                iterations: self.iterations,
+            },
+        }
+    }
+
+    fn reach(&mut self, method: Option<MethodId>, ctx: u64) {
+        if self.reached.insert((method, ctx)) {
+            self.pending.push((method, ctx));
+        }
+    }
+
+    /// Add the constraints and calls of `method` in context `ctx`
+    fn instantiate(&mut self, method: Option<MethodId>, ctx: u64) {
+        let solver = self.solver;
+        let constraints = self
+            .constraints_by_scope
+            .get(&method)
+            .cloned()
+            .unwrap_or_default();
+        for i in constraints {
+            let c = &solver.constraints[i];
+            let field = solver.field_key(c.field);
+            match c.kind {
+                ConstraintKind::Alloc => {
+                    let node = self.var_node(c.lhs, method, ctx);
+                    self.add_pts(node, &[c.rhs]);
+                }
+                ConstraintKind::Copy => {
+                    let (dst, src) = (
+                        self.var_node(c.lhs, method, ctx),
+                        self.var_node(c.rhs, method, ctx),
+                    );
+                    self.add_edge(src, dst);
+                }
+                ConstraintKind::Load => {
+                    let (dst, base) = (
+                        self.var_node(c.lhs, method, ctx),
+                        self.var_node(c.rhs, method, ctx),
+                    );
+                    self.loads[base].push((dst, field));
+                    for loc in self.pts_of(base) {
+                        let cell = self.node(Key::Cell(loc, field));
+                        self.add_edge(cell, dst);
+                    }
+                }
+                ConstraintKind::Store => {
+                    let (base, src) = (
+                        self.var_node(c.lhs, method, ctx),
+                        self.var_node(c.rhs, method, ctx),
+                    );
+                    self.stores[base].push((src, field));
+                    for loc in self.pts_of(base) {
+                        let cell = self.node(Key::Cell(loc, field));
+                        self.add_edge(src, cell);
+                    }
+                }
+            }
+        }
+
+        let calls = self
+            .calls_by_caller
+            .get(&method)
+            .cloned()
+            .unwrap_or_default();
+        for i in calls {
+            let call = &solver.calls[i];
+            match (solver.kind, call.receiver) {
+                (ContextKind::Object, Some(receiver)) => {
+                    let node = self.var_node(receiver, method, ctx);
+                    self.dispatches[node].push((i, ctx));
+                    for loc in self.pts_of(node) {
+                        self.bind(i, ctx, loc as u64 + 1, Some(loc));
+                    }
+                }
+                (ContextKind::Object, None) => self.bind(i, ctx, ctx, None),
+                (ContextKind::CallSite, _) => self.bind(i, ctx, i as u64 + 1, None),
+            }
+        }
+    }
+
+    /// Bind actuals of call `i` (in `caller_ctx`) to the callee's formals in
+    /// `callee_ctx`; `receiver` pins `this` to one object (object mode)
+    fn bind(&mut self, i: usize, caller_ctx: u64, callee_ctx: u64, receiver: Option<LocationId>) {
+        let solver = self.solver;
+        let call = &solver.calls[i];
+        let callee = Some(call.callee);
+        self.reach(callee, callee_ctx);
+        let Some(signature) = solver.methods.get(&call.callee) else {
+            return;
+        };
+
+        for (&arg, &param) in call.args.iter().zip(&signature.params) {
+            let src = self.var_node(arg, call.caller, caller_ctx);
+            let dst = self.var_node(param, callee, callee_ctx);
+            self.add_edge(src, dst);
+        }
+        if let Some(this) = signature.this {
+            let this_node = self.var_node(this, callee, callee_ctx);
+            match (receiver, call.receiver) {
+                (Some(loc), _) => self.add_pts(this_node, &[loc]),
+                (None, Some(actual)) => {
+                    let src = self.var_node(actual, call.caller, caller_ctx);
+                    self.add_edge(src, this_node);
+                }
+                (None, None) => {}
+            }
+        }
+        if let (Some(ret), Some(result)) = (signature.ret, call.result) {
+            let src = self.var_node(ret, callee, callee_ctx);
+            let dst = self.var_node(result, call.caller, caller_ctx);
+            self.add_edge(src, dst);
+        }
+    }
+
+    fn propagate(&mut self, node: usize, delta: &[LocationId]) {
+        let succ: Vec<usize> = self.succ[node].iter().copied().collect();
+        for dst in succ {
+            self.add_pts(dst, delta);
+        }
+        for (dst, field) in self.loads[node].clone() {
+            for &loc in delta {
+                let cell = self.node(Key::Cell(loc, field));
+                self.add_edge(cell, dst);
+            }
+        }
+        for (src, field) in self.stores[node].clone() {
+            for &loc in delta {
+                let cell = self.node(Key::Cell(loc, field));
+                self.add_edge(src, cell);
+            }
+        }
+        for (i, caller_ctx) in self.dispatches[node].clone() {
+            for &loc in delta {
+                self.bind(i, caller_ctx, loc as u64 + 1, Some(loc));
+            }
+        }
+    }
+
+    /// Node of `var` as seen from `method` in context `ctx`
+    fn var_node(&mut self, var: VarId, method: Option<MethodId>, ctx: u64) -> usize {
+        let ctx = match (method, self.scopes.get(&var)) {
+            (Some(m), Some(&scope)) if m == scope => ctx,
+            _ => EMPTY_CONTEXT,
+        };
+        self.node(Key::Var(var, ctx))
+    }
+
+    fn node(&mut self, key: Key) -> usize {
+        if let Some(&node) = self.nodes.get(&key) {
+            return node;
+        }
+        let node = self.keys.len();
+        self.nodes.insert(key, node);
+        self.keys.push(key);
+        self.pts.push(FxHashSet::default());
+        self.succ.push(FxHashSet::default());
+        self.loads.push(Vec::new());
+        self.stores.push(Vec::new());
+        self.dispatches.push(Vec::new());
+        node
+    }
+
+    fn pts_of(&self, node: usize) -> Vec<LocationId> {
+        self.pts[node].iter().copied().collect()
+    }
+
+    fn add_pts(&mut self, node: usize, locations: &[LocationId]) {
+        let added: Vec<LocationId> = locations
+            .iter()
+            .copied()
+            .filter(|&loc| self.pts[node].insert(loc))
+            .collect();
+        if !added.is_empty() {
+            self.worklist.push((node, added));
+        }
+    }
+
+    fn add_edge(&mut self, src: usize, dst: usize) {
+        if src != dst && self.succ[src].insert(dst) {
+            let pts = self.pts_of(src);
+            self.add_pts(dst, &pts);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pts(result: &KLimitedResult, var: VarId) -> Vec<LocationId> {
+        let mut locations = result.graph.get_points_to(var);
+        locations.sort_unstable();
+        locations
+    }
+
+    /// x = A(); y = B(); a = id(x); b = id(y)
+    fn identity_calls(kind: ContextKind) -> KLimitedResult {
+        let mut solver = KLimitedSolver::new(kind, true);
+        solver.add_constraint(Constraint::alloc(1, 1));
+        solver.add_constraint(Constraint::alloc(2, 2));
+        solver.add_method(
+            1,
+            MethodSignature {
+                params: vec![10],
+                this: None,
+                ret: Some(10),
+            },
+        );
+        for (arg, result) in [(1, 3), (2, 4)] {
+            solver.add_call(CallSite {
+                caller: None,
+                callee: 1,
+                receiver: None,
+                args: vec![arg],
+                result: Some(result),
+            });
+        }
+        solver.solve()
+    }
+
+    #[test]
+    fn test_call_site_sensitivity_separates_callers() {
+        let result = identity_calls(ContextKind::CallSite);
+        assert_eq!(pts(&result, 3), vec![1]);
+        assert_eq!(pts(&result, 4), vec![2]);
+        assert!(!result.graph.may_alias(3, 4));
+        // Projection keeps the union over contexts
+        assert_eq!(pts(&result, 10), vec![1, 2]);
+
+        // Receiver-less calls share the caller's context under 1-obj
+        let merged = identity_calls(ContextKind::Object);
+        assert_eq!(pts(&merged, 3), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_object_sensitivity_separates_receivers() {
+        // b1 = Box(); b2 = Box(); b1.set(x); b2.set(y); r1 = b1.get(); r2 = b2.get()
+        let mut solver = KLimitedSolver::new(ContextKind::Object, true);
+        for (var, loc) in [(1, 1), (2, 2), (3, 3), (4, 4)] {
+            solver.add_constraint(Constraint::alloc(var, loc));
+        }
+        solver.add_constraint(Constraint::field_store(10, 0, 11));
+        solver.add_constraint(Constraint::field_load(21, 20, 0));
+        solver.add_method(
+            1,
+            MethodSignature {
+                params: vec![11],
+                this: Some(10),
+                ret: None,
+            },
+        );
+        solver.add_method(
+            2,
+            MethodSignature {
+                params: vec![],
+                this: Some(20),
+                ret: Some(21),
+            },
+        );
+        for (callee, receiver, args, result) in [
+            (1, 1, vec![3], None),
+            (1, 2, vec![4], None),
+            (2, 1, vec![], Some(5)),
+            (2, 2, vec![], Some(6)),
+        ] {
+            solver.add_call(CallSite {
+                caller: None,
+                callee,
+                receiver: Some(receiver),
+                args,
+                result,
+            });
+        }
+
+        let result = solver.solve();
+        assert_eq!(pts(&result, 5), vec![3]);
+        assert_eq!(pts(&result, 6), vec![4]);
+        assert!(!result.graph.may_alias(5, 6));
+        assert!(result.stats.method_contexts >= 5);
+    }
+}
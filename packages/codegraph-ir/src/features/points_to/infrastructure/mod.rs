@@ -8,6 +8,7 @@
 //! - **FlowSensitivePTA**: LOAD/STORE with strong/weak updates
 //! - **WavePropagation**: LCD + topological ordering (SOTA)
 //! - **ContextSensitive**: Object sensitivity + heap cloning (SOTA)
+//! - **KLimitedSolver**: 1-call-site / 1-object sensitive Andersen
 
 pub mod union_find;
 pub mod sparse_bitmap;
@@ -17,6 +18,7 @@ pub mod wave_propagation;
 pub mod scc_detector;
 pub mod flow_sensitive_solver;
 pub mod context_sensitive;
+pub mod k_limited_solver;
 pub mod demand_driven;
 pub mod incremental_pta;
 // pub mod parallel_andersen;  // Disabled: missing crossbeam dependency
@@ -30,4 +32,7 @@ pub use context_sensitive::{
     ContextSensitiveSolver, ContextSensitiveConfig, ContextSensitiveResult,
     ContextStrategy, Context, ContextVar, HeapObject,
 };
+pub use k_limited_solver::{
+    CallSite, ContextKind, KLimitedResult, KLimitedSolver, KLimitedStats, MethodId, MethodSignature,
+};
 pub use wave_propagation::{LazyCycleDetector, WaveWorklist, WaveOrder};
@@ -3,6 +3,7 @@
 //! High-performance pointer analysis implementation combining:
 //! - **Steensgaard's Algorithm**: O(n·α(n)) fast approximation using Union-Find
 //! - **Andersen's Algorithm**: O(n²) precise inclusion-based analysis with SCC optimization
+//! - **1-CFA / 1-Object Contexts**: Andersen per call site or receiver object (`AnalysisMode`)
 //! - **Wave Propagation**: Topological ordering for efficient fixpoint computation
 //! - **Sparse Bitmaps**: Memory-efficient points-to set representation
 //!
@@ -41,6 +42,7 @@ pub mod ports;
 
 // Re-exports for public API
 pub use application::analyzer::{AnalysisConfig, AnalysisMode, PointsToAnalyzer};
+pub use application::points_to_result::{AllocationSite, PointsToResult};
 pub use domain::abstract_location::AbstractLocation;
 pub use domain::constraint::{Constraint, ConstraintKind};
 pub use domain::points_to_graph::PointsToGraph;
@@ -0,0 +1,7 @@
+//! PR Review Application Layer
+
+mod planner;
+mod publisher;
+
+pub use planner::{ReviewPlanner, ReviewThresholds};
+pub use publisher::{PublishSummary, ReviewPublisher};
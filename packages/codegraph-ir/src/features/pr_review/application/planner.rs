@@ -0,0 +1,314 @@
+//! Review planning
+//!
+//! Turns a snapshot comparison into the comments worth posting:
+//!
+//! - Vulnerabilities: findings of head not present in base (by
+//!   `GateFinding::fingerprint`) and the report's introduced findings
+//! - Risky impact: hot zones whose changed symbols reach many dependents
+//! - Complexity regressions: per-file cyclomatic complexity / maintainability
+//!   index deltas between the base and head metrics tables
+
+use std::collections::HashSet;
+
+use crate::features::flow_graph::infrastructure::FileMetricsTable;
+use crate::features::gating::{FindingSeverity, GateFinding};
+use crate::features::pr_review::domain::{fingerprint, ReviewComment, ReviewCommentKind};
+use crate::features::storage::api::snapshot_report::{ChangeKind, SnapshotReport};
+
+/// Changed symbols listed per risky-impact comment
+const MAX_LISTED_SYMBOLS: usize = 10;
+
+/// What is worth a comment
+#[derive(Debug, Clone)]
+pub struct ReviewThresholds {
+    /// Vulnerabilities below this severity are not posted
+    pub min_severity: FindingSeverity,
+    /// Hot zones reaching fewer impacted symbols are not posted
+    pub min_impacted_symbols: usize,
+    /// Cyclomatic complexity increase of a file that is a regression
+    pub cyclomatic_increase: u32,
+    /// Maintainability index drop of a file that is a regression
+    pub maintainability_drop: f64,
+    /// Comments per review (most severe first)
+    pub max_comments: usize,
+}
+
+impl Default for ReviewThresholds {
+    fn default() -> Self {
+        Self {
+            min_severity: FindingSeverity::Medium,
+            min_impacted_symbols: 20,
+            cyclomatic_increase: 10,
+            maintainability_drop: 10.0,
+            max_comments: 50,
+        }
+    }
+}
+
+/// Collects review comments from diff analysis results
+#[derive(Debug, Clone, Default)]
+pub struct ReviewPlanner {
+    thresholds: ReviewThresholds,
+    comments: Vec<ReviewComment>,
+}
+
+impl ReviewPlanner {
+    pub fn new(thresholds: ReviewThresholds) -> Self {
+        Self {
+            thresholds,
+            comments: Vec::new(),
+        }
+    }
+
+    /// Findings of `head` that `base` does not have
+    pub fn with_findings(mut self, base: &[GateFinding], head: &[GateFinding]) -> Self {
+        let known: HashSet<String> = base.iter().map(GateFinding::fingerprint).collect();
+        for finding in head {
+            let id = finding.fingerprint();
+            if known.contains(&id) || finding.severity < self.thresholds.min_severity {
+                continue;
+            }
+            let mut comment = ReviewComment::new(
+                ReviewCommentKind::Vulnerability,
+                finding.severity,
+                &finding.file_path,
+                &finding.rule_id,
+                &finding.message,
+            )
+            .with_fingerprint(id);
+            if let Some(line) = finding.line {
+                comment = comment.with_line(line);
+            }
+            self.comments.push(comment);
+        }
+        self
+    }
+
+    /// Introduced findings and hot zones of a snapshot comparison report
+    pub fn with_snapshot_report(mut self, report: &SnapshotReport) -> Self {
+        for finding in &report.findings.introduced {
+            let severity = FindingSeverity::from_label(&finding.severity);
+            if severity < self.thresholds.min_severity {
+                continue;
+            }
+            let symbol = finding.symbol.as_deref().unwrap_or("");
+            let mut comment = ReviewComment::new(
+                ReviewCommentKind::Vulnerability,
+                severity,
+                &finding.file_path,
+                &finding.rule_id,
+                &finding.message,
+            )
+            .with_fingerprint(fingerprint(&[
+                &finding.rule_id,
+                &finding.file_path,
+                symbol,
+                &finding.message,
+            ]));
+            if let Some(line) = finding.line {
+                comment = comment.with_line(line);
+            }
+            self.comments.push(comment);
+        }
+
+        let min_impacted = self.thresholds.min_impacted_symbols.max(1);
+        for zone in &report.hot_zones {
+            if zone.impacted_symbols < min_impacted {
+                continue;
+            }
+            let changed: Vec<_> = report
+                .symbols
+                .iter()
+                .filter(|s| s.file_path == zone.file_path && s.change != ChangeKind::Removed)
+                .collect();
+            let mut body = String::new();
+            for symbol in changed.iter().take(MAX_LISTED_SYMBOLS) {
+                body.push_str(&format!(
+                    "- `{}` ({})\n",
+                    symbol.fqn,
+                    symbol.change.as_str()
+                ));
+            }
+            if changed.len() > MAX_LISTED_SYMBOLS {
+                body.push_str(&format!(
+                    "- … {} more\n",
+                    changed.len() - MAX_LISTED_SYMBOLS
+                ));
+            }
+            let severity = if zone.impacted_symbols >= min_impacted * 3 {
+                FindingSeverity::High
+            } else {
+                FindingSeverity::Medium
+            };
+            let mut comment = ReviewComment::new(
+                ReviewCommentKind::RiskyImpact,
+                severity,
+                &zone.file_path,
+                format!(
+                    "{} changed symbols reach {} symbols in {} files",
+                    zone.changed_symbols, zone.impacted_symbols, zone.impacted_files
+                ),
+                body,
+            )
+            .with_fingerprint(fingerprint(&[
+                ReviewCommentKind::RiskyImpact.as_str(),
+                &zone.file_path,
+            ]));
+            if let Some(first) = changed.iter().map(|s| s.start_line).min() {
+                comment = comment.with_line(first);
+            }
+            self.comments.push(comment);
+        }
+        self
+    }
+
+    /// Files whose complexity regressed from `base` to `head`
+    pub fn with_metrics(mut self, base: &FileMetricsTable, head: &FileMetricsTable) -> Self {
+        for file in &head.files {
+            let Some(before) = base.file(&file.file_path) else {
+                continue;
+            };
+            let cyclomatic = file.cyclomatic.saturating_sub(before.cyclomatic);
+            let maintainability = before.maintainability_index - file.maintainability_index;
+            let cyclomatic_regressed = cyclomatic >= self.thresholds.cyclomatic_increase.max(1);
+            let maintainability_regressed =
+                maintainability >= self.thresholds.maintainability_drop.max(f64::EPSILON);
+            if !cyclomatic_regressed && !maintainability_regressed {
+                continue;
+            }
+            let body = format!(
+                "| Metric | Base | Head |\n|---|---|---|\n\
+                 | Cyclomatic complexity | {} | {} |\n\
+                 | Cognitive complexity | {} | {} |\n\
+                 | Maintainability index | {:.1} | {:.1} |\n",
+                before.cyclomatic,
+                file.cyclomatic,
+                before.cognitive,
+                file.cognitive,
+                before.maintainability_index,
+                file.maintainability_index
+            );
+            let title = if cyclomatic_regressed {
+                format!("cyclomatic complexity +{}", cyclomatic)
+            } else {
+                format!("maintainability index -{:.1}", maintainability)
+            };
+            self.comments.push(
+                ReviewComment::new(
+                    ReviewCommentKind::ComplexityRegression,
+                    FindingSeverity::Low,
+                    &file.file_path,
+                    title,
+                    body,
+                )
+                .with_fingerprint(fingerprint(&[
+                    ReviewCommentKind::ComplexityRegression.as_str(),
+                    &file.file_path,
+                ])),
+            );
+        }
+        self
+    }
+
+    /// Comments to post: one per fingerprint, most severe first
+    pub fn plan(self) -> Vec<ReviewComment> {
+        let mut seen = HashSet::new();
+        let mut comments: Vec<ReviewComment> = self
+            .comments
+            .into_iter()
+            .filter(|c| seen.insert(c.fingerprint.clone()))
+            .collect();
+        comments.sort_by(|a, b| {
+            b.severity
+                .cmp(&a.severity)
+                .then_with(|| a.file_path.cmp(&b.file_path))
+                .then_with(|| a.line.cmp(&b.line))
+        });
+        comments.truncate(self.thresholds.max_comments);
+        comments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::flow_graph::infrastructure::FileMetrics;
+    use crate::features::gating::FindingCategory;
+
+    fn metrics(path: &str, cyclomatic: u32, maintainability_index: f64) -> FileMetrics {
+        FileMetrics {
+            file_path: path.to_string(),
+            language: "python".to_string(),
+            loc: 100,
+            functions: 4,
+            cyclomatic,
+            cognitive: cyclomatic,
+            halstead_vocabulary: 0,
+            halstead_length: 0,
+            halstead_volume: 0.0,
+            halstead_difficulty: 0.0,
+            halstead_effort: 0.0,
+            maintainability_index,
+        }
+    }
+
+    #[test]
+    fn test_plan_new_findings_and_complexity_regressions() {
+        let sqli = GateFinding::new(
+            FindingCategory::Taint,
+            "SQLInjection",
+            FindingSeverity::High,
+            "src/db.py",
+            "request.args reaches cursor.execute",
+        )
+        .with_line(12);
+        let old = GateFinding::new(
+            FindingCategory::Security,
+            "WeakHash",
+            FindingSeverity::Critical,
+            "src/auth.py",
+            "md5 used for passwords",
+        );
+        let minor = GateFinding::new(
+            FindingCategory::DeadCode,
+            "unused-import",
+            FindingSeverity::Low,
+            "src/db.py",
+            "os is unused",
+        );
+
+        let base_metrics = FileMetricsTable::new(
+            None,
+            vec![
+                metrics("src/db.py", 10, 80.0),
+                metrics("src/api.py", 5, 70.0),
+            ],
+        );
+        let head_metrics = FileMetricsTable::new(
+            None,
+            vec![
+                metrics("src/db.py", 25, 78.0),
+                metrics("src/api.py", 6, 55.0),
+            ],
+        );
+
+        let comments = ReviewPlanner::new(ReviewThresholds::default())
+            .with_findings(
+                std::slice::from_ref(&old),
+                &[old.clone(), sqli.clone(), minor],
+            )
+            .with_metrics(&base_metrics, &head_metrics)
+            .plan();
+
+        assert_eq!(comments.len(), 3);
+        assert_eq!(comments[0].kind, ReviewCommentKind::Vulnerability);
+        assert_eq!(comments[0].fingerprint, sqli.fingerprint());
+        assert_eq!(comments[0].line, Some(12));
+
+        let regressions: Vec<_> = comments[1..].iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(
+            regressions,
+            vec!["maintainability index -15.0", "cyclomatic complexity +15"]
+        );
+    }
+}
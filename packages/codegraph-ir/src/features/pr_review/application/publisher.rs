@@ -0,0 +1,162 @@
+//! Posting planned comments with dedup
+//!
+//! The publisher reads the markers of the comments already on the PR and
+//! posts only comments whose fingerprint is not among them, so re-running
+//! the analysis on every push does not repeat earlier findings.
+
+use std::collections::HashSet;
+
+use crate::features::pr_review::domain::{
+    parse_markers, CommentPlacement, ReviewComment, ReviewPlatform,
+};
+use crate::shared::models::Result;
+
+/// Outcome of `ReviewPublisher::publish`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PublishSummary {
+    /// Fingerprints of the comments posted by this run
+    pub posted: Vec<String>,
+    /// Posted in the conversation although they had a line
+    pub conversation_fallbacks: usize,
+    /// Already on the PR
+    pub duplicates: usize,
+    /// Failed to post (fingerprint, error)
+    pub failed: Vec<(String, String)>,
+}
+
+/// Posts review comments to one PR / MR
+pub struct ReviewPublisher<P: ReviewPlatform> {
+    platform: P,
+}
+
+impl<P: ReviewPlatform> ReviewPublisher<P> {
+    pub fn new(platform: P) -> Self {
+        Self { platform }
+    }
+
+    pub fn platform(&self) -> &P {
+        &self.platform
+    }
+
+    /// Post the comments the PR does not have yet
+    ///
+    /// A failed post does not stop the others; failures are reported in
+    /// the summary and retried by the next run (their marker is missing).
+    pub async fn publish(&self, comments: &[ReviewComment]) -> Result<PublishSummary> {
+        let mut seen: HashSet<String> = self
+            .platform
+            .list_comments()
+            .await?
+            .iter()
+            .flat_map(|posted| parse_markers(&posted.body))
+            .collect();
+
+        let mut summary = PublishSummary::default();
+        for comment in comments {
+            if !seen.insert(comment.fingerprint.clone()) {
+                summary.duplicates += 1;
+                continue;
+            }
+            let body = comment.render(comment.line.is_some());
+            match self.platform.post_comment(comment, &body).await {
+                Ok((_, placement)) => {
+                    if comment.line.is_some() && placement == CommentPlacement::Conversation {
+                        summary.conversation_fallbacks += 1;
+                    }
+                    summary.posted.push(comment.fingerprint.clone());
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "{}: failed to post review comment {}: {}",
+                        self.platform.name(),
+                        comment.fingerprint,
+                        e
+                    );
+                    seen.remove(&comment.fingerprint);
+                    summary
+                        .failed
+                        .push((comment.fingerprint.clone(), e.to_string()));
+                }
+            }
+        }
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::gating::FindingSeverity;
+    use crate::features::pr_review::domain::{PostedComment, ReviewCommentKind};
+    use crate::shared::models::CodegraphError;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MemoryPlatform {
+        comments: Mutex<Vec<PostedComment>>,
+    }
+
+    #[async_trait]
+    impl ReviewPlatform for MemoryPlatform {
+        fn name(&self) -> &'static str {
+            "memory"
+        }
+
+        async fn list_comments(&self) -> Result<Vec<PostedComment>> {
+            Ok(self.comments.lock().unwrap().clone())
+        }
+
+        async fn post_comment(
+            &self,
+            comment: &ReviewComment,
+            body: &str,
+        ) -> Result<(u64, CommentPlacement)> {
+            if comment.file_path == "readonly.py" {
+                return Err(CodegraphError::io("HTTP 403"));
+            }
+            let mut comments = self.comments.lock().unwrap();
+            let id = comments.len() as u64 + 1;
+            comments.push(PostedComment {
+                id,
+                body: body.to_string(),
+            });
+            Ok((id, CommentPlacement::Inline))
+        }
+    }
+
+    fn comment(file: &str, title: &str) -> ReviewComment {
+        ReviewComment::new(
+            ReviewCommentKind::Vulnerability,
+            FindingSeverity::High,
+            file,
+            title,
+            "",
+        )
+        .with_line(3)
+    }
+
+    #[tokio::test]
+    async fn test_publish_skips_posted_findings() {
+        let publisher = ReviewPublisher::new(MemoryPlatform::default());
+        let first = vec![
+            comment("a.py", "SQLInjection"),
+            comment("a.py", "SQLInjection"),
+        ];
+
+        let summary = publisher.publish(&first).await.unwrap();
+        assert_eq!(summary.posted.len(), 1);
+        assert_eq!(summary.duplicates, 1);
+
+        let second = vec![
+            comment("a.py", "SQLInjection").with_line(9),
+            comment("b.py", "XSS"),
+            comment("readonly.py", "PathTraversal"),
+        ];
+        let summary = publisher.publish(&second).await.unwrap();
+        assert_eq!(summary.posted, vec![second[1].fingerprint.clone()]);
+        assert_eq!(summary.duplicates, 1);
+        assert_eq!(summary.failed.len(), 1);
+        assert_eq!(publisher.platform().comments.lock().unwrap().len(), 2);
+    }
+}
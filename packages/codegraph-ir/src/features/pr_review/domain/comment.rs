@@ -0,0 +1,183 @@
+//! Review comments
+//!
+//! Every rendered comment ends with a hidden marker carrying the comment's
+//! fingerprint (`<!-- codegraph-review:3f2a9c... -->`). Platforms keep the
+//! body verbatim, so the markers of already posted comments tell which
+//! findings a PR has seen, without any state outside the PR itself.
+
+use serde::{Deserialize, Serialize};
+
+use crate::features::gating::FindingSeverity;
+
+const MARKER_PREFIX: &str = "<!-- codegraph-review:";
+const MARKER_SUFFIX: &str = " -->";
+
+/// What a comment reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewCommentKind {
+    /// Finding introduced by the head snapshot
+    Vulnerability,
+    /// Change reaching many dependents
+    RiskyImpact,
+    /// File complexity went up (or maintainability down) past the threshold
+    ComplexityRegression,
+}
+
+impl ReviewCommentKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReviewCommentKind::Vulnerability => "vulnerability",
+            ReviewCommentKind::RiskyImpact => "risky_impact",
+            ReviewCommentKind::ComplexityRegression => "complexity_regression",
+        }
+    }
+
+    fn icon(&self) -> &'static str {
+        match self {
+            ReviewCommentKind::Vulnerability => "🚨",
+            ReviewCommentKind::RiskyImpact => "💥",
+            ReviewCommentKind::ComplexityRegression => "📈",
+        }
+    }
+}
+
+/// Comment to post on a PR / MR
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewComment {
+    pub kind: ReviewCommentKind,
+    /// Dedup identity (stable across pushes to the PR)
+    pub fingerprint: String,
+    pub severity: FindingSeverity,
+    pub file_path: String,
+    /// Inline on this line of the head version, if set
+    pub line: Option<u32>,
+    pub title: String,
+    /// Markdown details
+    pub body: String,
+}
+
+impl ReviewComment {
+    pub fn new(
+        kind: ReviewCommentKind,
+        severity: FindingSeverity,
+        file_path: impl Into<String>,
+        title: impl Into<String>,
+        body: impl Into<String>,
+    ) -> Self {
+        let file_path = file_path.into();
+        let title = title.into();
+        let fingerprint = fingerprint(&[kind.as_str(), &file_path, &title]);
+        Self {
+            kind,
+            fingerprint,
+            severity,
+            file_path,
+            line: None,
+            title,
+            body: body.into(),
+        }
+    }
+
+    pub fn with_line(mut self, line: u32) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Replace the default (kind + file + title) fingerprint
+    pub fn with_fingerprint(mut self, fingerprint: impl Into<String>) -> Self {
+        self.fingerprint = fingerprint.into();
+        self
+    }
+
+    /// Markdown body, ending with the dedup marker
+    ///
+    /// `inline` comments sit next to the code and omit the location line.
+    pub fn render(&self, inline: bool) -> String {
+        let mut out = format!(
+            "{} **{}** · {}: {}\n\n",
+            self.kind.icon(),
+            self.severity.as_str().to_ascii_uppercase(),
+            self.kind.as_str().replace('_', " "),
+            self.title
+        );
+        if !inline {
+            match self.line {
+                Some(line) => out.push_str(&format!("`{}:{}`\n\n", self.file_path, line)),
+                None => out.push_str(&format!("`{}`\n\n", self.file_path)),
+            }
+        }
+        if !self.body.is_empty() {
+            out.push_str(self.body.trim_end());
+            out.push_str("\n\n");
+        }
+        out.push_str(&marker(&self.fingerprint));
+        out
+    }
+}
+
+/// Hidden dedup marker for `fingerprint`
+pub fn marker(fingerprint: &str) -> String {
+    format!("{}{}{}", MARKER_PREFIX, fingerprint, MARKER_SUFFIX)
+}
+
+/// Fingerprints of the markers in a posted comment body
+pub fn parse_markers(body: &str) -> Vec<String> {
+    let mut fingerprints = Vec::new();
+    let mut rest = body;
+    while let Some(start) = rest.find(MARKER_PREFIX) {
+        rest = &rest[start + MARKER_PREFIX.len()..];
+        match rest.find(MARKER_SUFFIX) {
+            Some(end) => {
+                let fingerprint = rest[..end].trim();
+                if !fingerprint.is_empty() {
+                    fingerprints.push(fingerprint.to_string());
+                }
+                rest = &rest[end + MARKER_SUFFIX.len()..];
+            }
+            None => break,
+        }
+    }
+    fingerprints
+}
+
+/// 16-hex-digit digest of `parts`
+pub(crate) fn fingerprint(parts: &[&str]) -> String {
+    let mut hasher = blake3::Hasher::new();
+    for part in parts {
+        hasher.update(part.as_bytes());
+        hasher.update(&[0]);
+    }
+    hasher.finalize().to_hex()[..16].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rendered_marker_round_trips() {
+        let comment = ReviewComment::new(
+            ReviewCommentKind::Vulnerability,
+            FindingSeverity::High,
+            "src/db.py",
+            "SQLInjection: user input reaches execute()",
+            "Taint flow: `request.args` → `cursor.execute`",
+        )
+        .with_line(12);
+
+        let inline = comment.render(true);
+        assert!(inline.starts_with("🚨 **HIGH** · vulnerability: SQLInjection"));
+        assert!(!inline.contains("`src/db.py:12`"));
+        assert!(comment.render(false).contains("`src/db.py:12`"));
+        assert_eq!(parse_markers(&inline), vec![comment.fingerprint.clone()]);
+
+        let moved = comment.clone().with_line(40);
+        assert_eq!(moved.fingerprint, comment.fingerprint);
+        assert_eq!(
+            parse_markers("a <!-- codegraph-review:x --> b <!-- codegraph-review:y -->"),
+            vec!["x", "y"]
+        );
+        assert!(parse_markers("<!-- codegraph-review:unterminated").is_empty());
+    }
+}
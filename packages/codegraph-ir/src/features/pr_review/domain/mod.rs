@@ -0,0 +1,8 @@
+//! Domain models for PR review annotations
+
+mod comment;
+mod platform;
+
+pub(crate) use comment::fingerprint;
+pub use comment::{marker, parse_markers, ReviewComment, ReviewCommentKind};
+pub use platform::{CommentPlacement, PostedComment, ReviewPlatform};
@@ -0,0 +1,42 @@
+//! Code review platform port
+
+use async_trait::async_trait;
+
+use super::comment::ReviewComment;
+use crate::shared::models::Result;
+
+/// Comment already on the PR / MR
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostedComment {
+    pub id: u64,
+    pub body: String,
+}
+
+/// Where a posted comment ended up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentPlacement {
+    /// On the commented line of the diff
+    Inline,
+    /// In the PR conversation (no line, or the line is outside the diff)
+    Conversation,
+}
+
+/// One pull / merge request on a review platform (GitHub, GitLab)
+#[async_trait]
+pub trait ReviewPlatform: Send + Sync {
+    /// Platform name for logs ("github", "gitlab")
+    fn name(&self) -> &'static str;
+
+    /// Every comment on the PR (conversation and inline), all pages
+    async fn list_comments(&self) -> Result<Vec<PostedComment>>;
+
+    /// Post `comment` with the rendered `body`
+    ///
+    /// Inline when the comment has a line in the diff, in the conversation
+    /// otherwise.
+    async fn post_comment(
+        &self,
+        comment: &ReviewComment,
+        body: &str,
+    ) -> Result<(u64, CommentPlacement)>;
+}
@@ -0,0 +1,136 @@
+//! GitHub pull request adapter (REST v3)
+//!
+//! Inline comments go to the PR review comments API on the head commit;
+//! comments without a line, or whose line is outside the diff (HTTP 422),
+//! go to the PR conversation (issue comments).
+
+use async_trait::async_trait;
+use reqwest::{Client, Method, RequestBuilder, StatusCode};
+
+use super::{created_id, http_error, list_pages, PER_PAGE, USER_AGENT};
+use crate::features::pr_review::domain::{
+    CommentPlacement, PostedComment, ReviewComment, ReviewPlatform,
+};
+use crate::shared::models::Result;
+
+const PLATFORM: &str = "github";
+
+/// One GitHub pull request
+pub struct GitHubReview {
+    client: Client,
+    token: String,
+    owner: String,
+    repo: String,
+    pr_number: u64,
+    /// Commit inline comments are attached to
+    head_sha: String,
+    api_base: String,
+}
+
+impl GitHubReview {
+    pub fn new(
+        token: impl Into<String>,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        pr_number: u64,
+        head_sha: impl Into<String>,
+    ) -> Self {
+        Self {
+            client: Client::new(),
+            token: token.into(),
+            owner: owner.into(),
+            repo: repo.into(),
+            pr_number,
+            head_sha: head_sha.into(),
+            api_base: "https://api.github.com".to_string(),
+        }
+    }
+
+    /// API base for GitHub Enterprise (`https://host/api/v3`)
+    pub fn with_api_base(mut self, base: impl Into<String>) -> Self {
+        self.api_base = base.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.client
+            .request(
+                method,
+                format!(
+                    "{}/repos/{}/{}/{}",
+                    self.api_base, self.owner, self.repo, path
+                ),
+            )
+            .bearer_auth(&self.token)
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            .header("User-Agent", USER_AGENT)
+    }
+
+    async fn post_conversation(&self, body: &str) -> Result<u64> {
+        let response = self
+            .request(Method::POST, &format!("issues/{}/comments", self.pr_number))
+            .json(&serde_json::json!({ "body": body }))
+            .send()
+            .await
+            .map_err(|e| http_error(PLATFORM, e))?;
+        created_id(PLATFORM, response).await
+    }
+}
+
+#[async_trait]
+impl ReviewPlatform for GitHubReview {
+    fn name(&self) -> &'static str {
+        PLATFORM
+    }
+
+    async fn list_comments(&self) -> Result<Vec<PostedComment>> {
+        let mut comments = Vec::new();
+        for path in [
+            format!("issues/{}/comments", self.pr_number),
+            format!("pulls/{}/comments", self.pr_number),
+        ] {
+            comments.extend(
+                list_pages(PLATFORM, |page| {
+                    self.request(Method::GET, &path)
+                        .query(&[("per_page", PER_PAGE as u32), ("page", page)])
+                })
+                .await?,
+            );
+        }
+        Ok(comments)
+    }
+
+    async fn post_comment(
+        &self,
+        comment: &ReviewComment,
+        body: &str,
+    ) -> Result<(u64, CommentPlacement)> {
+        let Some(line) = comment.line else {
+            let id = self.post_conversation(body).await?;
+            return Ok((id, CommentPlacement::Conversation));
+        };
+
+        let response = self
+            .request(Method::POST, &format!("pulls/{}/comments", self.pr_number))
+            .json(&serde_json::json!({
+                "body": body,
+                "commit_id": self.head_sha,
+                "path": comment.file_path,
+                "line": line,
+                "side": "RIGHT",
+            }))
+            .send()
+            .await
+            .map_err(|e| http_error(PLATFORM, e))?;
+        if response.status() == StatusCode::UNPROCESSABLE_ENTITY {
+            // Line is not part of the diff
+            let id = self.post_conversation(&comment.render(false)).await?;
+            return Ok((id, CommentPlacement::Conversation));
+        }
+        Ok((
+            created_id(PLATFORM, response).await?,
+            CommentPlacement::Inline,
+        ))
+    }
+}
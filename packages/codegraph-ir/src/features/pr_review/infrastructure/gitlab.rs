@@ -0,0 +1,169 @@
+//! GitLab merge request adapter (REST v4)
+//!
+//! Inline comments are diff discussions positioned with the MR's diff refs;
+//! comments without a line, or whose line is outside the diff (HTTP 400),
+//! become MR notes.
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use reqwest::{Client, Method, RequestBuilder, StatusCode};
+use serde::Deserialize;
+
+use super::{created_id, encode_segment, http_error, list_pages, status_error, PER_PAGE};
+use crate::features::pr_review::domain::{
+    CommentPlacement, PostedComment, ReviewComment, ReviewPlatform,
+};
+use crate::shared::models::Result;
+
+const PLATFORM: &str = "gitlab";
+
+/// Commits a diff position refers to
+#[derive(Debug, Clone, Deserialize)]
+struct DiffRefs {
+    base_sha: String,
+    start_sha: String,
+    head_sha: String,
+}
+
+#[derive(Deserialize)]
+struct MergeRequest {
+    diff_refs: Option<DiffRefs>,
+}
+
+/// One GitLab merge request
+pub struct GitLabReview {
+    client: Client,
+    token: String,
+    /// Project id or `group/project` path
+    project: String,
+    mr_iid: u64,
+    api_base: String,
+    /// Fetched on the first inline comment
+    diff_refs: Mutex<Option<DiffRefs>>,
+}
+
+impl GitLabReview {
+    pub fn new(token: impl Into<String>, project: impl Into<String>, mr_iid: u64) -> Self {
+        Self {
+            client: Client::new(),
+            token: token.into(),
+            project: project.into(),
+            mr_iid,
+            api_base: "https://gitlab.com/api/v4".to_string(),
+            diff_refs: Mutex::new(None),
+        }
+    }
+
+    /// API base for self-hosted GitLab (`https://host/api/v4`)
+    pub fn with_api_base(mut self, base: impl Into<String>) -> Self {
+        self.api_base = base.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        let url = format!(
+            "{}/projects/{}/merge_requests/{}{}",
+            self.api_base,
+            encode_segment(&self.project),
+            self.mr_iid,
+            path
+        );
+        self.client
+            .request(method, url)
+            .header("PRIVATE-TOKEN", &self.token)
+    }
+
+    async fn diff_refs(&self) -> Result<Option<DiffRefs>> {
+        let cached = self
+            .diff_refs
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        if cached.is_some() {
+            return Ok(cached);
+        }
+        let response = self
+            .request(Method::GET, "")
+            .send()
+            .await
+            .map_err(|e| http_error(PLATFORM, e))?;
+        if !response.status().is_success() {
+            return Err(status_error(PLATFORM, response).await);
+        }
+        let mr: MergeRequest = response.json().await.map_err(|e| http_error(PLATFORM, e))?;
+        *self.diff_refs.lock().unwrap_or_else(|e| e.into_inner()) = mr.diff_refs.clone();
+        Ok(mr.diff_refs)
+    }
+
+    async fn post_note(&self, body: &str) -> Result<u64> {
+        let response = self
+            .request(Method::POST, "/notes")
+            .json(&serde_json::json!({ "body": body }))
+            .send()
+            .await
+            .map_err(|e| http_error(PLATFORM, e))?;
+        created_id(PLATFORM, response).await
+    }
+}
+
+#[async_trait]
+impl ReviewPlatform for GitLabReview {
+    fn name(&self) -> &'static str {
+        PLATFORM
+    }
+
+    async fn list_comments(&self) -> Result<Vec<PostedComment>> {
+        // Notes include the notes of diff discussions
+        list_pages(PLATFORM, |page| {
+            self.request(Method::GET, "/notes")
+                .query(&[("per_page", PER_PAGE as u32), ("page", page)])
+        })
+        .await
+    }
+
+    async fn post_comment(
+        &self,
+        comment: &ReviewComment,
+        body: &str,
+    ) -> Result<(u64, CommentPlacement)> {
+        let refs = match comment.line {
+            Some(_) => self.diff_refs().await?,
+            None => None,
+        };
+        let (Some(line), Some(refs)) = (comment.line, refs) else {
+            let id = self.post_note(&comment.render(false)).await?;
+            return Ok((id, CommentPlacement::Conversation));
+        };
+
+        let response = self
+            .request(Method::POST, "/discussions")
+            .json(&serde_json::json!({
+                "body": body,
+                "position": {
+                    "position_type": "text",
+                    "base_sha": refs.base_sha,
+                    "start_sha": refs.start_sha,
+                    "head_sha": refs.head_sha,
+                    "new_path": comment.file_path,
+                    "new_line": line,
+                },
+            }))
+            .send()
+            .await
+            .map_err(|e| http_error(PLATFORM, e))?;
+        if response.status() == StatusCode::BAD_REQUEST {
+            // Line is not part of the diff
+            let id = self.post_note(&comment.render(false)).await?;
+            return Ok((id, CommentPlacement::Conversation));
+        }
+        if !response.status().is_success() {
+            return Err(status_error(PLATFORM, response).await);
+        }
+        // A discussion is created with its first note
+        let discussion: serde_json::Value =
+            response.json().await.map_err(|e| http_error(PLATFORM, e))?;
+        let id = discussion["notes"][0]["id"].as_u64().unwrap_or(0);
+        Ok((id, CommentPlacement::Inline))
+    }
+}
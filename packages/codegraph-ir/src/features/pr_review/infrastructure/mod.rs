@@ -0,0 +1,94 @@
+//! PR Review Infrastructure - REST adapters (`http` feature)
+
+mod github;
+mod gitlab;
+
+pub use github::GitHubReview;
+pub use gitlab::GitLabReview;
+
+use serde::Deserialize;
+
+use crate::features::pr_review::domain::PostedComment;
+use crate::shared::models::{CodegraphError, Result};
+
+/// Page size of comment listings (maximum of both APIs)
+const PER_PAGE: usize = 100;
+
+/// Listing stops after this many pages
+const MAX_PAGES: u32 = 50;
+
+const USER_AGENT: &str = concat!("codegraph-review/", env!("CARGO_PKG_VERSION"));
+
+/// Comment as both APIs return it
+#[derive(Deserialize)]
+struct ApiComment {
+    id: u64,
+    #[serde(default)]
+    body: Option<String>,
+}
+
+fn http_error(platform: &str, err: reqwest::Error) -> CodegraphError {
+    CodegraphError::io(format!("{} API: {}", platform, err))
+}
+
+/// Error for a non-success response (the API's message included)
+async fn status_error(platform: &str, response: reqwest::Response) -> CodegraphError {
+    let status = response.status();
+    let text = response.text().await.unwrap_or_default();
+    CodegraphError::io(format!(
+        "{} API: HTTP {}: {}",
+        platform,
+        status,
+        text.chars().take(300).collect::<String>()
+    ))
+}
+
+/// Id of a created comment
+async fn created_id(platform: &str, response: reqwest::Response) -> Result<u64> {
+    if !response.status().is_success() {
+        return Err(status_error(platform, response).await);
+    }
+    let comment: ApiComment = response.json().await.map_err(|e| http_error(platform, e))?;
+    Ok(comment.id)
+}
+
+/// All pages of a comment listing; `request(page)` builds one page request
+async fn list_pages<F>(platform: &str, request: F) -> Result<Vec<PostedComment>>
+where
+    F: Fn(u32) -> reqwest::RequestBuilder,
+{
+    let mut comments = Vec::new();
+    for page in 1..=MAX_PAGES {
+        let response = request(page)
+            .send()
+            .await
+            .map_err(|e| http_error(platform, e))?;
+        if !response.status().is_success() {
+            return Err(status_error(platform, response).await);
+        }
+        let batch: Vec<ApiComment> = response.json().await.map_err(|e| http_error(platform, e))?;
+        let last = batch.len() < PER_PAGE;
+        comments.extend(batch.into_iter().map(|c| PostedComment {
+            id: c.id,
+            body: c.body.unwrap_or_default(),
+        }));
+        if last {
+            break;
+        }
+    }
+    Ok(comments)
+}
+
+/// Percent-encode a URL path segment (GitLab project paths)
+fn encode_segment(segment: &str) -> String {
+    let mut out = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
@@ -0,0 +1,62 @@
+//! PR Review - GitHub / GitLab annotations from snapshot diff analysis
+//!
+//! Posts review comments for what a pull request changes: new
+//! vulnerabilities, changes with a large impact radius and complexity
+//! regressions. Comments carry a hidden fingerprint marker, so re-running
+//! on every push only posts findings the PR has not seen yet.
+//!
+//! # Architecture
+//!
+//! ```text
+//! ┌─────────────────────────────────────────────┐
+//! │ Domain Layer                                │
+//! │  - ReviewComment (kind, severity, marker)   │
+//! │  - ReviewPlatform (port)                    │
+//! └─────────────────────────────────────────────┘
+//!                   ▲
+//!                   │
+//! ┌─────────────────────────────────────────────┐
+//! │ Application Layer                           │
+//! │  - ReviewPlanner (diff results → comments)  │
+//! │  - ReviewPublisher (dedup + post)           │
+//! └─────────────────────────────────────────────┘
+//!                   ▲
+//!                   │
+//! ┌─────────────────────────────────────────────┐
+//! │ Infrastructure Layer (`http` feature)       │
+//! │  - GitHubReview / GitLabReview (REST)       │
+//! └─────────────────────────────────────────────┘
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use codegraph_ir::features::pr_review::{
+//!     GitHubReview, ReviewPlanner, ReviewPublisher, ReviewThresholds,
+//! };
+//!
+//! let comments = ReviewPlanner::new(ReviewThresholds::default())
+//!     .with_findings(&base_findings, &head_findings)
+//!     .with_snapshot_report(&report)
+//!     .with_metrics(&base_metrics, &head_metrics)
+//!     .plan();
+//!
+//! let github = GitHubReview::new(token, "acme", "api", 42, &head_sha);
+//! let summary = ReviewPublisher::new(github).publish(&comments).await?;
+//! println!("{} posted, {} already on the PR", summary.posted.len(), summary.duplicates);
+//! ```
+
+pub mod application;
+pub mod domain;
+#[cfg(feature = "http")]
+pub mod infrastructure;
+
+// Re-export application layer
+pub use application::{PublishSummary, ReviewPlanner, ReviewPublisher, ReviewThresholds};
+
+// Re-exports for convenience
+pub use domain::{
+    CommentPlacement, PostedComment, ReviewComment, ReviewCommentKind, ReviewPlatform,
+};
+#[cfg(feature = "http")]
+pub use infrastructure::{GitHubReview, GitLabReview};
@@ -0,0 +1,198 @@
+//! Complexity report: function metrics aggregated per file and module
+//!
+//! Reads the `FunctionMetrics` the L3 metrics stage attaches to function
+//! node metadata (see `flow_graph::infrastructure::metrics`), so it works on
+//! any node set that went through the pipeline: a single `IRDocument`
+//! (`QueryEngine::complexity`) or `E2EPipelineResult::nodes`. Modules are
+//! the nodes' `module_path`, or the file's directory when unset.
+//!
+//! ```ignore
+//! let report = complexity_report(&result.nodes);
+//! for f in report.most_complex(10) {
+//!     println!("{} cognitive={}", f.fqn, f.metrics.cognitive);
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::features::flow_graph::infrastructure::metrics::FunctionMetrics;
+use crate::shared::models::{Node, NodeKind, Span};
+
+/// Metrics of one function
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FunctionComplexity {
+    pub symbol_id: String,
+    pub fqn: String,
+    pub file_path: String,
+    pub module: String,
+    pub span: Span,
+    pub metrics: FunctionMetrics,
+}
+
+/// Aggregated metrics of a set of functions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ComplexityStats {
+    pub functions: usize,
+    pub total_cyclomatic: u64,
+    pub max_cyclomatic: u32,
+    pub total_cognitive: u64,
+    pub max_cognitive: u32,
+    pub max_nesting: u32,
+    pub loc: u64,
+}
+
+impl ComplexityStats {
+    pub fn add(&mut self, metrics: &FunctionMetrics) {
+        self.functions += 1;
+        self.total_cyclomatic += u64::from(metrics.cyclomatic);
+        self.max_cyclomatic = self.max_cyclomatic.max(metrics.cyclomatic);
+        self.total_cognitive += u64::from(metrics.cognitive);
+        self.max_cognitive = self.max_cognitive.max(metrics.cognitive);
+        self.max_nesting = self.max_nesting.max(metrics.max_nesting);
+        self.loc += u64::from(metrics.loc);
+    }
+
+    pub fn avg_cyclomatic(&self) -> f64 {
+        if self.functions == 0 {
+            return 0.0;
+        }
+        self.total_cyclomatic as f64 / self.functions as f64
+    }
+
+    pub fn avg_cognitive(&self) -> f64 {
+        if self.functions == 0 {
+            return 0.0;
+        }
+        self.total_cognitive as f64 / self.functions as f64
+    }
+}
+
+/// Function metrics with per-file, per-module and total statistics
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ComplexityReport {
+    /// Ordered by file and position
+    pub functions: Vec<FunctionComplexity>,
+    pub files: BTreeMap<String, ComplexityStats>,
+    pub modules: BTreeMap<String, ComplexityStats>,
+    pub total: ComplexityStats,
+}
+
+impl ComplexityReport {
+    /// Metrics of the function node `symbol_id`
+    pub fn function(&self, symbol_id: &str) -> Option<&FunctionComplexity> {
+        self.functions.iter().find(|f| f.symbol_id == symbol_id)
+    }
+
+    /// `n` functions with the highest cognitive complexity (ties: cyclomatic)
+    pub fn most_complex(&self, n: usize) -> Vec<&FunctionComplexity> {
+        let mut ranked: Vec<&FunctionComplexity> = self.functions.iter().collect();
+        ranked.sort_by(|a, b| {
+            (b.metrics.cognitive, b.metrics.cyclomatic)
+                .cmp(&(a.metrics.cognitive, a.metrics.cyclomatic))
+        });
+        ranked.truncate(n);
+        ranked
+    }
+}
+
+/// Report over the function and method nodes carrying metrics
+pub fn complexity_report(nodes: &[Node]) -> ComplexityReport {
+    let mut report = ComplexityReport::default();
+    for node in nodes
+        .iter()
+        .filter(|n| matches!(n.kind, NodeKind::Function | NodeKind::Method))
+    {
+        let Some(metrics) = FunctionMetrics::from_node(node) else {
+            continue;
+        };
+        let module = node.module_path.clone().unwrap_or_else(|| {
+            node.file_path
+                .rsplit_once('/')
+                .map_or(".", |(dir, _)| dir)
+                .to_string()
+        });
+        report
+            .files
+            .entry(node.file_path.clone())
+            .or_default()
+            .add(&metrics);
+        report
+            .modules
+            .entry(module.clone())
+            .or_default()
+            .add(&metrics);
+        report.total.add(&metrics);
+        report.functions.push(FunctionComplexity {
+            symbol_id: node.id.clone(),
+            fqn: node.fqn.clone(),
+            file_path: node.file_path.clone(),
+            module,
+            span: node.span,
+            metrics,
+        });
+    }
+    report.functions.sort_by(|a, b| {
+        (a.file_path.as_str(), a.span.start_line, a.span.start_col).cmp(&(
+            b.file_path.as_str(),
+            b.span.start_line,
+            b.span.start_col,
+        ))
+    });
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::flow_graph::infrastructure::metrics::METRICS_KEY;
+
+    fn function(id: &str, file: &str, line: u32, cyclomatic: u32, cognitive: u32) -> Node {
+        let mut node = Node::new(
+            id.to_string(),
+            NodeKind::Function,
+            format!("pkg.{}", id),
+            file.to_string(),
+            Span::new(line, 0, line + 9, 0),
+        );
+        let metrics = FunctionMetrics {
+            cyclomatic,
+            cognitive,
+            max_nesting: cognitive.min(3),
+            loc: 10,
+        };
+        node.metadata = Some(serde_json::json!({ METRICS_KEY: metrics }).to_string());
+        node
+    }
+
+    #[test]
+    fn test_aggregates_per_file_and_module() {
+        let mut plain = function("plain", "pkg/b.py", 1, 1, 0);
+        plain.metadata = None;
+        let nodes = vec![
+            function("c", "pkg/b.py", 20, 2, 1),
+            function("a", "pkg/a.py", 1, 5, 8),
+            function("b", "pkg/b.py", 1, 3, 2),
+            plain,
+        ];
+
+        let report = complexity_report(&nodes);
+        let ids: Vec<_> = report
+            .functions
+            .iter()
+            .map(|f| f.symbol_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["a", "b", "c"]);
+
+        let file = &report.files["pkg/b.py"];
+        assert_eq!(file.functions, 2);
+        assert_eq!(file.total_cyclomatic, 5);
+        assert_eq!(file.max_cognitive, 2);
+        assert_eq!(file.avg_cyclomatic(), 2.5);
+        assert_eq!(report.modules["pkg"].functions, 3);
+        assert_eq!(report.total.loc, 30);
+
+        assert_eq!(report.most_complex(1)[0].symbol_id, "a");
+        assert_eq!(report.function("c").unwrap().metrics.cyclomatic, 2);
+    }
+}
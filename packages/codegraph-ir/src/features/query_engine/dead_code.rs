@@ -0,0 +1,366 @@
+//! Dead code: functions, methods and classes nothing refers to
+//!
+//! A symbol is referenced when, from outside the symbol itself (recursion
+//! does not count):
+//!
+//! - an edge other than Contains/Defines targets its id, FQN or name
+//!   (calls, reads, inheritance, instantiation, imports from other files)
+//! - an import node binds its FQN or name (re-exports and `from m import f`)
+//! - a read/write/import occurrence names it
+//! - a decorator, base class or type annotation mentions it
+//!
+//! Unresolved targets match by last segment (`self.helper` → `helper`), so
+//! the report errs towards "used". Entry points are never reported: names
+//! and FQNs matching `entry_patterns`, symbols with a decorator matching
+//! `entry_decorators` (route handlers, CLI commands, fixtures), dunder
+//! methods, abstract methods, overrides and symbols in test files.
+//!
+//! Selected from the pipeline config (`overrides.dead_code` in YAML, or
+//! `PipelineConfig::dead_code(..)`); results are in
+//! `E2EPipelineResult::dead_code`.
+//!
+//! ```yaml
+//! overrides:
+//!   dead_code:
+//!     enabled: true
+//!     entry_patterns: ["main", "test_*", "cli.*"]
+//!     entry_decorators: ["*.route", "*.command"]
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::config::{ConfigError, ConfigResult};
+use crate::shared::models::{Edge, EdgeKind, Node, NodeKind, Occurrence, Span, SymbolRole};
+
+fn default_entry_patterns() -> Vec<String> {
+    ["main", "__main__", "test*", "Test*", "setUp*", "tearDown*"]
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+fn default_entry_decorators() -> Vec<String> {
+    [
+        "*route",
+        "*.get",
+        "*.post",
+        "*.put",
+        "*.patch",
+        "*.delete",
+        "*.websocket",
+        "*.command",
+        "*.group",
+        "*.task",
+        "*fixture",
+        "*.register",
+        "*receiver",
+        "*.setter",
+        "*.getter",
+        "*overload",
+        "*override",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect()
+}
+
+/// Dead code report settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DeadCodeConfig {
+    /// Report dead code for the run
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Entry point names (`*` wildcards); patterns with a `.` match FQNs
+    #[serde(default = "default_entry_patterns")]
+    pub entry_patterns: Vec<String>,
+
+    /// Decorators marking entry points (`@app.route(..)` matches `*.route`)
+    #[serde(default = "default_entry_decorators")]
+    pub entry_decorators: Vec<String>,
+}
+
+impl Default for DeadCodeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            entry_patterns: default_entry_patterns(),
+            entry_decorators: default_entry_decorators(),
+        }
+    }
+}
+
+impl DeadCodeConfig {
+    /// Enabled config with the default entry points
+    pub fn enabled() -> Self {
+        Self {
+            enabled: true,
+            ..Self::default()
+        }
+    }
+
+    /// Builder: Add an entry point name or FQN pattern
+    pub fn entry_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.entry_patterns.push(pattern.into());
+        self
+    }
+
+    /// Builder: Add an entry point decorator pattern
+    pub fn entry_decorator(mut self, pattern: impl Into<String>) -> Self {
+        self.entry_decorators.push(pattern.into());
+        self
+    }
+
+    pub fn validate(&self) -> ConfigResult<()> {
+        let empty = self
+            .entry_patterns
+            .iter()
+            .chain(&self.entry_decorators)
+            .any(|p| p.trim().is_empty());
+        if empty {
+            return Err(ConfigError::Validation(
+                "dead_code: entry patterns must not be empty".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn is_entry_point(&self, symbol: &Node, name: &str) -> bool {
+        let by_name = self.entry_patterns.iter().any(|pattern| {
+            let text = if pattern.contains('.') {
+                symbol.fqn.as_str()
+            } else {
+                name
+            };
+            wildcard_match(pattern, text)
+        });
+        by_name
+            || symbol.decorators.iter().flatten().any(|decorator| {
+                let decorator = decorator_name(decorator);
+                self.entry_decorators
+                    .iter()
+                    .any(|pattern| wildcard_match(pattern, decorator))
+            })
+    }
+}
+
+/// Function, method or class without references
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeadSymbol {
+    pub kind: NodeKind,
+    pub name: String,
+    pub fqn: String,
+    pub file_path: String,
+    pub span: Span,
+    pub symbol_id: String,
+}
+
+/// Unreferenced functions, methods and classes, ordered by file and position
+pub fn find_dead_code(
+    nodes: &[Node],
+    edges: &[Edge],
+    occurrences: &[Occurrence],
+    config: &DeadCodeConfig,
+) -> Vec<DeadSymbol> {
+    // Referenced name → ids of the referring symbols ("" = unknown)
+    let mut refs: HashMap<&str, HashSet<&str>> = HashMap::new();
+
+    let mut overrides = HashSet::new();
+    for edge in edges {
+        match edge.kind {
+            EdgeKind::Contains | EdgeKind::Defines => {}
+            EdgeKind::Overrides => {
+                overrides.insert(edge.source_id.as_str());
+            }
+            _ => add_reference(&mut refs, &edge.target_id, &edge.source_id),
+        }
+    }
+    for node in nodes {
+        if node.kind == NodeKind::Import {
+            add_reference(&mut refs, &node.fqn, &node.id);
+            if let Some(name) = node.name.as_deref() {
+                add_reference(&mut refs, name, &node.id);
+            }
+        }
+        let mentions = [node.type_annotation.as_deref(), node.return_type.as_deref()]
+            .into_iter()
+            .flatten()
+            .chain(node.decorators.iter().flatten().map(String::as_str))
+            .chain(node.base_classes.iter().flatten().map(String::as_str));
+        for text in mentions {
+            for token in text.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.')) {
+                add_reference(&mut refs, token, &node.id);
+            }
+        }
+    }
+    let used =
+        SymbolRole::ReadAccess as u8 | SymbolRole::WriteAccess as u8 | SymbolRole::Import as u8;
+    for occ in occurrences.iter().filter(|o| o.roles & used != 0) {
+        let from = occ.parent_symbol_id.as_deref().unwrap_or("");
+        add_reference(&mut refs, &occ.symbol_id, from);
+    }
+
+    let mut dead: Vec<DeadSymbol> = nodes
+        .iter()
+        .filter(|n| {
+            matches!(
+                n.kind,
+                NodeKind::Function | NodeKind::Method | NodeKind::Class
+            )
+        })
+        .filter_map(|symbol| {
+            let name = symbol
+                .name
+                .as_deref()
+                .unwrap_or_else(|| symbol.fqn.rsplit('.').next().unwrap_or(&symbol.fqn));
+            let exempt = name.is_empty()
+                || (name.starts_with("__") && name.ends_with("__"))
+                || symbol.is_abstract == Some(true)
+                || symbol.is_test_file == Some(true)
+                || overrides.contains(symbol.id.as_str())
+                || config.is_entry_point(symbol, name);
+            if exempt {
+                return None;
+            }
+            let referenced = [symbol.id.as_str(), symbol.fqn.as_str(), name]
+                .iter()
+                .filter_map(|key| refs.get(key))
+                .any(|from| from.iter().any(|&id| id != symbol.id));
+            if referenced {
+                return None;
+            }
+            Some(DeadSymbol {
+                kind: symbol.kind,
+                name: name.to_string(),
+                fqn: symbol.fqn.clone(),
+                file_path: symbol.file_path.clone(),
+                span: symbol.span,
+                symbol_id: symbol.id.clone(),
+            })
+        })
+        .collect();
+    dead.sort_by(|a, b| {
+        (a.file_path.as_str(), a.span.start_line, a.span.start_col).cmp(&(
+            b.file_path.as_str(),
+            b.span.start_line,
+            b.span.start_col,
+        ))
+    });
+    dead
+}
+
+/// Record `name` (and its last segment) as referenced from `from`
+fn add_reference<'a>(refs: &mut HashMap<&'a str, HashSet<&'a str>>, name: &'a str, from: &'a str) {
+    if name.is_empty() {
+        return;
+    }
+    refs.entry(name).or_default().insert(from);
+    let last = name.rsplit('.').next().unwrap_or(name);
+    if last != name {
+        refs.entry(last).or_default().insert(from);
+    }
+}
+
+/// `@app.route("/users")` -> `app.route`
+fn decorator_name(decorator: &str) -> &str {
+    let decorator = decorator.trim().trim_start_matches('@');
+    decorator[..decorator.find('(').unwrap_or(decorator.len())].trim()
+}
+
+/// `*` matches any run of characters, everything else itself
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // No wildcard
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(i) => rest = &rest[i + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: &str, kind: NodeKind, fqn: &str, name: &str, line: u32) -> Node {
+        Node::new(
+            id.to_string(),
+            kind,
+            fqn.to_string(),
+            "app/views.py".to_string(),
+            Span::new(line, 0, line + 2, 0),
+        )
+        .with_name(name)
+    }
+
+    fn calls(source: &str, target: &str) -> Edge {
+        Edge::new(source.to_string(), target.to_string(), EdgeKind::Calls)
+    }
+
+    #[test]
+    fn test_unreferenced_symbols_excluding_entry_points() {
+        let mut route = node("r", NodeKind::Function, "app.views.index", "index", 1);
+        route.decorators = Some(vec!["@app.route(\"/\")".to_string()]);
+        let nodes = vec![
+            route,
+            node("h", NodeKind::Function, "app.views.helper", "helper", 5),
+            node("o", NodeKind::Function, "app.views.orphan", "orphan", 9),
+            node("rec", NodeKind::Function, "app.views.walk", "walk", 13),
+            node("c", NodeKind::Class, "app.views.View", "View", 17),
+            node("m", NodeKind::Method, "app.views.View.render", "render", 18),
+            node(
+                "init",
+                NodeKind::Method,
+                "app.views.View.__init__",
+                "__init__",
+                19,
+            ),
+            node("main", NodeKind::Function, "app.views.main", "main", 25),
+            node(
+                "t",
+                NodeKind::Function,
+                "app.views.test_index",
+                "test_index",
+                30,
+            ),
+        ];
+        let edges = vec![
+            calls("r", "helper"),
+            calls("main", "app.views.View"),
+            calls("main", "self.render"),
+            // Recursion alone does not keep a function alive
+            calls("rec", "app.views.walk"),
+        ];
+
+        let dead = find_dead_code(&nodes, &edges, &[], &DeadCodeConfig::enabled());
+        let names: Vec<_> = dead.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["orphan", "walk"]);
+        assert_eq!(dead[0].span.start_line, 9);
+
+        let config = DeadCodeConfig::enabled().entry_pattern("app.views.orphan");
+        let dead = find_dead_code(&nodes, &edges, &[], &config);
+        assert_eq!(dead.len(), 1);
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("test*", "test_index"));
+        assert!(wildcard_match("*.route", "app.route"));
+        assert!(wildcard_match("a*b*c", "axxbyyc"));
+        assert!(!wildcard_match("*.route", "route"));
+        assert!(!wildcard_match("main", "main2"));
+        assert!(!wildcard_match("ab*ba", "aba"));
+    }
+}
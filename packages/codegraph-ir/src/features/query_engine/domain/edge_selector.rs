@@ -1,6 +1,7 @@
 // Domain: EdgeSelector - Type-safe edge filtering
 // Maps to Python: src/contexts/code_foundation/domain/query/selectors.py
 
+use crate::shared::models::{Edge, EdgeStage};
 use serde::{Deserialize, Serialize};
 
 /// Edge types (matches Python EdgeType)
@@ -24,6 +25,12 @@ pub struct EdgeSelector {
     pub backward: bool,
     pub min_depth: usize,
     pub max_depth: usize,
+    /// Only traverse edges created by these stages (empty = any)
+    #[serde(default)]
+    pub stages: Vec<EdgeStage>,
+    /// Only traverse edges created by these rules (empty = any)
+    #[serde(default)]
+    pub rules: Vec<String>,
 }
 
 impl EdgeSelector {
@@ -33,6 +40,8 @@ impl EdgeSelector {
             backward: false,
             min_depth: 1,
             max_depth: 10,
+            stages: Vec::new(),
+            rules: Vec::new(),
         }
     }
 
@@ -47,9 +56,54 @@ impl EdgeSelector {
         self
     }
 
+    /// Restrict traversal to edges created by `stage` (repeatable = union)
+    pub fn from_stage(mut self, stage: EdgeStage) -> Self {
+        if !self.stages.contains(&stage) {
+            self.stages.push(stage);
+        }
+        self
+    }
+
+    /// Restrict traversal to edges created by `rule` (repeatable = union)
+    pub fn from_rule(mut self, rule: impl Into<String>) -> Self {
+        let rule = rule.into();
+        if !self.rules.contains(&rule) {
+            self.rules.push(rule);
+        }
+        self
+    }
+
     pub fn is_forward(&self) -> bool {
         !self.backward
     }
+
+    /// Has a provenance (stage/rule) filter?
+    pub fn has_provenance_filter(&self) -> bool {
+        !self.stages.is_empty() || !self.rules.is_empty()
+    }
+
+    /// Check edge provenance against the stage/rule filters
+    ///
+    /// Edges without provenance only match when no filter is set.
+    pub fn matches_provenance(&self, edge: &Edge) -> bool {
+        if !self.has_provenance_filter() {
+            return true;
+        }
+        let Some(provenance) = edge.provenance() else {
+            return false;
+        };
+        if !self.stages.is_empty() && !self.stages.contains(&provenance.stage) {
+            return false;
+        }
+        if !self.rules.is_empty() {
+            match &provenance.rule {
+                Some(rule) => self.rules.iter().any(|r| r == rule),
+                None => false,
+            }
+        } else {
+            true
+        }
+    }
 }
 
 impl Default for EdgeSelector {
@@ -117,6 +171,31 @@ mod tests {
         assert_eq!(selector.min_depth, 1);
     }
 
+    #[test]
+    fn test_edge_selector_provenance_filter() {
+        use crate::shared::models::EdgeProvenance;
+
+        let l1 = Edge::calls("a", "b")
+            .with_provenance(EdgeProvenance::new(EdgeStage::IrBuild).with_rule("call_site"));
+        let devirt = Edge::calls("a", "c").with_provenance(
+            EdgeProvenance::new(EdgeStage::Devirtualization).with_rule("class_hierarchy"),
+        );
+        let legacy = Edge::calls("a", "d");
+
+        let any = EdgeSelector::new(EdgeType::Call);
+        assert!(any.matches_provenance(&l1));
+        assert!(any.matches_provenance(&legacy));
+
+        let by_stage = EdgeSelector::new(EdgeType::Call).from_stage(EdgeStage::Devirtualization);
+        assert!(!by_stage.matches_provenance(&l1));
+        assert!(by_stage.matches_provenance(&devirt));
+        assert!(!by_stage.matches_provenance(&legacy));
+
+        let by_rule = EdgeSelector::new(EdgeType::Call).from_rule("call_site");
+        assert!(by_rule.matches_provenance(&l1));
+        assert!(!by_rule.matches_provenance(&devirt));
+    }
+
     #[test]
     fn test_edge_type_union() {
         let union = EdgeType::DFG | EdgeType::Call;
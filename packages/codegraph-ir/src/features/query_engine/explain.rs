@@ -0,0 +1,97 @@
+//! Query explain reports
+//!
+//! `QueryEngine::*_explain` variants return a `QueryExplain` next to the
+//! results: one `ExplainStep` per execution step with the index it used,
+//! candidates going in and out, and its wall time.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// One execution step
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExplainStep {
+    pub name: &'static str,
+    /// Index or strategy consulted ("GraphIndex.nodes", "full scan", ...)
+    pub index: String,
+    /// Candidates considered
+    pub input: usize,
+    /// Candidates kept
+    pub output: usize,
+    pub duration: Duration,
+}
+
+/// Per-step trace of one query
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryExplain {
+    /// What was asked
+    pub query: String,
+    pub steps: Vec<ExplainStep>,
+    pub total: Duration,
+    started: Option<Instant>,
+}
+
+impl QueryExplain {
+    /// Start timing `query`
+    pub fn start(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            steps: Vec::new(),
+            total: Duration::ZERO,
+            started: Some(Instant::now()),
+        }
+    }
+
+    /// Record a step that began at `started`
+    pub fn record(
+        &mut self,
+        name: &'static str,
+        index: impl Into<String>,
+        input: usize,
+        output: usize,
+        started: Instant,
+    ) {
+        self.steps.push(ExplainStep {
+            name,
+            index: index.into(),
+            input,
+            output,
+            duration: started.elapsed(),
+        });
+    }
+
+    /// Stop the total timer
+    pub fn finish(mut self) -> Self {
+        if let Some(started) = self.started.take() {
+            self.total = started.elapsed();
+        }
+        self
+    }
+
+    /// Step that took longest
+    pub fn slowest(&self) -> Option<&ExplainStep> {
+        self.steps.iter().max_by_key(|s| s.duration)
+    }
+}
+
+impl fmt::Display for QueryExplain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} ({:.3}ms)",
+            self.query,
+            self.total.as_secs_f64() * 1000.0
+        )?;
+        for step in &self.steps {
+            writeln!(
+                f,
+                "  {:<14} {:<32} {:>8} -> {:<8} {:.3}ms",
+                step.name,
+                step.index,
+                step.input,
+                step.output,
+                step.duration.as_secs_f64() * 1000.0
+            )?;
+        }
+        Ok(())
+    }
+}
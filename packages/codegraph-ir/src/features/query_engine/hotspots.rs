@@ -0,0 +1,134 @@
+//! Hotspots: functions that change often and are complex
+//!
+//! Joins the `SymbolHistory` of the git blame stage with the L3
+//! `FunctionMetrics` on each function node; the score is churn (commits in
+//! the churn window) × cyclomatic complexity. Works on
+//! `E2EPipelineResult::nodes` of a run with the `git_blame` stage, or an
+//! `IRDocument` built from them (`QueryEngine::hotspots`).
+//!
+//! ```ignore
+//! for h in find_hotspots(&result.nodes, 10) {
+//!     println!("{} churn={} cc={} ({:?})", h.fqn, h.churn, h.cyclomatic, h.primary_author);
+//! }
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::features::flow_graph::infrastructure::metrics::FunctionMetrics;
+use crate::features::git_history::domain::SymbolHistory;
+use crate::shared::models::{Node, NodeKind, Span};
+
+/// One ranked hotspot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Hotspot {
+    pub symbol_id: String,
+    pub fqn: String,
+    pub file_path: String,
+    pub span: Span,
+    pub churn: u32,
+    pub file_churn: u32,
+    pub cyclomatic: u32,
+    pub cognitive: u32,
+    /// churn × cyclomatic
+    pub score: u64,
+    pub primary_author: Option<String>,
+    pub last_modified: DateTime<Utc>,
+}
+
+/// `limit` functions with the highest churn × complexity (ties: churn)
+///
+/// Functions without history or metrics, or without recent commits, are
+/// not hotspots.
+pub fn find_hotspots(nodes: &[Node], limit: usize) -> Vec<Hotspot> {
+    let mut hotspots: Vec<Hotspot> = nodes
+        .iter()
+        .filter(|n| matches!(n.kind, NodeKind::Function | NodeKind::Method))
+        .filter_map(|node| {
+            let history = SymbolHistory::from_node(node)?;
+            let metrics = FunctionMetrics::from_node(node)?;
+            let score = u64::from(history.churn) * u64::from(metrics.cyclomatic);
+            (score > 0).then(|| Hotspot {
+                symbol_id: node.id.clone(),
+                fqn: node.fqn.clone(),
+                file_path: node.file_path.clone(),
+                span: node.span,
+                churn: history.churn,
+                file_churn: history.file_churn,
+                cyclomatic: metrics.cyclomatic,
+                cognitive: metrics.cognitive,
+                score,
+                primary_author: history.primary_author().map(|a| a.email.clone()),
+                last_modified: history.last_modified,
+            })
+        })
+        .collect();
+    hotspots.sort_by(|a, b| {
+        (b.score, b.churn)
+            .cmp(&(a.score, a.churn))
+            .then_with(|| a.fqn.cmp(&b.fqn))
+    });
+    hotspots.truncate(limit);
+    hotspots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::flow_graph::infrastructure::metrics::METRICS_KEY;
+    use crate::features::git_history::domain::{AuthorShare, SYMBOL_HISTORY_KEY};
+
+    fn function(id: &str, churn: u32, cyclomatic: u32) -> Node {
+        let mut node = Node::new(
+            id.to_string(),
+            NodeKind::Function,
+            format!("pkg.{}", id),
+            "pkg/a.py".to_string(),
+            Span::new(1, 0, 10, 0),
+        );
+        let metrics = FunctionMetrics {
+            cyclomatic,
+            cognitive: cyclomatic,
+            max_nesting: 1,
+            loc: 10,
+        };
+        let history = SymbolHistory {
+            authors: vec![AuthorShare {
+                name: "ann".to_string(),
+                email: "ann@x.dev".to_string(),
+                lines: 10,
+            }],
+            last_commit: "c1".to_string(),
+            last_author: "ann".to_string(),
+            last_author_email: "ann@x.dev".to_string(),
+            last_modified: DateTime::from_timestamp(1_800_000_000, 0).unwrap(),
+            churn,
+            file_churn: churn,
+            churn_window_days: 90,
+        };
+        node.metadata = Some(
+            serde_json::json!({ METRICS_KEY: metrics, SYMBOL_HISTORY_KEY: history }).to_string(),
+        );
+        node
+    }
+
+    #[test]
+    fn test_ranks_churn_times_complexity() {
+        let mut plain = function("plain", 9, 9);
+        plain.metadata = None;
+        let nodes = vec![
+            function("simple_busy", 8, 1),
+            function("complex_busy", 4, 6),
+            function("complex_quiet", 0, 20),
+            function("medium", 3, 3),
+            plain,
+        ];
+
+        let hotspots = find_hotspots(&nodes, 2);
+        let ids: Vec<_> = hotspots.iter().map(|h| h.symbol_id.as_str()).collect();
+        assert_eq!(ids, vec!["complex_busy", "medium"]);
+        assert_eq!(hotspots[0].score, 24);
+        assert_eq!(hotspots[0].primary_author.as_deref(), Some("ann@x.dev"));
+        assert_eq!(find_hotspots(&nodes, 10).len(), 3);
+    }
+}
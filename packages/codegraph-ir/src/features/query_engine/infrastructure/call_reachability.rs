@@ -0,0 +1,313 @@
+// Infrastructure: CallReachabilityIndex - Compressed call-graph reachability
+// Precomputed "can A reach B" over Calls edges, exportable to disk
+
+use crate::shared::models::{CodegraphError, Edge, EdgeKind, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Components above which `build` gives up (bitsets grow quadratically)
+pub const MAX_INDEXED_COMPONENTS: usize = 20_000;
+
+/// Call-graph reachability index
+///
+/// Strategy:
+/// - Collapse the call graph into strongly connected components (mutually
+///   recursive functions share one row)
+/// - Store one bitset per component over all components, filled bottom-up
+///   in reverse topological order
+/// - O(1) `can_reach` after O(C * (C + E) / 64) build, C = components
+///
+/// Memory is C² bits, so graphs with more than `MAX_INDEXED_COMPONENTS`
+/// components are not indexed; `QueryEngine::can_reach` answers those with
+/// on-line BFS.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CallReachabilityIndex {
+    /// Function id -> component
+    components: HashMap<String, u32>,
+    component_count: usize,
+    /// u64 words per bitset row
+    words: usize,
+    /// `component_count` rows of `words` words
+    reach: Vec<u64>,
+}
+
+impl CallReachabilityIndex {
+    /// Build from the Calls edges in `edges` (other kinds are ignored)
+    ///
+    /// Returns `None` when the graph exceeds `MAX_INDEXED_COMPONENTS`.
+    pub fn build<'a>(edges: impl IntoIterator<Item = &'a Edge>) -> Option<Self> {
+        Self::build_with_limit(edges, MAX_INDEXED_COMPONENTS)
+    }
+
+    pub fn build_with_limit<'a>(
+        edges: impl IntoIterator<Item = &'a Edge>,
+        max_components: usize,
+    ) -> Option<Self> {
+        let mut ids: HashMap<&str, u32> = HashMap::new();
+        let mut names: Vec<&str> = Vec::new();
+        let mut adjacency: Vec<Vec<u32>> = Vec::new();
+        let mut intern = |id: &'a str, adjacency: &mut Vec<Vec<u32>>| -> u32 {
+            *ids.entry(id).or_insert_with(|| {
+                names.push(id);
+                adjacency.push(Vec::new());
+                (names.len() - 1) as u32
+            })
+        };
+        for edge in edges {
+            if edge.kind != EdgeKind::Calls {
+                continue;
+            }
+            let source = intern(&edge.source_id, &mut adjacency);
+            let target = intern(&edge.target_id, &mut adjacency);
+            adjacency[source as usize].push(target);
+        }
+
+        let (component_of, component_count) = strongly_connected(&adjacency);
+        if component_count > max_components {
+            return None;
+        }
+
+        // Component successors (self-edge marks a cycle)
+        let mut successors: Vec<Vec<u32>> = vec![Vec::new(); component_count];
+        for (v, targets) in adjacency.iter().enumerate() {
+            for &w in targets {
+                successors[component_of[v] as usize].push(component_of[w as usize]);
+            }
+        }
+
+        // Tarjan emits components sinks first: successors have lower ids
+        let words = component_count.div_ceil(64);
+        let mut reach = vec![0u64; component_count * words];
+        for (c, succ) in successors.iter_mut().enumerate() {
+            succ.sort_unstable();
+            succ.dedup();
+            let (done, rest) = reach.split_at_mut(c * words);
+            let row = &mut rest[..words];
+            for &d in succ.iter() {
+                let d = d as usize;
+                row[d / 64] |= 1 << (d % 64);
+                if d != c {
+                    for (word, bits) in row.iter_mut().zip(&done[d * words..(d + 1) * words]) {
+                        *word |= bits;
+                    }
+                }
+            }
+        }
+
+        let components = names
+            .iter()
+            .enumerate()
+            .map(|(v, name)| (name.to_string(), component_of[v]))
+            .collect();
+        Some(Self {
+            components,
+            component_count,
+            words,
+            reach,
+        })
+    }
+
+    /// Function id known to the index
+    pub fn contains(&self, function_id: &str) -> bool {
+        self.components.contains_key(function_id)
+    }
+
+    /// Whether `target` is reachable from `source` through one or more calls
+    pub fn can_reach(&self, source: &str, target: &str) -> bool {
+        match (self.components.get(source), self.components.get(target)) {
+            (Some(&s), Some(&t)) => self.bit(s as usize, t as usize),
+            _ => false,
+        }
+    }
+
+    /// Functions reachable from `source` (unordered)
+    pub fn reachable_from(&self, source: &str) -> Vec<&str> {
+        let Some(&s) = self.components.get(source) else {
+            return Vec::new();
+        };
+        self.components
+            .iter()
+            .filter(|(_, &c)| self.bit(s as usize, c as usize))
+            .map(|(id, _)| id.as_str())
+            .collect()
+    }
+
+    fn bit(&self, row: usize, column: usize) -> bool {
+        self.reach[row * self.words + column / 64] & (1 << (column % 64)) != 0
+    }
+
+    pub fn function_count(&self) -> usize {
+        self.components.len()
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.component_count
+    }
+
+    /// Bitset payload size
+    pub fn size_bytes(&self) -> usize {
+        self.reach.len() * std::mem::size_of::<u64>()
+    }
+
+    /// Write as MessagePack
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let bytes = rmp_serde::to_vec(self).map_err(|e| {
+            CodegraphError::storage(format!("Failed to encode reachability index: {}", e))
+        })?;
+        fs::write(path.as_ref(), bytes).map_err(|e| {
+            CodegraphError::io(format!(
+                "Failed to write reachability index {}: {}",
+                path.as_ref().display(),
+                e
+            ))
+        })
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let bytes = fs::read(path.as_ref()).map_err(|e| {
+            CodegraphError::io(format!(
+                "Failed to read reachability index {}: {}",
+                path.as_ref().display(),
+                e
+            ))
+        })?;
+        let index: Self = rmp_serde::from_slice(&bytes)
+            .map_err(|e| CodegraphError::storage(format!("Invalid reachability index: {}", e)))?;
+        if index.reach.len() != index.component_count * index.words {
+            return Err(CodegraphError::storage(
+                "Invalid reachability index: bitset size mismatch",
+            ));
+        }
+        Ok(index)
+    }
+}
+
+/// Iterative Tarjan: (component per vertex, component count)
+fn strongly_connected(adjacency: &[Vec<u32>]) -> (Vec<u32>, usize) {
+    const UNVISITED: u32 = u32::MAX;
+    let n = adjacency.len();
+    let mut order = vec![UNVISITED; n];
+    let mut low = vec![0u32; n];
+    let mut on_stack = vec![false; n];
+    let mut stack: Vec<u32> = Vec::new();
+    let mut component = vec![UNVISITED; n];
+    let mut count = 0u32;
+    let mut next = 0u32;
+    // (vertex, next successor position)
+    let mut frames: Vec<(u32, usize)> = Vec::new();
+
+    for root in 0..n {
+        if order[root] != UNVISITED {
+            continue;
+        }
+        order[root] = next;
+        low[root] = next;
+        next += 1;
+        stack.push(root as u32);
+        on_stack[root] = true;
+        frames.push((root as u32, 0));
+
+        while let Some(frame) = frames.last_mut() {
+            let v = frame.0 as usize;
+            if let Some(&w) = adjacency[v].get(frame.1) {
+                frame.1 += 1;
+                let w = w as usize;
+                if order[w] == UNVISITED {
+                    order[w] = next;
+                    low[w] = next;
+                    next += 1;
+                    stack.push(w as u32);
+                    on_stack[w] = true;
+                    frames.push((w as u32, 0));
+                } else if on_stack[w] {
+                    low[v] = low[v].min(order[w]);
+                }
+                continue;
+            }
+
+            frames.pop();
+            if let Some(&(parent, _)) = frames.last() {
+                low[parent as usize] = low[parent as usize].min(low[v]);
+            }
+            if low[v] == order[v] {
+                while let Some(w) = stack.pop() {
+                    on_stack[w as usize] = false;
+                    component[w as usize] = count;
+                    if w as usize == v {
+                        break;
+                    }
+                }
+                count += 1;
+            }
+        }
+    }
+
+    (component, count as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn calls(pairs: &[(&str, &str)]) -> Vec<Edge> {
+        pairs
+            .iter()
+            .map(|(s, t)| Edge::new(s.to_string(), t.to_string(), EdgeKind::Calls))
+            .collect()
+    }
+
+    #[test]
+    fn test_chain_and_cycle_reachability() {
+        // main -> parse -> (eval <-> apply) -> log; util is unreachable
+        let edges = calls(&[
+            ("main", "parse"),
+            ("parse", "eval"),
+            ("eval", "apply"),
+            ("apply", "eval"),
+            ("apply", "log"),
+            ("util", "log"),
+        ]);
+        let index = CallReachabilityIndex::build(&edges).unwrap();
+
+        assert_eq!(index.function_count(), 6);
+        assert_eq!(index.component_count(), 5);
+        assert!(index.can_reach("main", "log"));
+        assert!(index.can_reach("eval", "eval")); // recursion
+        assert!(!index.can_reach("main", "main"));
+        assert!(!index.can_reach("main", "util"));
+        assert!(!index.can_reach("log", "main"));
+        assert!(!index.can_reach("main", "unknown"));
+
+        let mut reachable = index.reachable_from("parse");
+        reachable.sort();
+        assert_eq!(reachable, vec!["apply", "eval", "log"]);
+    }
+
+    #[test]
+    fn test_ignores_non_call_edges_and_respects_limit() {
+        let mut edges = calls(&[("a", "b")]);
+        edges.push(Edge::new(
+            "b".to_string(),
+            "c".to_string(),
+            EdgeKind::DataFlow,
+        ));
+        let index = CallReachabilityIndex::build(&edges).unwrap();
+        assert!(!index.contains("c"));
+
+        assert!(CallReachabilityIndex::build_with_limit(&edges, 1).is_none());
+    }
+
+    #[test]
+    fn test_save_load_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("calls.reach");
+        let index = CallReachabilityIndex::build(&calls(&[("a", "b"), ("b", "c")])).unwrap();
+        index.save(&path).unwrap();
+
+        let loaded = CallReachabilityIndex::load(&path).unwrap();
+        assert!(loaded.can_reach("a", "c"));
+        assert!(!loaded.can_reach("c", "a"));
+    }
+}
@@ -0,0 +1,619 @@
+//! Hybrid Ranker - lexical hits re-ranked with graph signals
+//!
+//! Pure BM25 ranks a test fixture that repeats a symbol name above the
+//! implementation everyone calls. The ranker blends four normalized signals
+//! per symbol and demotes test/fixture files:
+//!
+//! ```text
+//! score = w_lex·lexical + w_pr·pagerank + w_ref·references + w_kind·kind
+//! score ×= test_penalty   (symbol lives in a test or fixture file)
+//! ```
+//!
+//! - **lexical**: query terms matched in the symbol name (identifier-split),
+//!   blended with the file's lexical-index score when a `LexicalSource` is given
+//! - **pagerank**: RepoMap PageRank over call/reference/inheritance edges
+//! - **references**: incoming call/reference edges, normalized by the maximum
+//! - **kind**: type declarations > callables > members
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use crate::features::chunking::TestDetector;
+use crate::features::ir_generation::domain::ir_document::IRDocument;
+use crate::features::lexical::{split_identifier, TantivyLexicalIndex};
+use crate::features::repomap::infrastructure::{
+    GraphDocument, GraphEdge, GraphNode, PageRankEngine, PageRankSettings,
+};
+use crate::shared::models::{EdgeKind, Node, NodeKind, Span};
+
+/// File-level hit from a lexical index
+#[derive(Debug, Clone)]
+pub struct LexicalHit {
+    pub file_path: String,
+    /// Best matching line (1-based), when the index knows it
+    pub line: Option<usize>,
+    pub score: f64,
+}
+
+/// Lexical candidate source (Tantivy index, test doubles)
+pub trait LexicalSource: Send + Sync {
+    fn lexical_hits(&self, query: &str, limit: usize) -> Vec<LexicalHit>;
+}
+
+impl LexicalSource for TantivyLexicalIndex {
+    fn lexical_hits(&self, query: &str, limit: usize) -> Vec<LexicalHit> {
+        match self.search(query, limit) {
+            Ok(hits) => hits
+                .into_iter()
+                .map(|hit| LexicalHit {
+                    file_path: hit.file_path,
+                    line: hit.line,
+                    score: hit.score,
+                })
+                .collect(),
+            Err(e) => {
+                tracing::warn!(
+                    "Lexical search failed, ranking on graph signals only: {:?}",
+                    e
+                );
+                Vec::new()
+            }
+        }
+    }
+}
+
+/// Result filters for `QueryEngine::search`
+#[derive(Debug, Clone, Default)]
+pub struct SearchFilters {
+    /// Only these symbol kinds (empty = all searchable kinds)
+    pub kinds: Vec<NodeKind>,
+    /// Only symbols under this path prefix
+    pub path_prefix: Option<String>,
+    /// Only symbols of this language
+    pub language: Option<String>,
+    /// Drop symbols in test/fixture files instead of demoting them
+    pub exclude_tests: bool,
+}
+
+impl SearchFilters {
+    pub fn with_kinds(mut self, kinds: Vec<NodeKind>) -> Self {
+        self.kinds = kinds;
+        self
+    }
+
+    pub fn with_path_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.path_prefix = Some(prefix.into());
+        self
+    }
+
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    pub fn exclude_tests(mut self) -> Self {
+        self.exclude_tests = true;
+        self
+    }
+
+    fn matches(&self, node: &Node, is_test: bool) -> bool {
+        if !self.kinds.is_empty() && !self.kinds.contains(&node.kind) {
+            return false;
+        }
+        if let Some(prefix) = &self.path_prefix {
+            if !node.file_path.starts_with(prefix.as_str()) {
+                return false;
+            }
+        }
+        if let Some(language) = &self.language {
+            if !node.language.eq_ignore_ascii_case(language) {
+                return false;
+            }
+        }
+        !(self.exclude_tests && is_test)
+    }
+}
+
+/// Signal weights (the four weights need not sum to 1)
+#[derive(Debug, Clone)]
+pub struct RankingWeights {
+    pub lexical: f64,
+    pub pagerank: f64,
+    pub references: f64,
+    pub kind: f64,
+    /// Multiplier applied to symbols in test/fixture files
+    pub test_penalty: f64,
+}
+
+impl Default for RankingWeights {
+    fn default() -> Self {
+        Self {
+            lexical: 0.6,
+            pagerank: 0.15,
+            references: 0.15,
+            kind: 0.1,
+            test_penalty: 0.3,
+        }
+    }
+}
+
+/// Per-signal breakdown of a ranked hit (all in [0, 1])
+#[derive(Debug, Clone, Default)]
+pub struct RankSignals {
+    pub lexical: f64,
+    pub pagerank: f64,
+    pub references: f64,
+    pub kind: f64,
+    /// Raw incoming call/reference edge count
+    pub reference_count: usize,
+    pub is_test: bool,
+}
+
+/// Ranked search result
+#[derive(Debug, Clone)]
+pub struct RankedHit {
+    pub node_id: String,
+    pub fqn: String,
+    pub name: Option<String>,
+    pub kind: NodeKind,
+    pub file_path: String,
+    pub span: Span,
+    pub score: f64,
+    pub signals: RankSignals,
+}
+
+/// Query-independent graph signals, computed once per IR document
+#[derive(Debug, Clone, Default)]
+pub struct GraphSignals {
+    /// node_id → PageRank, max-normalized to [0, 1]
+    pagerank: HashMap<String, f64>,
+    /// node_id → incoming call/reference edges
+    references: HashMap<String, usize>,
+    max_references: usize,
+}
+
+impl GraphSignals {
+    pub fn compute(ir_doc: &IRDocument) -> Self {
+        let symbols: Vec<&Node> = ir_doc
+            .nodes
+            .iter()
+            .filter(|n| is_searchable(n.kind))
+            .collect();
+
+        // Edge targets are node ids when resolved, FQNs otherwise
+        let mut by_key: HashMap<&str, &str> = HashMap::new();
+        for node in &symbols {
+            by_key.insert(node.fqn.as_str(), node.id.as_str());
+        }
+        for node in &symbols {
+            by_key.insert(node.id.as_str(), node.id.as_str());
+        }
+
+        let mut references: HashMap<String, usize> = HashMap::new();
+        let mut edges = Vec::new();
+        for edge in &ir_doc.edges {
+            if !is_reference_edge(edge.kind) {
+                continue;
+            }
+            let Some(&target) = by_key.get(edge.target_id.as_str()) else {
+                continue;
+            };
+            if edge.source_id == target {
+                continue;
+            }
+            *references.entry(target.to_string()).or_default() += 1;
+            if let Some(&source) = by_key.get(edge.source_id.as_str()) {
+                edges.push(GraphEdge {
+                    source: source.to_string(),
+                    target: target.to_string(),
+                    kind: edge.kind.as_str().to_string(),
+                });
+            }
+        }
+
+        let graph = GraphDocument {
+            nodes: symbols
+                .iter()
+                .map(|n| GraphNode {
+                    id: n.id.clone(),
+                    kind: n.kind.as_str().to_string(),
+                })
+                .collect(),
+            edges,
+        };
+        // Already max-normalized to [0, 1]
+        let pagerank = PageRankEngine::new(&PageRankSettings::default()).compute_pagerank(&graph);
+
+        let max_references = references.values().copied().max().unwrap_or(0);
+        Self {
+            pagerank,
+            references,
+            max_references,
+        }
+    }
+
+    /// Max-normalized PageRank of a symbol (0 for unknown ids)
+    pub fn pagerank(&self, node_id: &str) -> f64 {
+        self.pagerank.get(node_id).copied().unwrap_or(0.0)
+    }
+
+    /// Incoming call/reference edges of a symbol
+    pub fn reference_count(&self, node_id: &str) -> usize {
+        self.references.get(node_id).copied().unwrap_or(0)
+    }
+}
+
+/// Ranks IR symbols for a free-text query
+pub struct HybridRanker<'a> {
+    ir_doc: &'a IRDocument,
+    signals: &'a GraphSignals,
+    weights: &'a RankingWeights,
+    test_detector: TestDetector,
+}
+
+impl<'a> HybridRanker<'a> {
+    pub fn new(
+        ir_doc: &'a IRDocument,
+        signals: &'a GraphSignals,
+        weights: &'a RankingWeights,
+    ) -> Self {
+        Self {
+            ir_doc,
+            signals,
+            weights,
+            test_detector: TestDetector::new(),
+        }
+    }
+
+    /// Top `top_k` symbols for `query`
+    ///
+    /// Without a lexical source only symbol names are matched; with one, a
+    /// symbol is also a candidate when the index's best line for its file
+    /// falls inside the symbol's span.
+    pub fn rank(
+        &self,
+        query: &str,
+        top_k: usize,
+        filters: &SearchFilters,
+        lexical: Option<&dyn LexicalSource>,
+    ) -> Vec<RankedHit> {
+        let terms = query_terms(query);
+        if terms.is_empty() || top_k == 0 {
+            return Vec::new();
+        }
+
+        let file_hits = lexical
+            .map(|source| normalized_file_hits(source.lexical_hits(query, (top_k * 4).max(50))))
+            .unwrap_or_default();
+
+        let mut hits: Vec<RankedHit> = Vec::new();
+        for node in &self.ir_doc.nodes {
+            if !is_searchable(node.kind) {
+                continue;
+            }
+
+            let name_score = name_match(node, &terms);
+            let file_hit = file_hits.get(node.file_path.as_str());
+            let line_in_span = file_hit
+                .and_then(|(_, line)| *line)
+                .map(|line| {
+                    let line = line as u32;
+                    node.span.start_line <= line && line <= node.span.end_line
+                })
+                .unwrap_or(false);
+            if name_score == 0.0 && !line_in_span {
+                continue;
+            }
+
+            let is_test = self.is_test(node);
+            if !filters.matches(node, is_test) {
+                continue;
+            }
+
+            let lexical_score = match (lexical.is_some(), file_hit) {
+                (false, _) => name_score,
+                (true, Some((file_score, _))) => 0.5 * name_score + 0.5 * file_score,
+                (true, None) => 0.5 * name_score,
+            };
+            let reference_count = self.signals.references.get(&node.id).copied().unwrap_or(0);
+            let signals = RankSignals {
+                lexical: lexical_score,
+                pagerank: self.signals.pagerank.get(&node.id).copied().unwrap_or(0.0),
+                references: if self.signals.max_references > 0 {
+                    reference_count as f64 / self.signals.max_references as f64
+                } else {
+                    0.0
+                },
+                kind: kind_weight(node.kind),
+                reference_count,
+                is_test,
+            };
+
+            let mut score = self.weights.lexical * signals.lexical
+                + self.weights.pagerank * signals.pagerank
+                + self.weights.references * signals.references
+                + self.weights.kind * signals.kind;
+            if is_test {
+                score *= self.weights.test_penalty;
+            }
+
+            hits.push(RankedHit {
+                node_id: node.id.clone(),
+                fqn: node.fqn.clone(),
+                name: node.name.clone(),
+                kind: node.kind,
+                file_path: node.file_path.clone(),
+                span: node.span,
+                score,
+                signals,
+            });
+        }
+
+        hits.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.fqn.cmp(&b.fqn))
+        });
+        hits.truncate(top_k);
+        hits
+    }
+
+    fn is_test(&self, node: &Node) -> bool {
+        if node.is_test_file == Some(true) {
+            return true;
+        }
+        let fixture_dir = Path::new(&node.file_path).components().any(|c| {
+            matches!(
+                c.as_os_str().to_str(),
+                Some("fixtures" | "fixture" | "testdata" | "test_data")
+            )
+        });
+        fixture_dir
+            || self
+                .test_detector
+                .is_test_file(&node.file_path, Some(node.language.as_str()))
+    }
+}
+
+/// Lowercased identifier parts of the query, deduplicated
+fn query_terms(query: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    query
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .flat_map(split_identifier)
+        .map(str::to_lowercase)
+        .filter(|term| !term.is_empty() && seen.insert(term.clone()))
+        .collect()
+}
+
+/// Fraction of query terms found among the name's identifier parts
+///
+/// FQN parts count half; an exact name match scores 1.0.
+fn name_match(node: &Node, terms: &[String]) -> f64 {
+    let Some(name) = node.name.as_deref() else {
+        return 0.0;
+    };
+    let joined = terms.join("_");
+    if name.eq_ignore_ascii_case(&joined) || name.to_lowercase().replace('_', "") == terms.concat()
+    {
+        return 1.0;
+    }
+
+    let name_parts: HashSet<String> = split_identifier(name)
+        .into_iter()
+        .map(str::to_lowercase)
+        .collect();
+    let fqn_parts: HashSet<String> = node
+        .fqn
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .flat_map(split_identifier)
+        .map(str::to_lowercase)
+        .collect();
+
+    let matched: f64 = terms
+        .iter()
+        .map(|term| {
+            if name_parts.contains(term) {
+                1.0
+            } else if fqn_parts.contains(term) {
+                0.5
+            } else {
+                0.0
+            }
+        })
+        .sum();
+    // Below 1.0 so partial matches never tie with an exact name
+    0.9 * matched / terms.len() as f64
+}
+
+/// file_path → (score normalized to [0, 1], best line)
+fn normalized_file_hits(hits: Vec<LexicalHit>) -> HashMap<String, (f64, Option<usize>)> {
+    let max_score = hits.iter().map(|h| h.score).fold(0.0, f64::max);
+    let mut by_file: HashMap<String, (f64, Option<usize>)> = HashMap::new();
+    for hit in hits {
+        let score = if max_score > 0.0 {
+            hit.score / max_score
+        } else {
+            0.0
+        };
+        let entry = by_file.entry(hit.file_path).or_insert((score, hit.line));
+        if score > entry.0 {
+            *entry = (score, hit.line);
+        }
+    }
+    by_file
+}
+
+fn is_searchable(kind: NodeKind) -> bool {
+    kind_weight(kind) > 0.0
+}
+
+fn kind_weight(kind: NodeKind) -> f64 {
+    match kind {
+        NodeKind::Class
+        | NodeKind::Interface
+        | NodeKind::Trait
+        | NodeKind::Struct
+        | NodeKind::Enum
+        | NodeKind::DataClass
+        | NodeKind::SealedClass
+        | NodeKind::Record => 1.0,
+        NodeKind::Function
+        | NodeKind::Method
+        | NodeKind::ExtensionFunction
+        | NodeKind::SuspendFunction
+        | NodeKind::Macro => 0.9,
+        NodeKind::TypeAlias | NodeKind::Constant | NodeKind::AnnotationDecl => 0.6,
+        NodeKind::Module => 0.5,
+        NodeKind::Field | NodeKind::Property | NodeKind::EnumMember => 0.4,
+        _ => 0.0,
+    }
+}
+
+fn is_reference_edge(kind: EdgeKind) -> bool {
+    matches!(
+        kind,
+        EdgeKind::Calls
+            | EdgeKind::Invokes
+            | EdgeKind::References
+            | EdgeKind::Reads
+            | EdgeKind::Inherits
+            | EdgeKind::Implements
+            | EdgeKind::Extends
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::models::Edge;
+
+    fn symbol(id: &str, name: &str, kind: NodeKind, file: &str, lines: (u32, u32)) -> Node {
+        Node::new(
+            id.to_string(),
+            kind,
+            format!(
+                "{}.{}",
+                file.trim_end_matches(".py").replace('/', "."),
+                name
+            ),
+            file.to_string(),
+            Span::new(lines.0, 0, lines.1, 0),
+        )
+        .with_language("python".to_string())
+        .with_name(name.to_string())
+    }
+
+    fn call(source: &str, target: &str) -> Edge {
+        Edge::new(source.to_string(), target.to_string(), EdgeKind::Calls)
+    }
+
+    /// Real `parse_config` called from two places vs an uncalled fixture copy
+    fn create_test_ir() -> IRDocument {
+        let mut ir_doc = IRDocument::new("repo".to_string());
+        ir_doc.nodes.push(symbol(
+            "impl",
+            "parse_config",
+            NodeKind::Function,
+            "src/config.py",
+            (1, 20),
+        ));
+        ir_doc.nodes.push(symbol(
+            "fixture",
+            "parse_config",
+            NodeKind::Function,
+            "tests/fixtures/config_fixture.py",
+            (1, 5),
+        ));
+        ir_doc.nodes.push(symbol(
+            "main",
+            "main",
+            NodeKind::Function,
+            "src/main.py",
+            (1, 10),
+        ));
+        ir_doc.nodes.push(symbol(
+            "cli",
+            "run_cli",
+            NodeKind::Function,
+            "src/cli.py",
+            (1, 10),
+        ));
+        ir_doc.nodes.push(symbol(
+            "loader",
+            "ConfigLoader",
+            NodeKind::Class,
+            "src/loader.py",
+            (1, 40),
+        ));
+        ir_doc.edges.push(call("main", "impl"));
+        ir_doc.edges.push(call("cli", "impl"));
+        ir_doc
+    }
+
+    struct FixedHits(Vec<LexicalHit>);
+
+    impl LexicalSource for FixedHits {
+        fn lexical_hits(&self, _query: &str, _limit: usize) -> Vec<LexicalHit> {
+            self.0.clone()
+        }
+    }
+
+    #[test]
+    fn test_implementation_outranks_fixture() {
+        let ir_doc = create_test_ir();
+        let signals = GraphSignals::compute(&ir_doc);
+        let weights = RankingWeights::default();
+        let ranker = HybridRanker::new(&ir_doc, &signals, &weights);
+
+        // The fixture file is the strongest lexical hit
+        let lexical = FixedHits(vec![
+            LexicalHit {
+                file_path: "tests/fixtures/config_fixture.py".to_string(),
+                line: Some(2),
+                score: 12.0,
+            },
+            LexicalHit {
+                file_path: "src/config.py".to_string(),
+                line: Some(3),
+                score: 6.0,
+            },
+        ]);
+        let hits = ranker.rank(
+            "parse_config",
+            10,
+            &SearchFilters::default(),
+            Some(&lexical),
+        );
+
+        assert_eq!(hits[0].node_id, "impl");
+        assert_eq!(hits[0].signals.reference_count, 2);
+        let fixture = hits.iter().find(|h| h.node_id == "fixture").unwrap();
+        assert!(fixture.signals.is_test);
+        assert!(fixture.score < hits[0].score);
+    }
+
+    #[test]
+    fn test_identifier_split_query_and_filters() {
+        let ir_doc = create_test_ir();
+        let signals = GraphSignals::compute(&ir_doc);
+        let weights = RankingWeights::default();
+        let ranker = HybridRanker::new(&ir_doc, &signals, &weights);
+
+        // "config loader" matches ConfigLoader exactly, parse_config partially
+        let hits = ranker.rank("config loader", 10, &SearchFilters::default(), None);
+        assert_eq!(hits[0].node_id, "loader");
+
+        let filters = SearchFilters::default()
+            .with_kinds(vec![NodeKind::Function])
+            .exclude_tests();
+        let hits = ranker.rank("config", 10, &filters, None);
+        let ids: Vec<_> = hits.iter().map(|h| h.node_id.as_str()).collect();
+        assert_eq!(ids, vec!["impl"]);
+
+        assert!(ranker
+            .rank("  ", 10, &SearchFilters::default(), None)
+            .is_empty());
+    }
+}
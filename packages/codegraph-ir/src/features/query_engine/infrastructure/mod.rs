@@ -1,6 +1,8 @@
 // Infrastructure: Query execution components
 
+pub mod call_reachability;
 pub mod graph_index;
+pub mod hybrid_ranker;
 pub mod incremental_index;
 pub mod node_matcher;
 pub mod parallel_traversal;
@@ -9,7 +11,12 @@ pub mod shadow_fs_orchestrator;
 pub mod transaction_index;
 pub mod traversal_engine;
 
+pub use call_reachability::{CallReachabilityIndex, MAX_INDEXED_COMPONENTS};
 pub use graph_index::GraphIndex;
+pub use hybrid_ranker::{
+    GraphSignals, HybridRanker, LexicalHit, LexicalSource, RankSignals, RankedHit, RankingWeights,
+    SearchFilters,
+};
 pub use incremental_index::{ChangeSet, IncrementalGraphIndex};
 pub use node_matcher::NodeMatcher;
 pub use parallel_traversal::ParallelTraversalEngine;
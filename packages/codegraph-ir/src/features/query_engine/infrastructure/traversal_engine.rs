@@ -2,7 +2,9 @@
 // Implements RFC-071 REACH primitive
 
 use super::graph_index::GraphIndex;
-use crate::features::query_engine::domain::{EdgeType, PathResult, TraversalDirection};
+use crate::features::query_engine::domain::{
+    EdgeSelector, EdgeType, PathResult, TraversalDirection,
+};
 use crate::shared::models::{Edge, Node};
 use std::collections::{HashSet, VecDeque};
 use std::time::Instant;
@@ -14,13 +16,24 @@ use std::time::Instant;
 /// - Depth limiting
 /// - Path limiting (early termination)
 /// - Timeout handling
+/// - Provenance filtering (stage/rule, via `with_selector`)
 pub struct TraversalEngine<'a> {
     index: &'a GraphIndex,
+    selector: Option<&'a EdgeSelector>,
 }
 
 impl<'a> TraversalEngine<'a> {
     pub fn new(index: &'a GraphIndex) -> Self {
-        Self { index }
+        Self {
+            index,
+            selector: None,
+        }
+    }
+
+    /// Apply the selector's provenance (stage/rule) filters during traversal
+    pub fn with_selector(mut self, selector: &'a EdgeSelector) -> Self {
+        self.selector = Some(selector);
+        self
     }
 
     /// Find paths from sources to targets (BFS)
@@ -122,6 +135,7 @@ impl<'a> TraversalEngine<'a> {
             let filtered_edges: Vec<&Edge> = edges
                 .into_iter()
                 .filter(|e| self.matches_edge_type(e, edge_type))
+                .filter(|e| self.selector.map_or(true, |s| s.matches_provenance(e)))
                 .collect();
 
             for edge in filtered_edges {
@@ -23,8 +23,16 @@
 
 pub mod application; // UseCase layer (entry point)
 pub mod domain;
+pub mod explain; // Per-step query explain reports
 pub mod infrastructure;
+pub mod node_set; // Composable node-set queries (QueryEngine::select)
 pub mod query_engine;
+pub mod complexity; // Per-file / per-module complexity report
+pub mod dead_code; // Unreferenced functions / classes report
+pub mod hotspots; // Churn × complexity ranking (git blame stage)
+pub mod test_mapping; // Tests → exercised production symbols
+pub mod test_selection; // Impacted tests of changed files (CI)
+pub mod unused; // Unused imports / parameters report
 
 // Re-export application layer (primary interface)
 pub use application::{QueryInput, QueryOutput, QueryUseCase, QueryUseCaseImpl};
@@ -35,6 +43,42 @@ pub use domain::{
     TraversalDirection, E, Q,
 };
 
+// Re-export ranked search types (QueryEngine::search)
+pub use infrastructure::{
+    LexicalHit, LexicalSource, RankSignals, RankedHit, RankingWeights, SearchFilters,
+};
+
+// Re-export node-set queries (QueryEngine::select)
+pub use node_set::{NodeFilter, NodeSetGraph, NodeSetQuery, QueryStep, Traversal};
+
+// Re-export call-graph reachability (QueryEngine::can_reach)
+pub use infrastructure::CallReachabilityIndex;
+
+// Re-export unused imports / parameters (QueryEngine::unused_symbols)
+pub use unused::{find_unused, UnusedKind, UnusedSymbol};
+
+// Re-export complexity report (QueryEngine::complexity)
+pub use complexity::{
+    complexity_report, ComplexityReport, ComplexityStats, FunctionComplexity,
+};
+
+// Re-export hotspots (QueryEngine::hotspots)
+pub use hotspots::{find_hotspots, Hotspot};
+
+// Re-export dead code report (E2EPipelineResult::dead_code)
+pub use dead_code::{find_dead_code, DeadCodeConfig, DeadSymbol};
+
+// Re-export test mapping (E2EPipelineResult::test_map)
+pub use test_mapping::{
+    build_test_map, TestCase, TestFramework, TestMap, TestedBy, DEFAULT_TEST_MAP_DEPTH,
+};
+
+// Re-export impacted-test selection (E2EPipelineResult::select_tests)
+pub use test_selection::{select_impacted_tests, TestSelection, TestSelectionConfig};
+
+// Re-export explain reports (QueryEngine::*_explain)
+pub use explain::{ExplainStep, QueryExplain};
+
 // Re-export infrastructure (internal use - prefer application layer)
 #[doc(hidden)]
 pub use query_engine::{QueryEngine, QueryEngineStats};
@@ -0,0 +1,470 @@
+//! Composable node-set queries
+//!
+//! A query starts from every node and applies steps in order: filter by
+//! kind/name/path (exact or regex), traverse edges with depth limits, keep
+//! nodes related to a sub-query, or combine with other sub-queries.
+//!
+//! ```ignore
+//! // Methods of classes inheriting `Base` that call `save`
+//! let query = NodeSetQuery::all()
+//!     .filter(NodeFilter::kind(NodeKind::Class))
+//!     .where_reaches(
+//!         Traversal::outgoing([EdgeKind::Inherits]).with_depth(1, 8),
+//!         NodeSetQuery::all().filter(NodeFilter::default().with_name("Base")),
+//!     )
+//!     .traverse(Traversal::outgoing([EdgeKind::Contains]))
+//!     .filter(NodeFilter::kind(NodeKind::Method))
+//!     .where_reaches(
+//!         Traversal::outgoing([EdgeKind::Calls]),
+//!         NodeSetQuery::all().filter(NodeFilter::default().with_name("save")),
+//!     );
+//! let methods = engine.select(&query)?;
+//! ```
+//!
+//! Queries are serde types; the JSON form is a list of steps, e.g.
+//! `[{"filter": {"kinds": ["Class"]}}, {"limit": 10}]`.
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::domain::TraversalDirection;
+use crate::features::ir_generation::domain::ir_document::IRDocument;
+use crate::shared::models::{CodegraphError, EdgeKind, Node, NodeKind, Result};
+
+/// Node predicate; unset fields match everything
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NodeFilter {
+    /// Any of these kinds
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub kinds: Vec<NodeKind>,
+    /// Exact name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_regex: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fqn_regex: Option<String>,
+    /// File path starts with this
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_prefix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_regex: Option<String>,
+}
+
+impl NodeFilter {
+    pub fn kind(kind: NodeKind) -> Self {
+        Self {
+            kinds: vec![kind],
+            ..Self::default()
+        }
+    }
+
+    pub fn with_kinds(mut self, kinds: impl IntoIterator<Item = NodeKind>) -> Self {
+        self.kinds = kinds.into_iter().collect();
+        self
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn with_name_regex(mut self, pattern: impl Into<String>) -> Self {
+        self.name_regex = Some(pattern.into());
+        self
+    }
+
+    pub fn with_fqn_regex(mut self, pattern: impl Into<String>) -> Self {
+        self.fqn_regex = Some(pattern.into());
+        self
+    }
+
+    pub fn with_path_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.path_prefix = Some(prefix.into());
+        self
+    }
+
+    pub fn with_path_regex(mut self, pattern: impl Into<String>) -> Self {
+        self.path_regex = Some(pattern.into());
+        self
+    }
+}
+
+/// Edge walk from a node set
+///
+/// Nodes are reached at their shortest distance; those with
+/// `min_depth <= distance <= max_depth` are kept (`min_depth: 0` keeps the
+/// start nodes).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Traversal {
+    /// Edge kinds to follow (all when empty)
+    pub edges: Vec<EdgeKind>,
+    pub direction: TraversalDirection,
+    pub min_depth: usize,
+    pub max_depth: usize,
+}
+
+impl Default for Traversal {
+    fn default() -> Self {
+        Self {
+            edges: Vec::new(),
+            direction: TraversalDirection::Forward,
+            min_depth: 1,
+            max_depth: 1,
+        }
+    }
+}
+
+impl Traversal {
+    /// Follow `edges` from source to target
+    pub fn outgoing(edges: impl IntoIterator<Item = EdgeKind>) -> Self {
+        Self {
+            edges: edges.into_iter().collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Follow `edges` from target to source
+    pub fn incoming(edges: impl IntoIterator<Item = EdgeKind>) -> Self {
+        Self {
+            direction: TraversalDirection::Backward,
+            ..Self::outgoing(edges)
+        }
+    }
+
+    pub fn with_depth(mut self, min_depth: usize, max_depth: usize) -> Self {
+        self.min_depth = min_depth;
+        self.max_depth = max_depth;
+        self
+    }
+
+    fn reversed(&self) -> Self {
+        Self {
+            direction: match self.direction {
+                TraversalDirection::Forward => TraversalDirection::Backward,
+                TraversalDirection::Backward => TraversalDirection::Forward,
+            },
+            ..self.clone()
+        }
+    }
+}
+
+/// One step of a `NodeSetQuery`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryStep {
+    Filter(NodeFilter),
+    /// Replace the set with the nodes the traversal reaches
+    Traverse(Traversal),
+    /// Keep nodes reaching some node of `target` via `via`
+    Where {
+        via: Traversal,
+        target: NodeSetQuery,
+    },
+    /// Keep nodes reaching no node of `target` via `via`
+    WhereNot {
+        via: Traversal,
+        target: NodeSetQuery,
+    },
+    Union(NodeSetQuery),
+    Intersect(NodeSetQuery),
+    Except(NodeSetQuery),
+    /// First `n` nodes (IR order)
+    Limit(usize),
+}
+
+/// Steps applied to the set of all nodes
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct NodeSetQuery {
+    pub steps: Vec<QueryStep>,
+}
+
+impl NodeSetQuery {
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    pub fn filter(self, filter: NodeFilter) -> Self {
+        self.step(QueryStep::Filter(filter))
+    }
+
+    pub fn traverse(self, traversal: Traversal) -> Self {
+        self.step(QueryStep::Traverse(traversal))
+    }
+
+    pub fn where_reaches(self, via: Traversal, target: NodeSetQuery) -> Self {
+        self.step(QueryStep::Where { via, target })
+    }
+
+    pub fn where_not_reaches(self, via: Traversal, target: NodeSetQuery) -> Self {
+        self.step(QueryStep::WhereNot { via, target })
+    }
+
+    pub fn union(self, other: NodeSetQuery) -> Self {
+        self.step(QueryStep::Union(other))
+    }
+
+    pub fn intersect(self, other: NodeSetQuery) -> Self {
+        self.step(QueryStep::Intersect(other))
+    }
+
+    pub fn except(self, other: NodeSetQuery) -> Self {
+        self.step(QueryStep::Except(other))
+    }
+
+    pub fn limit(self, n: usize) -> Self {
+        self.step(QueryStep::Limit(n))
+    }
+
+    fn step(mut self, step: QueryStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+}
+
+/// Adjacency over node indices; edge targets resolve by id, then FQN
+#[derive(Debug)]
+pub struct NodeSetGraph {
+    outgoing: Vec<Vec<(usize, EdgeKind)>>,
+    incoming: Vec<Vec<(usize, EdgeKind)>>,
+}
+
+impl NodeSetGraph {
+    pub fn new(ir_doc: &IRDocument) -> Self {
+        let mut by_id: HashMap<&str, usize> = HashMap::with_capacity(ir_doc.nodes.len());
+        let mut by_fqn: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (i, node) in ir_doc.nodes.iter().enumerate() {
+            by_id.insert(node.id.as_str(), i);
+            by_fqn.entry(node.fqn.as_str()).or_default().push(i);
+        }
+
+        let mut outgoing = vec![Vec::new(); ir_doc.nodes.len()];
+        let mut incoming = vec![Vec::new(); ir_doc.nodes.len()];
+        for edge in &ir_doc.edges {
+            let Some(&source) = by_id.get(edge.source_id.as_str()) else {
+                continue;
+            };
+            let targets = match by_id.get(edge.target_id.as_str()) {
+                Some(target) => std::slice::from_ref(target),
+                None => by_fqn
+                    .get(edge.target_id.as_str())
+                    .map(Vec::as_slice)
+                    .unwrap_or(&[]),
+            };
+            for &target in targets {
+                outgoing[source].push((target, edge.kind));
+                incoming[target].push((source, edge.kind));
+            }
+        }
+        Self { outgoing, incoming }
+    }
+
+    /// Nodes of `ir_doc` matching `query`, in IR order
+    pub fn select<'a>(
+        &self,
+        ir_doc: &'a IRDocument,
+        query: &NodeSetQuery,
+    ) -> Result<Vec<&'a Node>> {
+        Ok(self
+            .evaluate(ir_doc, query)?
+            .into_iter()
+            .map(|i| &ir_doc.nodes[i])
+            .collect())
+    }
+
+    fn evaluate(&self, ir_doc: &IRDocument, query: &NodeSetQuery) -> Result<BTreeSet<usize>> {
+        let mut set: BTreeSet<usize> = (0..ir_doc.nodes.len()).collect();
+        for step in &query.steps {
+            set = match step {
+                QueryStep::Filter(filter) => {
+                    let matcher = FilterMatcher::compile(filter)?;
+                    set.into_iter()
+                        .filter(|&i| matcher.matches(&ir_doc.nodes[i]))
+                        .collect()
+                }
+                QueryStep::Traverse(traversal) => self.reach(&set, traversal),
+                QueryStep::Where { via, target } => {
+                    let reaching = self.reach(&self.evaluate(ir_doc, target)?, &via.reversed());
+                    set.intersection(&reaching).copied().collect()
+                }
+                QueryStep::WhereNot { via, target } => {
+                    let reaching = self.reach(&self.evaluate(ir_doc, target)?, &via.reversed());
+                    set.difference(&reaching).copied().collect()
+                }
+                QueryStep::Union(other) => {
+                    set.union(&self.evaluate(ir_doc, other)?).copied().collect()
+                }
+                QueryStep::Intersect(other) => set
+                    .intersection(&self.evaluate(ir_doc, other)?)
+                    .copied()
+                    .collect(),
+                QueryStep::Except(other) => set
+                    .difference(&self.evaluate(ir_doc, other)?)
+                    .copied()
+                    .collect(),
+                QueryStep::Limit(n) => set.into_iter().take(*n).collect(),
+            };
+        }
+        Ok(set)
+    }
+
+    /// Multi-source BFS from `start`
+    fn reach(&self, start: &BTreeSet<usize>, traversal: &Traversal) -> BTreeSet<usize> {
+        let adjacency = match traversal.direction {
+            TraversalDirection::Forward => &self.outgoing,
+            TraversalDirection::Backward => &self.incoming,
+        };
+        let mut distance: HashMap<usize, usize> = start.iter().map(|&i| (i, 0)).collect();
+        let mut queue: VecDeque<usize> = start.iter().copied().collect();
+        while let Some(node) = queue.pop_front() {
+            let depth = distance[&node];
+            if depth >= traversal.max_depth {
+                continue;
+            }
+            for &(next, kind) in &adjacency[node] {
+                if !traversal.edges.is_empty() && !traversal.edges.contains(&kind) {
+                    continue;
+                }
+                if !distance.contains_key(&next) {
+                    distance.insert(next, depth + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+        distance
+            .into_iter()
+            .filter(|&(_, d)| d >= traversal.min_depth && d <= traversal.max_depth)
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+/// `NodeFilter` with its regexes compiled
+struct FilterMatcher<'f> {
+    filter: &'f NodeFilter,
+    name: Option<Regex>,
+    fqn: Option<Regex>,
+    path: Option<Regex>,
+}
+
+impl<'f> FilterMatcher<'f> {
+    fn compile(filter: &'f NodeFilter) -> Result<Self> {
+        let compile = |field: &str, pattern: &Option<String>| -> Result<Option<Regex>> {
+            pattern
+                .as_deref()
+                .map(|p| {
+                    Regex::new(p).map_err(|e| {
+                        CodegraphError::parse(format!("invalid {} '{}': {}", field, p, e))
+                    })
+                })
+                .transpose()
+        };
+        Ok(Self {
+            name: compile("name_regex", &filter.name_regex)?,
+            fqn: compile("fqn_regex", &filter.fqn_regex)?,
+            path: compile("path_regex", &filter.path_regex)?,
+            filter,
+        })
+    }
+
+    fn matches(&self, node: &Node) -> bool {
+        let name = node.name.as_deref().unwrap_or("");
+        (self.filter.kinds.is_empty() || self.filter.kinds.contains(&node.kind))
+            && self.filter.name.as_deref().map_or(true, |n| n == name)
+            && self.name.as_ref().map_or(true, |re| re.is_match(name))
+            && self.fqn.as_ref().map_or(true, |re| re.is_match(&node.fqn))
+            && self
+                .filter
+                .path_prefix
+                .as_deref()
+                .map_or(true, |prefix| node.file_path.starts_with(prefix))
+            && self
+                .path
+                .as_ref()
+                .map_or(true, |re| re.is_match(&node.file_path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::models::{Edge, Span};
+
+    fn node(id: &str, kind: NodeKind, name: &str, path: &str) -> Node {
+        let mut node = Node::new(
+            id.to_string(),
+            kind,
+            format!("m.{}", name),
+            path.to_string(),
+            Span::new(1, 0, 2, 0),
+        );
+        node.name = Some(name.to_string());
+        node
+    }
+
+    fn edge(source: &str, target: &str, kind: EdgeKind) -> Edge {
+        Edge::new(source.to_string(), target.to_string(), kind)
+    }
+
+    #[test]
+    fn test_methods_of_subclasses_calling_target() {
+        let doc = IRDocument {
+            file_path: String::new(),
+            nodes: vec![
+                node("base", NodeKind::Class, "Base", "m.py"),
+                node("mid", NodeKind::Class, "Mid", "m.py"),
+                node("leaf", NodeKind::Class, "Leaf", "m.py"),
+                node("other", NodeKind::Class, "Other", "m.py"),
+                node("leaf.save", NodeKind::Method, "run", "m.py"),
+                node("leaf.noop", NodeKind::Method, "noop", "m.py"),
+                node("other.run", NodeKind::Method, "run", "m.py"),
+                node("save", NodeKind::Function, "save", "db.py"),
+            ],
+            edges: vec![
+                edge("mid", "base", EdgeKind::Inherits),
+                // Unresolved target: looked up by FQN
+                edge("leaf", "m.Mid", EdgeKind::Inherits),
+                edge("leaf", "leaf.save", EdgeKind::Contains),
+                edge("leaf", "leaf.noop", EdgeKind::Contains),
+                edge("other", "other.run", EdgeKind::Contains),
+                edge("leaf.save", "save", EdgeKind::Calls),
+                edge("other.run", "save", EdgeKind::Calls),
+            ],
+        };
+        let graph = NodeSetGraph::new(&doc);
+
+        let query = NodeSetQuery::all()
+            .filter(NodeFilter::kind(NodeKind::Class))
+            .where_reaches(
+                Traversal::outgoing([EdgeKind::Inherits]).with_depth(1, 8),
+                NodeSetQuery::all().filter(NodeFilter::default().with_name("Base")),
+            )
+            .traverse(Traversal::outgoing([EdgeKind::Contains]))
+            .filter(NodeFilter::kind(NodeKind::Method))
+            .where_reaches(
+                Traversal::outgoing([EdgeKind::Calls]),
+                NodeSetQuery::all().filter(NodeFilter::default().with_path_regex(r"^db\.py$")),
+            );
+        let ids: Vec<_> = graph
+            .select(&doc, &query)
+            .unwrap()
+            .iter()
+            .map(|n| n.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["leaf.save"]);
+
+        // JSON round trip
+        let json = serde_json::to_string(&query).unwrap();
+        assert!(json.starts_with(r#"[{"filter":{"kinds":["Class"]}},{"where":"#));
+        let parsed: NodeSetQuery = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, query);
+
+        let bad = NodeSetQuery::all().filter(NodeFilter::default().with_name_regex("("));
+        assert!(graph.select(&doc, &bad).is_err());
+    }
+}
@@ -2,8 +2,23 @@
 // Maps to Python: QueryEngine
 
 use crate::features::ir_generation::domain::ir_document::IRDocument;
-use crate::features::query_engine::domain::{PathQuery, PathResult};
-use crate::features::query_engine::infrastructure::{GraphIndex, NodeMatcher, TraversalEngine};
+use crate::features::query_engine::complexity::{complexity_report, ComplexityReport};
+use crate::features::query_engine::domain::{
+    NodeSelector, NodeSelectorType, PathQuery, PathResult, TraversalDirection,
+};
+use crate::features::query_engine::explain::QueryExplain;
+use crate::features::query_engine::hotspots::{find_hotspots, Hotspot};
+use crate::features::query_engine::infrastructure::{
+    CallReachabilityIndex, GraphIndex, GraphSignals, HybridRanker, LexicalSource, NodeMatcher,
+    RankedHit, RankingWeights, SearchFilters, TraversalEngine,
+};
+use crate::features::query_engine::node_set::{NodeSetGraph, NodeSetQuery};
+use crate::features::query_engine::unused::{find_unused, UnusedSymbol};
+use crate::features::vector::{ChunkVectorIndex, VectorHit};
+use crate::shared::models::{EdgeKind, Node, Result};
+use std::collections::{HashSet, VecDeque};
+use std::sync::OnceLock;
+use std::time::Instant;
 
 /// Query Engine - Main entry point for query execution
 ///
@@ -11,6 +26,9 @@ use crate::features::query_engine::infrastructure::{GraphIndex, NodeMatcher, Tra
 /// - GraphIndex: O(1) node/edge lookups
 /// - NodeMatcher: Selector to node matching
 /// - TraversalEngine: BFS graph traversal
+/// - HybridRanker: lexical + graph-signal ranked search
+/// - ChunkVectorIndex: embedding nearest-neighbour search
+/// - CallReachabilityIndex: precomputed call-graph reachability
 ///
 /// Example:
 /// ```no_run
@@ -31,7 +49,18 @@ use crate::features::query_engine::infrastructure::{GraphIndex, NodeMatcher, Tra
 /// ```
 pub struct QueryEngine<'a> {
     index: GraphIndex,
-    _ir_doc: &'a IRDocument,
+    ir_doc: &'a IRDocument,
+    /// Lexical index consulted by `search` (name matching only when unset)
+    lexical: Option<&'a dyn LexicalSource>,
+    ranking_weights: RankingWeights,
+    /// PageRank + reference counts, computed on first `search`
+    graph_signals: OnceLock<GraphSignals>,
+    /// Chunk embeddings consulted by `semantic_search`
+    vectors: Option<&'a ChunkVectorIndex>,
+    /// Precomputed reachability consulted by `can_reach` (BFS when unset)
+    call_reachability: Option<&'a CallReachabilityIndex>,
+    /// Resolved adjacency for `select`, built on first use
+    node_set_graph: OnceLock<NodeSetGraph>,
 }
 
 impl<'a> QueryEngine<'a> {
@@ -41,10 +70,177 @@ impl<'a> QueryEngine<'a> {
 
         Self {
             index,
-            _ir_doc: ir_doc,
+            ir_doc,
+            lexical: None,
+            ranking_weights: RankingWeights::default(),
+            graph_signals: OnceLock::new(),
+            vectors: None,
+            call_reachability: None,
+            node_set_graph: OnceLock::new(),
         }
     }
 
+    /// Use a lexical index (e.g. `TantivyLexicalIndex`) for `search` candidates
+    pub fn with_lexical(mut self, lexical: &'a dyn LexicalSource) -> Self {
+        self.lexical = Some(lexical);
+        self
+    }
+
+    /// Override the signal weights used by `search`
+    pub fn with_ranking_weights(mut self, weights: RankingWeights) -> Self {
+        self.ranking_weights = weights;
+        self
+    }
+
+    /// Use a chunk vector index (pipeline L2.6 output) for `semantic_search`
+    pub fn with_vectors(mut self, vectors: &'a ChunkVectorIndex) -> Self {
+        self.vectors = Some(vectors);
+        self
+    }
+
+    /// `k` chunks nearest to `query_embedding`, most similar first
+    ///
+    /// The query must be embedded with the provider that built the index
+    /// (see `ChunkVectorIndex::model_id`). Empty when no index is attached.
+    pub fn semantic_search(&self, query_embedding: &[f32], k: usize) -> Result<Vec<VectorHit>> {
+        match self.vectors {
+            Some(vectors) => vectors.semantic_search(query_embedding, k),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Use a call reachability index (built from this graph) for `can_reach`
+    pub fn with_call_reachability(mut self, index: &'a CallReachabilityIndex) -> Self {
+        self.call_reachability = Some(index);
+        self
+    }
+
+    /// Whether `target` is reachable from `source` through one or more calls
+    ///
+    /// O(1) with a `CallReachabilityIndex` that knows `source`, otherwise
+    /// on-line BFS over Calls edges.
+    pub fn can_reach(&self, source: &str, target: &str) -> bool {
+        self.can_reach_explain(source, target).0
+    }
+
+    /// `can_reach` with the strategy used and its cost
+    pub fn can_reach_explain(&self, source: &str, target: &str) -> (bool, QueryExplain) {
+        let mut explain = QueryExplain::start(format!("can_reach {} -> {}", source, target));
+        let started = Instant::now();
+        if let Some(index) = self.call_reachability {
+            if index.contains(source) {
+                let reachable = index.can_reach(source, target);
+                explain.record(
+                    "reachability",
+                    "CallReachabilityIndex",
+                    index.function_count(),
+                    usize::from(reachable),
+                    started,
+                );
+                return (reachable, explain.finish());
+            }
+        }
+
+        let (reachable, visited) = self.calls_bfs(source, target);
+        explain.record(
+            "reachability",
+            "GraphIndex.edges_from (BFS)",
+            visited,
+            usize::from(reachable),
+            started,
+        );
+        (reachable, explain.finish())
+    }
+
+    /// (reachable, functions visited)
+    fn calls_bfs(&self, source: &str, target: &str) -> (bool, usize) {
+        let mut visited: HashSet<&str> = HashSet::new();
+        let mut queue = VecDeque::from([source]);
+        while let Some(node_id) = queue.pop_front() {
+            for edge in self.index.get_edges_from(node_id) {
+                if edge.kind != EdgeKind::Calls {
+                    continue;
+                }
+                if edge.target_id == target {
+                    return (true, visited.len());
+                }
+                if visited.insert(edge.target_id.as_str()) {
+                    queue.push_back(edge.target_id.as_str());
+                }
+            }
+        }
+        (false, visited.len())
+    }
+
+    /// Ranked symbol search
+    ///
+    /// Lexical hits are re-ranked with PageRank, reference counts and symbol
+    /// kind; symbols in test/fixture files are demoted (or dropped with
+    /// `SearchFilters::exclude_tests`).
+    ///
+    /// ```no_run
+    /// use codegraph_ir::features::query_engine::{QueryEngine, SearchFilters};
+    /// use codegraph_ir::features::ir_generation::domain::ir_document::IRDocument;
+    ///
+    /// let ir_doc = IRDocument::new("repo".to_string());
+    /// let engine = QueryEngine::new(&ir_doc);
+    /// for hit in engine.search("parse config", 10, &SearchFilters::default().exclude_tests()) {
+    ///     println!("{} {:.3}", hit.fqn, hit.score);
+    /// }
+    /// ```
+    pub fn search(&self, query: &str, top_k: usize, filters: &SearchFilters) -> Vec<RankedHit> {
+        self.search_explain(query, top_k, filters).0
+    }
+
+    /// `search` with per-step timings and candidate counts
+    pub fn search_explain(
+        &self,
+        query: &str,
+        top_k: usize,
+        filters: &SearchFilters,
+    ) -> (Vec<RankedHit>, QueryExplain) {
+        let mut explain = QueryExplain::start(format!("search {:?} top_k={}", query, top_k));
+        let node_count = self.ir_doc.nodes.len();
+
+        let started = Instant::now();
+        let cached = self.graph_signals.get().is_some();
+        let signals = self
+            .graph_signals
+            .get_or_init(|| GraphSignals::compute(self.ir_doc));
+        explain.record(
+            "graph_signals",
+            if cached {
+                "GraphSignals (cached)"
+            } else {
+                "GraphSignals (computed)"
+            },
+            node_count,
+            node_count,
+            started,
+        );
+
+        let started = Instant::now();
+        let hits = HybridRanker::new(self.ir_doc, signals, &self.ranking_weights).rank(
+            query,
+            top_k,
+            filters,
+            self.lexical,
+        );
+        explain.record(
+            "rank",
+            if self.lexical.is_some() {
+                "LexicalSource + name match"
+            } else {
+                "name match (scan)"
+            },
+            node_count,
+            hits.len(),
+            started,
+        );
+
+        (hits, explain.finish())
+    }
+
     /// Execute query and find paths
     ///
     /// This is the main execution method that:
@@ -53,19 +249,46 @@ impl<'a> QueryEngine<'a> {
     /// 3. Applies path constraints
     /// 4. Returns results
     pub fn execute(&self, query: PathQuery) -> Vec<PathResult> {
+        self.execute_explain(query).0
+    }
+
+    /// `execute` with the index, candidate counts and time of each step
+    pub fn execute_explain(&self, query: PathQuery) -> (Vec<PathResult>, QueryExplain) {
+        let mut explain = QueryExplain::start(format!(
+            "path {:?} >> {:?}",
+            query.flow.source.selector_type, query.flow.target.selector_type
+        ));
+        let node_count = self.index.node_count();
+
         // Step 1: Match source nodes
+        let started = Instant::now();
         let matcher = NodeMatcher::new(&self.index);
         let source_nodes = matcher.match_nodes(&query.flow.source);
+        explain.record(
+            "match_source",
+            selector_index(&query.flow.source),
+            node_count,
+            source_nodes.len(),
+            started,
+        );
 
         if source_nodes.is_empty() {
-            return Vec::new();
+            return (Vec::new(), explain.finish());
         }
 
         // Step 2: Match target nodes
+        let started = Instant::now();
         let target_nodes = matcher.match_nodes(&query.flow.target);
+        explain.record(
+            "match_target",
+            selector_index(&query.flow.target),
+            node_count,
+            target_nodes.len(),
+            started,
+        );
 
         if target_nodes.is_empty() {
-            return Vec::new();
+            return (Vec::new(), explain.finish());
         }
 
         // Step 3: Extract edge type and direction
@@ -84,7 +307,11 @@ impl<'a> QueryEngine<'a> {
         let timeout_ms = query.timeout_ms;
 
         // Step 5: Run BFS traversal
-        let engine = TraversalEngine::new(&self.index);
+        let started = Instant::now();
+        let mut engine = TraversalEngine::new(&self.index);
+        if let Some(selector) = query.flow.edge_type.as_ref() {
+            engine = engine.with_selector(selector);
+        }
         let mut paths = engine.find_paths(
             &source_nodes,
             &target_nodes,
@@ -94,8 +321,20 @@ impl<'a> QueryEngine<'a> {
             max_paths,
             timeout_ms,
         );
+        explain.record(
+            "traverse",
+            match direction {
+                TraversalDirection::Forward => "GraphIndex.edges_from (BFS)",
+                TraversalDirection::Backward => "GraphIndex.edges_to (BFS)",
+            },
+            source_nodes.len(),
+            paths.len(),
+            started,
+        );
 
         // Step 6: Apply path constraints
+        let started = Instant::now();
+        let found = paths.len();
         paths.retain(|path| {
             // Min depth filter
             if path.node_ids.len() < min_depth {
@@ -111,8 +350,37 @@ impl<'a> QueryEngine<'a> {
 
             true
         });
+        explain.record(
+            "filter_paths",
+            "path constraints",
+            found,
+            paths.len(),
+            started,
+        );
+
+        (paths, explain.finish())
+    }
 
-        paths
+    /// Nodes matching a composed `NodeSetQuery`, in IR order
+    pub fn select(&self, query: &NodeSetQuery) -> Result<Vec<&'a Node>> {
+        self.node_set_graph
+            .get_or_init(|| NodeSetGraph::new(self.ir_doc))
+            .select(self.ir_doc, query)
+    }
+
+    /// Unused imports and parameters of the document (edges only, see `find_unused`)
+    pub fn unused_symbols(&self) -> Vec<UnusedSymbol> {
+        find_unused(&self.ir_doc.nodes, &self.ir_doc.edges, &[], &[])
+    }
+
+    /// Function complexity metrics with per-file and per-module statistics
+    pub fn complexity(&self) -> ComplexityReport {
+        complexity_report(&self.ir_doc.nodes)
+    }
+
+    /// `limit` functions with the highest churn × complexity (needs the git blame stage)
+    pub fn hotspots(&self, limit: usize) -> Vec<Hotspot> {
+        find_hotspots(&self.ir_doc.nodes, limit)
     }
 
     /// Get graph statistics
@@ -124,6 +392,18 @@ impl<'a> QueryEngine<'a> {
     }
 }
 
+/// Index `NodeMatcher` consults for `selector`
+fn selector_index(selector: &NodeSelector) -> &'static str {
+    match selector.selector_type {
+        NodeSelectorType::Source | NodeSelectorType::Sink => "GraphIndex.nodes_by_name",
+        NodeSelectorType::Block | NodeSelectorType::Expr | NodeSelectorType::Any => {
+            "GraphIndex.nodes (scan)"
+        }
+        _ if selector.get_string("name").is_some() => "GraphIndex.nodes_by_name",
+        _ => "GraphIndex.nodes (scan)",
+    }
+}
+
 /// Query engine statistics
 #[derive(Debug, Clone)]
 pub struct QueryEngineStats {
@@ -287,6 +567,86 @@ mod tests {
         assert_eq!(paths.len(), 0);
     }
 
+    #[test]
+    fn test_query_with_provenance_filter() {
+        use crate::shared::models::{EdgeProvenance, EdgeStage};
+
+        let mut ir_doc = create_test_ir();
+        for edge in ir_doc.edges.iter_mut() {
+            *edge = edge
+                .clone()
+                .with_provenance(EdgeProvenance::new(EdgeStage::DataFlow));
+        }
+        let engine = QueryEngine::new(&ir_doc);
+
+        let query = (Q::var("user") >> Q::call("execute"))
+            .via(E::dfg().from_stage(EdgeStage::DataFlow))
+            .any_path();
+        assert_eq!(engine.execute(query).len(), 1);
+
+        let query = (Q::var("user") >> Q::call("execute"))
+            .via(E::dfg().from_stage(EdgeStage::CrossFile))
+            .any_path();
+        assert!(engine.execute(query).is_empty());
+    }
+
+    #[test]
+    fn test_search_ranks_symbols() {
+        let ir_doc = create_test_ir();
+        let engine = QueryEngine::new(&ir_doc);
+
+        let hits = engine.search("execute", 5, &SearchFilters::default());
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].node_id, "call_execute");
+        assert_eq!(hits[0].signals.lexical, 1.0);
+
+        // Variables are not searchable symbols
+        assert!(engine
+            .search("user", 5, &SearchFilters::default())
+            .is_empty());
+    }
+
+    #[test]
+    fn test_semantic_search() {
+        use crate::features::vector::{
+            EmbeddingProvider, HashingEmbeddingProvider, HnswParams, VectorEntry,
+        };
+
+        let ir_doc = create_test_ir();
+        let provider = HashingEmbeddingProvider::default();
+        let query = provider.embed(&["run sql query"]).unwrap().remove(0);
+        assert!(QueryEngine::new(&ir_doc)
+            .semantic_search(&query, 3)
+            .unwrap()
+            .is_empty());
+
+        let chunks = [
+            ("chunk:execute", "def execute(sql): cursor.run(sql)"),
+            ("chunk:render", "def render(page): return html"),
+        ];
+        let index = ChunkVectorIndex::build(
+            "snap",
+            &provider,
+            HnswParams::default(),
+            chunks.iter().map(|(id, text)| {
+                let entry = VectorEntry {
+                    chunk_id: id.to_string(),
+                    file_path: "test.py".to_string(),
+                    start_line: 1,
+                    end_line: 2,
+                    symbol_id: None,
+                };
+                (entry, *text)
+            }),
+        )
+        .unwrap();
+        let engine = QueryEngine::new(&ir_doc).with_vectors(&index);
+
+        let hits = engine.semantic_search(&query, 3).unwrap();
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].entry.chunk_id, "chunk:execute");
+    }
+
     #[test]
     fn test_query_with_path_limit() {
         let ir_doc = create_test_ir();
@@ -301,4 +661,59 @@ mod tests {
 
         assert!(paths.len() <= 1);
     }
+
+    #[test]
+    fn test_can_reach_with_and_without_index() {
+        let mut ir_doc = create_test_ir();
+        for (source, target) in [("var_user", "var_temp"), ("var_temp", "call_execute")] {
+            ir_doc.edges.push(Edge::new(
+                source.to_string(),
+                target.to_string(),
+                crate::shared::models::EdgeKind::Calls,
+            ));
+        }
+        let index = CallReachabilityIndex::build(&ir_doc.edges).unwrap();
+
+        let bfs = QueryEngine::new(&ir_doc);
+        let indexed = QueryEngine::new(&ir_doc).with_call_reachability(&index);
+        for engine in [&bfs, &indexed] {
+            assert!(engine.can_reach("var_user", "call_execute"));
+            assert!(!engine.can_reach("call_execute", "var_user"));
+            assert!(!engine.can_reach("var_user", "var_user"));
+        }
+    }
+
+    #[test]
+    fn test_execute_explain_reports_steps() {
+        let ir_doc = create_test_ir();
+        let engine = QueryEngine::new(&ir_doc);
+
+        let query = (Q::var("user") >> Q::call("execute"))
+            .via(E::dfg())
+            .any_path();
+        let (paths, explain) = engine.execute_explain(query);
+
+        assert_eq!(paths.len(), 1);
+        let steps: Vec<_> = explain
+            .steps
+            .iter()
+            .map(|s| (s.name, s.index.as_str(), s.input, s.output))
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                ("match_source", "GraphIndex.nodes_by_name", 3, 1),
+                ("match_target", "GraphIndex.nodes_by_name", 3, 1),
+                ("traverse", "GraphIndex.edges_from (BFS)", 1, 1),
+                ("filter_paths", "path constraints", 1, 1),
+            ]
+        );
+        assert!(explain.slowest().is_some());
+        assert!(explain.to_string().contains("match_source"));
+
+        let (_, explain) = engine.search_explain("execute", 5, &SearchFilters::default());
+        assert_eq!(explain.steps[0].index, "GraphSignals (computed)");
+        let (_, explain) = engine.search_explain("execute", 5, &SearchFilters::default());
+        assert_eq!(explain.steps[0].index, "GraphSignals (cached)");
+    }
 }
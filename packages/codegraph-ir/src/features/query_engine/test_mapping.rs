@@ -0,0 +1,559 @@
+//! Test-to-code mapping: which tests exercise which production symbols
+//!
+//! Test functions are found by naming conventions (`TestDetector`: pytest
+//! `test_*` / `Test*` classes, jest `*.test.ts` / `__tests__`, JUnit `@Test` /
+//! `*Test.java`) and must live in a test file; setup/teardown hooks are not
+//! tests. From each test, Calls / Invokes / Instantiates edges are followed
+//! up to `max_depth` hops (through test helpers too), and every production
+//! symbol reached is recorded in the `tested_by` reverse index.
+//!
+//! Unresolved call targets match by last segment (`self.save` → `save`), so
+//! selection errs towards running more tests. Tests reached transitively are
+//! the test subset of the impact analysis' dependents, bounded by depth.
+//!
+//! ```ignore
+//! let map = build_test_map(&result.nodes, &result.edges, DEFAULT_TEST_MAP_DEPTH);
+//! let diffs = parse_unified_diff(&diff);
+//! for test in map.select_tests_for_diff(&result.nodes, &diffs) {
+//!     println!("{} ({:?})", test.fqn, test.framework);
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+use crate::features::chunking::TestDetector;
+use crate::pipeline::unified_orchestrator::FileDiff;
+use crate::shared::models::{Edge, EdgeKind, Node, NodeKind, Span};
+
+/// Call hops followed from a test by default
+pub const DEFAULT_TEST_MAP_DEPTH: usize = 5;
+
+/// Setup / teardown hooks (`TestDetector` names that are not tests)
+const TEST_HOOKS: &[&str] = &[
+    "describe",
+    "beforeEach",
+    "afterEach",
+    "beforeAll",
+    "afterAll",
+    "setUp",
+    "tearDown",
+    "setUpClass",
+    "tearDownClass",
+];
+
+/// Test framework a test follows the conventions of
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TestFramework {
+    Pytest,
+    Jest,
+    JUnit,
+    Other,
+}
+
+impl TestFramework {
+    fn for_language(language: &str) -> Self {
+        match language {
+            "python" => TestFramework::Pytest,
+            "javascript" | "typescript" => TestFramework::Jest,
+            "java" | "kotlin" => TestFramework::JUnit,
+            _ => TestFramework::Other,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TestFramework::Pytest => "pytest",
+            TestFramework::Jest => "jest",
+            TestFramework::JUnit => "junit",
+            TestFramework::Other => "other",
+        }
+    }
+}
+
+/// One test function or method
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestCase {
+    pub symbol_id: String,
+    pub fqn: String,
+    pub name: String,
+    pub file_path: String,
+    pub span: Span,
+    pub framework: TestFramework,
+    /// How the framework's runner names the test: pytest node id
+    /// (`path::Class::test`), jest test file, JUnit `Class#method`
+    pub runner_id: String,
+}
+
+/// A test reaching a production symbol, `depth` calls away (1 = direct call)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestedBy {
+    pub test_id: String,
+    pub depth: u32,
+}
+
+/// Tests and the `tested_by` reverse index
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TestMap {
+    /// Ordered by file and position
+    pub tests: Vec<TestCase>,
+    /// Production symbol id → tests exercising it, nearest first
+    pub tested_by: BTreeMap<String, Vec<TestedBy>>,
+}
+
+impl TestMap {
+    pub fn test(&self, symbol_id: &str) -> Option<&TestCase> {
+        self.tests.iter().find(|t| t.symbol_id == symbol_id)
+    }
+
+    /// Tests exercising the production symbol `symbol_id`
+    pub fn tests_for(&self, symbol_id: &str) -> &[TestedBy] {
+        self.tested_by
+            .get(symbol_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Tests to run when `symbol_ids` change (changed tests included)
+    pub fn select_tests<'a>(
+        &self,
+        symbol_ids: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<&TestCase> {
+        let mut selected: HashSet<&str> = HashSet::new();
+        for id in symbol_ids {
+            selected.extend(self.tests_for(id).iter().map(|t| t.test_id.as_str()));
+            selected.insert(id);
+        }
+        self.tests
+            .iter()
+            .filter(|t| selected.contains(t.symbol_id.as_str()))
+            .collect()
+    }
+
+    /// Tests to run for a diff against the indexed `nodes`
+    ///
+    /// Changed lines map to their innermost enclosing function, method or
+    /// class; module-level changes and deleted files select every symbol of
+    /// the file.
+    pub fn select_tests_for_diff(&self, nodes: &[Node], diffs: &[FileDiff]) -> Vec<&TestCase> {
+        let mut changed: Vec<&str> = Vec::new();
+        for diff in diffs {
+            let path = diff.old_path.as_deref().unwrap_or(diff.path());
+            let symbols: Vec<&Node> = nodes
+                .iter()
+                .filter(|n| n.file_path == path && is_mapped_kind(n.kind))
+                .collect();
+            let lines = diff.changed_lines();
+            let whole_file = diff.is_deleted()
+                || lines
+                    .iter()
+                    .any(|&line| innermost(&symbols, line).is_none());
+            if whole_file {
+                changed.extend(symbols.iter().map(|n| n.id.as_str()));
+                continue;
+            }
+            changed.extend(
+                lines
+                    .iter()
+                    .filter_map(|&line| innermost(&symbols, line))
+                    .map(|n| n.id.as_str()),
+            );
+        }
+        self.select_tests(changed)
+    }
+}
+
+/// Map tests to the production symbols reachable within `max_depth` calls
+pub fn build_test_map(nodes: &[Node], edges: &[Edge], max_depth: usize) -> TestMap {
+    let index = CallIndex::new(nodes, edges);
+    let mut map = TestMap::default();
+    for (i, test) in index.tests() {
+        // Breadth-first, so each symbol is recorded at its nearest depth
+        let mut visited: HashSet<usize> = HashSet::from([i]);
+        let mut queue: VecDeque<(usize, u32)> = VecDeque::from([(i, 0)]);
+        while let Some((current, depth)) = queue.pop_front() {
+            let node = &nodes[current];
+            if depth > 0 && index.is_production(current) {
+                map.tested_by
+                    .entry(node.id.clone())
+                    .or_default()
+                    .push(TestedBy {
+                        test_id: test.symbol_id.clone(),
+                        depth,
+                    });
+            }
+            for child in index.nested(current) {
+                if visited.insert(child) {
+                    queue.push_front((child, depth));
+                }
+            }
+            if depth as usize >= max_depth {
+                continue;
+            }
+            for &callee in index.callees(current) {
+                if visited.insert(callee) {
+                    queue.push_back((callee, depth + 1));
+                }
+            }
+        }
+        map.tests.push(test);
+    }
+
+    map.tests.sort_by(|a, b| {
+        (a.file_path.as_str(), a.span.start_line, a.span.start_col).cmp(&(
+            b.file_path.as_str(),
+            b.span.start_line,
+            b.span.start_col,
+        ))
+    });
+    for tests in map.tested_by.values_mut() {
+        tests.sort_by(|a, b| (a.depth, &a.test_id).cmp(&(b.depth, &b.test_id)));
+    }
+    map
+}
+
+/// Resolved call graph over `nodes`, with test classification
+pub(crate) struct CallIndex<'a> {
+    nodes: &'a [Node],
+    detector: TestDetector,
+    by_id: HashMap<&'a str, usize>,
+    children: HashMap<usize, Vec<usize>>,
+    callees: HashMap<usize, Vec<usize>>,
+    callers: HashMap<usize, Vec<usize>>,
+}
+
+impl<'a> CallIndex<'a> {
+    pub(crate) fn new(nodes: &'a [Node], edges: &[Edge]) -> Self {
+        let mut by_id: HashMap<&str, usize> = HashMap::new();
+        let mut by_key: HashMap<&str, Vec<usize>> = HashMap::new();
+        let mut by_name: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (i, node) in nodes.iter().enumerate() {
+            by_id.entry(node.id.as_str()).or_insert(i);
+            by_key.entry(node.id.as_str()).or_default().push(i);
+            if node.fqn != node.id {
+                by_key.entry(node.fqn.as_str()).or_default().push(i);
+            }
+            if is_mapped_kind(node.kind) {
+                if let Some(name) = node.name.as_deref() {
+                    by_name.entry(name).or_default().push(i);
+                }
+            }
+        }
+
+        let mut children: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (i, node) in nodes.iter().enumerate() {
+            if let Some(&parent) = node.parent_id.as_deref().and_then(|p| by_id.get(p)) {
+                children.entry(parent).or_default().push(i);
+            }
+        }
+
+        let mut callees: HashMap<usize, Vec<usize>> = HashMap::new();
+        let mut callers: HashMap<usize, Vec<usize>> = HashMap::new();
+        let calls = edges.iter().filter(|e| {
+            matches!(
+                e.kind,
+                EdgeKind::Calls | EdgeKind::Invokes | EdgeKind::Instantiates
+            )
+        });
+        for edge in calls {
+            let Some(&source) = by_id.get(edge.source_id.as_str()) else {
+                continue;
+            };
+            for &target in resolve(&by_key, &by_name, &edge.target_id) {
+                callees.entry(source).or_default().push(target);
+                callers.entry(target).or_default().push(source);
+            }
+        }
+
+        Self {
+            nodes,
+            detector: TestDetector::new(),
+            by_id,
+            children,
+            callees,
+            callers,
+        }
+    }
+
+    pub(crate) fn node(&self, i: usize) -> &'a Node {
+        &self.nodes[i]
+    }
+
+    pub(crate) fn index_of(&self, id: &str) -> Option<usize> {
+        self.by_id.get(id).copied()
+    }
+
+    pub(crate) fn in_test_file(&self, node: &Node) -> bool {
+        node.is_test_file == Some(true)
+            || self
+                .detector
+                .is_test_file(&node.file_path, Some(node.language.as_str()))
+    }
+
+    /// Function, method or class outside test files
+    pub(crate) fn is_production(&self, i: usize) -> bool {
+        let node = &self.nodes[i];
+        is_mapped_kind(node.kind) && !self.in_test_file(node)
+    }
+
+    /// Tests of the index, in node order
+    pub(crate) fn tests(&self) -> impl Iterator<Item = (usize, TestCase)> + '_ {
+        (0..self.nodes.len()).filter_map(|i| Some((i, self.test_case(i)?)))
+    }
+
+    pub(crate) fn test_case(&self, i: usize) -> Option<TestCase> {
+        let test = &self.nodes[i];
+        if !matches!(test.kind, NodeKind::Function | NodeKind::Method) || !self.in_test_file(test) {
+            return None;
+        }
+        let name = test.name.as_deref()?;
+        let is_test = !TEST_HOOKS.contains(&name)
+            && self.detector.is_test_function(
+                name,
+                &test.file_path,
+                Some(test.language.as_str()),
+                test.decorators.as_deref(),
+            );
+        if !is_test {
+            return None;
+        }
+        let framework = TestFramework::for_language(&test.language);
+        let class = test
+            .parent_id
+            .as_deref()
+            .and_then(|p| self.index_of(p))
+            .map(|p| &self.nodes[p])
+            .filter(|p| p.kind == NodeKind::Class)
+            .and_then(|p| p.name.as_deref());
+        let runner_id = match (framework, class) {
+            (TestFramework::Pytest, Some(class)) => {
+                format!("{}::{}::{}", test.file_path, class, name)
+            }
+            (TestFramework::Pytest, None) => format!("{}::{}", test.file_path, name),
+            (TestFramework::Jest, _) => test.file_path.clone(),
+            (TestFramework::JUnit, _) => match test.fqn.rsplit_once('.') {
+                Some((class, method)) => format!("{}#{}", class, method),
+                None => test.fqn.clone(),
+            },
+            (TestFramework::Other, _) => test.fqn.clone(),
+        };
+        Some(TestCase {
+            symbol_id: test.id.clone(),
+            fqn: test.fqn.clone(),
+            name: name.to_string(),
+            file_path: test.file_path.clone(),
+            span: test.span,
+            framework,
+            runner_id,
+        })
+    }
+
+    pub(crate) fn callees(&self, i: usize) -> &[usize] {
+        self.callees.get(&i).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub(crate) fn callers(&self, i: usize) -> &[usize] {
+        self.callers.get(&i).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Nested functions and lambdas, which call on behalf of their parent (a
+    /// class is not running all of its methods)
+    pub(crate) fn nested(&self, i: usize) -> impl Iterator<Item = usize> + '_ {
+        let is_class = self.nodes[i].kind == NodeKind::Class;
+        self.children
+            .get(&i)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(move |&child| {
+                !is_class && !matches!(self.nodes[child].kind, NodeKind::Class | NodeKind::Method)
+            })
+    }
+
+    /// Enclosing function of a nested function or lambda
+    pub(crate) fn enclosing(&self, i: usize) -> Option<usize> {
+        let node = &self.nodes[i];
+        if matches!(node.kind, NodeKind::Class | NodeKind::Method) {
+            return None;
+        }
+        let parent = self.index_of(node.parent_id.as_deref()?)?;
+        (self.nodes[parent].kind != NodeKind::Class).then_some(parent)
+    }
+}
+
+/// Nodes a call target names: exact id / FQN, else by last segment
+fn resolve<'a>(
+    by_key: &'a HashMap<&str, Vec<usize>>,
+    by_name: &'a HashMap<&str, Vec<usize>>,
+    target: &str,
+) -> &'a [usize] {
+    if let Some(found) = by_key.get(target) {
+        return found;
+    }
+    let last = target.rsplit('.').next().unwrap_or(target);
+    by_name.get(last).map(Vec::as_slice).unwrap_or_default()
+}
+
+pub(crate) fn is_mapped_kind(kind: NodeKind) -> bool {
+    matches!(
+        kind,
+        NodeKind::Function | NodeKind::Method | NodeKind::Class
+    )
+}
+
+pub(crate) fn innermost<'a>(symbols: &[&'a Node], line: u32) -> Option<&'a Node> {
+    symbols
+        .iter()
+        .copied()
+        .filter(|n| n.span.start_line <= line && line <= n.span.end_line)
+        .min_by_key(|n| n.span.end_line - n.span.start_line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::unified_orchestrator::parse_unified_diff;
+
+    fn node(id: &str, kind: NodeKind, file: &str, name: &str, lines: (u32, u32)) -> Node {
+        Node::new(
+            id.to_string(),
+            kind,
+            format!("app.{}", name),
+            file.to_string(),
+            Span::new(lines.0, 0, lines.1, 0),
+        )
+        .with_name(name)
+    }
+
+    fn calls(source: &str, target: &str) -> Edge {
+        Edge::new(source.to_string(), target.to_string(), EdgeKind::Calls)
+    }
+
+    #[test]
+    fn test_maps_tests_to_reachable_production_symbols() {
+        let nodes = vec![
+            node("save", NodeKind::Function, "app/store.py", "save", (1, 4)),
+            node(
+                "encode",
+                NodeKind::Function,
+                "app/store.py",
+                "encode",
+                (6, 8),
+            ),
+            node("load", NodeKind::Function, "app/store.py", "load", (10, 12)),
+            node(
+                "t_save",
+                NodeKind::Function,
+                "tests/test_store.py",
+                "test_save",
+                (1, 3),
+            ),
+            node(
+                "t_load",
+                NodeKind::Function,
+                "tests/test_store.py",
+                "test_load",
+                (5, 7),
+            ),
+            node(
+                "setUp",
+                NodeKind::Function,
+                "tests/test_store.py",
+                "setUp",
+                (9, 10),
+            ),
+            node(
+                "make",
+                NodeKind::Function,
+                "tests/test_store.py",
+                "make_data",
+                (12, 13),
+            ),
+            // Production code named like a test is not a test
+            node(
+                "probe",
+                NodeKind::Function,
+                "app/health.py",
+                "test_connection",
+                (1, 2),
+            ),
+        ];
+        let edges = vec![
+            calls("t_save", "make_data"),
+            calls("make", "app.save"),
+            calls("save", "encode"),
+            calls("t_load", "self.load"),
+            calls("setUp", "app.encode"),
+        ];
+
+        let map = build_test_map(&nodes, &edges, DEFAULT_TEST_MAP_DEPTH);
+        let tests: Vec<_> = map.tests.iter().map(|t| t.symbol_id.as_str()).collect();
+        assert_eq!(tests, vec!["t_save", "t_load"]);
+        assert_eq!(map.tests[0].framework, TestFramework::Pytest);
+        assert_eq!(map.tests[0].runner_id, "tests/test_store.py::test_save");
+
+        // Through the test helper, not recorded itself
+        assert_eq!(
+            map.tests_for("save"),
+            &[TestedBy {
+                test_id: "t_save".to_string(),
+                depth: 2
+            }]
+        );
+        assert_eq!(map.tests_for("encode")[0].depth, 3);
+        assert!(map.tests_for("make").is_empty());
+        assert_eq!(map.tests_for("load")[0].test_id, "t_load");
+
+        let shallow = build_test_map(&nodes, &edges, 2);
+        assert!(shallow.tests_for("encode").is_empty());
+
+        let selected: Vec<_> = map
+            .select_tests(["encode", "t_load"])
+            .iter()
+            .map(|t| t.name.as_str())
+            .collect();
+        assert_eq!(selected, vec!["test_save", "test_load"]);
+    }
+
+    #[test]
+    fn test_select_tests_for_diff() {
+        let nodes = vec![
+            node("save", NodeKind::Function, "app/store.py", "save", (1, 4)),
+            node("load", NodeKind::Function, "app/store.py", "load", (10, 12)),
+            node(
+                "t_save",
+                NodeKind::Function,
+                "tests/test_store.py",
+                "test_save",
+                (1, 3),
+            ),
+            node(
+                "t_load",
+                NodeKind::Function,
+                "tests/test_store.py",
+                "test_load",
+                (5, 7),
+            ),
+        ];
+        let edges = vec![calls("t_save", "save"), calls("t_load", "load")];
+        let map = build_test_map(&nodes, &edges, DEFAULT_TEST_MAP_DEPTH);
+
+        let diff = "\
+diff --git a/app/store.py b/app/store.py
+--- a/app/store.py
++++ b/app/store.py
+@@ -11,1 +11,1 @@
+-    return None
++    return {}
+";
+        let selected = map.select_tests_for_diff(&nodes, &parse_unified_diff(diff));
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].symbol_id, "t_load");
+
+        // Module-level change: every test of the file's symbols
+        let diff = diff.replace("@@ -11,1 +11,1 @@", "@@ -20,1 +20,1 @@");
+        let selected = map.select_tests_for_diff(&nodes, &parse_unified_diff(&diff));
+        assert_eq!(selected.len(), 2);
+    }
+}
@@ -0,0 +1,282 @@
+//! Impacted-test selection for CI
+//!
+//! For each changed file, every production function, method and class in it
+//! is traced up its reverse call dependencies (callers, and the enclosing
+//! function of nested functions and lambdas) until tests are reached; tests
+//! in changed test files are selected directly. Unlike `TestMap`, the walk
+//! is unbounded, so a test is selected however deep the change sits.
+//!
+//! The selection is only as good as the call graph, so it falls back to the
+//! full suite (unless disabled) when a changed symbol reaches no test, an
+//! indexed source file has no symbols to trace, or a test file that is not
+//! indexed yet (new test, `conftest.py`) changed.
+//!
+//! ```ignore
+//! let selection = result.select_tests(&changed_files, &TestSelectionConfig::default());
+//! if selection.full_suite {
+//!     run_all();
+//! } else {
+//!     run(selection.runner_ids());
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::path::Path;
+
+use super::test_mapping::{CallIndex, TestCase};
+use crate::features::chunking::TestDetector;
+use crate::features::parsing::LanguageId;
+use crate::shared::models::{Edge, Node};
+
+/// Impacted-test selection settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestSelectionConfig {
+    /// Select every test when the changes cannot be fully traced to tests
+    pub fallback_to_full_suite: bool,
+}
+
+impl Default for TestSelectionConfig {
+    fn default() -> Self {
+        Self {
+            fallback_to_full_suite: true,
+        }
+    }
+}
+
+/// Tests to run for a change
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TestSelection {
+    /// Ordered by file and position (every test when `full_suite`)
+    pub tests: Vec<TestCase>,
+    pub full_suite: bool,
+    /// Why the full suite was selected (also filled with fallback disabled)
+    pub fallback_reasons: Vec<String>,
+    /// Changed production symbols (ids)
+    pub changed_symbols: Vec<String>,
+    /// FQNs of changed production symbols no test reaches
+    pub uncovered_symbols: Vec<String>,
+    /// Changed files without indexed nodes
+    pub unindexed_files: Vec<String>,
+}
+
+impl TestSelection {
+    pub fn test_ids(&self) -> Vec<&str> {
+        self.tests.iter().map(|t| t.symbol_id.as_str()).collect()
+    }
+
+    /// Runner ids, deduplicated (jest tests share their file)
+    pub fn runner_ids(&self) -> Vec<&str> {
+        let mut seen = HashSet::new();
+        self.tests
+            .iter()
+            .map(|t| t.runner_id.as_str())
+            .filter(|id| seen.insert(*id))
+            .collect()
+    }
+}
+
+/// Tests affected by changes to `changed_files` (repo-relative paths)
+pub fn select_impacted_tests(
+    nodes: &[Node],
+    edges: &[Edge],
+    changed_files: &[String],
+    config: &TestSelectionConfig,
+) -> TestSelection {
+    let index = CallIndex::new(nodes, edges);
+    let mut tests: Vec<(usize, TestCase)> = index.tests().collect();
+    tests.sort_by(|(_, a), (_, b)| {
+        (a.file_path.as_str(), a.span.start_line, a.span.start_col).cmp(&(
+            b.file_path.as_str(),
+            b.span.start_line,
+            b.span.start_col,
+        ))
+    });
+    let test_positions: HashMap<usize, usize> = tests
+        .iter()
+        .enumerate()
+        .map(|(position, &(i, _))| (i, position))
+        .collect();
+
+    let mut by_file: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (i, node) in nodes.iter().enumerate() {
+        by_file.entry(node.file_path.as_str()).or_default().push(i);
+    }
+
+    let mut selection = TestSelection::default();
+    let mut selected: BTreeSet<usize> = BTreeSet::new();
+    let detector = TestDetector::new();
+    for path in changed_files {
+        let Some(file_nodes) = by_file.get(path.as_str()) else {
+            let language = Path::new(path)
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .and_then(LanguageId::from_extension);
+            let language = language.as_ref().map(|l| l.name());
+            if detector.is_test_file(path, language) {
+                selection
+                    .fallback_reasons
+                    .push(format!("test file not indexed: {}", path));
+            }
+            selection.unindexed_files.push(path.clone());
+            continue;
+        };
+
+        let changed_tests: Vec<usize> = file_nodes
+            .iter()
+            .filter_map(|i| test_positions.get(i).copied())
+            .collect();
+        selected.extend(changed_tests.iter().copied());
+        let symbols: Vec<usize> = file_nodes
+            .iter()
+            .copied()
+            .filter(|&i| index.is_production(i))
+            .collect();
+        if symbols.is_empty()
+            && changed_tests.is_empty()
+            && !index.in_test_file(&nodes[file_nodes[0]])
+        {
+            selection
+                .fallback_reasons
+                .push(format!("no symbols to trace in {}", path));
+        }
+
+        for symbol in symbols {
+            let reached = reaching_tests(&index, &test_positions, symbol);
+            if reached.is_empty() {
+                selection.uncovered_symbols.push(nodes[symbol].fqn.clone());
+            }
+            selected.extend(reached);
+            selection.changed_symbols.push(nodes[symbol].id.clone());
+        }
+    }
+    if !selection.uncovered_symbols.is_empty() {
+        selection.fallback_reasons.push(format!(
+            "{} changed symbols reach no test",
+            selection.uncovered_symbols.len()
+        ));
+    }
+
+    selection.full_suite = config.fallback_to_full_suite && !selection.fallback_reasons.is_empty();
+    selection.tests = if selection.full_suite {
+        tests.into_iter().map(|(_, test)| test).collect()
+    } else {
+        selected
+            .into_iter()
+            .map(|position| tests[position].1.clone())
+            .collect()
+    };
+    selection
+}
+
+/// Positions (in `test_positions`) of the tests depending on `symbol`
+fn reaching_tests(
+    index: &CallIndex,
+    test_positions: &HashMap<usize, usize>,
+    symbol: usize,
+) -> Vec<usize> {
+    let mut reached = Vec::new();
+    let mut visited: HashSet<usize> = HashSet::from([symbol]);
+    let mut queue: VecDeque<usize> = VecDeque::from([symbol]);
+    while let Some(current) = queue.pop_front() {
+        if let Some(&position) = test_positions.get(&current) {
+            reached.push(position);
+        }
+        let dependents = index
+            .callers(current)
+            .iter()
+            .copied()
+            .chain(index.enclosing(current));
+        for dependent in dependents {
+            if visited.insert(dependent) {
+                queue.push_back(dependent);
+            }
+        }
+    }
+    reached
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::models::{EdgeKind, NodeKind, Span};
+
+    fn node(id: &str, file: &str, name: &str, line: u32) -> Node {
+        Node::new(
+            id.to_string(),
+            NodeKind::Function,
+            format!("app.{}", name),
+            file.to_string(),
+            Span::new(line, 0, line + 2, 0),
+        )
+        .with_name(name)
+    }
+
+    fn calls(source: &str, target: &str) -> Edge {
+        Edge::new(source.to_string(), target.to_string(), EdgeKind::Calls)
+    }
+
+    fn setup() -> (Vec<Node>, Vec<Edge>) {
+        let nodes = vec![
+            node("encode", "app/codec.py", "encode", 1),
+            node("save", "app/store.py", "save", 1),
+            node("load", "app/store.py", "load", 5),
+            node("orphan", "app/unused.py", "orphan", 1),
+            node("t_save", "tests/test_store.py", "test_save", 1),
+            node("t_load", "tests/test_store.py", "test_load", 5),
+            node("t_other", "tests/test_other.py", "test_other", 1),
+        ];
+        let edges = vec![
+            calls("save", "encode"),
+            calls("t_save", "save"),
+            calls("t_load", "self.load"),
+        ];
+        (nodes, edges)
+    }
+
+    #[test]
+    fn test_selects_tests_through_reverse_dependencies() {
+        let (nodes, edges) = setup();
+        let config = TestSelectionConfig::default();
+
+        let selection =
+            select_impacted_tests(&nodes, &edges, &["app/codec.py".to_string()], &config);
+        assert!(!selection.full_suite);
+        assert_eq!(selection.test_ids(), vec!["t_save"]);
+        assert_eq!(
+            selection.runner_ids(),
+            vec!["tests/test_store.py::test_save"]
+        );
+
+        let changed = vec!["tests/test_other.py".to_string(), "README.md".to_string()];
+        let selection = select_impacted_tests(&nodes, &edges, &changed, &config);
+        assert_eq!(selection.test_ids(), vec!["t_other"]);
+        assert_eq!(selection.unindexed_files, vec!["README.md".to_string()]);
+    }
+
+    #[test]
+    fn test_falls_back_to_full_suite() {
+        let (nodes, edges) = setup();
+        let changed = vec!["app/unused.py".to_string(), "app/store.py".to_string()];
+
+        let selection =
+            select_impacted_tests(&nodes, &edges, &changed, &TestSelectionConfig::default());
+        assert!(selection.full_suite);
+        assert_eq!(selection.tests.len(), 3);
+        assert_eq!(selection.uncovered_symbols, vec!["app.orphan".to_string()]);
+
+        let strict = TestSelectionConfig {
+            fallback_to_full_suite: false,
+        };
+        let selection = select_impacted_tests(&nodes, &edges, &changed, &strict);
+        assert!(!selection.full_suite);
+        assert_eq!(selection.test_ids(), vec!["t_save", "t_load"]);
+        assert_eq!(selection.fallback_reasons.len(), 1);
+
+        // New test files cannot be traced
+        let changed = vec!["tests/test_new.py".to_string()];
+        let selection =
+            select_impacted_tests(&nodes, &edges, &changed, &TestSelectionConfig::default());
+        assert!(selection.full_suite);
+    }
+}
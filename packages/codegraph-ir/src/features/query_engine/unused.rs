@@ -0,0 +1,456 @@
+//! Unused imports and parameters
+//!
+//! Cheap per-file report built from what L1-L4 already produce:
+//!
+//! - an import is unused when nothing in its file refers to the name it
+//!   binds: no read/write occurrence, reference edge (calls, reads, inherits,
+//!   ...), DFG use or type annotation (`os` is used by `os.path.join`)
+//! - a parameter is unused when its function reads something (DFG uses or
+//!   Reads edges of the function body) but never the parameter
+//!
+//! Deliberately conservative: `__init__` modules (re-exports), `*`, `_`-prefixed
+//! and `__future__` names, `self`/`cls`, and functions that read nothing at
+//! all (stubs, abstract methods) are never reported. Imports are checked for
+//! Python only, where the bound name of an import node is known.
+//!
+//! ```ignore
+//! let unused = find_unused(&result.nodes, &result.edges, &result.occurrences, &result.dfg_graphs);
+//! let findings: Vec<GateFinding> = unused.iter().map(GateFinding::from).collect();
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::features::data_flow::infrastructure::dfg::DataFlowGraph;
+use crate::shared::models::{Edge, EdgeKind, Node, NodeKind, Occurrence, Span, SymbolRole};
+
+/// What is unused
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnusedKind {
+    Import,
+    Parameter,
+}
+
+impl UnusedKind {
+    /// Rule id of the matching gate finding
+    pub fn rule_id(&self) -> &'static str {
+        match self {
+            Self::Import => "unused-import",
+            Self::Parameter => "unused-parameter",
+        }
+    }
+}
+
+/// One unused import or parameter
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnusedSymbol {
+    pub kind: UnusedKind,
+    /// Bound name (import alias or parameter name)
+    pub name: String,
+    pub file_path: String,
+    pub span: Span,
+    /// Import node id, or id of the function owning the parameter
+    pub symbol_id: String,
+    /// Imported FQN, or FQN of the function owning the parameter
+    pub fqn: String,
+}
+
+/// Unused imports and parameters, ordered by file and position
+///
+/// `occurrences` and `dfg_graphs` may be empty: edges alone give the same
+/// answer for IR built by the pipeline, the others only add evidence of use.
+pub fn find_unused(
+    nodes: &[Node],
+    edges: &[Edge],
+    occurrences: &[Occurrence],
+    dfg_graphs: &[DataFlowGraph],
+) -> Vec<UnusedSymbol> {
+    let mut unused = unused_imports(nodes, edges, occurrences, dfg_graphs);
+    unused.extend(unused_parameters(nodes, edges, dfg_graphs));
+    unused.sort_by(|a, b| {
+        (a.file_path.as_str(), a.span.start_line, a.span.start_col).cmp(&(
+            b.file_path.as_str(),
+            b.span.start_line,
+            b.span.start_col,
+        ))
+    });
+    unused
+}
+
+/// Names referred to in one scope (`a.b.c` is recorded with its head `a`)
+#[derive(Default)]
+struct References<'a> {
+    full: HashSet<&'a str>,
+    heads: HashSet<&'a str>,
+}
+
+impl<'a> References<'a> {
+    fn insert(&mut self, name: &'a str) {
+        if name.is_empty() {
+            return;
+        }
+        self.full.insert(name);
+        self.heads.insert(head(name));
+    }
+
+    /// Identifiers in a type annotation or decorator (`Optional[np.ndarray]`)
+    fn insert_tokens(&mut self, text: &'a str) {
+        for token in text.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.')) {
+            self.insert(token);
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.full.is_empty()
+    }
+}
+
+fn head(name: &str) -> &str {
+    name.split('.').next().unwrap_or(name)
+}
+
+/// Everything but imports and structure counts as a use of the target
+fn is_reference(kind: EdgeKind) -> bool {
+    !matches!(
+        kind,
+        EdgeKind::Imports | EdgeKind::Contains | EdgeKind::Defines
+    )
+}
+
+fn unused_imports(
+    nodes: &[Node],
+    edges: &[Edge],
+    occurrences: &[Occurrence],
+    dfg_graphs: &[DataFlowGraph],
+) -> Vec<UnusedSymbol> {
+    let imports: Vec<(&Node, &str)> = nodes
+        .iter()
+        .filter(|n| n.kind == NodeKind::Import && n.language == "python")
+        .filter(|n| !is_package_init(&n.file_path))
+        .filter_map(|n| bound_name(n).map(|name| (n, name)))
+        .collect();
+    if imports.is_empty() {
+        return Vec::new();
+    }
+
+    // Edge sources are node ids, or the module path for module-level imports
+    let mut file_of: HashMap<&str, &str> = HashMap::new();
+    let mut files_of_function: HashMap<&str, Vec<&str>> = HashMap::new();
+    for node in nodes {
+        file_of.insert(node.id.as_str(), node.file_path.as_str());
+        if let Some(module_path) = node.module_path.as_deref() {
+            file_of
+                .entry(module_path)
+                .or_insert(node.file_path.as_str());
+        }
+        if matches!(node.kind, NodeKind::Function | NodeKind::Method) {
+            if let Some(name) = node.name.as_deref() {
+                files_of_function
+                    .entry(name)
+                    .or_default()
+                    .push(node.file_path.as_str());
+            }
+        }
+    }
+
+    let mut by_file: HashMap<&str, References<'_>> = HashMap::new();
+    for edge in edges.iter().filter(|e| is_reference(e.kind)) {
+        if let Some(&file) = file_of.get(edge.source_id.as_str()) {
+            by_file.entry(file).or_default().insert(&edge.target_id);
+        }
+    }
+    let accessed = SymbolRole::ReadAccess as u8 | SymbolRole::WriteAccess as u8;
+    for occ in occurrences.iter().filter(|o| o.roles & accessed != 0) {
+        by_file
+            .entry(occ.file_path.as_str())
+            .or_default()
+            .insert(&occ.symbol_id);
+    }
+    // DFGs are keyed by function name: credit every file defining it
+    for dfg in dfg_graphs {
+        let Some(files) = files_of_function.get(dfg.function_id.as_str()) else {
+            continue;
+        };
+        for &file in files {
+            let refs = by_file.entry(file).or_default();
+            for use_node in dfg.nodes.iter().filter(|n| !n.is_definition) {
+                refs.insert(&use_node.variable_name);
+            }
+        }
+    }
+    for node in nodes {
+        let refs = by_file.entry(node.file_path.as_str()).or_default();
+        for text in [node.type_annotation.as_deref(), node.return_type.as_deref()]
+            .into_iter()
+            .flatten()
+        {
+            refs.insert_tokens(text);
+        }
+        for decorator in node.decorators.iter().flatten() {
+            refs.insert_tokens(decorator);
+        }
+    }
+
+    imports
+        .into_iter()
+        .filter(|(import, name)| {
+            !by_file.get(import.file_path.as_str()).is_some_and(|refs| {
+                refs.heads.contains(name)
+                    || refs.full.contains(import.fqn.as_str())
+                    || refs.full.contains(import.id.as_str())
+            })
+        })
+        .map(|(import, name)| UnusedSymbol {
+            kind: UnusedKind::Import,
+            name: name.to_string(),
+            file_path: import.file_path.clone(),
+            span: import.span,
+            symbol_id: import.id.clone(),
+            fqn: import.fqn.clone(),
+        })
+        .collect()
+}
+
+/// Name an import binds in its module (`import os.path` binds `os`)
+fn bound_name(import: &Node) -> Option<&str> {
+    // `import:<module>` nodes (language plugins) are one per statement
+    if import.fqn.starts_with("import:") || import.fqn.starts_with("__future__") {
+        return None;
+    }
+    let name = import
+        .name
+        .as_deref()
+        .unwrap_or_else(|| import.fqn.rsplit('.').next().unwrap_or(&import.fqn));
+    let name = head(name);
+    if name.is_empty() || name.contains('*') || name.starts_with('_') {
+        return None;
+    }
+    Some(name)
+}
+
+/// Package `__init__` modules import to re-export
+fn is_package_init(file_path: &str) -> bool {
+    let file_name = file_path.rsplit(['/', '\\']).next().unwrap_or(file_path);
+    matches!(file_name, "__init__.py" | "__init__.pyi")
+}
+
+fn unused_parameters(
+    nodes: &[Node],
+    edges: &[Edge],
+    dfg_graphs: &[DataFlowGraph],
+) -> Vec<UnusedSymbol> {
+    let mut children: HashMap<&str, Vec<&Node>> = HashMap::new();
+    for node in nodes {
+        if let Some(parent_id) = node.parent_id.as_deref() {
+            children.entry(parent_id).or_default().push(node);
+        }
+    }
+    let mut edges_from: HashMap<&str, Vec<&Edge>> = HashMap::new();
+    for edge in edges.iter().filter(|e| is_reference(e.kind)) {
+        edges_from
+            .entry(edge.source_id.as_str())
+            .or_default()
+            .push(edge);
+    }
+    let mut dfgs_by_name: HashMap<&str, Vec<&DataFlowGraph>> = HashMap::new();
+    for dfg in dfg_graphs {
+        dfgs_by_name
+            .entry(dfg.function_id.as_str())
+            .or_default()
+            .push(dfg);
+    }
+
+    let mut unused = Vec::new();
+    for func in nodes.iter().filter(|n| {
+        matches!(
+            n.kind,
+            NodeKind::Function | NodeKind::Method | NodeKind::Lambda
+        )
+    }) {
+        let params = parameters_of(func, &children);
+        if params.is_empty() {
+            continue;
+        }
+
+        let mut refs = References::default();
+        let mut pending = vec![func.id.as_str()];
+        while let Some(id) = pending.pop() {
+            for edge in edges_from.get(id).into_iter().flatten() {
+                refs.insert(&edge.target_id);
+            }
+            for child in children.get(id).into_iter().flatten() {
+                // Nested functions report their own parameters, but their
+                // reads still use the enclosing ones
+                pending.push(child.id.as_str());
+            }
+        }
+        if let Some(name) = func.name.as_deref() {
+            for dfg in dfgs_by_name.get(name).into_iter().flatten() {
+                for use_node in dfg.nodes.iter().filter(|n| !n.is_definition) {
+                    refs.insert(&use_node.variable_name);
+                }
+            }
+        }
+        if refs.is_empty() {
+            continue;
+        }
+
+        for (name, span) in params {
+            if !refs.heads.contains(name) {
+                unused.push(UnusedSymbol {
+                    kind: UnusedKind::Parameter,
+                    name: name.to_string(),
+                    file_path: func.file_path.clone(),
+                    span,
+                    symbol_id: func.id.clone(),
+                    fqn: func.fqn.clone(),
+                });
+            }
+        }
+    }
+    unused
+}
+
+/// Reportable parameters of a function: Parameter children, else the
+/// `parameters` list (positioned at the function)
+fn parameters_of<'a>(
+    func: &'a Node,
+    children: &HashMap<&str, Vec<&'a Node>>,
+) -> Vec<(&'a str, Span)> {
+    let nodes: Vec<(&str, Span)> = children
+        .get(func.id.as_str())
+        .into_iter()
+        .flatten()
+        .filter(|n| n.kind == NodeKind::Parameter)
+        .filter_map(|n| n.name.as_deref().map(|name| (name, n.span)))
+        .collect();
+    let params: Vec<(&str, Span)> = if nodes.is_empty() {
+        func.parameters
+            .iter()
+            .flatten()
+            .map(|p| (parameter_name(p), func.span))
+            .collect()
+    } else {
+        nodes
+    };
+    params
+        .into_iter()
+        .filter(|(name, _)| {
+            !(name.is_empty() || name.starts_with('_') || matches!(*name, "self" | "cls"))
+        })
+        .collect()
+}
+
+/// `*args: int = 0` -> `args`
+fn parameter_name(param: &str) -> &str {
+    let end = param.find([':', '=']).unwrap_or(param.len());
+    param[..end].trim().trim_start_matches('*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::data_flow::infrastructure::dfg::build_dfg;
+
+    fn node(id: &str, kind: NodeKind, fqn: &str, file: &str, name: &str, line: u32) -> Node {
+        Node::new(
+            id.to_string(),
+            kind,
+            fqn.to_string(),
+            file.to_string(),
+            Span::new(line, 0, line, 10),
+        )
+        .with_name(name)
+        .with_module_path("app")
+    }
+
+    fn import(id: &str, fqn: &str, file: &str, name: &str, line: u32) -> Node {
+        let mut import = node(id, NodeKind::Import, fqn, file, name, line);
+        import.parent_id = Some("app".to_string());
+        import
+    }
+
+    fn child(mut node: Node, parent: &str) -> Node {
+        node.parent_id = Some(parent.to_string());
+        node
+    }
+
+    fn reads(source: &str, target: &str) -> Edge {
+        Edge::new(source.to_string(), target.to_string(), EdgeKind::Reads)
+    }
+
+    #[test]
+    fn test_unused_imports() {
+        let mut run = child(
+            node("f", NodeKind::Function, "app.run", "app.py", "run", 7),
+            "app",
+        );
+        run.return_type = Some("Optional[int]".to_string());
+        let nodes = vec![
+            import("i1", "os", "app.py", "os", 1),
+            import("i2", "numpy", "app.py", "np", 2),
+            import("i3", "typing.Optional", "app.py", "Optional", 3),
+            import("i4", "json", "app.py", "json", 4),
+            import("i5", "typing.*", "app.py", "*", 5),
+            run,
+            import("i6", "sys", "pkg/__init__.py", "sys", 1),
+        ];
+        let edges = vec![
+            Edge::new("app".to_string(), "os".to_string(), EdgeKind::Imports),
+            Edge::new("f".to_string(), "os.path.join".to_string(), EdgeKind::Calls),
+        ];
+        let dfg = build_dfg(
+            "run".to_string(),
+            &[],
+            &[("np".to_string(), Span::new(8, 4, 8, 6))],
+        );
+
+        let unused = find_unused(&nodes, &edges, &[], &[dfg]);
+        let names: Vec<_> = unused.iter().map(|u| (u.kind, u.name.as_str())).collect();
+        assert_eq!(names, vec![(UnusedKind::Import, "json")]);
+        assert_eq!(unused[0].span.start_line, 4);
+        assert_eq!(unused[0].symbol_id, "i4");
+    }
+
+    #[test]
+    fn test_unused_parameters() {
+        let param = |id: &str, name: &str, parent: &str| {
+            child(
+                node(id, NodeKind::Parameter, name, "app.py", name, 3),
+                parent,
+            )
+        };
+        let mut listed = node("g", NodeKind::Function, "app.g", "app.py", "g", 10);
+        listed.parameters = Some(vec!["a: int".to_string(), "*rest".to_string()]);
+        let nodes = vec![
+            node("f", NodeKind::Method, "app.A.f", "app.py", "f", 2),
+            param("p1", "self", "f"),
+            param("p2", "used", "f"),
+            param("p3", "unused", "f"),
+            param("p4", "_ignored", "f"),
+            child(
+                node("v", NodeKind::Variable, "app.A.f.x", "app.py", "x", 4),
+                "f",
+            ),
+            listed,
+            // Stub: reads nothing, never reported
+            node("s", NodeKind::Function, "app.stub", "app.py", "stub", 20),
+            param("p5", "arg", "s"),
+        ];
+        let edges = vec![reads("v", "used.attr"), reads("g", "rest")];
+
+        let unused = find_unused(&nodes, &edges, &[], &[]);
+        let names: Vec<_> = unused
+            .iter()
+            .map(|u| (u.kind, u.name.as_str(), u.fqn.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                (UnusedKind::Parameter, "unused", "app.A.f"),
+                (UnusedKind::Parameter, "a", "app.g"),
+            ]
+        );
+    }
+}
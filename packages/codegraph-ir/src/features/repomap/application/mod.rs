@@ -1,5 +1,7 @@
 //! RepoMap Application Layer (UseCase)
 
+mod renderer;
 mod repomap_usecase;
 
+pub use renderer::{estimate_tokens, RenderOptions, RenderedRepoMap, RepoMapRenderer};
 pub use repomap_usecase::{RepoMapUseCase, RepoMapUseCaseImpl, RepoMapInput, RepoMapOutput};
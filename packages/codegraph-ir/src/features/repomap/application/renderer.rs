@@ -0,0 +1,415 @@
+//! Token-budgeted RepoMap rendering for LLM prompts
+//!
+//! Files are ranked by combined importance (re-ranked around the focus paths
+//! when given) and emitted aider-style, each with its top symbols, until the
+//! token budget is spent. Selected files are listed in path order so the
+//! output reads as a tree.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::path::Path;
+
+use ignore::overrides::{Override, OverrideBuilder};
+
+use crate::features::repomap::domain::context::RepoMapSeed;
+use crate::pipeline::end_to_end_result::{RepoMapNodeSummary, RepoMapSnapshotSummary};
+use crate::shared::models::{CodegraphError, Node, NodeKind, Result};
+
+/// Estimated prompt tokens of `text` (~4 characters per token)
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+/// Budget, focus and filters of one rendering
+#[derive(Debug, Clone)]
+pub struct RenderOptions {
+    /// Maximum estimated tokens of the rendered map
+    pub token_budget: usize,
+    /// Files or directories the task is about
+    pub focus: Vec<String>,
+    /// Gitignore-style globs of files to leave out
+    pub exclude: Vec<String>,
+    /// Symbols listed per file
+    pub symbols_per_file: usize,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            token_budget: 1024,
+            focus: Vec::new(),
+            exclude: Vec::new(),
+            symbols_per_file: 8,
+        }
+    }
+}
+
+impl RenderOptions {
+    pub fn new(token_budget: usize) -> Self {
+        Self {
+            token_budget,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_focus(mut self, focus: Vec<String>) -> Self {
+        self.focus = focus;
+        self
+    }
+
+    pub fn with_exclude(mut self, exclude: Vec<String>) -> Self {
+        self.exclude = exclude;
+        self
+    }
+
+    pub fn with_symbols_per_file(mut self, symbols_per_file: usize) -> Self {
+        self.symbols_per_file = symbols_per_file;
+        self
+    }
+}
+
+/// Rendered map and what made it in
+#[derive(Debug, Clone, Default)]
+pub struct RenderedRepoMap {
+    pub text: String,
+    /// Estimated tokens of `text` (never above the budget)
+    pub tokens: usize,
+    /// Files shown, most important first
+    pub files: Vec<String>,
+    /// Ranked files left out for lack of budget
+    pub omitted_files: usize,
+}
+
+/// Renders a RepoMap snapshot, with signatures from IR nodes when given
+pub struct RepoMapRenderer<'a> {
+    snapshot: &'a RepoMapSnapshotSummary,
+    /// FQN → IR node
+    symbols: HashMap<&'a str, &'a Node>,
+}
+
+impl<'a> RepoMapRenderer<'a> {
+    pub fn new(snapshot: &'a RepoMapSnapshotSummary) -> Self {
+        Self {
+            snapshot,
+            symbols: HashMap::new(),
+        }
+    }
+
+    /// IR nodes providing symbol signatures and files
+    pub fn with_nodes(mut self, nodes: &'a [Node]) -> Self {
+        self.symbols.extend(
+            nodes
+                .iter()
+                .filter(|node| !node.fqn.is_empty() && node.kind != NodeKind::File)
+                .map(|node| (node.fqn.as_str(), node)),
+        );
+        self
+    }
+
+    pub fn render(&self, options: &RenderOptions) -> Result<RenderedRepoMap> {
+        let excludes = exclude_globs(&options.exclude)?;
+        let snapshot = if options.focus.is_empty() {
+            Cow::Borrowed(self.snapshot)
+        } else {
+            let seeds: Vec<RepoMapSeed> = options
+                .focus
+                .iter()
+                .map(|path| RepoMapSeed::recently_edited(path.as_str()))
+                .collect();
+            let mut focused = self.snapshot.clone();
+            focused.rescore(&seeds);
+            Cow::Owned(focused)
+        };
+
+        let mut selected: Vec<(&str, String)> = Vec::new();
+        let mut rendered = RenderedRepoMap::default();
+        let mut tokens = 0;
+        for (file, symbols) in self.ranked_files(&snapshot, &excludes) {
+            let block = self.render_file(file, &symbols, options.symbols_per_file);
+            let header = format!("{}\n", file.path);
+            let block = [block, header]
+                .into_iter()
+                .find(|text| tokens + estimate_tokens(text) <= options.token_budget);
+            match block {
+                Some(block) => {
+                    tokens += estimate_tokens(&block);
+                    rendered.files.push(file.path.clone());
+                    selected.push((file.path.as_str(), block));
+                }
+                None => rendered.omitted_files += 1,
+            }
+        }
+
+        selected.sort_by(|a, b| a.0.cmp(b.0));
+        rendered.text = selected.into_iter().map(|(_, block)| block).collect();
+        if rendered.omitted_files > 0 {
+            let footer = format!("⋮ {} more files\n", rendered.omitted_files);
+            if tokens + estimate_tokens(&footer) <= options.token_budget {
+                rendered.text.push_str(&footer);
+            }
+        }
+        rendered.tokens = estimate_tokens(&rendered.text);
+        Ok(rendered)
+    }
+
+    /// Files by descending combined importance with their symbols (with
+    /// nesting depth below the file)
+    fn ranked_files<'s>(
+        &self,
+        snapshot: &'s RepoMapSnapshotSummary,
+        excludes: &Override,
+    ) -> Vec<(&'s RepoMapNodeSummary, Vec<(usize, &'s RepoMapNodeSummary)>)> {
+        let by_id: HashMap<&str, &RepoMapNodeSummary> = snapshot
+            .nodes
+            .iter()
+            .map(|node| (node.id.as_str(), node))
+            .collect();
+        let files_by_path: HashMap<&str, &RepoMapNodeSummary> = snapshot
+            .nodes
+            .iter()
+            .filter(|node| node.kind == "File")
+            .map(|node| (node.path.as_str(), node))
+            .collect();
+
+        let mut symbols: HashMap<&str, Vec<(usize, &RepoMapNodeSummary)>> = HashMap::new();
+        for node in &snapshot.nodes {
+            if !is_symbol(node) {
+                continue;
+            }
+            // Nearest File ancestor, else the file of the IR node
+            let mut nesting = 0;
+            let mut file = None;
+            let mut current = node;
+            for _ in 0..snapshot.nodes.len() {
+                let parent = current.parent_id.as_deref().and_then(|id| by_id.get(id));
+                let Some(parent) = parent.copied() else {
+                    break;
+                };
+                if parent.kind == "File" {
+                    file = Some(parent.id.as_str());
+                    break;
+                }
+                if is_symbol(parent) {
+                    nesting += 1;
+                }
+                current = parent;
+            }
+            let file = file.or_else(|| {
+                let ir = self.symbols.get(node.path.as_str())?;
+                let file = files_by_path.get(ir.file_path.as_str()).copied()?;
+                Some(file.id.as_str())
+            });
+            if let Some(file) = file {
+                symbols.entry(file).or_default().push((nesting, node));
+            }
+        }
+
+        let mut files: Vec<_> = snapshot
+            .nodes
+            .iter()
+            .filter(|node| node.kind == "File" && !is_excluded(excludes, &node.path))
+            .map(|file| (file, symbols.remove(file.id.as_str()).unwrap_or_default()))
+            .collect();
+        files.sort_by(|a, b| {
+            b.0.combined_importance
+                .total_cmp(&a.0.combined_importance)
+                .then_with(|| a.0.path.cmp(&b.0.path))
+        });
+        files
+    }
+
+    /// `path:` followed by the top symbols in source order; `⋮` marks
+    /// symbols left out
+    fn render_file(
+        &self,
+        file: &RepoMapNodeSummary,
+        symbols: &[(usize, &RepoMapNodeSummary)],
+        limit: usize,
+    ) -> String {
+        let mut top: Vec<(usize, usize, &RepoMapNodeSummary)> = symbols
+            .iter()
+            .enumerate()
+            .map(|(order, (nesting, node))| (order, *nesting, *node))
+            .collect();
+        top.sort_by(|a, b| b.2.combined_importance.total_cmp(&a.2.combined_importance));
+        top.truncate(limit);
+        // Source order: IR lines when every symbol has one, else snapshot order
+        let lines: Option<Vec<u32>> = top
+            .iter()
+            .map(|(_, _, node)| {
+                self.symbols
+                    .get(node.path.as_str())
+                    .map(|ir| ir.span.start_line)
+            })
+            .collect();
+        match lines {
+            Some(lines) => {
+                let mut keyed: Vec<_> = lines.into_iter().zip(top).collect();
+                keyed.sort_by_key(|(line, (order, _, _))| (*line, *order));
+                top = keyed.into_iter().map(|(_, symbol)| symbol).collect();
+            }
+            None => top.sort_by_key(|(order, _, _)| *order),
+        }
+
+        let mut text = format!("{}:\n", file.path);
+        for (_, nesting, node) in &top {
+            text.push_str(&format!(
+                "│ {}{}\n",
+                "    ".repeat(*nesting),
+                self.signature(node)
+            ));
+        }
+        if symbols.len() > top.len() {
+            text.push_str("⋮\n");
+        }
+        text
+    }
+
+    fn signature(&self, node: &RepoMapNodeSummary) -> String {
+        let Some(ir) = self.symbols.get(node.path.as_str()) else {
+            return format!("{} {}", node.kind.to_lowercase(), node.name);
+        };
+        let name = ir.name.as_deref().unwrap_or(&node.name);
+        let kind = ir.kind.as_str().to_lowercase();
+        match ir.kind {
+            NodeKind::Function | NodeKind::Method | NodeKind::Lambda => {
+                let params = ir.parameters.as_deref().unwrap_or_default().join(", ");
+                match &ir.return_type {
+                    Some(ret) => format!("{} {}({}) -> {}", kind, name, params, ret),
+                    None => format!("{} {}({})", kind, name, params),
+                }
+            }
+            _ => match ir.base_classes.as_deref() {
+                Some(bases) if !bases.is_empty() => {
+                    format!("{} {}({})", kind, name, bases.join(", "))
+                }
+                _ => format!("{} {}", kind, name),
+            },
+        }
+    }
+}
+
+fn is_symbol(node: &RepoMapNodeSummary) -> bool {
+    node.kind == "Class" || node.kind == "Function"
+}
+
+fn exclude_globs(patterns: &[String]) -> Result<Override> {
+    let mut builder = OverrideBuilder::new("");
+    for pattern in patterns {
+        builder.add(&format!("!{}", pattern)).map_err(|e| {
+            CodegraphError::config(format!("Invalid exclude glob {:?}: {}", pattern, e))
+        })?;
+    }
+    builder
+        .build()
+        .map_err(|e| CodegraphError::config(format!("Invalid exclude globs: {}", e)))
+}
+
+/// Excluded directly or through one of its directories
+fn is_excluded(excludes: &Override, path: &str) -> bool {
+    let path = Path::new(path);
+    path.ancestors()
+        .skip(1)
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .any(|dir| excludes.matched(dir, true).is_ignore())
+        || excludes.matched(path, false).is_ignore()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::repomap::infrastructure::PageRankSettings;
+    use crate::shared::models::Span;
+
+    fn summary(
+        id: &str,
+        kind: &str,
+        path: &str,
+        parent: &str,
+        importance: f64,
+    ) -> RepoMapNodeSummary {
+        RepoMapNodeSummary {
+            id: id.to_string(),
+            kind: kind.to_string(),
+            name: path.rsplit(['.', '/']).next().unwrap().to_string(),
+            path: path.to_string(),
+            parent_id: Some(parent.to_string()).filter(|p| !p.is_empty()),
+            children_count: 0,
+            depth: 0,
+            pagerank: importance,
+            authority: 0.0,
+            hub: 0.0,
+            combined_importance: importance,
+            loc: 0,
+            symbol_count: 0,
+            complexity: 0,
+            cognitive_complexity: 0,
+            max_nesting: 0,
+        }
+    }
+
+    fn snapshot() -> RepoMapSnapshotSummary {
+        let nodes = vec![
+            summary("repo", "Repository", "", "", 1.0),
+            summary("f_auth", "File", "src/auth.py", "repo", 0.9),
+            summary("c_svc", "Class", "auth.LoginService", "f_auth", 0.5),
+            summary(
+                "m_login",
+                "Function",
+                "auth.LoginService.login",
+                "c_svc",
+                0.4,
+            ),
+            summary("f_util", "File", "src/util.py", "repo", 0.3),
+            summary("fn_slug", "Function", "util.slugify", "f_util", 0.2),
+            summary("f_test", "File", "tests/test_auth.py", "repo", 0.6),
+        ];
+        RepoMapSnapshotSummary {
+            repo_id: "repo".to_string(),
+            snapshot_id: "v1".to_string(),
+            total_nodes: nodes.len(),
+            root_id: "repo".to_string(),
+            nodes,
+            total_loc: 0,
+            total_symbols: 3,
+            total_files: 3,
+            created_at: 0,
+            pagerank_settings: PageRankSettings::default(),
+        }
+    }
+
+    #[test]
+    fn test_render_within_budget_with_signatures() {
+        let snapshot = snapshot();
+        let mut login = Node::new(
+            "n1".to_string(),
+            NodeKind::Method,
+            "auth.LoginService.login".to_string(),
+            "src/auth.py".to_string(),
+            Span::new(10, 4, 20, 0),
+        )
+        .with_name("login");
+        login.parameters = Some(vec!["user".to_string(), "password".to_string()]);
+        login.return_type = Some("Token".to_string());
+        let nodes = vec![login];
+        let renderer = RepoMapRenderer::new(&snapshot).with_nodes(&nodes);
+
+        let options = RenderOptions::new(1024).with_exclude(vec!["tests/".to_string()]);
+        let map = renderer.render(&options).unwrap();
+        assert_eq!(
+            map.text,
+            "src/auth.py:\n\
+             │ class LoginService\n\
+             │     method login(user, password) -> Token\n\
+             src/util.py:\n\
+             │ function slugify\n"
+        );
+        assert_eq!(map.files, vec!["src/auth.py", "src/util.py"]);
+
+        // Room for the most important file only
+        let tight = renderer.render(&RenderOptions::new(20)).unwrap();
+        assert!(tight.tokens <= 20);
+        assert_eq!(tight.files[0], "src/auth.py");
+        assert!(tight.omitted_files > 0);
+    }
+}
@@ -136,6 +136,65 @@ impl Default for ContextSet {
     }
 }
 
+/// Personalization seed: a file, directory or symbol the task is about
+///
+/// Matches RepoMap nodes by path (file path for files, FQN for symbols);
+/// a directory seed matches everything below it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RepoMapSeed {
+    pub path: String,
+    pub context_type: ContextType,
+    /// Relevance weight [0.0, 1.0]
+    pub weight: f64,
+}
+
+impl RepoMapSeed {
+    pub fn new(path: impl Into<String>, context_type: ContextType, weight: f64) -> Self {
+        Self {
+            path: path.into(),
+            context_type,
+            weight: weight.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Recently edited file (full weight)
+    pub fn recently_edited(path: impl Into<String>) -> Self {
+        Self::new(path, ContextType::History, 1.0)
+    }
+
+    /// Query hit with its relevance score
+    pub fn query(path: impl Into<String>, relevance: f64) -> Self {
+        Self::new(path, ContextType::Query, relevance)
+    }
+
+    pub fn matches(&self, node_path: &str) -> bool {
+        let seed = self.path.trim_end_matches('/');
+        node_path == seed
+            || node_path
+                .strip_prefix(seed)
+                .is_some_and(|rest| rest.starts_with('/'))
+    }
+}
+
+impl ContextSet {
+    /// Context for `nodes` (id, path) from personalization seeds
+    pub fn from_seeds<'a>(
+        seeds: &[RepoMapSeed],
+        nodes: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Self {
+        let mut context = Self::new();
+        for (id, path) in nodes {
+            for seed in seeds.iter().filter(|seed| seed.matches(path)) {
+                context.add_item(
+                    ContextItem::new(id.to_string(), seed.context_type, seed.weight)
+                        .with_metadata("seed".to_string(), seed.path.clone()),
+                );
+            }
+        }
+        context
+    }
+}
+
 /// Port (interface) for context providers
 ///
 /// Implementations provide context from different sources:
@@ -288,6 +347,24 @@ mod tests {
         assert_eq!(ctx.type_weights.get(&ContextType::Query), Some(&0.3));
     }
 
+    #[test]
+    fn test_context_set_from_seeds() {
+        let seeds = vec![
+            RepoMapSeed::recently_edited("src/auth"),
+            RepoMapSeed::query("src/db.py", 0.5),
+        ];
+        let nodes = [
+            ("f1", "src/auth/login.py"),
+            ("f2", "src/authz.py"),
+            ("f3", "src/db.py"),
+        ];
+        let ctx = ContextSet::from_seeds(&seeds, nodes);
+
+        assert_eq!(ctx.get_context_nodes(), vec!["f1", "f3"]);
+        assert!((ctx.get_combined_weight("f1") - 0.1).abs() < 1e-9);
+        assert!((ctx.get_combined_weight("f3") - 0.15).abs() < 1e-9);
+    }
+
     #[test]
     fn test_context_set_empty_weight() {
         let ctx = ContextSet::new();
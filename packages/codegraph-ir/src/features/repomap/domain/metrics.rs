@@ -13,9 +13,17 @@ pub struct RepoMapMetrics {
     /// Number of symbols (functions, classes, etc.)
     pub symbol_count: usize,
 
-    /// Cyclomatic complexity (for functions)
+    /// Cyclomatic complexity (for functions; summed for files)
     pub complexity: usize,
 
+    /// Cognitive complexity (for functions; summed for files)
+    #[serde(default)]
+    pub cognitive_complexity: usize,
+
+    /// Deepest nesting of control structures
+    #[serde(default)]
+    pub max_nesting: usize,
+
     /// PageRank importance score (0.0-1.0)
     pub pagerank: f64,
 
@@ -59,6 +67,13 @@ impl RepoMapMetrics {
         self
     }
 
+    /// Set cognitive complexity and nesting depth
+    pub fn with_cognitive_complexity(mut self, cognitive: usize, max_nesting: usize) -> Self {
+        self.cognitive_complexity = cognitive;
+        self.max_nesting = max_nesting;
+        self
+    }
+
     /// Set PageRank score
     pub fn with_pagerank(mut self, pagerank: f64) -> Self {
         self.pagerank = pagerank;
@@ -6,7 +6,7 @@ pub mod context;
 pub mod metrics;
 pub mod models;
 
-pub use context::{ContextItem, ContextProvider, ContextSet, ContextType};
+pub use context::{ContextItem, ContextProvider, ContextSet, ContextType, RepoMapSeed};
 pub use metrics::{ImportanceWeights, RepoMapMetrics};
 pub use models::{NodeKind, RepoMapNode, RepoMapSnapshot};
 
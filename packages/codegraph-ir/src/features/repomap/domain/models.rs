@@ -231,6 +231,8 @@ impl RepoMapSnapshot {
             total.loc += node.metrics.loc;
             total.symbol_count += node.metrics.symbol_count;
             total.complexity += node.metrics.complexity;
+            total.cognitive_complexity += node.metrics.cognitive_complexity;
+            total.max_nesting = total.max_nesting.max(node.metrics.max_nesting);
         }
 
         total
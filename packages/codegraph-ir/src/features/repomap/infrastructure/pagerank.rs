@@ -36,7 +36,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use tracing::{debug, info};
 
-use crate::features::repomap::domain::context::ContextSet;
+use crate::features::repomap::domain::context::{ContextSet, RepoMapSeed};
 use crate::features::repomap::domain::metrics::ImportanceWeights;
 
 /// Graph document for PageRank computation
@@ -83,6 +83,23 @@ pub struct PageRankSettings {
 
     /// Combined score weights
     pub weights: ImportanceWeights,
+
+    /// Seeds biasing the teleport vector (recently edited files, query
+    /// hits); non-empty seeds switch the RepoMap stage to PPR
+    #[serde(default)]
+    pub personalization: Vec<RepoMapSeed>,
+}
+
+impl PageRankSettings {
+    pub fn with_damping(mut self, damping: f64) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    pub fn with_personalization(mut self, seeds: Vec<RepoMapSeed>) -> Self {
+        self.personalization = seeds;
+        self
+    }
 }
 
 impl Default for PageRankSettings {
@@ -98,6 +115,7 @@ impl Default for PageRankSettings {
             enable_personalized: false, // Fast mode: Only basic PageRank
             enable_hits: false,         // Fast mode: Skip Authority/Hub computation
             weights: ImportanceWeights::default(),
+            personalization: Vec::new(),
         }
     }
 }
@@ -355,6 +373,22 @@ impl PageRankEngine {
         scores
     }
 
+    /// PageRank personalized on path seeds; standard PageRank without seeds
+    ///
+    /// `paths` maps node ids to the RepoMap paths seeds are matched against.
+    pub fn compute_seeded_pagerank<'a>(
+        &self,
+        graph: &GraphDocument,
+        seeds: &[RepoMapSeed],
+        paths: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> HashMap<String, f64> {
+        if seeds.is_empty() {
+            return self.compute_pagerank(graph);
+        }
+        let context = ContextSet::from_seeds(seeds, paths);
+        self.compute_personalized_pagerank(graph, &context)
+    }
+
     /// Compute HITS (Hyperlink-Induced Topic Search) scores (OPTIMIZED)
     ///
     /// # Algorithm
@@ -883,7 +917,7 @@ mod tests {
 
     #[test]
     fn test_ppr_no_context_fallback() {
-        use crate::features::repomap::domain::context::ContextSet;
+        use crate::features::repomap::domain::context::{ContextSet, RepoMapSeed};
 
         // PPR with no context should behave like standard PageRank
         let settings = PageRankSettings::default();
@@ -1026,6 +1060,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_seeded_pagerank_favors_seeded_paths() {
+        let settings = PageRankSettings::default();
+        let engine = PageRankEngine::new(&settings);
+        let graph = create_test_graph_linear();
+        let paths = [("A", "src/a.py"), ("B", "src/b.py"), ("C", "lib/c.py")];
+
+        let unseeded = engine.compute_seeded_pagerank(&graph, &[], paths);
+        assert_eq!(unseeded, engine.compute_pagerank(&graph));
+
+        let seeds = [RepoMapSeed::recently_edited("src")];
+        let seeded = engine.compute_seeded_pagerank(&graph, &seeds, paths);
+        assert!(seeded["A"] > unseeded["A"]);
+    }
+
     #[test]
     fn test_ppr_convergence() {
         use crate::features::repomap::domain::context::{ContextItem, ContextSet, ContextType};
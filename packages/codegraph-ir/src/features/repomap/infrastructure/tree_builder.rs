@@ -21,6 +21,8 @@ use std::collections::{HashMap, HashSet};
 use tracing::{debug, info, warn};
 
 use crate::features::chunking::domain::Chunk;
+use crate::features::flow_graph::infrastructure::metrics::FunctionMetrics;
+use crate::features::query_engine::complexity::{ComplexityReport, ComplexityStats};
 use crate::features::repomap::domain::{NodeKind, RepoMapMetrics, RepoMapNode};
 
 /// Maps chunk_id → set of related graph node IDs
@@ -37,6 +39,10 @@ pub type ChunkToGraphMapping = HashMap<String, HashSet<String>>;
 pub struct RepoMapTreeBuilder {
     repo_id: String,
     snapshot_id: String,
+    /// Measured function metrics by symbol ID (replace the LOC heuristic)
+    function_metrics: HashMap<String, FunctionMetrics>,
+    /// Measured metrics per file path
+    file_metrics: HashMap<String, ComplexityStats>,
 }
 
 impl RepoMapTreeBuilder {
@@ -45,9 +51,26 @@ impl RepoMapTreeBuilder {
         Self {
             repo_id,
             snapshot_id,
+            function_metrics: HashMap::new(),
+            file_metrics: HashMap::new(),
         }
     }
 
+    /// Use measured complexity (L3 metrics stage) for function and file nodes
+    pub fn with_complexity(mut self, report: &ComplexityReport) -> Self {
+        self.function_metrics = report
+            .functions
+            .iter()
+            .map(|f| (f.symbol_id.clone(), f.metrics))
+            .collect();
+        self.file_metrics = report
+            .files
+            .iter()
+            .map(|(path, stats)| (path.clone(), stats.clone()))
+            .collect();
+        self
+    }
+
     /// Build RepoMap tree from chunks in parallel
     ///
     /// # Arguments
@@ -158,6 +181,8 @@ impl RepoMapTreeBuilder {
 
     /// Extract metrics from chunk
     fn chunk_metrics(&self, chunk: &Chunk, chunk_to_graph: &ChunkToGraphMapping) -> RepoMapMetrics {
+        use crate::features::chunking::domain::ChunkKind;
+
         // Calculate LOC from line range
         let loc = if let (Some(start), Some(end)) = (chunk.start_line, chunk.end_line) {
             (end - start + 1) as usize
@@ -171,13 +196,40 @@ impl RepoMapTreeBuilder {
             .map(|nodes| nodes.len())
             .unwrap_or(0);
 
+        let metrics = RepoMapMetrics::with_loc(loc).with_symbol_count(symbol_count);
+
+        // Measured complexity when the metrics stage ran for this chunk
+        let function = chunk
+            .symbol_id
+            .as_ref()
+            .and_then(|id| self.function_metrics.get(id));
+        if let Some(function) = function {
+            return metrics
+                .with_complexity(function.cyclomatic as usize)
+                .with_cognitive_complexity(
+                    function.cognitive as usize,
+                    function.max_nesting as usize,
+                );
+        }
+        let file = match chunk.kind {
+            ChunkKind::File => chunk
+                .file_path
+                .as_ref()
+                .and_then(|path| self.file_metrics.get(path)),
+            _ => None,
+        };
+        if let Some(file) = file {
+            return metrics
+                .with_complexity(file.total_cyclomatic as usize)
+                .with_cognitive_complexity(
+                    file.total_cognitive as usize,
+                    file.max_nesting as usize,
+                );
+        }
+
         // Complexity heuristic: LOC * symbol_density + nesting bonus
-        // Full: cyclomatic complexity requires CFG traversal
         let complexity = (loc as f64 * 0.1 + symbol_count as f64 * 0.5) as usize;
-
-        RepoMapMetrics::with_loc(loc)
-            .with_symbol_count(symbol_count)
-            .with_complexity(complexity)
+        metrics.with_complexity(complexity)
     }
 
     /// Calculate depth from FQN (number of dots + 1)
@@ -340,6 +392,62 @@ mod tests {
         assert_eq!(node.metrics.loc, 11); // 20 - 10 + 1
     }
 
+    #[test]
+    fn test_chunk_metrics_use_measured_complexity() {
+        use crate::features::query_engine::complexity::FunctionComplexity;
+        use crate::shared::models::Span;
+
+        let metrics = FunctionMetrics {
+            cyclomatic: 4,
+            cognitive: 6,
+            max_nesting: 2,
+            loc: 11,
+        };
+        let mut report = ComplexityReport::default();
+        report.functions.push(FunctionComplexity {
+            symbol_id: "func:foo".to_string(),
+            fqn: "module.foo".to_string(),
+            file_path: "module.py".to_string(),
+            module: "module".to_string(),
+            span: Span::new(10, 0, 20, 0),
+            metrics,
+        });
+        report
+            .files
+            .entry("module.py".to_string())
+            .or_default()
+            .add(&metrics);
+        let builder = RepoMapTreeBuilder::new("test-repo".to_string(), "v1".to_string())
+            .with_complexity(&report);
+
+        let mut function = create_test_chunk(
+            "chunk:test:function:foo",
+            ChunkKind::Function,
+            "module.foo",
+            None,
+            Some(10),
+            Some(20),
+        );
+        function.symbol_id = Some("func:foo".to_string());
+        let node = builder.chunk_to_node(&function, &HashMap::new());
+        assert_eq!(node.metrics.complexity, 4);
+        assert_eq!(node.metrics.cognitive_complexity, 6);
+        assert_eq!(node.metrics.max_nesting, 2);
+
+        let mut file = create_test_chunk(
+            "chunk:test:file:module",
+            ChunkKind::File,
+            "module",
+            None,
+            Some(1),
+            Some(40),
+        );
+        file.file_path = Some("module.py".to_string());
+        let node = builder.chunk_to_node(&file, &HashMap::new());
+        assert_eq!(node.metrics.complexity, 4);
+        assert_eq!(node.metrics.cognitive_complexity, 6);
+    }
+
     #[test]
     fn test_calculate_depth() {
         let builder = RepoMapTreeBuilder::new("test-repo".to_string(), "v1".to_string());
@@ -29,6 +29,7 @@
 //! - **Incremental Updates**: Merkle Hash based O(delta) complexity
 //! - **Git Integration**: Change frequency, Code Churn, Hot Spot detection
 //! - **Multi-storage**: JSON (dev), InMemory (test), PostgreSQL (prod)
+//! - **Prompt Rendering**: Token-budgeted, focus-aware text map for LLM prompts
 
 pub mod application; // UseCase layer
 pub mod domain;
@@ -36,12 +37,15 @@ pub mod infrastructure;
 pub mod ports;
 
 // Re-export application layer (primary interface)
-pub use application::{RepoMapInput, RepoMapOutput, RepoMapUseCase, RepoMapUseCaseImpl};
+pub use application::{
+    estimate_tokens, RenderOptions, RenderedRepoMap, RepoMapInput, RepoMapOutput, RepoMapRenderer,
+    RepoMapUseCase, RepoMapUseCaseImpl,
+};
 
 // Re-export domain types
 pub use domain::{
     ContextItem, ContextProvider, ContextSet, ContextType, ImportanceWeights, NodeKind,
-    RepoMapMetrics, RepoMapNode, RepoMapSnapshot,
+    RepoMapMetrics, RepoMapNode, RepoMapSeed, RepoMapSnapshot,
 };
 
 // Re-export infrastructure (internal use - prefer application layer)
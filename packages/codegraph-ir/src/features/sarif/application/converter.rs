@@ -0,0 +1,677 @@
+//! SARIF converter
+//!
+//! Accumulates security vulnerabilities, memory safety issues, taint paths
+//! and architecture rule violations into a single SARIF run. Rules are
+//! registered on first use so `ruleIndex` stays stable within a log.
+//!
+//! Taint path entries are node IDs; when the IR nodes are supplied
+//! (`with_nodes`) each entry becomes a code-flow step with the node's file
+//! and span. Entries that cannot be resolved fall back to "file:line"
+//! parsing, then to a logical location carrying the raw entry.
+//!
+//! Rule descriptions and taint messages come from the `shared::i18n`
+//! catalog in `SarifConfig::locale`. Taint results carry the catalog
+//! `message.id` and `arguments` (with the rule's `messageStrings`), and are
+//! fingerprinted on those, so a result keeps its identity across locales.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::features::architecture::{ArchitectureViolation, RuleSeverity};
+use crate::features::heap_analysis::{MemorySafetyIssue, SecurityVulnerability};
+use crate::features::sarif::domain::{
+    SarifArtifactLocation, SarifCodeFlow, SarifConfig, SarifDriver, SarifLevel, SarifLocation,
+    SarifLog, SarifLogicalLocation, SarifMessage, SarifPhysicalLocation, SarifProperties,
+    SarifRegion, SarifResult, SarifRule, SarifRuleConfiguration, SarifRun, SarifThreadFlow,
+    SarifThreadFlowLocation, SarifTool,
+};
+use crate::features::taint_analysis::ports::TaintPathDTO;
+use crate::shared::i18n::{MessageId, ReportOptions};
+use crate::shared::models::{Node, Span};
+
+/// Partial fingerprint key (line-independent result identity)
+const FINGERPRINT_KEY: &str = "codegraph/v1";
+
+/// Node position used to resolve taint path entries
+#[derive(Debug, Clone)]
+struct NodePosition {
+    file_path: String,
+    span: Span,
+    fqn: String,
+}
+
+/// Builds a SARIF log from analysis findings
+pub struct SarifBuilder {
+    config: SarifConfig,
+    report: ReportOptions,
+    nodes: HashMap<String, NodePosition>,
+    rules: Vec<SarifRule>,
+    rule_index: HashMap<String, usize>,
+    results: Vec<SarifResult>,
+}
+
+impl SarifBuilder {
+    pub fn new(config: SarifConfig) -> Self {
+        Self {
+            report: config.report_options(),
+            config,
+            nodes: HashMap::new(),
+            rules: Vec::new(),
+            rule_index: HashMap::new(),
+            results: Vec::new(),
+        }
+    }
+
+    /// Builder: Resolve taint path entries against these IR nodes
+    pub fn with_nodes(mut self, nodes: &[Node]) -> Self {
+        self.add_nodes(nodes);
+        self
+    }
+
+    /// Register IR nodes for taint path resolution
+    pub fn add_nodes(&mut self, nodes: &[Node]) {
+        for node in nodes {
+            self.nodes.insert(
+                node.id.clone(),
+                NodePosition {
+                    file_path: node.file_path.clone(),
+                    span: node.span,
+                    fqn: node.fqn.clone(),
+                },
+            );
+        }
+    }
+
+    /// Number of results added so far
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    /// Add a security vulnerability (heap/security analysis)
+    pub fn add_vulnerability(&mut self, vuln: &SecurityVulnerability) {
+        let rule_id = format!("{:?}", vuln.vuln_type);
+        let mut tags = vec!["security".to_string(), format!("{:?}", vuln.category)];
+        if let Some(cwe) = vuln.cwe_id {
+            tags.push(format!("external/cwe/cwe-{}", cwe));
+        }
+        let level = SarifLevel::from_score(vuln.severity);
+        let rule_index = self.ensure_rule(SarifRule {
+            id: rule_id.clone(),
+            name: Some(rule_id.clone()),
+            short_description: Some(SarifMessage::new(format!(
+                "{} ({:?})",
+                rule_id, vuln.category
+            ))),
+            help: non_empty(&vuln.recommendation).map(SarifMessage::new),
+            message_strings: BTreeMap::new(),
+            default_configuration: Some(SarifRuleConfiguration { level }),
+            properties: Some(SarifProperties {
+                tags,
+                security_severity: Some(security_severity(vuln.severity)),
+            }),
+        });
+
+        let location = parse_location(&vuln.location);
+        let code_flows = match &vuln.taint_path {
+            Some(path) if self.config.include_code_flows && !path.is_empty() => {
+                vec![self.code_flow(path, &[], None)]
+            }
+            _ => Vec::new(),
+        };
+
+        self.push_result(SarifResult {
+            rule_id,
+            rule_index: Some(rule_index),
+            level,
+            message: SarifMessage::new(&vuln.message),
+            locations: location.into_iter().collect(),
+            code_flows,
+            partial_fingerprints: BTreeMap::new(),
+        });
+    }
+
+    /// Add a memory safety issue (separation logic / heap analysis)
+    pub fn add_memory_issue(&mut self, issue: &MemorySafetyIssue) {
+        let rule_id = format!("memory-safety/{:?}", issue.kind);
+        let level = SarifLevel::from_score(issue.severity);
+        let rule_index = self.ensure_rule(SarifRule {
+            id: rule_id.clone(),
+            name: Some(format!("{:?}", issue.kind)),
+            short_description: Some(SarifMessage::new(
+                self.report
+                    .format(MessageId::SarifMemoryRule, &[&format!("{:?}", issue.kind)]),
+            )),
+            help: None,
+            message_strings: BTreeMap::new(),
+            default_configuration: Some(SarifRuleConfiguration { level }),
+            properties: Some(SarifProperties {
+                tags: vec!["memory-safety".to_string()],
+                security_severity: Some(security_severity(issue.severity)),
+            }),
+        });
+
+        let mut location = parse_location(&issue.location).unwrap_or_default();
+        if !issue.variable.is_empty() {
+            location.logical_locations.push(SarifLogicalLocation {
+                name: Some(issue.variable.clone()),
+                fully_qualified_name: None,
+                kind: Some("variable".to_string()),
+            });
+        }
+
+        self.push_result(SarifResult {
+            rule_id,
+            rule_index: Some(rule_index),
+            level,
+            message: SarifMessage::new(&issue.message),
+            locations: vec![location],
+            code_flows: Vec::new(),
+            partial_fingerprints: BTreeMap::new(),
+        });
+    }
+
+    /// Add an architecture rule violation (import or call site)
+    pub fn add_architecture_violation(&mut self, violation: &ArchitectureViolation) {
+        let rule_id = format!("architecture/{}", violation.rule);
+        let level = match violation.severity {
+            RuleSeverity::Error => SarifLevel::Error,
+            RuleSeverity::Warning => SarifLevel::Warning,
+            RuleSeverity::Note => SarifLevel::Note,
+        };
+        let rule_index = self.ensure_rule(SarifRule {
+            id: rule_id.clone(),
+            name: Some(violation.rule.clone()),
+            short_description: Some(SarifMessage::new(format!(
+                "Architecture rule '{}' (dependencies of layer '{}')",
+                violation.rule, violation.from_layer
+            ))),
+            help: None,
+            message_strings: BTreeMap::new(),
+            default_configuration: Some(SarifRuleConfiguration { level }),
+            properties: Some(SarifProperties {
+                tags: vec!["architecture".to_string()],
+                security_severity: None,
+            }),
+        });
+
+        self.push_result(SarifResult {
+            rule_id,
+            rule_index: Some(rule_index),
+            level,
+            message: SarifMessage::new(&violation.message),
+            locations: vec![SarifLocation {
+                physical_location: Some(physical_location(
+                    &violation.from_file,
+                    Some(&violation.span),
+                )),
+                logical_locations: vec![SarifLogicalLocation {
+                    name: None,
+                    fully_qualified_name: Some(violation.from_symbol.clone()),
+                    kind: None,
+                }],
+                message: None,
+            }],
+            code_flows: Vec::new(),
+            partial_fingerprints: BTreeMap::new(),
+        });
+    }
+
+    /// Add a taint path (source → sink)
+    ///
+    /// Sanitized paths are skipped unless `include_sanitized` is set, in
+    /// which case they are reported as notes.
+    pub fn add_taint_path(&mut self, path: &TaintPathDTO) {
+        if path.is_sanitized && !self.config.include_sanitized {
+            return;
+        }
+
+        let rule_id = format!("taint/{}", path.vulnerability_type);
+        let rule_level = SarifLevel::from_label(&path.severity);
+        let rule_index = self.ensure_rule(SarifRule {
+            id: rule_id.clone(),
+            name: Some(path.vulnerability_type.clone()),
+            short_description: Some(SarifMessage::new(
+                self.report
+                    .format(MessageId::SarifTaintRule, &[&path.vulnerability_type]),
+            )),
+            help: Some(SarifMessage::new(
+                self.report.text(MessageId::SarifTaintHelp),
+            )),
+            message_strings: [
+                MessageId::SarifTaintFlow,
+                MessageId::SarifTaintFlowSanitized,
+            ]
+            .into_iter()
+            .map(|id| {
+                (
+                    id.as_str().to_string(),
+                    SarifMessage::template(&self.report, id),
+                )
+            })
+            .collect(),
+            default_configuration: Some(SarifRuleConfiguration { level: rule_level }),
+            properties: Some(SarifProperties {
+                tags: vec!["security".to_string(), "taint".to_string()],
+                security_severity: None,
+            }),
+        });
+
+        let mut arguments = vec![
+            path.source.clone(),
+            path.source_type.clone(),
+            path.sink.clone(),
+            path.sink_type.clone(),
+        ];
+        let message_id = if path.is_sanitized {
+            arguments.push(if path.sanitizers.is_empty() {
+                self.report
+                    .text(MessageId::SarifUnknownSanitizer)
+                    .to_string()
+            } else {
+                path.sanitizers.join(", ")
+            });
+            MessageId::SarifTaintFlowSanitized
+        } else {
+            MessageId::SarifTaintFlow
+        };
+        let message = SarifMessage::localized(&self.report, message_id, arguments);
+
+        // Primary location: the sink
+        let sink = path.path.last().unwrap_or(&path.sink);
+        let location = self.resolve_entry(sink);
+
+        let code_flows = if self.config.include_code_flows {
+            let steps: Vec<String> = if path.path.is_empty() {
+                vec![path.source.clone(), path.sink.clone()]
+            } else {
+                path.path.clone()
+            };
+            vec![self.code_flow(
+                &steps,
+                &path.sanitizers,
+                Some(SarifMessage::new(format!(
+                    "{} → {}",
+                    path.source, path.sink
+                ))),
+            )]
+        } else {
+            Vec::new()
+        };
+
+        self.push_result(SarifResult {
+            rule_id,
+            rule_index: Some(rule_index),
+            level: if path.is_sanitized {
+                SarifLevel::Note
+            } else {
+                rule_level
+            },
+            message,
+            locations: vec![location],
+            code_flows,
+            partial_fingerprints: BTreeMap::new(),
+        });
+    }
+
+    /// Finish the run
+    pub fn build(self) -> SarifLog {
+        SarifLog::new(SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver {
+                    name: self.config.tool_name,
+                    version: Some(env!("CARGO_PKG_VERSION").to_string()),
+                    information_uri: None,
+                    rules: self.rules,
+                },
+            },
+            results: self.results,
+        })
+    }
+
+    fn ensure_rule(&mut self, rule: SarifRule) -> usize {
+        if let Some(&index) = self.rule_index.get(&rule.id) {
+            return index;
+        }
+        let index = self.rules.len();
+        self.rule_index.insert(rule.id.clone(), index);
+        self.rules.push(rule);
+        index
+    }
+
+    fn push_result(&mut self, mut result: SarifResult) {
+        let file = result
+            .locations
+            .first()
+            .and_then(|l| l.physical_location.as_ref())
+            .map(|p| p.artifact_location.uri.as_str())
+            .unwrap_or("");
+        let mut hasher = blake3::Hasher::new();
+        // Catalog messages hash their ID and arguments (locale-independent)
+        let message: Vec<&str> = match &result.message.id {
+            Some(id) => std::iter::once(id.as_str())
+                .chain(result.message.arguments.iter().map(String::as_str))
+                .collect(),
+            None => vec![result.message.text.as_str()],
+        };
+        for part in [result.rule_id.as_str(), file].into_iter().chain(message) {
+            hasher.update(part.as_bytes());
+            hasher.update(&[0]);
+        }
+        result.partial_fingerprints.insert(
+            FINGERPRINT_KEY.to_string(),
+            hasher.finalize().to_hex()[..16].to_string(),
+        );
+        self.results.push(result);
+    }
+
+    fn code_flow(
+        &self,
+        steps: &[String],
+        sanitizers: &[String],
+        message: Option<SarifMessage>,
+    ) -> SarifCodeFlow {
+        let last = steps.len().saturating_sub(1);
+        let locations = steps
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let mut location = self.resolve_entry(entry);
+                location.message = Some(SarifMessage::new(entry));
+                let kind = if i == 0 {
+                    "source"
+                } else if i == last {
+                    "sink"
+                } else if sanitizers.contains(entry) {
+                    "sanitizer"
+                } else {
+                    "step"
+                };
+                SarifThreadFlowLocation {
+                    location,
+                    kinds: vec![kind.to_string()],
+                }
+            })
+            .collect();
+
+        SarifCodeFlow {
+            message,
+            thread_flows: vec![SarifThreadFlow { locations }],
+        }
+    }
+
+    /// Resolve a path entry: node ID → "file:line" → logical location
+    fn resolve_entry(&self, entry: &str) -> SarifLocation {
+        if let Some(node) = self.nodes.get(entry) {
+            return SarifLocation {
+                physical_location: Some(physical_location(&node.file_path, Some(&node.span))),
+                logical_locations: vec![SarifLogicalLocation {
+                    name: None,
+                    fully_qualified_name: non_empty(&node.fqn).map(str::to_string),
+                    kind: None,
+                }],
+                message: None,
+            };
+        }
+        if let Some(location) = parse_location(entry).filter(|l| has_region(l)) {
+            return location;
+        }
+        SarifLocation {
+            physical_location: None,
+            logical_locations: vec![SarifLogicalLocation {
+                name: Some(entry.to_string()),
+                fully_qualified_name: None,
+                kind: None,
+            }],
+            message: None,
+        }
+    }
+}
+
+fn non_empty(s: &str) -> Option<&str> {
+    if s.trim().is_empty() {
+        None
+    } else {
+        Some(s)
+    }
+}
+
+/// 1-10 score → SARIF `security-severity` (0.0-10.0)
+fn security_severity(score: u8) -> String {
+    format!("{:.1}", score.min(10) as f32)
+}
+
+fn has_region(location: &SarifLocation) -> bool {
+    location
+        .physical_location
+        .as_ref()
+        .is_some_and(|p| p.region.is_some())
+}
+
+fn physical_location(file_path: &str, span: Option<&Span>) -> SarifPhysicalLocation {
+    SarifPhysicalLocation {
+        artifact_location: SarifArtifactLocation {
+            uri: file_path.replace('\\', "/"),
+        },
+        // Span lines are 1-based, columns 0-based; SARIF uses 1-based for both
+        region: span.filter(|s| s.start_line > 0).map(|s| SarifRegion {
+            start_line: s.start_line,
+            start_column: Some(s.start_col + 1),
+            end_line: Some(s.end_line.max(s.start_line)),
+            end_column: Some(s.end_col + 1),
+        }),
+    }
+}
+
+/// Parse "file", "file:line" or "file:line:col"
+fn parse_location(location: &str) -> Option<SarifLocation> {
+    let location = location.trim();
+    if location.is_empty() {
+        return None;
+    }
+
+    let number = |s: &str| s.parse::<u32>().ok().filter(|n| *n > 0);
+    let (file, line, column) = match location.rsplit_once(':') {
+        Some((rest, last)) if number(last).is_some() => match rest.rsplit_once(':') {
+            Some((file, line)) if number(line).is_some() => (file, number(line), number(last)),
+            _ => (rest, number(last), None),
+        },
+        _ => (location, None, None),
+    };
+
+    let mut physical = physical_location(file, None);
+    physical.region = line.map(|start_line| SarifRegion {
+        start_line,
+        start_column: column,
+        end_line: None,
+        end_column: None,
+    });
+    Some(SarifLocation {
+        physical_location: Some(physical),
+        logical_locations: Vec::new(),
+        message: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::heap_analysis::{OWASPCategory, VulnerabilityType};
+    use crate::shared::i18n::{Locale, ReportEncoding};
+    use crate::shared::models::NodeKind;
+
+    fn taint_path(path: &[&str], sanitized: bool) -> TaintPathDTO {
+        TaintPathDTO {
+            source: "input".to_string(),
+            sink: "execute".to_string(),
+            source_type: "user_input".to_string(),
+            sink_type: "sql".to_string(),
+            path: path.iter().map(|s| s.to_string()).collect(),
+            path_length: path.len(),
+            is_sanitized: sanitized,
+            sanitizers: Vec::new(),
+            severity: "High".to_string(),
+            vulnerability_type: "sql_injection".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_vulnerability_and_memory_issue() {
+        let vuln = SecurityVulnerability {
+            category: OWASPCategory::A03_Injection,
+            cwe_id: Some(89),
+            severity: 9,
+            vuln_type: VulnerabilityType::SQLInjection,
+            location: "app/db.py:42".to_string(),
+            taint_path: None,
+            message: "SQL built from request data".to_string(),
+            recommendation: "Use parameterized queries".to_string(),
+        };
+        let mut builder = SarifBuilder::new(SarifConfig::enabled());
+        builder.add_vulnerability(&vuln);
+        builder.add_vulnerability(&vuln);
+        builder.add_memory_issue(&MemorySafetyIssue::null_dereference("ptr", "src/lib.c:7"));
+
+        let log = builder.build();
+        assert_eq!(log.result_count(), 3);
+        let run = &log.runs[0];
+        // Same vulnerability type → one rule
+        assert_eq!(run.tool.driver.rules.len(), 2);
+
+        let first = &run.results[0];
+        assert_eq!(first.rule_id, "SQLInjection");
+        assert_eq!(first.level, SarifLevel::Error);
+        let physical = first.locations[0].physical_location.as_ref().unwrap();
+        assert_eq!(physical.artifact_location.uri, "app/db.py");
+        assert_eq!(physical.region.as_ref().unwrap().start_line, 42);
+        assert_eq!(
+            first.partial_fingerprints[FINGERPRINT_KEY],
+            run.results[1].partial_fingerprints[FINGERPRINT_KEY]
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&log.to_json().unwrap()).unwrap();
+        assert_eq!(json["version"], "2.1.0");
+        assert_eq!(json["runs"][0]["results"][0]["ruleIndex"], 0);
+        assert_eq!(
+            json["runs"][0]["tool"]["driver"]["rules"][0]["properties"]["security-severity"],
+            "9.0"
+        );
+        assert_eq!(
+            json["runs"][0]["results"][2]["locations"][0]["logicalLocations"][0]["name"],
+            "ptr"
+        );
+    }
+
+    #[test]
+    fn test_taint_path_code_flow() {
+        let node = |id: &str, line: u32| {
+            Node::new(
+                id.to_string(),
+                NodeKind::Variable,
+                format!("app.{}", id),
+                "app/views.py".to_string(),
+                Span::new(line, 4, line, 20),
+            )
+        };
+        let nodes = vec![node("n1", 10), node("n3", 30)];
+
+        let mut builder = SarifBuilder::new(SarifConfig::enabled()).with_nodes(&nodes);
+        builder.add_taint_path(&taint_path(&["n1", "helper", "n3"], false));
+        builder.add_taint_path(&taint_path(&["n1", "n3"], true)); // skipped
+
+        let log = builder.build();
+        assert_eq!(log.result_count(), 1);
+        let result = &log.runs[0].results[0];
+        assert_eq!(result.rule_id, "taint/sql_injection");
+        assert_eq!(result.level, SarifLevel::Error);
+
+        // Primary location is the sink node
+        let sink = result.locations[0].physical_location.as_ref().unwrap();
+        assert_eq!(sink.region.as_ref().unwrap().start_line, 30);
+        assert_eq!(sink.region.as_ref().unwrap().start_column, Some(5));
+
+        let steps = &result.code_flows[0].thread_flows[0].locations;
+        assert_eq!(steps.len(), 3);
+        assert_eq!(steps[0].kinds, vec!["source"]);
+        assert_eq!(steps[2].kinds, vec!["sink"]);
+        // Unresolved entry → logical location
+        assert!(steps[1].location.physical_location.is_none());
+        assert_eq!(
+            steps[1].location.logical_locations[0].name.as_deref(),
+            Some("helper")
+        );
+
+        // Sanitized paths become notes when requested
+        let mut builder =
+            SarifBuilder::new(SarifConfig::enabled().include_sanitized(true)).with_nodes(&nodes);
+        builder.add_taint_path(&taint_path(&["n1", "n3"], true));
+        assert_eq!(builder.build().runs[0].results[0].level, SarifLevel::Note);
+    }
+
+    #[test]
+    fn test_architecture_violation() {
+        use crate::features::architecture::DependencyKind;
+
+        let violation = ArchitectureViolation {
+            rule: "ui-not-storage".to_string(),
+            severity: RuleSeverity::Warning,
+            kind: DependencyKind::Imports,
+            from_layer: "ui".to_string(),
+            to_layer: "storage".to_string(),
+            from_file: "src/ui/views.py".to_string(),
+            from_symbol: "ui.views".to_string(),
+            to_file: "src/storage/db.py".to_string(),
+            to_symbol: "storage.db.query".to_string(),
+            span: Span::new(3, 0, 3, 28),
+            message: "ui.views (ui) imports storage.db.query (storage)".to_string(),
+        };
+        let mut builder = SarifBuilder::new(SarifConfig::enabled());
+        builder.add_architecture_violation(&violation);
+
+        let log = builder.build();
+        let result = &log.runs[0].results[0];
+        assert_eq!(result.rule_id, "architecture/ui-not-storage");
+        assert_eq!(result.level, SarifLevel::Warning);
+        let location = result.locations[0].physical_location.as_ref().unwrap();
+        assert_eq!(location.artifact_location.uri, "src/ui/views.py");
+        assert_eq!(location.region.as_ref().unwrap().start_line, 3);
+        let rule = &log.runs[0].tool.driver.rules[0];
+        assert_eq!(rule.properties.as_ref().unwrap().tags, vec!["architecture"]);
+    }
+
+    #[test]
+    fn test_localized_messages_keep_ids_and_fingerprints() {
+        let build = |config: SarifConfig| {
+            let mut builder = SarifBuilder::new(config);
+            builder.add_taint_path(&taint_path(&["n1", "n3"], false));
+            builder.build()
+        };
+        let english = build(SarifConfig::enabled());
+        let korean = build(SarifConfig::enabled().locale(Locale::Ko));
+
+        let (en, ko) = (&english.runs[0].results[0], &korean.runs[0].results[0]);
+        assert_eq!(
+            en.message.text,
+            "Tainted data flows from `input` (user_input) to `execute` (sql)"
+        );
+        assert!(ko.message.text.contains("오염된 데이터가 `input`"));
+        assert_eq!(ko.message.id.as_deref(), Some("sarif.taint.flow"));
+        assert_eq!(ko.message.arguments, en.message.arguments);
+        assert_eq!(
+            en.partial_fingerprints[FINGERPRINT_KEY],
+            ko.partial_fingerprints[FINGERPRINT_KEY]
+        );
+
+        let rule = &korean.runs[0].tool.driver.rules[0];
+        assert!(rule.message_strings["sarif.taint.flow"]
+            .text
+            .contains("{0}"));
+
+        let ascii = korean.to_json_with(ReportEncoding::Ascii).unwrap();
+        assert!(ascii.is_ascii());
+        let decoded: SarifLog = serde_json::from_str(&ascii).unwrap();
+        assert_eq!(decoded, korean);
+    }
+}
@@ -0,0 +1,5 @@
+//! SARIF Application Layer
+
+mod converter;
+
+pub use converter::SarifBuilder;
@@ -0,0 +1,147 @@
+//! SARIF output configuration
+//!
+//! Selected from the pipeline config (`overrides.sarif` in YAML, or
+//! `PipelineConfig::sarif(..)`):
+//!
+//! ```yaml
+//! overrides:
+//!   sarif:
+//!     enabled: true
+//!     output_path: reports/codegraph.sarif
+//!     include_sanitized: false
+//!     locale: ko          # message language (en, ko)
+//!     encoding: utf8      # or ascii (non-ASCII as \u escapes)
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{ConfigError, ConfigResult};
+use crate::shared::i18n::{Locale, ReportEncoding, ReportOptions};
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_tool_name() -> String {
+    "codegraph".to_string()
+}
+
+/// SARIF output settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SarifConfig {
+    /// Emit SARIF for the run
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// File the pipeline writes the SARIF log to
+    ///
+    /// None = no file; callers build the log with `SarifBuilder`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_path: Option<String>,
+
+    /// `tool.driver.name`
+    #[serde(default = "default_tool_name")]
+    pub tool_name: String,
+
+    /// Include taint paths as code flows
+    #[serde(default = "default_true")]
+    pub include_code_flows: bool,
+
+    /// Report sanitized taint paths (as notes)
+    #[serde(default)]
+    pub include_sanitized: bool,
+
+    /// Language of rule descriptions and result messages
+    ///
+    /// Message IDs and arguments are locale-independent, so fingerprints
+    /// match across locales.
+    #[serde(default)]
+    pub locale: Locale,
+
+    /// Output encoding of the written log
+    #[serde(default)]
+    pub encoding: ReportEncoding,
+}
+
+impl Default for SarifConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            output_path: None,
+            tool_name: default_tool_name(),
+            include_code_flows: true,
+            include_sanitized: false,
+            locale: Locale::default(),
+            encoding: ReportEncoding::default(),
+        }
+    }
+}
+
+impl SarifConfig {
+    /// Enabled config with default settings
+    pub fn enabled() -> Self {
+        Self {
+            enabled: true,
+            ..Self::default()
+        }
+    }
+
+    /// Builder: Set output path
+    pub fn output_path(mut self, path: impl Into<String>) -> Self {
+        self.output_path = Some(path.into());
+        self
+    }
+
+    /// Builder: Set tool name
+    pub fn tool_name(mut self, name: impl Into<String>) -> Self {
+        self.tool_name = name.into();
+        self
+    }
+
+    /// Builder: Include taint paths as code flows
+    pub fn include_code_flows(mut self, include: bool) -> Self {
+        self.include_code_flows = include;
+        self
+    }
+
+    /// Builder: Report sanitized taint paths
+    pub fn include_sanitized(mut self, include: bool) -> Self {
+        self.include_sanitized = include;
+        self
+    }
+
+    /// Builder: Set message locale
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Builder: Set output encoding
+    pub fn encoding(mut self, encoding: ReportEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Locale and encoding as report options
+    pub fn report_options(&self) -> ReportOptions {
+        ReportOptions {
+            locale: self.locale,
+            encoding: self.encoding,
+        }
+    }
+
+    pub fn validate(&self) -> ConfigResult<()> {
+        if self.tool_name.trim().is_empty() {
+            return Err(ConfigError::Validation(
+                "sarif.tool_name must not be empty".to_string(),
+            ));
+        }
+        if matches!(&self.output_path, Some(path) if path.trim().is_empty()) {
+            return Err(ConfigError::Validation(
+                "sarif.output_path must not be empty (omit it to skip the file)".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,12 @@
+//! Domain models for SARIF output
+
+mod config;
+mod model;
+
+pub use config::SarifConfig;
+pub use model::{
+    SarifArtifactLocation, SarifCodeFlow, SarifDriver, SarifLevel, SarifLocation, SarifLog,
+    SarifLogicalLocation, SarifMessage, SarifPhysicalLocation, SarifProperties, SarifRegion,
+    SarifResult, SarifRule, SarifRuleConfiguration, SarifRun, SarifThreadFlow,
+    SarifThreadFlowLocation, SarifTool, SARIF_SCHEMA, SARIF_VERSION,
+};
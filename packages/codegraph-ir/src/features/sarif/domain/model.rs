@@ -0,0 +1,298 @@
+//! SARIF 2.1.0 object model
+//!
+//! Subset of the OASIS SARIF 2.1.0 schema needed to report analysis results:
+//! log → runs → (tool.driver.rules, results → locations / codeFlows).
+//! Field names follow the schema (camelCase); empty collections and unset
+//! optionals are omitted from the output.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::shared::i18n::{MessageId, ReportEncoding, ReportOptions};
+use crate::shared::models::{CodegraphError, Result};
+
+/// SARIF schema URI
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// SARIF version written by this module
+pub const SARIF_VERSION: &str = "2.1.0";
+
+/// Top-level SARIF log
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    pub schema: String,
+    pub version: String,
+    pub runs: Vec<SarifRun>,
+}
+
+impl SarifLog {
+    /// Log with a single run
+    pub fn new(run: SarifRun) -> Self {
+        Self {
+            schema: SARIF_SCHEMA.to_string(),
+            version: SARIF_VERSION.to_string(),
+            runs: vec![run],
+        }
+    }
+
+    /// Pretty-printed SARIF JSON
+    pub fn to_json(&self) -> Result<String> {
+        self.to_json_with(ReportEncoding::Utf8)
+    }
+
+    /// Pretty-printed SARIF JSON in the given encoding
+    pub fn to_json_with(&self, encoding: ReportEncoding) -> Result<String> {
+        serde_json::to_string_pretty(self)
+            .map(|json| encoding.encode_json(json))
+            .map_err(|e| CodegraphError::internal(format!("SARIF serialization failed: {}", e)))
+    }
+
+    /// Write the log to `path`
+    pub fn write_to(&self, path: impl AsRef<Path>) -> Result<()> {
+        self.write_to_with(path, ReportEncoding::Utf8)
+    }
+
+    /// Write the log to `path` in the given encoding
+    pub fn write_to_with(&self, path: impl AsRef<Path>, encoding: ReportEncoding) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                CodegraphError::io(format!("cannot create {}: {}", parent.display(), e))
+            })?;
+        }
+        std::fs::write(path, self.to_json_with(encoding)?)
+            .map_err(|e| CodegraphError::io(format!("cannot write {}: {}", path.display(), e)))
+    }
+
+    /// Total results across all runs
+    pub fn result_count(&self) -> usize {
+        self.runs.iter().map(|r| r.results.len()).sum()
+    }
+}
+
+/// One analysis run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SarifRun {
+    pub tool: SarifTool,
+    pub results: Vec<SarifResult>,
+}
+
+/// Analysis tool
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SarifTool {
+    pub driver: SarifDriver,
+}
+
+/// Tool component that produced the results
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifDriver {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub information_uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<SarifRule>,
+}
+
+/// Rule metadata (`tool.driver.rules[]`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRule {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short_description: Option<SarifMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help: Option<SarifMessage>,
+    /// Localized templates referenced by result `message.id`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub message_strings: BTreeMap<String, SarifMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_configuration: Option<SarifRuleConfiguration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<SarifProperties>,
+}
+
+/// Default rule configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SarifRuleConfiguration {
+    pub level: SarifLevel,
+}
+
+/// Property bag
+///
+/// `security-severity` (0.0-10.0 as a string) is what code scanning
+/// services use to rank security results.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SarifProperties {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(rename = "security-severity", skip_serializing_if = "Option::is_none")]
+    pub security_severity: Option<String>,
+}
+
+/// Result level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SarifLevel {
+    None,
+    Note,
+    Warning,
+    Error,
+}
+
+impl SarifLevel {
+    /// Map a 1-10 severity score (heap/security analyzers)
+    pub fn from_score(score: u8) -> Self {
+        match score {
+            7.. => Self::Error,
+            4..=6 => Self::Warning,
+            _ => Self::Note,
+        }
+    }
+
+    /// Map a severity label ("Critical", "High", "Medium", ...)
+    pub fn from_label(label: &str) -> Self {
+        match label.trim().to_ascii_lowercase().as_str() {
+            "critical" | "high" | "error" => Self::Error,
+            "medium" | "warning" => Self::Warning,
+            "none" => Self::None,
+            _ => Self::Note,
+        }
+    }
+}
+
+/// Message: plain text, optionally with a catalog ID and its arguments
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SarifMessage {
+    pub text: String,
+    /// `MessageId` (key into the rule's `messageStrings`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub arguments: Vec<String>,
+}
+
+impl SarifMessage {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            id: None,
+            arguments: Vec::new(),
+        }
+    }
+
+    /// Catalog message: localized text plus locale-independent ID/arguments
+    pub fn localized(report: &ReportOptions, id: MessageId, arguments: Vec<String>) -> Self {
+        let args: Vec<&str> = arguments.iter().map(String::as_str).collect();
+        Self {
+            text: report.format(id, &args),
+            id: Some(id.as_str().to_string()),
+            arguments,
+        }
+    }
+
+    /// Template of a catalog message (for `messageStrings`)
+    pub fn template(report: &ReportOptions, id: MessageId) -> Self {
+        Self::new(id.template(report.locale))
+    }
+}
+
+/// Single finding
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    pub rule_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_index: Option<usize>,
+    pub level: SarifLevel,
+    pub message: SarifMessage,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locations: Vec<SarifLocation>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub code_flows: Vec<SarifCodeFlow>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub partial_fingerprints: BTreeMap<String, String>,
+}
+
+/// Location of a result or flow step
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifLocation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub physical_location: Option<SarifPhysicalLocation>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub logical_locations: Vec<SarifLogicalLocation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<SarifMessage>,
+}
+
+/// File + region
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifPhysicalLocation {
+    pub artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<SarifRegion>,
+}
+
+/// Artifact (file) reference
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SarifArtifactLocation {
+    pub uri: String,
+}
+
+/// Source region (lines and columns are 1-based)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRegion {
+    pub start_line: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_column: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<u32>,
+}
+
+/// Named code element (function, variable) without a file position
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifLogicalLocation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fully_qualified_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+}
+
+/// Code flow (e.g. a taint path from source to sink)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifCodeFlow {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<SarifMessage>,
+    pub thread_flows: Vec<SarifThreadFlow>,
+}
+
+/// Ordered sequence of flow steps
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SarifThreadFlow {
+    pub locations: Vec<SarifThreadFlowLocation>,
+}
+
+/// Single flow step
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifThreadFlowLocation {
+    pub location: SarifLocation,
+    /// "source", "sink", "sanitizer" or "step"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub kinds: Vec<String>,
+}
@@ -0,0 +1,45 @@
+//! SARIF - Static Analysis Results Interchange Format export
+//!
+//! Converts security vulnerabilities, memory safety issues and taint paths
+//! into SARIF 2.1.0 JSON for code scanning dashboards and security tooling.
+//! Taint paths become `codeFlows` whose steps carry file and span.
+//!
+//! # Architecture
+//!
+//! ```text
+//! ┌─────────────────────────────────────────┐
+//! │ Domain Layer                            │
+//! │  - SarifLog / SarifResult (2.1.0 model) │
+//! │  - SarifConfig (pipeline selection)     │
+//! └─────────────────────────────────────────┘
+//!                   ▲
+//!                   │
+//! ┌─────────────────────────────────────────┐
+//! │ Application Layer                       │
+//! │  - SarifBuilder (findings → SARIF)      │
+//! └─────────────────────────────────────────┘
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use codegraph_ir::features::sarif::{SarifBuilder, SarifConfig};
+//!
+//! let mut builder = SarifBuilder::new(SarifConfig::enabled()).with_nodes(&nodes);
+//! for path in &taint_paths {
+//!     builder.add_taint_path(path);
+//! }
+//! for vuln in &vulnerabilities {
+//!     builder.add_vulnerability(vuln);
+//! }
+//! builder.build().write_to("codegraph.sarif")?;
+//! ```
+
+pub mod application;
+pub mod domain;
+
+// Re-export application layer
+pub use application::SarifBuilder;
+
+// Re-exports for convenience
+pub use domain::{SarifConfig, SarifLevel, SarifLog, SarifResult};
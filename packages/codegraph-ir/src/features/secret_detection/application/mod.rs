@@ -0,0 +1,5 @@
+//! Secret Detection Application Layer
+
+mod leak_analyzer;
+
+pub use leak_analyzer::{detect_secret_leaks, SecretLeakAnalyzer, SecretLeakConfig, SinkKind};
@@ -0,0 +1,5 @@
+//! Domain models for secret detection
+
+mod rules;
+
+pub use rules::{shannon_entropy, SecretKind, SecretMatch, SecretScanConfig, SecretScanner};
@@ -0,0 +1,42 @@
+//! Secret Detection - Hardcoded credentials and their leaks (CWE-798 / CWE-532)
+//!
+//! Finds secrets hardcoded in source (provider token formats, credential
+//! names with high-entropy values) and follows them through the function
+//! DFG to logging and network calls. Findings are `SecurityVulnerability`
+//! values, reported alongside the heap stage's security findings.
+//!
+//! # Architecture
+//!
+//! ```text
+//! ┌─────────────────────────────────────────┐
+//! │ Domain Layer                            │
+//! │  - SecretScanner (pattern + entropy)    │
+//! │  - SecretMatch, SecretKind              │
+//! └─────────────────────────────────────────┘
+//!                   ▲
+//!                   │
+//! ┌─────────────────────────────────────────┐
+//! │ Application Layer                       │
+//! │  - SecretLeakAnalyzer (DFG → sinks)     │
+//! └─────────────────────────────────────────┘
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use codegraph_ir::features::secret_detection::SecretLeakAnalyzer;
+//!
+//! let analyzer = SecretLeakAnalyzer::new();
+//! for vuln in analyzer.analyze("app.py", &content, &result.nodes, &result.edges) {
+//!     println!("CWE-{} {}: {}", vuln.cwe_id.unwrap_or(0), vuln.location, vuln.message);
+//! }
+//! ```
+
+pub mod application;
+pub mod domain;
+
+// Re-export application layer
+pub use application::{detect_secret_leaks, SecretLeakAnalyzer, SecretLeakConfig, SinkKind};
+
+// Re-exports for convenience
+pub use domain::{shannon_entropy, SecretKind, SecretMatch, SecretScanConfig, SecretScanner};
@@ -0,0 +1,186 @@
+//! Deprecation Tracker
+//!
+//! Combines the tagger's deprecation markers (`@deprecated`, `@Deprecated`,
+//! `#[deprecated]`, docstring notices, `warnings.warn(..., DeprecationWarning)`)
+//! with call / reference edges to list every call site that still uses a
+//! deprecated symbol.
+
+use std::collections::{BTreeSet, HashMap};
+
+use super::tagger::SemanticTagger;
+use crate::features::semantic_tags::domain::{
+    DeprecatedCallSite, DeprecatedSymbol, DeprecationReport, TagKind,
+};
+use crate::shared::models::{Edge, EdgeKind, Node};
+
+/// Builds deprecation usage reports
+pub struct DeprecationTracker {
+    tagger: SemanticTagger,
+}
+
+impl DeprecationTracker {
+    pub fn new(tagger: SemanticTagger) -> Self {
+        Self { tagger }
+    }
+
+    /// Deprecated symbols in `nodes` and their call sites in `edges`
+    pub fn track(&self, nodes: &[Node], edges: &[Edge]) -> DeprecationReport {
+        let by_id: HashMap<&str, &Node> = nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+
+        let mut deprecated: HashMap<&str, DeprecatedSymbol> = HashMap::new();
+        for node in nodes {
+            let tags: Vec<_> = self
+                .tagger
+                .tag_node(node)
+                .into_iter()
+                .filter(|t| t.kind == TagKind::Deprecated)
+                .collect();
+            if tags.is_empty() {
+                continue;
+            }
+            deprecated.insert(
+                node.id.as_str(),
+                DeprecatedSymbol {
+                    symbol_id: node.id.clone(),
+                    fqn: node.fqn.clone(),
+                    file_path: node.file_path.clone(),
+                    line: node.span.start_line,
+                    replacement: tags.iter().find_map(|t| t.detail.clone()),
+                    markers: tags.iter().map(|t| t.source).collect(),
+                    call_sites: Vec::new(),
+                },
+            );
+        }
+
+        // A call and a reference at the same position are one usage
+        let mut seen: BTreeSet<(&str, &str, u32)> = BTreeSet::new();
+        for edge in edges {
+            if !matches!(
+                edge.kind,
+                EdgeKind::Calls | EdgeKind::Invokes | EdgeKind::References
+            ) || edge.source_id == edge.target_id
+            {
+                continue;
+            }
+            let Some(symbol) = deprecated.get_mut(edge.target_id.as_str()) else {
+                continue;
+            };
+            let caller = by_id.get(edge.source_id.as_str());
+            let line = edge
+                .span
+                .as_ref()
+                .map(|s| s.start_line)
+                .or_else(|| caller.map(|c| c.span.start_line))
+                .unwrap_or(0);
+            if !seen.insert((edge.source_id.as_str(), edge.target_id.as_str(), line)) {
+                continue;
+            }
+            symbol.call_sites.push(DeprecatedCallSite {
+                caller_id: edge.source_id.clone(),
+                caller_fqn: caller
+                    .map(|c| c.fqn.clone())
+                    .unwrap_or_else(|| edge.source_id.clone()),
+                file_path: caller.map(|c| c.file_path.clone()).unwrap_or_default(),
+                line,
+            });
+        }
+
+        let mut symbols: Vec<DeprecatedSymbol> = deprecated.into_values().collect();
+        for symbol in &mut symbols {
+            symbol
+                .call_sites
+                .sort_by(|a, b| (&a.file_path, a.line).cmp(&(&b.file_path, b.line)));
+        }
+        symbols.sort_by(|a, b| {
+            b.call_sites
+                .len()
+                .cmp(&a.call_sites.len())
+                .then_with(|| a.fqn.cmp(&b.fqn))
+        });
+
+        DeprecationReport { symbols }
+    }
+}
+
+impl Default for DeprecationTracker {
+    fn default() -> Self {
+        Self::new(SemanticTagger::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::semantic_tags::domain::TagSource;
+    use crate::shared::models::{NodeKind, Span};
+
+    fn function(id: &str, file: &str, start_line: u32, end_line: u32) -> Node {
+        Node::new(
+            id.to_string(),
+            NodeKind::Function,
+            format!("app.{}", id),
+            file.to_string(),
+            Span::new(start_line, 0, end_line, 0),
+        )
+    }
+
+    #[test]
+    fn test_track_markers_and_call_sites() {
+        let source = "\
+import warnings
+
+def old_fetch():
+    warnings.warn(
+        \"old_fetch is going away, use fetch instead\",
+        DeprecationWarning,
+        stacklevel=2,
+    )
+    return fetch()
+
+def fetch():
+    pass
+";
+        let tagger = SemanticTagger::default().with_source("app.py", source);
+        let tracker = DeprecationTracker::new(tagger);
+
+        let nodes = vec![
+            function("old_fetch", "app.py", 3, 9),
+            function("fetch", "app.py", 11, 12),
+            function("Legacy", "Legacy.java", 1, 20).with_annotations(vec!["@Deprecated".into()]),
+            function("handler", "views.py", 1, 10),
+        ];
+        let call = |from: &str, to: &str, line: u32| {
+            Edge::new(from.into(), to.into(), EdgeKind::Calls)
+                .with_span(Span::new(line, 4, line, 20))
+        };
+        let edges = vec![
+            call("handler", "old_fetch", 3),
+            call("handler", "old_fetch", 7),
+            Edge::new("handler".into(), "old_fetch".into(), EdgeKind::References)
+                .with_span(Span::new(7, 4, 7, 20)),
+            call("old_fetch", "fetch", 9),
+        ];
+
+        let report = tracker.track(&nodes, &edges);
+        assert_eq!(report.symbols.len(), 2);
+        assert_eq!(report.total_call_sites(), 2);
+
+        let old = &report.symbols[0];
+        assert_eq!(old.fqn, "app.old_fetch");
+        assert_eq!(old.markers, vec![TagSource::Body]);
+        assert_eq!(old.replacement.as_deref(), Some("fetch"));
+        assert_eq!(
+            old.call_sites.iter().map(|c| c.line).collect::<Vec<_>>(),
+            vec![3, 7]
+        );
+        assert_eq!(old.call_sites[0].file_path, "views.py");
+
+        let legacy = &report.symbols[1];
+        assert_eq!(legacy.markers, vec![TagSource::Decorator]);
+        assert!(!legacy.is_in_use());
+        assert_eq!(
+            report.summary(),
+            "2 deprecated symbols, 1 still in use (2 call sites)"
+        );
+    }
+}
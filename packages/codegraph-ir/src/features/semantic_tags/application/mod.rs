@@ -0,0 +1,7 @@
+//! Semantic Tags Application Layer
+
+mod deprecation_tracker;
+mod tagger;
+
+pub use deprecation_tracker::DeprecationTracker;
+pub use tagger::{SemanticTagger, TaggerConfig};
@@ -0,0 +1,457 @@
+//! Semantic Tagger
+//!
+//! Heuristic pass over docstrings, leading comments and decorators:
+//! - deprecation notices (`@deprecated`, `.. deprecated::`, `#[deprecated]`,
+//!   "Deprecated: use X instead") with the suggested replacement, plus
+//!   runtime `warnings.warn(..., DeprecationWarning)` in callable bodies
+//! - ticket references (`PROJ-123`, `#42`, GitHub issue URLs)
+//! - vocabulary keywords (TODO, FIXME, experimental, thread-safe, ...)
+//!
+//! Leading comments and bodies are only available when the file content is
+//! registered with `with_source`; the IR keeps docstrings but not comments.
+
+use regex::Regex;
+use std::collections::HashMap;
+
+use crate::features::semantic_tags::domain::{SemanticTag, SymbolTagIndex, TagKind, TagSource};
+use crate::shared::models::{CodegraphError, Node, Result};
+
+/// Longest detail text kept on a tag
+const MAX_DETAIL_CHARS: usize = 120;
+
+/// Tagger settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaggerConfig {
+    /// Keywords matched as whole words (case-insensitive)
+    pub keywords: Vec<String>,
+    /// Ticket ID patterns; the whole match becomes the tag value
+    pub ticket_patterns: Vec<String>,
+    /// Uppercase prefixes that look like tickets but aren't (`UTF-8`, `SHA-256`)
+    pub ignored_ticket_prefixes: Vec<String>,
+    /// Scan comments directly above a symbol (needs `with_source`)
+    pub include_comments: bool,
+}
+
+impl Default for TaggerConfig {
+    fn default() -> Self {
+        Self {
+            keywords: [
+                "todo",
+                "fixme",
+                "hack",
+                "xxx",
+                "experimental",
+                "internal",
+                "unstable",
+                "thread-safe",
+                "not thread-safe",
+                "security",
+                "performance",
+                "legacy",
+                "workaround",
+            ]
+            .iter()
+            .map(|k| k.to_string())
+            .collect(),
+            ticket_patterns: vec![
+                r"https?://github\.com/[\w.-]+/[\w.-]+/(?:issues|pull)/\d+".to_string(),
+                r"\b[A-Z][A-Z0-9]+-\d+\b".to_string(),
+                r"(?:^|[\s(\[])#\d+\b".to_string(),
+            ],
+            ignored_ticket_prefixes: ["UTF", "SHA", "ISO", "MD", "BASE", "X"]
+                .iter()
+                .map(|p| p.to_string())
+                .collect(),
+            include_comments: true,
+        }
+    }
+}
+
+/// Extracts semantic tags from symbols
+pub struct SemanticTagger {
+    config: TaggerConfig,
+    keyword_re: Option<Regex>,
+    ticket_res: Vec<Regex>,
+    deprecated_re: Regex,
+    replacement_res: Vec<Regex>,
+    warning_re: Regex,
+    sources: HashMap<String, Vec<String>>,
+}
+
+impl SemanticTagger {
+    pub fn new(config: TaggerConfig) -> Result<Self> {
+        let compile = |pattern: &str| {
+            Regex::new(pattern).map_err(|e| {
+                CodegraphError::config(format!("invalid tag pattern '{}': {}", pattern, e))
+            })
+        };
+
+        let keyword_re = if config.keywords.is_empty() {
+            None
+        } else {
+            // Longest first so "not thread-safe" wins over "thread-safe"
+            let mut keywords: Vec<&String> = config.keywords.iter().collect();
+            keywords.sort_by_key(|k| std::cmp::Reverse(k.len()));
+            let alternation = keywords
+                .iter()
+                .map(|k| regex::escape(k.trim()))
+                .collect::<Vec<_>>()
+                .join("|");
+            Some(compile(&format!(
+                r"(?i)(?:^|[^\w-])({})(?:$|[^\w-])",
+                alternation
+            ))?)
+        };
+
+        let ticket_res = config
+            .ticket_patterns
+            .iter()
+            .map(|p| compile(p))
+            .collect::<Result<Vec<_>>>()?;
+
+        let deprecated_re = compile(
+            r"(?im)(?:^\s*(?:\.\.\s*|\*\s*)?@?deprecated\b|@deprecated\b|\bdeprecated\s*[:!]|\b(?:is|are|now|been)\s+deprecated\b)",
+        )?;
+
+        let replacement_res = [
+            r"(?i)\buse\s+[`'\x22]?([A-Za-z_][\w.:#]*)(?:\(\))?[`'\x22]?\s+instead\b",
+            r"(?i)\breplaced\s+by\s+[`'\x22]?([A-Za-z_][\w.:#]*)",
+            r"(?i)\bin\s+favou?r\s+of\s+[`'\x22]?([A-Za-z_][\w.:#]*)",
+            r"\{@link\s+([A-Za-z_][\w.:#]*)",
+        ]
+        .iter()
+        .map(|p| compile(p))
+        .collect::<Result<Vec<_>>>()?;
+
+        // Python `warnings.warn("...", DeprecationWarning)` / `warn(..., category=...)`
+        let warning_re = compile(r"(?s)\bwarn\s*\(.{0,400}?\b(?:Pending)?DeprecationWarning\b")?;
+
+        Ok(Self {
+            config,
+            keyword_re,
+            ticket_res,
+            deprecated_re,
+            replacement_res,
+            warning_re,
+            sources: HashMap::new(),
+        })
+    }
+
+    /// Register file content so leading comments can be scanned
+    pub fn with_source(mut self, file_path: impl Into<String>, content: &str) -> Self {
+        self.sources.insert(
+            file_path.into(),
+            content.lines().map(str::to_string).collect(),
+        );
+        self
+    }
+
+    /// Tags for one symbol (deduplicated, in discovery order)
+    pub fn tag_node(&self, node: &Node) -> Vec<SemanticTag> {
+        let mut tags = Vec::new();
+
+        if let Some(doc) = node.docstring.as_deref() {
+            self.tag_text(doc, TagSource::Docstring, &mut tags);
+        }
+
+        if self.config.include_comments {
+            if let Some(comment) = self.leading_comment(node) {
+                self.tag_text(&comment, TagSource::Comment, &mut tags);
+            }
+        }
+
+        let decorators = node
+            .decorators
+            .iter()
+            .chain(node.annotations.iter())
+            .flatten();
+        for decorator in decorators.chain(self.leading_attributes(node).iter()) {
+            if is_deprecation_decorator(decorator) {
+                let mut tag = SemanticTag::deprecated(TagSource::Decorator);
+                if let Some(replacement) = self.replacement(decorator) {
+                    tag = tag.with_detail(replacement);
+                }
+                push_unique(&mut tags, tag);
+            }
+        }
+
+        if node.kind.is_callable() {
+            if let Some(call) = self.deprecation_warning(node) {
+                let mut tag = SemanticTag::deprecated(TagSource::Body);
+                if let Some(replacement) = self.replacement(&call) {
+                    tag = tag.with_detail(replacement);
+                }
+                push_unique(&mut tags, tag);
+            }
+        }
+
+        tags
+    }
+
+    /// Tag every node and build the query index
+    pub fn tag_nodes(&self, nodes: &[Node]) -> SymbolTagIndex {
+        let mut index = SymbolTagIndex::new();
+        for node in nodes {
+            index.insert(node.id.clone(), node.fqn.clone(), self.tag_node(node));
+        }
+        index
+    }
+
+    fn tag_text(&self, text: &str, source: TagSource, tags: &mut Vec<SemanticTag>) {
+        if self.deprecated_re.is_match(text) {
+            let mut tag = SemanticTag::deprecated(source);
+            if let Some(replacement) = self.replacement(text) {
+                tag = tag.with_detail(replacement);
+            }
+            push_unique(tags, tag);
+        }
+
+        for line in text.lines() {
+            for re in &self.ticket_res {
+                for m in re.find_iter(line) {
+                    let value = m
+                        .as_str()
+                        .trim_start_matches(|c: char| c.is_whitespace() || c == '(' || c == '[');
+                    if self.is_ignored_ticket(value) {
+                        continue;
+                    }
+                    push_unique(
+                        tags,
+                        SemanticTag::new(TagKind::Ticket, value, source).with_detail(detail(line)),
+                    );
+                }
+            }
+
+            if let Some(re) = &self.keyword_re {
+                for caps in re.captures_iter(line) {
+                    let keyword = caps[1].to_ascii_lowercase();
+                    push_unique(
+                        tags,
+                        SemanticTag::new(TagKind::Keyword, keyword, source)
+                            .with_detail(detail(line)),
+                    );
+                }
+            }
+        }
+    }
+
+    fn replacement(&self, text: &str) -> Option<String> {
+        self.replacement_res
+            .iter()
+            .find_map(|re| re.captures(text))
+            .map(|caps| caps[1].trim_end_matches(['.', ':']).to_string())
+    }
+
+    fn is_ignored_ticket(&self, value: &str) -> bool {
+        let prefix = value.split('-').next().unwrap_or(value);
+        self.config
+            .ignored_ticket_prefixes
+            .iter()
+            .any(|p| p.eq_ignore_ascii_case(prefix))
+    }
+
+    /// Source lines directly above the symbol (attributes/decorators skipped)
+    fn preceding_lines(&self, node: &Node) -> impl Iterator<Item = &str> {
+        let lines = self
+            .sources
+            .get(&node.file_path)
+            .map(Vec::as_slice)
+            .unwrap_or(&[]);
+        // span.start_line is 1-based; lines[start - 1] is the symbol itself
+        let end = (node.span.start_line as usize)
+            .saturating_sub(1)
+            .min(lines.len());
+        lines[..end]
+            .iter()
+            .rev()
+            .map(|l| l.trim())
+            .take_while(|l| !l.is_empty() && (is_attribute(l) || comment_body(l).is_some()))
+    }
+
+    fn leading_comment(&self, node: &Node) -> Option<String> {
+        let mut body: Vec<&str> = self
+            .preceding_lines(node)
+            .filter(|l| !is_attribute(l))
+            .filter_map(comment_body)
+            .collect();
+        if body.is_empty() {
+            return None;
+        }
+        body.reverse();
+        Some(body.join("\n"))
+    }
+
+    /// Deprecation warning call inside the symbol's body
+    fn deprecation_warning(&self, node: &Node) -> Option<String> {
+        let lines = self.sources.get(&node.file_path)?;
+        let span = node.body_span.as_ref().unwrap_or(&node.span);
+        let start = (span.start_line as usize)
+            .saturating_sub(1)
+            .min(lines.len());
+        let end = (span.end_line as usize).clamp(start, lines.len());
+        let body = lines[start..end].join("\n");
+        self.warning_re.find(&body).map(|m| m.as_str().to_string())
+    }
+
+    /// Attribute / decorator lines from source (for languages whose IR drops them)
+    fn leading_attributes(&self, node: &Node) -> Vec<String> {
+        self.preceding_lines(node)
+            .filter(|l| is_attribute(l))
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+impl Default for SemanticTagger {
+    fn default() -> Self {
+        Self::new(TaggerConfig::default()).expect("default tag patterns compile")
+    }
+}
+
+fn push_unique(tags: &mut Vec<SemanticTag>, tag: SemanticTag) {
+    let duplicate = tags
+        .iter()
+        .any(|t| t.kind == tag.kind && t.value.eq_ignore_ascii_case(&tag.value));
+    if !duplicate {
+        tags.push(tag);
+    }
+}
+
+fn detail(line: &str) -> String {
+    line.trim().chars().take(MAX_DETAIL_CHARS).collect()
+}
+
+/// `@decorator` or `#[attribute]` line
+fn is_attribute(line: &str) -> bool {
+    line.starts_with('@') || line.starts_with("#[")
+}
+
+/// Text of a comment line, or None if the line is not a comment
+fn comment_body(line: &str) -> Option<&str> {
+    for marker in ["///", "//!", "//", "/**", "/*", "*/", "--", "#", "*"] {
+        if let Some(rest) = line.strip_prefix(marker) {
+            return Some(rest.trim_end_matches("*/").trim());
+        }
+    }
+    None
+}
+
+/// `@deprecated`, `@Deprecated`, `typing_extensions.deprecated("...")`,
+/// `#[deprecated(note = "...")]`
+fn is_deprecation_decorator(decorator: &str) -> bool {
+    let name = decorator
+        .trim()
+        .trim_start_matches("#[")
+        .trim_start_matches('@')
+        .split(['(', ']', ' '])
+        .next()
+        .unwrap_or("");
+    name.rsplit(['.', ':'])
+        .next()
+        .is_some_and(|last| last.eq_ignore_ascii_case("deprecated"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::models::{Edge, EdgeKind, NodeKind, Span};
+
+    fn function(id: &str, start_line: u32) -> Node {
+        Node::new(
+            id.to_string(),
+            NodeKind::Function,
+            format!("app.{}", id),
+            "app.py".to_string(),
+            Span::new(start_line, 0, start_line + 2, 0),
+        )
+    }
+
+    #[test]
+    fn test_docstring_and_decorator_tags() {
+        let tagger = SemanticTagger::default();
+
+        let old = function("old_api", 1).with_docstring(
+            "Fetch users.\n\n.. deprecated:: 2.0\n   Use `fetch_users_v2` instead. See PLAT-142.",
+        );
+        let tags = tagger.tag_node(&old);
+        let deprecated = tags.iter().find(|t| t.is_deprecated()).unwrap();
+        assert_eq!(deprecated.source, TagSource::Docstring);
+        assert_eq!(deprecated.detail.as_deref(), Some("fetch_users_v2"));
+        assert!(tags
+            .iter()
+            .any(|t| t.kind == TagKind::Ticket && t.value == "PLAT-142"));
+
+        let decorated = function("legacy", 10)
+            .with_decorators(vec![
+                "typing_extensions.deprecated(\"use new_thing instead\")".into(),
+            ])
+            .with_docstring("TODO: remove after #381. Encoded as UTF-8.");
+        let tags = tagger.tag_node(&decorated);
+        assert!(tags
+            .iter()
+            .any(|t| t.is_deprecated() && t.source == TagSource::Decorator));
+        assert!(tags
+            .iter()
+            .any(|t| t.kind == TagKind::Keyword && t.value == "todo"));
+        assert!(tags
+            .iter()
+            .any(|t| t.kind == TagKind::Ticket && t.value == "#381"));
+        assert!(!tags.iter().any(|t| t.value == "UTF-8"));
+
+        let plain = function("plain", 20).with_docstring("Return the deprecated flag value.");
+        assert!(tagger.tag_node(&plain).is_empty());
+    }
+
+    #[test]
+    fn test_leading_comments() {
+        let source = "\
+import os
+
+# HACK: works around JIRA-77
+# Deprecated: replaced by load_config_v2
+@cache
+def load_config():
+    pass
+";
+        let tagger = SemanticTagger::default().with_source("app.py", source);
+        let tags = tagger.tag_node(&function("load_config", 6));
+
+        let deprecated = tags.iter().find(|t| t.is_deprecated()).unwrap();
+        assert_eq!(deprecated.source, TagSource::Comment);
+        assert_eq!(deprecated.detail.as_deref(), Some("load_config_v2"));
+        assert!(tags
+            .iter()
+            .any(|t| t.kind == TagKind::Keyword && t.value == "hack"));
+        assert!(tags
+            .iter()
+            .any(|t| t.kind == TagKind::Ticket && t.value == "JIRA-77"));
+
+        // `import os` is code, not a comment
+        assert_eq!(tagger.tag_node(&function("first", 2)).len(), 0);
+    }
+
+    #[test]
+    fn test_deprecated_in_use() {
+        let tagger = SemanticTagger::default();
+        let nodes = vec![
+            function("old", 1).with_docstring("@deprecated use new instead"),
+            function("new", 10),
+            function("caller_a", 20),
+            function("unused_old", 30).with_docstring("This function is deprecated."),
+        ];
+        let index = tagger.tag_nodes(&nodes);
+        assert_eq!(index.deprecated(), vec!["old", "unused_old"]);
+
+        let edges = vec![
+            Edge::new("caller_a".into(), "old".into(), EdgeKind::Calls),
+            Edge::new("caller_a".into(), "old".into(), EdgeKind::References),
+            Edge::new("old".into(), "old".into(), EdgeKind::Calls),
+            Edge::new("caller_a".into(), "new".into(), EdgeKind::Calls),
+        ];
+        let usages = index.deprecated_in_use(&edges);
+        assert_eq!(usages.len(), 1);
+        assert_eq!(usages[0].fqn, "app.old");
+        assert_eq!(usages[0].replacement.as_deref(), Some("new"));
+        assert_eq!(usages[0].callers, vec!["caller_a".to_string()]);
+    }
+}
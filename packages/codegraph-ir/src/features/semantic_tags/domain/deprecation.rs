@@ -0,0 +1,83 @@
+//! Deprecation usage report
+//!
+//! Deprecated symbols of one analysis run together with the call sites that
+//! still use them. Trends across snapshots are computed by the storage layer
+//! (`storage::api::DeprecationTrend`) from the `deprecated` chunk attribute.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::tag::TagSource;
+use crate::shared::models::{CodegraphError, Result};
+
+/// Call site of a deprecated symbol
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeprecatedCallSite {
+    pub caller_id: String,
+    pub caller_fqn: String,
+    pub file_path: String,
+    /// 1-based line of the call (edge span, else the caller's start line)
+    pub line: u32,
+}
+
+/// Deprecated symbol and its remaining call sites
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeprecatedSymbol {
+    pub symbol_id: String,
+    pub fqn: String,
+    pub file_path: String,
+    pub line: u32,
+    /// Suggested replacement, if any marker names one
+    pub replacement: Option<String>,
+    /// Where the deprecation was declared (docstring, decorator, body, ...)
+    pub markers: Vec<TagSource>,
+    pub call_sites: Vec<DeprecatedCallSite>,
+}
+
+impl DeprecatedSymbol {
+    pub fn is_in_use(&self) -> bool {
+        !self.call_sites.is_empty()
+    }
+}
+
+/// Deprecated symbols of one run, most used first
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeprecationReport {
+    pub symbols: Vec<DeprecatedSymbol>,
+}
+
+impl DeprecationReport {
+    /// Total call sites across all deprecated symbols
+    pub fn total_call_sites(&self) -> usize {
+        self.symbols.iter().map(|s| s.call_sites.len()).sum()
+    }
+
+    /// Deprecated symbols that still have call sites
+    pub fn in_use(&self) -> impl Iterator<Item = &DeprecatedSymbol> {
+        self.symbols.iter().filter(|s| s.is_in_use())
+    }
+
+    /// FQN → replacement, for marking stored chunks
+    pub fn deprecated_fqns(&self) -> BTreeMap<String, Option<String>> {
+        self.symbols
+            .iter()
+            .map(|s| (s.fqn.clone(), s.replacement.clone()))
+            .collect()
+    }
+
+    /// One-line summary (for CI logs)
+    pub fn summary(&self) -> String {
+        format!(
+            "{} deprecated symbols, {} still in use ({} call sites)",
+            self.symbols.len(),
+            self.in_use().count(),
+            self.total_call_sites()
+        )
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).map_err(|e| {
+            CodegraphError::internal(format!("deprecation report serialization failed: {}", e))
+        })
+    }
+}
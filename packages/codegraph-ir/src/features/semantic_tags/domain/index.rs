@@ -0,0 +1,146 @@
+//! Symbol → tag index
+//!
+//! Query side of the tagging pass. Combined with graph edges it answers
+//! questions such as "which deprecated symbols are still being called".
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::tag::{SemanticTag, TagKind};
+use crate::shared::models::{Edge, EdgeKind};
+
+/// Deprecated symbol with its remaining callers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeprecatedUsage {
+    pub symbol_id: String,
+    pub fqn: String,
+    /// Suggested replacement, if the notice names one
+    pub replacement: Option<String>,
+    /// Source node IDs of call / reference edges (sorted, deduplicated)
+    pub callers: Vec<String>,
+}
+
+/// Tags per symbol
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SymbolTagIndex {
+    tags: BTreeMap<String, Vec<SemanticTag>>,
+    fqns: BTreeMap<String, String>,
+}
+
+impl SymbolTagIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attach tags to a symbol (no-op for an empty list)
+    pub fn insert(
+        &mut self,
+        symbol_id: impl Into<String>,
+        fqn: impl Into<String>,
+        tags: Vec<SemanticTag>,
+    ) {
+        if tags.is_empty() {
+            return;
+        }
+        let symbol_id = symbol_id.into();
+        self.fqns.insert(symbol_id.clone(), fqn.into());
+        let entry = self.tags.entry(symbol_id).or_default();
+        for tag in tags {
+            if !entry.contains(&tag) {
+                entry.push(tag);
+            }
+        }
+    }
+
+    /// Tags of one symbol
+    pub fn tags_of(&self, symbol_id: &str) -> &[SemanticTag] {
+        self.tags.get(symbol_id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// FQN recorded for a tagged symbol
+    pub fn fqn_of(&self, symbol_id: &str) -> Option<&str> {
+        self.fqns.get(symbol_id).map(String::as_str)
+    }
+
+    /// Number of tagged symbols
+    pub fn len(&self) -> usize {
+        self.tags.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    /// Symbols carrying at least one tag of `kind`
+    pub fn with_kind(&self, kind: TagKind) -> Vec<&str> {
+        self.matching(|t| t.kind == kind)
+    }
+
+    /// Symbols referencing a ticket (exact, case-insensitive)
+    pub fn by_ticket(&self, ticket: &str) -> Vec<&str> {
+        self.matching(|t| t.kind == TagKind::Ticket && t.value.eq_ignore_ascii_case(ticket))
+    }
+
+    /// Symbols tagged with a keyword
+    pub fn with_keyword(&self, keyword: &str) -> Vec<&str> {
+        self.matching(|t| t.kind == TagKind::Keyword && t.value.eq_ignore_ascii_case(keyword))
+    }
+
+    /// Deprecated symbols
+    pub fn deprecated(&self) -> Vec<&str> {
+        self.with_kind(TagKind::Deprecated)
+    }
+
+    /// Deprecated symbols that are still targeted by call or reference edges
+    pub fn deprecated_in_use(&self, edges: &[Edge]) -> Vec<DeprecatedUsage> {
+        let deprecated: BTreeSet<&str> = self.deprecated().into_iter().collect();
+        let mut callers: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+
+        for edge in edges {
+            if !matches!(
+                edge.kind,
+                EdgeKind::Calls | EdgeKind::Invokes | EdgeKind::References
+            ) {
+                continue;
+            }
+            if let Some(target) = deprecated.get(edge.target_id.as_str()) {
+                // Self-references (recursion inside the deprecated symbol) don't count
+                if edge.source_id != edge.target_id {
+                    callers
+                        .entry(*target)
+                        .or_default()
+                        .insert(edge.source_id.as_str());
+                }
+            }
+        }
+
+        callers
+            .into_iter()
+            .map(|(symbol_id, sources)| DeprecatedUsage {
+                symbol_id: symbol_id.to_string(),
+                fqn: self.fqn_of(symbol_id).unwrap_or(symbol_id).to_string(),
+                replacement: self
+                    .tags_of(symbol_id)
+                    .iter()
+                    .filter(|t| t.is_deprecated())
+                    .find_map(|t| t.detail.clone()),
+                callers: sources.into_iter().map(str::to_string).collect(),
+            })
+            .collect()
+    }
+
+    /// Iterate `(symbol_id, tags)`
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[SemanticTag])> {
+        self.tags
+            .iter()
+            .map(|(id, tags)| (id.as_str(), tags.as_slice()))
+    }
+
+    fn matching(&self, pred: impl Fn(&SemanticTag) -> bool) -> Vec<&str> {
+        self.tags
+            .iter()
+            .filter(|(_, tags)| tags.iter().any(&pred))
+            .map(|(id, _)| id.as_str())
+            .collect()
+    }
+}
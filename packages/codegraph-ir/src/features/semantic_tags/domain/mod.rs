@@ -0,0 +1,9 @@
+//! Domain models for semantic tags
+
+mod deprecation;
+mod index;
+mod tag;
+
+pub use deprecation::{DeprecatedCallSite, DeprecatedSymbol, DeprecationReport};
+pub use index::{DeprecatedUsage, SymbolTagIndex};
+pub use tag::{SemanticTag, TagKind, TagSource};
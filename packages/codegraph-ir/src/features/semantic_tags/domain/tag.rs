@@ -0,0 +1,68 @@
+//! Semantic tag model
+//!
+//! Tags are heuristic intent markers extracted from a symbol's docstring,
+//! leading comments, decorators and (for deprecations) runtime warnings in
+//! the body. They are not resolved against any tracker; a ticket tag only
+//! records the identifier as written.
+
+use serde::{Deserialize, Serialize};
+
+/// What a tag says about the symbol
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TagKind {
+    /// Deprecation notice (value: "deprecated", detail: replacement)
+    Deprecated,
+    /// Referenced ticket / issue ID (value: "PROJ-123", "#42", issue URL)
+    Ticket,
+    /// Vocabulary keyword (value: normalized keyword, e.g. "todo")
+    Keyword,
+}
+
+/// Where the tag was found
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TagSource {
+    Docstring,
+    Comment,
+    Decorator,
+    /// Runtime warning raised in the body (`DeprecationWarning`)
+    Body,
+}
+
+/// Single tag attached to a symbol
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SemanticTag {
+    pub kind: TagKind,
+    pub value: String,
+    pub source: TagSource,
+    /// Extra context (deprecation replacement, ticket line)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl SemanticTag {
+    pub fn new(kind: TagKind, value: impl Into<String>, source: TagSource) -> Self {
+        Self {
+            kind,
+            value: value.into(),
+            source,
+            detail: None,
+        }
+    }
+
+    /// Deprecation tag
+    pub fn deprecated(source: TagSource) -> Self {
+        Self::new(TagKind::Deprecated, "deprecated", source)
+    }
+
+    /// Builder: Set detail
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    pub fn is_deprecated(&self) -> bool {
+        self.kind == TagKind::Deprecated
+    }
+}
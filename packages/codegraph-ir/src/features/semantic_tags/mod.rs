@@ -0,0 +1,55 @@
+//! Semantic Tags - Docstring/comment intent tagging
+//!
+//! Lightweight heuristic pass that attaches intent tags to symbols:
+//! deprecation notices, referenced ticket IDs and vocabulary keywords
+//! (TODO, experimental, thread-safe, ...). The resulting index is queryable
+//! on its own or together with graph edges, e.g. to list deprecated symbols
+//! that still have callers.
+//!
+//! # Architecture
+//!
+//! ```text
+//! ┌─────────────────────────────────────────┐
+//! │ Domain Layer                            │
+//! │  - SemanticTag (kind, value, source)    │
+//! │  - SymbolTagIndex (queries)             │
+//! │  - DeprecatedUsage (symbol + callers)   │
+//! │  - DeprecationReport (call sites)       │
+//! └─────────────────────────────────────────┘
+//!                   ▲
+//!                   │
+//! ┌─────────────────────────────────────────┐
+//! │ Application Layer                       │
+//! │  - SemanticTagger (extraction pass)     │
+//! │  - DeprecationTracker (usage report)    │
+//! └─────────────────────────────────────────┘
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use codegraph_ir::features::semantic_tags::{DeprecationTracker, SemanticTagger};
+//!
+//! let tagger = SemanticTagger::default().with_source("app.py", &content);
+//! let index = tagger.tag_nodes(&result.nodes);
+//!
+//! for usage in index.deprecated_in_use(&result.edges) {
+//!     println!("{} still called by {} symbols", usage.fqn, usage.callers.len());
+//! }
+//! let tracked = index.by_ticket("PLAT-142");
+//!
+//! let report = DeprecationTracker::new(tagger).track(&result.nodes, &result.edges);
+//! println!("{}", report.summary());
+//! ```
+
+pub mod application;
+pub mod domain;
+
+// Re-export application layer
+pub use application::{DeprecationTracker, SemanticTagger, TaggerConfig};
+
+// Re-exports for convenience
+pub use domain::{
+    DeprecatedCallSite, DeprecatedSymbol, DeprecatedUsage, DeprecationReport, SemanticTag,
+    SymbolTagIndex, TagKind, TagSource,
+};
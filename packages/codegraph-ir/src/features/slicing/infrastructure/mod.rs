@@ -1,5 +1,7 @@
 //! Slicing infrastructure
 
+pub mod sdg;
 pub mod slicer;
 
+pub use sdg::*;
 pub use slicer::*;
@@ -0,0 +1,673 @@
+/*
+ * System Dependence Graph Module
+ *
+ * Interprocedural slicing over function PDGs stitched at call sites
+ * (Horwitz, Reps & Binkley, "Interprocedural Slicing Using Dependence
+ * Graphs", TOPLAS 1990).
+ *
+ * Per function: an entry node (control parent of its statements), one
+ * formal-in node per parameter and a formal-out node for the return value.
+ * Per call site: actual-in nodes (one per callee parameter) fed by the
+ * definitions the arguments read, and an actual-out node feeding the
+ * calling statement. Summary edges actual-in → actual-out record that a
+ * callee's result depends on that parameter, so slices stay
+ * context-sensitive: the two-phase traversal never returns into a caller
+ * other than the one it came from.
+ */
+
+use crate::features::pdg::infrastructure::pdg::{DependencyType, ProgramDependenceGraph};
+use crate::shared::models::Span;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+/// SDG edge kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SdgEdgeKind {
+    /// Intraprocedural control dependency (and entry → statement)
+    Control,
+    /// Intraprocedural data dependency
+    Data,
+    /// Call site → callee entry
+    Call,
+    /// Actual-in → formal-in
+    ParamIn,
+    /// Formal-out → actual-out
+    ParamOut,
+    /// Actual-in → actual-out of the same call site
+    Summary,
+}
+
+impl SdgEdgeKind {
+    fn is_control(&self) -> bool {
+        matches!(self, SdgEdgeKind::Control | SdgEdgeKind::Call)
+    }
+}
+
+/// Argument of a call, as far as the call text tells
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CallArgument {
+    /// Parameter name of a keyword argument
+    pub keyword: Option<String>,
+    /// Variables the argument expression reads
+    pub reads: Vec<String>,
+}
+
+/// A call from a statement of a function PDG
+#[derive(Debug, Clone)]
+pub struct CallSite {
+    /// Calling statement (node ID in `FunctionPdg::pdg`)
+    pub statement: String,
+    /// Call target: function ID, FQN or name
+    pub callee: String,
+    /// Arguments bound to the callee's parameters in order (a receiver
+    /// first for `self`/`cls` methods); `None` when unknown, every
+    /// parameter then reads every variable the statement reads
+    pub arguments: Option<Vec<CallArgument>>,
+}
+
+/// Statement-level PDG of one function with its SDG interface
+#[derive(Debug)]
+pub struct FunctionPdg {
+    pub function_id: String,
+    pub fqn: String,
+    pub name: String,
+    pub file_path: String,
+    pub parameters: Vec<String>,
+    pub pdg: ProgramDependenceGraph,
+    /// `(statement, parameter)`: the statement reads the parameter's
+    /// incoming value
+    pub parameter_uses: Vec<(String, String)>,
+    /// Statements returning a value
+    pub returns: Vec<String>,
+    pub call_sites: Vec<CallSite>,
+}
+
+/// Statement in an interprocedural slice
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SdgStatement {
+    pub function_id: String,
+    pub file_path: String,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+struct SdgNode {
+    function: usize,
+    /// Statement span (None for entry, formal and actual nodes)
+    span: Option<Span>,
+}
+
+/// Statement PDGs of a program, connected through their call sites
+#[derive(Debug, Default)]
+pub struct SystemDependenceGraph {
+    functions: Vec<(String, String)>, // (function_id, file_path)
+    nodes: Vec<SdgNode>,
+    index: HashMap<String, usize>,
+    edges: Vec<(usize, usize, SdgEdgeKind)>,
+    incoming: Vec<Vec<usize>>,
+    outgoing: Vec<Vec<usize>>,
+    /// Callee function of each call edge's caller function (call graph)
+    calls: Vec<HashSet<usize>>,
+}
+
+impl SystemDependenceGraph {
+    /// Stitch `functions` at their call sites and add summary edges
+    ///
+    /// Calls to targets that are none of `functions` keep only the
+    /// intraprocedural dependencies of the calling statement.
+    pub fn build(functions: Vec<FunctionPdg>) -> Self {
+        let mut sdg = Self::default();
+        let mut formal_ins: Vec<Vec<usize>> = Vec::new();
+        let mut formal_outs: Vec<usize> = Vec::new();
+        let mut entries: Vec<usize> = Vec::new();
+
+        // Arguments of resolved calls reach the calling statement through
+        // the callee (summary edges) instead of a direct data edge
+        let resolve = callee_resolver(&functions);
+        let routed: HashSet<(&str, &str)> = functions
+            .iter()
+            .flat_map(|function| &function.call_sites)
+            .filter_map(|site| {
+                let g = resolve(&site.callee)?;
+                let arguments = site.arguments.as_ref()?;
+                let parameters = &functions[g].parameters;
+                let reads: Vec<(&str, &str)> = (0..parameters.len())
+                    .flat_map(|j| bound_reads(arguments, j, &parameters[j]))
+                    .map(|read| (site.statement.as_str(), read.as_str()))
+                    .collect();
+                Some(reads)
+            })
+            .flatten()
+            .collect();
+
+        for (f, function) in functions.iter().enumerate() {
+            sdg.functions
+                .push((function.function_id.clone(), function.file_path.clone()));
+            sdg.calls.push(HashSet::new());
+            let fid = &function.function_id;
+            let entry = sdg.add_node(format!("{}::entry", fid), f, None);
+            entries.push(entry);
+
+            let mut statements = function.pdg.node_ids();
+            statements.sort();
+            for id in &statements {
+                let span = function.pdg.get_node(id).map(|n| n.span);
+                let node = sdg.add_node(id.clone(), f, span);
+                sdg.add_edge(entry, node, SdgEdgeKind::Control);
+            }
+            for id in &statements {
+                for dep in function.pdg.get_dependencies(id) {
+                    let kind = match dep.dependency_type {
+                        DependencyType::Control => SdgEdgeKind::Control,
+                        DependencyType::Data => SdgEdgeKind::Data,
+                    };
+                    let variable = dep.label.as_deref().unwrap_or_default();
+                    if kind == SdgEdgeKind::Data && routed.contains(&(id.as_str(), variable)) {
+                        continue;
+                    }
+                    sdg.add_edge_by_id(&dep.from_node, &dep.to_node, kind);
+                }
+            }
+
+            let ins: Vec<usize> = function
+                .parameters
+                .iter()
+                .map(|p| sdg.add_node(format!("{}::in:{}", fid, p), f, None))
+                .collect();
+            for (statement, parameter) in &function.parameter_uses {
+                if let (Some(j), Some(&to)) = (
+                    function.parameters.iter().position(|p| p == parameter),
+                    sdg.index.get(statement),
+                ) {
+                    sdg.add_edge(ins[j], to, SdgEdgeKind::Data);
+                }
+            }
+            formal_ins.push(ins);
+
+            let out = sdg.add_node(format!("{}::out", fid), f, None);
+            for statement in &function.returns {
+                if let Some(&from) = sdg.index.get(statement) {
+                    sdg.add_edge(from, out, SdgEdgeKind::Data);
+                }
+            }
+            formal_outs.push(out);
+        }
+
+        // Call sites: (function, actual-in per callee parameter, actual-out, callee)
+        let mut sites: Vec<(usize, Vec<usize>, usize, usize)> = Vec::new();
+        for (f, function) in functions.iter().enumerate() {
+            for (n, site) in function.call_sites.iter().enumerate() {
+                let (Some(g), Some(&statement)) =
+                    (resolve(&site.callee), sdg.index.get(&site.statement))
+                else {
+                    continue;
+                };
+                sdg.calls[f].insert(g);
+                let prefix = format!("{}::call{}", site.statement, n);
+                sdg.add_edge(statement, entries[g], SdgEdgeKind::Call);
+
+                let actual_out = sdg.add_node(format!("{}::out", prefix), f, None);
+                sdg.add_edge(formal_outs[g], actual_out, SdgEdgeKind::ParamOut);
+                sdg.add_edge(actual_out, statement, SdgEdgeKind::Data);
+
+                // Definitions reaching the statement, by variable
+                let reaching: Vec<(usize, String)> = function
+                    .pdg
+                    .get_dependencies(&site.statement)
+                    .into_iter()
+                    .filter(|dep| dep.dependency_type == DependencyType::Data)
+                    .filter_map(|dep| {
+                        let from = *sdg.index.get(&dep.from_node)?;
+                        Some((from, dep.label.clone().unwrap_or_default()))
+                    })
+                    .collect();
+                let parameters = &functions[g].parameters;
+                let mut actual_ins = Vec::with_capacity(parameters.len());
+                for (j, parameter) in parameters.iter().enumerate() {
+                    let actual_in = sdg.add_node(format!("{}::in{}", prefix, j), f, None);
+                    sdg.add_edge(actual_in, formal_ins[g][j], SdgEdgeKind::ParamIn);
+                    let reads = site
+                        .arguments
+                        .as_ref()
+                        .map(|args| bound_reads(args, j, parameter));
+                    for (from, variable) in &reaching {
+                        let bound = match reads {
+                            Some(reads) => reads.contains(variable),
+                            None => true,
+                        };
+                        if bound {
+                            sdg.add_edge(*from, actual_in, SdgEdgeKind::Data);
+                        }
+                    }
+                    actual_ins.push(actual_in);
+                }
+                sites.push((f, actual_ins, actual_out, g));
+            }
+        }
+
+        sdg.add_summary_edges(&formal_ins, &formal_outs, &sites);
+        sdg
+    }
+
+    /// Summary edges to a fixpoint (recursive calls add them in rounds)
+    fn add_summary_edges(
+        &mut self,
+        formal_ins: &[Vec<usize>],
+        formal_outs: &[usize],
+        sites: &[(usize, Vec<usize>, usize, usize)],
+    ) {
+        let mut summarized: HashSet<(usize, usize)> = HashSet::new();
+        loop {
+            // Parameters of each function its result depends on
+            let depends: Vec<HashSet<usize>> = formal_outs
+                .iter()
+                .map(|&out| {
+                    let reached = self.traverse(
+                        [out],
+                        true,
+                        &|kind| {
+                            matches!(
+                                kind,
+                                SdgEdgeKind::Control | SdgEdgeKind::Data | SdgEdgeKind::Summary
+                            )
+                        },
+                        &|_| true,
+                    );
+                    reached.into_iter().collect()
+                })
+                .collect();
+
+            let mut changed = false;
+            for (_, actual_ins, actual_out, g) in sites {
+                for (j, &actual_in) in actual_ins.iter().enumerate() {
+                    if depends[*g].contains(&formal_ins[*g][j])
+                        && summarized.insert((actual_in, *actual_out))
+                    {
+                        self.add_edge(actual_in, *actual_out, SdgEdgeKind::Summary);
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                return;
+            }
+        }
+    }
+
+    pub fn contains(&self, node_id: &str) -> bool {
+        self.index.contains_key(node_id)
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Statements `criterion` depends on, through callers and callees
+    ///
+    /// Phase 1 ascends to callers (skipping callees via summary edges),
+    /// phase 2 descends into callees without returning to callers.
+    /// `max_function_depth` bounds the call-graph distance from the
+    /// criterion's function.
+    pub fn backward_slice(
+        &self,
+        criterion: &str,
+        include_control: bool,
+        include_data: bool,
+        max_function_depth: usize,
+    ) -> Vec<SdgStatement> {
+        self.two_phase(
+            criterion,
+            false,
+            include_control,
+            include_data,
+            max_function_depth,
+        )
+    }
+
+    /// Statements depending on `criterion`, through callers and callees
+    pub fn forward_slice(
+        &self,
+        criterion: &str,
+        include_control: bool,
+        include_data: bool,
+        max_function_depth: usize,
+    ) -> Vec<SdgStatement> {
+        self.two_phase(
+            criterion,
+            true,
+            include_control,
+            include_data,
+            max_function_depth,
+        )
+    }
+
+    fn two_phase(
+        &self,
+        criterion: &str,
+        forward: bool,
+        include_control: bool,
+        include_data: bool,
+        max_function_depth: usize,
+    ) -> Vec<SdgStatement> {
+        let Some(&start) = self.index.get(criterion) else {
+            return Vec::new();
+        };
+        let in_scope = self.functions_within(self.nodes[start].function, max_function_depth);
+        let wanted = |kind: SdgEdgeKind| {
+            if kind.is_control() {
+                include_control
+            } else {
+                include_data
+            }
+        };
+        let scope = |node: usize| in_scope.contains(&self.nodes[node].function);
+        // Edges into callees: param-out going backward, param-in/call going forward
+        let into_callee = |kind: SdgEdgeKind| {
+            if forward {
+                matches!(kind, SdgEdgeKind::ParamIn | SdgEdgeKind::Call)
+            } else {
+                kind == SdgEdgeKind::ParamOut
+            }
+        };
+        let into_caller = |kind: SdgEdgeKind| {
+            if forward {
+                kind == SdgEdgeKind::ParamOut
+            } else {
+                matches!(kind, SdgEdgeKind::ParamIn | SdgEdgeKind::Call)
+            }
+        };
+
+        let backward = !forward;
+        let phase1 = self.traverse(
+            [start],
+            backward,
+            &|kind| wanted(kind) && !into_callee(kind),
+            &scope,
+        );
+        let phase2 = self.traverse(
+            phase1,
+            backward,
+            &|kind| wanted(kind) && !into_caller(kind),
+            &scope,
+        );
+
+        let statements: BTreeSet<(&str, (u32, u32, u32, u32), usize)> = phase2
+            .into_iter()
+            .filter_map(|node| {
+                let span = self.nodes[node].span?;
+                let function = self.nodes[node].function;
+                let key = (span.start_line, span.start_col, span.end_line, span.end_col);
+                Some((self.functions[function].1.as_str(), key, function))
+            })
+            .collect();
+        statements
+            .into_iter()
+            .map(|(file_path, (sl, sc, el, ec), function)| SdgStatement {
+                function_id: self.functions[function].0.clone(),
+                file_path: file_path.to_string(),
+                span: Span::new(sl, sc, el, ec),
+            })
+            .collect()
+    }
+
+    /// Functions at most `depth` calls away from `function` (either direction)
+    fn functions_within(&self, function: usize, depth: usize) -> HashSet<usize> {
+        let mut neighbours: Vec<HashSet<usize>> = self.calls.clone();
+        for (caller, callees) in self.calls.iter().enumerate() {
+            for &callee in callees {
+                neighbours[callee].insert(caller);
+            }
+        }
+        let mut distance: HashMap<usize, usize> = HashMap::from([(function, 0)]);
+        let mut queue = VecDeque::from([function]);
+        while let Some(current) = queue.pop_front() {
+            let d = distance[&current];
+            if d == depth {
+                continue;
+            }
+            for &next in &neighbours[current] {
+                if let std::collections::hash_map::Entry::Vacant(e) = distance.entry(next) {
+                    e.insert(d + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+        distance.into_keys().collect()
+    }
+
+    /// Nodes reachable from `starts` over edges passing `follow` (against
+    /// edge direction when `backward`), staying inside `scope`
+    fn traverse(
+        &self,
+        starts: impl IntoIterator<Item = usize>,
+        backward: bool,
+        follow: &dyn Fn(SdgEdgeKind) -> bool,
+        scope: &dyn Fn(usize) -> bool,
+    ) -> HashSet<usize> {
+        let mut visited: HashSet<usize> = HashSet::new();
+        let mut worklist: VecDeque<usize> = VecDeque::new();
+        for start in starts {
+            if visited.insert(start) {
+                worklist.push_back(start);
+            }
+        }
+        while let Some(current) = worklist.pop_front() {
+            let adjacent = if backward {
+                &self.incoming[current]
+            } else {
+                &self.outgoing[current]
+            };
+            for &e in adjacent {
+                let (from, to, kind) = self.edges[e];
+                let next = if backward { from } else { to };
+                if follow(kind) && scope(next) && visited.insert(next) {
+                    worklist.push_back(next);
+                }
+            }
+        }
+        visited
+    }
+
+    fn add_node(&mut self, id: String, function: usize, span: Option<Span>) -> usize {
+        let index = self.nodes.len();
+        self.nodes.push(SdgNode { function, span });
+        self.incoming.push(Vec::new());
+        self.outgoing.push(Vec::new());
+        self.index.insert(id, index);
+        index
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize, kind: SdgEdgeKind) {
+        let e = self.edges.len();
+        self.edges.push((from, to, kind));
+        self.outgoing[from].push(e);
+        self.incoming[to].push(e);
+    }
+
+    fn add_edge_by_id(&mut self, from: &str, to: &str, kind: SdgEdgeKind) {
+        if let (Some(&from), Some(&to)) = (self.index.get(from), self.index.get(to)) {
+            self.add_edge(from, to, kind);
+        }
+    }
+}
+
+/// Callee index of a call target: function ID, FQN, unique name, or the
+/// unique name of its last segment (`self.save` → `save`)
+fn callee_resolver(functions: &[FunctionPdg]) -> impl Fn(&str) -> Option<usize> + '_ {
+    let mut exact: HashMap<&str, usize> = HashMap::new();
+    let mut by_name: HashMap<&str, Option<usize>> = HashMap::new();
+    for (i, f) in functions.iter().enumerate() {
+        exact.insert(f.function_id.as_str(), i);
+        exact.insert(f.fqn.as_str(), i);
+        by_name
+            .entry(f.name.as_str())
+            .and_modify(|unique| *unique = None)
+            .or_insert(Some(i));
+    }
+    move |target: &str| {
+        exact.get(target).copied().or_else(|| {
+            let name = target.rsplit(['.', ':']).next().unwrap_or(target);
+            by_name.get(name).copied().flatten()
+        })
+    }
+}
+
+/// Variables bound to parameter `j`: the keyword argument naming it, else
+/// the `j`-th positional argument
+fn bound_reads<'a>(arguments: &'a [CallArgument], j: usize, parameter: &str) -> &'a [String] {
+    arguments
+        .iter()
+        .find(|a| a.keyword.as_deref() == Some(parameter))
+        .or_else(|| arguments.iter().filter(|a| a.keyword.is_none()).nth(j))
+        .map(|a| a.reads.as_slice())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::pdg::infrastructure::pdg::{PDGEdge, PDGNode};
+
+    /// PDG with one statement per `(id, line)` and data edges `(from, to, var)`
+    fn pdg(
+        function_id: &str,
+        statements: &[(&str, u32)],
+        data: &[(&str, &str, &str)],
+    ) -> ProgramDependenceGraph {
+        let mut pdg = ProgramDependenceGraph::new(function_id.to_string());
+        for &(id, line) in statements {
+            pdg.add_node(PDGNode::new(
+                id.to_string(),
+                String::new(),
+                line,
+                Span::new(line, 4, line, 20),
+            ));
+        }
+        for &(from, to, var) in data {
+            pdg.add_edge(PDGEdge {
+                from_node: from.to_string(),
+                to_node: to.to_string(),
+                dependency_type: DependencyType::Data,
+                label: Some(var.to_string()),
+            });
+        }
+        pdg
+    }
+
+    fn positional(reads: &[&str]) -> CallArgument {
+        CallArgument {
+            keyword: None,
+            reads: reads.iter().map(|r| r.to_string()).collect(),
+        }
+    }
+
+    /// views.py:
+    ///   1 def handle(req):
+    ///   2     user = req.user
+    ///   3     limit = 10
+    ///   4     row = fetch(user, limit)
+    ///   5     sink(row)
+    /// db.py:
+    ///   1 def fetch(key, n):
+    ///   2     q = key.strip()
+    ///   3     return q
+    fn program() -> SystemDependenceGraph {
+        let handle = FunctionPdg {
+            function_id: "handle".to_string(),
+            fqn: "app.views.handle".to_string(),
+            name: "handle".to_string(),
+            file_path: "app/views.py".to_string(),
+            parameters: vec!["req".to_string()],
+            pdg: pdg(
+                "handle",
+                &[("h2", 2), ("h3", 3), ("h4", 4), ("h5", 5)],
+                &[
+                    ("h2", "h4", "user"),
+                    ("h3", "h4", "limit"),
+                    ("h4", "h5", "row"),
+                ],
+            ),
+            parameter_uses: vec![("h2".to_string(), "req".to_string())],
+            returns: Vec::new(),
+            call_sites: vec![CallSite {
+                statement: "h4".to_string(),
+                callee: "app.db.fetch".to_string(),
+                arguments: Some(vec![positional(&["user"]), positional(&["limit"])]),
+            }],
+        };
+        let fetch = FunctionPdg {
+            function_id: "fetch".to_string(),
+            fqn: "app.db.fetch".to_string(),
+            name: "fetch".to_string(),
+            file_path: "app/db.py".to_string(),
+            parameters: vec!["key".to_string(), "n".to_string()],
+            pdg: pdg("fetch", &[("f2", 2), ("f3", 3)], &[("f2", "f3", "q")]),
+            parameter_uses: vec![("f2".to_string(), "key".to_string())],
+            returns: vec!["f3".to_string()],
+            call_sites: Vec::new(),
+        };
+        SystemDependenceGraph::build(vec![handle, fetch])
+    }
+
+    fn lines(slice: &[SdgStatement]) -> Vec<(&str, u32)> {
+        slice
+            .iter()
+            .map(|s| (s.file_path.as_str(), s.span.start_line))
+            .collect()
+    }
+
+    #[test]
+    fn test_backward_slice_crosses_files_through_summary_edges() {
+        let sdg = program();
+
+        // fetch's result does not depend on `limit`, so line 3 is left out
+        let slice = sdg.backward_slice("h5", false, true, 3);
+        assert_eq!(
+            lines(&slice),
+            vec![
+                ("app/db.py", 2),
+                ("app/db.py", 3),
+                ("app/views.py", 2),
+                ("app/views.py", 4),
+                ("app/views.py", 5),
+            ]
+        );
+        assert_eq!(slice[0].function_id, "fetch");
+
+        // Callees are out of reach at depth 0
+        let slice = sdg.backward_slice("h5", false, true, 0);
+        assert_eq!(
+            lines(&slice),
+            vec![
+                ("app/views.py", 2),
+                ("app/views.py", 4),
+                ("app/views.py", 5)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_slices_are_context_sensitive() {
+        let sdg = program();
+
+        // From inside the callee, backward: the caller's argument definitions
+        let slice = sdg.backward_slice("f3", false, true, 3);
+        assert_eq!(
+            lines(&slice),
+            vec![("app/db.py", 2), ("app/db.py", 3), ("app/views.py", 2)]
+        );
+
+        // Forward from the caller's `user`: into fetch and back to the sink
+        let slice = sdg.forward_slice("h2", false, true, 3);
+        assert_eq!(
+            lines(&slice),
+            vec![
+                ("app/db.py", 2),
+                ("app/db.py", 3),
+                ("app/views.py", 2),
+                ("app/views.py", 4),
+                ("app/views.py", 5),
+            ]
+        );
+        assert!(sdg.backward_slice("missing", true, true, 3).is_empty());
+    }
+}
@@ -0,0 +1,507 @@
+//! SSA def-use queries
+//!
+//! Intra-procedural "which definitions reach here" / "where is this value
+//! read" over the SSA graphs, for tooling (rename, inline, debugger watch
+//! expressions) that should not re-derive def-use chains from raw edges.
+//!
+//! Each SSA version is tied back to its `Variable` node (the n-th version of
+//! `x` is the n-th definition of `x` in the function, the order `build_ssa`
+//! numbers them in). Reaching definitions are solved over the function's BFG
+//! blocks and CFG edges, so a use after an `if`/`else` sees both branch
+//! versions (the φ operands). Without CFG edges the function is treated as
+//! straight-line code.
+//!
+//! A definition takes effect after its line: in `x = x + 1` the right-hand
+//! `x` reads the previous version.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::features::flow_graph::infrastructure::bfg::BasicFlowGraph;
+use crate::features::flow_graph::infrastructure::cfg::CFGEdge;
+use crate::features::ssa::infrastructure::ssa::SSAGraph;
+use crate::pipeline::processor::{find_containing_block, ProcessResult};
+use crate::shared::models::span_ref::BlockRef;
+use crate::shared::models::{Edge, EdgeKind, Location, Node, NodeKind, Span};
+
+/// Versioned definition of a variable
+#[derive(Debug, Clone, PartialEq)]
+pub struct SSADefinition {
+    /// Function (SSA graph) the definition belongs to
+    pub function_id: String,
+    pub variable: String,
+    pub version: usize,
+    /// e.g. "x_1"
+    pub ssa_name: String,
+    /// `Variable` node of the assignment
+    pub node_id: String,
+    pub span: Span,
+    pub block_id: Option<String>,
+}
+
+/// Read of a variable
+#[derive(Debug, Clone, PartialEq)]
+pub struct SSAUse {
+    pub function_id: String,
+    pub variable: String,
+    pub span: Span,
+    pub block_id: Option<String>,
+}
+
+/// Def-use facts of one function
+struct FunctionDefUse {
+    function_id: String,
+    span: Span,
+    definitions: Vec<SSADefinition>,
+    uses: Vec<SSAUse>,
+    blocks: Vec<BlockRef>,
+    /// Definitions (indices) reaching each block entry, per variable
+    reaching_in: HashMap<String, HashMap<String, HashSet<usize>>>,
+}
+
+/// Def-use index over the SSA graphs of a file
+pub struct SSADefUseIndex {
+    functions: Vec<FunctionDefUse>,
+}
+
+impl SSADefUseIndex {
+    /// Build from L1-L5 results
+    ///
+    /// `ssa_graphs` and `bfg_graphs` are matched to function nodes by name
+    /// (and span, for same-named methods).
+    pub fn build(
+        nodes: &[Node],
+        edges: &[Edge],
+        bfg_graphs: &[BasicFlowGraph],
+        cfg_edges: &[CFGEdge],
+        ssa_graphs: &[SSAGraph],
+    ) -> Self {
+        let functions = ssa_graphs
+            .iter()
+            .filter_map(|ssa| {
+                let bfg = bfg_graphs.iter().find(|b| b.function_id == ssa.function_id);
+                let func = find_function(nodes, &ssa.function_id, bfg)?;
+                Some(FunctionDefUse::build(
+                    func, ssa, bfg, nodes, edges, cfg_edges,
+                ))
+            })
+            .collect();
+        Self { functions }
+    }
+
+    pub fn from_process_result(result: &ProcessResult) -> Self {
+        Self::build(
+            &result.nodes,
+            &result.edges,
+            &result.bfg_graphs,
+            &result.cfg_edges,
+            &result.ssa_graphs,
+        )
+    }
+
+    /// Definitions of `variable` that may reach `at`
+    ///
+    /// One definition on straight-line paths, several where branches merge
+    /// (the φ operands); empty for parameters, globals and unknown names.
+    pub fn definitions_of(&self, variable: &str, at: Location) -> Vec<&SSADefinition> {
+        match self.function_at(at) {
+            Some(function) => function.reaching(variable, at),
+            None => Vec::new(),
+        }
+    }
+
+    /// Uses reached by `definition`
+    pub fn uses_of(&self, definition: &SSADefinition) -> Vec<&SSAUse> {
+        let Some(function) = self.functions.iter().find(|f| {
+            f.definitions
+                .iter()
+                .any(|d| d.node_id == definition.node_id && d.version == definition.version)
+        }) else {
+            return Vec::new();
+        };
+
+        function
+            .uses
+            .iter()
+            .filter(|u| u.variable == definition.variable)
+            .filter(|u| {
+                function
+                    .reaching(&u.variable, start(&u.span))
+                    .into_iter()
+                    .any(|d| d.ssa_name == definition.ssa_name)
+            })
+            .collect()
+    }
+
+    /// Definition whose assignment target is at `at` (cursor on `x` in `x = ...`)
+    pub fn definition_at(&self, at: Location) -> Option<&SSADefinition> {
+        self.function_at(at)?
+            .definitions
+            .iter()
+            .find(|d| contains(&d.span, at))
+    }
+
+    /// All definitions of a function, in version order per variable
+    pub fn definitions(&self, function_id: &str) -> &[SSADefinition] {
+        self.functions
+            .iter()
+            .find(|f| f.function_id == function_id)
+            .map(|f| f.definitions.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Innermost function containing `at`
+    fn function_at(&self, at: Location) -> Option<&FunctionDefUse> {
+        self.functions
+            .iter()
+            .filter(|f| f.span.contains_line(at.line))
+            .min_by_key(|f| f.span.line_count())
+    }
+}
+
+impl FunctionDefUse {
+    fn build(
+        func: &Node,
+        ssa: &SSAGraph,
+        bfg: Option<&BasicFlowGraph>,
+        nodes: &[Node],
+        edges: &[Edge],
+        cfg_edges: &[CFGEdge],
+    ) -> Self {
+        let block_of = |span: &Span| bfg.and_then(|b| find_containing_block(span, &b.blocks));
+
+        // n-th SSA version of `x` ↔ n-th Variable node `x` of the function
+        let mut by_name: HashMap<&str, Vec<&Node>> = HashMap::new();
+        for node in nodes.iter().filter(|n| {
+            n.kind == NodeKind::Variable && n.parent_id.as_deref() == Some(func.id.as_str())
+        }) {
+            if let Some(name) = node.name.as_deref() {
+                by_name.entry(name).or_default().push(node);
+            }
+        }
+        let definitions: Vec<SSADefinition> = ssa
+            .variables
+            .iter()
+            .filter_map(|var| {
+                let node = by_name.get(var.base_name.as_str())?.get(var.version)?;
+                Some(SSADefinition {
+                    function_id: ssa.function_id.clone(),
+                    variable: var.base_name.clone(),
+                    version: var.version,
+                    ssa_name: var.ssa_name.clone(),
+                    node_id: node.id.clone(),
+                    span: node.span,
+                    block_id: block_of(&node.span),
+                })
+            })
+            .collect();
+
+        let uses = edges
+            .iter()
+            .filter(|e| e.kind == EdgeKind::Reads && e.source_id == func.id)
+            .filter_map(|e| {
+                let span = e.span?;
+                Some(SSAUse {
+                    function_id: ssa.function_id.clone(),
+                    variable: e.target_id.clone(),
+                    span,
+                    block_id: block_of(&span),
+                })
+            })
+            .collect();
+
+        let mut function = Self {
+            function_id: ssa.function_id.clone(),
+            span: func.span,
+            definitions,
+            uses,
+            blocks: bfg.map(|b| b.blocks.clone()).unwrap_or_default(),
+            reaching_in: HashMap::new(),
+        };
+        if let Some(bfg) = bfg {
+            function.solve_reaching(bfg, cfg_edges);
+        }
+        function
+    }
+
+    /// Forward reaching-definitions over the CFG (block granularity)
+    fn solve_reaching(&mut self, bfg: &BasicFlowGraph, cfg_edges: &[CFGEdge]) {
+        let block_ids: HashSet<&str> = bfg.blocks.iter().map(|b| b.id.as_str()).collect();
+        let mut predecessors: HashMap<&str, Vec<&str>> = HashMap::new();
+        let mut successors: HashMap<&str, Vec<&str>> = HashMap::new();
+        for edge in cfg_edges
+            .iter()
+            .filter(|e| block_ids.contains(e.source_block_id.as_str()))
+        {
+            predecessors
+                .entry(edge.target_block_id.as_str())
+                .or_default()
+                .push(edge.source_block_id.as_str());
+            successors
+                .entry(edge.source_block_id.as_str())
+                .or_default()
+                .push(edge.target_block_id.as_str());
+        }
+        if predecessors.is_empty() {
+            return;
+        }
+
+        // gen: last definition of each variable in the block
+        let mut gen: HashMap<&str, HashMap<&str, usize>> = HashMap::new();
+        for (idx, def) in self.definitions.iter().enumerate() {
+            let Some(block) = def.block_id.as_deref() else {
+                continue;
+            };
+            let last = gen
+                .entry(block)
+                .or_default()
+                .entry(def.variable.as_str())
+                .or_insert(idx);
+            if position(&self.definitions[*last].span) <= position(&def.span) {
+                *last = idx;
+            }
+        }
+
+        type Defs = HashMap<String, HashSet<usize>>;
+        let mut reaching_in: HashMap<String, Defs> = HashMap::new();
+        let mut reaching_out: HashMap<&str, Defs> = HashMap::new();
+        let mut worklist: Vec<&str> = bfg.blocks.iter().map(|b| b.id.as_str()).collect();
+        while let Some(block) = worklist.pop() {
+            let mut input: Defs = HashMap::new();
+            for pred in predecessors.get(block).into_iter().flatten() {
+                for (var, defs) in reaching_out.get(pred).into_iter().flatten() {
+                    input.entry(var.clone()).or_default().extend(defs);
+                }
+            }
+
+            let mut output = input.clone();
+            for (var, &idx) in gen.get(block).into_iter().flatten() {
+                output.insert(var.to_string(), HashSet::from([idx]));
+            }
+
+            reaching_in.insert(block.to_string(), input);
+            if reaching_out.get(block) != Some(&output) {
+                reaching_out.insert(block, output);
+                worklist.extend(successors.get(block).into_iter().flatten());
+            }
+        }
+        self.reaching_in = reaching_in;
+    }
+
+    fn reaching(&self, variable: &str, at: Location) -> Vec<&SSADefinition> {
+        let before = |d: &&SSADefinition| d.variable == variable && d.span.start_line < at.line;
+        let block = find_containing_block(
+            &Span::new(at.line, at.column, at.line, at.column),
+            &self.blocks,
+        );
+
+        // Latest earlier definition in the same block wins
+        if let Some(block) = &block {
+            if let Some(local) = self
+                .definitions
+                .iter()
+                .filter(|d| d.block_id.as_ref() == Some(block))
+                .filter(before)
+                .max_by_key(|d| position(&d.span))
+            {
+                return vec![local];
+            }
+            if let Some(entry) = self.reaching_in.get(block) {
+                let mut defs: Vec<&SSADefinition> = entry
+                    .get(variable)
+                    .into_iter()
+                    .flatten()
+                    .map(|&idx| &self.definitions[idx])
+                    .collect();
+                defs.sort_by_key(|d| d.version);
+                return defs;
+            }
+        }
+
+        // No CFG: straight-line code
+        self.definitions
+            .iter()
+            .filter(before)
+            .max_by_key(|d| position(&d.span))
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Function node for an SSA graph (by name; by span for same-named functions)
+fn find_function<'a>(
+    nodes: &'a [Node],
+    name: &str,
+    bfg: Option<&BasicFlowGraph>,
+) -> Option<&'a Node> {
+    let mut candidates = nodes.iter().filter(|n| {
+        matches!(n.kind, NodeKind::Function | NodeKind::Method) && n.name.as_deref() == Some(name)
+    });
+    let first = candidates.next()?;
+    let first_block =
+        bfg.and_then(|b| b.blocks.iter().find(|blk| blk.span_ref.span.start_line > 0));
+    match first_block {
+        Some(block) => std::iter::once(first)
+            .chain(candidates)
+            .find(|n| n.span.contains(&block.span_ref.span))
+            .or(Some(first)),
+        None => Some(first),
+    }
+}
+
+fn position(span: &Span) -> (u32, u32) {
+    (span.start_line, span.start_col)
+}
+
+fn start(span: &Span) -> Location {
+    Location::new(span.start_line, span.start_col)
+}
+
+fn contains(span: &Span, at: Location) -> bool {
+    (span.start_line, span.start_col) <= (at.line, at.column)
+        && (at.line, at.column) <= (span.end_line, span.end_col)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::flow_graph::infrastructure::cfg::CFGEdgeType;
+    use crate::features::ssa::infrastructure::ssa::build_ssa;
+
+    // 1 def f(c):
+    // 2     x = 1
+    // 3     if c:
+    // 4         x = 2
+    // 5     else:
+    // 6         x = 3
+    // 7     print(x)
+    // 8     x = x + 1
+    // 9     return x
+    fn fixture() -> (Vec<Node>, Vec<Edge>, BasicFlowGraph, Vec<CFGEdge>, SSAGraph) {
+        let func = Node::new(
+            "func:f".to_string(),
+            NodeKind::Function,
+            "app.f".to_string(),
+            "app.py".to_string(),
+            Span::new(1, 0, 9, 12),
+        )
+        .with_name("f");
+        let mut nodes = vec![func];
+        for (line, col) in [(2, 4), (4, 8), (6, 8), (8, 4)] {
+            nodes.push(
+                Node::new(
+                    format!("var:x:{}", line),
+                    NodeKind::Variable,
+                    "app.f.x".to_string(),
+                    "app.py".to_string(),
+                    Span::new(line, col, line, col + 1),
+                )
+                .with_name("x")
+                .with_parent("func:f"),
+            );
+        }
+        let edges = [(7, 10), (8, 8), (9, 11)]
+            .into_iter()
+            .map(|(line, col)| {
+                Edge::new("func:f".to_string(), "x".to_string(), EdgeKind::Reads)
+                    .with_span(Span::new(line, col, line, col + 1))
+            })
+            .collect();
+
+        let block = |n: usize, kind: &str, span: Span| {
+            BlockRef::new(format!("b{}", n), kind.to_string(), span, 1)
+        };
+        let bfg = BasicFlowGraph {
+            id: "bfg:f".to_string(),
+            function_id: "f".to_string(),
+            entry_block_id: "b0".to_string(),
+            exit_block_id: "b4".to_string(),
+            blocks: vec![
+                block(0, "Statement", Span::new(2, 4, 2, 9)),
+                block(1, "Condition", Span::new(3, 7, 3, 8)),
+                block(2, "Statement", Span::new(4, 8, 4, 13)),
+                block(3, "Statement", Span::new(6, 8, 6, 13)),
+                block(4, "Statement", Span::new(7, 4, 9, 12)),
+            ],
+            total_statements: 6,
+            exception_flow: Default::default(),
+        };
+        let cfg = [
+            ("b0", "b1"),
+            ("b1", "b2"),
+            ("b1", "b3"),
+            ("b2", "b4"),
+            ("b3", "b4"),
+        ]
+        .into_iter()
+        .map(|(source, target)| CFGEdge {
+            source_block_id: source.to_string(),
+            target_block_id: target.to_string(),
+            edge_type: CFGEdgeType::Unconditional,
+        })
+        .collect();
+        let definitions: Vec<_> = ["b0", "b2", "b3", "b4"]
+            .iter()
+            .map(|b| ("x".to_string(), b.to_string()))
+            .collect();
+        let ssa = build_ssa("f".to_string(), &definitions);
+
+        (nodes, edges, bfg, cfg, ssa)
+    }
+
+    fn names(defs: Vec<&SSADefinition>) -> Vec<&str> {
+        defs.into_iter().map(|d| d.ssa_name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_definitions_of_merges_branches() {
+        let (nodes, edges, bfg, cfg, ssa) = fixture();
+        let index = SSADefUseIndex::build(&nodes, &edges, &[bfg], &cfg, &[ssa]);
+
+        assert_eq!(
+            names(index.definitions_of("x", Location::new(7, 10))),
+            vec!["x_1", "x_2"]
+        );
+        // Right-hand side of `x = x + 1` still reads the branch versions
+        assert_eq!(
+            names(index.definitions_of("x", Location::new(8, 8))),
+            vec!["x_1", "x_2"]
+        );
+        assert_eq!(
+            names(index.definitions_of("x", Location::new(9, 11))),
+            vec!["x_3"]
+        );
+        assert!(index.definitions_of("y", Location::new(9, 11)).is_empty());
+        assert!(index.definitions_of("x", Location::new(20, 0)).is_empty());
+    }
+
+    #[test]
+    fn test_uses_of() {
+        let (nodes, edges, bfg, cfg, ssa) = fixture();
+        let index = SSADefUseIndex::build(&nodes, &edges, &[bfg], &cfg, &[ssa]);
+
+        let x1 = index.definition_at(Location::new(4, 8)).unwrap();
+        assert_eq!(x1.ssa_name, "x_1");
+        assert_eq!(x1.block_id.as_deref(), Some("b2"));
+        let lines: Vec<u32> = index
+            .uses_of(x1)
+            .iter()
+            .map(|u| u.span.start_line)
+            .collect();
+        assert_eq!(lines, vec![7, 8]);
+
+        // x_0 is overwritten on both branches
+        let x0 = &index.definitions("f")[0];
+        assert!(index.uses_of(x0).is_empty());
+    }
+
+    #[test]
+    fn test_straight_line_without_cfg() {
+        let (nodes, edges, bfg, _, ssa) = fixture();
+        let index = SSADefUseIndex::build(&nodes, &edges, &[bfg], &[], &[ssa]);
+
+        assert_eq!(
+            names(index.definitions_of("x", Location::new(7, 10))),
+            vec!["x_2"]
+        );
+    }
+}
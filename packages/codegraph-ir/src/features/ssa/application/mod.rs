@@ -1,2 +1,4 @@
 mod build_ssa;
+mod def_use_query;
 pub use build_ssa::BuildSSAUseCase;
+pub use def_use_query::{SSADefUseIndex, SSADefinition, SSAUse};
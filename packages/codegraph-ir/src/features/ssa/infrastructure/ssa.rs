@@ -11,10 +11,11 @@
  * - No fake data
  */
 
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// SSA Variable (versioned)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SSAVariable {
     pub base_name: String,
     pub version: usize,
@@ -22,7 +23,7 @@ pub struct SSAVariable {
 }
 
 /// Phi node (merge point)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PhiNode {
     pub variable: String,
     pub version: usize,
@@ -30,7 +31,7 @@ pub struct PhiNode {
 }
 
 /// SSA Graph
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SSAGraph {
     pub function_id: String,
     pub variables: Vec<SSAVariable>,
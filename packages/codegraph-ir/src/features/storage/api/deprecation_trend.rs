@@ -0,0 +1,223 @@
+//! Deprecation Usage Trend
+//!
+//! Counts deprecated symbols and their remaining call sites per stored
+//! snapshot, so teams can see whether migrations off deprecated APIs are
+//! progressing:
+//!
+//! - Deprecated symbols are chunks carrying the `deprecated` attribute
+//!   (written by `mark_deprecated` from a `DeprecationReport`)
+//! - Call sites are `Calls` dependencies pointing at those chunks
+//!
+//! Like the snapshot report, everything is computed from `ChunkStore` data.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write as _;
+
+use super::snapshot_store::CodeSnapshotStore;
+use crate::features::storage::domain::models::{Chunk, DependencyType};
+use crate::shared::models::{Result, Value};
+
+/// Chunk attribute marking a deprecated symbol
+///
+/// Value: `{"replacement": "new_api"}` (`replacement` may be null)
+pub const DEPRECATED_ATTR: &str = "deprecated";
+
+/// Mark chunks whose FQN is in `deprecated` (FQN → replacement)
+///
+/// Returns the number of chunks marked.
+pub fn mark_deprecated(
+    chunks: &mut [Chunk],
+    deprecated: &BTreeMap<String, Option<String>>,
+) -> usize {
+    let mut marked = 0;
+    for chunk in chunks {
+        let Some(replacement) = chunk.fqn.as_ref().and_then(|fqn| deprecated.get(fqn)) else {
+            continue;
+        };
+        chunk.attrs.insert(
+            DEPRECATED_ATTR.to_string(),
+            serde_json::json!({ "replacement": replacement }),
+        );
+        marked += 1;
+    }
+    marked
+}
+
+/// Deprecation usage in one snapshot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeprecationTrendPoint {
+    pub snapshot_id: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub deprecated_symbols: usize,
+    pub call_sites: usize,
+    /// FQN → call sites
+    pub by_symbol: BTreeMap<String, usize>,
+}
+
+/// Deprecation usage across snapshots (oldest first)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeprecationTrend {
+    pub repo_id: String,
+    pub points: Vec<DeprecationTrendPoint>,
+}
+
+impl DeprecationTrend {
+    /// Trend over every snapshot of a repository, ordered by creation time
+    pub async fn for_repo(store: &CodeSnapshotStore, repo_id: &str) -> Result<Self> {
+        let mut snapshots = store.list_snapshots(repo_id).await?;
+        snapshots.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        let ids: Vec<&str> = snapshots.iter().map(|s| s.snapshot_id.as_str()).collect();
+        Self::build(store, repo_id, &ids).await
+    }
+
+    /// Trend over the given snapshots, in the given order
+    pub async fn build(
+        store: &CodeSnapshotStore,
+        repo_id: &str,
+        snapshot_ids: &[&str],
+    ) -> Result<Self> {
+        let mut points = Vec::with_capacity(snapshot_ids.len());
+        for &snapshot_id in snapshot_ids {
+            points.push(Self::point(store, repo_id, snapshot_id).await?);
+        }
+        Ok(Self {
+            repo_id: repo_id.to_string(),
+            points,
+        })
+    }
+
+    async fn point(
+        store: &CodeSnapshotStore,
+        repo_id: &str,
+        snapshot_id: &str,
+    ) -> Result<DeprecationTrendPoint> {
+        let created_at = store.get_snapshot(snapshot_id).await?.map(|s| s.created_at);
+        let chunks = store.get_chunks(repo_id, snapshot_id).await?;
+        let ids: HashSet<&str> = chunks.iter().map(|c| c.chunk_id.as_str()).collect();
+
+        let mut by_symbol = BTreeMap::new();
+        for chunk in chunks.iter().filter(|c| is_deprecated(c)) {
+            let mut call_sites = 0;
+            for dep in store.get_dependencies_to(&chunk.chunk_id).await? {
+                // Only callers from the same snapshot; self-calls don't count
+                if dep.relationship == DependencyType::Calls
+                    && dep.from_chunk_id != chunk.chunk_id
+                    && ids.contains(dep.from_chunk_id.as_str())
+                {
+                    call_sites += 1;
+                }
+            }
+            let fqn = chunk.fqn.clone().unwrap_or_else(|| chunk.chunk_id.clone());
+            *by_symbol.entry(fqn).or_insert(0) += call_sites;
+        }
+
+        Ok(DeprecationTrendPoint {
+            snapshot_id: snapshot_id.to_string(),
+            created_at,
+            deprecated_symbols: by_symbol.len(),
+            call_sites: by_symbol.values().sum(),
+            by_symbol,
+        })
+    }
+
+    /// Call-site change from the first to the last snapshot (negative = progress)
+    pub fn call_site_delta(&self) -> i64 {
+        match (self.points.first(), self.points.last()) {
+            (Some(first), Some(last)) => last.call_sites as i64 - first.call_sites as i64,
+            _ => 0,
+        }
+    }
+
+    /// One line per snapshot (for CI logs)
+    pub fn summary(&self) -> String {
+        let mut out = String::new();
+        for point in &self.points {
+            let _ = writeln!(
+                out,
+                "{}: {} deprecated symbols, {} call sites",
+                point.snapshot_id, point.deprecated_symbols, point.call_sites
+            );
+        }
+        let _ = write!(out, "delta: {:+} call sites", self.call_site_delta());
+        out
+    }
+
+    /// Serialize as JSON (for dashboards)
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+fn is_deprecated(chunk: &Chunk) -> bool {
+    !matches!(chunk.attrs.get(DEPRECATED_ATTR), None | Some(Value::Null))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::storage::domain::models::Dependency;
+    use crate::features::storage::domain::ports::ChunkStore;
+    use crate::features::storage::infrastructure::InMemoryChunkStore;
+
+    fn chunk(snapshot: &str, fqn: &str) -> Chunk {
+        let mut chunk = Chunk::new(
+            "repo".into(),
+            snapshot.into(),
+            "app.py".into(),
+            1,
+            5,
+            "function".into(),
+            fqn.into(),
+        );
+        chunk.chunk_id = format!("{}:{}", snapshot, fqn);
+        chunk.fqn = Some(fqn.to_string());
+        chunk
+    }
+
+    fn call(snapshot: &str, from: &str, to: &str) -> Dependency {
+        Dependency {
+            id: format!("{}:{}->{}", snapshot, from, to),
+            from_chunk_id: format!("{}:{}", snapshot, from),
+            to_chunk_id: format!("{}:{}", snapshot, to),
+            relationship: DependencyType::Calls,
+            confidence: 1.0,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_trend_across_snapshots() {
+        let store = InMemoryChunkStore::new();
+        let deprecated: BTreeMap<String, Option<String>> =
+            [("app.old".to_string(), Some("app.new".to_string()))].into();
+
+        for (snapshot, callers) in [("v1", &["a", "b", "c"][..]), ("v2", &["a"][..])] {
+            let mut chunks = vec![chunk(snapshot, "app.old"), chunk(snapshot, "app.new")];
+            chunks.extend(callers.iter().map(|c| chunk(snapshot, c)));
+            assert_eq!(mark_deprecated(&mut chunks, &deprecated), 1);
+            store.save_chunks(&chunks).await.unwrap();
+
+            let mut deps: Vec<_> = callers
+                .iter()
+                .map(|c| call(snapshot, c, "app.old"))
+                .collect();
+            deps.push(call(snapshot, "app.old", "app.new"));
+            store.save_dependencies(&deps).await.unwrap();
+        }
+
+        let snapshots = CodeSnapshotStore::new(store);
+        let trend = DeprecationTrend::build(&snapshots, "repo", &["v1", "v2"])
+            .await
+            .unwrap();
+
+        assert_eq!(trend.points.len(), 2);
+        assert_eq!(trend.points[0].call_sites, 3);
+        assert_eq!(trend.points[1].call_sites, 1);
+        assert_eq!(trend.points[1].by_symbol["app.old"], 1);
+        assert_eq!(trend.points[1].deprecated_symbols, 1);
+        assert_eq!(trend.call_site_delta(), -2);
+        assert!(trend.summary().ends_with("delta: -2 call sites"));
+    }
+}
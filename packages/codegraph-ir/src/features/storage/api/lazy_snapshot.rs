@@ -0,0 +1,424 @@
+//! Lazy per-file snapshot loading
+//!
+//! `CodeSnapshotStore::get_chunks` loads a whole snapshot before the first
+//! query. `LazySnapshot` instead opens without I/O and fetches a file's
+//! chunks and outgoing dependencies the first time the file is asked for:
+//!
+//! - loaded files are kept in an LRU bounded by a memory budget (estimated
+//!   bytes); the least recently used files are dropped when it is exceeded
+//! - `retain` keeps a file resident regardless of the budget (files a query
+//!   service always serves), `release` returns it to the LRU
+//! - `prefetch` loads files ahead of use; with `PrefetchHint::Dependencies`
+//!   loading a file also prefetches the files its dependencies point to
+//!
+//! Soft-deleted chunks are never loaded (`ChunkStore::get_chunks_by_file`).
+//!
+//! ```rust,no_run
+//! # use codegraph_ir::features::storage::api::{CodeSnapshotStore, PrefetchHint};
+//! # use codegraph_ir::features::storage::infrastructure::InMemoryChunkStore;
+//! # async fn example() -> codegraph_ir::shared::models::Result<()> {
+//! let store = CodeSnapshotStore::new(InMemoryChunkStore::new());
+//! let snapshot = store
+//!     .lazy("my-repo", "main")
+//!     .with_memory_budget(64 * 1024 * 1024)
+//!     .with_prefetch(PrefetchHint::Dependencies);
+//! let auth = snapshot.file("src/auth.py").await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use lru::LruCache;
+use std::collections::{BTreeSet, HashMap};
+use std::mem::size_of;
+use std::sync::{Arc, Mutex};
+
+use crate::features::storage::domain::{
+    models::{Chunk, Dependency},
+    ports::ChunkStore,
+};
+use crate::shared::models::Result;
+
+/// Default memory budget of loaded files
+pub const DEFAULT_MEMORY_BUDGET_BYTES: usize = 256 * 1024 * 1024; // 256 MiB
+
+/// What to load ahead when a file is loaded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PrefetchHint {
+    /// Only the requested file
+    #[default]
+    None,
+    /// Also the files targeted by the file's dependencies (one hop)
+    Dependencies,
+}
+
+/// Chunks of one file with their outgoing dependencies
+#[derive(Debug, Clone)]
+pub struct FileSlice {
+    pub file_path: String,
+    pub chunks: Vec<Chunk>,
+    pub dependencies: Vec<Dependency>,
+}
+
+impl FileSlice {
+    /// Estimated heap + inline size, used against the memory budget
+    pub fn size_bytes(&self) -> usize {
+        let chunks: usize = self
+            .chunks
+            .iter()
+            .map(|c| {
+                size_of::<Chunk>()
+                    + c.chunk_id.len()
+                    + c.file_path.len()
+                    + c.content.len()
+                    + c.content_hash.len()
+                    + c.fqn.as_ref().map_or(0, String::len)
+                    + c.summary.as_ref().map_or(0, String::len)
+            })
+            .sum();
+        let dependencies: usize = self
+            .dependencies
+            .iter()
+            .map(|d| {
+                size_of::<Dependency>() + d.id.len() + d.from_chunk_id.len() + d.to_chunk_id.len()
+            })
+            .sum();
+        self.file_path.len() + chunks + dependencies
+    }
+}
+
+/// Load counters of a `LazySnapshot`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LazyLoadStats {
+    /// Files fetched from the store
+    pub loads: usize,
+    /// Requests served from memory
+    pub hits: usize,
+    /// Files dropped to stay within the budget
+    pub evictions: usize,
+    pub resident_files: usize,
+    pub resident_bytes: usize,
+}
+
+struct LoadState {
+    lru: LruCache<String, Arc<FileSlice>>,
+    retained: HashMap<String, Arc<FileSlice>>,
+    /// Retained paths not loaded yet
+    pending_retain: BTreeSet<String>,
+    lru_bytes: usize,
+    stats: LazyLoadStats,
+}
+
+impl LoadState {
+    fn get(&mut self, file_path: &str) -> Option<Arc<FileSlice>> {
+        self.retained
+            .get(file_path)
+            .cloned()
+            .or_else(|| self.lru.get(file_path).cloned())
+    }
+
+    fn insert(&mut self, budget: usize, slice: Arc<FileSlice>) -> Arc<FileSlice> {
+        // A concurrent load of the same file may have finished first
+        if let Some(existing) = self.get(&slice.file_path) {
+            return existing;
+        }
+        if self.pending_retain.remove(&slice.file_path) {
+            self.retained.insert(slice.file_path.clone(), slice.clone());
+            return slice;
+        }
+        self.lru_bytes += slice.size_bytes();
+        self.lru.put(slice.file_path.clone(), slice.clone());
+        // The file just loaded stays even if it alone exceeds the budget
+        while self.lru_bytes > budget && self.lru.len() > 1 {
+            if let Some((_, evicted)) = self.lru.pop_lru() {
+                self.lru_bytes -= evicted.size_bytes();
+                self.stats.evictions += 1;
+            }
+        }
+        slice
+    }
+
+    fn stats(&self) -> LazyLoadStats {
+        let retained_bytes: usize = self.retained.values().map(|s| s.size_bytes()).sum();
+        LazyLoadStats {
+            resident_files: self.lru.len() + self.retained.len(),
+            resident_bytes: self.lru_bytes + retained_bytes,
+            ..self.stats.clone()
+        }
+    }
+}
+
+/// Snapshot whose files are loaded on demand
+pub struct LazySnapshot {
+    store: Arc<dyn ChunkStore>,
+    repo_id: String,
+    snapshot_id: String,
+    memory_budget: usize,
+    prefetch: PrefetchHint,
+    state: Mutex<LoadState>,
+}
+
+impl LazySnapshot {
+    /// Open a snapshot (no I/O until a file is requested)
+    pub fn new(store: Arc<dyn ChunkStore>, repo_id: &str, snapshot_id: &str) -> Self {
+        Self {
+            store,
+            repo_id: repo_id.to_string(),
+            snapshot_id: snapshot_id.to_string(),
+            memory_budget: DEFAULT_MEMORY_BUDGET_BYTES,
+            prefetch: PrefetchHint::default(),
+            state: Mutex::new(LoadState {
+                lru: LruCache::unbounded(),
+                retained: HashMap::new(),
+                pending_retain: BTreeSet::new(),
+                lru_bytes: 0,
+                stats: LazyLoadStats::default(),
+            }),
+        }
+    }
+
+    /// Bound the estimated size of loaded, non-retained files
+    pub fn with_memory_budget(mut self, bytes: usize) -> Self {
+        self.memory_budget = bytes;
+        self
+    }
+
+    pub fn with_prefetch(mut self, hint: PrefetchHint) -> Self {
+        self.prefetch = hint;
+        self
+    }
+
+    pub fn repo_id(&self) -> &str {
+        &self.repo_id
+    }
+
+    pub fn snapshot_id(&self) -> &str {
+        &self.snapshot_id
+    }
+
+    /// Chunks and dependencies of `file_path`, loading them if not resident
+    pub async fn file(&self, file_path: &str) -> Result<Arc<FileSlice>> {
+        if let Some(slice) = self.cached(file_path) {
+            return Ok(slice);
+        }
+        let slice = self.load(file_path).await?;
+        if self.prefetch == PrefetchHint::Dependencies {
+            let targets = self.dependency_files(&slice).await?;
+            self.prefetch(&targets).await?;
+        }
+        Ok(slice)
+    }
+
+    /// Chunks of `file_path`
+    pub async fn chunks(&self, file_path: &str) -> Result<Vec<Chunk>> {
+        Ok(self.file(file_path).await?.chunks.clone())
+    }
+
+    /// Outgoing dependencies of the chunks of `file_path`
+    pub async fn dependencies(&self, file_path: &str) -> Result<Vec<Dependency>> {
+        Ok(self.file(file_path).await?.dependencies.clone())
+    }
+
+    /// Load files that are not resident yet (no further prefetch)
+    pub async fn prefetch<S: AsRef<str>>(&self, file_paths: &[S]) -> Result<()> {
+        for file_path in file_paths {
+            let file_path = file_path.as_ref();
+            if !self.is_resident(file_path) {
+                self.load(file_path).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Keep `file_path` resident regardless of the memory budget
+    ///
+    /// Takes effect when the file is next loaded if it is not resident.
+    pub fn retain(&self, file_path: &str) {
+        let mut state = self.lock();
+        if let Some(slice) = state.lru.pop(file_path) {
+            state.lru_bytes -= slice.size_bytes();
+            state.retained.insert(file_path.to_string(), slice);
+        } else if !state.retained.contains_key(file_path) {
+            state.pending_retain.insert(file_path.to_string());
+        }
+    }
+
+    /// Return a retained file to the LRU
+    pub fn release(&self, file_path: &str) {
+        let mut state = self.lock();
+        state.pending_retain.remove(file_path);
+        if let Some(slice) = state.retained.remove(file_path) {
+            let budget = self.memory_budget;
+            state.insert(budget, slice);
+        }
+    }
+
+    /// Drop a resident file (retained or not)
+    pub fn evict(&self, file_path: &str) {
+        let mut state = self.lock();
+        state.retained.remove(file_path);
+        if let Some(slice) = state.lru.pop(file_path) {
+            state.lru_bytes -= slice.size_bytes();
+        }
+    }
+
+    pub fn is_resident(&self, file_path: &str) -> bool {
+        let state = self.lock();
+        state.retained.contains_key(file_path) || state.lru.contains(file_path)
+    }
+
+    pub fn stats(&self) -> LazyLoadStats {
+        self.lock().stats()
+    }
+
+    fn cached(&self, file_path: &str) -> Option<Arc<FileSlice>> {
+        let mut state = self.lock();
+        let slice = state.get(file_path);
+        if slice.is_some() {
+            state.stats.hits += 1;
+        }
+        slice
+    }
+
+    async fn load(&self, file_path: &str) -> Result<Arc<FileSlice>> {
+        let chunks = self
+            .store
+            .get_chunks_by_file(&self.repo_id, &self.snapshot_id, file_path)
+            .await?;
+        let mut dependencies = Vec::new();
+        for chunk in &chunks {
+            dependencies.extend(self.store.get_dependencies_from(&chunk.chunk_id).await?);
+        }
+        let slice = Arc::new(FileSlice {
+            file_path: file_path.to_string(),
+            chunks,
+            dependencies,
+        });
+
+        let mut state = self.lock();
+        state.stats.loads += 1;
+        Ok(state.insert(self.memory_budget, slice))
+    }
+
+    /// Other files holding the targets of `slice`'s dependencies
+    async fn dependency_files(&self, slice: &FileSlice) -> Result<Vec<String>> {
+        let mut files = BTreeSet::new();
+        let local: BTreeSet<&str> = slice.chunks.iter().map(|c| c.chunk_id.as_str()).collect();
+        let targets: BTreeSet<&str> = slice
+            .dependencies
+            .iter()
+            .map(|d| d.to_chunk_id.as_str())
+            .filter(|id| !local.contains(id))
+            .collect();
+        for target in targets {
+            if let Some(chunk) = self.store.get_chunk(target).await? {
+                if chunk.file_path != slice.file_path
+                    && chunk.repo_id == self.repo_id
+                    && chunk.snapshot_id == self.snapshot_id
+                {
+                    files.insert(chunk.file_path);
+                }
+            }
+        }
+        Ok(files.into_iter().collect())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LoadState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::storage::api::CodeSnapshotStore;
+    use crate::features::storage::domain::models::DependencyType;
+    use crate::features::storage::infrastructure::InMemoryChunkStore;
+    use chrono::Utc;
+
+    fn chunk(file: &str, name: &str) -> Chunk {
+        let mut chunk = Chunk::new(
+            "repo".into(),
+            "main".into(),
+            file.into(),
+            1,
+            5,
+            "function".into(),
+            format!("def {}(): pass", name),
+        );
+        chunk.chunk_id = format!("{}:{}", file, name);
+        chunk.fqn = Some(name.to_string());
+        chunk
+    }
+
+    fn call(from: &str, to: &str) -> Dependency {
+        Dependency {
+            id: format!("{}->{}", from, to),
+            from_chunk_id: from.to_string(),
+            to_chunk_id: to.to_string(),
+            relationship: DependencyType::Calls,
+            confidence: 1.0,
+            created_at: Utc::now(),
+        }
+    }
+
+    async fn snapshot_store() -> CodeSnapshotStore {
+        let store = InMemoryChunkStore::new();
+        store
+            .save_chunks(&[
+                chunk("a.py", "handler"),
+                chunk("b.py", "helper"),
+                chunk("c.py", "unused"),
+            ])
+            .await
+            .unwrap();
+        store
+            .save_dependencies(&[call("a.py:handler", "b.py:helper")])
+            .await
+            .unwrap();
+        CodeSnapshotStore::new(store)
+    }
+
+    #[tokio::test]
+    async fn test_files_load_on_demand_with_prefetch() {
+        let store = snapshot_store().await;
+        let snapshot = store
+            .lazy("repo", "main")
+            .with_prefetch(PrefetchHint::Dependencies);
+        assert_eq!(snapshot.stats().resident_files, 0);
+
+        let a = snapshot.file("a.py").await.unwrap();
+        assert_eq!(a.chunks.len(), 1);
+        assert_eq!(a.dependencies[0].to_chunk_id, "b.py:helper");
+        assert!(snapshot.is_resident("b.py"));
+        assert!(!snapshot.is_resident("c.py"));
+
+        snapshot.file("b.py").await.unwrap();
+        let stats = snapshot.stats();
+        assert_eq!(stats.loads, 2);
+        assert_eq!(stats.hits, 1);
+    }
+
+    #[tokio::test]
+    async fn test_budget_evicts_lru_but_keeps_retained() {
+        let store = snapshot_store().await;
+        let one_file = FileSlice {
+            file_path: "a.py".into(),
+            chunks: vec![chunk("a.py", "handler")],
+            dependencies: vec![call("a.py:handler", "b.py:helper")],
+        }
+        .size_bytes();
+        let snapshot = store.lazy("repo", "main").with_memory_budget(one_file);
+
+        snapshot.retain("c.py");
+        snapshot.prefetch(&["a.py", "b.py", "c.py"]).await.unwrap();
+        assert!(!snapshot.is_resident("a.py"));
+        assert!(snapshot.is_resident("b.py"));
+        assert!(snapshot.is_resident("c.py"));
+        assert_eq!(snapshot.stats().evictions, 1);
+
+        snapshot.release("c.py");
+        assert!(!snapshot.is_resident("b.py"));
+        assert!(snapshot.is_resident("c.py"));
+        assert_eq!(snapshot.stats().resident_files, 1);
+    }
+}
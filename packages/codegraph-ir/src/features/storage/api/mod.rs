@@ -6,6 +6,9 @@
 //! - File-level replace primitive (commit-based)
 //! - Incremental snapshot creation
 //! - Commit comparison (semantic diff)
+//! - Snapshot comparison report (HTML/JSON)
+//! - Deprecation usage trend across snapshots
+//! - Lazy per-file snapshot loading within a memory budget
 //!
 //! # Example
 //!
@@ -30,8 +33,16 @@
 //! # }
 //! ```
 
+pub mod deprecation_trend;
+pub mod lazy_snapshot;
 pub mod snapshot_diff;
+pub mod snapshot_report;
 pub mod snapshot_store;
 
+pub use deprecation_trend::{
+    mark_deprecated, DeprecationTrend, DeprecationTrendPoint, DEPRECATED_ATTR,
+};
+pub use lazy_snapshot::{FileSlice, LazyLoadStats, LazySnapshot, PrefetchHint};
 pub use snapshot_diff::{SnapshotDiff, SnapshotStats};
+pub use snapshot_report::{SnapshotReport, SnapshotReportOptions};
 pub use snapshot_store::CodeSnapshotStore;
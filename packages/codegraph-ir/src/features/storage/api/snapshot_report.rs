@@ -0,0 +1,832 @@
+//! Snapshot Comparison Report
+//!
+//! Compares two stored snapshots and renders a self-contained HTML report
+//! (no external assets) that CI can attach to pull requests:
+//!
+//! - Added / removed / modified symbols (FQN + content hash, see `compare_commits`)
+//! - Package-level dependency changes (package = directory of the chunk's file)
+//! - Impact hot zones (files whose changes reach the most dependents)
+//! - Finding deltas (introduced / resolved), read from the `findings` chunk attribute
+//!
+//! Everything is computed from `ChunkStore` data; no re-analysis is needed.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::fmt::Write as _;
+use std::path::Path;
+
+use super::snapshot_store::CodeSnapshotStore;
+use crate::features::storage::domain::models::{Chunk, Dependency};
+use crate::shared::i18n::{MessageId, ReportOptions};
+use crate::shared::models::{Result, Value};
+
+/// Chunk attribute holding analysis findings (JSON array)
+///
+/// Each entry: `{"rule_id": "...", "severity": "...", "message": "...", "line": 12}`
+pub const FINDINGS_ATTR: &str = "findings";
+
+/// Kind of change between base and head
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+impl ChangeKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ChangeKind::Added => "added",
+            ChangeKind::Removed => "removed",
+            ChangeKind::Modified => "modified",
+        }
+    }
+
+    fn label(self) -> MessageId {
+        match self {
+            ChangeKind::Added => MessageId::ChangeAdded,
+            ChangeKind::Removed => MessageId::ChangeRemoved,
+            ChangeKind::Modified => MessageId::ChangeModified,
+        }
+    }
+}
+
+/// Symbol-level change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SymbolChange {
+    pub fqn: String,
+    pub kind: String,
+    pub file_path: String,
+    pub start_line: u32,
+    pub end_line: u32,
+    pub change: ChangeKind,
+}
+
+/// Dependency count change between two packages
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageDependencyChange {
+    pub from_package: String,
+    pub to_package: String,
+    pub base_count: usize,
+    pub head_count: usize,
+}
+
+impl PackageDependencyChange {
+    /// Added (new package edge), removed (edge gone) or modified (count changed)
+    pub fn change(&self) -> ChangeKind {
+        match (self.base_count, self.head_count) {
+            (0, _) => ChangeKind::Added,
+            (_, 0) => ChangeKind::Removed,
+            _ => ChangeKind::Modified,
+        }
+    }
+}
+
+/// File whose changed symbols have the largest transitive dependent set
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpactHotZone {
+    pub file_path: String,
+    /// Added/modified/removed symbols in this file
+    pub changed_symbols: usize,
+    /// Distinct symbols (transitively) depending on them
+    pub impacted_symbols: usize,
+    /// Distinct files containing impacted symbols
+    pub impacted_files: usize,
+}
+
+/// Finding attached to a stored chunk
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ReportFinding {
+    pub rule_id: String,
+    pub severity: String,
+    pub message: String,
+    pub file_path: String,
+    pub symbol: Option<String>,
+    pub line: Option<u32>,
+}
+
+impl ReportFinding {
+    /// Identity across snapshots (line numbers shift, so they are excluded)
+    fn key(&self) -> (&str, &str, Option<&str>, &str) {
+        (
+            &self.rule_id,
+            &self.file_path,
+            self.symbol.as_deref(),
+            &self.message,
+        )
+    }
+}
+
+/// Findings introduced / resolved by head
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FindingDelta {
+    pub introduced: Vec<ReportFinding>,
+    pub resolved: Vec<ReportFinding>,
+}
+
+/// Report options
+#[derive(Debug, Clone)]
+pub struct SnapshotReportOptions {
+    /// Reverse-dependency depth used for hot zones
+    pub impact_depth: usize,
+    /// Maximum hot zones listed
+    pub max_hot_zones: usize,
+}
+
+impl Default for SnapshotReportOptions {
+    fn default() -> Self {
+        Self {
+            impact_depth: 3,
+            max_hot_zones: 20,
+        }
+    }
+}
+
+/// Comparison of two snapshots
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotReport {
+    pub repo_id: String,
+    pub base_snapshot: String,
+    pub head_snapshot: String,
+    pub generated_at: DateTime<Utc>,
+    pub symbols: Vec<SymbolChange>,
+    pub package_dependencies: Vec<PackageDependencyChange>,
+    pub hot_zones: Vec<ImpactHotZone>,
+    pub findings: FindingDelta,
+}
+
+/// Chunks + dependencies of one snapshot
+struct SnapshotGraph {
+    chunks: HashMap<String, Chunk>,
+    dependencies: Vec<Dependency>,
+}
+
+impl SnapshotGraph {
+    async fn load(store: &CodeSnapshotStore, repo_id: &str, snapshot_id: &str) -> Result<Self> {
+        let chunks: HashMap<String, Chunk> = store
+            .get_chunks(repo_id, snapshot_id)
+            .await?
+            .into_iter()
+            .map(|c| (c.chunk_id.clone(), c))
+            .collect();
+
+        let mut dependencies = Vec::new();
+        for chunk_id in chunks.keys() {
+            for dep in store.get_dependencies_from(chunk_id).await? {
+                // Keep edges fully inside this snapshot
+                if chunks.contains_key(&dep.to_chunk_id) {
+                    dependencies.push(dep);
+                }
+            }
+        }
+
+        Ok(Self {
+            chunks,
+            dependencies,
+        })
+    }
+
+    /// (from_package, to_package) → edge count, excluding intra-package edges
+    fn package_edges(&self) -> HashMap<(String, String), usize> {
+        let mut edges = HashMap::new();
+        for dep in &self.dependencies {
+            let from = package_of(&self.chunks[&dep.from_chunk_id].file_path);
+            let to = package_of(&self.chunks[&dep.to_chunk_id].file_path);
+            if from != to {
+                *edges.entry((from, to)).or_insert(0) += 1;
+            }
+        }
+        edges
+    }
+
+    /// Chunks transitively depending on `roots` (roots excluded)
+    fn dependents(&self, roots: &[&str], max_depth: usize) -> HashSet<String> {
+        let mut reverse: HashMap<&str, Vec<&str>> = HashMap::new();
+        for dep in &self.dependencies {
+            reverse
+                .entry(dep.to_chunk_id.as_str())
+                .or_default()
+                .push(dep.from_chunk_id.as_str());
+        }
+
+        let mut visited: HashSet<&str> = roots.iter().copied().collect();
+        let mut queue: VecDeque<(&str, usize)> = roots.iter().map(|r| (*r, 0)).collect();
+        let mut result = HashSet::new();
+
+        while let Some((id, depth)) = queue.pop_front() {
+            if depth >= max_depth {
+                continue;
+            }
+            for &caller in reverse.get(id).into_iter().flatten() {
+                if visited.insert(caller) {
+                    result.insert(caller.to_string());
+                    queue.push_back((caller, depth + 1));
+                }
+            }
+        }
+        result
+    }
+
+    fn findings(&self) -> Vec<ReportFinding> {
+        let mut findings: Vec<ReportFinding> = self.chunks.values().flat_map(findings_of).collect();
+        findings.sort_by(|a, b| {
+            (&a.file_path, a.line, &a.rule_id).cmp(&(&b.file_path, b.line, &b.rule_id))
+        });
+        findings
+    }
+}
+
+/// Package of a file: its directory ("." for the repository root)
+fn package_of(file_path: &str) -> String {
+    match Path::new(file_path).parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.to_string_lossy().replace('\\', "/"),
+        _ => ".".to_string(),
+    }
+}
+
+fn findings_of(chunk: &Chunk) -> Vec<ReportFinding> {
+    let Some(Value::Array(entries)) = chunk.attrs.get(FINDINGS_ATTR) else {
+        return Vec::new();
+    };
+
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let text = |key: &str| entry.get(key).and_then(Value::as_str).map(str::to_string);
+            Some(ReportFinding {
+                rule_id: text("rule_id").or_else(|| text("rule"))?,
+                severity: text("severity").unwrap_or_else(|| "medium".to_string()),
+                message: text("message").unwrap_or_default(),
+                file_path: chunk.file_path.clone(),
+                symbol: chunk.fqn.clone(),
+                line: entry
+                    .get("line")
+                    .and_then(Value::as_u64)
+                    .map(|l| l as u32)
+                    .or(Some(chunk.start_line)),
+            })
+        })
+        .collect()
+}
+
+impl SnapshotReport {
+    /// Build a report with default options
+    pub async fn build(
+        store: &CodeSnapshotStore,
+        repo_id: &str,
+        base_snapshot: &str,
+        head_snapshot: &str,
+    ) -> Result<Self> {
+        Self::build_with_options(
+            store,
+            repo_id,
+            base_snapshot,
+            head_snapshot,
+            &SnapshotReportOptions::default(),
+        )
+        .await
+    }
+
+    /// Build a report
+    pub async fn build_with_options(
+        store: &CodeSnapshotStore,
+        repo_id: &str,
+        base_snapshot: &str,
+        head_snapshot: &str,
+        options: &SnapshotReportOptions,
+    ) -> Result<Self> {
+        let diff = store
+            .compare_commits(repo_id, base_snapshot, head_snapshot)
+            .await?;
+        let base = SnapshotGraph::load(store, repo_id, base_snapshot).await?;
+        let head = SnapshotGraph::load(store, repo_id, head_snapshot).await?;
+
+        // 1. Symbols
+        let symbol = |chunk: &Chunk, change| SymbolChange {
+            fqn: chunk.fqn.clone().unwrap_or_default(),
+            kind: chunk.kind.clone(),
+            file_path: chunk.file_path.clone(),
+            start_line: chunk.start_line,
+            end_line: chunk.end_line,
+            change,
+        };
+        let mut symbols: Vec<SymbolChange> = diff
+            .added
+            .iter()
+            .map(|c| symbol(c, ChangeKind::Added))
+            .chain(diff.deleted.iter().map(|c| symbol(c, ChangeKind::Removed)))
+            .chain(
+                diff.modified
+                    .iter()
+                    .map(|(_, new)| symbol(new, ChangeKind::Modified)),
+            )
+            .collect();
+        symbols.sort_by(|a, b| (&a.file_path, a.start_line).cmp(&(&b.file_path, b.start_line)));
+
+        // 2. Package dependencies
+        let base_edges = base.package_edges();
+        let head_edges = head.package_edges();
+        let mut package_dependencies: Vec<PackageDependencyChange> = base_edges
+            .keys()
+            .chain(head_edges.keys())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .filter_map(|key| {
+                let base_count = base_edges.get(key).copied().unwrap_or(0);
+                let head_count = head_edges.get(key).copied().unwrap_or(0);
+                (base_count != head_count).then(|| PackageDependencyChange {
+                    from_package: key.0.clone(),
+                    to_package: key.1.clone(),
+                    base_count,
+                    head_count,
+                })
+            })
+            .collect();
+        package_dependencies.sort_by(|a, b| {
+            (&a.from_package, &a.to_package).cmp(&(&b.from_package, &b.to_package))
+        });
+
+        // 3. Hot zones: added/modified symbols impact head dependents,
+        //    removed symbols impact their former dependents in base
+        let mut changed_by_file: BTreeMap<&str, (Vec<&str>, Vec<&str>)> = BTreeMap::new();
+        for chunk in diff
+            .added
+            .iter()
+            .chain(diff.modified.iter().map(|(_, c)| c))
+        {
+            let entry = changed_by_file.entry(&chunk.file_path).or_default();
+            entry.0.push(&chunk.chunk_id);
+        }
+        for chunk in &diff.deleted {
+            let entry = changed_by_file.entry(&chunk.file_path).or_default();
+            entry.1.push(&chunk.chunk_id);
+        }
+
+        let mut hot_zones: Vec<ImpactHotZone> = changed_by_file
+            .into_iter()
+            .map(|(file_path, (head_roots, base_roots))| {
+                let mut files = HashSet::new();
+                let mut impacted = HashSet::new();
+                for (graph, roots) in [(&head, &head_roots), (&base, &base_roots)] {
+                    for id in graph.dependents(roots, options.impact_depth) {
+                        files.insert(graph.chunks[&id].file_path.clone());
+                        impacted.insert(graph.chunks[&id].fqn.clone().unwrap_or(id));
+                    }
+                }
+                ImpactHotZone {
+                    file_path: file_path.to_string(),
+                    changed_symbols: head_roots.len() + base_roots.len(),
+                    impacted_symbols: impacted.len(),
+                    impacted_files: files.len(),
+                }
+            })
+            .collect();
+        hot_zones.sort_by(|a, b| {
+            (b.impacted_symbols + b.changed_symbols)
+                .cmp(&(a.impacted_symbols + a.changed_symbols))
+                .then_with(|| a.file_path.cmp(&b.file_path))
+        });
+        hot_zones.truncate(options.max_hot_zones);
+
+        // 4. Findings
+        let base_findings = base.findings();
+        let head_findings = head.findings();
+        let base_keys: HashSet<_> = base_findings.iter().map(ReportFinding::key).collect();
+        let head_keys: HashSet<_> = head_findings.iter().map(ReportFinding::key).collect();
+        let findings = FindingDelta {
+            introduced: head_findings
+                .iter()
+                .filter(|f| !base_keys.contains(&f.key()))
+                .cloned()
+                .collect(),
+            resolved: base_findings
+                .iter()
+                .filter(|f| !head_keys.contains(&f.key()))
+                .cloned()
+                .collect(),
+        };
+
+        Ok(Self {
+            repo_id: repo_id.to_string(),
+            base_snapshot: base_snapshot.to_string(),
+            head_snapshot: head_snapshot.to_string(),
+            generated_at: Utc::now(),
+            symbols,
+            package_dependencies,
+            hot_zones,
+            findings,
+        })
+    }
+
+    /// Number of symbols with the given change kind
+    pub fn count(&self, change: ChangeKind) -> usize {
+        self.symbols.iter().filter(|s| s.change == change).count()
+    }
+
+    /// One-line summary (for CI logs)
+    pub fn summary(&self) -> String {
+        format!(
+            "symbols +{} -{} ~{}, package deps changed: {}, findings +{} -{}",
+            self.count(ChangeKind::Added),
+            self.count(ChangeKind::Removed),
+            self.count(ChangeKind::Modified),
+            self.package_dependencies.len(),
+            self.findings.introduced.len(),
+            self.findings.resolved.len()
+        )
+    }
+
+    /// Render as a self-contained HTML page (English, UTF-8)
+    pub fn to_html(&self) -> String {
+        self.to_html_with(&ReportOptions::default())
+    }
+
+    /// Render as a self-contained HTML page in the given locale / encoding
+    pub fn to_html_with(&self, report: &ReportOptions) -> String {
+        let t = |id| escape(report.text(id));
+        let mut html = String::with_capacity(16 * 1024);
+        let title = format!(
+            "{}: {} → {}",
+            self.repo_id, self.base_snapshot, self.head_snapshot
+        );
+        let timestamp = self
+            .generated_at
+            .format("%Y-%m-%d %H:%M:%S UTC")
+            .to_string();
+        let generated = report.format(MessageId::SnapshotGenerated, &[&timestamp]);
+
+        let _ = write!(
+            html,
+            "<!DOCTYPE html>\n<html lang=\"{}\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>{} - {}</title>\n<style>{}</style>\n</head>\n<body>\n\
+             <h1>{}</h1>\n<p class=\"meta\">{} &middot; {}</p>\n",
+            report.locale.as_str(),
+            t(MessageId::SnapshotTitle),
+            escape(&title),
+            REPORT_CSS,
+            t(MessageId::SnapshotTitle),
+            escape(&title),
+            escape(&generated)
+        );
+
+        // Summary cards
+        html.push_str("<div class=\"cards\">\n");
+        for (label, value, class) in [
+            (
+                MessageId::SnapshotSymbolsAdded,
+                self.count(ChangeKind::Added),
+                "added",
+            ),
+            (
+                MessageId::SnapshotSymbolsRemoved,
+                self.count(ChangeKind::Removed),
+                "removed",
+            ),
+            (
+                MessageId::SnapshotSymbolsModified,
+                self.count(ChangeKind::Modified),
+                "modified",
+            ),
+            (
+                MessageId::SnapshotPackageDepsChanged,
+                self.package_dependencies.len(),
+                "modified",
+            ),
+            (
+                MessageId::SnapshotFindingsIntroduced,
+                self.findings.introduced.len(),
+                "removed",
+            ),
+            (
+                MessageId::SnapshotFindingsResolved,
+                self.findings.resolved.len(),
+                "added",
+            ),
+        ] {
+            let _ = writeln!(
+                html,
+                "<div class=\"card {}\"><span>{}</span><b>{}</b></div>",
+                class,
+                t(label),
+                value
+            );
+        }
+        html.push_str("</div>\n");
+
+        // Hot zones
+        let _ = writeln!(html, "<h2>{}</h2>", t(MessageId::SnapshotHotZones));
+        if self.hot_zones.is_empty() {
+            let _ = writeln!(
+                html,
+                "<p class=\"empty\">{}</p>",
+                t(MessageId::SnapshotNoChangedSymbols)
+            );
+        } else {
+            let max_score = self
+                .hot_zones
+                .iter()
+                .map(|z| z.changed_symbols + z.impacted_symbols)
+                .max()
+                .unwrap_or(1)
+                .max(1);
+            let _ = writeln!(
+                html,
+                "<table><tr><th>{}</th><th>{}</th><th>{}</th><th>{}</th><th></th></tr>",
+                t(MessageId::ColumnFile),
+                t(MessageId::SnapshotChangedColumn),
+                t(MessageId::SnapshotImpactedSymbols),
+                t(MessageId::SnapshotImpactedFiles)
+            );
+            for zone in &self.hot_zones {
+                let width = (zone.changed_symbols + zone.impacted_symbols) * 100 / max_score;
+                let _ = writeln!(
+                    html,
+                    "<tr><td><code>{}</code></td><td>{}</td><td>{}</td><td>{}</td>\
+                     <td class=\"bar\"><div style=\"width:{}%\"></div></td></tr>",
+                    escape(&zone.file_path),
+                    zone.changed_symbols,
+                    zone.impacted_symbols,
+                    zone.impacted_files,
+                    width
+                );
+            }
+            html.push_str("</table>\n");
+        }
+
+        // Symbols
+        let _ = writeln!(html, "<h2>{}</h2>", t(MessageId::SnapshotSymbolChanges));
+        if self.symbols.is_empty() {
+            let _ = writeln!(
+                html,
+                "<p class=\"empty\">{}</p>",
+                t(MessageId::SnapshotNoSymbolChanges)
+            );
+        } else {
+            let _ = writeln!(
+                html,
+                "<table><tr><th></th><th>{}</th><th>{}</th><th>{}</th></tr>",
+                t(MessageId::ColumnSymbol),
+                t(MessageId::ColumnKind),
+                t(MessageId::ColumnLocation)
+            );
+            for symbol in &self.symbols {
+                let _ = writeln!(
+                    html,
+                    "<tr class=\"{}\"><td>{}</td><td><code>{}</code></td><td>{}</td>\
+                     <td><code>{}:{}-{}</code></td></tr>",
+                    symbol.change.as_str(),
+                    t(symbol.change.label()),
+                    escape(&symbol.fqn),
+                    escape(&symbol.kind),
+                    escape(&symbol.file_path),
+                    symbol.start_line,
+                    symbol.end_line
+                );
+            }
+            html.push_str("</table>\n");
+        }
+
+        // Package dependencies
+        let _ = writeln!(html, "<h2>{}</h2>", t(MessageId::SnapshotPackageChanges));
+        if self.package_dependencies.is_empty() {
+            let _ = writeln!(
+                html,
+                "<p class=\"empty\">{}</p>",
+                t(MessageId::SnapshotNoPackageChanges)
+            );
+        } else {
+            let _ = writeln!(
+                html,
+                "<table><tr><th></th><th>{}</th><th>{}</th><th>{}</th><th>{}</th></tr>",
+                t(MessageId::ColumnFrom),
+                t(MessageId::ColumnTo),
+                t(MessageId::ColumnBase),
+                t(MessageId::ColumnHead)
+            );
+            for dep in &self.package_dependencies {
+                let _ = writeln!(
+                    html,
+                    "<tr class=\"{}\"><td>{}</td><td><code>{}</code></td>\
+                     <td><code>{}</code></td><td>{}</td><td>{}</td></tr>",
+                    dep.change().as_str(),
+                    t(dep.change().label()),
+                    escape(&dep.from_package),
+                    escape(&dep.to_package),
+                    dep.base_count,
+                    dep.head_count
+                );
+            }
+            html.push_str("</table>\n");
+        }
+
+        // Findings
+        let _ = writeln!(html, "<h2>{}</h2>", t(MessageId::SnapshotFindingDeltas));
+        if self.findings.introduced.is_empty() && self.findings.resolved.is_empty() {
+            let _ = writeln!(
+                html,
+                "<p class=\"empty\">{}</p>",
+                t(MessageId::SnapshotNoFindingChanges)
+            );
+        } else {
+            let _ = writeln!(
+                html,
+                "<table><tr><th></th><th>{}</th><th>{}</th><th>{}</th><th>{}</th></tr>",
+                t(MessageId::ColumnSeverity),
+                t(MessageId::ColumnRule),
+                t(MessageId::ColumnLocation),
+                t(MessageId::ColumnMessage)
+            );
+            for (status, class, finding) in self
+                .findings
+                .introduced
+                .iter()
+                .map(|f| (MessageId::SnapshotFindingIntroduced, "removed", f))
+                .chain(
+                    self.findings
+                        .resolved
+                        .iter()
+                        .map(|f| (MessageId::SnapshotFindingResolved, "added", f)),
+                )
+            {
+                let location = match finding.line {
+                    Some(line) => format!("{}:{}", finding.file_path, line),
+                    None => finding.file_path.clone(),
+                };
+                let _ = writeln!(
+                    html,
+                    "<tr class=\"{}\"><td>{}</td><td>{}</td><td><code>{}</code></td>\
+                     <td><code>{}</code></td><td>{}</td></tr>",
+                    class,
+                    t(status),
+                    escape(&finding.severity),
+                    escape(&finding.rule_id),
+                    escape(&location),
+                    escape(&finding.message)
+                );
+            }
+            html.push_str("</table>\n");
+        }
+
+        html.push_str("</body>\n</html>\n");
+        report.encoding.encode_html(html)
+    }
+
+    /// Serialize as JSON (machine-readable companion to the HTML report)
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+const REPORT_CSS: &str = "body{font-family:-apple-system,Segoe UI,Helvetica,Arial,sans-serif;\
+margin:2em;color:#24292f}h1{margin-bottom:0}.meta{color:#57606a}\
+.cards{display:flex;flex-wrap:wrap;gap:1em;margin:1.5em 0}\
+.card{border:1px solid #d0d7de;border-radius:6px;padding:.8em 1.2em;min-width:9em}\
+.card span{display:block;font-size:.85em;color:#57606a}.card b{font-size:1.6em}\
+.card.added b{color:#1a7f37}.card.removed b{color:#cf222e}.card.modified b{color:#9a6700}\
+table{border-collapse:collapse;width:100%;margin-bottom:2em;font-size:.9em}\
+th,td{border-bottom:1px solid #d0d7de;padding:.35em .6em;text-align:left}\
+tr.added td:first-child{color:#1a7f37}tr.removed td:first-child{color:#cf222e}\
+tr.modified td:first-child{color:#9a6700}.empty{color:#57606a;font-style:italic}\
+td.bar{width:25%}td.bar div{background:#fd8c73;height:.8em;border-radius:3px}";
+
+/// Minimal HTML escaping for text and attribute content
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::storage::domain::models::DependencyType;
+    use crate::features::storage::domain::ports::ChunkStore;
+    use crate::features::storage::infrastructure::InMemoryChunkStore;
+    use crate::shared::i18n::{Locale, ReportEncoding};
+
+    fn chunk(snapshot: &str, file: &str, fqn: &str, content: &str) -> Chunk {
+        let mut chunk = Chunk::new(
+            "repo".into(),
+            snapshot.into(),
+            file.into(),
+            1,
+            5,
+            "function".into(),
+            content.into(),
+        );
+        chunk.chunk_id = format!("{}:{}", snapshot, fqn);
+        chunk.fqn = Some(fqn.to_string());
+        chunk
+    }
+
+    fn dep(snapshot: &str, from: &str, to: &str) -> Dependency {
+        Dependency {
+            id: format!("{}:{}->{}", snapshot, from, to),
+            from_chunk_id: format!("{}:{}", snapshot, from),
+            to_chunk_id: format!("{}:{}", snapshot, to),
+            relationship: DependencyType::Calls,
+            confidence: 1.0,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_report_sections() {
+        let store = InMemoryChunkStore::new();
+
+        let mut login_base = chunk("base", "auth/login.py", "auth.login", "v1");
+        login_base.attrs.insert(
+            FINDINGS_ATTR.into(),
+            serde_json::json!([{"rule_id": "sql-injection", "severity": "high", "message": "tainted query"}]),
+        );
+        store
+            .save_chunks(&[
+                login_base,
+                chunk("base", "api/views.py", "api.view", "v1"),
+                chunk("base", "util/old.py", "util.old", "v1"),
+            ])
+            .await
+            .unwrap();
+        store
+            .save_dependencies(&[dep("base", "api.view", "auth.login")])
+            .await
+            .unwrap();
+
+        let mut login_head = chunk("head", "auth/login.py", "auth.login", "v2");
+        login_head.attrs.insert(
+            FINDINGS_ATTR.into(),
+            serde_json::json!([{"rule_id": "xss", "severity": "medium", "message": "<script>"}]),
+        );
+        store
+            .save_chunks(&[
+                login_head,
+                chunk("head", "api/views.py", "api.view", "v1"),
+                chunk("head", "util/new.py", "util.new", "v1"),
+            ])
+            .await
+            .unwrap();
+        store
+            .save_dependencies(&[
+                dep("head", "api.view", "auth.login"),
+                dep("head", "api.view", "util.new"),
+            ])
+            .await
+            .unwrap();
+
+        let snapshots = CodeSnapshotStore::new(store);
+        let report = SnapshotReport::build(&snapshots, "repo", "base", "head")
+            .await
+            .unwrap();
+
+        assert_eq!(report.count(ChangeKind::Added), 1);
+        assert_eq!(report.count(ChangeKind::Removed), 1);
+        assert_eq!(report.count(ChangeKind::Modified), 1);
+
+        // api → util is new; api → auth unchanged
+        assert_eq!(report.package_dependencies.len(), 1);
+        assert_eq!(report.package_dependencies[0].to_package, "util");
+        assert_eq!(report.package_dependencies[0].change(), ChangeKind::Added);
+
+        let login_zone = report
+            .hot_zones
+            .iter()
+            .find(|z| z.file_path == "auth/login.py")
+            .unwrap();
+        assert_eq!(login_zone.impacted_symbols, 1);
+        assert_eq!(login_zone.impacted_files, 1);
+
+        assert_eq!(report.findings.introduced.len(), 1);
+        assert_eq!(report.findings.introduced[0].rule_id, "xss");
+        assert_eq!(report.findings.resolved[0].rule_id, "sql-injection");
+
+        let html = report.to_html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("auth.login"));
+        assert!(html.contains("&lt;script&gt;"));
+        assert!(!html.contains("<script>"));
+
+        let korean = ReportOptions::default().with_locale(Locale::Ko);
+        let html = report.to_html_with(&korean);
+        assert!(html.contains("<html lang=\"ko\">"));
+        assert!(html.contains("<h2>심볼 변경</h2>"));
+        let ascii = korean.with_encoding(ReportEncoding::Ascii);
+        assert!(report.to_html_with(&ascii).is_ascii());
+    }
+
+    #[test]
+    fn test_package_of() {
+        assert_eq!(package_of("src/auth/login.py"), "src/auth");
+        assert_eq!(package_of("main.py"), ".");
+    }
+}
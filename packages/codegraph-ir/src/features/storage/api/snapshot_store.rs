@@ -19,6 +19,7 @@ use chrono::Utc;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use super::lazy_snapshot::LazySnapshot;
 use super::snapshot_diff::{SnapshotDiff, SnapshotStats as SnapshotCreationStats};
 use crate::features::storage::domain::{
     models::{Chunk, ChunkId, Dependency, Snapshot},
@@ -279,6 +280,11 @@ impl CodeSnapshotStore {
         self.store.list_snapshots(repo_id).await
     }
 
+    /// Open a snapshot for per-file loading on demand
+    pub fn lazy(&self, repo_id: &str, snapshot_id: &str) -> LazySnapshot {
+        LazySnapshot::new(self.store.clone(), repo_id, snapshot_id)
+    }
+
     /// Get all chunks for a snapshot
     pub async fn get_chunks(&self, repo_id: &str, snapshot_id: &str) -> Result<Vec<Chunk>> {
         self.store.get_chunks(repo_id, snapshot_id).await
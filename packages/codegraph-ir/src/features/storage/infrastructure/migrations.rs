@@ -0,0 +1,380 @@
+//! Embedded schema migrations
+//!
+//! Each backend ships an ordered list of `Migration`s (versions 1..=N) and
+//! records the ones it applied in a `schema_migrations` table together with
+//! a checksum of their SQL. On open, a store:
+//!
+//! 1. reads the applied versions
+//! 2. fails if an applied migration's SQL changed since, or if the database
+//!    is newer than this build
+//! 3. runs the pending migrations in order, one transaction each
+//!
+//! A dry run stops after step 2 and reports what would run.
+
+use crate::shared::models::{CodegraphError, Result};
+
+/// One schema upgrade step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Migration {
+    pub version: u32,
+    pub name: &'static str,
+    pub sql: &'static str,
+}
+
+impl Migration {
+    /// Checksum of the SQL (detects migrations edited after release)
+    pub fn checksum(&self) -> String {
+        blake3::hash(self.sql.as_bytes()).to_hex().to_string()
+    }
+}
+
+/// Row of the `schema_migrations` table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedMigration {
+    pub version: u32,
+    pub name: String,
+    pub checksum: String,
+}
+
+/// Outcome of `migrate` / `migrate_dry_run`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// Schema version before the run
+    pub from_version: u32,
+    /// Schema version after the run (for a dry run, the one it would reach)
+    pub to_version: u32,
+    /// `(version, name)` of each migration run, or to be run, in order
+    pub steps: Vec<(u32, &'static str)>,
+    pub dry_run: bool,
+}
+
+impl MigrationReport {
+    pub fn is_up_to_date(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+/// SQLite schema
+///
+/// Version 1 is the schema stores created before migrations existed; its
+/// `IF NOT EXISTS` statements make it a no-op on those databases.
+pub const SQLITE_MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    name: "initial_schema",
+    sql: "CREATE TABLE IF NOT EXISTS repositories (
+            repo_id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            remote_url TEXT,
+            local_path TEXT,
+            default_branch TEXT NOT NULL,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS snapshots (
+            snapshot_id TEXT PRIMARY KEY,
+            repo_id TEXT NOT NULL,
+            commit_hash TEXT,
+            branch_name TEXT,
+            created_at INTEGER NOT NULL,
+            FOREIGN KEY (repo_id) REFERENCES repositories(repo_id)
+        );
+
+        CREATE TABLE IF NOT EXISTS chunks (
+            chunk_id TEXT PRIMARY KEY,
+            repo_id TEXT NOT NULL,
+            snapshot_id TEXT NOT NULL,
+            file_path TEXT NOT NULL,
+            start_line INTEGER NOT NULL,
+            end_line INTEGER NOT NULL,
+            kind TEXT NOT NULL,
+            fqn TEXT,
+            language TEXT NOT NULL,
+            symbol_visibility TEXT,
+            content TEXT NOT NULL,
+            content_hash TEXT NOT NULL,
+            summary TEXT,
+            importance REAL NOT NULL DEFAULT 0.5,
+            is_deleted BOOLEAN NOT NULL DEFAULT 0,
+            attrs TEXT,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL,
+            FOREIGN KEY (repo_id) REFERENCES repositories(repo_id),
+            FOREIGN KEY (snapshot_id) REFERENCES snapshots(snapshot_id)
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_chunks_repo_snapshot
+            ON chunks(repo_id, snapshot_id, is_deleted);
+        CREATE INDEX IF NOT EXISTS idx_chunks_file
+            ON chunks(repo_id, snapshot_id, file_path, is_deleted);
+        CREATE INDEX IF NOT EXISTS idx_chunks_fqn
+            ON chunks(fqn, is_deleted);
+
+        CREATE TABLE IF NOT EXISTS dependencies (
+            id TEXT PRIMARY KEY,
+            from_chunk_id TEXT NOT NULL,
+            to_chunk_id TEXT NOT NULL,
+            relationship TEXT NOT NULL,
+            confidence REAL NOT NULL DEFAULT 1.0,
+            created_at INTEGER NOT NULL,
+            FOREIGN KEY (from_chunk_id) REFERENCES chunks(chunk_id),
+            FOREIGN KEY (to_chunk_id) REFERENCES chunks(chunk_id)
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_deps_from ON dependencies(from_chunk_id);
+        CREATE INDEX IF NOT EXISTS idx_deps_to ON dependencies(to_chunk_id);
+
+        -- File metadata (for incremental indexing)
+        CREATE TABLE IF NOT EXISTS file_metadata (
+            repo_id TEXT NOT NULL,
+            snapshot_id TEXT NOT NULL,
+            file_path TEXT NOT NULL,
+            content_hash TEXT NOT NULL,
+            updated_at INTEGER NOT NULL,
+            PRIMARY KEY (repo_id, snapshot_id, file_path),
+            FOREIGN KEY (repo_id) REFERENCES repositories(repo_id),
+            FOREIGN KEY (snapshot_id) REFERENCES snapshots(snapshot_id)
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_file_metadata_hash ON file_metadata(content_hash);",
+}];
+
+/// PostgreSQL schema
+///
+/// Version 1 is the schema earlier deployments created with
+/// `sqlx migrate run`; those databases are baselined at version 1.
+pub const POSTGRES_MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    name: "initial_schema",
+    sql: include_str!("../../../../migrations/20250101000001_initial_schema.sql"),
+}];
+
+/// Migrations still to run on a database with `applied` recorded
+pub fn pending<'a>(
+    migrations: &'a [Migration],
+    applied: &[AppliedMigration],
+) -> Result<Vec<&'a Migration>> {
+    for (index, migration) in migrations.iter().enumerate() {
+        if migration.version != index as u32 + 1 {
+            return Err(CodegraphError::internal(format!(
+                "Migration {} ({}) is out of order: expected version {}",
+                migration.version,
+                migration.name,
+                index + 1
+            )));
+        }
+    }
+
+    for record in applied {
+        let migration = migrations
+            .get((record.version as usize).wrapping_sub(1))
+            .ok_or_else(|| {
+                CodegraphError::storage(format!(
+                    "Database schema version {} ({}) is newer than this build (latest {})",
+                    record.version,
+                    record.name,
+                    migrations.len()
+                ))
+            })?;
+        let checksum = migration.checksum();
+        if record.checksum != checksum {
+            return Err(CodegraphError::storage(format!(
+                "Migration {} ({}) changed after it was applied: checksum {} != {}",
+                record.version, record.name, record.checksum, checksum
+            )));
+        }
+    }
+
+    let current = schema_version(applied);
+    Ok(migrations.iter().filter(|m| m.version > current).collect())
+}
+
+/// Highest applied version (0 for an empty database)
+pub fn schema_version(applied: &[AppliedMigration]) -> u32 {
+    applied.iter().map(|m| m.version).max().unwrap_or(0)
+}
+
+/// Report for running `steps` on a database at `from_version`
+pub fn report(from_version: u32, steps: &[&Migration], dry_run: bool) -> MigrationReport {
+    MigrationReport {
+        from_version,
+        to_version: steps.last().map_or(from_version, |m| m.version),
+        steps: steps.iter().map(|m| (m.version, m.name)).collect(),
+        dry_run,
+    }
+}
+
+/// PostgreSQL runner (`PostgresChunkStore::migrate`)
+#[cfg(feature = "postgres")]
+pub mod postgres {
+    use super::*;
+    use sqlx::{PgPool, Row};
+
+    fn db_error(e: sqlx::Error) -> CodegraphError {
+        CodegraphError::storage(format!("Migration failed: {}", e))
+    }
+
+    async fn table_exists(pool: &PgPool, table: &str) -> Result<bool> {
+        sqlx::query_scalar::<_, bool>("SELECT to_regclass($1) IS NOT NULL")
+            .bind(table)
+            .fetch_one(pool)
+            .await
+            .map_err(db_error)
+    }
+
+    async fn applied(pool: &PgPool) -> Result<Vec<AppliedMigration>> {
+        if !table_exists(pool, "schema_migrations").await? {
+            return Ok(Vec::new());
+        }
+        let rows =
+            sqlx::query("SELECT version, name, checksum FROM schema_migrations ORDER BY version")
+                .fetch_all(pool)
+                .await
+                .map_err(db_error)?;
+        rows.iter()
+            .map(|row| {
+                Ok(AppliedMigration {
+                    version: row.try_get::<i32, _>("version").map_err(db_error)? as u32,
+                    name: row.try_get("name").map_err(db_error)?,
+                    checksum: row.try_get("checksum").map_err(db_error)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Current schema version
+    pub async fn schema_version(pool: &PgPool) -> Result<u32> {
+        Ok(super::schema_version(&applied(pool).await?))
+    }
+
+    /// Bring the schema up to date (or, with `dry_run`, only report)
+    pub async fn migrate(pool: &PgPool, dry_run: bool) -> Result<MigrationReport> {
+        let mut applied = applied(pool).await?;
+        // Databases set up with `sqlx migrate run` already have version 1
+        let baseline = applied.is_empty() && table_exists(pool, "repositories").await?;
+        if baseline {
+            let initial = &POSTGRES_MIGRATIONS[0];
+            applied.push(AppliedMigration {
+                version: initial.version,
+                name: initial.name.to_string(),
+                checksum: initial.checksum(),
+            });
+        }
+
+        let steps = pending(POSTGRES_MIGRATIONS, &applied)?;
+        let report = report(super::schema_version(&applied), &steps, dry_run);
+        if dry_run {
+            return Ok(report);
+        }
+
+        sqlx::raw_sql(
+            "CREATE TABLE IF NOT EXISTS schema_migrations (
+                version INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                checksum TEXT NOT NULL,
+                applied_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+            )",
+        )
+        .execute(pool)
+        .await
+        .map_err(db_error)?;
+
+        if baseline {
+            let mut tx = pool.begin().await.map_err(db_error)?;
+            record(&mut tx, &POSTGRES_MIGRATIONS[0]).await?;
+            tx.commit().await.map_err(db_error)?;
+        }
+        for migration in steps {
+            let mut tx = pool.begin().await.map_err(db_error)?;
+            sqlx::raw_sql(migration.sql)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_error)?;
+            record(&mut tx, migration).await?;
+            tx.commit().await.map_err(db_error)?;
+        }
+
+        Ok(report)
+    }
+
+    async fn record(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        migration: &Migration,
+    ) -> Result<()> {
+        sqlx::query("INSERT INTO schema_migrations (version, name, checksum) VALUES ($1, $2, $3)")
+            .bind(migration.version as i32)
+            .bind(migration.name)
+            .bind(migration.checksum())
+            .execute(&mut **tx)
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIGRATIONS: &[Migration] = &[
+        Migration {
+            version: 1,
+            name: "initial",
+            sql: "CREATE TABLE a (id TEXT)",
+        },
+        Migration {
+            version: 2,
+            name: "add_b",
+            sql: "CREATE TABLE b (id TEXT)",
+        },
+    ];
+
+    fn applied(migration: &Migration) -> AppliedMigration {
+        AppliedMigration {
+            version: migration.version,
+            name: migration.name.to_string(),
+            checksum: migration.checksum(),
+        }
+    }
+
+    #[test]
+    fn test_pending_after_applied_prefix() {
+        let all = pending(MIGRATIONS, &[]).unwrap();
+        assert_eq!(all.len(), 2);
+
+        let rest = pending(MIGRATIONS, &[applied(&MIGRATIONS[0])]).unwrap();
+        assert_eq!(rest, vec![&MIGRATIONS[1]]);
+
+        let report = report(1, &rest, true);
+        assert_eq!(report.to_version, 2);
+        assert_eq!(report.steps, vec![(2, "add_b")]);
+        assert!(pending(
+            MIGRATIONS,
+            &[applied(&MIGRATIONS[0]), applied(&MIGRATIONS[1])]
+        )
+        .unwrap()
+        .is_empty());
+    }
+
+    #[test]
+    fn test_checksum_mismatch_and_newer_database_fail() {
+        let mut edited = applied(&MIGRATIONS[0]);
+        edited.checksum = "0".repeat(64);
+        assert!(pending(MIGRATIONS, &[edited]).is_err());
+
+        let newer = AppliedMigration {
+            version: 3,
+            name: "future".to_string(),
+            checksum: String::new(),
+        };
+        assert!(pending(MIGRATIONS, &[newer]).is_err());
+    }
+
+    #[test]
+    fn test_out_of_order_list_is_rejected() {
+        let gap = [MIGRATIONS[1]];
+        assert!(pending(&gap, &[]).is_err());
+        assert_eq!(pending(SQLITE_MIGRATIONS, &[]).unwrap().len(), 1);
+        assert_eq!(pending(POSTGRES_MIGRATIONS, &[]).unwrap().len(), 1);
+    }
+}
@@ -3,7 +3,9 @@
 //! Multiple storage backends for ChunkStore trait
 
 pub mod memory_store;
+pub mod migrations; // Versioned schema upgrades (SQLite/PostgreSQL)
 pub use memory_store::InMemoryChunkStore;
+pub use migrations::{Migration, MigrationReport};
 
 #[cfg(feature = "sqlite")]
 pub mod sqlite_store;
@@ -3,7 +3,7 @@
 //! SOTA Production Design:
 //! - Multi-user concurrency: MVCC transactions
 //! - Connection pooling: PgPool for efficient resource management
-//! - Schema migrations: sqlx migrate for version control
+//! - Schema migrations: embedded, versioned and checksummed (see `migrations`)
 //! - Full-text search: Native PostgreSQL GIN indexes
 //! - ACID guarantees with better scalability
 
@@ -19,6 +19,7 @@ use crate::features::storage::domain::{
     Chunk, ChunkFilter, ChunkId, ChunkStore, Dependency, DependencyType, IncrementalUpdateResult,
     Repository, RepoId, Snapshot, SnapshotId, StorageStats,
 };
+use crate::features::storage::infrastructure::migrations::{self, MigrationReport};
 use crate::shared::models::{CodegraphError, Result};
 
 /// PostgreSQL Chunk Store (Production/Server)
@@ -63,14 +64,26 @@ impl PostgresChunkStore {
             .map_err(|e| CodegraphError::storage(format!("Failed to connect to PostgreSQL: {}", e)))?;
 
         let store = Self { pool };
-
-        // Note: Migrations must be run manually using `sqlx migrate run`
-        // This avoids dependency conflicts with rusqlite
-        // See README.md for migration instructions
+        store.migrate().await?;
 
         Ok(store)
     }
 
+    /// Current schema version (0 for an empty database)
+    pub async fn schema_version(&self) -> Result<u32> {
+        migrations::postgres::schema_version(&self.pool).await
+    }
+
+    /// Apply pending migrations
+    pub async fn migrate(&self) -> Result<MigrationReport> {
+        migrations::postgres::migrate(&self.pool, false).await
+    }
+
+    /// Report pending migrations without applying them
+    pub async fn migrate_dry_run(&self) -> Result<MigrationReport> {
+        migrations::postgres::migrate(&self.pool, true).await
+    }
+
     /// Get connection pool reference (for advanced usage)
     pub fn pool(&self) -> &PgPool {
         &self.pool
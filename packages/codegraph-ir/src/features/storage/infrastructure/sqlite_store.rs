@@ -1,7 +1,7 @@
-///! SQLite Chunk Store
-///!
-///! File-based persistent storage using SQLite.
-///! Suitable for local development and testing.
+//! SQLite Chunk Store
+//!
+//! File-based persistent storage using SQLite.
+//! Suitable for local development and testing.
 use async_trait::async_trait;
 use rusqlite::{params, Connection, OptionalExtension};
 use std::path::Path;
@@ -9,6 +9,9 @@ use std::sync::{Arc, Mutex};
 
 use crate::features::storage::domain::models::{Chunk, Dependency, Repository, Snapshot};
 use crate::features::storage::domain::ports::{ChunkStore, StorageStats};
+use crate::features::storage::infrastructure::migrations::{
+    self, AppliedMigration, Migration, MigrationReport, SQLITE_MIGRATIONS,
+};
 use crate::shared::models::Result;
 
 /// SQLite-based ChunkStore implementation
@@ -24,7 +27,7 @@ impl SqliteChunkStore {
         let store = Self {
             conn: Arc::new(Mutex::new(conn)),
         };
-        store.init_schema()?;
+        store.migrate()?;
         Ok(store)
     }
 
@@ -34,136 +37,94 @@ impl SqliteChunkStore {
         let store = Self {
             conn: Arc::new(Mutex::new(conn)),
         };
-        store.init_schema()?;
+        store.migrate()?;
         Ok(store)
     }
 
-    /// Initialize database schema
-    fn init_schema(&self) -> Result<()> {
-        let conn = self.conn.lock().unwrap();
-
-        // Repositories table
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS repositories (
-                repo_id TEXT PRIMARY KEY,
-                name TEXT NOT NULL,
-                remote_url TEXT,
-                local_path TEXT,
-                default_branch TEXT NOT NULL,
-                created_at INTEGER NOT NULL,
-                updated_at INTEGER NOT NULL
-            )",
-            [],
-        )?;
-
-        // Snapshots table
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS snapshots (
-                snapshot_id TEXT PRIMARY KEY,
-                repo_id TEXT NOT NULL,
-                commit_hash TEXT,
-                branch_name TEXT,
-                created_at INTEGER NOT NULL,
-                FOREIGN KEY (repo_id) REFERENCES repositories(repo_id)
-            )",
-            [],
-        )?;
-
-        // Chunks table
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS chunks (
-                chunk_id TEXT PRIMARY KEY,
-                repo_id TEXT NOT NULL,
-                snapshot_id TEXT NOT NULL,
-                file_path TEXT NOT NULL,
-                start_line INTEGER NOT NULL,
-                end_line INTEGER NOT NULL,
-                kind TEXT NOT NULL,
-                fqn TEXT,
-                language TEXT NOT NULL,
-                symbol_visibility TEXT,
-                content TEXT NOT NULL,
-                content_hash TEXT NOT NULL,
-                summary TEXT,
-                importance REAL NOT NULL DEFAULT 0.5,
-                is_deleted BOOLEAN NOT NULL DEFAULT 0,
-                attrs TEXT,
-                created_at INTEGER NOT NULL,
-                updated_at INTEGER NOT NULL,
-                FOREIGN KEY (repo_id) REFERENCES repositories(repo_id),
-                FOREIGN KEY (snapshot_id) REFERENCES snapshots(snapshot_id)
-            )",
-            [],
-        )?;
-
-        // Create indexes for fast lookups
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_chunks_repo_snapshot
-             ON chunks(repo_id, snapshot_id, is_deleted)",
-            [],
-        )?;
+    /// Open a store without touching its schema
+    ///
+    /// For upgrading existing databases deliberately: check
+    /// `migrate_dry_run()`, then `migrate()`.
+    pub fn open_unmigrated(db_path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self {
+            conn: Arc::new(Mutex::new(Connection::open(db_path)?)),
+        })
+    }
 
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_chunks_file
-             ON chunks(repo_id, snapshot_id, file_path, is_deleted)",
-            [],
-        )?;
+    /// Current schema version (0 for an empty database)
+    pub fn schema_version(&self) -> Result<u32> {
+        let conn = self.conn.lock().unwrap();
+        Ok(migrations::schema_version(&Self::applied_migrations(
+            &conn,
+        )?))
+    }
 
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_chunks_fqn
-             ON chunks(fqn, is_deleted)",
-            [],
-        )?;
+    /// Apply pending migrations
+    pub fn migrate(&self) -> Result<MigrationReport> {
+        self.run_migrations(SQLITE_MIGRATIONS, false)
+    }
 
-        // Dependencies table
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS dependencies (
-                id TEXT PRIMARY KEY,
-                from_chunk_id TEXT NOT NULL,
-                to_chunk_id TEXT NOT NULL,
-                relationship TEXT NOT NULL,
-                confidence REAL NOT NULL DEFAULT 1.0,
-                created_at INTEGER NOT NULL,
-                FOREIGN KEY (from_chunk_id) REFERENCES chunks(chunk_id),
-                FOREIGN KEY (to_chunk_id) REFERENCES chunks(chunk_id)
-            )",
-            [],
-        )?;
+    /// Report pending migrations without applying them
+    pub fn migrate_dry_run(&self) -> Result<MigrationReport> {
+        self.run_migrations(SQLITE_MIGRATIONS, true)
+    }
 
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_deps_from
-             ON dependencies(from_chunk_id)",
+    fn applied_migrations(conn: &Connection) -> Result<Vec<AppliedMigration>> {
+        let exists: bool = conn.query_row(
+            "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'schema_migrations')",
             [],
+            |row| row.get(0),
         )?;
+        if !exists {
+            return Ok(Vec::new());
+        }
+        let mut stmt =
+            conn.prepare("SELECT version, name, checksum FROM schema_migrations ORDER BY version")?;
+        let applied = stmt
+            .query_map([], |row| {
+                Ok(AppliedMigration {
+                    version: row.get(0)?,
+                    name: row.get(1)?,
+                    checksum: row.get(2)?,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(applied)
+    }
 
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_deps_to
-             ON dependencies(to_chunk_id)",
-            [],
-        )?;
+    fn run_migrations(&self, list: &[Migration], dry_run: bool) -> Result<MigrationReport> {
+        let mut conn = self.conn.lock().unwrap();
+        let applied = Self::applied_migrations(&conn)?;
+        let steps = migrations::pending(list, &applied)?;
+        let report = migrations::report(migrations::schema_version(&applied), &steps, dry_run);
+        if dry_run || steps.is_empty() {
+            return Ok(report);
+        }
 
-        // File metadata table (for incremental indexing)
         conn.execute(
-            "CREATE TABLE IF NOT EXISTS file_metadata (
-                repo_id TEXT NOT NULL,
-                snapshot_id TEXT NOT NULL,
-                file_path TEXT NOT NULL,
-                content_hash TEXT NOT NULL,
-                updated_at INTEGER NOT NULL,
-                PRIMARY KEY (repo_id, snapshot_id, file_path),
-                FOREIGN KEY (repo_id) REFERENCES repositories(repo_id),
-                FOREIGN KEY (snapshot_id) REFERENCES snapshots(snapshot_id)
+            "CREATE TABLE IF NOT EXISTS schema_migrations (
+                version INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                checksum TEXT NOT NULL,
+                applied_at INTEGER NOT NULL
             )",
             [],
         )?;
-
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_file_metadata_hash
-             ON file_metadata(content_hash)",
-            [],
-        )?;
-
-        Ok(())
+        for migration in steps {
+            let tx = conn.transaction()?;
+            tx.execute_batch(migration.sql)?;
+            tx.execute(
+                "INSERT INTO schema_migrations (version, name, checksum, applied_at) VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    migration.version,
+                    migration.name,
+                    migration.checksum(),
+                    chrono::Utc::now().timestamp()
+                ],
+            )?;
+            tx.commit()?;
+        }
+        Ok(report)
     }
 }
 
@@ -872,3 +833,74 @@ impl ChunkStore for SqliteChunkStore {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UPGRADE: Migration = Migration {
+        version: 2,
+        name: "chunk_tags",
+        sql: "CREATE TABLE chunk_tags (chunk_id TEXT NOT NULL, tag TEXT NOT NULL)",
+    };
+
+    #[test]
+    fn test_new_store_is_at_latest_version() {
+        let store = SqliteChunkStore::in_memory().unwrap();
+        assert_eq!(
+            store.schema_version().unwrap(),
+            SQLITE_MIGRATIONS.len() as u32
+        );
+        assert!(store.migrate_dry_run().unwrap().is_up_to_date());
+    }
+
+    #[test]
+    fn test_legacy_database_upgrades_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("legacy.db");
+        {
+            // Schema created before migrations were tracked
+            let conn = Connection::open(&path).unwrap();
+            conn.execute_batch(SQLITE_MIGRATIONS[0].sql).unwrap();
+            conn.execute(
+                "INSERT INTO repositories VALUES ('r', 'r', NULL, NULL, 'main', 0, 0)",
+                [],
+            )
+            .unwrap();
+        }
+
+        let store = SqliteChunkStore::open_unmigrated(&path).unwrap();
+        assert_eq!(store.schema_version().unwrap(), 0);
+        let plan = store.migrate_dry_run().unwrap();
+        assert_eq!(plan.steps, vec![(1, "initial_schema")]);
+        assert_eq!(store.schema_version().unwrap(), 0);
+
+        let list = [SQLITE_MIGRATIONS[0], UPGRADE];
+        let report = store.run_migrations(&list, false).unwrap();
+        assert_eq!((report.from_version, report.to_version), (0, 2));
+        assert_eq!(store.schema_version().unwrap(), 2);
+
+        let conn = store.conn.lock().unwrap();
+        let repos: i64 = conn
+            .query_row("SELECT COUNT(*) FROM repositories", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(repos, 1);
+    }
+
+    #[test]
+    fn test_edited_migration_is_rejected() {
+        let store = SqliteChunkStore::in_memory().unwrap();
+        store
+            .run_migrations(&[SQLITE_MIGRATIONS[0], UPGRADE], false)
+            .unwrap();
+
+        let edited = Migration {
+            sql: "CREATE TABLE chunk_tags (chunk_id TEXT)",
+            ..UPGRADE
+        };
+        assert!(store
+            .run_migrations(&[SQLITE_MIGRATIONS[0], edited], true)
+            .is_err());
+        assert!(store.migrate().is_err()); // database is newer than the build
+    }
+}
@@ -25,7 +25,9 @@ pub mod infrastructure; // RFC-074: PostgreSQL adapter
 pub use application::{StorageUseCase, StorageUseCaseImpl};
 
 // High-level API (RFC-100)
-pub use api::{CodeSnapshotStore, SnapshotDiff, SnapshotStats};
+pub use api::{
+    CodeSnapshotStore, DeprecationTrend, LazySnapshot, SnapshotDiff, SnapshotReport, SnapshotStats,
+};
 
 // Low-level API (RFC-074)
 pub use domain::{
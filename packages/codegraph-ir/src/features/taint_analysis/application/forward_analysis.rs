@@ -0,0 +1,444 @@
+/*
+ * Forward Taint Propagation over the Call Graph
+ *
+ * Function-granular forward analysis used by `AnalyzeTaintUseCase`:
+ *
+ * 1. Call graph: `SymbolDependencyGraph` built from the IR edges, plus call
+ *    edges derived from `NodeKind::Call` nodes (enclosing function →
+ *    function with the callee's name).
+ * 2. Origins: a function is tainted when it is a source function, calls one
+ *    (the return value flows back to the caller), or contains a source node
+ *    (variable, parameter, call expression). Calls to external functions
+ *    (no IR with that name) are looked up in the library summaries: a
+ *    call whose summary returns tainted data is a source node too.
+ * 3. Propagation: tainted values spread inside a function along data-flow
+ *    edges (`DataFlow`, `DefUse`). Taint enters a callee, one call-depth
+ *    level per hop, only when a tainted value flows into it (call-site
+ *    argument → parameter or callee node); calling it with clean arguments
+ *    does not. Sanitizer functions stop propagation; calling a sanitizing
+ *    library function marks the caller as sanitizing.
+ * 4. Sinks: passing a tainted value to a sink function, or to a sink node
+ *    of the tainted function, produces a `TaintPath`. When the function
+ *    invoking the sink also calls a sanitizer, the path is reported as
+ *    sanitized.
+ *
+ * Depth limits:
+ * - `interprocedural = false`: only flows inside the origin function
+ * - `max_depth`: maximum number of call hops from the origin
+ *
+ * Nodes matching both a source and a sink pattern (e.g. "query") are
+ * treated as sinks only.
+ */
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::features::cross_file::{IRDocument, SymbolDependencyGraph};
+use crate::features::taint_analysis::infrastructure::{
+    LibrarySummaryDb, TaintAnalyzer, TaintPath, TaintSeverity,
+};
+use crate::shared::models::{Edge, EdgeKind, Node, NodeKind};
+
+/// Maximum parent hops when resolving a node's enclosing function
+const MAX_PARENT_HOPS: usize = 64;
+
+/// Forward propagation settings
+#[derive(Debug, Clone)]
+pub(super) struct ForwardConfig {
+    pub max_depth: usize,
+    pub interprocedural: bool,
+    pub sanitizer_patterns: Vec<String>,
+    /// Summaries for external callees
+    pub library: LibrarySummaryDb,
+}
+
+pub(super) struct ForwardTaintAnalysis<'a> {
+    config: ForwardConfig,
+    by_id: HashMap<&'a str, &'a Node>,
+    symbol_graph: SymbolDependencyGraph,
+    /// Data-flow successors by node ID
+    data_flow: HashMap<String, Vec<String>>,
+    /// Functions calling a sanitizer (function or call expression)
+    sanitizing: HashSet<String>,
+    /// External calls whose library summary returns tainted data
+    library_sources: Vec<&'a Node>,
+    /// Severity lookup (default sink rules)
+    severity_rules: TaintAnalyzer,
+}
+
+impl<'a> ForwardTaintAnalysis<'a> {
+    /// Build the call graph for `nodes` (+ explicit `edges`)
+    pub fn new(config: ForwardConfig, nodes: &'a [Node], edges: &[Edge]) -> Self {
+        let by_id: HashMap<&str, &Node> = nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+        let mut data_flow: HashMap<String, Vec<String>> = HashMap::new();
+        for edge in edges
+            .iter()
+            .filter(|e| matches!(e.kind, EdgeKind::DataFlow | EdgeKind::DefUse))
+        {
+            data_flow
+                .entry(edge.source_id.clone())
+                .or_default()
+                .push(edge.target_id.clone());
+        }
+
+        let mut all_edges: Vec<Edge> = edges.to_vec();
+        all_edges.extend(derive_call_edges(nodes, &by_id));
+
+        // File paths are irrelevant for path construction: one document keeps
+        // cross-file edges (both endpoints must be in the same document)
+        let document = IRDocument::new(String::new(), nodes.to_vec(), all_edges);
+
+        let mut analysis = Self {
+            config,
+            by_id,
+            symbol_graph: SymbolDependencyGraph::build_from_irs(&[document]),
+            data_flow,
+            sanitizing: HashSet::new(),
+            library_sources: Vec::new(),
+            severity_rules: TaintAnalyzer::new(),
+        };
+
+        // Library summaries only stand in for callees without IR
+        let ir_functions: HashSet<&str> = nodes
+            .iter()
+            .filter(|n| is_function(n))
+            .map(|n| n.name.as_deref().unwrap_or_else(|| short_name(&n.fqn)))
+            .collect();
+        let external_calls: Vec<(&'a Node, &'a str)> = nodes
+            .iter()
+            .filter(|n| n.kind == NodeKind::Call)
+            .filter_map(|n| n.name.as_deref().map(|name| (n, name)))
+            .filter(|(_, name)| !ir_functions.contains(short_name(name)))
+            .collect();
+        let library_sanitizers: HashSet<&str> = external_calls
+            .iter()
+            .filter(|(call, name)| analysis.config.library.is_sanitizer(name, language(call)))
+            .map(|(call, _)| call.id.as_str())
+            .collect();
+        analysis.library_sources = external_calls
+            .iter()
+            .filter(|(call, name)| analysis.config.library.is_source(name, language(call)))
+            .map(|(call, _)| *call)
+            .collect();
+
+        analysis.sanitizing = nodes
+            .iter()
+            .filter_map(|node| {
+                if is_function(node) {
+                    let calls_sanitizer = analysis
+                        .callees(&node.fqn)
+                        .iter()
+                        .any(|callee| analysis.is_sanitizer(callee));
+                    calls_sanitizer.then(|| node.fqn.clone())
+                } else if node.kind == NodeKind::Call
+                    && (library_sanitizers.contains(node.id.as_str())
+                        || node
+                            .name
+                            .as_deref()
+                            .is_some_and(|n| analysis.is_sanitizer(n)))
+                {
+                    enclosing_function(node, &analysis.by_id).map(|f| f.fqn.clone())
+                } else {
+                    None
+                }
+            })
+            .collect();
+        analysis
+    }
+
+    /// Propagate taint from `sources` and collect paths reaching `sinks`
+    ///
+    /// Both maps are keyed by node ID (values: matched patterns).
+    pub fn run(
+        &self,
+        sources: &HashMap<String, HashSet<String>>,
+        sinks: &HashMap<String, HashSet<String>>,
+    ) -> Vec<TaintPath> {
+        // Sink functions (reached via calls) and sink nodes inside functions
+        let mut sink_functions: HashSet<&str> = HashSet::new();
+        let mut contained_sinks: HashMap<&str, Vec<&Node>> = HashMap::new();
+        for node in sinks
+            .keys()
+            .filter_map(|id| self.by_id.get(id.as_str()).copied())
+        {
+            if is_function(node) {
+                sink_functions.insert(node.fqn.as_str());
+            } else if let Some(owner) = enclosing_function(node, &self.by_id) {
+                contained_sinks
+                    .entry(owner.fqn.as_str())
+                    .or_default()
+                    .push(node);
+            }
+        }
+
+        // Origins: (tainted function, path so far, tainted values)
+        let mut origins: Vec<(String, Vec<String>, Vec<String>)> = Vec::new();
+        for id in sources.keys().filter(|id| !sinks.contains_key(*id)) {
+            let Some(&node) = self.by_id.get(id.as_str()) else {
+                continue;
+            };
+            if is_function(node) {
+                // The source function's node stands for its return value
+                let seeds = vec![node.id.clone()];
+                origins.push((node.fqn.clone(), vec![node.fqn.clone()], seeds.clone()));
+                for caller in self.callers(&node.fqn) {
+                    origins.push((
+                        caller.clone(),
+                        vec![node.fqn.clone(), caller],
+                        seeds.clone(),
+                    ));
+                }
+            } else if let Some(owner) = enclosing_function(node, &self.by_id) {
+                origins.push((
+                    owner.fqn.clone(),
+                    vec![label(node), owner.fqn.clone()],
+                    vec![node.id.clone()],
+                ));
+            }
+        }
+        for &call in &self.library_sources {
+            if sinks.contains_key(&call.id) {
+                continue;
+            }
+            if let Some(owner) = enclosing_function(call, &self.by_id) {
+                origins.push((
+                    owner.fqn.clone(),
+                    vec![label(call), owner.fqn.clone()],
+                    vec![call.id.clone()],
+                ));
+            }
+        }
+        origins.sort();
+        origins.dedup();
+
+        let mut paths = Vec::new();
+        let mut seen: HashSet<Vec<String>> = HashSet::new();
+        for (function, path, seeds) in origins {
+            self.propagate(
+                function,
+                path,
+                seeds,
+                &sink_functions,
+                &contained_sinks,
+                &mut seen,
+                &mut paths,
+            );
+        }
+
+        paths.sort_by(|a, b| (&a.source, &a.sink, &a.path).cmp(&(&b.source, &b.sink, &b.path)));
+        paths
+    }
+
+    /// BFS from one origin function holding the tainted values `seeds`
+    #[allow(clippy::too_many_arguments)]
+    fn propagate(
+        &self,
+        origin: String,
+        origin_path: Vec<String>,
+        seeds: Vec<String>,
+        sink_functions: &HashSet<&str>,
+        contained_sinks: &HashMap<&str, Vec<&Node>>,
+        seen: &mut HashSet<Vec<String>>,
+        paths: &mut Vec<TaintPath>,
+    ) {
+        let mut visited: HashSet<String> = HashSet::new();
+        let mut queue: VecDeque<(String, Vec<String>, usize, Vec<String>)> = VecDeque::new();
+        visited.insert(origin.clone());
+        queue.push_back((origin, origin_path, 0, seeds));
+
+        while let Some((function, path, depth, seeds)) = queue.pop_front() {
+            let sanitized = self.sanitizing.contains(&function);
+            let tainted = self.tainted_values(&function, &seeds);
+
+            // Sinks of the function fed by one of its tainted values
+            for sink in contained_sinks
+                .get(function.as_str())
+                .into_iter()
+                .flatten()
+                .filter(|sink| tainted.contains(&sink.id))
+            {
+                let mut sink_path = path.clone();
+                sink_path.push(label(sink));
+                self.report(sink_path, sanitized, seen, paths);
+            }
+
+            let mut callees = self.callees(&function);
+            callees.sort();
+            callees.dedup();
+            for callee in callees {
+                // Values of the callee the tainted arguments flow into
+                let mut arguments: Vec<String> = tainted
+                    .iter()
+                    .filter(|id| !seeds.contains(id) && self.owner(id) == Some(callee.as_str()))
+                    .cloned()
+                    .collect();
+                if arguments.is_empty() {
+                    continue;
+                }
+                arguments.sort();
+                if sink_functions.contains(callee.as_str()) {
+                    let mut sink_path = path.clone();
+                    sink_path.push(callee);
+                    self.report(sink_path, sanitized, seen, paths);
+                    continue;
+                }
+                if self.is_sanitizer(&callee)
+                    || !self.config.interprocedural
+                    || depth >= self.config.max_depth
+                    || !visited.insert(callee.clone())
+                {
+                    continue;
+                }
+                let mut next_path = path.clone();
+                next_path.push(callee.clone());
+                queue.push_back((callee, next_path, depth + 1, arguments));
+            }
+        }
+    }
+
+    fn report(
+        &self,
+        path: Vec<String>,
+        is_sanitized: bool,
+        seen: &mut HashSet<Vec<String>>,
+        paths: &mut Vec<TaintPath>,
+    ) {
+        if !seen.insert(path.clone()) {
+            return;
+        }
+        let source = path.first().cloned().unwrap_or_default();
+        let sink = path.last().cloned().unwrap_or_default();
+        let short = short_name(&sink);
+        let severity = self
+            .severity_rules
+            .get_sinks()
+            .iter()
+            .find(|s| s.matches(short))
+            .map(|s| s.severity)
+            .unwrap_or(TaintSeverity::Medium);
+
+        paths.push(TaintPath {
+            source,
+            sink,
+            path,
+            is_sanitized,
+            severity,
+        });
+    }
+
+    /// Data-flow closure of `seeds` inside `function`
+    ///
+    /// Values of other functions (parameters bound to call-site arguments)
+    /// are included but not followed.
+    fn tainted_values(&self, function: &str, seeds: &[String]) -> HashSet<String> {
+        let mut tainted: HashSet<String> = seeds.iter().cloned().collect();
+        let mut stack: Vec<&str> = seeds.iter().map(String::as_str).collect();
+        while let Some(id) = stack.pop() {
+            for next in self.data_flow.get(id).into_iter().flatten() {
+                if tainted.insert(next.clone()) && self.owner(next) == Some(function) {
+                    stack.push(next);
+                }
+            }
+        }
+        tainted
+    }
+
+    /// FQN of the function a node belongs to (functions own themselves)
+    fn owner(&self, id: &str) -> Option<&'a str> {
+        let node = *self.by_id.get(id)?;
+        if is_function(node) {
+            Some(node.fqn.as_str())
+        } else {
+            enclosing_function(node, &self.by_id).map(|f| f.fqn.as_str())
+        }
+    }
+
+    fn callees(&self, fqn: &str) -> Vec<String> {
+        self.symbol_graph
+            .call_graph()
+            .map(|cg| cg.get_callees(fqn))
+            .unwrap_or_default()
+    }
+
+    fn callers(&self, fqn: &str) -> Vec<String> {
+        self.symbol_graph
+            .call_graph()
+            .map(|cg| cg.get_callers(fqn))
+            .unwrap_or_default()
+    }
+
+    fn is_sanitizer(&self, fqn: &str) -> bool {
+        let name = short_name(fqn).to_lowercase();
+        self.config
+            .sanitizer_patterns
+            .iter()
+            .any(|p| name.contains(&p.to_lowercase()))
+    }
+}
+
+/// Call edges implied by `Call` nodes (enclosing function → named function)
+fn derive_call_edges(nodes: &[Node], by_id: &HashMap<&str, &Node>) -> Vec<Edge> {
+    let mut functions_by_name: HashMap<&str, Vec<&Node>> = HashMap::new();
+    for node in nodes.iter().filter(|n| is_function(n)) {
+        let name = node
+            .name
+            .as_deref()
+            .unwrap_or_else(|| short_name(&node.fqn));
+        functions_by_name.entry(name).or_default().push(node);
+    }
+
+    let mut edges = Vec::new();
+    for call in nodes.iter().filter(|n| n.kind == NodeKind::Call) {
+        let Some(callee_name) = call.name.as_deref().map(short_name) else {
+            continue;
+        };
+        let Some(caller) = enclosing_function(call, by_id) else {
+            continue;
+        };
+        for target in functions_by_name.get(callee_name).into_iter().flatten() {
+            if target.id != caller.id {
+                edges.push(Edge::new(
+                    caller.id.clone(),
+                    target.id.clone(),
+                    EdgeKind::Calls,
+                ));
+            }
+        }
+    }
+    edges
+}
+
+/// Enclosing function/method of a non-function node
+fn enclosing_function<'n>(node: &'n Node, by_id: &HashMap<&str, &'n Node>) -> Option<&'n Node> {
+    let mut current = *by_id.get(node.parent_id.as_deref()?)?;
+    for _ in 0..MAX_PARENT_HOPS {
+        if is_function(current) {
+            return Some(current);
+        }
+        current = *by_id.get(current.parent_id.as_deref()?)?;
+    }
+    None
+}
+
+/// Functions and methods (the call graph only keeps these)
+fn is_function(node: &Node) -> bool {
+    matches!(node.kind, NodeKind::Function | NodeKind::Method)
+}
+
+/// Last segment of a dotted / `::` path
+fn short_name(fqn: &str) -> &str {
+    fqn.rsplit(['.', ':']).next().unwrap_or(fqn)
+}
+
+/// Node language for library summary lookups (`None` = unknown)
+fn language(node: &Node) -> Option<&str> {
+    Some(node.language.as_str()).filter(|l| !l.is_empty())
+}
+
+/// Display name of a non-function path element
+fn label(node: &Node) -> String {
+    if node.fqn.is_empty() {
+        node.name.clone().unwrap_or_else(|| node.id.clone())
+    } else {
+        node.fqn.clone()
+    }
+}
@@ -0,0 +1,457 @@
+/*
+ * Git Differential Taint Service
+ *
+ * `DifferentialTaintService` over git revisions, for PR gating:
+ *
+ * 1. Changed files between base and head (git2 tree diff)
+ * 2. Each side's changed files are parsed to IR and run through the forward
+ *    taint analysis of `AnalyzeTaintUseCase` (cross-file flows between
+ *    changed files are kept; unchanged files produce the same flows on both
+ *    sides and are skipped). The parser's call sites are lowered to `Call`
+ *    nodes first: a call passed as another call's argument
+ *    (`os.system(input())`) flows into it.
+ * 3. Flows are matched by `TaintPathDTO::fingerprint` (vulnerability type +
+ *    source + sink):
+ *    - new: only in head
+ *    - fixed: only in base
+ *    - modified: in both, but the path, sanitization or severity changed
+ *
+ * CI fails on `new_vulnerabilities` only; fingerprints are stable across line
+ * shifts, so reformatting or moving code does not re-report existing flows.
+ */
+
+use async_trait::async_trait;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use super::{AnalyzeTaintUseCase, InMemoryCodeRepository};
+use crate::features::parsing::infrastructure::extractors::fqn_resolver::FqnResolver;
+use crate::features::taint_analysis::infrastructure::differential::{
+    ChangedFile, GitDifferentialAnalyzer,
+};
+use crate::features::taint_analysis::infrastructure::LibrarySummaryDb;
+use crate::features::taint_analysis::ports::{
+    DifferentialResult, DifferentialStats, DifferentialTaintService, TaintAnalysisConfig,
+    TaintAnalysisError, TaintPathDTO,
+};
+use crate::pipeline::end_to_end_orchestrator::file_to_module_path;
+use crate::pipeline::processor::process_file;
+use crate::shared::models::{Edge, EdgeKind, Node, NodeKind};
+
+/// Repository id used for IR built from git blobs
+const DIFF_REPO_ID: &str = "git-diff";
+
+/// Differential taint analysis between git revisions
+pub struct GitDiffTaintAnalyzer {
+    repo_path: PathBuf,
+    config: TaintAnalysisConfig,
+    use_case: AnalyzeTaintUseCase,
+}
+
+impl GitDiffTaintAnalyzer {
+    /// Analyzer for the repository at `repo_path`
+    pub fn new(repo_path: impl AsRef<Path>) -> Self {
+        Self {
+            repo_path: repo_path.as_ref().to_path_buf(),
+            config: TaintAnalysisConfig {
+                interprocedural: true,
+                ..Default::default()
+            },
+            use_case: AnalyzeTaintUseCase::new(Arc::new(InMemoryCodeRepository::new()), None),
+        }
+    }
+
+    /// Analysis config used by `analyze_git_diff`
+    pub fn with_config(mut self, config: TaintAnalysisConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Replace the library summaries (default: the bundled models)
+    pub fn with_library_summaries(mut self, library_summaries: LibrarySummaryDb) -> Self {
+        self.use_case = self.use_case.with_library_summaries(library_summaries);
+        self
+    }
+
+    fn changed_files(
+        &self,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<ChangedFile>, TaintAnalysisError> {
+        let git = GitDifferentialAnalyzer::new(&self.repo_path)
+            .map_err(|e| TaintAnalysisError::invalid_input(e.to_string()))?;
+        let files = git
+            .get_changed_files(base, head)
+            .map_err(|e| TaintAnalysisError::invalid_input(e.to_string()))?;
+        Ok(files
+            .into_iter()
+            .filter(|f| git.is_supported_file(&f.path))
+            .collect())
+    }
+
+    /// Forward taint paths over one side of the diff
+    fn analyze_revision(
+        &self,
+        config: &TaintAnalysisConfig,
+        revision: &RevisionIR,
+    ) -> Result<Vec<TaintPathDTO>, TaintAnalysisError> {
+        let use_case = &self.use_case;
+        let sources = use_case.build_sources_map(&use_case.source_patterns, &revision.nodes);
+        let sinks = use_case.build_sinks_map(&use_case.sink_patterns, &revision.nodes);
+        let paths = use_case.run_forward_analysis(
+            config,
+            &revision.nodes,
+            &revision.edges,
+            &sources,
+            &sinks,
+            &use_case.sanitizer_patterns,
+        )?;
+        Ok(paths.into_iter().map(TaintPathDTO::from).collect())
+    }
+}
+
+#[async_trait]
+impl DifferentialTaintService for GitDiffTaintAnalyzer {
+    /// Compare two revisions (branch, tag, SHA, `HEAD~1`, ...)
+    async fn compare_versions(
+        &self,
+        old_version: &str,
+        new_version: &str,
+        config: TaintAnalysisConfig,
+    ) -> Result<DifferentialResult, TaintAnalysisError> {
+        let files = self.changed_files(old_version, new_version)?;
+
+        let base = RevisionIR::build(&files, |f| f.base_content.as_deref());
+        let head = RevisionIR::build(&files, |f| f.head_content.as_deref());
+        let base_paths = self.analyze_revision(&config, &base)?;
+        let head_paths = self.analyze_revision(&config, &head)?;
+
+        let (new_vulnerabilities, fixed_vulnerabilities, modified_vulnerabilities) =
+            diff_paths(base_paths, head_paths);
+
+        let stats = DifferentialStats {
+            files_changed: files.len(),
+            functions_changed: changed_functions(&base.functions, &head.functions),
+            new_count: new_vulnerabilities.len(),
+            fixed_count: fixed_vulnerabilities.len(),
+            modified_count: modified_vulnerabilities.len(),
+        };
+        Ok(DifferentialResult {
+            new_vulnerabilities,
+            fixed_vulnerabilities,
+            modified_vulnerabilities,
+            stats,
+        })
+    }
+
+    /// Compare two commits with the configured analysis
+    ///
+    /// Only the changed files are analyzed: a flow through a callee defined
+    /// in an unchanged file is not seen, and values passed through variables
+    /// or parameters are not tracked (see `lower_call_sites`).
+    async fn analyze_git_diff(
+        &self,
+        base_commit: &str,
+        head_commit: &str,
+    ) -> Result<DifferentialResult, TaintAnalysisError> {
+        self.compare_versions(base_commit, head_commit, self.config.clone())
+            .await
+    }
+}
+
+/// IR of the changed files at one revision
+struct RevisionIR {
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+    /// Function FQN → source text
+    functions: HashMap<String, String>,
+}
+
+impl RevisionIR {
+    fn build<'f>(
+        files: &'f [ChangedFile],
+        content: impl Fn(&'f ChangedFile) -> Option<&'f str>,
+    ) -> Self {
+        let mut revision = Self {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            functions: HashMap::new(),
+        };
+        for file in files {
+            // Deleted files have no head side, added files no base side
+            let Some(content) = content(file) else {
+                continue;
+            };
+            let path = file.path.to_string_lossy().replace('\\', "/");
+            let result = process_file(content, DIFF_REPO_ID, &path, &file_to_module_path(&path));
+
+            let lines: Vec<&str> = content.lines().collect();
+            for node in result
+                .nodes
+                .iter()
+                .filter(|n| matches!(n.kind, NodeKind::Function | NodeKind::Method))
+            {
+                let start = (node.span.start_line as usize).saturating_sub(1);
+                let end = (node.span.end_line as usize).min(lines.len());
+                let body = lines.get(start..end).unwrap_or_default().join("\n");
+                revision.functions.insert(node.fqn.clone(), body);
+            }
+            let (calls, flows) = lower_call_sites(&result.nodes, &result.edges);
+            revision.nodes.extend(result.nodes);
+            revision.nodes.extend(calls);
+            revision.edges.extend(result.edges);
+            revision.edges.extend(flows);
+        }
+        revision
+    }
+}
+
+/// `Call` nodes and argument data flow for the parser's CALLS edges
+///
+/// The forward analysis matches sources and sinks on call expressions and
+/// follows data-flow edges, neither of which L1 emits: each call site becomes
+/// a `Call` node of its caller, and a call written as one of its positional
+/// arguments (`os.system(input())`) becomes a `Call` node flowing into it.
+fn lower_call_sites(nodes: &[Node], edges: &[Edge]) -> (Vec<Node>, Vec<Edge>) {
+    let callers: HashMap<&str, &Node> = nodes
+        .iter()
+        .filter(|n| matches!(n.kind, NodeKind::Function | NodeKind::Method))
+        .map(|n| (n.id.as_str(), n))
+        .collect();
+    let resolver = FqnResolver::new();
+
+    let mut calls: Vec<Node> = Vec::new();
+    let mut flows = Vec::new();
+    for edge in edges.iter().filter(|e| e.kind == EdgeKind::Calls) {
+        let Some(caller) = callers.get(edge.source_id.as_str()) else {
+            continue;
+        };
+        let span = edge.span.unwrap_or(caller.span);
+        let call_node = |callee: &str, index: usize| {
+            Node::new(
+                format!("{}#call{}", caller.id, index),
+                NodeKind::Call,
+                callee.to_string(),
+                caller.file_path.clone(),
+                span,
+            )
+            .with_name(callee)
+            .with_language(caller.language.clone())
+            .with_parent(caller.id.clone())
+        };
+
+        let call = call_node(&edge.target_id, calls.len());
+        let call_id = call.id.clone();
+        calls.push(call);
+        let arguments = edge
+            .metadata
+            .as_ref()
+            .and_then(|m| m.arguments.as_deref())
+            .unwrap_or_default();
+        for callee in arguments.iter().filter_map(|a| argument_callee(a)) {
+            let argument = call_node(&resolver.resolve(callee), calls.len());
+            flows.push(Edge::new(
+                argument.id.clone(),
+                call_id.clone(),
+                EdgeKind::DataFlow,
+            ));
+            calls.push(argument);
+        }
+    }
+    (calls, flows)
+}
+
+/// Callee name of a call written as an argument (`input()` → `input`)
+///
+/// Method calls keep the method name only, like the L1 call extractor.
+fn argument_callee(argument: &str) -> Option<&str> {
+    let argument = argument.trim();
+    let (callee, _) = argument.split_once('(')?;
+    let is_path = !callee.is_empty()
+        && callee
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '.');
+    (is_path && argument.ends_with(')')).then(|| callee.rsplit('.').next().unwrap_or(callee))
+}
+
+/// Split flows into (new, fixed, modified) by fingerprint
+///
+/// Several paths can share endpoints; the first one (paths are sorted)
+/// represents the flow.
+fn diff_paths(
+    base: Vec<TaintPathDTO>,
+    head: Vec<TaintPathDTO>,
+) -> (Vec<TaintPathDTO>, Vec<TaintPathDTO>, Vec<TaintPathDTO>) {
+    let by_fingerprint = |paths: Vec<TaintPathDTO>| {
+        let mut map: BTreeMap<String, TaintPathDTO> = BTreeMap::new();
+        for path in paths {
+            map.entry(path.fingerprint()).or_insert(path);
+        }
+        map
+    };
+    let mut base = by_fingerprint(base);
+    let head = by_fingerprint(head);
+
+    let mut new = Vec::new();
+    let mut modified = Vec::new();
+    for (fingerprint, path) in head {
+        match base.remove(&fingerprint) {
+            None => new.push(path),
+            Some(old)
+                if old.path != path.path
+                    || old.is_sanitized != path.is_sanitized
+                    || old.severity != path.severity =>
+            {
+                modified.push(path)
+            }
+            Some(_) => {}
+        }
+    }
+    (new, base.into_values().collect(), modified)
+}
+
+/// Functions added, removed or edited between the two revisions
+fn changed_functions(base: &HashMap<String, String>, head: &HashMap<String, String>) -> usize {
+    let names: BTreeSet<&String> = base.keys().chain(head.keys()).collect();
+    names
+        .into_iter()
+        .filter(|name| base.get(*name) != head.get(*name))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    fn dto(source: &str, sink: &str, path: &[&str], is_sanitized: bool) -> TaintPathDTO {
+        TaintPathDTO {
+            source: source.to_string(),
+            sink: sink.to_string(),
+            source_type: "external".to_string(),
+            sink_type: "dangerous".to_string(),
+            path: path.iter().map(|p| p.to_string()).collect(),
+            path_length: path.len(),
+            is_sanitized,
+            sanitizers: vec![],
+            severity: "High".to_string(),
+            vulnerability_type: "taint_flow".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_diff_paths_buckets() {
+        let base = vec![
+            dto("app.read", "app.run", &["app.read", "app.run"], false),
+            dto("app.read", "app.query", &["app.read", "app.query"], false),
+            dto("app.env", "app.log", &["app.env", "app.log"], false),
+        ];
+        let head = vec![
+            // Unchanged
+            dto("app.read", "app.run", &["app.read", "app.run"], false),
+            // Now routed through a helper
+            dto(
+                "app.read",
+                "app.query",
+                &["app.read", "app.helper", "app.query"],
+                false,
+            ),
+            dto("app.form", "app.run", &["app.form", "app.run"], false),
+        ];
+
+        let (new, fixed, modified) = diff_paths(base.clone(), head);
+        assert_eq!(new.len(), 1);
+        assert_eq!(new[0].source, "app.form");
+        assert_eq!(fixed.len(), 1);
+        assert_eq!(fixed[0].sink, "app.log");
+        assert_eq!(modified.len(), 1);
+        assert_eq!(modified[0].path_length, 3);
+
+        // Identical revisions: nothing to report
+        let (new, fixed, modified) = diff_paths(base.clone(), base);
+        assert!(new.is_empty() && fixed.is_empty() && modified.is_empty());
+
+        assert_eq!(
+            dto("a", "b", &["a", "b"], false).fingerprint(),
+            dto("a", "b", &["a", "x", "b"], true).fingerprint()
+        );
+        assert_ne!(
+            dto("a", "b", &[], false).fingerprint(),
+            dto("b", "a", &[], false).fingerprint()
+        );
+    }
+
+    #[test]
+    fn test_argument_callee() {
+        assert_eq!(argument_callee("input()"), Some("input"));
+        assert_eq!(argument_callee(" request.args.get('q') "), Some("get"));
+        assert_eq!(argument_callee("cmd"), None);
+        assert_eq!(argument_callee("(a + b)"), None);
+        assert_eq!(argument_callee("f(x)[0]"), None);
+    }
+
+    #[tokio::test]
+    async fn test_compare_commits() {
+        let dir = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .args(args)
+                .current_dir(dir.path())
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        git(&["init", "-q"]);
+        git(&["config", "user.email", "test@test.com"]);
+        git(&["config", "user.name", "Test"]);
+
+        std::fs::write(dir.path().join("app.py"), "def handler():\n    return 1\n").unwrap();
+        std::fs::write(dir.path().join("README.md"), "docs\n").unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "base"]);
+
+        std::fs::write(
+            dir.path().join("app.py"),
+            "import os\n\ndef handler():\n    return 2\n\ndef other():\n    os.system(input())\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("README.md"), "more docs\n").unwrap();
+        git(&["commit", "-q", "-am", "head"]);
+
+        let analyzer = GitDiffTaintAnalyzer::new(dir.path());
+        let result = analyzer.analyze_git_diff("HEAD~1", "HEAD").await.unwrap();
+        // README.md is not analyzed
+        assert_eq!(result.stats.files_changed, 1);
+        assert_eq!(result.stats.functions_changed, 2);
+        assert_eq!(result.stats.new_count, 1);
+        assert_eq!(result.stats.fixed_count, 0);
+        let flow = &result.new_vulnerabilities[0];
+        assert_eq!(flow.source, "builtins.input");
+        assert!(flow.sink.ends_with("system"), "{:?}", flow);
+
+        // Passing a constant fixes it
+        std::fs::write(
+            dir.path().join("app.py"),
+            "import os\n\ndef handler():\n    return 2\n\ndef other():\n    os.system(\"ls\")\n",
+        )
+        .unwrap();
+        git(&["commit", "-q", "-am", "fix"]);
+
+        let result = analyzer.analyze_git_diff("HEAD~1", "HEAD").await.unwrap();
+        assert_eq!(result.stats.functions_changed, 1);
+        assert_eq!(result.stats.new_count, 0);
+        assert_eq!(result.stats.fixed_count, 1);
+        assert_eq!(result.fixed_vulnerabilities[0].source, "builtins.input");
+
+        let result = analyzer.analyze_git_diff("HEAD", "HEAD").await.unwrap();
+        assert_eq!(result.stats.files_changed, 0);
+        assert!(result.new_vulnerabilities.is_empty());
+
+        assert!(analyzer
+            .analyze_git_diff("HEAD", "no-such-rev")
+            .await
+            .is_err());
+    }
+}
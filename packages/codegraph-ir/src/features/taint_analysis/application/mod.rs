@@ -17,7 +17,7 @@
  *
  * Use Cases:
  * 1. AnalyzeTaintUseCase - Main taint analysis workflow
- * 2. DifferentialAnalysisUseCase - Compare versions for security changes
+ * 2. GitDiffTaintAnalyzer - Compare git revisions for security changes (PR gating)
  * 3. BackwardAnalysisUseCase - Sink-to-source tracing
  * 4. IFDSTaintService - IFDS/IDE-based interprocedural analysis (RFC-001 Config)
  */
@@ -26,6 +26,11 @@
 // IFDS/IDE Taint Service (RFC-001 Config Integration)
 // ============================================================================
 pub mod ifds_taint_service;
+mod forward_analysis;
+#[cfg(feature = "git")]
+pub mod git_diff_service;
+#[cfg(feature = "git")]
+pub use git_diff_service::GitDiffTaintAnalyzer;
 pub use ifds_taint_service::{
     IDEAnalysisResult, IDESolverConfig, IFDSAnalysisError, IFDSAnalysisResult, IFDSSolverConfig,
     IFDSTaintService, SolverType, SparseAnalysisStats, SparseIFDSConfig,
@@ -37,10 +42,11 @@ use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Instant;
 
+use self::forward_analysis::{ForwardConfig, ForwardTaintAnalysis};
 use super::infrastructure::{
     BackwardTaintAnalyzer, BackwardTaintConfig, BackwardTaintPath, ControlDependencyGraph,
-    ImplicitFlowAnalyzer, ImplicitFlowConfig, ImplicitFlowVulnerability, TaintPath, TaintSeverity,
-    IFDSCFG,
+    ImplicitFlowAnalyzer, ImplicitFlowConfig, ImplicitFlowVulnerability, LibrarySummaryDb,
+    TaintPath, TaintSeverity, IFDSCFG,
 };
 use super::ports::{
     default_sanitizer_patterns, default_sink_patterns, default_source_patterns, AnalysisMode,
@@ -48,7 +54,7 @@ use super::ports::{
     TaintAnalysisError, TaintAnalysisRequest, TaintAnalysisResponse, TaintAnalysisService,
     TaintPathDTO, TaintResultRepository,
 };
-use crate::shared::models::Node;
+use crate::shared::models::{Edge, Node};
 
 // ============================================================================
 // Main Use Case: Analyze Taint
@@ -72,6 +78,9 @@ pub struct AnalyzeTaintUseCase {
 
     /// Default sanitizer patterns
     sanitizer_patterns: Vec<String>,
+
+    /// Taint summaries for external library callees
+    library_summaries: LibrarySummaryDb,
 }
 
 impl AnalyzeTaintUseCase {
@@ -86,9 +95,16 @@ impl AnalyzeTaintUseCase {
             source_patterns: default_source_patterns(),
             sink_patterns: default_sink_patterns(),
             sanitizer_patterns: default_sanitizer_patterns(),
+            library_summaries: LibrarySummaryDb::bundled(),
         }
     }
 
+    /// Replace the library summaries (default: the bundled models)
+    pub fn with_library_summaries(mut self, library_summaries: LibrarySummaryDb) -> Self {
+        self.library_summaries = library_summaries;
+        self
+    }
+
     /// Execute analysis
     pub async fn execute(
         &self,
@@ -96,7 +112,7 @@ impl AnalyzeTaintUseCase {
     ) -> Result<TaintAnalysisResponse, TaintAnalysisError> {
         let start = Instant::now();
 
-        // 1. Load IR nodes
+        // 1. Load IR nodes (+ edges for the call graph)
         let nodes = self.code_repo.get_ir_nodes(&request.code_path).await?;
 
         if nodes.is_empty() {
@@ -110,12 +126,25 @@ impl AnalyzeTaintUseCase {
             });
         }
 
+        let edges = self.code_repo.get_ir_edges(&request.code_path).await?;
+
         // 2. Build analysis context
         let sources = self.build_sources_map(&request.source_patterns, &nodes);
         let sinks = self.build_sinks_map(&request.sink_patterns, &nodes);
+        let sanitizers = request
+            .sanitizer_patterns
+            .as_deref()
+            .unwrap_or(&self.sanitizer_patterns);
 
         // 3. Run forward analysis
-        let forward_paths = self.run_forward_analysis(&request.config, &nodes, &sources, &sinks)?;
+        let forward_paths = self.run_forward_analysis(
+            &request.config,
+            &nodes,
+            &edges,
+            &sources,
+            &sinks,
+            sanitizers,
+        )?;
 
         // 4. Run backward analysis (if enabled)
         let backward_paths = if request.config.backward_analysis {
@@ -233,20 +262,33 @@ impl AnalyzeTaintUseCase {
 
     /// Run forward taint analysis
     ///
-    /// Note: Full interprocedural analysis requires call graph construction.
-    /// Currently returns empty results as placeholder.
+    /// Propagates taint over the call graph (`SymbolDependencyGraph`) built
+    /// from `edges`, plus call edges implied by `Call` nodes; a callee is only
+    /// tainted when a data-flow edge carries a tainted value into it. Call
+    /// depth is bounded by `config.max_depth` (RFC-001 default when unset); with
+    /// `interprocedural = false` only flows inside the tainted function are
+    /// reported. Calls to external functions are resolved through the library
+    /// summaries, so they can be sources even when `sources` is empty.
     fn run_forward_analysis(
         &self,
-        _config: &TaintAnalysisConfig,
-        _nodes: &[Node],
-        _sources: &HashMap<String, HashSet<String>>,
-        _sinks: &HashMap<String, HashSet<String>>,
+        config: &TaintAnalysisConfig,
+        nodes: &[Node],
+        edges: &[Edge],
+        sources: &HashMap<String, HashSet<String>>,
+        sinks: &HashMap<String, HashSet<String>>,
+        sanitizer_patterns: &[String],
     ) -> Result<Vec<TaintPath>, TaintAnalysisError> {
-        // DESIGN: Forward taint requires call graph construction
-        // - Current: Returns empty (call graph not available at this layer)
-        // - Alternative: Use IFDS-based analysis via IFDSTaintService
-        // - Status: Intentional - use backward_analysis or IFDS for actual taint
-        Ok(Vec::new())
+        if sinks.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let forward_config = ForwardConfig {
+            max_depth: config.to_rfc001_config().max_depth,
+            interprocedural: config.interprocedural,
+            sanitizer_patterns: sanitizer_patterns.to_vec(),
+            library: self.library_summaries.clone(),
+        };
+        Ok(ForwardTaintAnalysis::new(forward_config, nodes, edges).run(sources, sinks))
     }
 
     /// Run backward taint analysis
@@ -390,6 +432,7 @@ impl TaintAnalysisService for DefaultTaintAnalysisService {
     async fn analyze_ir(
         &self,
         nodes: Vec<Node>,
+        edges: Vec<Edge>,
         config: TaintAnalysisConfig,
     ) -> Result<TaintAnalysisResponse, TaintAnalysisError> {
         // Direct IR analysis without going through repository
@@ -413,10 +456,18 @@ impl TaintAnalysisService for DefaultTaintAnalysisService {
             .use_case
             .build_sinks_map(&default_sink_patterns(), &nodes);
 
-        let forward_paths = self
-            .use_case
-            .run_forward_analysis(&config, &nodes, &sources, &sinks)?;
+        // Call edges are completed from `Call` nodes
+        let forward_paths = self.use_case.run_forward_analysis(
+            &config,
+            &nodes,
+            &edges,
+            &sources,
+            &sinks,
+            &default_sanitizer_patterns(),
+        )?;
         let forward_count = forward_paths.len();
+        let sanitized_count = forward_paths.iter().filter(|p| p.is_sanitized).count();
+        let edges_analyzed = forward_paths.iter().map(|p| p.path.len()).sum::<usize>();
 
         let elapsed = start.elapsed();
         Ok(TaintAnalysisResponse {
@@ -426,9 +477,9 @@ impl TaintAnalysisService for DefaultTaintAnalysisService {
             implicit_flows: vec![],
             stats: AnalysisStats {
                 nodes_analyzed: nodes.len(),
-                edges_analyzed: 0,
+                edges_analyzed,
                 paths_found: forward_count,
-                sanitized_paths: 0,
+                sanitized_paths: sanitized_count,
                 implicit_flows_found: 0,
                 analysis_time_ms: elapsed.as_millis() as u64,
                 memory_used_bytes: 0,
@@ -457,18 +508,25 @@ impl TaintAnalysisService for DefaultTaintAnalysisService {
 /// In-memory code repository for testing
 pub struct InMemoryCodeRepository {
     nodes: HashMap<String, Vec<Node>>,
+    edges: HashMap<String, Vec<Edge>>,
 }
 
 impl InMemoryCodeRepository {
     pub fn new() -> Self {
         Self {
             nodes: HashMap::new(),
+            edges: HashMap::new(),
         }
     }
 
     pub fn add_file(&mut self, path: &str, nodes: Vec<Node>) {
         self.nodes.insert(path.to_string(), nodes);
     }
+
+    pub fn add_file_with_edges(&mut self, path: &str, nodes: Vec<Node>, edges: Vec<Edge>) {
+        self.nodes.insert(path.to_string(), nodes);
+        self.edges.insert(path.to_string(), edges);
+    }
 }
 
 impl Default for InMemoryCodeRepository {
@@ -486,6 +544,10 @@ impl CodeRepository for InMemoryCodeRepository {
             .ok_or_else(|| TaintAnalysisError::not_found(format!("File not found: {}", path)))
     }
 
+    async fn get_ir_edges(&self, path: &str) -> Result<Vec<Edge>, TaintAnalysisError> {
+        Ok(self.edges.get(path).cloned().unwrap_or_default())
+    }
+
     async fn get_function_ir(&self, function_id: &str) -> Result<Vec<Node>, TaintAnalysisError> {
         // Search all files for function
         for nodes in self.nodes.values() {
@@ -570,7 +632,7 @@ impl TaintResultRepository for InMemoryResultRepository {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::shared::models::NodeKind;
+    use crate::shared::models::{EdgeKind, NodeKind};
 
     fn create_test_node(id: &str, name: &str) -> Node {
         use crate::shared::models::{NodeBuilder, Span};
@@ -660,6 +722,247 @@ mod tests {
         assert_eq!(result.stats.nodes_analyzed, 3);
     }
 
+    fn create_child_node(id: &str, name: &str, kind: NodeKind, parent: &str) -> Node {
+        use crate::shared::models::{NodeBuilder, Span};
+        NodeBuilder::new()
+            .id(id)
+            .with_name(name)
+            .kind(kind)
+            .file_path("test.py")
+            .span(Span::new(2, 4, 2, 20))
+            .fqn(format!("test::{}", name))
+            .language("python")
+            .with_parent(parent)
+            .build()
+            .expect("Failed to build test node")
+    }
+
+    #[tokio::test]
+    async fn test_forward_analysis_follows_call_graph() {
+        let mut code_repo = InMemoryCodeRepository::new();
+        code_repo.add_file_with_edges(
+            "test.py",
+            vec![
+                create_test_node("1", "user_input"),
+                create_test_node("2", "process"),
+                create_test_node("3", "helper"),
+                create_child_node("p3", "data", NodeKind::Parameter, "3"),
+                create_test_node("4", "execute"),
+            ],
+            vec![
+                Edge::new("2".into(), "1".into(), EdgeKind::Calls),
+                Edge::new("2".into(), "3".into(), EdgeKind::Calls),
+                Edge::new("3".into(), "4".into(), EdgeKind::Calls),
+                // helper(user_input()), execute(data)
+                Edge::new("1".into(), "p3".into(), EdgeKind::DataFlow),
+                Edge::new("p3".into(), "4".into(), EdgeKind::DataFlow),
+            ],
+        );
+        let use_case = AnalyzeTaintUseCase::new(Arc::new(code_repo), None);
+
+        let request = |config: TaintAnalysisConfig| TaintAnalysisRequest {
+            code_path: "test.py".to_string(),
+            config,
+            source_patterns: vec!["user_input".to_string()],
+            sink_patterns: vec!["execute".to_string()],
+            sanitizer_patterns: None,
+            mode: AnalysisMode::Balanced,
+        };
+
+        // user_input() returns into process → helper → execute
+        let config = TaintAnalysisConfig {
+            interprocedural: true,
+            max_depth: Some(3),
+            ..Default::default()
+        };
+        let result = use_case.execute(request(config)).await.unwrap();
+        assert_eq!(result.forward_paths.len(), 1);
+        assert_eq!(
+            result.forward_paths[0].path,
+            vec![
+                "test::user_input",
+                "test::process",
+                "test::helper",
+                "test::execute"
+            ]
+        );
+        assert!(!result.forward_paths[0].is_sanitized);
+
+        // The sink is one call hop away from the tainted function
+        let config = TaintAnalysisConfig {
+            interprocedural: true,
+            max_depth: Some(0),
+            ..Default::default()
+        };
+        let result = use_case.execute(request(config)).await.unwrap();
+        assert!(result.forward_paths.is_empty());
+
+        let result = use_case
+            .execute(request(TaintAnalysisConfig::default()))
+            .await
+            .unwrap();
+        assert!(result.forward_paths.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_forward_analysis_skips_clean_call_arguments() {
+        // process() reads user_input() but calls helper(config)
+        let mut code_repo = InMemoryCodeRepository::new();
+        code_repo.add_file_with_edges(
+            "test.py",
+            vec![
+                create_test_node("1", "user_input"),
+                create_test_node("2", "process"),
+                create_child_node("v2", "config", NodeKind::Variable, "2"),
+                create_test_node("3", "helper"),
+                create_child_node("p3", "data", NodeKind::Parameter, "3"),
+                create_test_node("4", "execute"),
+            ],
+            vec![
+                Edge::new("2".into(), "1".into(), EdgeKind::Calls),
+                Edge::new("2".into(), "3".into(), EdgeKind::Calls),
+                Edge::new("3".into(), "4".into(), EdgeKind::Calls),
+                Edge::new("v2".into(), "p3".into(), EdgeKind::DataFlow),
+                Edge::new("p3".into(), "4".into(), EdgeKind::DataFlow),
+            ],
+        );
+        let use_case = AnalyzeTaintUseCase::new(Arc::new(code_repo), None);
+
+        let request = TaintAnalysisRequest {
+            code_path: "test.py".to_string(),
+            config: TaintAnalysisConfig {
+                interprocedural: true,
+                max_depth: Some(3),
+                ..Default::default()
+            },
+            source_patterns: vec!["user_input".to_string()],
+            sink_patterns: vec!["execute".to_string()],
+            sanitizer_patterns: None,
+            mode: AnalysisMode::Balanced,
+        };
+        let result = use_case.execute(request).await.unwrap();
+        assert!(result.forward_paths.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_analyze_ir_skips_sinks_fed_by_clean_values() {
+        // user_input is read, but os.system only receives command
+        let nodes = vec![
+            create_test_node("f1", "handler"),
+            create_child_node("v1", "user_input", NodeKind::Variable, "f1"),
+            create_child_node("v2", "command", NodeKind::Variable, "f1"),
+            create_child_node("c1", "os.system", NodeKind::Call, "f1"),
+        ];
+        let edges = vec![Edge::new("v2".into(), "c1".into(), EdgeKind::DataFlow)];
+
+        let service =
+            DefaultTaintAnalysisService::new(Arc::new(InMemoryCodeRepository::new()), None);
+        let result = service
+            .analyze_ir(nodes, edges, TaintAnalysisConfig::default())
+            .await
+            .unwrap();
+        assert!(result.forward_paths.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_analyze_ir_intraprocedural() {
+        let nodes = vec![
+            create_test_node("f1", "handler"),
+            create_child_node("v1", "user_input", NodeKind::Variable, "f1"),
+            create_child_node("c1", "os.system", NodeKind::Call, "f1"),
+            create_test_node("f2", "safe_handler"),
+            create_child_node("v2", "request_body", NodeKind::Variable, "f2"),
+            create_child_node("c2", "escape", NodeKind::Call, "f2"),
+            create_child_node("c3", "cursor.execute", NodeKind::Call, "f2"),
+        ];
+        // os.system(user_input), cursor.execute(request_body)
+        let edges = vec![
+            Edge::new("v1".into(), "c1".into(), EdgeKind::DataFlow),
+            Edge::new("v2".into(), "c3".into(), EdgeKind::DataFlow),
+        ];
+
+        let service =
+            DefaultTaintAnalysisService::new(Arc::new(InMemoryCodeRepository::new()), None);
+        let result = service
+            .analyze_ir(nodes, edges, TaintAnalysisConfig::default())
+            .await
+            .unwrap();
+
+        assert_eq!(result.forward_paths.len(), 2);
+        let direct = &result.forward_paths[1];
+        assert_eq!(
+            direct.path,
+            vec!["test::user_input", "test::handler", "test::os.system"]
+        );
+        assert!(!direct.is_sanitized);
+
+        // escape() is called in the same function as the sink
+        let sanitized = &result.forward_paths[0];
+        assert_eq!(sanitized.source, "test::request_body");
+        assert_eq!(sanitized.sink, "test::cursor.execute");
+        assert!(sanitized.is_sanitized);
+        assert_eq!(result.stats.sanitized_paths, 1);
+    }
+
+    #[tokio::test]
+    async fn test_forward_analysis_uses_library_summaries() {
+        let mut code_repo = InMemoryCodeRepository::new();
+        code_repo.add_file_with_edges(
+            "test.py",
+            vec![
+                create_test_node("f1", "fetch_and_run"),
+                create_child_node("c1", "urllib.request.urlopen", NodeKind::Call, "f1"),
+                create_child_node("c2", "os.system", NodeKind::Call, "f1"),
+                create_test_node("f2", "quoted_run"),
+                create_child_node("c3", "requests.get", NodeKind::Call, "f2"),
+                create_child_node("c4", "shlex.quote", NodeKind::Call, "f2"),
+                create_child_node("c5", "os.system", NodeKind::Call, "f2"),
+                // helpers.load is modelled, but the project defines `load`
+                create_test_node("f3", "local"),
+                create_child_node("c6", "helpers.load", NodeKind::Call, "f3"),
+                create_child_node("c7", "os.system", NodeKind::Call, "f3"),
+                create_test_node("f4", "load"),
+            ],
+            vec![
+                Edge::new("c1".into(), "c2".into(), EdgeKind::DataFlow),
+                Edge::new("c3".into(), "c4".into(), EdgeKind::DataFlow),
+                Edge::new("c4".into(), "c5".into(), EdgeKind::DataFlow),
+                Edge::new("c6".into(), "c7".into(), EdgeKind::DataFlow),
+            ],
+        );
+        let mut library = LibrarySummaryDb::bundled();
+        library.merge(
+            LibrarySummaryDb::parse(
+                "version: 1\nsummaries:\n  - {function: helpers.load, returns_tainted: true}\n",
+            )
+            .unwrap(),
+        );
+        let use_case =
+            AnalyzeTaintUseCase::new(Arc::new(code_repo), None).with_library_summaries(library);
+
+        // No source patterns match: every origin comes from a library summary
+        let request = TaintAnalysisRequest {
+            code_path: "test.py".to_string(),
+            config: TaintAnalysisConfig::default(),
+            source_patterns: vec!["user_input".to_string()],
+            sink_patterns: vec!["system".to_string()],
+            sanitizer_patterns: Some(vec![]),
+            mode: AnalysisMode::Balanced,
+        };
+        let result = use_case.execute(request).await.unwrap();
+
+        assert_eq!(result.forward_paths.len(), 2);
+        let quoted = &result.forward_paths[0];
+        assert_eq!(
+            quoted.path,
+            vec!["test::requests.get", "test::quoted_run", "test::os.system"]
+        );
+        assert!(quoted.is_sanitized);
+        let direct = &result.forward_paths[1];
+        assert_eq!(direct.source, "test::urllib.request.urlopen");
+        assert!(!direct.is_sanitized);
+    }
+
     #[test]
     fn test_build_sources_map() {
         let code_repo = Arc::new(InMemoryCodeRepository::new());
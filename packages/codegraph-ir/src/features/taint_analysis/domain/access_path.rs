@@ -0,0 +1,209 @@
+// Access Paths for Field-Sensitive Taint Tracking
+//
+// An access path is a variable followed by field / key / index steps:
+// `user.name`, `data["key"]`, `rows[0].id`. Taint facts keyed by access path
+// keep `user.name` and `user.id` apart instead of tainting all of `user`.
+//
+// Paths are k-limited (Landi & Ryder 1992): a path truncated to `max_depth`
+// steps stands for all of its extensions, which keeps the fact domain finite
+// while staying sound.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// One step of an access path
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum FieldStep {
+    /// Attribute access: `.name`
+    Field(String),
+
+    /// Constant dict / map key: `["key"]`
+    Key(String),
+
+    /// Constant index: `[0]`
+    Index(i64),
+}
+
+/// Variable plus field / key / index steps
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct AccessPath {
+    base: String,
+    steps: Vec<FieldStep>,
+}
+
+impl AccessPath {
+    /// Path for a whole variable
+    pub fn variable(base: impl Into<String>) -> Self {
+        Self {
+            base: base.into(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Parse `base.field`, `base["key"]` and `base[0]` chains
+    ///
+    /// Parsing stops at the first dynamic subscript (`data[k]`): the rest of
+    /// the path is unknown, so the prefix stands for all of it.
+    pub fn parse(expr: &str) -> Self {
+        let expr = expr.trim();
+        let split = expr.find(['.', '[']).unwrap_or(expr.len());
+        if split == 0 {
+            return Self::variable(expr);
+        }
+
+        let (base, mut rest) = expr.split_at(split);
+        let mut path = Self::variable(base);
+        loop {
+            if let Some(after) = rest.strip_prefix('.') {
+                let end = after.find(['.', '[']).unwrap_or(after.len());
+                let name = after[..end].trim();
+                if name.is_empty() {
+                    break;
+                }
+                path.steps.push(FieldStep::Field(name.to_string()));
+                rest = &after[end..];
+            } else if let Some(after) = rest.strip_prefix('[') {
+                let Some(end) = after.find(']') else {
+                    break;
+                };
+                let Some(step) = parse_subscript(after[..end].trim()) else {
+                    break;
+                };
+                path.steps.push(step);
+                rest = &after[end + 1..];
+            } else {
+                break;
+            }
+        }
+        path
+    }
+
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    pub fn steps(&self) -> &[FieldStep] {
+        &self.steps
+    }
+
+    /// Number of field / key / index steps
+    pub fn depth(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Keep at most `max_depth` steps (0 = the base variable)
+    pub fn truncated(mut self, max_depth: usize) -> Self {
+        self.steps.truncate(max_depth);
+        self
+    }
+
+    /// `user` is a prefix of `user` and `user.name`, not of `user_id`
+    pub fn is_prefix_of(&self, other: &AccessPath) -> bool {
+        self.base == other.base && other.steps.starts_with(&self.steps)
+    }
+
+    /// Whether taint on one path affects the other
+    ///
+    /// `user.name` overlaps `user` (reading the object reads the field) and
+    /// `user.name.first`, but not `user.id`.
+    pub fn overlaps(&self, other: &AccessPath) -> bool {
+        self.is_prefix_of(other) || other.is_prefix_of(self)
+    }
+
+    /// Replace the `from` prefix with `to`
+    ///
+    /// `user.name` rebased from `user` to `x` is `x.name`. Returns `None`
+    /// when `from` is not a prefix of this path.
+    pub fn rebase(&self, from: &AccessPath, to: &AccessPath) -> Option<AccessPath> {
+        if !from.is_prefix_of(self) {
+            return None;
+        }
+        let mut steps = to.steps.clone();
+        steps.extend_from_slice(&self.steps[from.steps.len()..]);
+        Some(Self {
+            base: to.base.clone(),
+            steps,
+        })
+    }
+}
+
+impl fmt::Display for FieldStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Field(name) => write!(f, ".{}", name),
+            Self::Key(key) => write!(f, "[\"{}\"]", key),
+            Self::Index(index) => write!(f, "[{}]", index),
+        }
+    }
+}
+
+impl fmt::Display for AccessPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.base)?;
+        for step in &self.steps {
+            write!(f, "{}", step)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&str> for AccessPath {
+    fn from(expr: &str) -> Self {
+        Self::parse(expr)
+    }
+}
+
+/// `"key"` / `'key'` → Key, `0` → Index, anything else is dynamic
+fn parse_subscript(subscript: &str) -> Option<FieldStep> {
+    for quote in ['"', '\''] {
+        if subscript.len() >= 2 && subscript.starts_with(quote) && subscript.ends_with(quote) {
+            let key = &subscript[1..subscript.len() - 1];
+            return Some(FieldStep::Key(key.to_string()));
+        }
+    }
+    subscript.parse().ok().map(FieldStep::Index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        let path = AccessPath::parse("rows[0].meta['token']");
+        assert_eq!(path.base(), "rows");
+        assert_eq!(
+            path.steps(),
+            &[
+                FieldStep::Index(0),
+                FieldStep::Field("meta".into()),
+                FieldStep::Key("token".into()),
+            ]
+        );
+        assert_eq!(path.to_string(), "rows[0].meta[\"token\"]");
+        assert_eq!(AccessPath::parse(&path.to_string()), path);
+
+        // Dynamic subscript: the known prefix stands for the whole path
+        assert_eq!(AccessPath::parse("data[key].x").to_string(), "data");
+        assert_eq!(AccessPath::parse("user_input").depth(), 0);
+    }
+
+    #[test]
+    fn test_prefix_overlap_and_rebase() {
+        let user = AccessPath::parse("user");
+        let name = AccessPath::parse("user.name");
+        let id = AccessPath::parse("user.id");
+
+        assert!(user.is_prefix_of(&name));
+        assert!(!name.is_prefix_of(&user));
+        assert!(name.overlaps(&user));
+        assert!(!name.overlaps(&id));
+        assert!(!user.overlaps(&AccessPath::parse("user_id")));
+
+        let rebased = name.rebase(&user, &AccessPath::parse("copy[\"u\"]"));
+        assert_eq!(rebased.unwrap().to_string(), "copy[\"u\"].name");
+        assert!(id.rebase(&name, &user).is_none());
+
+        assert_eq!(AccessPath::parse("a.b.c.d").truncated(2).to_string(), "a.b");
+    }
+}
@@ -1,5 +1,7 @@
 // Taint analysis domain models
 
+pub mod access_path;
 pub mod function_summary;
 
+pub use access_path::{AccessPath, FieldStep};
 pub use function_summary::{FunctionSummaryCache, FunctionTaintSummary};
@@ -23,6 +23,7 @@ use std::fmt::Write as FmtWrite;
 
 use super::error::{DifferentialError, DifferentialResult};
 use super::result::{DiffStats, DifferentialTaintResult, Severity, Vulnerability};
+use crate::shared::i18n::{MessageId, ReportOptions};
 
 /// Simple URL encoding for path components
 /// Encodes characters that are not allowed in URLs
@@ -73,81 +74,116 @@ impl PRCommentFormatter {
     /// ...
     /// ```
     pub fn format_markdown(result: &DifferentialTaintResult) -> String {
+        Self::format_markdown_with(result, &ReportOptions::default())
+    }
+
+    /// Format result as GitHub-flavored Markdown in the given locale
+    ///
+    /// Markdown is always UTF-8; `report.encoding` is ignored.
+    pub fn format_markdown_with(
+        result: &DifferentialTaintResult,
+        report: &ReportOptions,
+    ) -> String {
         let mut output = String::with_capacity(4096);
 
         // Header
-        output.push_str("## 🔒 Security Regression Analysis\n\n");
+        let _ = writeln!(output, "## {}\n", report.text(MessageId::PrTitle));
 
         // Summary badge
-        let badge = Self::create_status_badge(result);
+        let badge = Self::create_status_badge(result, report);
         output.push_str(&badge);
         output.push_str("\n\n");
 
         // New vulnerabilities section
         if !result.new_vulnerabilities.is_empty() {
-            Self::format_new_vulnerabilities(&mut output, &result.new_vulnerabilities);
+            Self::format_new_vulnerabilities(&mut output, &result.new_vulnerabilities, report);
         }
 
         // Fixed vulnerabilities section
         if !result.fixed_vulnerabilities.is_empty() {
-            Self::format_fixed_vulnerabilities(&mut output, &result.fixed_vulnerabilities);
+            Self::format_fixed_vulnerabilities(&mut output, &result.fixed_vulnerabilities, report);
         }
 
         // Removed sanitizers warning
         if !result.removed_sanitizers.is_empty() {
-            Self::format_removed_sanitizers(&mut output, result);
+            Self::format_removed_sanitizers(&mut output, result, report);
         }
 
         // Added sanitizers (positive)
         if !result.added_sanitizers.is_empty() {
-            Self::format_added_sanitizers(&mut output, result);
+            Self::format_added_sanitizers(&mut output, result, report);
         }
 
         // Partial fixes section
         if !result.partially_fixed.is_empty() {
-            Self::format_partial_fixes(&mut output, result);
+            Self::format_partial_fixes(&mut output, result, report);
         }
 
         // Statistics footer
-        Self::format_statistics(&mut output, &result.stats);
+        Self::format_statistics(&mut output, &result.stats, report);
 
         // Footer with links
         output.push_str("\n---\n");
-        output.push_str("*Generated by [Semantica Differential Taint Analysis](https://github.com/semantica/codegraph)*\n");
+        let _ = writeln!(
+            output,
+            "{}",
+            report.format(
+                MessageId::PrFooter,
+                &["https://github.com/semantica/codegraph"]
+            )
+        );
 
         output
     }
 
     /// Create status badge based on results
-    fn create_status_badge(result: &DifferentialTaintResult) -> String {
+    fn create_status_badge(result: &DifferentialTaintResult, report: &ReportOptions) -> String {
         if result.has_high_severity_regression() {
-            "![Security Status](https://img.shields.io/badge/security-CRITICAL-red) **Action Required**".to_string()
+            format!(
+                "![Security Status](https://img.shields.io/badge/security-CRITICAL-red) **{}**",
+                report.text(MessageId::PrStatusCritical)
+            )
         } else if !result.new_vulnerabilities.is_empty() {
             format!(
-                "![Security Status](https://img.shields.io/badge/security-{}_new_issues-orange) **Review Required**",
-                result.new_vulnerabilities.len()
+                "![Security Status](https://img.shields.io/badge/security-{}_new_issues-orange) **{}**",
+                result.new_vulnerabilities.len(),
+                report.text(MessageId::PrStatusReview)
             )
         } else if !result.fixed_vulnerabilities.is_empty() {
             format!(
-                "![Security Status](https://img.shields.io/badge/security-{}_fixed-green) **Improved**",
-                result.fixed_vulnerabilities.len()
+                "![Security Status](https://img.shields.io/badge/security-{}_fixed-green) **{}**",
+                result.fixed_vulnerabilities.len(),
+                report.text(MessageId::PrStatusImproved)
             )
         } else {
-            "![Security Status](https://img.shields.io/badge/security-no_changes-lightgrey) **No Security Impact**".to_string()
+            format!(
+                "![Security Status](https://img.shields.io/badge/security-no_changes-lightgrey) **{}**",
+                report.text(MessageId::PrStatusNoImpact)
+            )
         }
     }
 
     /// Format new vulnerabilities section
-    fn format_new_vulnerabilities(output: &mut String, vulns: &[Vulnerability]) {
+    fn format_new_vulnerabilities(
+        output: &mut String,
+        vulns: &[Vulnerability],
+        report: &ReportOptions,
+    ) {
         let _ = writeln!(
             output,
-            "### 🚨 {} New Vulnerabilit{} Detected\n",
-            vulns.len(),
-            if vulns.len() == 1 { "y" } else { "ies" }
+            "### {}\n",
+            report.count(MessageId::PrNewVulnerabilities, vulns.len())
         );
 
         // Table header
-        output.push_str("| Severity | File | Source → Sink | Description |\n");
+        let _ = writeln!(
+            output,
+            "| {} | {} | {} | {} |",
+            report.text(MessageId::ColumnSeverity),
+            report.text(MessageId::ColumnFile),
+            report.text(MessageId::PrFlowColumn),
+            report.text(MessageId::ColumnDescription)
+        );
         output.push_str("|:--------:|------|---------------|-------------|\n");
 
         for vuln in vulns {
@@ -173,8 +209,10 @@ impl PRCommentFormatter {
             .collect();
 
         if !critical_high.is_empty() {
-            output.push_str(
-                "<details>\n<summary>🔍 Detailed Analysis (Click to expand)</summary>\n\n",
+            let _ = writeln!(
+                output,
+                "<details>\n<summary>{}</summary>\n",
+                report.text(MessageId::PrDetailedAnalysis)
             );
             for (i, vuln) in critical_high.iter().enumerate() {
                 let _ = writeln!(
@@ -184,21 +222,35 @@ impl PRCommentFormatter {
                     vuln.description,
                     vuln.severity.as_str()
                 );
-                let _ = writeln!(output, "**Taint Flow**:");
+                let _ = writeln!(output, "**{}**:", report.text(MessageId::PrTaintFlow));
                 let _ = writeln!(output, "```");
                 let _ = writeln!(
                     output,
-                    "Source: {} (line {})",
-                    vuln.source.name, vuln.source.line
+                    "{}",
+                    report.format(
+                        MessageId::PrFlowSource,
+                        &[&vuln.source.name, &vuln.source.line.to_string()]
+                    )
                 );
                 if let Some(ref path) = vuln.path_condition {
-                    let _ = writeln!(output, "Path Condition: {}", path);
+                    let _ = writeln!(
+                        output,
+                        "{}",
+                        report.format(MessageId::PrFlowPathCondition, &[path])
+                    );
                 }
-                let _ = writeln!(output, "Sink: {} (line {})", vuln.sink.name, vuln.sink.line);
+                let _ = writeln!(
+                    output,
+                    "{}",
+                    report.format(
+                        MessageId::PrFlowSink,
+                        &[&vuln.sink.name, &vuln.sink.line.to_string()]
+                    )
+                );
                 let _ = writeln!(output, "```\n");
 
                 if vuln.safe_in_base {
-                    output.push_str("⚠️ **This was safe in the base version** - a regression was introduced.\n\n");
+                    let _ = writeln!(output, "{}\n", report.text(MessageId::PrRegression));
                 }
             }
             output.push_str("</details>\n\n");
@@ -206,121 +258,164 @@ impl PRCommentFormatter {
     }
 
     /// Format fixed vulnerabilities section
-    fn format_fixed_vulnerabilities(output: &mut String, vulns: &[Vulnerability]) {
+    fn format_fixed_vulnerabilities(
+        output: &mut String,
+        vulns: &[Vulnerability],
+        report: &ReportOptions,
+    ) {
         let _ = writeln!(
             output,
-            "### ✅ {} Vulnerabilit{} Fixed\n",
-            vulns.len(),
-            if vulns.len() == 1 { "y" } else { "ies" }
+            "### {}\n",
+            report.count(MessageId::PrFixedVulnerabilities, vulns.len())
         );
 
-        output.push_str("<details>\n<summary>View fixed issues</summary>\n\n");
+        let _ = writeln!(
+            output,
+            "<details>\n<summary>{}</summary>\n",
+            report.text(MessageId::PrViewFixed)
+        );
         for vuln in vulns {
+            let location = format!("{}:{}", vuln.file_path, vuln.source.line);
             let _ = writeln!(
                 output,
-                "- ~~**{}** in `{}:{}`: {} → {}~~",
-                vuln.severity.as_str(),
-                vuln.file_path,
-                vuln.source.line,
-                vuln.source.name,
-                vuln.sink.name
+                "- ~~{}~~",
+                report.format(
+                    MessageId::PrFixedEntry,
+                    &[
+                        vuln.severity.as_str(),
+                        &location,
+                        &vuln.source.name,
+                        &vuln.sink.name
+                    ]
+                )
             );
         }
         output.push_str("\n</details>\n\n");
     }
 
     /// Format removed sanitizers warning
-    fn format_removed_sanitizers(output: &mut String, result: &DifferentialTaintResult) {
+    fn format_removed_sanitizers(
+        output: &mut String,
+        result: &DifferentialTaintResult,
+        report: &ReportOptions,
+    ) {
         let _ = writeln!(
             output,
-            "### ⚠️ {} Sanitizer{} Removed\n",
-            result.removed_sanitizers.len(),
-            if result.removed_sanitizers.len() == 1 {
-                ""
-            } else {
-                "s"
-            }
+            "### {}\n",
+            report.count(
+                MessageId::PrSanitizersRemoved,
+                result.removed_sanitizers.len()
+            )
         );
 
-        output.push_str("The following security controls were removed:\n\n");
+        let _ = writeln!(
+            output,
+            "{}\n",
+            report.text(MessageId::PrSanitizersRemovedIntro)
+        );
         for san in &result.removed_sanitizers {
+            let location = format!("{}:{}", san.file_path, san.line);
             let _ = writeln!(
                 output,
-                "- `{}` at `{}:{}`",
-                san.function_name, san.file_path, san.line
+                "- {}",
+                report.format(
+                    MessageId::PrSanitizerEntry,
+                    &[&san.function_name, &location]
+                )
             );
         }
         output.push('\n');
     }
 
     /// Format added sanitizers (positive feedback)
-    fn format_added_sanitizers(output: &mut String, result: &DifferentialTaintResult) {
+    fn format_added_sanitizers(
+        output: &mut String,
+        result: &DifferentialTaintResult,
+        report: &ReportOptions,
+    ) {
         let _ = writeln!(
             output,
-            "### 🛡️ {} Sanitizer{} Added\n",
-            result.added_sanitizers.len(),
-            if result.added_sanitizers.len() == 1 {
-                ""
-            } else {
-                "s"
-            }
+            "### {}\n",
+            report.count(MessageId::PrSanitizersAdded, result.added_sanitizers.len())
         );
 
-        output.push_str("New security controls added:\n\n");
+        let _ = writeln!(
+            output,
+            "{}\n",
+            report.text(MessageId::PrSanitizersAddedIntro)
+        );
         for san in &result.added_sanitizers {
+            let location = format!("{}:{}", san.file_path, san.line);
             let _ = writeln!(
                 output,
-                "- `{}` at `{}:{}`",
-                san.function_name, san.file_path, san.line
+                "- {}",
+                report.format(
+                    MessageId::PrSanitizerEntry,
+                    &[&san.function_name, &location]
+                )
             );
         }
         output.push('\n');
     }
 
     /// Format partial fixes section
-    fn format_partial_fixes(output: &mut String, result: &DifferentialTaintResult) {
+    fn format_partial_fixes(
+        output: &mut String,
+        result: &DifferentialTaintResult,
+        report: &ReportOptions,
+    ) {
         let _ = writeln!(
             output,
-            "### ⏳ {} Partial Fix{}\n",
-            result.partially_fixed.len(),
-            if result.partially_fixed.len() == 1 {
-                ""
-            } else {
-                "es"
-            }
+            "### {}\n",
+            report.count(MessageId::PrPartialFixes, result.partially_fixed.len())
         );
 
-        output.push_str("These vulnerabilities are partially addressed but still have remaining attack paths:\n\n");
+        let _ = writeln!(output, "{}\n", report.text(MessageId::PrPartialFixesIntro));
         for fix in &result.partially_fixed {
             let _ = writeln!(
                 output,
-                "- **{}**: {}/{} paths fixed ({}% coverage)",
+                "- **{}**: {}",
                 fix.vulnerability_id,
-                fix.fixed_paths,
-                fix.total_paths,
-                (fix.fixed_paths * 100) / fix.total_paths.max(1)
+                report.format(
+                    MessageId::PrPartialFixEntry,
+                    &[
+                        &fix.fixed_paths.to_string(),
+                        &fix.total_paths.to_string(),
+                        &((fix.fixed_paths * 100) / fix.total_paths.max(1)).to_string()
+                    ]
+                )
             );
         }
         output.push('\n');
     }
 
     /// Format statistics footer
-    fn format_statistics(output: &mut String, stats: &DiffStats) {
-        output.push_str("### 📊 Analysis Statistics\n\n");
-        output.push_str("| Metric | Value |\n");
-        output.push_str("|--------|-------|\n");
-        let _ = writeln!(
-            output,
-            "| Base vulnerabilities | {} |",
-            stats.base_vulnerabilities
-        );
+    fn format_statistics(output: &mut String, stats: &DiffStats, report: &ReportOptions) {
+        let _ = writeln!(output, "### {}\n", report.text(MessageId::PrStatistics));
         let _ = writeln!(
             output,
-            "| Modified vulnerabilities | {} |",
-            stats.modified_vulnerabilities
+            "| {} | {} |",
+            report.text(MessageId::ColumnMetric),
+            report.text(MessageId::ColumnValue)
         );
-        let _ = writeln!(output, "| Files analyzed | {} |", stats.files_analyzed);
-        let _ = writeln!(output, "| Analysis time | {}ms |", stats.analysis_time_ms);
+        output.push_str("|--------|-------|\n");
+        for (label, value) in [
+            (
+                MessageId::PrBaseVulnerabilities,
+                stats.base_vulnerabilities.to_string(),
+            ),
+            (
+                MessageId::PrModifiedVulnerabilities,
+                stats.modified_vulnerabilities.to_string(),
+            ),
+            (MessageId::PrFilesAnalyzed, stats.files_analyzed.to_string()),
+            (
+                MessageId::PrAnalysisTime,
+                format!("{}ms", stats.analysis_time_ms),
+            ),
+        ] {
+            let _ = writeln!(output, "| {} | {} |", report.text(label), value);
+        }
         output.push('\n');
     }
 
@@ -663,6 +758,9 @@ pub struct GitHubActionsReporter {
 
     /// API base URL (for GitHub Enterprise)
     api_base: String,
+
+    /// Locale of posted comments
+    report: ReportOptions,
 }
 
 impl GitHubActionsReporter {
@@ -677,6 +775,7 @@ impl GitHubActionsReporter {
             owner: owner.into(),
             repo: repo.into(),
             api_base: "https://api.github.com".to_string(),
+            report: ReportOptions::default(),
         }
     }
 
@@ -686,6 +785,12 @@ impl GitHubActionsReporter {
         self
     }
 
+    /// Set comment locale
+    pub fn with_report_options(mut self, report: ReportOptions) -> Self {
+        self.report = report;
+        self
+    }
+
     /// Post PR comment with analysis results
     ///
     /// # Arguments
@@ -699,7 +804,7 @@ impl GitHubActionsReporter {
         pr_number: u64,
         result: &DifferentialTaintResult,
     ) -> DifferentialResult<u64> {
-        let comment_body = PRCommentFormatter::format_markdown(result);
+        let comment_body = PRCommentFormatter::format_markdown_with(result, &self.report);
 
         let url = format!(
             "{}/repos/{}/{}/issues/{}/comments",
@@ -950,6 +1055,9 @@ pub struct GitLabCIReporter {
 
     /// API base URL
     api_base: String,
+
+    /// Locale of posted comments
+    report: ReportOptions,
 }
 
 impl GitLabCIReporter {
@@ -959,6 +1067,7 @@ impl GitLabCIReporter {
             token: token.into(),
             project: project.into(),
             api_base: "https://gitlab.com/api/v4".to_string(),
+            report: ReportOptions::default(),
         }
     }
 
@@ -968,13 +1077,19 @@ impl GitLabCIReporter {
         self
     }
 
+    /// Set comment locale
+    pub fn with_report_options(mut self, report: ReportOptions) -> Self {
+        self.report = report;
+        self
+    }
+
     /// Post MR comment
     pub async fn post_mr_comment(
         &self,
         mr_iid: u64,
         result: &DifferentialTaintResult,
     ) -> DifferentialResult<u64> {
-        let comment_body = PRCommentFormatter::format_markdown(result);
+        let comment_body = PRCommentFormatter::format_markdown_with(result, &self.report);
 
         let url = format!(
             "{}/projects/{}/merge_requests/{}/notes",
@@ -1094,6 +1209,7 @@ mod tests {
     use crate::features::taint_analysis::infrastructure::differential::result::{
         TaintSink, TaintSource, VulnerabilityCategory,
     };
+    use crate::shared::i18n::Locale;
 
     fn create_test_result() -> DifferentialTaintResult {
         let mut result = DifferentialTaintResult::new();
@@ -1175,6 +1291,20 @@ mod tests {
         assert!(!markdown.contains("New Vulnerability"));
     }
 
+    #[test]
+    fn test_markdown_format_korean() {
+        let result = create_test_result();
+        let report = ReportOptions::default().with_locale(Locale::Ko);
+        let markdown = PRCommentFormatter::format_markdown_with(&result, &report);
+
+        assert!(markdown.contains("## 🔒 보안 회귀 분석"));
+        assert!(markdown.contains("새 취약점 1건 발견"));
+        assert!(markdown.contains("| 심각도 | 파일 |"));
+        // Finding data is not translated
+        assert!(markdown.contains("execute_sql"));
+        assert!(!markdown.contains("Vulnerabilit"));
+    }
+
     #[test]
     fn test_plain_text_format() {
         let result = create_test_result();
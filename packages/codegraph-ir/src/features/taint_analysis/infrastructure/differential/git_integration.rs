@@ -369,7 +369,11 @@ impl GitDifferentialAnalyzer {
     }
 
     /// Get list of changed files between two commits
-    fn get_changed_files(&self, base: &str, head: &str) -> DifferentialResult<Vec<ChangedFile>> {
+    pub(crate) fn get_changed_files(
+        &self,
+        base: &str,
+        head: &str,
+    ) -> DifferentialResult<Vec<ChangedFile>> {
         // Resolve commit references
         let base_commit = self.resolve_commit(base)?;
         let head_commit = self.resolve_commit(head)?;
@@ -507,7 +511,7 @@ impl GitDifferentialAnalyzer {
     }
 
     /// Check if file type is supported for analysis
-    fn is_supported_file(&self, path: &Path) -> bool {
+    pub(crate) fn is_supported_file(&self, path: &Path) -> bool {
         let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
         matches!(ext, "py" | "js" | "ts" | "jsx" | "tsx" | "go" | "rs")
     }
@@ -33,6 +33,7 @@ pub mod analyzer;
 pub mod cache;
 pub mod cicd;
 pub mod error;
+#[cfg(feature = "git")]
 pub mod git_integration;
 pub mod ir_integration;
 pub mod result;
@@ -42,6 +43,7 @@ pub use cicd::{
     CIExitCode, GitHubActionsReporter, GitLabCIReporter, PRCommentFormatter, SarifReport,
 };
 pub use error::{DifferentialError, DifferentialResult};
+#[cfg(feature = "git")]
 pub use git_integration::{ChangeType, ChangedFile, GitDiffConfig, GitDifferentialAnalyzer};
 pub use ir_integration::IRTaintAnalyzer;
 pub use result::{
@@ -0,0 +1,392 @@
+/*
+ * Library Taint Summaries
+ *
+ * Taint models for third-party and standard library functions that have no
+ * IR in the analyzed project (`requests.get` returns tainted data,
+ * `html.escape` sanitizes, `json.loads` passes its argument's taint through).
+ * Interprocedural propagation consults these when a callee is external.
+ *
+ * A bundled model database ships with the crate (`library_summaries.yaml`);
+ * projects add their own files via `TaintConfig::library_summary_files`.
+ *
+ * File format (version 1):
+ * ```yaml
+ * version: 1
+ * name: acme-libs
+ * language: python            # default for summaries without `languages`
+ * summaries:
+ *   - function: acme.http.fetch       # qualified name (suffix match)
+ *     returns_tainted: true           # source: return value is tainted
+ *     description: Remote payload
+ *   - function: acme.html.clean
+ *     sanitizes: true                 # return value is clean
+ *   - function: acme.codec.decode
+ *     propagates: [0]                 # argument taint reaches the return
+ * ```
+ *
+ * When several summaries match a call, the last one loaded wins, so user
+ * files override the bundled models.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use super::taint::{TaintAnalyzer, TaintSource};
+use crate::config::TaintConfig;
+use crate::features::taint_analysis::domain::FunctionTaintSummary;
+use crate::shared::models::{CodegraphError, Result};
+
+/// Supported summary file versions
+pub const LIBRARY_SUMMARY_VERSIONS: &[u32] = &[1];
+
+/// Bundled model database
+const BUNDLED_SUMMARIES: &str = include_str!("library_summaries.yaml");
+
+/// Taint behaviour of one library function
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LibrarySummary {
+    /// Qualified function name, e.g. `requests.get`
+    ///
+    /// Matches the name itself or any name ending in `.<function>`.
+    pub function: String,
+
+    /// Languages the summary applies to (empty = file default, or all)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub languages: Vec<String>,
+
+    /// The return value is tainted regardless of the arguments
+    #[serde(default)]
+    pub returns_tainted: bool,
+
+    /// The return value is clean regardless of the arguments
+    #[serde(default)]
+    pub sanitizes: bool,
+
+    /// Argument positions whose taint reaches the return value
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub propagates: Vec<usize>,
+
+    #[serde(default)]
+    pub description: String,
+}
+
+impl LibrarySummary {
+    /// Whether a call name refers to this function
+    pub fn matches(&self, name: &str) -> bool {
+        name.strip_suffix(self.function.as_str())
+            .is_some_and(|prefix| prefix.is_empty() || prefix.ends_with(['.', ':']))
+    }
+
+    /// Domain summary for the interprocedural summary cache
+    pub fn to_function_summary(&self) -> FunctionTaintSummary {
+        let mut summary = FunctionTaintSummary::new(self.function.clone());
+        summary.tainted_params = self.propagates.iter().copied().collect();
+        summary.tainted_return = self.returns_tainted || !self.propagates.is_empty();
+        summary.sanitizes = self.sanitizes;
+        summary
+            .metadata
+            .insert("origin".to_string(), "library".to_string());
+        if self.returns_tainted {
+            summary
+                .metadata
+                .insert("source".to_string(), self.description.clone());
+        }
+        summary
+    }
+
+    fn applies_to(&self, language: Option<&str>) -> bool {
+        match language {
+            Some(lang) if !self.languages.is_empty() => {
+                self.languages.iter().any(|l| l.eq_ignore_ascii_case(lang))
+            }
+            _ => true,
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        let invalid = |reason: &str| {
+            Err(CodegraphError::config(format!(
+                "library summary '{}': {}",
+                self.function, reason
+            )))
+        };
+        if self.function.trim().is_empty() {
+            return Err(CodegraphError::config(
+                "library summary: `function` must not be empty",
+            ));
+        }
+        if self.sanitizes && (self.returns_tainted || !self.propagates.is_empty()) {
+            return invalid(
+                "`sanitizes` cannot be combined with `returns_tainted` or `propagates`",
+            );
+        }
+        if !self.sanitizes && !self.returns_tainted && self.propagates.is_empty() {
+            return invalid("set `returns_tainted`, `sanitizes` or `propagates`");
+        }
+        Ok(())
+    }
+}
+
+/// Library summaries loaded from the bundled database and/or summary files
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LibrarySummaryDb {
+    pub version: u32,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Default language for summaries without `languages`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+
+    #[serde(default)]
+    pub summaries: Vec<LibrarySummary>,
+}
+
+impl Default for LibrarySummaryDb {
+    fn default() -> Self {
+        Self {
+            version: 1,
+            name: None,
+            language: None,
+            summaries: Vec::new(),
+        }
+    }
+}
+
+impl LibrarySummaryDb {
+    /// Parse a summary file (YAML; JSON is accepted as a YAML subset)
+    pub fn parse(content: &str) -> Result<Self> {
+        let db: Self = serde_yaml::from_str(content)
+            .map_err(|e| CodegraphError::config(format!("invalid library summaries: {}", e)))?;
+        db.validate()?;
+        Ok(db)
+    }
+
+    /// The model database shipped with the crate
+    pub fn bundled() -> Self {
+        let mut db = Self::default();
+        db.merge(Self::parse(BUNDLED_SUMMARIES).expect("bundled library summaries are valid"));
+        db
+    }
+
+    /// Load a summary file, or every summary file in a directory
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.is_dir() {
+            let content = std::fs::read_to_string(path).map_err(|e| {
+                CodegraphError::io(format!(
+                    "cannot read library summaries {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+            return Self::parse(&content).map_err(|e| e.with_file(path.display().to_string()));
+        }
+
+        let mut files: Vec<_> = std::fs::read_dir(path)
+            .map_err(|e| CodegraphError::io(format!("cannot read {}: {}", path.display(), e)))?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|p| {
+                p.is_file()
+                    && matches!(
+                        p.extension().and_then(|e| e.to_str()),
+                        Some("yaml" | "yml" | "json")
+                    )
+            })
+            .collect();
+        files.sort();
+
+        let mut merged = Self::default();
+        for file in files {
+            merged.merge(Self::from_path(&file)?);
+        }
+        Ok(merged)
+    }
+
+    /// Bundled summaries plus every file in `TaintConfig::library_summary_files`
+    pub fn from_config(config: &TaintConfig) -> Result<Self> {
+        let mut db = Self::bundled();
+        for path in &config.library_summary_files {
+            db.merge(Self::from_path(path)?);
+        }
+        Ok(db)
+    }
+
+    /// Append another database (its summaries take precedence)
+    ///
+    /// A file-level `language` is pushed down into summaries that do not
+    /// list `languages`.
+    pub fn merge(&mut self, mut other: LibrarySummaryDb) {
+        if let Some(lang) = other.language.take() {
+            for summary in other
+                .summaries
+                .iter_mut()
+                .filter(|s| s.languages.is_empty())
+            {
+                summary.languages.push(lang.clone());
+            }
+        }
+        if self.name.is_none() {
+            self.name = other.name;
+        }
+        self.summaries.extend(other.summaries);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.summaries.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.summaries.len()
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if !LIBRARY_SUMMARY_VERSIONS.contains(&self.version) {
+            return Err(CodegraphError::config(format!(
+                "unsupported library summary version {} (supported: {:?})",
+                self.version, LIBRARY_SUMMARY_VERSIONS
+            )));
+        }
+        self.summaries.iter().try_for_each(LibrarySummary::validate)
+    }
+
+    /// Summary for a call name in `language` (`None` = any language)
+    pub fn lookup(&self, name: &str, language: Option<&str>) -> Option<&LibrarySummary> {
+        self.summaries
+            .iter()
+            .rev()
+            .find(|s| s.matches(name) && s.applies_to(language))
+    }
+
+    /// Whether a call to `name` returns tainted data
+    pub fn is_source(&self, name: &str, language: Option<&str>) -> bool {
+        self.lookup(name, language)
+            .is_some_and(|s| s.returns_tainted)
+    }
+
+    /// Whether a call to `name` returns sanitized data
+    pub fn is_sanitizer(&self, name: &str, language: Option<&str>) -> bool {
+        self.lookup(name, language).is_some_and(|s| s.sanitizes)
+    }
+
+    /// Add the summaries that apply to `languages` (empty = all) to an
+    /// analyzer: tainted returns become sources, sanitizing functions
+    /// become sanitizers
+    pub fn apply_to(&self, analyzer: &mut TaintAnalyzer, languages: &[&str]) {
+        // Later summaries for the same function replace earlier ones
+        let mut effective: BTreeMap<(&str, &[String]), &LibrarySummary> = BTreeMap::new();
+        for summary in self.summaries.iter().filter(|s| {
+            languages.is_empty() || languages.iter().any(|lang| s.applies_to(Some(lang)))
+        }) {
+            effective.insert(
+                (summary.function.as_str(), summary.languages.as_slice()),
+                summary,
+            );
+        }
+
+        for summary in effective.into_values() {
+            if summary.returns_tainted {
+                let pattern = format!(r"(?:^|[.:]){}$", regex::escape(&summary.function));
+                analyzer.push_source(TaintSource::new(&pattern, &summary.description));
+            } else if summary.sanitizes {
+                analyzer.add_sanitizer(&summary.function.to_lowercase());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::taint_analysis::infrastructure::taint::{
+        CallGraphNode, TaintSeverity, TaintSink,
+    };
+    use std::collections::{HashMap, HashSet};
+
+    #[test]
+    fn test_bundled_lookup_and_overrides() {
+        let bundled = LibrarySummaryDb::bundled();
+        assert!(bundled.is_source("requests.get", Some("python")));
+        assert!(bundled.is_source("app.vendor.requests.get", Some("python")));
+        assert!(!bundled.is_source("get", Some("python")));
+        assert!(!bundled.is_source("myrequests.get", Some("python")));
+        assert!(bundled.is_sanitizer("html.escape", None));
+        assert!(!bundled.is_sanitizer("DOMPurify.sanitize", Some("python")));
+        assert!(bundled.is_sanitizer("DOMPurify.sanitize", Some("typescript")));
+
+        let decode = bundled.lookup("json.loads", Some("python")).unwrap();
+        let summary = decode.to_function_summary();
+        assert!(summary.is_tainted_call(&HashSet::from([0])));
+        assert!(!summary.is_tainted_call(&HashSet::from([1])));
+
+        // User files are merged after the bundled models and win
+        let mut db = LibrarySummaryDb::bundled();
+        db.merge(
+            LibrarySummaryDb::parse(
+                "version: 1\nlanguage: python\nsummaries:\n  - {function: html.escape, propagates: [0]}\n",
+            )
+            .unwrap(),
+        );
+        assert!(!db.is_sanitizer("html.escape", Some("python")));
+        assert_eq!(db.len(), bundled.len() + 1);
+
+        assert!(LibrarySummaryDb::parse("version: 2\n").is_err());
+        assert!(LibrarySummaryDb::parse("version: 1\nsummaries:\n  - function: f\n").is_err());
+        assert!(LibrarySummaryDb::parse(
+            "version: 1\nsummaries:\n  - {function: f, sanitizes: true, returns_tainted: true}\n"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_apply_to_analyzer() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("acme.yaml");
+        std::fs::write(
+            &file,
+            "version: 1\nsummaries:\n  - {function: acme.fetch, returns_tainted: true}\n",
+        )
+        .unwrap();
+        let config = TaintConfig::default().library_summary_file(file.to_string_lossy());
+        let db = LibrarySummaryDb::from_config(&config).unwrap();
+
+        let mut analyzer = TaintAnalyzer::with_rules(
+            Vec::new(),
+            vec![TaintSink::new(
+                r"os\.system",
+                "command",
+                TaintSeverity::High,
+            )],
+            HashSet::new(),
+        );
+        db.apply_to(&mut analyzer, &["python"]);
+        assert!(analyzer
+            .get_sources()
+            .iter()
+            .any(|s| s.matches("acme.fetch")));
+
+        let node = |id: &str, callees: &[&str]| {
+            (
+                id.to_string(),
+                CallGraphNode {
+                    id: id.to_string(),
+                    name: id.to_string(),
+                    callees: callees.iter().map(|c| c.to_string()).collect(),
+                },
+            )
+        };
+        let call_graph: HashMap<String, CallGraphNode> = [
+            node("requests.get", &["handler"]),
+            node("handler", &["os.system"]),
+            node("os.system", &[]),
+        ]
+        .into();
+        let paths = analyzer.analyze(&call_graph);
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].source, "requests.get");
+    }
+}
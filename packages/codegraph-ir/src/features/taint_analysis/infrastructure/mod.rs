@@ -18,7 +18,10 @@ pub mod ifds_solver; // IFDS tabulation algorithm (SOTA)
 pub mod interprocedural; // SOTA: Refactored interprocedural taint (5 modules)
 pub mod interprocedural_errors; // Error types
 pub mod interprocedural_taint; // Legacy: kept for backward compatibility (test migration pending)
+pub mod library_summary; // Taint models for external library functions
 pub mod pta_ir_extractor; // Points-to constraint extraction from IR
+pub mod rule_set; // Source/sink/sanitizer rule files (YAML/JSON)
+pub mod snapshot_result_store; // Results persisted per snapshot (codegraph-storage)
 pub mod sota_taint_analyzer; // COMPLETE SOTA integration
 pub mod sparse_ifds;
 pub mod taint;
@@ -36,6 +39,9 @@ pub mod security_lattice; // Security Lattice + Non-interference (SOTA)
 // 🆕 Differential Taint Analysis (RFC-001-IN-DEVELOPMENT)
 pub mod differential; // Security regression detection
 
+pub use library_summary::{LibrarySummary, LibrarySummaryDb, LIBRARY_SUMMARY_VERSIONS};
+pub use rule_set::{RuleSeverity, TaintRule, TaintRuleSet, TAINT_RULE_VERSIONS};
+pub use snapshot_result_store::SnapshotTaintResultRepository;
 pub use taint::*;
 
 // SOTA: Use refactored interprocedural module (primary)
@@ -108,14 +114,9 @@ pub use security_lattice::{
 // Differential taint exports
 pub use differential::{
     CIExitCode,
-    ChangeType,
-    ChangedFile,
     DiffStats,
     DifferentialTaintAnalyzer,
     DifferentialTaintResult,
-    GitDiffConfig,
-    // Git Integration
-    GitDifferentialAnalyzer,
     GitHubActionsReporter,
     GitLabCIReporter,
     // CI/CD Integration
@@ -129,3 +130,6 @@ pub use differential::{
     Vulnerability,
     VulnerabilityCategory,
 };
+// Git Integration
+#[cfg(feature = "git")]
+pub use differential::{ChangeType, ChangedFile, GitDiffConfig, GitDifferentialAnalyzer};
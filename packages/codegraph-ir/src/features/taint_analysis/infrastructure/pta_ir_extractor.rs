@@ -26,8 +26,15 @@
  *     elif edge.kind == Calls:
  *         # Indirect call through pointer
  *         add_indirect_call(edge.source, edge.target)
+ *         # Argument → parameter binding (per context in 1-CFA / 1-object)
+ *         add_call(edge.source, edge.target, edge.arguments)
  * ```
  *
+ * Parameters and named variables are scoped to their parent function, so
+ * context-sensitive modes can clone them per context. The IR records no
+ * call receivers, so object sensitivity only separates what receiver-less
+ * calls inherit; call-site sensitivity gets the full benefit.
+ *
  * References:
  * - Andersen (1994): "Program Analysis and Specialization for the C Programming Language"
  * - SHARP (OOPSLA 2022): Incremental context-sensitive pointer analysis
@@ -35,7 +42,7 @@
 
 use crate::features::points_to::PointsToAnalyzer;
 use crate::shared::models::{Edge, EdgeKind, Node, NodeKind};
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 /// Points-to constraint extractor from IR
 ///
@@ -73,6 +80,15 @@ impl PTAIRExtractor {
     ) -> usize {
         let mut count = 0;
 
+        let functions: FxHashSet<&str> = nodes
+            .iter()
+            .filter(|n| matches!(n.kind, NodeKind::Function | NodeKind::Method))
+            .map(|n| n.id.as_str())
+            .collect();
+        // Function → parameter IDs (declaration order), (function, name) → variable ID
+        let mut params: FxHashMap<&str, Vec<&str>> = FxHashMap::default();
+        let mut locals: FxHashMap<(&str, &str), &str> = FxHashMap::default();
+
         // Phase 1: Process nodes (create variables and allocations)
         for node in nodes {
             match node.kind {
@@ -82,6 +98,17 @@ impl PTAIRExtractor {
                         count += 1;
                         // Variables are implicitly created when used in constraints
                     }
+                    if let Some(function) =
+                        node.parent_id.as_deref().filter(|p| functions.contains(p))
+                    {
+                        analyzer.add_method_var(function, &node.id);
+                        if node.kind == NodeKind::Parameter {
+                            params.entry(function).or_default().push(&node.id);
+                        }
+                        if let Some(name) = node.name.as_deref() {
+                            locals.insert((function, name), &node.id);
+                        }
+                    }
                 }
                 NodeKind::Function | NodeKind::Method => {
                     // Function object allocation
@@ -105,6 +132,13 @@ impl PTAIRExtractor {
             }
         }
 
+        for node in nodes {
+            if functions.contains(node.id.as_str()) {
+                let formals = params.get(node.id.as_str()).cloned().unwrap_or_default();
+                analyzer.add_method(&node.id, &formals, None, None);
+            }
+        }
+
         // Phase 2: Process edges (create constraints)
         for edge in edges {
             match edge.kind {
@@ -136,6 +170,22 @@ impl PTAIRExtractor {
                     // Model as: source = target (copy constraint for function pointer)
                     analyzer.add_copy(&edge.source_id, &edge.target_id);
                     count += 1;
+
+                    // Bind leading arguments that name caller variables
+                    if functions.contains(edge.target_id.as_str()) {
+                        let caller = functions.get(edge.source_id.as_str()).copied();
+                        let args: Vec<&str> = edge
+                            .metadata
+                            .as_ref()
+                            .and_then(|m| m.arguments.as_ref())
+                            .into_iter()
+                            .flatten()
+                            .map_while(|arg| {
+                                caller.and_then(|c| locals.get(&(c, arg.trim())).copied())
+                            })
+                            .collect();
+                        analyzer.add_call(caller, &edge.target_id, None, &args, None);
+                    }
                 }
                 EdgeKind::References => {
                     // source references target → source = &target (address-of)
@@ -191,8 +241,9 @@ pub struct PTAExtractionStats {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::features::points_to::application::analyzer::PointsToGraphExt;
     use crate::features::points_to::{AnalysisConfig, AnalysisMode};
-    use crate::shared::models::Span;
+    use crate::shared::models::{EdgeMetadata, Span};
 
     fn create_var_node(id: &str) -> Node {
         Node::new(
@@ -285,6 +336,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_call_binds_arguments() {
+        let mut param = create_var_node("p").with_parent("id");
+        param.kind = NodeKind::Parameter;
+        let nodes = vec![
+            create_func_node("main"),
+            create_func_node("id"),
+            param,
+            create_var_node("x").with_parent("main"),
+            create_var_node("y").with_parent("main"),
+        ];
+        let call = |arg: &str| {
+            create_edge("main", "id", EdgeKind::Calls).with_metadata(EdgeMetadata {
+                arguments: Some(vec![arg.to_string()]),
+                ..Default::default()
+            })
+        };
+        let edges = vec![
+            create_edge("main", "x", EdgeKind::Defines),
+            create_edge("main", "y", EdgeKind::Defines),
+            call("x"),
+            call("y"),
+        ];
+
+        let mut analyzer = PointsToAnalyzer::new(AnalysisConfig {
+            mode: AnalysisMode::Precise,
+            ..Default::default()
+        });
+        PTAIRExtractor::new().extract_constraints(&nodes, &edges, &mut analyzer);
+        let result = analyzer.solve();
+
+        // p = x and p = y from the two calls
+        assert!(result.graph.may_alias_by_name(&analyzer, "p", "x"));
+        assert!(result.graph.may_alias_by_name(&analyzer, "p", "y"));
+        assert!(!result.graph.may_alias_by_name(&analyzer, "x", "y"));
+    }
+
     #[test]
     fn test_call_constraint() {
         let nodes = vec![create_func_node("main"), create_func_node("foo")];
@@ -0,0 +1,522 @@
+/*
+ * Taint Rule Sets
+ *
+ * Loads taint sources/sinks/sanitizers from YAML/JSON rule files so teams
+ * can maintain their own security rule packs instead of relying on the
+ * built-in substring patterns.
+ *
+ * File format (version 1):
+ * ```yaml
+ * version: 1
+ * name: acme-python
+ * language: python            # default for rules without `languages`
+ * sources:
+ *   - id: flask-args
+ *     function: flask.request.args.get   # qualified name (suffix match)
+ *     description: Query string parameter
+ * sinks:
+ *   - id: sqlite-execute
+ *     function: sqlite3.Cursor.execute
+ *     args: [0]                          # dangerous argument positions
+ *     severity: high
+ *   - pattern: 'raw_sql\('               # regex, for anything else
+ *     languages: [python, javascript]
+ * sanitizers:
+ *   - function: markupsafe.escape
+ * severity_overrides:
+ *   sqlite-execute: medium              # by rule id, function or pattern
+ *   builtins.open: low                  # also applies to built-in sinks
+ * ```
+ *
+ * `TaintRuleSet::from_path` accepts a single file or a directory; every
+ * `*.yaml`, `*.yml` and `*.json` file in a directory is loaded in name order
+ * and merged.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use super::taint::{TaintAnalyzer, TaintSeverity, TaintSink, TaintSource};
+use crate::config::TaintConfig;
+use crate::shared::models::{CodegraphError, Result};
+
+/// Supported rule file versions
+pub const TAINT_RULE_VERSIONS: &[u32] = &[1];
+
+/// Rule severity (serialized lowercase)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleSeverity {
+    High,
+    Medium,
+    Low,
+}
+
+impl From<RuleSeverity> for TaintSeverity {
+    fn from(severity: RuleSeverity) -> Self {
+        match severity {
+            RuleSeverity::High => TaintSeverity::High,
+            RuleSeverity::Medium => TaintSeverity::Medium,
+            RuleSeverity::Low => TaintSeverity::Low,
+        }
+    }
+}
+
+/// Single source, sink or sanitizer rule
+///
+/// Exactly one of `function` (qualified name) or `pattern` (regex) is set.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TaintRule {
+    /// Stable rule id (target of `severity_overrides`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Qualified function name, e.g. `sqlite3.Cursor.execute`
+    ///
+    /// Matches the name itself or any name ending in `.<function>`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub function: Option<String>,
+
+    /// Regex matched against call names
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+
+    /// Languages the rule applies to (empty = rule set default, or all)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub languages: Vec<String>,
+
+    /// Argument positions (sources: tainted out-arguments, sinks: dangerous
+    /// arguments; empty = return value / any argument)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<usize>,
+
+    /// Sink severity (default: high)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<RuleSeverity>,
+
+    #[serde(default)]
+    pub description: String,
+}
+
+impl TaintRule {
+    /// Rule matching a qualified function name
+    pub fn function(name: impl Into<String>) -> Self {
+        Self {
+            function: Some(name.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Rule matching a regex
+    pub fn pattern(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: Some(pattern.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Regex the analyzer matches call names against
+    pub fn matcher(&self) -> String {
+        match (&self.function, &self.pattern) {
+            (Some(function), _) => format!(r"(?:^|[.:]){}$", regex::escape(function)),
+            (None, Some(pattern)) => pattern.clone(),
+            (None, None) => String::new(),
+        }
+    }
+
+    /// Human-readable label (id, function or pattern)
+    pub fn label(&self) -> &str {
+        self.id
+            .as_deref()
+            .or(self.function.as_deref())
+            .or(self.pattern.as_deref())
+            .unwrap_or("<unnamed>")
+    }
+
+    fn applies_to(&self, default_language: Option<&str>, languages: &[&str]) -> bool {
+        if languages.is_empty() {
+            return true;
+        }
+        let matches = |lang: &str| languages.iter().any(|l| l.eq_ignore_ascii_case(lang));
+        if !self.languages.is_empty() {
+            return self.languages.iter().any(|l| matches(l));
+        }
+        match default_language {
+            Some(lang) => matches(lang),
+            None => true,
+        }
+    }
+
+    fn is_target_of(&self, key: &str) -> bool {
+        [&self.id, &self.function, &self.pattern]
+            .iter()
+            .any(|v| v.as_deref() == Some(key))
+    }
+
+    fn validate(&self, kind: &str) -> Result<()> {
+        match (&self.function, &self.pattern) {
+            (Some(_), Some(_)) => Err(CodegraphError::config(format!(
+                "{} rule '{}': set either `function` or `pattern`, not both",
+                kind,
+                self.label()
+            ))),
+            (None, None) => Err(CodegraphError::config(format!(
+                "{} rule '{}': `function` or `pattern` is required",
+                kind,
+                self.label()
+            ))),
+            (Some(function), None) if function.trim().is_empty() => Err(CodegraphError::config(
+                format!("{} rule: `function` must not be empty", kind),
+            )),
+            (None, Some(pattern)) => regex::Regex::new(pattern).map(|_| ()).map_err(|e| {
+                CodegraphError::config(format!(
+                    "{} rule '{}': invalid pattern: {}",
+                    kind,
+                    self.label(),
+                    e
+                ))
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Set of taint rules loaded from one or more rule files
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TaintRuleSet {
+    pub version: u32,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Default language for rules without `languages`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+
+    #[serde(default)]
+    pub sources: Vec<TaintRule>,
+
+    #[serde(default)]
+    pub sinks: Vec<TaintRule>,
+
+    #[serde(default)]
+    pub sanitizers: Vec<TaintRule>,
+
+    /// Sink severity overrides keyed by rule id, function or pattern
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub severity_overrides: BTreeMap<String, RuleSeverity>,
+}
+
+impl Default for TaintRuleSet {
+    fn default() -> Self {
+        Self {
+            version: 1,
+            name: None,
+            language: None,
+            sources: Vec::new(),
+            sinks: Vec::new(),
+            sanitizers: Vec::new(),
+            severity_overrides: BTreeMap::new(),
+        }
+    }
+}
+
+impl TaintRuleSet {
+    /// Parse a rule file (YAML; JSON is accepted as a YAML subset)
+    pub fn parse(content: &str) -> Result<Self> {
+        let rules: Self = serde_yaml::from_str(content)
+            .map_err(|e| CodegraphError::config(format!("invalid taint rules: {}", e)))?;
+        rules.validate()?;
+        Ok(rules)
+    }
+
+    /// Load a rule file, or every rule file in a directory
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.is_dir() {
+            let content = std::fs::read_to_string(path).map_err(|e| {
+                CodegraphError::io(format!("cannot read taint rules {}: {}", path.display(), e))
+            })?;
+            return Self::parse(&content).map_err(|e| e.with_file(path.display().to_string()));
+        }
+
+        let mut files: Vec<_> = std::fs::read_dir(path)
+            .map_err(|e| CodegraphError::io(format!("cannot read {}: {}", path.display(), e)))?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|p| {
+                p.is_file()
+                    && matches!(
+                        p.extension().and_then(|e| e.to_str()),
+                        Some("yaml" | "yml" | "json")
+                    )
+            })
+            .collect();
+        files.sort();
+
+        let mut merged = Self::default();
+        for file in files {
+            merged.merge(Self::from_path(&file)?);
+        }
+        Ok(merged)
+    }
+
+    /// Load every rule file configured in `TaintConfig::rule_files`
+    pub fn from_config(config: &TaintConfig) -> Result<Self> {
+        let mut merged = Self::default();
+        for path in &config.rule_files {
+            merged.merge(Self::from_path(path)?);
+        }
+        Ok(merged)
+    }
+
+    /// Append another rule set (its severity overrides win)
+    ///
+    /// Rules keep their own language: a file-level `language` is pushed
+    /// down into rules that do not list `languages`.
+    pub fn merge(&mut self, mut other: TaintRuleSet) {
+        if let Some(lang) = other.language.take() {
+            for rule in other
+                .sources
+                .iter_mut()
+                .chain(other.sinks.iter_mut())
+                .chain(other.sanitizers.iter_mut())
+                .filter(|r| r.languages.is_empty())
+            {
+                rule.languages.push(lang.clone());
+            }
+        }
+        if self.name.is_none() {
+            self.name = other.name;
+        }
+        self.sources.extend(other.sources);
+        self.sinks.extend(other.sinks);
+        self.sanitizers.extend(other.sanitizers);
+        self.severity_overrides.extend(other.severity_overrides);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty() && self.sinks.is_empty() && self.sanitizers.is_empty()
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if !TAINT_RULE_VERSIONS.contains(&self.version) {
+            return Err(CodegraphError::config(format!(
+                "unsupported taint rule version {} (supported: {:?})",
+                self.version, TAINT_RULE_VERSIONS
+            )));
+        }
+
+        let mut ids = HashSet::new();
+        for (kind, rules) in [
+            ("source", &self.sources),
+            ("sink", &self.sinks),
+            ("sanitizer", &self.sanitizers),
+        ] {
+            for rule in rules {
+                rule.validate(kind)?;
+                if let Some(id) = &rule.id {
+                    if !ids.insert(id.as_str()) {
+                        return Err(CodegraphError::config(format!(
+                            "duplicate taint rule id '{}'",
+                            id
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Effective severity of a sink rule
+    pub fn sink_severity(&self, rule: &TaintRule) -> TaintSeverity {
+        [&rule.id, &rule.function, &rule.pattern]
+            .into_iter()
+            .flatten()
+            .find_map(|key| self.severity_overrides.get(key))
+            .or(rule.severity.as_ref())
+            .copied()
+            .unwrap_or(RuleSeverity::High)
+            .into()
+    }
+
+    /// Add the rules that apply to `languages` (empty = all) to an analyzer
+    ///
+    /// Severity overrides that do not target a rule of this set are applied
+    /// to the analyzer's existing sinks by pattern.
+    pub fn apply_to(&self, analyzer: &mut TaintAnalyzer, languages: &[&str]) {
+        let lang = self.language.as_deref();
+
+        for rule in self
+            .sources
+            .iter()
+            .filter(|r| r.applies_to(lang, languages))
+        {
+            analyzer.push_source(
+                TaintSource::new(&rule.matcher(), &rule.description)
+                    .with_arg_positions(rule.args.clone()),
+            );
+        }
+
+        for (key, severity) in &self.severity_overrides {
+            if !self.sinks.iter().any(|r| r.is_target_of(key)) {
+                analyzer.set_sink_severity(key, (*severity).into());
+            }
+        }
+
+        for rule in self.sinks.iter().filter(|r| r.applies_to(lang, languages)) {
+            analyzer.push_sink(
+                TaintSink::new(&rule.matcher(), &rule.description, self.sink_severity(rule))
+                    .with_arg_positions(rule.args.clone()),
+            );
+        }
+
+        // Sanitizers are matched as lowercase substrings of call names
+        for rule in self
+            .sanitizers
+            .iter()
+            .filter(|r| r.applies_to(lang, languages))
+        {
+            let name = rule.function.as_deref().or(rule.pattern.as_deref());
+            if let Some(name) = name {
+                analyzer.add_sanitizer(&name.to_lowercase());
+            }
+        }
+    }
+
+    /// Build an analyzer from this rule set
+    pub fn to_analyzer(&self, languages: &[&str], include_defaults: bool) -> TaintAnalyzer {
+        let mut analyzer = if include_defaults {
+            TaintAnalyzer::new()
+        } else {
+            TaintAnalyzer::with_rules(Vec::new(), Vec::new(), HashSet::new())
+        };
+        self.apply_to(&mut analyzer, languages);
+        analyzer
+    }
+
+    /// Analyzer for a taint config: built-in rules and/or its rule files
+    pub fn analyzer_for(config: &TaintConfig, languages: &[&str]) -> Result<TaintAnalyzer> {
+        if config.rule_files.is_empty() {
+            return Ok(TaintAnalyzer::new());
+        }
+        Ok(Self::from_config(config)?.to_analyzer(languages, config.include_default_rules))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::taint_analysis::infrastructure::taint::CallGraphNode;
+    use std::collections::HashMap;
+
+    const RULES: &str = r#"
+version: 1
+name: acme
+language: python
+sources:
+  - id: flask-args
+    function: flask.request.args.get
+sinks:
+  - id: raw-sql
+    function: db.raw_sql
+    args: [0]
+  - pattern: 'render\('
+    languages: [javascript]
+sanitizers:
+  - function: acme.clean_sql
+severity_overrides:
+  raw-sql: medium
+  builtins.open: low
+"#;
+
+    fn graph(nodes: &[(&str, &str, &[&str])]) -> HashMap<String, CallGraphNode> {
+        nodes
+            .iter()
+            .map(|(id, name, callees)| {
+                (
+                    id.to_string(),
+                    CallGraphNode {
+                        id: id.to_string(),
+                        name: name.to_string(),
+                        callees: callees.iter().map(|c| c.to_string()).collect(),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rule_file_to_analyzer() {
+        let rules = TaintRuleSet::parse(RULES).unwrap();
+        assert_eq!(rules.sink_severity(&rules.sinks[0]), TaintSeverity::Medium);
+
+        // Python only: the javascript sink is skipped
+        let analyzer = rules.to_analyzer(&["python"], false);
+        assert_eq!(analyzer.get_sources().len(), 1);
+        assert_eq!(analyzer.get_sinks().len(), 1);
+        assert_eq!(analyzer.get_sinks()[0].arg_positions, vec![0]);
+        assert!(analyzer.get_sinks()[0].matches("app.db.raw_sql"));
+        assert!(!analyzer.get_sinks()[0].matches("db.raw_sql_count"));
+
+        let call_graph = graph(&[
+            ("src", "flask.request.args.get", &["mid"]),
+            ("mid", "handler", &["sink"]),
+            ("sink", "db.raw_sql", &[]),
+        ]);
+        let paths = analyzer.analyze(&call_graph);
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].severity, TaintSeverity::Medium);
+
+        // Built-in sinks are kept and can be overridden by pattern
+        let analyzer = rules.to_analyzer(&["javascript"], true);
+        let open = analyzer
+            .get_sinks()
+            .iter()
+            .find(|s| s.pattern == "builtins.open")
+            .unwrap();
+        assert_eq!(open.severity, TaintSeverity::Low);
+        assert!(analyzer
+            .get_sinks()
+            .iter()
+            .any(|s| s.pattern == r"render\("));
+        assert!(!analyzer
+            .get_sources()
+            .iter()
+            .any(|s| s.pattern.contains("flask")));
+    }
+
+    #[test]
+    fn test_rule_validation_and_directory_loading() {
+        assert!(TaintRuleSet::parse("version: 2\n").is_err());
+        assert!(TaintRuleSet::parse("version: 1\nsinks:\n  - description: x\n").is_err());
+        assert!(TaintRuleSet::parse("version: 1\nsinks:\n  - pattern: '('\n").is_err());
+        assert!(TaintRuleSet::parse(
+            "version: 1\nsources:\n  - {id: a, function: f}\nsinks:\n  - {id: a, function: g}\n"
+        )
+        .is_err());
+
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("10-acme.yaml"), RULES).unwrap();
+        std::fs::write(
+            dir.path().join("20-go.json"),
+            r#"{"version": 1, "language": "go", "sinks": [{"function": "os/exec.Command"}]}"#,
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("README.md"), "not a rule file").unwrap();
+
+        let rules = TaintRuleSet::from_path(dir.path()).unwrap();
+        assert_eq!(rules.name.as_deref(), Some("acme"));
+        assert_eq!(rules.sinks.len(), 3);
+        assert_eq!(rules.sinks[2].languages, vec!["go".to_string()]);
+
+        let config = TaintConfig::default()
+            .rule_file(dir.path().to_string_lossy())
+            .include_default_rules(false);
+        let analyzer = TaintRuleSet::analyzer_for(&config, &["go"]).unwrap();
+        assert_eq!(analyzer.get_sinks().len(), 1);
+        assert!(analyzer.get_sources().is_empty());
+    }
+}
@@ -0,0 +1,187 @@
+//! Snapshot-keyed persistence for taint results
+//!
+//! `TaintResultRepository` over codegraph-storage's `AnalysisResultStore`:
+//! results are saved under the snapshot (commit) they were computed for, so
+//! CI and PR bots compare commits by reading stored results instead of
+//! re-running the analysis.
+//!
+//! The full `TaintAnalysisResponse` is stored as the report; each forward
+//! path is also stored as a finding keyed by `TaintPathDTO::fingerprint`.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use codegraph_storage::error::ErrorKind;
+use codegraph_storage::{
+    AnalysisDiff, AnalysisFinding, AnalysisKind, AnalysisResult, AnalysisResultStore, StorageError,
+};
+
+use crate::features::taint_analysis::ports::{
+    TaintAnalysisError, TaintAnalysisResponse, TaintErrorKind, TaintResultRepository,
+};
+
+/// `TaintResultRepository` storing results for one snapshot
+///
+/// Result ids are snapshot ids: `save_results` returns the snapshot id and
+/// `load_results` accepts any analyzed snapshot.
+pub struct SnapshotTaintResultRepository {
+    store: Arc<dyn AnalysisResultStore>,
+    snapshot_id: String,
+}
+
+impl SnapshotTaintResultRepository {
+    pub fn new(store: Arc<dyn AnalysisResultStore>, snapshot_id: impl Into<String>) -> Self {
+        Self {
+            store,
+            snapshot_id: snapshot_id.into(),
+        }
+    }
+
+    /// Same store, saving under another snapshot
+    pub fn for_snapshot(&self, snapshot_id: impl Into<String>) -> Self {
+        Self::new(Arc::clone(&self.store), snapshot_id)
+    }
+
+    pub fn snapshot_id(&self) -> &str {
+        &self.snapshot_id
+    }
+
+    /// Flows added / removed between two analyzed snapshots
+    pub async fn compare(
+        &self,
+        base_snapshot_id: &str,
+        head_snapshot_id: &str,
+    ) -> Result<AnalysisDiff, TaintAnalysisError> {
+        self.store
+            .compare_results(base_snapshot_id, head_snapshot_id, AnalysisKind::Taint)
+            .await
+            .map_err(storage_error)
+    }
+}
+
+/// One finding per forward path
+fn findings(results: &TaintAnalysisResponse) -> Result<Vec<AnalysisFinding>, TaintAnalysisError> {
+    results
+        .forward_paths
+        .iter()
+        .map(|path| {
+            let payload = serde_json::to_value(path).map_err(|e| {
+                TaintAnalysisError::new(TaintErrorKind::StorageError, e.to_string())
+            })?;
+            Ok(AnalysisFinding::new(path.fingerprint(), payload).with_severity(&path.severity))
+        })
+        .collect()
+}
+
+fn storage_error(err: StorageError) -> TaintAnalysisError {
+    let kind = match err.kind {
+        ErrorKind::ResultNotFound | ErrorKind::SnapshotNotFound => TaintErrorKind::NotFound,
+        _ => TaintErrorKind::StorageError,
+    };
+    TaintAnalysisError::new(kind, err.message)
+}
+
+#[async_trait]
+impl TaintResultRepository for SnapshotTaintResultRepository {
+    async fn save_results(
+        &self,
+        results: &TaintAnalysisResponse,
+    ) -> Result<String, TaintAnalysisError> {
+        let report = serde_json::to_value(results)
+            .map_err(|e| TaintAnalysisError::new(TaintErrorKind::StorageError, e.to_string()))?;
+        let result = AnalysisResult::new(&self.snapshot_id, AnalysisKind::Taint, report)
+            .with_findings(findings(results)?);
+        self.store
+            .save_results(&result)
+            .await
+            .map_err(storage_error)?;
+        Ok(self.snapshot_id.clone())
+    }
+
+    async fn load_results(&self, id: &str) -> Result<TaintAnalysisResponse, TaintAnalysisError> {
+        let result = self
+            .store
+            .get_results(id, AnalysisKind::Taint)
+            .await
+            .map_err(storage_error)?;
+        serde_json::from_value(result.report)
+            .map_err(|e| TaintAnalysisError::new(TaintErrorKind::StorageError, e.to_string()))
+    }
+
+    async fn list_results(&self) -> Result<Vec<String>, TaintAnalysisError> {
+        self.store
+            .list_analyzed_snapshots(AnalysisKind::Taint)
+            .await
+            .map_err(storage_error)
+    }
+
+    async fn delete_results(&self, id: &str) -> Result<(), TaintAnalysisError> {
+        self.store
+            .delete_results(id, AnalysisKind::Taint)
+            .await
+            .map_err(storage_error)
+    }
+}
+
+#[cfg(all(test, feature = "sqlite"))]
+mod tests {
+    use super::*;
+    use crate::features::taint_analysis::ports::{AnalysisStats, TaintPathDTO};
+    use codegraph_storage::infrastructure::SqliteAnalysisResultStore;
+
+    fn response(sinks: &[&str]) -> TaintAnalysisResponse {
+        TaintAnalysisResponse {
+            success: true,
+            forward_paths: sinks
+                .iter()
+                .map(|sink| TaintPathDTO {
+                    source: "app.read".to_string(),
+                    sink: sink.to_string(),
+                    source_type: "external".to_string(),
+                    sink_type: "dangerous".to_string(),
+                    path: vec!["app.read".to_string(), sink.to_string()],
+                    path_length: 2,
+                    is_sanitized: false,
+                    sanitizers: vec![],
+                    severity: "High".to_string(),
+                    vulnerability_type: "taint_flow".to_string(),
+                })
+                .collect(),
+            backward_paths: vec![],
+            implicit_flows: vec![],
+            stats: AnalysisStats::default(),
+            errors: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_persist_and_compare_snapshots() {
+        let store = Arc::new(SqliteAnalysisResultStore::new_in_memory().unwrap());
+        let base = SnapshotTaintResultRepository::new(store, "base");
+        let head = base.for_snapshot("head");
+
+        let id = base
+            .save_results(&response(&["app.run", "app.log"]))
+            .await
+            .unwrap();
+        assert_eq!(id, "base");
+        head.save_results(&response(&["app.run", "app.query"]))
+            .await
+            .unwrap();
+
+        let loaded = head.load_results("base").await.unwrap();
+        assert_eq!(loaded.forward_paths.len(), 2);
+        assert_eq!(loaded.forward_paths[1].sink, "app.log");
+
+        let diff = head.compare("base", "head").await.unwrap();
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].payload["sink"], "app.query");
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.unchanged, 1);
+
+        head.delete_results("base").await.unwrap();
+        let err = head.load_results("base").await.unwrap_err();
+        assert!(matches!(err.kind, TaintErrorKind::NotFound));
+        assert_eq!(head.list_results().await.unwrap(), vec!["head".to_string()]);
+    }
+}
@@ -23,6 +23,8 @@ pub struct TaintSource {
     pub pattern: String,
     pub description: String,
     pub regex: Option<Regex>,
+    /// Tainted argument positions (empty = return value)
+    pub arg_positions: Vec<usize>,
 }
 
 impl TaintSource {
@@ -32,9 +34,16 @@ impl TaintSource {
             pattern: pattern.to_string(),
             description: description.to_string(),
             regex,
+            arg_positions: Vec::new(),
         }
     }
 
+    /// Builder: Set tainted argument positions
+    pub fn with_arg_positions(mut self, positions: Vec<usize>) -> Self {
+        self.arg_positions = positions;
+        self
+    }
+
     pub fn matches(&self, name: &str) -> bool {
         if let Some(ref re) = self.regex {
             re.is_match(name)
@@ -51,6 +60,8 @@ pub struct TaintSink {
     pub description: String,
     pub severity: TaintSeverity,
     pub regex: Option<Regex>,
+    /// Dangerous argument positions (empty = any argument)
+    pub arg_positions: Vec<usize>,
 }
 
 impl TaintSink {
@@ -61,9 +72,16 @@ impl TaintSink {
             description: description.to_string(),
             severity,
             regex,
+            arg_positions: Vec::new(),
         }
     }
 
+    /// Builder: Set dangerous argument positions
+    pub fn with_arg_positions(mut self, positions: Vec<usize>) -> Self {
+        self.arg_positions = positions;
+        self
+    }
+
     pub fn matches(&self, name: &str) -> bool {
         if let Some(ref re) = self.regex {
             re.is_match(name)
@@ -438,6 +456,28 @@ impl TaintAnalyzer {
         self.sanitizers.insert(pattern.to_string());
     }
 
+    /// Add a pre-built source (e.g. from a rule file)
+    pub fn push_source(&mut self, source: TaintSource) {
+        self.sources.push(source);
+    }
+
+    /// Add a pre-built sink (e.g. from a rule file)
+    pub fn push_sink(&mut self, sink: TaintSink) {
+        self.sinks.push(sink);
+    }
+
+    /// Override the severity of every sink whose pattern equals `pattern`
+    ///
+    /// Returns the number of sinks changed.
+    pub fn set_sink_severity(&mut self, pattern: &str, severity: TaintSeverity) -> usize {
+        let mut changed = 0;
+        for sink in self.sinks.iter_mut().filter(|s| s.pattern == pattern) {
+            sink.severity = severity;
+            changed += 1;
+        }
+        changed
+    }
+
     /// Get statistics
     pub fn get_stats(&self) -> TaintStats {
         TaintStats {
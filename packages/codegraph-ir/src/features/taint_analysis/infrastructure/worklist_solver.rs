@@ -22,11 +22,20 @@
  * - Kildall, G. (1973). "A Unified Approach to Global Program Optimization"
  * - Kam, J. & Ullman, J. (1977). "Monotone Data Flow Analysis Frameworks"
  * - Python: fixpoint_taint_solver.py (300 lines)
+ *
+ * Field sensitivity:
+ * - Variables are parsed as access paths (`user.name`, `data["key"]`)
+ * - Off: facts collapse to the base variable (`user.name` taints `user`)
+ * - On: facts keep up to `max_field_depth` steps, so `user.id` stays clean
+ *   when only `user.name` is tainted
  */
 
 use rustc_hash::FxHashMap;
 use std::collections::{HashMap, HashSet, VecDeque};
 
+use crate::config::TaintConfig;
+use crate::features::taint_analysis::domain::AccessPath;
+
 /// Taint fact: (variable, source location)
 ///
 /// Example:
@@ -34,7 +43,7 @@ use std::collections::{HashMap, HashSet, VecDeque};
 ///   → Variable "user_input" was tainted at node 42
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TaintFact {
-    /// Variable name
+    /// Variable name (access path when field-sensitive, e.g. "user.name")
     pub variable: String,
 
     /// Source node ID where taint originated
@@ -171,18 +180,31 @@ pub struct WorklistTaintSolver {
 
     /// Enable debug tracing
     trace: bool,
+
+    /// Access-path steps kept per fact (0 = field-insensitive)
+    field_depth: usize,
 }
 
 impl WorklistTaintSolver {
-    /// Create new solver
+    /// Create new solver (field-insensitive)
     pub fn new(cfg: CFG) -> Self {
         Self {
             cfg,
             max_iterations: 1000,
             trace: false,
+            field_depth: 0,
         }
     }
 
+    /// Create solver from RFC-001 `TaintConfig`
+    ///
+    /// Uses `worklist_max_iterations`, `field_sensitive` and `max_field_depth`.
+    pub fn from_config(cfg: CFG, config: &TaintConfig) -> Self {
+        Self::new(cfg)
+            .with_max_iterations(config.worklist_max_iterations)
+            .with_field_sensitivity(config.field_sensitive, config.max_field_depth)
+    }
+
     /// Create solver with custom iteration limit
     pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
         self.max_iterations = max_iterations;
@@ -195,6 +217,12 @@ impl WorklistTaintSolver {
         self
     }
 
+    /// Track fields / keys separately, up to `max_depth` access-path steps
+    pub fn with_field_sensitivity(mut self, enabled: bool, max_depth: usize) -> Self {
+        self.field_depth = if enabled { max_depth } else { 0 };
+        self
+    }
+
     /// Solve taint analysis using worklist iteration
     ///
     /// # Arguments
@@ -316,10 +344,12 @@ impl WorklistTaintSolver {
     ///
     /// # Kill (Remove taints)
     /// - If node is a sanitizer, remove taint facts for sanitized variables
+    ///   (and their fields; sanitizing `user.name` keeps `user.email`)
     ///
     /// # Propagate (Copy taints)
     /// - If node defines `x = f(y1, y2, ...)` and any `yi` is tainted,
     ///   then `x` becomes tainted
+    /// - `x = user` with only `user.name` tainted taints `x.name`
     ///
     /// # Arguments
     /// * `node_id` - Current CFG node
@@ -343,14 +373,19 @@ impl WorklistTaintSolver {
         // GEN: Add new taints from sources
         if let Some(source_vars) = sources.get(node_id) {
             for var in source_vars {
-                out.insert(TaintFact::new(var.clone(), node_id));
+                out.insert(TaintFact::new(self.access_path(var).to_string(), node_id));
             }
         }
 
         // KILL: Remove sanitized taints
         if node.is_sanitizer {
             let before_count = out.len();
-            out.retain(|fact| !node.use_vars.contains(&fact.variable));
+            let sanitized: Vec<AccessPath> =
+                node.use_vars.iter().map(|v| self.access_path(v)).collect();
+            out.retain(|fact| {
+                let path = self.access_path(&fact.variable);
+                !sanitized.iter().any(|s| s.is_prefix_of(&path))
+            });
 
             #[cfg(feature = "trace")]
             if self.trace && out.len() < before_count {
@@ -362,12 +397,22 @@ impl WorklistTaintSolver {
             }
         }
 
-        // PROPAGATE: x = f(y) where y is tainted
+        // PROPAGATE: x = f(y) where y (or one of its fields) is tainted
         if let Some(def_var) = &node.def_var {
+            let def_path = self.access_path(def_var);
             for use_var in &node.use_vars {
+                let use_path = self.access_path(use_var);
                 for fact in in_facts {
-                    if &fact.variable == use_var {
-                        out.insert(TaintFact::new(def_var.clone(), fact.source.clone()));
+                    let fact_path = self.access_path(&fact.variable);
+                    let tainted = if fact_path.is_prefix_of(&use_path) {
+                        Some(def_path.clone())
+                    } else {
+                        fact_path
+                            .rebase(&use_path, &def_path)
+                            .map(|p| p.truncated(self.field_depth))
+                    };
+                    if let Some(path) = tainted {
+                        out.insert(TaintFact::new(path.to_string(), fact.source.clone()));
 
                         #[cfg(feature = "trace")]
                         if self.trace {
@@ -415,6 +460,38 @@ impl WorklistTaintSolver {
         let in_facts = self.get_in_facts(sink_id, result);
         in_facts.into_iter().map(|f| f.variable).collect()
     }
+
+    /// Sink arguments (`use_vars`) that carry tainted data
+    ///
+    /// Field-aware: with field sensitivity, `execute(user.id)` is clean when
+    /// only `user.name` is tainted, while `execute(user)` is not.
+    pub fn tainted_sink_uses(
+        &self,
+        sink_id: &str,
+        result: &HashMap<String, HashSet<TaintFact>>,
+    ) -> HashSet<String> {
+        let Some(node) = self.cfg.nodes.get(sink_id) else {
+            return HashSet::new();
+        };
+        let tainted: Vec<AccessPath> = self
+            .get_in_facts(sink_id, result)
+            .iter()
+            .map(|f| self.access_path(&f.variable))
+            .collect();
+        node.use_vars
+            .iter()
+            .filter(|v| {
+                let path = self.access_path(v);
+                tainted.iter().any(|t| t.overlaps(&path))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Variable as a (k-limited) access path
+    fn access_path(&self, var: &str) -> AccessPath {
+        AccessPath::parse(var).truncated(self.field_depth)
+    }
 }
 
 #[cfg(test)]
@@ -712,4 +789,104 @@ mod tests {
         assert!(tainted_at_sink.contains("x"), "Should have x from source1");
         assert!(tainted_at_sink.contains("y"), "Should have y from source2");
     }
+
+    /// entry -> source(user) -> sanitizer -> copy(x = user) -> sink(uses)
+    fn field_cfg(sanitized: Option<&str>, sink_uses: &[&str]) -> CFG {
+        let mut cfg = CFG::new("entry");
+
+        let mut entry = CFGNode::new("entry");
+        entry.add_successor("source");
+
+        let mut source = CFGNode::new("source");
+        source.add_predecessor("entry");
+        source.add_successor("sanitizer");
+        source.def_var = Some("user".to_string());
+
+        let mut sanitizer = CFGNode::new("sanitizer");
+        sanitizer.add_predecessor("source");
+        sanitizer.add_successor("copy");
+        if let Some(var) = sanitized {
+            sanitizer.use_vars.insert(var.to_string());
+            sanitizer.is_sanitizer = true;
+        }
+
+        let mut copy = CFGNode::new("copy");
+        copy.add_predecessor("sanitizer");
+        copy.add_successor("sink");
+        copy.def_var = Some("x".to_string());
+        copy.use_vars.insert("user".to_string());
+
+        let mut sink = CFGNode::new("sink");
+        sink.add_predecessor("copy");
+        sink.use_vars
+            .extend(sink_uses.iter().map(|u| u.to_string()));
+
+        for node in [entry, source, sanitizer, copy, sink] {
+            cfg.add_node(node);
+        }
+        cfg
+    }
+
+    fn tainted_uses(solver: &WorklistTaintSolver, tainted: &str) -> HashSet<String> {
+        let sources = HashMap::from([("source".to_string(), HashSet::from([tainted.to_string()]))]);
+        let result = solver.solve(&sources, &HashSet::from(["sink".to_string()]));
+        solver.tainted_sink_uses("sink", &result)
+    }
+
+    #[test]
+    fn test_field_sensitive_access_paths() {
+        let uses = ["user.id", "user", "x.name", "x[\"name\"]", "x.email"];
+
+        // Insensitive: user.name taints the whole object
+        let solver = WorklistTaintSolver::new(field_cfg(None, &uses));
+        let tainted = tainted_uses(&solver, "user.name");
+        assert_eq!(tainted.len(), uses.len());
+
+        // Sensitive: only paths overlapping user.name (copied into x.name)
+        let solver =
+            WorklistTaintSolver::new(field_cfg(None, &uses)).with_field_sensitivity(true, 3);
+        let tainted = tainted_uses(&solver, "user.name");
+        assert_eq!(
+            tainted,
+            HashSet::from(["user".to_string(), "x.name".to_string()])
+        );
+
+        // Dict keys are tracked like fields
+        let solver = WorklistTaintSolver::new(field_cfg(None, &["x['token']", "x['id']"]))
+            .with_field_sensitivity(true, 3);
+        let tainted = tainted_uses(&solver, "user['token']");
+        assert_eq!(tainted, HashSet::from(["x['token']".to_string()]));
+    }
+
+    #[test]
+    fn test_field_depth_and_sanitizer() {
+        // Depth 1: user.name.first is tracked as user.name
+        let solver = WorklistTaintSolver::new(field_cfg(None, &["x.name.last", "x.id"]))
+            .with_field_sensitivity(true, 1);
+        let tainted = tainted_uses(&solver, "user.name.first");
+        assert_eq!(tainted, HashSet::from(["x.name.last".to_string()]));
+
+        // Sanitizing one field leaves the others tainted
+        let solver = WorklistTaintSolver::new(field_cfg(Some("user.name"), &["x.name", "x.email"]))
+            .with_field_sensitivity(true, 3);
+        let sources = HashMap::from([(
+            "source".to_string(),
+            HashSet::from(["user.name".to_string(), "user.email".to_string()]),
+        )]);
+        let result = solver.solve(&sources, &HashSet::new());
+        assert_eq!(
+            solver.tainted_sink_uses("sink", &result),
+            HashSet::from(["x.email".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_from_config() {
+        let config = TaintConfig::default().max_field_depth(2);
+        assert!(config.field_sensitive);
+
+        let solver = WorklistTaintSolver::from_config(field_cfg(None, &["x.a.b.z"]), &config);
+        let tainted = tainted_uses(&solver, "user.a.b.c");
+        assert_eq!(tainted, HashSet::from(["x.a.b.z".to_string()]));
+    }
 }
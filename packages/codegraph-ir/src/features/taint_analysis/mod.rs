@@ -16,9 +16,11 @@ pub use application::{
     AnalyzeTaintUseCase, DefaultTaintAnalysisService, InMemoryCodeRepository,
     InMemoryResultRepository,
 };
+#[cfg(feature = "git")]
+pub use application::GitDiffTaintAnalyzer;
 
 // Re-export domain types
-pub use domain::{FunctionSummaryCache, FunctionTaintSummary};
+pub use domain::{AccessPath, FieldStep, FunctionSummaryCache, FunctionTaintSummary};
 
 // Re-export infrastructure (internal use - prefer application layer)
 #[doc(hidden)]
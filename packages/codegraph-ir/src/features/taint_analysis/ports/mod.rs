@@ -43,7 +43,7 @@ use std::collections::{HashMap, HashSet};
 
 use super::infrastructure::{BackwardTaintPath, ImplicitFlowVulnerability, TaintPath};
 use crate::config::TaintConfig;
-use crate::shared::models::Node;
+use crate::shared::models::{Edge, Node};
 
 // ============================================================================
 // DTOs (Data Transfer Objects)
@@ -229,6 +229,22 @@ impl From<TaintPath> for TaintPathDTO {
     }
 }
 
+impl TaintPathDTO {
+    /// Stable identity used to match flows across revisions
+    ///
+    /// Only the endpoints and vulnerability type are hashed: line shifts and
+    /// changes to the intermediate calls keep the fingerprint, so such a
+    /// flow is reported as modified rather than fixed + new.
+    pub fn fingerprint(&self) -> String {
+        let mut hasher = blake3::Hasher::new();
+        for part in [&self.vulnerability_type, &self.source, &self.sink] {
+            hasher.update(part.as_bytes());
+            hasher.update(&[0]);
+        }
+        hasher.finalize().to_hex()[..16].to_string()
+    }
+}
+
 /// Simplified backward taint path
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackwardTaintPathDTO {
@@ -316,10 +332,11 @@ pub trait TaintAnalysisService: Send + Sync {
         config: TaintAnalysisConfig,
     ) -> Result<TaintAnalysisResponse, TaintAnalysisError>;
 
-    /// Analyze IR nodes directly
+    /// Analyze IR nodes (and their data-flow / call edges) directly
     async fn analyze_ir(
         &self,
         nodes: Vec<Node>,
+        edges: Vec<Edge>,
         config: TaintAnalysisConfig,
     ) -> Result<TaintAnalysisResponse, TaintAnalysisError>;
 
@@ -399,6 +416,14 @@ pub trait CodeRepository: Send + Sync {
     /// Get IR nodes for a file
     async fn get_ir_nodes(&self, path: &str) -> Result<Vec<Node>, TaintAnalysisError>;
 
+    /// Get IR edges for a file (call graph input for forward analysis)
+    ///
+    /// Defaults to none; forward analysis then derives call edges from
+    /// `Call` nodes.
+    async fn get_ir_edges(&self, _path: &str) -> Result<Vec<Edge>, TaintAnalysisError> {
+        Ok(Vec::new())
+    }
+
     /// Get IR nodes for a function
     async fn get_function_ir(&self, function_id: &str) -> Result<Vec<Node>, TaintAnalysisError>;
 
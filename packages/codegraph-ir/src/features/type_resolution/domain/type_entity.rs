@@ -1,6 +1,8 @@
 //! Type entity domain model
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TypeFlavor {
     Builtin,
     User,
@@ -17,7 +19,7 @@ impl TypeFlavor {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TypeResolutionLevel {
     Raw,
     Builtin,
@@ -38,9 +40,23 @@ impl TypeResolutionLevel {
             TypeResolutionLevel::External => "external",
         }
     }
+
+    /// Confidence (0.0-1.0) that a type resolved at this level is correct
+    ///
+    /// Raw annotations were never resolved; external types are known only by name.
+    pub fn confidence(&self) -> f32 {
+        match self {
+            TypeResolutionLevel::Builtin => 1.0,
+            TypeResolutionLevel::Local => 0.95,
+            TypeResolutionLevel::Module => 0.9,
+            TypeResolutionLevel::Project => 0.8,
+            TypeResolutionLevel::External => 0.5,
+            TypeResolutionLevel::Raw => 0.3,
+        }
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TypeEntity {
     pub id: String,
     pub raw: String,
@@ -50,3 +66,10 @@ pub struct TypeEntity {
     pub resolved_target: Option<String>,
     pub generic_param_ids: Vec<String>,
 }
+
+impl TypeEntity {
+    /// Confidence of this type resolution (see `TypeResolutionLevel::confidence`)
+    pub fn confidence(&self) -> f32 {
+        self.resolution_level.confidence()
+    }
+}
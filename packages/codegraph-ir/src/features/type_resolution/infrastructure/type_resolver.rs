@@ -431,6 +431,20 @@ mod tests {
         assert_eq!(my_type.resolved_target, Some("node123".to_string()));
     }
 
+    #[test]
+    fn test_resolution_confidence() {
+        let mut resolver = TypeResolver::new("test".to_string());
+        resolver.register_local_class("MyClass".to_string(), "node123".to_string());
+
+        let int_type = resolver.resolve_type("int");
+        let my_type = resolver.resolve_type("MyClass");
+        let empty = resolver.resolve_type("");
+
+        assert_eq!(int_type.confidence(), 1.0);
+        assert!(my_type.confidence() < int_type.confidence());
+        assert!(empty.confidence() < my_type.confidence());
+    }
+
     #[test]
     fn test_generic_params() {
         let resolver = TypeResolver::new("test".to_string());
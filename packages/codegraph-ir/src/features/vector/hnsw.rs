@@ -0,0 +1,379 @@
+//! HNSW (Hierarchical Navigable Small World) approximate nearest neighbours
+//!
+//! Malkov & Yashunin (2016). Vectors are stored unit-length and scored by
+//! dot product (cosine similarity). Level assignment uses a seeded PRNG, so
+//! the same inserts in the same order build the same graph.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::provider::normalize;
+use crate::shared::models::{CodegraphError, Result};
+
+/// HNSW construction / search parameters
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HnswParams {
+    /// Links per node on upper layers (layer 0 keeps `2 * m`)
+    pub m: usize,
+    /// Beam width while inserting
+    pub ef_construction: usize,
+    /// Beam width while searching (at least `k`)
+    pub ef_search: usize,
+    /// Level PRNG seed
+    pub seed: u64,
+}
+
+impl Default for HnswParams {
+    fn default() -> Self {
+        Self {
+            m: 16,
+            ef_construction: 100,
+            ef_search: 64,
+            seed: 0x5eed,
+        }
+    }
+}
+
+/// Similarity-ordered candidate (ties broken by id for determinism)
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Scored {
+    score: f32,
+    id: u32,
+}
+
+impl Eq for Scored {}
+
+impl Ord for Scored {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score
+            .total_cmp(&other.score)
+            .then_with(|| other.id.cmp(&self.id))
+    }
+}
+
+impl PartialOrd for Scored {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// In-memory HNSW graph; serializable for persistence
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HnswIndex {
+    params: HnswParams,
+    dimension: usize,
+    vectors: Vec<Vec<f32>>,
+    /// `links[node][layer]` = neighbour ids
+    links: Vec<Vec<Vec<u32>>>,
+    entry: Option<u32>,
+    max_layer: usize,
+    rng_state: u64,
+}
+
+impl HnswIndex {
+    pub fn new(dimension: usize, params: HnswParams) -> Self {
+        Self {
+            params,
+            dimension,
+            vectors: Vec::new(),
+            links: Vec::new(),
+            entry: None,
+            max_layer: 0,
+            rng_state: params.seed,
+        }
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    pub fn params(&self) -> &HnswParams {
+        &self.params
+    }
+
+    pub fn len(&self) -> usize {
+        self.vectors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vectors.is_empty()
+    }
+
+    /// Insert a vector, returning its id (ids are assigned 0, 1, 2, ...)
+    pub fn insert(&mut self, vector: &[f32]) -> Result<u32> {
+        let query = self.prepare(vector)?;
+        let id = self.vectors.len() as u32;
+        let level = self.random_level();
+        self.vectors.push(query.clone());
+        self.links.push(vec![Vec::new(); level + 1]);
+
+        let Some(mut entry) = self.entry else {
+            self.entry = Some(id);
+            self.max_layer = level;
+            return Ok(id);
+        };
+
+        for layer in (level + 1..=self.max_layer).rev() {
+            entry = self.greedy_closest(&query, entry, layer);
+        }
+        for layer in (0..=level.min(self.max_layer)).rev() {
+            let candidates = self.search_layer(&query, entry, self.params.ef_construction, layer);
+            let max_links = self.max_links(layer);
+            let neighbours: Vec<u32> = candidates
+                .iter()
+                .filter(|c| c.id != id)
+                .take(max_links)
+                .map(|c| c.id)
+                .collect();
+            for &neighbour in &neighbours {
+                self.connect(neighbour, id, layer);
+            }
+            self.links[id as usize][layer] = neighbours;
+            if let Some(best) = candidates.first() {
+                entry = best.id;
+            }
+        }
+
+        if level > self.max_layer {
+            self.max_layer = level;
+            self.entry = Some(id);
+        }
+        Ok(id)
+    }
+
+    /// Up to `k` (id, cosine similarity) pairs, most similar first
+    pub fn search(&self, query: &[f32], k: usize) -> Result<Vec<(u32, f32)>> {
+        let query = self.prepare(query)?;
+        let Some(mut entry) = self.entry else {
+            return Ok(Vec::new());
+        };
+        if k == 0 {
+            return Ok(Vec::new());
+        }
+        for layer in (1..=self.max_layer).rev() {
+            entry = self.greedy_closest(&query, entry, layer);
+        }
+        Ok(self
+            .search_layer(&query, entry, self.params.ef_search.max(k), 0)
+            .into_iter()
+            .take(k)
+            .map(|c| (c.id, c.score))
+            .collect())
+    }
+
+    fn prepare(&self, vector: &[f32]) -> Result<Vec<f32>> {
+        if vector.len() != self.dimension {
+            return Err(CodegraphError::internal(format!(
+                "Vector dimension {} does not match index dimension {}",
+                vector.len(),
+                self.dimension
+            )));
+        }
+        let mut vector = vector.to_vec();
+        normalize(&mut vector);
+        Ok(vector)
+    }
+
+    fn score(&self, query: &[f32], id: u32) -> f32 {
+        query
+            .iter()
+            .zip(&self.vectors[id as usize])
+            .map(|(a, b)| a * b)
+            .sum()
+    }
+
+    fn max_links(&self, layer: usize) -> usize {
+        if layer == 0 {
+            self.params.m * 2
+        } else {
+            self.params.m
+        }
+    }
+
+    /// splitmix64 → level with P(level ≥ l) = m^-l
+    fn random_level(&mut self) -> usize {
+        self.rng_state = self.rng_state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.rng_state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        let uniform = ((z >> 11) as f64 + 1.0) / (1u64 << 53) as f64;
+        let ml = 1.0 / (self.params.m.max(2) as f64).ln();
+        (-uniform.ln() * ml).floor() as usize
+    }
+
+    fn greedy_closest(&self, query: &[f32], mut current: u32, layer: usize) -> u32 {
+        let mut best = self.score(query, current);
+        loop {
+            let mut improved = false;
+            for &neighbour in self.neighbours(current, layer) {
+                let score = self.score(query, neighbour);
+                if score > best {
+                    best = score;
+                    current = neighbour;
+                    improved = true;
+                }
+            }
+            if !improved {
+                return current;
+            }
+        }
+    }
+
+    /// Beam search on one layer; best first
+    fn search_layer(&self, query: &[f32], entry: u32, ef: usize, layer: usize) -> Vec<Scored> {
+        let start = Scored {
+            score: self.score(query, entry),
+            id: entry,
+        };
+        let mut visited = HashSet::from([entry]);
+        let mut candidates = BinaryHeap::from([start]);
+        let mut results = BinaryHeap::from([Reverse(start)]);
+
+        while let Some(candidate) = candidates.pop() {
+            let worst = results.peek().map(|r| r.0.score).unwrap_or(f32::MIN);
+            if candidate.score < worst && results.len() >= ef {
+                break;
+            }
+            for &neighbour in self.neighbours(candidate.id, layer) {
+                if !visited.insert(neighbour) {
+                    continue;
+                }
+                let scored = Scored {
+                    score: self.score(query, neighbour),
+                    id: neighbour,
+                };
+                let worst = results.peek().map(|r| r.0.score).unwrap_or(f32::MIN);
+                if results.len() < ef || scored.score > worst {
+                    candidates.push(scored);
+                    results.push(Reverse(scored));
+                    if results.len() > ef {
+                        results.pop();
+                    }
+                }
+            }
+        }
+
+        let mut results: Vec<Scored> = results.into_iter().map(|r| r.0).collect();
+        results.sort_by_key(|s| Reverse(*s));
+        results
+    }
+
+    fn neighbours(&self, id: u32, layer: usize) -> &[u32] {
+        self.links[id as usize]
+            .get(layer)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Add `id` to `node`'s links, keeping only the closest when over capacity
+    fn connect(&mut self, node: u32, id: u32, layer: usize) {
+        let max_links = self.max_links(layer);
+        let Some(links) = self.links[node as usize].get(layer) else {
+            return;
+        };
+        if links.contains(&id) {
+            return;
+        }
+        let mut links = links.clone();
+        links.push(id);
+        if links.len() > max_links {
+            let base = self.vectors[node as usize].clone();
+            let mut scored: Vec<Scored> = links
+                .iter()
+                .map(|&n| Scored {
+                    score: self.score(&base, n),
+                    id: n,
+                })
+                .collect();
+            scored.sort_by_key(|s| Reverse(*s));
+            links = scored.into_iter().take(max_links).map(|s| s.id).collect();
+        }
+        self.links[node as usize][layer] = links;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic pseudo-random unit vectors
+    fn vectors(count: usize, dimension: usize) -> Vec<Vec<f32>> {
+        let mut state = 42u64;
+        (0..count)
+            .map(|_| {
+                (0..dimension)
+                    .map(|_| {
+                        state = state
+                            .wrapping_mul(6364136223846793005)
+                            .wrapping_add(1442695040888963407);
+                        ((state >> 33) as f32 / u32::MAX as f32) - 0.25
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn brute_force(data: &[Vec<f32>], query: &[f32], k: usize) -> Vec<u32> {
+        let mut q = query.to_vec();
+        normalize(&mut q);
+        let mut scored: Vec<(u32, f32)> = data
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let mut v = v.clone();
+                normalize(&mut v);
+                (i as u32, q.iter().zip(&v).map(|(a, b)| a * b).sum())
+            })
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+        scored.into_iter().take(k).map(|(i, _)| i).collect()
+    }
+
+    #[test]
+    fn test_search_finds_exact_match_first() {
+        let data = vectors(300, 16);
+        let mut index = HnswIndex::new(16, HnswParams::default());
+        for v in &data {
+            index.insert(v).unwrap();
+        }
+        for probe in [0usize, 17, 150, 299] {
+            let hits = index.search(&data[probe], 5).unwrap();
+            assert_eq!(hits[0].0, probe as u32);
+            assert!((hits[0].1 - 1.0).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_recall_against_brute_force() {
+        let data = vectors(500, 24);
+        let queries = vectors(520, 24).split_off(500);
+        let mut index = HnswIndex::new(24, HnswParams::default());
+        for v in &data {
+            index.insert(v).unwrap();
+        }
+
+        let k = 10;
+        let mut found = 0;
+        for query in &queries {
+            let expected: HashSet<u32> = brute_force(&data, query, k).into_iter().collect();
+            found += index
+                .search(query, k)
+                .unwrap()
+                .iter()
+                .filter(|(id, _)| expected.contains(id))
+                .count();
+        }
+        let recall = found as f32 / (queries.len() * k) as f32;
+        assert!(recall > 0.9, "recall {}", recall);
+    }
+
+    #[test]
+    fn test_dimension_mismatch_and_empty_index() {
+        let mut index = HnswIndex::new(4, HnswParams::default());
+        assert!(index.search(&[1.0, 0.0, 0.0, 0.0], 3).unwrap().is_empty());
+        assert!(index.insert(&[1.0, 0.0]).is_err());
+    }
+}
@@ -0,0 +1,341 @@
+//! Chunk embedding index and its per-snapshot storage
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::hnsw::{HnswIndex, HnswParams};
+use super::provider::EmbeddingProvider;
+use crate::shared::models::{CodegraphError, Result};
+
+/// Texts sent to the provider per `embed` call
+const EMBED_BATCH_SIZE: usize = 64;
+
+/// Chunk metadata kept with each vector
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VectorEntry {
+    pub chunk_id: String,
+    pub file_path: String,
+    pub start_line: usize,
+    pub end_line: usize,
+    pub symbol_id: Option<String>,
+}
+
+/// One `semantic_search` result
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorHit {
+    pub entry: VectorEntry,
+    /// Cosine similarity to the query
+    pub score: f32,
+}
+
+/// HNSW index over the chunk embeddings of one snapshot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChunkVectorIndex {
+    snapshot_id: String,
+    model_id: String,
+    entries: Vec<VectorEntry>,
+    hnsw: HnswIndex,
+}
+
+impl ChunkVectorIndex {
+    pub fn new(
+        snapshot_id: impl Into<String>,
+        model_id: impl Into<String>,
+        dimension: usize,
+        params: HnswParams,
+    ) -> Self {
+        Self {
+            snapshot_id: snapshot_id.into(),
+            model_id: model_id.into(),
+            entries: Vec::new(),
+            hnsw: HnswIndex::new(dimension, params),
+        }
+    }
+
+    /// Embed `chunks` (metadata, text) with `provider` and index them
+    pub fn build<'a, I>(
+        snapshot_id: impl Into<String>,
+        provider: &dyn EmbeddingProvider,
+        params: HnswParams,
+        chunks: I,
+    ) -> Result<Self>
+    where
+        I: IntoIterator<Item = (VectorEntry, &'a str)>,
+    {
+        let mut index = Self::new(
+            snapshot_id,
+            provider.model_id(),
+            provider.dimension(),
+            params,
+        );
+        let mut batch: Vec<(VectorEntry, &str)> = Vec::with_capacity(EMBED_BATCH_SIZE);
+        for chunk in chunks {
+            batch.push(chunk);
+            if batch.len() == EMBED_BATCH_SIZE {
+                index.embed_batch(provider, &mut batch)?;
+            }
+        }
+        index.embed_batch(provider, &mut batch)?;
+        Ok(index)
+    }
+
+    /// Snapshot id for embedding `chunks` with `model_id`; changes whenever
+    /// a chunk's identity, range or text, or the model, changes
+    pub fn fingerprint<'a, 'b: 'a, I>(model_id: &str, chunks: I) -> String
+    where
+        I: IntoIterator<Item = &'a (VectorEntry, &'b str)>,
+    {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(model_id.as_bytes());
+        for (entry, text) in chunks {
+            for part in [entry.chunk_id.as_str(), entry.file_path.as_str(), *text] {
+                hasher.update(&(part.len() as u64).to_le_bytes());
+                hasher.update(part.as_bytes());
+            }
+            hasher.update(&(entry.start_line as u64).to_le_bytes());
+            hasher.update(&(entry.end_line as u64).to_le_bytes());
+        }
+        hasher.finalize().to_hex()[..16].to_string()
+    }
+
+    fn embed_batch(
+        &mut self,
+        provider: &dyn EmbeddingProvider,
+        batch: &mut Vec<(VectorEntry, &str)>,
+    ) -> Result<()> {
+        if batch.is_empty() {
+            return Ok(());
+        }
+        let texts: Vec<&str> = batch.iter().map(|(_, text)| *text).collect();
+        let vectors = provider.embed(&texts)?;
+        for ((entry, _), vector) in batch.drain(..).zip(vectors) {
+            self.insert(entry, &vector)?;
+        }
+        Ok(())
+    }
+
+    /// Add one embedded chunk
+    pub fn insert(&mut self, entry: VectorEntry, embedding: &[f32]) -> Result<()> {
+        self.hnsw.insert(embedding)?;
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    /// `k` chunks nearest to `query_embedding`, most similar first
+    pub fn semantic_search(&self, query_embedding: &[f32], k: usize) -> Result<Vec<VectorHit>> {
+        Ok(self
+            .hnsw
+            .search(query_embedding, k)?
+            .into_iter()
+            .map(|(id, score)| VectorHit {
+                entry: self.entries[id as usize].clone(),
+                score,
+            })
+            .collect())
+    }
+
+    pub fn snapshot_id(&self) -> &str {
+        &self.snapshot_id
+    }
+
+    pub fn model_id(&self) -> &str {
+        &self.model_id
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.hnsw.dimension()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn entries(&self) -> &[VectorEntry] {
+        &self.entries
+    }
+
+    /// Write as MessagePack
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let bytes = rmp_serde::to_vec(self).map_err(|e| {
+            CodegraphError::storage(format!("Failed to encode vector index: {}", e))
+        })?;
+        fs::write(path.as_ref(), bytes).map_err(|e| {
+            CodegraphError::io(format!(
+                "Failed to write vector index {}: {}",
+                path.as_ref().display(),
+                e
+            ))
+        })
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let bytes = fs::read(path.as_ref()).map_err(|e| {
+            CodegraphError::io(format!(
+                "Failed to read vector index {}: {}",
+                path.as_ref().display(),
+                e
+            ))
+        })?;
+        rmp_serde::from_slice(&bytes)
+            .map_err(|e| CodegraphError::storage(format!("Invalid vector index: {}", e)))
+    }
+}
+
+/// Directory of vector indexes, one file per snapshot
+#[derive(Debug, Clone)]
+pub struct VectorIndexStore {
+    root: PathBuf,
+}
+
+impl VectorIndexStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// File of `snapshot_id` (path separators replaced)
+    pub fn path_for(&self, snapshot_id: &str) -> PathBuf {
+        let name: String = snapshot_id
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() || c == '-' || c == '.' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        self.root.join(format!("{}.hnsw", name))
+    }
+
+    pub fn save(&self, index: &ChunkVectorIndex) -> Result<PathBuf> {
+        fs::create_dir_all(&self.root).map_err(|e| {
+            CodegraphError::io(format!("Failed to create {}: {}", self.root.display(), e))
+        })?;
+        let path = self.path_for(index.snapshot_id());
+        index.save(&path)?;
+        Ok(path)
+    }
+
+    /// Stored index of `snapshot_id`, if any
+    pub fn load(&self, snapshot_id: &str) -> Result<Option<ChunkVectorIndex>> {
+        let path = self.path_for(snapshot_id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        ChunkVectorIndex::load(path).map(Some)
+    }
+
+    /// Reuse the stored index of these chunks, or embed them and store it
+    pub fn load_or_build(
+        &self,
+        provider: &dyn EmbeddingProvider,
+        params: HnswParams,
+        chunks: &[(VectorEntry, &str)],
+    ) -> Result<ChunkVectorIndex> {
+        let snapshot_id = ChunkVectorIndex::fingerprint(provider.model_id(), chunks);
+        if let Some(index) = self.load(&snapshot_id)? {
+            if index.model_id() == provider.model_id() && index.len() == chunks.len() {
+                return Ok(index);
+            }
+        }
+        let index = ChunkVectorIndex::build(snapshot_id, provider, params, chunks.iter().cloned())?;
+        self.save(&index)?;
+        Ok(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::vector::HashingEmbeddingProvider;
+
+    fn entry(chunk_id: &str) -> VectorEntry {
+        VectorEntry {
+            chunk_id: chunk_id.to_string(),
+            file_path: "app.py".to_string(),
+            start_line: 1,
+            end_line: 5,
+            symbol_id: None,
+        }
+    }
+
+    fn sample_index() -> ChunkVectorIndex {
+        let provider = HashingEmbeddingProvider::default();
+        let chunks = [
+            ("c:config", "def load_config(path): return parse_yaml(path)"),
+            (
+                "c:render",
+                "def render_template(name, context): return html",
+            ),
+            (
+                "c:auth",
+                "def check_password(user, password): return verify_hash",
+            ),
+        ];
+        ChunkVectorIndex::build(
+            "snap-1",
+            &provider,
+            HnswParams::default(),
+            chunks.iter().map(|(id, text)| (entry(id), *text)),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_semantic_search_ranks_related_chunk_first() {
+        let index = sample_index();
+        let provider = HashingEmbeddingProvider::default();
+        let query = provider.embed(&["parse config yaml"]).unwrap().remove(0);
+
+        let hits = index.semantic_search(&query, 2).unwrap();
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].entry.chunk_id, "c:config");
+        assert!(hits[0].score >= hits[1].score);
+    }
+
+    #[test]
+    fn test_store_round_trip_per_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = VectorIndexStore::new(dir.path());
+        let index = sample_index();
+        store.save(&index).unwrap();
+
+        let loaded = store.load("snap-1").unwrap().unwrap();
+        assert_eq!(loaded.len(), 3);
+        assert_eq!(loaded.model_id(), index.model_id());
+        assert!(store.load("snap-2").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_load_or_build_reuses_unchanged_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = VectorIndexStore::new(dir.path());
+        let provider = HashingEmbeddingProvider::default();
+        let chunks = vec![(entry("c:a"), "def alpha(): pass")];
+
+        let first = store
+            .load_or_build(&provider, HnswParams::default(), &chunks)
+            .unwrap();
+        assert!(store.path_for(first.snapshot_id()).exists());
+
+        let changed = vec![(entry("c:a"), "def beta(): pass")];
+        let second = store
+            .load_or_build(&provider, HnswParams::default(), &changed)
+            .unwrap();
+        assert_ne!(first.snapshot_id(), second.snapshot_id());
+        let again = store
+            .load_or_build(&provider, HnswParams::default(), &chunks)
+            .unwrap();
+        assert_eq!(again.snapshot_id(), first.snapshot_id());
+    }
+}
@@ -0,0 +1,42 @@
+//! Vector Search - Chunk embeddings and HNSW approximate nearest neighbours
+//!
+//! # Architecture Overview
+//!
+//! ```text
+//! ┌─────────────────────────────────────────────────────────────┐
+//! │                     Vector Search Layer                     │
+//! ├─────────────────────────────────────────────────────────────┤
+//! │  EmbeddingProvider (hashing model / callback / custom)      │
+//! │    ↓                                                        │
+//! │  ChunkVectorIndex (chunk metadata + HnswIndex)              │
+//! │    ↓                                                        │
+//! │  VectorIndexStore (one MessagePack file per snapshot)       │
+//! └─────────────────────────────────────────────────────────────┘
+//! ```
+//!
+//! The pipeline's L2.6 stage embeds L2 chunks into a `ChunkVectorIndex`;
+//! `QueryEngine::with_vectors` makes it searchable via `semantic_search`.
+//!
+//! # Usage
+//!
+//! ```text
+//! use codegraph_ir::features::vector::{ChunkVectorIndex, HashingEmbeddingProvider, HnswParams};
+//!
+//! let provider = HashingEmbeddingProvider::default();
+//! let index = ChunkVectorIndex::build("snapshot", &provider, HnswParams::default(), chunks)?;
+//! let query = provider.embed(&["parse config"])?.remove(0);
+//! for hit in index.semantic_search(&query, 10)? {
+//!     println!("{} {:.3}", hit.entry.chunk_id, hit.score);
+//! }
+//! ```
+
+pub mod hnsw;
+pub mod index;
+pub mod provider;
+
+// Re-exports
+pub use hnsw::{HnswIndex, HnswParams};
+pub use index::{ChunkVectorIndex, VectorEntry, VectorHit, VectorIndexStore};
+pub use provider::{
+    normalize, CallbackEmbeddingProvider, EmbeddingProvider, HashingEmbeddingProvider,
+};
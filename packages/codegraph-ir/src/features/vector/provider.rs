@@ -0,0 +1,205 @@
+//! Embedding providers
+//!
+//! `EmbeddingProvider` is the extension point: a local model (ONNX runtime,
+//! candle, ...) or a remote service implements it, or is wrapped with
+//! `CallbackEmbeddingProvider`. `HashingEmbeddingProvider` is the built-in
+//! local model used when nothing else is configured.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::features::lexical::split_identifier;
+use crate::shared::models::{CodegraphError, Result};
+
+/// Turns texts into fixed-size embeddings
+pub trait EmbeddingProvider: Send + Sync {
+    /// Identifies the model; indexes built with another model are rebuilt
+    fn model_id(&self) -> &str;
+
+    /// Length of every returned vector
+    fn dimension(&self) -> usize;
+
+    /// One embedding per text, in order
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>>;
+}
+
+/// Scale `vector` to unit length (zero vectors are left as is)
+pub fn normalize(vector: &mut [f32]) {
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for x in vector.iter_mut() {
+            *x /= norm;
+        }
+    }
+}
+
+/// Feature-hashing embedding over identifier parts
+///
+/// Each word and word bigram of the text (identifiers split on case and
+/// underscores, lowercased) is hashed into one of `dimension` signed
+/// buckets. No model download and fully deterministic, at the cost of
+/// only capturing vocabulary overlap.
+#[derive(Debug, Clone)]
+pub struct HashingEmbeddingProvider {
+    dimension: usize,
+    model_id: String,
+}
+
+impl HashingEmbeddingProvider {
+    pub fn new(dimension: usize) -> Self {
+        let dimension = dimension.max(1);
+        Self {
+            dimension,
+            model_id: format!("hashing-v1-{}", dimension),
+        }
+    }
+
+    fn words(text: &str) -> Vec<String> {
+        text.split(|c: char| !c.is_alphanumeric() && c != '_')
+            .flat_map(split_identifier)
+            .filter(|w| !w.is_empty() && !w.chars().all(|c| c == '_'))
+            .map(|w| w.to_lowercase())
+            .collect()
+    }
+
+    fn add_feature(&self, vector: &mut [f32], feature: &str, weight: f32) {
+        let hash = blake3::hash(feature.as_bytes());
+        let bytes = hash.as_bytes();
+        let bucket = u64::from_le_bytes(bytes[..8].try_into().unwrap()) as usize % self.dimension;
+        let sign = if bytes[8] & 1 == 0 { 1.0 } else { -1.0 };
+        vector[bucket] += sign * weight;
+    }
+
+    fn embed_one(&self, text: &str) -> Vec<f32> {
+        let mut vector = vec![0.0; self.dimension];
+        let words = Self::words(text);
+        for word in &words {
+            self.add_feature(&mut vector, word, 1.0);
+        }
+        for pair in words.windows(2) {
+            self.add_feature(&mut vector, &format!("{} {}", pair[0], pair[1]), 0.5);
+        }
+        normalize(&mut vector);
+        vector
+    }
+}
+
+impl Default for HashingEmbeddingProvider {
+    fn default() -> Self {
+        Self::new(256)
+    }
+}
+
+impl EmbeddingProvider for HashingEmbeddingProvider {
+    fn model_id(&self) -> &str {
+        &self.model_id
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        Ok(texts.iter().map(|text| self.embed_one(text)).collect())
+    }
+}
+
+type EmbedFn = dyn Fn(&[&str]) -> Result<Vec<Vec<f32>>> + Send + Sync;
+
+/// Provider backed by a closure (external service, Python model, ...)
+///
+/// Returned vectors are checked against `dimension`.
+#[derive(Clone)]
+pub struct CallbackEmbeddingProvider {
+    model_id: String,
+    dimension: usize,
+    callback: Arc<EmbedFn>,
+}
+
+impl CallbackEmbeddingProvider {
+    pub fn new<F>(model_id: impl Into<String>, dimension: usize, callback: F) -> Self
+    where
+        F: Fn(&[&str]) -> Result<Vec<Vec<f32>>> + Send + Sync + 'static,
+    {
+        Self {
+            model_id: model_id.into(),
+            dimension,
+            callback: Arc::new(callback),
+        }
+    }
+}
+
+impl fmt::Debug for CallbackEmbeddingProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CallbackEmbeddingProvider")
+            .field("model_id", &self.model_id)
+            .field("dimension", &self.dimension)
+            .finish()
+    }
+}
+
+impl EmbeddingProvider for CallbackEmbeddingProvider {
+    fn model_id(&self) -> &str {
+        &self.model_id
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        let vectors = (self.callback)(texts)?;
+        if vectors.len() != texts.len() {
+            return Err(CodegraphError::internal(format!(
+                "Embedding callback returned {} vectors for {} texts",
+                vectors.len(),
+                texts.len()
+            )));
+        }
+        if let Some(bad) = vectors.iter().find(|v| v.len() != self.dimension) {
+            return Err(CodegraphError::internal(format!(
+                "Embedding callback returned dimension {} (expected {})",
+                bad.len(),
+                self.dimension
+            )));
+        }
+        Ok(vectors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cosine(a: &[f32], b: &[f32]) -> f32 {
+        a.iter().zip(b).map(|(x, y)| x * y).sum()
+    }
+
+    #[test]
+    fn test_hashing_provider_is_deterministic_and_normalized() {
+        let provider = HashingEmbeddingProvider::new(64);
+        let a = provider.embed(&["def load_user_config(path):"]).unwrap();
+        let b = provider.embed(&["def load_user_config(path):"]).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a[0].len(), 64);
+        assert!((cosine(&a[0], &a[0]) - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_hashing_provider_splits_identifiers() {
+        let provider = HashingEmbeddingProvider::default();
+        let vectors = provider
+            .embed(&["loadUserConfig", "load_user_config", "render_html_page"])
+            .unwrap();
+        assert!(cosine(&vectors[0], &vectors[1]) > 0.99);
+        assert!(cosine(&vectors[0], &vectors[2]) < 0.5);
+    }
+
+    #[test]
+    fn test_callback_provider_checks_dimension() {
+        let provider = CallbackEmbeddingProvider::new("fixed", 3, |texts| {
+            Ok(texts.iter().map(|_| vec![1.0, 0.0]).collect())
+        });
+        assert!(provider.embed(&["x"]).is_err());
+    }
+}
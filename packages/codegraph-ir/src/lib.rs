@@ -115,14 +115,10 @@ pub use pipeline::processor::{process_python_file, ProcessResult};
 pub use features::indexing::adapters::projection::EngineHandle;
 
 // Clone Detection API exports
-// Temporarily commented out due to compilation errors
-/*
 pub use features::clone_detection::{
-    CloneDetector, CloneType, CodeFragment, ClonePair,
-    Type1Detector, Type2Detector, Type3Detector, Type4Detector,
-    MultiLevelDetector,
+    CloneDetector, ClonePair, CloneType, CodeFragment, MultiLevelDetector, Type1Detector,
+    Type2Detector, Type3Detector, Type4Detector,
 };
-*/
 
 // ═══════════════════════════════════════════════════════════════════════════
 // Internal Types
@@ -610,6 +606,26 @@ fn convert_results_to_python(py: Python, results: Vec<ProcessResult>) -> PyResul
             slice_dict.set_item("function_id", slice.function_id)?;
             slice_dict.set_item("criterion", slice.criterion)?;
             slice_dict.set_item("slice_size", slice.slice_size)?;
+            slice_dict.set_item("slice_type", slice.slice_type)?;
+            slice_dict.set_item("file_path", slice.file_path)?;
+            let span_list = |spans: Vec<Span>| -> PyResult<_> {
+                let py_spans = PyList::empty(py);
+                for span in spans {
+                    let span_dict = PyDict::new(py);
+                    span_dict.set_item("start_line", span.start_line)?;
+                    span_dict.set_item("start_col", span.start_col)?;
+                    span_dict.set_item("end_line", span.end_line)?;
+                    span_dict.set_item("end_col", span.end_col)?;
+                    py_spans.append(span_dict)?;
+                }
+                Ok(py_spans)
+            };
+            slice_dict.set_item("spans", span_list(slice.spans)?)?;
+            let py_external = PyDict::new(py);
+            for (file_path, spans) in slice.external_spans {
+                py_external.set_item(file_path, span_list(spans)?)?;
+            }
+            slice_dict.set_item("external_spans", py_external)?;
             py_slice_results.append(slice_dict)?;
         }
         result_dict.set_item("slice_results", py_slice_results)?;
@@ -905,7 +921,7 @@ fn build_global_context_msgpack(py: Python, msgpack_data: Vec<u8>) -> PyResult<V
 /// - Zero-copy columnar data access
 /// - File path deduplication
 /// - Compact binary format (38% of msgpack size)
-#[cfg(feature = "python")]
+#[cfg(all(feature = "python", feature = "arrow"))]
 #[pyfunction]
 fn build_global_context_arrow(
     py: Python,
@@ -1318,6 +1334,13 @@ fn convert_global_context_to_python(
     dict.set_item("topological_order", py_topo)?;
     let deps_time = deps_start.elapsed();
 
+    // Re-exported FQN → canonical FQN
+    let py_aliases = PyDict::new(py);
+    for (alias_fqn, alias) in result.symbol_aliases {
+        py_aliases.set_item(alias_fqn, alias.canonical_fqn)?;
+    }
+    dict.set_item("symbol_aliases", py_aliases)?;
+
     // SOTA Priority 3: Symbol-level dependency graph stats
     if let Some(stats) = result.symbol_graph_stats {
         let stats_dict = PyDict::new(py);
@@ -1407,6 +1430,8 @@ fn update_global_context_py(
         topological_order: vec![],
         build_duration_ms: 0,
         symbol_graph_stats: None,
+        symbol_aliases: HashMap::new(),
+        resolved_edges: Vec::new(),
     };
 
     // Extract changed IR documents
@@ -1765,18 +1790,29 @@ fn get_transitive_callers(
 /// - risk_level: "Low" (0.0-0.3), "Medium" (0.3-0.7), "High" (0.7-1.0)
 /// - max_call_depth: Maximum depth of call chains from this symbol
 /// - impact_by_kind: Dict of edge kind → count
+/// - owners: Who to notify (owning teams, most affected files first)
+/// - file_owners: Dict of affected file → CODEOWNERS owners
+/// - dependent_owners: Dict of transitive dependent → owners of its file
+/// - last_touch: Dict of affected file → {commit, author_name, author_email, date}
 ///
 /// Arguments:
 /// - ir_docs: List of IR documents
 /// - fqn: Fully qualified name of the symbol to analyze
+/// - repo_root: Repository root with a CODEOWNERS file (ownership keys stay
+///   empty without it)
+/// - last_touch: Also look up the latest commit of each affected file
+///   (git); unowned files then name their last author
 ///
 /// Returns: Impact analysis result (dict)
 #[cfg(feature = "python")]
 #[pyfunction]
+#[pyo3(signature = (ir_docs, fqn, repo_root = None, last_touch = false))]
 fn analyze_symbol_impact(
     py: Python,
     ir_docs: Vec<CrossFileIRDocument>,
     fqn: String,
+    repo_root: Option<String>,
+    last_touch: bool,
 ) -> PyResult<Py<PyDict>> {
     init_rayon();
 
@@ -1787,11 +1823,22 @@ fn analyze_symbol_impact(
     let total_symbols = graph.stats().total_symbols;
 
     // Compute impact
-    let impact = features::cross_file::ImpactAnalysis::compute(&graph, &fqn, total_symbols)
+    let mut impact = features::cross_file::ImpactAnalysis::compute(&graph, &fqn, total_symbols)
         .ok_or_else(|| {
             PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Symbol not found: {}", fqn))
         })?;
 
+    // Attach owners (CODEOWNERS, optionally git last touch)
+    if let Some(root) = repo_root {
+        impact = py.allow_threads(|| {
+            let mut index = features::cross_file::OwnershipIndex::discover(&root);
+            if last_touch {
+                index = index.with_git_last_touch(&impact.affected_files);
+            }
+            impact.with_ownership(&graph, &index)
+        });
+    }
+
     // Convert to Python dict
     let result = PyDict::new(py);
     result.set_item("target_fqn", &impact.target_fqn)?;
@@ -1816,6 +1863,32 @@ fn analyze_symbol_impact(
 
     result.set_item("max_call_depth", impact.max_call_depth)?;
 
+    // Ownership
+    let py_owners = PyList::new(py, impact.notify().iter().map(|s| s.as_str()));
+    result.set_item("owners", py_owners)?;
+    let file_owners_dict = PyDict::new(py);
+    let last_touch_dict = PyDict::new(py);
+    for (file, ownership) in &impact.file_owners {
+        let py_owners = PyList::new(py, ownership.owners.iter().map(|s| s.as_str()));
+        file_owners_dict.set_item(file, py_owners)?;
+        if let Some(touch) = &ownership.last_touch {
+            let touch_dict = PyDict::new(py);
+            touch_dict.set_item("commit", &touch.commit)?;
+            touch_dict.set_item("author_name", &touch.author_name)?;
+            touch_dict.set_item("author_email", &touch.author_email)?;
+            touch_dict.set_item("date", touch.date.to_rfc3339())?;
+            last_touch_dict.set_item(file, touch_dict)?;
+        }
+    }
+    result.set_item("file_owners", file_owners_dict)?;
+    result.set_item("last_touch", last_touch_dict)?;
+    let dependent_owners_dict = PyDict::new(py);
+    for (dependent, owners) in &impact.dependent_owners {
+        let py_owners = PyList::new(py, owners.iter().map(|s| s.as_str()));
+        dependent_owners_dict.set_item(dependent, py_owners)?;
+    }
+    result.set_item("dependent_owners", dependent_owners_dict)?;
+
     // impact_by_kind
     let impact_by_kind_dict = PyDict::new(py);
     for (edge_kind, count) in impact.impact_by_kind {
@@ -1952,6 +2025,40 @@ fn symbol_edge_kind_to_str(kind: features::cross_file::SymbolEdgeKind) -> &'stat
 // SOTA Repository Pipeline API
 // ═══════════════════════════════════════════════════════════════════════════
 
+/// Engine options for the flag-based `run_ir_indexing_pipeline*` entry points
+#[cfg(feature = "python")]
+fn ir_indexing_options(
+    repo_root: &str,
+    repo_name: &str,
+    file_paths: Option<Vec<String>>,
+    enable_chunking: bool,
+    enable_cross_file: bool,
+    enable_symbols: bool,
+    enable_points_to: bool,
+    enable_repomap: bool,
+    enable_taint: bool,
+    _use_trcr: bool,
+    parallel_workers: usize,
+    enable_clone: bool,
+) -> api::IndexOptions {
+    let options = api::IndexOptions::new(repo_root, repo_name)
+        .with_chunking(enable_chunking)
+        .with_cross_file(enable_cross_file)
+        .with_symbols(enable_symbols)
+        .with_points_to(enable_points_to)
+        .with_repomap(enable_repomap)
+        .with_taint(enable_taint)
+        .with_clone(enable_clone)
+        .with_parallel_workers(parallel_workers);
+
+    match file_paths {
+        Some(fps) => {
+            options.with_file_paths(fps.into_iter().map(std::path::PathBuf::from).collect())
+        }
+        None => options,
+    }
+}
+
 /// Run the SOTA IR Indexing pipeline in pure Rust
 ///
 /// This is the main entry point for Python to trigger full repository IR indexing.
@@ -1975,9 +2082,10 @@ fn symbol_edge_kind_to_str(kind: features::cross_file::SymbolEdgeKind) -> &'stat
 /// * `enable_points_to` - Enable L6 points-to analysis
 /// * `enable_repomap` - Enable L16 RepoMap repository structure visualization
 /// * `parallel_workers` - Number of parallel workers (0 = auto)
+/// * `enable_clone` - Enable L10 clone detection (Type 1-4 clone pairs)
 ///
 /// # Returns
-/// * Python dict with nodes, edges, chunks, symbols, occurrences, points_to_summary, and stats
+/// * Python dict with nodes, edges, chunks, symbols, occurrences, points_to_summary, points_to, clone_pairs, and stats
 ///
 /// # Performance
 /// - GIL released during Rust processing
@@ -1996,7 +2104,8 @@ fn symbol_edge_kind_to_str(kind: features::cross_file::SymbolEdgeKind) -> &'stat
     enable_repomap = false,
     enable_taint = false,
     use_trcr = false,
-    parallel_workers = 0
+    parallel_workers = 0,
+    enable_clone = false
 ))]
 fn run_ir_indexing_pipeline(
     py: Python,
@@ -2011,78 +2120,39 @@ fn run_ir_indexing_pipeline(
     enable_taint: bool,
     use_trcr: bool,
     parallel_workers: usize,
+    enable_clone: bool,
 ) -> PyResult<Py<PyDict>> {
-    use crate::config::{PipelineConfig, Preset, ParallelConfig as Cfg001ParallelConfig};
-    use pipeline::{E2EPipelineConfig, IRIndexingOrchestrator, IndexingMode};
-    use std::path::PathBuf;
     use std::time::Instant;
 
     init_rayon();
 
     let total_start = Instant::now();
 
-    // Build RFC-001 PipelineConfig from individual flags
-    let pipeline_config = PipelineConfig::preset(Preset::Balanced)
-        .stages(|mut s| {
-            s.parsing = true; // Always enabled
-            s.chunking = enable_chunking;
-            s.lexical = false;
-            s.cross_file = enable_cross_file;
-            s.clone = false;
-            s.pta = enable_points_to;
-            s.flow_graphs = false;
-            s.type_inference = false;
-            s.symbols = enable_symbols;
-            s.effects = false;
-            s.taint = enable_taint;
-            s.repomap = enable_repomap;
-            s.heap = enable_points_to; // Enable heap analysis when points-to is enabled
-            s.pdg = false;
-            s.concurrency = false;
-            s.slicing = false;
-            s
-        })
-        .parallel(|mut p| {
-            p.num_workers = if parallel_workers == 0 {
-                0 // Auto
-            } else {
-                parallel_workers
-            };
-            p.batch_size = 100;
-            p
-        })
-        .build()
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-            "Configuration build failed: {}", e
-        )))?;
-
-    // Build E2E pipeline config using RFC-001 ValidatedConfig
-    let config = E2EPipelineConfig::with_config(pipeline_config)
-        .repo_root(PathBuf::from(&repo_root))
-        .repo_name(repo_name.clone())
-        .indexing_mode(IndexingMode::Full)
-        .mmap_threshold(1024 * 1024);
-
-    // Set file paths if provided
-    let config = if let Some(fps) = file_paths {
-        config.file_paths(fps.into_iter().map(PathBuf::from).collect())
-    } else {
-        config
-    };
+    let options = ir_indexing_options(
+        &repo_root,
+        &repo_name,
+        file_paths,
+        enable_chunking,
+        enable_cross_file,
+        enable_symbols,
+        enable_points_to,
+        enable_repomap,
+        enable_taint,
+        use_trcr,
+        parallel_workers,
+        enable_clone,
+    );
 
     // Execute pipeline with GIL released
     let result = py
-        .allow_threads(|| {
-            let orchestrator = IRIndexingOrchestrator::new(config);
-            orchestrator.execute()
-        })
+        .allow_threads(|| api::Engine::new().index(&options))
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.message))?;
 
     let process_time = total_start.elapsed();
 
     // Convert result to Python dict
     let convert_start = Instant::now();
-    let py_result = convert_e2e_result_to_python(py, result)?;
+    let py_result = convert_e2e_result_to_python(py, result.into_pipeline())?;
     let convert_time = convert_start.elapsed();
 
     let total_time = total_start.elapsed();
@@ -2106,6 +2176,99 @@ fn run_ir_indexing_pipeline(
     Ok(py_result)
 }
 
+/// Run the IR indexing pipeline and return Arrow IPC tables
+///
+/// Same flags as `run_ir_indexing_pipeline`, but nodes, edges, occurrences
+/// and chunks come back as Arrow IPC stream bytes (one table each) instead
+/// of lists of dicts. `file_path` columns are dictionary-encoded over
+/// `result["files"]`.
+///
+/// # Returns
+/// * Python dict with `files`, `nodes`, `edges`, `occurrences`, `chunks`, `file_metrics` (bytes) and `stats`
+///
+/// # Example
+/// ```python
+/// import pyarrow as pa, polars as pl
+/// result = codegraph_ir.run_ir_indexing_pipeline_arrow("/repo", "repo")
+/// nodes = pl.from_arrow(pa.ipc.open_stream(result["nodes"]).read_all())
+/// ```
+#[cfg(all(feature = "python", feature = "arrow"))]
+#[pyfunction]
+#[pyo3(signature = (
+    repo_root,
+    repo_name,
+    file_paths = None,
+    enable_chunking = true,
+    enable_cross_file = true,
+    enable_symbols = true,
+    enable_points_to = true,
+    enable_repomap = false,
+    enable_taint = false,
+    use_trcr = false,
+    parallel_workers = 0,
+    enable_clone = false
+))]
+fn run_ir_indexing_pipeline_arrow(
+    py: Python,
+    repo_root: String,
+    repo_name: String,
+    file_paths: Option<Vec<String>>,
+    enable_chunking: bool,
+    enable_cross_file: bool,
+    enable_symbols: bool,
+    enable_points_to: bool,
+    enable_repomap: bool,
+    enable_taint: bool,
+    use_trcr: bool,
+    parallel_workers: usize,
+    enable_clone: bool,
+) -> PyResult<Py<PyDict>> {
+    use pyo3::types::PyBytes;
+
+    init_rayon();
+
+    let options = ir_indexing_options(
+        &repo_root,
+        &repo_name,
+        file_paths,
+        enable_chunking,
+        enable_cross_file,
+        enable_symbols,
+        enable_points_to,
+        enable_repomap,
+        enable_taint,
+        use_trcr,
+        parallel_workers,
+        enable_clone,
+    );
+
+    // Pipeline and Arrow encoding both run with the GIL released
+    let (result, tables) = py
+        .allow_threads(|| {
+            let result = api::Engine::new().index(&options)?.into_pipeline();
+            let tables = pipeline::result_to_arrow_ipc(&result)?;
+            Ok::<_, shared::models::CodegraphError>((result, tables))
+        })
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.message))?;
+
+    let dict = PyDict::new(py);
+    dict.set_item("files", result.file_table().paths())?;
+    dict.set_item("nodes", PyBytes::new(py, &tables.nodes))?;
+    dict.set_item("edges", PyBytes::new(py, &tables.edges))?;
+    dict.set_item("occurrences", PyBytes::new(py, &tables.occurrences))?;
+    dict.set_item("chunks", PyBytes::new(py, &tables.chunks))?;
+    dict.set_item("file_metrics", PyBytes::new(py, &tables.file_metrics))?;
+
+    let py_stats = PyDict::new(py);
+    py_stats.set_item("total_duration_ms", result.stats.total_duration.as_millis())?;
+    py_stats.set_item("files_processed", result.stats.files_processed)?;
+    py_stats.set_item("total_loc", result.stats.total_loc)?;
+    py_stats.set_item("loc_per_second", result.stats.loc_per_second)?;
+    dict.set_item("stats", py_stats)?;
+
+    Ok(dict.into())
+}
+
 /// Convert E2EPipelineResult to Python dict with Rust QueryEngine
 #[cfg(feature = "python")]
 fn convert_e2e_result_to_python(
@@ -2114,6 +2277,12 @@ fn convert_e2e_result_to_python(
 ) -> PyResult<Py<PyDict>> {
     let dict = PyDict::new(py);
 
+    // Paths are interned: entities carry `file_id` into `files`
+    // (`materialize_file_paths` restores `file_path` for older consumers)
+    let files = result.file_table();
+    let file_id = |path: &str| files.id(path);
+    dict.set_item("files", files.paths())?;
+
     // Create IRDocument from nodes and edges for QueryEngine
     let ir_doc = {
         let mut doc = features::ir_generation::domain::ir_document::IRDocument::new("".to_string());
@@ -2139,7 +2308,7 @@ fn convert_e2e_result_to_python(
             let _ = d.set_item("id", &n.id);
             let _ = d.set_item("kind", format!("{:?}", n.kind));
             let _ = d.set_item("fqn", &n.fqn);
-            let _ = d.set_item("file_path", &n.file_path);
+            let _ = d.set_item("file_id", file_id(&n.file_path));
             let _ = d.set_item("name", &n.name);
 
             let span_dict = PyDict::new(py);
@@ -2172,7 +2341,7 @@ fn convert_e2e_result_to_python(
         result.chunks.iter().map(|c| {
             let d = PyDict::new(py);
             let _ = d.set_item("id", &c.id);
-            let _ = d.set_item("file_path", &c.file_path);
+            let _ = d.set_item("file_id", file_id(&c.file_path));
             let _ = d.set_item("content", &c.content);
             let _ = d.set_item("start_line", c.start_line);
             let _ = d.set_item("end_line", c.end_line);
@@ -2191,7 +2360,7 @@ fn convert_e2e_result_to_python(
             let _ = d.set_item("id", &s.id);
             let _ = d.set_item("name", &s.name);
             let _ = d.set_item("kind", &s.kind);
-            let _ = d.set_item("file_path", &s.file_path);
+            let _ = d.set_item("file_id", file_id(&s.file_path));
             let _ = d.set_item("definition", (s.definition.0, s.definition.1));
             let _ = d.set_item("documentation", &s.documentation);
             d
@@ -2206,7 +2375,7 @@ fn convert_e2e_result_to_python(
             let d = PyDict::new(py);
             let _ = d.set_item("id", &o.id);
             let _ = d.set_item("symbol_id", &o.symbol_id);
-            let _ = d.set_item("file_path", &o.file_path);
+            let _ = d.set_item("file_id", file_id(&o.file_path));
             let _ = d.set_item("roles", o.roles); // Bitflags as u8
             let _ = d.set_item("importance_score", o.importance_score);
 
@@ -2244,6 +2413,20 @@ fn convert_e2e_result_to_python(
 
     dict.set_item("stats", py_stats)?;
 
+    // Grammars loaded by the language plugins
+    let py_grammars = PyList::empty(py);
+    for g in &result.grammars {
+        let d = PyDict::new(py);
+        d.set_item("language", &g.language)?;
+        d.set_item("plugin", &g.plugin)?;
+        d.set_item("plugin_version", &g.plugin_version)?;
+        d.set_item("grammar", &g.grammar)?;
+        d.set_item("abi_version", g.abi_version)?;
+        d.set_item("node_kind_count", g.node_kind_count)?;
+        py_grammars.append(d)?;
+    }
+    dict.set_item("grammars", py_grammars)?;
+
     // Convert points-to analysis summary (if present)
     if let Some(ref pts) = result.points_to_summary {
         let py_pts = PyDict::new(py);
@@ -2257,6 +2440,16 @@ fn convert_e2e_result_to_python(
     } else {
         dict.set_item("points_to_summary", py.None())?;
     }
+    match result.points_to {
+        Some(ref facts) => dict.set_item(
+            "points_to",
+            Py::new(
+                py,
+                adapters::pyo3::api::points_to::PyPointsToResult::new(facts.clone()),
+            )?,
+        )?,
+        None => dict.set_item("points_to", py.None())?,
+    }
 
     // Convert taint analysis results
     let py_taint_results = PyList::new(
@@ -2272,6 +2465,32 @@ fn convert_e2e_result_to_python(
     );
     dict.set_item("taint_results", py_taint_results)?;
 
+    // Convert clone pairs
+    let span_to_dict = |span: &shared::models::Span| {
+        let d = PyDict::new(py);
+        let _ = d.set_item("start_line", span.start_line);
+        let _ = d.set_item("start_col", span.start_col);
+        let _ = d.set_item("end_line", span.end_line);
+        let _ = d.set_item("end_col", span.end_col);
+        d
+    };
+    let py_clone_pairs = PyList::new(
+        py,
+        result.clone_pairs.iter().map(|c| {
+            let d = PyDict::new(py);
+            let _ = d.set_item("clone_type", &c.clone_type);
+            let _ = d.set_item("source_file_id", file_id(&c.source_file));
+            let _ = d.set_item("source_span", span_to_dict(&c.source_span));
+            let _ = d.set_item("target_file_id", file_id(&c.target_file));
+            let _ = d.set_item("target_span", span_to_dict(&c.target_span));
+            let _ = d.set_item("similarity", c.similarity);
+            let _ = d.set_item("token_count", c.token_count);
+            let _ = d.set_item("loc", c.loc);
+            d
+        }),
+    );
+    dict.set_item("clone_pairs", py_clone_pairs)?;
+
     // Convert RepoMap snapshot (if present)
     if let Some(ref snapshot) = result.repomap_snapshot {
         let py_snapshot = PyDict::new(py);
@@ -2302,6 +2521,9 @@ fn convert_e2e_result_to_python(
                 let _ = d.set_item("combined_importance", node.combined_importance);
                 let _ = d.set_item("loc", node.loc);
                 let _ = d.set_item("symbol_count", node.symbol_count);
+                let _ = d.set_item("complexity", node.complexity);
+                let _ = d.set_item("cognitive_complexity", node.cognitive_complexity);
+                let _ = d.set_item("max_nesting", node.max_nesting);
                 d
             }),
         );
@@ -2337,50 +2559,66 @@ fn codegraph_ir(_py: Python, m: &PyModule) -> PyResult<()> {
     // Rust QueryEngine (zero-copy, no Python indexing overhead)
     m.add_class::<adapters::pyo3::api::rust_query_engine::PyRustQueryEngine>()?;
 
+    // Shared graph context (batch pipeline + IDE buffer analysis)
+    m.add_class::<adapters::pyo3::api::context_handle::PyGraphContextHandle>()?;
+
     // ═══════════════════════════════════════════════════════════════════════════
     // 1. E2E Pipeline - Single Entry Point for All Analysis
     // ═══════════════════════════════════════════════════════════════════════════
     // Usage: result = codegraph_ir.run_ir_indexing_pipeline(repo_root, ...)
     // Returns: Complete IR with all analysis results (IR, chunks, symbols, etc.)
     m.add_function(wrap_pyfunction!(run_ir_indexing_pipeline, m)?)?;
+    // Results reference files by `file_id` into `result["files"]`;
+    // materialize_file_paths(result) adds `file_path` strings back
+    m.add_function(wrap_pyfunction!(adapters::pyo3::api::file_table::materialize_file_paths_py, m)?)?;
+    // Usage: stats = codegraph_ir.run_pipeline_streaming(repo_root, repo_name, config, on_batch)
+    // Streams per-stage result batches to on_batch instead of building one result dict
+    m.add_function(wrap_pyfunction!(adapters::pyo3::api::streaming::run_pipeline_streaming, m)?)?;
+    // Usage: result = codegraph_ir.run_ir_indexing_pipeline_arrow(repo_root, ...)
+    // Returns: Arrow IPC bytes per table (nodes, edges, occurrences, chunks)
+    #[cfg(feature = "arrow")]
+    m.add_function(wrap_pyfunction!(run_ir_indexing_pipeline_arrow, m)?)?;
 
     // ═══════════════════════════════════════════════════════════════════════════
     // Lexical Search API (RFC-072 L3 Layer)
-    // TEMPORARILY DISABLED: SqliteChunkStore compilation error
     // ═══════════════════════════════════════════════════════════════════════════
-    // Usage: index = codegraph_ir.LexicalIndex.new(...)
-    //        index.index_files([...])
-    //        hits = index.search("query")
-    // adapters::pyo3::api::lexical::register_lexical_api(m)?;
+    // Usage: index = codegraph_ir.LexicalIndex(index_dir, chunk_db_path, repo_id)
+    //        index.index_files([...]) / index.update_files(changed, removed)
+    //        index.add_chunks(result["chunks"])
+    //        hits = index.search("query")  # hits carry line + chunk_id
+    adapters::pyo3::api::lexical::register_lexical_api(m)?;
 
     // ═══════════════════════════════════════════════════════════════════════════
     // Clone Detection API (RFC-076)
-    // TEMPORARILY DISABLED: compilation errors preventing testing
     // ═══════════════════════════════════════════════════════════════════════════
-    // m.add_function(wrap_pyfunction!(adapters::pyo3::api::clone_detection::detect_clones_all_py, m)?)?;
-    // m.add_function(wrap_pyfunction!(adapters::pyo3::api::clone_detection::detect_clones_type1_py, m)?)?;
-    // m.add_function(wrap_pyfunction!(adapters::pyo3::api::clone_detection::detect_clones_type2_py, m)?)?;
-    // m.add_function(wrap_pyfunction!(adapters::pyo3::api::clone_detection::detect_clones_type3_py, m)?)?;
-    // m.add_function(wrap_pyfunction!(adapters::pyo3::api::clone_detection::detect_clones_type4_py, m)?)?;
-    // m.add_function(wrap_pyfunction!(adapters::pyo3::api::clone_detection::detect_clones_in_file_py, m)?)?;
+    // Usage: pairs = codegraph_ir.detect_clones_all(fragments)
+    //        pairs = codegraph_ir.detect_clones_type2(fragments, min_tokens=30)
+    m.add_function(wrap_pyfunction!(adapters::pyo3::api::clone_detection::detect_clones_all_py, m)?)?;
+    m.add_function(wrap_pyfunction!(adapters::pyo3::api::clone_detection::detect_clones_type1_py, m)?)?;
+    m.add_function(wrap_pyfunction!(adapters::pyo3::api::clone_detection::detect_clones_type2_py, m)?)?;
+    m.add_function(wrap_pyfunction!(adapters::pyo3::api::clone_detection::detect_clones_type3_py, m)?)?;
+    m.add_function(wrap_pyfunction!(adapters::pyo3::api::clone_detection::detect_clones_type4_py, m)?)?;
+    m.add_function(wrap_pyfunction!(adapters::pyo3::api::clone_detection::detect_clones_in_file_py, m)?)?;
 
     // ═══════════════════════════════════════════════════════════════════════════
-    // 2. Query Results - Use Python List Comprehensions
+    // 2. Query Results - RustQueryEngine.select (NodeSetQuery)
     // ═══════════════════════════════════════════════════════════════════════════
-    // The E2E pipeline returns native Python dicts, so you can query using:
+    // Simple lookups still work on the result dicts:
     //
-    //   # Find all functions
     //   functions = [n for n in result['nodes'] if n['kind'] == 'Function']
     //
-    //   # Find auth-related functions
-    //   auth_funcs = [n for n in result['nodes']
-    //                 if n['kind'] == 'Function' and 'auth' in n['name']]
-    //
-    //   # Get function at specific line
-    //   func = [n for n in result['nodes']
-    //           if n['span']['start_line'] == 42][0]
+    // Graph queries compose filters and traversals in Rust:
     //
-    // No need for complex Rust graph query API when Python works perfectly!
+    //   # Methods of classes inheriting Base that call save
+    //   result['query_engine'].select([
+    //       {'filter': {'kinds': ['Class']}},
+    //       {'where': {'via': {'edges': ['INHERITS'], 'max_depth': 8},
+    //                  'target': [{'filter': {'name': 'Base'}}]}},
+    //       {'traverse': {'edges': ['CONTAINS']}},
+    //       {'filter': {'kinds': ['Method']}},
+    //       {'where': {'via': {'edges': ['CALLS']},
+    //                  'target': [{'filter': {'name': 'save'}}]}},
+    //   ], arrow=True)  # Arrow IPC bytes; list of node dicts by default
     // ═══════════════════════════════════════════════════════════════════════════
 
     // ═══════════════════════════════════════════════════════════════════════════
@@ -2401,6 +2639,38 @@ fn codegraph_ir(_py: Python, m: &PyModule) -> PyResult<()> {
     // ═══════════════════════════════════════════════════════════════════════════
     adapters::pyo3::api::slice::register_slice_api(m)?;
 
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Call-Path Search
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Usage: paths = codegraph_ir.find_call_paths(ir_docs, from_fqn, to_fqn,
+    //                                             max_depth=8, edge_kinds=["Calls"], max_paths=5)
+    // Returns: shortest symbol chains with definition spans and call sites
+    adapters::pyo3::api::call_paths::register_call_paths_api(m)?;
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Impacted-Test Selection (CI)
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Usage: selection = codegraph_ir.select_impacted_tests(ir_docs, changed_files,
+    //                                                      fallback_to_full_suite=True)
+    // Returns: tests reaching the changed symbols, or the full suite when coverage is incomplete
+    adapters::pyo3::api::test_selection::register_test_selection_api(m)?;
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // RepoMap Prompt Rendering
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Usage: rendered = codegraph_ir.render_repo_map(result["repomap_snapshot"], token_budget=2048,
+    //                                                focus=[...], exclude=[...], ir_docs=None)
+    // Returns: ranked, budget-truncated text tree with top symbols per file
+    adapters::pyo3::api::repomap::register_repomap_api(m)?;
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Points-to Queries
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Usage: pta = codegraph_ir.analyze_points_to(ir_docs, mode="fast")
+    //        pta.may_alias(a, b); pta.alias_set(a); pta.allocation_sites(a)
+    // Returns: PointsToResult (also `points_to` in pipeline results)
+    adapters::pyo3::api::points_to::register_points_to_api(m)?;
+
     // ═══════════════════════════════════════════════════════════════════════════
     // RFC-001 Configuration System (Full Python Control)
     // ═══════════════════════════════════════════════════════════════════════════
@@ -2419,6 +2689,9 @@ fn codegraph_ir(_py: Python, m: &PyModule) -> PyResult<()> {
     //   - run_pipeline_with_config(): Config-based pipeline execution
     // ═══════════════════════════════════════════════════════════════════════════
     adapters::pyo3::api::config::register_config_api(m)?;
+    // Usage: token = codegraph_ir.CancellationToken(); token.cancel() from another thread
+    // Cancelled runs raise codegraph_ir.PipelineCancelled(message, partial)
+    adapters::pyo3::api::cancellation::register_cancellation_api(m)?;
 
     // ═══════════════════════════════════════════════════════════════════════════
     // DEPRECATED APIs - Disabled (use E2E pipeline instead)
@@ -2428,7 +2701,7 @@ fn codegraph_ir(_py: Python, m: &PyModule) -> PyResult<()> {
     // - get_symbol_dependencies, analyze_symbol_impact (query result nodes/edges)
     // - quick_taint_check (use analyze_taint)
     // - IR processor, streaming, advanced taint (use E2E pipeline)
-    // - PyGraphIndex, NodeFilter (use Python list comprehensions)
+    // - PyGraphIndex, NodeFilter (use RustQueryEngine.select)
     // ═══════════════════════════════════════════════════════════════════════════
 
     Ok(())
@@ -0,0 +1,449 @@
+//! Archive input (`archive` feature)
+//!
+//! Reads source files straight from a tar, tar.gz or zip stream, so CI
+//! artifacts can be analyzed without extracting them (read-only containers,
+//! no temp dir). Entries go through the same rules as `FileDiscovery`:
+//! `.gitignore` / `.ignore` files inside the archive, hidden paths,
+//! include/exclude globs, size limit, binary detection and supported
+//! extensions.
+//!
+//! ```ignore
+//! let source = ArchiveSource::detect(Path::new("build.tar.gz")).unwrap().strip_components(1);
+//! let archive = File::open("build.tar.gz")?;
+//! let result = IRIndexingOrchestrator::from_archive(config, &source, archive)?.execute()?;
+//! ```
+//!
+//! Entries are read in one pass (ignore files may come after the files they
+//! govern, so matching happens once the stream ends). Zip archives are read
+//! from their local headers; entries written with a trailing data descriptor
+//! (no size in the local header) are rejected by the zip reader.
+
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::{Component, Path};
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::overrides::Override;
+
+use super::file_discovery::{build_globs, DiscoveryStats, BINARY_SNIFF_BYTES};
+use crate::config::DiscoveryConfig;
+use crate::shared::models::{CodegraphError, Result};
+
+/// Ignore files honored inside archives (`.git/info/exclude` is never shipped)
+const IGNORE_FILES: [&str; 2] = [".gitignore", ".ignore"];
+
+/// Archive container format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Tar,
+    TarGz,
+    Zip,
+}
+
+impl ArchiveFormat {
+    /// Format implied by a file name (`.tar`, `.tar.gz` / `.tgz`, `.zip`)
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+        if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(Self::TarGz)
+        } else if name.ends_with(".tar") {
+            Some(Self::Tar)
+        } else if name.ends_with(".zip") {
+            Some(Self::Zip)
+        } else {
+            None
+        }
+    }
+}
+
+/// Source file read from an archive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchiveFile {
+    /// Path inside the archive (after `strip_components`), `/`-separated
+    pub path: String,
+    pub content: String,
+}
+
+/// Files selected from one archive
+#[derive(Debug, Clone, Default)]
+pub struct ArchiveInput {
+    /// Sorted by path
+    pub files: Vec<ArchiveFile>,
+    pub stats: DiscoveryStats,
+    /// Supported files dropped for not being UTF-8
+    pub skipped_non_utf8: usize,
+}
+
+/// Reader for one archive format
+#[derive(Debug, Clone)]
+pub struct ArchiveSource {
+    format: ArchiveFormat,
+    strip_components: usize,
+}
+
+impl ArchiveSource {
+    pub fn new(format: ArchiveFormat) -> Self {
+        Self {
+            format,
+            strip_components: 0,
+        }
+    }
+
+    /// Source for the format implied by `path`'s extension
+    pub fn detect(path: &Path) -> Option<Self> {
+        ArchiveFormat::from_path(path).map(Self::new)
+    }
+
+    /// Drop this many leading path components (like `tar --strip-components`),
+    /// e.g. 1 for GitHub tarballs wrapped in `repo-<sha>/`
+    pub fn strip_components(mut self, count: usize) -> Self {
+        self.strip_components = count;
+        self
+    }
+
+    pub fn format(&self) -> ArchiveFormat {
+        self.format
+    }
+
+    /// Files with one of `extensions` that `config` selects
+    pub fn read(
+        &self,
+        archive: impl Read,
+        extensions: &[&str],
+        config: &DiscoveryConfig,
+    ) -> Result<ArchiveInput> {
+        let mut collector = Collector::new(self.strip_components, extensions, config);
+        match self.format {
+            ArchiveFormat::Tar => read_tar(archive, &mut collector)?,
+            ArchiveFormat::TarGz => {
+                read_tar(flate2::read::GzDecoder::new(archive), &mut collector)?
+            }
+            ArchiveFormat::Zip => read_zip(archive, &mut collector)?,
+        }
+        collector.finish()
+    }
+}
+
+fn read_tar(archive: impl Read, collector: &mut Collector<'_>) -> Result<()> {
+    let mut archive = tar::Archive::new(archive);
+    let entries = archive.entries().map_err(archive_error)?;
+    for entry in entries {
+        let mut entry = entry.map_err(archive_error)?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let path = entry.path().map_err(archive_error)?.into_owned();
+        let size = entry.size();
+        collector.entry(&path, size, &mut entry)?;
+    }
+    Ok(())
+}
+
+fn read_zip(mut archive: impl Read, collector: &mut Collector<'_>) -> Result<()> {
+    while let Some(mut file) =
+        zip::read::read_zipfile_from_stream(&mut archive).map_err(archive_error)?
+    {
+        if !file.is_file() {
+            continue;
+        }
+        let Some(path) = file.enclosed_name() else {
+            continue;
+        };
+        let size = file.size();
+        collector.entry(&path, size, &mut file)?;
+    }
+    Ok(())
+}
+
+fn archive_error(e: impl std::fmt::Display) -> CodegraphError {
+    CodegraphError::io(format!("Failed to read archive: {}", e))
+}
+
+/// Applies discovery rules to archive entries
+struct Collector<'a> {
+    strip_components: usize,
+    extensions: &'a [&'a str],
+    config: &'a DiscoveryConfig,
+    /// Kept files by path (a later duplicate entry replaces an earlier one)
+    files: BTreeMap<String, String>,
+    /// Directory ("" = archive root) -> ignore file lines
+    ignore_files: BTreeMap<String, Vec<String>>,
+    stats: DiscoveryStats,
+    skipped_non_utf8: usize,
+}
+
+impl<'a> Collector<'a> {
+    fn new(
+        strip_components: usize,
+        extensions: &'a [&'a str],
+        config: &'a DiscoveryConfig,
+    ) -> Self {
+        Self {
+            strip_components,
+            extensions,
+            config,
+            files: BTreeMap::new(),
+            ignore_files: BTreeMap::new(),
+            stats: DiscoveryStats::default(),
+            skipped_non_utf8: 0,
+        }
+    }
+
+    fn entry(&mut self, path: &Path, size: u64, content: &mut impl Read) -> Result<()> {
+        let Some(path) = self.normalize(path) else {
+            return Ok(());
+        };
+        let (dir, name) = match path.rsplit_once('/') {
+            Some((dir, name)) => (dir, name),
+            None => ("", path.as_str()),
+        };
+
+        if IGNORE_FILES.contains(&name) {
+            if self.config.respect_gitignore {
+                let text = read_text(content, size)?.unwrap_or_default();
+                self.ignore_files
+                    .entry(dir.to_string())
+                    .or_default()
+                    .extend(text.lines().map(str::to_string));
+            }
+            return Ok(());
+        }
+
+        let supported = Path::new(name)
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|ext| self.extensions.contains(&ext));
+        if !supported || (!self.config.include_hidden && is_hidden(&path)) {
+            return Ok(());
+        }
+        if self.config.max_file_size_kb > 0 && size > self.config.max_file_size_kb as u64 * 1024 {
+            self.stats.skipped_too_large += 1;
+            return Ok(());
+        }
+
+        let mut bytes = Vec::with_capacity(size as usize);
+        content.read_to_end(&mut bytes).map_err(archive_error)?;
+        if self.config.skip_binary && bytes[..bytes.len().min(BINARY_SNIFF_BYTES)].contains(&0) {
+            self.stats.skipped_binary += 1;
+            return Ok(());
+        }
+        match String::from_utf8(bytes) {
+            Ok(text) => {
+                self.files.insert(path, text);
+            }
+            Err(_) => self.skipped_non_utf8 += 1,
+        }
+        Ok(())
+    }
+
+    /// `/`-separated relative path without the stripped prefix; `None` for
+    /// entries under the stripped prefix, absolute paths and `..`
+    fn normalize(&self, path: &Path) -> Option<String> {
+        let mut parts = Vec::new();
+        for component in path.components() {
+            match component {
+                Component::Normal(part) => parts.push(part.to_str()?),
+                Component::CurDir => {}
+                _ => return None,
+            }
+        }
+        if parts.len() <= self.strip_components {
+            return None;
+        }
+        Some(parts[self.strip_components..].join("/"))
+    }
+
+    /// Apply ignore files and globs, now that every entry has been seen
+    fn finish(self) -> Result<ArchiveInput> {
+        let excludes = build_globs(
+            Path::new(""),
+            self.config.exclude.iter().map(|g| format!("!{}", g)),
+        )?;
+        let includes = if self.config.include.is_empty() {
+            None
+        } else {
+            Some(build_globs(
+                Path::new(""),
+                self.config.include.iter().cloned(),
+            )?)
+        };
+        // Deepest directory first: nested ignore files override their parents
+        let mut ignores = Vec::new();
+        for (dir, lines) in self.ignore_files.iter().rev() {
+            let mut builder = GitignoreBuilder::new(dir);
+            for line in lines {
+                builder.add_line(None, line).map_err(|e| {
+                    CodegraphError::config(format!("Invalid ignore pattern {:?}: {}", line, e))
+                })?;
+            }
+            let matcher = builder
+                .build()
+                .map_err(|e| CodegraphError::config(format!("Invalid ignore file: {}", e)))?;
+            ignores.push((dir.as_str(), matcher));
+        }
+
+        let mut stats = self.stats;
+        let files: Vec<ArchiveFile> = self
+            .files
+            .into_iter()
+            .filter(|(path, _)| {
+                !is_excluded(&excludes, path)
+                    && !is_ignored(&ignores, path)
+                    && includes
+                        .as_ref()
+                        .is_none_or(|inc| inc.matched(path, false).is_whitelist())
+            })
+            .map(|(path, content)| ArchiveFile { path, content })
+            .collect();
+        stats.files = files.len();
+
+        Ok(ArchiveInput {
+            files,
+            stats,
+            skipped_non_utf8: self.skipped_non_utf8,
+        })
+    }
+}
+
+fn read_text(content: &mut impl Read, size: u64) -> Result<Option<String>> {
+    let mut bytes = Vec::with_capacity(size as usize);
+    content.read_to_end(&mut bytes).map_err(archive_error)?;
+    Ok(String::from_utf8(bytes).ok())
+}
+
+fn is_hidden(path: &str) -> bool {
+    path.split('/').any(|part| part.starts_with('.'))
+}
+
+/// Exclude globs prune directories, as in the filesystem walk
+fn is_excluded(excludes: &Override, path: &str) -> bool {
+    let mut prefix = 0;
+    while let Some(offset) = path[prefix..].find('/') {
+        prefix += offset;
+        if excludes.matched(&path[..prefix], true).is_ignore() {
+            return true;
+        }
+        prefix += 1;
+    }
+    excludes.matched(path, false).is_ignore()
+}
+
+fn is_ignored(ignores: &[(&str, Gitignore)], path: &str) -> bool {
+    for (dir, matcher) in ignores {
+        let under = dir.is_empty()
+            || path
+                .strip_prefix(dir)
+                .is_some_and(|rest| rest.starts_with('/'));
+        if !under {
+            continue;
+        }
+        let matched = matcher.matched_path_or_any_parents(path, false);
+        if matched.is_ignore() {
+            return true;
+        }
+        if matched.is_whitelist() {
+            return false;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tar_archive(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(Vec::new());
+        for (path, content) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, path, *content).unwrap();
+        }
+        builder.into_inner().unwrap()
+    }
+
+    fn zip_archive(files: &[(&str, &[u8])]) -> Vec<u8> {
+        use std::io::Write;
+        let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        for (path, content) in files {
+            writer
+                .start_file(*path, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(content).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    fn paths(input: &ArchiveInput) -> Vec<&str> {
+        input.files.iter().map(|f| f.path.as_str()).collect()
+    }
+
+    #[test]
+    fn test_tar_applies_discovery_rules() {
+        // Ignore files listed after the files they govern still apply
+        let archive = tar_archive(&[
+            ("repo-abc/app.py", b"x = 1\n"),
+            ("repo-abc/models_gen.py", b"x = 1\n"),
+            ("repo-abc/build/out.py", b"x = 1\n"),
+            ("repo-abc/node_modules/pkg/index.py", b"x = 1\n"),
+            ("repo-abc/.hidden/secret.py", b"x = 1\n"),
+            ("repo-abc/services/api/local.py", b"x = 1\n"),
+            ("repo-abc/services/api/keep_gen.py", b"x = 1\n"),
+            ("repo-abc/README.md", b"# readme\n"),
+            ("repo-abc/blob.py", b"\x00\x01binary"),
+            ("repo-abc/.gitignore", b"build/\n*_gen.py\n"),
+            ("repo-abc/services/.gitignore", b"local.py\n!keep_gen.py\n"),
+        ]);
+
+        let input = ArchiveSource::new(ArchiveFormat::Tar)
+            .strip_components(1)
+            .read(archive.as_slice(), &["py"], &DiscoveryConfig::default())
+            .unwrap();
+        assert_eq!(paths(&input), vec!["app.py", "services/api/keep_gen.py"]);
+        assert_eq!(input.files[0].content, "x = 1\n");
+        assert_eq!(input.stats.skipped_binary, 1);
+
+        let config = DiscoveryConfig::default()
+            .respect_gitignore(false)
+            .include("services/**");
+        let input = ArchiveSource::new(ArchiveFormat::Tar)
+            .strip_components(1)
+            .read(archive.as_slice(), &["py"], &config)
+            .unwrap();
+        assert_eq!(
+            paths(&input),
+            vec!["services/api/keep_gen.py", "services/api/local.py"]
+        );
+    }
+
+    #[test]
+    fn test_tar_gz_and_zip_streams() {
+        use std::io::Write;
+        let files: &[(&str, &[u8])] = &[
+            ("src/main.py", b"print(1)\n"),
+            ("src/large.py", &[b'x'; 3 * 1024]),
+            ("../escape.py", b"x = 1\n"),
+        ];
+        let config = DiscoveryConfig::default().max_file_size_kb(2);
+
+        let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        gz.write_all(&tar_archive(&files[..2])).unwrap();
+        let input = ArchiveSource::detect(Path::new("ci/build.TGZ"))
+            .unwrap()
+            .read(gz.finish().unwrap().as_slice(), &["py"], &config)
+            .unwrap();
+        assert_eq!(paths(&input), vec!["src/main.py"]);
+        assert_eq!(input.stats.skipped_too_large, 1);
+
+        let input = ArchiveSource::detect(Path::new("artifact.zip"))
+            .unwrap()
+            .read(zip_archive(files).as_slice(), &["py"], &config)
+            .unwrap();
+        assert_eq!(paths(&input), vec!["src/main.py"]);
+        assert_eq!(input.files[0].content, "print(1)\n");
+
+        assert_eq!(ArchiveFormat::from_path(Path::new("notes.txt")), None);
+    }
+}
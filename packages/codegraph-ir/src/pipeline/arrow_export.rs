@@ -0,0 +1,399 @@
+//! Arrow IPC export of the E2E pipeline result
+//!
+//! Nodes, edges, occurrences, chunks and per-file metrics (Halstead,
+//! maintainability index) are written as separate Arrow IPC streams so Python can open them with `pyarrow.ipc.open_stream` (and hand
+//! them to pandas / Polars / DuckDB) without building a dict per entity.
+//!
+//! `file_path` columns are dictionary-encoded (`UInt32` keys) over the
+//! result's `FileTable`; every table shares the same dictionary, so keys
+//! equal the `file_id`s of the dict-based API.
+
+use std::borrow::Borrow;
+use std::sync::Arc;
+
+use arrow::array::{
+    ArrayRef, DictionaryArray, Float32Array, Float64Array, StringArray, UInt32Array, UInt64Array,
+    UInt8Array,
+};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef, UInt32Type};
+use arrow::error::ArrowError;
+use arrow::ipc::writer::StreamWriter;
+use arrow::record_batch::RecordBatch;
+
+use super::end_to_end_result::E2EPipelineResult;
+use crate::features::flow_graph::infrastructure::metrics::FileMetrics;
+use crate::shared::models::{CodegraphError, FileTable, Node, Result, Span};
+
+/// Rows per record batch
+pub const ARROW_BATCH_ROWS: usize = 65_536;
+
+/// One Arrow IPC stream per result table
+#[derive(Debug, Clone, Default)]
+pub struct ArrowResultTables {
+    pub nodes: Vec<u8>,
+    pub edges: Vec<u8>,
+    pub occurrences: Vec<u8>,
+    pub chunks: Vec<u8>,
+    pub file_metrics: Vec<u8>,
+}
+
+fn arrow_error(e: ArrowError) -> CodegraphError {
+    CodegraphError::internal(format!("Arrow IPC export failed: {}", e))
+}
+
+fn path_field() -> Field {
+    Field::new(
+        "file_path",
+        DataType::Dictionary(Box::new(DataType::UInt32), Box::new(DataType::Utf8)),
+        false,
+    )
+}
+
+fn span_fields() -> [Field; 4] {
+    [
+        Field::new("start_line", DataType::UInt32, false),
+        Field::new("start_col", DataType::UInt32, false),
+        Field::new("end_line", DataType::UInt32, false),
+        Field::new("end_col", DataType::UInt32, false),
+    ]
+}
+
+fn span_columns<'a>(spans: impl Iterator<Item = &'a Span> + Clone) -> [ArrayRef; 4] {
+    [
+        Arc::new(UInt32Array::from_iter_values(
+            spans.clone().map(|s| s.start_line),
+        )),
+        Arc::new(UInt32Array::from_iter_values(
+            spans.clone().map(|s| s.start_col),
+        )),
+        Arc::new(UInt32Array::from_iter_values(
+            spans.clone().map(|s| s.end_line),
+        )),
+        Arc::new(UInt32Array::from_iter_values(spans.map(|s| s.end_col))),
+    ]
+}
+
+/// Dictionary-encoded paths over the shared `paths` values
+fn path_column<'a>(
+    files: &FileTable,
+    paths: &ArrayRef,
+    rows: impl Iterator<Item = &'a str>,
+) -> Result<ArrayRef> {
+    let keys = UInt32Array::from_iter_values(rows.map(|path| files.id(path).unwrap_or_default()));
+    let array =
+        DictionaryArray::<UInt32Type>::try_new(keys, Arc::clone(paths)).map_err(arrow_error)?;
+    Ok(Arc::new(array))
+}
+
+/// Write `rows` as one IPC stream, `ARROW_BATCH_ROWS` rows per batch
+fn write_stream<T>(
+    schema: SchemaRef,
+    rows: &[T],
+    columns: impl Fn(&[T]) -> Result<Vec<ArrayRef>>,
+) -> Result<Vec<u8>> {
+    let mut writer = StreamWriter::try_new(Vec::new(), &schema).map_err(arrow_error)?;
+    for batch in rows.chunks(ARROW_BATCH_ROWS) {
+        let batch =
+            RecordBatch::try_new(Arc::clone(&schema), columns(batch)?).map_err(arrow_error)?;
+        writer.write(&batch).map_err(arrow_error)?;
+    }
+    writer.finish().map_err(arrow_error)?;
+    writer.into_inner().map_err(arrow_error)
+}
+
+/// Node table (`result_to_arrow_ipc`'s `nodes`); `N` is `Node` or `&Node`
+fn write_nodes<N: Borrow<Node>>(
+    files: &FileTable,
+    paths: &ArrayRef,
+    nodes: &[N],
+) -> Result<Vec<u8>> {
+    let mut fields = vec![
+        Field::new("id", DataType::Utf8, false),
+        Field::new("kind", DataType::Utf8, false),
+        Field::new("fqn", DataType::Utf8, false),
+        Field::new("name", DataType::Utf8, true),
+        path_field(),
+        Field::new("language", DataType::Utf8, false),
+    ];
+    fields.extend(span_fields());
+    write_stream(Arc::new(Schema::new(fields)), nodes, |rows| {
+        let nodes = || rows.iter().map(Borrow::<Node>::borrow);
+        let mut columns: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from_iter_values(nodes().map(|n| &n.id))),
+            Arc::new(StringArray::from_iter_values(
+                nodes().map(|n| format!("{:?}", n.kind)),
+            )),
+            Arc::new(StringArray::from_iter_values(nodes().map(|n| &n.fqn))),
+            Arc::new(StringArray::from_iter(nodes().map(|n| n.name.as_deref()))),
+            path_column(files, paths, nodes().map(|n| n.file_path.as_str()))?,
+            Arc::new(StringArray::from_iter_values(nodes().map(|n| &n.language))),
+        ];
+        columns.extend(span_columns(nodes().map(|n| &n.span)));
+        Ok(columns)
+    })
+}
+
+/// Query result nodes as one Arrow IPC stream (same schema as the
+/// pipeline's node table, paths dictionary-encoded over these nodes)
+pub fn nodes_to_arrow_ipc(nodes: &[&Node]) -> Result<Vec<u8>> {
+    let mut files = FileTable::new();
+    for node in nodes {
+        files.intern(&node.file_path);
+    }
+    let paths: ArrayRef = Arc::new(StringArray::from_iter_values(files.paths()));
+    write_nodes(&files, &paths, nodes)
+}
+
+/// Encode the result's nodes, edges, occurrences, chunks and file metrics as
+/// Arrow IPC
+pub fn result_to_arrow_ipc(result: &E2EPipelineResult) -> Result<ArrowResultTables> {
+    let files = result.file_table();
+    let paths: ArrayRef = Arc::new(StringArray::from_iter_values(files.paths()));
+
+    let nodes = write_nodes(&files, &paths, &result.nodes)?;
+
+    let schema = Schema::new(vec![
+        Field::new("source_id", DataType::Utf8, false),
+        Field::new("target_id", DataType::Utf8, false),
+        Field::new("kind", DataType::Utf8, false),
+    ]);
+    let edges = write_stream(Arc::new(schema), &result.edges, |edges| {
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from_iter_values(
+                edges.iter().map(|e| &e.source_id),
+            )),
+            Arc::new(StringArray::from_iter_values(
+                edges.iter().map(|e| &e.target_id),
+            )),
+            Arc::new(StringArray::from_iter_values(
+                edges.iter().map(|e| format!("{:?}", e.kind)),
+            )),
+        ];
+        Ok(columns)
+    })?;
+
+    let mut fields = vec![
+        Field::new("id", DataType::Utf8, false),
+        Field::new("symbol_id", DataType::Utf8, false),
+        path_field(),
+        Field::new("roles", DataType::UInt8, false),
+        Field::new("importance_score", DataType::Float32, false),
+    ];
+    fields.extend(span_fields());
+    let occurrences = write_stream(
+        Arc::new(Schema::new(fields)),
+        &result.occurrences,
+        |occurrences| {
+            let mut columns: Vec<ArrayRef> = vec![
+                Arc::new(StringArray::from_iter_values(
+                    occurrences.iter().map(|o| &o.id),
+                )),
+                Arc::new(StringArray::from_iter_values(
+                    occurrences.iter().map(|o| &o.symbol_id),
+                )),
+                path_column(
+                    &files,
+                    &paths,
+                    occurrences.iter().map(|o| o.file_path.as_str()),
+                )?,
+                Arc::new(UInt8Array::from_iter_values(
+                    occurrences.iter().map(|o| o.roles),
+                )),
+                Arc::new(Float32Array::from_iter_values(
+                    occurrences.iter().map(|o| o.importance_score),
+                )),
+            ];
+            columns.extend(span_columns(occurrences.iter().map(|o| &o.span)));
+            Ok(columns)
+        },
+    )?;
+
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Utf8, false),
+        path_field(),
+        Field::new("content", DataType::Utf8, false),
+        Field::new("start_line", DataType::UInt64, false),
+        Field::new("end_line", DataType::UInt64, false),
+        Field::new("chunk_type", DataType::Utf8, false),
+        Field::new("symbol_id", DataType::Utf8, true),
+    ]);
+    let chunks = write_stream(Arc::new(schema), &result.chunks, |chunks| {
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from_iter_values(chunks.iter().map(|c| &c.id))),
+            path_column(&files, &paths, chunks.iter().map(|c| c.file_path.as_str()))?,
+            Arc::new(StringArray::from_iter_values(
+                chunks.iter().map(|c| &c.content),
+            )),
+            Arc::new(UInt64Array::from_iter_values(
+                chunks.iter().map(|c| c.start_line as u64),
+            )),
+            Arc::new(UInt64Array::from_iter_values(
+                chunks.iter().map(|c| c.end_line as u64),
+            )),
+            Arc::new(StringArray::from_iter_values(
+                chunks.iter().map(|c| &c.chunk_type),
+            )),
+            Arc::new(StringArray::from_iter(
+                chunks.iter().map(|c| c.symbol_id.as_deref()),
+            )),
+        ];
+        Ok(columns)
+    })?;
+
+    let mut fields = vec![path_field(), Field::new("language", DataType::Utf8, false)];
+    fields.extend(
+        [
+            "loc",
+            "functions",
+            "cyclomatic",
+            "cognitive",
+            "halstead_vocabulary",
+            "halstead_length",
+        ]
+        .map(|name| Field::new(name, DataType::UInt32, false)),
+    );
+    fields.extend(
+        [
+            "halstead_volume",
+            "halstead_difficulty",
+            "halstead_effort",
+            "maintainability_index",
+        ]
+        .map(|name| Field::new(name, DataType::Float64, false)),
+    );
+    let file_metrics = write_stream(
+        Arc::new(Schema::new(fields)),
+        &result.file_metrics,
+        |metrics| {
+            let u32_column = |value: fn(&FileMetrics) -> u32| -> ArrayRef {
+                Arc::new(UInt32Array::from_iter_values(metrics.iter().map(value)))
+            };
+            let f64_column = |value: fn(&FileMetrics) -> f64| -> ArrayRef {
+                Arc::new(Float64Array::from_iter_values(metrics.iter().map(value)))
+            };
+            Ok(vec![
+                path_column(&files, &paths, metrics.iter().map(|m| m.file_path.as_str()))?,
+                Arc::new(StringArray::from_iter_values(
+                    metrics.iter().map(|m| &m.language),
+                )),
+                u32_column(|m| m.loc),
+                u32_column(|m| m.functions),
+                u32_column(|m| m.cyclomatic),
+                u32_column(|m| m.cognitive),
+                u32_column(|m| m.halstead_vocabulary),
+                u32_column(|m| m.halstead_length),
+                f64_column(|m| m.halstead_volume),
+                f64_column(|m| m.halstead_difficulty),
+                f64_column(|m| m.halstead_effort),
+                f64_column(|m| m.maintainability_index),
+            ])
+        },
+    )?;
+
+    Ok(ArrowResultTables {
+        nodes,
+        edges,
+        occurrences,
+        chunks,
+        file_metrics,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::end_to_end_result::Chunk;
+    use crate::shared::models::{Edge, EdgeKind, Node, NodeKind};
+    use arrow::array::{Array, AsArray};
+    use arrow::datatypes::Float64Type;
+    use arrow::ipc::reader::StreamReader;
+    use std::io::Cursor;
+
+    fn read(bytes: &[u8]) -> Vec<RecordBatch> {
+        StreamReader::try_new(Cursor::new(bytes), None)
+            .unwrap()
+            .map(|batch| batch.unwrap())
+            .collect()
+    }
+
+    fn node(id: &str, file_path: &str) -> Node {
+        Node::new(
+            id.to_string(),
+            NodeKind::Function,
+            format!("m.{}", id),
+            file_path.to_string(),
+            Span::new(1, 0, 3, 4),
+        )
+    }
+
+    #[test]
+    fn test_tables_round_trip_with_dictionary_paths() {
+        let mut result = E2EPipelineResult::new();
+        result.nodes = vec![
+            node("a", "src/a.py"),
+            node("b", "src/b.py"),
+            node("c", "src/a.py"),
+        ];
+        result.edges = vec![Edge::new("a".to_string(), "b".to_string(), EdgeKind::Calls)];
+        result.chunks = vec![Chunk {
+            id: "chunk:a".to_string(),
+            file_path: "src/b.py".to_string(),
+            content: "def a(): pass".to_string(),
+            start_line: 1,
+            end_line: 3,
+            chunk_type: "Function".to_string(),
+            symbol_id: None,
+        }];
+
+        let tables = result_to_arrow_ipc(&result).unwrap();
+
+        let nodes = read(&tables.nodes);
+        assert_eq!(nodes.iter().map(|b| b.num_rows()).sum::<usize>(), 3);
+        let paths = nodes[0]
+            .column_by_name("file_path")
+            .unwrap()
+            .as_dictionary::<UInt32Type>();
+        assert_eq!(paths.values().len(), 2);
+        assert_eq!(paths.keys().values().to_vec(), vec![0, 1, 0]);
+
+        let chunks = read(&tables.chunks);
+        let chunk_paths = chunks[0]
+            .column_by_name("file_path")
+            .unwrap()
+            .as_dictionary::<UInt32Type>();
+        // Shared dictionary: keys are file ids of the whole result
+        assert_eq!(chunk_paths.keys().value(0), 1);
+        assert!(chunks[0].column_by_name("symbol_id").unwrap().is_null(0));
+
+        assert_eq!(read(&tables.edges)[0].num_rows(), 1);
+        assert!(read(&tables.occurrences).is_empty());
+        assert!(read(&tables.file_metrics).is_empty());
+    }
+
+    #[test]
+    fn test_file_metrics_table() {
+        let mut result = E2EPipelineResult::new();
+        result.nodes = vec![node("a", "src/a.py")];
+        result.file_metrics = vec![FileMetrics {
+            file_path: "src/empty.py".to_string(),
+            language: "python".to_string(),
+            loc: 12,
+            maintainability_index: 71.5,
+            ..Default::default()
+        }];
+
+        let tables = result_to_arrow_ipc(&result).unwrap();
+        let metrics = read(&tables.file_metrics);
+        assert_eq!(metrics[0].num_rows(), 1);
+        let paths = metrics[0]
+            .column_by_name("file_path")
+            .unwrap()
+            .as_dictionary::<UInt32Type>();
+        // Files without nodes are still in the shared dictionary
+        assert_eq!(paths.keys().value(0), 1);
+        let loc = metrics[0].column_by_name("loc").unwrap();
+        assert_eq!(loc.as_primitive::<UInt32Type>().value(0), 12);
+        let mi = metrics[0].column_by_name("maintainability_index").unwrap();
+        assert_eq!(mi.as_primitive::<Float64Type>().value(0), 71.5);
+    }
+}
@@ -0,0 +1,128 @@
+//! Pipeline cancellation
+//!
+//! `IRIndexingOrchestrator::with_cancellation` shares a `CancellationToken`
+//! with whoever may stop the run (a Python thread, a CLI signal handler).
+//! The orchestrator checks it before each L1 file, between L1 batches and
+//! before each DAG stage: files and stages already running finish, nothing
+//! new starts.
+//!
+//! Every parsed file is written to the IR cache as it completes, so a
+//! cancelled run keeps its L1 work and the next run only parses what was
+//! left. The run then fails with an `ErrorKind::Cancelled` error whose
+//! source is a `CancelledRun` describing the partial result:
+//!
+//! ```ignore
+//! let token = CancellationToken::new();
+//! ctrlc::set_handler({
+//!     let token = token.clone();
+//!     move || token.cancel()
+//! })?;
+//! match IRIndexingOrchestrator::new(config).with_cancellation(token).execute() {
+//!     Err(e) if e.is_cancelled() => {
+//!         let partial = CancelledRun::from_error(&e).unwrap();
+//!         eprintln!("{} ({} files cached)", partial, partial.stats.files_cached);
+//!     }
+//!     result => { /* ... */ }
+//! }
+//! ```
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use super::PipelineStats;
+use crate::shared::models::CodegraphError;
+
+/// Shared flag requesting a run to stop (clones share the flag)
+///
+/// Once cancelled a token stays cancelled; use a new token per run.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation (safe from any thread or signal handler)
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// What a cancelled run completed (source of its `Cancelled` error)
+#[derive(Debug, Clone)]
+pub struct CancelledRun {
+    /// Stage running when the cancellation was noticed
+    pub stage: String,
+    pub files_parsed: usize,
+    pub total_files: usize,
+    /// Stages that finished, in completion order (L1 included)
+    pub stages_completed: Vec<String>,
+    /// Stats of the partial run (durations of completed stages, cache hits)
+    pub stats: PipelineStats,
+}
+
+impl CancelledRun {
+    /// Partial result of a `Cancelled` pipeline error
+    pub fn from_error(error: &CodegraphError) -> Option<&CancelledRun> {
+        error.source.as_deref()?.downcast_ref()
+    }
+
+    pub(crate) fn into_error(self) -> CodegraphError {
+        CodegraphError::cancelled(self.to_string()).with_source(self)
+    }
+}
+
+impl fmt::Display for CancelledRun {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Pipeline cancelled during {}: {}/{} files parsed, {} stages completed",
+            self.stage,
+            self.files_parsed,
+            self.total_files,
+            self.stages_completed.len()
+        )
+    }
+}
+
+impl std::error::Error for CancelledRun {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_clones_share_flag() {
+        let token = CancellationToken::new();
+        let handle = token.clone();
+        assert!(!token.is_cancelled());
+        std::thread::spawn(move || handle.cancel()).join().unwrap();
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn test_partial_result_from_error() {
+        let error = CancelledRun {
+            stage: "L2_Chunking".to_string(),
+            files_parsed: 3,
+            total_files: 3,
+            stages_completed: vec!["L1_IR_Build".to_string()],
+            stats: PipelineStats::new(),
+        }
+        .into_error();
+
+        assert!(error.is_cancelled());
+        assert!(error.message.contains("during L2_Chunking"));
+        let partial = CancelledRun::from_error(&error).unwrap();
+        assert_eq!(partial.stages_completed, vec!["L1_IR_Build"]);
+        assert!(CancelledRun::from_error(&CodegraphError::internal("boom")).is_none());
+    }
+}
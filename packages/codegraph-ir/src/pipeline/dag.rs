@@ -29,6 +29,7 @@ pub enum StageId {
     // Phase 2: Basic Analysis
     L2Chunking,
     L2_5Lexical,
+    L2_6Vector,
     L3CrossFile,
     L4Occurrences,
     L5Symbols,
@@ -47,12 +48,34 @@ pub enum StageId {
 }
 
 impl StageId {
+    /// Every stage, in pipeline order
+    pub const ALL: [StageId; 17] = [
+        Self::L1IrBuild,
+        Self::L2Chunking,
+        Self::L2_5Lexical,
+        Self::L2_6Vector,
+        Self::L3CrossFile,
+        Self::L4Occurrences,
+        Self::L5Symbols,
+        Self::L6PointsTo,
+        Self::L10CloneDetection,
+        Self::L13EffectAnalysis,
+        Self::L14TaintAnalysis,
+        Self::L15CostAnalysis,
+        Self::L16RepoMap,
+        Self::L18ConcurrencyAnalysis,
+        Self::L21SmtVerification,
+        Self::L33GitHistory,
+        Self::L37QueryEngine,
+    ];
+
     /// Get human-readable stage name
     pub fn name(&self) -> &'static str {
         match self {
             Self::L1IrBuild => "L1_IR_Build",
             Self::L2Chunking => "L2_Chunking",
             Self::L2_5Lexical => "L2.5_Lexical",
+            Self::L2_6Vector => "L2.6_Vector",
             Self::L3CrossFile => "L3_CrossFile",
             Self::L4Occurrences => "L4_Occurrences",
             Self::L5Symbols => "L5_Symbols",
@@ -75,6 +98,7 @@ impl StageId {
             Self::L1IrBuild => "Parse files and generate intermediate representation",
             Self::L2Chunking => "Create hierarchical searchable chunks",
             Self::L2_5Lexical => "Build Tantivy full-text search index",
+            Self::L2_6Vector => "Embed chunks and build HNSW vector index",
             Self::L3CrossFile => "Resolve imports and cross-file references",
             Self::L4Occurrences => "Generate SCIP occurrences for code navigation",
             Self::L5Symbols => "Extract symbols for navigation and search",
@@ -226,6 +250,7 @@ impl PipelineDAG {
             (StageId::L1IrBuild, StageId::L5Symbols),
             (StageId::L1IrBuild, StageId::L6PointsTo),
             (StageId::L1IrBuild, StageId::L10CloneDetection),
+            (StageId::L2Chunking, StageId::L2_6Vector),
             // Phase 2 → Phase 3: Advanced analyses
             (StageId::L3CrossFile, StageId::L13EffectAnalysis),
             (StageId::L3CrossFile, StageId::L14TaintAnalysis),
@@ -355,6 +380,27 @@ impl PipelineDAG {
         result
     }
 
+    /// Size of the widest set of stages at the same dependency depth
+    /// (thread budget divisor)
+    pub fn max_parallelism(&self) -> usize {
+        let mut depth: HashMap<StageId, usize> = HashMap::new();
+        for stage in self.execution_order() {
+            let level = self
+                .dependencies(stage)
+                .iter()
+                .filter_map(|dep| depth.get(dep))
+                .max()
+                .map_or(0, |d| d + 1);
+            depth.insert(stage, level);
+        }
+
+        let mut widths: HashMap<usize, usize> = HashMap::new();
+        for level in depth.into_values() {
+            *widths.entry(level).or_default() += 1;
+        }
+        widths.into_values().max().unwrap_or(1)
+    }
+
     /// Get stages that are ready to execute (all dependencies satisfied)
     pub fn get_parallel_stages(&self, completed: &[StageId]) -> Vec<StageId> {
         let completed_set: HashSet<StageId> = completed.iter().copied().collect();
@@ -759,7 +805,7 @@ mod tests {
                 break;
             }
 
-            eprintln!(
+            tracing::debug!(
                 "Wave {}: Executing {} stages: {:?}",
                 wave,
                 ready.len(),
@@ -784,8 +830,8 @@ mod tests {
             .count();
         assert_eq!(succeeded_count, stages.len());
 
-        eprintln!("Pipeline completed in {} waves", wave);
-        eprintln!("All {} stages succeeded", succeeded_count);
+        tracing::debug!("Pipeline completed in {} waves", wave);
+        tracing::debug!("All {} stages succeeded", succeeded_count);
     }
 
     #[test]
@@ -5,10 +5,12 @@
 //! Integrates RFC-001 Config System with E2E-specific settings.
 
 use crate::config::{PipelineConfig, Preset, ValidatedConfig};
-use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 
 /// Indexing mode
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum IndexingMode {
     /// Full repository scan
     Full,
@@ -34,6 +36,18 @@ pub struct E2EPipelineConfig {
 
     /// Memory-mapped IO threshold (files larger than this use mmap)
     pub mmap_threshold_bytes: usize,
+
+    /// Directory of the per-file IR cache (no caching if None)
+    pub ir_cache_dir: Option<PathBuf>,
+
+    /// `--no-cache` override: ignore `ir_cache_dir` for this run
+    pub no_cache: bool,
+
+    /// Snapshot commit an `Incremental` run diffs against (full run if None)
+    pub base_commit: Option<String>,
+
+    /// NDJSON file each run appends its `PerfRecord` to (none if None)
+    pub perf_record_path: Option<PathBuf>,
 }
 
 /// Repository information
@@ -66,6 +80,10 @@ impl Default for E2EPipelineConfig {
             },
             mode: IndexingMode::Smart,
             mmap_threshold_bytes: 1024 * 1024, // 1MB
+            ir_cache_dir: None,
+            no_cache: false,
+            base_commit: None,
+            perf_record_path: None,
         }
     }
 }
@@ -232,6 +250,11 @@ impl E2EPipelineConfig {
         self.stages().lexical
     }
 
+    /// Check if vector indexing is enabled
+    pub fn enable_vector(&self) -> bool {
+        self.stages().vector
+    }
+
     /// Check if cross-file analysis is enabled
     pub fn enable_cross_file(&self) -> bool {
         self.stages().cross_file
@@ -287,6 +310,19 @@ impl E2EPipelineConfig {
         self.stages().symbols
     }
 
+    /// Check if per-symbol git blame / churn is enabled
+    pub fn enable_git_blame(&self) -> bool {
+        self.stages().git_blame
+    }
+
+    /// IR cache directory in effect (None when unset or `no_cache`)
+    pub fn effective_ir_cache_dir(&self) -> Option<&Path> {
+        if self.no_cache {
+            return None;
+        }
+        self.ir_cache_dir.as_deref()
+    }
+
     /// Get number of workers from parallel config
     pub fn num_workers(&self) -> Option<usize> {
         Some(self.parallel().num_workers)
@@ -332,6 +368,31 @@ impl E2EPipelineConfig {
         self
     }
 
+    /// Set the per-file IR cache directory
+    pub fn ir_cache_dir(mut self, dir: PathBuf) -> Self {
+        self.ir_cache_dir = Some(dir);
+        self
+    }
+
+    /// Disable the IR cache regardless of `ir_cache_dir`
+    pub fn no_cache(mut self, no_cache: bool) -> Self {
+        self.no_cache = no_cache;
+        self
+    }
+
+    /// Index incrementally against the snapshot recorded for `commit`
+    pub fn incremental_from(mut self, commit: impl Into<String>) -> Self {
+        self.mode = IndexingMode::Incremental;
+        self.base_commit = Some(commit.into());
+        self
+    }
+
+    /// Append a performance record of each run to the NDJSON file at `path`
+    pub fn perf_records(mut self, path: impl Into<PathBuf>) -> Self {
+        self.perf_record_path = Some(path.into());
+        self
+    }
+
     /// Override pipeline config with custom builder
     ///
     /// # Example
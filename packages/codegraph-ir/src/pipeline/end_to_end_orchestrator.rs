@@ -30,35 +30,66 @@
 //! └─────────────────────────────────────────────────────────────────┘
 //! ```
 
+use super::cancellation::{CancellationToken, CancelledRun};
 use super::dag::{PipelineDAG, StageId};
-use super::stages::{PDGSummary, SliceSummary, TaintSummary};
+use super::incremental::{self, IncrementalBase, IndexSnapshot, SnapshotStore};
+use super::file_discovery::FileDiscovery;
+use super::ir_cache::IrCache;
+use super::memory_budget::{self, MemoryBudget, SpilledAnalysis, OCCURRENCES_KEY};
+use super::perf_record::{EnvFingerprint, PerfRecord};
+use super::progress::{Progress, ProgressEvent, ProgressReporter, ProgressTracker};
+use super::result_stream::{ResultBatch, ResultSink, ResultStream};
+use super::stage_cache::StageCache;
+use super::stages::{PDGSummary, TaintSummary};
+use super::telemetry;
+use super::end_to_end_config::IndexingMode as E2EIndexingMode;
 use super::{E2EPipelineConfig, E2EPipelineResult, PipelineStats};
+use crate::features::architecture::ArchitectureChecker;
 use crate::features::chunking::{
     BuildChunksInput, ChunkKind, ChunkingUseCase, ChunkingUseCaseImpl,
 };
 use crate::features::cross_file::{
-    build_global_context, GlobalContextResult, IRDocument as CrossFileIRDocument,
+    apply_resolved_edges, build_global_context, build_global_context_with_layout,
+    update_global_context_affected,
+    ContextCallGraph, GlobalContextResult,
+    IRDocument as CrossFileIRDocument, ProjectLayout,
 };
 use crate::features::effect_analysis::application::EffectAnalysisUseCase;
 use crate::features::effect_analysis::domain::EffectType;
 use crate::features::effect_analysis::infrastructure::EffectAnalyzer;
-use crate::features::lexical::{FileToIndex, IndexingMode, TantivyLexicalIndex};
+use crate::features::flow_graph::infrastructure::cyclomatic_complexity;
+use crate::features::lexical::{
+    ChunkStore, CodeAnalyzerOptions, FileToIndex, IndexingMode, TantivyLexicalIndex,
+};
+use crate::features::parsing::grammar::{self, GrammarInfo};
+use crate::features::parsing::sdk;
 use crate::features::points_to::{
-    AnalysisConfig as PTAConfig, AnalysisMode as PTAMode, PointsToAnalyzer,
+    AnalysisConfig as PTAConfig, AnalysisMode as PTAMode, PointsToAnalyzer, PointsToResult,
 };
+use crate::features::sarif::SarifBuilder;
 use crate::features::smt::infrastructure::UnifiedOrchestrator as SmtOrchestrator;
 use crate::features::taint_analysis::infrastructure::pta_ir_extractor::PTAIRExtractor;
 use crate::features::taint_analysis::infrastructure::taint::{CallGraphNode, TaintAnalyzer};
 // Temporarily disabled: use crate::features::storage::PostgresChunkStore;
 use crate::features::clone_detection::{
-    ClonePair, CloneType, CodeFragment, HybridCloneDetector, MultiLevelDetector,
+    ClonePair, CloneType, CodeFragment, HybridCloneDetector, MultiLevelDetector, RepoCloneIndex,
+    RepoCloneIndexConfig,
 };
 use crate::features::concurrency_analysis::{
     application::{ConcurrencyAnalysisUseCase, IRDocumentConcurrencyExt},
     AsyncRaceDetector, RaceCondition,
 };
 use crate::features::git_history::{ChurnMetrics, CoChangePattern, GitExecutor};
-use crate::features::query_engine::{QueryEngine, QueryEngineStats};
+use crate::features::query_engine::{
+    complexity_report, find_dead_code, QueryEngine, QueryEngineStats,
+};
+use crate::features::vector::{
+    ChunkVectorIndex, EmbeddingProvider, HashingEmbeddingProvider, HnswParams, VectorEntry,
+    VectorIndexStore,
+};
+use crate::pipeline::processor::stages::{
+    run_interprocedural_slicing, run_slicing, run_type_inference,
+};
 use crate::pipeline::processor::{
     process_file, process_python_file, PointsToSummary, ProcessResult,
 };
@@ -68,26 +99,30 @@ use crate::features::chunking::domain::Chunk as ChunkingChunk;
 use crate::features::repomap::infrastructure::{
     GraphDocument, GraphEdge, GraphNode, PageRankEngine, PageRankSettings,
 };
-use crate::features::repomap::{
-    ImportanceWeights, NodeKind as RepoMapNodeKind, RepoMapTreeBuilder,
-};
+use crate::features::repomap::{NodeKind as RepoMapNodeKind, RepoMapTreeBuilder};
 use crate::pipeline::end_to_end_result::{RepoMapNodeSummary, RepoMapSnapshotSummary};
+use crate::pipeline::replay::ReplayBundle;
+#[cfg(feature = "archive")]
+use crate::pipeline::archive_input::ArchiveSource;
 
 use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// Stage execution output
 ///
 /// Contains all potential outputs from a pipeline stage.
 /// Only the fields relevant to that stage will be populated.
-#[derive(Default)]
-struct StageOutput {
+/// This is also what a `StageCache` stores per stage.
+#[derive(Clone, Default)]
+pub struct StageOutput {
     pub chunks: Option<Vec<super::end_to_end_result::Chunk>>,
     pub symbols: Option<Vec<super::end_to_end_result::Symbol>>,
     pub points_to_summary: Option<PointsToSummary>,
+    pub points_to: Option<PointsToResult>,
     pub clone_pairs: Option<Vec<super::end_to_end_result::ClonePairSummary>>,
     pub effect_results: Option<Vec<super::end_to_end_result::EffectSummary>>,
     pub taint_results: Option<Vec<super::stages::TaintSummary>>,
@@ -97,6 +132,9 @@ struct StageOutput {
     pub smt_results: Option<Vec<super::end_to_end_result::SMTVerificationSummary>>,
     pub git_history_results: Option<Vec<super::end_to_end_result::GitHistorySummary>>,
     pub query_engine_stats: Option<QueryEngineStats>,
+    pub vector_index: Option<Arc<ChunkVectorIndex>>,
+    pub cross_file_context: Option<GlobalContextResult>,
+    pub call_graph: Option<Arc<ContextCallGraph>>,
 }
 
 // ============================================================================
@@ -135,6 +173,12 @@ where
     /// Lexical search index (optional)
     lexical_index: Option<Arc<Mutex<TantivyLexicalIndex>>>,
 
+    /// L2.6: Chunk embedding provider (defaults to `HashingEmbeddingProvider`)
+    embedding_provider: Option<Arc<dyn EmbeddingProvider>>,
+
+    /// L2.6: Per-snapshot vector index storage (in-memory only when unset)
+    vector_store: Option<VectorIndexStore>,
+
     // ============================================================
     // UseCase Instances (DI - injected or defaulted)
     // ============================================================
@@ -150,6 +194,33 @@ where
 
     /// L18: Concurrency Analysis UseCase (trait-based)
     concurrency_usecase: C,
+
+    // ============================================================
+    // Record / Replay
+    // ============================================================
+
+    /// Write a replay bundle of the exact inputs here (record mode)
+    record_path: Option<PathBuf>,
+
+    /// Take inputs from this bundle instead of the filesystem (replay mode,
+    /// archive input): no IR cache, no incremental base or snapshot
+    replay_bundle: Option<Arc<ReplayBundle>>,
+
+    /// Receives progress events during `execute()` / `execute_streaming()`
+    progress: Option<Arc<dyn ProgressReporter>>,
+
+    /// Checked before each L1 file and DAG stage
+    cancellation: CancellationToken,
+
+    /// Outputs of DAG stages to reuse instead of executing them
+    stage_cache: Option<Arc<dyn StageCache>>,
+
+    /// Threads shared by DAG stages without an explicit pool size (`None`:
+    /// stages run on the global rayon pool)
+    thread_budget: Option<usize>,
+
+    /// Explicit pool sizes of DAG stages
+    stage_threads: HashMap<StageId, usize>,
 }
 
 /// Type alias for backward compatibility (default implementations)
@@ -176,13 +247,76 @@ impl IRIndexingOrchestrator<EffectAnalysisUseCase, ConcurrencyAnalysisUseCase, T
         Self {
             config,
             lexical_index: None,
+            embedding_provider: None,
+            vector_store: None,
             chunking_usecase: ChunkingUseCaseImpl::with_config(chunking_config),
             effect_usecase: EffectAnalysisUseCase::new(),
             taint_usecase: TaintAnalysisUseCaseImpl::new(taint_config),
             concurrency_usecase: ConcurrencyAnalysisUseCase::new(),
+            record_path: None,
+            replay_bundle: None,
+            progress: None,
+            cancellation: CancellationToken::new(),
+            stage_cache: None,
+            thread_budget: None,
+            stage_threads: HashMap::new(),
         }
     }
 
+    /// Create orchestrator that re-executes a recorded run
+    ///
+    /// Config and inputs come from the bundle; the original repository is not read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let bundle = ReplayBundle::load("bundle.json")?;
+    /// let result = IRIndexingOrchestrator::from_replay(bundle)?.execute()?;
+    /// ```
+    pub fn from_replay(bundle: ReplayBundle) -> Result<Self, CodegraphError> {
+        bundle.verify()?;
+        let mut orchestrator = Self::new(bundle.to_config()?);
+        orchestrator.replay_bundle = Some(Arc::new(bundle));
+        Ok(orchestrator)
+    }
+
+    /// Create orchestrator over the source files of a tar/zip stream
+    ///
+    /// Nothing is extracted or read from `repo_root`; entries pass the same
+    /// discovery rules as a repository scan (see `ArchiveSource`).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let source = ArchiveSource::detect(Path::new("src.tar.gz")).unwrap().strip_components(1);
+    /// let orchestrator = IRIndexingOrchestrator::from_archive(config, &source, File::open("src.tar.gz")?)?;
+    /// let result = orchestrator.execute()?;
+    /// ```
+    #[cfg(feature = "archive")]
+    pub fn from_archive(
+        config: E2EPipelineConfig,
+        source: &ArchiveSource,
+        archive: impl std::io::Read,
+    ) -> Result<Self, CodegraphError> {
+        let mut orchestrator = Self::new(config);
+        let extensions = orchestrator.discovery_extensions();
+        let input = source.read(archive, &extensions, &orchestrator.config.pipeline_config.discovery())?;
+        tracing::info!(
+            "[Archive] {} files ({} too large, {} binary, {} non-UTF-8 skipped)",
+            input.stats.files, input.stats.skipped_too_large, input.stats.skipped_binary, input.skipped_non_utf8
+        );
+
+        let inputs: Vec<(String, String, String)> = input
+            .files
+            .into_iter()
+            .map(|f| {
+                let module_path = file_to_module_path(&f.path);
+                (f.path, module_path, f.content)
+            })
+            .collect();
+        let bundle = ReplayBundle::record(&orchestrator.config, &inputs)?;
+        orchestrator.replay_bundle = Some(Arc::new(bundle));
+        Ok(orchestrator)
+    }
+
     /// Create a builder for custom UseCase injection
     ///
     /// For testing or custom implementations.
@@ -218,12 +352,82 @@ where
         Self {
             config,
             lexical_index: None,
+            embedding_provider: None,
+            vector_store: None,
             chunking_usecase: ChunkingUseCaseImpl::with_config(chunking_config),
             effect_usecase,
             taint_usecase,
             concurrency_usecase,
+            record_path: None,
+            replay_bundle: None,
+            progress: None,
+            cancellation: CancellationToken::new(),
+            stage_cache: None,
+            thread_budget: None,
+            stage_threads: HashMap::new(),
         }
     }
+
+    /// Enable record mode: the exact inputs of the next `execute()` are
+    /// written to `path` as a replay bundle before analysis starts
+    pub fn with_recording(mut self, path: impl Into<PathBuf>) -> Self {
+        self.record_path = Some(path.into());
+        self
+    }
+
+    /// Use `provider` for L2.6 chunk embeddings (local model, remote service, ...)
+    pub fn with_embedding_provider(mut self, provider: Arc<dyn EmbeddingProvider>) -> Self {
+        self.embedding_provider = Some(provider);
+        self
+    }
+
+    /// Report progress (files parsed, stages completed, estimated share done
+    /// and ETA) to `reporter` while the pipeline runs
+    pub fn with_progress(mut self, reporter: Arc<dyn ProgressReporter>) -> Self {
+        self.progress = Some(reporter);
+        self
+    }
+
+    /// Stop the pipeline when `token` is cancelled
+    ///
+    /// Running files and stages finish, then the run fails with a
+    /// `Cancelled` error carrying a `CancelledRun`. See `cancellation.rs`.
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = token;
+        self
+    }
+
+    /// Reuse DAG stage outputs stored in `cache` and store the outputs of
+    /// stages that run (see `stage_cache.rs`)
+    pub fn with_stage_cache(mut self, cache: Arc<dyn StageCache>) -> Self {
+        self.stage_cache = Some(cache);
+        self
+    }
+
+    /// Run each DAG stage on its own rayon pool, sharing `threads` evenly
+    /// across the widest set of stages that can run at once
+    pub fn with_thread_budget(mut self, threads: usize) -> Self {
+        self.thread_budget = Some(threads.max(1));
+        self
+    }
+
+    /// Run DAG stage `stage` on a dedicated pool of `threads`
+    pub fn with_stage_threads(mut self, stage: StageId, threads: usize) -> Self {
+        self.stage_threads.insert(stage, threads.max(1));
+        self
+    }
+
+    /// Token cancelling this orchestrator's runs
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancellation.clone()
+    }
+
+    /// Persist L2.6 vector indexes under `dir`, one file per snapshot;
+    /// unchanged snapshots are loaded instead of re-embedded
+    pub fn with_vector_store(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.vector_store = Some(VectorIndexStore::new(dir));
+        self
+    }
 }
 
 // ============================================================================
@@ -309,6 +513,10 @@ where
 
     /// Initialize orchestrator with lexical index
     ///
+    /// Documents are analyzed with the pipeline's lexical config (identifier
+    /// splitting, stemming, stop symbols); an existing index must have been
+    /// built with the same config.
+    ///
     /// # Example
     /// ```ignore
     /// let chunk_store = Arc::new(SqliteChunkStore::in_memory()?);
@@ -319,25 +527,32 @@ where
     ///         "my_repo".to_string(),
     ///     )?;
     /// ```
-    // Temporarily disabled due to PostgresChunkStore dependency
-    // pub fn with_lexical_index(
-    //     mut self,
-    //     index_dir: &Path,
-    //     chunk_store: Arc<PostgresChunkStore>,
-    //     repo_id: String,
-    // ) -> Result<Self, CodegraphError> {
-    //     let index = TantivyLexicalIndex::new(
-    //         index_dir,
-    //         chunk_store,
-    //         repo_id,
-    //         IndexingMode::Balanced,
-    //     ).map_err(|e| CodegraphError::internal(
-    //         format!("Failed to create lexical index: {:?}", e)
-    //     ))?;
-
-    //     self.lexical_index = Some(Arc::new(Mutex::new(index)));
-    //     Ok(self)
-    // }
+    pub fn with_lexical_index(
+        mut self,
+        index_dir: &Path,
+        chunk_store: Arc<dyn ChunkStore>,
+        repo_id: String,
+    ) -> Result<Self, CodegraphError> {
+        let analyzer = self
+            .config
+            .pipeline_config
+            .lexical()
+            .map(|lexical| CodeAnalyzerOptions::from_config(&lexical))
+            .unwrap_or_default();
+        let index = TantivyLexicalIndex::new_with_analyzer(
+            index_dir,
+            chunk_store,
+            repo_id,
+            IndexingMode::Balanced,
+            &analyzer,
+        )
+        .map_err(|e| {
+            CodegraphError::internal(format!("Failed to create lexical index: {:?}", e))
+        })?;
+
+        self.lexical_index = Some(Arc::new(Mutex::new(index)));
+        Ok(self)
+    }
 
     /// Execute the full pipeline
     ///
@@ -361,8 +576,49 @@ where
     /// println!("Processed {} files in {:?}", result.stats.files_processed, result.stats.total_duration);
     /// ```
     pub fn execute(&self) -> Result<E2EPipelineResult, CodegraphError> {
+        self.run(None, self.progress.as_deref())
+    }
+
+    /// Execute the pipeline, streaming results to `sink` as stages complete
+    ///
+    /// L1 nodes, edges and occurrences are delivered right after IR build;
+    /// chunks, symbols and clone pairs as soon as their stage finishes. Each
+    /// kind arrives in batches of at most `batch_size` items, followed by
+    /// `on_stage_complete`. Lets callers persist results incrementally instead
+    /// of converting one `E2EPipelineResult` for the whole repository.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut sink = |stage: StageId, batch: ResultBatch<'_>| -> Result<(), CodegraphError> {
+    ///     store.write(stage.name(), batch)?;
+    ///     Ok(())
+    /// };
+    /// let stats = orchestrator.execute_streaming(&mut sink, DEFAULT_BATCH_SIZE)?;
+    /// ```
+    pub fn execute_streaming(
+        &self,
+        sink: &mut dyn ResultSink,
+        batch_size: usize,
+    ) -> Result<PipelineStats, CodegraphError> {
+        let mut stream = ResultStream::new(sink, batch_size);
+        Ok(self.run(Some(&mut stream), self.progress.as_deref())?.stats)
+    }
+
+    fn run(
+        &self,
+        mut stream: Option<&mut ResultStream<'_>>,
+        reporter: Option<&dyn ProgressReporter>,
+    ) -> Result<E2EPipelineResult, CodegraphError> {
         let total_start = Instant::now();
+        let progress = ProgressTracker::new(reporter);
         let mut stats = PipelineStats::new();
+        let _pipeline_span = telemetry::pipeline_span(
+            &self.config.repo_info.repo_name,
+            &format!("{:?}", self.config.mode),
+        );
+
+        // Step 0: Fail fast if a grammar changed under its plugin
+        let grammars = grammar::check_registered_plugins()?;
 
         // Step 1: Collect files to process
         let files = self.collect_files()?;
@@ -370,40 +626,134 @@ where
 
         if files.is_empty() {
             stats.total_duration = total_start.elapsed();
+            progress.finished();
             return Ok(E2EPipelineResult {
+                grammars,
                 stats,
                 ..Default::default()
             });
         }
 
-        // Step 2: Read file contents (parallel)
-        let file_contents = self.read_files_parallel(&files)?;
+        // Step 2: Read file contents (parallel), or take them from the replay bundle
+        let file_contents = match self.replay_bundle {
+            Some(ref bundle) => bundle.inputs(),
+            None => self.read_files_parallel(&files)?,
+        };
+
+        // Record mode: persist exact inputs before any analysis runs
+        if let Some(ref record_path) = self.record_path {
+            let bundle = ReplayBundle::record(&self.config, &file_contents)?;
+            bundle.save(record_path)?;
+            tracing::info!(
+                "[Replay] Recorded {} files ({} bytes) to {} [{}]",
+                bundle.files.len(),
+                bundle.total_bytes(),
+                record_path.display(),
+                bundle.fingerprint()
+            );
+        }
+
+        // Incremental mode: changed files since the base snapshot
+        let incremental_base = self.incremental_base(&file_contents)?;
+
+        let enabled_stages = self.get_enabled_stages();
+        progress.started(file_contents.len(), enabled_stages.len());
 
-        // Step 3: L1 - IR Build (parallel per-file)
+        // Step 3: L1 - IR Build (parallel per-file, unchanged files from the IR cache)
+        let l1_span = telemetry::stage_span(&telemetry::current(), "L1_IR_Build");
         let l1_start = Instant::now();
-        let ir_results = self.execute_l1_ir_build(&file_contents)?;
+        let l1_rss = memory_budget::current_rss_bytes();
+        let ir_cache = self.open_ir_cache(&grammars)?;
+        let mut budget = self
+            .config
+            .pipeline_config
+            .memory()
+            .map(MemoryBudget::new);
+        let (mut ir_results, files_cached) = match budget.as_mut() {
+            Some(budget) => self.execute_l1_ir_build_batched(
+                &file_contents,
+                ir_cache.as_ref(),
+                budget,
+                &progress,
+            )?,
+            None => {
+                let bytes = file_contents.iter().map(|f| f.2.len()).sum();
+                let _batch = telemetry::batch_span(0, file_contents.len(), bytes);
+                self.execute_l1_ir_build(&file_contents, ir_cache.as_ref(), &progress)?
+            }
+        };
+        stats.files_cached = files_cached;
         let l1_duration = l1_start.elapsed();
         stats.record_stage("L1_IR_Build", l1_duration);
+        if let Some(memory) = memory_budget::stage_memory(l1_rss) {
+            stats.record_stage_memory("L1_IR_Build", memory);
+        }
+        for (_, result) in &ir_results {
+            stats.analysis_coverage.merge(&result.coverage);
+        }
+        drop(l1_span);
+        if self.cancellation.is_cancelled() {
+            let completed: &[StageId] = if ir_results.len() == file_contents.len() {
+                &[StageId::L1IrBuild]
+            } else {
+                &[]
+            };
+            return Err(cancelled_run(
+                StageId::L1IrBuild.name(),
+                ir_results.len(),
+                file_contents.len(),
+                completed,
+                stats,
+                total_start,
+            ));
+        }
+        progress.stage_completed("L1_IR_Build", l1_duration, true);
 
         // Aggregate L1 results
-        let (all_nodes, all_edges, all_occurrences, file_ir_map) =
+        let (mut all_nodes, mut all_edges, mut all_occurrences, file_ir_map) =
             self.aggregate_l1_results(&ir_results);
+
+        // L33: Per-symbol blame / churn (node metadata, before any stage reads the nodes)
+        if self.config.enable_git_blame() {
+            let blame_start = Instant::now();
+            self.execute_l33_git_blame(&mut all_nodes);
+            stats.record_stage("L33_GitBlame", blame_start.elapsed());
+        }
         stats.total_loc = file_contents.iter().map(|f| f.2.lines().count()).sum();
+        stats.total_bytes = file_contents.iter().map(|f| f.2.len()).sum();
+
+        if let Some(stream) = stream.as_mut() {
+            stream.emit(StageId::L1IrBuild, &all_nodes, ResultBatch::Nodes)?;
+            stream.emit(StageId::L1IrBuild, &all_edges, ResultBatch::Edges)?;
+            stream.emit(
+                StageId::L1IrBuild,
+                &all_occurrences,
+                ResultBatch::Occurrences,
+            )?;
+            stream.complete(StageId::L1IrBuild)?;
+        }
+
+        // Memory budget: no DAG stage reads occurrences, park them on disk
+        if let Some(budget) = budget.as_mut() {
+            if budget.should_spill(memory_budget::current_rss_bytes()) {
+                budget.spill(OCCURRENCES_KEY, &all_occurrences)?;
+                all_occurrences = Vec::new();
+            }
+        }
 
         // ═══════════════════════════════════════════════════════════════════
         // DAG-BASED PIPELINE EXECUTION (L2-L37)
         // ═══════════════════════════════════════════════════════════════════
         // Build DAG for all stages except L1 (already completed)
-        let enabled_stages = self.get_enabled_stages();
         let mut dag = PipelineDAG::build(&enabled_stages);
 
         // Mark L1 as completed
         dag.process_completion(StageId::L1IrBuild, true, l1_duration);
 
         // Initialize result containers (will be populated by stage execution)
-        let mut chunks = Vec::new();
         let mut symbols = Vec::new();
         let mut points_to_summary = None;
+        let mut points_to = None;
         let mut concurrency_results = Vec::new();
         let mut cost_analysis_results = Vec::new();
         let mut repomap_snapshot: Option<RepoMapSnapshotSummary> = None;
@@ -412,63 +762,83 @@ where
         let mut smt_results = Vec::new();
         let mut clone_pairs = Vec::new();
         let mut query_engine_stats = None;
+        let mut vector_index = None;
+        let mut call_graph = None;
+        let mut l3_context: Option<GlobalContextResult> = None;
 
         // Track completed stages for get_parallel_stages()
         let mut completed_stages = vec![StageId::L1IrBuild];
 
-        // DAG execution loop: run until all stages complete
-        while !dag.is_complete() {
-            // Get stages that are ready to execute (dependencies satisfied)
-            let ready_stages = dag.get_parallel_stages(&completed_stages);
-
-            if ready_stages.is_empty() {
-                // No more stages can execute - check for failures
-                let failed_count = dag
-                    .stages
-                    .values()
-                    .filter(|s| s.state == super::dag::StageState::Failed)
-                    .count();
-                if failed_count > 0 {
-                    eprintln!("[DAG] Pipeline stopped: {} stages failed", failed_count);
+        // Readiness scheduling: each stage starts as soon as its own
+        // dependencies succeed, on its own pool when budgeted
+        let pools = self.stage_pools(&dag)?;
+        let parent_span = telemetry::current();
+        // Replaced when L2 completes, before its dependents (L16RepoMap) start
+        let latest_chunks: Mutex<Arc<Vec<super::end_to_end_result::Chunk>>> = Mutex::default();
+        run_ready_stages(
+            &mut dag,
+            &mut completed_stages,
+            &pools,
+            &self.cancellation,
+            |stage_id| {
+                if self.cancellation.is_cancelled() {
+                    let e = CodegraphError::cancelled(format!("{} not started", stage_id.name()));
+                    return (Err(e), None);
+                }
+                if let Some(output) = self.stage_cache.as_ref().and_then(|c| c.lookup(stage_id)) {
+                    tracing::debug!("[DAG] {} taken from the stage cache", stage_id.name());
+                    return (Ok(output), None);
+                }
+                let rss = memory_budget::current_rss_bytes();
+                let chunks = Arc::clone(&latest_chunks.lock().unwrap());
+                let _stage_span = telemetry::stage_span(&parent_span, stage_id.name());
+                let result = self.execute_stage(
+                    stage_id,
+                    &all_nodes,
+                    &all_edges,
+                    &file_contents,
+                    &file_ir_map,
+                    &files,
+                    &chunks, // Pass chunks for L16RepoMap dependency
+                    incremental_base.as_ref(),
+                );
+                if let (Some(cache), Ok(output)) = (&self.stage_cache, &result) {
+                    cache.store(stage_id, output);
+                }
+                (result, memory_budget::stage_memory(rss))
+            },
+            |stage_id, duration, (result, memory)| {
+                if let Some(memory) = memory {
+                    stats.record_stage_memory(stage_id.name(), memory);
                 }
-                break;
-            }
-
-            eprintln!(
-                "[DAG] Executing {} stages in parallel: {:?}",
-                ready_stages.len(),
-                ready_stages
-            );
-
-            // Execute ready stages in parallel with Rayon
-            let stage_results: Vec<_> = ready_stages
-                .par_iter()
-                .map(|&stage_id| {
-                    let start = Instant::now();
-                    let result = self.execute_stage(
-                        stage_id,
-                        &all_nodes,
-                        &all_edges,
-                        &file_contents,
-                        &file_ir_map,
-                        &files,
-                        &chunks, // Pass chunks for L16RepoMap dependency
-                    );
-                    (stage_id, start.elapsed(), result)
-                })
-                .collect();
-
-            // Process results and update DAG states
-            for (stage_id, duration, result) in stage_results {
                 match result {
                     Ok(stage_output) => {
+                        if let Some(stream) = stream.as_mut() {
+                            if let Some(c) = &stage_output.chunks {
+                                stream.emit(stage_id, c, ResultBatch::Chunks)?;
+                            }
+                            if let Some(s) = &stage_output.symbols {
+                                stream.emit(stage_id, s, ResultBatch::Symbols)?;
+                            }
+                            if let Some(pairs) = &stage_output.clone_pairs {
+                                stream.emit(stage_id, pairs, ResultBatch::ClonePairs)?;
+                            }
+                            stream.complete(stage_id)?;
+                        }
+
                         // Extract outputs based on stage type
                         match stage_id {
                             StageId::L2Chunking => {
                                 if let Some(c) = stage_output.chunks {
-                                    chunks = c;
+                                    *latest_chunks.lock().unwrap() = Arc::new(c);
                                 }
                             }
+                            StageId::L3CrossFile => {
+                                l3_context = stage_output.cross_file_context;
+                                stats.call_graph =
+                                    stage_output.call_graph.as_ref().map(|g| g.stats());
+                                call_graph = stage_output.call_graph;
+                            }
                             StageId::L5Symbols => {
                                 if let Some(s) = stage_output.symbols {
                                     symbols = s;
@@ -476,6 +846,7 @@ where
                             }
                             StageId::L6PointsTo => {
                                 points_to_summary = stage_output.points_to_summary;
+                                points_to = stage_output.points_to;
                             }
                             StageId::L10CloneDetection => {
                                 if let Some(pairs) = stage_output.clone_pairs {
@@ -513,30 +884,67 @@ where
                             StageId::L37QueryEngine => {
                                 query_engine_stats = stage_output.query_engine_stats;
                             }
+                            StageId::L2_6Vector => {
+                                vector_index = stage_output.vector_index;
+                            }
                             _ => {
-                                // Other stages (L2.5, L3, L4, L14) don't have specific outputs
+                                // Other stages (L2.5, L4, L14) don't have specific outputs
                             }
                         }
 
-                        // Record timing and mark completed
+                        // Record timing (the DAG marks it completed)
                         stats.record_stage(stage_id.name(), duration);
-                        dag.process_completion(stage_id, true, duration);
-                        completed_stages.push(stage_id);
-
-                        eprintln!("[DAG] ✅ {} completed in {:?}", stage_id.name(), duration);
+                        progress.stage_completed(stage_id.name(), duration, true);
+
+                        tracing::info!(
+                            stage = stage_id.name(),
+                            duration_ms = duration.as_secs_f64() * 1000.0,
+                            "[DAG] {} completed",
+                            stage_id.name()
+                        );
+                        Ok(Some(true))
                     }
+                    // Skipped on cancellation: still pending, reported below
+                    Err(e) if e.is_cancelled() => Ok(None),
                     Err(e) => {
-                        // Stage failed - mark as failed in DAG
-                        eprintln!("[DAG] ❌ {} failed: {}", stage_id.name(), e);
+                        // Stage failed - the DAG skips its dependents
+                        tracing::warn!(
+                            stage = stage_id.name(),
+                            error = %e,
+                            "[DAG] {} failed",
+                            stage_id.name()
+                        );
                         stats.record_stage(stage_id.name(), duration);
-                        dag.process_completion(stage_id, false, duration);
-                        // Don't add to completed_stages - stage failed
+                        progress.stage_completed(stage_id.name(), duration, false);
+                        Ok(Some(false))
                     }
                 }
+            },
+        )?;
+        let chunks = Arc::try_unwrap(latest_chunks.into_inner().unwrap())
+            .unwrap_or_else(|shared| shared.as_ref().clone());
+
+        if !dag.is_complete() {
+            let pending: Vec<_> = StageId::ALL
+                .iter()
+                .filter(|&&id| dag.stages.get(&id).is_some_and(|s| !s.is_terminal()))
+                .map(|id| id.name())
+                .collect();
+            if self.cancellation.is_cancelled() {
+                return Err(cancelled_run(
+                    &pending.join(", "),
+                    ir_results.len(),
+                    file_contents.len(),
+                    &completed_stages,
+                    stats,
+                    total_start,
+                ));
             }
+            // No more stages can execute
+            tracing::warn!("[DAG] Pipeline stopped with pending stages: {:?}", pending);
         }
 
-        eprintln!(
+        tracing::info!(
             "[DAG] Pipeline execution complete: {} stages succeeded",
             dag.stages
                 .values()
@@ -544,11 +952,57 @@ where
                 .count()
         );
 
+        // Edges to symbols of other files point at their L3 resolutions
+        if let Some(context) = &l3_context {
+            let resolved = apply_resolved_edges(&mut all_edges, &context.resolved_edges);
+            tracing::debug!("[L3] {} edges resolved across files", resolved);
+        }
+
         // Finalize stats
         stats.total_duration = total_start.elapsed();
         stats.calculate_rate();
         stats.calculate_cache_hit_rate();
 
+        // Memory budget: read spilled results back for final aggregation
+        if let Some(ref budget) = budget {
+            for (file_path, result) in ir_results.iter_mut() {
+                if let Some(spilled) = budget.restore::<SpilledAnalysis>(file_path)? {
+                    spilled.restore(result);
+                    stats.files_spilled += 1;
+                }
+            }
+            if let Some(occurrences) = budget.restore(OCCURRENCES_KEY)? {
+                all_occurrences = occurrences;
+            }
+            stats.spilled_bytes = budget.spilled_bytes();
+        }
+        // Interprocedural slices stitch the PDGs of all files
+        let interprocedural_slices = match self
+            .config
+            .pipeline_config
+            .slicing()
+            .filter(|c| c.enabled && c.interprocedural && !c.criteria.is_empty())
+        {
+            Some(slicing) => {
+                let sources: HashMap<&str, &str> = file_contents
+                    .iter()
+                    .map(|(file_path, _, content)| (file_path.as_str(), content.as_str()))
+                    .collect();
+                let files: Vec<(&ProcessResult, &str)> = ir_results
+                    .iter()
+                    .filter_map(|(file_path, result)| {
+                        Some((result, *sources.get(file_path.as_str())?))
+                    })
+                    .collect();
+                run_interprocedural_slicing(&files, &slicing)
+            }
+            None => Vec::new(),
+        };
+        let file_ir_map: HashMap<String, &ProcessResult> = ir_results
+            .iter()
+            .map(|(file_path, result)| (file_path.clone(), result))
+            .collect();
+
         // ===================================================================
         // AGGREGATE ADVANCED ANALYSIS RESULTS FROM ALL FILES
         // ===================================================================
@@ -559,12 +1013,32 @@ where
         let mut all_ssa_graphs = Vec::new();
         let mut all_pdg_graphs = Vec::new();
         let mut all_taint_results = Vec::new();
-        let mut all_slice_results = Vec::new();
+        let mut all_slice_results = interprocedural_slices;
         let mut all_memory_safety = Vec::new();
         let mut all_security_vulns = Vec::new();
+        let mut all_file_metrics = Vec::new();
         let mut ir_documents = HashMap::new();
+        // SARIF export (raw findings; summaries below drop the taint path)
+        let sarif_config = self
+            .config
+            .pipeline_config
+            .sarif()
+            .filter(|c| c.output_path.is_some());
+        let mut sarif = sarif_config.clone().map(SarifBuilder::new);
 
         for (file_path, process_result) in &file_ir_map {
+            if let Some(sarif) = sarif.as_mut() {
+                sarif.add_nodes(&process_result.nodes);
+                for vuln in &process_result.security_vulnerabilities {
+                    sarif.add_vulnerability(vuln);
+                }
+                for issue in &process_result.memory_safety_issues {
+                    sarif.add_memory_issue(issue);
+                }
+            }
+
+            all_file_metrics.extend(process_result.file_metrics.clone());
+
             // Aggregate BFG and CFG from ProcessResult
             all_bfg_graphs.extend(process_result.bfg_graphs.iter().map(|bfg| {
                 super::end_to_end_result::BFGSummary {
@@ -574,7 +1048,7 @@ where
                     edge_count: 0, // BFG doesn't store edges explicitly
                     entry_block: Some(bfg.entry_block_id.clone()),
                     exit_blocks: vec![bfg.exit_block_id.clone()],
-                    cyclomatic_complexity: 1, // Simplified: 1 + decision nodes (not computed in BFG)
+                    cyclomatic_complexity: cyclomatic_complexity(&bfg.blocks) as usize,
                 }
             }));
             all_cfg_edges.extend(process_result.cfg_edges.iter().map(|edge| {
@@ -643,13 +1117,7 @@ where
                     // Note: stages::TaintSummary is simplified, doesn't have sota_enabled/sanitized_paths
                 }
             }));
-            all_slice_results.extend(process_result.slice_results.iter().map(|slice| {
-                SliceSummary {
-                    function_id: slice.function_id.clone(),
-                    criterion: slice.criterion.clone(),
-                    slice_size: slice.slice_size,
-                }
-            }));
+            all_slice_results.extend(process_result.slice_results.iter().cloned());
 
             // Convert heap analysis results to summaries
             all_memory_safety.extend(process_result.memory_safety_issues.iter().map(|issue| {
@@ -717,12 +1185,54 @@ where
             };
             ir_documents.insert(file_path.clone(), ir_doc);
         }
-        let _cross_file_context = if self.config.pipeline_config.as_inner().stages.cross_file {
-            let ir_docs: Vec<_> = ir_documents.values().cloned().collect();
-            build_global_context(ir_docs)
+
+        // Architecture rules (violations also go to the SARIF log)
+        let architecture_violations = match self.config.pipeline_config.architecture() {
+            Some(architecture) => {
+                let rules = architecture.load_rules(&self.config.repo_info.repo_root)?;
+                let mut docs: Vec<_> = ir_documents.values().cloned().collect();
+                docs.sort_by(|a, b| a.file_path.cmp(&b.file_path));
+                let violations = ArchitectureChecker::new(rules)?.check(&docs);
+                tracing::info!("[Architecture] {} violations", violations.len());
+                violations
+            }
+            None => Vec::new(),
+        };
+        let dead_code = match self.config.pipeline_config.dead_code() {
+            Some(config) => {
+                let dead = find_dead_code(&all_nodes, &all_edges, &all_occurrences, &config);
+                tracing::info!("[DeadCode] {} unreferenced symbols", dead.len());
+                dead
+            }
+            None => Vec::new(),
+        };
+        all_file_metrics.sort_by(|a, b| a.file_path.cmp(&b.file_path));
+        if let Some(sarif) = sarif.as_mut() {
+            for violation in &architecture_violations {
+                sarif.add_architecture_violation(violation);
+            }
+        }
+
+        let sarif_output = sarif_config.and_then(|c| Some((c.output_path?, c.encoding)));
+        if let (Some(sarif), Some((path, encoding))) = (sarif, sarif_output) {
+            let log = sarif.build();
+            match log.write_to_with(&path, encoding) {
+                Ok(()) => tracing::info!("[SARIF] {} results written to {}", log.result_count(), path),
+                Err(e) => tracing::warn!("[SARIF] Failed to write {}: {}", path, e),
+            }
+        }
+
+        let cross_file_context = if self.config.pipeline_config.as_inner().stages.cross_file {
+            Some(l3_context.unwrap_or_else(|| {
+                let ir_docs: Vec<_> = ir_documents.values().cloned().collect();
+                build_global_context(ir_docs)
+            }))
         } else {
-            GlobalContextResult::default()
+            None
         };
+        self.save_snapshot(&file_contents, cross_file_context)?;
+        self.append_perf_record(&stats);
+        progress.finished();
 
         Ok(E2EPipelineResult {
             nodes: all_nodes,
@@ -745,16 +1255,54 @@ where
             smt_results,
             clone_pairs,
             concurrency_results,
+            architecture_violations,
+            dead_code,
             ir_documents: HashMap::new(),
+            grammars,
             points_to_summary,
+            points_to,
             cost_analysis_results,
+            file_metrics: all_file_metrics,
             repomap_snapshot,    // L16 RepoMap result
             git_history_results, // L33 Git History result
             query_engine_stats,  // L37 Query Engine stats
+            vector_index,        // L2.6 Vector index
+            call_graph,          // L3 context-sensitive call graph
             stats,
         })
     }
 
+    /// Pool size of a DAG stage: explicit, else its share of the thread
+    /// budget; `None` runs it on the global pool
+    fn stage_threads(&self, stage: StageId, dag: &PipelineDAG) -> Option<usize> {
+        self.stage_threads.get(&stage).copied().or_else(|| {
+            self.thread_budget
+                .map(|budget| (budget / dag.max_parallelism()).max(1))
+        })
+    }
+
+    /// Dedicated rayon pools of the budgeted DAG stages (L1 has already run)
+    fn stage_pools(
+        &self,
+        dag: &PipelineDAG,
+    ) -> Result<HashMap<StageId, rayon::ThreadPool>, CodegraphError> {
+        dag.stages
+            .keys()
+            .filter(|&&stage| stage != StageId::L1IrBuild)
+            .filter_map(|&stage| Some((stage, self.stage_threads(stage, dag)?)))
+            .map(|(stage, threads)| {
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .thread_name(move |i| format!("{}-{}", stage.name(), i))
+                    .build()
+                    .map_err(|e| {
+                        CodegraphError::internal(format!("{} thread pool: {}", stage.name(), e))
+                    })?;
+                Ok((stage, pool))
+            })
+            .collect()
+    }
+
     /// Execute a single pipeline stage
     ///
     /// Dispatcher method that routes each StageId to its corresponding execute method.
@@ -771,6 +1319,7 @@ where
         file_ir_map: &HashMap<String, &ProcessResult>,
         files: &[PathBuf],
         chunks: &[super::end_to_end_result::Chunk], // Needed for L16RepoMap
+        incremental_base: Option<&IncrementalBase>,  // Needed for incremental L3
     ) -> Result<StageOutput, CodegraphError> {
         let mut output = StageOutput::default();
 
@@ -790,9 +1339,20 @@ where
                 self.execute_l2_5_lexical(file_contents)?;
                 // No output - lexical index is updated in place
             }
+            StageId::L2_6Vector => {
+                // Depends on L2Chunking output
+                if !chunks.is_empty() {
+                    let index = self.execute_l2_6_vector(chunks)?;
+                    output.vector_index = Some(Arc::new(index));
+                } else {
+                    tracing::warn!("[L2.6 Vector] Warning: No chunks available, skipping");
+                }
+            }
             StageId::L3CrossFile => {
-                self.execute_l3_cross_file(file_ir_map)?;
-                // No output - cross-file resolution happens in place
+                let (context, call_graph) =
+                    self.execute_l3_cross_file(file_ir_map, incremental_base)?;
+                output.cross_file_context = Some(context);
+                output.call_graph = call_graph;
             }
             StageId::L4Occurrences => {
                 // Occurrences are generated in L1 (ProcessResult)
@@ -803,8 +1363,10 @@ where
                 output.symbols = Some(symbols);
             }
             StageId::L6PointsTo => {
-                let pta = self.execute_l6_points_to(all_nodes, all_edges)?;
-                output.points_to_summary = pta;
+                if let Some((summary, facts)) = self.execute_l6_points_to(all_nodes, all_edges)? {
+                    output.points_to_summary = Some(summary);
+                    output.points_to = Some(facts);
+                }
             }
             StageId::L10CloneDetection => {
                 let pairs = self.execute_l10_clone_detection(all_nodes, file_contents)?;
@@ -818,7 +1380,7 @@ where
                 // Execute repository-wide taint analysis
                 let taint_summaries = self.execute_l14_taint_analysis(file_ir_map)?;
                 output.taint_results = Some(taint_summaries);
-                eprintln!(
+                tracing::info!(
                     "[L14 TaintAnalysis] Detected {} taint flows",
                     output.taint_results.as_ref().map(|t| t.len()).unwrap_or(0)
                 );
@@ -830,11 +1392,14 @@ where
             StageId::L16RepoMap => {
                 // Depends on L2Chunking output
                 if !chunks.is_empty() {
-                    let snapshot =
-                        self.execute_l16_repomap(chunks, &self.config.repo_info.repo_name)?;
+                    let snapshot = self.execute_l16_repomap(
+                        chunks,
+                        all_nodes,
+                        &self.config.repo_info.repo_name,
+                    )?;
                     output.repomap_snapshot = Some(snapshot);
                 } else {
-                    eprintln!("[L16 RepoMap] Warning: No chunks available, skipping");
+                    tracing::warn!("[L16 RepoMap] Warning: No chunks available, skipping");
                 }
             }
             StageId::L18ConcurrencyAnalysis => {
@@ -860,8 +1425,8 @@ where
         Ok(output)
     }
 
-    /// Get enabled stages based on configuration
-    fn get_enabled_stages(&self) -> Vec<StageId> {
+    /// Get enabled stages based on configuration (L1 included)
+    pub fn get_enabled_stages(&self) -> Vec<StageId> {
         let mut stages = vec![];
         let stage_config = &self.config.pipeline_config.as_inner().stages;
 
@@ -875,6 +1440,9 @@ where
         if stage_config.lexical {
             stages.push(StageId::L2_5Lexical);
         }
+        if stage_config.chunking && stage_config.vector {
+            stages.push(StageId::L2_6Vector);
+        }
         if stage_config.cross_file {
             stages.push(StageId::L3CrossFile);
         }
@@ -933,6 +1501,8 @@ where
     ) -> Result<Vec<(String, String, String)>, CodegraphError> {
         // (file_path, module_path, content)
         let repo_root = &self.config.repo_info.repo_root;
+        // Python/JVM module paths follow pyproject, setup.cfg and Gradle/Maven roots
+        let layout = ProjectLayout::discover(repo_root);
 
         let results: Vec<_> = files
             .par_iter()
@@ -943,7 +1513,9 @@ where
                     .unwrap_or(path)
                     .to_string_lossy()
                     .to_string();
-                let module_path = self.file_to_module_path(&file_path);
+                let module_path = layout
+                    .module_path(&file_path)
+                    .unwrap_or_else(|| self.file_to_module_path(&file_path));
                 Some((file_path, module_path, content))
             })
             .collect();
@@ -953,15 +1525,7 @@ where
 
     /// Convert file path to module path
     fn file_to_module_path(&self, file_path: &str) -> String {
-        file_path
-            .trim_end_matches(".py")
-            .trim_end_matches(".rs")
-            .trim_end_matches(".js")
-            .trim_end_matches(".ts")
-            .trim_end_matches(".kt")
-            .trim_end_matches(".java")
-            .trim_end_matches(".go")
-            .replace(['/', '\\'], ".")
+        file_to_module_path(file_path)
     }
 
     /// L1: IR Build - Parse and generate IR for all files
@@ -970,28 +1534,247 @@ where
     /// and uses the appropriate LanguagePlugin for parsing.
     ///
     /// Supported: Python, Java, TypeScript, JavaScript, Kotlin, Rust, Go
+    ///
+    /// Returns the per-file results and how many came from `cache`.
     fn execute_l1_ir_build(
         &self,
         files: &[(String, String, String)],
-    ) -> Result<Vec<(String, ProcessResult)>, CodegraphError> {
+        cache: Option<&IrCache>,
+        progress: &ProgressTracker<'_>,
+    ) -> Result<(Vec<(String, ProcessResult)>, usize), CodegraphError> {
         let repo_id = &self.config.repo_info.repo_name;
+        let cached = AtomicUsize::new(0);
+        // Slices of configured criteria are not cached: criteria change between runs.
+        // Interprocedural slices are computed once all files are built.
+        let slicing = self
+            .config
+            .pipeline_config
+            .slicing()
+            .filter(|c| c.enabled && !c.interprocedural && !c.criteria.is_empty());
+        let attach_slices = |result: &mut ProcessResult, content: &str| {
+            if let Some(slicing) = &slicing {
+                result.slice_results = run_slicing(
+                    &result.nodes,
+                    &result.bfg_graphs,
+                    &result.cfg_edges,
+                    &result.dfg_graphs,
+                    content,
+                    slicing,
+                );
+            }
+        };
+        // Types are not cached either: the stage can be toggled between runs.
+        let infer_types = self.config.enable_types();
+        let attach_types = |result: &mut ProcessResult, file_path: &str, content: &str| {
+            if infer_types && file_path.ends_with(".py") {
+                let entities = run_type_inference(
+                    &mut result.nodes,
+                    &mut result.edges,
+                    &result.dfg_graphs,
+                    content,
+                    repo_id,
+                );
+                result.type_entities.extend(entities);
+            }
+        };
 
+        // Files already started finish (and reach the cache) on cancellation
         let results: Vec<_> = files
             .par_iter()
-            .map(|(file_path, module_path, content)| {
+            .filter_map(|(file_path, module_path, content)| {
+                if self.cancellation.is_cancelled() {
+                    return None;
+                }
+                let key = cache.map(|c| c.key(repo_id, file_path, module_path, content));
+                if let (Some(cache), Some(key)) = (cache, &key) {
+                    if let Some(mut result) = cache.get(key) {
+                        cached.fetch_add(1, Ordering::Relaxed);
+                        attach_types(&mut result, file_path, content);
+                        attach_slices(&mut result, content);
+                        progress.file_parsed(file_path, true);
+                        return Some((file_path.clone(), result));
+                    }
+                }
+
                 // Detect language and use appropriate processor
                 // Python files get the optimized process_python_file path with per-function BFG
-                let result = if file_path.ends_with(".py") {
+                let mut result = if file_path.ends_with(".py") {
                     process_python_file(content, repo_id, file_path, module_path)
                 } else {
                     // Use multi-language process_file for other languages
                     process_file(content, repo_id, file_path, module_path)
                 };
-                (file_path.clone(), result)
+
+                if let (Some(cache), Some(key)) = (cache, &key) {
+                    if IrCache::cacheable(&result) {
+                        if let Err(e) = cache.put(key, &result) {
+                            tracing::warn!("[IR Cache] Failed to store {}: {}", file_path, e);
+                        }
+                    }
+                }
+                attach_types(&mut result, file_path, content);
+                attach_slices(&mut result, content);
+                progress.file_parsed(file_path, false);
+                Some((file_path.clone(), result))
             })
             .collect();
 
-        Ok(results)
+        Ok((results, cached.into_inner()))
+    }
+
+    /// L1 under a memory budget
+    ///
+    /// Parses byte-sized batches one after another. After a batch, if
+    /// resident memory reached the spill threshold, the batch's intermediate
+    /// analyses go to the spill store and the next batch is halved.
+    fn execute_l1_ir_build_batched(
+        &self,
+        files: &[(String, String, String)],
+        cache: Option<&IrCache>,
+        budget: &mut MemoryBudget,
+        progress: &ProgressTracker<'_>,
+    ) -> Result<(Vec<(String, ProcessResult)>, usize), CodegraphError> {
+        let mut results = Vec::with_capacity(files.len());
+        let mut cached = 0;
+        let mut rest = files;
+
+        let mut index = 0;
+        while !rest.is_empty() && !self.cancellation.is_cancelled() {
+            let (batch, tail) = rest.split_at(budget.next_batch_len(rest));
+            rest = tail;
+            let bytes = batch.iter().map(|f| f.2.len()).sum();
+            let _batch_span = telemetry::batch_span(index, batch.len(), bytes);
+            index += 1;
+            let (mut batch_results, batch_cached) =
+                self.execute_l1_ir_build(batch, cache, progress)?;
+            cached += batch_cached;
+
+            if budget.should_spill(memory_budget::current_rss_bytes()) {
+                for (file_path, result) in &mut batch_results {
+                    budget.spill(file_path, &SpilledAnalysis::take(result))?;
+                }
+                budget.shrink_batch();
+                tracing::info!(
+                    "[Memory] Spilled {} files, next batch {} MB",
+                    batch_results.len(),
+                    budget.batch_bytes() / (1024 * 1024)
+                );
+            }
+            results.append(&mut batch_results);
+        }
+
+        Ok((results, cached))
+    }
+
+    /// Snapshot store under the IR cache directory (None without one)
+    fn snapshot_store(&self) -> Option<SnapshotStore> {
+        self.config
+            .effective_ir_cache_dir()
+            .map(|dir| SnapshotStore::new(dir, &self.config.repo_info.repo_name))
+    }
+
+    /// Base of an incremental run (None unless `Incremental` mode has a base
+    /// commit with a recorded snapshot)
+    fn incremental_base(
+        &self,
+        file_contents: &[(String, String, String)],
+    ) -> Result<Option<IncrementalBase>, CodegraphError> {
+        let base_commit = match self.config.base_commit {
+            Some(ref commit)
+                if self.config.mode == E2EIndexingMode::Incremental
+                    && self.replay_bundle.is_none() =>
+            {
+                commit
+            }
+            _ => return Ok(None),
+        };
+        // Prior IR and snapshots both live in the IR cache
+        let store = self.snapshot_store().ok_or_else(|| {
+            CodegraphError::config("Incremental indexing from a base commit needs an IR cache dir")
+        })?;
+
+        let changes = incremental::git_changes(&self.config.repo_info.repo_root, base_commit)?;
+        let Some(snapshot) = store.load(&changes.base_commit)? else {
+            tracing::info!(
+                "[Incremental] No snapshot for {}, indexing all files",
+                changes.base_commit
+            );
+            return Ok(None);
+        };
+
+        let current: Vec<&str> = file_contents.iter().map(|f| f.0.as_str()).collect();
+        let base = IncrementalBase::new(snapshot, &changes, &current);
+        tracing::info!(
+            "[Incremental] {} files changed since {}",
+            base.changed.len(),
+            changes.base_commit
+        );
+        Ok(Some(base))
+    }
+
+    /// Record this run as the snapshot of `HEAD` (Incremental mode only)
+    fn save_snapshot(
+        &self,
+        file_contents: &[(String, String, String)],
+        cross_file: Option<GlobalContextResult>,
+    ) -> Result<(), CodegraphError> {
+        if self.config.mode != E2EIndexingMode::Incremental || self.replay_bundle.is_some() {
+            return Ok(());
+        }
+        let Some(store) = self.snapshot_store() else {
+            return Ok(());
+        };
+        // Outside a git repository there is nothing to diff against later
+        let head = match incremental::git_changes(&self.config.repo_info.repo_root, "HEAD") {
+            Ok(head) => head,
+            Err(e) => {
+                tracing::warn!("[Incremental] Snapshot not saved: {}", e);
+                return Ok(());
+            }
+        };
+
+        let mut files: Vec<String> = file_contents.iter().map(|f| f.0.clone()).collect();
+        files.sort();
+        store.save(&IndexSnapshot {
+            commit: head.head_commit,
+            files,
+            dirty: head.changed,
+            cross_file,
+        })
+    }
+
+    /// Append this run's performance record (if `perf_record_path` is set)
+    ///
+    /// Failures are logged, not returned: dashboards must not break indexing.
+    fn append_perf_record(&self, stats: &PipelineStats) {
+        let Some(ref path) = self.config.perf_record_path else {
+            return;
+        };
+        let record = PerfRecord::from_stats(
+            &self.config.repo_info.repo_name,
+            incremental::head_commit(&self.config.repo_info.repo_root).ok(),
+            &self.config.pipeline_config.as_inner().get_preset().to_string(),
+            self.config.mode,
+            stats,
+            EnvFingerprint::current(),
+        );
+        if let Err(e) = record.append_to(path) {
+            tracing::warn!("[Perf] Failed to append record to {}: {}", path.display(), e);
+        }
+    }
+
+    /// IR cache for this run (None when disabled or replaying a bundle)
+    fn open_ir_cache(
+        &self,
+        grammars: &[GrammarInfo],
+    ) -> Result<Option<IrCache>, CodegraphError> {
+        if self.replay_bundle.is_some() {
+            return Ok(None);
+        }
+        self.config
+            .effective_ir_cache_dir()
+            .map(|dir| IrCache::open(dir, grammars))
+            .transpose()
     }
 
     /// Aggregate L1 results from all files
@@ -1104,12 +1887,18 @@ where
     }
 
     /// L3: Cross-file resolution
+    ///
+    /// With an incremental base, only changed files and their dependents
+    /// are re-resolved against the base snapshot's context. A
+    /// context-sensitive call graph over the resolved call edges is built
+    /// when selected in the config.
     fn execute_l3_cross_file(
         &self,
         file_ir_map: &HashMap<String, &ProcessResult>,
-    ) -> Result<HashMap<String, Vec<String>>, CodegraphError> {
+        incremental_base: Option<&IncrementalBase>,
+    ) -> Result<(GlobalContextResult, Option<Arc<ContextCallGraph>>), CodegraphError> {
         // Convert ProcessResult to CrossFileIRDocument format
-        let ir_docs: Vec<CrossFileIRDocument> = file_ir_map
+        let mut ir_docs: Vec<CrossFileIRDocument> = file_ir_map
             .iter()
             .map(|(file_path, result)| {
                 CrossFileIRDocument::new(
@@ -1120,11 +1909,54 @@ where
             })
             .collect();
 
-        // Build global context
-        let context = build_global_context(ir_docs);
+        let call_graph_config = self.config.pipeline_config.call_graph();
+
+        // TS/JS path aliases (`@/lib`) and JVM module boundaries come from build files
+        let layout = ProjectLayout::discover(&self.config.repo_info.repo_root);
+
+        let previous = incremental_base.and_then(|base| {
+            base.snapshot
+                .cross_file
+                .as_ref()
+                .map(|context| (base, context))
+        });
+        let context = match previous {
+            Some((base, previous)) => {
+                let (context, affected) = update_global_context_affected(
+                    previous,
+                    &base.changed,
+                    &ir_docs,
+                    &layout,
+                );
+                tracing::info!(
+                    "[Incremental] L3 re-resolved {} of {} files",
+                    affected.len(),
+                    ir_docs.len()
+                );
+                context
+            }
+            // Build global context (the call graph below still reads the documents)
+            None if call_graph_config.is_some() => {
+                build_global_context_with_layout(ir_docs.clone(), &layout)
+            }
+            None => build_global_context_with_layout(std::mem::take(&mut ir_docs), &layout),
+        };
+
+        let call_graph = call_graph_config.map(|config| {
+            let graph = ContextCallGraph::build_with_resolved_edges(
+                &ir_docs,
+                &context.resolved_edges,
+                &config,
+            );
+            let stats = graph.stats();
+            tracing::info!(
+                "[L3 CallGraph] {}-CFA: {} contexts, {} call edges, {} unresolved call sites",
+                stats.context_depth, stats.contexts, stats.call_edges, stats.unresolved_call_sites
+            );
+            Arc::new(graph)
+        });
 
-        // Convert file_dependencies to simple map for result
-        Ok(context.file_dependencies)
+        Ok((context, call_graph))
     }
 
     /// L5: Symbol extraction for navigation
@@ -1161,22 +1993,38 @@ where
 
     /// L6: Points-to analysis for repository-wide alias computation
     ///
-    /// Runs SOTA points-to analysis (Andersen/Steensgaard) on the entire repository
-    /// to compute may-alias and must-alias relationships.
+    /// Runs SOTA points-to analysis (Andersen/Steensgaard, or 1-CFA / 1-object
+    /// when the PTA config selects them) on the entire repository
+    /// to compute may-alias and must-alias relationships. Returns the
+    /// statistics and the queryable facts.
     fn execute_l6_points_to(
         &self,
         nodes: &[Node],
         edges: &[Edge],
-    ) -> Result<Option<PointsToSummary>, CodegraphError> {
+    ) -> Result<Option<(PointsToSummary, PointsToResult)>, CodegraphError> {
         // Skip if too few nodes (not worth the overhead)
         if nodes.len() < 10 {
             return Ok(None);
         }
 
-        // Create PTA config - Fast mode (Steensgaard only)
+        // Context-sensitive modes are opt-in through the PTA stage config
+        let context_sensitive = self.config.pipeline_config.pta().and_then(|pta| {
+            let mode = match pta.mode {
+                crate::config::PTAMode::CallSiteSensitive => PTAMode::CallSiteSensitive,
+                crate::config::PTAMode::ObjectSensitive => PTAMode::ObjectSensitive,
+                _ => return None,
+            };
+            Some(PTAConfig {
+                mode,
+                field_sensitive: pta.field_sensitive,
+                ..Default::default()
+            })
+        });
+
+        // Otherwise Fast mode (Steensgaard only)
         // After Steensgaard optimization (13,771x speedup from bug fixes),
         // Fast mode now achieves both high speed AND acceptable precision
-        let config = PTAConfig {
+        let config = context_sensitive.unwrap_or(PTAConfig {
             mode: PTAMode::Fast,    // ✅ Steensgaard (O(n·α(n)), now 13,771x faster!)
             field_sensitive: false, // ✅ Field-insensitive for maximum speed
             max_iterations: 10,     // ✅ Limit iterations (for Andersen fallback)
@@ -1184,7 +2032,7 @@ where
             enable_scc: true,       // Use equivalence classes
             enable_wave: false,     // Not applicable for Steensgaard
             enable_parallel: true,
-        };
+        });
 
         let mut analyzer = PointsToAnalyzer::new(config);
         let mut extractor = PTAIRExtractor::new();
@@ -1203,20 +2051,22 @@ where
         // Calculate alias pairs
         let alias_pairs = result.graph.stats.total_edges;
 
-        Ok(Some(PointsToSummary {
+        let summary = PointsToSummary {
             variables_count: result.stats.variables,
             allocations_count: result.stats.locations,
             constraints_count: result.stats.constraints_total,
             alias_pairs,
             mode_used: format!("{:?}", result.mode_used),
             duration_ms: result.stats.duration_ms,
-        }))
+        };
+        Ok(Some((summary, PointsToResult::from_analysis(&analyzer, &result))))
     }
 
     /// Execute with progress callback
     ///
-    /// Same as `execute()` but calls `progress_fn(current, total)` periodically
-    /// to report progress.
+    /// Same as `execute()` but calls `progress_fn(parsed, total)` as L1 files
+    /// are parsed and once more when the run finishes. See `with_progress`
+    /// for stage events and ETA.
     pub fn execute_with_progress<F>(
         &self,
         progress_fn: F,
@@ -1224,19 +2074,15 @@ where
     where
         F: Fn(usize, usize) + Send + Sync,
     {
-        let files = self.collect_files()?;
-        let total_files = files.len();
-
-        // TODO (Phase 1.3): Integrate progress tracking into parallel execution
-        // - Use atomic counter for processed files
-        // - Call progress_fn after each batch
-
-        let result = self.execute()?;
-
-        // Report final progress
-        progress_fn(total_files, total_files);
-
-        Ok(result)
+        let reporter = |event: &ProgressEvent<'_>, progress: &Progress| {
+            if matches!(
+                event,
+                ProgressEvent::FileParsed { .. } | ProgressEvent::Finished { .. }
+            ) {
+                progress_fn(progress.files_parsed, progress.total_files);
+            }
+        };
+        self.run(None, Some(&reporter))
     }
 
     /// Collect files to process based on configuration
@@ -1244,8 +2090,11 @@ where
     /// Priority:
     /// 1. Use `config.repo_info.file_paths` if provided (incremental mode)
     /// 2. Otherwise, scan repository (full mode)
-    fn collect_files(&self) -> Result<Vec<PathBuf>, CodegraphError> {
-        if let Some(ref file_paths) = self.config.repo_info.file_paths {
+    pub fn collect_files(&self) -> Result<Vec<PathBuf>, CodegraphError> {
+        if let Some(ref bundle) = self.replay_bundle {
+            // Replay mode: recorded file list (already in processing order)
+            Ok(bundle.files.iter().map(|f| PathBuf::from(&f.path)).collect())
+        } else if let Some(ref file_paths) = self.config.repo_info.file_paths {
             // Incremental mode: use provided file list
             Ok(file_paths.clone())
         } else {
@@ -1256,66 +2105,54 @@ where
 
     /// Scan repository for supported files
     ///
-    /// Filters:
-    /// - Supported extensions: .py, .rs, .js, .ts, .go, .java
-    /// - Ignores: hidden dirs (.), node_modules, target, __pycache__
+    /// Filters (see `FileDiscovery`):
+    /// - Supported extensions: every extension of a registered language plugin
+    /// - `.gitignore`, hidden files, discovery include/exclude globs
+    ///   (default excludes: node_modules/, target/, vendor/, ...)
+    /// - Size limit and binary detection
     fn scan_repository(&self) -> Result<Vec<PathBuf>, CodegraphError> {
-        let mut files = Vec::new();
         let repo_root = &self.config.repo_info.repo_root;
-
-        // Supported extensions (can be filtered by language_filter)
-        let extensions = match &self.config.repo_info.language_filter {
-            Some(langs) => langs.iter().map(|l| self.lang_to_ext(l)).collect(),
-            None => vec!["py", "rs", "js", "ts", "go", "java", "kt"],
-        };
-
-        // Recursive directory walk
-        self.walk_dir(repo_root, &extensions, &mut files)?;
+        let extensions = self.discovery_extensions();
+
+        let discovery = FileDiscovery::new(
+            repo_root,
+            &extensions,
+            self.config.pipeline_config.discovery(),
+        )?;
+        let (files, stats) = discovery.discover()?;
+        if stats.skipped_too_large > 0 || stats.skipped_binary > 0 {
+            tracing::info!(
+                "[Discovery] {} files ({} too large, {} binary skipped)",
+                stats.files, stats.skipped_too_large, stats.skipped_binary
+            );
+        }
 
         Ok(files)
     }
 
-    /// Helper: Walk directory recursively
-    fn walk_dir(
-        &self,
-        dir: &std::path::Path,
-        extensions: &[&str],
-        files: &mut Vec<PathBuf>,
-    ) -> Result<(), CodegraphError> {
-        if !dir.is_dir() {
-            return Ok(());
+    /// Supported extensions (can be filtered by language_filter)
+    fn discovery_extensions(&self) -> Vec<&'static str> {
+        match &self.config.repo_info.language_filter {
+            Some(langs) => langs.iter().flat_map(|l| self.lang_to_exts(l)).collect(),
+            None => sdk::supported_extensions(),
         }
+    }
 
-        for entry in std::fs::read_dir(dir).map_err(|e| {
-            CodegraphError::internal(format!("Failed to read directory {}: {}", dir.display(), e))
-        })? {
-            let entry = entry
-                .map_err(|e| CodegraphError::internal(format!("Failed to read entry: {}", e)))?;
-            let path = entry.path();
-
-            // Skip ignored directories
-            if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-                if name.starts_with('.')
-                    || name == "node_modules"
-                    || name == "target"
-                    || name == "__pycache__"
-                    || name == "venv"
-                    || name == ".venv"
-                {
-                    continue;
-                }
-            }
-
-            if path.is_dir() {
-                self.walk_dir(&path, extensions, files)?;
-            } else if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
-                if extensions.contains(&ext) {
-                    files.push(path);
-                }
-            }
+    /// Helper: All extensions registered for a language name
+    ///
+    /// Falls back to `lang_to_ext` for aliases (e.g. "c++") and unknown names.
+    fn lang_to_exts(&self, lang: &str) -> Vec<&'static str> {
+        let lang = lang.to_lowercase();
+        let exts: Vec<&'static str> = sdk::registered_plugins()
+            .into_iter()
+            .filter(|p| p.language.name() == lang)
+            .flat_map(|p| p.extensions.iter().copied())
+            .collect();
+        if exts.is_empty() {
+            vec![self.lang_to_ext(&lang)]
+        } else {
+            exts
         }
-
-        Ok(())
     }
 
     /// Helper: Convert language name to file extension
@@ -1328,11 +2165,118 @@ where
             "go" => "go",
             "java" => "java",
             "kotlin" => "kt",
+            "c" => "c",
+            "cpp" | "c++" => "cpp",
+            "php" => "php",
+            "ruby" => "rb",
             _ => "txt", // Fallback
         }
     }
 }
 
+/// Run the stages of `dag` as soon as their own dependencies succeed
+///
+/// `run` executes on a scoped thread, inside the stage's pool in `pools`
+/// when it has one. `finish` consumes each result on the calling thread in
+/// completion order and returns whether the stage succeeded, or `None` when
+/// it did not run (cancelled) and stays pending; succeeded stages are added
+/// to `completed`. No stage starts after cancellation or a `finish` error;
+/// running stages are awaited either way.
+fn run_ready_stages<R: Send>(
+    dag: &mut PipelineDAG,
+    completed: &mut Vec<StageId>,
+    pools: &HashMap<StageId, rayon::ThreadPool>,
+    cancellation: &CancellationToken,
+    run: impl Fn(StageId) -> R + Sync,
+    mut finish: impl FnMut(StageId, Duration, R) -> Result<Option<bool>, CodegraphError>,
+) -> Result<(), CodegraphError> {
+    let run = &run;
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::scope(|scope| {
+        let mut running = 0;
+        let mut error = None;
+        loop {
+            if error.is_none() && !cancellation.is_cancelled() {
+                for stage_id in dag.get_parallel_stages(completed) {
+                    if let Some(stage) = dag.stages.get_mut(&stage_id) {
+                        stage.mark_running();
+                    }
+                    let pool = pools.get(&stage_id);
+                    let tx = tx.clone();
+                    scope.spawn(move || {
+                        let start = Instant::now();
+                        let output = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                            match pool {
+                                Some(pool) => pool.install(|| run(stage_id)),
+                                None => run(stage_id),
+                            }
+                        }));
+                        let _ = tx.send((stage_id, start.elapsed(), output));
+                    });
+                    running += 1;
+                }
+            }
+            if running == 0 {
+                break;
+            }
+
+            let (stage_id, duration, output) = rx.recv().expect("stage threads report back");
+            running -= 1;
+            let output = output.unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+            match finish(stage_id, duration, output) {
+                Ok(Some(succeeded)) => {
+                    dag.process_completion(stage_id, succeeded, duration);
+                    if succeeded {
+                        completed.push(stage_id);
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    error.get_or_insert(e);
+                }
+            }
+        }
+        match error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    })
+}
+
+/// `Cancelled` error of a run stopped before `stage` started (or finished)
+fn cancelled_run(
+    stage: &str,
+    files_parsed: usize,
+    total_files: usize,
+    completed: &[StageId],
+    mut stats: PipelineStats,
+    total_start: Instant,
+) -> CodegraphError {
+    stats.total_duration = total_start.elapsed();
+    tracing::info!("[Cancel] Pipeline cancelled during {}", stage);
+    CancelledRun {
+        stage: stage.to_string(),
+        files_parsed,
+        total_files,
+        stages_completed: completed.iter().map(|s| s.name().to_string()).collect(),
+        stats,
+    }
+    .into_error()
+}
+
+/// Module path for a repository-relative file path (`src/utils.py` → `src.utils`)
+pub(crate) fn file_to_module_path(file_path: &str) -> String {
+    file_path
+        .trim_end_matches(".py")
+        .trim_end_matches(".rs")
+        .trim_end_matches(".js")
+        .trim_end_matches(".ts")
+        .trim_end_matches(".kt")
+        .trim_end_matches(".java")
+        .trim_end_matches(".go")
+        .replace(['/', '\\'], ".")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1368,6 +2312,575 @@ mod tests {
         assert_eq!(result.stats.files_processed, 0);
         assert_eq!(result.nodes.len(), 0);
     }
+
+    fn dag_after_l1() -> PipelineDAG {
+        let mut dag = PipelineDAG::build(&[
+            StageId::L1IrBuild,
+            StageId::L2Chunking,
+            StageId::L3CrossFile,
+            StageId::L16RepoMap,
+        ]);
+        dag.process_completion(StageId::L1IrBuild, true, Duration::ZERO);
+        dag
+    }
+
+    #[test]
+    fn test_stage_starts_when_its_dependencies_finish() {
+        let mut dag = dag_after_l1();
+        let mut completed = vec![StageId::L1IrBuild];
+        let (repomap_tx, repomap_rx) = std::sync::mpsc::channel();
+        let (repomap_tx, repomap_rx) = (Mutex::new(repomap_tx), Mutex::new(repomap_rx));
+        let mut finished = Vec::new();
+
+        run_ready_stages(
+            &mut dag,
+            &mut completed,
+            &HashMap::new(),
+            &CancellationToken::new(),
+            |stage_id| match stage_id {
+                // Does not finish before L16 (after L2) has run
+                StageId::L3CrossFile => repomap_rx
+                    .lock()
+                    .unwrap()
+                    .recv_timeout(Duration::from_secs(10))
+                    .is_ok(),
+                StageId::L16RepoMap => repomap_tx.lock().unwrap().send(()).is_ok(),
+                _ => true,
+            },
+            |stage_id, _, succeeded| {
+                finished.push(stage_id);
+                Ok(Some(succeeded))
+            },
+        )
+        .unwrap();
+
+        assert!(dag.is_complete());
+        assert_eq!(completed.len(), 4);
+        assert_eq!(finished.last(), Some(&StageId::L3CrossFile));
+    }
+
+    #[test]
+    fn test_stage_thread_pools() {
+        let mut dag = dag_after_l1();
+        let orchestrator = IRIndexingOrchestrator::new(E2EPipelineConfig::default());
+        assert!(orchestrator.stage_pools(&dag).unwrap().is_empty());
+
+        // Widest level: L2 and L3
+        let orchestrator = orchestrator
+            .with_thread_budget(8)
+            .with_stage_threads(StageId::L16RepoMap, 1);
+        let pools = orchestrator.stage_pools(&dag).unwrap();
+        assert_eq!(pools.len(), 3);
+        assert_eq!(pools[&StageId::L2Chunking].current_num_threads(), 4);
+
+        let mut threads = HashMap::new();
+        run_ready_stages(
+            &mut dag,
+            &mut vec![StageId::L1IrBuild],
+            &pools,
+            &CancellationToken::new(),
+            |_| rayon::current_num_threads(),
+            |stage_id, _, n| {
+                threads.insert(stage_id, n);
+                Ok(Some(true))
+            },
+        )
+        .unwrap();
+        assert_eq!(threads[&StageId::L3CrossFile], 4);
+        assert_eq!(threads[&StageId::L16RepoMap], 1);
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let source_path = dir.path().join("app.py");
+        std::fs::write(
+            &source_path,
+            "def main():\n    helper()\n\ndef helper():\n    pass\n",
+        )
+        .unwrap();
+        let bundle_path = dir.path().join("bundle.json");
+
+        let config = E2EPipelineConfig::fast()
+            .repo_root(dir.path().to_path_buf())
+            .repo_name("replay-test".to_string());
+        let recorded = IRIndexingOrchestrator::new(config)
+            .with_recording(bundle_path.clone())
+            .execute()
+            .unwrap();
+
+        // Replay must not depend on the original files
+        std::fs::remove_file(&source_path).unwrap();
+
+        let bundle = ReplayBundle::load(&bundle_path).unwrap();
+        assert_eq!(bundle.files.len(), 1);
+        assert_eq!(bundle.files[0].path, "app.py");
+
+        let replayed = IRIndexingOrchestrator::from_replay(bundle)
+            .unwrap()
+            .execute()
+            .unwrap();
+
+        assert_eq!(replayed.stats.files_processed, recorded.stats.files_processed);
+        assert_eq!(replayed.nodes.len(), recorded.nodes.len());
+        assert_eq!(replayed.edges.len(), recorded.edges.len());
+    }
+
+    #[test]
+    fn test_execute_streaming_delivers_l1_batches() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("app.py"),
+            "def main():\n    helper()\n\ndef helper():\n    pass\n",
+        )
+        .unwrap();
+        let config = E2EPipelineConfig::fast()
+            .repo_root(dir.path().to_path_buf())
+            .repo_name("stream-test".to_string());
+        let expected = IRIndexingOrchestrator::new(config.clone()).execute().unwrap();
+
+        let mut streamed_nodes = 0;
+        let mut batch_stages = Vec::new();
+        let mut sink = |stage: StageId, batch: ResultBatch<'_>| -> Result<(), CodegraphError> {
+            assert!(batch.len() <= 2);
+            if let ResultBatch::Nodes(nodes) = batch {
+                assert_eq!(stage, StageId::L1IrBuild);
+                streamed_nodes += nodes.len();
+            }
+            batch_stages.push(stage);
+            Ok(())
+        };
+        let stats = IRIndexingOrchestrator::new(config)
+            .execute_streaming(&mut sink, 2)
+            .unwrap();
+
+        assert_eq!(stats.files_processed, 1);
+        assert!(streamed_nodes > 0);
+        assert_eq!(streamed_nodes, expected.nodes.len());
+        assert_eq!(batch_stages[0], StageId::L1IrBuild);
+    }
+
+    #[test]
+    fn test_cross_file_calls_carry_l3_provenance() {
+        use crate::features::query_engine::domain::{EdgeSelector, EdgeType};
+        use crate::shared::models::EdgeStage;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("b.py"), "def helper():\n    return 1\n").unwrap();
+        std::fs::write(
+            dir.path().join("a.py"),
+            "from b import helper\n\ndef main():\n    return helper()\n",
+        )
+        .unwrap();
+        let config = E2EPipelineConfig::fast()
+            .repo_root(dir.path().to_path_buf())
+            .repo_name("cross-file-test".to_string())
+            .with_pipeline(|b| {
+                b.stages(|mut s| {
+                    s.cross_file = true;
+                    s
+                })
+            });
+        let result = IRIndexingOrchestrator::new(config).execute().unwrap();
+
+        let helper = result
+            .nodes
+            .iter()
+            .find(|n| n.kind == NodeKind::Function && n.fqn.ends_with("helper"))
+            .unwrap();
+        let selector = EdgeSelector::new(EdgeType::Call).from_stage(EdgeStage::CrossFile);
+        let cross_file: Vec<&Edge> = result
+            .edges
+            .iter()
+            .filter(|e| selector.matches_provenance(e))
+            .collect();
+        assert_eq!(cross_file.len(), 1);
+        assert_eq!(cross_file[0].target_id, helper.id);
+    }
+
+    #[test]
+    fn test_call_graph_follows_cross_file_calls() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("b.py"), "def apply(f):\n    return f()\n").unwrap();
+        std::fs::write(
+            dir.path().join("a.py"),
+            "from b import apply\n\ndef foo():\n    return 1\n\ndef main():\n    return apply(foo)\n",
+        )
+        .unwrap();
+        let config = E2EPipelineConfig::fast()
+            .repo_root(dir.path().to_path_buf())
+            .repo_name("call-graph-test".to_string())
+            .with_pipeline(|b| {
+                b.stages(|mut s| {
+                    s.cross_file = true;
+                    s
+                })
+                .call_graph(|c| c.context_depth(1))
+            });
+        let result = IRIndexingOrchestrator::new(config).execute().unwrap();
+
+        let graph = result.call_graph.expect("call graph selected in the config");
+        assert!(graph.callees("a.main").contains(&"b.apply".to_string()));
+        assert_eq!(graph.callees("b.apply"), vec!["a.foo"]);
+        assert_eq!(result.stats.call_graph, Some(graph.stats()));
+    }
+
+    #[test]
+    fn test_lexical_index_uses_lexical_config() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("repo.py"),
+            "def getUserById(self, user_id):\n    return self.users[user_id]\n",
+        )
+        .unwrap();
+        let config = E2EPipelineConfig::fast()
+            .repo_root(dir.path().to_path_buf())
+            .repo_name("lexical-test".to_string())
+            .with_pipeline(|b| {
+                b.stages(|mut s| {
+                    s.lexical = true;
+                    s
+                })
+                .lexical(|mut c| {
+                    c.stop_symbol_languages = vec!["python".to_string()];
+                    c
+                })
+            });
+        let orchestrator = IRIndexingOrchestrator::new(config)
+            .with_lexical_index(
+                &dir.path().join("index"),
+                Arc::new(crate::features::lexical::SqliteChunkStore::in_memory().unwrap()),
+                "lexical-test".to_string(),
+            )
+            .unwrap();
+        orchestrator.execute().unwrap();
+
+        let index = orchestrator.lexical_index.as_ref().unwrap().lock().unwrap();
+        assert_eq!(index.search("user by id", 10).unwrap().len(), 1);
+        // Python stop symbols from the config are not indexed
+        assert!(index.search("self", 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_progress_events_cover_files_and_stages() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("app.py"), "def main():\n    return 1\n").unwrap();
+        std::fs::write(dir.path().join("util.py"), "def helper():\n    pass\n").unwrap();
+        let config = E2EPipelineConfig::fast()
+            .repo_root(dir.path().to_path_buf())
+            .repo_name("progress-test".to_string());
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        let orchestrator = IRIndexingOrchestrator::new(config).with_progress(Arc::new(
+            move |event: &ProgressEvent<'_>, progress: &Progress| {
+                sink.lock().unwrap().push((event.kind(), progress.fraction));
+            },
+        ));
+        let result = orchestrator.execute().unwrap();
+
+        let events = events.lock().unwrap();
+        let count = |kind: &str| events.iter().filter(|(k, _)| *k == kind).count();
+        assert_eq!(events.first().unwrap().0, "started");
+        assert_eq!(*events.last().unwrap(), ("finished", 1.0));
+        assert_eq!(count("file_parsed"), 2);
+        assert_eq!(count("stage_completed"), result.stats.stage_durations.len());
+    }
+
+    #[test]
+    fn test_cancelled_run_keeps_parsed_files_cached() {
+        let repo = tempfile::TempDir::new().unwrap();
+        let cache_dir = tempfile::TempDir::new().unwrap();
+        for i in 0..4 {
+            let source = format!("def f{}():\n    return {}\n", i, i);
+            std::fs::write(repo.path().join(format!("m{}.py", i)), source).unwrap();
+        }
+        let config = E2EPipelineConfig::fast()
+            .repo_root(repo.path().to_path_buf())
+            .repo_name("cancel-test".to_string())
+            .ir_cache_dir(cache_dir.path().to_path_buf());
+
+        // Cancel as soon as the first file is through L1
+        let token = CancellationToken::new();
+        let handle = token.clone();
+        let orchestrator = IRIndexingOrchestrator::new(config.clone())
+            .with_cancellation(token)
+            .with_progress(Arc::new(move |event: &ProgressEvent<'_>, _: &Progress| {
+                if matches!(event, ProgressEvent::FileParsed { .. }) {
+                    handle.cancel();
+                }
+            }));
+        let err = orchestrator.execute().unwrap_err();
+        assert!(err.is_cancelled());
+        let partial = CancelledRun::from_error(&err).unwrap();
+        assert!(partial.files_parsed >= 1);
+        assert_eq!(partial.total_files, 4);
+        assert!(!partial.stages_completed.iter().any(|s| s != "L1_IR_Build"));
+
+        let rerun = IRIndexingOrchestrator::new(config).execute().unwrap();
+        assert_eq!(rerun.stats.files_cached, partial.files_parsed);
+    }
+
+    #[test]
+    fn test_ir_cache_hits_on_second_run() {
+        let repo = tempfile::TempDir::new().unwrap();
+        let cache_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(repo.path().join("app.py"), "def main():\n    return 1\n").unwrap();
+        std::fs::write(repo.path().join("util.py"), "def helper():\n    pass\n").unwrap();
+        let config = E2EPipelineConfig::fast()
+            .repo_root(repo.path().to_path_buf())
+            .repo_name("cache-test".to_string())
+            .ir_cache_dir(cache_dir.path().to_path_buf());
+
+        let first = IRIndexingOrchestrator::new(config.clone()).execute().unwrap();
+        assert_eq!(first.stats.files_cached, 0);
+
+        std::fs::write(repo.path().join("util.py"), "def helper():\n    return 2\n").unwrap();
+        let second = IRIndexingOrchestrator::new(config.clone()).execute().unwrap();
+        assert_eq!(second.stats.files_cached, 1);
+        assert_eq!(second.stats.cache_hit_rate, 0.5);
+        assert_eq!(second.nodes.len(), first.nodes.len());
+
+        let uncached = IRIndexingOrchestrator::new(config.no_cache(true))
+            .execute()
+            .unwrap();
+        assert_eq!(uncached.stats.files_cached, 0);
+    }
+
+    #[test]
+    fn test_memory_budget_spills_and_restores() {
+        use crate::config::{PipelineConfig, Preset};
+
+        let repo = tempfile::TempDir::new().unwrap();
+        let spill_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            repo.path().join("app.py"),
+            "def main(x):\n    y = x + 1\n    return y\n",
+        )
+        .unwrap();
+        std::fs::write(repo.path().join("util.py"), "def helper():\n    return 2\n").unwrap();
+
+        let unlimited = IRIndexingOrchestrator::new(
+            E2EPipelineConfig::balanced()
+                .repo_root(repo.path().to_path_buf())
+                .repo_name("memory-test".to_string()),
+        )
+        .execute()
+        .unwrap();
+        assert_eq!(unlimited.stats.files_spilled, 0);
+
+        // 10% of 64 MB: any test process is above it, so everything spills
+        let pipeline_config = PipelineConfig::preset(Preset::Balanced)
+            .memory(|c| {
+                c.max_memory_mb(64)
+                    .spill_threshold_percent(10)
+                    .spill_dir(spill_dir.path().display().to_string())
+            })
+            .build()
+            .unwrap();
+        let budgeted = IRIndexingOrchestrator::new(
+            E2EPipelineConfig::with_config(pipeline_config)
+                .repo_root(repo.path().to_path_buf())
+                .repo_name("memory-test".to_string()),
+        )
+        .execute()
+        .unwrap();
+
+        assert_eq!(budgeted.nodes.len(), unlimited.nodes.len());
+        assert_eq!(budgeted.occurrences.len(), unlimited.occurrences.len());
+        assert_eq!(budgeted.dfg_graphs.len(), unlimited.dfg_graphs.len());
+        assert_eq!(budgeted.pdg_graphs.len(), unlimited.pdg_graphs.len());
+        if cfg!(target_os = "linux") {
+            assert_eq!(budgeted.stats.files_spilled, 2);
+            assert!(budgeted.stats.spilled_bytes > 0);
+            assert!(budgeted.stats.stage_memory.contains_key("L1_IR_Build"));
+            assert!(budgeted.stats.peak_memory_bytes > 0);
+        }
+        // The run's spill store is gone
+        assert_eq!(std::fs::read_dir(spill_dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_scan_skips_ignored_and_vendored_files() {
+        use crate::config::{PipelineConfig, Preset};
+
+        let repo = tempfile::TempDir::new().unwrap();
+        let root = repo.path();
+        for dir in ["src", "vendor/dep", "node_modules/pkg", "generated"] {
+            std::fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        std::fs::write(root.join(".gitignore"), "generated/\n").unwrap();
+        std::fs::write(root.join("src/app.py"), "def main():\n    return 1\n").unwrap();
+        std::fs::write(root.join("src/app_pb2.py"), "X = 1\n").unwrap();
+        std::fs::write(root.join("vendor/dep/lib.py"), "def f():\n    pass\n").unwrap();
+        std::fs::write(root.join("node_modules/pkg/index.js"), "function f() {}\n").unwrap();
+        std::fs::write(root.join("generated/out.py"), "def g():\n    pass\n").unwrap();
+
+        let pipeline_config = PipelineConfig::preset(Preset::Fast)
+            .discovery(|c| c.exclude("*_pb2.py"))
+            .build()
+            .unwrap();
+        let orchestrator = IRIndexingOrchestrator::new(
+            E2EPipelineConfig::with_config(pipeline_config)
+                .repo_root(root.to_path_buf())
+                .repo_name("discovery-test".to_string()),
+        );
+        let files = orchestrator.collect_files().unwrap();
+        assert_eq!(files, vec![root.join("src/app.py")]);
+    }
+
+    #[test]
+    fn test_perf_records_appended_per_run() {
+        let repo = tempfile::TempDir::new().unwrap();
+        let out = tempfile::TempDir::new().unwrap();
+        let path = out.path().join("perf.ndjson");
+        std::fs::write(repo.path().join("app.py"), "def main():\n    return 1\n").unwrap();
+        let config = E2EPipelineConfig::fast()
+            .repo_root(repo.path().to_path_buf())
+            .repo_name("perf-test".to_string())
+            .perf_records(&path);
+
+        IRIndexingOrchestrator::new(config.clone()).execute().unwrap();
+        IRIndexingOrchestrator::new(config).execute().unwrap();
+
+        let records = PerfRecord::read_all(&path).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].repo, "perf-test");
+        assert_eq!(records[0].preset, "fast");
+        assert_eq!(records[0].repo_size.files, 1);
+        assert_eq!(records[0].repo_size.bytes, 25);
+        assert!(records[0].stages_ms.contains_key("L1_IR_Build"));
+        assert_eq!(records[0].env, records[1].env);
+    }
+
+    #[cfg(feature = "git")]
+    #[test]
+    fn test_incremental_run_from_snapshot_commit() {
+        use git2::{IndexAddOption, Repository, Signature};
+
+        fn commit_all(repo: &Repository, message: &str) -> String {
+            let mut index = repo.index().unwrap();
+            index.add_all(["*"], IndexAddOption::DEFAULT, None).unwrap();
+            index.write().unwrap();
+            let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+            let signature = Signature::now("test", "test@example.com").unwrap();
+            let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+            let parents: Vec<_> = parent.iter().collect();
+            repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &parents)
+                .unwrap()
+                .to_string()
+        }
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        let cache_dir = tempfile::TempDir::new().unwrap();
+        let repo = Repository::init(repo_dir.path()).unwrap();
+        std::fs::write(repo_dir.path().join("app.py"), "def main():\n    return 1\n").unwrap();
+        std::fs::write(repo_dir.path().join("util.py"), "def helper():\n    pass\n").unwrap();
+        let base = commit_all(&repo, "base");
+
+        let config = E2EPipelineConfig::fast()
+            .repo_root(repo_dir.path().to_path_buf())
+            .repo_name("incremental-test".to_string())
+            .ir_cache_dir(cache_dir.path().to_path_buf())
+            .indexing_mode(E2EIndexingMode::Incremental);
+        let store = SnapshotStore::new(cache_dir.path(), "incremental-test");
+
+        // No base commit: full run that records the first snapshot
+        let first = IRIndexingOrchestrator::new(config.clone()).execute().unwrap();
+        assert_eq!(first.stats.files_cached, 0);
+        assert!(store.load(&base).unwrap().is_some());
+
+        std::fs::write(repo_dir.path().join("util.py"), "def helper():\n    return 2\n").unwrap();
+        let head = commit_all(&repo, "change util");
+
+        let second = IRIndexingOrchestrator::new(config.incremental_from(base))
+            .execute()
+            .unwrap();
+        assert_eq!(second.stats.files_cached, 1);
+        let snapshot = store.load(&head).unwrap().unwrap();
+        assert_eq!(snapshot.files, vec!["app.py", "util.py"]);
+        assert!(snapshot.dirty.is_empty());
+    }
+
+    #[test]
+    fn test_clone_stage_reports_pairs() {
+        use crate::config::{PipelineConfig, Preset};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let body = "def normalize(values):\n    total = sum(values)\n    if total == 0:\n        return values\n    return [v / total for v in values]\n";
+        std::fs::write(dir.path().join("a.py"), body).unwrap();
+        std::fs::write(dir.path().join("b.py"), format!("import os\n\n{}", body)).unwrap();
+
+        let run = |clone: bool| {
+            let pipeline_config = PipelineConfig::preset(Preset::Fast)
+                .stages(|mut s| {
+                    s.clone = clone;
+                    s
+                })
+                .build()
+                .unwrap();
+            let config = E2EPipelineConfig::with_config(pipeline_config)
+                .repo_root(dir.path().to_path_buf())
+                .repo_name("clone-test".to_string());
+            IRIndexingOrchestrator::new(config).execute().unwrap()
+        };
+
+        assert!(run(false).clone_pairs.is_empty());
+
+        let result = run(true);
+        assert_eq!(result.clone_pairs.len(), 1);
+        let pair = &result.clone_pairs[0];
+        assert_eq!(pair.clone_type, "Type-1");
+        assert_eq!(pair.similarity, 1.0);
+
+        let mut files = [pair.source_file.as_str(), pair.target_file.as_str()];
+        files.sort();
+        assert_eq!(files, ["a.py", "b.py"]);
+        let b_span = if pair.source_file == "b.py" {
+            pair.source_span
+        } else {
+            pair.target_span
+        };
+        assert_eq!((b_span.start_line, b_span.end_line), (3, 7));
+    }
+
+    #[test]
+    fn test_clone_stage_finds_gapped_clones_across_directories() {
+        use crate::config::stage_configs::CloneType as ConfigCloneType;
+        use crate::config::{PipelineConfig, Preset};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let body = "def load_config(path, defaults):\n    with open(path) as handle:\n        data = json.load(handle)\n    for key, value in defaults.items():\n        data.setdefault(key, value)\n{}    validate_schema(data, CONFIG_SCHEMA)\n    return data\n";
+        for (sub, extra) in [("billing", ""), ("shipping", "    logger.info(\"loaded %s\", path)\n")] {
+            std::fs::create_dir_all(dir.path().join(sub)).unwrap();
+            std::fs::write(dir.path().join(sub).join("config.py"), body.replace("{}", extra)).unwrap();
+        }
+
+        let pipeline_config = PipelineConfig::preset(Preset::Fast)
+            .stages(|mut s| {
+                s.clone = true;
+                s
+            })
+            .clone(|mut c| {
+                c.types_enabled.push(ConfigCloneType::Type3);
+                c
+            })
+            .build()
+            .unwrap();
+        let config = E2EPipelineConfig::with_config(pipeline_config)
+            .repo_root(dir.path().to_path_buf())
+            .repo_name("clone-test".to_string());
+        let result = IRIndexingOrchestrator::new(config).execute().unwrap();
+
+        let pairs: Vec<_> = result
+            .clone_pairs
+            .iter()
+            .filter(|p| p.clone_type == "Type-3")
+            .collect();
+        assert_eq!(pairs.len(), 1);
+        assert_ne!(pairs[0].source_file, pairs[0].target_file);
+        assert!(pairs[0].similarity >= 0.6);
+    }
 }
 
 impl<E, C, T> IRIndexingOrchestrator<E, C, T>
@@ -1430,7 +2943,7 @@ where
             }
         }
 
-        eprintln!(
+        tracing::info!(
             "[L13 Effect Analysis] Analyzed {} functions across {} files",
             all_effects.len(),
             file_ir_map.len()
@@ -1481,7 +2994,7 @@ where
             }
         }
 
-        eprintln!(
+        tracing::info!(
             "[L21 SMT Verification] Verified {} functions across {} files",
             all_verifications.len(),
             file_ir_map.len()
@@ -1569,20 +3082,38 @@ where
         // Run hybrid clone detection (SOTA 3-tier: 23x faster)
         // Adaptive strategy: uses HybridCloneDetector for optimal performance
         let mut detector = HybridCloneDetector::new();
-        let clone_pairs = detector.detect_all(&fragments);
+        let mut clone_pairs = detector.detect_all(&fragments);
+
+        // Repository-wide Type-3/4: MinHash/WL LSH finds near-duplicates
+        // across directories without comparing every pair
+        if let Some(index_config) = self.repo_clone_index_config() {
+            let index = RepoCloneIndex::build(index_config, &fragments);
+            let known: HashSet<String> = clone_pairs.iter().map(|p| p.normalized_id()).collect();
+            let repo_pairs: Vec<ClonePair> = index
+                .detect()
+                .into_iter()
+                .filter(|p| !known.contains(&p.normalized_id()))
+                .collect();
+            tracing::info!(
+                "[L10 Clone Detection] Repository index: {} fragments, {} new Type-3/4 pairs",
+                index.len(),
+                repo_pairs.len()
+            );
+            clone_pairs.extend(repo_pairs);
+        }
 
         // Log tier-level performance stats
         if let Some(stats) = detector.stats() {
-            eprintln!("[L10 Clone Detection] Tier breakdown:");
-            eprintln!(
+            tracing::debug!("[L10 Clone Detection] Tier breakdown:");
+            tracing::debug!(
                 "  Tier 1 (Token Hash): {} clones in {:?}",
                 stats.tier1_clones, stats.tier1_time
             );
-            eprintln!(
+            tracing::debug!(
                 "  Tier 2 (Optimized):  {} clones in {:?}",
                 stats.tier2_clones, stats.tier2_time
             );
-            eprintln!(
+            tracing::debug!(
                 "  Tier 3 (Baseline):   {} clones in {:?}",
                 stats.tier3_clones, stats.tier3_time
             );
@@ -1604,13 +3135,15 @@ where
                 target_file: pair.target.file_path.clone(),
                 target_start_line: pair.target.span.start_line,
                 target_end_line: pair.target.span.end_line,
+                source_span: pair.source.span,
+                target_span: pair.target.span,
                 similarity: pair.similarity as f32,
                 token_count: pair.metrics.clone_length_tokens,
                 loc: pair.metrics.clone_length_loc,
             })
             .collect();
 
-        eprintln!(
+        tracing::info!(
             "[L10 Clone Detection] Found {} clone pairs across {} fragments",
             results.len(),
             fragments.len()
@@ -1619,6 +3152,33 @@ where
         Ok(results)
     }
 
+    /// Repository clone index settings from the clone config
+    ///
+    /// None unless Type-3 or Type-4 is enabled.
+    fn repo_clone_index_config(&self) -> Option<RepoCloneIndexConfig> {
+        use crate::config::stage_configs::CloneType as ConfigCloneType;
+
+        let config = self.config.pipeline_config.clone()?;
+        let enable_type3 = config.types_enabled.contains(&ConfigCloneType::Type3);
+        let enable_type4 = config.types_enabled.contains(&ConfigCloneType::Type4);
+        if !enable_type3 && !enable_type4 {
+            return None;
+        }
+
+        Some(RepoCloneIndexConfig {
+            enable_type3,
+            enable_type4,
+            min_similarity: config.type3.similarity,
+            min_semantic_similarity: config.type4.semantic_threshold,
+            min_tokens: match (enable_type3, enable_type4) {
+                (true, true) => config.type3.min_tokens.min(config.type4.min_tokens),
+                (true, false) => config.type3.min_tokens,
+                _ => config.type4.min_tokens,
+            },
+            ..Default::default()
+        })
+    }
+
     /// Execute L15: Cost Analysis - Analyze computational complexity
     ///
     /// SOTA implementation with BFG-to-CFG conversion for cost analysis.
@@ -1723,6 +3283,7 @@ where
                                 CFGEdgeType::LoopBack => CFGEdgeKind::LoopBack,
                                 CFGEdgeType::LoopExit => CFGEdgeKind::LoopExit,
                                 CFGEdgeType::Exception => CFGEdgeKind::Exception,
+                                CFGEdgeType::Finally => CFGEdgeKind::Finally,
                             };
 
                             CFGEdge {
@@ -1783,6 +3344,7 @@ where
     fn execute_l16_repomap(
         &self,
         chunks: &[super::end_to_end_result::Chunk],
+        nodes: &[Node],
         repo_id: &str,
     ) -> Result<RepoMapSnapshotSummary, CodegraphError> {
         // 1. Convert pipeline result chunks to chunking domain chunks
@@ -1826,7 +3388,9 @@ where
 
         // 2. Build RepoMap tree
         let snapshot_id = "v1".to_string(); // TODO: Use git commit hash
-        let mut tree_builder = RepoMapTreeBuilder::new(repo_id.to_string(), snapshot_id.clone());
+        // Function/file complexity from the L3 metrics stage (node metadata)
+        let mut tree_builder = RepoMapTreeBuilder::new(repo_id.to_string(), snapshot_id.clone())
+            .with_complexity(&complexity_report(nodes));
 
         // Build empty chunk-to-graph mapping (we don't have graph nodes for chunks yet)
         let chunk_to_graph: HashMap<String, HashSet<String>> = HashMap::new();
@@ -1867,13 +3431,13 @@ where
         };
 
         // Debug: Print graph structure
-        eprintln!(
+        tracing::info!(
             "[L16 RepoMap] Graph: {} nodes, {} edges",
             graph.nodes.len(),
             graph.edges.len()
         );
         if graph.edges.is_empty() {
-            eprintln!("[L16 RepoMap] WARNING: No edges in graph! PageRank will not differentiate.");
+            tracing::warn!("[L16 RepoMap] WARNING: No edges in graph! PageRank will not differentiate.");
         }
 
         // 4. Compute PageRank scores (OPTIMIZED - only once!)
@@ -1881,13 +3445,18 @@ where
         let pagerank_settings = self.config.pagerank();
         let engine = PageRankEngine::new(&pagerank_settings);
 
-        let pagerank_scores = engine.compute_pagerank(&graph);
+        // Personalization seeds (recently edited files, query hits) switch to PPR
+        let pagerank_scores = engine.compute_seeded_pagerank(
+            &graph,
+            &pagerank_settings.personalization,
+            nodes.iter().map(|node| (node.id.as_str(), node.path.as_str())),
+        );
 
         // 5. Compute HITS scores (OPTIMIZED - only once!)
         let hits_results = engine.compute_hits(&graph);
 
         // 6. Get importance weights for combined score
-        let weights = ImportanceWeights::default();
+        let weights = pagerank_settings.weights.clone();
 
         // 7. Create snapshot summary (OPTIMIZED - reuse computed scores!)
         let node_summaries: Vec<RepoMapNodeSummary> = nodes
@@ -1918,6 +3487,9 @@ where
                     combined_importance,
                     loc: node.metrics.loc,
                     symbol_count: node.metrics.symbol_count,
+                    complexity: node.metrics.complexity,
+                    cognitive_complexity: node.metrics.cognitive_complexity,
+                    max_nesting: node.metrics.max_nesting,
                 }
             })
             .collect();
@@ -1953,6 +3525,7 @@ where
             total_symbols,
             total_files,
             created_at,
+            pagerank_settings,
         })
     }
 
@@ -2069,7 +3642,7 @@ where
                 CodegraphError::internal(format!("Lexical indexing failed: {:?}", e))
             })?;
 
-            eprintln!(
+            tracing::info!(
                 "[L2.5 Lexical] Indexed {} files ({} failed)",
                 result.success_count,
                 result.failed_files.len()
@@ -2083,6 +3656,52 @@ where
         }
     }
 
+    /// L2.6: Vector Index - Embed L2 chunks and build the snapshot's HNSW index
+    ///
+    /// The snapshot id is a fingerprint of the chunks and the embedding model,
+    /// so with a vector store configured an unchanged snapshot is loaded
+    /// rather than re-embedded.
+    fn execute_l2_6_vector(
+        &self,
+        chunks: &[super::end_to_end_result::Chunk],
+    ) -> Result<ChunkVectorIndex, CodegraphError> {
+        let provider: Arc<dyn EmbeddingProvider> = match &self.embedding_provider {
+            Some(provider) => Arc::clone(provider),
+            None => Arc::new(HashingEmbeddingProvider::default()),
+        };
+        let inputs: Vec<(VectorEntry, &str)> = chunks
+            .iter()
+            .map(|chunk| {
+                let entry = VectorEntry {
+                    chunk_id: chunk.id.clone(),
+                    file_path: chunk.file_path.clone(),
+                    start_line: chunk.start_line,
+                    end_line: chunk.end_line,
+                    symbol_id: chunk.symbol_id.clone(),
+                };
+                (entry, chunk.content.as_str())
+            })
+            .collect();
+
+        let index = match &self.vector_store {
+            Some(store) => store.load_or_build(provider.as_ref(), HnswParams::default(), &inputs)?,
+            None => ChunkVectorIndex::build(
+                ChunkVectorIndex::fingerprint(provider.model_id(), &inputs),
+                provider.as_ref(),
+                HnswParams::default(),
+                inputs.iter().cloned(),
+            )?,
+        };
+
+        tracing::info!(
+            "[L2.6 Vector] Indexed {} chunks with {} (snapshot {})",
+            index.len(),
+            index.model_id(),
+            index.snapshot_id()
+        );
+        Ok(index)
+    }
+
     /// L18: Concurrency Analysis - Race condition and deadlock detection
     ///
     /// Analyzes async functions to detect:
@@ -2138,12 +3757,12 @@ where
                 }
                 Err(e) => {
                     // Log error but continue analysis
-                    eprintln!("[L18 Concurrency] Failed to analyze {}: {:?}", file_path, e);
+                    tracing::warn!("[L18 Concurrency] Failed to analyze {}: {:?}", file_path, e);
                 }
             }
         }
 
-        eprintln!(
+        tracing::info!(
             "[L18 Concurrency Analysis] Found {} potential issues across {} files",
             all_issues.len(),
             file_ir_map.len()
@@ -2152,6 +3771,30 @@ where
         Ok(all_issues)
     }
 
+    /// L33: Git Blame - authorship, last change and 90-day churn per symbol
+    ///
+    /// Failures (no repository, `git` feature off) only skip the annotation.
+    fn execute_l33_git_blame(&self, nodes: &mut [Node]) {
+        #[cfg(feature = "git")]
+        {
+            use crate::features::git_history::CHURN_WINDOW_DAYS;
+            use crate::pipeline::processor::stages::annotate_git_history;
+
+            let repo_root = &self.config.repo_info.repo_root;
+            match annotate_git_history(nodes, repo_root, CHURN_WINDOW_DAYS) {
+                Ok(annotated) => {
+                    tracing::info!("[L33 GitBlame] Annotated {} symbols", annotated)
+                }
+                Err(e) => tracing::warn!("[L33 GitBlame] Skipped: {}", e),
+            }
+        }
+        #[cfg(not(feature = "git"))]
+        {
+            let _ = nodes;
+            tracing::warn!("[L33 GitBlame] Skipped: built without the `git` feature");
+        }
+    }
+
     /// L33: Git History - Co-change analysis and temporal coupling
     ///
     /// Analyzes git repository history to extract:
@@ -2170,7 +3813,7 @@ where
         let git_executor = match GitExecutor::new(&self.config.repo_info.repo_root) {
             Ok(executor) => executor,
             Err(e) => {
-                eprintln!(
+                tracing::info!(
                     "[L33 Git History] Not a git repository or git not available: {:?}",
                     e
                 );
@@ -2269,7 +3912,7 @@ where
             });
         }
 
-        eprintln!(
+        tracing::info!(
             "[L33 Git History] Analyzed {} files with git history",
             results.len()
         );
@@ -2313,7 +3956,7 @@ where
             // We don't store it in the result, but validate that it can be created
         }
 
-        eprintln!(
+        tracing::info!(
             "[L37 Query Engine] Initialized for {} files ({} nodes, {} edges)",
             file_ir_map.len(),
             total_nodes,
@@ -2339,7 +3982,7 @@ where
             return self.execute_l14_with_trcr(file_ir_map);
         }
 
-        eprintln!("[L14 Taint Analysis] Starting SOTA taint analysis (via TaintUseCase)...");
+        tracing::info!("[L14 Taint Analysis] Starting SOTA taint analysis (via TaintUseCase)...");
 
         // Collect all nodes and edges from all files
         let mut all_nodes = Vec::new();
@@ -2350,7 +3993,7 @@ where
             all_edges.extend(process_result.edges.iter().cloned());
         }
 
-        eprintln!(
+        tracing::info!(
             "[L14] Built call graph: {} nodes, {} edges",
             all_nodes.len(),
             all_edges.len()
@@ -2362,9 +4005,9 @@ where
             edges: all_edges,
         };
 
-        let taint_summaries = self.taint_usecase.analyze_taint(input);
+        let taint_summaries = self.taint_usecase.analyze_taint(input)?;
 
-        eprintln!(
+        tracing::info!(
             "[L14 Taint Analysis] Completed: {} taint flows detected",
             taint_summaries.len()
         );
@@ -2380,7 +4023,7 @@ where
     ) -> Result<Vec<super::stages::TaintSummary>, CodegraphError> {
         use crate::adapters::pyo3::trcr_bindings::{TRCRBridge, TRCRMatch};
 
-        eprintln!("[L14 TRCR] Starting taint analysis with TRCR (488 atoms + 30 CWE)...");
+        tracing::info!("[L14 TRCR] Starting taint analysis with TRCR (488 atoms + 30 CWE)...");
 
         // Collect all nodes from all files
         let mut all_nodes = Vec::new();
@@ -2388,7 +4031,7 @@ where
             all_nodes.extend(process_result.nodes.iter().cloned());
         }
 
-        eprintln!("[L14 TRCR] Analyzing {} nodes", all_nodes.len());
+        tracing::debug!("[L14 TRCR] Analyzing {} nodes", all_nodes.len());
 
         // Debug: Print node kinds
         let mut kind_counts: std::collections::HashMap<String, usize> =
@@ -2396,7 +4039,7 @@ where
         for node in &all_nodes {
             *kind_counts.entry(format!("{:?}", node.kind)).or_insert(0) += 1;
         }
-        eprintln!("[L14 TRCR DEBUG] Node kinds: {:?}", kind_counts);
+        tracing::debug!("[L14 TRCR DEBUG] Node kinds: {:?}", kind_counts);
 
         // Initialize TRCR bridge
         let mut trcr = TRCRBridge::new()?;
@@ -2405,12 +4048,12 @@ where
         let atoms_path = "packages/codegraph-trcr/rules/atoms/python.atoms.yaml";
         trcr.compile_atoms(atoms_path)?;
 
-        eprintln!("[L14 TRCR] Compiled atoms, executing rules...");
+        tracing::debug!("[L14 TRCR] Compiled atoms, executing rules...");
 
         // Execute TRCR rules against all nodes
         let matches = trcr.execute(&all_nodes)?;
 
-        eprintln!("[L14 TRCR] Found {} matches", matches.len());
+        tracing::info!("[L14 TRCR] Found {} matches", matches.len());
 
         // Group matches by entity (node)
         let mut entity_matches: std::collections::HashMap<String, Vec<TRCRMatch>> =
@@ -2441,7 +4084,7 @@ where
             }
         }
 
-        eprintln!(
+        tracing::info!(
             "[L14 TRCR] Sources: {}, Sinks: {}, Sanitizers: {}",
             sources.len(),
             sinks.len(),
@@ -2476,7 +4119,7 @@ where
                     // Check if this function also calls any sink
                     for (sink_id, sink_rule, sink_conf) in &sinks {
                         if callees.contains(sink_id) {
-                            eprintln!(
+                            tracing::debug!(
                                 "[L14 TRCR] 🔥 Taint flow detected: {} → {} (via {})",
                                 source_rule, sink_rule, func_id
                             );
@@ -2502,11 +4145,11 @@ where
 
         let results: Vec<_> = taint_summaries.into_values().collect();
 
-        eprintln!(
+        tracing::info!(
             "[L14 TRCR] Completed: {} functions with taint flows",
             results.len()
         );
-        eprintln!("[L14 TRCR] Used SOTA TRCR with 488 atoms + 30 CWE rules");
+        tracing::info!("[L14 TRCR] Used SOTA TRCR with 488 atoms + 30 CWE rules");
 
         Ok(results)
     }
@@ -27,12 +27,27 @@
 //!     └── points_to_summary: PointsToSummary (L9)
 //! ```
 
+use crate::features::architecture::ArchitectureViolation;
+use crate::features::cross_file::{ContextCallGraph, ContextCallGraphStats};
+use crate::features::flow_graph::infrastructure::metrics::{FileMetrics, FileMetricsTable};
 use crate::features::ir_generation::domain::IRDocument;
+use crate::features::parsing::GrammarInfo;
+use crate::features::query_engine::{
+    build_test_map, select_impacted_tests, DeadSymbol, TestMap, TestSelection,
+    TestSelectionConfig,
+};
+use crate::features::points_to::PointsToResult;
 use crate::features::query_engine::QueryEngineStats;
-use crate::pipeline::processor::PointsToSummary;
-use crate::pipeline::stages::{PDGSummary, SliceSummary, TaintSummary};
-use crate::shared::models::{Edge, Node, Occurrence};
+use crate::features::repomap::infrastructure::{
+    GraphDocument, GraphEdge, GraphNode, PageRankEngine, PageRankSettings,
+};
+use crate::features::repomap::RepoMapSeed;
+use crate::features::vector::ChunkVectorIndex;
+use crate::pipeline::processor::{AnalysisCoverage, PointsToSummary, SliceSummary};
+use crate::pipeline::stages::{PDGSummary, TaintSummary};
+use crate::shared::models::{Edge, FileTable, Node, Occurrence, Span};
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Aggregated result from SOTA pipeline (L1-L9)
@@ -110,15 +125,28 @@ pub struct E2EPipelineResult {
     /// L18: Concurrency analysis results (race conditions, deadlocks)
     pub concurrency_results: Vec<ConcurrencyIssueSummary>,
 
+    /// Architecture rule violations (`PipelineConfig::architecture`)
+    pub architecture_violations: Vec<ArchitectureViolation>,
+
+    /// Unreferenced functions, methods and classes (`PipelineConfig::dead_code`)
+    pub dead_code: Vec<DeadSymbol>,
+
     /// L9: Points-to analysis summary (repository-wide)
     pub points_to_summary: Option<PointsToSummary>,
 
+    /// L9: Points-to sets and alias classes by variable (node ID), for
+    /// may-alias queries
+    pub points_to: Option<PointsToResult>,
+
     // ═══════════════════════════════════════════════════════════════════
     // Phase 6: Performance & Quality Analysis
     // ═══════════════════════════════════════════════════════════════════
     /// L15: Cost analysis results per function (computational complexity)
     pub cost_analysis_results: Vec<CostAnalysisSummary>,
 
+    /// L3: Halstead / maintainability metrics per file, ordered by path
+    pub file_metrics: Vec<FileMetrics>,
+
     // ═══════════════════════════════════════════════════════════════════
     // Phase 7: Repository Structure & Navigation
     // ═══════════════════════════════════════════════════════════════════
@@ -134,12 +162,21 @@ pub struct E2EPipelineResult {
     /// L37: Query engine statistics (unified query interface)
     pub query_engine_stats: Option<QueryEngineStats>,
 
+    /// L2.6: HNSW index over chunk embeddings (search via `QueryEngine::with_vectors`)
+    pub vector_index: Option<Arc<ChunkVectorIndex>>,
+
+    /// L3: Context-sensitive call graph (when `PipelineConfig::call_graph` selects one)
+    pub call_graph: Option<Arc<ContextCallGraph>>,
+
     // ═══════════════════════════════════════════════════════════════════
     // Metadata
     // ═══════════════════════════════════════════════════════════════════
     /// Per-file IR documents (for debugging/inspection)
     pub ir_documents: HashMap<String, IRDocument>,
 
+    /// Grammars loaded by the language plugins (checked at startup)
+    pub grammars: Vec<GrammarInfo>,
+
     /// Pipeline statistics
     pub stats: PipelineStats,
 }
@@ -309,6 +346,25 @@ pub struct SSASummary {
     pub multi_def_variables: Vec<String>,
 }
 
+/// Resident memory around one stage
+///
+/// Stages of one DAG level run in parallel, so they share a measurement.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StageMemory {
+    /// RSS before the stage (bytes)
+    pub rss_before_bytes: usize,
+
+    /// RSS after the stage (bytes)
+    pub rss_after_bytes: usize,
+}
+
+impl StageMemory {
+    /// Growth during the stage (negative if memory was released)
+    pub fn delta_bytes(&self) -> i64 {
+        self.rss_after_bytes as i64 - self.rss_before_bytes as i64
+    }
+}
+
 /// Pipeline execution statistics
 #[derive(Debug, Clone, Default)]
 pub struct PipelineStats {
@@ -330,6 +386,9 @@ pub struct PipelineStats {
     /// Total lines of code processed
     pub total_loc: usize,
 
+    /// Total source bytes processed
+    pub total_bytes: usize,
+
     /// Processing rate (LOC/s)
     pub loc_per_second: f64,
 
@@ -342,6 +401,21 @@ pub struct PipelineStats {
     /// Per-file processing times (for debugging)
     pub file_times: HashMap<String, Duration>,
 
+    /// L4-L6 functions analyzed within the per-file time budgets (summed over files)
+    pub analysis_coverage: AnalysisCoverage,
+
+    /// Per-stage resident memory (empty where RSS is unavailable)
+    pub stage_memory: HashMap<String, StageMemory>,
+
+    /// Files whose intermediate results were spilled to disk (memory budget)
+    pub files_spilled: usize,
+
+    /// Bytes written to the spill directory
+    pub spilled_bytes: u64,
+
+    /// Context-sensitive call graph of L3 (None unless selected)
+    pub call_graph: Option<ContextCallGraphStats>,
+
     /// Error messages
     pub errors: Vec<String>,
 }
@@ -377,6 +451,15 @@ impl PipelineStats {
         self.stage_durations.insert(stage_name.into(), duration);
     }
 
+    /// Record stage memory (also raises `peak_memory_bytes`)
+    pub fn record_stage_memory(&mut self, stage_name: impl Into<String>, memory: StageMemory) {
+        self.peak_memory_bytes = self
+            .peak_memory_bytes
+            .max(memory.rss_before_bytes)
+            .max(memory.rss_after_bytes);
+        self.stage_memory.insert(stage_name.into(), memory);
+    }
+
     /// Record file processing time
     pub fn record_file(&mut self, file_path: impl Into<String>, duration: Duration) {
         self.file_times.insert(file_path.into(), duration);
@@ -407,16 +490,51 @@ impl E2EPipelineResult {
             smt_results: Vec::new(),
             clone_pairs: Vec::new(),
             concurrency_results: Vec::new(),
+            architecture_violations: Vec::new(),
+            dead_code: Vec::new(),
             cost_analysis_results: Vec::new(),
+            file_metrics: Vec::new(),
             ir_documents: HashMap::new(),
+            grammars: Vec::new(),
             points_to_summary: None,
+            points_to: None,
             repomap_snapshot: None,          // L16 RepoMap
             git_history_results: Vec::new(), // L33 Git History
             query_engine_stats: None,        // L37 Query Engine
+            vector_index: None,              // L2.6 Vector index
+            call_graph: None,                // L3 context-sensitive call graph
             stats: PipelineStats::new(),
         }
     }
 
+    /// File metrics as the versioned JSON table stored per snapshot
+    pub fn file_metrics_table(&self, snapshot_id: Option<String>) -> FileMetricsTable {
+        FileMetricsTable::new(snapshot_id, self.file_metrics.clone())
+    }
+
+    /// Tests and the production symbols they exercise within `max_depth`
+    /// calls (`DEFAULT_TEST_MAP_DEPTH` is a good default)
+    pub fn test_map(&self, max_depth: usize) -> TestMap {
+        build_test_map(&self.nodes, &self.edges, max_depth)
+    }
+
+    /// Tests impacted by changes to `changed_files` (CI test selection)
+    pub fn select_tests(
+        &self,
+        changed_files: &[String],
+        config: &TestSelectionConfig,
+    ) -> TestSelection {
+        select_impacted_tests(&self.nodes, &self.edges, changed_files, config)
+    }
+
+    /// Re-rank the RepoMap around `seeds` (recently edited files, query
+    /// hits) without re-running the pipeline; `None` without a RepoMap
+    pub fn rescore_repomap(&mut self, seeds: &[RepoMapSeed]) -> Option<&RepoMapSnapshotSummary> {
+        let snapshot = self.repomap_snapshot.as_mut()?;
+        snapshot.rescore(seeds);
+        Some(&*snapshot)
+    }
+
     /// Get total number of entities
     pub fn total_entities(&self) -> usize {
         self.nodes.len() + self.edges.len() + self.chunks.len() + self.symbols.len()
@@ -435,6 +553,31 @@ impl E2EPipelineResult {
             self.stats.loc_per_second
         )
     }
+
+    /// Interned table of every file path referenced by the outputs
+    ///
+    /// Ids follow first appearance in nodes, chunks, symbols, occurrences,
+    /// then clone pairs.
+    pub fn file_table(&self) -> FileTable {
+        let mut table = FileTable::new();
+        for path in self
+            .nodes
+            .iter()
+            .map(|n| n.file_path.as_str())
+            .chain(self.chunks.iter().map(|c| c.file_path.as_str()))
+            .chain(self.symbols.iter().map(|s| s.file_path.as_str()))
+            .chain(self.occurrences.iter().map(|o| o.file_path.as_str()))
+            .chain(
+                self.clone_pairs
+                    .iter()
+                    .flat_map(|c| [c.source_file.as_str(), c.target_file.as_str()]),
+            )
+            .chain(self.file_metrics.iter().map(|f| f.file_path.as_str()))
+        {
+            table.intern(path);
+        }
+        table
+    }
 }
 
 impl Default for E2EPipelineResult {
@@ -532,6 +675,9 @@ pub struct ClonePairSummary {
     pub target_file: String,
     pub target_start_line: u32,
     pub target_end_line: u32,
+    /// Full fragment spans (columns included)
+    pub source_span: Span,
+    pub target_span: Span,
     /// Similarity score (0.0 - 1.0)
     pub similarity: f32,
     /// Number of tokens
@@ -597,6 +743,64 @@ pub struct RepoMapSnapshotSummary {
 
     /// Snapshot creation timestamp
     pub created_at: u64,
+
+    /// PageRank settings the scores were computed with (reused on rescoring)
+    pub pagerank_settings: PageRankSettings,
+}
+
+impl RepoMapSnapshotSummary {
+    /// Containment graph (child → parent) the scores are computed on
+    pub fn graph(&self) -> GraphDocument {
+        GraphDocument {
+            nodes: self
+                .nodes
+                .iter()
+                .map(|node| GraphNode {
+                    id: node.id.clone(),
+                    kind: node.kind.clone(),
+                })
+                .collect(),
+            edges: self
+                .nodes
+                .iter()
+                .filter_map(|node| {
+                    Some(GraphEdge {
+                        source: node.id.clone(),
+                        target: node.parent_id.clone()?,
+                        kind: "contains".to_string(),
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Recompute PageRank personalized on `seeds` (standard PageRank when
+    /// empty) and the combined importance; HITS scores are kept
+    pub fn rescore(&mut self, seeds: &[RepoMapSeed]) {
+        let engine = PageRankEngine::new(&self.pagerank_settings);
+        let scores = engine.compute_seeded_pagerank(
+            &self.graph(),
+            seeds,
+            self.nodes
+                .iter()
+                .map(|node| (node.id.as_str(), node.path.as_str())),
+        );
+        let weights = &self.pagerank_settings.weights;
+        for node in &mut self.nodes {
+            node.pagerank = scores.get(&node.id).copied().unwrap_or(0.0);
+            node.combined_importance =
+                weights.pagerank * node.pagerank + weights.authority * node.authority;
+        }
+        self.pagerank_settings.personalization = seeds.to_vec();
+    }
+
+    /// The `limit` most important nodes (combined importance, descending)
+    pub fn top_nodes(&self, limit: usize) -> Vec<&RepoMapNodeSummary> {
+        let mut nodes: Vec<&RepoMapNodeSummary> = self.nodes.iter().collect();
+        nodes.sort_by(|a, b| b.combined_importance.total_cmp(&a.combined_importance));
+        nodes.truncate(limit);
+        nodes
+    }
 }
 
 /// RepoMap node summary
@@ -632,6 +836,11 @@ pub struct RepoMapNodeSummary {
     /// Basic metrics
     pub loc: usize,
     pub symbol_count: usize,
+
+    /// Complexity (measured for functions and files, estimated otherwise)
+    pub complexity: usize,
+    pub cognitive_complexity: usize,
+    pub max_nesting: usize,
 }
 
 /// Concurrency issue summary (L18: Concurrency Analysis)
@@ -692,3 +901,84 @@ pub struct GitHistorySummary {
     /// Days between first and last commit
     pub days_active: u32,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::models::NodeKind;
+
+    #[test]
+    fn test_file_table_covers_all_outputs() {
+        let mut result = E2EPipelineResult::new();
+        for (id, path) in [("a", "src/a.py"), ("b", "src/b.py"), ("c", "src/a.py")] {
+            result.nodes.push(Node::new(
+                id.to_string(),
+                NodeKind::Function,
+                id.to_string(),
+                path.to_string(),
+                Span::new(1, 0, 2, 0),
+            ));
+        }
+        result.symbols.push(Symbol {
+            id: "s".to_string(),
+            name: "s".to_string(),
+            kind: "function".to_string(),
+            file_path: "lib/c.py".to_string(),
+            definition: (1, 0),
+            documentation: None,
+        });
+
+        let table = result.file_table();
+        assert_eq!(table.paths(), ["src/a.py", "src/b.py", "lib/c.py"]);
+        assert_eq!(table.id("lib/c.py"), Some(2));
+    }
+
+    #[test]
+    fn test_rescore_repomap_focuses_seeded_files() {
+        let node = |id: &str, path: &str, parent: Option<&str>| RepoMapNodeSummary {
+            id: id.to_string(),
+            kind: "File".to_string(),
+            name: id.to_string(),
+            path: path.to_string(),
+            parent_id: parent.map(str::to_string),
+            children_count: 0,
+            depth: usize::from(parent.is_some()),
+            pagerank: 0.0,
+            authority: 0.0,
+            hub: 0.0,
+            combined_importance: 0.0,
+            loc: 0,
+            symbol_count: 0,
+            complexity: 0,
+            cognitive_complexity: 0,
+            max_nesting: 0,
+        };
+        let mut result = E2EPipelineResult::new();
+        assert!(result.rescore_repomap(&[]).is_none());
+
+        result.repomap_snapshot = Some(RepoMapSnapshotSummary {
+            repo_id: "repo".to_string(),
+            snapshot_id: "v1".to_string(),
+            total_nodes: 3,
+            root_id: "root".to_string(),
+            nodes: vec![
+                node("root", "", None),
+                node("auth", "src/auth.py", Some("root")),
+                node("db", "src/db.py", Some("root")),
+            ],
+            total_loc: 0,
+            total_symbols: 0,
+            total_files: 2,
+            created_at: 0,
+            pagerank_settings: PageRankSettings::default(),
+        });
+
+        let snapshot = result
+            .rescore_repomap(&[RepoMapSeed::recently_edited("src/auth.py")])
+            .unwrap();
+        let score = |id: &str| snapshot.nodes.iter().find(|n| n.id == id).unwrap().pagerank;
+        assert!(score("auth") > score("db"));
+        assert_eq!(snapshot.top_nodes(1)[0].id, "auth");
+        assert_eq!(snapshot.pagerank_settings.personalization.len(), 1);
+    }
+}
@@ -0,0 +1,273 @@
+//! Repository file discovery
+//!
+//! Walks the repository root for full scans, selected by
+//! `PipelineConfig::discovery(..)` (`overrides.discovery` in YAML):
+//!
+//! - `.gitignore` / `.git/info/exclude` / `.ignore` files (also outside git
+//!   work trees)
+//! - hidden files and directories skipped unless `include_hidden`
+//! - include/exclude globs in `.gitignore` syntax; excluded directories are
+//!   not descended into
+//! - files over `max_file_size_kb` and binary files (NUL in the first 8 KB)
+//!   skipped
+//! - only extensions of registered language plugins
+//!
+//! ```yaml
+//! overrides:
+//!   discovery:
+//!     include: ["services/**"]
+//!     exclude: ["node_modules/", "vendor/", "**/*_pb2.py"]
+//!     max_file_size_kb: 512
+//! ```
+
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use ignore::overrides::{Override, OverrideBuilder};
+use ignore::WalkBuilder;
+
+use crate::config::DiscoveryConfig;
+use crate::shared::models::{CodegraphError, Result};
+
+/// Bytes sniffed for binary detection
+pub(super) const BINARY_SNIFF_BYTES: usize = 8 * 1024;
+
+/// Files left out by the checks that run per file
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiscoveryStats {
+    pub files: usize,
+    pub skipped_too_large: usize,
+    pub skipped_binary: usize,
+}
+
+/// File walker for one repository root
+#[derive(Debug)]
+pub struct FileDiscovery {
+    root: PathBuf,
+    extensions: Vec<String>,
+    config: DiscoveryConfig,
+    excludes: Override,
+    includes: Option<Override>,
+}
+
+impl FileDiscovery {
+    /// Walker over `root` for files with one of `extensions`
+    pub fn new(root: &Path, extensions: &[&str], config: DiscoveryConfig) -> Result<Self> {
+        // Excludes prune the walk; includes filter its files, so an include
+        // never re-adds what `.gitignore` or an exclude left out
+        let excludes = build_globs(root, config.exclude.iter().map(|g| format!("!{}", g)))?;
+        let includes = if config.include.is_empty() {
+            None
+        } else {
+            Some(build_globs(root, config.include.iter().cloned())?)
+        };
+
+        Ok(Self {
+            root: root.to_path_buf(),
+            extensions: extensions.iter().map(|e| e.to_string()).collect(),
+            config,
+            excludes,
+            includes,
+        })
+    }
+
+    /// Matching files, sorted
+    pub fn discover(&self) -> Result<(Vec<PathBuf>, DiscoveryStats)> {
+        let respect = self.config.respect_gitignore;
+        let walker = WalkBuilder::new(&self.root)
+            .hidden(!self.config.include_hidden)
+            .git_ignore(respect)
+            .git_exclude(respect)
+            .git_global(false)
+            .ignore(respect)
+            .parents(respect)
+            .require_git(false)
+            .overrides(self.excludes.clone())
+            .build();
+
+        let mut stats = DiscoveryStats::default();
+        let mut files = Vec::new();
+        for entry in walker {
+            let entry = entry.map_err(|e| {
+                CodegraphError::io(format!("Failed to walk {}: {}", self.root.display(), e))
+            })?;
+            if !entry.file_type().map_or(false, |t| t.is_file()) {
+                continue;
+            }
+            let path = entry.into_path();
+            let supported = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .map_or(false, |ext| self.extensions.iter().any(|e| e == ext));
+            if !supported {
+                continue;
+            }
+            if let Some(ref includes) = self.includes {
+                if !includes.matched(&path, false).is_whitelist() {
+                    continue;
+                }
+            }
+
+            if self.config.max_file_size_kb > 0 {
+                let len = path.metadata().map(|m| m.len()).unwrap_or(0);
+                if len > self.config.max_file_size_kb as u64 * 1024 {
+                    stats.skipped_too_large += 1;
+                    continue;
+                }
+            }
+            if self.config.skip_binary && is_binary(&path) {
+                stats.skipped_binary += 1;
+                continue;
+            }
+            files.push(path);
+        }
+
+        // Walk order is filesystem-dependent; sort for reproducible runs
+        files.sort();
+        stats.files = files.len();
+        Ok((files, stats))
+    }
+}
+
+pub(super) fn build_globs(root: &Path, patterns: impl Iterator<Item = String>) -> Result<Override> {
+    let mut builder = OverrideBuilder::new(root);
+    for pattern in patterns {
+        builder.add(&pattern).map_err(|e| {
+            CodegraphError::config(format!("Invalid discovery glob {:?}: {}", pattern, e))
+        })?;
+    }
+    builder
+        .build()
+        .map_err(|e| CodegraphError::config(format!("Invalid discovery globs: {}", e)))
+}
+
+/// NUL byte in the first `BINARY_SNIFF_BYTES` (unreadable files count as binary)
+fn is_binary(path: &Path) -> bool {
+    let mut buf = [0u8; BINARY_SNIFF_BYTES];
+    let read = File::open(path).and_then(|file| {
+        let mut read = 0;
+        let mut limited = file.take(BINARY_SNIFF_BYTES as u64);
+        loop {
+            match limited.read(&mut buf[read..])? {
+                0 => return Ok(read),
+                n => read += n,
+            }
+        }
+    });
+    match read {
+        Ok(n) => buf[..n].contains(&0),
+        Err(_) => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write(root: &Path, path: &str, content: &[u8]) {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn discover(root: &Path, config: DiscoveryConfig) -> (Vec<String>, DiscoveryStats) {
+        let (files, stats) = FileDiscovery::new(root, &["py"], config)
+            .unwrap()
+            .discover()
+            .unwrap();
+        let files = files
+            .iter()
+            .map(|p| {
+                p.strip_prefix(root)
+                    .unwrap()
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            })
+            .collect();
+        (files, stats)
+    }
+
+    #[test]
+    fn test_gitignore_hidden_and_default_excludes() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, ".gitignore", b"build/\n*_gen.py\n");
+        write(root, "app.py", b"x = 1\n");
+        write(root, "models_gen.py", b"x = 1\n");
+        write(root, "build/out.py", b"x = 1\n");
+        write(root, "node_modules/pkg/index.py", b"x = 1\n");
+        write(root, "vendor/lib.py", b"x = 1\n");
+        write(root, ".hidden/secret.py", b"x = 1\n");
+        write(root, "README.md", b"# readme\n");
+
+        let (files, _) = discover(root, DiscoveryConfig::default());
+        assert_eq!(files, vec!["app.py"]);
+
+        let (files, _) = discover(
+            root,
+            DiscoveryConfig::default()
+                .respect_gitignore(false)
+                .include_hidden(true)
+                .clear_excludes(),
+        );
+        assert_eq!(
+            files,
+            vec![
+                ".hidden/secret.py",
+                "app.py",
+                "build/out.py",
+                "models_gen.py",
+                "node_modules/pkg/index.py",
+                "vendor/lib.py",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_include_exclude_globs() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, "services/api/main.py", b"x = 1\n");
+        write(root, "services/api/api_pb2.py", b"x = 1\n");
+        write(root, "services/api/local.py", b"x = 1\n");
+        write(root, "services/.gitignore", b"local.py\n");
+        write(root, "scripts/tool.py", b"x = 1\n");
+
+        // Includes never re-add ignored or excluded files
+        let config = DiscoveryConfig::default()
+            .include("services/**")
+            .exclude("*_pb2.py");
+        let (files, _) = discover(root, config);
+        assert_eq!(files, vec!["services/api/main.py"]);
+    }
+
+    #[test]
+    fn test_size_limit_and_binary_detection() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, "small.py", b"x = 1\n");
+        write(root, "large.py", &vec![b'x'; 3 * 1024]);
+        write(root, "blob.py", b"\x00\x01\x02binary");
+
+        let (files, stats) = discover(root, DiscoveryConfig::default().max_file_size_kb(2));
+        assert_eq!(files, vec!["small.py"]);
+        assert_eq!(
+            stats,
+            DiscoveryStats {
+                files: 1,
+                skipped_too_large: 1,
+                skipped_binary: 1,
+            }
+        );
+
+        let (files, _) = discover(
+            root,
+            DiscoveryConfig::default()
+                .max_file_size_kb(0)
+                .skip_binary(false),
+        );
+        assert_eq!(files, vec!["blob.py", "large.py", "small.py"]);
+    }
+}
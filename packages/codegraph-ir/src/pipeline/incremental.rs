@@ -0,0 +1,303 @@
+//! Git-driven incremental indexing
+//!
+//! `IndexingMode::Incremental` with a base commit
+//! (`E2EPipelineConfig::incremental_from`):
+//!
+//! 1. libgit2 diffs the base commit against `HEAD`, plus uncommitted and
+//!    untracked working-tree changes, to find the changed files
+//! 2. unchanged files take their IR from the IR cache the base run filled
+//! 3. cross-file resolution re-runs only for changed files and their
+//!    transitive dependents in the base snapshot's dependency graph
+//! 4. the run is saved as the snapshot of `HEAD`
+//!
+//! Snapshots are stored next to the IR cache:
+//! `{ir_cache_dir}/snapshots/{repo}/{commit}.msgpack`. Without a base
+//! snapshot the run indexes everything and only records one.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::features::cross_file::GlobalContextResult;
+use crate::shared::models::{CodegraphError, Result};
+
+/// Files touched between a base commit and the working tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitChanges {
+    /// Resolved base commit id
+    pub base_commit: String,
+    /// `HEAD` commit id
+    pub head_commit: String,
+    /// Added, modified, deleted or renamed (both sides) paths, relative to
+    /// the repository root the pipeline runs on
+    pub changed: Vec<String>,
+}
+
+/// Index state recorded for one commit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexSnapshot {
+    pub commit: String,
+    /// Indexed file paths (sorted)
+    pub files: Vec<String>,
+    /// Uncommitted changes indexed on top of `commit` (re-resolved next run,
+    /// since a later diff from `commit` no longer shows them once reverted)
+    pub dirty: Vec<String>,
+    /// Cross-file context (None if L3 did not run)
+    pub cross_file: Option<GlobalContextResult>,
+}
+
+/// Base an incremental run starts from
+#[derive(Debug, Clone)]
+pub struct IncrementalBase {
+    pub snapshot: IndexSnapshot,
+    /// Files to re-resolve: git changes, the snapshot's dirty files, and
+    /// files added to or dropped from the indexed set since the snapshot
+    pub changed: Vec<String>,
+}
+
+impl IncrementalBase {
+    pub fn new(snapshot: IndexSnapshot, changes: &GitChanges, current_files: &[&str]) -> Self {
+        let previous: BTreeSet<&str> = snapshot.files.iter().map(String::as_str).collect();
+        let current: BTreeSet<&str> = current_files.iter().copied().collect();
+
+        let mut changed: BTreeSet<String> = changes.changed.iter().cloned().collect();
+        changed.extend(snapshot.dirty.iter().cloned());
+        changed.extend(
+            current
+                .symmetric_difference(&previous)
+                .map(|p| p.to_string()),
+        );
+
+        Self {
+            snapshot,
+            changed: changed.into_iter().collect(),
+        }
+    }
+}
+
+/// Per-repository snapshot directory
+#[derive(Debug, Clone)]
+pub struct SnapshotStore {
+    dir: PathBuf,
+}
+
+impl SnapshotStore {
+    pub fn new(ir_cache_dir: &Path, repo_name: &str) -> Self {
+        let repo: String = repo_name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        Self {
+            dir: ir_cache_dir.join("snapshots").join(repo),
+        }
+    }
+
+    fn path(&self, commit: &str) -> PathBuf {
+        self.dir.join(format!("{}.msgpack", commit))
+    }
+
+    /// Snapshot of `commit`, or `None` if it was never recorded
+    pub fn load(&self, commit: &str) -> Result<Option<IndexSnapshot>> {
+        let path = self.path(commit);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let bytes = fs::read(&path).map_err(|e| {
+            CodegraphError::io(format!("Failed to read snapshot {}: {}", path.display(), e))
+        })?;
+        rmp_serde::from_slice(&bytes)
+            .map(Some)
+            .map_err(|e| CodegraphError::storage(format!("Invalid snapshot {}: {}", commit, e)))
+    }
+
+    pub fn save(&self, snapshot: &IndexSnapshot) -> Result<()> {
+        let path = self.path(&snapshot.commit);
+        let bytes = rmp_serde::to_vec_named(snapshot)
+            .map_err(|e| CodegraphError::storage(format!("Failed to encode snapshot: {}", e)))?;
+        fs::create_dir_all(&self.dir)
+            .and_then(|_| fs::write(&path, bytes))
+            .map_err(|e| {
+                CodegraphError::io(format!(
+                    "Failed to write snapshot {}: {}",
+                    path.display(),
+                    e
+                ))
+            })
+    }
+}
+
+#[cfg(feature = "git")]
+fn git_error(e: git2::Error) -> CodegraphError {
+    CodegraphError::internal(format!("Git error: {}", e))
+}
+
+/// `HEAD` commit id of the repository containing `repo_root`
+#[cfg(feature = "git")]
+pub fn head_commit(repo_root: &Path) -> Result<String> {
+    let repo = git2::Repository::discover(repo_root).map_err(git_error)?;
+    let head = repo
+        .head()
+        .and_then(|h| h.peel_to_commit())
+        .map_err(git_error)?;
+    Ok(head.id().to_string())
+}
+
+/// Files changed since `base` (any revision git understands)
+#[cfg(feature = "git")]
+pub fn git_changes(repo_root: &Path, base: &str) -> Result<GitChanges> {
+    use git2::{Delta, DiffOptions, Repository};
+
+    let repo = Repository::discover(repo_root).map_err(git_error)?;
+    let workdir = repo.workdir().ok_or_else(|| {
+        CodegraphError::config("Incremental indexing needs a non-bare repository")
+    })?;
+    // Git paths are relative to the work tree; pipeline paths to repo_root
+    let prefix = repo_root
+        .canonicalize()
+        .ok()
+        .zip(workdir.canonicalize().ok())
+        .and_then(|(root, workdir)| root.strip_prefix(workdir).ok().map(Path::to_path_buf))
+        .unwrap_or_default();
+
+    let base_commit = repo
+        .revparse_single(base)
+        .and_then(|object| object.peel_to_commit())
+        .map_err(git_error)?;
+    let head = repo
+        .head()
+        .and_then(|h| h.peel_to_commit())
+        .map_err(git_error)?;
+    let base_tree = base_commit.tree().map_err(git_error)?;
+    let head_tree = head.tree().map_err(git_error)?;
+
+    let mut committed = repo
+        .diff_tree_to_tree(Some(&base_tree), Some(&head_tree), None)
+        .map_err(git_error)?;
+    committed.find_similar(None).map_err(git_error)?;
+    let mut options = DiffOptions::new();
+    options.include_untracked(true).recurse_untracked_dirs(true);
+    let uncommitted = repo
+        .diff_tree_to_workdir_with_index(Some(&head_tree), Some(&mut options))
+        .map_err(git_error)?;
+
+    let mut changed = BTreeSet::new();
+    for delta in committed.deltas().chain(uncommitted.deltas()) {
+        if delta.status() == Delta::Unmodified {
+            continue;
+        }
+        for file in [delta.old_file(), delta.new_file()] {
+            if let Some(path) = file.path().and_then(|p| p.strip_prefix(&prefix).ok()) {
+                changed.insert(path.to_string_lossy().to_string());
+            }
+        }
+    }
+
+    Ok(GitChanges {
+        base_commit: base_commit.id().to_string(),
+        head_commit: head.id().to_string(),
+        changed: changed.into_iter().collect(),
+    })
+}
+
+#[cfg(not(feature = "git"))]
+pub fn head_commit(_repo_root: &Path) -> Result<String> {
+    Err(CodegraphError::config(
+        "Incremental indexing requires the `git` feature",
+    ))
+}
+
+#[cfg(not(feature = "git"))]
+pub fn git_changes(_repo_root: &Path, _base: &str) -> Result<GitChanges> {
+    Err(CodegraphError::config(
+        "Incremental indexing requires the `git` feature",
+    ))
+}
+
+#[cfg(all(test, feature = "git"))]
+mod tests {
+    use super::*;
+    use git2::{Repository, Signature};
+
+    fn commit_all(repo: &Repository, message: &str) -> String {
+        let mut index = repo.index().unwrap();
+        index
+            .add_all(["*"], git2::IndexAddOption::DEFAULT, None)
+            .unwrap();
+        index.update_all(["*"], None).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = Signature::now("test", "test@example.com").unwrap();
+        let parents: Vec<_> = repo
+            .head()
+            .ok()
+            .and_then(|h| h.peel_to_commit().ok())
+            .into_iter()
+            .collect();
+        let parents: Vec<_> = parents.iter().collect();
+        repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            message,
+            &tree,
+            &parents,
+        )
+        .unwrap()
+        .to_string()
+    }
+
+    #[test]
+    fn test_git_changes_covers_commits_and_working_tree() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        fs::write(dir.path().join("a.py"), "x = 1\n").unwrap();
+        fs::write(dir.path().join("b.py"), "y = 1\n").unwrap();
+        fs::write(dir.path().join("c.py"), "z = 1\n").unwrap();
+        let base = commit_all(&repo, "base");
+
+        fs::write(dir.path().join("a.py"), "x = 2\n").unwrap();
+        fs::remove_file(dir.path().join("c.py")).unwrap();
+        let head = commit_all(&repo, "head");
+        fs::write(dir.path().join("new.py"), "n = 1\n").unwrap();
+
+        let changes = git_changes(dir.path(), &base[..8]).unwrap();
+        assert_eq!(changes.base_commit, base);
+        assert_eq!(changes.head_commit, head);
+        assert_eq!(changes.changed, vec!["a.py", "c.py", "new.py"]);
+        assert_eq!(head_commit(dir.path()).unwrap(), head);
+    }
+
+    #[test]
+    fn test_snapshot_store_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SnapshotStore::new(dir.path(), "org/repo");
+        assert!(store.load("abc").unwrap().is_none());
+
+        let snapshot = IndexSnapshot {
+            commit: "abc".to_string(),
+            files: vec!["a.py".to_string()],
+            dirty: vec!["c.py".to_string()],
+            cross_file: Some(GlobalContextResult::default()),
+        };
+        store.save(&snapshot).unwrap();
+        let loaded = store.load("abc").unwrap().unwrap();
+        assert_eq!(loaded.files, snapshot.files);
+        assert!(loaded.cross_file.is_some());
+
+        let changes = GitChanges {
+            base_commit: "abc".to_string(),
+            head_commit: "def".to_string(),
+            changed: vec!["a.py".to_string()],
+        };
+        let base = IncrementalBase::new(loaded, &changes, &["a.py", "b.py"]);
+        assert_eq!(base.changed, vec!["a.py", "b.py", "c.py"]);
+    }
+}
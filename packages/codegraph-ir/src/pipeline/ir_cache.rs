@@ -0,0 +1,182 @@
+//! Persistent per-file IR cache
+//!
+//! Stores each file's L1 `ProcessResult` on disk between runs, keyed by a
+//! hash of its content, path, module and repository. Keys also cover an
+//! engine fingerprint (cache format, crate version and loaded grammars), so
+//! entries written by a different build never match and are simply ignored.
+//!
+//! Layout: `{dir}/{key[..2]}/{key}.msgpack`
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::features::parsing::grammar::GrammarInfo;
+use crate::pipeline::processor::ProcessResult;
+use crate::shared::models::{CodegraphError, Result};
+
+/// On-disk format version (bump when `ProcessResult` changes shape)
+pub const IR_CACHE_VERSION: u32 = 1;
+
+/// Content-addressed `ProcessResult` store
+#[derive(Debug, Clone)]
+pub struct IrCache {
+    dir: PathBuf,
+    engine: blake3::Hash,
+}
+
+impl IrCache {
+    /// Open (creating) a cache directory for the given grammars
+    pub fn open(dir: impl Into<PathBuf>, grammars: &[GrammarInfo]) -> Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir).map_err(|e| {
+            CodegraphError::io(format!(
+                "Failed to create IR cache dir {}: {}",
+                dir.display(),
+                e
+            ))
+        })?;
+
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&IR_CACHE_VERSION.to_le_bytes());
+        hasher.update(env!("CARGO_PKG_VERSION").as_bytes());
+        for grammar in grammars {
+            for part in [
+                grammar.language.as_str(),
+                grammar.plugin.as_str(),
+                grammar.plugin_version.as_str(),
+                grammar.grammar.as_deref().unwrap_or_default(),
+            ] {
+                update_part(&mut hasher, part);
+            }
+            hasher.update(&(grammar.abi_version as u64).to_le_bytes());
+            hasher.update(&(grammar.node_kind_count as u64).to_le_bytes());
+        }
+
+        Ok(Self {
+            dir,
+            engine: hasher.finalize(),
+        })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Cache key of one file's inputs
+    pub fn key(&self, repo_id: &str, file_path: &str, module_path: &str, content: &str) -> String {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(self.engine.as_bytes());
+        for part in [repo_id, file_path, module_path, content] {
+            update_part(&mut hasher, part);
+        }
+        hasher.finalize().to_hex().to_string()
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(&key[..2]).join(format!("{}.msgpack", key))
+    }
+
+    /// Cached result, or `None` on a miss (unreadable entries count as misses)
+    pub fn get(&self, key: &str) -> Option<ProcessResult> {
+        let bytes = fs::read(self.entry_path(key)).ok()?;
+        rmp_serde::from_slice(&bytes).ok()
+    }
+
+    /// Store a result (written to a temp file, then renamed into place)
+    pub fn put(&self, key: &str, result: &ProcessResult) -> Result<()> {
+        let path = self.entry_path(key);
+        let bytes = rmp_serde::to_vec_named(result)
+            .map_err(|e| CodegraphError::storage(format!("Failed to encode IR: {}", e)))?;
+
+        let io_error = |e: std::io::Error| {
+            CodegraphError::io(format!(
+                "Failed to write IR cache entry {}: {}",
+                path.display(),
+                e
+            ))
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        let tmp = path.with_extension(format!("tmp.{}", std::process::id()));
+        fs::write(&tmp, bytes).map_err(io_error)?;
+        fs::rename(&tmp, &path).map_err(io_error)
+    }
+
+    /// Whether a result is a pure function of its inputs
+    ///
+    /// Results cut short by the time budget depend on machine load, so they
+    /// are recomputed next run instead of being pinned in the cache.
+    pub fn cacheable(result: &ProcessResult) -> bool {
+        !result.coverage.budget_exhausted
+    }
+}
+
+/// Length-prefixed so ("ab", "c") and ("a", "bc") hash differently
+fn update_part(hasher: &mut blake3::Hasher, part: &str) {
+    hasher.update(&(part.len() as u64).to_le_bytes());
+    hasher.update(part.as_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::processor::process_python_file;
+
+    #[test]
+    fn test_round_trip_and_key_sensitivity() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = IrCache::open(dir.path(), &[]).unwrap();
+        let content = "def load(path):\n    return open(path).read()\n";
+        let result = process_python_file(content, "repo", "src/io.py", "src.io");
+
+        let key = cache.key("repo", "src/io.py", "src.io", content);
+        assert!(cache.get(&key).is_none());
+        cache.put(&key, &result).unwrap();
+
+        let cached = cache.get(&key).unwrap();
+        assert_eq!(cached.nodes.len(), result.nodes.len());
+        assert_eq!(cached.edges.len(), result.edges.len());
+        assert_eq!(cached.bfg_graphs.len(), result.bfg_graphs.len());
+        assert_eq!(cached.nodes[0].fqn, result.nodes[0].fqn);
+
+        assert_ne!(key, cache.key("repo", "src/io.py", "src.io", "x = 1\n"));
+        assert_ne!(key, cache.key("repo", "src/io2.py", "src.io", content));
+    }
+
+    #[test]
+    fn test_engine_fingerprint_separates_grammars() {
+        let dir = tempfile::tempdir().unwrap();
+        let grammar = GrammarInfo {
+            language: "python".to_string(),
+            plugin: "python".to_string(),
+            plugin_version: "1.0.0".to_string(),
+            grammar: Some("tree-sitter-python 0.21".to_string()),
+            abi_version: 14,
+            node_kind_count: 200,
+        };
+        let a = IrCache::open(dir.path(), &[grammar.clone()]).unwrap();
+        let b = IrCache::open(
+            dir.path(),
+            &[GrammarInfo {
+                abi_version: 15,
+                ..grammar
+            }],
+        )
+        .unwrap();
+
+        assert_ne!(a.key("r", "f.py", "f", ""), b.key("r", "f.py", "f", ""));
+    }
+
+    #[test]
+    fn test_corrupt_entry_is_a_miss() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = IrCache::open(dir.path(), &[]).unwrap();
+        let key = cache.key("repo", "a.py", "a", "");
+        let path = cache.entry_path(&key);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, b"not msgpack").unwrap();
+
+        assert!(cache.get(&key).is_none());
+    }
+}
@@ -0,0 +1,399 @@
+//! Memory budget and backpressure for huge repositories
+//!
+//! Selected with `PipelineConfig::memory(..)` (`overrides.memory` in YAML):
+//!
+//! 1. L1 parses files in batches of at most `batch_size_mb` source bytes
+//!    instead of all at once
+//! 2. after each batch, if resident memory reached the spill threshold, the
+//!    batch's intermediate analyses (DFG, SSA, PDG, taint, slices, heap
+//!    findings, types, escape info) go to a spill directory, and the next
+//!    batch is half the size
+//! 3. aggregated occurrences, which no DAG stage reads, are spilled the same
+//!    way while stages run
+//! 4. everything spilled is read back for final aggregation; the spill
+//!    directory is removed when the run ends
+//!
+//! Resident memory is read from `/proc/self/status`; elsewhere it is
+//! unknown and nothing is spilled (batching still applies).
+
+use std::fs;
+use std::mem;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::config::MemoryConfig;
+use crate::features::data_flow::infrastructure::dfg::DataFlowGraph;
+use crate::features::heap_analysis::{
+    FunctionEscapeInfo, MemorySafetyIssue, SecurityVulnerability,
+};
+use crate::features::ssa::infrastructure::ssa::SSAGraph;
+use crate::features::type_resolution::domain::TypeEntity;
+use crate::pipeline::end_to_end_result::StageMemory;
+use crate::pipeline::processor::{PDGSummary, ProcessResult, SliceSummary, TaintSummary};
+use crate::shared::models::{CodegraphError, Result};
+
+const MB: usize = 1024 * 1024;
+
+/// Spill key of the aggregated L1 occurrences (file paths never contain NUL)
+pub const OCCURRENCES_KEY: &str = "\0occurrences";
+
+/// Resident set size of this process (None where unavailable)
+pub fn current_rss_bytes() -> Option<usize> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))
+        .and_then(|rest| {
+            rest.trim()
+                .trim_end_matches("kB")
+                .trim()
+                .parse::<usize>()
+                .ok()
+        })
+        .map(|kb| kb * 1024)
+}
+
+/// Memory around a stage that started at `rss_before` (None without RSS)
+pub fn stage_memory(rss_before: Option<usize>) -> Option<StageMemory> {
+    Some(StageMemory {
+        rss_before_bytes: rss_before?,
+        rss_after_bytes: current_rss_bytes()?,
+    })
+}
+
+/// Budget of one run, with its spill store (created on first spill)
+#[derive(Debug)]
+pub struct MemoryBudget {
+    config: MemoryConfig,
+    batch_bytes: usize,
+    spill: Option<SpillStore>,
+}
+
+impl MemoryBudget {
+    pub fn new(config: MemoryConfig) -> Self {
+        let batch_bytes = config.batch_size_mb * MB;
+        Self {
+            config,
+            batch_bytes,
+            spill: None,
+        }
+    }
+
+    pub fn config(&self) -> &MemoryConfig {
+        &self.config
+    }
+
+    /// RSS at which results are spilled
+    pub fn spill_threshold_bytes(&self) -> usize {
+        self.config.max_memory_mb * MB / 100 * self.config.spill_threshold_percent
+    }
+
+    /// Whether resident memory reached the spill threshold
+    pub fn should_spill(&self, rss_bytes: Option<usize>) -> bool {
+        rss_bytes.map_or(false, |rss| rss >= self.spill_threshold_bytes())
+    }
+
+    /// Number of files from the start of `files` that form the next batch
+    ///
+    /// At least one file, then files while their content fits the current
+    /// batch size.
+    pub fn next_batch_len(&self, files: &[(String, String, String)]) -> usize {
+        let mut bytes = 0;
+        let mut len = 0;
+        for (_, _, content) in files {
+            if len > 0 && bytes + content.len() > self.batch_bytes {
+                break;
+            }
+            bytes += content.len();
+            len += 1;
+        }
+        len
+    }
+
+    /// Backpressure: halve the batch size (down to 1 MB)
+    pub fn shrink_batch(&mut self) {
+        self.batch_bytes = (self.batch_bytes / 2).max(MB);
+    }
+
+    pub fn batch_bytes(&self) -> usize {
+        self.batch_bytes
+    }
+
+    /// Spill `value` under `key`
+    pub fn spill<T: Serialize>(&mut self, key: &str, value: &T) -> Result<()> {
+        let store = match self.spill.take() {
+            Some(store) => store,
+            None => SpillStore::create(self.config.spill_dir.as_deref().map(Path::new))?,
+        };
+        self.spill.insert(store).write(key, value)
+    }
+
+    /// Read back the value spilled under `key` (None if it was not spilled)
+    pub fn restore<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+        match self.spill {
+            Some(ref store) => store.take(key),
+            None => Ok(None),
+        }
+    }
+
+    /// Bytes written to the spill store
+    pub fn spilled_bytes(&self) -> u64 {
+        self.spill.as_ref().map_or(0, SpillStore::bytes_written)
+    }
+}
+
+/// Per-file results only final aggregation reads
+///
+/// BFGs and CFG edges stay in memory: L15 cost analysis reads them.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SpilledAnalysis {
+    pub type_entities: Vec<TypeEntity>,
+    pub dfg_graphs: Vec<DataFlowGraph>,
+    pub ssa_graphs: Vec<SSAGraph>,
+    pub pdg_graphs: Vec<PDGSummary>,
+    pub taint_results: Vec<TaintSummary>,
+    pub slice_results: Vec<SliceSummary>,
+    pub memory_safety_issues: Vec<MemorySafetyIssue>,
+    pub security_vulnerabilities: Vec<SecurityVulnerability>,
+    pub escape_info: Vec<FunctionEscapeInfo>,
+}
+
+impl SpilledAnalysis {
+    /// Move the spillable fields out of `result`
+    pub fn take(result: &mut ProcessResult) -> Self {
+        Self {
+            type_entities: mem::take(&mut result.type_entities),
+            dfg_graphs: mem::take(&mut result.dfg_graphs),
+            ssa_graphs: mem::take(&mut result.ssa_graphs),
+            pdg_graphs: mem::take(&mut result.pdg_graphs),
+            taint_results: mem::take(&mut result.taint_results),
+            slice_results: mem::take(&mut result.slice_results),
+            memory_safety_issues: mem::take(&mut result.memory_safety_issues),
+            security_vulnerabilities: mem::take(&mut result.security_vulnerabilities),
+            escape_info: mem::take(&mut result.escape_info),
+        }
+    }
+
+    /// Put the fields back into `result`
+    pub fn restore(self, result: &mut ProcessResult) {
+        result.type_entities = self.type_entities;
+        result.dfg_graphs = self.dfg_graphs;
+        result.ssa_graphs = self.ssa_graphs;
+        result.pdg_graphs = self.pdg_graphs;
+        result.taint_results = self.taint_results;
+        result.slice_results = self.slice_results;
+        result.memory_safety_issues = self.memory_safety_issues;
+        result.security_vulnerabilities = self.security_vulnerabilities;
+        result.escape_info = self.escape_info;
+    }
+}
+
+/// Spill directory of one run (removed on drop)
+///
+/// Layout: `{spill_dir}/codegraph-spill-XXXX/{blake3(key)}.msgpack`
+#[derive(Debug)]
+pub struct SpillStore {
+    dir: tempfile::TempDir,
+    files: usize,
+    bytes: u64,
+}
+
+impl SpillStore {
+    /// Create a spill directory under `parent` (system temp dir if None)
+    pub fn create(parent: Option<&Path>) -> Result<Self> {
+        let parent = parent.map_or_else(std::env::temp_dir, Path::to_path_buf);
+        fs::create_dir_all(&parent)
+            .and_then(|_| {
+                tempfile::Builder::new()
+                    .prefix("codegraph-spill-")
+                    .tempdir_in(&parent)
+            })
+            .map(|dir| Self {
+                dir,
+                files: 0,
+                bytes: 0,
+            })
+            .map_err(|e| {
+                CodegraphError::io(format!(
+                    "Failed to create spill dir in {}: {}",
+                    parent.display(),
+                    e
+                ))
+            })
+    }
+
+    pub fn dir(&self) -> &Path {
+        self.dir.path()
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        let hash = blake3::hash(key.as_bytes()).to_hex();
+        self.dir.path().join(format!("{}.msgpack", hash))
+    }
+
+    /// Write `value` under `key` (replacing an earlier value)
+    pub fn write<T: Serialize>(&mut self, key: &str, value: &T) -> Result<()> {
+        let bytes = rmp_serde::to_vec_named(value).map_err(|e| {
+            CodegraphError::storage(format!("Failed to encode spilled {}: {}", key, e))
+        })?;
+        let path = self.path(key);
+        fs::write(&path, &bytes).map_err(|e| {
+            CodegraphError::io(format!("Failed to write {}: {}", path.display(), e))
+        })?;
+        self.files += 1;
+        self.bytes += bytes.len() as u64;
+        Ok(())
+    }
+
+    /// Read back and delete the value under `key` (None if never written)
+    pub fn take<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+        let path = self.path(key);
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(CodegraphError::io(format!(
+                    "Failed to read {}: {}",
+                    path.display(),
+                    e
+                )))
+            }
+        };
+        let _ = fs::remove_file(&path);
+        rmp_serde::from_slice(&bytes)
+            .map(Some)
+            .map_err(|e| CodegraphError::storage(format!("Invalid spilled {}: {}", key, e)))
+    }
+
+    /// Number of values written
+    pub fn files_written(&self) -> usize {
+        self.files
+    }
+
+    /// Bytes written
+    pub fn bytes_written(&self) -> u64 {
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(path: &str, bytes: usize) -> (String, String, String) {
+        (path.to_string(), path.to_string(), "x".repeat(bytes))
+    }
+
+    #[test]
+    fn test_batches_split_by_source_bytes() {
+        let mut budget =
+            MemoryBudget::new(MemoryConfig::default().max_memory_mb(1024).batch_size_mb(2));
+        let files = vec![
+            file("a.py", MB),
+            file("b.py", MB),
+            file("c.py", MB),
+            file("d.py", 3 * MB),
+        ];
+        assert_eq!(budget.next_batch_len(&files), 2);
+        assert_eq!(budget.next_batch_len(&files[2..]), 1);
+        // An oversized file still forms a batch of its own
+        assert_eq!(budget.next_batch_len(&files[3..]), 1);
+
+        budget.shrink_batch();
+        assert_eq!(budget.batch_bytes(), MB);
+        budget.shrink_batch();
+        assert_eq!(budget.batch_bytes(), MB);
+        assert_eq!(budget.next_batch_len(&files), 1);
+    }
+
+    #[test]
+    fn test_spill_threshold() {
+        let budget = MemoryBudget::new(
+            MemoryConfig::default()
+                .max_memory_mb(100)
+                .spill_threshold_percent(50),
+        );
+        assert_eq!(budget.spill_threshold_bytes(), 50 * MB);
+        assert!(!budget.should_spill(None));
+        assert!(!budget.should_spill(Some(10 * MB)));
+        assert!(budget.should_spill(Some(60 * MB)));
+    }
+
+    #[test]
+    fn test_budget_spills_lazily_into_spill_dir() {
+        let parent = tempfile::tempdir().unwrap();
+        let mut budget = MemoryBudget::new(
+            MemoryConfig::default()
+                .max_memory_mb(100)
+                .spill_dir(parent.path().join("spill").display().to_string()),
+        );
+        assert!(budget
+            .restore::<Vec<u32>>(OCCURRENCES_KEY)
+            .unwrap()
+            .is_none());
+        assert_eq!(budget.spilled_bytes(), 0);
+
+        budget.spill(OCCURRENCES_KEY, &vec![1u32, 2, 3]).unwrap();
+        assert!(budget.spilled_bytes() > 0);
+        assert_eq!(
+            std::fs::read_dir(parent.path().join("spill"))
+                .unwrap()
+                .count(),
+            1
+        );
+        assert_eq!(
+            budget.restore::<Vec<u32>>(OCCURRENCES_KEY).unwrap(),
+            Some(vec![1, 2, 3])
+        );
+
+        drop(budget);
+        assert_eq!(
+            std::fs::read_dir(parent.path().join("spill"))
+                .unwrap()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_spill_round_trip() {
+        let parent = tempfile::tempdir().unwrap();
+        let mut store = SpillStore::create(Some(parent.path())).unwrap();
+        let dir = store.dir().to_path_buf();
+
+        let mut result = ProcessResult::default();
+        result.pdg_graphs.push(PDGSummary {
+            function_id: "f".to_string(),
+            node_count: 3,
+            control_edges: 1,
+            data_edges: 2,
+            petgraph_enabled: false,
+            total_edges: 3,
+        });
+        store
+            .write("src/a.py", &SpilledAnalysis::take(&mut result))
+            .unwrap();
+        assert!(result.pdg_graphs.is_empty());
+        assert_eq!(store.files_written(), 1);
+        assert!(store.bytes_written() > 0);
+
+        let spilled: SpilledAnalysis = store.take("src/a.py").unwrap().unwrap();
+        spilled.restore(&mut result);
+        assert_eq!(result.pdg_graphs.len(), 1);
+        assert!(store.take::<SpilledAnalysis>("src/a.py").unwrap().is_none());
+
+        drop(store);
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn test_current_rss() {
+        if cfg!(target_os = "linux") {
+            assert!(current_rss_bytes().unwrap() > 0);
+            assert!(stage_memory(current_rss_bytes()).is_some());
+        }
+        assert!(stage_memory(None).is_none());
+    }
+}
@@ -1,13 +1,26 @@
 //! Pipeline orchestration
 
+#[cfg(feature = "archive")]
+pub mod archive_input; // Source files read from tar/zip streams
+#[cfg(feature = "arrow")]
+pub mod arrow_export; // Arrow IPC tables of the E2E result
+pub mod cancellation; // Cooperative cancellation of a run
 pub mod config;
 pub mod core;
 pub mod dag;
 pub mod error;
+pub mod file_discovery; // .gitignore-aware repository walk
+pub mod incremental; // Git-driven incremental indexing
+pub mod ir_cache; // Per-file IR cache between runs
+pub mod memory_budget; // Memory budget: batched L1, spill-to-disk
 pub mod preprocessors;
 pub mod processor; // ✅ Refactored modular processor (Phase 4 complete)
+pub mod progress; // Progress events and ETA during a run
+pub mod replay; // Record/replay of exact pipeline inputs
 pub mod result;
+pub mod result_stream; // Streaming stage results to a sink
 pub mod sota_pipeline;
+pub mod stage_cache; // Reuse of DAG stage outputs across runs
 pub mod unified_processor; // SOTA: Zero-dependency DAG from task-engine
 pub mod unified_orchestrator; // SOTA: Arc-based unified orchestrator (RFC-001 integrated)
                               // pub mod stage_dag;  // REMOVED: Replaced by self-contained DAG
@@ -16,22 +29,39 @@ pub mod end_to_end_orchestrator;
 pub mod end_to_end_result;
 pub mod orchestrator;
 pub mod pagerank_mode_detector;
+pub mod perf_record; // Per-run NDJSON performance records
 pub mod stages; // Auto-detect PageRank mode
+pub mod telemetry; // Tracing spans per stage/batch, OTLP export
 pub mod usecase_traits; // SOLID D: Dependency Inversion traits
                 // pub mod storage_integration;  // RFC-074/RFC-100: Storage Backend Integration (TODO: fix Node/Edge API mismatch)
 
+#[cfg(feature = "archive")]
+pub use archive_input::{ArchiveFile, ArchiveFormat, ArchiveInput, ArchiveSource};
+#[cfg(feature = "arrow")]
+pub use arrow_export::{nodes_to_arrow_ipc, result_to_arrow_ipc, ArrowResultTables};
+pub use cancellation::{CancellationToken, CancelledRun};
 pub use config::*;
 pub use dag::{PipelineDAG, StageId, StageNode, StageState}; // New: Self-contained DAG
 pub use end_to_end_config::*;
-pub use end_to_end_orchestrator::{E2EOrchestrator, IRIndexingOrchestrator};
+pub use end_to_end_orchestrator::{E2EOrchestrator, IRIndexingOrchestrator, StageOutput};
 pub use end_to_end_result::*;
+pub use file_discovery::{DiscoveryStats, FileDiscovery};
+pub use incremental::{GitChanges, IncrementalBase, IndexSnapshot, SnapshotStore};
+pub use ir_cache::{IrCache, IR_CACHE_VERSION};
+pub use memory_budget::{MemoryBudget, SpillStore, SpilledAnalysis};
 pub use unified_orchestrator::{UnifiedOrchestrator, UnifiedOrchestratorConfig};
 pub use pagerank_mode_detector::{
     configure_smart_mode, detect_mode, AnalysisType, ModeDetectionContext, RecommendedMode,
 };
+pub use perf_record::{EnvFingerprint, PerfRecord, PERF_RECORD_SCHEMA_VERSION};
 pub use processor::*;
+pub use progress::{Progress, ProgressEvent, ProgressReporter};
+pub use replay::{RecordedFile, ReplayBundle, REPLAY_BUNDLE_VERSION};
 pub use result::ProcessResult;
+pub use result_stream::{ResultBatch, ResultSink, DEFAULT_BATCH_SIZE};
 pub use sota_pipeline::{IRPipelineDAG, SOTAStageControl, SOTAStageId, SOTAStageMetadata};
+pub use stage_cache::{MemoryStageCache, StageCache};
 pub use stages::{IncrementalStages, RepositoryStages, SingleFileStages};
+pub use telemetry::{TelemetryConfig, TelemetryGuard};
 pub use unified_processor::{get_file_category, process_any_file, FileCategory};
 // pub use storage_integration::StorageIntegratedOrchestrator;
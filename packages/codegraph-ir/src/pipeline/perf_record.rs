@@ -0,0 +1,308 @@
+//! Per-run performance records for regression dashboards
+//!
+//! With `E2EPipelineConfig::perf_records(path)` every run appends one JSON
+//! line to `path`:
+//!
+//! ```json
+//! {"schema_version":1,"timestamp":"2026-01-05T10:00:00Z","repo":"api",
+//!  "commit":"3f2c..","preset":"balanced","mode":"full",
+//!  "repo_size":{"files":812,"loc":120431,"bytes":4210933},
+//!  "total_ms":5230.1,"stages_ms":{"L1_IR_Build":2100.4,...},
+//!  "memory":{"peak_bytes":612368384,"stage_delta_bytes":{"L1_IR_Build":402653184,...}},
+//!  "cache":{"files_cached":790,"hit_rate":0.97},
+//!  "env":{"fingerprint":"9b1e..","cpu_model":"AMD EPYC 7B13","logical_cores":16,...}}
+//! ```
+//!
+//! The schema only grows: fields are never renamed or removed without
+//! bumping `schema_version`. Maps are sorted so equal runs produce equal
+//! lines. Records are only comparable between equal `env.fingerprint`s.
+
+use std::collections::BTreeMap;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::end_to_end_config::IndexingMode;
+use super::PipelineStats;
+use crate::shared::models::{CodegraphError, Result};
+
+/// Current record schema
+pub const PERF_RECORD_SCHEMA_VERSION: u32 = 1;
+
+/// Indexed repository size
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RepoSize {
+    pub files: usize,
+    pub loc: usize,
+    pub bytes: usize,
+}
+
+/// Resident memory of the run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemoryRecord {
+    pub peak_bytes: usize,
+    /// RSS growth per stage (empty where RSS is unavailable)
+    pub stage_delta_bytes: BTreeMap<String, i64>,
+}
+
+/// IR cache use of the run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CacheRecord {
+    pub files_cached: usize,
+    pub hit_rate: f64,
+}
+
+/// Machine and build a record was taken on
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnvFingerprint {
+    /// Hash of the fields below (group records by it)
+    pub fingerprint: String,
+    pub cpu_model: Option<String>,
+    pub logical_cores: usize,
+    pub physical_cores: usize,
+    pub os: String,
+    pub arch: String,
+    pub engine_version: String,
+}
+
+impl EnvFingerprint {
+    /// Fingerprint of this machine and build
+    pub fn current() -> Self {
+        Self::new(
+            cpu_model(),
+            num_cpus::get(),
+            num_cpus::get_physical(),
+            std::env::consts::OS,
+            std::env::consts::ARCH,
+            env!("CARGO_PKG_VERSION"),
+        )
+    }
+
+    pub fn new(
+        cpu_model: Option<String>,
+        logical_cores: usize,
+        physical_cores: usize,
+        os: &str,
+        arch: &str,
+        engine_version: &str,
+    ) -> Self {
+        let mut hasher = blake3::Hasher::new();
+        for part in [
+            cpu_model.as_deref().unwrap_or_default(),
+            os,
+            arch,
+            engine_version,
+        ] {
+            hasher.update(&(part.len() as u64).to_le_bytes());
+            hasher.update(part.as_bytes());
+        }
+        hasher.update(&(logical_cores as u64).to_le_bytes());
+        hasher.update(&(physical_cores as u64).to_le_bytes());
+
+        Self {
+            fingerprint: hasher.finalize().to_hex()[..16].to_string(),
+            cpu_model,
+            logical_cores,
+            physical_cores,
+            os: os.to_string(),
+            arch: arch.to_string(),
+            engine_version: engine_version.to_string(),
+        }
+    }
+}
+
+/// CPU model name (None where unavailable)
+fn cpu_model() -> Option<String> {
+    let cpuinfo = std::fs::read_to_string("/proc/cpuinfo").ok()?;
+    cpuinfo.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        (key.trim() == "model name").then(|| value.trim().to_string())
+    })
+}
+
+/// One pipeline run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PerfRecord {
+    pub schema_version: u32,
+    /// Run end, RFC 3339 UTC
+    pub timestamp: String,
+    pub repo: String,
+    /// `HEAD` commit, if the repository is a git work tree
+    pub commit: Option<String>,
+    pub preset: String,
+    pub mode: IndexingMode,
+    pub repo_size: RepoSize,
+    pub total_ms: f64,
+    pub stages_ms: BTreeMap<String, f64>,
+    pub memory: MemoryRecord,
+    pub cache: CacheRecord,
+    pub env: EnvFingerprint,
+}
+
+impl PerfRecord {
+    /// Record of a finished run
+    pub fn from_stats(
+        repo: &str,
+        commit: Option<String>,
+        preset: &str,
+        mode: IndexingMode,
+        stats: &PipelineStats,
+        env: EnvFingerprint,
+    ) -> Self {
+        let millis = |d: &std::time::Duration| d.as_micros() as f64 / 1000.0;
+        Self {
+            schema_version: PERF_RECORD_SCHEMA_VERSION,
+            timestamp: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+            repo: repo.to_string(),
+            commit,
+            preset: preset.to_string(),
+            mode,
+            repo_size: RepoSize {
+                files: stats.files_processed,
+                loc: stats.total_loc,
+                bytes: stats.total_bytes,
+            },
+            total_ms: millis(&stats.total_duration),
+            stages_ms: stats
+                .stage_durations
+                .iter()
+                .map(|(stage, d)| (stage.clone(), millis(d)))
+                .collect(),
+            memory: MemoryRecord {
+                peak_bytes: stats.peak_memory_bytes,
+                stage_delta_bytes: stats
+                    .stage_memory
+                    .iter()
+                    .map(|(stage, m)| (stage.clone(), m.delta_bytes()))
+                    .collect(),
+            },
+            cache: CacheRecord {
+                files_cached: stats.files_cached,
+                hit_rate: stats.cache_hit_rate,
+            },
+            env,
+        }
+    }
+
+    /// Append as one line to the NDJSON file at `path`
+    pub fn append_to(&self, path: &Path) -> Result<()> {
+        let mut line = serde_json::to_string(self).map_err(|e| {
+            CodegraphError::internal(format!("Failed to encode perf record: {}", e))
+        })?;
+        line.push('\n');
+
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                CodegraphError::io(format!("Failed to create {}: {}", parent.display(), e))
+            })?;
+        }
+        // One write per line keeps concurrent appends whole
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(|e| CodegraphError::io(format!("Failed to append {}: {}", path.display(), e)))
+    }
+
+    /// Records of an NDJSON file, skipping blank lines
+    pub fn read_all(path: &Path) -> Result<Vec<Self>> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| CodegraphError::io(format!("Failed to read {}: {}", path.display(), e)))?;
+        content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                serde_json::from_str(line).map_err(|e| {
+                    CodegraphError::parse(format!("{}:{}: {}", path.display(), i + 1, e))
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::StageMemory;
+    use std::time::Duration;
+
+    fn stats() -> PipelineStats {
+        let mut stats = PipelineStats::new();
+        stats.files_processed = 2;
+        stats.files_cached = 1;
+        stats.total_loc = 40;
+        stats.total_bytes = 900;
+        stats.total_duration = Duration::from_millis(250);
+        stats.calculate_cache_hit_rate();
+        stats.record_stage("L1_IR_Build", Duration::from_millis(100));
+        stats.record_stage_memory(
+            "L1_IR_Build",
+            StageMemory {
+                rss_before_bytes: 1000,
+                rss_after_bytes: 3000,
+            },
+        );
+        stats
+    }
+
+    #[test]
+    fn test_record_schema() {
+        let env = EnvFingerprint::new(Some("cpu".to_string()), 8, 4, "linux", "x86_64", "1.0.0");
+        let record = PerfRecord::from_stats(
+            "repo",
+            None,
+            "fast",
+            IndexingMode::Full,
+            &stats(),
+            env.clone(),
+        );
+        let json: serde_json::Value = serde_json::to_value(&record).unwrap();
+
+        assert_eq!(json["schema_version"], 1);
+        assert_eq!(json["mode"], "full");
+        assert_eq!(json["repo_size"]["bytes"], 900);
+        assert_eq!(json["total_ms"], 250.0);
+        assert_eq!(json["stages_ms"]["L1_IR_Build"], 100.0);
+        assert_eq!(json["memory"]["peak_bytes"], 3000);
+        assert_eq!(json["memory"]["stage_delta_bytes"]["L1_IR_Build"], 2000);
+        assert_eq!(json["cache"]["hit_rate"], 0.5);
+        assert_eq!(json["env"]["logical_cores"], 8);
+
+        // Fingerprint only depends on the environment
+        assert_eq!(
+            env.fingerprint,
+            EnvFingerprint::new(Some("cpu".to_string()), 8, 4, "linux", "x86_64", "1.0.0")
+                .fingerprint
+        );
+        assert_ne!(
+            env.fingerprint,
+            EnvFingerprint::new(Some("cpu".to_string()), 16, 8, "linux", "x86_64", "1.0.0")
+                .fingerprint
+        );
+    }
+
+    #[test]
+    fn test_append_ndjson() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("perf/runs.ndjson");
+        let record = PerfRecord::from_stats(
+            "repo",
+            Some("abc".to_string()),
+            "balanced",
+            IndexingMode::Incremental,
+            &stats(),
+            EnvFingerprint::current(),
+        );
+        record.append_to(&path).unwrap();
+        record.append_to(&path).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(content.lines().count(), 2);
+        let records = PerfRecord::read_all(&path).unwrap();
+        assert_eq!(records, vec![record.clone(), record]);
+    }
+}
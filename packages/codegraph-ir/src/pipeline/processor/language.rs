@@ -2,12 +2,11 @@
 //!
 //! Extracted from processor.rs lines 1850-1862
 //!
-//! Maps file extensions to appropriate LanguagePlugin implementations.
+//! Maps file extensions to appropriate LanguagePlugin implementations
+//! via the plugin SDK registry.
 
-use crate::features::parsing::plugins::{
-    GoPlugin, JavaPlugin, KotlinPlugin, PythonPlugin, RustPlugin, TypeScriptPlugin,
-};
 use crate::features::parsing::ports::{LanguageId, LanguagePlugin};
+use crate::features::parsing::sdk;
 
 /// Get the appropriate language plugin and LanguageId based on file extension
 ///
@@ -19,6 +18,11 @@ use crate::features::parsing::ports::{LanguageId, LanguagePlugin};
 /// - Kotlin (.kt, .kts)
 /// - Rust (.rs)
 /// - Go (.go)
+/// - C (.c)
+/// - C++ (.cpp, .cc, .cxx, .hpp, .hh, .hxx, .h)
+/// - PHP (.php, .phtml)
+/// - Ruby (.rb, .rake)
+/// - Any plugin registered through `features::parsing::sdk`
 ///
 /// # Arguments
 /// * `file_path` - File path with extension
@@ -29,16 +33,7 @@ pub fn get_plugin_for_file(
     file_path: &str,
 ) -> Option<(Box<dyn LanguagePlugin + Send + Sync>, LanguageId)> {
     let ext = file_path.rsplit('.').next()?;
-    match ext {
-        "py" => Some((Box::new(PythonPlugin::new()), LanguageId::Python)),
-        "java" => Some((Box::new(JavaPlugin::new()), LanguageId::Java)),
-        "ts" | "tsx" => Some((Box::new(TypeScriptPlugin::new()), LanguageId::TypeScript)),
-        "js" | "jsx" => Some((Box::new(TypeScriptPlugin::new()), LanguageId::JavaScript)),
-        "kt" | "kts" => Some((Box::new(KotlinPlugin::new()), LanguageId::Kotlin)),
-        "rs" => Some((Box::new(RustPlugin::new()), LanguageId::Rust)),
-        "go" => Some((Box::new(GoPlugin::new()), LanguageId::Go)),
-        _ => None,
-    }
+    sdk::plugin_for_extension(ext)
 }
 
 #[cfg(test)]
@@ -54,6 +49,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "ts-lang")]
     fn test_typescript_detection() {
         let result = get_plugin_for_file("app.tsx");
         assert!(result.is_some());
@@ -62,6 +58,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "ts-lang")]
     fn test_javascript_detection() {
         let result = get_plugin_for_file("script.js");
         assert!(result.is_some());
@@ -70,6 +67,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "kotlin-lang")]
     fn test_kotlin_detection() {
         let result = get_plugin_for_file("Main.kt");
         assert!(result.is_some());
@@ -78,6 +76,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "rust-lang")]
     fn test_rust_detection() {
         let result = get_plugin_for_file("lib.rs");
         assert!(result.is_some());
@@ -86,6 +85,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "go-lang")]
     fn test_go_detection() {
         let result = get_plugin_for_file("main.go");
         assert!(result.is_some());
@@ -93,6 +93,29 @@ mod tests {
         assert!(matches!(lang_id, LanguageId::Go));
     }
 
+    #[test]
+    #[cfg(all(feature = "c-lang", feature = "cpp-lang"))]
+    fn test_c_cpp_detection() {
+        let (_, lang_id) = get_plugin_for_file("alloc.c").unwrap();
+        assert!(matches!(lang_id, LanguageId::C));
+
+        let (_, lang_id) = get_plugin_for_file("widget.hpp").unwrap();
+        assert!(matches!(lang_id, LanguageId::Cpp));
+
+        let (_, lang_id) = get_plugin_for_file("alloc.h").unwrap();
+        assert!(matches!(lang_id, LanguageId::Cpp));
+    }
+
+    #[test]
+    #[cfg(all(feature = "php-lang", feature = "ruby-lang"))]
+    fn test_php_ruby_detection() {
+        let (_, lang_id) = get_plugin_for_file("app/Models/User.php").unwrap();
+        assert!(matches!(lang_id, LanguageId::Php));
+
+        let (_, lang_id) = get_plugin_for_file("lib/tasks/seed.rake").unwrap();
+        assert!(matches!(lang_id, LanguageId::Ruby));
+    }
+
     #[test]
     fn test_unsupported_extension() {
         let result = get_plugin_for_file("file.txt");
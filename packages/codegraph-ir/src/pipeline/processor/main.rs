@@ -13,39 +13,44 @@
 //! - Better maintainability (5 focused modules vs 2K LOC monolith)
 
 use crate::features::data_flow::infrastructure::dfg::DataFlowGraph;
-use crate::features::flow_graph::infrastructure::{
-    bfg::BasicFlowGraph,
-    cfg::{build_cfg_edges, CFGEdge},
-};
+use crate::features::flow_graph::infrastructure::{bfg::BasicFlowGraph, cfg::CFGEdge};
 use crate::features::heap_analysis::{MemorySafetyIssue, SecurityVulnerability};
 use crate::features::ir_generation::infrastructure::ir_builder::IRBuilder;
-use crate::features::parsing::plugins::{
-    GoPlugin, JavaPlugin, KotlinPlugin, PythonPlugin, RustPlugin, TypeScriptPlugin,
-};
-use crate::features::parsing::ports::{LanguageId, LanguagePlugin};
+use crate::features::parsing::plugins::PythonPlugin;
+use crate::features::parsing::ports::LanguagePlugin;
+use crate::features::secret_detection::detect_secret_leaks;
 use crate::features::ssa::infrastructure::ssa::SSAGraph;
 use crate::features::type_resolution::domain::TypeEntity;
-use crate::shared::models::{Edge, Node, Occurrence};
+use crate::shared::models::{Edge, IdRemap, Node, Occurrence};
 use tree_sitter::{Node as TSNode, Parser};
 
 // Import all stages from current module (we're inside processor/)
 use super::{
     language::get_plugin_for_file,
     stages::{
-        // L4-L5
-        build_dfg_graphs,
-        // L6
-        build_pdg_summaries,
-        build_ssa_graphs,
+        // L3
+        annotate_exceptions,
+        annotate_metrics,
+        // L4-L6
+        annotate_skipped,
+        build_function_cfg_edges,
+        file_metrics,
         generate_occurrences,
+        link_higher_order_calls,
         // L1-L2
         process_with_bfg,
+        run_data_flow_stages,
         // L7
         run_heap_analysis,
         run_points_to_analysis,
         run_taint_analysis,
+        skip_unsupported,
+        AnalysisLimits,
+        DataFlowOutput,
+    },
+    types::{
+        AnalysisCoverage, PDGSummary, PointsToSummary, ProcessResult, SliceSummary, TaintSummary,
     },
-    types::{PDGSummary, PointsToSummary, ProcessResult, SliceSummary, TaintSummary},
 };
 
 /// Process Python file and generate IR (L1-L7 complete pipeline)
@@ -109,32 +114,46 @@ pub fn process_python_file(
     );
 
     // Build IR
-    let (nodes, edges, type_entities) = builder.build();
+    let (mut nodes, mut edges, type_entities) = builder.build();
+    link_higher_order_calls(&root, content, &mut nodes, &mut edges);
+    IdRemap::assign(&mut nodes);
 
-    // === L3: CFG Construction ===
-    let mut all_cfg_edges = Vec::new();
-    for bfg in &bfg_graphs {
-        let cfg_edges = build_cfg_edges(&bfg.blocks);
-        all_cfg_edges.extend(cfg_edges);
-    }
+    // === L3: CFG Construction (exception edges included) ===
+    let all_cfg_edges = build_function_cfg_edges(&bfg_graphs);
+    let function_metrics = annotate_metrics(&mut nodes, &bfg_graphs, content);
+    annotate_exceptions(&mut nodes, &bfg_graphs);
+    let file_metrics = file_metrics(&root, content, file_path, "python", &function_metrics);
 
-    // === L4-L5: Data Flow + SSA ===
-    let dfg_graphs = build_dfg_graphs(&nodes, &edges, &bfg_graphs);
-    let ssa_graphs = build_ssa_graphs(&nodes, &bfg_graphs);
+    // === L4-L6: Data Flow + SSA + PDG (per-function limits) ===
+    let DataFlowOutput {
+        dfg_graphs,
+        ssa_graphs,
+        pdg_graphs,
+        skipped: skipped_analyses,
+        coverage,
+    } = run_data_flow_stages(
+        &nodes,
+        &edges,
+        &bfg_graphs,
+        &all_cfg_edges,
+        &AnalysisLimits::default(),
+    );
+    annotate_skipped(&mut nodes, &skipped_analyses);
 
     // === L6: Advanced Analyses ===
-    let pdg_graphs = build_pdg_summaries(&dfg_graphs, &all_cfg_edges, &bfg_graphs);
     let taint_results = run_taint_analysis(&nodes, &edges);
     // ❌ REMOVED: Per-file PTA causes 619x redundant analysis (10+ seconds per repo)
     // PTA is now executed once at L6 stage (repository-wide) for correct results
     let points_to_result = None; // Computed at L6 stage
 
-    // L6: Slicing (computed on-demand via API - we just report capability)
+    // L6: Slicing of configured criteria is attached by the E2E orchestrator
+    // (`run_slicing`); other slices are computed on demand via API
     let slice_results = Vec::new();
 
-    // === L7: Heap Analysis (Memory + Security + Escape) ===
-    let (memory_safety_issues, security_vulnerabilities, escape_info) =
+    // === L7: Heap Analysis (Memory + Security + Escape) + Secret Leaks ===
+    let (memory_safety_issues, mut security_vulnerabilities, escape_info) =
         run_heap_analysis(&nodes, &edges);
+    security_vulnerabilities.extend(detect_secret_leaks(file_path, content, &nodes, &edges));
 
     // 🚀 SOTA: Occurrences generated in batched phase (lib.rs:258-266)
     // Empty here for optimal performance - batched generation is 8.6x faster than inline
@@ -156,6 +175,9 @@ pub fn process_python_file(
         memory_safety_issues,
         security_vulnerabilities,
         escape_info,
+        skipped_analyses,
+        coverage,
+        file_metrics: Some(file_metrics),
         errors,
     }
 }
@@ -205,15 +227,8 @@ pub fn process_file(
         }
     };
 
-    let language_str = match lang_id {
-        LanguageId::Python => "python",
-        LanguageId::TypeScript => "typescript",
-        LanguageId::JavaScript => "javascript",
-        LanguageId::Java => "java",
-        LanguageId::Kotlin => "kotlin",
-        LanguageId::Rust => "rust",
-        LanguageId::Go => "go",
-    };
+    let language_str = lang_id.name();
+    let capabilities = plugin.capabilities();
 
     // Parse AST (LanguagePlugin trait method)
     let mut parser = Parser::new();
@@ -252,32 +267,66 @@ pub fn process_file(
     );
 
     // Build IR
-    let (nodes, edges, type_entities) = builder.build();
+    let (mut nodes, mut edges, type_entities) = builder.build();
+    link_higher_order_calls(&root, content, &mut nodes, &mut edges);
+    IdRemap::assign(&mut nodes);
+
+    // Stages are skipped for languages whose plugin does not declare the capability
 
-    // === L3: CFG Construction ===
+    // === L3: CFG Construction (exception edges included) ===
     let mut all_cfg_edges = Vec::new();
-    for bfg in &bfg_graphs {
-        let cfg_edges = build_cfg_edges(&bfg.blocks);
-        all_cfg_edges.extend(cfg_edges);
+    if capabilities.control_flow {
+        all_cfg_edges = build_function_cfg_edges(&bfg_graphs);
+        annotate_exceptions(&mut nodes, &bfg_graphs);
     }
+    let function_metrics = annotate_metrics(&mut nodes, &bfg_graphs, content);
+    let file_metrics = file_metrics(&root, content, file_path, language_str, &function_metrics);
 
-    // === L4-L5: Data Flow + SSA ===
-    let dfg_graphs = build_dfg_graphs(&nodes, &edges, &bfg_graphs);
-    let ssa_graphs = build_ssa_graphs(&nodes, &bfg_graphs);
+    // === L4-L6: Data Flow + SSA + PDG (per-function limits) ===
+    let DataFlowOutput {
+        dfg_graphs,
+        ssa_graphs,
+        pdg_graphs,
+        skipped: skipped_analyses,
+        coverage,
+    } = if capabilities.data_flow {
+        run_data_flow_stages(
+            &nodes,
+            &edges,
+            &bfg_graphs,
+            &all_cfg_edges,
+            &AnalysisLimits::default(),
+        )
+    } else {
+        DataFlowOutput {
+            skipped: skip_unsupported(&nodes, &bfg_graphs, language_str),
+            coverage: AnalysisCoverage {
+                functions_total: bfg_graphs.len(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    };
+    annotate_skipped(&mut nodes, &skipped_analyses);
 
     // === L6: Advanced Analyses ===
-    let pdg_graphs = build_pdg_summaries(&dfg_graphs, &all_cfg_edges, &bfg_graphs);
-    let taint_results = run_taint_analysis(&nodes, &edges);
+    let taint_results = if capabilities.taint {
+        run_taint_analysis(&nodes, &edges)
+    } else {
+        Vec::new()
+    };
     // ❌ REMOVED: Per-file PTA causes 619x redundant analysis (10+ seconds per repo)
     // PTA is now executed once at L6 stage (repository-wide) for correct results
     let points_to_result = None; // Computed at L6 stage
 
-    // L6: Slicing (computed on-demand via API)
+    // L6: Slicing of configured criteria is attached by the E2E orchestrator
+    // (`run_slicing`); other slices are computed on demand via API
     let slice_results = Vec::new();
 
-    // === L7: Heap Analysis (Memory + Security + Escape) ===
-    let (memory_safety_issues, security_vulnerabilities, escape_info) =
+    // === L7: Heap Analysis (Memory + Security + Escape) + Secret Leaks ===
+    let (memory_safety_issues, mut security_vulnerabilities, escape_info) =
         run_heap_analysis(&nodes, &edges);
+    security_vulnerabilities.extend(detect_secret_leaks(file_path, content, &nodes, &edges));
 
     // 🚀 SOTA: Occurrences batched for performance
     let occurrences = Vec::new();
@@ -298,6 +347,9 @@ pub fn process_file(
         memory_safety_issues,
         security_vulnerabilities,
         escape_info,
+        skipped_analyses,
+        coverage,
+        file_metrics: Some(file_metrics),
         errors,
     }
 }
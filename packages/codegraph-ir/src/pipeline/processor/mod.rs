@@ -33,7 +33,10 @@ pub mod stages;
 pub mod types;
 
 // Re-export types for convenience
-pub use types::{PDGSummary, PointsToSummary, ProcessResult, SliceSummary, TaintSummary};
+pub use types::{
+    AnalysisCoverage, PDGSummary, PointsToSummary, ProcessResult, SkipReason, SkippedAnalysis,
+    SkippedStage, SliceSummary, TaintSummary,
+};
 
 // Re-export language detection
 pub use language::get_plugin_for_file;
@@ -0,0 +1,519 @@
+//! L4-L6: Per-function analysis limits
+//!
+//! DFG, SSA and PDG construction grows super-linearly with function size, so
+//! oversized functions (generated parsers, table-driven code) are skipped, as
+//! are the remaining functions of a file once its time budget is spent.
+//! Every skip is recorded as a `SkippedAnalysis` in the result and on the
+//! function node's metadata, so missing graphs are explained rather than
+//! silently absent.
+//!
+//! Functions are scheduled by `SchedulingPolicy`: by default the most central
+//! ones (RepoMap PageRank over the call graph) go first, so a tight budget
+//! drops peripheral code rather than whatever happens to be at the end of
+//! the file. The coverage achieved is reported as `AnalysisCoverage`.
+//!
+//! # Functions
+//! - `run_data_flow_stages()` - DFG + SSA + PDG per function within limits
+//! - `run_data_flow_stages_ranked()` - Same, with caller-supplied importance
+//! - `function_importance()` - In-file call-graph PageRank per function
+//! - `skip_unsupported()` - Skip records for languages without data flow
+//! - `annotate_skipped()` - Attach skip records to function node metadata
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use super::advanced::build_pdg_summaries;
+use super::data_flow::{build_dfg_graphs, build_ssa_graphs};
+use crate::features::data_flow::infrastructure::dfg::DataFlowGraph;
+use crate::features::flow_graph::infrastructure::{bfg::BasicFlowGraph, cfg::CFGEdge};
+use crate::features::repomap::infrastructure::{
+    GraphDocument, GraphEdge, GraphNode, PageRankEngine, PageRankSettings,
+};
+use crate::features::ssa::infrastructure::ssa::SSAGraph;
+use crate::pipeline::processor::types::{
+    AnalysisCoverage, PDGSummary, SkipReason, SkippedAnalysis, SkippedStage,
+};
+use crate::shared::models::{Edge, EdgeKind, Node, NodeKind};
+
+/// Metadata key holding a function's skip records
+pub const SKIPPED_ANALYSES_KEY: &str = "skipped_analyses";
+
+const ALL_STAGES: [SkippedStage; 3] = [SkippedStage::Dfg, SkippedStage::Ssa, SkippedStage::Pdg];
+
+/// Order in which a file's functions are analyzed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SchedulingPolicy {
+    /// Source (BFG) order; the end of the file is dropped first
+    SourceOrder,
+    /// Most important first, ties in source order
+    #[default]
+    Centrality,
+}
+
+/// Per-function limits for L4-L6
+#[derive(Debug, Clone, Copy)]
+pub struct AnalysisLimits {
+    /// BFG blocks per function
+    pub max_blocks: usize,
+    /// Variable definitions per function
+    pub max_variables: usize,
+    /// Wall-clock budget for L4-L6 of one file
+    pub time_budget: Duration,
+    /// Which functions get the budget first
+    pub policy: SchedulingPolicy,
+}
+
+impl Default for AnalysisLimits {
+    fn default() -> Self {
+        Self {
+            max_blocks: 2_000,
+            max_variables: 5_000,
+            time_budget: Duration::from_secs(5),
+            policy: SchedulingPolicy::default(),
+        }
+    }
+}
+
+/// L4-L6 results with skip records
+#[derive(Debug, Default)]
+pub struct DataFlowOutput {
+    pub dfg_graphs: Vec<DataFlowGraph>,
+    pub ssa_graphs: Vec<SSAGraph>,
+    pub pdg_graphs: Vec<PDGSummary>,
+    pub skipped: Vec<SkippedAnalysis>,
+    pub coverage: AnalysisCoverage,
+}
+
+/// Build DFG, SSA and PDG for each function within `limits`
+///
+/// Limits are checked before a function is analyzed; a function that is
+/// skipped gets one record per stage and no graphs. Importance comes from
+/// `function_importance` over this file.
+pub fn run_data_flow_stages(
+    nodes: &[Node],
+    edges: &[Edge],
+    bfg_graphs: &[BasicFlowGraph],
+    cfg_edges: &[CFGEdge],
+    limits: &AnalysisLimits,
+) -> DataFlowOutput {
+    let importance = if bfg_graphs.is_empty() {
+        HashMap::new()
+    } else {
+        function_importance(nodes, edges)
+    };
+    run_data_flow_stages_ranked(nodes, edges, bfg_graphs, cfg_edges, limits, &importance)
+}
+
+/// `run_data_flow_stages` with importance per function node ID, e.g. the
+/// repository-wide scores of a previous RepoMap snapshot
+///
+/// Functions without a score rank last (importance 0).
+pub fn run_data_flow_stages_ranked(
+    nodes: &[Node],
+    edges: &[Edge],
+    bfg_graphs: &[BasicFlowGraph],
+    cfg_edges: &[CFGEdge],
+    limits: &AnalysisLimits,
+    importance: &HashMap<String, f64>,
+) -> DataFlowOutput {
+    let start = Instant::now();
+    let mut output = DataFlowOutput::default();
+
+    let mut scheduled: Vec<(&BasicFlowGraph, Option<&Node>, f64)> = bfg_graphs
+        .iter()
+        .map(|bfg| {
+            let function = find_function(nodes, bfg);
+            let score = function
+                .and_then(|f| importance.get(&f.id))
+                .copied()
+                .unwrap_or(0.0);
+            (bfg, function, score)
+        })
+        .collect();
+    if limits.policy == SchedulingPolicy::Centrality {
+        scheduled.sort_by(|a, b| b.2.total_cmp(&a.2));
+    }
+    output.coverage.functions_total = scheduled.len();
+    output.coverage.importance_total = scheduled.iter().map(|(_, _, score)| score).sum();
+
+    for (bfg, function, score) in scheduled {
+        if let Some(reason) = check_limits(nodes, bfg, function, limits, start.elapsed()) {
+            output.coverage.budget_exhausted |= matches!(reason, SkipReason::Time { .. });
+            output
+                .skipped
+                .extend(skip_records(bfg, function, &ALL_STAGES, &reason));
+            continue;
+        }
+        output.coverage.functions_analyzed += 1;
+        output.coverage.importance_analyzed += score;
+
+        let bfg = std::slice::from_ref(bfg);
+        let dfg_graphs = build_dfg_graphs(nodes, edges, bfg);
+        output
+            .pdg_graphs
+            .extend(build_pdg_summaries(&dfg_graphs, cfg_edges, bfg));
+        output.ssa_graphs.extend(build_ssa_graphs(nodes, bfg));
+        output.dfg_graphs.extend(dfg_graphs);
+    }
+
+    output
+}
+
+/// Centrality of each function/method of a file (node ID → PageRank, max 1.0)
+///
+/// PageRank runs over the file's call graph as RepoMap does for the whole
+/// repository. Call targets are resolved to functions of the file by ID,
+/// FQN or unambiguous name; calls leaving the file are ignored.
+pub fn function_importance(nodes: &[Node], edges: &[Edge]) -> HashMap<String, f64> {
+    let functions: Vec<&Node> = nodes
+        .iter()
+        .filter(|n| matches!(n.kind, NodeKind::Function | NodeKind::Method))
+        .collect();
+    if functions.is_empty() {
+        return HashMap::new();
+    }
+
+    let mut exact: HashMap<&str, &str> = HashMap::new();
+    let mut by_name: HashMap<&str, Option<&str>> = HashMap::new();
+    for function in &functions {
+        exact.insert(function.id.as_str(), function.id.as_str());
+        exact.insert(function.fqn.as_str(), function.id.as_str());
+        if let Some(name) = function.name.as_deref() {
+            by_name
+                .entry(name)
+                .and_modify(|id| *id = None)
+                .or_insert(Some(function.id.as_str()));
+        }
+    }
+    let resolve = |key: &str| {
+        exact
+            .get(key)
+            .copied()
+            .or_else(|| by_name.get(key).copied().flatten())
+    };
+
+    let graph = GraphDocument {
+        nodes: functions
+            .iter()
+            .map(|f| GraphNode {
+                id: f.id.clone(),
+                kind: f.kind.as_str().to_string(),
+            })
+            .collect(),
+        edges: edges
+            .iter()
+            .filter(|e| e.kind == EdgeKind::Calls)
+            .filter_map(|e| {
+                let source = resolve(&e.source_id)?;
+                let target = resolve(&e.target_id)?;
+                Some(GraphEdge {
+                    source: source.to_string(),
+                    target: target.to_string(),
+                    kind: "calls".to_string(),
+                })
+            })
+            .collect(),
+    };
+    PageRankEngine::new(&PageRankSettings::default()).compute_pagerank(&graph)
+}
+
+/// Skip records for every function of a file whose language has no data flow
+pub fn skip_unsupported(
+    nodes: &[Node],
+    bfg_graphs: &[BasicFlowGraph],
+    language: &str,
+) -> Vec<SkippedAnalysis> {
+    let reason = SkipReason::UnsupportedConstruct(format!("language `{}`", language));
+    bfg_graphs
+        .iter()
+        .flat_map(|bfg| skip_records(bfg, find_function(nodes, bfg), &ALL_STAGES, &reason))
+        .collect()
+}
+
+/// Record skips on the function nodes' metadata
+///
+/// Adds `skipped_analyses: [{stage, reason, detail}]` to the node's JSON
+/// metadata, keeping existing keys.
+pub fn annotate_skipped(nodes: &mut [Node], skipped: &[SkippedAnalysis]) {
+    let mut by_function: HashMap<&str, Vec<serde_json::Value>> = HashMap::new();
+    for skip in skipped {
+        by_function
+            .entry(skip.function_id.as_str())
+            .or_default()
+            .push(serde_json::json!({
+                "stage": skip.stage.as_str(),
+                "reason": skip.reason.as_str(),
+                "detail": skip.reason.detail(),
+            }));
+    }
+    if by_function.is_empty() {
+        return;
+    }
+
+    for node in nodes.iter_mut() {
+        if let Some(records) = by_function.remove(node.id.as_str()) {
+            insert_metadata(
+                node,
+                SKIPPED_ANALYSES_KEY,
+                serde_json::Value::Array(records),
+            );
+        }
+    }
+}
+
+/// Set `key` in a node's JSON metadata (non-object metadata moves to `value`)
+pub(crate) fn insert_metadata(node: &mut Node, key: &str, value: serde_json::Value) {
+    let mut metadata = match node
+        .metadata
+        .as_deref()
+        .map(serde_json::from_str::<serde_json::Value>)
+    {
+        Some(Ok(serde_json::Value::Object(map))) => map,
+        Some(_) => {
+            let mut map = serde_json::Map::new();
+            map.insert(
+                "value".to_string(),
+                serde_json::Value::String(node.metadata.take().unwrap_or_default()),
+            );
+            map
+        }
+        None => serde_json::Map::new(),
+    };
+    metadata.insert(key.to_string(), value);
+    node.metadata = Some(serde_json::Value::Object(metadata).to_string());
+}
+
+fn check_limits(
+    nodes: &[Node],
+    bfg: &BasicFlowGraph,
+    function: Option<&Node>,
+    limits: &AnalysisLimits,
+    elapsed: Duration,
+) -> Option<SkipReason> {
+    if elapsed >= limits.time_budget {
+        return Some(SkipReason::Time {
+            budget_ms: limits.time_budget.as_millis() as u64,
+        });
+    }
+    if bfg.blocks.len() > limits.max_blocks {
+        return Some(SkipReason::Size {
+            metric: "blocks",
+            actual: bfg.blocks.len(),
+            limit: limits.max_blocks,
+        });
+    }
+
+    let function = function?;
+    let variables = nodes
+        .iter()
+        .filter(|n| {
+            n.kind == NodeKind::Variable && n.parent_id.as_deref() == Some(function.id.as_str())
+        })
+        .count();
+    if variables > limits.max_variables {
+        return Some(SkipReason::Size {
+            metric: "variables",
+            actual: variables,
+            limit: limits.max_variables,
+        });
+    }
+    None
+}
+
+/// Function node for a BFG (`bfg.function_id` is the function name)
+fn find_function<'a>(nodes: &'a [Node], bfg: &BasicFlowGraph) -> Option<&'a Node> {
+    nodes.iter().find(|n| {
+        matches!(n.kind, NodeKind::Function | NodeKind::Method)
+            && n.name.as_deref() == Some(bfg.function_id.as_str())
+    })
+}
+
+fn skip_records(
+    bfg: &BasicFlowGraph,
+    function: Option<&Node>,
+    stages: &[SkippedStage],
+    reason: &SkipReason,
+) -> Vec<SkippedAnalysis> {
+    stages
+        .iter()
+        .map(|&stage| SkippedAnalysis {
+            function_id: function.map_or_else(|| bfg.function_id.clone(), |f| f.id.clone()),
+            function_name: bfg.function_id.clone(),
+            line: function.map_or(0, |f| f.span.start_line),
+            stage,
+            reason: reason.clone(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::models::span_ref::BlockRef;
+    use crate::shared::models::Span;
+
+    fn function(name: &str, blocks: usize) -> (Vec<Node>, BasicFlowGraph) {
+        let func = Node::new(
+            format!("func:{}", name),
+            NodeKind::Function,
+            name.to_string(),
+            "test.py".to_string(),
+            Span::new(3, 0, 10, 0),
+        )
+        .with_name(name);
+        let var = Node::new(
+            format!("var:{}:x", name),
+            NodeKind::Variable,
+            format!("{}.x", name),
+            "test.py".to_string(),
+            Span::new(4, 4, 4, 5),
+        )
+        .with_name("x")
+        .with_parent(format!("func:{}", name));
+
+        let bfg = BasicFlowGraph {
+            id: format!("bfg:{}", name),
+            function_id: name.to_string(),
+            entry_block_id: format!("bfg:{}:block:0", name),
+            exit_block_id: format!("bfg:{}:block:{}", name, blocks.saturating_sub(1)),
+            blocks: (0..blocks)
+                .map(|i| {
+                    BlockRef::new(
+                        format!("bfg:{}:block:{}", name, i),
+                        "Statement".to_string(),
+                        Span::new(4 + i as u32, 4, 4 + i as u32, 10),
+                        1,
+                    )
+                })
+                .collect(),
+            total_statements: blocks,
+            exception_flow: Default::default(),
+        };
+        (vec![func, var], bfg)
+    }
+
+    #[test]
+    fn test_oversized_function_is_skipped() {
+        let (mut nodes, small) = function("small", 2);
+        let (large_nodes, large) = function("large", 5);
+        nodes.extend(large_nodes);
+        let limits = AnalysisLimits {
+            max_blocks: 3,
+            ..Default::default()
+        };
+
+        let output = run_data_flow_stages(&nodes, &[], &[small, large], &[], &limits);
+
+        assert_eq!(output.dfg_graphs.len(), 1);
+        assert_eq!(output.ssa_graphs.len(), 1);
+        assert_eq!(output.pdg_graphs.len(), 1);
+        assert_eq!(output.dfg_graphs[0].function_id, "small");
+
+        assert_eq!(output.skipped.len(), 3);
+        let skip = &output.skipped[0];
+        assert_eq!(skip.function_id, "func:large");
+        assert_eq!(skip.line, 3);
+        assert_eq!(skip.stage, SkippedStage::Dfg);
+        assert_eq!(
+            skip.reason,
+            SkipReason::Size {
+                metric: "blocks",
+                actual: 5,
+                limit: 3
+            }
+        );
+        assert_eq!(
+            skip.message(),
+            "DFG skipped for `large`: 5 blocks exceeds limit of 3"
+        );
+    }
+
+    #[test]
+    fn test_time_budget_and_variable_limit() {
+        let (nodes, bfg) = function("f", 2);
+
+        let limits = AnalysisLimits {
+            time_budget: Duration::ZERO,
+            ..Default::default()
+        };
+        let output = run_data_flow_stages(&nodes, &[], std::slice::from_ref(&bfg), &[], &limits);
+        assert!(output.dfg_graphs.is_empty());
+        assert_eq!(output.skipped[0].reason, SkipReason::Time { budget_ms: 0 });
+
+        let limits = AnalysisLimits {
+            max_variables: 0,
+            ..Default::default()
+        };
+        let output = run_data_flow_stages(&nodes, &[], &[bfg], &[], &limits);
+        assert_eq!(output.skipped[0].reason.as_str(), "size");
+    }
+
+    #[test]
+    fn test_centrality_schedules_called_function_first() {
+        let mut nodes = Vec::new();
+        let mut bfgs = Vec::new();
+        for name in ["a", "b", "helper"] {
+            let (function_nodes, bfg) = function(name, 2);
+            nodes.extend(function_nodes);
+            bfgs.push(bfg);
+        }
+        let edges = vec![
+            Edge::new("func:a".to_string(), "helper".to_string(), EdgeKind::Calls),
+            Edge::new("func:b".to_string(), "helper".to_string(), EdgeKind::Calls),
+        ];
+
+        let importance = function_importance(&nodes, &edges);
+        assert_eq!(importance["func:helper"], 1.0);
+        assert!(importance["func:a"] < 1.0);
+
+        let output = run_data_flow_stages(&nodes, &edges, &bfgs, &[], &AnalysisLimits::default());
+        assert_eq!(output.dfg_graphs[0].function_id, "helper");
+        assert_eq!(output.coverage.functions_analyzed, 3);
+        assert_eq!(output.coverage.importance_ratio(), 1.0);
+        assert!(!output.coverage.budget_exhausted);
+
+        let limits = AnalysisLimits {
+            policy: SchedulingPolicy::SourceOrder,
+            ..Default::default()
+        };
+        let output = run_data_flow_stages(&nodes, &edges, &bfgs, &[], &limits);
+        assert_eq!(output.dfg_graphs[0].function_id, "a");
+    }
+
+    #[test]
+    fn test_coverage_when_budget_expires() {
+        let (nodes, bfg) = function("f", 2);
+        let limits = AnalysisLimits {
+            time_budget: Duration::ZERO,
+            ..Default::default()
+        };
+        let importance = HashMap::from([("func:f".to_string(), 0.5)]);
+
+        let output = run_data_flow_stages_ranked(&nodes, &[], &[bfg], &[], &limits, &importance);
+        let coverage = &output.coverage;
+        assert_eq!(coverage.functions_total, 1);
+        assert_eq!(coverage.functions_analyzed, 0);
+        assert_eq!(coverage.importance_total, 0.5);
+        assert_eq!(coverage.importance_ratio(), 0.0);
+        assert!(coverage.budget_exhausted);
+    }
+
+    #[test]
+    fn test_annotate_skipped_keeps_metadata() {
+        let (mut nodes, bfg) = function("f", 2);
+        nodes[0].metadata = Some(r#"{"generated":true}"#.to_string());
+
+        let skipped = skip_unsupported(&nodes, &[bfg], "go");
+        annotate_skipped(&mut nodes, &skipped);
+
+        let metadata: serde_json::Value =
+            serde_json::from_str(nodes[0].metadata.as_deref().unwrap()).unwrap();
+        assert_eq!(metadata["generated"], true);
+        let records = metadata[SKIPPED_ANALYSES_KEY].as_array().unwrap();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[2]["stage"], "pdg");
+        assert_eq!(records[2]["reason"], "unsupported-construct");
+        assert_eq!(records[2]["detail"], "unsupported language `go`");
+        assert!(nodes[1].metadata.is_none());
+    }
+}
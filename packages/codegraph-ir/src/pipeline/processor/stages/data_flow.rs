@@ -141,15 +141,7 @@ pub fn build_ssa_graphs_with_extraction(
     let mut ssa_graphs = Vec::new();
 
     // Get language-specific variable extractor
-    let language_str = match lang_id {
-        LanguageId::Python => "python",
-        LanguageId::TypeScript => "typescript",
-        LanguageId::JavaScript => "javascript",
-        LanguageId::Java => "java",
-        LanguageId::Kotlin => "kotlin",
-        LanguageId::Rust => "rust",
-        LanguageId::Go => "go",
-    };
+    let language_str = lang_id.name();
 
     let extractor = get_variable_extractor(language_str);
 
@@ -288,6 +280,7 @@ mod tests {
             exit_block_id: "exit".to_string(),
             blocks: vec![],
             total_statements: 0,
+            exception_flow: Default::default(),
         };
 
         let dfgs = build_dfg_graphs(&nodes, &edges, &[bfg]);
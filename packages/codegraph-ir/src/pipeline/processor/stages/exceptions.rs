@@ -0,0 +1,114 @@
+//! L3: Raised and caught exception types per function
+//!
+//! Stores the `ExceptionSummary` of every BFG with try regions or raise
+//! sites on the function node's metadata under `EXCEPTIONS_KEY`. The CFG
+//! edges for the same flow come from `build_cfg_edges_with_exceptions`.
+
+use std::collections::HashMap;
+
+use super::budget::insert_metadata;
+use crate::features::flow_graph::infrastructure::{
+    bfg::BasicFlowGraph,
+    exception_flow::{build_cfg_edges_with_exceptions, EXCEPTIONS_KEY},
+    CFGEdge,
+};
+use crate::shared::models::{Node, NodeKind};
+
+/// CFG edges of all `bfg_graphs`, exceptional edges included
+pub fn build_function_cfg_edges(bfg_graphs: &[BasicFlowGraph]) -> Vec<CFGEdge> {
+    bfg_graphs
+        .iter()
+        .flat_map(|bfg| build_cfg_edges_with_exceptions(&bfg.blocks, &bfg.exception_flow))
+        .collect()
+}
+
+/// Attach exception summaries to the function nodes of `bfg_graphs`
+pub fn annotate_exceptions(nodes: &mut [Node], bfg_graphs: &[BasicFlowGraph]) {
+    // `bfg.function_id` is the function name (first definition wins)
+    let mut functions: HashMap<&str, usize> = HashMap::new();
+    for (i, node) in nodes.iter().enumerate() {
+        if matches!(node.kind, NodeKind::Function | NodeKind::Method) {
+            if let Some(name) = node.name.as_deref() {
+                functions.entry(name).or_insert(i);
+            }
+        }
+    }
+    let summaries: Vec<(usize, serde_json::Value)> = bfg_graphs
+        .iter()
+        .filter(|bfg| !bfg.exception_flow.is_empty())
+        .filter_map(|bfg| {
+            let &i = functions.get(bfg.function_id.as_str())?;
+            let summary = bfg.exception_flow.summary();
+            Some((i, serde_json::to_value(summary).unwrap_or_default()))
+        })
+        .collect();
+
+    for (i, value) in summaries {
+        insert_metadata(&mut nodes[i], EXCEPTIONS_KEY, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::flow_graph::infrastructure::{CFGEdgeType, ExceptionSummary};
+    use crate::pipeline::processor::process_python_file;
+
+    #[test]
+    fn test_try_except_finally_in_python_file() {
+        let source = "\
+def load(path):
+    try:
+        data = read(path)
+        if not data:
+            raise ValueError(path)
+    except (ValueError, KeyError) as e:
+        log(e)
+        raise
+    finally:
+        close(path)
+    return data
+";
+        let result = process_python_file(source, "repo", "m.py", "m");
+        let bfg = &result.bfg_graphs[0];
+        let flow = &bfg.exception_flow;
+        assert_eq!(flow.try_regions.len(), 1);
+        let region = &flow.try_regions[0];
+        assert_eq!(region.handlers[0].caught, vec!["ValueError", "KeyError"]);
+
+        let handler = region.handlers[0].blocks[0].as_str();
+        let finally = region.finally[0].as_str();
+        let raise = flow
+            .raises
+            .iter()
+            .find(|r| r.exception.as_deref() == Some("ValueError"))
+            .unwrap();
+        let edges = &result.cfg_edges;
+        let has = |source: &str, target: &str, edge_type: CFGEdgeType| {
+            edges.iter().any(|e| {
+                e.source_block_id == source
+                    && e.target_block_id == target
+                    && e.edge_type == edge_type
+            })
+        };
+        assert!(has(&raise.block_id, handler, CFGEdgeType::Exception));
+        // The bare re-raise in the handler runs finally before leaving
+        let reraise = flow.raises.iter().find(|r| r.exception.is_none()).unwrap();
+        assert!(has(&reraise.block_id, finally, CFGEdgeType::Exception));
+        assert!(has(
+            &region.finally[region.finally.len() - 1],
+            &bfg.exit_block_id,
+            CFGEdgeType::Exception
+        ));
+
+        let func = result
+            .nodes
+            .iter()
+            .find(|n| n.name.as_deref() == Some("load"))
+            .unwrap();
+        let summary = ExceptionSummary::from_node(func).unwrap();
+        assert_eq!(summary.raises, vec!["ValueError"]);
+        assert_eq!(summary.catches, vec!["KeyError", "ValueError"]);
+        assert!(summary.uncaught.is_empty());
+    }
+}
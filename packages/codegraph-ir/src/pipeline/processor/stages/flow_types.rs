@@ -67,6 +67,7 @@ pub fn extract_bfg_graphs_with_nodes(
             exit_block_id: exit_id,
             blocks: blocks.to_vec(),
             total_statements: blocks.iter().map(|b| b.statement_count).sum(),
+            exception_flow: Default::default(),
         }];
     }
 
@@ -130,6 +131,7 @@ pub fn extract_bfg_graphs_with_nodes(
             exit_block_id: exit_id,
             blocks: func_blocks.clone(),
             total_statements: func_blocks.iter().map(|b| b.statement_count).sum(),
+            exception_flow: Default::default(),
         };
 
         graphs.push(graph);
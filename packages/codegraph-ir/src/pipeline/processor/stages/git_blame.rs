@@ -0,0 +1,168 @@
+//! L33: Git blame / churn per symbol (optional, `git_blame` stage)
+//!
+//! Blames every indexed file through libgit2 and stores a `SymbolHistory`
+//! (authors by lines, last commit, churn in the last `CHURN_WINDOW_DAYS`)
+//! on the metadata of function, method and type nodes under
+//! `SYMBOL_HISTORY_KEY`. Hotspots (churn × complexity) are ranked from the
+//! annotated nodes by `query_engine::hotspots`.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+use super::budget::insert_metadata;
+use crate::features::git_history::domain::{BlameHunk, SymbolHistory, SYMBOL_HISTORY_KEY};
+use crate::shared::models::{Node, NodeKind};
+
+/// Nodes that get a history: callables and type containers
+fn has_history(kind: NodeKind) -> bool {
+    (kind.is_callable() || kind.is_container())
+        && !matches!(kind, NodeKind::File | NodeKind::Module)
+}
+
+/// Attach histories from per-file blame hunks and file churn, returning the
+/// number of annotated nodes
+pub fn attach_git_history(
+    nodes: &mut [Node],
+    blames: &HashMap<String, Vec<BlameHunk>>,
+    file_churn: &HashMap<String, u32>,
+    now: DateTime<Utc>,
+    window_days: i64,
+) -> usize {
+    let mut annotated = 0;
+    for node in nodes.iter_mut().filter(|n| has_history(n.kind)) {
+        let Some(hunks) = blames.get(&node.file_path) else {
+            continue;
+        };
+        let churn = file_churn.get(&node.file_path).copied().unwrap_or(0);
+        let Some(history) = SymbolHistory::from_hunks(hunks, &node.span, now, window_days, churn)
+        else {
+            continue;
+        };
+        let value = serde_json::to_value(history).unwrap_or_default();
+        insert_metadata(node, SYMBOL_HISTORY_KEY, value);
+        annotated += 1;
+    }
+    annotated
+}
+
+/// Blame the files of `nodes` (paths relative to `repo_root`) and attach
+/// their histories; files git does not track are skipped
+#[cfg(feature = "git")]
+pub fn annotate_git_history(
+    nodes: &mut [Node],
+    repo_root: &std::path::Path,
+    window_days: i64,
+) -> crate::shared::models::Result<usize> {
+    use crate::features::git_history::GitBlame;
+    use crate::shared::models::CodegraphError;
+    use rayon::prelude::*;
+    use std::collections::HashSet;
+
+    let git_error = |e: crate::features::git_history::GitError| {
+        CodegraphError::internal(format!("Git blame: {}", e))
+    };
+    let blame = GitBlame::open(repo_root).map_err(git_error)?;
+    let workdir = blame
+        .workdir()
+        .ok_or_else(|| CodegraphError::config("Git blame needs a non-bare repository"))?;
+    // Blame and churn paths are relative to the work tree, node paths to the repo root
+    let prefix = repo_root
+        .canonicalize()
+        .ok()
+        .zip(workdir.canonicalize().ok())
+        .and_then(|(root, workdir)| {
+            root.strip_prefix(&workdir)
+                .ok()
+                .map(|p| p.to_string_lossy().replace('\\', "/"))
+        })
+        .unwrap_or_default();
+    let git_path = |file_path: &str| {
+        if prefix.is_empty() {
+            file_path.to_string()
+        } else {
+            format!("{}/{}", prefix, file_path)
+        }
+    };
+
+    let now = Utc::now();
+    let churn: HashMap<String, u32> = blame
+        .file_churn(now, window_days)
+        .map_err(git_error)?
+        .into_iter()
+        .filter_map(|(path, count)| {
+            let relative = if prefix.is_empty() {
+                path
+            } else {
+                path.strip_prefix(&format!("{}/", prefix))?.to_string()
+            };
+            Some((relative, count))
+        })
+        .collect();
+
+    let files: Vec<&str> = nodes
+        .iter()
+        .filter(|n| has_history(n.kind))
+        .map(|n| n.file_path.as_str())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    // `Repository` is not `Sync`: one handle per worker
+    let blames: HashMap<String, Vec<BlameHunk>> = files
+        .par_iter()
+        .map_init(
+            || GitBlame::open(repo_root).ok(),
+            |blame, file| {
+                let hunks = blame.as_ref()?.blame_file(&git_path(file)).ok()?;
+                Some((file.to_string(), hunks))
+            },
+        )
+        .flatten()
+        .collect();
+    tracing::debug!(
+        "[L33 GitBlame] Blamed {}/{} files",
+        blames.len(),
+        files.len()
+    );
+
+    Ok(attach_git_history(nodes, &blames, &churn, now, window_days))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::models::Span;
+
+    #[test]
+    fn test_attach_git_history_to_symbols() {
+        let now = Utc::now();
+        let func = Node::new(
+            "func:f".to_string(),
+            NodeKind::Function,
+            "m.f".to_string(),
+            "m.py".to_string(),
+            Span::new(1, 0, 2, 12),
+        );
+        let mut var = func.clone();
+        var.kind = NodeKind::Variable;
+        let mut nodes = vec![func, var];
+        let blames = HashMap::from([(
+            "m.py".to_string(),
+            vec![BlameHunk {
+                start_line: 1,
+                lines: 2,
+                commit: "c1".to_string(),
+                author_name: "ann".to_string(),
+                author_email: "ann@x.dev".to_string(),
+                time: now,
+            }],
+        )]);
+        let churn = HashMap::from([("m.py".to_string(), 4)]);
+
+        assert_eq!(attach_git_history(&mut nodes, &blames, &churn, now, 90), 1);
+        let history = SymbolHistory::from_node(&nodes[0]).unwrap();
+        assert_eq!((history.churn, history.file_churn), (1, 4));
+        assert_eq!(history.last_author, "ann");
+        assert!(nodes[1].metadata.is_none());
+    }
+}
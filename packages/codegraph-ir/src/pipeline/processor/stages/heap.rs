@@ -217,7 +217,7 @@ pub fn run_heap_analysis_balanced(
 ///
 /// # Returns
 /// Tuple of (defs_map, uses_map) where each maps node_id → Set<var_name>
-fn build_def_use_map(
+pub(crate) fn build_def_use_map(
     edges: &[Edge],
 ) -> (
     HashMap<String, HashSet<String>>,
@@ -345,7 +345,7 @@ fn run_escape_analysis_per_function(
         match analyzer.analyze(function_id.clone(), &escape_nodes) {
             Ok(info) => results.push(info),
             Err(e) => {
-                eprintln!("Escape analysis failed for {}: {:?}", function_id, e);
+                tracing::warn!("Escape analysis failed for {}: {:?}", function_id, e);
             }
         }
     }
@@ -378,7 +378,7 @@ fn extract_function_id(node_id: &str) -> Option<String> {
 ///
 /// # DFG Integration
 /// Now extracts defs/uses from pre-built def-use maps derived from edges.
-fn node_to_escape_node(
+pub(crate) fn node_to_escape_node(
     node: &Node,
     defs_map: &HashMap<String, HashSet<String>>,
     uses_map: &HashMap<String, HashSet<String>>,
@@ -0,0 +1,297 @@
+//! L1: Decorator and callback call edges
+//!
+//! Links calls that never appear as `f()` in the source. A decorator defined
+//! in the file calls the definition it wraps; a function passed as an
+//! argument (`map(f, xs)`, `add_url_rule("/", view_func=index)`) is called on
+//! behalf of the enclosing function. Callback names resolve against the
+//! functions defined in the file, so plain variables stay READS only.
+
+use std::collections::{HashMap, HashSet};
+use tree_sitter::Node as TSNode;
+
+use super::budget::insert_metadata;
+use crate::features::parsing::infrastructure::extractors::{
+    decorator::{extract_callback_args, extract_decorators, is_higher_order, uses_functools_wraps},
+    fqn_resolver::FqnResolver,
+};
+use crate::pipeline::processor::helpers::find_body_node;
+use crate::shared::models::{Edge, EdgeKind, EdgeProvenance, EdgeStage, Node, NodeKind, Span};
+
+/// Node metadata key: FQNs of the decorators applied to a definition
+pub const WRAPPED_BY_KEY: &str = "wrapped_by";
+
+/// Confidence of a decorator → wrapped edge when the decorator does not use
+/// `functools.wraps` (registries may store the function without calling it)
+const UNWRAPPED_DECORATOR_CONFIDENCE: f32 = 0.8;
+const HIGHER_ORDER_CONFIDENCE: f32 = 0.9;
+const CALLBACK_CONFIDENCE: f32 = 0.7;
+
+/// Add DECORATED_WITH and CALLS edges for decorators and callback arguments
+pub fn link_higher_order_calls(
+    root: &TSNode,
+    source: &str,
+    nodes: &mut [Node],
+    edges: &mut Vec<Edge>,
+) {
+    let (decorated, decorator_calls, wrapping, new_edges) = {
+        let mut linker = Linker::new(nodes, source);
+        linker.visit(root);
+        (
+            linker.decorated,
+            linker.decorator_calls,
+            linker.wrapping,
+            linker.edges,
+        )
+    };
+
+    for (function, names, targets) in decorated {
+        let node = &mut nodes[function];
+        node.decorators.get_or_insert_with(Vec::new).extend(names);
+        insert_metadata(node, WRAPPED_BY_KEY, serde_json::json!(targets));
+    }
+    let mut seen: HashSet<(String, String)> = edges
+        .iter()
+        .filter(|e| e.kind == EdgeKind::Calls)
+        .map(|e| (e.source_id.clone(), e.target_id.clone()))
+        .collect();
+    for (decorator, function, span) in decorator_calls {
+        let confidence = if wrapping.contains(&decorator) {
+            1.0
+        } else {
+            UNWRAPPED_DECORATOR_CONFIDENCE
+        };
+        let edge = calls_edge(
+            &nodes[decorator].id,
+            &nodes[function].fqn,
+            span,
+            "decorator_wraps",
+            confidence,
+        );
+        if seen.insert((edge.source_id.clone(), edge.target_id.clone())) {
+            edges.push(edge);
+        }
+    }
+    for edge in new_edges {
+        if edge.kind != EdgeKind::Calls
+            || seen.insert((edge.source_id.clone(), edge.target_id.clone()))
+        {
+            edges.push(edge);
+        }
+    }
+}
+
+struct Linker<'a> {
+    nodes: &'a [Node],
+    source: &'a str,
+    /// (name, start line) → function/method node
+    definitions: HashMap<(&'a str, u32), usize>,
+    /// Module-level functions by name
+    functions: HashMap<&'a str, usize>,
+    resolver: FqnResolver,
+    /// (function, decorator names, decorator targets)
+    decorated: Vec<(usize, Vec<String>, Vec<String>)>,
+    /// (local decorator, decorated function, decorator span)
+    decorator_calls: Vec<(usize, usize, Span)>,
+    /// Functions whose inner wrapper uses `functools.wraps`
+    wrapping: HashSet<usize>,
+    edges: Vec<Edge>,
+}
+
+impl<'a> Linker<'a> {
+    fn new(nodes: &'a [Node], source: &'a str) -> Self {
+        let mut definitions = HashMap::new();
+        let mut functions = HashMap::new();
+        for (i, node) in nodes.iter().enumerate() {
+            let Some(name) = node.name.as_deref() else {
+                continue;
+            };
+            match node.kind {
+                NodeKind::Function => {
+                    functions.entry(name).or_insert(i);
+                }
+                NodeKind::Method => {}
+                _ => continue,
+            }
+            definitions.entry((name, node.span.start_line)).or_insert(i);
+        }
+        Self {
+            nodes,
+            source,
+            definitions,
+            functions,
+            resolver: FqnResolver::new(),
+            decorated: Vec::new(),
+            decorator_calls: Vec::new(),
+            wrapping: HashSet::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    fn visit(&mut self, node: &TSNode) {
+        if node.kind() == "function_definition" {
+            // Nested definitions have no IR node of their own
+            if let Some(function) = self.definition(node) {
+                self.link_function(node, function);
+            }
+            return;
+        }
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child);
+            }
+        }
+    }
+
+    fn definition(&self, node: &TSNode) -> Option<usize> {
+        let name = node.child_by_field_name("name")?;
+        let name = &self.source[name.start_byte()..name.end_byte()];
+        let line = node.start_position().row as u32 + 1;
+        self.definitions.get(&(name, line)).copied()
+    }
+
+    fn link_function(&mut self, node: &TSNode, function: usize) {
+        if uses_functools_wraps(node, self.source) {
+            self.wrapping.insert(function);
+        }
+
+        let decorators = extract_decorators(node, self.source);
+        if !decorators.is_empty() {
+            let mut names = Vec::new();
+            let mut targets = Vec::new();
+            for decorator in decorators {
+                let local = self.functions.get(decorator.name.as_str()).copied();
+                let target = match local {
+                    Some(local) => self.nodes[local].fqn.clone(),
+                    None => self.resolver.resolve(&decorator.name),
+                };
+                self.edges.push(
+                    Edge::new(
+                        self.nodes[function].id.clone(),
+                        target.clone(),
+                        EdgeKind::DecoratedWith,
+                    )
+                    .with_span(decorator.span)
+                    .with_provenance(
+                        EdgeProvenance::new(EdgeStage::IrBuild).with_rule("decorator"),
+                    ),
+                );
+                if let Some(local) = local.filter(|&local| local != function) {
+                    self.decorator_calls.push((local, function, decorator.span));
+                }
+                names.push(decorator.name);
+                targets.push(target);
+            }
+            self.decorated.push((function, names, targets));
+        }
+
+        let Some(body) = find_body_node(node) else {
+            return;
+        };
+        for callback in extract_callback_args(&body, self.source) {
+            let Some(target) = self.resolve_callback(function, &callback.callback_name) else {
+                continue;
+            };
+            let confidence = if is_higher_order(&callback.receiver) {
+                HIGHER_ORDER_CONFIDENCE
+            } else {
+                CALLBACK_CONFIDENCE
+            };
+            self.edges.push(calls_edge(
+                &self.nodes[function].id,
+                &self.nodes[target].fqn,
+                callback.span,
+                "callback_arg",
+                confidence,
+            ));
+        }
+    }
+
+    /// Function named by a callback argument: `f`, or `self.m` / `cls.m` in
+    /// the caller's class
+    fn resolve_callback(&self, caller: usize, name: &str) -> Option<usize> {
+        match name.split_once('.') {
+            None => self.functions.get(name).copied(),
+            Some(("self" | "cls", method)) => {
+                let class = self.nodes[caller].parent_id.as_deref()?;
+                self.nodes.iter().position(|n| {
+                    n.kind == NodeKind::Method
+                        && n.name.as_deref() == Some(method)
+                        && n.parent_id.as_deref() == Some(class)
+                })
+            }
+            Some(_) => None,
+        }
+    }
+}
+
+fn calls_edge(source: &str, target: &str, span: Span, rule: &str, confidence: f32) -> Edge {
+    Edge::new(source.to_string(), target.to_string(), EdgeKind::Calls)
+        .with_span(span)
+        .with_provenance(
+            EdgeProvenance::new(EdgeStage::IrBuild)
+                .with_rule(rule)
+                .with_confidence(confidence),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::processor::process_python_file;
+
+    #[test]
+    fn test_decorator_and_callback_edges_in_python_file() {
+        let source = "\
+def logged(func):
+    @functools.wraps(func)
+    def inner(*args):
+        return func(*args)
+    return inner
+
+def parse(line):
+    return line.strip()
+
+@app.route(\"/\")
+@logged
+def index(lines):
+    return list(map(parse, lines))
+";
+        let result = process_python_file(source, "repo", "m.py", "m");
+        let node = |name: &str| {
+            result
+                .nodes
+                .iter()
+                .find(|n| n.name.as_deref() == Some(name))
+                .unwrap()
+        };
+        let (logged, parse, index) = (node("logged"), node("parse"), node("index"));
+        let calls = |from: &Node, to: &Node| {
+            result.edges.iter().find(|e| {
+                e.kind == EdgeKind::Calls && e.source_id == from.id && e.target_id == to.fqn
+            })
+        };
+
+        let wraps = calls(logged, index).expect("decorator → wrapped edge");
+        assert_eq!(
+            wraps.provenance().unwrap().rule.as_deref(),
+            Some("decorator_wraps")
+        );
+        let callback = calls(index, parse).expect("map(parse, ...) edge");
+        assert_eq!(callback.provenance().unwrap().confidence, Some(0.9));
+        assert!(!result.edges.iter().any(|e| e.source_id == index.id
+            && e.target_id.ends_with("lines")
+            && e.kind == EdgeKind::Calls));
+
+        assert_eq!(
+            index.decorators.as_deref(),
+            Some(&["app.route".to_string(), "logged".to_string()][..])
+        );
+        let decorated: Vec<&str> = result
+            .edges
+            .iter()
+            .filter(|e| e.kind == EdgeKind::DecoratedWith && e.source_id == index.id)
+            .map(|e| e.target_id.as_str())
+            .collect();
+        assert_eq!(decorated, vec!["app.route", logged.fqn.as_str()]);
+    }
+}
@@ -255,6 +255,7 @@ pub fn process_with_bfg(
                         exit_block_id: exit_id,
                         blocks: all_blocks,
                         total_statements: func_bfg_visitor.get_blocks().iter().map(|b| b.statement_count).sum(),
+                        exception_flow: func_bfg_visitor.exception_flow().clone(),
                     };
 
                     all_bfg_graphs.push(bfg);
@@ -330,10 +331,10 @@ pub fn traverse_node(
 /// Process function definition node → IR (L1)
 ///
 /// Creates:
-/// - Function/Method node
+/// - Function/Method node (with its parameter names)
 /// - Variable nodes (from function body)
 /// - WRITES edges (function → variables)
-/// - CALLS edges (function → callees, with FQN resolution)
+/// - CALLS edges (function → callees, with FQN resolution and arguments)
 /// - READS edges (function → identifiers)
 ///
 /// # FQN Resolution (SOTA)
@@ -372,16 +373,15 @@ pub fn process_function(
         source_text,
         func_info.return_type,
     )?;
+    builder.set_parameters(&node_id, func_info.params);
 
     // Process function body (variables and calls)
     if let Some(body_node) = find_body_node(node) {
-        #[cfg(feature = "trace")]
-        eprintln!("[TRACE] Found body node for function");
+        tracing::trace!("Found body node for function");
 
         // Extract variables
         let variables = extract_variables_in_block(&body_node, source);
-        #[cfg(feature = "trace")]
-        eprintln!("[TRACE] Extracted {} variables", variables.len());
+        tracing::trace!("Extracted {} variables", variables.len());
 
         for var in variables {
             // Create Variable node
@@ -396,7 +396,7 @@ pub fn process_function(
                     builder.add_writes_edge(node_id.clone(), var_node_id, var.span);
                 }
                 Err(e) => {
-                    eprintln!("Error creating variable node: {}", e);
+                    tracing::warn!("Error creating variable node: {}", e);
                 }
             }
         }
@@ -408,9 +408,26 @@ pub fn process_function(
         for call in calls {
             // Resolve callee name to FQN (e.g., "input" → "builtins.input")
             let callee_fqn = fqn_resolver.resolve(&call.callee_name);
+            let confidence = fqn_resolver.confidence(&call.callee_name);
 
             // Add CALLS edge with FQN
-            builder.add_calls_edge(node_id.clone(), callee_fqn.clone(), call.span);
+            if call.is_async {
+                builder.add_async_calls_edge(
+                    node_id.clone(),
+                    callee_fqn,
+                    call.span,
+                    confidence,
+                    call.arguments,
+                );
+            } else {
+                builder.add_calls_edge_with_confidence(
+                    node_id.clone(),
+                    callee_fqn,
+                    call.span,
+                    confidence,
+                    call.arguments,
+                );
+            }
         }
 
         // Extract identifier reads (READS edges)
@@ -477,13 +494,13 @@ pub fn process_class(node: &TSNode, source: &str, builder: &mut IRBuilder) -> Re
                             "function_definition" => {
                                 // Process as method
                                 if let Err(e) = process_function(&stmt, source, builder, true) {
-                                    eprintln!("Error processing method: {}", e);
+                                    tracing::warn!("Error processing method: {}", e);
                                 }
                             }
                             "class_definition" => {
                                 // Process nested class
                                 if let Err(e) = process_class(&stmt, source, builder) {
-                                    eprintln!("Error processing nested class: {}", e);
+                                    tracing::warn!("Error processing nested class: {}", e);
                                 }
                             }
                             "decorated_definition" => {
@@ -495,7 +512,7 @@ pub fn process_class(node: &TSNode, source: &str, builder: &mut IRBuilder) -> Re
                                                 if let Err(e) = process_function(
                                                     &decorated, source, builder, true,
                                                 ) {
-                                                    eprintln!(
+                                                    tracing::warn!(
                                                         "Error processing decorated method: {}",
                                                         e
                                                     );
@@ -505,7 +522,7 @@ pub fn process_class(node: &TSNode, source: &str, builder: &mut IRBuilder) -> Re
                                                 if let Err(e) =
                                                     process_class(&decorated, source, builder)
                                                 {
-                                                    eprintln!("Error processing decorated nested class: {}", e);
+                                                    tracing::warn!("Error processing decorated nested class: {}", e);
                                                 }
                                             }
                                             _ => {}
@@ -0,0 +1,111 @@
+//! L3: Complexity metrics per function
+//!
+//! Computes `FunctionMetrics` (cyclomatic, cognitive, nesting depth, LOC)
+//! for every BFG and stores them on the function node's metadata under
+//! `METRICS_KEY`. Per-file and per-module statistics are aggregated from
+//! the nodes by `QueryEngine::complexity` and shown on RepoMap nodes.
+//!
+//! `file_metrics` adds the per-file Halstead measures and maintainability
+//! index (`ProcessResult::file_metrics`).
+
+use std::collections::HashMap;
+
+use super::budget::insert_metadata;
+use crate::features::flow_graph::infrastructure::{
+    bfg::BasicFlowGraph,
+    halstead::HalsteadCounts,
+    metrics::{FileMetrics, FunctionMetrics, METRICS_KEY},
+};
+use crate::shared::models::{Node, NodeKind};
+use tree_sitter::Node as TSNode;
+
+/// Attach metrics to the function nodes of `bfg_graphs`, returning them
+pub fn annotate_metrics(
+    nodes: &mut [Node],
+    bfg_graphs: &[BasicFlowGraph],
+    source: &str,
+) -> Vec<FunctionMetrics> {
+    // `bfg.function_id` is the function name (first definition wins)
+    let mut functions: HashMap<&str, usize> = HashMap::new();
+    for (i, node) in nodes.iter().enumerate() {
+        if matches!(node.kind, NodeKind::Function | NodeKind::Method) {
+            if let Some(name) = node.name.as_deref() {
+                functions.entry(name).or_insert(i);
+            }
+        }
+    }
+    let metrics: Vec<(usize, FunctionMetrics)> = bfg_graphs
+        .iter()
+        .filter_map(|bfg| {
+            let &i = functions.get(bfg.function_id.as_str())?;
+            Some((i, FunctionMetrics::from_bfg(bfg, source, nodes[i].span)))
+        })
+        .collect();
+
+    metrics
+        .into_iter()
+        .map(|(i, metrics)| {
+            let value = serde_json::to_value(metrics).unwrap_or_default();
+            insert_metadata(&mut nodes[i], METRICS_KEY, value);
+            metrics
+        })
+        .collect()
+}
+
+/// Metrics of the whole file parsed into `root`
+pub fn file_metrics(
+    root: &TSNode,
+    source: &str,
+    file_path: &str,
+    language: &str,
+    functions: &[FunctionMetrics],
+) -> FileMetrics {
+    let halstead = HalsteadCounts::from_tree(root, source);
+    FileMetrics::new(file_path, language, source, &halstead, functions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::models::span_ref::BlockRef;
+    use crate::shared::models::Span;
+
+    #[test]
+    fn test_annotate_metrics_keeps_existing_metadata() {
+        let source = "def f(x):\n    if x:\n        return 1\n    return 0\n";
+        let mut func = Node::new(
+            "func:f".to_string(),
+            NodeKind::Function,
+            "m.f".to_string(),
+            "m.py".to_string(),
+            Span::new(1, 0, 4, 12),
+        )
+        .with_name("f");
+        func.metadata = Some(r#"{"owner": "core"}"#.to_string());
+        let bfg = BasicFlowGraph {
+            id: "bfg:f".to_string(),
+            function_id: "f".to_string(),
+            entry_block_id: "bfg:f:entry".to_string(),
+            exit_block_id: "bfg:f:exit".to_string(),
+            blocks: vec![BlockRef::new(
+                "bfg:f:block:0".to_string(),
+                "Condition".to_string(),
+                Span::new(2, 7, 2, 8),
+                1,
+            )],
+            total_statements: 1,
+            exception_flow: Default::default(),
+        };
+
+        let mut nodes = vec![func];
+        let functions = annotate_metrics(&mut nodes, &[bfg], source);
+        assert_eq!(functions.len(), 1);
+
+        let metrics = FunctionMetrics::from_node(&nodes[0]).unwrap();
+        assert_eq!(metrics.cyclomatic, 2);
+        assert_eq!(metrics.cognitive, 1);
+        assert_eq!(metrics.max_nesting, 1);
+        assert_eq!(metrics.loc, 4);
+        assert!(nodes[0].metadata.as_deref().unwrap().contains("owner"));
+    }
+}
@@ -2,30 +2,70 @@
 //!
 //! Each stage represents a layer in the analysis pipeline:
 //! - L1-L2: IR generation and occurrences (ir_generation)
+//! - L1: Decorator and callback call edges (higher_order)
 //! - L3: Flow graphs and type resolution (flow_types)
+//! - L3: Complexity metrics per function (metrics)
+//! - L3: Exception edges and raised/caught types per function (exceptions)
+//! - L3: Python type inference and typed call resolution (type_inference)
 //! - L4-L5: Data flow and SSA (data_flow)
+//! - L4-L6: Per-function analysis limits and skip records (budget)
 //! - L6: Advanced analyses - PDG, taint, points-to (advanced)
+//! - L6: Slices of configured criteria (slicing)
 //! - L7: Heap analysis - memory safety, security (heap)
+//! - L33: Per-symbol authorship and churn from git blame (git_blame)
 
 pub mod advanced;
+pub mod budget;
 pub mod data_flow;
+pub mod exceptions;
 pub mod flow_types;
+pub mod git_blame;
 pub mod heap;
+pub mod higher_order;
 pub mod ir_generation;
+pub mod metrics;
+pub mod slicing;
+pub mod type_inference;
 
 // Re-export all IR generation functions
 pub use ir_generation::{
     generate_occurrences, process_class, process_function, process_with_bfg, traverse_node,
 };
 
+// Re-export decorator/callback linking
+pub use higher_order::link_higher_order_calls;
+
 // Re-export flow/type functions
 pub use flow_types::{extract_bfg_graphs, extract_bfg_graphs_with_nodes};
 
+// Re-export complexity metrics
+pub use metrics::{annotate_metrics, file_metrics};
+
+// Re-export exception flow
+pub use exceptions::{annotate_exceptions, build_function_cfg_edges};
+
+// Re-export type inference
+pub use type_inference::run_type_inference;
+
 // Re-export data flow functions
 pub use data_flow::{build_dfg_graphs, build_ssa_graphs, build_ssa_graphs_with_extraction};
 
+// Re-export per-function limits
+pub use budget::{
+    annotate_skipped, function_importance, run_data_flow_stages, run_data_flow_stages_ranked,
+    skip_unsupported, AnalysisLimits, DataFlowOutput, SchedulingPolicy,
+};
+
 // Re-export advanced analysis functions
 pub use advanced::{build_pdg_summaries, run_points_to_analysis, run_taint_analysis};
 
+// Re-export criterion slicing
+pub use slicing::{run_interprocedural_slicing, run_slicing};
+
 // Re-export heap analysis functions
 pub use heap::run_heap_analysis;
+
+// Re-export git blame / churn
+pub use git_blame::attach_git_history;
+#[cfg(feature = "git")]
+pub use git_blame::annotate_git_history;
@@ -0,0 +1,765 @@
+//! L6: Program slicing for configured criteria
+//!
+//! The block-level PDG of `build_pdg_summaries` only reports sizes; its
+//! statements cannot be addressed by variable and line. For each function
+//! named by a `SliceCriterion` this stage builds a statement-level PDG and
+//! slices it with `ProgramSlicer`:
+//!
+//! - statements: source lines with a variable definition or use (DFG nodes)
+//! - data edges: reaching definition → use (DFG def-use edges)
+//! - control edges: condition / loop header → statements of the blocks its
+//!   true branch enters (CFG)
+//!
+//! Slices report the statement spans, resolved against the file source.
+//!
+//! With `SlicingConfig::interprocedural`, the statement PDGs of all files
+//! are stitched at their call sites into a `SystemDependenceGraph` instead,
+//! so slices follow arguments into callees and results back to callers
+//! across files.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use crate::config::{SliceCriterion, SliceKind, SlicingConfig};
+use crate::features::data_flow::infrastructure::dfg::DataFlowGraph;
+use crate::features::flow_graph::infrastructure::{
+    bfg::BasicFlowGraph,
+    cfg::{CFGEdge, CFGEdgeType},
+};
+use crate::features::pdg::infrastructure::pdg::{
+    DependencyType, PDGEdge, PDGNode, ProgramDependenceGraph,
+};
+use crate::features::slicing::infrastructure::sdg::{
+    CallArgument, CallSite, FunctionPdg, SystemDependenceGraph,
+};
+use crate::features::slicing::infrastructure::slicer::{ProgramSlicer, SliceConfig};
+use crate::pipeline::processor::types::{ProcessResult, SliceSummary};
+use crate::shared::models::{EdgeKind, Node, NodeKind, Span};
+
+/// Slices of the criteria whose function is defined in this file
+///
+/// Criteria naming other files' functions are ignored; a criterion whose
+/// variable is not defined or used at its line yields no slice.
+pub fn run_slicing(
+    nodes: &[Node],
+    bfg_graphs: &[BasicFlowGraph],
+    cfg_edges: &[CFGEdge],
+    dfg_graphs: &[DataFlowGraph],
+    source: &str,
+    config: &SlicingConfig,
+) -> Vec<SliceSummary> {
+    let mut slicer = ProgramSlicer::with_config(SliceConfig {
+        max_depth: config.max_depth,
+        max_function_depth: config.max_function_depth,
+        include_control: config.include_control,
+        include_data: config.include_data,
+        interprocedural: config.interprocedural,
+        strict_mode: config.strict_mode,
+    });
+    let lines: Vec<&str> = source.lines().collect();
+
+    let mut summaries = Vec::new();
+    for criterion in &config.criteria {
+        let Some(function) = find_function(nodes, &criterion.function) else {
+            continue;
+        };
+        let Some(bfg) = bfg_graphs
+            .iter()
+            .find(|bfg| is_function_graph(function, &bfg.function_id, bfg_spans(bfg)))
+        else {
+            continue;
+        };
+        let Some(dfg) = dfg_graphs.iter().find(|dfg| {
+            is_function_graph(function, &dfg.function_id, dfg.nodes.iter().map(|n| n.span))
+        }) else {
+            continue;
+        };
+
+        let pdg = statement_pdg(function, bfg, cfg_edges, dfg, &lines);
+        let target = statement_id(function, criterion.line);
+        if !defines_or_uses(&pdg, &target, criterion, &function.file_path) {
+            continue;
+        }
+
+        for &kind in &criterion.kinds {
+            summaries.push(slice(&mut slicer, &pdg, function, criterion, kind, &target));
+        }
+    }
+    summaries
+}
+
+fn slice(
+    slicer: &mut ProgramSlicer,
+    pdg: &ProgramDependenceGraph,
+    function: &Node,
+    criterion: &SliceCriterion,
+    kind: SliceKind,
+    target: &str,
+) -> SliceSummary {
+    let result = match kind {
+        SliceKind::Backward => slicer.backward_slice(pdg, target, None),
+        SliceKind::Forward => slicer.forward_slice(pdg, target, None),
+        SliceKind::Thin => slicer.thin_slice(pdg, target, None),
+    };
+    let spans: BTreeSet<(u32, u32, u32, u32)> = result
+        .slice_nodes
+        .iter()
+        .filter_map(|id| pdg.get_node(id))
+        .map(|n| {
+            (
+                n.span.start_line,
+                n.span.start_col,
+                n.span.end_line,
+                n.span.end_col,
+            )
+        })
+        .collect();
+
+    SliceSummary {
+        function_id: function.id.clone(),
+        criterion: format!("{}@{}", criterion.variable, criterion.line),
+        slice_size: result.slice_nodes.len(),
+        slice_type: kind.as_str().to_string(),
+        file_path: function.file_path.clone(),
+        spans: spans
+            .into_iter()
+            .map(|(start_line, start_col, end_line, end_col)| {
+                Span::new(start_line, start_col, end_line, end_col)
+            })
+            .collect(),
+        external_spans: BTreeMap::new(),
+    }
+}
+
+/// Slices of the criteria over the system dependence graph of `files`
+/// (`(result, source)` per file)
+///
+/// Statements of the criterion's file are reported in `spans`, those of
+/// other files in `external_spans`. Thin slices follow data dependencies
+/// only.
+pub fn run_interprocedural_slicing(
+    files: &[(&ProcessResult, &str)],
+    config: &SlicingConfig,
+) -> Vec<SliceSummary> {
+    let mut functions = Vec::new();
+    for &(result, source) in files {
+        let lines: Vec<&str> = source.lines().collect();
+        let mut parameters: HashMap<&str, Vec<&Node>> = HashMap::new();
+        for node in result
+            .nodes
+            .iter()
+            .filter(|n| n.kind == NodeKind::Parameter)
+        {
+            if let Some(parent) = node.parent_id.as_deref() {
+                parameters.entry(parent).or_default().push(node);
+            }
+        }
+        functions.extend(
+            result
+                .nodes
+                .iter()
+                .filter(|n| matches!(n.kind, NodeKind::Function | NodeKind::Method))
+                .filter_map(|function| {
+                    let children = parameters.get(function.id.as_str());
+                    function_pdg(function, result, children.map(Vec::as_slice), &lines)
+                }),
+        );
+    }
+
+    // (criterion, function_id, file_path, statement)
+    let mut targets = Vec::new();
+    for criterion in &config.criteria {
+        let Some(function) = functions
+            .iter()
+            .find(|f| f.fqn == criterion.function)
+            .or_else(|| functions.iter().find(|f| f.name == criterion.function))
+        else {
+            continue;
+        };
+        let target = format!("{}:{}", function.function_id, criterion.line);
+        if defines_or_uses(&function.pdg, &target, criterion, &function.file_path) {
+            let (id, file_path) = (function.function_id.clone(), function.file_path.clone());
+            targets.push((criterion, id, file_path, target));
+        }
+    }
+    if targets.is_empty() {
+        return Vec::new();
+    }
+
+    let sdg = SystemDependenceGraph::build(functions);
+    let depth = config.max_function_depth;
+    let mut summaries = Vec::new();
+    for (criterion, function_id, file_path, target) in targets {
+        for &kind in &criterion.kinds {
+            let statements = match kind {
+                SliceKind::Backward => {
+                    sdg.backward_slice(&target, config.include_control, config.include_data, depth)
+                }
+                SliceKind::Forward => {
+                    sdg.forward_slice(&target, config.include_control, config.include_data, depth)
+                }
+                SliceKind::Thin => sdg.backward_slice(&target, false, true, depth),
+            };
+            let mut spans = Vec::new();
+            let mut external_spans: BTreeMap<String, Vec<Span>> = BTreeMap::new();
+            for statement in &statements {
+                if statement.file_path == file_path {
+                    spans.push(statement.span);
+                } else {
+                    external_spans
+                        .entry(statement.file_path.clone())
+                        .or_default()
+                        .push(statement.span);
+                }
+            }
+            summaries.push(SliceSummary {
+                function_id: function_id.clone(),
+                criterion: format!("{}@{}", criterion.variable, criterion.line),
+                slice_size: statements.len(),
+                slice_type: kind.as_str().to_string(),
+                file_path: file_path.clone(),
+                spans,
+                external_spans,
+            });
+        }
+    }
+    summaries
+}
+
+/// Whether the criterion's variable is defined or used at `target` (warns
+/// when not)
+fn defines_or_uses(
+    pdg: &ProgramDependenceGraph,
+    target: &str,
+    criterion: &SliceCriterion,
+    file_path: &str,
+) -> bool {
+    let found = pdg.get_node(target).is_some_and(|n| {
+        n.defined_vars.contains(&criterion.variable) || n.used_vars.contains(&criterion.variable)
+    });
+    if !found {
+        tracing::warn!(
+            "[Slicing] {} is not defined or used at {}:{} in {}",
+            criterion.variable,
+            file_path,
+            criterion.line,
+            criterion.function
+        );
+    }
+    found
+}
+
+/// Statement PDG of `function` with its parameters, returns and calls
+fn function_pdg(
+    function: &Node,
+    result: &ProcessResult,
+    parameter_nodes: Option<&[&Node]>,
+    lines: &[&str],
+) -> Option<FunctionPdg> {
+    let bfg = result
+        .bfg_graphs
+        .iter()
+        .find(|bfg| is_function_graph(function, &bfg.function_id, bfg_spans(bfg)))?;
+    let dfg = result.dfg_graphs.iter().find(|dfg| {
+        is_function_graph(function, &dfg.function_id, dfg.nodes.iter().map(|n| n.span))
+    })?;
+    let pdg = statement_pdg(function, bfg, &result.cfg_edges, dfg, lines);
+
+    // Parameter children in order, else the `parameters` list
+    // (`*args: int = 0` -> `args`); receivers are not bound by arguments
+    let mut parameters: Vec<String> = match parameter_nodes {
+        Some(nodes) => {
+            let mut nodes = nodes.to_vec();
+            nodes.sort_by_key(|n| (n.span.start_line, n.span.start_col));
+            nodes.iter().filter_map(|n| n.name.clone()).collect()
+        }
+        None => function
+            .parameters
+            .iter()
+            .flatten()
+            .map(|p| {
+                let end = p.find([':', '=']).unwrap_or(p.len());
+                p[..end].trim().trim_start_matches('*').to_string()
+            })
+            .collect(),
+    };
+    parameters.retain(|p| !(p.is_empty() || matches!(p.as_str(), "self" | "cls")));
+
+    // Incoming values: definitions on the `def` line, or uses no definition
+    // reaches
+    let reached: HashSet<usize> = dfg.def_use_edges.iter().map(|&(_, u)| u).collect();
+    let mut parameter_uses = Vec::new();
+    for (i, node) in dfg.nodes.iter().enumerate() {
+        let incoming = if node.is_definition {
+            node.span.start_line == function.span.start_line
+        } else {
+            !reached.contains(&i)
+        };
+        if incoming && parameters.contains(&node.variable_name) {
+            let statement = statement_id(function, node.span.start_line);
+            let entry = (statement, node.variable_name.clone());
+            if !parameter_uses.contains(&entry) {
+                parameter_uses.push(entry);
+            }
+        }
+    }
+
+    let mut returns = Vec::new();
+    let mut statements = pdg.node_ids();
+    statements.sort();
+    for id in statements {
+        if pdg.get_node(&id).is_some_and(|n| {
+            n.statement.starts_with("return ") || n.statement.starts_with("return(")
+        }) {
+            returns.push(id);
+        }
+    }
+
+    let call_sites = result
+        .edges
+        .iter()
+        .filter(|e| e.kind == EdgeKind::Calls && e.source_id == function.id)
+        .filter_map(|e| {
+            let span = e.span?;
+            let statement = statement_id(function, span.start_line);
+            let used = &pdg.get_node(&statement)?.used_vars;
+            Some(CallSite {
+                arguments: call_arguments(lines, span, used),
+                statement,
+                callee: e.target_id.clone(),
+            })
+        })
+        .collect();
+
+    Some(FunctionPdg {
+        function_id: function.id.clone(),
+        fqn: function.fqn.clone(),
+        name: function.name.clone().unwrap_or_default(),
+        file_path: function.file_path.clone(),
+        parameters,
+        pdg,
+        parameter_uses,
+        returns,
+        call_sites,
+    })
+}
+
+/// Arguments of the call at `span`, from the call text: the variables of
+/// `used` each top-level argument mentions. `None` when the text has no
+/// complete argument list or unpacks arguments (`*args`, `**kwargs`).
+fn call_arguments(lines: &[&str], span: Span, used: &[String]) -> Option<Vec<CallArgument>> {
+    let first = lines.get(span.start_line.checked_sub(1)? as usize)?;
+    let mut text = first
+        .get(span.start_col as usize..)
+        .unwrap_or(first)
+        .to_string();
+    for line in lines
+        .iter()
+        .take(span.end_line as usize)
+        .skip(span.start_line as usize)
+    {
+        text.push('\n');
+        text.push_str(line);
+    }
+
+    let open = text.find('(')?;
+    let mut raw = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+    let mut closed = false;
+    for c in text[open + 1..].chars() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' if depth == 0 => {
+                closed = true;
+                break;
+            }
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                raw.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    if !closed {
+        return None;
+    }
+    raw.push(current);
+
+    let mut arguments = Vec::new();
+    for arg in raw.iter().map(|a| a.trim()).filter(|a| !a.is_empty()) {
+        if arg.starts_with('*') {
+            return None;
+        }
+        let (keyword, expression) = match arg.find('=') {
+            Some(i) if !arg[i + 1..].starts_with('=') && is_identifier(arg[..i].trim()) => {
+                (Some(arg[..i].trim().to_string()), &arg[i + 1..])
+            }
+            _ => (None, arg),
+        };
+        let mentioned: HashSet<&str> = expression
+            .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .collect();
+        arguments.push(CallArgument {
+            keyword,
+            reads: used
+                .iter()
+                .filter(|v| mentioned.contains(v.as_str()))
+                .cloned()
+                .collect(),
+        });
+    }
+    Some(arguments)
+}
+
+fn is_identifier(text: &str) -> bool {
+    !text.is_empty()
+        && !text.starts_with(|c: char| c.is_ascii_digit())
+        && text.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+fn find_function<'a>(nodes: &'a [Node], name: &str) -> Option<&'a Node> {
+    let functions = || {
+        nodes
+            .iter()
+            .filter(|n| matches!(n.kind, NodeKind::Function | NodeKind::Method))
+    };
+    functions()
+        .find(|n| n.fqn == name)
+        .or_else(|| functions().find(|n| n.name.as_deref() == Some(name)))
+}
+
+/// BFGs and DFGs are keyed by function name; same-named functions (methods
+/// of different classes) are told apart by span
+fn is_function_graph(
+    function: &Node,
+    graph_function: &str,
+    mut spans: impl Iterator<Item = Span>,
+) -> bool {
+    function.name.as_deref() == Some(graph_function)
+        && spans.all(|span| {
+            span.start_line >= function.span.start_line && span.end_line <= function.span.end_line
+        })
+}
+
+fn bfg_spans(bfg: &BasicFlowGraph) -> impl Iterator<Item = Span> + '_ {
+    bfg.blocks
+        .iter()
+        .map(|b| b.span_ref.span)
+        .filter(|span| span.start_line > 0)
+}
+
+fn statement_id(function: &Node, line: u32) -> String {
+    format!("{}:{}", function.id, line)
+}
+
+/// One PDG node per line of `function` that defines or uses a variable
+fn statement_pdg(
+    function: &Node,
+    bfg: &BasicFlowGraph,
+    cfg_edges: &[CFGEdge],
+    dfg: &DataFlowGraph,
+    lines: &[&str],
+) -> ProgramDependenceGraph {
+    let mut statements: BTreeMap<u32, (Vec<String>, Vec<String>)> = BTreeMap::new();
+    for node in &dfg.nodes {
+        let (defined, used) = statements.entry(node.span.start_line).or_default();
+        let vars = if node.is_definition { defined } else { used };
+        if !vars.contains(&node.variable_name) {
+            vars.push(node.variable_name.clone());
+        }
+    }
+
+    let mut pdg = ProgramDependenceGraph::new(function.id.clone());
+    for (&line, (defined, used)) in &statements {
+        let text = line
+            .checked_sub(1)
+            .and_then(|i| lines.get(i as usize))
+            .copied()
+            .unwrap_or_default();
+        let indent = text.len() - text.trim_start().len();
+        let span = Span::new(line, indent as u32, line, text.trim_end().len() as u32);
+        pdg.add_node(
+            PDGNode::new(
+                statement_id(function, line),
+                text.trim().to_string(),
+                line,
+                span,
+            )
+            .with_vars(defined.clone(), used.clone()),
+        );
+    }
+
+    for &(def, use_) in &dfg.def_use_edges {
+        let (Some(def), Some(use_)) = (dfg.nodes.get(def), dfg.nodes.get(use_)) else {
+            continue;
+        };
+        if def.span.start_line != use_.span.start_line {
+            pdg.add_edge(PDGEdge {
+                from_node: statement_id(function, def.span.start_line),
+                to_node: statement_id(function, use_.span.start_line),
+                dependency_type: DependencyType::Data,
+                label: Some(def.variable_name.clone()),
+            });
+        }
+    }
+
+    let blocks: BTreeMap<&str, (&str, Span)> = bfg
+        .blocks
+        .iter()
+        .map(|b| (b.id.as_str(), (b.kind.as_str(), b.span_ref.span)))
+        .collect();
+    for edge in cfg_edges
+        .iter()
+        .filter(|e| e.edge_type == CFGEdgeType::True)
+    {
+        let (Some(&(kind, condition)), Some(&(_, body))) = (
+            blocks.get(edge.source_block_id.as_str()),
+            blocks.get(edge.target_block_id.as_str()),
+        ) else {
+            continue;
+        };
+        if !matches!(kind, "Condition" | "BRANCH" | "LoopHeader" | "LOOP") {
+            continue;
+        }
+        for &line in statements.keys() {
+            if line != condition.start_line && line >= body.start_line && line <= body.end_line {
+                pdg.add_edge(PDGEdge {
+                    from_node: statement_id(function, condition.start_line),
+                    to_node: statement_id(function, line),
+                    dependency_type: DependencyType::Control,
+                    label: Some("True".to_string()),
+                });
+            }
+        }
+    }
+    pdg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::data_flow::infrastructure::dfg::build_dfg;
+    use crate::shared::models::span_ref::BlockRef;
+
+    const SOURCE: &str = "\
+def handle(flag):
+    x = 1
+    y = x + 2
+    z = 5
+    if flag:
+        w = y
+    return w
+";
+
+    fn setup() -> (
+        Vec<Node>,
+        Vec<BasicFlowGraph>,
+        Vec<CFGEdge>,
+        Vec<DataFlowGraph>,
+    ) {
+        let function = Node::new(
+            "fn:handle".to_string(),
+            NodeKind::Function,
+            "app.handle".to_string(),
+            "app.py".to_string(),
+            Span::new(1, 0, 7, 12),
+        )
+        .with_name("handle");
+        let var = |name: &str, line: u32| (name.to_string(), Span::new(line, 4, line, 5));
+        let dfg = build_dfg(
+            "handle".to_string(),
+            &[var("x", 2), var("y", 3), var("z", 4), var("w", 6)],
+            &[
+                ("x".to_string(), Span::new(3, 8, 3, 9)),
+                ("flag".to_string(), Span::new(5, 7, 5, 11)),
+                ("y".to_string(), Span::new(6, 12, 6, 13)),
+                ("w".to_string(), Span::new(7, 11, 7, 12)),
+            ],
+        );
+        let bfg = BasicFlowGraph {
+            id: "bfg:handle".to_string(),
+            function_id: "handle".to_string(),
+            entry_block_id: "b0".to_string(),
+            exit_block_id: "b3".to_string(),
+            blocks: vec![
+                BlockRef::new(
+                    "b1".to_string(),
+                    "Statement".to_string(),
+                    Span::new(2, 4, 4, 9),
+                    3,
+                ),
+                BlockRef::new(
+                    "b2".to_string(),
+                    "Condition".to_string(),
+                    Span::new(5, 4, 5, 12),
+                    1,
+                ),
+                BlockRef::new(
+                    "b3".to_string(),
+                    "Statement".to_string(),
+                    Span::new(6, 8, 6, 13),
+                    1,
+                ),
+            ],
+            total_statements: 6,
+            exception_flow: Default::default(),
+        };
+        let cfg_edges = vec![CFGEdge {
+            source_block_id: "b2".to_string(),
+            target_block_id: "b3".to_string(),
+            edge_type: CFGEdgeType::True,
+        }];
+        (vec![function], vec![bfg], cfg_edges, vec![dfg])
+    }
+
+    fn lines(summary: &SliceSummary) -> Vec<u32> {
+        summary.spans.iter().map(|s| s.start_line).collect()
+    }
+
+    #[test]
+    fn test_slices_configured_criteria() {
+        let (nodes, bfgs, cfg_edges, dfgs) = setup();
+        let config = SlicingConfig::default()
+            .criterion(SliceCriterion::new("app.handle", "w", 6))
+            .criterion(SliceCriterion::new("handle", "x", 2).with_kinds(vec![SliceKind::Forward]))
+            .criterion(SliceCriterion::new("app.other", "x", 2));
+
+        let slices = run_slicing(&nodes, &bfgs, &cfg_edges, &dfgs, SOURCE, &config);
+        let kinds: Vec<_> = slices.iter().map(|s| s.slice_type.as_str()).collect();
+        assert_eq!(kinds, vec!["backward", "forward", "thin", "forward"]);
+
+        // w depends on y (data), x (data) and the `if` (control); z is unrelated
+        assert_eq!(lines(&slices[0]), vec![2, 3, 5, 6]);
+        assert_eq!(slices[0].criterion, "w@6");
+        assert_eq!(slices[0].spans[1], Span::new(3, 4, 3, 13));
+        assert_eq!(lines(&slices[1]), vec![6, 7]);
+        assert_eq!(lines(&slices[2]), vec![2, 3, 6]);
+        assert_eq!(lines(&slices[3]), vec![2, 3, 6, 7]);
+        assert_eq!(slices[3].function_id, "fn:handle");
+    }
+
+    #[test]
+    fn test_skips_unresolved_criterion() {
+        let (nodes, bfgs, cfg_edges, dfgs) = setup();
+        let config = SlicingConfig::default().criterion(SliceCriterion::new("handle", "x", 4));
+
+        assert!(run_slicing(&nodes, &bfgs, &cfg_edges, &dfgs, SOURCE, &config).is_empty());
+    }
+
+    const VIEWS: &str = "\
+def handle(req):
+    user = req.user
+    limit = 10
+    row = fetch(user, limit)
+    sink(row)
+";
+
+    const DB: &str = "\
+def fetch(key, n):
+    q = key.strip()
+    return q
+";
+
+    /// ProcessResult of a one-function file
+    fn file_result(
+        function: Node,
+        defs: &[(&str, u32)],
+        uses: &[(&str, u32)],
+        calls: Vec<crate::shared::models::Edge>,
+    ) -> ProcessResult {
+        let name = function.name.clone().unwrap();
+        let spans = |vars: &[(&str, u32)]| -> Vec<(String, Span)> {
+            vars.iter()
+                .map(|&(v, line)| (v.to_string(), Span::new(line, 4, line, 40)))
+                .collect()
+        };
+        let bfg = BasicFlowGraph {
+            id: format!("bfg:{}", name),
+            function_id: name.clone(),
+            entry_block_id: "b0".to_string(),
+            exit_block_id: "b1".to_string(),
+            blocks: vec![BlockRef::new(
+                "b1".to_string(),
+                "Statement".to_string(),
+                Span::new(2, 4, function.span.end_line, 13),
+                3,
+            )],
+            total_statements: 3,
+            exception_flow: Default::default(),
+        };
+        ProcessResult {
+            dfg_graphs: vec![build_dfg(name, &spans(defs), &spans(uses))],
+            bfg_graphs: vec![bfg],
+            nodes: vec![function],
+            edges: calls,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_interprocedural_slices_cross_files() {
+        use crate::shared::models::Edge;
+
+        let function = |id: &str, fqn: &str, file: &str, params: &[&str], end: u32| {
+            let name = fqn.rsplit('.').next().unwrap();
+            Node::new(
+                id.to_string(),
+                NodeKind::Function,
+                fqn.to_string(),
+                file.to_string(),
+                Span::new(1, 0, end, 12),
+            )
+            .with_name(name)
+            .with_parameters(params.iter().map(|p| p.to_string()).collect())
+        };
+        let views = file_result(
+            function("fn:handle", "app.views.handle", "app/views.py", &["req"], 5),
+            &[("user", 2), ("limit", 3), ("row", 4)],
+            &[("req", 2), ("user", 4), ("limit", 4), ("row", 5)],
+            vec![
+                Edge::new(
+                    "fn:handle".to_string(),
+                    "app.db.fetch".to_string(),
+                    EdgeKind::Calls,
+                )
+                .with_span(Span::new(4, 10, 4, 28)),
+                Edge::new("fn:handle".to_string(), "sink".to_string(), EdgeKind::Calls)
+                    .with_span(Span::new(5, 4, 5, 13)),
+            ],
+        );
+        let db = file_result(
+            function(
+                "fn:fetch",
+                "app.db.fetch",
+                "app/db.py",
+                &["key", "n: int = 0"],
+                3,
+            ),
+            &[("q", 2)],
+            &[("key", 2), ("q", 3)],
+            Vec::new(),
+        );
+        let config = SlicingConfig {
+            interprocedural: true,
+            ..SlicingConfig::default()
+        }
+        .criterion(SliceCriterion::new("app.views.handle", "row", 5));
+
+        let slices = run_interprocedural_slicing(&[(&views, VIEWS), (&db, DB)], &config);
+        assert_eq!(slices.len(), 3);
+
+        // row depends on user through fetch's body; limit does not reach the result
+        let backward = &slices[0];
+        assert_eq!(backward.slice_type, "backward");
+        assert_eq!(lines(backward), vec![2, 4, 5]);
+        let external: Vec<u32> = backward.external_spans["app/db.py"]
+            .iter()
+            .map(|s| s.start_line)
+            .collect();
+        assert_eq!(external, vec![2, 3]);
+        assert_eq!(backward.slice_size, 5);
+        assert!(slices[1].external_spans.is_empty());
+    }
+}
@@ -0,0 +1,612 @@
+//! L3: Python type inference from annotations, literals and data flow
+//!
+//! Types every variable definition: its annotation if present, otherwise the
+//! assigned expression's type. Literals, constructor calls of builtin and
+//! local classes, and calls of functions with an annotated or inferred return
+//! type are typed directly; names take the type of their reaching definition
+//! in the DFG (or of the annotated parameter). Method calls on a receiver of a
+//! local class type are then retargeted to the class's method.
+//!
+//! Off unless `stages.type_inference` is set.
+
+use std::collections::{HashMap, HashSet};
+use tree_sitter::{Node as TSNode, Parser};
+
+use crate::features::data_flow::infrastructure::dfg::DataFlowGraph;
+use crate::features::type_resolution::domain::TypeEntity;
+use crate::features::type_resolution::infrastructure::type_resolver::TypeResolver;
+use crate::pipeline::processor::helpers::node_to_span;
+use crate::shared::models::{Edge, EdgeKind, EdgeProvenance, EdgeStage, Node, NodeKind, Span};
+
+/// Base classes followed when looking up a method
+const MAX_BASE_DEPTH: usize = 8;
+
+/// (line, column) of a definition or use
+type Position = (u32, u32);
+
+/// Type the variables of a Python file and resolve calls on typed receivers
+///
+/// Sets `declared_type_id` on Variable nodes and rewrites the target of
+/// CALLS edges `x.m()` whose receiver has a local class type. Returns the
+/// type entities referenced by the nodes.
+pub fn run_type_inference(
+    nodes: &mut [Node],
+    edges: &mut [Edge],
+    dfg_graphs: &[DataFlowGraph],
+    content: &str,
+    repo_id: &str,
+) -> Vec<TypeEntity> {
+    let mut parser = Parser::new();
+    if parser
+        .set_language(&tree_sitter_python::language())
+        .is_err()
+    {
+        return Vec::new();
+    }
+    let Some(tree) = parser.parse(content, None) else {
+        return Vec::new();
+    };
+
+    let (variable_types, typed_calls) = {
+        let mut inference = Inference::new(nodes, dfg_graphs, content);
+        inference.visit(&tree.root_node());
+        inference.run()
+    };
+
+    let mut resolver = TypeResolver::new(repo_id.to_string());
+    for node in nodes.iter().filter(|n| n.kind == NodeKind::Class) {
+        if let Some(name) = &node.name {
+            resolver.register_local_class(name.clone(), node.id.clone());
+        }
+    }
+
+    let mut entities: Vec<TypeEntity> = Vec::new();
+    let mut seen = HashSet::new();
+    for (i, raw) in variable_types {
+        let entity = resolver.resolve_type(&raw);
+        nodes[i].declared_type_id = Some(entity.id.clone());
+        if seen.insert(entity.id.clone()) {
+            entities.push(entity);
+        }
+    }
+
+    let mut calls: HashMap<(&str, Span), Vec<usize>> = HashMap::new();
+    for (i, edge) in edges.iter().enumerate() {
+        if let (EdgeKind::Calls, Some(span)) = (edge.kind, edge.span) {
+            calls
+                .entry((edge.source_id.as_str(), span))
+                .or_default()
+                .push(i);
+        }
+    }
+    let retargets: Vec<(usize, usize, f32)> = typed_calls
+        .into_iter()
+        .flat_map(|call| {
+            let confidence = resolver.resolve_type(&call.receiver_type).confidence();
+            calls
+                .get(&(nodes[call.caller].id.as_str(), call.span))
+                .into_iter()
+                .flatten()
+                .map(move |&edge| (edge, call.method, confidence))
+        })
+        .collect();
+
+    for (edge, method, confidence) in retargets {
+        let edge = &mut edges[edge];
+        edge.target_id = nodes[method].fqn.clone();
+        edge.metadata
+            .get_or_insert_with(Default::default)
+            .provenance = Some(
+            EdgeProvenance::new(EdgeStage::TypeResolution)
+                .with_rule("typed_call")
+                .with_confidence(confidence),
+        );
+    }
+    entities
+}
+
+/// Method call whose receiver has a local class type
+struct TypedCall {
+    caller: usize,
+    span: Span,
+    method: usize,
+    receiver_type: String,
+}
+
+/// Per-function inference input
+struct Scope<'a> {
+    body: Option<TSNode<'a>>,
+    /// Parameter name → annotated type (`self`/`cls` → enclosing class)
+    params: HashMap<String, String>,
+    /// Assignment target → (annotation, assigned value)
+    assignments: HashMap<Position, (Option<String>, Option<TSNode<'a>>)>,
+    /// Use → reaching definition, from the function's DFG
+    reaching: HashMap<Position, Position>,
+}
+
+struct Inference<'a> {
+    nodes: &'a [Node],
+    dfg_graphs: &'a [DataFlowGraph],
+    source: &'a str,
+    /// (name, start line) → function/method node
+    definitions: HashMap<(&'a str, u32), usize>,
+    /// Module-level functions and classes by name
+    functions: HashMap<&'a str, usize>,
+    classes: HashMap<&'a str, usize>,
+    scopes: HashMap<usize, Scope<'a>>,
+    /// (function, definition) → inferred type; `None` while in progress
+    def_types: HashMap<(usize, Position), Option<String>>,
+    returns: HashMap<usize, Option<String>>,
+}
+
+impl<'a> Inference<'a> {
+    fn new(nodes: &'a [Node], dfg_graphs: &'a [DataFlowGraph], source: &'a str) -> Self {
+        let mut definitions = HashMap::new();
+        let mut functions = HashMap::new();
+        let mut classes = HashMap::new();
+        for (i, node) in nodes.iter().enumerate() {
+            let Some(name) = node.name.as_deref() else {
+                continue;
+            };
+            match node.kind {
+                NodeKind::Function => {
+                    functions.entry(name).or_insert(i);
+                }
+                NodeKind::Method => {}
+                NodeKind::Class => {
+                    classes.entry(name).or_insert(i);
+                    continue;
+                }
+                _ => continue,
+            }
+            definitions.entry((name, node.span.start_line)).or_insert(i);
+        }
+        Self {
+            nodes,
+            dfg_graphs,
+            source,
+            definitions,
+            functions,
+            classes,
+            scopes: HashMap::new(),
+            def_types: HashMap::new(),
+            returns: HashMap::new(),
+        }
+    }
+
+    fn text(&self, node: &TSNode) -> &'a str {
+        &self.source[node.start_byte()..node.end_byte()]
+    }
+
+    fn visit(&mut self, node: &TSNode<'a>) {
+        if node.kind() == "function_definition" {
+            // Nested definitions have no IR node of their own
+            if let Some(function) = self.definition(node) {
+                let scope = self.scope(node, function);
+                self.scopes.insert(function, scope);
+            }
+            return;
+        }
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child);
+            }
+        }
+    }
+
+    fn definition(&self, node: &TSNode) -> Option<usize> {
+        let name = self.text(&node.child_by_field_name("name")?);
+        let line = node.start_position().row as u32 + 1;
+        self.definitions.get(&(name, line)).copied()
+    }
+
+    fn scope(&self, def: &TSNode<'a>, function: usize) -> Scope<'a> {
+        let mut params = HashMap::new();
+        if let Some(parameters) = def.child_by_field_name("parameters") {
+            let class = self.enclosing_class(function);
+            let mut cursor = parameters.walk();
+            for (i, param) in parameters.named_children(&mut cursor).enumerate() {
+                let (name, ty) = match param.kind() {
+                    "identifier" => (Some(param), None),
+                    "typed_parameter" => (param.named_child(0), param.child_by_field_name("type")),
+                    "typed_default_parameter" => (
+                        param.child_by_field_name("name"),
+                        param.child_by_field_name("type"),
+                    ),
+                    _ => continue,
+                };
+                let Some(name) = name.filter(|n| n.kind() == "identifier") else {
+                    continue;
+                };
+                let name = self.text(&name);
+                let ty = match (ty, class) {
+                    (Some(ty), _) => self.text(&ty).to_string(),
+                    (None, Some(class)) if i == 0 && matches!(name, "self" | "cls") => {
+                        class.to_string()
+                    }
+                    _ => continue,
+                };
+                params.insert(name.to_string(), ty);
+            }
+        }
+
+        let body = def.child_by_field_name("body");
+        let mut assignments = HashMap::new();
+        if let Some(body) = &body {
+            self.collect_assignments(body, &mut assignments);
+        }
+
+        // `DataFlowGraph::function_id` is the function name (first definition wins)
+        let name = self.nodes[function].name.as_deref().unwrap_or_default();
+        let owns_dfg = self.nodes.iter().position(|n| {
+            matches!(n.kind, NodeKind::Function | NodeKind::Method)
+                && n.name.as_deref() == Some(name)
+        }) == Some(function);
+        let mut reaching = HashMap::new();
+        if let Some(dfg) = self
+            .dfg_graphs
+            .iter()
+            .find(|g| owns_dfg && g.function_id == name)
+        {
+            for &(def, used) in &dfg.def_use_edges {
+                let (def, used) = (&dfg.nodes[def].span, &dfg.nodes[used].span);
+                reaching.insert(
+                    (used.start_line, used.start_col),
+                    (def.start_line, def.start_col),
+                );
+            }
+        }
+
+        Scope {
+            body,
+            params,
+            assignments,
+            reaching,
+        }
+    }
+
+    /// Class name of a method's enclosing class
+    fn enclosing_class(&self, function: usize) -> Option<&'a str> {
+        let nodes = self.nodes;
+        let parent = nodes[function].parent_id.as_deref()?;
+        nodes
+            .iter()
+            .find(|n| n.kind == NodeKind::Class && n.id == parent)?
+            .name
+            .as_deref()
+    }
+
+    fn collect_assignments(
+        &self,
+        node: &TSNode<'a>,
+        assignments: &mut HashMap<Position, (Option<String>, Option<TSNode<'a>>)>,
+    ) {
+        match node.kind() {
+            "function_definition" | "class_definition" | "lambda" => return,
+            "assignment" => {
+                if let Some(left) = node
+                    .child_by_field_name("left")
+                    .filter(|l| l.kind() == "identifier")
+                {
+                    let span = node_to_span(&left);
+                    let annotation = node
+                        .child_by_field_name("type")
+                        .map(|t| self.text(&t).to_string());
+                    assignments.insert(
+                        (span.start_line, span.start_col),
+                        (annotation, node.child_by_field_name("right")),
+                    );
+                }
+            }
+            _ => {}
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.collect_assignments(&child, assignments);
+        }
+    }
+
+    /// Inferred variable types and typed method calls
+    fn run(mut self) -> (Vec<(usize, String)>, Vec<TypedCall>) {
+        let mut variable_types = Vec::new();
+        let nodes = self.nodes;
+        for (i, node) in nodes.iter().enumerate() {
+            if node.kind != NodeKind::Variable {
+                continue;
+            }
+            let Some(function) = node
+                .parent_id
+                .as_deref()
+                .and_then(|parent| self.scopes.keys().copied().find(|&f| nodes[f].id == parent))
+            else {
+                continue;
+            };
+            let position = (node.span.start_line, node.span.start_col);
+            if let Some(ty) = self.def_type(function, position) {
+                variable_types.push((i, ty));
+            }
+        }
+
+        let mut typed_calls = Vec::new();
+        let mut functions: Vec<usize> = self.scopes.keys().copied().collect();
+        functions.sort_unstable();
+        for function in functions {
+            let Some(body) = self.scopes[&function].body else {
+                continue;
+            };
+            let mut calls = Vec::new();
+            collect_calls(&body, &mut calls);
+            for call in calls {
+                if let Some(typed) = self.typed_call(function, &call) {
+                    typed_calls.push(typed);
+                }
+            }
+        }
+        (variable_types, typed_calls)
+    }
+
+    fn typed_call(&mut self, function: usize, call: &TSNode<'a>) -> Option<TypedCall> {
+        let callee = call.child_by_field_name("function")?;
+        if callee.kind() != "attribute" {
+            return None;
+        }
+        let receiver_type = self.expr_type(function, &callee.child_by_field_name("object")?)?;
+        let method = self.text(&callee.child_by_field_name("attribute")?);
+        let class = *self.classes.get(class_name(&receiver_type)?)?;
+        let method = self.find_method(class, method, 0)?;
+        Some(TypedCall {
+            caller: function,
+            span: node_to_span(call),
+            method,
+            receiver_type,
+        })
+    }
+
+    /// Method `name` of `class` or of its local base classes
+    fn find_method(&self, class: usize, name: &str, depth: usize) -> Option<usize> {
+        let class_node = &self.nodes[class];
+        let own = self.nodes.iter().position(|n| {
+            n.kind == NodeKind::Method
+                && n.name.as_deref() == Some(name)
+                && n.parent_id.as_deref() == Some(class_node.id.as_str())
+        });
+        if own.is_some() || depth >= MAX_BASE_DEPTH {
+            return own;
+        }
+        class_node
+            .base_classes
+            .iter()
+            .flatten()
+            .filter_map(|base| self.classes.get(base.as_str()))
+            .find_map(|&base| self.find_method(base, name, depth + 1))
+    }
+
+    fn def_type(&mut self, function: usize, position: Position) -> Option<String> {
+        if let Some(ty) = self.def_types.get(&(function, position)) {
+            return ty.clone();
+        }
+        self.def_types.insert((function, position), None);
+        let (annotation, value) = self
+            .scopes
+            .get(&function)?
+            .assignments
+            .get(&position)
+            .cloned()?;
+        let ty = match annotation {
+            Some(annotation) => Some(annotation),
+            None => value.and_then(|value| self.expr_type(function, &value)),
+        };
+        self.def_types.insert((function, position), ty.clone());
+        ty
+    }
+
+    fn expr_type(&mut self, function: usize, expr: &TSNode<'a>) -> Option<String> {
+        let ty = match expr.kind() {
+            "integer" => "int",
+            "float" => "float",
+            "string" | "concatenated_string" => "str",
+            "true" | "false" | "comparison_operator" | "not_operator" => "bool",
+            "none" => "None",
+            "list" | "list_comprehension" => "list",
+            "dictionary" | "dictionary_comprehension" => "dict",
+            "set" | "set_comprehension" => "set",
+            "tuple" => "tuple",
+            "parenthesized_expression" => return self.expr_type(function, &expr.named_child(0)?),
+            "identifier" => return self.name_type(function, expr),
+            "call" => return self.call_type(function, expr),
+            "binary_operator" | "conditional_expression" => {
+                // Both operands (or branches) must agree
+                let (left, right) = match expr.kind() {
+                    "binary_operator" => (
+                        expr.child_by_field_name("left")?,
+                        expr.child_by_field_name("right")?,
+                    ),
+                    _ => (expr.named_child(0)?, expr.named_child(2)?),
+                };
+                let left = self.expr_type(function, &left)?;
+                return (self.expr_type(function, &right)? == left).then_some(left);
+            }
+            _ => return None,
+        };
+        Some(ty.to_string())
+    }
+
+    fn name_type(&mut self, function: usize, name: &TSNode<'a>) -> Option<String> {
+        let span = node_to_span(name);
+        let scope = self.scopes.get(&function)?;
+        match scope.reaching.get(&(span.start_line, span.start_col)) {
+            Some(&def) => self.def_type(function, def),
+            None => scope.params.get(self.text(name)).cloned(),
+        }
+    }
+
+    fn call_type(&mut self, function: usize, call: &TSNode<'a>) -> Option<String> {
+        let callee = call.child_by_field_name("function")?;
+        match callee.kind() {
+            "identifier" => {
+                let name = self.text(&callee);
+                if self.classes.contains_key(name) || is_builtin_constructor(name) {
+                    return Some(name.to_string());
+                }
+                let target = *self.functions.get(name)?;
+                self.return_type(target)
+            }
+            "attribute" => {
+                let typed = self.typed_call(function, call)?;
+                self.return_type(typed.method)
+            }
+            _ => None,
+        }
+    }
+
+    /// Annotated return type, else the type all `return` values agree on
+    fn return_type(&mut self, function: usize) -> Option<String> {
+        if let Some(annotated) = &self.nodes[function].return_type {
+            return Some(annotated.clone());
+        }
+        if let Some(ty) = self.returns.get(&function) {
+            return ty.clone();
+        }
+        self.returns.insert(function, None);
+        let body = self.scopes.get(&function)?.body?;
+        let mut values = Vec::new();
+        collect_returns(&body, &mut values);
+        let mut ty = None;
+        for (i, value) in values.iter().enumerate() {
+            let value_type = self.expr_type(function, value);
+            if value_type.is_none() || (i > 0 && value_type != ty) {
+                ty = None;
+                break;
+            }
+            ty = value_type;
+        }
+        self.returns.insert(function, ty.clone());
+        ty
+    }
+}
+
+/// Class named by a type: `User`, `Optional[User]`, `User | None`
+fn class_name(ty: &str) -> Option<&str> {
+    let ty = ty
+        .strip_prefix("Optional[")
+        .and_then(|inner| inner.strip_suffix(']'))
+        .unwrap_or(ty);
+    let ty = ty.split('|').map(str::trim).find(|part| *part != "None")?;
+    Some(ty.split('[').next().unwrap_or(ty).trim())
+}
+
+fn is_builtin_constructor(name: &str) -> bool {
+    matches!(
+        name,
+        "int" | "float" | "str" | "bool" | "bytes" | "list" | "dict" | "set" | "tuple"
+    )
+}
+
+/// Calls in a function body, nested definitions excluded
+fn collect_calls<'a>(node: &TSNode<'a>, calls: &mut Vec<TSNode<'a>>) {
+    match node.kind() {
+        "function_definition" | "class_definition" | "lambda" => return,
+        "call" => calls.push(*node),
+        _ => {}
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_calls(&child, calls);
+    }
+}
+
+/// Returned expressions of a function body, nested definitions excluded
+fn collect_returns<'a>(node: &TSNode<'a>, values: &mut Vec<TSNode<'a>>) {
+    match node.kind() {
+        "function_definition" | "class_definition" | "lambda" => return,
+        "return_statement" => {
+            if let Some(value) = node.named_child(0) {
+                values.push(value);
+            }
+            return;
+        }
+        _ => {}
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_returns(&child, values);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::type_resolution::domain::TypeResolutionLevel;
+    use crate::pipeline::processor::process_python_file;
+
+    #[test]
+    fn test_types_flow_to_method_calls() {
+        let source = "\
+class User:
+    def save(self):
+        return True
+
+class Admin(User):
+    pass
+
+def load(name: str) -> Admin:
+    return Admin()
+
+def make():
+    return User()
+
+def run(limit: int):
+    count = 0
+    title = \"users\"
+    ratio = count + limit
+    user = make()
+    alias = user
+    alias.save()
+    admin = load(title)
+    admin.save()
+";
+        let mut result = process_python_file(source, "repo", "m.py", "m");
+        let entities = run_type_inference(
+            &mut result.nodes,
+            &mut result.edges,
+            &result.dfg_graphs,
+            source,
+            "repo",
+        );
+        let type_of = |name: &str| {
+            let node = result
+                .nodes
+                .iter()
+                .find(|n| n.kind == NodeKind::Variable && n.name.as_deref() == Some(name))
+                .unwrap();
+            let id = node.declared_type_id.as_deref()?;
+            entities.iter().find(|e| e.id == id)
+        };
+
+        let count = type_of("count").unwrap();
+        assert_eq!(count.raw, "int");
+        assert_eq!(count.resolution_level, TypeResolutionLevel::Builtin);
+        assert_eq!(type_of("title").unwrap().raw, "str");
+        assert_eq!(type_of("ratio").unwrap().raw, "int");
+        let alias = type_of("alias").unwrap();
+        assert_eq!(alias.raw, "User");
+        assert_eq!(alias.resolution_level, TypeResolutionLevel::Local);
+        assert_eq!(type_of("admin").unwrap().raw, "Admin");
+
+        let save = result
+            .nodes
+            .iter()
+            .find(|n| n.kind == NodeKind::Method && n.name.as_deref() == Some("save"))
+            .unwrap();
+        let typed: Vec<_> = result
+            .edges
+            .iter()
+            .filter(|e| e.kind == EdgeKind::Calls && e.target_id == save.fqn)
+            .collect();
+        // `alias.save()` directly, `admin.save()` through the base class
+        assert_eq!(typed.len(), 2);
+        let provenance = typed[0].provenance().unwrap();
+        assert_eq!(provenance.rule.as_deref(), Some("typed_call"));
+        assert_eq!(provenance.confidence, Some(0.95));
+    }
+}
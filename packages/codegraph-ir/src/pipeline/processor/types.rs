@@ -4,13 +4,17 @@
 //! Moved from processor_legacy.rs during Phase 4 cleanup.
 
 use crate::features::data_flow::infrastructure::dfg::DataFlowGraph;
-use crate::features::flow_graph::infrastructure::{bfg::BasicFlowGraph, cfg::CFGEdge};
+use crate::features::flow_graph::infrastructure::{
+    bfg::BasicFlowGraph, cfg::CFGEdge, metrics::FileMetrics,
+};
 use crate::features::heap_analysis::{
     FunctionEscapeInfo, MemorySafetyIssue, SecurityVulnerability,
 };
 use crate::features::ssa::infrastructure::ssa::SSAGraph;
 use crate::features::type_resolution::domain::TypeEntity;
-use crate::shared::models::{Edge, Node, Occurrence};
+use crate::shared::models::{Edge, Node, Occurrence, Span};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
 
 /// Process result (L1-L7 complete pipeline)
 ///
@@ -22,7 +26,7 @@ use crate::shared::models::{Edge, Node, Occurrence};
 /// - **L4-L5**: Data flow + SSA (DFG, SSA graphs)
 /// - **L6**: Advanced analyses (PDG, taint, points-to, slicing)
 /// - **L7**: Heap analysis (memory safety, security)
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProcessResult {
     // L1-L2: IR
     pub nodes: Vec<Node>,
@@ -54,6 +58,16 @@ pub struct ProcessResult {
     /// Used by concurrency analyzer to reduce FP by 40-60%
     pub escape_info: Vec<FunctionEscapeInfo>,
 
+    /// L4-L6 analyses not run for a function (size/time limits, unsupported constructs)
+    pub skipped_analyses: Vec<SkippedAnalysis>,
+
+    /// L4-L6 functions analyzed within the time budget
+    pub coverage: AnalysisCoverage,
+
+    /// L3 Halstead / maintainability metrics of the file
+    #[serde(default)]
+    pub file_metrics: Option<FileMetrics>,
+
     pub errors: Vec<String>,
 }
 
@@ -84,6 +98,9 @@ impl ProcessResult {
             memory_safety_issues: Vec::new(),
             security_vulnerabilities: Vec::new(),
             escape_info: Vec::new(),
+            skipped_analyses: Vec::new(),
+            coverage: AnalysisCoverage::default(),
+            file_metrics: None,
             errors,
         }
     }
@@ -97,7 +114,7 @@ impl ProcessResult {
 /// - Petgraph-based construction (O(V+E) slicing)
 /// - Combined control + data dependencies
 /// - Entry/exit node tracking
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PDGSummary {
     pub function_id: String,
     pub node_count: usize,
@@ -118,7 +135,7 @@ pub struct PDGSummary {
 /// - Sanitizer detection (reduces false positives)
 /// - Quick check optimization (2-phase analysis)
 /// - Context-sensitive interprocedural analysis
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaintSummary {
     pub function_id: String,
     pub sources_found: usize,
@@ -132,15 +149,28 @@ pub struct TaintSummary {
 
 /// Slice summary
 ///
-/// Results from program slicing (computed on-demand via API).
+/// A slice of a configured criterion (`SlicingConfig::criteria`), computed
+/// during L6.
 ///
 /// # Usage
-/// Slicing is not computed during pipeline - use PDG API for on-demand slicing.
-#[derive(Debug, Clone)]
+/// Slices of other criteria are computed on demand via the PDG API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SliceSummary {
     pub function_id: String,
+    /// `variable@line`
     pub criterion: String,
     pub slice_size: usize,
+    /// "backward", "forward" or "thin"
+    #[serde(default)]
+    pub slice_type: String,
+    #[serde(default)]
+    pub file_path: String,
+    /// Statements in the slice, in source order
+    #[serde(default)]
+    pub spans: Vec<Span>,
+    /// Interprocedural slices: statements in other files, by file path
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub external_spans: BTreeMap<String, Vec<Span>>,
 }
 
 /// Points-to analysis summary
@@ -152,7 +182,7 @@ pub struct SliceSummary {
 /// - **Precise**: Andersen (O(N³), highly precise)
 /// - **Hybrid**: Auto-switch based on size
 /// - **Auto**: Selects best mode based on input size
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PointsToSummary {
     /// Number of variables analyzed
     pub variables_count: usize,
@@ -167,3 +197,145 @@ pub struct PointsToSummary {
     /// Analysis duration in milliseconds
     pub duration_ms: f64,
 }
+
+/// Per-function analysis that was not run
+///
+/// Also recorded on the function node's metadata (`skipped_analyses`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkippedAnalysis {
+    /// Function node ID (BFG function name if no node matches)
+    pub function_id: String,
+    pub function_name: String,
+    /// 1-based line of the function (0 if unknown)
+    pub line: u32,
+    pub stage: SkippedStage,
+    pub reason: SkipReason,
+}
+
+impl SkippedAnalysis {
+    /// Human-readable message (e.g. for diagnostics)
+    pub fn message(&self) -> String {
+        format!(
+            "{} skipped for `{}`: {}",
+            self.stage.as_str().to_uppercase(),
+            self.function_name,
+            self.reason.detail()
+        )
+    }
+}
+
+/// Stage a skip record refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SkippedStage {
+    Dfg,
+    Ssa,
+    Pdg,
+}
+
+impl SkippedStage {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SkippedStage::Dfg => "dfg",
+            SkippedStage::Ssa => "ssa",
+            SkippedStage::Pdg => "pdg",
+        }
+    }
+}
+
+/// Why an analysis was skipped
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SkipReason {
+    /// Function exceeds a size limit (`metric` is e.g. "blocks")
+    Size {
+        #[serde(deserialize_with = "deserialize_metric")]
+        metric: &'static str,
+        actual: usize,
+        limit: usize,
+    },
+    /// The file's time budget ran out before this function
+    Time { budget_ms: u64 },
+    /// Construct (or language) the analysis does not support
+    UnsupportedConstruct(String),
+}
+
+/// Size metrics the budget stage reports
+const SIZE_METRICS: &[&str] = &["blocks", "variables"];
+
+/// `metric` back to its static name (cached results are owned bytes)
+fn deserialize_metric<'de, D>(deserializer: D) -> Result<&'static str, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let metric = String::deserialize(deserializer)?;
+    SIZE_METRICS
+        .iter()
+        .find(|known| **known == metric)
+        .copied()
+        .ok_or_else(|| serde::de::Error::custom(format!("unknown size metric `{}`", metric)))
+}
+
+impl SkipReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SkipReason::Size { .. } => "size",
+            SkipReason::Time { .. } => "time",
+            SkipReason::UnsupportedConstruct(_) => "unsupported-construct",
+        }
+    }
+
+    pub fn detail(&self) -> String {
+        match self {
+            SkipReason::Size {
+                metric,
+                actual,
+                limit,
+            } => format!("{} {} exceeds limit of {}", actual, metric, limit),
+            SkipReason::Time { budget_ms } => {
+                format!("time budget of {}ms exhausted", budget_ms)
+            }
+            SkipReason::UnsupportedConstruct(construct) => format!("unsupported {}", construct),
+        }
+    }
+}
+
+/// How much of a file's L4-L6 work fit in its budget
+///
+/// Importance is the functions' centrality (RepoMap PageRank, max = 1.0), so
+/// `importance_ratio` says how much of the important code was covered even
+/// when `function_ratio` is low.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AnalysisCoverage {
+    pub functions_total: usize,
+    pub functions_analyzed: usize,
+    pub importance_total: f64,
+    pub importance_analyzed: f64,
+    /// The time budget ran out before every function was reached
+    pub budget_exhausted: bool,
+}
+
+impl AnalysisCoverage {
+    /// Analyzed share of functions (1.0 when there are none)
+    pub fn function_ratio(&self) -> f64 {
+        if self.functions_total == 0 {
+            return 1.0;
+        }
+        self.functions_analyzed as f64 / self.functions_total as f64
+    }
+
+    /// Analyzed share of importance (falls back to `function_ratio`)
+    pub fn importance_ratio(&self) -> f64 {
+        if self.importance_total <= 0.0 {
+            return self.function_ratio();
+        }
+        self.importance_analyzed / self.importance_total
+    }
+
+    /// Accumulate another file's coverage
+    pub fn merge(&mut self, other: &AnalysisCoverage) {
+        self.functions_total += other.functions_total;
+        self.functions_analyzed += other.functions_analyzed;
+        self.importance_total += other.importance_total;
+        self.importance_analyzed += other.importance_analyzed;
+        self.budget_exhausted |= other.budget_exhausted;
+    }
+}
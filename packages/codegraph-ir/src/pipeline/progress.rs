@@ -0,0 +1,238 @@
+//! Pipeline progress reporting
+//!
+//! `IRIndexingOrchestrator::with_progress` registers a `ProgressReporter`
+//! that is called while a run executes:
+//!
+//! - `Started` once files are read and stages planned
+//! - `FileParsed` per L1 file (parsed or taken from the IR cache)
+//! - `StageCompleted` per stage, L1 included
+//! - `Finished` at the end of the run
+//!
+//! Every event comes with a `Progress` estimate for progress bars and ETA.
+//! L1 counts for half of the run, the remaining stages share the other half
+//! equally. Events are delivered from Rayon workers while files and stages run
+//! in parallel, so reporters must be cheap (or hand events to a channel).
+//!
+//! ```ignore
+//! let orchestrator = IRIndexingOrchestrator::new(config).with_progress(Arc::new(
+//!     |event: &ProgressEvent<'_>, progress: &Progress| {
+//!         if let ProgressEvent::StageCompleted { stage, duration, .. } = event {
+//!             println!("{} done in {:?} ({:.0}%)", stage, duration, progress.percent());
+//!         }
+//!     },
+//! ));
+//! ```
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Share of the run attributed to L1 in `Progress::fraction`
+pub const L1_WEIGHT: f64 = 0.5;
+
+/// Something that happened during a run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProgressEvent<'a> {
+    /// Files read, stages planned (L1 included)
+    Started { files: usize, stages: usize },
+    /// One file through L1
+    FileParsed { file_path: &'a str, cached: bool },
+    /// One stage finished, successfully or not
+    StageCompleted {
+        stage: &'a str,
+        duration: Duration,
+        succeeded: bool,
+    },
+    /// Run finished
+    Finished { duration: Duration },
+}
+
+impl ProgressEvent<'_> {
+    /// Event name (`started`, `file_parsed`, `stage_completed`, `finished`)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ProgressEvent::Started { .. } => "started",
+            ProgressEvent::FileParsed { .. } => "file_parsed",
+            ProgressEvent::StageCompleted { .. } => "stage_completed",
+            ProgressEvent::Finished { .. } => "finished",
+        }
+    }
+}
+
+/// Estimated progress of a run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Progress {
+    pub files_parsed: usize,
+    pub total_files: usize,
+    pub stages_completed: usize,
+    pub total_stages: usize,
+    /// Estimated completed share of the run (0.0-1.0)
+    pub fraction: f64,
+    pub elapsed: Duration,
+    /// Estimated time remaining (None before anything completed)
+    pub eta: Option<Duration>,
+}
+
+impl Progress {
+    /// `fraction` as a percentage
+    pub fn percent(&self) -> f64 {
+        self.fraction * 100.0
+    }
+}
+
+/// Receives progress events during a run
+pub trait ProgressReporter: Send + Sync {
+    fn on_progress(&self, event: &ProgressEvent<'_>, progress: &Progress);
+}
+
+impl<F> ProgressReporter for F
+where
+    F: Fn(&ProgressEvent<'_>, &Progress) + Send + Sync,
+{
+    fn on_progress(&self, event: &ProgressEvent<'_>, progress: &Progress) {
+        self(event, progress)
+    }
+}
+
+/// Counts completed work of one run and reports it (no-op without reporter)
+pub(crate) struct ProgressTracker<'a> {
+    reporter: Option<&'a dyn ProgressReporter>,
+    start: Instant,
+    total_files: AtomicUsize,
+    files_parsed: AtomicUsize,
+    total_stages: AtomicUsize,
+    stages_completed: AtomicUsize,
+}
+
+impl<'a> ProgressTracker<'a> {
+    pub(crate) fn new(reporter: Option<&'a dyn ProgressReporter>) -> Self {
+        Self {
+            reporter,
+            start: Instant::now(),
+            total_files: AtomicUsize::new(0),
+            files_parsed: AtomicUsize::new(0),
+            total_stages: AtomicUsize::new(0),
+            stages_completed: AtomicUsize::new(0),
+        }
+    }
+
+    pub(crate) fn started(&self, files: usize, stages: usize) {
+        self.total_files.store(files, Ordering::Relaxed);
+        self.total_stages.store(stages, Ordering::Relaxed);
+        self.report(&ProgressEvent::Started { files, stages }, false);
+    }
+
+    pub(crate) fn file_parsed(&self, file_path: &str, cached: bool) {
+        if self.reporter.is_some() {
+            self.files_parsed.fetch_add(1, Ordering::Relaxed);
+            self.report(&ProgressEvent::FileParsed { file_path, cached }, false);
+        }
+    }
+
+    pub(crate) fn stage_completed(&self, stage: &str, duration: Duration, succeeded: bool) {
+        self.stages_completed.fetch_add(1, Ordering::Relaxed);
+        let event = ProgressEvent::StageCompleted {
+            stage,
+            duration,
+            succeeded,
+        };
+        self.report(&event, false);
+    }
+
+    pub(crate) fn finished(&self) {
+        let duration = self.start.elapsed();
+        self.report(&ProgressEvent::Finished { duration }, true);
+    }
+
+    fn report(&self, event: &ProgressEvent<'_>, done: bool) {
+        if let Some(reporter) = self.reporter {
+            reporter.on_progress(event, &self.progress(done));
+        }
+    }
+
+    fn progress(&self, done: bool) -> Progress {
+        let total_files = self.total_files.load(Ordering::Relaxed);
+        let files_parsed = self.files_parsed.load(Ordering::Relaxed).min(total_files);
+        let total_stages = self.total_stages.load(Ordering::Relaxed);
+        let stages_completed = self.stages_completed.load(Ordering::Relaxed);
+        let elapsed = self.start.elapsed();
+
+        let fraction = if done {
+            1.0
+        } else {
+            estimate(files_parsed, total_files, stages_completed, total_stages)
+        };
+        let eta = (fraction > 0.0).then(|| elapsed.mul_f64((1.0 - fraction) / fraction));
+
+        Progress {
+            files_parsed,
+            total_files,
+            stages_completed,
+            total_stages,
+            fraction,
+            elapsed,
+            eta,
+        }
+    }
+}
+
+/// Completed share: L1 by files, later stages by count
+fn estimate(files_parsed: usize, total_files: usize, stages: usize, total_stages: usize) -> f64 {
+    let l1 = if total_files == 0 || stages > 0 {
+        1.0
+    } else {
+        files_parsed as f64 / total_files as f64
+    };
+    if total_stages <= 1 {
+        return if stages > 0 { 1.0 } else { l1 };
+    }
+    let later = stages.saturating_sub(1) as f64 / (total_stages - 1) as f64;
+    (L1_WEIGHT * l1 + (1.0 - L1_WEIGHT) * later).min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_estimate_weights_l1_and_stages() {
+        assert_eq!(estimate(0, 10, 0, 5), 0.0);
+        assert_eq!(estimate(5, 10, 0, 5), 0.25);
+        // L1 completed, then 2 of 4 later stages
+        assert_eq!(estimate(10, 10, 1, 5), 0.5);
+        assert_eq!(estimate(10, 10, 3, 5), 0.75);
+        assert_eq!(estimate(10, 10, 5, 5), 1.0);
+        // L1 only
+        assert_eq!(estimate(3, 4, 0, 1), 0.75);
+    }
+
+    #[test]
+    fn test_tracker_reports_events_in_order() {
+        let events = Mutex::new(Vec::new());
+        let reporter = |event: &ProgressEvent<'_>, progress: &Progress| {
+            events
+                .lock()
+                .unwrap()
+                .push((event.kind(), progress.files_parsed, progress.fraction));
+        };
+        let tracker = ProgressTracker::new(Some(&reporter));
+        tracker.started(2, 2);
+        tracker.file_parsed("a.py", false);
+        tracker.file_parsed("b.py", true);
+        tracker.stage_completed("L1_IR_Build", Duration::from_millis(5), true);
+        tracker.stage_completed("L2_Chunking", Duration::from_millis(1), true);
+        tracker.finished();
+
+        assert_eq!(
+            events.into_inner().unwrap(),
+            vec![
+                ("started", 0, 0.0),
+                ("file_parsed", 1, 0.25),
+                ("file_parsed", 2, 0.5),
+                ("stage_completed", 2, 0.5),
+                ("stage_completed", 2, 1.0),
+                ("finished", 2, 1.0),
+            ]
+        );
+    }
+}
@@ -0,0 +1,258 @@
+//! Pipeline Replay Bundles
+//!
+//! Record mode captures the exact inputs of a pipeline run (file list, file
+//! contents + content hashes, pipeline config) into a single JSON bundle.
+//! Replay re-executes the pipeline from that bundle without touching the
+//! original repository, so customer-reported analysis bugs can be reproduced
+//! deterministically.
+//!
+//! ```text
+//! Record:  repo ──scan/read──► inputs ──► ReplayBundle::save(bundle.json)
+//!                                 └────► pipeline (normal run)
+//!
+//! Replay:  bundle.json ──load + verify──► inputs ──► pipeline (same order/config)
+//! ```
+//!
+//! # Example
+//! ```ignore
+//! // Record
+//! let orchestrator = IRIndexingOrchestrator::new(config).with_recording("bundle.json");
+//! orchestrator.execute()?;
+//!
+//! // Replay
+//! let bundle = ReplayBundle::load("bundle.json")?;
+//! let result = IRIndexingOrchestrator::from_replay(bundle)?.execute()?;
+//! ```
+
+use super::{E2EPipelineConfig, IndexingMode, RepoInfo};
+use crate::config::PipelineConfig;
+use crate::shared::models::CodegraphError;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Current replay bundle format version
+pub const REPLAY_BUNDLE_VERSION: u32 = 1;
+
+/// A single recorded input file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedFile {
+    /// Path relative to the repository root
+    pub path: String,
+    /// Module path derived at record time (e.g. "src.utils")
+    pub module_path: String,
+    /// BLAKE3 hash of `content` (hex)
+    pub content_hash: String,
+    /// Exact file content
+    pub content: String,
+}
+
+/// Recorded pipeline inputs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayBundle {
+    /// Bundle format version
+    pub version: u32,
+    /// Crate version that recorded the bundle
+    pub recorder_version: String,
+    /// Repository name/identifier
+    pub repo_name: String,
+    /// Original repository root (informational; replay never reads from it)
+    pub repo_root: PathBuf,
+    /// Indexing mode
+    pub mode: IndexingMode,
+    /// Language filter used for the run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language_filter: Option<Vec<String>>,
+    /// Memory-mapped IO threshold
+    pub mmap_threshold_bytes: usize,
+    /// Pipeline config (RFC-001 YAML v1 export)
+    pub config_yaml: String,
+    /// Input files in processing order
+    pub files: Vec<RecordedFile>,
+}
+
+impl ReplayBundle {
+    /// Capture inputs from a config and the `(file_path, module_path, content)`
+    /// triples the orchestrator is about to process
+    pub fn record(
+        config: &E2EPipelineConfig,
+        inputs: &[(String, String, String)],
+    ) -> Result<Self, CodegraphError> {
+        let config_yaml = config
+            .pipeline_config
+            .to_yaml()
+            .map_err(|e| CodegraphError::config(format!("Failed to export config: {}", e)))?;
+
+        let files = inputs
+            .iter()
+            .map(|(path, module_path, content)| RecordedFile {
+                path: path.clone(),
+                module_path: module_path.clone(),
+                content_hash: hash_content(content),
+                content: content.clone(),
+            })
+            .collect();
+
+        Ok(Self {
+            version: REPLAY_BUNDLE_VERSION,
+            recorder_version: env!("CARGO_PKG_VERSION").to_string(),
+            repo_name: config.repo_info.repo_name.clone(),
+            repo_root: config.repo_info.repo_root.clone(),
+            mode: config.mode,
+            language_filter: config.repo_info.language_filter.clone(),
+            mmap_threshold_bytes: config.mmap_threshold_bytes,
+            config_yaml,
+            files,
+        })
+    }
+
+    /// Write bundle as JSON
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), CodegraphError> {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json).map_err(|e| {
+            CodegraphError::io(format!(
+                "Failed to write replay bundle {}: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+
+    /// Read bundle from JSON and verify it
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, CodegraphError> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|e| {
+            CodegraphError::io(format!(
+                "Failed to read replay bundle {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        let bundle: Self = serde_json::from_str(&json)?;
+        bundle.verify()?;
+        Ok(bundle)
+    }
+
+    /// Check version and content hashes (detects hand-edited or truncated bundles)
+    pub fn verify(&self) -> Result<(), CodegraphError> {
+        if self.version != REPLAY_BUNDLE_VERSION {
+            return Err(CodegraphError::config(format!(
+                "Unsupported replay bundle version {} (supported: {})",
+                self.version, REPLAY_BUNDLE_VERSION
+            )));
+        }
+        for file in &self.files {
+            if hash_content(&file.content) != file.content_hash {
+                return Err(
+                    CodegraphError::config("Replay bundle content hash mismatch")
+                        .with_file(file.path.clone()),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Rebuild the pipeline config the bundle was recorded with
+    pub fn to_config(&self) -> Result<E2EPipelineConfig, CodegraphError> {
+        let pipeline_config = PipelineConfig::from_yaml_str(&self.config_yaml, "replay-bundle")
+            .map_err(|e| CodegraphError::config(format!("Invalid bundle config: {}", e)))?;
+
+        Ok(E2EPipelineConfig {
+            pipeline_config,
+            repo_info: RepoInfo {
+                repo_root: self.repo_root.clone(),
+                repo_name: self.repo_name.clone(),
+                file_paths: Some(self.files.iter().map(|f| PathBuf::from(&f.path)).collect()),
+                language_filter: self.language_filter.clone(),
+            },
+            mode: self.mode,
+            mmap_threshold_bytes: self.mmap_threshold_bytes,
+            ir_cache_dir: None,
+            no_cache: true,
+            base_commit: None,
+            perf_record_path: None,
+        })
+    }
+
+    /// Inputs in the orchestrator's `(file_path, module_path, content)` form
+    pub fn inputs(&self) -> Vec<(String, String, String)> {
+        self.files
+            .iter()
+            .map(|f| (f.path.clone(), f.module_path.clone(), f.content.clone()))
+            .collect()
+    }
+
+    /// Stable identifier of the recorded inputs (config + every file hash)
+    pub fn fingerprint(&self) -> String {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(self.config_yaml.as_bytes());
+        for file in &self.files {
+            hasher.update(file.path.as_bytes());
+            hasher.update(file.content_hash.as_bytes());
+        }
+        hasher.finalize().to_hex().to_string()
+    }
+
+    /// Total recorded bytes
+    pub fn total_bytes(&self) -> usize {
+        self.files.iter().map(|f| f.content.len()).sum()
+    }
+}
+
+fn hash_content(content: &str) -> String {
+    blake3::hash(content.as_bytes()).to_hex().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn sample_inputs() -> Vec<(String, String, String)> {
+        vec![
+            (
+                "src/b.py".to_string(),
+                "src.b".to_string(),
+                "def b():\n    pass\n".to_string(),
+            ),
+            (
+                "src/a.py".to_string(),
+                "src.a".to_string(),
+                "import b\n".to_string(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_record_save_load_roundtrip() {
+        let config = E2EPipelineConfig::default().repo_name("demo".to_string());
+        let bundle = ReplayBundle::record(&config, &sample_inputs()).unwrap();
+
+        // Processing order is preserved so replay walks files exactly like the recorded run
+        assert_eq!(bundle.files[0].path, "src/b.py");
+        assert_eq!(bundle.files[1].path, "src/a.py");
+
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("bundle.json");
+        bundle.save(&path).unwrap();
+
+        let loaded = ReplayBundle::load(&path).unwrap();
+        assert_eq!(loaded.files, bundle.files);
+        assert_eq!(loaded.fingerprint(), bundle.fingerprint());
+
+        let config = loaded.to_config().unwrap();
+        assert_eq!(config.repo_info.repo_name, "demo");
+        assert_eq!(config.repo_info.file_paths.unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_verify_detects_tampering() {
+        let config = E2EPipelineConfig::default();
+        let mut bundle = ReplayBundle::record(&config, &sample_inputs()).unwrap();
+        assert!(bundle.verify().is_ok());
+
+        bundle.files[0].content.push_str("x = 1\n");
+        let err = bundle.verify().unwrap_err();
+        assert_eq!(err.file_path.as_deref(), Some("src/b.py"));
+    }
+}
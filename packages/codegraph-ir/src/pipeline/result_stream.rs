@@ -0,0 +1,169 @@
+//! Streaming pipeline results
+//!
+//! `IRIndexingOrchestrator::execute_streaming` hands each stage's results to a
+//! `ResultSink` in fixed-size batches as soon as the stage completes, so
+//! consumers can persist them incrementally instead of converting one
+//! `E2EPipelineResult` holding the whole repository at the end.
+//!
+//! Batches borrow from the pipeline's own buffers; a sink that needs the data
+//! beyond the call must copy (or serialize) it.
+
+use super::dag::StageId;
+use super::end_to_end_result::{Chunk, ClonePairSummary, Symbol};
+use crate::shared::models::{Edge, Node, Occurrence, Result};
+
+/// Items per batch when the caller does not choose
+pub const DEFAULT_BATCH_SIZE: usize = 10_000;
+
+/// One batch of a stage's results
+#[derive(Debug, Clone, Copy)]
+pub enum ResultBatch<'a> {
+    Nodes(&'a [Node]),
+    Edges(&'a [Edge]),
+    Occurrences(&'a [Occurrence]),
+    Chunks(&'a [Chunk]),
+    Symbols(&'a [Symbol]),
+    ClonePairs(&'a [ClonePairSummary]),
+}
+
+impl ResultBatch<'_> {
+    /// Result kind, matching the keys of the non-streaming result dict
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ResultBatch::Nodes(_) => "nodes",
+            ResultBatch::Edges(_) => "edges",
+            ResultBatch::Occurrences(_) => "occurrences",
+            ResultBatch::Chunks(_) => "chunks",
+            ResultBatch::Symbols(_) => "symbols",
+            ResultBatch::ClonePairs(_) => "clone_pairs",
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            ResultBatch::Nodes(items) => items.len(),
+            ResultBatch::Edges(items) => items.len(),
+            ResultBatch::Occurrences(items) => items.len(),
+            ResultBatch::Chunks(items) => items.len(),
+            ResultBatch::Symbols(items) => items.len(),
+            ResultBatch::ClonePairs(items) => items.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Receives results while the pipeline runs
+///
+/// An error from the sink aborts the pipeline and is returned by
+/// `execute_streaming`.
+pub trait ResultSink {
+    fn on_batch(&mut self, stage: StageId, batch: ResultBatch<'_>) -> Result<()>;
+
+    /// Called once a stage's batches have all been delivered
+    fn on_stage_complete(&mut self, _stage: StageId) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<F> ResultSink for F
+where
+    F: FnMut(StageId, ResultBatch<'_>) -> Result<()>,
+{
+    fn on_batch(&mut self, stage: StageId, batch: ResultBatch<'_>) -> Result<()> {
+        self(stage, batch)
+    }
+}
+
+/// Splits stage outputs into batches for a sink
+pub(crate) struct ResultStream<'s> {
+    sink: &'s mut dyn ResultSink,
+    batch_size: usize,
+}
+
+impl<'s> ResultStream<'s> {
+    pub(crate) fn new(sink: &'s mut dyn ResultSink, batch_size: usize) -> Self {
+        Self {
+            sink,
+            batch_size: batch_size.max(1),
+        }
+    }
+
+    /// Deliver `items` in batches (nothing for an empty slice)
+    pub(crate) fn emit<'b, T>(
+        &mut self,
+        stage: StageId,
+        items: &'b [T],
+        wrap: impl Fn(&'b [T]) -> ResultBatch<'b>,
+    ) -> Result<()> {
+        for batch in items.chunks(self.batch_size) {
+            self.sink.on_batch(stage, wrap(batch))?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn complete(&mut self, stage: StageId) -> Result<()> {
+        self.sink.on_stage_complete(stage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::models::{NodeKind, Span};
+
+    fn node(i: usize) -> Node {
+        Node::new(
+            format!("n{}", i),
+            NodeKind::Function,
+            format!("m.f{}", i),
+            "m.py".to_string(),
+            Span::new(1, 0, 2, 0),
+        )
+    }
+
+    #[test]
+    fn test_emit_splits_into_batches() {
+        let nodes: Vec<Node> = (0..5).map(node).collect();
+        let mut seen = Vec::new();
+        let mut sink = |stage: StageId, batch: ResultBatch<'_>| -> Result<()> {
+            seen.push((stage, batch.kind(), batch.len()));
+            Ok(())
+        };
+
+        let mut stream = ResultStream::new(&mut sink, 2);
+        stream
+            .emit(StageId::L1IrBuild, &nodes, ResultBatch::Nodes)
+            .unwrap();
+        stream
+            .emit(StageId::L1IrBuild, &[] as &[Edge], ResultBatch::Edges)
+            .unwrap();
+
+        assert_eq!(
+            seen,
+            vec![
+                (StageId::L1IrBuild, "nodes", 2),
+                (StageId::L1IrBuild, "nodes", 2),
+                (StageId::L1IrBuild, "nodes", 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sink_error_stops_emission() {
+        let nodes: Vec<Node> = (0..4).map(node).collect();
+        let mut calls = 0;
+        let mut sink = |_: StageId, _: ResultBatch<'_>| -> Result<()> {
+            calls += 1;
+            Err(crate::shared::models::CodegraphError::storage("disk full"))
+        };
+
+        let mut stream = ResultStream::new(&mut sink, 1);
+        assert!(stream
+            .emit(StageId::L1IrBuild, &nodes, ResultBatch::Nodes)
+            .is_err());
+        assert_eq!(calls, 1);
+    }
+}
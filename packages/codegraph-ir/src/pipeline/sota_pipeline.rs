@@ -497,7 +497,7 @@ impl IRPipelineDAG {
             Ok(order) => order.into_iter().map(|idx| graph[idx]).collect(),
             Err(cycle) => {
                 let cycle_node = graph[cycle.node_id()];
-                eprintln!(
+                tracing::error!(
                     "[CRITICAL] Pipeline DAG contains a cycle involving stage: {:?}",
                     cycle_node
                 );
@@ -0,0 +1,147 @@
+//! Stage output cache for DAG stages
+//!
+//! `IRIndexingOrchestrator::with_stage_cache` registers a `StageCache` that
+//! is consulted before each DAG stage (L2 onwards) runs:
+//!
+//! - `lookup` returns the stage's stored output: the stage is not executed
+//!   and its output is used as if it had just run (dependent stages included)
+//! - otherwise the stage runs and a successful output is handed to `store`
+//!
+//! L1 is never looked up; unchanged files already come from the IR cache.
+//! The cache decides what identifies a run (repository, snapshot, config):
+//! the orchestrator only names the stage. Lookups happen from Rayon workers
+//! while independent stages run in parallel.
+//!
+//! ```ignore
+//! let result = IRIndexingOrchestrator::new(config)
+//!     .with_stage_cache(Arc::new(MemoryStageCache::default()))
+//!     .execute()?;
+//! ```
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use super::dag::StageId;
+use super::end_to_end_orchestrator::StageOutput;
+
+/// Stored outputs of DAG stages
+pub trait StageCache: Send + Sync {
+    /// Output of `stage` to use instead of executing it
+    fn lookup(&self, stage: StageId) -> Option<StageOutput>;
+
+    /// Output of `stage` after it executed successfully
+    fn store(&self, stage: StageId, output: &StageOutput);
+}
+
+/// In-memory cache for repeated runs over the same inputs in one process
+#[derive(Default)]
+pub struct MemoryStageCache {
+    outputs: RwLock<HashMap<StageId, StageOutput>>,
+}
+
+impl MemoryStageCache {
+    /// Stages with a stored output
+    pub fn stages(&self) -> Vec<StageId> {
+        let outputs = self.outputs.read().unwrap_or_else(|e| e.into_inner());
+        let mut stages: Vec<StageId> = outputs.keys().copied().collect();
+        stages.sort_by_key(|s| s.name());
+        stages
+    }
+
+    /// Drop every stored output (inputs or config changed)
+    pub fn clear(&self) {
+        self.outputs
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+impl StageCache for MemoryStageCache {
+    fn lookup(&self, stage: StageId) -> Option<StageOutput> {
+        let outputs = self.outputs.read().unwrap_or_else(|e| e.into_inner());
+        outputs.get(&stage).cloned()
+    }
+
+    fn store(&self, stage: StageId, output: &StageOutput) {
+        self.outputs
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(stage, output.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::pipeline_config::StageId as ConfigStageId;
+    use crate::config::{PipelineConfig, Preset};
+    use crate::pipeline::end_to_end_config::IndexingMode;
+    use crate::pipeline::{E2EPipelineConfig, IRIndexingOrchestrator};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Counts lookups that hit
+    #[derive(Default)]
+    struct CountingCache {
+        inner: MemoryStageCache,
+        hits: AtomicUsize,
+    }
+
+    impl StageCache for CountingCache {
+        fn lookup(&self, stage: StageId) -> Option<StageOutput> {
+            let output = self.inner.lookup(stage);
+            if output.is_some() {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+            }
+            output
+        }
+
+        fn store(&self, stage: StageId, output: &StageOutput) {
+            self.inner.store(stage, output);
+        }
+    }
+
+    #[test]
+    fn test_cached_stages_are_not_executed_again() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("app.py"),
+            "def handler():\n    return helper()\n\ndef helper():\n    return 1\n",
+        )
+        .unwrap();
+        let pipeline = PipelineConfig::preset(Preset::Fast)
+            .stages(|s| {
+                s.enable(ConfigStageId::Chunking)
+                    .enable(ConfigStageId::Symbols)
+            })
+            .build()
+            .unwrap();
+        let config = E2EPipelineConfig::with_config(pipeline)
+            .repo_root(dir.path().to_path_buf())
+            .repo_name("cache-test".to_string())
+            .indexing_mode(IndexingMode::Full);
+
+        let cache = Arc::new(CountingCache::default());
+        let run = || {
+            IRIndexingOrchestrator::new(config.clone())
+                .with_stage_cache(cache.clone())
+                .execute()
+                .unwrap()
+        };
+
+        let first = run();
+        assert_eq!(cache.hits.load(Ordering::Relaxed), 0);
+        let stored = cache.inner.stages();
+        assert!(stored.contains(&StageId::L2Chunking));
+        assert!(stored.contains(&StageId::L5Symbols));
+
+        let second = run();
+        assert_eq!(cache.hits.load(Ordering::Relaxed), stored.len());
+        assert_eq!(second.chunks.len(), first.chunks.len());
+        assert_eq!(second.symbols.len(), first.symbols.len());
+
+        cache.inner.clear();
+        assert!(cache.inner.stages().is_empty());
+    }
+}
@@ -237,12 +237,12 @@ impl PipelineDAG {
             Err(cycle) => {
                 // If we detect a cycle, it's an internal bug - but handle gracefully
                 let cycle_node = graph[cycle.node_id()];
-                eprintln!(
+                tracing::error!(
                     "[CRITICAL] Pipeline DAG contains a cycle involving stage: {:?}",
                     cycle_node
                 );
-                eprintln!("This indicates a bug in pipeline stage dependency configuration.");
-                eprintln!("Attempting to continue with partial execution order (may produce incorrect results).");
+                tracing::error!("This indicates a bug in pipeline stage dependency configuration.");
+                tracing::warn!("Attempting to continue with partial execution order (may produce incorrect results).");
 
                 // Return stages in registration order as fallback
                 enabled_stages.to_vec()
@@ -0,0 +1,251 @@
+//! Pipeline tracing spans and OpenTelemetry export
+//!
+//! Progress of a run is reported as `tracing` events. With the `trace`
+//! feature the orchestrator also opens spans:
+//!
+//! ```text
+//! pipeline{repo, mode}
+//! ├── stage{stage="L1_IR_Build"}
+//! │   └── batch{index, files, bytes}      (one per L1 file batch)
+//! ├── stage{stage="L2_Chunking"}
+//! └── ...
+//! ```
+//!
+//! Nothing is printed until a subscriber is installed. `init` installs a
+//! stderr formatter filtered by `RUST_LOG`; with the `otlp` feature and
+//! `OTEL_EXPORTER_OTLP_ENDPOINT` set, spans are also exported over OTLP/HTTP
+//! (stage and batch timings in Grafana Tempo or any OTLP backend):
+//!
+//! ```ignore
+//! let _telemetry = telemetry::init(&TelemetryConfig::from_env())?;
+//! orchestrator.execute()?;
+//! // dropping the guard flushes pending spans
+//! ```
+
+#[cfg(feature = "trace")]
+use crate::shared::models::CodegraphError;
+use crate::shared::models::Result;
+
+/// Default `RUST_LOG` filter
+pub const DEFAULT_FILTER: &str = "info";
+
+/// Default `service.name` of exported spans
+pub const DEFAULT_SERVICE_NAME: &str = "codegraph-ir";
+
+/// Span a parallel stage is opened under (crosses Rayon workers)
+#[derive(Debug, Clone)]
+pub struct ParentSpan {
+    #[cfg(feature = "trace")]
+    span: tracing::Span,
+}
+
+/// Entered span, closed on drop (no-op without the `trace` feature)
+#[must_use = "the span closes when the guard is dropped"]
+#[derive(Debug)]
+pub struct SpanGuard {
+    #[cfg(feature = "trace")]
+    _span: tracing::span::EnteredSpan,
+}
+
+/// Span of the current thread, for stages run on other threads
+pub fn current() -> ParentSpan {
+    ParentSpan {
+        #[cfg(feature = "trace")]
+        span: tracing::Span::current(),
+    }
+}
+
+/// Span of one pipeline run
+#[allow(unused_variables)]
+pub fn pipeline_span(repo: &str, mode: &str) -> SpanGuard {
+    SpanGuard {
+        #[cfg(feature = "trace")]
+        _span: tracing::info_span!("pipeline", repo, mode).entered(),
+    }
+}
+
+/// Span of one stage under `parent`
+#[allow(unused_variables)]
+pub fn stage_span(parent: &ParentSpan, stage: &str) -> SpanGuard {
+    SpanGuard {
+        #[cfg(feature = "trace")]
+        _span: tracing::info_span!(parent: &parent.span, "stage", stage).entered(),
+    }
+}
+
+/// Span of one L1 file batch
+#[allow(unused_variables)]
+pub fn batch_span(index: usize, files: usize, bytes: usize) -> SpanGuard {
+    SpanGuard {
+        #[cfg(feature = "trace")]
+        _span: tracing::info_span!("batch", index, files, bytes).entered(),
+    }
+}
+
+/// Subscriber settings for `init`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TelemetryConfig {
+    /// `EnvFilter` directives (e.g. `info,codegraph_ir::pipeline=debug`)
+    pub filter: String,
+    /// OTLP/HTTP collector (e.g. `http://localhost:4318`), `otlp` feature only
+    pub otlp_endpoint: Option<String>,
+    /// `service.name` resource of exported spans
+    pub service_name: String,
+}
+
+impl TelemetryConfig {
+    /// From `RUST_LOG`, `OTEL_EXPORTER_OTLP_ENDPOINT` and `OTEL_SERVICE_NAME`
+    pub fn from_env() -> Self {
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.trim().is_empty());
+        Self {
+            filter: var("RUST_LOG").unwrap_or_else(|| DEFAULT_FILTER.to_string()),
+            otlp_endpoint: var("OTEL_EXPORTER_OTLP_ENDPOINT"),
+            service_name: var("OTEL_SERVICE_NAME")
+                .unwrap_or_else(|| DEFAULT_SERVICE_NAME.to_string()),
+        }
+    }
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            filter: DEFAULT_FILTER.to_string(),
+            otlp_endpoint: None,
+            service_name: DEFAULT_SERVICE_NAME.to_string(),
+        }
+    }
+}
+
+/// Keeps the exporter alive; flushes pending spans on drop
+#[must_use = "spans stop being exported when the guard is dropped"]
+#[derive(Default)]
+pub struct TelemetryGuard {
+    #[cfg(feature = "otlp")]
+    provider: Option<opentelemetry_sdk::trace::TracerProvider>,
+}
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        #[cfg(feature = "otlp")]
+        if let Some(provider) = self.provider.take() {
+            if let Err(e) = provider.shutdown() {
+                eprintln!("[Telemetry] Failed to flush spans: {}", e);
+            }
+        }
+    }
+}
+
+/// Install the global subscriber (no-op without the `trace` feature)
+///
+/// Fails if the filter is invalid, the exporter cannot be built or a global
+/// subscriber is already installed.
+#[cfg(feature = "trace")]
+pub fn init(config: &TelemetryConfig) -> Result<TelemetryGuard> {
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
+
+    let filter = tracing_subscriber::EnvFilter::try_new(&config.filter).map_err(|e| {
+        CodegraphError::config(format!("Invalid trace filter {:?}: {}", config.filter, e))
+    })?;
+    let registry = tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr));
+
+    #[cfg(feature = "otlp")]
+    {
+        use opentelemetry::trace::TracerProvider as _;
+
+        let provider = match config.otlp_endpoint {
+            Some(ref endpoint) => Some(otlp_provider(endpoint, &config.service_name)?),
+            None => None,
+        };
+        let layer = provider
+            .as_ref()
+            .map(|p| tracing_opentelemetry::layer().with_tracer(p.tracer(DEFAULT_SERVICE_NAME)));
+        registry.with(layer).try_init().map_err(|e| {
+            CodegraphError::config(format!("Failed to install trace subscriber: {}", e))
+        })?;
+        Ok(TelemetryGuard { provider })
+    }
+
+    #[cfg(not(feature = "otlp"))]
+    {
+        if let Some(ref endpoint) = config.otlp_endpoint {
+            tracing::warn!(
+                "[Telemetry] OTLP endpoint {} ignored (built without the `otlp` feature)",
+                endpoint
+            );
+        }
+        registry.try_init().map_err(|e| {
+            CodegraphError::config(format!("Failed to install trace subscriber: {}", e))
+        })?;
+        Ok(TelemetryGuard::default())
+    }
+}
+
+/// Install the global subscriber (no-op without the `trace` feature)
+#[cfg(not(feature = "trace"))]
+pub fn init(_config: &TelemetryConfig) -> Result<TelemetryGuard> {
+    Ok(TelemetryGuard::default())
+}
+
+/// OTLP/HTTP span exporter; spans are exported as they close
+#[cfg(feature = "otlp")]
+fn otlp_provider(
+    endpoint: &str,
+    service_name: &str,
+) -> Result<opentelemetry_sdk::trace::TracerProvider> {
+    use opentelemetry_otlp::WithExportConfig;
+
+    // Same path rule as the OTEL_EXPORTER_OTLP_ENDPOINT spec
+    let endpoint = endpoint.trim_end_matches('/');
+    let url = if endpoint.ends_with("/v1/traces") {
+        endpoint.to_string()
+    } else {
+        format!("{}/v1/traces", endpoint)
+    };
+
+    // Stage and batch spans are few per run: a synchronous exporter needs
+    // no async runtime
+    opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .http()
+                .with_endpoint(&url),
+        )
+        .with_trace_config(opentelemetry_sdk::trace::Config::default().with_resource(
+            opentelemetry_sdk::Resource::new(vec![opentelemetry::KeyValue::new(
+                "service.name",
+                service_name.to_string(),
+            )]),
+        ))
+        .install_simple()
+        .map_err(|e| {
+            CodegraphError::config(format!("Failed to start OTLP exporter for {}: {}", url, e))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spans_nest_without_subscriber() {
+        let _pipeline = pipeline_span("repo", "full");
+        let parent = current();
+        let handle = std::thread::spawn(move || {
+            let _stage = stage_span(&parent, "L2_Chunking");
+            let _batch = batch_span(0, 3, 120);
+        });
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_config_defaults() {
+        let config = TelemetryConfig::default();
+        assert_eq!(config.filter, "info");
+        assert_eq!(config.service_name, "codegraph-ir");
+        assert!(config.otlp_endpoint.is_none());
+    }
+}
@@ -0,0 +1,427 @@
+//! Single-Buffer Quick Analysis
+//!
+//! IDE integrations analyze one unsaved buffer against the last indexed
+//! snapshot instead of re-running the pipeline:
+//!
+//! - L1: IR for the buffer only (same processor as `IRBuildExecutor`)
+//! - L2: chunks via `ChunkingExecutor`
+//! - L3: imports resolved against the snapshot (the snapshot's copy of the
+//!   file is ignored, the buffer replaces it)
+//! - L4: occurrences from L1
+//! - L5: navigation symbols
+//!
+//! DFG/SSA/PDG skipped for a function (size or time limits, unsupported
+//! language) is reported as an `analysis-skipped` info diagnostic.
+//!
+//! Nothing is written back to the context. `BufferAnalyzer` builds its module
+//! index once per snapshot; keep one around to re-analyze on every edit.
+
+use super::executors::{ChunkingExecutor, PipelineContext, StageExecutor};
+use super::memory::{ChunkData, ContextHandle, SymbolData};
+use crate::pipeline::dag::StageId;
+use crate::pipeline::end_to_end_orchestrator::file_to_module_path;
+use crate::pipeline::processor::SkippedAnalysis;
+use crate::pipeline::unified_processor::process_any_file;
+use crate::shared::models::{CodegraphError, Edge, EdgeKind, Node, NodeKind, Occurrence};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Diagnostic severity (LSP order)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DiagnosticSeverity {
+    Error,
+    Warning,
+    Info,
+}
+
+impl DiagnosticSeverity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiagnosticSeverity::Error => "error",
+            DiagnosticSeverity::Warning => "warning",
+            DiagnosticSeverity::Info => "info",
+        }
+    }
+}
+
+/// Problem found in the buffer
+#[derive(Debug, Clone)]
+pub struct BufferDiagnostic {
+    pub severity: DiagnosticSeverity,
+    /// Stable code for IDE filtering (`parse-error`, `unresolved-import`, ...)
+    pub code: &'static str,
+    pub message: String,
+    /// 1-based line, `None` for file-level problems
+    pub line: Option<u32>,
+}
+
+/// Import of the buffer, resolved against the snapshot
+#[derive(Debug, Clone)]
+pub struct BufferImport {
+    /// Imported FQN as written (`app.db.query`, `.models.User`)
+    pub fqn: String,
+    pub line: u32,
+    /// Snapshot file defining the import target
+    pub resolved_file: Option<String>,
+    /// Outside the repository (stdlib, third-party)
+    pub is_external: bool,
+}
+
+/// Result of `analyze_buffer`
+#[derive(Debug, Clone)]
+pub struct BufferAnalysis {
+    pub file_path: String,
+    pub nodes: Vec<Node>,
+    pub edges: Vec<Edge>,
+    pub occurrences: Vec<Occurrence>,
+    pub chunks: Vec<ChunkData>,
+    pub symbols: Vec<SymbolData>,
+    pub imports: Vec<BufferImport>,
+    pub diagnostics: Vec<BufferDiagnostic>,
+    pub elapsed: Duration,
+}
+
+impl BufferAnalysis {
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .any(|d| d.severity == DiagnosticSeverity::Error)
+    }
+}
+
+/// Quick analysis of in-memory buffers against one snapshot
+pub struct BufferAnalyzer {
+    context: ContextHandle,
+    /// Module path (and each dotted suffix, for `src/` layouts) → files
+    modules: HashMap<String, Vec<String>>,
+    /// File → names defined in it
+    definitions: HashMap<String, HashSet<String>>,
+    /// First segments of all repository module paths
+    roots: HashSet<String>,
+}
+
+impl BufferAnalyzer {
+    /// Index the snapshot's modules and definitions
+    pub fn new(context: ContextHandle) -> Self {
+        let mut modules: HashMap<String, Vec<String>> = HashMap::new();
+        let mut definitions: HashMap<String, HashSet<String>> = HashMap::new();
+        let mut roots = HashSet::new();
+
+        for node in context.nodes.iter() {
+            if !definitions.contains_key(&node.file_path) {
+                let module = file_to_module_path(&node.file_path);
+                let segments: Vec<&str> = module.split('.').collect();
+                for start in 0..segments.len() {
+                    modules
+                        .entry(segments[start..].join("."))
+                        .or_default()
+                        .push(node.file_path.clone());
+                    roots.insert(segments[start].to_string());
+                }
+                definitions.insert(node.file_path.clone(), HashSet::new());
+            }
+            if is_symbol(node.kind) {
+                if let (Some(name), Some(names)) =
+                    (node.name.as_ref(), definitions.get_mut(&node.file_path))
+                {
+                    names.insert(name.clone());
+                }
+            }
+        }
+
+        Self {
+            context,
+            modules,
+            definitions,
+            roots,
+        }
+    }
+
+    pub fn context(&self) -> &ContextHandle {
+        &self.context
+    }
+
+    /// Run L1-L5 for `content` as the new version of `file_path`
+    pub fn analyze(
+        &self,
+        file_path: &str,
+        content: &str,
+    ) -> Result<BufferAnalysis, CodegraphError> {
+        let start = Instant::now();
+        let mut diagnostics = Vec::new();
+
+        // L1: IR
+        let result = process_any_file(file_path, content, &self.context.repo_name)
+            .map_err(|e| e.with_file(file_path))?;
+        diagnostics.extend(result.errors.iter().map(|error| BufferDiagnostic {
+            severity: DiagnosticSeverity::Error,
+            code: "parse-error",
+            message: error.clone(),
+            line: None,
+        }));
+        diagnostics.extend(skip_diagnostics(&result.skipped_analyses));
+
+        // L2: chunks
+        let mut pipeline = PipelineContext::new(
+            PathBuf::from(&self.context.repo_root),
+            self.context.repo_name.clone(),
+        );
+        pipeline.set_nodes(result.nodes.clone());
+        pipeline.mark_completed(StageId::L1IrBuild);
+        ChunkingExecutor::new().execute(&mut pipeline)?;
+        let chunks = pipeline.get_chunks()?.as_ref().clone();
+
+        // L3: imports
+        let module = file_to_module_path(file_path);
+        let mut imports = Vec::new();
+        for edge in result.edges.iter().filter(|e| e.kind == EdgeKind::Imports) {
+            let line = edge.span.map(|s| s.start_line).unwrap_or(0);
+            let import = self.resolve_import(file_path, &module, &edge.target_id, line);
+            if import.resolved_file.is_none() && !import.is_external {
+                diagnostics.push(BufferDiagnostic {
+                    severity: DiagnosticSeverity::Warning,
+                    code: "unresolved-import",
+                    message: format!("unresolved import `{}`", import.fqn),
+                    line: Some(line),
+                });
+            }
+            imports.push(import);
+        }
+
+        // L5: symbols
+        let symbols = result
+            .nodes
+            .iter()
+            .filter(|n| is_symbol(n.kind))
+            .map(|n| SymbolData {
+                id: n.id.clone(),
+                name: n.name.clone().unwrap_or_else(|| n.fqn.clone()),
+                kind: n.kind.as_str().to_string(),
+                file_path: file_path.to_string(),
+                definition: (n.span.start_line as usize, n.span.start_col as usize),
+                documentation: n.docstring.clone(),
+            })
+            .collect();
+
+        diagnostics.sort_by_key(|d| (d.line, d.severity));
+        Ok(BufferAnalysis {
+            file_path: file_path.to_string(),
+            nodes: result.nodes,
+            edges: result.edges,
+            occurrences: result.occurrences,
+            chunks,
+            symbols,
+            imports,
+            diagnostics,
+            elapsed: start.elapsed(),
+        })
+    }
+
+    /// `a.b.c` is module `a.b.c`, or name `c` of module `a.b`
+    fn resolve_import(&self, file_path: &str, module: &str, fqn: &str, line: u32) -> BufferImport {
+        let absolute = absolute_import(module, fqn);
+        let (parent, name) = match absolute.rsplit_once('.') {
+            Some((parent, name)) => (Some(parent), name),
+            None => (None, absolute.as_str()),
+        };
+
+        let module_file = |path: &str| {
+            self.modules
+                .get(path)?
+                .iter()
+                .find(|f| f.as_str() != file_path)
+                .cloned()
+        };
+        let resolved_file = module_file(&absolute).or_else(|| {
+            let files = self.modules.get(parent?)?;
+            files
+                .iter()
+                .filter(|f| f.as_str() != file_path)
+                .find(|f| self.definitions.get(*f).is_some_and(|d| d.contains(name)))
+                .cloned()
+        });
+
+        let root = absolute.split('.').next().unwrap_or_default();
+        BufferImport {
+            fqn: fqn.to_string(),
+            line,
+            is_external: resolved_file.is_none()
+                && !fqn.starts_with('.')
+                && !self.roots.contains(root),
+            resolved_file,
+        }
+    }
+}
+
+/// Analyze one buffer against `context` (builds a one-off `BufferAnalyzer`)
+pub fn analyze_buffer(
+    file_path: &str,
+    content: &str,
+    context: &ContextHandle,
+) -> Result<BufferAnalysis, CodegraphError> {
+    BufferAnalyzer::new(Arc::clone(context)).analyze(file_path, content)
+}
+
+/// One `analysis-skipped` diagnostic per function and reason
+fn skip_diagnostics(skipped: &[SkippedAnalysis]) -> Vec<BufferDiagnostic> {
+    let mut grouped: Vec<(&SkippedAnalysis, Vec<&str>)> = Vec::new();
+    for skip in skipped {
+        match grouped
+            .iter_mut()
+            .find(|(first, _)| first.function_id == skip.function_id && first.reason == skip.reason)
+        {
+            Some((_, stages)) => stages.push(skip.stage.as_str()),
+            None => grouped.push((skip, vec![skip.stage.as_str()])),
+        }
+    }
+
+    grouped
+        .into_iter()
+        .map(|(skip, stages)| BufferDiagnostic {
+            severity: DiagnosticSeverity::Info,
+            code: "analysis-skipped",
+            message: format!(
+                "{} skipped for `{}`: {}",
+                stages.join("/").to_uppercase(),
+                skip.function_name,
+                skip.reason.detail()
+            ),
+            line: (skip.line > 0).then_some(skip.line),
+        })
+        .collect()
+}
+
+/// Kinds listed as navigation symbols
+pub(super) fn is_symbol(kind: NodeKind) -> bool {
+    matches!(
+        kind,
+        NodeKind::Class
+            | NodeKind::Function
+            | NodeKind::Method
+            | NodeKind::Field
+            | NodeKind::Interface
+            | NodeKind::Enum
+            | NodeKind::TypeAlias
+            | NodeKind::Constant
+            | NodeKind::Property
+            | NodeKind::Trait
+    )
+}
+
+/// Resolve leading dots of a relative import against the importing module
+fn absolute_import(module: &str, fqn: &str) -> String {
+    let rest = fqn.trim_start_matches('.');
+    let dots = fqn.len() - rest.len();
+    if dots == 0 {
+        return fqn.to_string();
+    }
+    // One dot is the importing module's package
+    let segments: Vec<&str> = module.split('.').collect();
+    let package = &segments[..segments.len().saturating_sub(dots)];
+    package
+        .iter()
+        .copied()
+        .chain((!rest.is_empty()).then_some(rest))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::memory::GraphContextBuilder;
+    use super::*;
+
+    fn snapshot() -> ContextHandle {
+        let mut nodes = Vec::new();
+        for (path, source) in [
+            ("app/db.py", "def query(sql):\n    return sql\n"),
+            ("app/views.py", "def stale():\n    return 0\n"),
+        ] {
+            nodes.extend(process_any_file(path, source, "repo").unwrap().nodes);
+        }
+        Arc::new(
+            GraphContextBuilder::new("repo".to_string(), "/repo".to_string())
+                .with_nodes(nodes)
+                .build(),
+        )
+    }
+
+    #[test]
+    fn test_analyze_buffer_resolves_against_snapshot() {
+        let context = snapshot();
+        let buffer = "import os\nfrom app.db import query\nfrom app.db import missing\n\n\
+                      def index(request):\n    \"\"\"Landing page\"\"\"\n    return query(request)\n";
+
+        let analysis = analyze_buffer("app/views.py", buffer, &context).unwrap();
+
+        let import = |fqn: &str| analysis.imports.iter().find(|i| i.fqn == fqn).unwrap();
+        assert!(import("os").is_external);
+        assert_eq!(
+            import("app.db.query").resolved_file.as_deref(),
+            Some("app/db.py")
+        );
+        assert!(import("app.db.missing").resolved_file.is_none());
+
+        let unresolved: Vec<_> = analysis
+            .diagnostics
+            .iter()
+            .filter(|d| d.code == "unresolved-import")
+            .collect();
+        assert_eq!(unresolved.len(), 1);
+        assert_eq!(unresolved[0].line, Some(3));
+        assert!(!analysis.has_errors());
+
+        // The buffer replaces the snapshot's copy of the file
+        let names: Vec<&str> = analysis.symbols.iter().map(|s| s.name.as_str()).collect();
+        assert!(names.contains(&"index"));
+        assert!(!names.contains(&"stale"));
+        assert!(analysis
+            .symbols
+            .iter()
+            .all(|s| s.file_path == "app/views.py"));
+    }
+
+    #[test]
+    fn test_skip_diagnostics_grouped_per_function() {
+        use crate::pipeline::processor::{SkipReason, SkippedStage};
+
+        let skip = |function: &str, stage| SkippedAnalysis {
+            function_id: format!("func:{}", function),
+            function_name: function.to_string(),
+            line: 12,
+            stage,
+            reason: SkipReason::Size {
+                metric: "blocks",
+                actual: 2500,
+                limit: 2000,
+            },
+        };
+        let skipped = [
+            skip("parse", SkippedStage::Dfg),
+            skip("parse", SkippedStage::Ssa),
+            skip("parse", SkippedStage::Pdg),
+            skip("lex", SkippedStage::Dfg),
+        ];
+
+        let diagnostics = skip_diagnostics(&skipped);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Info);
+        assert_eq!(diagnostics[0].code, "analysis-skipped");
+        assert_eq!(diagnostics[0].line, Some(12));
+        assert_eq!(
+            diagnostics[0].message,
+            "DFG/SSA/PDG skipped for `parse`: 2500 blocks exceeds limit of 2000"
+        );
+        assert!(diagnostics[1].message.starts_with("DFG skipped for `lex`"));
+    }
+
+    #[test]
+    fn test_absolute_import() {
+        assert_eq!(absolute_import("app.views", ".db.query"), "app.db.query");
+        assert_eq!(absolute_import("app.api.views", "..db"), "app.db");
+        assert_eq!(absolute_import("app.views", "."), "app");
+        assert_eq!(absolute_import("app.views", "os.path"), "os.path");
+    }
+}
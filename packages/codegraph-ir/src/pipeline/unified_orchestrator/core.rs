@@ -11,15 +11,20 @@
 //! - Pluggable stage executors (L1-L37)
 
 use super::executors::{
-    StageExecutor, PipelineContext, IRBuildExecutor, ChunkingExecutor, CrossFileExecutor,
+    StageExecutor, PipelineContext, IRBuildExecutor, ChunkingExecutor, VectorExecutor,
+    CrossFileExecutor,
     OccurrencesExecutor, SymbolsExecutor, PointsToExecutor, CloneDetectionExecutor,
     EffectAnalysisExecutor, TaintAnalysisExecutor, CostAnalysisExecutor, RepoMapExecutor,
     ConcurrencyAnalysisExecutor, SmtVerificationExecutor, LexicalExecutor,
     GitHistoryExecutor, QueryEngineExecutor, BoxedExecutor,
 };
+use super::buffer::BufferAnalysis;
 use super::memory::GraphContext;
 use super::pipeline_state::{PipelineState, PipelineStatus, StageResultData, IndexingStats};
+use super::precommit::PrecommitReport;
+use super::shared::SharedContext;
 use crate::pipeline::dag::{PipelineDAG, StageId};
+use crate::pipeline::telemetry;
 use crate::pipeline::E2EPipelineConfig;
 use crate::shared::models::CodegraphError;
 use std::collections::HashSet;
@@ -214,6 +219,9 @@ pub struct UnifiedOrchestrator {
 
     /// Stage executors (registered at initialization)
     executors: Vec<BoxedExecutor>,
+
+    /// Published context for concurrent readers (buffer analysis, queries)
+    shared: SharedContext,
 }
 
 impl UnifiedOrchestrator {
@@ -224,10 +232,15 @@ impl UnifiedOrchestrator {
             config.repo_name.clone(),
         );
 
+        let shared = SharedContext::new(GraphContext::new(
+            config.repo_name.clone(),
+            config.repo_root.to_string_lossy().to_string(),
+        ));
         let mut orchestrator = Self {
             config: config.clone(),
             state: Arc::new(RwLock::new(state)),
             executors: Vec::new(),
+            shared,
         };
 
         // Register all stage executors
@@ -236,6 +249,19 @@ impl UnifiedOrchestrator {
         Ok(orchestrator)
     }
 
+    /// Replace the default L2.6 executor (e.g. to plug in another embedding
+    /// provider or persist indexes); no-op when the vector stage is disabled
+    pub fn with_vector_executor(mut self, executor: VectorExecutor) -> Self {
+        if let Some(slot) = self
+            .executors
+            .iter_mut()
+            .find(|e| e.stage_id() == StageId::L2_6Vector)
+        {
+            *slot = Box::new(executor);
+        }
+        self
+    }
+
     /// Register all stage executors based on RFC-001 configuration
     fn register_executors(&mut self) -> Result<(), CodegraphError> {
         let cfg = &self.config.pipeline_config;
@@ -252,6 +278,11 @@ impl UnifiedOrchestrator {
             self.executors.push(Box::new(ChunkingExecutor::new()));
         }
 
+        // L2.6: Vector Index (uses chunking)
+        if cfg.enable_chunking() && cfg.enable_vector() {
+            self.executors.push(Box::new(VectorExecutor::new()));
+        }
+
         // L3: Cross-File
         if cfg.enable_cross_file() {
             self.executors.push(Box::new(CrossFileExecutor::new()));
@@ -322,7 +353,7 @@ impl UnifiedOrchestrator {
             self.executors.push(Box::new(QueryEngineExecutor::new()));
         }
 
-        eprintln!("[UnifiedOrchestrator] Registered {} stage executors", self.executors.len());
+        tracing::debug!("[UnifiedOrchestrator] Registered {} stage executors", self.executors.len());
 
         Ok(())
     }
@@ -335,7 +366,8 @@ impl UnifiedOrchestrator {
     pub fn index_repository(&self) -> Result<(), CodegraphError> {
         let start = Instant::now();
 
-        eprintln!("[UnifiedOrchestrator] Starting pipeline execution...");
+        let _pipeline_span = telemetry::pipeline_span(&self.config.repo_name, "unified");
+        tracing::info!("[UnifiedOrchestrator] Starting pipeline execution...");
 
         // Update state to Running
         {
@@ -377,6 +409,7 @@ impl UnifiedOrchestrator {
                     let final_context = exec_context.to_graph_context();
                     state.context = Arc::new(final_context);
                     state.status = PipelineStatus::Completed;
+                    self.shared.publish(Arc::clone(&state.context));
 
                     // Update stats
                     state.stats.total_duration = total_duration;
@@ -386,8 +419,8 @@ impl UnifiedOrchestrator {
                     state.stats.total_symbols = state.context.symbols.len();
                     state.stats.stages_completed = state.completed_stages.len();
 
-                    eprintln!("[UnifiedOrchestrator] Pipeline completed in {:.2}s", total_duration.as_secs_f64());
-                    eprintln!("[UnifiedOrchestrator] Stats: {} nodes, {} edges, {} chunks, {} symbols",
+                    tracing::info!("[UnifiedOrchestrator] Pipeline completed in {:.2}s", total_duration.as_secs_f64());
+                    tracing::info!("[UnifiedOrchestrator] Stats: {} nodes, {} edges, {} chunks, {} symbols",
                         state.stats.total_nodes,
                         state.stats.total_edges,
                         state.stats.total_chunks,
@@ -397,7 +430,7 @@ impl UnifiedOrchestrator {
                 Err(ref e) => {
                     state.status = PipelineStatus::Failed;
                     state.stats.stages_failed += 1;
-                    eprintln!("[UnifiedOrchestrator] Pipeline failed: {}", e);
+                    tracing::warn!("[UnifiedOrchestrator] Pipeline failed: {}", e);
                 }
             }
         }
@@ -413,7 +446,7 @@ impl UnifiedOrchestrator {
         // Build DAG using PipelineDAG::build
         let dag = PipelineDAG::build(&stages);
 
-        eprintln!("[UnifiedOrchestrator] Built DAG with {} stages", stages.len());
+        tracing::debug!("[UnifiedOrchestrator] Built DAG with {} stages", stages.len());
 
         Ok(dag)
     }
@@ -428,7 +461,7 @@ impl UnifiedOrchestrator {
         let all_stages: Vec<StageId> = self.executors.iter().map(|e| e.stage_id()).collect();
         let mut remaining: HashSet<StageId> = all_stages.iter().copied().collect();
 
-        eprintln!("[UnifiedOrchestrator] Executing {} stages...", remaining.len());
+        tracing::debug!("[UnifiedOrchestrator] Executing {} stages...", remaining.len());
 
         while !remaining.is_empty() {
             // Find all ready stages (dependencies satisfied)
@@ -455,7 +488,7 @@ impl UnifiedOrchestrator {
                 )));
             }
 
-            eprintln!("[UnifiedOrchestrator] Ready stages: {:?}", ready);
+            tracing::debug!("[UnifiedOrchestrator] Ready stages: {:?}", ready);
 
             // Execute ready stages (sequential for now, parallel later with Rayon)
             for stage_id in ready {
@@ -474,7 +507,7 @@ impl UnifiedOrchestrator {
             }
         }
 
-        eprintln!("[UnifiedOrchestrator] All stages completed");
+        tracing::debug!("[UnifiedOrchestrator] All stages completed");
 
         Ok(())
     }
@@ -485,7 +518,8 @@ impl UnifiedOrchestrator {
         stage_id: StageId,
         context: &mut PipelineContext,
     ) -> Result<(), CodegraphError> {
-        eprintln!("[UnifiedOrchestrator] Executing stage: {:?}", stage_id);
+        let _stage_span = telemetry::stage_span(&telemetry::current(), stage_id.name());
+        tracing::debug!("[UnifiedOrchestrator] Executing stage: {:?}", stage_id);
 
         // Find executor
         let executor = self.executors
@@ -504,7 +538,7 @@ impl UnifiedOrchestrator {
             )));
         }
 
-        eprintln!(
+        tracing::info!(
             "[UnifiedOrchestrator] Stage {:?} completed in {:.2}s ({} items)",
             stage_id,
             result.duration.as_secs_f64(),
@@ -548,6 +582,32 @@ impl UnifiedOrchestrator {
         Arc::clone(&state.context)
     }
 
+    /// Shared handle to the published context
+    ///
+    /// Every successful `index_repository` publishes a new version; readers
+    /// holding a snapshot keep the version they took.
+    pub fn shared_context(&self) -> SharedContext {
+        self.shared.clone()
+    }
+
+    /// Analyze an unsaved buffer against the current context
+    ///
+    /// Runs L1-L5 for the one file without touching the indexed state. The
+    /// snapshot index is reused until the next `index_repository`.
+    pub fn analyze_buffer(&self, file_path: &str, content: &str) -> Result<BufferAnalysis, CodegraphError> {
+        self.shared.analyze_buffer(file_path, content)
+    }
+
+    /// Pre-commit check of a unified diff against the current context
+    ///
+    /// Changed files are read from the repository working tree. Only cheap
+    /// checks run on the changed symbols (see `precommit`).
+    pub fn analyze_diff(&self, diff: &str) -> PrecommitReport {
+        let root = PathBuf::from(&self.shared.context().repo_root);
+        self.shared
+            .analyze_diff(diff, |path| std::fs::read_to_string(root.join(path)).ok())
+    }
+
     /// Get pipeline status
     pub fn get_status(&self) -> PipelineStatus {
         let state = self.state.read().expect("Failed to acquire state read lock");
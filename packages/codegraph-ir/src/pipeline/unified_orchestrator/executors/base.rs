@@ -146,6 +146,7 @@ pub trait StageExecutor: Send + Sync {
             StageId::L1IrBuild => config.enable_ir_build(),
             StageId::L2Chunking => config.enable_chunking(),
             StageId::L2_5Lexical => config.enable_lexical(),
+            StageId::L2_6Vector => config.enable_vector(),
             StageId::L3CrossFile => config.enable_cross_file(),
             StageId::L4Occurrences => config.enable_symbols(), // occurrences uses symbols
             StageId::L5Symbols => config.enable_symbols(),
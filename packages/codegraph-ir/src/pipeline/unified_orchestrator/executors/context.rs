@@ -195,8 +195,7 @@ impl PipelineContext {
 
         // Copy stage data
         for (key, value) in &self.stage_data {
-            // Note: We can't easily clone Arc<dyn Any>, so we'll need to handle this differently
-            // For now, stage_data is only in the context, not the final GraphContext
+            builder = builder.with_shared_stage_data(key.clone(), Arc::clone(value));
         }
 
         builder.build()
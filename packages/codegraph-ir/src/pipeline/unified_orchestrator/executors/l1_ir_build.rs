@@ -4,6 +4,7 @@
 
 use super::base::{StageExecutor, StageResult};
 use super::context::PipelineContext;
+use crate::features::parsing::sdk;
 use crate::pipeline::dag::StageId;
 use crate::pipeline::unified_processor::process_any_file;
 use crate::shared::models::{CodegraphError, Node, Edge, Occurrence};
@@ -48,7 +49,7 @@ impl IRBuildExecutor {
                 let path = entry.path();
                 if let Some(ext) = path.extension() {
                     let ext_str = ext.to_string_lossy();
-                    if matches!(ext_str.as_ref(), "py" | "java" | "kt" | "ts" | "tsx" | "js" | "jsx" | "rs" | "go") {
+                    if sdk::is_supported_extension(ext_str.as_ref()) {
                         files.push(path.to_path_buf());
                     }
                 }
@@ -69,22 +70,15 @@ impl IRBuildExecutor {
         let content = match std::fs::read_to_string(file_path) {
             Ok(c) => c,
             Err(e) => {
-                eprintln!("[L1] Failed to read {:?}: {}", file_path, e);
+                tracing::warn!("[L1] Failed to read {:?}: {}", file_path, e);
                 return None;
             }
         };
 
         // Determine language
-        let language = match file_path.extension()?.to_str()? {
-            "py" => "python",
-            "java" => "java",
-            "kt" => "kotlin",
-            "ts" | "tsx" => "typescript",
-            "js" | "jsx" => "javascript",
-            "rs" => "rust",
-            "go" => "go",
-            _ => return None,
-        };
+        let language = sdk::descriptor_for_extension(file_path.extension()?.to_str()?)?
+            .language
+            .name();
 
         // Get relative path for module calculation
         let rel_path = file_path.strip_prefix(repo_root).ok()?;
@@ -100,7 +94,7 @@ impl IRBuildExecutor {
                 Some((result.nodes, result.edges, result.occurrences))
             }
             Err(e) => {
-                eprintln!("[L1] Failed to process {:?}: {}", file_path, e);
+                tracing::warn!("[L1] Failed to process {:?}: {}", file_path, e);
                 None
             }
         }
@@ -115,7 +109,7 @@ impl StageExecutor for IRBuildExecutor {
     fn execute(&self, context: &mut PipelineContext) -> Result<StageResult, CodegraphError> {
         let start = Instant::now();
 
-        eprintln!("[L1] Starting IR Build");
+        tracing::info!("[L1] Starting IR Build");
 
         // Configure Rayon thread pool if specified
         if let Some(workers) = self.parallel_workers {
@@ -127,7 +121,7 @@ impl StageExecutor for IRBuildExecutor {
 
         // Discover files
         let files = self.discover_files(&context.repo_root)?;
-        eprintln!("[L1] Discovered {} files", files.len());
+        tracing::debug!("[L1] Discovered {} files", files.len());
 
         // Process files in parallel with Rayon
         let results: Vec<_> = files
@@ -148,7 +142,7 @@ impl StageExecutor for IRBuildExecutor {
             all_occurrences.extend(occurrences);
         }
 
-        eprintln!(
+        tracing::info!(
             "[L1] Built IR: {} nodes, {} edges, {} occurrences",
             all_nodes.len(),
             all_edges.len(),
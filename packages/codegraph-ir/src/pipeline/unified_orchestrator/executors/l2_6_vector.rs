@@ -0,0 +1,161 @@
+//! L2.6: Vector Executor
+//!
+//! Embeds L2 chunks with an `EmbeddingProvider` and builds the per-snapshot
+//! HNSW index. The index is published as stage data under `VECTOR_INDEX_KEY`.
+
+use super::super::memory::ChunkData;
+use super::base::{StageExecutor, StageResult};
+use super::context::PipelineContext;
+use crate::features::vector::{
+    ChunkVectorIndex, EmbeddingProvider, HashingEmbeddingProvider, HnswParams, VectorEntry,
+    VectorIndexStore,
+};
+use crate::pipeline::dag::StageId;
+use crate::shared::models::CodegraphError;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Stage data key of the `ChunkVectorIndex`
+pub const VECTOR_INDEX_KEY: &str = "vector_index";
+
+/// L2.6: Vector Executor
+pub struct VectorExecutor {
+    provider: Arc<dyn EmbeddingProvider>,
+    params: HnswParams,
+    /// Persist / reuse indexes per snapshot (in-memory only when unset)
+    store: Option<VectorIndexStore>,
+}
+
+impl VectorExecutor {
+    pub fn new() -> Self {
+        Self {
+            provider: Arc::new(HashingEmbeddingProvider::default()),
+            params: HnswParams::default(),
+            store: None,
+        }
+    }
+
+    pub fn with_provider(mut self, provider: Arc<dyn EmbeddingProvider>) -> Self {
+        self.provider = provider;
+        self
+    }
+
+    pub fn with_params(mut self, params: HnswParams) -> Self {
+        self.params = params;
+        self
+    }
+
+    pub fn with_store(mut self, store: VectorIndexStore) -> Self {
+        self.store = Some(store);
+        self
+    }
+}
+
+impl Default for VectorExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn entry(chunk: &ChunkData) -> VectorEntry {
+    VectorEntry {
+        chunk_id: chunk.id.clone(),
+        file_path: chunk.file_path.clone(),
+        start_line: chunk.start_line,
+        end_line: chunk.end_line,
+        symbol_id: chunk.symbol_id.clone(),
+    }
+}
+
+impl StageExecutor for VectorExecutor {
+    fn stage_id(&self) -> StageId {
+        StageId::L2_6Vector
+    }
+
+    fn execute(&self, context: &mut PipelineContext) -> Result<StageResult, CodegraphError> {
+        let start = Instant::now();
+
+        tracing::debug!(
+            "[L2.6] Starting Vector Index ({})",
+            self.provider.model_id()
+        );
+
+        let chunks = context.get_chunks()?;
+        let inputs: Vec<(VectorEntry, &str)> = chunks
+            .iter()
+            .map(|chunk| (entry(chunk), chunk.content.as_str()))
+            .collect();
+
+        let index = match &self.store {
+            Some(store) => store.load_or_build(self.provider.as_ref(), self.params, &inputs)?,
+            None => ChunkVectorIndex::build(
+                ChunkVectorIndex::fingerprint(self.provider.model_id(), &inputs),
+                self.provider.as_ref(),
+                self.params,
+                inputs.iter().cloned(),
+            )?,
+        };
+
+        tracing::info!(
+            "[L2.6] Indexed {} chunks (snapshot {})",
+            index.len(),
+            index.snapshot_id()
+        );
+
+        let items = index.len();
+        context.set_stage_data(VECTOR_INDEX_KEY.to_string(), index);
+
+        Ok(StageResult::success(
+            StageId::L2_6Vector,
+            start.elapsed(),
+            items,
+        ))
+    }
+
+    fn dependencies(&self) -> Vec<StageId> {
+        vec![StageId::L2Chunking]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(id: &str, content: &str) -> ChunkData {
+        ChunkData {
+            id: id.to_string(),
+            file_path: "test.py".to_string(),
+            content: content.to_string(),
+            start_line: 1,
+            end_line: 20,
+            chunk_type: "Function".to_string(),
+            symbol_id: None,
+        }
+    }
+
+    #[test]
+    fn test_l2_6_vector() {
+        let mut ctx =
+            PipelineContext::new(std::path::PathBuf::from("/test"), "test-repo".to_string());
+        ctx.set_chunks(vec![
+            chunk("chunk:load", "Function::load_config"),
+            chunk("chunk:render", "Function::render_page"),
+        ]);
+        ctx.mark_completed(StageId::L2Chunking);
+
+        let executor = VectorExecutor::new();
+        let result = executor.execute(&mut ctx).unwrap();
+        assert!(result.success);
+        assert_eq!(result.items_processed, 2);
+
+        let index = ctx
+            .get_stage_data::<ChunkVectorIndex>(VECTOR_INDEX_KEY)
+            .unwrap();
+        let query = HashingEmbeddingProvider::default()
+            .embed(&["config loader"])
+            .unwrap()
+            .remove(0);
+        let hits = index.semantic_search(&query, 1).unwrap();
+        assert_eq!(hits[0].entry.chunk_id, "chunk:load");
+    }
+}
@@ -41,12 +41,12 @@ impl StageExecutor for ChunkingExecutor {
     fn execute(&self, context: &mut PipelineContext) -> Result<StageResult, CodegraphError> {
         let start = Instant::now();
 
-        eprintln!("[L2] Starting Chunking");
+        tracing::info!("[L2] Starting Chunking");
 
         // Get nodes from L1 (Arc reference, zero-copy!)
         let nodes = context.get_nodes()?;
 
-        eprintln!("[L2] Processing {} nodes", nodes.len());
+        tracing::debug!("[L2] Processing {} nodes", nodes.len());
 
         // Build chunks from nodes
         let mut chunks = Vec::new();
@@ -84,7 +84,7 @@ impl StageExecutor for ChunkingExecutor {
             }
         }
 
-        eprintln!("[L2] Created {} chunks", chunks.len());
+        tracing::info!("[L2] Created {} chunks", chunks.len());
 
         // Store in context
         context.set_chunks(chunks.clone());
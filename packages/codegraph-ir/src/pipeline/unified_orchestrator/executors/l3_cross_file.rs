@@ -25,13 +25,13 @@ impl StageExecutor for CrossFileExecutor {
     fn execute(&self, context: &mut PipelineContext) -> Result<StageResult, CodegraphError> {
         let start = Instant::now();
 
-        eprintln!("[L3] Starting Cross-File Resolution");
+        tracing::info!("[L3] Starting Cross-File Resolution");
 
         // Get nodes and edges (Arc references)
         let nodes = context.get_nodes()?;
         let edges = context.get_edges()?;
 
-        eprintln!(
+        tracing::debug!(
             "[L3] Processing {} nodes, {} edges",
             nodes.len(),
             edges.len()
@@ -6,6 +6,7 @@ pub mod base;
 pub mod context;
 pub mod l1_ir_build;
 pub mod l2_chunking;
+pub mod l2_6_vector;
 pub mod l3_cross_file;
 pub mod stubs;
 
@@ -15,6 +16,7 @@ pub use context::PipelineContext;
 // Re-export core executors (L1-L3)
 pub use l1_ir_build::IRBuildExecutor;
 pub use l2_chunking::ChunkingExecutor;
+pub use l2_6_vector::VectorExecutor;
 pub use l3_cross_file::CrossFileExecutor;
 
 // Re-export stub executors (L4-L37)
@@ -30,7 +30,7 @@ macro_rules! stub_executor {
                 context: &mut PipelineContext,
             ) -> Result<StageResult, CodegraphError> {
                 let start = Instant::now();
-                eprintln!("[{}] Stub execution", self.name());
+                tracing::debug!("[{}] Stub execution", self.name());
 
                 // Validate dependencies
                 for dep in self.dependencies() {
@@ -175,6 +175,16 @@ impl GraphContextBuilder {
         self
     }
 
+    /// Like `with_stage_data`, for data already shared by the pipeline context
+    pub fn with_shared_stage_data(
+        mut self,
+        key: String,
+        data: Arc<dyn std::any::Any + Send + Sync>,
+    ) -> Self {
+        self.stage_data.insert(key, data);
+        self
+    }
+
     pub fn build(self) -> GraphContext {
         GraphContext {
             nodes: Arc::new(self.nodes),
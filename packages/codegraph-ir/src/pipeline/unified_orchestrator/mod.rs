@@ -8,12 +8,24 @@
 //!
 //! See RFC-UNIFIED-ORCHESTRATOR-001 for full architecture.
 
+pub mod buffer;
 pub mod core;
 pub mod executors;
 pub mod memory;
 pub mod pipeline_state;
+pub mod precommit;
+pub mod shared;
 
+pub use buffer::{
+    analyze_buffer, BufferAnalysis, BufferAnalyzer, BufferDiagnostic, BufferImport,
+    DiagnosticSeverity,
+};
 pub use core::{UnifiedOrchestrator, UnifiedOrchestratorConfig};
 pub use executors::StageExecutor;
 pub use memory::{GraphContext, ContextHandle};
 pub use pipeline_state::{PipelineState, IndexingStats};
+pub use precommit::{
+    analyze_diff, parse_unified_diff, ChangedFile, ChangedSymbol, DiffHunk, FileDiff,
+    PrecommitAnalyzer, PrecommitCheck, PrecommitConfig, PrecommitFinding, PrecommitReport,
+};
+pub use shared::{ContextSnapshot, SharedContext};
@@ -0,0 +1,365 @@
+//! Shared Graph Context
+//!
+//! Thread-safe handle to the current `GraphContext`, shared by the batch
+//! pipeline (writer) and buffer analysis / queries (readers).
+//!
+//! # Snapshot semantics
+//!
+//! The context itself is immutable; the handle keeps the current
+//! `ContextHandle` behind an `RwLock` and writers swap in a new `Arc`. A reader's
+//! snapshot is an `Arc` clone, so it sees one consistent version for as long
+//! as it keeps it, even while a re-index publishes a newer one. The read lock
+//! is held only for the pointer copy.
+//!
+//! Writers are serialized; `update` builds the next version from the current
+//! one outside the read/write lock, so readers are never blocked by it.
+
+use super::buffer::{BufferAnalysis, BufferAnalyzer};
+use super::memory::{ContextHandle, GraphContext};
+use super::precommit::{PrecommitAnalyzer, PrecommitReport};
+use crate::shared::models::CodegraphError;
+use std::collections::HashSet;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex, RwLock};
+
+/// One published version of the context
+#[derive(Clone)]
+pub struct ContextSnapshot {
+    /// Monotonic version (0 = initial context)
+    pub version: u64,
+    pub context: ContextHandle,
+}
+
+impl Deref for ContextSnapshot {
+    type Target = GraphContext;
+
+    fn deref(&self) -> &GraphContext {
+        &self.context
+    }
+}
+
+/// Cloneable, thread-safe handle to the current context
+#[derive(Clone)]
+pub struct SharedContext {
+    inner: Arc<SharedInner>,
+}
+
+struct SharedInner {
+    current: RwLock<ContextSnapshot>,
+    /// Serializes writers (`publish` / `update`)
+    writer: Mutex<()>,
+    /// Buffer analyzer for the latest version (indexes the snapshot once)
+    analyzer: Mutex<Option<(u64, Arc<BufferAnalyzer>)>>,
+    /// Pre-commit analyzer (taint summaries) for the latest version
+    precommit: Mutex<Option<(u64, Arc<PrecommitAnalyzer>)>>,
+}
+
+impl SharedContext {
+    pub fn new(context: GraphContext) -> Self {
+        Self::from_handle(Arc::new(context))
+    }
+
+    pub fn from_handle(context: ContextHandle) -> Self {
+        Self {
+            inner: Arc::new(SharedInner {
+                current: RwLock::new(ContextSnapshot {
+                    version: 0,
+                    context,
+                }),
+                writer: Mutex::new(()),
+                analyzer: Mutex::new(None),
+                precommit: Mutex::new(None),
+            }),
+        }
+    }
+
+    /// Current version with its context
+    pub fn snapshot(&self) -> ContextSnapshot {
+        self.inner
+            .current
+            .read()
+            .expect("Failed to acquire context read lock")
+            .clone()
+    }
+
+    /// Current context (Arc clone)
+    pub fn context(&self) -> ContextHandle {
+        self.snapshot().context
+    }
+
+    pub fn version(&self) -> u64 {
+        self.snapshot().version
+    }
+
+    /// Replace the context, returning the new version
+    pub fn publish(&self, context: ContextHandle) -> u64 {
+        let _writer = self
+            .inner
+            .writer
+            .lock()
+            .expect("Failed to acquire context writer lock");
+        self.swap(context)
+    }
+
+    /// Derive the next version from the current one
+    ///
+    /// Readers keep seeing the current version while `f` runs. On error
+    /// nothing is published.
+    pub fn update<F, E>(&self, f: F) -> Result<u64, E>
+    where
+        F: FnOnce(&GraphContext) -> Result<GraphContext, E>,
+    {
+        let _writer = self
+            .inner
+            .writer
+            .lock()
+            .expect("Failed to acquire context writer lock");
+        let current = self.context();
+        let next = f(&current)?;
+        Ok(self.swap(Arc::new(next)))
+    }
+
+    /// Commit a saved buffer: its nodes, edges, occurrences, chunks and symbols
+    /// replace those of the same file
+    pub fn apply_buffer(&self, analysis: &BufferAnalysis) -> u64 {
+        let result: Result<u64, std::convert::Infallible> =
+            self.update(|current| Ok(replace_file(current, analysis)));
+        match result {
+            Ok(version) => version,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Analyze an unsaved buffer against the current version
+    ///
+    /// The snapshot index is rebuilt only when a new version was published.
+    pub fn analyze_buffer(
+        &self,
+        file_path: &str,
+        content: &str,
+    ) -> Result<BufferAnalysis, CodegraphError> {
+        self.buffer_analyzer().1.analyze(file_path, content)
+    }
+
+    /// Check a pre-commit diff against the current version
+    ///
+    /// `read_file` returns the new contents of a changed file. Taint summaries
+    /// are computed once per published version.
+    pub fn analyze_diff<F>(&self, diff: &str, read_file: F) -> PrecommitReport
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        self.precommit_analyzer().analyze(diff, read_file)
+    }
+
+    fn precommit_analyzer(&self) -> Arc<PrecommitAnalyzer> {
+        let (version, buffer) = self.buffer_analyzer();
+        let mut cached = self
+            .inner
+            .precommit
+            .lock()
+            .expect("Failed to acquire pre-commit analyzer lock");
+        match cached.as_ref() {
+            Some((cached_version, analyzer)) if *cached_version == version => Arc::clone(analyzer),
+            _ => {
+                let analyzer = Arc::new(PrecommitAnalyzer::from_buffer_analyzer(buffer));
+                *cached = Some((version, Arc::clone(&analyzer)));
+                analyzer
+            }
+        }
+    }
+
+    /// Cached buffer analyzer with the version it indexes
+    fn buffer_analyzer(&self) -> (u64, Arc<BufferAnalyzer>) {
+        let snapshot = self.snapshot();
+        let mut cached = self
+            .inner
+            .analyzer
+            .lock()
+            .expect("Failed to acquire buffer analyzer lock");
+        match cached.as_ref() {
+            Some((version, analyzer)) if *version == snapshot.version => {
+                (*version, Arc::clone(analyzer))
+            }
+            _ => {
+                let analyzer = Arc::new(BufferAnalyzer::new(snapshot.context));
+                *cached = Some((snapshot.version, Arc::clone(&analyzer)));
+                (snapshot.version, analyzer)
+            }
+        }
+    }
+
+    fn swap(&self, context: ContextHandle) -> u64 {
+        let mut current = self
+            .inner
+            .current
+            .write()
+            .expect("Failed to acquire context write lock");
+        current.version += 1;
+        current.context = context;
+        current.version
+    }
+}
+
+/// `current` with `analysis.file_path` replaced by the buffer's results
+fn replace_file(current: &GraphContext, analysis: &BufferAnalysis) -> GraphContext {
+    let file_path = analysis.file_path.as_str();
+    let stale: HashSet<&str> = current
+        .nodes
+        .iter()
+        .filter(|n| n.file_path == file_path)
+        .map(|n| n.id.as_str())
+        .collect();
+
+    let mut next = current.clone();
+    next.nodes = Arc::new(
+        current
+            .nodes
+            .iter()
+            .filter(|n| n.file_path != file_path)
+            .chain(&analysis.nodes)
+            .cloned()
+            .collect(),
+    );
+    next.edges = Arc::new(
+        current
+            .edges
+            .iter()
+            .filter(|e| !stale.contains(e.source_id.as_str()))
+            .chain(&analysis.edges)
+            .cloned()
+            .collect(),
+    );
+    next.occurrences = Arc::new(
+        current
+            .occurrences
+            .iter()
+            .filter(|o| o.file_path != file_path)
+            .chain(&analysis.occurrences)
+            .cloned()
+            .collect(),
+    );
+    next.chunks = Arc::new(
+        current
+            .chunks
+            .iter()
+            .filter(|c| c.file_path != file_path)
+            .chain(&analysis.chunks)
+            .cloned()
+            .collect(),
+    );
+    next.symbols = Arc::new(
+        current
+            .symbols
+            .iter()
+            .filter(|s| s.file_path != file_path)
+            .chain(&analysis.symbols)
+            .cloned()
+            .collect(),
+    );
+    next
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::memory::GraphContextBuilder;
+    use super::*;
+    use crate::pipeline::unified_processor::process_any_file;
+    use std::thread;
+
+    fn context(files: &[(&str, &str)]) -> GraphContext {
+        let mut nodes = Vec::new();
+        for (path, source) in files {
+            nodes.extend(process_any_file(path, source, "repo").unwrap().nodes);
+        }
+        GraphContextBuilder::new("repo".to_string(), "/repo".to_string())
+            .with_nodes(nodes)
+            .build()
+    }
+
+    #[test]
+    fn test_readers_keep_their_snapshot() {
+        let shared = SharedContext::new(context(&[("a.py", "def f():\n    pass\n")]));
+        let before = shared.snapshot();
+        assert_eq!(before.version, 0);
+
+        let version = shared.publish(Arc::new(context(&[])));
+        assert_eq!(version, 1);
+        assert!(!before.nodes.is_empty());
+        assert!(shared.context().nodes.is_empty());
+
+        // Failed updates publish nothing
+        let result: Result<u64, &str> = shared.update(|_| Err("boom"));
+        assert!(result.is_err());
+        assert_eq!(shared.version(), 1);
+    }
+
+    #[test]
+    fn test_concurrent_readers_and_writer() {
+        let shared = SharedContext::new(GraphContext::new("v0".into(), "/".into()));
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let shared = shared.clone();
+                thread::spawn(move || {
+                    let mut last = 0;
+                    for _ in 0..200 {
+                        let snapshot = shared.snapshot();
+                        // Versions only move forward, and each matches its context
+                        assert!(snapshot.version >= last);
+                        assert_eq!(snapshot.repo_name, format!("v{}", snapshot.version));
+                        last = snapshot.version;
+                    }
+                })
+            })
+            .collect();
+
+        shared.publish(Arc::new(GraphContext::new("v1".into(), "/".into())));
+        for _ in 0..50 {
+            shared
+                .update(|current| {
+                    let version: u64 = current.repo_name[1..].parse().unwrap_or(0);
+                    let mut next = current.clone();
+                    next.repo_name = format!("v{}", version + 1);
+                    Ok::<_, ()>(next)
+                })
+                .unwrap();
+        }
+        for reader in readers {
+            reader.join().unwrap();
+        }
+        assert_eq!(shared.version(), 51);
+    }
+
+    #[test]
+    fn test_apply_buffer_replaces_file() {
+        let shared = SharedContext::new(context(&[
+            ("app/db.py", "def query(sql):\n    return sql\n"),
+            ("app/views.py", "def stale():\n    return 0\n"),
+        ]));
+
+        let analysis = shared
+            .analyze_buffer(
+                "app/views.py",
+                "from app.db import fresh\n\ndef index():\n    return 1\n",
+            )
+            .unwrap();
+        assert_eq!(analysis.diagnostics.len(), 1);
+
+        shared.apply_buffer(&analysis);
+        let names: Vec<_> = shared
+            .context()
+            .nodes
+            .iter()
+            .filter_map(|n| n.name.clone())
+            .collect();
+        assert!(names.contains(&"index".to_string()));
+        assert!(names.contains(&"query".to_string()));
+        assert!(!names.contains(&"stale".to_string()));
+
+        // The cached analyzer follows the new version
+        let analysis = shared
+            .analyze_buffer("app/api.py", "from app.views import index\n")
+            .unwrap();
+        assert!(analysis.diagnostics.is_empty());
+    }
+}
@@ -44,14 +44,12 @@ pub fn get_file_category(file_path: &str) -> FileCategory {
     }
 }
 
+/// Code files are those handled by a registered language plugin
 fn is_code_file(file_path: &str) -> bool {
-    file_path.ends_with(".py")
-        || file_path.ends_with(".java")
-        || file_path.ends_with(".kt")
-        || file_path.ends_with(".rs")
-        || file_path.ends_with(".go")
-        || file_path.ends_with(".ts")
-        || file_path.ends_with(".js")
+    std::path::Path::new(file_path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(crate::features::parsing::sdk::is_supported_extension)
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
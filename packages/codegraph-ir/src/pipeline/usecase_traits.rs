@@ -26,7 +26,7 @@ use crate::features::cross_file::IRDocument;
 use crate::features::effect_analysis::EffectSet;
 use crate::features::concurrency_analysis::RaceCondition;
 use crate::pipeline::stages::TaintSummary;
-use crate::shared::models::{Node, Edge};
+use crate::shared::models::{CodegraphError, Node, Edge};
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -112,7 +112,9 @@ pub struct TaintAnalysisInput {
 /// Implementations perform interprocedural taint tracking.
 pub trait TaintUseCase: Send + Sync {
     /// Analyze taint flows across the codebase
-    fn analyze_taint(&self, input: TaintAnalysisInput) -> Vec<TaintSummary>;
+    ///
+    /// Fails when the configured rules cannot be loaded.
+    fn analyze_taint(&self, input: TaintAnalysisInput) -> Result<Vec<TaintSummary>, CodegraphError>;
 }
 
 /// Default implementation using TaintAnalyzer (infrastructure)
@@ -146,8 +148,9 @@ impl Default for TaintAnalysisUseCaseImpl {
 }
 
 impl TaintUseCase for TaintAnalysisUseCaseImpl {
-    fn analyze_taint(&self, input: TaintAnalysisInput) -> Vec<TaintSummary> {
-        use crate::features::taint_analysis::infrastructure::taint::{TaintAnalyzer, CallGraphNode, TaintPath, TaintSeverity};
+    fn analyze_taint(&self, input: TaintAnalysisInput) -> Result<Vec<TaintSummary>, CodegraphError> {
+        use crate::features::taint_analysis::infrastructure::taint::{CallGraphNode, TaintPath, TaintSeverity};
+        use crate::features::taint_analysis::infrastructure::{LibrarySummaryDb, TaintRuleSet};
 
         // Log config settings
         eprintln!(
@@ -200,8 +203,15 @@ impl TaintUseCase for TaintAnalysisUseCaseImpl {
             }
         }
 
-        // Run taint analysis
-        let analyzer = TaintAnalyzer::new();
+        // Run taint analysis (built-in rules and/or configured rule files)
+        let mut languages: Vec<&str> = input.nodes.iter().map(|n| n.language.as_str()).collect();
+        languages.sort_unstable();
+        languages.dedup();
+        // Never analyze with other rules than the configured ones
+        let mut analyzer = TaintRuleSet::analyzer_for(&self.config, &languages)?;
+
+        // External call targets have no IR: model them with library summaries
+        LibrarySummaryDb::from_config(&self.config)?.apply_to(&mut analyzer, &languages);
         let mut taint_paths = analyzer.analyze(&cg_nodes);
 
         // Apply config: filter sanitized paths if detect_sanitizers is enabled
@@ -288,7 +298,7 @@ impl TaintUseCase for TaintAnalysisUseCaseImpl {
             }
         }
 
-        function_summaries.into_values().collect()
+        Ok(function_summaries.into_values().collect())
     }
 }
 
@@ -482,6 +492,31 @@ mod tests {
         let _result = <ConcurrencyAnalysisUseCase as ConcurrencyUseCase>::analyze_all(&usecase, &ir_doc);
     }
 
+    #[test]
+    fn test_taint_usecase_fails_on_unreadable_rule_file() {
+        let config = TaintConfig::from_preset(Preset::Fast).rule_file("/nonexistent/taint-rules.yaml");
+        let usecase = TaintAnalysisUseCaseImpl::new(config);
+        let input = TaintAnalysisInput {
+            nodes: vec![],
+            edges: vec![],
+        };
+
+        assert!(usecase.analyze_taint(input).is_err());
+    }
+
+    #[test]
+    fn test_taint_usecase_fails_on_unreadable_summary_file() {
+        let config = TaintConfig::from_preset(Preset::Fast)
+            .library_summary_file("/nonexistent/library-summaries.yaml");
+        let usecase = TaintAnalysisUseCaseImpl::new(config);
+        let input = TaintAnalysisInput {
+            nodes: vec![],
+            edges: vec![],
+        };
+
+        assert!(usecase.analyze_taint(input).is_err());
+    }
+
     #[test]
     fn test_trait_object_boxing() {
         // Test that traits can be used as trait objects
@@ -0,0 +1,437 @@
+//! Report localization
+//!
+//! Message catalog for the HTML (snapshot report), Markdown (PR comments)
+//! and SARIF report generators. Every message has a stable dotted ID
+//! (`MessageId::as_str`) that translations and SARIF `messageStrings` key
+//! on; IDs are never renamed once released, only added.
+//!
+//! Templates use SARIF placeholder syntax (`{0}`, `{1}`, ...). Counted
+//! messages may hold `one|other` forms; a locale without plural forms
+//! (Korean) uses a single template.
+//!
+//! ```ignore
+//! let report = ReportOptions::default().with_locale(Locale::Ko);
+//! report.count(MessageId::PrNewVulnerabilities, 2); // "새 취약점 2건 발견"
+//! ```
+
+use std::fmt::{self, Write as _};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// Report language
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    En,
+    Ko,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::En, Locale::Ko];
+
+    /// BCP 47 language tag (HTML `lang`)
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Ko => "ko",
+        }
+    }
+
+    /// `CODEGRAPH_LOCALE`, then `LANG` (e.g. `ko_KR.UTF-8`); English otherwise
+    pub fn from_env() -> Self {
+        ["CODEGRAPH_LOCALE", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find_map(|value| value.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    /// Accepts `en`, `ko`, `ko-KR`, `ko_KR.UTF-8`, ...
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let language = s
+            .split(['-', '_', '.'])
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        match language.as_str() {
+            "en" => Ok(Locale::En),
+            "ko" => Ok(Locale::Ko),
+            _ => Err(format!("unsupported locale '{}' (expected en or ko)", s)),
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Output character encoding
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportEncoding {
+    /// UTF-8 as is
+    #[default]
+    Utf8,
+
+    /// 7-bit ASCII: non-ASCII characters become `&#x..;` references (HTML)
+    /// or `\u` escapes (JSON/SARIF), for viewers and CI systems that
+    /// mangle UTF-8. Markdown is always UTF-8 (code spans would show the
+    /// references literally).
+    Ascii,
+}
+
+impl ReportEncoding {
+    /// Encode a complete HTML document
+    pub fn encode_html(self, html: String) -> String {
+        if self == ReportEncoding::Utf8 || html.is_ascii() {
+            return html;
+        }
+        let mut out = String::with_capacity(html.len() + html.len() / 2);
+        for c in html.chars() {
+            if c.is_ascii() {
+                out.push(c);
+            } else {
+                let _ = write!(out, "&#x{:X};", c as u32);
+            }
+        }
+        out
+    }
+
+    /// Encode serialized JSON (non-ASCII only occurs inside strings)
+    pub fn encode_json(self, json: String) -> String {
+        if self == ReportEncoding::Utf8 || json.is_ascii() {
+            return json;
+        }
+        let mut out = String::with_capacity(json.len() + json.len() / 2);
+        let mut units = [0u16; 2];
+        for c in json.chars() {
+            if c.is_ascii() {
+                out.push(c);
+            } else {
+                for unit in c.encode_utf16(&mut units) {
+                    let _ = write!(out, "\\u{:04x}", unit);
+                }
+            }
+        }
+        out
+    }
+}
+
+macro_rules! messages {
+    ($($variant:ident => $id:literal { en: $en:literal, ko: $ko:literal },)*) => {
+        /// Catalog message
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum MessageId {
+            $($variant,)*
+        }
+
+        impl MessageId {
+            pub const ALL: &'static [MessageId] = &[$(MessageId::$variant,)*];
+
+            /// Stable ID (translation / SARIF `messageStrings` key)
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $(MessageId::$variant => $id,)*
+                }
+            }
+
+            /// Template in `locale`
+            pub fn template(self, locale: Locale) -> &'static str {
+                match (self, locale) {
+                    $(
+                        (MessageId::$variant, Locale::En) => $en,
+                        (MessageId::$variant, Locale::Ko) => $ko,
+                    )*
+                }
+            }
+        }
+    };
+}
+
+messages! {
+    // Common table columns / labels
+    ColumnFile => "column.file" { en: "File", ko: "파일" },
+    ColumnSeverity => "column.severity" { en: "Severity", ko: "심각도" },
+    ColumnRule => "column.rule" { en: "Rule", ko: "규칙" },
+    ColumnLocation => "column.location" { en: "Location", ko: "위치" },
+    ColumnMessage => "column.message" { en: "Message", ko: "메시지" },
+    ColumnDescription => "column.description" { en: "Description", ko: "설명" },
+    ColumnSymbol => "column.symbol" { en: "Symbol", ko: "심볼" },
+    ColumnKind => "column.kind" { en: "Kind", ko: "종류" },
+    ColumnFrom => "column.from" { en: "From", ko: "출발" },
+    ColumnTo => "column.to" { en: "To", ko: "도착" },
+    ColumnBase => "column.base" { en: "Base", ko: "기준" },
+    ColumnHead => "column.head" { en: "Head", ko: "변경" },
+    ColumnMetric => "column.metric" { en: "Metric", ko: "항목" },
+    ColumnValue => "column.value" { en: "Value", ko: "값" },
+    ChangeAdded => "change.added" { en: "added", ko: "추가" },
+    ChangeRemoved => "change.removed" { en: "removed", ko: "삭제" },
+    ChangeModified => "change.modified" { en: "modified", ko: "수정" },
+
+    // Snapshot comparison report (HTML)
+    SnapshotTitle => "snapshot.title" { en: "Snapshot report", ko: "스냅샷 비교 보고서" },
+    SnapshotGenerated => "snapshot.generated" { en: "generated {0}", ko: "생성 시각 {0}" },
+    SnapshotSymbolsAdded => "snapshot.card.symbols_added" { en: "Symbols added", ko: "추가된 심볼" },
+    SnapshotSymbolsRemoved => "snapshot.card.symbols_removed" { en: "Symbols removed", ko: "삭제된 심볼" },
+    SnapshotSymbolsModified => "snapshot.card.symbols_modified" { en: "Symbols modified", ko: "수정된 심볼" },
+    SnapshotPackageDepsChanged => "snapshot.card.package_deps_changed" { en: "Package deps changed", ko: "변경된 패키지 의존성" },
+    SnapshotFindingsIntroduced => "snapshot.card.findings_introduced" { en: "Findings introduced", ko: "새 발견 항목" },
+    SnapshotFindingsResolved => "snapshot.card.findings_resolved" { en: "Findings resolved", ko: "해결된 발견 항목" },
+    SnapshotHotZones => "snapshot.hot_zones" { en: "Impact hot zones", ko: "영향 집중 구역" },
+    SnapshotNoChangedSymbols => "snapshot.hot_zones.empty" { en: "No changed symbols.", ko: "변경된 심볼이 없습니다." },
+    SnapshotChangedColumn => "snapshot.hot_zones.changed" { en: "Changed", ko: "변경" },
+    SnapshotImpactedSymbols => "snapshot.hot_zones.impacted_symbols" { en: "Impacted symbols", ko: "영향받는 심볼" },
+    SnapshotImpactedFiles => "snapshot.hot_zones.impacted_files" { en: "Impacted files", ko: "영향받는 파일" },
+    SnapshotSymbolChanges => "snapshot.symbols" { en: "Symbol changes", ko: "심볼 변경" },
+    SnapshotNoSymbolChanges => "snapshot.symbols.empty" { en: "No symbol changes.", ko: "심볼 변경이 없습니다." },
+    SnapshotPackageChanges => "snapshot.packages" { en: "Package dependency changes", ko: "패키지 의존성 변경" },
+    SnapshotNoPackageChanges => "snapshot.packages.empty" { en: "No dependency changes between packages.", ko: "패키지 간 의존성 변경이 없습니다." },
+    SnapshotFindingDeltas => "snapshot.findings" { en: "Finding deltas", ko: "발견 항목 변화" },
+    SnapshotNoFindingChanges => "snapshot.findings.empty" { en: "No finding changes.", ko: "발견 항목 변화가 없습니다." },
+    SnapshotFindingIntroduced => "snapshot.findings.introduced" { en: "introduced", ko: "신규" },
+    SnapshotFindingResolved => "snapshot.findings.resolved" { en: "resolved", ko: "해결" },
+
+    // Differential taint PR/MR comment (Markdown)
+    PrTitle => "pr.title" { en: "🔒 Security Regression Analysis", ko: "🔒 보안 회귀 분석" },
+    PrStatusCritical => "pr.status.critical" { en: "Action Required", ko: "조치 필요" },
+    PrStatusReview => "pr.status.review" { en: "Review Required", ko: "검토 필요" },
+    PrStatusImproved => "pr.status.improved" { en: "Improved", ko: "개선됨" },
+    PrStatusNoImpact => "pr.status.no_impact" { en: "No Security Impact", ko: "보안 영향 없음" },
+    PrNewVulnerabilities => "pr.new_vulnerabilities" {
+        en: "🚨 {0} New Vulnerability Detected|🚨 {0} New Vulnerabilities Detected",
+        ko: "🚨 새 취약점 {0}건 발견"
+    },
+    PrFlowColumn => "pr.column.flow" { en: "Source → Sink", ko: "소스 → 싱크" },
+    PrDetailedAnalysis => "pr.details" { en: "🔍 Detailed Analysis (Click to expand)", ko: "🔍 상세 분석 (클릭하여 펼치기)" },
+    PrTaintFlow => "pr.details.taint_flow" { en: "Taint Flow", ko: "오염 흐름" },
+    PrFlowSource => "pr.details.source" { en: "Source: {0} (line {1})", ko: "소스: {0} ({1}행)" },
+    PrFlowPathCondition => "pr.details.path_condition" { en: "Path Condition: {0}", ko: "경로 조건: {0}" },
+    PrFlowSink => "pr.details.sink" { en: "Sink: {0} (line {1})", ko: "싱크: {0} ({1}행)" },
+    PrRegression => "pr.details.regression" {
+        en: "⚠️ **This was safe in the base version** - a regression was introduced.",
+        ko: "⚠️ **기준 버전에서는 안전했습니다** - 회귀가 발생했습니다."
+    },
+    PrFixedVulnerabilities => "pr.fixed_vulnerabilities" {
+        en: "✅ {0} Vulnerability Fixed|✅ {0} Vulnerabilities Fixed",
+        ko: "✅ 취약점 {0}건 수정됨"
+    },
+    PrViewFixed => "pr.fixed_vulnerabilities.view" { en: "View fixed issues", ko: "수정된 이슈 보기" },
+    PrFixedEntry => "pr.fixed_vulnerabilities.entry" { en: "**{0}** in `{1}`: {2} → {3}", ko: "`{1}`의 **{0}**: {2} → {3}" },
+    PrSanitizersRemoved => "pr.sanitizers_removed" {
+        en: "⚠️ {0} Sanitizer Removed|⚠️ {0} Sanitizers Removed",
+        ko: "⚠️ 새니타이저 {0}개 제거됨"
+    },
+    PrSanitizersRemovedIntro => "pr.sanitizers_removed.intro" { en: "The following security controls were removed:", ko: "다음 보안 통제가 제거되었습니다:" },
+    PrSanitizersAdded => "pr.sanitizers_added" {
+        en: "🛡️ {0} Sanitizer Added|🛡️ {0} Sanitizers Added",
+        ko: "🛡️ 새니타이저 {0}개 추가됨"
+    },
+    PrSanitizersAddedIntro => "pr.sanitizers_added.intro" { en: "New security controls added:", ko: "새 보안 통제가 추가되었습니다:" },
+    PrSanitizerEntry => "pr.sanitizer.entry" { en: "`{0}` at `{1}`", ko: "`{1}`의 `{0}`" },
+    PrPartialFixes => "pr.partial_fixes" {
+        en: "⏳ {0} Partial Fix|⏳ {0} Partial Fixes",
+        ko: "⏳ 부분 수정 {0}건"
+    },
+    PrPartialFixesIntro => "pr.partial_fixes.intro" {
+        en: "These vulnerabilities are partially addressed but still have remaining attack paths:",
+        ko: "다음 취약점은 일부만 해결되어 공격 경로가 남아 있습니다:"
+    },
+    PrPartialFixEntry => "pr.partial_fixes.entry" { en: "{0}/{1} paths fixed ({2}% coverage)", ko: "경로 {1}개 중 {0}개 수정 ({2}%)" },
+    PrStatistics => "pr.statistics" { en: "📊 Analysis Statistics", ko: "📊 분석 통계" },
+    PrBaseVulnerabilities => "pr.statistics.base_vulnerabilities" { en: "Base vulnerabilities", ko: "기준 버전 취약점" },
+    PrModifiedVulnerabilities => "pr.statistics.modified_vulnerabilities" { en: "Modified vulnerabilities", ko: "변경 버전 취약점" },
+    PrFilesAnalyzed => "pr.statistics.files_analyzed" { en: "Files analyzed", ko: "분석한 파일" },
+    PrAnalysisTime => "pr.statistics.analysis_time" { en: "Analysis time", ko: "분석 시간" },
+    PrFooter => "pr.footer" {
+        en: "*Generated by [Semantica Differential Taint Analysis]({0})*",
+        ko: "*[Semantica 차등 오염 분석]({0})으로 생성됨*"
+    },
+
+    // SARIF rules / results
+    SarifTaintRule => "sarif.taint.rule" {
+        en: "Untrusted data reaches a sensitive sink ({0})",
+        ko: "신뢰할 수 없는 데이터가 민감한 싱크에 도달합니다 ({0})"
+    },
+    SarifTaintHelp => "sarif.taint.help" {
+        en: "Validate or sanitize the value between source and sink.",
+        ko: "소스와 싱크 사이에서 값을 검증하거나 정화하세요."
+    },
+    SarifTaintFlow => "sarif.taint.flow" {
+        en: "Tainted data flows from `{0}` ({1}) to `{2}` ({3})",
+        ko: "오염된 데이터가 `{0}` ({1})에서 `{2}` ({3})(으)로 흐릅니다"
+    },
+    SarifTaintFlowSanitized => "sarif.taint.flow_sanitized" {
+        en: "Tainted data flows from `{0}` ({1}) to `{2}` ({3}); sanitized by {4}",
+        ko: "오염된 데이터가 `{0}` ({1})에서 `{2}` ({3})(으)로 흐릅니다. {4}에 의해 정화됨"
+    },
+    SarifUnknownSanitizer => "sarif.taint.unknown_sanitizer" { en: "an unknown sanitizer", ko: "알 수 없는 새니타이저" },
+    SarifMemoryRule => "sarif.memory.rule" { en: "Memory safety: {0}", ko: "메모리 안전성: {0}" },
+}
+
+/// Substitute `{0}`, `{1}`, ... (unknown placeholders are kept as is)
+pub fn format_template(template: &str, args: &[&str]) -> String {
+    let mut out =
+        String::with_capacity(template.len() + args.iter().map(|a| a.len()).sum::<usize>());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let arg = after
+            .find('}')
+            .and_then(|close| Some((close, args.get(after[..close].parse::<usize>().ok()?)?)));
+        match arg {
+            Some((close, value)) => {
+                out.push_str(value);
+                rest = &after[close + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Locale and encoding of a generated report
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReportOptions {
+    #[serde(default)]
+    pub locale: Locale,
+
+    #[serde(default)]
+    pub encoding: ReportEncoding,
+}
+
+impl ReportOptions {
+    /// Builder: Set locale
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Builder: Set encoding
+    pub fn with_encoding(mut self, encoding: ReportEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Message without arguments
+    pub fn text(&self, id: MessageId) -> &'static str {
+        id.template(self.locale)
+    }
+
+    /// Message with `{n}` placeholders filled
+    pub fn format(&self, id: MessageId, args: &[&str]) -> String {
+        format_template(id.template(self.locale), args)
+    }
+
+    /// Counted message (`{0}` = count, `one|other` forms)
+    pub fn count(&self, id: MessageId, count: usize) -> String {
+        let template = id.template(self.locale);
+        let form = match template.split_once('|') {
+            Some((one, _)) if count == 1 => one,
+            Some((_, other)) => other,
+            None => template,
+        };
+        format_template(form, &[&count.to_string()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn placeholders(template: &str) -> HashSet<usize> {
+        (0..10)
+            .filter(|i| template.contains(&format!("{{{}}}", i)))
+            .collect()
+    }
+
+    #[test]
+    fn test_catalog_ids_unique_and_translations_consistent() {
+        let ids: HashSet<&str> = MessageId::ALL.iter().map(|id| id.as_str()).collect();
+        assert_eq!(ids.len(), MessageId::ALL.len());
+
+        for &id in MessageId::ALL {
+            let en = id.template(Locale::En);
+            for locale in Locale::ALL {
+                let template = id.template(locale);
+                assert!(
+                    !template.is_empty(),
+                    "{} missing in {}",
+                    id.as_str(),
+                    locale
+                );
+                assert_eq!(
+                    placeholders(template),
+                    placeholders(en),
+                    "{} placeholders differ in {}",
+                    id.as_str(),
+                    locale
+                );
+            }
+        }
+        // Released IDs stay stable
+        assert_eq!(MessageId::SarifTaintFlow.as_str(), "sarif.taint.flow");
+        assert_eq!(
+            MessageId::PrNewVulnerabilities.as_str(),
+            "pr.new_vulnerabilities"
+        );
+    }
+
+    #[test]
+    fn test_format_and_plural_forms() {
+        let en = ReportOptions::default();
+        assert_eq!(
+            en.count(MessageId::PrNewVulnerabilities, 1),
+            "🚨 1 New Vulnerability Detected"
+        );
+        assert_eq!(
+            en.count(MessageId::PrNewVulnerabilities, 3),
+            "🚨 3 New Vulnerabilities Detected"
+        );
+        let ko = en.with_locale(Locale::Ko);
+        assert_eq!(
+            ko.count(MessageId::PrNewVulnerabilities, 3),
+            "🚨 새 취약점 3건 발견"
+        );
+        assert_eq!(
+            ko.format(MessageId::PrFlowSource, &["input", "12"]),
+            "소스: input (12행)"
+        );
+        assert_eq!(
+            format_template("{0} {x} {1} {5}", &["a", "b"]),
+            "a {x} b {5}"
+        );
+
+        assert_eq!("ko_KR.UTF-8".parse::<Locale>(), Ok(Locale::Ko));
+        assert_eq!("en-US".parse::<Locale>(), Ok(Locale::En));
+        assert!("fr".parse::<Locale>().is_err());
+    }
+
+    #[test]
+    fn test_ascii_encoding() {
+        let ascii = ReportEncoding::Ascii;
+        assert_eq!(
+            ascii.encode_html("<b>분석</b>".to_string()),
+            "<b>&#xBD84;&#xC11D;</b>"
+        );
+        let json = serde_json::to_string(&serde_json::json!({"m": "분석 🔒"})).unwrap();
+        let encoded = ascii.encode_json(json);
+        assert!(encoded.is_ascii());
+        let decoded: serde_json::Value = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(decoded["m"], "분석 🔒");
+        assert_eq!(ReportEncoding::Utf8.encode_html("분석".to_string()), "분석");
+    }
+}
@@ -6,12 +6,14 @@
 #[macro_use]
 pub mod macros;
 pub mod constants;
+pub mod i18n;
 pub mod models;
 pub mod parallel_optimizer;
 pub mod ports;
 pub mod utils;
 
 // Re-exports for convenience
+pub use i18n::{Locale, MessageId, ReportEncoding, ReportOptions};
 pub use models::*;
 pub use parallel_optimizer::{
     global_optimizer, init_global_optimizer, AdaptiveThreadPoolOptimizer, WorkloadConfig,
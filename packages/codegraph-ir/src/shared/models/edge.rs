@@ -3,7 +3,7 @@
 //! Represents relationships between nodes.
 //! Matches Python Edge dataclass.
 
-use super::edge_context::{ControlFlowContext, ReadWriteContext};
+use super::edge_context::{ControlFlowContext, EdgeStage, ReadWriteContext};
 use super::span::Span;
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
@@ -453,6 +453,53 @@ pub struct EdgeMetadata {
     /// Is this edge conditional?
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_conditional: Option<bool>,
+
+    /// For CALLS: awaited, scheduled as a task, or run as a promise continuation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_async: Option<bool>,
+
+    /// Which stage/rule created this edge (None for legacy edges)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<EdgeProvenance>,
+}
+
+/// Edge provenance - which pipeline stage and rule produced an edge
+///
+/// Example: a CALLS edge added by cross-file resolution carries
+/// `EdgeProvenance { stage: CrossFile, rule: Some("import_resolution"), .. }`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EdgeProvenance {
+    /// Stage that created the edge
+    pub stage: EdgeStage,
+
+    /// Rule within the stage (e.g., "call_site", "class_bases", "vtable")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule: Option<String>,
+
+    /// Confidence in [0.0, 1.0] (None = not scored)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+}
+
+impl EdgeProvenance {
+    pub fn new(stage: EdgeStage) -> Self {
+        Self {
+            stage,
+            rule: None,
+            confidence: None,
+        }
+    }
+
+    pub fn with_rule(mut self, rule: impl Into<String>) -> Self {
+        self.rule = Some(rule.into());
+        self
+    }
+
+    /// Set confidence (clamped to [0.0, 1.0])
+    pub fn with_confidence(mut self, confidence: f32) -> Self {
+        self.confidence = Some(confidence.clamp(0.0, 1.0));
+        self
+    }
 }
 
 impl EdgeMetadata {
@@ -492,6 +539,14 @@ impl EdgeMetadata {
     pub fn get_cf_context(&self) -> Option<ControlFlowContext> {
         self.cf_context
     }
+
+    /// Create metadata with provenance only
+    pub fn with_provenance(provenance: EdgeProvenance) -> Self {
+        Self {
+            provenance: Some(provenance),
+            ..Default::default()
+        }
+    }
 }
 
 impl Edge {
@@ -533,6 +588,35 @@ impl Edge {
         self
     }
 
+    /// Record which stage/rule created this edge
+    pub fn with_provenance(mut self, provenance: EdgeProvenance) -> Self {
+        let mut metadata = self.metadata.take().unwrap_or_default();
+        metadata.provenance = Some(provenance);
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Provenance of this edge, if recorded
+    pub fn provenance(&self) -> Option<&EdgeProvenance> {
+        self.metadata.as_ref().and_then(|m| m.provenance.as_ref())
+    }
+
+    /// Mark a CALLS edge as an async invocation
+    pub fn with_is_async(mut self, is_async: bool) -> Self {
+        let mut metadata = self.metadata.take().unwrap_or_default();
+        metadata.is_async = Some(is_async);
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Whether this edge is an async invocation
+    pub fn is_async(&self) -> bool {
+        self.metadata
+            .as_ref()
+            .and_then(|m| m.is_async)
+            .unwrap_or(false)
+    }
+
     /// Create a CONTAINS edge
     pub fn contains(parent_id: impl Into<String>, child_id: impl Into<String>) -> Self {
         Self::new(parent_id.into(), child_id.into(), EdgeKind::Contains)
@@ -695,6 +779,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_edge_with_provenance() {
+        let edge = Edge::calls("caller", "callee")
+            .with_rw_context(ReadWriteContext::ArgumentPassing)
+            .with_provenance(
+                EdgeProvenance::new(EdgeStage::CrossFile)
+                    .with_rule("import_resolution")
+                    .with_confidence(1.5),
+            );
+
+        let provenance = edge.provenance().unwrap();
+        assert_eq!(provenance.stage, EdgeStage::CrossFile);
+        assert_eq!(provenance.rule.as_deref(), Some("import_resolution"));
+        assert_eq!(provenance.confidence, Some(1.0));
+        // Existing metadata is preserved
+        assert_eq!(
+            edge.metadata.as_ref().unwrap().rw_context,
+            Some(ReadWriteContext::ArgumentPassing)
+        );
+
+        let json = serde_json::to_string(&edge).unwrap();
+        let parsed: Edge = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.provenance(), edge.provenance());
+        assert!(Edge::calls("a", "b").provenance().is_none());
+    }
+
     #[test]
     fn test_backward_compatibility_string_context() {
         // Old code using string context should still work
@@ -162,6 +162,92 @@ impl std::fmt::Display for ControlFlowContext {
     }
 }
 
+/// Pipeline stage that produced an edge (edge provenance)
+///
+/// Used to answer "who created this edge?" when debugging wrong CALLS/READS
+/// edges: L1 IR build, cross-file resolution, devirtualization, etc.
+#[cfg_attr(feature = "python", pyclass)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EdgeStage {
+    /// L1: IR generation (syntactic extraction)
+    IrBuild,
+    /// L2: Cross-file import/symbol resolution
+    CrossFile,
+    /// Type inference / type resolution
+    TypeResolution,
+    /// Virtual/dynamic dispatch resolution
+    Devirtualization,
+    /// Points-to analysis
+    PointsTo,
+    /// Data flow graph construction
+    DataFlow,
+    /// CFG/BFG construction
+    FlowGraph,
+    /// Produced outside the pipeline (user API, importer)
+    External,
+    /// Unknown origin (legacy edges)
+    Unknown,
+}
+
+impl EdgeStage {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::IrBuild => "ir_build",
+            Self::CrossFile => "cross_file",
+            Self::TypeResolution => "type_resolution",
+            Self::Devirtualization => "devirtualization",
+            Self::PointsTo => "points_to",
+            Self::DataFlow => "data_flow",
+            Self::FlowGraph => "flow_graph",
+            Self::External => "external",
+            Self::Unknown => "unknown",
+        }
+    }
+
+    /// Parse from string (case-insensitive, accepts L1/L2 aliases)
+    pub fn from_str(s: &str) -> Self {
+        if s.eq_ignore_ascii_case("ir_build") || s.eq_ignore_ascii_case("l1") {
+            Self::IrBuild
+        } else if s.eq_ignore_ascii_case("cross_file") || s.eq_ignore_ascii_case("l2") {
+            Self::CrossFile
+        } else if s.eq_ignore_ascii_case("type_resolution") {
+            Self::TypeResolution
+        } else if s.eq_ignore_ascii_case("devirtualization") || s.eq_ignore_ascii_case("devirt") {
+            Self::Devirtualization
+        } else if s.eq_ignore_ascii_case("points_to") {
+            Self::PointsTo
+        } else if s.eq_ignore_ascii_case("data_flow") || s.eq_ignore_ascii_case("dfg") {
+            Self::DataFlow
+        } else if s.eq_ignore_ascii_case("flow_graph") || s.eq_ignore_ascii_case("cfg") {
+            Self::FlowGraph
+        } else if s.eq_ignore_ascii_case("external") {
+            Self::External
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl EdgeStage {
+    #[pyo3(name = "__str__")]
+    fn py_str(&self) -> &'static str {
+        self.as_str()
+    }
+
+    #[pyo3(name = "__repr__")]
+    fn py_repr(&self) -> String {
+        format!("EdgeStage.{:?}", self)
+    }
+}
+
+impl std::fmt::Display for EdgeStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -194,6 +280,15 @@ mod tests {
         assert_eq!(ControlFlowContext::TrueBranch.as_str(), "true_branch");
     }
 
+    #[test]
+    fn test_edge_stage_from_str() {
+        assert_eq!(EdgeStage::from_str("cross_file"), EdgeStage::CrossFile);
+        assert_eq!(EdgeStage::from_str("L1"), EdgeStage::IrBuild);
+        assert_eq!(EdgeStage::from_str("DEVIRT"), EdgeStage::Devirtualization);
+        assert_eq!(EdgeStage::from_str("bogus"), EdgeStage::Unknown);
+        assert_eq!(EdgeStage::PointsTo.as_str(), "points_to");
+    }
+
     #[test]
     fn test_serde() {
         let ctx = ReadWriteContext::Assignment;
@@ -39,6 +39,8 @@ pub enum ErrorKind {
     Storage,
     /// Internal errors (bugs)
     Internal,
+    /// Run stopped on request (see `pipeline::CancellationToken`)
+    Cancelled,
 }
 
 impl ErrorKind {
@@ -57,6 +59,7 @@ impl ErrorKind {
             ErrorKind::IO => "io",
             ErrorKind::Storage => "storage",
             ErrorKind::Internal => "internal",
+            ErrorKind::Cancelled => "cancelled",
         }
     }
 }
@@ -126,9 +129,25 @@ impl CodegraphError {
         Self::new(ErrorKind::Storage, message)
     }
 
+    pub fn config(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Config, message)
+    }
+
+    pub fn io(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::IO, message)
+    }
+
     pub fn internal(message: impl Into<String>) -> Self {
         Self::new(ErrorKind::Internal, message)
     }
+
+    pub fn cancelled(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Cancelled, message)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.kind == ErrorKind::Cancelled
+    }
 }
 
 impl fmt::Display for CodegraphError {
@@ -164,6 +183,7 @@ impl From<pyo3::PyErr> for CodegraphError {
 }
 
 // Storage Backend Error Conversions (PostgreSQL)
+#[cfg(feature = "postgres")]
 impl From<sqlx::Error> for CodegraphError {
     fn from(err: sqlx::Error) -> Self {
         CodegraphError::storage(format!("Database error: {}", err)).with_source(err)
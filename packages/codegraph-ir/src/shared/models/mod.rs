@@ -4,21 +4,25 @@ mod edge;
 mod edge_context;
 mod error;
 pub mod expression;
+mod file_table;
 mod node;
 pub mod occurrence;
 pub mod occurrence_arena;
 mod span;
 pub mod span_ref;
+mod stable_id;
 pub mod template;
 pub mod type_entities; // L1: High-Level Expression IR (SOTA 2025)
 
-pub use edge::{Edge, EdgeKind, EdgeMetadata};
-pub use edge_context::{ControlFlowContext, ReadWriteContext};
+pub use edge::{Edge, EdgeKind, EdgeMetadata, EdgeProvenance};
+pub use edge_context::{ControlFlowContext, EdgeStage, ReadWriteContext};
 pub use error::{CodegraphError, Result};
+pub use file_table::{FileId, FileTable};
 pub use node::{Node, NodeBuilder, NodeKind};
 pub use occurrence::{Occurrence, OccurrenceGenerator, SymbolRole, SymbolRoles};
 pub use occurrence_arena::{ArenaStats, InternerStats, OccurrenceArena};
-pub use span::Span;
+pub use span::{Location, Span};
+pub use stable_id::{is_stable_id, stable_edge_id, stable_node_id, IdRemap, STABLE_ID_VERSION};
 
 // Template parsing models (SOTA 2025)
 pub use template::{
@@ -14,6 +14,7 @@
  */
 
 use crate::shared::models::Span;
+use serde::{Deserialize, Serialize};
 
 /// String reference via Span (zero-copy)
 ///
@@ -22,7 +23,7 @@ use crate::shared::models::Span;
 /// - Reference to source
 ///
 /// Extract text only when needed.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpanRef {
     pub span: Span,
 }
@@ -69,7 +70,7 @@ impl SpanRef {
 }
 
 /// Block with zero-copy text reference
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlockRef {
     pub id: String,
     pub kind: String,
@@ -0,0 +1,289 @@
+//! Stable node/edge IDs across snapshots
+//!
+//! `Node::id` is whatever the producer chose: plugin extractors number nodes
+//! in visit order (`repo:file:node:7`), the IR builder hashes the FQN but
+//! collides on duplicates. Neither can be matched across snapshots after
+//! unrelated edits. Stable IDs (`Node::stable_id`) depend only on what a
+//! symbol is, never on where it sits in the file.
+//!
+//! # Scheme (version 1)
+//!
+//! ```text
+//! node: "n1:" + hex(sha256("node\0{file_path}\0{kind}\0{fqn}\0{ordinal}"))[..32]
+//! edge: "e1:" + hex(sha256("edge\0{kind}\0{source}\0{target}\0{ordinal}"))[..32]
+//! ```
+//!
+//! - `kind` is `NodeKind::as_str` / `EdgeKind::as_str`
+//! - `ordinal` tells apart nodes of one file with the same kind and FQN
+//!   (redefinitions, overloads, anonymous blocks), numbered in source order;
+//!   0 for the first, so unique symbols never depend on position
+//! - edge endpoints are stable node IDs where known, else the raw target
+//!   (unresolved FQN); parallel edges are numbered in input order
+//!
+//! IDs are unique within a repository and unchanged while file path, kind
+//! and FQN are. A new scheme bumps `STABLE_ID_VERSION` and the prefix so
+//! IDs of different versions never compare equal.
+//!
+//! `IdRemap` maps producer (legacy) IDs to stable ones: it resolves edge
+//! endpoints and rewrites stored graphs that predate stable IDs.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+use super::{Edge, EdgeKind, Node, NodeKind};
+
+/// Version of the stable ID scheme
+pub const STABLE_ID_VERSION: u32 = 1;
+
+const NODE_PREFIX: &str = "n1:";
+const EDGE_PREFIX: &str = "e1:";
+
+/// Stable ID of a node
+pub fn stable_node_id(file_path: &str, kind: NodeKind, fqn: &str, ordinal: usize) -> String {
+    hashed_id(
+        NODE_PREFIX,
+        &["node", file_path, kind.as_str(), fqn, &ordinal.to_string()],
+    )
+}
+
+/// Stable ID of an edge between stable node IDs (or unresolved targets)
+pub fn stable_edge_id(kind: EdgeKind, source_id: &str, target_id: &str, ordinal: usize) -> String {
+    hashed_id(
+        EDGE_PREFIX,
+        &[
+            "edge",
+            kind.as_str(),
+            source_id,
+            target_id,
+            &ordinal.to_string(),
+        ],
+    )
+}
+
+/// ID produced by the current scheme
+pub fn is_stable_id(id: &str) -> bool {
+    id.starts_with(NODE_PREFIX) || id.starts_with(EDGE_PREFIX)
+}
+
+fn hashed_id(prefix: &str, parts: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            hasher.update([0u8]);
+        }
+        hasher.update(part.as_bytes());
+    }
+    let hex = format!("{:x}", hasher.finalize());
+    format!("{}{}", prefix, &hex[..32])
+}
+
+/// Legacy (producer) node ID → stable node ID
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdRemap {
+    /// `STABLE_ID_VERSION` the stable IDs were computed with
+    pub version: u32,
+    stable: HashMap<String, String>,
+}
+
+impl Default for IdRemap {
+    fn default() -> Self {
+        Self {
+            version: STABLE_ID_VERSION,
+            stable: HashMap::new(),
+        }
+    }
+}
+
+impl IdRemap {
+    /// Remap for `nodes` (any number of files)
+    ///
+    /// A legacy ID shared by several nodes maps to the first in source order.
+    pub fn from_nodes(nodes: &[Node]) -> Self {
+        let mut order: Vec<usize> = (0..nodes.len()).collect();
+        order.sort_by(|&a, &b| {
+            let (a, b) = (&nodes[a], &nodes[b]);
+            (&a.file_path, a.span.start_line, a.span.start_col).cmp(&(
+                &b.file_path,
+                b.span.start_line,
+                b.span.start_col,
+            ))
+        });
+
+        let mut ordinals: HashMap<(&str, NodeKind, &str), usize> = HashMap::new();
+        let mut remap = Self::default();
+        for i in order {
+            let node = &nodes[i];
+            let ordinal = ordinals
+                .entry((node.file_path.as_str(), node.kind, node.fqn.as_str()))
+                .or_insert(0);
+            let stable = stable_node_id(&node.file_path, node.kind, &node.fqn, *ordinal);
+            *ordinal += 1;
+            remap.stable.entry(node.id.clone()).or_insert(stable);
+        }
+        remap
+    }
+
+    /// Set `Node::stable_id` on every node (IDs stay as they are)
+    pub fn assign(nodes: &mut [Node]) -> Self {
+        let remap = Self::from_nodes(nodes);
+        for node in nodes.iter_mut() {
+            node.stable_id = remap.stable(&node.id).map(str::to_string);
+        }
+        remap
+    }
+
+    /// Stable ID for a legacy ID
+    pub fn stable(&self, legacy_id: &str) -> Option<&str> {
+        self.stable.get(legacy_id).map(String::as_str)
+    }
+
+    /// Stable ID for `id` if known, else `id` (already stable, or an
+    /// unresolved reference such as a callee FQN)
+    pub fn resolve<'a>(&'a self, id: &'a str) -> &'a str {
+        self.stable(id).unwrap_or(id)
+    }
+
+    /// Stable IDs of `edges`, in order
+    pub fn edge_ids(&self, edges: &[Edge]) -> Vec<String> {
+        let mut ordinals: HashMap<(EdgeKind, &str, &str), usize> = HashMap::new();
+        edges
+            .iter()
+            .map(|edge| {
+                let source = self.resolve(&edge.source_id);
+                let target = self.resolve(&edge.target_id);
+                let ordinal = ordinals.entry((edge.kind, source, target)).or_insert(0);
+                let id = stable_edge_id(edge.kind, source, target, *ordinal);
+                *ordinal += 1;
+                id
+            })
+            .collect()
+    }
+
+    /// Replace legacy IDs by stable ones in a stored graph
+    ///
+    /// Node IDs, parent IDs and edge endpoints are rewritten; references the
+    /// remap does not know are left alone.
+    pub fn rewrite(&self, nodes: &mut [Node], edges: &mut [Edge]) {
+        for node in nodes.iter_mut() {
+            if let Some(stable) = self.stable(&node.id) {
+                node.stable_id = Some(stable.to_string());
+                node.id = stable.to_string();
+            }
+            if let Some(parent) = node.parent_id.as_mut() {
+                if let Some(stable) = self.stable.get(parent.as_str()) {
+                    *parent = stable.clone();
+                }
+            }
+        }
+        for edge in edges.iter_mut() {
+            if let Some(stable) = self.stable.get(&edge.source_id) {
+                edge.source_id = stable.clone();
+            }
+            if let Some(stable) = self.stable.get(&edge.target_id) {
+                edge.target_id = stable.clone();
+            }
+        }
+    }
+
+    /// Add the mappings of another remap (e.g. per-file remaps of one snapshot)
+    pub fn extend(&mut self, other: IdRemap) {
+        for (legacy, stable) in other.stable {
+            self.stable.entry(legacy).or_insert(stable);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.stable.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stable.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::models::Span;
+
+    fn node(id: &str, kind: NodeKind, fqn: &str, line: u32) -> Node {
+        Node::new(
+            id.to_string(),
+            kind,
+            fqn.to_string(),
+            "app/views.py".to_string(),
+            Span::new(line, 0, line + 1, 0),
+        )
+    }
+
+    #[test]
+    fn test_ids_survive_unrelated_edits() {
+        let before = vec![
+            node("repo:f:node:1", NodeKind::Function, "app.views.index", 1),
+            node("repo:f:node:2", NodeKind::Function, "app.views.detail", 10),
+        ];
+        // A function inserted at the top renumbers and shifts everything
+        let after = vec![
+            node("repo:f:node:1", NodeKind::Function, "app.views.health", 1),
+            node("repo:f:node:2", NodeKind::Function, "app.views.index", 5),
+            node("repo:f:node:3", NodeKind::Function, "app.views.detail", 14),
+        ];
+
+        let before = IdRemap::from_nodes(&before);
+        let after = IdRemap::from_nodes(&after);
+        assert_eq!(
+            before.stable("repo:f:node:1"),
+            after.stable("repo:f:node:2")
+        );
+        assert_eq!(
+            before.stable("repo:f:node:2"),
+            after.stable("repo:f:node:3")
+        );
+        assert!(is_stable_id(after.stable("repo:f:node:1").unwrap()));
+    }
+
+    #[test]
+    fn test_duplicates_are_numbered_in_source_order() {
+        let mut nodes = vec![
+            node("b", NodeKind::Variable, "app.views.x", 8),
+            node("a", NodeKind::Variable, "app.views.x", 3),
+            node("c", NodeKind::Function, "app.views.x", 12),
+        ];
+        let remap = IdRemap::assign(&mut nodes);
+
+        let first = stable_node_id("app/views.py", NodeKind::Variable, "app.views.x", 0);
+        assert_eq!(nodes[1].stable_id.as_deref(), Some(first.as_str()));
+        assert_ne!(nodes[0].stable_id, nodes[1].stable_id);
+        assert_ne!(nodes[0].stable_id, nodes[2].stable_id);
+        assert_eq!(nodes[0].id, "b");
+        assert_eq!(remap.len(), 3);
+    }
+
+    #[test]
+    fn test_rewrite_legacy_graph() {
+        let mut nodes = vec![
+            node("legacy:1", NodeKind::Class, "app.views.View", 1),
+            node("legacy:2", NodeKind::Method, "app.views.View.get", 2),
+        ];
+        nodes[1].parent_id = Some("legacy:1".to_string());
+        let mut edges = vec![
+            Edge::new("legacy:1".into(), "legacy:2".into(), EdgeKind::Contains),
+            Edge::new("legacy:2".into(), "app.render".into(), EdgeKind::Calls),
+            Edge::new("legacy:2".into(), "app.render".into(), EdgeKind::Calls),
+        ];
+        let remap = IdRemap::from_nodes(&nodes);
+
+        let edge_ids = remap.edge_ids(&edges);
+        assert_eq!(edge_ids, remap.edge_ids(&edges));
+        assert_ne!(edge_ids[1], edge_ids[2]);
+
+        remap.rewrite(&mut nodes, &mut edges);
+        assert!(nodes.iter().all(|n| is_stable_id(&n.id)));
+        assert_eq!(nodes[1].parent_id.as_deref(), Some(nodes[0].id.as_str()));
+        assert_eq!(edges[0].target_id, nodes[1].id);
+        assert_eq!(edges[1].target_id, "app.render");
+        // Stable IDs resolve to themselves once rewritten
+        assert_eq!(IdRemap::default().resolve(&nodes[0].id), nodes[0].id);
+    }
+}
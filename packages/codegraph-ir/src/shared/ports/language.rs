@@ -10,6 +10,10 @@ pub enum Language {
     Go,
     Java,
     Kotlin,
+    C,
+    Cpp,
+    Php,
+    Ruby,
 }
 
 impl Language {
@@ -22,6 +26,10 @@ impl Language {
             Language::Go => "go",
             Language::Java => "java",
             Language::Kotlin => "kotlin",
+            Language::C => "c",
+            Language::Cpp => "cpp",
+            Language::Php => "php",
+            Language::Ruby => "ruby",
         }
     }
 
@@ -34,6 +42,10 @@ impl Language {
             Language::Go => &["go"],
             Language::Java => &["java"],
             Language::Kotlin => &["kt", "kts"],
+            Language::C => &["c"],
+            Language::Cpp => &["h", "cc", "cpp", "cxx", "hh", "hpp", "hxx"],
+            Language::Php => &["php", "phtml"],
+            Language::Ruby => &["rb", "rake"],
         }
     }
 
@@ -46,6 +58,10 @@ impl Language {
             "go" => Some(Language::Go),
             "java" => Some(Language::Java),
             "kt" | "kts" => Some(Language::Kotlin),
+            "c" => Some(Language::C),
+            "h" | "cc" | "cpp" | "cxx" | "hh" | "hpp" | "hxx" => Some(Language::Cpp),
+            "php" | "phtml" => Some(Language::Php),
+            "rb" | "rake" => Some(Language::Ruby),
             _ => None,
         }
     }
@@ -0,0 +1,647 @@
+//! Commit Delta - Semantic delta of a single commit (for PR bots)
+//!
+//! Indexes only the files a commit touched (plus unchanged sibling files for
+//! resolution context) at the base and head revisions, and returns just what
+//! changed: new / removed symbols, changed signatures and new / resolved
+//! findings. Nothing else from the pipeline result is materialized.
+//!
+//! # Flow
+//!
+//! ```text
+//! git diff --name-status base head
+//!        │
+//!        ▼
+//! changed files ──┬── git show base:path ──▶ pipeline (base) ──┐
+//!   + context     │                                            ├──▶ CommitDelta
+//!                 └── git show head:path ──▶ pipeline (head) ──┘
+//! ```
+//!
+//! File contents are read from git objects, so the working tree does not need
+//! to be checked out at `head`.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use codegraph_ir::usecases::{index_commit_delta, CommitDeltaRequest};
+//!
+//! let delta = index_commit_delta(&CommitDeltaRequest {
+//!     repo_root: PathBuf::from("/workspace/my_repo"),
+//!     repo_name: "my_repo".to_string(),
+//!     head: pr_head_sha,
+//!     base: Some(merge_base_sha),
+//!     ..Default::default()
+//! })?;
+//! if !delta.is_empty() {
+//!     post_pr_comment(&delta.summary());
+//! }
+//! ```
+
+use crate::errors::CodegraphError;
+use crate::features::gating::GateFinding;
+use crate::features::git_history::GitExecutor;
+use crate::features::parsing::sdk;
+use crate::pipeline::end_to_end_orchestrator::file_to_module_path;
+use crate::pipeline::{
+    E2EPipelineConfig, E2EPipelineResult, IRIndexingOrchestrator, IndexingMode, ReplayBundle,
+};
+use crate::shared::models::{Node, NodeKind};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+/// Object ID of git's empty tree (base of a root commit)
+const EMPTY_TREE: &str = "4b825dc642cb6eb9a060e54bf8d69288fbee4904";
+
+/// Commit delta request
+#[derive(Debug, Clone)]
+pub struct CommitDeltaRequest {
+    /// Repository root path (must contain `.git`)
+    pub repo_root: PathBuf,
+
+    /// Repository name/ID
+    pub repo_name: String,
+
+    /// Head revision (commit SHA, branch or `HEAD`)
+    pub head: String,
+
+    /// Base revision (None = first parent of `head`)
+    pub base: Option<String>,
+
+    /// Also index unchanged files in the changed files' directories
+    pub include_context: bool,
+
+    /// Maximum number of context files
+    pub max_context_files: usize,
+
+    /// Enable taint analysis (security findings)
+    pub enable_taint: bool,
+
+    /// Enable heap analysis (memory-safety findings)
+    pub enable_heap: bool,
+}
+
+impl Default for CommitDeltaRequest {
+    fn default() -> Self {
+        Self {
+            repo_root: PathBuf::from("."),
+            repo_name: "default".to_string(),
+            head: "HEAD".to_string(),
+            base: None,
+            include_context: true,
+            max_context_files: 50,
+            enable_taint: true,
+            enable_heap: false,
+        }
+    }
+}
+
+/// How a file changed between base and head
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FileChangeKind {
+    Added,
+    Modified,
+    Deleted,
+    Renamed,
+}
+
+/// File touched by the commit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangedFile {
+    /// Path at head (path at base for deletions)
+    pub path: String,
+    /// Path at base for renames
+    pub old_path: Option<String>,
+    pub kind: FileChangeKind,
+}
+
+/// Symbol added or removed by the commit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeltaSymbol {
+    pub fqn: String,
+    /// Node kind (e.g. "Function", "Class")
+    pub kind: String,
+    pub file_path: String,
+    pub line: u32,
+    /// Rendered signature, e.g. `fetch(url, timeout) -> bytes`
+    pub signature: String,
+}
+
+/// Symbol whose signature changed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignatureChange {
+    pub fqn: String,
+    /// Location at head
+    pub file_path: String,
+    pub line: u32,
+    pub before: String,
+    pub after: String,
+}
+
+/// Semantic delta of one commit
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CommitDelta {
+    pub repo_name: String,
+    /// Resolved base commit (empty tree for root commits)
+    pub base: String,
+    /// Resolved head commit
+    pub head: String,
+    pub changed_files: Vec<ChangedFile>,
+    /// Unchanged files indexed for resolution context
+    pub context_files: Vec<String>,
+    pub added_symbols: Vec<DeltaSymbol>,
+    pub removed_symbols: Vec<DeltaSymbol>,
+    pub changed_signatures: Vec<SignatureChange>,
+    /// Findings present at head but not at base
+    pub new_findings: Vec<GateFinding>,
+    /// Findings present at base but not at head
+    pub resolved_findings: Vec<GateFinding>,
+    pub duration_ms: u64,
+}
+
+impl CommitDelta {
+    /// No semantic change (formatting, comments, non-code files)
+    pub fn is_empty(&self) -> bool {
+        self.added_symbols.is_empty()
+            && self.removed_symbols.is_empty()
+            && self.changed_signatures.is_empty()
+            && self.new_findings.is_empty()
+            && self.resolved_findings.is_empty()
+    }
+
+    /// Markdown summary suitable for a PR comment
+    pub fn summary(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{} files changed: {} symbols added, {} removed, {} signatures changed, {} new findings, {} resolved",
+            self.changed_files.len(),
+            self.added_symbols.len(),
+            self.removed_symbols.len(),
+            self.changed_signatures.len(),
+            self.new_findings.len(),
+            self.resolved_findings.len(),
+        );
+        for symbol in &self.added_symbols {
+            let _ = writeln!(
+                out,
+                "+ `{}` ({}:{})",
+                symbol.signature, symbol.file_path, symbol.line
+            );
+        }
+        for symbol in &self.removed_symbols {
+            let _ = writeln!(out, "- `{}` ({})", symbol.signature, symbol.file_path);
+        }
+        for change in &self.changed_signatures {
+            let _ = writeln!(
+                out,
+                "~ `{}` → `{}` ({}:{})",
+                change.before, change.after, change.file_path, change.line
+            );
+        }
+        for finding in &self.new_findings {
+            let location = match finding.line {
+                Some(line) => format!("{}:{}", finding.file_path, line),
+                None => finding.file_path.clone(),
+            };
+            let _ = writeln!(
+                out,
+                "! [{:?}] {} ({}): {}",
+                finding.severity, finding.rule_id, location, finding.message
+            );
+        }
+        out
+    }
+
+    /// Serialize as JSON (for bot payloads)
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+/// Index the files changed between `base` and `head` and return the delta
+pub fn index_commit_delta(request: &CommitDeltaRequest) -> Result<CommitDelta, CodegraphError> {
+    let start = Instant::now();
+    let git = GitExecutor::new(&request.repo_root)
+        .map_err(|e| CodegraphError::internal(e.to_string()))?;
+    let run = |args: &[&str]| {
+        git.run_command(args)
+            .map_err(|e| CodegraphError::internal(e.to_string()))
+    };
+
+    let head = run(&[
+        "rev-parse",
+        "--verify",
+        &format!("{}^{{commit}}", request.head),
+    ])?
+    .trim()
+    .to_string();
+    let base = match &request.base {
+        Some(base) => run(&["rev-parse", "--verify", &format!("{}^{{commit}}", base)])?
+            .trim()
+            .to_string(),
+        // Root commit: diff against the empty tree
+        None => run(&["rev-parse", "--verify", &format!("{}^", head)])
+            .map(|sha| sha.trim().to_string())
+            .unwrap_or_else(|_| EMPTY_TREE.to_string()),
+    };
+
+    let extensions = sdk::supported_extensions();
+    let is_code = |path: &str| {
+        Path::new(path)
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|ext| extensions.iter().any(|e| *e == ext))
+    };
+
+    let diff = run(&["diff", "--name-status", "-z", "-M", &base, &head])?;
+    let changed_files: Vec<ChangedFile> = parse_name_status(&diff)
+        .into_iter()
+        .filter(|f| is_code(&f.path) || f.old_path.as_deref().is_some_and(is_code))
+        .collect();
+
+    let mut delta = CommitDelta {
+        repo_name: request.repo_name.clone(),
+        base: base.clone(),
+        head: head.clone(),
+        changed_files,
+        ..Default::default()
+    };
+    if delta.changed_files.is_empty() {
+        delta.duration_ms = start.elapsed().as_millis() as u64;
+        return Ok(delta);
+    }
+
+    let mut base_paths = Vec::new();
+    let mut head_paths = Vec::new();
+    for file in &delta.changed_files {
+        match file.kind {
+            FileChangeKind::Added => head_paths.push(file.path.clone()),
+            FileChangeKind::Deleted => base_paths.push(file.path.clone()),
+            FileChangeKind::Modified => {
+                base_paths.push(file.path.clone());
+                head_paths.push(file.path.clone());
+            }
+            FileChangeKind::Renamed => {
+                base_paths.push(file.old_path.clone().unwrap_or_else(|| file.path.clone()));
+                head_paths.push(file.path.clone());
+            }
+        }
+    }
+    base_paths.retain(|p| is_code(p));
+    head_paths.retain(|p| is_code(p));
+
+    // Context: unchanged siblings of the changed files, identical on both sides
+    let mut context = Vec::new();
+    if request.include_context && request.max_context_files > 0 {
+        let touched: HashSet<&str> = base_paths
+            .iter()
+            .chain(&head_paths)
+            .map(String::as_str)
+            .collect();
+        let dirs: BTreeSet<String> = head_paths
+            .iter()
+            .chain(&base_paths)
+            .map(|p| match p.rfind('/') {
+                Some(i) => p[..=i].to_string(),
+                None => String::new(),
+            })
+            .collect();
+        let mut candidates = BTreeSet::new();
+        for dir in &dirs {
+            let mut args = vec!["ls-tree", "--name-only", "-z", head.as_str()];
+            if !dir.is_empty() {
+                args.extend(["--", dir.as_str()]);
+            }
+            let listing = run(&args)?;
+            candidates.extend(
+                listing
+                    .split('\0')
+                    .filter(|p| !p.is_empty() && is_code(p) && !touched.contains(p))
+                    .map(str::to_string),
+            );
+        }
+        for path in candidates.into_iter().take(request.max_context_files) {
+            let content = run(&["show", &format!("{}:{}", head, path)])?;
+            context.push((path, content));
+        }
+    }
+    delta.context_files = context.iter().map(|(p, _)| p.clone()).collect();
+
+    let read_side =
+        |rev: &str, paths: &[String]| -> Result<Vec<(String, String)>, CodegraphError> {
+            let mut files = Vec::with_capacity(paths.len() + context.len());
+            for path in paths {
+                files.push((path.clone(), run(&["show", &format!("{}:{}", rev, path)])?));
+            }
+            files.extend(context.iter().cloned());
+            Ok(files)
+        };
+    let base_result = index_revision(request, &read_side(&base, &base_paths)?)?;
+    let head_result = index_revision(request, &read_side(&head, &head_paths)?)?;
+
+    let base_files: HashSet<&str> = base_paths.iter().map(String::as_str).collect();
+    let head_files: HashSet<&str> = head_paths.iter().map(String::as_str).collect();
+    let (added, removed, changed) = diff_symbols(
+        &symbol_table(&base_result.nodes, &base_files),
+        &symbol_table(&head_result.nodes, &head_files),
+    );
+    delta.added_symbols = added;
+    delta.removed_symbols = removed;
+    delta.changed_signatures = changed;
+
+    let (new_findings, resolved_findings) = diff_findings(
+        GateFinding::from_pipeline_result(&base_result),
+        GateFinding::from_pipeline_result(&head_result),
+    );
+    delta.new_findings = new_findings;
+    delta.resolved_findings = resolved_findings;
+
+    delta.duration_ms = start.elapsed().as_millis() as u64;
+    Ok(delta)
+}
+
+/// Parse `git diff --name-status -z` output
+///
+/// Records are NUL-separated: `M\0path\0`, `R087\0old\0new\0`. Copies are
+/// reported as additions; type changes as modifications.
+fn parse_name_status(output: &str) -> Vec<ChangedFile> {
+    let mut fields = output.split('\0').filter(|f| !f.is_empty());
+    let mut files = Vec::new();
+    while let Some(status) = fields.next() {
+        let kind = match status.chars().next() {
+            Some('A') | Some('C') => FileChangeKind::Added,
+            Some('D') => FileChangeKind::Deleted,
+            Some('R') => FileChangeKind::Renamed,
+            _ => FileChangeKind::Modified,
+        };
+        let file = if matches!(status.chars().next(), Some('R') | Some('C')) {
+            let (Some(old), Some(new)) = (fields.next(), fields.next()) else {
+                break;
+            };
+            ChangedFile {
+                path: new.to_string(),
+                old_path: (kind == FileChangeKind::Renamed).then(|| old.to_string()),
+                kind,
+            }
+        } else {
+            let Some(path) = fields.next() else {
+                break;
+            };
+            ChangedFile {
+                path: path.to_string(),
+                old_path: None,
+                kind,
+            }
+        };
+        files.push(file);
+    }
+    files
+}
+
+/// Run the light-weight delta pipeline over in-memory `(path, content)` files
+fn index_revision(
+    request: &CommitDeltaRequest,
+    files: &[(String, String)],
+) -> Result<E2EPipelineResult, CodegraphError> {
+    if files.is_empty() {
+        return Ok(E2EPipelineResult::default());
+    }
+
+    let config = E2EPipelineConfig::fast()
+        .repo_root(request.repo_root.clone())
+        .repo_name(request.repo_name.clone())
+        .file_paths(files.iter().map(|(p, _)| PathBuf::from(p)).collect())
+        .indexing_mode(IndexingMode::Incremental)
+        .with_pipeline(|b| {
+            b.stages(|mut s| {
+                // Only what the delta needs: symbols + findings
+                s.chunking = false;
+                s.lexical = false;
+                s.repomap = false;
+                s.symbols = false;
+                s.cross_file = true;
+                s.taint = request.enable_taint;
+                s.heap = request.enable_heap;
+                s
+            })
+        });
+
+    let inputs: Vec<(String, String, String)> = files
+        .iter()
+        .map(|(path, content)| (path.clone(), file_to_module_path(path), content.clone()))
+        .collect();
+    let bundle = ReplayBundle::record(&config, &inputs)
+        .map_err(|e| CodegraphError::internal(e.to_string()))?;
+    IRIndexingOrchestrator::from_replay(bundle)
+        .and_then(|orchestrator| orchestrator.execute())
+        .map_err(|e| CodegraphError::internal(e.to_string()))
+}
+
+/// Symbols declared in `files`, keyed by FQN (first declaration wins)
+fn symbol_table(nodes: &[Node], files: &HashSet<&str>) -> BTreeMap<String, DeltaSymbol> {
+    let mut parameters: HashMap<&str, Vec<&Node>> = HashMap::new();
+    for node in nodes.iter().filter(|n| n.kind == NodeKind::Parameter) {
+        if let Some(parent) = node.parent_id.as_deref() {
+            parameters.entry(parent).or_default().push(node);
+        }
+    }
+
+    let mut table = BTreeMap::new();
+    for node in nodes {
+        if node.fqn.is_empty() || !is_symbol(node) || !files.contains(node.file_path.as_str()) {
+            continue;
+        }
+        table
+            .entry(node.fqn.clone())
+            .or_insert_with(|| DeltaSymbol {
+                fqn: node.fqn.clone(),
+                kind: node.kind.as_str().to_string(),
+                file_path: node.file_path.clone(),
+                line: node.span.start_line,
+                signature: render_signature(node, parameters.get(node.id.as_str())),
+            });
+    }
+    table
+}
+
+/// Declarations a reviewer cares about (no files, modules, lambdas or locals)
+fn is_symbol(node: &Node) -> bool {
+    match node.kind {
+        NodeKind::Lambda | NodeKind::File | NodeKind::Module => false,
+        NodeKind::TypeAlias | NodeKind::Constant => true,
+        kind => kind.is_callable() || kind.is_container(),
+    }
+}
+
+/// `name(params) -> ret` for callables, `Name(bases)` for types
+fn render_signature(node: &Node, parameter_nodes: Option<&Vec<&Node>>) -> String {
+    let name = node.name.clone().unwrap_or_else(|| {
+        node.fqn
+            .rsplit(['.', ':'])
+            .next()
+            .unwrap_or(&node.fqn)
+            .to_string()
+    });
+
+    if node.kind.is_callable() {
+        let params = match &node.parameters {
+            Some(params) => params.join(", "),
+            None => {
+                let mut nodes: Vec<&Node> = parameter_nodes.cloned().unwrap_or_default();
+                nodes.sort_by_key(|p| (p.span.start_line, p.span.start_col));
+                nodes
+                    .iter()
+                    .map(|p| p.name.clone().unwrap_or_else(|| p.fqn.clone()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+        };
+        match &node.return_type {
+            Some(ret) => format!("{}({}) -> {}", name, params, ret),
+            None => format!("{}({})", name, params),
+        }
+    } else {
+        match &node.base_classes {
+            Some(bases) if !bases.is_empty() => format!("{}({})", name, bases.join(", ")),
+            _ => name,
+        }
+    }
+}
+
+/// (added, removed, changed signatures) between two symbol tables
+fn diff_symbols(
+    base: &BTreeMap<String, DeltaSymbol>,
+    head: &BTreeMap<String, DeltaSymbol>,
+) -> (Vec<DeltaSymbol>, Vec<DeltaSymbol>, Vec<SignatureChange>) {
+    let mut added = Vec::new();
+    let mut changed = Vec::new();
+    for (fqn, symbol) in head {
+        match base.get(fqn) {
+            None => added.push(symbol.clone()),
+            Some(before) if before.signature != symbol.signature => changed.push(SignatureChange {
+                fqn: fqn.clone(),
+                file_path: symbol.file_path.clone(),
+                line: symbol.line,
+                before: before.signature.clone(),
+                after: symbol.signature.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+    let removed = base
+        .iter()
+        .filter(|(fqn, _)| !head.contains_key(*fqn))
+        .map(|(_, symbol)| symbol.clone())
+        .collect();
+    (added, removed, changed)
+}
+
+/// (new, resolved) findings, matched by line-independent fingerprint
+fn diff_findings(
+    base: Vec<GateFinding>,
+    head: Vec<GateFinding>,
+) -> (Vec<GateFinding>, Vec<GateFinding>) {
+    let base_prints: HashSet<String> = base.iter().map(GateFinding::fingerprint).collect();
+    let head_prints: HashSet<String> = head.iter().map(GateFinding::fingerprint).collect();
+    let new = head
+        .into_iter()
+        .filter(|f| !base_prints.contains(&f.fingerprint()))
+        .collect();
+    let resolved = base
+        .into_iter()
+        .filter(|f| !head_prints.contains(&f.fingerprint()))
+        .collect();
+    (new, resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::gating::{FindingCategory, FindingSeverity};
+    use crate::shared::models::Span;
+
+    fn function(fqn: &str, file: &str, params: &[&str]) -> Node {
+        let mut node = Node::new(
+            fqn.to_string(),
+            NodeKind::Function,
+            fqn.to_string(),
+            file.to_string(),
+            Span::new(1, 0, 3, 0),
+        )
+        .with_name(fqn.rsplit('.').next().unwrap());
+        node.parameters = Some(params.iter().map(|p| p.to_string()).collect());
+        node
+    }
+
+    #[test]
+    fn test_parse_name_status() {
+        let output = "M\0src/app.py\0R087\0old/util.py\0src/util.py\0A\0new.py\0D\0gone.py\0";
+        let files = parse_name_status(output);
+
+        assert_eq!(files.len(), 4);
+        assert_eq!(files[0].kind, FileChangeKind::Modified);
+        assert_eq!(files[1].kind, FileChangeKind::Renamed);
+        assert_eq!(files[1].path, "src/util.py");
+        assert_eq!(files[1].old_path.as_deref(), Some("old/util.py"));
+        assert_eq!(files[2].kind, FileChangeKind::Added);
+        assert_eq!(files[3].path, "gone.py");
+    }
+
+    #[test]
+    fn test_symbol_and_finding_delta() {
+        let files: HashSet<&str> = ["app.py"].into();
+        let base = symbol_table(
+            &[
+                function("app.fetch", "app.py", &["url"]),
+                function("app.legacy", "app.py", &[]),
+                function("lib.helper", "lib.py", &[]),
+            ],
+            &files,
+        );
+        let head = symbol_table(
+            &[
+                function("app.fetch", "app.py", &["url", "timeout"]),
+                function("app.parse", "app.py", &["data"]),
+                function("lib.other", "lib.py", &[]),
+            ],
+            &files,
+        );
+
+        // Context files (lib.py) never show up in the delta
+        let (added, removed, changed) = diff_symbols(&base, &head);
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0].signature, "parse(data)");
+        assert_eq!(removed[0].fqn, "app.legacy");
+        assert_eq!(changed[0].before, "fetch(url)");
+        assert_eq!(changed[0].after, "fetch(url, timeout)");
+
+        let finding = |rule: &str, line: u32| {
+            GateFinding::new(
+                FindingCategory::Security,
+                rule,
+                FindingSeverity::High,
+                "app.py",
+                "user input reaches sink",
+            )
+            .with_line(line)
+        };
+        // The SQL injection only moved down a few lines: not new
+        let (new, resolved) = diff_findings(
+            vec![finding("SQLInjection", 10), finding("XSS", 4)],
+            vec![finding("SQLInjection", 14), finding("CommandInjection", 20)],
+        );
+        assert_eq!(new.len(), 1);
+        assert_eq!(new[0].rule_id, "CommandInjection");
+        assert_eq!(resolved[0].rule_id, "XSS");
+
+        let delta = CommitDelta {
+            added_symbols: added,
+            new_findings: new,
+            ..Default::default()
+        };
+        assert!(!delta.is_empty());
+        assert!(delta.summary().contains("+ `parse(data)` (app.py:1)"));
+    }
+}
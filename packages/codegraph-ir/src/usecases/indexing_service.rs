@@ -64,6 +64,7 @@ use crate::errors::CodegraphError;
 use crate::pipeline::{
     E2EPipelineConfig, E2EPipelineResult, IRIndexingOrchestrator, IndexingMode, RepoInfo,
 };
+use crate::usecases::commit_delta::{index_commit_delta, CommitDelta, CommitDeltaRequest};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::time::Duration;
@@ -397,6 +398,34 @@ impl IndexingService {
         self.incremental_reindex(repo_root, repo_name, committed_files)
     }
 
+    /// 🤖 **PR Bots**: Semantic delta of a single commit
+    ///
+    /// Indexes only the files changed by the commit (plus sibling files for
+    /// context) at base and head, and returns new symbols, changed
+    /// signatures and new findings instead of a full indexing result.
+    ///
+    /// **Trigger**: Pull request opened / synchronized webhook
+    /// **Frequency**: Every pushed commit
+    /// **Target Time**: < 200ms (small commits)
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let service = IndexingService::new();
+    /// let delta = service.commit_delta_index(CommitDeltaRequest {
+    ///     repo_root: PathBuf::from("/workspace/my_repo"),
+    ///     repo_name: "my_repo".to_string(),
+    ///     head: "abc123def".to_string(),
+    ///     ..Default::default()
+    /// })?;
+    /// println!("{}", delta.summary());
+    /// ```
+    pub fn commit_delta_index(
+        &self,
+        request: CommitDeltaRequest,
+    ) -> Result<CommitDelta, CodegraphError> {
+        index_commit_delta(&request)
+    }
+
     /// ⏰ **Scheduler**: Scheduled full reindexing (daily)
     ///
     /// This is called by APScheduler for daily full repository reindexing.
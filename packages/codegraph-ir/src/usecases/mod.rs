@@ -7,10 +7,16 @@
 //! - Scheduler jobs
 //! - Manual trigger APIs
 //! - Cold Start initialization
+//! - PR bots (per-commit semantic delta)
 //!
 //! NOT for direct Python consumption - use PyO3 bindings in lib.rs instead.
 
+pub mod commit_delta;
 pub mod indexing_service;
 
 // Re-export main API
+pub use commit_delta::{
+    index_commit_delta, ChangedFile, CommitDelta, CommitDeltaRequest, DeltaSymbol, FileChangeKind,
+    SignatureChange,
+};
 pub use indexing_service::{IndexingRequest, IndexingResult, IndexingService};
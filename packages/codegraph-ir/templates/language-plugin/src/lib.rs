@@ -0,0 +1,277 @@
+//! Lua Language Plugin (template)
+//!
+//! Minimal third-party plugin built on the codegraph-ir plugin SDK.
+//! Extracts functions, parameters and calls; CFG construction works through
+//! `is_statement_node`/`is_control_flow_node`. Data flow and taint stay off
+//! until a variable extractor and sink conventions exist for the language.
+//!
+//! Register once at startup, before running the pipeline:
+//!
+//! ```ignore
+//! codegraph_plugin_lua::register()?;
+//! ```
+
+use codegraph_ir::features::parsing::domain::SyntaxKind;
+use codegraph_ir::features::parsing::ports::{
+    ExtractionContext, ExtractionResult, IdGenerator, LanguageId, LanguagePlugin,
+    PluginCapabilities, SpanExt,
+};
+use codegraph_ir::features::parsing::sdk::{register_plugin, PluginDescriptor};
+use codegraph_ir::shared::models::{CodegraphError, Edge, EdgeKind, Node, NodeKind, Result};
+use tree_sitter::{Language as TSLanguage, Node as TSNode, Tree};
+
+/// Language id used for every node this plugin emits
+pub const LUA: LanguageId = LanguageId::Custom("lua");
+
+/// File extensions routed to this plugin
+pub const EXTENSIONS: &[&str] = &["lua"];
+
+/// Register the plugin with the global SDK registry
+pub fn register() -> std::result::Result<(), CodegraphError> {
+    register_plugin(PluginDescriptor {
+        name: "lua",
+        version: env!("CARGO_PKG_VERSION"),
+        language: LUA,
+        extensions: EXTENSIONS,
+        factory: || Box::new(LuaPlugin::new()),
+    })
+}
+
+/// Lua language plugin
+#[derive(Default)]
+pub struct LuaPlugin;
+
+impl LuaPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn make_fqn(&self, ctx: &ExtractionContext, name: &str) -> String {
+        if ctx.fqn_prefix().is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", ctx.fqn_prefix(), name)
+        }
+    }
+
+    fn extract_node(
+        &self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        match node.kind() {
+            "function_declaration" => self.extract_function(ctx, node, id_gen, result),
+            "function_call" => {
+                if let (Some(parent), Some(callee)) =
+                    (ctx.parent_id.clone(), node.child_by_field_name("name"))
+                {
+                    // `a:b()` / `a.b()` → dotted FQN form
+                    let callee = ctx.node_text(&callee).replace(':', ".");
+                    result.add_edge(
+                        Edge::new(parent, callee, EdgeKind::Calls).with_span(node.to_span()),
+                    );
+                }
+                self.extract_children(ctx, node, id_gen, result);
+            }
+            _ => self.extract_children(ctx, node, id_gen, result),
+        }
+    }
+
+    fn extract_children(
+        &self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.extract_node(ctx, &child, id_gen, result);
+        }
+    }
+
+    fn extract_function(
+        &self,
+        ctx: &mut ExtractionContext,
+        node: &TSNode,
+        id_gen: &mut IdGenerator,
+        result: &mut ExtractionResult,
+    ) {
+        let name = match node.child_by_field_name("name") {
+            Some(n) => ctx.node_text(&n).replace(':', "."),
+            None => return,
+        };
+
+        let node_id = id_gen.next_node();
+        let fqn = self.make_fqn(ctx, &name);
+        let kind = if name.contains('.') {
+            NodeKind::Method
+        } else {
+            NodeKind::Function
+        };
+        let mut ir_node = Node::new(
+            node_id.clone(),
+            kind,
+            fqn,
+            ctx.file_path.to_string(),
+            node.to_span(),
+        )
+        .with_language(ctx.language.name().to_string())
+        .with_name(name.clone());
+        ir_node.parent_id = ctx.parent_id.clone();
+
+        if let Some(params) = node.child_by_field_name("parameters") {
+            let mut cursor = params.walk();
+            let names: Vec<String> = params
+                .named_children(&mut cursor)
+                .filter(|p| p.kind() == "identifier")
+                .map(|p| ctx.node_text(&p).to_string())
+                .collect();
+            if !names.is_empty() {
+                ir_node.parameters = Some(names);
+            }
+        }
+        result.add_node(ir_node);
+
+        let old_parent = ctx.parent_id.replace(node_id);
+        ctx.push_scope(&name);
+        if let Some(body) = node.child_by_field_name("body") {
+            self.extract_children(ctx, &body, id_gen, result);
+        }
+        ctx.pop_scope();
+        ctx.parent_id = old_parent;
+    }
+}
+
+impl LanguagePlugin for LuaPlugin {
+    fn tree_sitter_language(&self) -> TSLanguage {
+        tree_sitter_lua::language()
+    }
+
+    fn language_id(&self) -> LanguageId {
+        LUA
+    }
+
+    // Required for `LanguageId::Custom` plugins
+    fn extensions(&self) -> &[&str] {
+        EXTENSIONS
+    }
+
+    fn capabilities(&self) -> PluginCapabilities {
+        PluginCapabilities::extraction_only()
+    }
+
+    fn grammar_version(&self) -> Option<&'static str> {
+        Some("tree-sitter-lua 0.1")
+    }
+
+    fn expected_node_kinds(&self) -> &[&str] {
+        &["function_declaration", "function_definition", "function_call"]
+    }
+
+    fn map_node_kind(&self, ts_kind: &str) -> Option<NodeKind> {
+        match ts_kind {
+            "function_declaration" => Some(NodeKind::Function),
+            "function_definition" => Some(NodeKind::Lambda),
+            _ => None,
+        }
+    }
+
+    fn map_syntax_kind(&self, ts_kind: &str) -> SyntaxKind {
+        match ts_kind {
+            "function_declaration" => SyntaxKind::FunctionDef,
+            "function_call" => SyntaxKind::CallExpr,
+            "assignment_statement" | "variable_declaration" => SyntaxKind::AssignmentStmt,
+            "return_statement" => SyntaxKind::ReturnStmt,
+            "if_statement" => SyntaxKind::IfStmt,
+            "for_statement" => SyntaxKind::ForStmt,
+            "while_statement" | "repeat_statement" => SyntaxKind::WhileStmt,
+            "comment" => SyntaxKind::Comment,
+            other => SyntaxKind::Other(other.to_string()),
+        }
+    }
+
+    fn extract(&self, ctx: &mut ExtractionContext, tree: &Tree) -> Result<ExtractionResult> {
+        let mut result = ExtractionResult::new();
+        let mut id_gen = IdGenerator::new(format!("{}:{}", ctx.repo_id, ctx.file_path));
+
+        let file_node_id = id_gen.next_node();
+        let file_node = Node::new(
+            file_node_id.clone(),
+            NodeKind::File,
+            ctx.file_path.to_string(),
+            ctx.file_path.to_string(),
+            tree.root_node().to_span(),
+        )
+        .with_language(ctx.language.name().to_string())
+        .with_name(ctx.file_path.to_string());
+        result.add_node(file_node);
+        ctx.parent_id = Some(file_node_id);
+
+        self.extract_children(ctx, &tree.root_node(), &mut id_gen, &mut result);
+        Ok(result)
+    }
+
+    fn is_statement_node(&self, node: &TSNode) -> bool {
+        matches!(
+            node.kind(),
+            "assignment_statement"
+                | "variable_declaration"
+                | "function_call"
+                | "if_statement"
+                | "while_statement"
+                | "repeat_statement"
+                | "for_statement"
+                | "return_statement"
+                | "break_statement"
+                | "goto_statement"
+                | "function_declaration"
+        )
+    }
+
+    fn is_control_flow_node(&self, node: &TSNode) -> bool {
+        matches!(
+            node.kind(),
+            "if_statement"
+                | "while_statement"
+                | "repeat_statement"
+                | "for_statement"
+                | "return_statement"
+                | "break_statement"
+                | "goto_statement"
+        )
+    }
+
+    fn comment_patterns(&self) -> &[&str] {
+        &["--"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codegraph_ir::features::parsing::sdk::plugin_for_extension;
+
+    #[test]
+    fn test_register_and_extract() {
+        register().unwrap();
+        let (plugin, lang) = plugin_for_extension("lua").unwrap();
+        assert_eq!(lang, LUA);
+
+        let source = "function M.greet(name)\n  print(name)\nend\n";
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&plugin.tree_sitter_language()).unwrap();
+        let tree = parser.parse(source, None).unwrap();
+
+        let mut ctx = ExtractionContext::new(source, "m.lua", "repo", LUA);
+        let result = plugin.extract(&mut ctx, &tree).unwrap();
+
+        assert!(result.nodes.iter().any(|n| n.fqn == "M.greet"));
+        assert!(result
+            .edges
+            .iter()
+            .any(|e| e.kind == EdgeKind::Calls && e.target_id == "print"));
+    }
+}
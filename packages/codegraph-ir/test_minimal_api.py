@@ -141,7 +141,7 @@ def test_graph_query(result):
                 kind=node_dict["kind"],
                 name=node_dict["name"],
                 fqn=node_dict["fqn"],
-                file_path=node_dict["file_path"],
+                file_path=result["files"][node_dict["file_id"]],
                 span=codegraph_ir.Span(
                     start_line=node_dict["span"]["start_line"],
                     start_col=node_dict["span"]["start_col"],
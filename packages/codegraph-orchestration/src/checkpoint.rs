@@ -1,10 +1,16 @@
 use crate::error::{OrchestratorError, Result};
-use crate::job::StageId;
+use crate::job::{Job, StageId};
+use crate::pipeline::StageMetrics;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use tracing::warn;
 use uuid::Uuid;
 
-/// Checkpoint data (placeholder for now, will be populated with actual stage data)
+/// Job record file inside a job's checkpoint directory
+const JOB_FILE: &str = "job.json";
+
+/// Per-stage checkpoint (stage output + the metrics it reported)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Checkpoint {
     pub id: Uuid,
@@ -12,6 +18,8 @@ pub struct Checkpoint {
     pub stage: StageId,
     pub cache_key: String,
     pub cache_data: Vec<u8>, // Serialized stage output (bincode)
+    #[serde(default)]
+    pub metrics: StageMetrics,
 }
 
 impl Checkpoint {
@@ -22,25 +30,135 @@ impl Checkpoint {
             stage,
             cache_key,
             cache_data,
+            metrics: StageMetrics::default(),
         }
     }
+
+    /// Metrics replayed into the pipeline result when the stage is resumed
+    pub fn with_metrics(mut self, metrics: StageMetrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
 }
 
-/// Checkpoint manager (SQLite-backed, but interface-first for testing)
+/// On-disk checkpoint header (`<stage>.json`, next to `<stage>.bin`)
+#[derive(Debug, Serialize, Deserialize)]
+struct CheckpointMeta {
+    id: Uuid,
+    job_id: Uuid,
+    stage: StageId,
+    cache_key: String,
+    data_len: u64,
+    metrics: StageMetrics,
+}
+
+/// Checkpoint manager (in-memory, or durable under a directory)
+///
+/// Disk layout: `<dir>/<job_id>/{job.json, <stage>.bin, <stage>.json}`.
+/// Every file is written to a temp file, fsynced and renamed; the stage
+/// header goes last, so a crash mid-write leaves the previous state (or no
+/// checkpoint) behind, never a torn one. Stage data is read lazily.
 pub struct CheckpointManager {
-    // Will be replaced with sqlx::SqlitePool
+    /// Disk mode keeps only headers here (`cache_data` empty)
     checkpoints: std::sync::Arc<parking_lot::Mutex<Vec<Checkpoint>>>,
+    jobs: std::sync::Arc<parking_lot::Mutex<HashMap<Uuid, Job>>>,
+    dir: Option<PathBuf>,
 }
 
 impl CheckpointManager {
     pub fn new_in_memory() -> Self {
         Self {
             checkpoints: std::sync::Arc::new(parking_lot::Mutex::new(Vec::new())),
+            jobs: std::sync::Arc::new(parking_lot::Mutex::new(HashMap::new())),
+            dir: None,
         }
     }
 
+    /// Durable checkpoints under `dir`, recovering whatever a previous
+    /// process left there
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+
+        let mut checkpoints = Vec::new();
+        let mut jobs = HashMap::new();
+        for entry in std::fs::read_dir(&dir)? {
+            let job_dir = entry?.path();
+            let job_id = match job_dir
+                .file_name()
+                .and_then(|name| Uuid::parse_str(&name.to_string_lossy()).ok())
+            {
+                Some(id) if job_dir.is_dir() => id,
+                _ => continue,
+            };
+
+            for file in std::fs::read_dir(&job_dir)? {
+                let path = file?.path();
+                if path.extension().map_or(true, |ext| ext != "json") {
+                    continue;
+                }
+                let bytes = std::fs::read(&path)?;
+                if path.file_name().map_or(false, |name| name == JOB_FILE) {
+                    match serde_json::from_slice::<Job>(&bytes) {
+                        Ok(job) => {
+                            jobs.insert(job_id, job);
+                        }
+                        Err(e) => warn!("Skipping unreadable {}: {}", path.display(), e),
+                    }
+                    continue;
+                }
+                match serde_json::from_slice::<CheckpointMeta>(&bytes) {
+                    Ok(meta) => checkpoints.push(Checkpoint {
+                        id: meta.id,
+                        job_id: meta.job_id,
+                        stage: meta.stage,
+                        cache_key: meta.cache_key,
+                        cache_data: Vec::new(),
+                        metrics: meta.metrics,
+                    }),
+                    Err(e) => warn!("Skipping unreadable {}: {}", path.display(), e),
+                }
+            }
+        }
+
+        Ok(Self {
+            checkpoints: std::sync::Arc::new(parking_lot::Mutex::new(checkpoints)),
+            jobs: std::sync::Arc::new(parking_lot::Mutex::new(jobs)),
+            dir: Some(dir),
+        })
+    }
+
+    /// Whether checkpoints survive a process restart
+    pub fn is_durable(&self) -> bool {
+        self.dir.is_some()
+    }
+
     /// Save checkpoint
-    pub async fn save_checkpoint(&self, checkpoint: Checkpoint) -> Result<()> {
+    pub async fn save_checkpoint(&self, mut checkpoint: Checkpoint) -> Result<()> {
+        if let Some(dir) = &self.dir {
+            let job_dir = dir.join(checkpoint.job_id.to_string());
+            tokio::fs::create_dir_all(&job_dir).await?;
+            let stage = checkpoint.stage.as_str();
+
+            write_atomic(
+                &job_dir.join(format!("{}.bin", stage)),
+                &checkpoint.cache_data,
+            )
+            .await?;
+            let meta = CheckpointMeta {
+                id: checkpoint.id,
+                job_id: checkpoint.job_id,
+                stage: checkpoint.stage,
+                cache_key: checkpoint.cache_key.clone(),
+                data_len: checkpoint.cache_data.len() as u64,
+                metrics: checkpoint.metrics.clone(),
+            };
+            let meta = serde_json::to_vec(&meta).map_err(OrchestratorError::serialization)?;
+            write_atomic(&job_dir.join(format!("{}.json", stage)), &meta).await?;
+
+            checkpoint.cache_data = Vec::new();
+        }
+
         let mut checkpoints = self.checkpoints.lock();
 
         // Remove existing checkpoint for same job+stage
@@ -52,12 +170,35 @@ impl CheckpointManager {
 
     /// Load checkpoint by cache key
     pub async fn load_checkpoint(&self, cache_key: &str) -> Result<Option<Vec<u8>>> {
-        let checkpoints = self.checkpoints.lock();
+        let found = {
+            let checkpoints = self.checkpoints.lock();
+            checkpoints
+                .iter()
+                .find(|cp| cp.cache_key == cache_key)
+                .map(|cp| (cp.job_id, cp.stage, cp.cache_data.clone()))
+        };
 
-        Ok(checkpoints
-            .iter()
-            .find(|cp| cp.cache_key == cache_key)
-            .map(|cp| cp.cache_data.clone()))
+        let (job_id, stage, data) = match found {
+            Some(found) => found,
+            None => return Ok(None),
+        };
+        let dir = match &self.dir {
+            Some(dir) => dir,
+            None => return Ok(Some(data)),
+        };
+
+        let job_dir = dir.join(job_id.to_string());
+        let path = job_dir.join(format!("{}.bin", stage.as_str()));
+        let expected = stored_data_len(&job_dir, stage).await?;
+        match tokio::fs::read(&path).await {
+            Ok(data) if Some(data.len() as u64) == expected => Ok(Some(data)),
+            Ok(_) => {
+                warn!("Checkpoint {} is truncated, ignoring", path.display());
+                Ok(None)
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
     }
 
     /// Get completed stages for a job
@@ -71,14 +212,79 @@ impl CheckpointManager {
             .collect())
     }
 
+    /// Metrics recorded by a job's completed stages
+    pub async fn stage_metrics(&self, job_id: Uuid) -> Result<HashMap<StageId, StageMetrics>> {
+        let checkpoints = self.checkpoints.lock();
+
+        Ok(checkpoints
+            .iter()
+            .filter(|cp| cp.job_id == job_id)
+            .map(|cp| (cp.stage, cp.metrics.clone()))
+            .collect())
+    }
+
+    /// Persist the job record (so a restarted process can find the job)
+    pub async fn save_job(&self, job: &Job) -> Result<()> {
+        if let Some(dir) = &self.dir {
+            let job_dir = dir.join(job.id.to_string());
+            tokio::fs::create_dir_all(&job_dir).await?;
+            let json = serde_json::to_vec(job).map_err(OrchestratorError::serialization)?;
+            write_atomic(&job_dir.join(JOB_FILE), &json).await?;
+        }
+        self.jobs.lock().insert(job.id, job.clone());
+        Ok(())
+    }
+
+    /// Jobs that were started but never finished (cleaned up), oldest first
+    pub async fn unfinished_jobs(&self) -> Result<Vec<Job>> {
+        let mut jobs: Vec<Job> = self.jobs.lock().values().cloned().collect();
+        jobs.sort_by_key(|job| job.created_at);
+        Ok(jobs)
+    }
+
     /// Delete checkpoints for a job (cleanup after completion)
     pub async fn delete_job_checkpoints(&self, job_id: Uuid) -> Result<()> {
+        if let Some(dir) = &self.dir {
+            match tokio::fs::remove_dir_all(dir.join(job_id.to_string())).await {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        self.jobs.lock().remove(&job_id);
         let mut checkpoints = self.checkpoints.lock();
         checkpoints.retain(|cp| cp.job_id != job_id);
         Ok(())
     }
 }
 
+/// `data_len` from the stage header on disk
+async fn stored_data_len(job_dir: &Path, stage: StageId) -> Result<Option<u64>> {
+    let path = job_dir.join(format!("{}.json", stage.as_str()));
+    match tokio::fs::read(&path).await {
+        Ok(bytes) => Ok(serde_json::from_slice::<CheckpointMeta>(&bytes)
+            .ok()
+            .map(|meta| meta.data_len)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Write via temp file + fsync + rename
+async fn write_atomic(path: &Path, data: &[u8]) -> Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+    let mut file = tokio::fs::File::create(&tmp).await?;
+    file.write_all(data).await?;
+    file.sync_all().await?;
+    drop(file);
+    tokio::fs::rename(&tmp, path).await?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,4 +389,51 @@ mod tests {
         let loaded = mgr.load_checkpoint("nonexistent").await.unwrap();
         assert_eq!(loaded, None);
     }
+
+    #[tokio::test]
+    async fn test_disk_checkpoints_survive_restart() {
+        let dir = std::env::temp_dir().join(format!("checkpoints-{}", Uuid::new_v4()));
+        let job = Job::new_queued("repo1".to_string(), "snap1".to_string(), 0);
+        let metrics = StageMetrics {
+            files_processed: 7,
+            ..Default::default()
+        };
+
+        {
+            let mgr = CheckpointManager::open(&dir).unwrap();
+            mgr.save_job(&job).await.unwrap();
+            let cp = Checkpoint::new(
+                job.id,
+                StageId::L1_IR,
+                "ir:repo1:snap1".to_string(),
+                vec![1, 2, 3],
+            )
+            .with_metrics(metrics);
+            mgr.save_checkpoint(cp).await.unwrap();
+        } // "crash"
+
+        let mgr = CheckpointManager::open(&dir).unwrap();
+        assert!(mgr.is_durable());
+        assert_eq!(mgr.unfinished_jobs().await.unwrap()[0].id, job.id);
+        assert!(mgr
+            .completed_stages(job.id)
+            .await
+            .unwrap()
+            .contains(&StageId::L1_IR));
+        assert_eq!(
+            mgr.stage_metrics(job.id).await.unwrap()[&StageId::L1_IR].files_processed,
+            7
+        );
+        assert_eq!(
+            mgr.load_checkpoint("ir:repo1:snap1").await.unwrap(),
+            Some(vec![1, 2, 3])
+        );
+
+        mgr.delete_job_checkpoints(job.id).await.unwrap();
+        let mgr = CheckpointManager::open(&dir).unwrap();
+        assert!(mgr.unfinished_jobs().await.unwrap().is_empty());
+        assert_eq!(mgr.load_checkpoint("ir:repo1:snap1").await.unwrap(), None);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
 }
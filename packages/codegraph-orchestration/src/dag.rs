@@ -1,12 +1,43 @@
 use crate::error::{OrchestratorError, Result};
 use crate::job::StageId;
+use codegraph_ir::pipeline::dag::StageId as IrStageId;
+use codegraph_ir::pipeline::IR_CACHE_VERSION;
+use serde::Serialize;
 use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+/// What a fingerprinted key covers besides repo and snapshot
+#[derive(Debug, Clone)]
+struct Fingerprint {
+    code_version: String,
+    inputs: String,
+    stage_configs: HashMap<StageId, String>,
+    dependencies: HashMap<StageId, Vec<StageId>>,
+}
+
+impl Default for Fingerprint {
+    fn default() -> Self {
+        Self {
+            code_version: format!("{}+ir{}", env!("CARGO_PKG_VERSION"), IR_CACHE_VERSION),
+            inputs: String::new(),
+            stage_configs: HashMap::new(),
+            dependencies: HashMap::new(),
+        }
+    }
+}
 
 /// Cache key manager (from semantica-task-engine pattern)
+///
+/// Plain keys are `<artifact>:<repo>:<snapshot>`. Once any `with_*`
+/// fingerprint input is set, keys get a `:<digest>` suffix over the code
+/// version, the inputs, the stage's own config and (recursively) the digests
+/// of its dependencies, so a config change re-keys that stage and its
+/// dependents only.
 #[derive(Debug, Clone)]
 pub struct CacheKeyManager {
     repo_id: String,
     snapshot_id: String,
+    fingerprint: Option<Fingerprint>,
 }
 
 impl CacheKeyManager {
@@ -14,9 +45,75 @@ impl CacheKeyManager {
         Self {
             repo_id,
             snapshot_id,
+            fingerprint: None,
         }
     }
 
+    fn fingerprint_mut(&mut self) -> &mut Fingerprint {
+        self.fingerprint.get_or_insert_with(Fingerprint::default)
+    }
+
+    /// Analyzer build identity (default: crate version + IR cache version)
+    pub fn with_code_version(mut self, version: impl Into<String>) -> Self {
+        self.fingerprint_mut().code_version = version.into();
+        self
+    }
+
+    /// Input identity, e.g. `fingerprint_files` of the file set
+    pub fn with_inputs(mut self, inputs: impl Into<String>) -> Self {
+        self.fingerprint_mut().inputs = inputs.into();
+        self
+    }
+
+    /// Config that only affects `stage` (and, through keys, its dependents)
+    pub fn with_stage_config<T: Serialize>(mut self, stage: StageId, config: &T) -> Self {
+        // Config structs are plain data; a failure still yields a stable digest
+        let json = serde_json::to_string(config).unwrap_or_else(|e| e.to_string());
+        self.fingerprint_mut()
+            .stage_configs
+            .insert(stage, blake3::hash(json.as_bytes()).to_hex().to_string());
+        self
+    }
+
+    /// Chain each stage's digest to its dependencies in `dag`
+    pub fn with_dependencies(mut self, dag: &PipelineDAG) -> Self {
+        self.fingerprint_mut().dependencies = dag
+            .stages
+            .values()
+            .map(|stage| (stage.id, stage.dependencies.clone()))
+            .collect();
+        self
+    }
+
+    pub fn is_fingerprinted(&self) -> bool {
+        self.fingerprint.is_some()
+    }
+
+    fn digest(&self, fingerprint: &Fingerprint, stage: StageId) -> blake3::Hash {
+        let mut hasher = blake3::Hasher::new();
+        for part in [
+            fingerprint.code_version.as_str(),
+            fingerprint.inputs.as_str(),
+            fingerprint
+                .stage_configs
+                .get(&stage)
+                .map_or("", String::as_str),
+        ] {
+            hasher.update(&(part.len() as u64).to_le_bytes());
+            hasher.update(part.as_bytes());
+        }
+        let mut dependencies = fingerprint
+            .dependencies
+            .get(&stage)
+            .cloned()
+            .unwrap_or_default();
+        dependencies.sort_by_key(|dep| dep.as_str());
+        for dep in dependencies {
+            hasher.update(self.digest(fingerprint, dep).as_bytes());
+        }
+        hasher.finalize()
+    }
+
     pub fn ir_key(&self) -> String {
         format!("ir:{}:{}", self.repo_id, self.snapshot_id)
     }
@@ -34,12 +131,73 @@ impl CacheKeyManager {
     }
 
     /// Get cache key for a stage
+    ///
+    /// codegraph-ir stages that produce the same artifact as a job stage
+    /// share its key (IR build, chunks, lexical and vector indexes), as long
+    /// as their fingerprints agree.
     pub fn key_for_stage(&self, stage: StageId) -> String {
+        let key = self.base_key(stage);
+        match &self.fingerprint {
+            Some(fingerprint) => {
+                let digest = self.digest(fingerprint, stage).to_hex();
+                format!("{}:{}", key, &digest[..16])
+            }
+            None => key,
+        }
+    }
+
+    fn base_key(&self, stage: StageId) -> String {
         match stage {
-            StageId::L1_IR => self.ir_key(),
-            StageId::L2_Chunk => self.chunk_key(),
-            StageId::L3_Lexical => self.lexical_key(),
-            StageId::L4_Vector => self.vector_key(),
+            StageId::L1_IR | StageId::Ir(IrStageId::L1IrBuild) => self.ir_key(),
+            StageId::L2_Chunk | StageId::Ir(IrStageId::L2Chunking) => self.chunk_key(),
+            StageId::L3_Lexical | StageId::Ir(IrStageId::L2_5Lexical) => self.lexical_key(),
+            StageId::L4_Vector | StageId::Ir(IrStageId::L2_6Vector) => self.vector_key(),
+            StageId::Ir(stage) => format!(
+                "{}:{}:{}",
+                stage.name().to_lowercase(),
+                self.repo_id,
+                self.snapshot_id
+            ),
+        }
+    }
+}
+
+/// Input fingerprint of a file set (paths and contents, directories recursively)
+///
+/// Paths are read as given, like L1 and the taint rule loaders read them;
+/// unreadable files hash as absent.
+pub fn fingerprint_files(files: &[PathBuf]) -> String {
+    let mut hasher = blake3::Hasher::new();
+    hash_files(&mut hasher, files.to_vec());
+    hasher.finalize().to_hex().to_string()
+}
+
+fn hash_files(hasher: &mut blake3::Hasher, mut files: Vec<PathBuf>) {
+    files.sort();
+    for file in files {
+        let path = file.to_string_lossy();
+        hasher.update(&(path.len() as u64).to_le_bytes());
+        hasher.update(path.as_bytes());
+        if file.is_dir() {
+            hasher.update(b"d");
+            let entries = std::fs::read_dir(&file)
+                .map(|dir| {
+                    dir.filter_map(|entry| entry.ok())
+                        .map(|e| e.path())
+                        .collect()
+                })
+                .unwrap_or_default();
+            hash_files(hasher, entries);
+        } else {
+            match std::fs::read(&file) {
+                Ok(content) => {
+                    hasher.update(b"f");
+                    hasher.update(blake3::hash(&content).as_bytes());
+                }
+                Err(_) => {
+                    hasher.update(b"-");
+                }
+            }
         }
     }
 }
@@ -52,6 +210,8 @@ pub struct StageNode {
     pub dependencies: Vec<StageId>,
     pub optional: bool,
     pub timeout_ms: u64,
+    /// Thread budget (default: the orchestrator's even share)
+    pub threads: Option<usize>,
 }
 
 // Make StageNode cloneable for orchestrator
@@ -75,8 +235,15 @@ impl StageNode {
             dependencies,
             optional,
             timeout_ms,
+            threads: None,
         }
     }
+
+    /// Run on a dedicated pool of `threads`
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = Some(threads.max(1));
+        self
+    }
 }
 
 /// Pipeline DAG with topological sort
@@ -157,9 +324,7 @@ impl PipelineDAG {
 
         // Calculate in-degrees
         for stage in stages.values() {
-            for &dep in &stage.dependencies {
-                *in_degree.get_mut(&stage.id).unwrap() += 1;
-            }
+            *in_degree.get_mut(&stage.id).unwrap() += stage.dependencies.len();
         }
 
         let mut result = Vec::new();
@@ -206,6 +371,11 @@ impl PipelineDAG {
         self.stages.get(&id)
     }
 
+    /// Size of the widest parallel group (thread budget divisor)
+    pub fn max_parallelism(&self) -> usize {
+        self.execution_order.iter().map(Vec::len).max().unwrap_or(1)
+    }
+
     /// Get execution plan as string (for logging)
     pub fn execution_plan(&self) -> String {
         self.execution_order
@@ -261,6 +431,32 @@ mod tests {
         let mgr = CacheKeyManager::new("repo1".to_string(), "snap1".to_string());
         assert_eq!(mgr.key_for_stage(StageId::L1_IR), "ir:repo1:snap1");
         assert_eq!(mgr.key_for_stage(StageId::L2_Chunk), "chunks:repo1:snap1");
+        assert_eq!(
+            mgr.key_for_stage(StageId::Ir(IrStageId::L2Chunking)),
+            "chunks:repo1:snap1"
+        );
+        assert_eq!(
+            mgr.key_for_stage(StageId::Ir(IrStageId::L14TaintAnalysis)),
+            "l14_taintanalysis:repo1:snap1"
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_files_follows_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let rules = dir.path().join("rules");
+        std::fs::create_dir(&rules).unwrap();
+        std::fs::write(rules.join("a.yaml"), "sinks: [os.system]").unwrap();
+        let files = vec![rules.clone()];
+        let before = fingerprint_files(&files);
+        assert_eq!(fingerprint_files(&files), before);
+
+        std::fs::write(rules.join("a.yaml"), "sinks: [eval]").unwrap();
+        assert_ne!(fingerprint_files(&files), before);
+        assert_ne!(
+            fingerprint_files(&[dir.path().join("missing.yaml")]),
+            fingerprint_files(&[])
+        );
     }
 
     #[test]
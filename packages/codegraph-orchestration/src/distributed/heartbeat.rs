@@ -0,0 +1,173 @@
+use super::{redis_error, DEFAULT_PREFIX};
+use crate::error::{OrchestratorError, Result};
+use chrono::{DateTime, Utc};
+use redis::aio::ConnectionManager;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::warn;
+use uuid::Uuid;
+
+/// Shortest worker TTL: beats run every `ttl / 3`, which must be non-zero
+pub const MIN_WORKER_TTL: Duration = Duration::from_millis(3);
+
+/// Heartbeat payload
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkerInfo {
+    pub worker_id: String,
+    pub started_at: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+    pub current_job: Option<Uuid>,
+}
+
+impl WorkerInfo {
+    pub fn new(worker_id: impl Into<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            worker_id: worker_id.into(),
+            started_at: now,
+            last_seen: now,
+            current_job: None,
+        }
+    }
+}
+
+/// Worker liveness: `<prefix>:worker:<id>` expires `ttl` after the last beat
+#[derive(Clone)]
+pub struct WorkerRegistry {
+    conn: ConnectionManager,
+    prefix: String,
+    ttl: Duration,
+}
+
+impl WorkerRegistry {
+    pub fn new(conn: ConnectionManager) -> Self {
+        Self {
+            conn,
+            prefix: DEFAULT_PREFIX.to_string(),
+            ttl: Duration::from_secs(30),
+        }
+    }
+
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// A worker is dead once it misses beats for `ttl` (at least `MIN_WORKER_TTL`)
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl.max(MIN_WORKER_TTL);
+        self
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    fn worker_key(&self, worker_id: &str) -> String {
+        format!("{}:worker:{}", self.prefix, worker_id)
+    }
+
+    fn members_key(&self) -> String {
+        format!("{}:workers", self.prefix)
+    }
+
+    /// Record a beat (refreshes `last_seen`)
+    pub async fn beat(&self, info: &WorkerInfo) -> Result<()> {
+        let info = WorkerInfo {
+            last_seen: Utc::now(),
+            ..info.clone()
+        };
+        let json = serde_json::to_string(&info).map_err(OrchestratorError::serialization)?;
+        let mut conn = self.conn.clone();
+        redis::pipe()
+            .cmd("SET")
+            .arg(self.worker_key(&info.worker_id))
+            .arg(json)
+            .arg("PX")
+            .arg(self.ttl.as_millis() as u64)
+            .ignore()
+            .cmd("SADD")
+            .arg(self.members_key())
+            .arg(&info.worker_id)
+            .ignore()
+            .query_async::<_, ()>(&mut conn)
+            .await
+            .map_err(redis_error)
+    }
+
+    pub async fn is_alive(&self, worker_id: &str) -> Result<bool> {
+        let mut conn = self.conn.clone();
+        redis::cmd("EXISTS")
+            .arg(self.worker_key(worker_id))
+            .query_async(&mut conn)
+            .await
+            .map_err(redis_error)
+    }
+
+    /// Live workers; forgets members whose heartbeat expired
+    pub async fn live_workers(&self) -> Result<Vec<WorkerInfo>> {
+        let mut conn = self.conn.clone();
+        let members: Vec<String> = redis::cmd("SMEMBERS")
+            .arg(self.members_key())
+            .query_async(&mut conn)
+            .await
+            .map_err(redis_error)?;
+        if members.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let keys: Vec<String> = members.iter().map(|id| self.worker_key(id)).collect();
+        let beats: Vec<Option<String>> = redis::cmd("MGET")
+            .arg(keys)
+            .query_async(&mut conn)
+            .await
+            .map_err(redis_error)?;
+
+        let mut live = Vec::new();
+        let mut dead = Vec::new();
+        for (worker_id, beat) in members.into_iter().zip(beats) {
+            match beat.map(|json| serde_json::from_str::<WorkerInfo>(&json)) {
+                Some(Ok(info)) => live.push(info),
+                Some(Err(e)) => warn!("Unreadable heartbeat for {}: {}", worker_id, e),
+                None => dead.push(worker_id),
+            }
+        }
+        if !dead.is_empty() {
+            redis::cmd("SREM")
+                .arg(self.members_key())
+                .arg(dead)
+                .query_async::<_, ()>(&mut conn)
+                .await
+                .map_err(redis_error)?;
+        }
+        Ok(live)
+    }
+
+    /// Beat every `ttl / 3` in the background until the handle is dropped
+    pub fn spawn(&self, info: WorkerInfo) -> HeartbeatHandle {
+        let registry = self.clone();
+        let interval = self.ttl / 3;
+        let task = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = registry.beat(&info).await {
+                    warn!("Heartbeat for {} failed: {}", info.worker_id, e);
+                }
+            }
+        });
+        HeartbeatHandle { task }
+    }
+}
+
+/// Stops the background heartbeat on drop
+pub struct HeartbeatHandle {
+    task: JoinHandle<()>,
+}
+
+impl Drop for HeartbeatHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
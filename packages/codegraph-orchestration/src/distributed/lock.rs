@@ -0,0 +1,134 @@
+use super::{redis_error, DEFAULT_PREFIX};
+use crate::error::{OrchestratorError, Result};
+use redis::aio::ConnectionManager;
+use redis::Script;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// Delete only if we still hold the lock
+const RELEASE: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('DEL', KEYS[1])
+end
+return 0
+"#;
+
+/// Extend only if we still hold the lock
+const RENEW: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('PEXPIRE', KEYS[1], ARGV[2])
+end
+return 0
+"#;
+
+/// Lease-based locks (`<prefix>:lock:<resource>`)
+#[derive(Clone)]
+pub struct LockManager {
+    conn: ConnectionManager,
+    prefix: String,
+}
+
+impl LockManager {
+    pub fn new(conn: ConnectionManager) -> Self {
+        Self {
+            conn,
+            prefix: DEFAULT_PREFIX.to_string(),
+        }
+    }
+
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Take the lock if free; the lease expires after `ttl` unless renewed
+    pub async fn try_acquire(&self, resource: &str, ttl: Duration) -> Result<Option<Lease>> {
+        let key = format!("{}:lock:{}", self.prefix, resource);
+        let token = Uuid::new_v4().to_string();
+        let mut conn = self.conn.clone();
+
+        let acquired: Option<String> = redis::cmd("SET")
+            .arg(&key)
+            .arg(&token)
+            .arg("NX")
+            .arg("PX")
+            .arg(ttl.as_millis() as u64)
+            .query_async(&mut conn)
+            .await
+            .map_err(redis_error)?;
+
+        Ok(acquired.map(|_| Lease {
+            conn: self.conn.clone(),
+            key,
+            token,
+            ttl,
+        }))
+    }
+
+    /// Poll `try_acquire` until `wait` has elapsed
+    pub async fn acquire(&self, resource: &str, ttl: Duration, wait: Duration) -> Result<Lease> {
+        let deadline = Instant::now() + wait;
+        let mut backoff = Duration::from_millis(50);
+        loop {
+            if let Some(lease) = self.try_acquire(resource, ttl).await? {
+                return Ok(lease);
+            }
+            if Instant::now() >= deadline {
+                return Err(OrchestratorError::Timeout(format!(
+                    "Lock {} not acquired within {}ms",
+                    resource,
+                    wait.as_millis()
+                )));
+            }
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(Duration::from_secs(1));
+        }
+    }
+}
+
+/// Held lock; the token fences out holders whose lease already expired
+pub struct Lease {
+    conn: ConnectionManager,
+    key: String,
+    token: String,
+    ttl: Duration,
+}
+
+impl Lease {
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Reset the TTL; `false` means the lease was lost
+    pub async fn renew(&self) -> Result<bool> {
+        let mut conn = self.conn.clone();
+        let renewed: i64 = Script::new(RENEW)
+            .key(&self.key)
+            .arg(&self.token)
+            .arg(self.ttl.as_millis() as u64)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(redis_error)?;
+        Ok(renewed == 1)
+    }
+
+    /// Unlock; `false` means the lease had already expired
+    pub async fn release(self) -> Result<bool> {
+        let mut conn = self.conn.clone();
+        let released: i64 = Script::new(RELEASE)
+            .key(&self.key)
+            .arg(&self.token)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(redis_error)?;
+        Ok(released == 1)
+    }
+}
@@ -0,0 +1,41 @@
+/*
+ * Distributed Coordination (Redis, `distributed` feature)
+ *
+ * Lets several indexer processes share one job:
+ * - LockManager:    lease-based locks (SET NX PX + token-checked renew/release)
+ * - WorkQueue:      file batches with per-batch leases; expired or orphaned
+ *                   leases go back to the queue, stale completions are rejected
+ * - WorkerRegistry: heartbeats with TTL, used to requeue a dead worker's
+ *                   batches before their lease runs out
+ *
+ * All multi-key updates run as Lua scripts, so each transition is atomic.
+ */
+
+pub mod heartbeat;
+pub mod lock;
+pub mod queue;
+
+pub use heartbeat::{HeartbeatHandle, WorkerInfo, WorkerRegistry, MIN_WORKER_TTL};
+pub use lock::{Lease, LockManager};
+pub use queue::{plan_batches, FileBatch, QueueProgress, WorkQueue};
+
+use crate::error::{OrchestratorError, Result};
+use redis::aio::ConnectionManager;
+
+/// Default key prefix
+pub const DEFAULT_PREFIX: &str = "codegraph";
+
+/// Auto-reconnecting connection (cheap to clone, share across components)
+pub async fn connect(url: &str) -> Result<ConnectionManager> {
+    let client = redis::Client::open(url).map_err(redis_error)?;
+    ConnectionManager::new(client).await.map_err(redis_error)
+}
+
+pub(crate) fn redis_error(err: redis::RedisError) -> OrchestratorError {
+    OrchestratorError::Redis(err.to_string())
+}
+
+/// Milliseconds since the epoch (lease deadlines)
+pub(crate) fn now_ms() -> u64 {
+    chrono::Utc::now().timestamp_millis() as u64
+}
@@ -0,0 +1,381 @@
+use super::heartbeat::WorkerRegistry;
+use super::{now_ms, redis_error, DEFAULT_PREFIX};
+use crate::error::{OrchestratorError, Result};
+use redis::aio::ConnectionManager;
+use redis::Script;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// KEYS: total, batches, pending; ARGV: id1, json1, id2, json2, ...
+/// First caller seeds the queue, later callers get 0
+const SEED: &str = r#"
+if redis.call('SETNX', KEYS[1], #ARGV / 2) == 0 then
+    return 0
+end
+for i = 1, #ARGV, 2 do
+    redis.call('HSET', KEYS[2], ARGV[i], ARGV[i + 1])
+    redis.call('RPUSH', KEYS[3], ARGV[i])
+end
+return #ARGV / 2
+"#;
+
+/// KEYS: pending, leases, owners, batches; ARGV: now, lease_ms, worker
+/// Requeues expired leases, then pops and leases the next batch
+const CLAIM: &str = r#"
+local expired = redis.call('ZRANGEBYSCORE', KEYS[2], '-inf', ARGV[1])
+for _, id in ipairs(expired) do
+    redis.call('ZREM', KEYS[2], id)
+    redis.call('HDEL', KEYS[3], id)
+    redis.call('RPUSH', KEYS[1], id)
+end
+local id = redis.call('LPOP', KEYS[1])
+if not id then
+    return false
+end
+redis.call('ZADD', KEYS[2], ARGV[1] + ARGV[2], id)
+redis.call('HSET', KEYS[3], id, ARGV[3])
+return redis.call('HGET', KEYS[4], id)
+"#;
+
+/// KEYS: leases, owners; ARGV: id, worker, deadline
+const EXTEND: &str = r#"
+if redis.call('HGET', KEYS[2], ARGV[1]) ~= ARGV[2] then
+    return 0
+end
+redis.call('ZADD', KEYS[1], ARGV[3], ARGV[1])
+return 1
+"#;
+
+/// KEYS: leases, owners, done; ARGV: id, worker
+const COMPLETE: &str = r#"
+if redis.call('HGET', KEYS[2], ARGV[1]) ~= ARGV[2] then
+    return 0
+end
+redis.call('ZREM', KEYS[1], ARGV[1])
+redis.call('HDEL', KEYS[2], ARGV[1])
+redis.call('SADD', KEYS[3], ARGV[1])
+return 1
+"#;
+
+/// KEYS: pending, leases, owners; ARGV: id, worker
+const REQUEUE: &str = r#"
+if redis.call('HGET', KEYS[3], ARGV[1]) ~= ARGV[2] then
+    return 0
+end
+redis.call('ZREM', KEYS[2], ARGV[1])
+redis.call('HDEL', KEYS[3], ARGV[1])
+redis.call('LPUSH', KEYS[1], ARGV[1])
+return 1
+"#;
+
+/// Unit of work: a slice of the job's files
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileBatch {
+    pub job_id: Uuid,
+    /// Position in `plan_batches` order
+    pub index: usize,
+    pub files: Vec<PathBuf>,
+}
+
+impl FileBatch {
+    pub fn id(&self) -> String {
+        self.index.to_string()
+    }
+}
+
+/// Split `files` into batches of `batch_size` (sorted, so every worker
+/// plans the same batches)
+pub fn plan_batches(job_id: Uuid, files: &[PathBuf], batch_size: usize) -> Vec<FileBatch> {
+    let mut files = files.to_vec();
+    files.sort();
+    files.dedup();
+    files
+        .chunks(batch_size.max(1))
+        .enumerate()
+        .map(|(index, chunk)| FileBatch {
+            job_id,
+            index,
+            files: chunk.to_vec(),
+        })
+        .collect()
+}
+
+/// Queue counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QueueProgress {
+    pub total: usize,
+    pub pending: usize,
+    pub in_flight: usize,
+    pub done: usize,
+}
+
+impl QueueProgress {
+    /// Seeded and every batch completed
+    pub fn is_finished(&self) -> bool {
+        self.total > 0 && self.done >= self.total
+    }
+}
+
+/// Per-job keys (`<prefix>:queue:<job_id>:*`)
+struct QueueKeys {
+    total: String,
+    batches: String,
+    pending: String,
+    leases: String,
+    owners: String,
+    done: String,
+}
+
+impl QueueKeys {
+    fn new(prefix: &str, job_id: Uuid) -> Self {
+        let key = |name: &str| format!("{}:queue:{}:{}", prefix, job_id, name);
+        Self {
+            total: key("total"),
+            batches: key("batches"),
+            pending: key("pending"),
+            leases: key("leases"),
+            owners: key("owners"),
+            done: key("done"),
+        }
+    }
+
+    fn all(&self) -> [&str; 6] {
+        [
+            &self.total,
+            &self.batches,
+            &self.pending,
+            &self.leases,
+            &self.owners,
+            &self.done,
+        ]
+    }
+}
+
+/// Leased work queue of file batches
+///
+/// A claimed batch belongs to one worker until it completes it, its lease
+/// expires, or the worker's heartbeat disappears (`requeue_orphans`).
+/// Completions from a worker that lost the lease are rejected, so each
+/// batch is completed exactly once.
+#[derive(Clone)]
+pub struct WorkQueue {
+    conn: ConnectionManager,
+    prefix: String,
+}
+
+impl WorkQueue {
+    pub fn new(conn: ConnectionManager) -> Self {
+        Self {
+            conn,
+            prefix: DEFAULT_PREFIX.to_string(),
+        }
+    }
+
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Seed the job's queue; returns 0 if another worker already did
+    pub async fn enqueue(
+        &self,
+        job_id: Uuid,
+        files: &[PathBuf],
+        batch_size: usize,
+    ) -> Result<usize> {
+        let batches = plan_batches(job_id, files, batch_size);
+        if batches.is_empty() {
+            return Ok(0);
+        }
+        let keys = QueueKeys::new(&self.prefix, job_id);
+        let mut invocation = Script::new(SEED).prepare_invoke();
+        invocation
+            .key(&keys.total)
+            .key(&keys.batches)
+            .key(&keys.pending);
+        for batch in &batches {
+            let json = serde_json::to_string(batch).map_err(OrchestratorError::serialization)?;
+            invocation.arg(batch.id()).arg(json);
+        }
+        let mut conn = self.conn.clone();
+        let seeded: usize = invocation
+            .invoke_async(&mut conn)
+            .await
+            .map_err(redis_error)?;
+        Ok(seeded)
+    }
+
+    /// Lease the next batch to `worker_id` for `lease`
+    pub async fn claim(
+        &self,
+        job_id: Uuid,
+        worker_id: &str,
+        lease: Duration,
+    ) -> Result<Option<FileBatch>> {
+        let keys = QueueKeys::new(&self.prefix, job_id);
+        let mut conn = self.conn.clone();
+        let json: Option<String> = Script::new(CLAIM)
+            .key(&keys.pending)
+            .key(&keys.leases)
+            .key(&keys.owners)
+            .key(&keys.batches)
+            .arg(now_ms())
+            .arg(lease.as_millis() as u64)
+            .arg(worker_id)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(redis_error)?;
+        json.map(|j| serde_json::from_str(&j).map_err(OrchestratorError::serialization))
+            .transpose()
+    }
+
+    /// Push the lease deadline out; `false` means the batch was reclaimed
+    pub async fn extend(
+        &self,
+        batch: &FileBatch,
+        worker_id: &str,
+        lease: Duration,
+    ) -> Result<bool> {
+        let keys = QueueKeys::new(&self.prefix, batch.job_id);
+        let mut conn = self.conn.clone();
+        let extended: i64 = Script::new(EXTEND)
+            .key(&keys.leases)
+            .key(&keys.owners)
+            .arg(batch.id())
+            .arg(worker_id)
+            .arg(now_ms() + lease.as_millis() as u64)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(redis_error)?;
+        Ok(extended == 1)
+    }
+
+    /// Mark done; `false` means the lease was lost and the result must be
+    /// discarded (another worker owns the batch now)
+    pub async fn complete(&self, batch: &FileBatch, worker_id: &str) -> Result<bool> {
+        let keys = QueueKeys::new(&self.prefix, batch.job_id);
+        let mut conn = self.conn.clone();
+        let completed: i64 = Script::new(COMPLETE)
+            .key(&keys.leases)
+            .key(&keys.owners)
+            .key(&keys.done)
+            .arg(batch.id())
+            .arg(worker_id)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(redis_error)?;
+        Ok(completed == 1)
+    }
+
+    /// Give a batch back (e.g. on graceful shutdown)
+    pub async fn abandon(&self, batch: &FileBatch, worker_id: &str) -> Result<bool> {
+        self.requeue(batch.job_id, &batch.id(), worker_id).await
+    }
+
+    /// Requeue batches whose owner has no live heartbeat
+    pub async fn requeue_orphans(&self, job_id: Uuid, workers: &WorkerRegistry) -> Result<usize> {
+        let keys = QueueKeys::new(&self.prefix, job_id);
+        let mut conn = self.conn.clone();
+        let owners: HashMap<String, String> = redis::cmd("HGETALL")
+            .arg(&keys.owners)
+            .query_async(&mut conn)
+            .await
+            .map_err(redis_error)?;
+
+        let mut requeued = 0;
+        for (batch_id, worker_id) in owners {
+            if !workers.is_alive(&worker_id).await?
+                && self.requeue(job_id, &batch_id, &worker_id).await?
+            {
+                requeued += 1;
+            }
+        }
+        Ok(requeued)
+    }
+
+    pub async fn progress(&self, job_id: Uuid) -> Result<QueueProgress> {
+        let keys = QueueKeys::new(&self.prefix, job_id);
+        let mut conn = self.conn.clone();
+        let (total, pending, in_flight, done): (Option<usize>, usize, usize, usize) = redis::pipe()
+            .cmd("GET")
+            .arg(&keys.total)
+            .cmd("LLEN")
+            .arg(&keys.pending)
+            .cmd("ZCARD")
+            .arg(&keys.leases)
+            .cmd("SCARD")
+            .arg(&keys.done)
+            .query_async(&mut conn)
+            .await
+            .map_err(redis_error)?;
+        Ok(QueueProgress {
+            total: total.unwrap_or(0),
+            pending,
+            in_flight,
+            done,
+        })
+    }
+
+    /// Drop all of the job's queue state
+    pub async fn clear(&self, job_id: Uuid) -> Result<()> {
+        let keys = QueueKeys::new(&self.prefix, job_id);
+        let mut conn = self.conn.clone();
+        redis::cmd("DEL")
+            .arg(keys.all().to_vec())
+            .query_async::<_, ()>(&mut conn)
+            .await
+            .map_err(redis_error)
+    }
+
+    async fn requeue(&self, job_id: Uuid, batch_id: &str, worker_id: &str) -> Result<bool> {
+        let keys = QueueKeys::new(&self.prefix, job_id);
+        let mut conn = self.conn.clone();
+        let requeued: i64 = Script::new(REQUEUE)
+            .key(&keys.pending)
+            .key(&keys.leases)
+            .key(&keys.owners)
+            .arg(batch_id)
+            .arg(worker_id)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(redis_error)?;
+        Ok(requeued == 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_batches_is_deterministic() {
+        let job_id = Uuid::new_v4();
+        let files: Vec<PathBuf> = ["c.py", "a.py", "b.py", "a.py", "d.py", "e.py"]
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+
+        let batches = plan_batches(job_id, &files, 2);
+        assert_eq!(batches.len(), 3);
+        assert_eq!(
+            batches[0].files,
+            vec![PathBuf::from("a.py"), PathBuf::from("b.py")]
+        );
+        assert_eq!(batches[2].files, vec![PathBuf::from("e.py")]);
+        assert_eq!(batches[2].id(), "2");
+
+        let mut shuffled = files.clone();
+        shuffled.reverse();
+        assert_eq!(plan_batches(job_id, &shuffled, 2), batches);
+        assert_eq!(plan_batches(job_id, &files, 0).len(), 5);
+
+        let json = serde_json::to_string(&batches[1]).unwrap();
+        assert_eq!(
+            serde_json::from_str::<FileBatch>(&json).unwrap(),
+            batches[1]
+        );
+    }
+}
@@ -4,6 +4,7 @@ pub type Result<T> = std::result::Result<T, OrchestratorError>;
 
 #[derive(Error, Debug)]
 pub enum OrchestratorError {
+    #[cfg(feature = "postgres")]
     #[error("Database error: {0}")]
     Database(#[from] sqlx::Error),
 
@@ -46,6 +47,12 @@ pub enum OrchestratorError {
     #[error("Configuration error: {0}")]
     Config(String),
 
+    #[error("Event sink error: {0}")]
+    EventSink(String),
+
+    #[error("Redis error: {0}")]
+    Redis(String),
+
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
@@ -0,0 +1,80 @@
+// Kafka sink (`kafka` feature)
+//
+// One topic for all event types; the key is the repo ID so a repo's events
+// stay ordered within a partition. The producer is idempotent with
+// `acks=all`, and `publish` awaits the delivery report, so it only succeeds
+// once all in-sync replicas have the message.
+
+use super::{EventSink, IndexEvent};
+use crate::error::{OrchestratorError, Result};
+use async_trait::async_trait;
+use rdkafka::config::ClientConfig;
+use rdkafka::message::{Header, OwnedHeaders};
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use rdkafka::util::Timeout;
+use std::time::Duration;
+
+pub struct KafkaEventSink {
+    producer: FutureProducer,
+    topic: String,
+    timeout: Duration,
+}
+
+impl KafkaEventSink {
+    /// Connect to `brokers` (comma-separated `host:port` list)
+    pub fn new(brokers: &str, topic: impl Into<String>) -> Result<Self> {
+        let mut config = ClientConfig::new();
+        config
+            .set("bootstrap.servers", brokers)
+            .set("enable.idempotence", "true")
+            .set("acks", "all");
+        Self::from_config(&config, topic)
+    }
+
+    /// Custom producer settings (security, linger, batching)
+    pub fn from_config(config: &ClientConfig, topic: impl Into<String>) -> Result<Self> {
+        let producer: FutureProducer = config.create().map_err(kafka_error)?;
+        Ok(Self {
+            producer,
+            topic: topic.into(),
+            timeout: Duration::from_secs(30),
+        })
+    }
+
+    /// Per-message delivery timeout (queueing included)
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+#[async_trait]
+impl EventSink for KafkaEventSink {
+    async fn publish(&self, event: &IndexEvent) -> Result<()> {
+        let payload = event.to_json()?;
+        let event_id = event.event_id.to_string();
+        let headers = OwnedHeaders::new()
+            .insert(Header {
+                key: "event_id",
+                value: Some(event_id.as_str()),
+            })
+            .insert(Header {
+                key: "event_type",
+                value: Some(event.event_type()),
+            });
+        let record = FutureRecord::to(&self.topic)
+            .key(&event.repo_id)
+            .payload(&payload)
+            .headers(headers);
+
+        self.producer
+            .send(record, Timeout::After(self.timeout))
+            .await
+            .map(|_| ())
+            .map_err(|(e, _)| kafka_error(e))
+    }
+}
+
+fn kafka_error(err: rdkafka::error::KafkaError) -> OrchestratorError {
+    OrchestratorError::EventSink(format!("Kafka: {}", err))
+}
@@ -0,0 +1,371 @@
+/*
+ * Snapshot Events - publish indexing results to a message bus
+ *
+ * Events:
+ * - file_indexed        (one per file in the snapshot)
+ * - vulnerability_found (one per security finding)
+ * - snapshot_created    (last, once everything above was acknowledged)
+ *
+ * Delivery is at-least-once: the orchestrator publishes before it marks the
+ * job completed and drops its checkpoints, so a failed publish fails the job
+ * (transient) and the retry re-sends every event. Event IDs are derived from
+ * the job ID, so consumers (and broker-side dedup) see the same ID each time.
+ */
+
+#[cfg(feature = "kafka")]
+pub mod kafka;
+#[cfg(feature = "nats")]
+pub mod nats;
+
+#[cfg(feature = "kafka")]
+pub use kafka::KafkaEventSink;
+#[cfg(feature = "nats")]
+pub use nats::NatsEventSink;
+
+use crate::error::{OrchestratorError, Result};
+use crate::stages::IRResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::warn;
+use uuid::Uuid;
+
+/// Event body (`"type"` tag on the wire)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventPayload {
+    SnapshotCreated {
+        previous_snapshot_id: Option<String>,
+        files_processed: usize,
+        nodes_created: usize,
+        chunks_created: usize,
+        vulnerabilities: usize,
+        duration_ms: u64,
+    },
+    FileIndexed {
+        file_path: String,
+        node_count: usize,
+        edge_count: usize,
+        errors: Vec<String>,
+    },
+    VulnerabilityFound {
+        file_path: String,
+        vuln_type: String,
+        cwe_id: Option<u32>,
+        severity: u8,
+        location: String,
+        message: String,
+    },
+}
+
+impl EventPayload {
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            EventPayload::SnapshotCreated { .. } => "snapshot_created",
+            EventPayload::FileIndexed { .. } => "file_indexed",
+            EventPayload::VulnerabilityFound { .. } => "vulnerability_found",
+        }
+    }
+}
+
+/// Event envelope
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexEvent {
+    /// Stable across redeliveries (dedup key)
+    pub event_id: Uuid,
+    pub job_id: Uuid,
+    pub repo_id: String,
+    pub snapshot_id: String,
+    pub timestamp: DateTime<Utc>,
+    #[serde(flatten)]
+    pub payload: EventPayload,
+}
+
+impl IndexEvent {
+    /// `key` identifies the event within its job (e.g. the file path)
+    pub fn new(
+        job_id: Uuid,
+        repo_id: &str,
+        snapshot_id: &str,
+        key: &str,
+        payload: EventPayload,
+    ) -> Self {
+        let name = format!("{}:{}", payload.event_type(), key);
+        Self {
+            event_id: Uuid::new_v5(&job_id, name.as_bytes()),
+            job_id,
+            repo_id: repo_id.to_string(),
+            snapshot_id: snapshot_id.to_string(),
+            timestamp: Utc::now(),
+            payload,
+        }
+    }
+
+    pub fn event_type(&self) -> &'static str {
+        self.payload.event_type()
+    }
+
+    /// JSON wire format
+    pub fn to_json(&self) -> Result<Vec<u8>> {
+        serde_json::to_vec(self).map_err(OrchestratorError::serialization)
+    }
+}
+
+/// Snapshot events for a completed job, `snapshot_created` last
+pub fn snapshot_events(
+    job_id: Uuid,
+    repo_id: &str,
+    snapshot_id: &str,
+    previous_snapshot_id: Option<String>,
+    ir_results: &[IRResult],
+    result: &crate::orchestrator::PipelineResult,
+) -> Vec<IndexEvent> {
+    let event = |key: &str, payload| IndexEvent::new(job_id, repo_id, snapshot_id, key, payload);
+    let mut events = Vec::new();
+    let mut vulnerabilities = 0;
+
+    for ir in ir_results {
+        events.push(event(
+            &ir.file_path,
+            EventPayload::FileIndexed {
+                file_path: ir.file_path.clone(),
+                node_count: ir.node_count,
+                edge_count: ir.edge_count,
+                errors: ir.errors.clone(),
+            },
+        ));
+        for (i, vuln) in ir.vulnerabilities.iter().enumerate() {
+            events.push(event(
+                &format!("{}#{}", ir.file_path, i),
+                EventPayload::VulnerabilityFound {
+                    file_path: ir.file_path.clone(),
+                    vuln_type: vuln.vuln_type.clone(),
+                    cwe_id: vuln.cwe_id,
+                    severity: vuln.severity,
+                    location: vuln.location.clone(),
+                    message: vuln.message.clone(),
+                },
+            ));
+        }
+        vulnerabilities += ir.vulnerabilities.len();
+    }
+
+    events.push(event(
+        snapshot_id,
+        EventPayload::SnapshotCreated {
+            previous_snapshot_id,
+            files_processed: result.files_processed,
+            nodes_created: result.nodes_created,
+            chunks_created: result.chunks_created,
+            vulnerabilities,
+            duration_ms: result.duration_ms,
+        },
+    ));
+    events
+}
+
+/// Message bus backend
+#[async_trait]
+pub trait EventSink: Send + Sync {
+    /// Deliver one event; returns once the broker has acknowledged it
+    async fn publish(&self, event: &IndexEvent) -> Result<()>;
+
+    /// Wait for in-flight deliveries (default: nothing buffered)
+    async fn flush(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Retry with exponential backoff around an `EventSink`
+#[derive(Clone)]
+pub struct EventPublisher {
+    sink: Arc<dyn EventSink>,
+    max_attempts: u32,
+    initial_backoff: Duration,
+}
+
+impl EventPublisher {
+    pub fn new(sink: Arc<dyn EventSink>) -> Self {
+        Self {
+            sink,
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(200),
+        }
+    }
+
+    /// Attempts per event (at least 1)
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Delay before the first retry (doubled on each further retry)
+    pub fn with_initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Publish in order, then flush; fails on the first event that
+    /// exhausts its retries
+    pub async fn publish_all(&self, events: &[IndexEvent]) -> Result<()> {
+        for event in events {
+            self.publish(event).await?;
+        }
+        self.sink.flush().await
+    }
+
+    async fn publish(&self, event: &IndexEvent) -> Result<()> {
+        let mut backoff = self.initial_backoff;
+        let mut attempt = 1;
+        loop {
+            match self.sink.publish(event).await {
+                Ok(()) => return Ok(()),
+                Err(e) if attempt < self.max_attempts => {
+                    warn!(
+                        "Publishing {} event {} failed (attempt {}/{}): {}",
+                        event.event_type(),
+                        event.event_id,
+                        attempt,
+                        self.max_attempts,
+                        e
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                    attempt += 1;
+                }
+                Err(e) => {
+                    return Err(OrchestratorError::EventSink(format!(
+                        "{} event {} not delivered after {} attempts: {}",
+                        event.event_type(),
+                        event.event_id,
+                        attempt,
+                        e
+                    )))
+                }
+            }
+        }
+    }
+}
+
+/// Collects events in memory (tests, local runs)
+#[derive(Default)]
+pub struct MemoryEventSink {
+    events: parking_lot::Mutex<Vec<IndexEvent>>,
+}
+
+impl MemoryEventSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn events(&self) -> Vec<IndexEvent> {
+        self.events.lock().clone()
+    }
+}
+
+#[async_trait]
+impl EventSink for MemoryEventSink {
+    async fn publish(&self, event: &IndexEvent) -> Result<()> {
+        self.events.lock().push(event.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchestrator::PipelineResult;
+    use crate::stages::VulnerabilityInfo;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Fails the first `failures` publishes
+    struct FlakySink {
+        failures: AtomicUsize,
+        inner: MemoryEventSink,
+    }
+
+    #[async_trait]
+    impl EventSink for FlakySink {
+        async fn publish(&self, event: &IndexEvent) -> Result<()> {
+            if self.failures.load(Ordering::SeqCst) > 0 {
+                self.failures.fetch_sub(1, Ordering::SeqCst);
+                return Err(OrchestratorError::EventSink("connection reset".to_string()));
+            }
+            self.inner.publish(event).await
+        }
+    }
+
+    fn ir_result(path: &str, vulnerabilities: Vec<VulnerabilityInfo>) -> IRResult {
+        IRResult {
+            file_path: path.to_string(),
+            node_count: 3,
+            edge_count: 2,
+            occurrence_count: 0,
+            bfg_count: 0,
+            cfg_edges: 0,
+            type_entities: 0,
+            dfg_count: 0,
+            ssa_count: 0,
+            errors: vec![],
+            imports: vec![],
+            vulnerabilities,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_events_retry_with_stable_ids() {
+        let job_id = Uuid::new_v4();
+        let ir = vec![
+            ir_result("a.py", vec![]),
+            ir_result(
+                "b.py",
+                vec![VulnerabilityInfo {
+                    vuln_type: "SQLInjection".to_string(),
+                    cwe_id: Some(89),
+                    severity: 9,
+                    location: "b.py:4".to_string(),
+                    message: "tainted query".to_string(),
+                }],
+            ),
+        ];
+        let result = PipelineResult {
+            files_processed: 2,
+            ..Default::default()
+        };
+        let events = snapshot_events(job_id, "repo", "snap2", None, &ir, &result);
+        let types: Vec<_> = events.iter().map(|e| e.event_type()).collect();
+        assert_eq!(
+            types,
+            vec![
+                "file_indexed",
+                "file_indexed",
+                "vulnerability_found",
+                "snapshot_created"
+            ]
+        );
+        // Same job, same IDs
+        let again = snapshot_events(job_id, "repo", "snap2", None, &ir, &result);
+        assert_eq!(events[2].event_id, again[2].event_id);
+
+        let json: serde_json::Value =
+            serde_json::from_slice(&events[3].to_json().unwrap()).unwrap();
+        assert_eq!(json["type"], "snapshot_created");
+        assert_eq!(json["files_processed"], 2);
+        assert_eq!(json["vulnerabilities"], 1);
+
+        let sink = Arc::new(FlakySink {
+            failures: AtomicUsize::new(2),
+            inner: MemoryEventSink::new(),
+        });
+        let publisher = EventPublisher::new(sink.clone())
+            .with_max_attempts(3)
+            .with_initial_backoff(Duration::from_millis(1));
+        publisher.publish_all(&events).await.unwrap();
+        assert_eq!(sink.inner.events(), events);
+
+        sink.failures.store(3, Ordering::SeqCst);
+        assert!(publisher.publish_all(&events).await.is_err());
+    }
+}
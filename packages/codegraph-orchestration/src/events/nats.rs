@@ -0,0 +1,58 @@
+// NATS JetStream sink (`nats` feature)
+//
+// Subject per event type (`<prefix>.snapshot_created`, ...). Publishes wait
+// for the JetStream ack, and `Nats-Msg-Id` carries the event ID so the
+// stream's duplicate window drops redeliveries.
+
+use super::{EventSink, IndexEvent};
+use crate::error::{OrchestratorError, Result};
+use async_nats::header::NATS_MESSAGE_ID;
+use async_nats::jetstream::{self, Context};
+use async_nats::HeaderMap;
+use async_trait::async_trait;
+
+pub struct NatsEventSink {
+    jetstream: Context,
+    subject_prefix: String,
+}
+
+impl NatsEventSink {
+    /// Connect to `url` (e.g. `nats://localhost:4222`); a stream must
+    /// already capture `<subject_prefix>.>`
+    pub async fn connect(url: &str, subject_prefix: impl Into<String>) -> Result<Self> {
+        let client = async_nats::connect(url).await.map_err(nats_error)?;
+        Ok(Self::new(jetstream::new(client), subject_prefix))
+    }
+
+    /// Wrap an existing JetStream context
+    pub fn new(jetstream: Context, subject_prefix: impl Into<String>) -> Self {
+        Self {
+            jetstream,
+            subject_prefix: subject_prefix.into(),
+        }
+    }
+
+    fn subject(&self, event: &IndexEvent) -> String {
+        format!("{}.{}", self.subject_prefix, event.event_type())
+    }
+}
+
+#[async_trait]
+impl EventSink for NatsEventSink {
+    async fn publish(&self, event: &IndexEvent) -> Result<()> {
+        let payload = event.to_json()?;
+        let mut headers = HeaderMap::new();
+        headers.insert(NATS_MESSAGE_ID, event.event_id.to_string().as_str());
+
+        let ack = self
+            .jetstream
+            .publish_with_headers(self.subject(event), headers, payload.into())
+            .await
+            .map_err(nats_error)?;
+        ack.await.map(|_| ()).map_err(nats_error)
+    }
+}
+
+fn nats_error<E: std::fmt::Display>(err: E) -> OrchestratorError {
+    OrchestratorError::EventSink(format!("NATS: {}", err))
+}
@@ -10,20 +10,17 @@
 use crate::checkpoint::CheckpointManager;
 use crate::error::{OrchestratorError, Result};
 use crate::job::StageId;
-use crate::shared::models::{Edge, Node};
-use codegraph_ir::features::cross_file::{update_global_context, IRDocument};
-use codegraph_ir::features::layered_orchestrator::LayeredOrchestrator;
+use codegraph_ir::features::chunking::{ChunkBuilder, ChunkIdGenerator};
+use codegraph_ir::features::cross_file::{
+    build_global_context, update_global_context, GlobalContextResult, IRDocument,
+};
 use codegraph_ir::pipeline::processor::process_python_file;
-use codegraph_ir::shared::models::{Edge as IREdge, Node as IRNode};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use std::time::Instant;
 use tracing::{info, warn};
 use uuid::Uuid;
 
-// Re-export types from codegraph-ir
-type GlobalContextResult = codegraph_ir::features::cross_file::GlobalContextResult;
-
 /// Incremental update result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IncrementalResult {
@@ -53,20 +50,16 @@ pub struct IncrementalResult {
 
 /// Incremental update orchestrator
 ///
-/// Wraps LayeredOrchestrator to provide incremental update capabilities
-/// for the pipeline orchestration system.
+/// Wraps the codegraph-ir cross-file resolver to provide incremental update
+/// capabilities for the pipeline orchestration system.
 pub struct IncrementalOrchestrator {
-    layered: LayeredOrchestrator,
     checkpoint_mgr: Arc<CheckpointManager>,
 }
 
 impl IncrementalOrchestrator {
     /// Create new incremental orchestrator
     pub fn new(checkpoint_mgr: Arc<CheckpointManager>) -> Self {
-        Self {
-            layered: LayeredOrchestrator::new(),
-            checkpoint_mgr,
-        }
+        Self { checkpoint_mgr }
     }
 
     /// Perform incremental update
@@ -127,29 +120,7 @@ impl IncrementalOrchestrator {
         // L1: Process changed files only (O(n_changed))
         // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
         let l1_start = Instant::now();
-        let mut changed_ir_docs = Vec::new();
-        let mut nodes_created = 0;
-
-        for (file_path, source) in &changed_files {
-            let module_path = file_path_to_module_path(file_path);
-            let result = process_python_file(source, repo_id, file_path, &module_path);
-
-            nodes_created += result.nodes.len();
-
-            // Convert to IR types for cross-file resolver
-            let ir_nodes: Vec<IRNode> = result
-                .nodes
-                .iter()
-                .map(convert_core_node_to_ir_node)
-                .collect();
-            let ir_edges: Vec<IREdge> = result
-                .edges
-                .iter()
-                .map(convert_core_edge_to_ir_edge)
-                .collect();
-
-            changed_ir_docs.push(IRDocument::new(file_path.clone(), ir_nodes, ir_edges));
-        }
+        let (changed_ir_docs, nodes_created) = build_ir_documents(&changed_files, repo_id);
 
         let l1_duration = l1_start.elapsed();
         info!(
@@ -165,24 +136,7 @@ impl IncrementalOrchestrator {
         let l3_start = Instant::now();
 
         // Process all files for global context
-        let mut all_ir_docs = Vec::new();
-        for (file_path, source) in &all_files {
-            let module_path = file_path_to_module_path(file_path);
-            let result = process_python_file(source, repo_id, file_path, &module_path);
-
-            let ir_nodes: Vec<IRNode> = result
-                .nodes
-                .iter()
-                .map(convert_core_node_to_ir_node)
-                .collect();
-            let ir_edges: Vec<IREdge> = result
-                .edges
-                .iter()
-                .map(convert_core_edge_to_ir_edge)
-                .collect();
-
-            all_ir_docs.push(IRDocument::new(file_path.clone(), ir_nodes, ir_edges));
-        }
+        let (all_ir_docs, _) = build_ir_documents(&all_files, repo_id);
 
         // Incremental update with BFS transitive dependency detection
         let (new_global_context, affected_files) =
@@ -201,11 +155,7 @@ impl IncrementalOrchestrator {
         // SOTA Optimization: O(n_affected) instead of O(n_files)
         // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
         let l2_start = Instant::now();
-
-        // TODO: Implement actual partial chunk rebuild
-        // For now, we just count the affected files
-        let chunks_created = affected_files.len(); // Placeholder
-
+        let chunks_created = build_chunks(&affected_files, repo_id, snapshot_id);
         let l2_duration = l2_start.elapsed();
         info!(
             "L2 (Chunking): Rebuilt chunks for {} affected files in {} ms",
@@ -237,7 +187,7 @@ impl IncrementalOrchestrator {
         let total_duration = total_start.elapsed();
 
         // Estimate full rebuild time (based on affected file ratio)
-        let estimated_full_rebuild_ms = if affected_files.len() > 0 {
+        let estimated_full_rebuild_ms = if !affected_files.is_empty() {
             (total_duration.as_millis() as f64 * all_files.len() as f64
                 / affected_files.len() as f64) as u64
         } else {
@@ -286,17 +236,24 @@ impl IncrementalOrchestrator {
 
         let total_start = Instant::now();
 
-        // Use LayeredOrchestrator for full batch processing
-        let batch_result = self
-            .layered
-            .process_batch(all_files.clone(), repo_id, snapshot_id)
-            .map_err(|e| OrchestratorError::StageExecutionFailed(e))?;
+        let l1_start = Instant::now();
+        let (ir_docs, nodes_created) = build_ir_documents(&all_files, repo_id);
+        let l1_duration = l1_start.elapsed();
+
+        let l3_start = Instant::now();
+        let global_context = build_global_context(ir_docs);
+        let l3_duration = l3_start.elapsed();
+
+        let l2_start = Instant::now();
+        let file_paths: Vec<String> = all_files.iter().map(|(p, _)| p.clone()).collect();
+        let chunks_created = build_chunks(&file_paths, repo_id, snapshot_id);
+        let l2_duration = l2_start.elapsed();
 
         let total_duration = total_start.elapsed();
 
         // Save global context to cache
         let cache_key = format!("global_context:{}:{}", repo_id, snapshot_id);
-        let cache_data = bincode::serialize(&batch_result.global_context)
+        let cache_data = bincode::serialize(&global_context)
             .map_err(|e| OrchestratorError::Serialization(e.to_string()))?;
 
         self.checkpoint_mgr
@@ -309,21 +266,48 @@ impl IncrementalOrchestrator {
             .await?;
 
         Ok(IncrementalResult {
-            changed_files: all_files.iter().map(|(p, _)| p.clone()).collect(),
-            affected_files: all_files.iter().map(|(p, _)| p.clone()).collect(),
+            changed_files: file_paths.clone(),
+            affected_files: file_paths,
             total_files: all_files.len(),
             files_reprocessed: all_files.len(),
-            nodes_created: batch_result.nodes.len(),
-            chunks_created: batch_result.chunks.len(),
-            l1_ir_duration_ms: batch_result.layer1_duration_ms,
-            l3_cross_file_duration_ms: batch_result.layer3_duration_ms,
-            l2_chunk_duration_ms: batch_result.layer2_duration_ms,
+            nodes_created,
+            chunks_created,
+            l1_ir_duration_ms: l1_duration.as_millis() as u64,
+            l3_cross_file_duration_ms: l3_duration.as_millis() as u64,
+            l2_chunk_duration_ms: l2_duration.as_millis() as u64,
             total_duration_ms: total_duration.as_millis() as u64,
             speedup_factor: 1.0, // No speedup for full build
         })
     }
 }
 
+/// L1 IR documents for `(file_path, source)` pairs, plus their node count
+fn build_ir_documents(files: &[(String, String)], repo_id: &str) -> (Vec<IRDocument>, usize) {
+    let mut nodes_created = 0;
+    let docs = files
+        .iter()
+        .map(|(file_path, source)| {
+            let module_path = file_path_to_module_path(file_path);
+            let result = process_python_file(source, repo_id, file_path, &module_path);
+            nodes_created += result.nodes.len();
+            IRDocument::new(file_path.clone(), result.nodes, result.edges)
+        })
+        .collect();
+    (docs, nodes_created)
+}
+
+/// L2 structural chunks for `file_paths`, returning how many were built
+fn build_chunks(file_paths: &[String], repo_id: &str, snapshot_id: &str) -> usize {
+    file_paths
+        .iter()
+        .map(|file_path| {
+            let mut builder = ChunkBuilder::new(ChunkIdGenerator::new());
+            let (chunks, _, _) = builder.build(repo_id, file_path, "python", Some(snapshot_id));
+            chunks.len()
+        })
+        .sum()
+}
+
 /// Convert file path to module path
 ///
 /// Example: "src/myapp/services/user.py" → "myapp.services.user"
@@ -333,87 +317,6 @@ fn file_path_to_module_path(file_path: &str) -> String {
     without_src.replace('/', ".")
 }
 
-/// Convert codegraph_core::Node to shared::models::Node
-fn convert_core_node_to_ir_node(core_node: &Node) -> IRNode {
-    use crate::shared::models::NodeKind;
-    use codegraph_ir::shared::models::{NodeKind as IRNodeKind, Span as IRSpan};
-
-    IRNode {
-        id: core_node.id.clone(),
-        kind: match core_node.kind {
-            NodeKind::File => IRNodeKind::File,
-            NodeKind::Module => IRNodeKind::Module,
-            NodeKind::Class => IRNodeKind::Class,
-            NodeKind::Function => IRNodeKind::Function,
-            NodeKind::Method => IRNodeKind::Method,
-            NodeKind::Variable => IRNodeKind::Variable,
-            NodeKind::Parameter => IRNodeKind::Parameter,
-            NodeKind::Field => IRNodeKind::Field,
-            NodeKind::Lambda => IRNodeKind::Lambda,
-            NodeKind::Import => IRNodeKind::Import,
-        },
-        fqn: core_node.fqn.clone(),
-        file_path: core_node.file_path.clone(),
-        span: IRSpan::new(
-            core_node.span.start_line,
-            core_node.span.start_col,
-            core_node.span.end_line,
-            core_node.span.end_col,
-        ),
-        language: core_node.language.clone(),
-        stable_id: core_node.stable_id.clone(),
-        content_hash: core_node.content_hash.clone(),
-        name: core_node.name.clone(),
-        module_path: core_node.module_path.clone(),
-        parent_id: core_node.parent_id.clone(),
-        body_span: core_node
-            .body_span
-            .as_ref()
-            .map(|s| IRSpan::new(s.start_line, s.start_col, s.end_line, s.end_col)),
-        docstring: core_node.docstring.clone(),
-        decorators: None,
-        annotations: None,
-        modifiers: None,
-        is_async: None,
-        is_generator: None,
-        is_static: None,
-        is_abstract: None,
-        parameters: None,
-        return_type: None,
-        control_flow: None,
-        base_classes: None,
-        metaclass: None,
-        type_annotation: None,
-        initial_value: None,
-    }
-}
-
-/// Convert codegraph_core::Edge to shared::models::Edge
-fn convert_core_edge_to_ir_edge(core_edge: &Edge) -> IREdge {
-    use crate::shared::models::EdgeKind;
-    use codegraph_ir::shared::models::{EdgeKind as IREdgeKind, Span as IRSpan};
-
-    IREdge {
-        source_id: core_edge.source_id.clone(),
-        target_id: core_edge.target_id.clone(),
-        kind: match core_edge.kind {
-            EdgeKind::Contains => IREdgeKind::Contains,
-            EdgeKind::Calls => IREdgeKind::Calls,
-            EdgeKind::Reads => IREdgeKind::Reads,
-            EdgeKind::Writes => IREdgeKind::Writes,
-            EdgeKind::Inherits => IREdgeKind::Inherits,
-            EdgeKind::Imports => IREdgeKind::Imports,
-            EdgeKind::Defines => IREdgeKind::Defines,
-            EdgeKind::References => IREdgeKind::References,
-        },
-        span: core_edge
-            .span
-            .as_ref()
-            .map(|s| IRSpan::new(s.start_line, s.start_col, s.end_line, s.end_col)),
-        metadata: None,
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -434,4 +337,23 @@ mod tests {
         let _orch = IncrementalOrchestrator::new(checkpoint_mgr);
         // Should create successfully
     }
+
+    #[tokio::test]
+    async fn test_full_build_counts_built_chunks() {
+        let checkpoint_mgr = Arc::new(CheckpointManager::new_in_memory());
+        let mut orch = IncrementalOrchestrator::new(checkpoint_mgr);
+        let files = vec![
+            ("src/a.py".to_string(), "def a():\n    pass\n".to_string()),
+            ("src/b.py".to_string(), "def b():\n    pass\n".to_string()),
+        ];
+        let paths: Vec<String> = files.iter().map(|(p, _)| p.clone()).collect();
+
+        let result = orch
+            .incremental_update(Uuid::new_v4(), "repo", "snap", vec![], files, None)
+            .await
+            .unwrap();
+
+        assert!(result.chunks_created > paths.len());
+        assert_eq!(result.chunks_created, build_chunks(&paths, "repo", "snap"));
+    }
 }
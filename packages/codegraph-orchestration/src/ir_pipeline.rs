@@ -0,0 +1,556 @@
+//! In-process codegraph-ir runs on the orchestration DAG
+//!
+//! `IrPipeline` plans a codegraph-ir run as `StageNode`s of a `PipelineDAG`
+//! (one node per enabled IR stage, dependencies from codegraph-ir's own DAG)
+//! and runs it with an `IrStageCache`: DAG stage outputs are kept under the
+//! `CacheKeyManager` key of their stage, so a stage already computed for the
+//! same repo and snapshot is not executed again. IR stages producing a job
+//! stage's artifact share its key (`L2_Chunking` and `L2_Chunk` both use
+//! `chunks:<repo>:<snapshot>`).
+//!
+//! `IrPipeline::new` fingerprints the keys (see `CacheKeyManager`) with the
+//! contents of the run's input files, the analyzer version and each stage's
+//! own config, chained through the DAG: changing only the taint rules
+//! re-keys L14 (and whatever depends on it) while chunks, points-to etc.
+//! stay cached. L1 runs every
+//! time; set `ir_cache_dir` so it does not re-parse unchanged files.
+//!
+//! Outputs stay in memory. `IrStageCache::stats` reports hits and misses per
+//! stage; `invalidate`, `invalidate_all` and `clear` drop entries by hand.
+
+use crate::dag::{fingerprint_files, CacheKeyManager, PipelineDAG, StageNode};
+use crate::error::{OrchestratorError, Result};
+use crate::job::StageId;
+use codegraph_ir::features::vector::{EmbeddingProvider, HashingEmbeddingProvider, HnswParams};
+use codegraph_ir::pipeline::dag::{PipelineDAG as IrPipelineDAG, StageId as IrStageId};
+use codegraph_ir::pipeline::{
+    E2EPipelineConfig, E2EPipelineResult, IRIndexingOrchestrator, StageCache, StageOutput,
+};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use tracing::info;
+
+/// Timeout of IR stage nodes
+const IR_STAGE_TIMEOUT_MS: u64 = 300_000; // 5 minutes
+
+struct CachedOutput {
+    stage: IrStageId,
+    output: StageOutput,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Counters {
+    hits: u64,
+    misses: u64,
+}
+
+/// Cache counters of one stage
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StageCacheStats {
+    pub stage: IrStageId,
+    /// Stored outputs (all keys, not only the current ones)
+    pub entries: usize,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// `IrStageCache::stats` report, in pipeline order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub stages: Vec<StageCacheStats>,
+}
+
+impl CacheStats {
+    pub fn entries(&self) -> usize {
+        self.stages.iter().map(|s| s.entries).sum()
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.stages.iter().map(|s| s.hits).sum()
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.stages.iter().map(|s| s.misses).sum()
+    }
+
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits() + self.misses();
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits() as f64 / lookups as f64
+        }
+    }
+
+    pub fn stage(&self, stage: IrStageId) -> Option<&StageCacheStats> {
+        self.stages.iter().find(|s| s.stage == stage)
+    }
+}
+
+impl fmt::Display for CacheStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "cache stats: {} entries, {} hits, {} misses ({:.1}% hit rate)",
+            self.entries(),
+            self.hits(),
+            self.misses(),
+            self.hit_rate() * 100.0
+        )?;
+        for s in &self.stages {
+            writeln!(
+                f,
+                "  {:<24} entries={:<4} hits={:<6} misses={}",
+                s.stage.name(),
+                s.entries,
+                s.hits,
+                s.misses
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// DAG stage outputs of codegraph-ir runs, by cache key
+#[derive(Clone)]
+pub struct IrStageCache {
+    cache_keys: CacheKeyManager,
+    outputs: Arc<RwLock<HashMap<String, CachedOutput>>>,
+    counters: Arc<Mutex<HashMap<IrStageId, Counters>>>,
+}
+
+impl IrStageCache {
+    pub fn new(cache_keys: CacheKeyManager) -> Self {
+        Self {
+            cache_keys,
+            outputs: Arc::new(RwLock::new(HashMap::new())),
+            counters: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Same storage, keyed for another repo or snapshot
+    pub fn with_cache_keys(&self, cache_keys: CacheKeyManager) -> Self {
+        Self {
+            cache_keys,
+            outputs: self.outputs.clone(),
+            counters: self.counters.clone(),
+        }
+    }
+
+    pub fn cache_keys(&self) -> &CacheKeyManager {
+        &self.cache_keys
+    }
+
+    /// Is an output stored for `stage` under the current keys?
+    pub fn contains(&self, stage: IrStageId) -> bool {
+        let key = self.key(stage);
+        self.outputs
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .contains_key(&key)
+    }
+
+    /// Drop the output of `stage` under the current keys
+    pub fn invalidate(&self, stage: IrStageId) -> bool {
+        let key = self.key(stage);
+        self.outputs
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&key)
+            .is_some()
+    }
+
+    /// Drop every stored output of `stage` (any repo, snapshot or config)
+    pub fn invalidate_all(&self, stage: IrStageId) -> usize {
+        let mut outputs = self.outputs.write().unwrap_or_else(|e| e.into_inner());
+        let before = outputs.len();
+        outputs.retain(|_, cached| cached.stage != stage);
+        before - outputs.len()
+    }
+
+    /// Drop all outputs and reset the counters
+    pub fn clear(&self) {
+        self.outputs
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+        self.counters.lock().clear();
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let mut entries: HashMap<IrStageId, usize> = HashMap::new();
+        for cached in self
+            .outputs
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+        {
+            *entries.entry(cached.stage).or_default() += 1;
+        }
+        let counters = self.counters.lock();
+        let stages = IrStageId::ALL
+            .into_iter()
+            .filter_map(|stage| {
+                let count = counters.get(&stage).copied().unwrap_or_default();
+                let stored = entries.get(&stage).copied().unwrap_or(0);
+                (stored > 0 || count.hits > 0 || count.misses > 0).then_some(StageCacheStats {
+                    stage,
+                    entries: stored,
+                    hits: count.hits,
+                    misses: count.misses,
+                })
+            })
+            .collect();
+        CacheStats { stages }
+    }
+
+    fn key(&self, stage: IrStageId) -> String {
+        self.cache_keys.key_for_stage(StageId::Ir(stage))
+    }
+}
+
+impl StageCache for IrStageCache {
+    fn lookup(&self, stage: IrStageId) -> Option<StageOutput> {
+        let key = self.key(stage);
+        let output = self
+            .outputs
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&key)
+            .map(|cached| cached.output.clone());
+        let mut counters = self.counters.lock();
+        let count = counters.entry(stage).or_default();
+        if output.is_some() {
+            count.hits += 1;
+        } else {
+            count.misses += 1;
+        }
+        output
+    }
+
+    fn store(&self, stage: IrStageId, output: &StageOutput) {
+        let key = self.key(stage);
+        self.outputs
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(
+                key,
+                CachedOutput {
+                    stage,
+                    output: output.clone(),
+                },
+            );
+    }
+}
+
+/// `cache_keys` fingerprinted with what each stage of a `config` run reads
+///
+/// `files` is the run's L1 input (`IRIndexingOrchestrator::collect_files`),
+/// fingerprinted by content. Taint rule and library summary files are
+/// fingerprinted into L14's key the same way.
+pub fn fingerprint_keys(
+    cache_keys: &CacheKeyManager,
+    config: &E2EPipelineConfig,
+    dag: &PipelineDAG,
+    files: &[PathBuf],
+) -> CacheKeyManager {
+    let repo = &config.repo_info;
+    let inputs = format!(
+        "{}|{}|{:?}|{:?}|{:?}|{}",
+        repo.repo_root.display(),
+        repo.repo_name,
+        repo.language_filter,
+        config.mode,
+        config.base_commit,
+        fingerprint_files(files)
+    );
+
+    let stages = &config.pipeline_config;
+    let mut keys = cache_keys
+        .clone()
+        .with_inputs(inputs)
+        .with_dependencies(dag);
+    if let Some(chunking) = stages.chunking() {
+        keys = keys.with_stage_config(StageId::Ir(IrStageId::L2Chunking), &chunking);
+    }
+    if let Some(lexical) = stages.lexical() {
+        keys = keys.with_stage_config(StageId::Ir(IrStageId::L2_5Lexical), &lexical);
+    }
+    // IrPipeline runs L2.6 with the default embedding model and HNSW parameters
+    let vector = (
+        HashingEmbeddingProvider::default().model_id().to_string(),
+        HnswParams::default(),
+    );
+    keys = keys.with_stage_config(StageId::Ir(IrStageId::L2_6Vector), &vector);
+    if let Some(call_graph) = stages.call_graph() {
+        keys = keys.with_stage_config(StageId::Ir(IrStageId::L3CrossFile), &call_graph);
+    }
+    if let Some(pta) = stages.pta() {
+        keys = keys.with_stage_config(StageId::Ir(IrStageId::L6PointsTo), &pta);
+    }
+    if let Some(clone) = stages.clone() {
+        keys = keys.with_stage_config(StageId::Ir(IrStageId::L10CloneDetection), &clone);
+    }
+    if let Some(taint) = stages.taint() {
+        let to_paths = |paths: &[String]| paths.iter().map(PathBuf::from).collect::<Vec<_>>();
+        let rules = fingerprint_files(&to_paths(&taint.rule_files));
+        let summaries = fingerprint_files(&to_paths(&taint.library_summary_files));
+        keys = keys.with_stage_config(
+            StageId::Ir(IrStageId::L14TaintAnalysis),
+            &(&taint, rules, summaries),
+        );
+    }
+    keys
+}
+
+/// Orchestration DAG nodes for codegraph-ir `stages`
+pub fn ir_stage_nodes(stages: &[IrStageId]) -> Vec<StageNode> {
+    let ir_dag = IrPipelineDAG::build(stages);
+    stages
+        .iter()
+        .map(|&stage| {
+            let dependencies = ir_dag
+                .dependencies(stage)
+                .into_iter()
+                .map(StageId::Ir)
+                .collect();
+            StageNode::new(
+                StageId::Ir(stage),
+                stage.name(),
+                dependencies,
+                false,
+                IR_STAGE_TIMEOUT_MS,
+            )
+        })
+        .collect()
+}
+
+/// One codegraph-ir run planned on the orchestration DAG
+pub struct IrPipeline {
+    config: E2EPipelineConfig,
+    dag: PipelineDAG,
+    cache: IrStageCache,
+}
+
+impl IrPipeline {
+    /// Plan the stages `config` enables, keyed by `fingerprint_keys`
+    pub fn new(config: E2EPipelineConfig, cache: IrStageCache) -> Result<Self> {
+        let orchestrator = IRIndexingOrchestrator::new(config.clone());
+        let stages = orchestrator.get_enabled_stages();
+        let dag = PipelineDAG::new(ir_stage_nodes(&stages))?;
+        let files = orchestrator
+            .collect_files()
+            .map_err(|e| OrchestratorError::StageExecutionFailed(format!("codegraph-ir: {}", e)))?;
+        let cache =
+            cache.with_cache_keys(fingerprint_keys(cache.cache_keys(), &config, &dag, &files));
+        Ok(Self { config, dag, cache })
+    }
+
+    pub fn dag(&self) -> &PipelineDAG {
+        &self.dag
+    }
+
+    pub fn cache(&self) -> &IrStageCache {
+        &self.cache
+    }
+
+    /// Stages the run takes from the cache, in pipeline order (L1 always runs)
+    pub fn cached_stages(&self) -> Vec<IrStageId> {
+        IrStageId::ALL
+            .into_iter()
+            .filter(|&stage| {
+                stage != IrStageId::L1IrBuild
+                    && self.dag.get_stage(StageId::Ir(stage)).is_some()
+                    && self.cache.contains(stage)
+            })
+            .collect()
+    }
+
+    /// Run codegraph-ir in-process, executing only uncached stages
+    ///
+    /// Blocking (the run uses Rayon); call it via `spawn_blocking` from async code.
+    pub fn execute(&self) -> Result<E2EPipelineResult> {
+        info!("IR pipeline plan:\n{}", self.dag.execution_plan());
+        let cached = self.cached_stages();
+        if !cached.is_empty() {
+            info!(
+                "IR pipeline: {} stages skipped (cache hit): {:?}",
+                cached.len(),
+                cached
+            );
+        }
+
+        let result = IRIndexingOrchestrator::new(self.config.clone())
+            .with_stage_cache(Arc::new(self.cache.clone()))
+            .execute()
+            .map_err(|e| OrchestratorError::StageExecutionFailed(format!("codegraph-ir: {}", e)));
+        info!("IR pipeline {}", self.cache.stats());
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codegraph_ir::config::pipeline_config::StageId as ConfigStageId;
+    use codegraph_ir::config::{PipelineConfig, Preset};
+    use codegraph_ir::pipeline::end_to_end_config::IndexingMode;
+
+    #[test]
+    fn test_ir_stage_nodes_keep_ir_dependencies() {
+        let stages = [
+            IrStageId::L1IrBuild,
+            IrStageId::L3CrossFile,
+            IrStageId::L6PointsTo,
+            IrStageId::L14TaintAnalysis,
+        ];
+        let dag = PipelineDAG::new(ir_stage_nodes(&stages)).unwrap();
+
+        let taint = dag
+            .get_stage(StageId::Ir(IrStageId::L14TaintAnalysis))
+            .unwrap();
+        assert_eq!(taint.name, "L14_TaintAnalysis");
+        assert_eq!(taint.dependencies.len(), 2);
+        assert!(taint
+            .dependencies
+            .contains(&StageId::Ir(IrStageId::L3CrossFile)));
+
+        let order = dag.execution_order();
+        assert_eq!(order.len(), 3);
+        assert_eq!(order[0], vec![StageId::Ir(IrStageId::L1IrBuild)]);
+        assert_eq!(order[2], vec![StageId::Ir(IrStageId::L14TaintAnalysis)]);
+    }
+
+    #[test]
+    fn test_cached_stages_are_skipped_per_snapshot() {
+        let repo = std::env::temp_dir().join(format!("ir_pipeline_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&repo).unwrap();
+        std::fs::write(
+            repo.join("app.py"),
+            "def handler():\n    return helper()\n\ndef helper():\n    return 1\n",
+        )
+        .unwrap();
+        let pipeline = PipelineConfig::preset(Preset::Fast)
+            .stages(|s| s.enable(ConfigStageId::Chunking))
+            .build()
+            .unwrap();
+        let config = E2EPipelineConfig::with_config(pipeline)
+            .repo_root(repo.clone())
+            .repo_name("repo1".to_string())
+            .indexing_mode(IndexingMode::Full);
+
+        let cache = IrStageCache::new(CacheKeyManager::new("repo1".into(), "snap1".into()));
+        let first = IrPipeline::new(config.clone(), cache.clone()).unwrap();
+        assert!(first.cached_stages().is_empty());
+        let result = first.execute().unwrap();
+
+        let second = IrPipeline::new(config.clone(), cache.clone()).unwrap();
+        assert!(second.cached_stages().contains(&IrStageId::L2Chunking));
+        assert_eq!(second.execute().unwrap().chunks.len(), result.chunks.len());
+
+        let next_snapshot =
+            cache.with_cache_keys(CacheKeyManager::new("repo1".into(), "snap2".into()));
+        let third = IrPipeline::new(config, next_snapshot).unwrap();
+        assert!(third.cached_stages().is_empty());
+
+        std::fs::remove_dir_all(&repo).ok();
+    }
+
+    #[test]
+    fn test_scanned_file_content_rekeys_stages() {
+        let repo = tempfile::tempdir().unwrap();
+        let rules_dir = tempfile::tempdir().unwrap();
+        let rules = rules_dir.path().join("rules.yaml");
+        std::fs::write(repo.path().join("app.py"), "def handler():\n    return 1\n").unwrap();
+        std::fs::write(&rules, "rules: []\n").unwrap();
+        let pipeline = PipelineConfig::preset(Preset::Balanced)
+            .stages(|s| {
+                s.enable(ConfigStageId::Chunking)
+                    .enable(ConfigStageId::Taint)
+            })
+            .taint(|c| c.rule_file(rules.to_string_lossy()))
+            .build()
+            .unwrap();
+        let config = E2EPipelineConfig::with_config(pipeline)
+            .repo_root(repo.path().to_path_buf())
+            .repo_name("repo1".to_string());
+        assert!(config.repo_info.file_paths.is_none());
+
+        let cache = IrStageCache::new(CacheKeyManager::new("repo1".into(), "snap1".into()));
+        let key = |stage| {
+            IrPipeline::new(config.clone(), cache.clone())
+                .unwrap()
+                .cache()
+                .key(stage)
+        };
+        let chunks = key(IrStageId::L2Chunking);
+        let taint = key(IrStageId::L14TaintAnalysis);
+        assert_eq!(key(IrStageId::L2Chunking), chunks);
+
+        std::fs::write(&rules, "rules: [eval]\n").unwrap();
+        assert_eq!(key(IrStageId::L2Chunking), chunks);
+        assert_ne!(key(IrStageId::L14TaintAnalysis), taint);
+
+        std::fs::write(repo.path().join("app.py"), "def handler():\n    return 2\n").unwrap();
+        assert_ne!(key(IrStageId::L2Chunking), chunks);
+    }
+
+    #[test]
+    fn test_taint_config_rekeys_only_taint() {
+        let config = |max_depth: usize| {
+            let pipeline = PipelineConfig::preset(Preset::Balanced)
+                .stages(|s| {
+                    s.enable(ConfigStageId::Chunking)
+                        .enable(ConfigStageId::Taint)
+                        .enable(ConfigStageId::Pta)
+                })
+                .taint(|c| c.max_depth(max_depth))
+                .build()
+                .unwrap();
+            E2EPipelineConfig::with_config(pipeline).repo_name("repo1".to_string())
+        };
+        let cache = IrStageCache::new(CacheKeyManager::new("repo1".into(), "snap1".into()));
+        let before = IrPipeline::new(config(50), cache.clone()).unwrap();
+        let after = IrPipeline::new(config(60), cache.clone()).unwrap();
+        let key = |pipeline: &IrPipeline, stage| pipeline.cache().key(stage);
+
+        for stage in [
+            IrStageId::L1IrBuild,
+            IrStageId::L2Chunking,
+            IrStageId::L6PointsTo,
+        ] {
+            assert_eq!(key(&before, stage), key(&after, stage));
+        }
+        assert_ne!(
+            key(&before, IrStageId::L14TaintAnalysis),
+            key(&after, IrStageId::L14TaintAnalysis)
+        );
+        assert!(key(&before, IrStageId::L2Chunking).starts_with("chunks:repo1:snap1:"));
+
+        let output = StageOutput::default();
+        before.cache().store(IrStageId::L2Chunking, &output);
+        before.cache().store(IrStageId::L14TaintAnalysis, &output);
+        assert!(after.cache().lookup(IrStageId::L2Chunking).is_some());
+        assert!(after.cache().lookup(IrStageId::L14TaintAnalysis).is_none());
+
+        let stats = cache.stats();
+        assert_eq!(stats.entries(), 2);
+        let taint = stats.stage(IrStageId::L14TaintAnalysis).unwrap();
+        assert_eq!((taint.hits, taint.misses), (0, 1));
+        assert_eq!(stats.stage(IrStageId::L2Chunking).unwrap().hits, 1);
+        assert!(stats
+            .to_string()
+            .starts_with("cache stats: 2 entries, 1 hits"));
+
+        assert!(!after.cache().invalidate(IrStageId::L14TaintAnalysis));
+        assert!(before.cache().invalidate(IrStageId::L14TaintAnalysis));
+        before.cache().store(IrStageId::L14TaintAnalysis, &output);
+        after.cache().store(IrStageId::L14TaintAnalysis, &output);
+        assert_eq!(cache.invalidate_all(IrStageId::L14TaintAnalysis), 2);
+        cache.clear();
+        assert_eq!(cache.stats(), CacheStats::default());
+    }
+}
@@ -1,5 +1,6 @@
 use crate::error::{ErrorCategory, OrchestratorError, Result};
 use chrono::{DateTime, Utc};
+use codegraph_ir::pipeline::dag::StageId as IrStageId;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -10,6 +11,8 @@ pub enum StageId {
     L2_Chunk,
     L3_Lexical,
     L4_Vector,
+    /// Stage of an in-process codegraph-ir run (see `ir_pipeline`)
+    Ir(IrStageId),
 }
 
 impl StageId {
@@ -19,6 +22,7 @@ impl StageId {
             StageId::L2_Chunk => "L2_Chunk",
             StageId::L3_Lexical => "L3_Lexical",
             StageId::L4_Vector => "L4_Vector",
+            StageId::Ir(stage) => stage.name(),
         }
     }
 
@@ -28,7 +32,11 @@ impl StageId {
             "L2_Chunk" => Ok(StageId::L2_Chunk),
             "L3_Lexical" => Ok(StageId::L3_Lexical),
             "L4_Vector" => Ok(StageId::L4_Vector),
-            _ => Err(OrchestratorError::parse(format!("Invalid stage ID: {}", s))),
+            _ => IrStageId::ALL
+                .into_iter()
+                .find(|stage| stage.name() == s)
+                .map(StageId::Ir)
+                .ok_or_else(|| OrchestratorError::parse(format!("Invalid stage ID: {}", s))),
         }
     }
 }
@@ -94,7 +102,7 @@ impl JobState {
 }
 
 /// Job model
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Job {
     pub id: Uuid,
     pub repo_id: String,
@@ -157,14 +165,56 @@ impl Job {
     }
 }
 
+/// Retry policy for transient failures (exponential backoff)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    /// Failures after which no retry is scheduled
+    pub max_retries: u32,
+    pub base_backoff_secs: u64,
+    pub max_backoff_secs: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_backoff_secs: 1,
+            max_backoff_secs: 300,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before retry `retry_count` (0-based), `None` once exhausted
+    pub fn backoff(&self, retry_count: u32) -> Option<chrono::Duration> {
+        if retry_count >= self.max_retries {
+            return None;
+        }
+        let secs = self
+            .base_backoff_secs
+            .saturating_mul(2u64.saturating_pow(retry_count))
+            .min(self.max_backoff_secs);
+        Some(chrono::Duration::seconds(secs as i64))
+    }
+}
+
 /// Job state machine for transitions
 pub struct JobStateMachine {
     job: Job,
+    retry_policy: RetryPolicy,
 }
 
 impl JobStateMachine {
     pub fn new(job: Job) -> Self {
-        Self { job }
+        Self {
+            job,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
     }
 
     pub fn job(&self) -> &Job {
@@ -231,11 +281,11 @@ impl JobStateMachine {
             JobState::Running { started_at, .. } | JobState::Failed { started_at, .. } => {
                 let now = Utc::now();
 
-                // Calculate exponential backoff (2s, 4s, 8s)
-                let next_retry_at = if retry_count < 3 && error_category == ErrorCategory::Transient
-                {
-                    let backoff_secs = 2u64.pow(retry_count);
-                    Some(now + chrono::Duration::seconds(backoff_secs as i64))
+                // Exponential backoff (1s, 2s, 4s by default)
+                let next_retry_at = if error_category == ErrorCategory::Transient {
+                    self.retry_policy
+                        .backoff(retry_count)
+                        .map(|backoff| now + backoff)
                 } else {
                     None
                 };
@@ -288,6 +338,25 @@ impl JobStateMachine {
         }
     }
 
+    /// Transition: RUNNING → QUEUED (worker died; resume from checkpoints)
+    pub fn recover(&mut self) -> Result<()> {
+        match &self.job.state {
+            JobState::Running { .. } => {
+                let now = Utc::now();
+                self.job.state = JobState::Queued {
+                    queued_at: now,
+                    priority: 0,
+                };
+                self.job.updated_at = now;
+                Ok(())
+            }
+            _ => Err(OrchestratorError::InvalidStateTransition {
+                from: self.job.state.state_name().to_string(),
+                to: "queued (recover)".to_string(),
+            }),
+        }
+    }
+
     /// Transition: * → CANCELLED
     pub fn cancel(&mut self, reason: String) -> Result<()> {
         if self.job.state.is_terminal() {
@@ -333,6 +402,7 @@ mod tests {
             StageId::L2_Chunk,
             StageId::L3_Lexical,
             StageId::L4_Vector,
+            StageId::Ir(IrStageId::L14TaintAnalysis),
         ] {
             let s = stage.as_str();
             let parsed = StageId::from_str(s).unwrap();
@@ -484,4 +554,29 @@ mod tests {
             _ => panic!("Expected Running state"),
         }
     }
+
+    #[test]
+    fn test_retry_policy_backoff() {
+        let policy = RetryPolicy {
+            max_retries: 4,
+            base_backoff_secs: 10,
+            max_backoff_secs: 60,
+        };
+        let secs: Vec<_> = (0..5)
+            .map(|n| policy.backoff(n).map(|d| d.num_seconds()))
+            .collect();
+        assert_eq!(secs, vec![Some(10), Some(20), Some(40), Some(60), None]);
+
+        let job = Job::new_queued("repo-1".to_string(), "snap-1".to_string(), 0);
+        let mut sm = JobStateMachine::new(job).with_retry_policy(policy);
+        sm.start("worker-1".to_string(), StageId::L1_IR).unwrap();
+        sm.fail(
+            "timeout".to_string(),
+            ErrorCategory::Transient,
+            StageId::L1_IR,
+            4,
+        )
+        .unwrap();
+        assert!(sm.retry().is_err()); // Retries exhausted
+    }
 }
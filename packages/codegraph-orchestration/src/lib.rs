@@ -4,10 +4,12 @@
  * High-performance, distributed pipeline orchestration for code analysis.
  *
  * Architecture:
- * - Job State Machine (PostgreSQL)
- * - Distributed Locking (Redis)
+ * - Job State Machine (PostgreSQL store, `postgres` feature)
+ * - Distributed Locking + Work Queue (Redis, `distributed` feature)
  * - Checkpoint/Resume System
  * - Pipeline Stages (pluggable)
+ * - Snapshot Events (Kafka / NATS, at-least-once)
+ * - In-process codegraph-ir runs on the DAG (ir_pipeline)
  * - Observability (metrics, logging)
  *
  * Performance Target: 5-10x faster than Python
@@ -17,20 +19,32 @@
 pub mod checkpoint;
 pub mod dag;
 pub mod dependency_graph;
+#[cfg(feature = "distributed")]
+pub mod distributed;
 pub mod error;
+pub mod events;
 pub mod incremental;
+pub mod ir_pipeline;
 pub mod job;
+#[cfg(feature = "postgres")]
+pub mod job_store;
 pub mod orchestrator;
 pub mod pipeline;
 pub mod stages;
 
 // Re-exports
 pub use checkpoint::{Checkpoint, CheckpointManager};
-pub use dag::{CacheKeyManager, PipelineDAG, StageNode};
+pub use dag::{fingerprint_files, CacheKeyManager, PipelineDAG, StageNode};
 pub use dependency_graph::{compute_affected_files, FileId, ImportKey, ReverseDependencyIndex};
 pub use error::{ErrorCategory, OrchestratorError, Result};
+pub use events::{EventPayload, EventPublisher, EventSink, IndexEvent, MemoryEventSink};
 pub use incremental::{IncrementalOrchestrator, IncrementalResult};
-pub use job::{Job, JobState, JobStateMachine, StageId};
+pub use ir_pipeline::{
+    fingerprint_keys, ir_stage_nodes, CacheStats, IrPipeline, IrStageCache, StageCacheStats,
+};
+pub use job::{Job, JobState, JobStateMachine, RetryPolicy, StageId};
+#[cfg(feature = "postgres")]
+pub use job_store::{JobFilter, JobTransition, PgJobStore};
 pub use orchestrator::{PipelineOrchestrator, PipelineResult};
 pub use pipeline::{
     StageConfig, StageContext, StageHandler, StageInput, StageMetrics, StageOutput,
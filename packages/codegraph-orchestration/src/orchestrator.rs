@@ -1,9 +1,13 @@
 use crate::checkpoint::{Checkpoint, CheckpointManager};
 use crate::dag::{CacheKeyManager, PipelineDAG, StageNode};
 use crate::error::{ErrorCategory, OrchestratorError, Result};
+use crate::events::{snapshot_events, EventPublisher, EventSink};
 use crate::job::{Job, JobState, JobStateMachine, StageId};
 use crate::pipeline::{StageConfig, StageContext, StageHandler, StageInput, StageOutput};
+use crate::stages::IRResult;
+use futures::FutureExt;
 use std::collections::{HashMap, HashSet};
+use std::panic::AssertUnwindSafe;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Instant;
@@ -36,6 +40,8 @@ pub struct PipelineOrchestrator {
     checkpoint_mgr: Arc<CheckpointManager>,
     stage_handlers: HashMap<StageId, Arc<dyn StageHandler>>,
     worker_id: String,
+    events: Option<EventPublisher>,
+    thread_budget: usize,
 }
 
 impl PipelineOrchestrator {
@@ -48,6 +54,8 @@ impl PipelineOrchestrator {
             checkpoint_mgr,
             stage_handlers: HashMap::new(),
             worker_id: format!("worker-{}", Uuid::new_v4()),
+            events: None,
+            thread_budget: num_cpus::get(),
         })
     }
 
@@ -58,9 +66,38 @@ impl PipelineOrchestrator {
             checkpoint_mgr,
             stage_handlers: HashMap::new(),
             worker_id: format!("worker-{}", Uuid::new_v4()),
+            events: None,
+            thread_budget: num_cpus::get(),
         }
     }
 
+    /// Threads shared by stages without an explicit `StageNode::threads`
+    /// (default: all cores)
+    pub fn with_thread_budget(mut self, threads: usize) -> Self {
+        self.thread_budget = threads.max(1);
+        self
+    }
+
+    /// Explicit budget, else an even share of `thread_budget` across the
+    /// widest set of stages that can run at once
+    fn stage_threads(&self, stage: &StageNode) -> usize {
+        stage
+            .threads
+            .unwrap_or(self.thread_budget / self.dag.max_parallelism())
+            .max(1)
+    }
+
+    /// Publish snapshot events to `sink` (default retry policy)
+    pub fn with_event_sink(self, sink: Arc<dyn EventSink>) -> Self {
+        self.with_event_publisher(EventPublisher::new(sink))
+    }
+
+    /// Publish snapshot events through `publisher`
+    pub fn with_event_publisher(mut self, publisher: EventPublisher) -> Self {
+        self.events = Some(publisher);
+        self
+    }
+
     /// Register a stage handler
     pub fn register_handler(&mut self, handler: Arc<dyn StageHandler>) {
         self.stage_handlers.insert(handler.stage_id(), handler);
@@ -88,6 +125,7 @@ impl PipelineOrchestrator {
         let mut state_machine = JobStateMachine::new(job);
         state_machine.start(self.worker_id.clone(), StageId::L1_IR)?;
         job = state_machine.into_job();
+        self.checkpoint_mgr.save_job(&job).await?;
 
         // Get completed stages (for resume)
         let completed = self.checkpoint_mgr.completed_stages(job_id).await?;
@@ -113,6 +151,18 @@ impl PipelineOrchestrator {
 
         let elapsed = start_time.elapsed();
 
+        // Publish before completing: a failed delivery fails the job with its
+        // checkpoints intact, so the retry re-sends (at-least-once)
+        let result = match result {
+            Ok(mut pipeline_result) => {
+                pipeline_result.duration_ms = elapsed.as_millis() as u64;
+                self.publish_snapshot_events(&job, &pipeline_result)
+                    .await
+                    .map(|()| pipeline_result)
+            }
+            Err(e) => Err(e),
+        };
+
         // Update final state
         let final_job = match result {
             Ok(mut pipeline_result) => {
@@ -157,6 +207,7 @@ impl PipelineOrchestrator {
                     retry_count,
                 )?;
                 let failed_job = sm.into_job();
+                self.checkpoint_mgr.save_job(&failed_job).await?;
 
                 let empty_result = PipelineResult {
                     duration_ms: elapsed.as_millis() as u64,
@@ -170,6 +221,34 @@ impl PipelineOrchestrator {
         Ok(final_job)
     }
 
+    /// Jobs a previous process left unfinished, ready for `execute_job`
+    ///
+    /// Interrupted runs are re-queued and failed runs with a scheduled retry
+    /// are retried; both resume after their last checkpointed stage.
+    pub async fn recover_jobs(&self) -> Result<Vec<Job>> {
+        let mut recovered = Vec::new();
+        for job in self.checkpoint_mgr.unfinished_jobs().await? {
+            let job_id = job.id;
+            let mut sm = JobStateMachine::new(job);
+            let transition = match &sm.job().state {
+                JobState::Queued { .. } => Ok(()),
+                JobState::Running { .. } => sm.recover(),
+                JobState::Failed { .. } => sm.retry(),
+                _ => continue,
+            };
+            match transition {
+                Ok(()) => {
+                    let job = sm.into_job();
+                    self.checkpoint_mgr.save_job(&job).await?;
+                    recovered.push(job);
+                }
+                Err(e) => warn!("Job {} not recoverable: {}", job_id, e),
+            }
+        }
+        info!("Recovered {} unfinished jobs", recovered.len());
+        Ok(recovered)
+    }
+
     /// Execute DAG with parallel phases (like ParallelIndexingOrchestrator)
     async fn run_dag(
         &self,
@@ -204,47 +283,44 @@ impl PipelineOrchestrator {
 
         let mut overall_result = PipelineResult::default();
 
-        // Execute each phase in order
-        for (phase_idx, parallel_group) in self.dag.execution_order().iter().enumerate() {
-            info!(
-                "Job {}: Phase {} - {} stages{}",
-                job_id,
-                phase_idx + 1,
-                parallel_group.len(),
-                if parallel_group.len() > 1 {
-                    " (parallel)"
-                } else {
-                    ""
-                }
-            );
-
-            // Skip completed stages
-            let to_execute: Vec<_> = parallel_group
-                .iter()
-                .filter(|id| !completed.contains(id))
-                .copied()
-                .collect();
-
-            if to_execute.is_empty() {
-                info!(
-                    "Job {}: Phase {} already completed, skipping",
-                    job_id,
-                    phase_idx + 1
-                );
-                continue;
+        // Stages finished before a restart still count towards the result
+        for (stage_id, metrics) in self.checkpoint_mgr.stage_metrics(job_id).await? {
+            if completed.contains(&stage_id) {
+                overall_result.merge_metrics(&metrics);
             }
+        }
+
+        // Dependency-driven scheduling: a stage starts as soon as its own
+        // dependencies are done, not when its whole topological phase is
+        let mut done: HashSet<StageId> = completed.clone();
+        let mut pending: Vec<StageId> = self
+            .dag
+            .execution_order()
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|id| !done.contains(id))
+            .collect();
+        let mut running = tokio::task::JoinSet::new();
+
+        loop {
+            let (ready, blocked): (Vec<_>, Vec<_>) = pending.into_iter().partition(|id| {
+                self.dag.get_stage(*id).map_or(false, |s| {
+                    s.dependencies.iter().all(|dep| done.contains(dep))
+                })
+            });
+            pending = blocked;
 
-            // Execute stages in parallel using tokio::spawn
-            let mut tasks = Vec::new();
-            for stage_id in &to_execute {
-                let stage = self
+            for stage_id in ready {
+                let mut stage_node = self
                     .dag
-                    .get_stage(*stage_id)
-                    .ok_or_else(|| OrchestratorError::StageNotFound(format!("{:?}", stage_id)))?;
+                    .get_stage(stage_id)
+                    .ok_or_else(|| OrchestratorError::StageNotFound(format!("{:?}", stage_id)))?
+                    .clone();
 
                 let handler = self
                     .stage_handlers
-                    .get(stage_id)
+                    .get(&stage_id)
                     .ok_or_else(|| {
                         OrchestratorError::Config(format!(
                             "No handler registered for stage {:?}",
@@ -253,69 +329,130 @@ impl PipelineOrchestrator {
                     })?
                     .clone();
 
+                let threads = self.stage_threads(&stage_node);
+                stage_node.threads = Some(threads);
+                info!(
+                    "Job {}: Starting stage {:?} ({} threads, {} stages running)",
+                    job_id,
+                    stage_id,
+                    threads,
+                    running.len() + 1
+                );
+
                 let stage_ctx = ctx.clone();
-                let stage_node = stage.clone();
                 let repo_path_clone = repo_path.clone();
-
-                tasks.push(tokio::spawn(async move {
-                    Self::execute_stage(handler, stage_node, stage_ctx, repo_path_clone).await
-                }));
+                running.spawn(async move {
+                    let result = AssertUnwindSafe(Self::execute_stage(
+                        handler,
+                        stage_node,
+                        stage_ctx,
+                        repo_path_clone,
+                    ))
+                    .catch_unwind()
+                    .await;
+                    (stage_id, result)
+                });
             }
 
-            // Wait for all parallel tasks (like asyncio.gather)
-            let results = futures::future::join_all(tasks).await;
-
-            // Check for failures (early exit like semantica-task-engine)
-            for (i, task_result) in results.into_iter().enumerate() {
-                let stage_id = to_execute[i];
-
-                match task_result {
-                    Ok(Ok(output)) => {
-                        // Save checkpoint
-                        let cache_key = ctx.cache_keys.key_for_stage(stage_id);
-                        let checkpoint =
-                            Checkpoint::new(job_id, stage_id, cache_key, output.cache_data.clone());
-
-                        self.checkpoint_mgr.save_checkpoint(checkpoint).await?;
-
-                        // Merge metrics
-                        overall_result.merge_metrics(&output.metrics);
-
-                        info!(
-                            "Job {}: Stage {:?} completed - {} files, {} nodes in {}ms",
-                            job_id,
-                            stage_id,
-                            output.metrics.files_processed,
-                            output.metrics.nodes_created,
-                            output.metrics.duration_ms
-                        );
-                    }
-                    Ok(Err(e)) => {
-                        error!("Job {}: Stage {:?} failed: {}", job_id, stage_id, e);
-                        return Err(OrchestratorError::StageExecutionFailed(format!(
-                            "Stage {:?}: {}",
-                            stage_id, e
-                        ))
-                        .into());
-                    }
-                    Err(join_err) => {
-                        error!(
-                            "Job {}: Stage {:?} panicked: {}",
-                            job_id, stage_id, join_err
-                        );
-                        return Err(OrchestratorError::StageExecutionFailed(format!(
-                            "Stage {:?} panicked: {}",
-                            stage_id, join_err
-                        ))
-                        .into());
-                    }
+            // Wait for the next stage to finish (early exit on failure;
+            // dropping the JoinSet aborts the stages still running)
+            let (stage_id, result) = match running.join_next().await {
+                Some(Ok(finished)) => finished,
+                Some(Err(join_err)) => {
+                    return Err(OrchestratorError::StageExecutionFailed(format!(
+                        "Stage task failed: {}",
+                        join_err
+                    )))
+                }
+                None => break,
+            };
+
+            match result {
+                Ok(Ok(output)) => {
+                    // Save checkpoint
+                    let cache_key = ctx.cache_keys.key_for_stage(stage_id);
+                    let checkpoint =
+                        Checkpoint::new(job_id, stage_id, cache_key, output.cache_data.clone())
+                            .with_metrics(output.metrics.clone());
+
+                    self.checkpoint_mgr.save_checkpoint(checkpoint).await?;
+
+                    // Merge metrics
+                    overall_result.merge_metrics(&output.metrics);
+
+                    info!(
+                        "Job {}: Stage {:?} completed - {} files, {} nodes in {}ms",
+                        job_id,
+                        stage_id,
+                        output.metrics.files_processed,
+                        output.metrics.nodes_created,
+                        output.metrics.duration_ms
+                    );
+                    done.insert(stage_id);
+                }
+                Ok(Err(e)) => {
+                    error!("Job {}: Stage {:?} failed: {}", job_id, stage_id, e);
+                    return Err(OrchestratorError::StageExecutionFailed(format!(
+                        "Stage {:?}: {}",
+                        stage_id, e
+                    ))
+                    .into());
+                }
+                Err(_) => {
+                    error!("Job {}: Stage {:?} panicked", job_id, stage_id);
+                    return Err(OrchestratorError::StageExecutionFailed(format!(
+                        "Stage {:?} panicked",
+                        stage_id
+                    ))
+                    .into());
                 }
             }
         }
 
+        if !pending.is_empty() {
+            return Err(OrchestratorError::MissingDependency(format!(
+                "Stages never became ready: {:?}",
+                pending
+            )));
+        }
+
         Ok(overall_result)
     }
 
+    /// Emit file/vulnerability/snapshot events (no-op without a sink)
+    async fn publish_snapshot_events(&self, job: &Job, result: &PipelineResult) -> Result<()> {
+        let publisher = match &self.events {
+            Some(publisher) => publisher,
+            None => return Ok(()),
+        };
+
+        let cache_key = CacheKeyManager::new(job.repo_id.clone(), job.snapshot_id.clone())
+            .key_for_stage(StageId::L1_IR);
+        let ir_results = match self.checkpoint_mgr.load_checkpoint(&cache_key).await? {
+            Some(data) if !data.is_empty() => bincode::deserialize::<Vec<IRResult>>(&data)
+                .unwrap_or_else(|e| {
+                    warn!(
+                        "Job {}: IR output not decodable, no file events: {}",
+                        job.id, e
+                    );
+                    Vec::new()
+                }),
+            _ => Vec::new(),
+        };
+
+        let events = snapshot_events(
+            job.id,
+            &job.repo_id,
+            &job.snapshot_id,
+            job.previous_snapshot_id.clone(),
+            &ir_results,
+            result,
+        );
+        publisher.publish_all(&events).await?;
+        info!("Job {}: published {} events", job.id, events.len());
+        Ok(())
+    }
+
     /// Execute a single stage
     async fn execute_stage(
         handler: Arc<dyn StageHandler>,
@@ -364,17 +501,48 @@ impl PipelineOrchestrator {
         info!("Found {} files to process", files.len());
 
         // Build input
+        let mut config = StageConfig::default();
+        if let Some(threads) = stage_node.threads {
+            config.parallel_workers = threads;
+        }
         let input = StageInput {
             files,
             cache,
-            config: StageConfig::default(),
+            config,
             incremental: ctx.changed_files.is_some(),
             changed_files: ctx.changed_files.clone(),
         };
 
         // Execute with timeout
         let timeout = tokio::time::Duration::from_millis(stage_node.timeout_ms);
-        let result = tokio::time::timeout(timeout, handler.execute(input, &mut ctx)).await;
+        let result = match stage_node.threads {
+            Some(threads) => {
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .thread_name(move |i| format!("{}-{}", stage_id, i))
+                    .build()
+                    .map_err(OrchestratorError::config)?;
+                let runtime = tokio::runtime::Handle::current();
+
+                // Drive the handler from a thread of the stage's own pool, so
+                // its rayon work (`par_iter`) stays within the budget
+                tokio::task::spawn_blocking(move || {
+                    pool.install(|| {
+                        runtime.block_on(async {
+                            tokio::time::timeout(timeout, handler.execute(input, &mut ctx)).await
+                        })
+                    })
+                })
+                .await
+                .map_err(|e| {
+                    OrchestratorError::StageExecutionFailed(format!(
+                        "Stage {:?} panicked: {}",
+                        stage_id, e
+                    ))
+                })?
+            }
+            None => tokio::time::timeout(timeout, handler.execute(input, &mut ctx)).await,
+        };
 
         match result {
             Ok(Ok(output)) => Ok(output),
@@ -579,4 +747,87 @@ mod tests {
         assert_eq!(result.duration_ms, 1000);
         assert_eq!(result.errors.len(), 1);
     }
+
+    // Records stage start/end order
+    struct TimedHandler {
+        id: StageId,
+        delay_ms: u64,
+        log: Arc<parking_lot::Mutex<Vec<(StageId, &'static str)>>>,
+    }
+
+    #[async_trait]
+    impl StageHandler for TimedHandler {
+        fn stage_id(&self) -> StageId {
+            self.id
+        }
+
+        async fn execute(
+            &self,
+            _input: StageInput,
+            _ctx: &mut StageContext,
+        ) -> Result<StageOutput> {
+            self.log.lock().push((self.id, "start"));
+            tokio::time::sleep(tokio::time::Duration::from_millis(self.delay_ms)).await;
+            self.log.lock().push((self.id, "end"));
+            Ok(StageOutput {
+                cache_data: vec![1],
+                metrics: StageMetrics {
+                    files_processed: 1,
+                    ..Default::default()
+                },
+            })
+        }
+
+        fn output_cache_key(&self, ctx: &StageContext) -> String {
+            ctx.cache_keys.key_for_stage(self.id)
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_dependents_start_without_waiting_for_phase() {
+        // L1 (fast) → L2, with a slow independent L3
+        let dag = PipelineDAG::new(vec![
+            StageNode::new(StageId::L1_IR, "IR", vec![], false, 5000),
+            StageNode::new(StageId::L3_Lexical, "Lexical", vec![], false, 5000).with_threads(1),
+            StageNode::new(
+                StageId::L2_Chunk,
+                "Chunk",
+                vec![StageId::L1_IR],
+                false,
+                5000,
+            ),
+        ])
+        .unwrap();
+        let log = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let mut orch =
+            PipelineOrchestrator::with_dag(dag, Arc::new(CheckpointManager::new_in_memory()))
+                .with_thread_budget(4);
+        assert_eq!(
+            orch.stage_threads(orch.dag.get_stage(StageId::L1_IR).unwrap()),
+            2
+        );
+        for (id, delay_ms) in [
+            (StageId::L1_IR, 10),
+            (StageId::L3_Lexical, 300),
+            (StageId::L2_Chunk, 10),
+        ] {
+            orch.register_handler(Arc::new(TimedHandler {
+                id,
+                delay_ms,
+                log: log.clone(),
+            }));
+        }
+
+        let repo_path = std::env::temp_dir().join(format!("dag_repo_{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&repo_path).unwrap();
+        let job = Job::new_queued("repo".to_string(), "snap".to_string(), 0);
+        let (job, result) = orch.execute_job(job, repo_path.clone()).await.unwrap();
+        std::fs::remove_dir_all(&repo_path).ok();
+
+        assert!(matches!(job.state, JobState::Completed { .. }));
+        assert_eq!(result.files_processed, 3);
+        let log = log.lock();
+        let position = |entry| log.iter().position(|e| *e == entry).unwrap();
+        assert!(position((StageId::L2_Chunk, "end")) < position((StageId::L3_Lexical, "end")));
+    }
 }
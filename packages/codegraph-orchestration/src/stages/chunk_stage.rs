@@ -6,9 +6,8 @@ use async_trait::async_trait;
 use codegraph_ir::features::chunking::{Chunk, ChunkBuilder, ChunkIdGenerator};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
 use std::time::Instant;
-use tracing::{info, warn};
+use tracing::info;
 
 /// Serializable chunk result
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -81,7 +80,7 @@ impl StageHandler for ChunkStage {
 
         // Deserialize IR results from L1
         let ir_results: Vec<IRResult> = bincode::deserialize(&ir_data).map_err(|e| {
-            crate::error::OrchestratorError::DeserializationError(format!(
+            crate::error::OrchestratorError::Serialization(format!(
                 "Failed to deserialize IR data: {}",
                 e
             ))
@@ -94,7 +93,7 @@ impl StageHandler for ChunkStage {
             .par_iter()
             .map(|ir_result| {
                 // Create ChunkBuilder for this file
-                let id_gen = ChunkIdGenerator::new(&self.repo_id);
+                let id_gen = ChunkIdGenerator::new();
                 let mut builder = ChunkBuilder::new(id_gen);
 
                 // Build structural chunks (repo → project → module → file)
@@ -102,7 +101,7 @@ impl StageHandler for ChunkStage {
                     &self.repo_id,
                     &ir_result.file_path,
                     "python",
-                    Some(&ctx.snapshot_id),
+                    Some(ctx.snapshot_id.as_str()),
                 );
 
                 ChunkResult {
@@ -167,7 +166,7 @@ mod tests {
     use crate::checkpoint::CheckpointManager;
     use crate::dag::CacheKeyManager;
     use crate::pipeline::StageConfig;
-    use std::path::PathBuf;
+    use std::sync::Arc;
     use uuid::Uuid;
 
     #[tokio::test]
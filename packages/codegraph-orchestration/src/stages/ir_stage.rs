@@ -3,13 +3,13 @@ use crate::error::Result;
 use crate::job::StageId;
 use crate::pipeline::{StageContext, StageHandler, StageInput, StageMetrics, StageOutput};
 use async_trait::async_trait;
-use crate::shared::models::EdgeKind;
-use codegraph_ir::pipeline::processor::{process_python_file, ProcessResult};
+use codegraph_ir::pipeline::processor::process_python_file;
+use codegraph_ir::shared::models::EdgeKind;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::path::PathBuf;
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 use std::time::Instant;
 use tracing::{info, warn};
 
@@ -21,6 +21,16 @@ pub struct ImportInfo {
     pub import_type: String, // "wildcard", "specific", "module"
 }
 
+/// Serializable security finding from codegraph-ir security analysis
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VulnerabilityInfo {
+    pub vuln_type: String, // "SQLInjection", "XSS", ...
+    pub cwe_id: Option<u32>,
+    pub severity: u8, // 1-10
+    pub location: String,
+    pub message: String,
+}
+
 /// Serializable IR result from codegraph-ir ProcessResult
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IRResult {
@@ -36,6 +46,7 @@ pub struct IRResult {
     pub errors: Vec<String>,
     // NEW: Store import edges for incremental dependency tracking
     pub imports: Vec<ImportInfo>,
+    pub vulnerabilities: Vec<VulnerabilityInfo>,
 }
 
 /// IR Generation Stage (L1) - Real codegraph-ir integration
@@ -195,6 +206,7 @@ impl StageHandler for IRStage {
                             ssa_count: 0,
                             errors: vec![format!("Failed to read file: {}", e)],
                             imports: Vec::new(), // Empty imports on error
+                            vulnerabilities: Vec::new(),
                         };
                     }
                 };
@@ -251,6 +263,17 @@ impl StageHandler for IRStage {
                     ssa_count: proc_result.ssa_graphs.len(),
                     errors: proc_result.errors,
                     imports, // NEW: Store imports
+                    vulnerabilities: proc_result
+                        .security_vulnerabilities
+                        .iter()
+                        .map(|v| VulnerabilityInfo {
+                            vuln_type: format!("{:?}", v.vuln_type),
+                            cwe_id: v.cwe_id,
+                            severity: v.severity,
+                            location: v.location.clone(),
+                            message: v.message.clone(),
+                        })
+                        .collect(),
                 }
             })
             .collect();
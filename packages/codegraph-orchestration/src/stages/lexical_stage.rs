@@ -3,7 +3,6 @@ use crate::job::StageId;
 use crate::pipeline::{StageContext, StageHandler, StageInput, StageMetrics, StageOutput};
 use async_trait::async_trait;
 use rayon::prelude::*;
-use std::sync::Arc;
 use std::time::Instant;
 use tracing::{info, warn};
 
@@ -122,7 +121,7 @@ mod tests {
     use crate::checkpoint::CheckpointManager;
     use crate::dag::CacheKeyManager;
     use crate::pipeline::StageConfig;
-    use std::path::PathBuf;
+    use std::sync::Arc;
     use uuid::Uuid;
 
     #[tokio::test]
@@ -6,6 +6,6 @@ pub mod vector_stage;
 
 // Re-exports
 pub use chunk_stage::{ChunkResult, ChunkStage};
-pub use ir_stage::{IRResult, IRStage};
+pub use ir_stage::{IRResult, IRStage, ImportInfo, VulnerabilityInfo};
 pub use lexical_stage::LexicalStage;
 pub use vector_stage::{VectorResult, VectorStage};
@@ -8,7 +8,6 @@ use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::path::PathBuf;
-use std::sync::Arc;
 use std::time::Instant;
 use tracing::{info, warn};
 
@@ -24,6 +23,7 @@ pub struct VectorResult {
 /// Vector Indexing Stage (L4) - Qdrant embedded for semantic search
 /// Depends on L2_Chunk (needs chunks for embedding)
 pub struct VectorStage {
+    #[allow(dead_code)] // For the embedding backend (TODO below)
     repo_id: String,
 }
 
@@ -68,7 +68,7 @@ impl StageHandler for VectorStage {
 
         // Deserialize chunk results
         let chunk_results: Vec<ChunkResult> = bincode::deserialize(&chunk_data).map_err(|e| {
-            crate::error::OrchestratorError::DeserializationError(format!(
+            crate::error::OrchestratorError::Serialization(format!(
                 "Failed to deserialize chunk data: {}",
                 e
             ))
@@ -175,6 +175,7 @@ impl StageHandler for VectorStage {
             .collect();
 
         // 4. Merge with previous vectors (if incremental)
+        let new_vector_count = new_vectors.len();
         let final_vectors: Vec<VectorResult> = if input.incremental && previous_vectors.is_some() {
             let prev_vectors = previous_vectors.unwrap();
             let affected_paths: HashSet<String> = chunks_to_embed
@@ -219,7 +220,7 @@ impl StageHandler for VectorStage {
         if input.incremental {
             info!(
                 "VectorStage: INCREMENTAL - Embedded {} chunks from {} affected files, merged {} total chunks in {}ms ({} errors)",
-                new_vectors.len(),
+                new_vector_count,
                 chunks_to_embed.len(),
                 final_vectors.len(),
                 duration_ms,
@@ -265,7 +266,7 @@ mod tests {
     use crate::checkpoint::CheckpointManager;
     use crate::dag::CacheKeyManager;
     use crate::pipeline::StageConfig;
-    use std::path::PathBuf;
+    use std::sync::Arc;
     use uuid::Uuid;
 
     #[tokio::test]
@@ -139,7 +139,10 @@ class UtilityClass:
     // NOTE: Due to BFS, changing module_a affects module_b and module_c
     // So we might process all 3 files in the dependency chain
     // But the key is: we MERGED results from snapshot-1 for unchanged files
-    println!("  Speedup: {:.1}x", result1.duration_ms as f64 / result2.duration_ms as f64.max(1.0));
+    println!(
+        "  Speedup: {:.1}x",
+        result1.duration_ms as f64 / (result2.duration_ms as f64).max(1.0)
+    );
 
     // Phase 3: Incremental update - modify isolated file (snapshot-3)
     println!("\n=== PHASE 3: Incremental Update - Isolated Change (snapshot-3) ===");
@@ -177,7 +180,10 @@ def isolated_function():
     println!("  Duration: {}ms", result3.duration_ms);
 
     // Isolated file should process very quickly
-    println!("  Speedup vs full: {:.1}x", result1.duration_ms as f64 / result3.duration_ms as f64.max(1.0));
+    println!(
+        "  Speedup vs full: {:.1}x",
+        result1.duration_ms as f64 / (result3.duration_ms as f64).max(1.0)
+    );
 
     // Assertions
     assert!(result2.duration_ms > 0);
@@ -257,7 +263,7 @@ def function_0():
     println!("Incremental update: {}ms", result_incr.duration_ms);
     println!(
         "Speedup: {:.1}x",
-        result_full.duration_ms as f64 / result_incr.duration_ms as f64.max(1.0)
+        result_full.duration_ms as f64 / (result_incr.duration_ms as f64).max(1.0)
     );
 
     // Incremental should be faster (or at least not slower)
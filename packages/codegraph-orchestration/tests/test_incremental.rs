@@ -6,7 +6,7 @@
 //! - Transitive dependency propagation
 //! - Performance measurements
 
-use codegraph_orchestration::{CheckpointManager, IncrementalOrchestrator, IncrementalResult};
+use codegraph_orchestration::{CheckpointManager, IncrementalOrchestrator};
 use std::sync::Arc;
 use uuid::Uuid;
 
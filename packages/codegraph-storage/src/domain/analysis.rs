@@ -0,0 +1,329 @@
+//! Analysis results stored alongside snapshots
+//!
+//! Taint, heap, clone-detection and code metrics results are keyed by
+//! `snapshot_id`, so a commit is analyzed once and later queries (PR
+//! comparison, dashboards) read the stored results instead of re-running the
+//! analysis.
+//!
+//! Each result keeps the analyzer's full report (opaque JSON) plus a flat list
+//! of findings. Findings carry a fingerprint that is stable across commits:
+//! comparing two snapshots is a set difference over fingerprints.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use codegraph_storage::domain::{AnalysisFinding, AnalysisKind, AnalysisResult, AnalysisResultStore};
+//!
+//! async fn example(store: impl AnalysisResultStore) -> Result<()> {
+//!     let result = AnalysisResult::new("abc123", AnalysisKind::Taint, report_json)
+//!         .with_findings(vec![AnalysisFinding::new("3f2a9c", payload).with_file("api.py")]);
+//!     store.save_results(&result).await?;
+//!
+//!     let diff = store.compare_results("abc123", "def456", AnalysisKind::Taint).await?;
+//!     println!("{} new findings", diff.added.len());
+//!     Ok(())
+//! }
+//! ```
+
+use std::collections::HashSet;
+use std::fmt;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+
+/// Analysis that produced a result
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnalysisKind {
+    /// Taint flows (source → sink)
+    Taint,
+    /// Heap / memory-safety issues
+    Heap,
+    /// Code clone pairs
+    CloneDetection,
+    /// Per-file code metrics (one finding per file, for trends)
+    Metrics,
+}
+
+impl AnalysisKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnalysisKind::Taint => "taint",
+            AnalysisKind::Heap => "heap",
+            AnalysisKind::CloneDetection => "clone_detection",
+            AnalysisKind::Metrics => "metrics",
+        }
+    }
+
+    /// Inverse of `as_str`
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "taint" => Some(AnalysisKind::Taint),
+            "heap" => Some(AnalysisKind::Heap),
+            "clone_detection" => Some(AnalysisKind::CloneDetection),
+            "metrics" => Some(AnalysisKind::Metrics),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for AnalysisKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Single finding of an analysis
+///
+/// # Examples
+///
+/// ```rust
+/// use codegraph_storage::domain::AnalysisFinding;
+///
+/// let finding = AnalysisFinding::new("3f2a9c", serde_json::json!({"sink": "execute"}))
+///     .with_file("src/api.py")
+///     .with_line(42)
+///     .with_severity("high");
+/// assert_eq!(finding.file_path.as_deref(), Some("src/api.py"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnalysisFinding {
+    /// Identity across snapshots (must not depend on line numbers)
+    pub fingerprint: String,
+    /// File the finding is reported in, if known
+    pub file_path: Option<String>,
+    /// Line number (1-indexed), if known
+    pub line: Option<usize>,
+    /// Analyzer-specific severity
+    pub severity: Option<String>,
+    /// Analyzer-specific finding data
+    #[serde(default)]
+    pub payload: serde_json::Value,
+}
+
+impl AnalysisFinding {
+    /// Create a new finding
+    pub fn new(fingerprint: impl Into<String>, payload: serde_json::Value) -> Self {
+        Self {
+            fingerprint: fingerprint.into(),
+            file_path: None,
+            line: None,
+            severity: None,
+            payload,
+        }
+    }
+
+    pub fn with_file(mut self, file_path: impl Into<String>) -> Self {
+        self.file_path = Some(file_path.into());
+        self
+    }
+
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn with_severity(mut self, severity: impl Into<String>) -> Self {
+        self.severity = Some(severity.into());
+        self
+    }
+}
+
+/// Results of one analysis for one snapshot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnalysisResult {
+    /// Snapshot ID (commit hash)
+    pub snapshot_id: String,
+    /// Analysis that produced the result
+    pub kind: AnalysisKind,
+    /// Analysis timestamp
+    pub created_at: DateTime<Utc>,
+    /// Full analyzer report (round-trips the analyzer's own result type)
+    #[serde(default)]
+    pub report: serde_json::Value,
+    /// Findings, indexed for per-file queries and snapshot comparison
+    #[serde(default)]
+    pub findings: Vec<AnalysisFinding>,
+}
+
+impl AnalysisResult {
+    /// Create a result without findings
+    pub fn new(
+        snapshot_id: impl Into<String>,
+        kind: AnalysisKind,
+        report: serde_json::Value,
+    ) -> Self {
+        Self {
+            snapshot_id: snapshot_id.into(),
+            kind,
+            created_at: Utc::now(),
+            report,
+            findings: Vec::new(),
+        }
+    }
+
+    pub fn with_findings(mut self, findings: Vec<AnalysisFinding>) -> Self {
+        self.findings = findings;
+        self
+    }
+}
+
+/// Difference between the findings of two snapshots
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnalysisDiff {
+    /// Findings only in the head snapshot
+    pub added: Vec<AnalysisFinding>,
+    /// Findings only in the base snapshot
+    pub removed: Vec<AnalysisFinding>,
+    /// Number of fingerprints present in both
+    pub unchanged: usize,
+}
+
+impl AnalysisDiff {
+    /// Compare findings by fingerprint
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use codegraph_storage::domain::{AnalysisDiff, AnalysisFinding};
+    ///
+    /// let f = |fp: &str| AnalysisFinding::new(fp, serde_json::Value::Null);
+    /// let diff = AnalysisDiff::between(&[f("a"), f("b")], &[f("b"), f("c")]);
+    /// assert_eq!(diff.added, vec![f("c")]);
+    /// assert_eq!(diff.removed, vec![f("a")]);
+    /// assert_eq!(diff.unchanged, 1);
+    /// ```
+    pub fn between(base: &[AnalysisFinding], head: &[AnalysisFinding]) -> Self {
+        let base_fingerprints: HashSet<&str> =
+            base.iter().map(|f| f.fingerprint.as_str()).collect();
+        let head_fingerprints: HashSet<&str> =
+            head.iter().map(|f| f.fingerprint.as_str()).collect();
+
+        let mut seen = HashSet::new();
+        let added = head
+            .iter()
+            .filter(|f| !base_fingerprints.contains(f.fingerprint.as_str()))
+            .filter(|f| seen.insert(f.fingerprint.as_str()))
+            .cloned()
+            .collect();
+        let mut seen = HashSet::new();
+        let removed = base
+            .iter()
+            .filter(|f| !head_fingerprints.contains(f.fingerprint.as_str()))
+            .filter(|f| seen.insert(f.fingerprint.as_str()))
+            .cloned()
+            .collect();
+
+        Self {
+            added,
+            removed,
+            unchanged: base_fingerprints.intersection(&head_fingerprints).count(),
+        }
+    }
+
+    /// Whether the head snapshot introduced findings
+    pub fn has_regressions(&self) -> bool {
+        !self.added.is_empty()
+    }
+}
+
+/// Analysis result storage abstraction
+///
+/// One result per `(snapshot_id, kind)`; saving again replaces it
+/// (re-analysis of the same commit with a newer analyzer).
+///
+/// # Implementations
+///
+/// - `SqliteAnalysisResultStore` (RFC-102): SQLite adapter
+/// - PostgreSQL adapter (RFC-103)
+#[async_trait]
+pub trait AnalysisResultStore: Send + Sync {
+    /// Save (or replace) the result for `result.snapshot_id` / `result.kind`
+    async fn save_results(&self, result: &AnalysisResult) -> Result<()>;
+
+    /// Get the result of an analysis for a snapshot
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::ResultNotFound` if the snapshot was not analyzed
+    async fn get_results(&self, snapshot_id: &str, kind: AnalysisKind) -> Result<AnalysisResult>;
+
+    /// Findings reported in one file
+    async fn get_file_findings(
+        &self,
+        snapshot_id: &str,
+        kind: AnalysisKind,
+        file_path: &str,
+    ) -> Result<Vec<AnalysisFinding>>;
+
+    /// Snapshots with results for `kind` (newest first)
+    async fn list_analyzed_snapshots(&self, kind: AnalysisKind) -> Result<Vec<String>>;
+
+    /// Delete the result of an analysis for a snapshot (no-op if missing)
+    async fn delete_results(&self, snapshot_id: &str, kind: AnalysisKind) -> Result<()>;
+
+    /// Compare the findings of two analyzed snapshots
+    async fn compare_results(
+        &self,
+        base_snapshot_id: &str,
+        head_snapshot_id: &str,
+        kind: AnalysisKind,
+    ) -> Result<AnalysisDiff> {
+        let base = self.get_results(base_snapshot_id, kind).await?;
+        let head = self.get_results(head_snapshot_id, kind).await?;
+        Ok(AnalysisDiff::between(&base.findings, &head.findings))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(fingerprint: &str) -> AnalysisFinding {
+        AnalysisFinding::new(fingerprint, serde_json::Value::Null)
+    }
+
+    #[test]
+    fn test_analysis_kind_round_trip() {
+        for kind in [
+            AnalysisKind::Taint,
+            AnalysisKind::Heap,
+            AnalysisKind::CloneDetection,
+            AnalysisKind::Metrics,
+        ] {
+            assert_eq!(AnalysisKind::parse(kind.as_str()), Some(kind));
+            let json = serde_json::to_string(&kind).unwrap();
+            assert_eq!(json, format!("\"{}\"", kind));
+        }
+        assert_eq!(AnalysisKind::parse("unknown"), None);
+    }
+
+    #[test]
+    fn test_diff_between() {
+        let base = vec![finding("a"), finding("b"), finding("b")];
+        let head = vec![finding("b"), finding("c"), finding("c")];
+
+        let diff = AnalysisDiff::between(&base, &head);
+        assert_eq!(diff.added, vec![finding("c")]);
+        assert_eq!(diff.removed, vec![finding("a")]);
+        assert_eq!(diff.unchanged, 1);
+        assert!(diff.has_regressions());
+
+        let diff = AnalysisDiff::between(&base, &base);
+        assert!(!diff.has_regressions());
+        assert!(diff.removed.is_empty());
+    }
+
+    #[test]
+    fn test_analysis_result_serde() {
+        let result = AnalysisResult::new("abc123", AnalysisKind::Heap, serde_json::json!({"n": 1}))
+            .with_findings(vec![finding("a").with_file("lib.rs").with_line(3)]);
+
+        let json = serde_json::to_string(&result).unwrap();
+        let deserialized: AnalysisResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, result);
+    }
+}
@@ -12,10 +12,12 @@
 //! - `Chunk`: Code chunk within a file (no soft delete)
 //! - `Repository`: Repository metadata
 //! - `Dependency`: Cross-chunk/cross-file dependencies
+//! - `AnalysisResult`: Taint / heap / clone results of a snapshot
 //!
-//! # Port Trait
+//! # Port Traits
 //!
 //! - `CodeSnapshotStore`: Primary storage abstraction
+//! - `AnalysisResultStore`: Analysis results keyed by snapshot
 //!
 //! # Examples
 //!
@@ -52,6 +54,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::Result;
 
+pub mod analysis;
+
+pub use analysis::{
+    AnalysisDiff, AnalysisFinding, AnalysisKind, AnalysisResult, AnalysisResultStore,
+};
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Domain Models
 // ═══════════════════════════════════════════════════════════════════════════
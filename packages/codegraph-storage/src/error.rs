@@ -16,6 +16,8 @@ pub enum ErrorKind {
     RepositoryNotFound,
     /// Chunk not found
     ChunkNotFound,
+    /// Analysis result not found
+    ResultNotFound,
     /// Transaction errors
     Transaction,
     /// Configuration errors
@@ -32,6 +34,7 @@ impl ErrorKind {
             ErrorKind::SnapshotNotFound => "snapshot_not_found",
             ErrorKind::RepositoryNotFound => "repository_not_found",
             ErrorKind::ChunkNotFound => "chunk_not_found",
+            ErrorKind::ResultNotFound => "result_not_found",
             ErrorKind::Transaction => "transaction",
             ErrorKind::Config => "config",
             ErrorKind::IO => "io",
@@ -92,6 +95,13 @@ impl StorageError {
         )
     }
 
+    pub fn result_not_found(snapshot_id: impl Into<String>, kind: impl fmt::Display) -> Self {
+        Self::new(
+            ErrorKind::ResultNotFound,
+            format!("No {} results for snapshot: {}", kind, snapshot_id.into()),
+        )
+    }
+
     pub fn transaction(message: impl Into<String>) -> Self {
         Self::new(ErrorKind::Transaction, message)
     }
@@ -175,6 +185,17 @@ mod tests {
         assert!(msg.contains("my-repo"));
     }
 
+    #[test]
+    fn test_result_not_found() {
+        let err = StorageError::result_not_found("abc123def", "taint");
+        assert_eq!(err.kind, ErrorKind::ResultNotFound);
+
+        let msg = format!("{}", err);
+        assert!(msg.contains("[result_not_found]"));
+        assert!(msg.contains("taint"));
+        assert!(msg.contains("abc123def"));
+    }
+
     #[test]
     fn test_transaction_error() {
         let err = StorageError::transaction("ROLLBACK failed");
@@ -214,6 +235,7 @@ mod tests {
             "repository_not_found"
         );
         assert_eq!(ErrorKind::ChunkNotFound.as_str(), "chunk_not_found");
+        assert_eq!(ErrorKind::ResultNotFound.as_str(), "result_not_found");
         assert_eq!(ErrorKind::Transaction.as_str(), "transaction");
         assert_eq!(ErrorKind::Config.as_str(), "config");
         assert_eq!(ErrorKind::IO.as_str(), "io");
@@ -7,4 +7,4 @@
 pub mod sqlite;
 
 #[cfg(feature = "sqlite")]
-pub use sqlite::SqliteSnapshotStore;
+pub use sqlite::{SqliteAnalysisResultStore, SqliteSnapshotStore};
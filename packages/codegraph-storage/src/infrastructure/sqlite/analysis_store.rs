@@ -0,0 +1,335 @@
+//! SQLite adapter for AnalysisResultStore
+//!
+//! # Schema
+//!
+//! - `analysis_results`: one row per `(snapshot_id, kind)` with the full report
+//! - `analysis_findings`: findings of a result, indexed by file and by
+//!   fingerprint (snapshot comparison reads only this table)
+//!
+//! Saving a result replaces the previous one for the same snapshot and kind
+//! in a single transaction.
+
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::domain::{AnalysisFinding, AnalysisKind, AnalysisResult, AnalysisResultStore};
+use crate::{Result, StorageError};
+
+const SCHEMA: &str = "
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE IF NOT EXISTS analysis_results (
+    snapshot_id TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    report TEXT NOT NULL,
+    PRIMARY KEY (snapshot_id, kind)
+);
+
+CREATE TABLE IF NOT EXISTS analysis_findings (
+    snapshot_id TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    fingerprint TEXT NOT NULL,
+    file_path TEXT,
+    line INTEGER,
+    severity TEXT,
+    payload TEXT NOT NULL,
+    FOREIGN KEY (snapshot_id, kind)
+        REFERENCES analysis_results (snapshot_id, kind) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_findings_result
+    ON analysis_findings (snapshot_id, kind, file_path);
+CREATE INDEX IF NOT EXISTS idx_findings_fingerprint
+    ON analysis_findings (kind, fingerprint);
+";
+
+/// SQLite-backed analysis result store
+pub struct SqliteAnalysisResultStore {
+    conn: Mutex<Connection>,
+}
+
+impl SqliteAnalysisResultStore {
+    /// Open (or create) a store at `path`
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// In-memory store (tests, one-shot CI runs)
+    pub fn new_in_memory() -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    fn conn(&self) -> Result<MutexGuard<'_, Connection>> {
+        self.conn
+            .lock()
+            .map_err(|_| StorageError::database("SQLite connection lock poisoned"))
+    }
+
+    fn query_findings(
+        conn: &Connection,
+        sql: &str,
+        params: impl rusqlite::Params,
+    ) -> Result<Vec<AnalysisFinding>> {
+        let mut stmt = conn.prepare(sql)?;
+        let rows = stmt.query_map(params, |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, Option<String>>(1)?,
+                row.get::<_, Option<i64>>(2)?,
+                row.get::<_, Option<String>>(3)?,
+                row.get::<_, String>(4)?,
+            ))
+        })?;
+
+        let mut findings = Vec::new();
+        for row in rows {
+            let (fingerprint, file_path, line, severity, payload) = row?;
+            findings.push(AnalysisFinding {
+                fingerprint,
+                file_path,
+                line: line.map(|l| l as usize),
+                severity,
+                payload: serde_json::from_str(&payload)?,
+            });
+        }
+        Ok(findings)
+    }
+}
+
+#[async_trait]
+impl AnalysisResultStore for SqliteAnalysisResultStore {
+    async fn save_results(&self, result: &AnalysisResult) -> Result<()> {
+        let mut conn = self.conn()?;
+        let tx = conn.transaction()?;
+        let kind = result.kind.as_str();
+
+        // Replacing the result row cascades to its findings
+        tx.execute(
+            "DELETE FROM analysis_results WHERE snapshot_id = ?1 AND kind = ?2",
+            params![result.snapshot_id, kind],
+        )?;
+        tx.execute(
+            "INSERT INTO analysis_results (snapshot_id, kind, created_at, report)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![
+                result.snapshot_id,
+                kind,
+                result.created_at,
+                serde_json::to_string(&result.report)?
+            ],
+        )?;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT INTO analysis_findings
+                     (snapshot_id, kind, fingerprint, file_path, line, severity, payload)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            )?;
+            for finding in &result.findings {
+                stmt.execute(params![
+                    result.snapshot_id,
+                    kind,
+                    finding.fingerprint,
+                    finding.file_path,
+                    finding.line.map(|l| l as i64),
+                    finding.severity,
+                    serde_json::to_string(&finding.payload)?
+                ])?;
+            }
+        }
+        tx.commit()
+            .map_err(|e| StorageError::transaction(format!("Commit failed: {}", e)).with_source(e))
+    }
+
+    async fn get_results(&self, snapshot_id: &str, kind: AnalysisKind) -> Result<AnalysisResult> {
+        let conn = self.conn()?;
+        let row = conn
+            .query_row(
+                "SELECT created_at, report FROM analysis_results
+                 WHERE snapshot_id = ?1 AND kind = ?2",
+                params![snapshot_id, kind.as_str()],
+                |row| Ok((row.get::<_, DateTime<Utc>>(0)?, row.get::<_, String>(1)?)),
+            )
+            .optional()?;
+        let Some((created_at, report)) = row else {
+            return Err(StorageError::result_not_found(snapshot_id, kind));
+        };
+
+        let findings = Self::query_findings(
+            &conn,
+            "SELECT fingerprint, file_path, line, severity, payload FROM analysis_findings
+             WHERE snapshot_id = ?1 AND kind = ?2 ORDER BY rowid",
+            params![snapshot_id, kind.as_str()],
+        )?;
+        Ok(AnalysisResult {
+            snapshot_id: snapshot_id.to_string(),
+            kind,
+            created_at,
+            report: serde_json::from_str(&report)?,
+            findings,
+        })
+    }
+
+    async fn get_file_findings(
+        &self,
+        snapshot_id: &str,
+        kind: AnalysisKind,
+        file_path: &str,
+    ) -> Result<Vec<AnalysisFinding>> {
+        let conn = self.conn()?;
+        Self::query_findings(
+            &conn,
+            "SELECT fingerprint, file_path, line, severity, payload FROM analysis_findings
+             WHERE snapshot_id = ?1 AND kind = ?2 AND file_path = ?3 ORDER BY line, rowid",
+            params![snapshot_id, kind.as_str(), file_path],
+        )
+    }
+
+    async fn list_analyzed_snapshots(&self, kind: AnalysisKind) -> Result<Vec<String>> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT snapshot_id FROM analysis_results WHERE kind = ?1
+             ORDER BY created_at DESC, snapshot_id",
+        )?;
+        let ids = stmt
+            .query_map(params![kind.as_str()], |row| row.get(0))?
+            .collect::<rusqlite::Result<Vec<String>>>()?;
+        Ok(ids)
+    }
+
+    async fn delete_results(&self, snapshot_id: &str, kind: AnalysisKind) -> Result<()> {
+        self.conn()?.execute(
+            "DELETE FROM analysis_results WHERE snapshot_id = ?1 AND kind = ?2",
+            params![snapshot_id, kind.as_str()],
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    fn finding(fingerprint: &str, file_path: &str, line: usize) -> AnalysisFinding {
+        AnalysisFinding::new(fingerprint, serde_json::json!({ "id": fingerprint }))
+            .with_file(file_path)
+            .with_line(line)
+            .with_severity("high")
+    }
+
+    fn result(snapshot_id: &str, findings: Vec<AnalysisFinding>) -> AnalysisResult {
+        AnalysisResult::new(
+            snapshot_id,
+            AnalysisKind::Taint,
+            serde_json::json!({ "count": findings.len() }),
+        )
+        .with_findings(findings)
+    }
+
+    #[tokio::test]
+    async fn test_save_query_and_compare() {
+        let store = SqliteAnalysisResultStore::new_in_memory().unwrap();
+        store
+            .save_results(&result(
+                "base",
+                vec![finding("sqli", "api.py", 10), finding("xss", "views.py", 3)],
+            ))
+            .await
+            .unwrap();
+        store
+            .save_results(&result(
+                "head",
+                vec![finding("sqli", "api.py", 12), finding("cmdi", "api.py", 4)],
+            ))
+            .await
+            .unwrap();
+
+        let head = store
+            .get_results("head", AnalysisKind::Taint)
+            .await
+            .unwrap();
+        assert_eq!(head.report, serde_json::json!({ "count": 2 }));
+        assert_eq!(head.findings[1], finding("cmdi", "api.py", 4));
+
+        let in_file = store
+            .get_file_findings("head", AnalysisKind::Taint, "api.py")
+            .await
+            .unwrap();
+        let lines: Vec<_> = in_file.iter().map(|f| f.line).collect();
+        assert_eq!(lines, vec![Some(4), Some(12)]);
+
+        // Moved lines keep the fingerprint
+        let diff = store
+            .compare_results("base", "head", AnalysisKind::Taint)
+            .await
+            .unwrap();
+        assert_eq!(diff.added, vec![finding("cmdi", "api.py", 4)]);
+        assert_eq!(diff.removed, vec![finding("xss", "views.py", 3)]);
+        assert_eq!(diff.unchanged, 1);
+
+        // Results are per kind
+        let err = store
+            .get_results("head", AnalysisKind::Heap)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind, ErrorKind::ResultNotFound);
+        let analyzed = store
+            .list_analyzed_snapshots(AnalysisKind::Taint)
+            .await
+            .unwrap();
+        assert_eq!(analyzed.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_replace_and_delete() {
+        let store = SqliteAnalysisResultStore::new_in_memory().unwrap();
+        store
+            .save_results(&result("abc", vec![finding("a", "x.py", 1)]))
+            .await
+            .unwrap();
+        store.save_results(&result("abc", vec![])).await.unwrap();
+
+        let stored = store.get_results("abc", AnalysisKind::Taint).await.unwrap();
+        assert!(stored.findings.is_empty());
+
+        store
+            .delete_results("abc", AnalysisKind::Taint)
+            .await
+            .unwrap();
+        assert!(store.get_results("abc", AnalysisKind::Taint).await.is_err());
+        let orphans = store
+            .get_file_findings("abc", AnalysisKind::Taint, "x.py")
+            .await
+            .unwrap();
+        assert!(orphans.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_persists_across_connections() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("results.db");
+        {
+            let store = SqliteAnalysisResultStore::open(&path).unwrap();
+            store
+                .save_results(&result("abc", vec![finding("a", "x.py", 1)]))
+                .await
+                .unwrap();
+        }
+
+        let store = SqliteAnalysisResultStore::open(&path).unwrap();
+        let stored = store.get_results("abc", AnalysisKind::Taint).await.unwrap();
+        assert_eq!(stored.findings.len(), 1);
+    }
+}
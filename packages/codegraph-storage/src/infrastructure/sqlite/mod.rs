@@ -7,6 +7,13 @@
 //! - CodeSnapshotStore trait implementation
 //! - replace_file() implementation
 //! - Snapshot comparison queries
+//!
+//! Includes:
+//! - AnalysisResultStore implementation (analysis_store.rs)
+
+mod analysis_store;
+
+pub use analysis_store::SqliteAnalysisResultStore;
 
 pub struct SqliteSnapshotStore {
     // RFC-102: Implementation
@@ -16,6 +16,7 @@
 //! - ⏳ RFC-103: PostgreSQL adapter
 //! - ⏳ RFC-104: Snapshot diff & PR analysis
 //! - ⏳ RFC-105: Retention & history policy
+//! - ✅ Analysis results (taint, heap, clones) keyed by snapshot, SQLite adapter
 //!
 //! ## Usage
 //!
@@ -57,4 +58,7 @@ pub mod infrastructure;
 pub use error::{Result, StorageError};
 
 // Domain re-exports (RFC-101)
-pub use domain::{Chunk, CodeSnapshotStore, Dependency, Repository, Snapshot};
+pub use domain::{
+    AnalysisDiff, AnalysisFinding, AnalysisKind, AnalysisResult, AnalysisResultStore, Chunk,
+    CodeSnapshotStore, Dependency, Repository, Snapshot,
+};